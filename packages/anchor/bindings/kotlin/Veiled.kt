@@ -0,0 +1,2096 @@
+// Generated by `cargo xtask bindings` from target/idl/veiled.json. Do not edit.
+package veiled
+
+object Veiled {
+    const val PROGRAM_ID = "H6apEGZAw23AKUeqCX41wkDv2LVwX3Ec8oYPip7k3xzA"
+
+    object Instructions {
+        val ACKNOWLEDGE_ACCESS_LOG = byteArrayOf(-105, -40, 104, -45, 30, 79, -4, 29)
+        val ADD_PERSONHOOD_PROVIDER = byteArrayOf(127, 66, 3, 18, -77, -53, -61, -90)
+        val ADD_SECP256R1_VERIFIER = byteArrayOf(-100, -78, -83, -18, -111, -29, 119, -7)
+        val ADD_VERIFIER = byteArrayOf(-91, 72, -121, -31, 67, -75, -1, -121)
+        val ADVANCE_VERIFICATION_SESSION = byteArrayOf(39, -119, -72, -84, -11, 10, 56, 7)
+        val ARCHIVE_ATTESTATION = byteArrayOf(-123, -97, 110, 78, 53, -83, 16, -76)
+        val ATTACH_PERSONHOOD_ATTESTATION = byteArrayOf(41, 55, 121, 81, -25, -100, 79, -125)
+        val BAN_NULLIFIER = byteArrayOf(32, 10, -83, -44, -44, -116, 126, -48)
+        val BEGIN_MIGRATION = byteArrayOf(-56, 55, -93, -83, -90, -76, -7, 81)
+        val CANCEL_REVEAL_ESCROW = byteArrayOf(-46, 121, 29, -34, -85, -92, -65, 65)
+        val CHALLENGE_VERIFICATION = byteArrayOf(-56, 70, 70, -86, -115, -12, 27, 85)
+        val CLAIM_ALIAS = byteArrayOf(32, -52, -37, -15, 101, -35, 69, 16)
+        val CLAIM_DOMAIN_FEES = byteArrayOf(108, 69, 104, -74, -26, 23, 38, -41)
+        val CLAIM_USAGE_POINTS = byteArrayOf(-23, -101, -114, 86, 58, -64, -94, -88)
+        val CLAIM_USERNAME = byteArrayOf(-95, 41, 99, -1, -60, 83, -35, -108)
+        val CLOSE_EXPIRED_NULLIFIER = byteArrayOf(-68, -92, -13, -118, 115, -6, -17, -16)
+        val CLOSE_NOTIFICATION_CHANNEL = byteArrayOf(-123, 97, -2, 99, 122, 2, -60, 70)
+        val CLOSE_RESULT_RECEIPT = byteArrayOf(-95, 95, -46, -25, -53, -38, 125, -117)
+        val CLOSE_SESSION = byteArrayOf(68, 114, -78, -116, -34, 38, -8, -45)
+        val CLOSE_VERIFICATION_SESSION = byteArrayOf(-68, -53, 38, -45, -6, -19, 66, 113)
+        val COMPACT_ACCESS_LOGS = byteArrayOf(3, -48, -78, -89, 107, -59, 48, -35)
+        val COMPLETE_MIGRATION = byteArrayOf(-96, 78, 74, 46, 91, -123, -53, 44)
+        val CREATE_DOMAIN_FEE_VAULT_ATA = byteArrayOf(-67, 77, 94, -2, 56, 2, 49, 66)
+        val CREATE_IDENTITY_ROOT = byteArrayOf(58, 115, -119, 26, -20, -1, -39, -34)
+        val CREATE_TREASURY_ATA = byteArrayOf(-19, -69, -110, -95, -114, 53, 14, 69)
+        val DELEGATE_SESSION = byteArrayOf(82, 83, 119, 119, -60, -37, 5, -59)
+        val DEPRECATE_CIRCUIT = byteArrayOf(61, -106, -114, -59, 14, -77, 114, -114)
+        val FINALIZE_VERIFICATION_SESSION = byteArrayOf(101, 62, 26, 53, 113, -24, 98, 88)
+        val GLOBAL_LOGOUT = byteArrayOf(17, 89, -118, -31, -124, -108, -14, 112)
+        val GRANT_AUDITOR = byteArrayOf(39, 25, 30, 66, 99, -33, -41, 91)
+        val GRANT_PERMISSIONS = byteArrayOf(-27, -43, 34, 13, -85, -17, -94, 71)
+        val INIT_NULLIFIER_TREE = byteArrayOf(29, 120, -55, -80, 84, -4, -91, -26)
+        val INITIALIZE_CONFIG = byteArrayOf(-48, 127, 21, 1, -62, -66, -60, 70)
+        val INITIALIZE_DOMAIN_CONFIG = byteArrayOf(39, 42, 56, -45, -107, -9, 21, 87)
+        val ISSUE_VC_COMMITMENT = byteArrayOf(35, 79, 50, 12, 96, 47, 0, 39)
+        val LINK_IDENTITY_ROOT = byteArrayOf(-60, -47, -14, -50, 82, -122, -78, 93)
+        val LOG_PERMISSION_ACCESS = byteArrayOf(-79, 118, 71, 15, -36, 48, -59, -57)
+        val MIGRATE_GRANT = byteArrayOf(-54, -124, -77, -63, -63, -100, -28, -108)
+        val MIGRATE_NULLIFIER = byteArrayOf(12, 32, -67, -125, 43, 104, -6, -22)
+        val MIGRATE_SESSION = byteArrayOf(-80, -128, 4, 35, 63, 1, 118, 108)
+        val MIRROR_ATTESTATION = byteArrayOf(113, -93, -117, 46, -125, 101, 38, -33)
+        val OPEN_REVEAL_ESCROW = byteArrayOf(-59, -29, 72, 91, 79, 8, -40, -120)
+        val OPEN_VERIFICATION_SESSION = byteArrayOf(-23, -124, -4, -62, 21, 120, -6, -7)
+        val PAY_DOMAIN_FEE = byteArrayOf(73, -6, -19, 122, -56, -106, 51, 118)
+        val PAY_FEE = byteArrayOf(98, 25, -104, 0, 46, 9, -70, 61)
+        val PIN_VK_HASH = byteArrayOf(-96, -66, 110, 16, 67, -31, -97, -82)
+        val PUBLISH_POINTS_ROOT = byteArrayOf(-20, -33, 39, 34, -115, 3, 41, 69)
+        val RECOVER_IDENTITY = byteArrayOf(-20, -66, 126, -64, 28, -93, 102, 116)
+        val REGISTER_ATTESTOR = byteArrayOf(68, -55, 86, -12, 51, 113, 15, 94)
+        val REGISTER_CIRCUIT = byteArrayOf(-48, -9, -15, -120, 81, -90, -50, -57)
+        val REGISTER_DOMAIN = byteArrayOf(-20, 7, -48, -105, -83, -107, 73, 104)
+        val REGISTER_GROTH16_VK = byteArrayOf(-31, 22, 110, -115, 26, -44, -116, 26)
+        val REGISTER_HONK_VK = byteArrayOf(40, -15, -72, -33, -26, -59, 123, -37)
+        val RELEASE_REVEAL_ESCROW = byteArrayOf(35, -78, -80, -103, 59, 77, 66, -41)
+        val RELEASE_USERNAME = byteArrayOf(-40, -93, -68, 29, 37, 50, -78, 65)
+        val REMOVE_PERSONHOOD_PROVIDER = byteArrayOf(-46, -92, 28, 54, -76, -33, 24, 82)
+        val REMOVE_SECP256R1_VERIFIER = byteArrayOf(-120, 95, -100, 125, -23, 76, -25, 62)
+        val REMOVE_VERIFIER = byteArrayOf(-77, 9, -124, -73, -23, 23, -84, 111)
+        val RENEW_ATTESTATION = byteArrayOf(-47, -83, 109, 25, -1, 94, -53, -34)
+        val RENEW_SESSION = byteArrayOf(-9, 4, -100, 37, 60, 16, 86, 19)
+        val RESTORE_ATTESTATION = byteArrayOf(96, 104, -102, 1, -32, 82, -125, -65)
+        val REVOKE_ATTESTATION = byteArrayOf(12, -100, 103, -95, -62, -10, -45, -77)
+        val REVOKE_AUDITOR = byteArrayOf(-45, 3, -93, 88, 50, -123, 79, 124)
+        val REVOKE_PERMISSIONS = byteArrayOf(115, 59, -120, -29, -58, 42, -67, -20)
+        val REVOKE_SESSION_DELEGATE = byteArrayOf(-58, 34, 37, -81, -88, 55, 90, 0)
+        val ROTATE_VERIFIER_KEY = byteArrayOf(-85, 76, 76, 25, -42, 63, -101, -102)
+        val SELECT_VERIFIER = byteArrayOf(-58, -60, -26, -29, 43, -101, -96, 14)
+        val SET_ACCEPTED_MINT = byteArrayOf(-75, -6, -19, 63, -6, -68, 71, -74)
+        val SET_ASSURANCE_TIER = byteArrayOf(32, 98, 58, -7, -7, 68, 26, 15)
+        val SET_ATTESTOR_STATUS = byteArrayOf(68, 3, -39, -95, -62, -118, 121, 11)
+        val SET_CHALLENGE_WINDOW = byteArrayOf(-11, -72, 111, 8, 37, -47, -11, -57)
+        val SET_DOMAIN_ASSURANCE_TIER = byteArrayOf(-55, -119, 7, -67, 42, -128, 61, -97)
+        val SET_DOMAIN_ATTESTOR_CLAIMS = byteArrayOf(72, -74, -31, 21, 11, -106, -113, -104)
+        val SET_DOMAIN_EXPIRY_UNIT = byteArrayOf(69, -35, 87, -126, 74, 60, -62, -119)
+        val SET_DOMAIN_FEE_SHARE = byteArrayOf(16, -86, -112, 5, -110, 79, -84, 20)
+        val SET_DOMAIN_IDLE_TIMEOUT = byteArrayOf(70, 45, -44, -31, -34, 124, 54, -37)
+        val SET_DOMAIN_MAX_RESULT_AGE = byteArrayOf(91, -124, -74, -104, -108, -105, 110, -39)
+        val SET_DOMAIN_NULLIFIER_STORE = byteArrayOf(101, 118, -50, -73, 50, 28, 24, -90)
+        val SET_DOMAIN_ORIGINS = byteArrayOf(49, -90, -16, 4, 44, -8, -44, -67)
+        val SET_DOMAIN_PAUSED = byteArrayOf(52, 81, 48, -19, 20, -36, -92, -68)
+        val SET_DOMAIN_RATE_LIMIT = byteArrayOf(93, -63, -42, -97, -34, 115, 22, -111)
+        val SET_DOMAIN_ROLE = byteArrayOf(-72, 31, -87, 86, -35, -1, -52, 46)
+        val SET_DOMAIN_SESSION_BOUNDS = byteArrayOf(-58, 109, 64, 39, -16, 88, 2, -23)
+        val SET_DOMAIN_VERIFIERS = byteArrayOf(55, 61, 105, -102, 105, 73, -37, -118)
+        val SET_GUARDIANS = byteArrayOf(-90, 69, -116, -73, -99, -87, -3, 40)
+        val SET_NOTIFICATION_CHANNEL = byteArrayOf(72, 36, -116, -122, -112, -82, 75, -74)
+        val SET_PRICING = byteArrayOf(52, -56, -99, -64, 18, -122, 57, 123)
+        val SET_RECEIPT_RETENTION = byteArrayOf(79, 96, -114, 22, 66, 96, 112, -71)
+        val SET_RELAYER_QUOTA = byteArrayOf(-44, 116, 48, 90, 55, -7, -117, -90)
+        val SET_SAS_BRIDGE = byteArrayOf(50, 52, 2, -67, 23, -6, 53, -63)
+        val SET_VC_STATUS = byteArrayOf(-30, -109, 79, 105, 34, -89, 88, -77)
+        val SET_VERIFIER_GRACE_PERIOD = byteArrayOf(-72, 112, -43, -30, -56, -111, 62, -66)
+        val SET_VERIFIER_THRESHOLD = byteArrayOf(127, 76, -31, 127, 56, -122, -34, -112)
+        val SET_VERIFIER_WEIGHT = byteArrayOf(-7, 81, -71, 91, 107, -66, -45, 8)
+        val STORE_ATTESTATION = byteArrayOf(-94, 94, -41, -40, -31, 16, 16, -77)
+        val SWEEP_TREASURY_ATA = byteArrayOf(-127, 26, 124, -57, 100, 50, 91, 11)
+        val TOP_UP_RENT = byteArrayOf(-28, 107, -53, -68, -119, -87, -104, 122)
+        val TOUCH_SESSION = byteArrayOf(21, 54, -106, -110, 73, -127, 85, 78)
+        val TRANSFER_USERNAME = byteArrayOf(-7, -37, 120, -88, 30, -79, 38, 41)
+        val TRY_CHECK_PERMISSION = byteArrayOf(-87, -50, -97, -107, -110, 63, -106, 42)
+        val UNBAN_NULLIFIER = byteArrayOf(10, -93, 17, -42, -101, 9, -126, 56)
+        val UNLINK_IDENTITY_ROOT = byteArrayOf(81, -121, 21, -101, -31, -16, -104, 19)
+        val UPLOAD_PROOF_CHUNK = byteArrayOf(60, -41, 88, 47, -88, 107, 123, -106)
+        val VALIDATE_ATTESTATION = byteArrayOf(-6, -24, 36, -113, -107, 1, 73, -36)
+        val VALIDATE_SESSION_DELEGATE = byteArrayOf(117, 19, 30, 120, 82, 102, -124, -27)
+        val VALIDATE_SESSION_TOKEN = byteArrayOf(-83, 31, -74, -87, -70, 78, -4, 105)
+        val VERIFIER_HEARTBEAT = byteArrayOf(25, -18, -35, 14, -6, -108, 0, -116)
+        val VERIFY_AUTH = byteArrayOf(-72, -47, 18, 17, 66, -11, 80, 76)
+        val VERIFY_AUTH_BATCH = byteArrayOf(-85, 65, 26, -58, -6, 57, 124, -76)
+        val VERIFY_AUTH_COMPRESSED = byteArrayOf(-89, -79, 112, 87, -127, -30, 60, 104)
+        val VERIFY_AUTH_GROTH16 = byteArrayOf(18, -89, -82, 76, 18, -70, -13, 2)
+        val VERIFY_AUTH_SHARDED = byteArrayOf(-35, 87, 113, -28, 108, 109, -66, -13)
+        val VERIFY_AUTH_V1 = byteArrayOf(54, 111, 0, -63, -109, -2, -54, -120)
+        val VERIFY_AUTH_WITH_CONSENT = byteArrayOf(0, -47, 92, -35, 58, 108, -11, 56)
+        val VERIFY_AUTH_WITH_DEVICE = byteArrayOf(-83, -49, 0, -116, -91, 98, 16, -60)
+        val VERIFY_AUTH_WITH_ORIGIN = byteArrayOf(1, -21, -79, 47, -80, 74, -22, 48)
+        val WRITE_HONK_VK = byteArrayOf(-128, -57, -86, -100, 70, -81, 110, 33)
+    }
+
+    object Accounts {
+        val ACCESS_LOG_SUMMARY = byteArrayOf(-9, 49, 84, -8, -122, 13, 85, -51)
+        val ALIAS = byteArrayOf(-81, 23, 49, 34, 113, 79, -27, -52)
+        val ARCHIVED_PERSONHOOD_ATTESTATION = byteArrayOf(74, 40, -36, -120, -26, -12, -77, -28)
+        val ASSURANCE_POLICY = byteArrayOf(68, -99, -23, 110, -81, -4, 7, 81)
+        val ATTESTATION = byteArrayOf(-104, 125, -73, 86, 36, -110, 121, 73)
+        val ATTESTOR = byteArrayOf(-3, -16, 76, -60, 16, 53, -17, -83)
+        val AUDITOR_GRANT = byteArrayOf(18, -76, 34, -39, -5, 105, -89, 40)
+        val CIRCUIT_REGISTRY = byteArrayOf(-8, 61, 106, 55, -118, -104, 88, 37)
+        val DOMAIN_CONFIG = byteArrayOf(-55, -24, -44, -27, 59, -15, 106, -59)
+        val EXPIRY_HISTOGRAM = byteArrayOf(-127, -31, 9, -127, -70, 118, 16, 125)
+        val FEE_SHARE_POLICY = byteArrayOf(78, 118, 108, 56, -95, -44, -12, 46)
+        val GROTH16_VERIFYING_KEY = byteArrayOf(14, 89, 2, -93, 52, 90, 23, -96)
+        val GUARDIAN_SET = byteArrayOf(120, 77, 74, 98, 34, 83, 96, 125)
+        val HONK_VERIFYING_KEY = byteArrayOf(-38, -117, 32, -2, -83, -53, -115, -126)
+        val IDENTITY_ROOT = byteArrayOf(-82, -64, 112, 51, 48, 57, -45, -61)
+        val LOGOUT_EPOCH = byteArrayOf(-18, 25, 18, 99, -19, -10, 107, -68)
+        val MIGRATION_STATE = byteArrayOf(95, -110, -121, 64, -111, 25, -59, 115)
+        val NOTIFICATION_CHANNEL = byteArrayOf(-56, -37, -122, 10, 125, -87, 114, 80)
+        val NULLIFIER_ACCOUNT = byteArrayOf(-6, 31, -18, -79, -43, 98, 48, -84)
+        val NULLIFIER_BAN = byteArrayOf(69, -97, 117, -26, 31, 14, -51, -58)
+        val NULLIFIER_LINK = byteArrayOf(-52, -70, -31, -48, -42, 19, -76, 81)
+        val NULLIFIER_SHARD = byteArrayOf(-59, -99, 1, -110, -100, -119, -45, 61)
+        val NULLIFIER_TREE = byteArrayOf(-37, 121, 3, -32, 21, 113, -40, 116)
+        val PAYER_RATE_LIMIT = byteArrayOf(92, -64, -67, 53, -2, 82, 49, 62)
+        val PERMISSION_ACCESS = byteArrayOf(31, 40, 88, -74, 105, -114, -24, 36)
+        val PERMISSION_GRANT = byteArrayOf(-32, 114, -124, -56, 84, -80, 71, 71)
+        val PERSONHOOD_ATTESTATION = byteArrayOf(-123, 103, 115, -2, -95, -120, -112, -22)
+        val PERSONHOOD_PROVIDER_REGISTRY = byteArrayOf(27, 15, -98, -106, 46, -23, 22, -62)
+        val POINTS = byteArrayOf(-124, 81, 12, -110, -1, -23, 97, -14)
+        val POINTS_ROOT = byteArrayOf(32, 107, 79, 72, -16, 114, -106, 122)
+        val PROOF_RECORD = byteArrayOf(-19, 59, -101, -84, -52, 117, 87, 44)
+        val PROTOCOL_CONFIG = byteArrayOf(-49, 91, -6, 28, -104, -77, -41, -47)
+        val PROTOCOL_STATS = byteArrayOf(-123, -29, -40, -15, -21, -84, 35, 31)
+        val RESULT_RECEIPT = byteArrayOf(-75, -17, -100, 8, -43, -127, -126, -34)
+        val REVEAL_ESCROW = byteArrayOf(-55, 113, 96, -77, 54, -42, -40, 106)
+        val SAS_BRIDGE = byteArrayOf(13, -100, 25, -62, 119, -123, 61, -81)
+        val SECP256R1_VERIFIER_REGISTRY = byteArrayOf(32, -38, -69, -86, 29, 62, 57, -81)
+        val SESSION_DELEGATE = byteArrayOf(-20, 108, -128, -56, 59, -57, -16, 74)
+        val USERNAME = byteArrayOf(-107, 84, 41, 80, -79, -53, -24, -88)
+        val VC_COMMITMENT = byteArrayOf(-108, 122, 22, -122, 45, -121, -18, -27)
+        val VERIFICATION_SESSION = byteArrayOf(-9, 69, -57, 26, 112, -61, -13, -2)
+        val VERIFIER_REGISTRY = byteArrayOf(21, -37, -88, -121, 51, -74, 88, -127)
+        val VERIFIER_WEIGHTS = byteArrayOf(-63, -72, 76, -114, 15, -112, 58, -62)
+    }
+
+    object Events {
+        val ACCEPTED_MINT_UPDATED_EVENT = byteArrayOf(-114, 115, 91, -3, -3, 104, 98, -57)
+        val ACCESS_LOG_ACKNOWLEDGED_EVENT = byteArrayOf(109, 61, 113, 124, -81, 58, -99, -42)
+        val ACCESS_LOGS_COMPACTED_EVENT = byteArrayOf(-87, -25, -7, 65, 98, -43, 100, 50)
+        val ALIAS_CLAIMED_EVENT = byteArrayOf(69, -54, 5, -75, -41, 82, 102, -48)
+        val ASSURANCE_TIER_SET_EVENT = byteArrayOf(-33, 0, 30, -43, 1, -52, -80, -126)
+        val ATTESTATION_MIRRORED_EVENT = byteArrayOf(119, -41, -38, 43, -47, 93, 43, -41)
+        val ATTESTATION_RENEWED_EVENT = byteArrayOf(-48, -34, -71, -42, -20, -54, -77, -16)
+        val ATTESTATION_REVOKED_EVENT = byteArrayOf(-101, -2, -55, -110, 102, -44, -72, 44)
+        val ATTESTATION_STORED_EVENT = byteArrayOf(-60, -46, 47, -30, -47, -92, -91, -124)
+        val ATTESTOR_REGISTERED_EVENT = byteArrayOf(13, -116, 29, -22, -16, -115, -30, 56)
+        val ATTESTOR_STATUS_SET_EVENT = byteArrayOf(-2, 26, 58, -53, 126, 31, -114, 80)
+        val AUDITOR_GRANTED_EVENT = byteArrayOf(39, -87, 35, 63, 104, -83, -46, -82)
+        val AUDITOR_REVOKED_EVENT = byteArrayOf(73, 110, 50, 12, 39, 122, 113, -62)
+        val AUTH_VERIFIED_EVENT = byteArrayOf(45, -22, 106, 84, 98, 85, 14, 125)
+        val CHALLENGE_WINDOW_SET_EVENT = byteArrayOf(27, 44, 21, -110, -38, -83, 33, 46)
+        val CIRCUIT_DEPRECATED_EVENT = byteArrayOf(-20, 75, 120, 123, -42, 117, 48, -28)
+        val CIRCUIT_REGISTERED_EVENT = byteArrayOf(-81, 74, 81, -105, 50, -32, -127, -12)
+        val COMPRESSED_NULLIFIER_REGISTERED_EVENT = byteArrayOf(-51, -19, -73, 40, -68, 86, 64, 124)
+        val DOMAIN_ASSURANCE_TIER_SET_EVENT = byteArrayOf(-118, 79, 73, -20, 23, 64, -108, 7)
+        val DOMAIN_ATTESTOR_CLAIMS_SET_EVENT = byteArrayOf(87, -15, 84, -85, 35, -1, -69, -12)
+        val DOMAIN_CONFIG_INITIALIZED_EVENT = byteArrayOf(-106, -83, -22, 4, -20, 1, -16, 13)
+        val DOMAIN_EXPIRY_UNIT_SET_EVENT = byteArrayOf(-68, 85, -115, 109, 60, 5, 32, 4)
+        val DOMAIN_FEE_PAID_EVENT = byteArrayOf(-17, -94, 98, -10, 70, 18, 32, 43)
+        val DOMAIN_FEE_SHARE_SET_EVENT = byteArrayOf(-99, 93, 118, -10, 29, -79, -39, 27)
+        val DOMAIN_FEE_VAULT_ATA_CREATED_EVENT = byteArrayOf(110, 66, -77, -113, 70, 82, -1, -71)
+        val DOMAIN_FEES_CLAIMED_EVENT = byteArrayOf(118, 93, -115, -39, -124, 99, 77, -120)
+        val DOMAIN_IDLE_TIMEOUT_SET_EVENT = byteArrayOf(27, -62, 6, -19, -37, -66, -35, -98)
+        val DOMAIN_MAX_RESULT_AGE_SET_EVENT = byteArrayOf(49, 81, -92, -104, 74, -13, 37, -119)
+        val DOMAIN_NULLIFIER_STORE_SET_EVENT = byteArrayOf(-20, 115, 3, -31, 56, -53, 3, 13)
+        val DOMAIN_ORIGINS_SET_EVENT = byteArrayOf(35, 93, 84, -11, 93, 102, 5, 73)
+        val DOMAIN_PAUSED_SET_EVENT = byteArrayOf(20, 122, 95, -105, 74, 104, -57, -36)
+        val DOMAIN_RATE_LIMIT_SET_EVENT = byteArrayOf(-15, 69, -6, -53, -64, 11, -25, 8)
+        val DOMAIN_REGISTERED_EVENT = byteArrayOf(116, 0, 51, 23, -81, 108, -46, 19)
+        val DOMAIN_ROLE_SET_EVENT = byteArrayOf(-41, 34, 45, 27, 95, -19, 18, 60)
+        val DOMAIN_SESSION_BOUNDS_SET_EVENT = byteArrayOf(-9, 27, -124, -75, 32, 105, 95, -42)
+        val DOMAIN_VERIFIERS_SET_EVENT = byteArrayOf(109, -41, -4, 38, -12, 67, 41, 9)
+        val EXPIRED_NULLIFIER_CLOSED_EVENT = byteArrayOf(-96, 92, -59, -75, -99, -96, -68, 78)
+        val FEE_PAID_EVENT = byteArrayOf(-25, -109, -125, 1, 60, -77, 4, 4)
+        val GLOBAL_LOGOUT_EVENT = byteArrayOf(-1, 35, 50, -17, -79, -86, 120, 72)
+        val GROTH16_VK_REGISTERED_EVENT = byteArrayOf(-105, 60, 117, -52, 4, 94, 70, 104)
+        val GUARDIANS_SET_EVENT = byteArrayOf(-114, 108, 4, 63, 117, -102, -71, -63)
+        val HONK_VK_REGISTERED_EVENT = byteArrayOf(-84, -68, -60, -88, 58, -121, -27, -15)
+        val IDENTITY_RECOVERED_EVENT = byteArrayOf(62, 52, -15, 76, -59, 89, 31, -72)
+        val IDENTITY_ROOT_CREATED_EVENT = byteArrayOf(-79, -98, -113, 120, 87, 70, 45, 107)
+        val IDENTITY_ROOT_LINKED_EVENT = byteArrayOf(11, 24, 71, -49, 114, 25, -4, -7)
+        val IDENTITY_ROOT_UNLINKED_EVENT = byteArrayOf(77, -106, 32, 4, 46, 19, -124, 25)
+        val MIGRATION_BEGUN_EVENT = byteArrayOf(109, -112, 33, 124, -35, -82, -101, -102)
+        val MIGRATION_COMPLETED_EVENT = byteArrayOf(-68, -120, -84, -32, -121, 77, -50, -31)
+        val MIGRATION_PROGRESS_EVENT = byteArrayOf(85, 123, -50, 35, -39, -128, -48, -119)
+        val NOTIFICATION_CHANNEL_CLOSED_EVENT = byteArrayOf(-30, -78, -19, -126, 6, -15, -13, 119)
+        val NOTIFICATION_CHANNEL_SET_EVENT = byteArrayOf(37, -86, -43, 81, -115, 121, -37, -76)
+        val NULLIFIER_BANNED_EVENT = byteArrayOf(118, 85, 124, -87, -53, 45, -126, -77)
+        val NULLIFIER_MIGRATED_EVENT = byteArrayOf(112, -9, -59, 97, 109, -88, -44, 75)
+        val NULLIFIER_TREE_INITIALIZED_EVENT = byteArrayOf(64, -27, -8, -42, 99, -84, -116, -4)
+        val NULLIFIER_UNBANNED_EVENT = byteArrayOf(123, -103, -40, -11, -83, 113, 21, -103)
+        val PERMISSION_ACCESSED_EVENT = byteArrayOf(-125, -91, 107, -49, 58, -58, 121, 50)
+        val PERMISSION_DENIED_EVENT = byteArrayOf(-107, -37, 27, 49, -95, 25, -89, -27)
+        val PERMISSION_GRANTED_EVENT = byteArrayOf(-47, 124, -42, -71, 117, -104, -64, 29)
+        val PERMISSION_REVOKED_EVENT = byteArrayOf(-19, -61, 120, 61, 46, 58, -11, -58)
+        val PERSONHOOD_ATTESTATION_ARCHIVED_EVENT = byteArrayOf(63, -46, 32, 66, -18, -75, -69, 87)
+        val PERSONHOOD_ATTESTATION_RESTORED_EVENT = byteArrayOf(-93, -65, -44, -1, -37, 126, -121, -89)
+        val PERSONHOOD_ATTESTED_EVENT = byteArrayOf(-17, -36, -4, -74, -63, -41, -95, 83)
+        val PERSONHOOD_PROVIDER_ADDED_EVENT = byteArrayOf(-122, 47, 29, -1, 65, -79, 11, 15)
+        val PERSONHOOD_PROVIDER_REMOVED_EVENT = byteArrayOf(61, -97, 94, -112, 103, 125, -39, 88)
+        val POINTS_ROOT_PUBLISHED_EVENT = byteArrayOf(-75, 46, 86, 55, 90, -106, 70, 86)
+        val PRICING_UPDATED_EVENT = byteArrayOf(-35, 26, 39, 100, -10, -96, -16, 111)
+        val RECEIPT_RETENTION_SET_EVENT = byteArrayOf(72, 27, -34, -86, 84, -49, -72, -89)
+        val RELAYER_QUOTA_SET_EVENT = byteArrayOf(105, 5, -127, -105, 43, 82, 50, 104)
+        val RENT_TOPPED_UP_EVENT = byteArrayOf(28, 23, 127, 76, -61, 62, 114, -12)
+        val RESULT_RECEIPT_CLOSED_EVENT = byteArrayOf(-111, 81, 85, -43, 120, -119, 25, -94)
+        val REVEAL_ESCROW_CANCELLED_EVENT = byteArrayOf(75, 66, -17, -110, 2, -120, 37, 19)
+        val REVEAL_ESCROW_OPENED_EVENT = byteArrayOf(61, -128, -5, 27, 127, -89, 126, -77)
+        val REVEAL_ESCROW_RELEASED_EVENT = byteArrayOf(22, -8, 94, -25, -75, -3, 99, -83)
+        val SAS_BRIDGE_SET_EVENT = byteArrayOf(84, 45, -127, 35, 47, 69, -63, 25)
+        val SECP256R1_VERIFIER_ADDED_EVENT = byteArrayOf(100, 41, -82, 115, 86, 49, 82, 121)
+        val SECP256R1_VERIFIER_REMOVED_EVENT = byteArrayOf(71, 72, -85, 2, 69, 8, -47, 7)
+        val SESSION_CLOSED_EVENT = byteArrayOf(120, -75, -99, 20, 60, -3, 37, 3)
+        val SESSION_DELEGATE_REVOKED_EVENT = byteArrayOf(36, 48, 103, 5, 108, 118, -17, 41)
+        val SESSION_DELEGATED_EVENT = byteArrayOf(-82, -101, -33, -38, -109, -93, 10, 19)
+        val SESSION_RENEWED_EVENT = byteArrayOf(-98, 5, 27, 90, -114, 5, -107, -53)
+        val SHARDED_NULLIFIER_REGISTERED_EVENT = byteArrayOf(-111, 22, 58, 88, 62, 13, 65, -59)
+        val TREASURY_ATA_CREATED_EVENT = byteArrayOf(-14, -28, -124, -12, -68, 62, 114, -55)
+        val TREASURY_SWEPT_EVENT = byteArrayOf(43, -119, 93, -63, 125, 26, 88, -112)
+        val USAGE_POINTS_CLAIMED_EVENT = byteArrayOf(-35, -43, 77, 123, 38, 119, 14, -47)
+        val USERNAME_CLAIMED_EVENT = byteArrayOf(38, 50, -79, -108, 64, 73, -13, 71)
+        val USERNAME_RELEASED_EVENT = byteArrayOf(-76, -96, -67, 51, 116, 90, 27, 3)
+        val USERNAME_TRANSFERRED_EVENT = byteArrayOf(9, -109, -31, -33, 101, -17, -112, -5)
+        val VC_COMMITMENT_ISSUED_EVENT = byteArrayOf(86, -57, -51, -72, -47, 108, 31, -34)
+        val VC_STATUS_CHANGED_EVENT = byteArrayOf(-101, 81, 63, -45, -56, -24, 66, -13)
+        val VERIFICATION_CHALLENGED_EVENT = byteArrayOf(114, 31, 63, -22, 113, -76, -102, -98)
+        val VERIFICATION_SESSION_OPENED_EVENT = byteArrayOf(-29, 76, 109, 14, -92, -33, -88, 115)
+        val VERIFICATION_SESSION_RESOLVED_EVENT = byteArrayOf(-13, -123, 100, 9, -48, 4, 6, 106)
+        val VERIFIER_ADDED_EVENT = byteArrayOf(-13, 75, 0, 79, 15, 102, -90, 86)
+        val VERIFIER_GRACE_PERIOD_SET_EVENT = byteArrayOf(122, -57, 32, -21, -36, 99, -12, 9)
+        val VERIFIER_KEY_ROTATED_EVENT = byteArrayOf(-8, -97, 89, -56, 21, 95, -114, 51)
+        val VERIFIER_REMOVED_EVENT = byteArrayOf(64, 70, 21, -82, 6, -106, 28, 108)
+        val VERIFIER_THRESHOLD_SET_EVENT = byteArrayOf(-52, 127, -94, -59, 51, -70, 59, -79)
+        val VERIFIER_WEIGHT_SET_EVENT = byteArrayOf(18, 44, 114, -5, -55, 89, -41, -38)
+        val VK_HASH_PINNED_EVENT = byteArrayOf(-71, 82, 61, 98, -85, -75, -18, -76)
+    }
+}
+
+data class AddPersonhoodProviderArgs(
+    val provider: String,
+)
+
+data class AddSecp256r1VerifierArgs(
+    val verifier: ByteArray,
+)
+
+data class AddVerifierArgs(
+    val verifier: String,
+)
+
+data class AdvanceVerificationSessionArgs(
+    val maxSteps: UByte,
+)
+
+data class AttachPersonhoodAttestationArgs(
+    val nullifier: ByteArray,
+    val provider: String,
+    val score: UShort,
+    val issuedAt: Long,
+    val expiresAt: Long,
+)
+
+data class BanNullifierArgs(
+    val nullifier: ByteArray,
+)
+
+data class BeginMigrationArgs(
+    val targetVersion: UShort,
+    val steps: List<MigrationStep>,
+    val deadline: Long,
+)
+
+data class ChallengeVerificationArgs(
+    val evidence: ByteArray,
+    val domain: ByteArray,
+)
+
+data class ClaimAliasArgs(
+    val handleHash: ByteArray,
+)
+
+data class ClaimDomainFeesArgs(
+    val amount: ULong,
+)
+
+data class ClaimUsagePointsArgs(
+    val nullifier: ByteArray,
+    val amount: ULong,
+    val leafIndex: UInt,
+    val proof: List<ByteArray>,
+)
+
+data class ClaimUsernameArgs(
+    val name: String,
+)
+
+data class DelegateSessionArgs(
+    val delegate: String,
+    val scope: ULong,
+    val expiresIn: Long,
+)
+
+data class DeprecateCircuitArgs(
+    val circuitId: UInt,
+)
+
+data class GrantAuditorArgs(
+    val nullifier: ByteArray,
+    val auditor: String,
+    val nameHash: ByteArray,
+    val expiresIn: ExpiryKind,
+)
+
+data class GrantPermissionsArgs(
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val appId: String,
+    val permissions: List<Permission>,
+    val expiresIn: ExpiryKind,
+)
+
+data class InitNullifierTreeArgs(
+    val maxDepth: UInt,
+    val maxBufferSize: UInt,
+)
+
+data class InitializeDomainConfigArgs(
+    val domain: ByteArray,
+)
+
+data class IssueVcCommitmentArgs(
+    val nullifier: ByteArray,
+    val vcHash: ByteArray,
+)
+
+data class LinkIdentityRootArgs(
+    val linkageProofHash: ByteArray,
+)
+
+data class LogPermissionAccessArgs(
+    val permissionUsed: Permission,
+    val metadata: String,
+    val domain: ByteArray,
+)
+
+data class MigrateGrantArgs(
+    val nullifier: ByteArray,
+    val appId: String,
+)
+
+data class MigrateNullifierArgs(
+    val result: SignedVerificationResult,
+    val newNullifier: ByteArray,
+    val expiresIn: Long,
+)
+
+data class MigrateSessionArgs(
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+)
+
+data class OpenRevealEscrowArgs(
+    val ciphertext: ByteArray,
+    val keyCommitment: ByteArray,
+    val delaySecs: Long,
+)
+
+data class OpenVerificationSessionArgs(
+    val circuitId: UInt,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val proofHash: ByteArray,
+    val publicInputsCount: UShort,
+)
+
+data class PayDomainFeeArgs(
+    val maxAmount: ULong,
+)
+
+data class PayFeeArgs(
+    val maxAmount: ULong,
+)
+
+data class PinVkHashArgs(
+    val vkHash: ByteArray,
+)
+
+data class PublishPointsRootArgs(
+    val root: ByteArray,
+    val leafCount: UInt,
+)
+
+data class RecoverIdentityArgs(
+    val result: SignedVerificationResult,
+    val nullifier: ByteArray,
+    val newNullifier: ByteArray,
+    val domain: ByteArray,
+)
+
+data class RegisterAttestorArgs(
+    val issuer: String,
+    val nameHash: ByteArray,
+    val claimMask: UByte,
+)
+
+data class RegisterCircuitArgs(
+    val circuitId: UInt,
+    val vkHash: ByteArray,
+)
+
+data class RegisterDomainArgs(
+    val domain: ByteArray,
+    val verifier: String?,
+    val defaultSessionSecs: Long,
+    val maxResultAgeSecs: UInt,
+    val maxPermissions: UByte,
+)
+
+data class RegisterGroth16VkArgs(
+    val circuitId: UInt,
+    val alphaG1: ByteArray,
+    val betaG2: ByteArray,
+    val gammaG2: ByteArray,
+    val deltaG2: ByteArray,
+    val ic: List<ByteArray>,
+)
+
+data class RegisterHonkVkArgs(
+    val circuitId: UInt,
+    val logCircuitSize: UByte,
+    val publicInputsSize: UInt,
+    val pubInputsOffset: UInt,
+)
+
+data class ReleaseRevealEscrowArgs(
+    val revealKey: ByteArray,
+)
+
+data class RemovePersonhoodProviderArgs(
+    val provider: String,
+)
+
+data class RemoveSecp256r1VerifierArgs(
+    val verifier: ByteArray,
+)
+
+data class RemoveVerifierArgs(
+    val verifier: String,
+)
+
+data class RenewAttestationArgs(
+    val result: SignedVerificationResult,
+    val expiresIn: Long,
+)
+
+data class RenewSessionArgs(
+    val result: SignedVerificationResult,
+    val domain: ByteArray,
+    val expiresIn: Long,
+)
+
+data class RestoreAttestationArgs(
+    val nullifier: ByteArray,
+    val provider: String,
+    val score: UShort,
+    val issuedAt: Long,
+    val expiresAt: Long,
+)
+
+data class RotateVerifierKeyArgs(
+    val oldKey: String,
+    val newKey: String,
+)
+
+data class SelectVerifierArgs(
+    val nullifier: ByteArray,
+)
+
+data class SetAcceptedMintArgs(
+    val mint: String,
+    val priceFeedId: ByteArray,
+    val accepted: Boolean,
+)
+
+data class SetAssuranceTierArgs(
+    val tier: UByte,
+    val maxResultAgeSecs: UInt,
+)
+
+data class SetAttestorStatusArgs(
+    val status: AttestorStatus,
+)
+
+data class SetChallengeWindowArgs(
+    val challengeWindowSecs: Long,
+)
+
+data class SetDomainAssuranceTierArgs(
+    val assuranceTier: UByte,
+)
+
+data class SetDomainAttestorClaimsArgs(
+    val claimMask: UByte,
+)
+
+data class SetDomainExpiryUnitArgs(
+    val sessionExpiryUnit: ExpiryUnit,
+)
+
+data class SetDomainFeeShareArgs(
+    val domainShareBps: UShort,
+)
+
+data class SetDomainIdleTimeoutArgs(
+    val idleTimeoutSecs: Long,
+)
+
+data class SetDomainMaxResultAgeArgs(
+    val maxResultAgeSecs: UInt,
+)
+
+data class SetDomainNullifierStoreArgs(
+    val nullifierStore: NullifierStore,
+)
+
+data class SetDomainOriginsArgs(
+    val origins: List<ByteArray>,
+)
+
+data class SetDomainPausedArgs(
+    val paused: Boolean,
+)
+
+data class SetDomainRateLimitArgs(
+    val rateLimit: UInt,
+)
+
+data class SetDomainRoleArgs(
+    val role: DomainRole,
+    val key: String,
+)
+
+data class SetDomainSessionBoundsArgs(
+    val minSessionSecs: Long,
+    val maxSessionSecs: Long,
+)
+
+data class SetDomainVerifiersArgs(
+    val verifiers: List<String>,
+)
+
+data class SetGuardiansArgs(
+    val guardians: List<String>,
+    val threshold: UByte,
+)
+
+data class SetNotificationChannelArgs(
+    val channelCommitment: ByteArray,
+    val encryptedToken: ByteArray,
+)
+
+data class SetPricingArgs(
+    val feeUsdMicros: ULong,
+    val maxPriceAgeSecs: ULong,
+    val maxPriceConfBps: UShort,
+)
+
+data class SetReceiptRetentionArgs(
+    val receiptRetentionSecs: Long,
+)
+
+data class SetRelayerQuotaArgs(
+    val relayer: String,
+    val quota: UInt,
+)
+
+data class SetSasBridgeArgs(
+    val credential: String,
+    val schema: String,
+)
+
+data class SetVcStatusArgs(
+    val status: VcStatus,
+)
+
+data class SetVerifierGracePeriodArgs(
+    val gracePeriodSecs: Long,
+)
+
+data class SetVerifierThresholdArgs(
+    val threshold: UByte,
+)
+
+data class SetVerifierWeightArgs(
+    val verifier: String,
+    val weight: UByte,
+)
+
+data class StoreAttestationArgs(
+    val result: SignedVerificationResult,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val expiresIn: Long,
+)
+
+data class SweepTreasuryAtaArgs(
+    val amount: ULong,
+)
+
+data class TryCheckPermissionArgs(
+    val permission: Permission,
+)
+
+data class UnlinkIdentityRootArgs(
+    val nullifier: ByteArray,
+)
+
+data class UploadProofChunkArgs(
+    val offset: UInt,
+    val chunk: ByteArray,
+)
+
+data class ValidateSessionDelegateArgs(
+    val permission: Permission,
+)
+
+data class VerifyAuthArgs(
+    val result: SignedVerificationResult,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val expiresIn: Long,
+)
+
+data class VerifyAuthBatchArgs(
+    val entries: List<VerifyAuthEntry>,
+)
+
+data class VerifyAuthCompressedArgs(
+    val result: SignedVerificationResult,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val proof: NonInclusionProof,
+)
+
+data class VerifyAuthGroth16Args(
+    val circuitId: UInt,
+    val proof: ByteArray,
+    val publicInputs: List<ByteArray>,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+)
+
+data class VerifyAuthShardedArgs(
+    val result: SignedVerificationResult,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+)
+
+data class VerifyAuthV1Args(
+    val verificationResult: ByteArray,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+)
+
+data class VerifyAuthWithConsentArgs(
+    val result: SignedVerificationResult,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val consent: UserConsent,
+)
+
+data class VerifyAuthWithDeviceArgs(
+    val result: SignedVerificationResult,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val deviceKey: String,
+    val expiresIn: Long,
+)
+
+data class VerifyAuthWithOriginArgs(
+    val result: SignedVerificationResult,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val originHash: ByteArray?,
+)
+
+data class WriteHonkVkArgs(
+    val circuitId: UInt,
+    val offset: UByte,
+    val commitments: List<ByteArray>,
+)
+
+data class AcceptedMint(
+    val mint: String,
+    val priceFeedId: ByteArray,
+)
+
+data class AcceptedMintUpdatedEvent(
+    val mint: String,
+    val priceFeedId: ByteArray,
+    val accepted: Boolean,
+)
+
+data class AccessLogAcknowledgedEvent(
+    val nullifier: ByteArray,
+    val auditor: String,
+    val permissionAccess: String,
+    val acknowledgedAt: Long,
+)
+
+data class AccessLogSummary(
+    val permissionGrant: String,
+    val root: ByteArray,
+    val compactedCount: ULong,
+    val batchCount: ULong,
+    val lastAccessedAt: Long,
+    val bump: UByte,
+)
+
+data class AccessLogsCompactedEvent(
+    val permissionGrant: String,
+    val accessLogs: List<String>,
+    val batchRoot: ByteArray,
+    val summaryRoot: ByteArray,
+    val compactedCount: ULong,
+    val reward: ULong,
+)
+
+data class Alias(
+    val handleHash: ByteArray,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val authority: String,
+    val claimedAt: Long,
+    val bump: UByte,
+)
+
+data class AliasClaimedEvent(
+    val handleHash: ByteArray,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val claimedAt: Long,
+)
+
+data class ArchivedPersonhoodAttestation(
+    val attestationHash: ByteArray,
+    val issuedAt: Long,
+    val archivedAt: Long,
+    val bump: UByte,
+)
+
+data class AssurancePolicy(
+    val maxResultAgeSecs: List<UInt>,
+    val bump: UByte,
+)
+
+data class AssuranceTierSetEvent(
+    val tier: UByte,
+    val maxResultAgeSecs: UInt,
+)
+
+data class Attestation(
+    val nullifier: ByteArray,
+    val claimType: ClaimType,
+    val domain: ByteArray,
+    val claimDataHash: ByteArray,
+    val circuitId: UInt,
+    val issuer: String,
+    val issuedAt: Long,
+    val expiresAt: Long,
+    val bump: UByte,
+    val revokedAt: Long,
+)
+
+data class AttestationMirroredEvent(
+    val nullifier: ByteArray,
+    val claimType: ClaimType,
+    val domain: ByteArray,
+    val sasAttestation: String,
+    val expiresAt: Long,
+)
+
+data class AttestationRenewedEvent(
+    val nullifier: ByteArray,
+    val claimType: ClaimType,
+    val domain: ByteArray,
+    val claimDataHash: ByteArray,
+    val issuer: String,
+    val expiresAt: Long,
+)
+
+data class AttestationRevokedEvent(
+    val nullifier: ByteArray,
+    val claimType: ClaimType,
+    val domain: ByteArray,
+    val issuer: String,
+    val revokedAt: Long,
+)
+
+data class AttestationStoredEvent(
+    val nullifier: ByteArray,
+    val claimType: ClaimType,
+    val domain: ByteArray,
+    val claimDataHash: ByteArray,
+    val issuer: String,
+    val expiresAt: Long,
+)
+
+data class Attestor(
+    val issuer: String,
+    val nameHash: ByteArray,
+    val claimMask: UByte,
+    val status: AttestorStatus,
+    val registeredAt: Long,
+    val bump: UByte,
+)
+
+data class AttestorRegisteredEvent(
+    val issuer: String,
+    val nameHash: ByteArray,
+    val claimMask: UByte,
+)
+
+sealed class AttestorStatus {
+    object Active : AttestorStatus()
+    object Suspended : AttestorStatus()
+}
+
+data class AttestorStatusSetEvent(
+    val issuer: String,
+    val status: AttestorStatus,
+)
+
+data class AuditorGrant(
+    val nullifier: ByteArray,
+    val auditor: String,
+    val nameHash: ByteArray,
+    val grantedBy: String,
+    val grantedAt: Long,
+    val expiresAt: ExpiryKind,
+    val revoked: Boolean,
+    val bump: UByte,
+)
+
+data class AuditorGrantedEvent(
+    val nullifier: ByteArray,
+    val auditor: String,
+    val nameHash: ByteArray,
+    val grantedAt: Long,
+    val expiresAt: ExpiryKind,
+)
+
+data class AuditorRevokedEvent(
+    val nullifier: ByteArray,
+    val auditor: String,
+    val revokedAt: Long,
+)
+
+data class AuthVerifiedEvent(
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val proofHash: ByteArray,
+    val expiresAt: ExpiryKind,
+)
+
+data class ChallengeWindowSetEvent(
+    val domain: ByteArray,
+    val challengeWindowSecs: Long,
+)
+
+data class CircuitDeprecatedEvent(
+    val circuitId: UInt,
+    val vkHash: ByteArray,
+)
+
+data class CircuitEntry(
+    val circuitId: UInt,
+    val vkHash: ByteArray,
+    val deprecated: Boolean,
+)
+
+data class CircuitRegisteredEvent(
+    val circuitId: UInt,
+    val vkHash: ByteArray,
+)
+
+data class CircuitRegistry(
+    val circuits: List<CircuitEntry>,
+    val bump: UByte,
+)
+
+sealed class ClaimType {
+    object Auth : ClaimType()
+    object BalanceThreshold : ClaimType()
+    object AgeOver : ClaimType()
+    object NftOwnership : ClaimType()
+}
+
+data class CompressedNullifierRegisteredEvent(
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val proofHash: ByteArray,
+    val leafIndex: UInt,
+    val lowIndex: UInt,
+    val nextIndex: UInt,
+    val nextValue: ByteArray,
+    val registeredAt: Long,
+)
+
+data class DomainAssuranceTierSetEvent(
+    val domain: ByteArray,
+    val assuranceTier: UByte,
+)
+
+data class DomainAttestorClaimsSetEvent(
+    val domain: ByteArray,
+    val claimMask: UByte,
+)
+
+data class DomainConfig(
+    val domain: ByteArray,
+    val owner: String,
+    val pinnedVkHash: ByteArray,
+    val verifiers: List<String>,
+    val bump: UByte,
+    val challengeWindowSecs: Long,
+    val rateLimit: UInt,
+    val assuranceTier: UByte,
+    val maxResultAgeSecs: UInt,
+    val operator: String,
+    val treasurer: String,
+    val paused: Boolean,
+    val allowedOrigins: List<ByteArray>,
+    val minSessionSecs: Long,
+    val maxSessionSecs: Long,
+    val attestorClaimMask: UByte,
+    val idleTimeoutSecs: Long,
+    val nullifierStore: NullifierStore,
+    val maxPermissions: UByte,
+    val sessionExpiryUnit: ExpiryUnit,
+    val defaultSessionSecs: Long,
+)
+
+data class DomainConfigInitializedEvent(
+    val domain: ByteArray,
+    val owner: String,
+)
+
+data class DomainExpiryUnitSetEvent(
+    val domain: ByteArray,
+    val sessionExpiryUnit: ExpiryUnit,
+)
+
+data class DomainFeePaidEvent(
+    val payer: String,
+    val domain: ByteArray,
+    val mint: String,
+    val feeUsdMicros: ULong,
+    val amount: ULong,
+    val domainAmount: ULong,
+    val price: Long,
+    val exponent: Int,
+    val publishTime: Long,
+)
+
+data class DomainFeeShareSetEvent(
+    val domainShareBps: UShort,
+)
+
+data class DomainFeeVaultAtaCreatedEvent(
+    val domain: ByteArray,
+    val mint: String,
+    val domainFeeVaultAta: String,
+)
+
+data class DomainFeesClaimedEvent(
+    val domain: ByteArray,
+    val mint: String,
+    val destination: String,
+    val authority: String,
+    val amount: ULong,
+)
+
+data class DomainIdleTimeoutSetEvent(
+    val domain: ByteArray,
+    val idleTimeoutSecs: Long,
+)
+
+data class DomainMaxResultAgeSetEvent(
+    val domain: ByteArray,
+    val maxResultAgeSecs: UInt,
+)
+
+data class DomainNullifierStoreSetEvent(
+    val domain: ByteArray,
+    val nullifierStore: NullifierStore,
+)
+
+data class DomainOriginsSetEvent(
+    val domain: ByteArray,
+    val origins: List<ByteArray>,
+)
+
+data class DomainPausedSetEvent(
+    val domain: ByteArray,
+    val paused: Boolean,
+    val authority: String,
+)
+
+data class DomainRateLimitSetEvent(
+    val domain: ByteArray,
+    val rateLimit: UInt,
+)
+
+data class DomainRegisteredEvent(
+    val domain: ByteArray,
+    val owner: String,
+    val verifier: String?,
+    val defaultSessionSecs: Long,
+    val maxResultAgeSecs: UInt,
+    val maxPermissions: UByte,
+)
+
+sealed class DomainRole {
+    object Owner : DomainRole()
+    object Operator : DomainRole()
+    object Treasurer : DomainRole()
+}
+
+data class DomainRoleSetEvent(
+    val domain: ByteArray,
+    val role: DomainRole,
+    val key: String,
+)
+
+data class DomainSessionBoundsSetEvent(
+    val domain: ByteArray,
+    val minSessionSecs: Long,
+    val maxSessionSecs: Long,
+)
+
+data class DomainVerifiersSetEvent(
+    val domain: ByteArray,
+    val verifiers: List<String>,
+)
+
+data class ExpiredNullifierClosedEvent(
+    val nullifier: ByteArray,
+    val payer: String,
+    val cranker: String,
+    val reward: ULong,
+    val closedAt: Long,
+)
+
+data class ExpiryHistogram(
+    val domain: ByteArray,
+    val buckets: List<ULong>,
+    val slotGrants: ULong,
+    val bump: UByte,
+)
+
+sealed class ExpiryKind {
+    data class UnixTime(
+        val _0: Long,
+    ) : ExpiryKind()
+    data class Slot(
+        val _0: ULong,
+    ) : ExpiryKind()
+}
+
+sealed class ExpiryUnit {
+    object UnixTime : ExpiryUnit()
+    object Slot : ExpiryUnit()
+}
+
+data class FeePaidEvent(
+    val payer: String,
+    val mint: String,
+    val feeUsdMicros: ULong,
+    val amount: ULong,
+    val price: Long,
+    val exponent: Int,
+    val publishTime: Long,
+)
+
+data class FeeSharePolicy(
+    val domainShareBps: UShort,
+    val bump: UByte,
+)
+
+data class GlobalLogoutEvent(
+    val identity: String,
+    val loggedOutAt: Long,
+)
+
+data class Groth16VerifyingKey(
+    val circuitId: UInt,
+    val alphaG1: ByteArray,
+    val betaG2: ByteArray,
+    val gammaG2: ByteArray,
+    val deltaG2: ByteArray,
+    val ic: List<ByteArray>,
+    val bump: UByte,
+)
+
+data class Groth16VkRegisteredEvent(
+    val circuitId: UInt,
+    val vkHash: ByteArray,
+    val publicInputs: UByte,
+)
+
+data class GuardianSet(
+    val nullifier: ByteArray,
+    val guardians: List<String>,
+    val threshold: UByte,
+    val nonce: ULong,
+    val updatedAt: Long,
+    val bump: UByte,
+)
+
+data class GuardiansSetEvent(
+    val nullifier: ByteArray,
+    val guardians: List<String>,
+    val threshold: UByte,
+    val nonce: ULong,
+    val updatedAt: Long,
+)
+
+data class HonkVerifyingKey(
+    val circuitId: UInt,
+    val logCircuitSize: UByte,
+    val publicInputsSize: UInt,
+    val pubInputsOffset: UInt,
+    val commitments: List<ByteArray>,
+    val written: UInt,
+    val bump: UByte,
+)
+
+data class HonkVkRegisteredEvent(
+    val circuitId: UInt,
+    val vkHash: ByteArray,
+    val logCircuitSize: UByte,
+    val publicInputsSize: UInt,
+)
+
+data class IdentityRecoveredEvent(
+    val nullifier: ByteArray,
+    val newNullifier: ByteArray,
+    val domain: ByteArray,
+    val proofHash: ByteArray,
+    val newAuthority: String,
+    val sessions: UInt,
+    val grants: UInt,
+    val approvals: UByte,
+    val recoveredAt: Long,
+)
+
+data class IdentityRoot(
+    val owner: String,
+    val members: List<RootMember>,
+    val createdAt: Long,
+    val bump: UByte,
+)
+
+data class IdentityRootCreatedEvent(
+    val root: String,
+    val owner: String,
+    val createdAt: Long,
+)
+
+data class IdentityRootLinkedEvent(
+    val root: String,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val linkageProofHash: ByteArray,
+    val linkedAt: Long,
+)
+
+data class IdentityRootUnlinkedEvent(
+    val root: String,
+    val nullifier: ByteArray,
+    val unlinkedAt: Long,
+)
+
+data class LogoutEpoch(
+    val identity: String,
+    val loggedOutAt: Long,
+    val bump: UByte,
+)
+
+sealed class MigratedAccount {
+    object Session : MigratedAccount()
+    object ProofRecord : MigratedAccount()
+    object PermissionGrant : MigratedAccount()
+    object DomainConfig : MigratedAccount()
+}
+
+data class MigrationBegunEvent(
+    val version: UShort,
+    val targetVersion: UShort,
+    val steps: List<MigrationStep>,
+    val deadline: Long,
+)
+
+data class MigrationCompletedEvent(
+    val version: UShort,
+    val steps: List<MigrationStep>,
+    val forced: Boolean,
+)
+
+data class MigrationProgressEvent(
+    val targetVersion: UShort,
+    val account: MigratedAccount,
+    val migrated: ULong,
+    val total: ULong,
+)
+
+data class MigrationState(
+    val version: UShort,
+    val targetVersion: UShort,
+    val steps: List<MigrationStep>,
+    val startedAt: Long,
+    val deadline: Long,
+    val bump: UByte,
+)
+
+data class MigrationStep(
+    val account: MigratedAccount,
+    val required: Boolean,
+    val total: ULong,
+    val migrated: ULong,
+)
+
+data class NonInclusionProof(
+    val root: ByteArray,
+    val lowIndex: UInt,
+    val lowValue: ByteArray,
+    val lowNextIndex: UInt,
+    val lowNextValue: ByteArray,
+)
+
+data class NotificationChannel(
+    val nullifier: ByteArray,
+    val appId: String,
+    val authority: String,
+    val channelCommitment: ByteArray,
+    val encryptedToken: ByteArray,
+    val updatedAt: Long,
+    val bump: UByte,
+)
+
+data class NotificationChannelClosedEvent(
+    val nullifier: ByteArray,
+    val appId: String,
+    val closedAt: Long,
+)
+
+data class NotificationChannelSetEvent(
+    val nullifier: ByteArray,
+    val appId: String,
+    val channelCommitment: ByteArray,
+    val updatedAt: Long,
+)
+
+data class NullifierAccount(
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val domainLen: UByte,
+    val authority: String,
+    val createdAt: Long,
+    val lastActiveAt: Long,
+    val idleTimeoutSecs: Long,
+    val expiresAt: ExpiryKind,
+    val publicInputsHash: ByteArray,
+    val circuitId: UInt,
+    val proofHash: ByteArray,
+    val verifiedAt: Long,
+    val verifier: String,
+    val finalizesAt: Long,
+    val payer: String,
+    val deviceKey: String,
+    val bump: UByte,
+)
+
+data class NullifierBan(
+    val domain: ByteArray,
+    val nullifier: ByteArray,
+    val bannedBy: String,
+    val bannedAt: Long,
+    val bump: UByte,
+)
+
+data class NullifierBannedEvent(
+    val domain: ByteArray,
+    val nullifier: ByteArray,
+    val bannedAt: Long,
+)
+
+data class NullifierLink(
+    val nullifier: ByteArray,
+    val successor: ByteArray,
+    val domain: ByteArray,
+    val proofHash: ByteArray,
+    val authority: String,
+    val linkedAt: Long,
+    val bump: UByte,
+)
+
+data class NullifierMigratedEvent(
+    val nullifier: ByteArray,
+    val successor: ByteArray,
+    val domain: ByteArray,
+    val proofHash: ByteArray,
+    val grants: List<String>,
+    val migratedAt: Long,
+)
+
+data class NullifierShard(
+    val bits: ByteArray,
+    val domain: ByteArray,
+    val registered: UInt,
+    val shard: UShort,
+    val bump: UByte,
+    val padding: ByteArray,
+)
+
+sealed class NullifierStore {
+    object Accounts : NullifierStore()
+    object Bitmap : NullifierStore()
+    object Compressed : NullifierStore()
+}
+
+data class NullifierTree(
+    val domain: ByteArray,
+    val merkleTree: String,
+    val nextIndex: UInt,
+    val bump: UByte,
+)
+
+data class NullifierTreeInitializedEvent(
+    val domain: ByteArray,
+    val merkleTree: String,
+    val maxDepth: UInt,
+    val maxBufferSize: UInt,
+)
+
+data class NullifierUnbannedEvent(
+    val domain: ByteArray,
+    val nullifier: ByteArray,
+    val unbannedAt: Long,
+)
+
+data class PayerRateLimit(
+    val payer: String,
+    val currentBucket: Long,
+    val buckets: List<UInt>,
+    val quota: UInt,
+    val bump: UByte,
+    val padding: ByteArray,
+)
+
+sealed class Permission {
+    object RevealWalletAddress : Permission()
+    object RevealExactBalance : Permission()
+    object RevealTokenBalances : Permission()
+    object RevealNFTList : Permission()
+    object RevealTransactionHistory : Permission()
+    object RevealStakingPositions : Permission()
+    object RevealDeFiPositions : Permission()
+    object SignTransactions : Permission()
+    object ReceiveNotifications : Permission()
+}
+
+data class PermissionAccess(
+    val permissionGrant: String,
+    val accessedAt: Long,
+    val permissionUsed: Permission,
+    val metadata: String,
+    val payer: String,
+)
+
+data class PermissionAccessedEvent(
+    val nullifier: ByteArray,
+    val appId: String,
+    val permission: Permission,
+    val permissionMask: ULong,
+    val accessedAt: Long,
+)
+
+sealed class PermissionDenialReason {
+    object Revoked : PermissionDenialReason()
+    object Expired : PermissionDenialReason()
+    object NotGranted : PermissionDenialReason()
+}
+
+data class PermissionDeniedEvent(
+    val nullifier: ByteArray,
+    val appId: String,
+    val permission: Permission,
+    val permissionMask: ULong,
+    val reason: PermissionDenialReason,
+    val checkedAt: Long,
+)
+
+data class PermissionGrant(
+    val nullifier: ByteArray,
+    val appId: String,
+    val permissions: List<Permission>,
+    val grantedAt: Long,
+    val expiresAt: ExpiryKind,
+    val revoked: Boolean,
+    val bump: UByte,
+    val permissionMask: ULong,
+    val successor: ByteArray,
+)
+
+data class PermissionGrantedEvent(
+    val nullifier: ByteArray,
+    val appId: String,
+    val permissions: List<Permission>,
+    val permissionMask: ULong,
+    val grantedAt: Long,
+    val expiresAt: ExpiryKind,
+)
+
+data class PermissionRevokedEvent(
+    val nullifier: ByteArray,
+    val appId: String,
+    val revokedAt: Long,
+)
+
+data class PersonhoodAttestation(
+    val nullifier: ByteArray,
+    val provider: String,
+    val score: UShort,
+    val issuedAt: Long,
+    val expiresAt: Long,
+    val bump: UByte,
+)
+
+data class PersonhoodAttestationArchivedEvent(
+    val nullifier: ByteArray,
+    val provider: String,
+    val attestationHash: ByteArray,
+    val archivedAt: Long,
+)
+
+data class PersonhoodAttestationRestoredEvent(
+    val nullifier: ByteArray,
+    val provider: String,
+    val score: UShort,
+    val issuedAt: Long,
+    val expiresAt: Long,
+)
+
+data class PersonhoodAttestedEvent(
+    val nullifier: ByteArray,
+    val provider: String,
+    val score: UShort,
+    val issuedAt: Long,
+    val expiresAt: Long,
+)
+
+data class PersonhoodProviderAddedEvent(
+    val provider: String,
+)
+
+data class PersonhoodProviderRegistry(
+    val providers: List<String>,
+    val bump: UByte,
+)
+
+data class PersonhoodProviderRemovedEvent(
+    val provider: String,
+)
+
+data class Points(
+    val nullifier: ByteArray,
+    val balance: ULong,
+    val claimedAt: Long,
+    val bump: UByte,
+)
+
+data class PointsRoot(
+    val root: ByteArray,
+    val leafCount: UInt,
+    val publishedAt: Long,
+    val bump: UByte,
+)
+
+data class PointsRootPublishedEvent(
+    val root: ByteArray,
+    val leafCount: UInt,
+    val publishedAt: Long,
+)
+
+data class PricingUpdatedEvent(
+    val feeUsdMicros: ULong,
+    val maxPriceAgeSecs: ULong,
+    val maxPriceConfBps: UShort,
+)
+
+data class ProofRecord(
+    val proofHash: ByteArray,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val createdAt: Long,
+    val bump: UByte,
+)
+
+data class ProtocolConfig(
+    val authority: String,
+    val acceptedMints: List<AcceptedMint>,
+    val feeUsdMicros: ULong,
+    val maxPriceAgeSecs: ULong,
+    val maxPriceConfBps: UShort,
+    val bump: UByte,
+)
+
+data class ProtocolStats(
+    val totalVerifications: ULong,
+    val totalGrants: ULong,
+    val totalRevocations: ULong,
+    val totalAttestations: ULong,
+    val bump: UByte,
+)
+
+data class ReceiptRetentionSetEvent(
+    val receiptRetentionSecs: Long,
+)
+
+data class RelayerQuotaSetEvent(
+    val relayer: String,
+    val quota: UInt,
+)
+
+data class RentToppedUpEvent(
+    val account: String,
+    val payer: String,
+    val lamports: ULong,
+)
+
+data class ResultReceipt(
+    val signatureHash: ByteArray,
+    val payer: String,
+    val consumedAt: Long,
+    val retainUntil: Long,
+    val bump: UByte,
+)
+
+data class ResultReceiptClosedEvent(
+    val signatureHash: ByteArray,
+    val payer: String,
+    val closedAt: Long,
+)
+
+data class RevealEscrow(
+    val nullifier: ByteArray,
+    val appId: String,
+    val authority: String,
+    val ciphertext: ByteArray,
+    val keyCommitment: ByteArray,
+    val revealKey: ByteArray,
+    val openedAt: Long,
+    val revealsAt: Long,
+    val released: Boolean,
+    val bump: UByte,
+)
+
+data class RevealEscrowCancelledEvent(
+    val nullifier: ByteArray,
+    val appId: String,
+    val released: Boolean,
+    val cancelledAt: Long,
+)
+
+data class RevealEscrowOpenedEvent(
+    val nullifier: ByteArray,
+    val appId: String,
+    val keyCommitment: ByteArray,
+    val revealsAt: Long,
+)
+
+data class RevealEscrowReleasedEvent(
+    val nullifier: ByteArray,
+    val appId: String,
+    val revealKey: ByteArray,
+    val releasedAt: Long,
+)
+
+data class RootMember(
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val linkageProofHash: ByteArray,
+    val linkedAt: Long,
+)
+
+data class SasBridge(
+    val credential: String,
+    val schema: String,
+    val bump: UByte,
+)
+
+data class SasBridgeSetEvent(
+    val credential: String,
+    val schema: String,
+)
+
+data class Secp256r1VerifierAddedEvent(
+    val verifier: ByteArray,
+)
+
+data class Secp256r1VerifierRegistry(
+    val verifiers: List<ByteArray>,
+    val bump: UByte,
+)
+
+data class Secp256r1VerifierRemovedEvent(
+    val verifier: ByteArray,
+)
+
+data class SessionClosedEvent(
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val authority: String,
+    val closedAt: Long,
+)
+
+data class SessionDelegate(
+    val session: String,
+    val delegate: String,
+    val scope: ULong,
+    val createdAt: Long,
+    val expiresAt: Long,
+    val authority: String,
+    val bump: UByte,
+)
+
+data class SessionDelegateRevokedEvent(
+    val session: String,
+    val delegate: String,
+    val revokedAt: Long,
+)
+
+data class SessionDelegatedEvent(
+    val nullifier: ByteArray,
+    val session: String,
+    val delegate: String,
+    val scope: ULong,
+    val expiresAt: Long,
+)
+
+data class SessionDetails(
+    val nullifierAccount: String,
+    val createdAt: Long,
+    val expiresAt: ExpiryKind,
+)
+
+data class SessionRenewedEvent(
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val proofHash: ByteArray,
+    val expiresAt: ExpiryKind,
+)
+
+data class ShardedNullifierRegisteredEvent(
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val proofHash: ByteArray,
+    val shard: UShort,
+    val registeredAt: Long,
+)
+
+data class SignedVerificationResult(
+    val isValid: Boolean,
+    val proofHash: ByteArray,
+    val timestamp: ULong,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val publicInputsHash: ByteArray?,
+    val circuitId: UInt?,
+    val vkHash: ByteArray?,
+    val clusterId: ByteArray?,
+    val instanceId: ByteArray?,
+    val claimType: ClaimType?,
+    val tagged: Boolean,
+    val signature: ByteArray,
+)
+
+data class TreasuryAtaCreatedEvent(
+    val mint: String,
+    val treasuryAta: String,
+)
+
+data class TreasurySweptEvent(
+    val mint: String,
+    val destination: String,
+    val amount: ULong,
+)
+
+data class UsagePointsClaimedEvent(
+    val nullifier: ByteArray,
+    val amount: ULong,
+    val root: ByteArray,
+    val claimedAt: Long,
+)
+
+data class UserConsent(
+    val user: String,
+    val expiresAt: Long,
+)
+
+data class Username(
+    val name: ByteArray,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val authority: String,
+    val claimedAt: Long,
+    val transferredAt: Long,
+    val bump: UByte,
+)
+
+data class UsernameClaimedEvent(
+    val name: ByteArray,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val claimedAt: Long,
+)
+
+data class UsernameReleasedEvent(
+    val name: ByteArray,
+    val nullifier: ByteArray,
+    val releasedAt: Long,
+)
+
+data class UsernameTransferredEvent(
+    val name: ByteArray,
+    val fromNullifier: ByteArray,
+    val toNullifier: ByteArray,
+    val transferredAt: Long,
+)
+
+data class VcCommitment(
+    val nullifier: ByteArray,
+    val issuer: String,
+    val vcHash: ByteArray,
+    val status: VcStatus,
+    val issuedAt: Long,
+    val updatedAt: Long,
+    val bump: UByte,
+)
+
+data class VcCommitmentIssuedEvent(
+    val nullifier: ByteArray,
+    val issuer: String,
+    val vcHash: ByteArray,
+    val issuedAt: Long,
+)
+
+sealed class VcStatus {
+    object Active : VcStatus()
+    object Suspended : VcStatus()
+    object Revoked : VcStatus()
+}
+
+data class VcStatusChangedEvent(
+    val nullifier: ByteArray,
+    val issuer: String,
+    val vcHash: ByteArray,
+    val status: VcStatus,
+    val updatedAt: Long,
+)
+
+data class VerificationChallengedEvent(
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val proofHash: ByteArray,
+    val challenger: String,
+    val challengedAt: Long,
+)
+
+data class VerificationSession(
+    val authority: String,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val circuitId: UInt,
+    val proofHash: ByteArray,
+    val proofLen: UInt,
+    val publicInputsCount: UShort,
+    val bytesReceived: UInt,
+    val status: VerificationStatus,
+    val step: UInt,
+    val openedAt: Long,
+    val bump: UByte,
+)
+
+data class VerificationSessionOpenedEvent(
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+    val circuitId: UInt,
+    val authority: String,
+    val proofLen: UInt,
+    val steps: UInt,
+)
+
+data class VerificationSessionResolvedEvent(
+    val nullifier: ByteArray,
+    val authority: String,
+    val verified: Boolean,
+    val steps: UInt,
+)
+
+sealed class VerificationStatus {
+    object Uploading : VerificationStatus()
+    object Verifying : VerificationStatus()
+    object Verified : VerificationStatus()
+    object Rejected : VerificationStatus()
+}
+
+data class VerifierAddedEvent(
+    val verifier: String,
+)
+
+data class VerifierGracePeriodSetEvent(
+    val gracePeriodSecs: Long,
+)
+
+data class VerifierKeyRotatedEvent(
+    val oldKey: String,
+    val newKey: String,
+    val rotatedAt: Long,
+    val graceEndsAt: Long,
+)
+
+data class VerifierRegistry(
+    val verifiers: List<String>,
+    val threshold: UByte,
+    val bump: UByte,
+    val rotations: List<VerifierRotation>,
+    val gracePeriodSecs: Long,
+    val receiptRetentionSecs: Long,
+)
+
+data class VerifierRemovedEvent(
+    val verifier: String,
+)
+
+data class VerifierRotation(
+    val oldKey: String,
+    val newKey: String,
+    val rotatedAt: Long,
+)
+
+data class VerifierThresholdSetEvent(
+    val threshold: UByte,
+)
+
+data class VerifierWeight(
+    val verifier: String,
+    val weight: UByte,
+    val lastHeartbeat: Long,
+)
+
+data class VerifierWeightSetEvent(
+    val verifier: String,
+    val weight: UByte,
+)
+
+data class VerifierWeights(
+    val entries: List<VerifierWeight>,
+    val bump: UByte,
+)
+
+data class VerifyAuthEntry(
+    val result: SignedVerificationResult,
+    val nullifier: ByteArray,
+    val domain: ByteArray,
+)
+
+data class VkHashPinnedEvent(
+    val domain: ByteArray,
+    val vkHash: ByteArray,
+)
+
+enum class VeiledError(val code: Int, val msg: String) {
+    INVALID_PROOF(6000, "Invalid proof"),
+    DUPLICATE_NULLIFIER(6001, "Nullifier already used"),
+    PROOF_EXPIRED(6002, "Proof expired"),
+    DOMAIN_TOO_LONG(6003, "Domain string exceeds maximum length of 255 characters"),
+    SANDBOX_UNAVAILABLE(6004, "The sandbox domain is only available on devnet builds"),
+    INVALID_PUBLIC_INPUTS(6005, "Invalid public inputs"),
+    OFFSET_MISMATCH(6006, "Offset mismatch - points to wrong instruction"),
+    INVALID_INSTRUCTION_DATA(6007, "Invalid instruction data"),
+    INVALID_SIGNATURE_COUNT(6008, "Invalid signature count"),
+    INVALID_MESSAGE_SIZE(6009, "Invalid message size"),
+    PROOF_HASH_MISMATCH(6010, "Proof hash mismatch"),
+    IS_VALID_MISMATCH(6011, "Is valid mismatch"),
+    AUTHORITY_MISMATCH(6012, "Authority public key mismatch"),
+    NULLIFIER_MISMATCH(6013, "Nullifier mismatch - result was signed for a different nullifier"),
+    DOMAIN_MISMATCH(6014, "Domain mismatch - result was signed for a different domain"),
+    UNSUPPORTED_RESULT_VERSION(6015, "Unsupported verification result version"),
+    PUBLIC_INPUTS_MISMATCH(6016, "Public inputs hash mismatch - result was signed for different public inputs"),
+    CIRCUIT_MISMATCH(6017, "Circuit mismatch - result was signed for a different circuit"),
+    VK_HASH_MISMATCH(6018, "Verification key hash mismatch"),
+    CLUSTER_MISMATCH(6019, "Verification result was signed for a different cluster"),
+    INSTANCE_MISMATCH(6020, "Verification result was signed for a different Veiled instance"),
+    PROOF_ALREADY_USED(6021, "Proof has already been used to register a nullifier"),
+    INVALID_VERIFICATION_BATCH(6022, "Verification batch must be 1-4 results, each with its nullifier, proof record, domain config and result receipt accounts"),
+    INVALID_RESULT_LAYOUT(6023, "Verification result fields must extend the message in order"),
+    BAD_ED25519_PROGRAM(6024, "Expected Ed25519 program"),
+    BAD_ED25519_ACCOUNTS(6025, "Bad Ed25519 accounts"),
+    PERMISSION_REVOKED(6026, "Permission has been revoked"),
+    PERMISSION_EXPIRED(6027, "Permission has expired"),
+    PERMISSION_NOT_GRANTED(6028, "Permission not granted"),
+    UNAUTHORIZED_REVOCATION(6029, "Unauthorized to revoke this permission"),
+    TOO_MANY_PERMISSIONS(6030, "Too many permissions requested"),
+    INVALID_EXPIRY(6031, "Expiry duration must be positive and within range"),
+    INVALID_NOTIFICATION_TOKEN(6032, "Encrypted notification token must be 1-256 bytes"),
+    NOT_CHANNEL_AUTHORITY(6033, "Signer does not own this notification channel"),
+    INVALID_REVEAL_DELAY(6034, "Reveal delay must be positive and at most 90 days"),
+    INVALID_REVEAL_CIPHERTEXT(6035, "Escrowed ciphertext must be 1-128 bytes"),
+    NOT_ESCROW_AUTHORITY(6036, "Signer does not own this reveal escrow"),
+    REVEAL_NOT_READY(6037, "Reveal delay has not passed yet"),
+    REVEAL_KEY_MISMATCH(6038, "Reveal key does not match the escrow's commitment"),
+    REVEAL_ALREADY_RELEASED(6039, "Reveal escrow has already been released"),
+    REVEAL_WINDOW_CLOSED(6040, "Reveal delay is over - the escrow can no longer be cancelled"),
+    NOT_VC_ISSUER(6041, "Signer is not the credential's issuer"),
+    VC_REVOKED(6042, "Credential has been revoked; its status can no longer change"),
+    AUDITOR_REVOKED(6043, "Auditor grant has been revoked"),
+    AUDITOR_EXPIRED(6044, "Auditor grant has expired"),
+    ACCESS_LOG_MISMATCH(6045, "Access log does not belong to this permission grant"),
+    INVALID_COMPACTION_BATCH(6046, "Compaction batch must be 1-16 (access log, payer) pairs"),
+    ACCESS_LOG_TOO_RECENT(6047, "Access log is too recent to compact"),
+    ACCESS_LOG_PAYER_MISMATCH(6048, "Refund account does not match the access log payer"),
+    UNAUTHORIZED(6049, "Signer is not the protocol authority"),
+    MINT_NOT_ACCEPTED(6050, "Mint is not accepted for payments"),
+    MINT_ALREADY_ACCEPTED(6051, "Mint is already accepted"),
+    TOO_MANY_ACCEPTED_MINTS(6052, "Too many accepted mints"),
+    CIRCUIT_NOT_REGISTERED(6053, "Circuit is not registered"),
+    CIRCUIT_DEPRECATED(6054, "Circuit has been deprecated"),
+    CIRCUIT_ALREADY_REGISTERED(6055, "Circuit ID is already registered"),
+    TOO_MANY_CIRCUITS(6056, "Too many registered circuits"),
+    INVALID_GROTH16_VK(6057, "Groth16 verifying key needs 2 to 4 public inputs and the circuit's vk_hash"),
+    INVALID_GROTH16_PROOF(6058, "Groth16 proof failed to verify"),
+    UNREGISTERED_VERIFIER(6059, "Verification result was not signed by a registered verifier"),
+    VERIFIER_ALREADY_REGISTERED(6060, "Verifier is already registered"),
+    VERIFIER_NOT_REGISTERED(6061, "Verifier is not registered"),
+    TOO_MANY_VERIFIERS(6062, "Too many registered verifiers"),
+    INVALID_VERIFIER_THRESHOLD(6063, "Threshold must be between 1 and the number of registered verifiers"),
+    QUORUM_NOT_MET(6064, "Not enough registered verifiers co-signed the result"),
+    TOO_MANY_ED25519_INSTRUCTIONS(6065, "Too many Ed25519 instructions in the transaction"),
+    TOO_MANY_SECP256R1_INSTRUCTIONS(6066, "Too many Secp256r1 instructions in the transaction"),
+    BAD_SECP256R1_ACCOUNTS(6067, "Bad Secp256r1 accounts"),
+    INVALID_SECP256R1_KEY(6068, "Secp256r1 verifier key must be a 33-byte compressed P-256 point"),
+    TOO_MANY_ROTATIONS(6069, "Too many verifier key rotations inside the grace period"),
+    INVALID_GRACE_PERIOD(6070, "Grace period must be between 0 and 24 hours"),
+    INVALID_DOMAIN(6071, "Domain must be 1-32 lowercase ASCII letters, digits, '-' or '.', null-padded"),
+    NOT_DOMAIN_OWNER(6072, "Signer is not the domain owner"),
+    VK_HASH_REQUIRED(6073, "Domain pins a verification key - result must carry vk_hash (V5+)"),
+    INVALID_CHALLENGE_WINDOW(6074, "Challenge window must be between 0 and 7 days"),
+    INVALID_RATE_LIMIT(6075, "Domain rate limit must be between 0 and 600 verifications per minute"),
+    NOT_DOMAIN_OPERATOR(6076, "Signer is neither the domain owner nor its operator"),
+    NOT_DOMAIN_TREASURER(6077, "Signer is neither the domain owner nor its treasurer"),
+    INVALID_DOMAIN_ROLE_KEY(6078, "Domain owner can't be set to the default key"),
+    DOMAIN_PAUSED(6079, "Domain is paused"),
+    TOO_MANY_ORIGINS(6080, "Too many registered origins"),
+    ORIGIN_NOT_ALLOWED(6081, "Origin is not registered for the domain"),
+    CHALLENGE_WINDOW_CLOSED(6082, "Verification has already finalized and can no longer be challenged"),
+    INVALID_CHALLENGE(6083, "Challenge evidence does not dispute this verification"),
+    FEE_NOT_CONFIGURED(6084, "Fee is not configured"),
+    ORACLE_FEED_MISMATCH(6085, "Price update is for a different feed than the mint's configured feed"),
+    INVALID_ORACLE_PRICE(6086, "Price update account is invalid, unverified, or non-positive"),
+    ORACLE_PRICE_STALE(6087, "Oracle price is too old"),
+    ORACLE_PRICE_UNCERTAIN(6088, "Oracle price confidence interval is too wide"),
+    PRICE_QUOTE_OVERFLOW(6089, "Price quote overflowed"),
+    FEE_EXCEEDS_MAX_AMOUNT(6090, "Quoted fee exceeds the payer's maximum amount"),
+    INVALID_FEE_SHARE(6091, "Domain fee share must be at most 50%"),
+    SESSION_EXPIRED(6092, "Session has expired"),
+    SESSION_REVOKED(6093, "Session was revoked by a global logout"),
+    SESSION_PENDING(6094, "Session is pending until its challenge window ends"),
+    RATE_LIMITED(6095, "Fee payer exceeded its verification rate limit - retry once the window slides"),
+    INVALID_ASSURANCE_TIER(6096, "Assurance tier is not defined (tiers are added in order, up to 8)"),
+    INVALID_RESULT_AGE(6097, "Maximum result age must be at most 1 hour (and non-zero for a tier)"),
+    TIMESTAMP_IN_FUTURE(6098, "Verification result timestamp is in the future"),
+    INVALID_POINTS_ROOT(6099, "Points root must cover at least one leaf"),
+    INVALID_POINTS_PROOF(6100, "Merkle proof doesn't match the published points root"),
+    PERSONHOOD_PROVIDER_ALREADY_REGISTERED(6101, "Personhood provider is already registered"),
+    PERSONHOOD_PROVIDER_NOT_REGISTERED(6102, "Personhood provider is not registered"),
+    TOO_MANY_PERSONHOOD_PROVIDERS(6103, "Too many personhood providers"),
+    INVALID_PERSONHOOD_SCORE(6104, "Personhood score must be at most 100"),
+    STALE_PERSONHOOD_ATTESTATION(6105, "Personhood attestation is expired, future-dated, or older than the attached one"),
+    MISSING_PERSONHOOD_SIGNATURE(6106, "No Ed25519 instruction carries the provider's signature over the attestation"),
+    ATTESTATION_PREIMAGE_MISMATCH(6107, "Attestation fields don't match the archived hash"),
+    MIGRATION_IN_PROGRESS(6108, "A migration is already in progress"),
+    NO_MIGRATION_IN_PROGRESS(6109, "No migration is in progress"),
+    INVALID_MIGRATION_PLAN(6110, "Migration needs a newer version, at most 4 distinct unstarted steps and a future deadline"),
+    MIGRATION_INCOMPLETE(6111, "Required migration steps are unfinished and the deadline hasn't passed"),
+    UNKNOWN_MIGRATION_STEP(6112, "The open migration has no step for this account type"),
+    UNSUPPORTED_ACCOUNT_VERSION(6113, "Account version is not accepted by the current migration state"),
+    INVALID_VERIFIER_WEIGHT(6114, "Verifier weight must be at most 16"),
+    VERIFIER_NOT_WEIGHTED(6115, "Verifier has no selection weight"),
+    NO_VERIFIER_AVAILABLE(6116, "No weighted, live verifier is available"),
+    MISSING_USER_CONSENT(6117, "No Ed25519 instruction carries the user's consent signature"),
+    USER_CONSENT_EXPIRED(6118, "User consent has expired"),
+    INVALID_SESSION_BOUNDS(6119, "Session bounds must be 0 or within the protocol's, with min <= max"),
+    SESSION_EXPIRY_OUT_OF_BOUNDS(6120, "Requested session lifetime is outside the domain's bounds"),
+    RESULT_LENGTH_MISMATCH(6121, "Verification result length doesn't match its version's"),
+    NON_CANONICAL_BOOL(6122, "Boolean fields must be encoded as 0 or 1"),
+    SIGNATURE_ALREADY_USED(6123, "Verification result signature has already been consumed"),
+    INVALID_RECEIPT_RETENTION(6124, "Receipt retention must be between 1 hour and 30 days"),
+    RECEIPT_RETAINED(6125, "Result receipt is still inside its retention window"),
+    UNKNOWN_CLAIM_TYPE(6126, "Unknown verification result claim type"),
+    CLAIM_TYPE_MISMATCH(6127, "Signed message claim type doesn't match result"),
+    CLAIM_NOT_SESSION(6128, "Only Auth claims open sessions"),
+    CLAIM_NOT_ATTESTATION(6129, "Auth claims open sessions rather than attestations"),
+    ATTESTATION_EXPIRY_OUT_OF_BOUNDS(6130, "Attestation lifetime must be at most a year"),
+    ATTESTATION_ACTIVE(6131, "Attestation for this claim is still active; renew it instead"),
+    ATTESTATION_EXPIRED(6132, "Attestation has expired"),
+    ATTESTATION_CLAIM_MISMATCH(6133, "Renewal result is for a different claim than the attestation"),
+    ATTESTATION_REVOKED(6134, "Attestation has been revoked by its issuer"),
+    NOT_ATTESTATION_ISSUER(6135, "Only the attestation's issuer can revoke it"),
+    INVALID_CLAIM_MASK(6136, "Claim type mask has bits for Auth or unknown claim types"),
+    UNTRUSTED_ATTESTOR(6137, "Domain only accepts this claim type from a registered attestor vetted for it"),
+    ATTESTOR_SUSPENDED(6138, "Attestor is suspended"),
+    SAS_BRIDGE_MISMATCH(6139, "Account doesn't match the SAS bridge's credential or schema"),
+    MESSAGE_TAG_MISMATCH(6140, "Signed message doesn't start with the Veiled protocol tag"),
+    UNTAGGED_RESULT(6141, "Result envelope is untagged - byte envelopes must be V9"),
+    SESSION_NOT_EXPIRED(6142, "Session hasn't expired yet"),
+    MISSING_SESSION_CLOSE_SIGNATURE(6143, "Session close must be signed by the session's authority"),
+    SESSION_IDLE(6144, "Session has been idle longer than its domain's idle timeout"),
+    INVALID_IDLE_TIMEOUT(6145, "Idle timeout must be 0 or within the protocol's session lifetimes"),
+    MISSING_DEVICE_SIGNATURE(6146, "Session's device key did not sign this instruction"),
+    INVALID_DELEGATE_SCOPE(6147, "Delegate scope must include at least one permission"),
+    INVALID_DELEGATE_EXPIRY(6148, "Delegate lifetime must be between 1 second and an hour"),
+    DELEGATE_NOT_ALLOWED(6149, "Delegate has expired or its scope doesn't include this permission"),
+    WRONG_NULLIFIER_STORE(6150, "Domain registers nullifiers in another store; use that store's instructions"),
+    INVALID_NON_INCLUSION_PROOF(6151, "Low leaf doesn't bracket the nullifier, so it may already be registered"),
+    INVALID_USERNAME(6152, "Usernames are 3 to 32 ASCII letters, digits or underscores"),
+    MISSING_USERNAME_TRANSFER_SIGNATURE(6153, "Username transfer must be signed by the username's authority"),
+    INVALID_GUARDIAN_SET(6154, "Guardian set needs 1 to 8 distinct guardians and a threshold between 1 and their count"),
+    NOT_ENOUGH_GUARDIAN_SIGNATURES(6155, "Not enough guardians signed this recovery"),
+    INVALID_RECOVERY_ACCOUNTS(6156, "Recovery accounts must be (old, new) pairs of the nullifier's sessions or grants"),
+    IDENTITY_ROOT_FULL(6157, "Identity root already links its maximum number of nullifiers"),
+    NULLIFIER_ALREADY_LINKED(6158, "Nullifier is already linked to this identity root"),
+    NULLIFIER_NOT_LINKED(6159, "Nullifier is not linked to this identity root"),
+    MISSING_IDENTITY_LINK_SIGNATURE(6160, "Identity link must be signed by the identity root's owner"),
+    NULLIFIER_BANNED(6161, "Nullifier is banned from this domain"),
+    INVALID_SNS_NAME_ACCOUNT(6162, "SNS name account missing or not the one for this .sol domain"),
+    NOT_SNS_NAME_OWNER(6163, "Signer doesn't own the SNS name for this .sol domain"),
+    REENTRANCY_DETECTED(6164, "Instruction invoked too deep in a CPI chain (possible reentrancy)"),
+    INVALID_HONK_VK(6165, "UltraHonk key is malformed, incomplete or doesn't match the circuit's vk_hash"),
+    INVALID_PROOF_LENGTH(6166, "Upload must be the circuit's public inputs followed by one UltraHonk proof"),
+    PROOF_CHUNK_OUT_OF_ORDER(6167, "Proof chunk must start where the upload left off and stay within it"),
+    INVALID_SESSION_STATUS(6168, "Verification session is not in the right state for this instruction");
+
+    companion object {
+        fun fromCode(code: Int): VeiledError? = values().firstOrNull { it.code == code }
+    }
+}
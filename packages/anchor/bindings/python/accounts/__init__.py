@@ -0,0 +1,43 @@
+from .access_log_summary import AccessLogSummary, AccessLogSummaryJSON
+from .alias import Alias, AliasJSON
+from .archived_personhood_attestation import ArchivedPersonhoodAttestation, ArchivedPersonhoodAttestationJSON
+from .assurance_policy import AssurancePolicy, AssurancePolicyJSON
+from .attestation import Attestation, AttestationJSON
+from .attestor import Attestor, AttestorJSON
+from .auditor_grant import AuditorGrant, AuditorGrantJSON
+from .circuit_registry import CircuitRegistry, CircuitRegistryJSON
+from .domain_config import DomainConfig, DomainConfigJSON
+from .expiry_histogram import ExpiryHistogram, ExpiryHistogramJSON
+from .fee_share_policy import FeeSharePolicy, FeeSharePolicyJSON
+from .groth16_verifying_key import Groth16VerifyingKey, Groth16VerifyingKeyJSON
+from .guardian_set import GuardianSet, GuardianSetJSON
+from .honk_verifying_key import HonkVerifyingKey, HonkVerifyingKeyJSON
+from .identity_root import IdentityRoot, IdentityRootJSON
+from .logout_epoch import LogoutEpoch, LogoutEpochJSON
+from .migration_state import MigrationState, MigrationStateJSON
+from .notification_channel import NotificationChannel, NotificationChannelJSON
+from .nullifier_account import NullifierAccount, NullifierAccountJSON
+from .nullifier_ban import NullifierBan, NullifierBanJSON
+from .nullifier_link import NullifierLink, NullifierLinkJSON
+from .nullifier_shard import NullifierShard, NullifierShardJSON
+from .nullifier_tree import NullifierTree, NullifierTreeJSON
+from .payer_rate_limit import PayerRateLimit, PayerRateLimitJSON
+from .permission_access import PermissionAccess, PermissionAccessJSON
+from .permission_grant import PermissionGrant, PermissionGrantJSON
+from .personhood_attestation import PersonhoodAttestation, PersonhoodAttestationJSON
+from .personhood_provider_registry import PersonhoodProviderRegistry, PersonhoodProviderRegistryJSON
+from .points import Points, PointsJSON
+from .points_root import PointsRoot, PointsRootJSON
+from .proof_record import ProofRecord, ProofRecordJSON
+from .protocol_config import ProtocolConfig, ProtocolConfigJSON
+from .protocol_stats import ProtocolStats, ProtocolStatsJSON
+from .result_receipt import ResultReceipt, ResultReceiptJSON
+from .reveal_escrow import RevealEscrow, RevealEscrowJSON
+from .sas_bridge import SasBridge, SasBridgeJSON
+from .secp256r1_verifier_registry import Secp256r1VerifierRegistry, Secp256r1VerifierRegistryJSON
+from .session_delegate import SessionDelegate, SessionDelegateJSON
+from .username import Username, UsernameJSON
+from .vc_commitment import VcCommitment, VcCommitmentJSON
+from .verification_session import VerificationSession, VerificationSessionJSON
+from .verifier_registry import VerifierRegistry, VerifierRegistryJSON
+from .verifier_weights import VerifierWeights, VerifierWeightsJSON
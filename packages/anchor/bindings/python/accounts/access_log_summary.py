@@ -0,0 +1,112 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+
+
+class AccessLogSummaryJSON(typing.TypedDict):
+    permission_grant: str
+    root: list[int]
+    compacted_count: int
+    batch_count: int
+    last_accessed_at: int
+    bump: int
+
+
+@dataclass
+class AccessLogSummary:
+    discriminator: typing.ClassVar = b"\xf71T\xf8\x86\rU\xcd"
+    layout: typing.ClassVar = borsh.CStruct(
+        "permission_grant" / BorshPubkey,
+        "root" / borsh.U8[32],
+        "compacted_count" / borsh.U64,
+        "batch_count" / borsh.U64,
+        "last_accessed_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    permission_grant: Pubkey
+    root: list[int]
+    compacted_count: int
+    batch_count: int
+    last_accessed_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["AccessLogSummary"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["AccessLogSummary"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["AccessLogSummary"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "AccessLogSummary":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = AccessLogSummary.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            permission_grant=dec.permission_grant,
+            root=dec.root,
+            compacted_count=dec.compacted_count,
+            batch_count=dec.batch_count,
+            last_accessed_at=dec.last_accessed_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> AccessLogSummaryJSON:
+        return {
+            "permission_grant": str(self.permission_grant),
+            "root": self.root,
+            "compacted_count": self.compacted_count,
+            "batch_count": self.batch_count,
+            "last_accessed_at": self.last_accessed_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AccessLogSummaryJSON) -> "AccessLogSummary":
+        return cls(
+            permission_grant=Pubkey.from_string(obj["permission_grant"]),
+            root=obj["root"],
+            compacted_count=obj["compacted_count"],
+            batch_count=obj["batch_count"],
+            last_accessed_at=obj["last_accessed_at"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,99 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from ..program_id import PROGRAM_ID
+
+
+class ArchivedPersonhoodAttestationJSON(typing.TypedDict):
+    attestation_hash: list[int]
+    issued_at: int
+    archived_at: int
+    bump: int
+
+
+@dataclass
+class ArchivedPersonhoodAttestation:
+    discriminator: typing.ClassVar = b"J(\xdc\x88\xe6\xf4\xb3\xe4"
+    layout: typing.ClassVar = borsh.CStruct(
+        "attestation_hash" / borsh.U8[32],
+        "issued_at" / borsh.I64,
+        "archived_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    attestation_hash: list[int]
+    issued_at: int
+    archived_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["ArchivedPersonhoodAttestation"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["ArchivedPersonhoodAttestation"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["ArchivedPersonhoodAttestation"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "ArchivedPersonhoodAttestation":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = ArchivedPersonhoodAttestation.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            attestation_hash=dec.attestation_hash,
+            issued_at=dec.issued_at,
+            archived_at=dec.archived_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> ArchivedPersonhoodAttestationJSON:
+        return {
+            "attestation_hash": self.attestation_hash,
+            "issued_at": self.issued_at,
+            "archived_at": self.archived_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: ArchivedPersonhoodAttestationJSON) -> "ArchivedPersonhoodAttestation":
+        return cls(
+            attestation_hash=obj["attestation_hash"],
+            issued_at=obj["issued_at"],
+            archived_at=obj["archived_at"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,88 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from construct import Construct
+from ..program_id import PROGRAM_ID
+
+
+class AssurancePolicyJSON(typing.TypedDict):
+    max_result_age_secs: list[int]
+    bump: int
+
+
+@dataclass
+class AssurancePolicy:
+    discriminator: typing.ClassVar = b"D\x9d\xe9n\xaf\xfc\x07Q"
+    layout: typing.ClassVar = borsh.CStruct(
+        "max_result_age_secs" / borsh.Vec(typing.cast(Construct, borsh.U32)),
+        "bump" / borsh.U8,
+    )
+    max_result_age_secs: list[int]
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["AssurancePolicy"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["AssurancePolicy"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["AssurancePolicy"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "AssurancePolicy":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = AssurancePolicy.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            max_result_age_secs=dec.max_result_age_secs,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> AssurancePolicyJSON:
+        return {
+            "max_result_age_secs": self.max_result_age_secs,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AssurancePolicyJSON) -> "AssurancePolicy":
+        return cls(
+            max_result_age_secs=obj["max_result_age_secs"],
+            bump=obj["bump"],
+        )
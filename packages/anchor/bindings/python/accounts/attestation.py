@@ -0,0 +1,137 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+from .. import types
+
+
+class AttestationJSON(typing.TypedDict):
+    nullifier: list[int]
+    claim_type: types.claim_type.ClaimTypeJSON
+    domain: list[int]
+    claim_data_hash: list[int]
+    circuit_id: int
+    issuer: str
+    issued_at: int
+    expires_at: int
+    bump: int
+    revoked_at: int
+
+
+@dataclass
+class Attestation:
+    discriminator: typing.ClassVar = b"\x98}\xb7V$\x92yI"
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "claim_type" / types.claim_type.layout,
+        "domain" / borsh.U8[32],
+        "claim_data_hash" / borsh.U8[32],
+        "circuit_id" / borsh.U32,
+        "issuer" / BorshPubkey,
+        "issued_at" / borsh.I64,
+        "expires_at" / borsh.I64,
+        "bump" / borsh.U8,
+        "revoked_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    claim_type: types.claim_type.ClaimTypeKind
+    domain: list[int]
+    claim_data_hash: list[int]
+    circuit_id: int
+    issuer: Pubkey
+    issued_at: int
+    expires_at: int
+    bump: int
+    revoked_at: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["Attestation"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["Attestation"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["Attestation"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "Attestation":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = Attestation.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            nullifier=dec.nullifier,
+            claim_type=types.claim_type.from_decoded(dec.claim_type),
+            domain=dec.domain,
+            claim_data_hash=dec.claim_data_hash,
+            circuit_id=dec.circuit_id,
+            issuer=dec.issuer,
+            issued_at=dec.issued_at,
+            expires_at=dec.expires_at,
+            bump=dec.bump,
+            revoked_at=dec.revoked_at,
+        )
+
+    def to_json(self) -> AttestationJSON:
+        return {
+            "nullifier": self.nullifier,
+            "claim_type": self.claim_type.to_json(),
+            "domain": self.domain,
+            "claim_data_hash": self.claim_data_hash,
+            "circuit_id": self.circuit_id,
+            "issuer": str(self.issuer),
+            "issued_at": self.issued_at,
+            "expires_at": self.expires_at,
+            "bump": self.bump,
+            "revoked_at": self.revoked_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AttestationJSON) -> "Attestation":
+        return cls(
+            nullifier=obj["nullifier"],
+            claim_type=types.claim_type.from_json(obj["claim_type"]),
+            domain=obj["domain"],
+            claim_data_hash=obj["claim_data_hash"],
+            circuit_id=obj["circuit_id"],
+            issuer=Pubkey.from_string(obj["issuer"]),
+            issued_at=obj["issued_at"],
+            expires_at=obj["expires_at"],
+            bump=obj["bump"],
+            revoked_at=obj["revoked_at"],
+        )
@@ -0,0 +1,113 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+from .. import types
+
+
+class AttestorJSON(typing.TypedDict):
+    issuer: str
+    name_hash: list[int]
+    claim_mask: int
+    status: types.attestor_status.AttestorStatusJSON
+    registered_at: int
+    bump: int
+
+
+@dataclass
+class Attestor:
+    discriminator: typing.ClassVar = b"\xfd\xf0L\xc4\x105\xef\xad"
+    layout: typing.ClassVar = borsh.CStruct(
+        "issuer" / BorshPubkey,
+        "name_hash" / borsh.U8[32],
+        "claim_mask" / borsh.U8,
+        "status" / types.attestor_status.layout,
+        "registered_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    issuer: Pubkey
+    name_hash: list[int]
+    claim_mask: int
+    status: types.attestor_status.AttestorStatusKind
+    registered_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["Attestor"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["Attestor"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["Attestor"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "Attestor":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = Attestor.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            issuer=dec.issuer,
+            name_hash=dec.name_hash,
+            claim_mask=dec.claim_mask,
+            status=types.attestor_status.from_decoded(dec.status),
+            registered_at=dec.registered_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> AttestorJSON:
+        return {
+            "issuer": str(self.issuer),
+            "name_hash": self.name_hash,
+            "claim_mask": self.claim_mask,
+            "status": self.status.to_json(),
+            "registered_at": self.registered_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AttestorJSON) -> "Attestor":
+        return cls(
+            issuer=Pubkey.from_string(obj["issuer"]),
+            name_hash=obj["name_hash"],
+            claim_mask=obj["claim_mask"],
+            status=types.attestor_status.from_json(obj["status"]),
+            registered_at=obj["registered_at"],
+            bump=obj["bump"],
+        )
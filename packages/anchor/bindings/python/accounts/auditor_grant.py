@@ -0,0 +1,125 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+from .. import types
+
+
+class AuditorGrantJSON(typing.TypedDict):
+    nullifier: list[int]
+    auditor: str
+    name_hash: list[int]
+    granted_by: str
+    granted_at: int
+    expires_at: types.expiry_kind.ExpiryKindJSON
+    revoked: bool
+    bump: int
+
+
+@dataclass
+class AuditorGrant:
+    discriminator: typing.ClassVar = b"\x12\xb4\"\xd9\xfbi\xa7("
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "auditor" / BorshPubkey,
+        "name_hash" / borsh.U8[32],
+        "granted_by" / BorshPubkey,
+        "granted_at" / borsh.I64,
+        "expires_at" / types.expiry_kind.layout,
+        "revoked" / borsh.Bool,
+        "bump" / borsh.U8,
+    )
+    nullifier: list[int]
+    auditor: Pubkey
+    name_hash: list[int]
+    granted_by: Pubkey
+    granted_at: int
+    expires_at: types.expiry_kind.ExpiryKindKind
+    revoked: bool
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["AuditorGrant"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["AuditorGrant"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["AuditorGrant"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "AuditorGrant":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = AuditorGrant.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            nullifier=dec.nullifier,
+            auditor=dec.auditor,
+            name_hash=dec.name_hash,
+            granted_by=dec.granted_by,
+            granted_at=dec.granted_at,
+            expires_at=types.expiry_kind.from_decoded(dec.expires_at),
+            revoked=dec.revoked,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> AuditorGrantJSON:
+        return {
+            "nullifier": self.nullifier,
+            "auditor": str(self.auditor),
+            "name_hash": self.name_hash,
+            "granted_by": str(self.granted_by),
+            "granted_at": self.granted_at,
+            "expires_at": self.expires_at.to_json(),
+            "revoked": self.revoked,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AuditorGrantJSON) -> "AuditorGrant":
+        return cls(
+            nullifier=obj["nullifier"],
+            auditor=Pubkey.from_string(obj["auditor"]),
+            name_hash=obj["name_hash"],
+            granted_by=Pubkey.from_string(obj["granted_by"]),
+            granted_at=obj["granted_at"],
+            expires_at=types.expiry_kind.from_json(obj["expires_at"]),
+            revoked=obj["revoked"],
+            bump=obj["bump"],
+        )
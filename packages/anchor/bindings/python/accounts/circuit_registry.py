@@ -0,0 +1,93 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from construct import Construct
+from ..program_id import PROGRAM_ID
+from .. import types
+
+
+class CircuitRegistryJSON(typing.TypedDict):
+    circuits: list[types.circuit_entry.CircuitEntryJSON]
+    bump: int
+
+
+@dataclass
+class CircuitRegistry:
+    discriminator: typing.ClassVar = b"\xf8=j7\x8a\x98X%"
+    layout: typing.ClassVar = borsh.CStruct(
+        "circuits" / borsh.Vec(typing.cast(Construct, types.circuit_entry.CircuitEntry.layout)),
+        "bump" / borsh.U8,
+    )
+    circuits: list[types.circuit_entry.CircuitEntry]
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["CircuitRegistry"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["CircuitRegistry"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["CircuitRegistry"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "CircuitRegistry":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = CircuitRegistry.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            circuits=list(
+                map(lambda item: types.circuit_entry.CircuitEntry.from_decoded(item), dec.circuits)
+            ),
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> CircuitRegistryJSON:
+        return {
+            "circuits": list(map(lambda item: item.to_json(), self.circuits)),
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: CircuitRegistryJSON) -> "CircuitRegistry":
+        return cls(
+            circuits=list(
+                map(lambda item: types.circuit_entry.CircuitEntry.from_json(item), obj["circuits"])
+            ),
+            bump=obj["bump"],
+        )
@@ -0,0 +1,206 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from construct import Construct
+from ..program_id import PROGRAM_ID
+from .. import types
+
+
+class DomainConfigJSON(typing.TypedDict):
+    domain: list[int]
+    owner: str
+    pinned_vk_hash: list[int]
+    verifiers: list[str]
+    bump: int
+    challenge_window_secs: int
+    rate_limit: int
+    assurance_tier: int
+    max_result_age_secs: int
+    operator: str
+    treasurer: str
+    paused: bool
+    allowed_origins: list[list[int]]
+    min_session_secs: int
+    max_session_secs: int
+    attestor_claim_mask: int
+    idle_timeout_secs: int
+    nullifier_store: types.nullifier_store.NullifierStoreJSON
+    max_permissions: int
+    session_expiry_unit: types.expiry_unit.ExpiryUnitJSON
+    default_session_secs: int
+
+
+@dataclass
+class DomainConfig:
+    discriminator: typing.ClassVar = b"\xc9\xe8\xd4\xe5;\xf1j\xc5"
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "owner" / BorshPubkey,
+        "pinned_vk_hash" / borsh.U8[32],
+        "verifiers" / borsh.Vec(typing.cast(Construct, BorshPubkey)),
+        "bump" / borsh.U8,
+        "challenge_window_secs" / borsh.I64,
+        "rate_limit" / borsh.U32,
+        "assurance_tier" / borsh.U8,
+        "max_result_age_secs" / borsh.U32,
+        "operator" / BorshPubkey,
+        "treasurer" / BorshPubkey,
+        "paused" / borsh.Bool,
+        "allowed_origins" / borsh.Vec(typing.cast(Construct, borsh.U8[32])),
+        "min_session_secs" / borsh.I64,
+        "max_session_secs" / borsh.I64,
+        "attestor_claim_mask" / borsh.U8,
+        "idle_timeout_secs" / borsh.I64,
+        "nullifier_store" / types.nullifier_store.layout,
+        "max_permissions" / borsh.U8,
+        "session_expiry_unit" / types.expiry_unit.layout,
+        "default_session_secs" / borsh.I64,
+    )
+    domain: list[int]
+    owner: Pubkey
+    pinned_vk_hash: list[int]
+    verifiers: list[Pubkey]
+    bump: int
+    challenge_window_secs: int
+    rate_limit: int
+    assurance_tier: int
+    max_result_age_secs: int
+    operator: Pubkey
+    treasurer: Pubkey
+    paused: bool
+    allowed_origins: list[list[int]]
+    min_session_secs: int
+    max_session_secs: int
+    attestor_claim_mask: int
+    idle_timeout_secs: int
+    nullifier_store: types.nullifier_store.NullifierStoreKind
+    max_permissions: int
+    session_expiry_unit: types.expiry_unit.ExpiryUnitKind
+    default_session_secs: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["DomainConfig"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["DomainConfig"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["DomainConfig"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "DomainConfig":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = DomainConfig.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            domain=dec.domain,
+            owner=dec.owner,
+            pinned_vk_hash=dec.pinned_vk_hash,
+            verifiers=dec.verifiers,
+            bump=dec.bump,
+            challenge_window_secs=dec.challenge_window_secs,
+            rate_limit=dec.rate_limit,
+            assurance_tier=dec.assurance_tier,
+            max_result_age_secs=dec.max_result_age_secs,
+            operator=dec.operator,
+            treasurer=dec.treasurer,
+            paused=dec.paused,
+            allowed_origins=dec.allowed_origins,
+            min_session_secs=dec.min_session_secs,
+            max_session_secs=dec.max_session_secs,
+            attestor_claim_mask=dec.attestor_claim_mask,
+            idle_timeout_secs=dec.idle_timeout_secs,
+            nullifier_store=types.nullifier_store.from_decoded(dec.nullifier_store),
+            max_permissions=dec.max_permissions,
+            session_expiry_unit=types.expiry_unit.from_decoded(dec.session_expiry_unit),
+            default_session_secs=dec.default_session_secs,
+        )
+
+    def to_json(self) -> DomainConfigJSON:
+        return {
+            "domain": self.domain,
+            "owner": str(self.owner),
+            "pinned_vk_hash": self.pinned_vk_hash,
+            "verifiers": list(map(lambda item: str(item), self.verifiers)),
+            "bump": self.bump,
+            "challenge_window_secs": self.challenge_window_secs,
+            "rate_limit": self.rate_limit,
+            "assurance_tier": self.assurance_tier,
+            "max_result_age_secs": self.max_result_age_secs,
+            "operator": str(self.operator),
+            "treasurer": str(self.treasurer),
+            "paused": self.paused,
+            "allowed_origins": self.allowed_origins,
+            "min_session_secs": self.min_session_secs,
+            "max_session_secs": self.max_session_secs,
+            "attestor_claim_mask": self.attestor_claim_mask,
+            "idle_timeout_secs": self.idle_timeout_secs,
+            "nullifier_store": self.nullifier_store.to_json(),
+            "max_permissions": self.max_permissions,
+            "session_expiry_unit": self.session_expiry_unit.to_json(),
+            "default_session_secs": self.default_session_secs,
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainConfigJSON) -> "DomainConfig":
+        return cls(
+            domain=obj["domain"],
+            owner=Pubkey.from_string(obj["owner"]),
+            pinned_vk_hash=obj["pinned_vk_hash"],
+            verifiers=list(
+                map(lambda item: Pubkey.from_string(item), obj["verifiers"])
+            ),
+            bump=obj["bump"],
+            challenge_window_secs=obj["challenge_window_secs"],
+            rate_limit=obj["rate_limit"],
+            assurance_tier=obj["assurance_tier"],
+            max_result_age_secs=obj["max_result_age_secs"],
+            operator=Pubkey.from_string(obj["operator"]),
+            treasurer=Pubkey.from_string(obj["treasurer"]),
+            paused=obj["paused"],
+            allowed_origins=obj["allowed_origins"],
+            min_session_secs=obj["min_session_secs"],
+            max_session_secs=obj["max_session_secs"],
+            attestor_claim_mask=obj["attestor_claim_mask"],
+            idle_timeout_secs=obj["idle_timeout_secs"],
+            nullifier_store=types.nullifier_store.from_json(obj["nullifier_store"]),
+            max_permissions=obj["max_permissions"],
+            session_expiry_unit=types.expiry_unit.from_json(obj["session_expiry_unit"]),
+            default_session_secs=obj["default_session_secs"],
+        )
@@ -0,0 +1,99 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from ..program_id import PROGRAM_ID
+
+
+class ExpiryHistogramJSON(typing.TypedDict):
+    domain: list[int]
+    buckets: list[int]
+    slot_grants: int
+    bump: int
+
+
+@dataclass
+class ExpiryHistogram:
+    discriminator: typing.ClassVar = b"\x81\xe1\t\x81\xbav\x10}"
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "buckets" / borsh.U64[7],
+        "slot_grants" / borsh.U64,
+        "bump" / borsh.U8,
+    )
+    domain: list[int]
+    buckets: list[int]
+    slot_grants: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["ExpiryHistogram"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["ExpiryHistogram"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["ExpiryHistogram"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "ExpiryHistogram":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = ExpiryHistogram.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            domain=dec.domain,
+            buckets=dec.buckets,
+            slot_grants=dec.slot_grants,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> ExpiryHistogramJSON:
+        return {
+            "domain": self.domain,
+            "buckets": self.buckets,
+            "slot_grants": self.slot_grants,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: ExpiryHistogramJSON) -> "ExpiryHistogram":
+        return cls(
+            domain=obj["domain"],
+            buckets=obj["buckets"],
+            slot_grants=obj["slot_grants"],
+            bump=obj["bump"],
+        )
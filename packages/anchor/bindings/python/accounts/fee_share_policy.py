@@ -0,0 +1,87 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from ..program_id import PROGRAM_ID
+
+
+class FeeSharePolicyJSON(typing.TypedDict):
+    domain_share_bps: int
+    bump: int
+
+
+@dataclass
+class FeeSharePolicy:
+    discriminator: typing.ClassVar = b"Nvl8\xa1\xd4\xf4."
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain_share_bps" / borsh.U16,
+        "bump" / borsh.U8,
+    )
+    domain_share_bps: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["FeeSharePolicy"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["FeeSharePolicy"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["FeeSharePolicy"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "FeeSharePolicy":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = FeeSharePolicy.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            domain_share_bps=dec.domain_share_bps,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> FeeSharePolicyJSON:
+        return {
+            "domain_share_bps": self.domain_share_bps,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: FeeSharePolicyJSON) -> "FeeSharePolicy":
+        return cls(
+            domain_share_bps=obj["domain_share_bps"],
+            bump=obj["bump"],
+        )
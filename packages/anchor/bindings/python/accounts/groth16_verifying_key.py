@@ -0,0 +1,118 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from construct import Construct
+from ..program_id import PROGRAM_ID
+
+
+class Groth16VerifyingKeyJSON(typing.TypedDict):
+    circuit_id: int
+    alpha_g1: list[int]
+    beta_g2: list[int]
+    gamma_g2: list[int]
+    delta_g2: list[int]
+    ic: list[list[int]]
+    bump: int
+
+
+@dataclass
+class Groth16VerifyingKey:
+    discriminator: typing.ClassVar = b"\x0eY\x02\xa34Z\x17\xa0"
+    layout: typing.ClassVar = borsh.CStruct(
+        "circuit_id" / borsh.U32,
+        "alpha_g1" / borsh.U8[64],
+        "beta_g2" / borsh.U8[128],
+        "gamma_g2" / borsh.U8[128],
+        "delta_g2" / borsh.U8[128],
+        "ic" / borsh.Vec(typing.cast(Construct, borsh.U8[64])),
+        "bump" / borsh.U8,
+    )
+    circuit_id: int
+    alpha_g1: list[int]
+    beta_g2: list[int]
+    gamma_g2: list[int]
+    delta_g2: list[int]
+    ic: list[list[int]]
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["Groth16VerifyingKey"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["Groth16VerifyingKey"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["Groth16VerifyingKey"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "Groth16VerifyingKey":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = Groth16VerifyingKey.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            circuit_id=dec.circuit_id,
+            alpha_g1=dec.alpha_g1,
+            beta_g2=dec.beta_g2,
+            gamma_g2=dec.gamma_g2,
+            delta_g2=dec.delta_g2,
+            ic=dec.ic,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> Groth16VerifyingKeyJSON:
+        return {
+            "circuit_id": self.circuit_id,
+            "alpha_g1": self.alpha_g1,
+            "beta_g2": self.beta_g2,
+            "gamma_g2": self.gamma_g2,
+            "delta_g2": self.delta_g2,
+            "ic": self.ic,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: Groth16VerifyingKeyJSON) -> "Groth16VerifyingKey":
+        return cls(
+            circuit_id=obj["circuit_id"],
+            alpha_g1=obj["alpha_g1"],
+            beta_g2=obj["beta_g2"],
+            gamma_g2=obj["gamma_g2"],
+            delta_g2=obj["delta_g2"],
+            ic=obj["ic"],
+            bump=obj["bump"],
+        )
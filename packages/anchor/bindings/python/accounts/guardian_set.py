@@ -0,0 +1,115 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from construct import Construct
+from ..program_id import PROGRAM_ID
+
+
+class GuardianSetJSON(typing.TypedDict):
+    nullifier: list[int]
+    guardians: list[str]
+    threshold: int
+    nonce: int
+    updated_at: int
+    bump: int
+
+
+@dataclass
+class GuardianSet:
+    discriminator: typing.ClassVar = b"xMJb\"S`}"
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "guardians" / borsh.Vec(typing.cast(Construct, BorshPubkey)),
+        "threshold" / borsh.U8,
+        "nonce" / borsh.U64,
+        "updated_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    nullifier: list[int]
+    guardians: list[Pubkey]
+    threshold: int
+    nonce: int
+    updated_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["GuardianSet"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["GuardianSet"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["GuardianSet"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "GuardianSet":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = GuardianSet.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            nullifier=dec.nullifier,
+            guardians=dec.guardians,
+            threshold=dec.threshold,
+            nonce=dec.nonce,
+            updated_at=dec.updated_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> GuardianSetJSON:
+        return {
+            "nullifier": self.nullifier,
+            "guardians": list(map(lambda item: str(item), self.guardians)),
+            "threshold": self.threshold,
+            "nonce": self.nonce,
+            "updated_at": self.updated_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: GuardianSetJSON) -> "GuardianSet":
+        return cls(
+            nullifier=obj["nullifier"],
+            guardians=list(
+                map(lambda item: Pubkey.from_string(item), obj["guardians"])
+            ),
+            threshold=obj["threshold"],
+            nonce=obj["nonce"],
+            updated_at=obj["updated_at"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,117 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from ..program_id import PROGRAM_ID
+
+
+class HonkVerifyingKeyJSON(typing.TypedDict):
+    circuit_id: int
+    log_circuit_size: int
+    public_inputs_size: int
+    pub_inputs_offset: int
+    commitments: list[list[int]]
+    written: int
+    bump: int
+
+
+@dataclass
+class HonkVerifyingKey:
+    discriminator: typing.ClassVar = b"\xda\x8b \xfe\xad\xcb\x8d\x82"
+    layout: typing.ClassVar = borsh.CStruct(
+        "circuit_id" / borsh.U32,
+        "log_circuit_size" / borsh.U8,
+        "public_inputs_size" / borsh.U32,
+        "pub_inputs_offset" / borsh.U32,
+        "commitments" / borsh.U8[64][27],
+        "written" / borsh.U32,
+        "bump" / borsh.U8,
+    )
+    circuit_id: int
+    log_circuit_size: int
+    public_inputs_size: int
+    pub_inputs_offset: int
+    commitments: list[list[int]]
+    written: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["HonkVerifyingKey"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["HonkVerifyingKey"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["HonkVerifyingKey"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "HonkVerifyingKey":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = HonkVerifyingKey.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            circuit_id=dec.circuit_id,
+            log_circuit_size=dec.log_circuit_size,
+            public_inputs_size=dec.public_inputs_size,
+            pub_inputs_offset=dec.pub_inputs_offset,
+            commitments=dec.commitments,
+            written=dec.written,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> HonkVerifyingKeyJSON:
+        return {
+            "circuit_id": self.circuit_id,
+            "log_circuit_size": self.log_circuit_size,
+            "public_inputs_size": self.public_inputs_size,
+            "pub_inputs_offset": self.pub_inputs_offset,
+            "commitments": self.commitments,
+            "written": self.written,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: HonkVerifyingKeyJSON) -> "HonkVerifyingKey":
+        return cls(
+            circuit_id=obj["circuit_id"],
+            log_circuit_size=obj["log_circuit_size"],
+            public_inputs_size=obj["public_inputs_size"],
+            pub_inputs_offset=obj["pub_inputs_offset"],
+            commitments=obj["commitments"],
+            written=obj["written"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,106 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from construct import Construct
+from ..program_id import PROGRAM_ID
+from .. import types
+
+
+class IdentityRootJSON(typing.TypedDict):
+    owner: str
+    members: list[types.root_member.RootMemberJSON]
+    created_at: int
+    bump: int
+
+
+@dataclass
+class IdentityRoot:
+    discriminator: typing.ClassVar = b"\xae\xc0p309\xd3\xc3"
+    layout: typing.ClassVar = borsh.CStruct(
+        "owner" / BorshPubkey,
+        "members" / borsh.Vec(typing.cast(Construct, types.root_member.RootMember.layout)),
+        "created_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    owner: Pubkey
+    members: list[types.root_member.RootMember]
+    created_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["IdentityRoot"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["IdentityRoot"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["IdentityRoot"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "IdentityRoot":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = IdentityRoot.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            owner=dec.owner,
+            members=list(
+                map(lambda item: types.root_member.RootMember.from_decoded(item), dec.members)
+            ),
+            created_at=dec.created_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> IdentityRootJSON:
+        return {
+            "owner": str(self.owner),
+            "members": list(map(lambda item: item.to_json(), self.members)),
+            "created_at": self.created_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: IdentityRootJSON) -> "IdentityRoot":
+        return cls(
+            owner=Pubkey.from_string(obj["owner"]),
+            members=list(
+                map(lambda item: types.root_member.RootMember.from_json(item), obj["members"])
+            ),
+            created_at=obj["created_at"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,94 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+
+
+class LogoutEpochJSON(typing.TypedDict):
+    identity: str
+    logged_out_at: int
+    bump: int
+
+
+@dataclass
+class LogoutEpoch:
+    discriminator: typing.ClassVar = b"\xee\x19\x12c\xed\xf6k\xbc"
+    layout: typing.ClassVar = borsh.CStruct(
+        "identity" / BorshPubkey,
+        "logged_out_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    identity: Pubkey
+    logged_out_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["LogoutEpoch"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["LogoutEpoch"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["LogoutEpoch"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "LogoutEpoch":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = LogoutEpoch.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            identity=dec.identity,
+            logged_out_at=dec.logged_out_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> LogoutEpochJSON:
+        return {
+            "identity": str(self.identity),
+            "logged_out_at": self.logged_out_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: LogoutEpochJSON) -> "LogoutEpoch":
+        return cls(
+            identity=Pubkey.from_string(obj["identity"]),
+            logged_out_at=obj["logged_out_at"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,117 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from construct import Construct
+from ..program_id import PROGRAM_ID
+from .. import types
+
+
+class MigrationStateJSON(typing.TypedDict):
+    version: int
+    target_version: int
+    steps: list[types.migration_step.MigrationStepJSON]
+    started_at: int
+    deadline: int
+    bump: int
+
+
+@dataclass
+class MigrationState:
+    discriminator: typing.ClassVar = b"_\x92\x87@\x91\x19\xc5s"
+    layout: typing.ClassVar = borsh.CStruct(
+        "version" / borsh.U16,
+        "target_version" / borsh.U16,
+        "steps" / borsh.Vec(typing.cast(Construct, types.migration_step.MigrationStep.layout)),
+        "started_at" / borsh.I64,
+        "deadline" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    version: int
+    target_version: int
+    steps: list[types.migration_step.MigrationStep]
+    started_at: int
+    deadline: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["MigrationState"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["MigrationState"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["MigrationState"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "MigrationState":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = MigrationState.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            version=dec.version,
+            target_version=dec.target_version,
+            steps=list(
+                map(lambda item: types.migration_step.MigrationStep.from_decoded(item), dec.steps)
+            ),
+            started_at=dec.started_at,
+            deadline=dec.deadline,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> MigrationStateJSON:
+        return {
+            "version": self.version,
+            "target_version": self.target_version,
+            "steps": list(map(lambda item: item.to_json(), self.steps)),
+            "started_at": self.started_at,
+            "deadline": self.deadline,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: MigrationStateJSON) -> "MigrationState":
+        return cls(
+            version=obj["version"],
+            target_version=obj["target_version"],
+            steps=list(
+                map(lambda item: types.migration_step.MigrationStep.from_json(item), obj["steps"])
+            ),
+            started_at=obj["started_at"],
+            deadline=obj["deadline"],
+            bump=obj["bump"],
+        )
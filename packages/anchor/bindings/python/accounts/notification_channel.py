@@ -0,0 +1,118 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+
+
+class NotificationChannelJSON(typing.TypedDict):
+    nullifier: list[int]
+    app_id: str
+    authority: str
+    channel_commitment: list[int]
+    encrypted_token: list[int]
+    updated_at: int
+    bump: int
+
+
+@dataclass
+class NotificationChannel:
+    discriminator: typing.ClassVar = b"\xc8\xdb\x86\n}\xa9rP"
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "app_id" / BorshPubkey,
+        "authority" / BorshPubkey,
+        "channel_commitment" / borsh.U8[32],
+        "encrypted_token" / borsh.Bytes,
+        "updated_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    nullifier: list[int]
+    app_id: Pubkey
+    authority: Pubkey
+    channel_commitment: list[int]
+    encrypted_token: bytes
+    updated_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["NotificationChannel"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["NotificationChannel"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["NotificationChannel"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "NotificationChannel":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = NotificationChannel.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            nullifier=dec.nullifier,
+            app_id=dec.app_id,
+            authority=dec.authority,
+            channel_commitment=dec.channel_commitment,
+            encrypted_token=dec.encrypted_token,
+            updated_at=dec.updated_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> NotificationChannelJSON:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": str(self.app_id),
+            "authority": str(self.authority),
+            "channel_commitment": self.channel_commitment,
+            "encrypted_token": list(self.encrypted_token),
+            "updated_at": self.updated_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: NotificationChannelJSON) -> "NotificationChannel":
+        return cls(
+            nullifier=obj["nullifier"],
+            app_id=Pubkey.from_string(obj["app_id"]),
+            authority=Pubkey.from_string(obj["authority"]),
+            channel_commitment=obj["channel_commitment"],
+            encrypted_token=bytes(obj["encrypted_token"]),
+            updated_at=obj["updated_at"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,179 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+from .. import types
+
+
+class NullifierAccountJSON(typing.TypedDict):
+    nullifier: list[int]
+    domain: list[int]
+    domain_len: int
+    authority: str
+    created_at: int
+    last_active_at: int
+    idle_timeout_secs: int
+    expires_at: types.expiry_kind.ExpiryKindJSON
+    public_inputs_hash: list[int]
+    circuit_id: int
+    proof_hash: list[int]
+    verified_at: int
+    verifier: str
+    finalizes_at: int
+    payer: str
+    device_key: str
+    bump: int
+
+
+@dataclass
+class NullifierAccount:
+    discriminator: typing.ClassVar = b"\xfa\x1f\xee\xb1\xd5b0\xac"
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "domain_len" / borsh.U8,
+        "authority" / BorshPubkey,
+        "created_at" / borsh.I64,
+        "last_active_at" / borsh.I64,
+        "idle_timeout_secs" / borsh.I64,
+        "expires_at" / types.expiry_kind.layout,
+        "public_inputs_hash" / borsh.U8[32],
+        "circuit_id" / borsh.U32,
+        "proof_hash" / borsh.U8[32],
+        "verified_at" / borsh.I64,
+        "verifier" / BorshPubkey,
+        "finalizes_at" / borsh.I64,
+        "payer" / BorshPubkey,
+        "device_key" / BorshPubkey,
+        "bump" / borsh.U8,
+    )
+    nullifier: list[int]
+    domain: list[int]
+    domain_len: int
+    authority: Pubkey
+    created_at: int
+    last_active_at: int
+    idle_timeout_secs: int
+    expires_at: types.expiry_kind.ExpiryKindKind
+    public_inputs_hash: list[int]
+    circuit_id: int
+    proof_hash: list[int]
+    verified_at: int
+    verifier: Pubkey
+    finalizes_at: int
+    payer: Pubkey
+    device_key: Pubkey
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["NullifierAccount"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["NullifierAccount"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["NullifierAccount"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "NullifierAccount":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = NullifierAccount.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            nullifier=dec.nullifier,
+            domain=dec.domain,
+            domain_len=dec.domain_len,
+            authority=dec.authority,
+            created_at=dec.created_at,
+            last_active_at=dec.last_active_at,
+            idle_timeout_secs=dec.idle_timeout_secs,
+            expires_at=types.expiry_kind.from_decoded(dec.expires_at),
+            public_inputs_hash=dec.public_inputs_hash,
+            circuit_id=dec.circuit_id,
+            proof_hash=dec.proof_hash,
+            verified_at=dec.verified_at,
+            verifier=dec.verifier,
+            finalizes_at=dec.finalizes_at,
+            payer=dec.payer,
+            device_key=dec.device_key,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> NullifierAccountJSON:
+        return {
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "domain_len": self.domain_len,
+            "authority": str(self.authority),
+            "created_at": self.created_at,
+            "last_active_at": self.last_active_at,
+            "idle_timeout_secs": self.idle_timeout_secs,
+            "expires_at": self.expires_at.to_json(),
+            "public_inputs_hash": self.public_inputs_hash,
+            "circuit_id": self.circuit_id,
+            "proof_hash": self.proof_hash,
+            "verified_at": self.verified_at,
+            "verifier": str(self.verifier),
+            "finalizes_at": self.finalizes_at,
+            "payer": str(self.payer),
+            "device_key": str(self.device_key),
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: NullifierAccountJSON) -> "NullifierAccount":
+        return cls(
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+            domain_len=obj["domain_len"],
+            authority=Pubkey.from_string(obj["authority"]),
+            created_at=obj["created_at"],
+            last_active_at=obj["last_active_at"],
+            idle_timeout_secs=obj["idle_timeout_secs"],
+            expires_at=types.expiry_kind.from_json(obj["expires_at"]),
+            public_inputs_hash=obj["public_inputs_hash"],
+            circuit_id=obj["circuit_id"],
+            proof_hash=obj["proof_hash"],
+            verified_at=obj["verified_at"],
+            verifier=Pubkey.from_string(obj["verifier"]),
+            finalizes_at=obj["finalizes_at"],
+            payer=Pubkey.from_string(obj["payer"]),
+            device_key=Pubkey.from_string(obj["device_key"]),
+            bump=obj["bump"],
+        )
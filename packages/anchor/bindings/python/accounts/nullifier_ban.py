@@ -0,0 +1,106 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+
+
+class NullifierBanJSON(typing.TypedDict):
+    domain: list[int]
+    nullifier: list[int]
+    banned_by: str
+    banned_at: int
+    bump: int
+
+
+@dataclass
+class NullifierBan:
+    discriminator: typing.ClassVar = b"E\x9fu\xe6\x1f\x0e\xcd\xc6"
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "nullifier" / borsh.U8[32],
+        "banned_by" / BorshPubkey,
+        "banned_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    domain: list[int]
+    nullifier: list[int]
+    banned_by: Pubkey
+    banned_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["NullifierBan"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["NullifierBan"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["NullifierBan"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "NullifierBan":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = NullifierBan.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            domain=dec.domain,
+            nullifier=dec.nullifier,
+            banned_by=dec.banned_by,
+            banned_at=dec.banned_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> NullifierBanJSON:
+        return {
+            "domain": self.domain,
+            "nullifier": self.nullifier,
+            "banned_by": str(self.banned_by),
+            "banned_at": self.banned_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: NullifierBanJSON) -> "NullifierBan":
+        return cls(
+            domain=obj["domain"],
+            nullifier=obj["nullifier"],
+            banned_by=Pubkey.from_string(obj["banned_by"]),
+            banned_at=obj["banned_at"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,118 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+
+
+class NullifierLinkJSON(typing.TypedDict):
+    nullifier: list[int]
+    successor: list[int]
+    domain: list[int]
+    proof_hash: list[int]
+    authority: str
+    linked_at: int
+    bump: int
+
+
+@dataclass
+class NullifierLink:
+    discriminator: typing.ClassVar = b"\xcc\xba\xe1\xd0\xd6\x13\xb4Q"
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "successor" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "proof_hash" / borsh.U8[32],
+        "authority" / BorshPubkey,
+        "linked_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    nullifier: list[int]
+    successor: list[int]
+    domain: list[int]
+    proof_hash: list[int]
+    authority: Pubkey
+    linked_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["NullifierLink"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["NullifierLink"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["NullifierLink"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "NullifierLink":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = NullifierLink.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            nullifier=dec.nullifier,
+            successor=dec.successor,
+            domain=dec.domain,
+            proof_hash=dec.proof_hash,
+            authority=dec.authority,
+            linked_at=dec.linked_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> NullifierLinkJSON:
+        return {
+            "nullifier": self.nullifier,
+            "successor": self.successor,
+            "domain": self.domain,
+            "proof_hash": self.proof_hash,
+            "authority": str(self.authority),
+            "linked_at": self.linked_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: NullifierLinkJSON) -> "NullifierLink":
+        return cls(
+            nullifier=obj["nullifier"],
+            successor=obj["successor"],
+            domain=obj["domain"],
+            proof_hash=obj["proof_hash"],
+            authority=Pubkey.from_string(obj["authority"]),
+            linked_at=obj["linked_at"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,111 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from ..program_id import PROGRAM_ID
+
+
+class NullifierShardJSON(typing.TypedDict):
+    bits: list[int]
+    domain: list[int]
+    registered: int
+    shard: int
+    bump: int
+    padding: list[int]
+
+
+@dataclass
+class NullifierShard:
+    discriminator: typing.ClassVar = b"\xc5\x9d\x01\x92\x9c\x89\xd3="
+    layout: typing.ClassVar = borsh.CStruct(
+        "bits" / borsh.U8[1024],
+        "domain" / borsh.U8[32],
+        "registered" / borsh.U32,
+        "shard" / borsh.U16,
+        "bump" / borsh.U8,
+        "padding" / borsh.U8[1],
+    )
+    bits: list[int]
+    domain: list[int]
+    registered: int
+    shard: int
+    bump: int
+    padding: list[int]
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["NullifierShard"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["NullifierShard"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["NullifierShard"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "NullifierShard":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = NullifierShard.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            bits=dec.bits,
+            domain=dec.domain,
+            registered=dec.registered,
+            shard=dec.shard,
+            bump=dec.bump,
+            padding=dec.padding,
+        )
+
+    def to_json(self) -> NullifierShardJSON:
+        return {
+            "bits": self.bits,
+            "domain": self.domain,
+            "registered": self.registered,
+            "shard": self.shard,
+            "bump": self.bump,
+            "padding": self.padding,
+        }
+
+    @classmethod
+    def from_json(cls, obj: NullifierShardJSON) -> "NullifierShard":
+        return cls(
+            bits=obj["bits"],
+            domain=obj["domain"],
+            registered=obj["registered"],
+            shard=obj["shard"],
+            bump=obj["bump"],
+            padding=obj["padding"],
+        )
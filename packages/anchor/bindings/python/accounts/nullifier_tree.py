@@ -0,0 +1,100 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+
+
+class NullifierTreeJSON(typing.TypedDict):
+    domain: list[int]
+    merkle_tree: str
+    next_index: int
+    bump: int
+
+
+@dataclass
+class NullifierTree:
+    discriminator: typing.ClassVar = b"\xdby\x03\xe0\x15q\xd8t"
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "merkle_tree" / BorshPubkey,
+        "next_index" / borsh.U32,
+        "bump" / borsh.U8,
+    )
+    domain: list[int]
+    merkle_tree: Pubkey
+    next_index: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["NullifierTree"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["NullifierTree"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["NullifierTree"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "NullifierTree":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = NullifierTree.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            domain=dec.domain,
+            merkle_tree=dec.merkle_tree,
+            next_index=dec.next_index,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> NullifierTreeJSON:
+        return {
+            "domain": self.domain,
+            "merkle_tree": str(self.merkle_tree),
+            "next_index": self.next_index,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: NullifierTreeJSON) -> "NullifierTree":
+        return cls(
+            domain=obj["domain"],
+            merkle_tree=Pubkey.from_string(obj["merkle_tree"]),
+            next_index=obj["next_index"],
+            bump=obj["bump"],
+        )
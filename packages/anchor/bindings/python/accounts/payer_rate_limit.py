@@ -0,0 +1,112 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+
+
+class PayerRateLimitJSON(typing.TypedDict):
+    payer: str
+    current_bucket: int
+    buckets: list[int]
+    quota: int
+    bump: int
+    padding: list[int]
+
+
+@dataclass
+class PayerRateLimit:
+    discriminator: typing.ClassVar = b"\\\xc0\xbd5\xfeR1>"
+    layout: typing.ClassVar = borsh.CStruct(
+        "payer" / BorshPubkey,
+        "current_bucket" / borsh.I64,
+        "buckets" / borsh.U32[6],
+        "quota" / borsh.U32,
+        "bump" / borsh.U8,
+        "padding" / borsh.U8[3],
+    )
+    payer: Pubkey
+    current_bucket: int
+    buckets: list[int]
+    quota: int
+    bump: int
+    padding: list[int]
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["PayerRateLimit"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["PayerRateLimit"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["PayerRateLimit"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "PayerRateLimit":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = PayerRateLimit.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            payer=dec.payer,
+            current_bucket=dec.current_bucket,
+            buckets=dec.buckets,
+            quota=dec.quota,
+            bump=dec.bump,
+            padding=dec.padding,
+        )
+
+    def to_json(self) -> PayerRateLimitJSON:
+        return {
+            "payer": str(self.payer),
+            "current_bucket": self.current_bucket,
+            "buckets": self.buckets,
+            "quota": self.quota,
+            "bump": self.bump,
+            "padding": self.padding,
+        }
+
+    @classmethod
+    def from_json(cls, obj: PayerRateLimitJSON) -> "PayerRateLimit":
+        return cls(
+            payer=Pubkey.from_string(obj["payer"]),
+            current_bucket=obj["current_bucket"],
+            buckets=obj["buckets"],
+            quota=obj["quota"],
+            bump=obj["bump"],
+            padding=obj["padding"],
+        )
@@ -0,0 +1,107 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+from .. import types
+
+
+class PermissionAccessJSON(typing.TypedDict):
+    permission_grant: str
+    accessed_at: int
+    permission_used: types.permission.PermissionJSON
+    metadata: str
+    payer: str
+
+
+@dataclass
+class PermissionAccess:
+    discriminator: typing.ClassVar = b"\x1f(X\xb6i\x8e\xe8$"
+    layout: typing.ClassVar = borsh.CStruct(
+        "permission_grant" / BorshPubkey,
+        "accessed_at" / borsh.I64,
+        "permission_used" / types.permission.layout,
+        "metadata" / borsh.String,
+        "payer" / BorshPubkey,
+    )
+    permission_grant: Pubkey
+    accessed_at: int
+    permission_used: types.permission.PermissionKind
+    metadata: str
+    payer: Pubkey
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["PermissionAccess"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["PermissionAccess"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["PermissionAccess"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "PermissionAccess":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = PermissionAccess.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            permission_grant=dec.permission_grant,
+            accessed_at=dec.accessed_at,
+            permission_used=types.permission.from_decoded(dec.permission_used),
+            metadata=dec.metadata,
+            payer=dec.payer,
+        )
+
+    def to_json(self) -> PermissionAccessJSON:
+        return {
+            "permission_grant": str(self.permission_grant),
+            "accessed_at": self.accessed_at,
+            "permission_used": self.permission_used.to_json(),
+            "metadata": self.metadata,
+            "payer": str(self.payer),
+        }
+
+    @classmethod
+    def from_json(cls, obj: PermissionAccessJSON) -> "PermissionAccess":
+        return cls(
+            permission_grant=Pubkey.from_string(obj["permission_grant"]),
+            accessed_at=obj["accessed_at"],
+            permission_used=types.permission.from_json(obj["permission_used"]),
+            metadata=obj["metadata"],
+            payer=Pubkey.from_string(obj["payer"]),
+        )
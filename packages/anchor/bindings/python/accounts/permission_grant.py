@@ -0,0 +1,136 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from construct import Construct
+from ..program_id import PROGRAM_ID
+from .. import types
+
+
+class PermissionGrantJSON(typing.TypedDict):
+    nullifier: list[int]
+    app_id: str
+    permissions: list[types.permission.PermissionJSON]
+    granted_at: int
+    expires_at: types.expiry_kind.ExpiryKindJSON
+    revoked: bool
+    bump: int
+    permission_mask: int
+    successor: list[int]
+
+
+@dataclass
+class PermissionGrant:
+    discriminator: typing.ClassVar = b"\xe0r\x84\xc8T\xb0GG"
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "app_id" / BorshPubkey,
+        "permissions" / borsh.Vec(typing.cast(Construct, types.permission.layout)),
+        "granted_at" / borsh.I64,
+        "expires_at" / types.expiry_kind.layout,
+        "revoked" / borsh.Bool,
+        "bump" / borsh.U8,
+        "permission_mask" / borsh.U64,
+        "successor" / borsh.U8[32],
+    )
+    nullifier: list[int]
+    app_id: Pubkey
+    permissions: list[types.permission.PermissionKind]
+    granted_at: int
+    expires_at: types.expiry_kind.ExpiryKindKind
+    revoked: bool
+    bump: int
+    permission_mask: int
+    successor: list[int]
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["PermissionGrant"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["PermissionGrant"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["PermissionGrant"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "PermissionGrant":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = PermissionGrant.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            nullifier=dec.nullifier,
+            app_id=dec.app_id,
+            permissions=list(
+                map(lambda item: types.permission.from_decoded(item), dec.permissions)
+            ),
+            granted_at=dec.granted_at,
+            expires_at=types.expiry_kind.from_decoded(dec.expires_at),
+            revoked=dec.revoked,
+            bump=dec.bump,
+            permission_mask=dec.permission_mask,
+            successor=dec.successor,
+        )
+
+    def to_json(self) -> PermissionGrantJSON:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": str(self.app_id),
+            "permissions": list(map(lambda item: item.to_json(), self.permissions)),
+            "granted_at": self.granted_at,
+            "expires_at": self.expires_at.to_json(),
+            "revoked": self.revoked,
+            "bump": self.bump,
+            "permission_mask": self.permission_mask,
+            "successor": self.successor,
+        }
+
+    @classmethod
+    def from_json(cls, obj: PermissionGrantJSON) -> "PermissionGrant":
+        return cls(
+            nullifier=obj["nullifier"],
+            app_id=Pubkey.from_string(obj["app_id"]),
+            permissions=list(
+                map(lambda item: types.permission.from_json(item), obj["permissions"])
+            ),
+            granted_at=obj["granted_at"],
+            expires_at=types.expiry_kind.from_json(obj["expires_at"]),
+            revoked=obj["revoked"],
+            bump=obj["bump"],
+            permission_mask=obj["permission_mask"],
+            successor=obj["successor"],
+        )
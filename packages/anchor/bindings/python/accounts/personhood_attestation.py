@@ -0,0 +1,112 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+
+
+class PersonhoodAttestationJSON(typing.TypedDict):
+    nullifier: list[int]
+    provider: str
+    score: int
+    issued_at: int
+    expires_at: int
+    bump: int
+
+
+@dataclass
+class PersonhoodAttestation:
+    discriminator: typing.ClassVar = b"\x85gs\xfe\xa1\x88\x90\xea"
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "provider" / BorshPubkey,
+        "score" / borsh.U16,
+        "issued_at" / borsh.I64,
+        "expires_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    nullifier: list[int]
+    provider: Pubkey
+    score: int
+    issued_at: int
+    expires_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["PersonhoodAttestation"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["PersonhoodAttestation"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["PersonhoodAttestation"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "PersonhoodAttestation":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = PersonhoodAttestation.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            nullifier=dec.nullifier,
+            provider=dec.provider,
+            score=dec.score,
+            issued_at=dec.issued_at,
+            expires_at=dec.expires_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> PersonhoodAttestationJSON:
+        return {
+            "nullifier": self.nullifier,
+            "provider": str(self.provider),
+            "score": self.score,
+            "issued_at": self.issued_at,
+            "expires_at": self.expires_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: PersonhoodAttestationJSON) -> "PersonhoodAttestation":
+        return cls(
+            nullifier=obj["nullifier"],
+            provider=Pubkey.from_string(obj["provider"]),
+            score=obj["score"],
+            issued_at=obj["issued_at"],
+            expires_at=obj["expires_at"],
+            bump=obj["bump"],
+        )
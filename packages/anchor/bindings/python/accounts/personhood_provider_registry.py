@@ -0,0 +1,91 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from construct import Construct
+from ..program_id import PROGRAM_ID
+
+
+class PersonhoodProviderRegistryJSON(typing.TypedDict):
+    providers: list[str]
+    bump: int
+
+
+@dataclass
+class PersonhoodProviderRegistry:
+    discriminator: typing.ClassVar = b"\x1b\x0f\x9e\x96.\xe9\x16\xc2"
+    layout: typing.ClassVar = borsh.CStruct(
+        "providers" / borsh.Vec(typing.cast(Construct, BorshPubkey)),
+        "bump" / borsh.U8,
+    )
+    providers: list[Pubkey]
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["PersonhoodProviderRegistry"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["PersonhoodProviderRegistry"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["PersonhoodProviderRegistry"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "PersonhoodProviderRegistry":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = PersonhoodProviderRegistry.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            providers=dec.providers,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> PersonhoodProviderRegistryJSON:
+        return {
+            "providers": list(map(lambda item: str(item), self.providers)),
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: PersonhoodProviderRegistryJSON) -> "PersonhoodProviderRegistry":
+        return cls(
+            providers=list(
+                map(lambda item: Pubkey.from_string(item), obj["providers"])
+            ),
+            bump=obj["bump"],
+        )
@@ -0,0 +1,99 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from ..program_id import PROGRAM_ID
+
+
+class PointsJSON(typing.TypedDict):
+    nullifier: list[int]
+    balance: int
+    claimed_at: int
+    bump: int
+
+
+@dataclass
+class Points:
+    discriminator: typing.ClassVar = b"\x84Q\x0c\x92\xff\xe9a\xf2"
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "balance" / borsh.U64,
+        "claimed_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    nullifier: list[int]
+    balance: int
+    claimed_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["Points"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["Points"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["Points"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "Points":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = Points.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            nullifier=dec.nullifier,
+            balance=dec.balance,
+            claimed_at=dec.claimed_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> PointsJSON:
+        return {
+            "nullifier": self.nullifier,
+            "balance": self.balance,
+            "claimed_at": self.claimed_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: PointsJSON) -> "Points":
+        return cls(
+            nullifier=obj["nullifier"],
+            balance=obj["balance"],
+            claimed_at=obj["claimed_at"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,99 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from ..program_id import PROGRAM_ID
+
+
+class PointsRootJSON(typing.TypedDict):
+    root: list[int]
+    leaf_count: int
+    published_at: int
+    bump: int
+
+
+@dataclass
+class PointsRoot:
+    discriminator: typing.ClassVar = b" kOH\xf0r\x96z"
+    layout: typing.ClassVar = borsh.CStruct(
+        "root" / borsh.U8[32],
+        "leaf_count" / borsh.U32,
+        "published_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    root: list[int]
+    leaf_count: int
+    published_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["PointsRoot"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["PointsRoot"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["PointsRoot"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "PointsRoot":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = PointsRoot.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            root=dec.root,
+            leaf_count=dec.leaf_count,
+            published_at=dec.published_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> PointsRootJSON:
+        return {
+            "root": self.root,
+            "leaf_count": self.leaf_count,
+            "published_at": self.published_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: PointsRootJSON) -> "PointsRoot":
+        return cls(
+            root=obj["root"],
+            leaf_count=obj["leaf_count"],
+            published_at=obj["published_at"],
+            bump=obj["bump"],
+        )
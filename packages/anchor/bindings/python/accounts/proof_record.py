@@ -0,0 +1,105 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from ..program_id import PROGRAM_ID
+
+
+class ProofRecordJSON(typing.TypedDict):
+    proof_hash: list[int]
+    nullifier: list[int]
+    domain: list[int]
+    created_at: int
+    bump: int
+
+
+@dataclass
+class ProofRecord:
+    discriminator: typing.ClassVar = b"\xed;\x9b\xac\xccuW,"
+    layout: typing.ClassVar = borsh.CStruct(
+        "proof_hash" / borsh.U8[32],
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "created_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    proof_hash: list[int]
+    nullifier: list[int]
+    domain: list[int]
+    created_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["ProofRecord"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["ProofRecord"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["ProofRecord"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "ProofRecord":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = ProofRecord.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            proof_hash=dec.proof_hash,
+            nullifier=dec.nullifier,
+            domain=dec.domain,
+            created_at=dec.created_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> ProofRecordJSON:
+        return {
+            "proof_hash": self.proof_hash,
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "created_at": self.created_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: ProofRecordJSON) -> "ProofRecord":
+        return cls(
+            proof_hash=obj["proof_hash"],
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+            created_at=obj["created_at"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,120 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from construct import Construct
+from ..program_id import PROGRAM_ID
+from .. import types
+
+
+class ProtocolConfigJSON(typing.TypedDict):
+    authority: str
+    accepted_mints: list[types.accepted_mint.AcceptedMintJSON]
+    fee_usd_micros: int
+    max_price_age_secs: int
+    max_price_conf_bps: int
+    bump: int
+
+
+@dataclass
+class ProtocolConfig:
+    discriminator: typing.ClassVar = b"\xcf[\xfa\x1c\x98\xb3\xd7\xd1"
+    layout: typing.ClassVar = borsh.CStruct(
+        "authority" / BorshPubkey,
+        "accepted_mints" / borsh.Vec(typing.cast(Construct, types.accepted_mint.AcceptedMint.layout)),
+        "fee_usd_micros" / borsh.U64,
+        "max_price_age_secs" / borsh.U64,
+        "max_price_conf_bps" / borsh.U16,
+        "bump" / borsh.U8,
+    )
+    authority: Pubkey
+    accepted_mints: list[types.accepted_mint.AcceptedMint]
+    fee_usd_micros: int
+    max_price_age_secs: int
+    max_price_conf_bps: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["ProtocolConfig"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["ProtocolConfig"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["ProtocolConfig"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "ProtocolConfig":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = ProtocolConfig.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            authority=dec.authority,
+            accepted_mints=list(
+                map(lambda item: types.accepted_mint.AcceptedMint.from_decoded(item), dec.accepted_mints)
+            ),
+            fee_usd_micros=dec.fee_usd_micros,
+            max_price_age_secs=dec.max_price_age_secs,
+            max_price_conf_bps=dec.max_price_conf_bps,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> ProtocolConfigJSON:
+        return {
+            "authority": str(self.authority),
+            "accepted_mints": list(
+                map(lambda item: item.to_json(), self.accepted_mints)
+            ),
+            "fee_usd_micros": self.fee_usd_micros,
+            "max_price_age_secs": self.max_price_age_secs,
+            "max_price_conf_bps": self.max_price_conf_bps,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: ProtocolConfigJSON) -> "ProtocolConfig":
+        return cls(
+            authority=Pubkey.from_string(obj["authority"]),
+            accepted_mints=list(
+                map(lambda item: types.accepted_mint.AcceptedMint.from_json(item), obj["accepted_mints"])
+            ),
+            fee_usd_micros=obj["fee_usd_micros"],
+            max_price_age_secs=obj["max_price_age_secs"],
+            max_price_conf_bps=obj["max_price_conf_bps"],
+            bump=obj["bump"],
+        )
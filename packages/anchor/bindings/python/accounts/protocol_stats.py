@@ -0,0 +1,105 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from ..program_id import PROGRAM_ID
+
+
+class ProtocolStatsJSON(typing.TypedDict):
+    total_verifications: int
+    total_grants: int
+    total_revocations: int
+    total_attestations: int
+    bump: int
+
+
+@dataclass
+class ProtocolStats:
+    discriminator: typing.ClassVar = b"\x85\xe3\xd8\xf1\xeb\xac#\x1f"
+    layout: typing.ClassVar = borsh.CStruct(
+        "total_verifications" / borsh.U64,
+        "total_grants" / borsh.U64,
+        "total_revocations" / borsh.U64,
+        "total_attestations" / borsh.U64,
+        "bump" / borsh.U8,
+    )
+    total_verifications: int
+    total_grants: int
+    total_revocations: int
+    total_attestations: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["ProtocolStats"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["ProtocolStats"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["ProtocolStats"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "ProtocolStats":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = ProtocolStats.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            total_verifications=dec.total_verifications,
+            total_grants=dec.total_grants,
+            total_revocations=dec.total_revocations,
+            total_attestations=dec.total_attestations,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> ProtocolStatsJSON:
+        return {
+            "total_verifications": self.total_verifications,
+            "total_grants": self.total_grants,
+            "total_revocations": self.total_revocations,
+            "total_attestations": self.total_attestations,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: ProtocolStatsJSON) -> "ProtocolStats":
+        return cls(
+            total_verifications=obj["total_verifications"],
+            total_grants=obj["total_grants"],
+            total_revocations=obj["total_revocations"],
+            total_attestations=obj["total_attestations"],
+            bump=obj["bump"],
+        )
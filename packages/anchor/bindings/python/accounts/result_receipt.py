@@ -0,0 +1,106 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+
+
+class ResultReceiptJSON(typing.TypedDict):
+    signature_hash: list[int]
+    payer: str
+    consumed_at: int
+    retain_until: int
+    bump: int
+
+
+@dataclass
+class ResultReceipt:
+    discriminator: typing.ClassVar = b"\xb5\xef\x9c\x08\xd5\x81\x82\xde"
+    layout: typing.ClassVar = borsh.CStruct(
+        "signature_hash" / borsh.U8[32],
+        "payer" / BorshPubkey,
+        "consumed_at" / borsh.I64,
+        "retain_until" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    signature_hash: list[int]
+    payer: Pubkey
+    consumed_at: int
+    retain_until: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["ResultReceipt"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["ResultReceipt"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["ResultReceipt"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "ResultReceipt":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = ResultReceipt.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            signature_hash=dec.signature_hash,
+            payer=dec.payer,
+            consumed_at=dec.consumed_at,
+            retain_until=dec.retain_until,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> ResultReceiptJSON:
+        return {
+            "signature_hash": self.signature_hash,
+            "payer": str(self.payer),
+            "consumed_at": self.consumed_at,
+            "retain_until": self.retain_until,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: ResultReceiptJSON) -> "ResultReceipt":
+        return cls(
+            signature_hash=obj["signature_hash"],
+            payer=Pubkey.from_string(obj["payer"]),
+            consumed_at=obj["consumed_at"],
+            retain_until=obj["retain_until"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,136 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+
+
+class RevealEscrowJSON(typing.TypedDict):
+    nullifier: list[int]
+    app_id: str
+    authority: str
+    ciphertext: list[int]
+    key_commitment: list[int]
+    reveal_key: list[int]
+    opened_at: int
+    reveals_at: int
+    released: bool
+    bump: int
+
+
+@dataclass
+class RevealEscrow:
+    discriminator: typing.ClassVar = b"\xc9q`\xb36\xd6\xd8j"
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "app_id" / BorshPubkey,
+        "authority" / BorshPubkey,
+        "ciphertext" / borsh.Bytes,
+        "key_commitment" / borsh.U8[32],
+        "reveal_key" / borsh.U8[32],
+        "opened_at" / borsh.I64,
+        "reveals_at" / borsh.I64,
+        "released" / borsh.Bool,
+        "bump" / borsh.U8,
+    )
+    nullifier: list[int]
+    app_id: Pubkey
+    authority: Pubkey
+    ciphertext: bytes
+    key_commitment: list[int]
+    reveal_key: list[int]
+    opened_at: int
+    reveals_at: int
+    released: bool
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["RevealEscrow"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["RevealEscrow"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["RevealEscrow"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "RevealEscrow":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = RevealEscrow.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            nullifier=dec.nullifier,
+            app_id=dec.app_id,
+            authority=dec.authority,
+            ciphertext=dec.ciphertext,
+            key_commitment=dec.key_commitment,
+            reveal_key=dec.reveal_key,
+            opened_at=dec.opened_at,
+            reveals_at=dec.reveals_at,
+            released=dec.released,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> RevealEscrowJSON:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": str(self.app_id),
+            "authority": str(self.authority),
+            "ciphertext": list(self.ciphertext),
+            "key_commitment": self.key_commitment,
+            "reveal_key": self.reveal_key,
+            "opened_at": self.opened_at,
+            "reveals_at": self.reveals_at,
+            "released": self.released,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: RevealEscrowJSON) -> "RevealEscrow":
+        return cls(
+            nullifier=obj["nullifier"],
+            app_id=Pubkey.from_string(obj["app_id"]),
+            authority=Pubkey.from_string(obj["authority"]),
+            ciphertext=bytes(obj["ciphertext"]),
+            key_commitment=obj["key_commitment"],
+            reveal_key=obj["reveal_key"],
+            opened_at=obj["opened_at"],
+            reveals_at=obj["reveals_at"],
+            released=obj["released"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,94 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+
+
+class SasBridgeJSON(typing.TypedDict):
+    credential: str
+    schema: str
+    bump: int
+
+
+@dataclass
+class SasBridge:
+    discriminator: typing.ClassVar = b"\r\x9c\x19\xc2w\x85=\xaf"
+    layout: typing.ClassVar = borsh.CStruct(
+        "credential" / BorshPubkey,
+        "schema" / BorshPubkey,
+        "bump" / borsh.U8,
+    )
+    credential: Pubkey
+    schema: Pubkey
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["SasBridge"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["SasBridge"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["SasBridge"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "SasBridge":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = SasBridge.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            credential=dec.credential,
+            schema=dec.schema,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> SasBridgeJSON:
+        return {
+            "credential": str(self.credential),
+            "schema": str(self.schema),
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: SasBridgeJSON) -> "SasBridge":
+        return cls(
+            credential=Pubkey.from_string(obj["credential"]),
+            schema=Pubkey.from_string(obj["schema"]),
+            bump=obj["bump"],
+        )
@@ -0,0 +1,88 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from construct import Construct
+from ..program_id import PROGRAM_ID
+
+
+class Secp256r1VerifierRegistryJSON(typing.TypedDict):
+    verifiers: list[list[int]]
+    bump: int
+
+
+@dataclass
+class Secp256r1VerifierRegistry:
+    discriminator: typing.ClassVar = b" \xda\xbb\xaa\x1d>9\xaf"
+    layout: typing.ClassVar = borsh.CStruct(
+        "verifiers" / borsh.Vec(typing.cast(Construct, borsh.U8[33])),
+        "bump" / borsh.U8,
+    )
+    verifiers: list[list[int]]
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["Secp256r1VerifierRegistry"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["Secp256r1VerifierRegistry"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["Secp256r1VerifierRegistry"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "Secp256r1VerifierRegistry":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = Secp256r1VerifierRegistry.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            verifiers=dec.verifiers,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> Secp256r1VerifierRegistryJSON:
+        return {
+            "verifiers": self.verifiers,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: Secp256r1VerifierRegistryJSON) -> "Secp256r1VerifierRegistry":
+        return cls(
+            verifiers=obj["verifiers"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,118 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+
+
+class SessionDelegateJSON(typing.TypedDict):
+    session: str
+    delegate: str
+    scope: int
+    created_at: int
+    expires_at: int
+    authority: str
+    bump: int
+
+
+@dataclass
+class SessionDelegate:
+    discriminator: typing.ClassVar = b"\xecl\x80\xc8;\xc7\xf0J"
+    layout: typing.ClassVar = borsh.CStruct(
+        "session" / BorshPubkey,
+        "delegate" / BorshPubkey,
+        "scope" / borsh.U64,
+        "created_at" / borsh.I64,
+        "expires_at" / borsh.I64,
+        "authority" / BorshPubkey,
+        "bump" / borsh.U8,
+    )
+    session: Pubkey
+    delegate: Pubkey
+    scope: int
+    created_at: int
+    expires_at: int
+    authority: Pubkey
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["SessionDelegate"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["SessionDelegate"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["SessionDelegate"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "SessionDelegate":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = SessionDelegate.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            session=dec.session,
+            delegate=dec.delegate,
+            scope=dec.scope,
+            created_at=dec.created_at,
+            expires_at=dec.expires_at,
+            authority=dec.authority,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> SessionDelegateJSON:
+        return {
+            "session": str(self.session),
+            "delegate": str(self.delegate),
+            "scope": self.scope,
+            "created_at": self.created_at,
+            "expires_at": self.expires_at,
+            "authority": str(self.authority),
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: SessionDelegateJSON) -> "SessionDelegate":
+        return cls(
+            session=Pubkey.from_string(obj["session"]),
+            delegate=Pubkey.from_string(obj["delegate"]),
+            scope=obj["scope"],
+            created_at=obj["created_at"],
+            expires_at=obj["expires_at"],
+            authority=Pubkey.from_string(obj["authority"]),
+            bump=obj["bump"],
+        )
@@ -0,0 +1,118 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+
+
+class UsernameJSON(typing.TypedDict):
+    name: list[int]
+    nullifier: list[int]
+    domain: list[int]
+    authority: str
+    claimed_at: int
+    transferred_at: int
+    bump: int
+
+
+@dataclass
+class Username:
+    discriminator: typing.ClassVar = b"\x95T)P\xb1\xcb\xe8\xa8"
+    layout: typing.ClassVar = borsh.CStruct(
+        "name" / borsh.U8[32],
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "authority" / BorshPubkey,
+        "claimed_at" / borsh.I64,
+        "transferred_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    name: list[int]
+    nullifier: list[int]
+    domain: list[int]
+    authority: Pubkey
+    claimed_at: int
+    transferred_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["Username"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["Username"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["Username"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "Username":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = Username.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            name=dec.name,
+            nullifier=dec.nullifier,
+            domain=dec.domain,
+            authority=dec.authority,
+            claimed_at=dec.claimed_at,
+            transferred_at=dec.transferred_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> UsernameJSON:
+        return {
+            "name": self.name,
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "authority": str(self.authority),
+            "claimed_at": self.claimed_at,
+            "transferred_at": self.transferred_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: UsernameJSON) -> "Username":
+        return cls(
+            name=obj["name"],
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+            authority=Pubkey.from_string(obj["authority"]),
+            claimed_at=obj["claimed_at"],
+            transferred_at=obj["transferred_at"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,119 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+from .. import types
+
+
+class VcCommitmentJSON(typing.TypedDict):
+    nullifier: list[int]
+    issuer: str
+    vc_hash: list[int]
+    status: types.vc_status.VcStatusJSON
+    issued_at: int
+    updated_at: int
+    bump: int
+
+
+@dataclass
+class VcCommitment:
+    discriminator: typing.ClassVar = b"\x94z\x16\x86-\x87\xee\xe5"
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "issuer" / BorshPubkey,
+        "vc_hash" / borsh.U8[32],
+        "status" / types.vc_status.layout,
+        "issued_at" / borsh.I64,
+        "updated_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    nullifier: list[int]
+    issuer: Pubkey
+    vc_hash: list[int]
+    status: types.vc_status.VcStatusKind
+    issued_at: int
+    updated_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["VcCommitment"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["VcCommitment"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["VcCommitment"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "VcCommitment":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = VcCommitment.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            nullifier=dec.nullifier,
+            issuer=dec.issuer,
+            vc_hash=dec.vc_hash,
+            status=types.vc_status.from_decoded(dec.status),
+            issued_at=dec.issued_at,
+            updated_at=dec.updated_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> VcCommitmentJSON:
+        return {
+            "nullifier": self.nullifier,
+            "issuer": str(self.issuer),
+            "vc_hash": self.vc_hash,
+            "status": self.status.to_json(),
+            "issued_at": self.issued_at,
+            "updated_at": self.updated_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VcCommitmentJSON) -> "VcCommitment":
+        return cls(
+            nullifier=obj["nullifier"],
+            issuer=Pubkey.from_string(obj["issuer"]),
+            vc_hash=obj["vc_hash"],
+            status=types.vc_status.from_json(obj["status"]),
+            issued_at=obj["issued_at"],
+            updated_at=obj["updated_at"],
+            bump=obj["bump"],
+        )
@@ -0,0 +1,149 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from ..program_id import PROGRAM_ID
+from .. import types
+
+
+class VerificationSessionJSON(typing.TypedDict):
+    authority: str
+    nullifier: list[int]
+    domain: list[int]
+    circuit_id: int
+    proof_hash: list[int]
+    proof_len: int
+    public_inputs_count: int
+    bytes_received: int
+    status: types.verification_status.VerificationStatusJSON
+    step: int
+    opened_at: int
+    bump: int
+
+
+@dataclass
+class VerificationSession:
+    discriminator: typing.ClassVar = b"\xf7E\xc7\x1ap\xc3\xf3\xfe"
+    layout: typing.ClassVar = borsh.CStruct(
+        "authority" / BorshPubkey,
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "circuit_id" / borsh.U32,
+        "proof_hash" / borsh.U8[32],
+        "proof_len" / borsh.U32,
+        "public_inputs_count" / borsh.U16,
+        "bytes_received" / borsh.U32,
+        "status" / types.verification_status.layout,
+        "step" / borsh.U32,
+        "opened_at" / borsh.I64,
+        "bump" / borsh.U8,
+    )
+    authority: Pubkey
+    nullifier: list[int]
+    domain: list[int]
+    circuit_id: int
+    proof_hash: list[int]
+    proof_len: int
+    public_inputs_count: int
+    bytes_received: int
+    status: types.verification_status.VerificationStatusKind
+    step: int
+    opened_at: int
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["VerificationSession"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["VerificationSession"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["VerificationSession"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "VerificationSession":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = VerificationSession.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            authority=dec.authority,
+            nullifier=dec.nullifier,
+            domain=dec.domain,
+            circuit_id=dec.circuit_id,
+            proof_hash=dec.proof_hash,
+            proof_len=dec.proof_len,
+            public_inputs_count=dec.public_inputs_count,
+            bytes_received=dec.bytes_received,
+            status=types.verification_status.from_decoded(dec.status),
+            step=dec.step,
+            opened_at=dec.opened_at,
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> VerificationSessionJSON:
+        return {
+            "authority": str(self.authority),
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "circuit_id": self.circuit_id,
+            "proof_hash": self.proof_hash,
+            "proof_len": self.proof_len,
+            "public_inputs_count": self.public_inputs_count,
+            "bytes_received": self.bytes_received,
+            "status": self.status.to_json(),
+            "step": self.step,
+            "opened_at": self.opened_at,
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VerificationSessionJSON) -> "VerificationSession":
+        return cls(
+            authority=Pubkey.from_string(obj["authority"]),
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+            circuit_id=obj["circuit_id"],
+            proof_hash=obj["proof_hash"],
+            proof_len=obj["proof_len"],
+            public_inputs_count=obj["public_inputs_count"],
+            bytes_received=obj["bytes_received"],
+            status=types.verification_status.from_json(obj["status"]),
+            step=obj["step"],
+            opened_at=obj["opened_at"],
+            bump=obj["bump"],
+        )
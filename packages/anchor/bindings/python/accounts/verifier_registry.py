@@ -0,0 +1,120 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from anchorpy.borsh_extension import BorshPubkey
+from construct import Construct
+from ..program_id import PROGRAM_ID
+from .. import types
+
+
+class VerifierRegistryJSON(typing.TypedDict):
+    verifiers: list[str]
+    threshold: int
+    bump: int
+    rotations: list[types.verifier_rotation.VerifierRotationJSON]
+    grace_period_secs: int
+    receipt_retention_secs: int
+
+
+@dataclass
+class VerifierRegistry:
+    discriminator: typing.ClassVar = b"\x15\xdb\xa8\x873\xb6X\x81"
+    layout: typing.ClassVar = borsh.CStruct(
+        "verifiers" / borsh.Vec(typing.cast(Construct, BorshPubkey)),
+        "threshold" / borsh.U8,
+        "bump" / borsh.U8,
+        "rotations" / borsh.Vec(typing.cast(Construct, types.verifier_rotation.VerifierRotation.layout)),
+        "grace_period_secs" / borsh.I64,
+        "receipt_retention_secs" / borsh.I64,
+    )
+    verifiers: list[Pubkey]
+    threshold: int
+    bump: int
+    rotations: list[types.verifier_rotation.VerifierRotation]
+    grace_period_secs: int
+    receipt_retention_secs: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["VerifierRegistry"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["VerifierRegistry"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["VerifierRegistry"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "VerifierRegistry":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = VerifierRegistry.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            verifiers=dec.verifiers,
+            threshold=dec.threshold,
+            bump=dec.bump,
+            rotations=list(
+                map(lambda item: types.verifier_rotation.VerifierRotation.from_decoded(item), dec.rotations)
+            ),
+            grace_period_secs=dec.grace_period_secs,
+            receipt_retention_secs=dec.receipt_retention_secs,
+        )
+
+    def to_json(self) -> VerifierRegistryJSON:
+        return {
+            "verifiers": list(map(lambda item: str(item), self.verifiers)),
+            "threshold": self.threshold,
+            "bump": self.bump,
+            "rotations": list(map(lambda item: item.to_json(), self.rotations)),
+            "grace_period_secs": self.grace_period_secs,
+            "receipt_retention_secs": self.receipt_retention_secs,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VerifierRegistryJSON) -> "VerifierRegistry":
+        return cls(
+            verifiers=list(
+                map(lambda item: Pubkey.from_string(item), obj["verifiers"])
+            ),
+            threshold=obj["threshold"],
+            bump=obj["bump"],
+            rotations=list(
+                map(lambda item: types.verifier_rotation.VerifierRotation.from_json(item), obj["rotations"])
+            ),
+            grace_period_secs=obj["grace_period_secs"],
+            receipt_retention_secs=obj["receipt_retention_secs"],
+        )
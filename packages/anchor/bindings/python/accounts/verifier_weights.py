@@ -0,0 +1,93 @@
+import typing
+from dataclasses import dataclass
+from solders.pubkey import Pubkey
+from solana.rpc.async_api import AsyncClient
+from solana.rpc.commitment import Commitment
+import borsh_construct as borsh
+from anchorpy.coder.accounts import ACCOUNT_DISCRIMINATOR_SIZE
+from anchorpy.error import AccountInvalidDiscriminator
+from anchorpy.utils.rpc import get_multiple_accounts
+from construct import Construct
+from ..program_id import PROGRAM_ID
+from .. import types
+
+
+class VerifierWeightsJSON(typing.TypedDict):
+    entries: list[types.verifier_weight.VerifierWeightJSON]
+    bump: int
+
+
+@dataclass
+class VerifierWeights:
+    discriminator: typing.ClassVar = b"\xc1\xb8L\x8e\x0f\x90:\xc2"
+    layout: typing.ClassVar = borsh.CStruct(
+        "entries" / borsh.Vec(typing.cast(Construct, types.verifier_weight.VerifierWeight.layout)),
+        "bump" / borsh.U8,
+    )
+    entries: list[types.verifier_weight.VerifierWeight]
+    bump: int
+
+    @classmethod
+    async def fetch(
+        cls,
+        conn: AsyncClient,
+        address: Pubkey,
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.Optional["VerifierWeights"]:
+        resp = await conn.get_account_info(address, commitment=commitment)
+        info = resp.value
+        if info is None:
+            return None
+        if info.owner != program_id:
+            raise ValueError("Account does not belong to this program")
+        bytes_data = info.data
+        return cls.decode(bytes_data)
+
+    @classmethod
+    async def fetch_multiple(
+        cls,
+        conn: AsyncClient,
+        addresses: list[Pubkey],
+        commitment: typing.Optional[Commitment] = None,
+        program_id: Pubkey = PROGRAM_ID,
+    ) -> typing.List[typing.Optional["VerifierWeights"]]:
+        infos = await get_multiple_accounts(conn, addresses, commitment=commitment)
+        res: typing.List[typing.Optional["VerifierWeights"]] = []
+        for info in infos:
+            if info is None:
+                res.append(None)
+                continue
+            if info.account.owner != program_id:
+                raise ValueError("Account does not belong to this program")
+            res.append(cls.decode(info.account.data))
+        return res
+
+    @classmethod
+    def decode(cls, data: bytes) -> "VerifierWeights":
+        if data[:ACCOUNT_DISCRIMINATOR_SIZE] != cls.discriminator:
+            raise AccountInvalidDiscriminator(
+                "The discriminator for this account is invalid"
+            )
+        dec = VerifierWeights.layout.parse(data[ACCOUNT_DISCRIMINATOR_SIZE:])
+        return cls(
+            entries=list(
+                map(lambda item: types.verifier_weight.VerifierWeight.from_decoded(item), dec.entries)
+            ),
+            bump=dec.bump,
+        )
+
+    def to_json(self) -> VerifierWeightsJSON:
+        return {
+            "entries": list(map(lambda item: item.to_json(), self.entries)),
+            "bump": self.bump,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VerifierWeightsJSON) -> "VerifierWeights":
+        return cls(
+            entries=list(
+                map(lambda item: types.verifier_weight.VerifierWeight.from_json(item), obj["entries"])
+            ),
+            bump=obj["bump"],
+        )
@@ -0,0 +1,1874 @@
+import typing
+from anchorpy.error import ProgramError
+
+
+class InvalidProof(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6000, "Invalid proof")
+
+    code = 6000
+    name = "InvalidProof"
+    msg = "Invalid proof"
+
+
+class DuplicateNullifier(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6001, "Nullifier already used")
+
+    code = 6001
+    name = "DuplicateNullifier"
+    msg = "Nullifier already used"
+
+
+class ProofExpired(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6002, "Proof expired")
+
+    code = 6002
+    name = "ProofExpired"
+    msg = "Proof expired"
+
+
+class DomainTooLong(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6003, "Domain string exceeds maximum length of 255 characters")
+
+    code = 6003
+    name = "DomainTooLong"
+    msg = "Domain string exceeds maximum length of 255 characters"
+
+
+class SandboxUnavailable(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6004, "The sandbox domain is only available on devnet builds")
+
+    code = 6004
+    name = "SandboxUnavailable"
+    msg = "The sandbox domain is only available on devnet builds"
+
+
+class InvalidPublicInputs(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6005, "Invalid public inputs")
+
+    code = 6005
+    name = "InvalidPublicInputs"
+    msg = "Invalid public inputs"
+
+
+class OffsetMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6006, "Offset mismatch - points to wrong instruction")
+
+    code = 6006
+    name = "OffsetMismatch"
+    msg = "Offset mismatch - points to wrong instruction"
+
+
+class InvalidInstructionData(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6007, "Invalid instruction data")
+
+    code = 6007
+    name = "InvalidInstructionData"
+    msg = "Invalid instruction data"
+
+
+class InvalidSignatureCount(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6008, "Invalid signature count")
+
+    code = 6008
+    name = "InvalidSignatureCount"
+    msg = "Invalid signature count"
+
+
+class InvalidMessageSize(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6009, "Invalid message size")
+
+    code = 6009
+    name = "InvalidMessageSize"
+    msg = "Invalid message size"
+
+
+class ProofHashMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6010, "Proof hash mismatch")
+
+    code = 6010
+    name = "ProofHashMismatch"
+    msg = "Proof hash mismatch"
+
+
+class IsValidMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6011, "Is valid mismatch")
+
+    code = 6011
+    name = "IsValidMismatch"
+    msg = "Is valid mismatch"
+
+
+class AuthorityMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6012, "Authority public key mismatch")
+
+    code = 6012
+    name = "AuthorityMismatch"
+    msg = "Authority public key mismatch"
+
+
+class NullifierMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6013, "Nullifier mismatch - result was signed for a different nullifier")
+
+    code = 6013
+    name = "NullifierMismatch"
+    msg = "Nullifier mismatch - result was signed for a different nullifier"
+
+
+class DomainMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6014, "Domain mismatch - result was signed for a different domain")
+
+    code = 6014
+    name = "DomainMismatch"
+    msg = "Domain mismatch - result was signed for a different domain"
+
+
+class UnsupportedResultVersion(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6015, "Unsupported verification result version")
+
+    code = 6015
+    name = "UnsupportedResultVersion"
+    msg = "Unsupported verification result version"
+
+
+class PublicInputsMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6016, "Public inputs hash mismatch - result was signed for different public inputs")
+
+    code = 6016
+    name = "PublicInputsMismatch"
+    msg = "Public inputs hash mismatch - result was signed for different public inputs"
+
+
+class CircuitMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6017, "Circuit mismatch - result was signed for a different circuit")
+
+    code = 6017
+    name = "CircuitMismatch"
+    msg = "Circuit mismatch - result was signed for a different circuit"
+
+
+class VkHashMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6018, "Verification key hash mismatch")
+
+    code = 6018
+    name = "VkHashMismatch"
+    msg = "Verification key hash mismatch"
+
+
+class ClusterMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6019, "Verification result was signed for a different cluster")
+
+    code = 6019
+    name = "ClusterMismatch"
+    msg = "Verification result was signed for a different cluster"
+
+
+class InstanceMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6020, "Verification result was signed for a different Veiled instance")
+
+    code = 6020
+    name = "InstanceMismatch"
+    msg = "Verification result was signed for a different Veiled instance"
+
+
+class ProofAlreadyUsed(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6021, "Proof has already been used to register a nullifier")
+
+    code = 6021
+    name = "ProofAlreadyUsed"
+    msg = "Proof has already been used to register a nullifier"
+
+
+class InvalidVerificationBatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6022, "Verification batch must be 1-4 results, each with its nullifier, proof record, domain config and result receipt accounts")
+
+    code = 6022
+    name = "InvalidVerificationBatch"
+    msg = "Verification batch must be 1-4 results, each with its nullifier, proof record, domain config and result receipt accounts"
+
+
+class InvalidResultLayout(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6023, "Verification result fields must extend the message in order")
+
+    code = 6023
+    name = "InvalidResultLayout"
+    msg = "Verification result fields must extend the message in order"
+
+
+class BadEd25519Program(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6024, "Expected Ed25519 program")
+
+    code = 6024
+    name = "BadEd25519Program"
+    msg = "Expected Ed25519 program"
+
+
+class BadEd25519Accounts(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6025, "Bad Ed25519 accounts")
+
+    code = 6025
+    name = "BadEd25519Accounts"
+    msg = "Bad Ed25519 accounts"
+
+
+class PermissionRevoked(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6026, "Permission has been revoked")
+
+    code = 6026
+    name = "PermissionRevoked"
+    msg = "Permission has been revoked"
+
+
+class PermissionExpired(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6027, "Permission has expired")
+
+    code = 6027
+    name = "PermissionExpired"
+    msg = "Permission has expired"
+
+
+class PermissionNotGranted(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6028, "Permission not granted")
+
+    code = 6028
+    name = "PermissionNotGranted"
+    msg = "Permission not granted"
+
+
+class UnauthorizedRevocation(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6029, "Unauthorized to revoke this permission")
+
+    code = 6029
+    name = "UnauthorizedRevocation"
+    msg = "Unauthorized to revoke this permission"
+
+
+class TooManyPermissions(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6030, "Too many permissions requested")
+
+    code = 6030
+    name = "TooManyPermissions"
+    msg = "Too many permissions requested"
+
+
+class InvalidExpiry(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6031, "Expiry duration must be positive and within range")
+
+    code = 6031
+    name = "InvalidExpiry"
+    msg = "Expiry duration must be positive and within range"
+
+
+class InvalidNotificationToken(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6032, "Encrypted notification token must be 1-256 bytes")
+
+    code = 6032
+    name = "InvalidNotificationToken"
+    msg = "Encrypted notification token must be 1-256 bytes"
+
+
+class NotChannelAuthority(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6033, "Signer does not own this notification channel")
+
+    code = 6033
+    name = "NotChannelAuthority"
+    msg = "Signer does not own this notification channel"
+
+
+class InvalidRevealDelay(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6034, "Reveal delay must be positive and at most 90 days")
+
+    code = 6034
+    name = "InvalidRevealDelay"
+    msg = "Reveal delay must be positive and at most 90 days"
+
+
+class InvalidRevealCiphertext(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6035, "Escrowed ciphertext must be 1-128 bytes")
+
+    code = 6035
+    name = "InvalidRevealCiphertext"
+    msg = "Escrowed ciphertext must be 1-128 bytes"
+
+
+class NotEscrowAuthority(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6036, "Signer does not own this reveal escrow")
+
+    code = 6036
+    name = "NotEscrowAuthority"
+    msg = "Signer does not own this reveal escrow"
+
+
+class RevealNotReady(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6037, "Reveal delay has not passed yet")
+
+    code = 6037
+    name = "RevealNotReady"
+    msg = "Reveal delay has not passed yet"
+
+
+class RevealKeyMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6038, "Reveal key does not match the escrow's commitment")
+
+    code = 6038
+    name = "RevealKeyMismatch"
+    msg = "Reveal key does not match the escrow's commitment"
+
+
+class RevealAlreadyReleased(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6039, "Reveal escrow has already been released")
+
+    code = 6039
+    name = "RevealAlreadyReleased"
+    msg = "Reveal escrow has already been released"
+
+
+class RevealWindowClosed(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6040, "Reveal delay is over - the escrow can no longer be cancelled")
+
+    code = 6040
+    name = "RevealWindowClosed"
+    msg = "Reveal delay is over - the escrow can no longer be cancelled"
+
+
+class NotVcIssuer(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6041, "Signer is not the credential's issuer")
+
+    code = 6041
+    name = "NotVcIssuer"
+    msg = "Signer is not the credential's issuer"
+
+
+class VcRevoked(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6042, "Credential has been revoked; its status can no longer change")
+
+    code = 6042
+    name = "VcRevoked"
+    msg = "Credential has been revoked; its status can no longer change"
+
+
+class AuditorRevoked(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6043, "Auditor grant has been revoked")
+
+    code = 6043
+    name = "AuditorRevoked"
+    msg = "Auditor grant has been revoked"
+
+
+class AuditorExpired(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6044, "Auditor grant has expired")
+
+    code = 6044
+    name = "AuditorExpired"
+    msg = "Auditor grant has expired"
+
+
+class AccessLogMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6045, "Access log does not belong to this permission grant")
+
+    code = 6045
+    name = "AccessLogMismatch"
+    msg = "Access log does not belong to this permission grant"
+
+
+class InvalidCompactionBatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6046, "Compaction batch must be 1-16 (access log, payer) pairs")
+
+    code = 6046
+    name = "InvalidCompactionBatch"
+    msg = "Compaction batch must be 1-16 (access log, payer) pairs"
+
+
+class AccessLogTooRecent(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6047, "Access log is too recent to compact")
+
+    code = 6047
+    name = "AccessLogTooRecent"
+    msg = "Access log is too recent to compact"
+
+
+class AccessLogPayerMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6048, "Refund account does not match the access log payer")
+
+    code = 6048
+    name = "AccessLogPayerMismatch"
+    msg = "Refund account does not match the access log payer"
+
+
+class Unauthorized(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6049, "Signer is not the protocol authority")
+
+    code = 6049
+    name = "Unauthorized"
+    msg = "Signer is not the protocol authority"
+
+
+class MintNotAccepted(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6050, "Mint is not accepted for payments")
+
+    code = 6050
+    name = "MintNotAccepted"
+    msg = "Mint is not accepted for payments"
+
+
+class MintAlreadyAccepted(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6051, "Mint is already accepted")
+
+    code = 6051
+    name = "MintAlreadyAccepted"
+    msg = "Mint is already accepted"
+
+
+class TooManyAcceptedMints(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6052, "Too many accepted mints")
+
+    code = 6052
+    name = "TooManyAcceptedMints"
+    msg = "Too many accepted mints"
+
+
+class CircuitNotRegistered(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6053, "Circuit is not registered")
+
+    code = 6053
+    name = "CircuitNotRegistered"
+    msg = "Circuit is not registered"
+
+
+class CircuitDeprecated(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6054, "Circuit has been deprecated")
+
+    code = 6054
+    name = "CircuitDeprecated"
+    msg = "Circuit has been deprecated"
+
+
+class CircuitAlreadyRegistered(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6055, "Circuit ID is already registered")
+
+    code = 6055
+    name = "CircuitAlreadyRegistered"
+    msg = "Circuit ID is already registered"
+
+
+class TooManyCircuits(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6056, "Too many registered circuits")
+
+    code = 6056
+    name = "TooManyCircuits"
+    msg = "Too many registered circuits"
+
+
+class InvalidGroth16Vk(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6057, "Groth16 verifying key needs 2 to 4 public inputs and the circuit's vk_hash")
+
+    code = 6057
+    name = "InvalidGroth16Vk"
+    msg = "Groth16 verifying key needs 2 to 4 public inputs and the circuit's vk_hash"
+
+
+class InvalidGroth16Proof(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6058, "Groth16 proof failed to verify")
+
+    code = 6058
+    name = "InvalidGroth16Proof"
+    msg = "Groth16 proof failed to verify"
+
+
+class UnregisteredVerifier(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6059, "Verification result was not signed by a registered verifier")
+
+    code = 6059
+    name = "UnregisteredVerifier"
+    msg = "Verification result was not signed by a registered verifier"
+
+
+class VerifierAlreadyRegistered(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6060, "Verifier is already registered")
+
+    code = 6060
+    name = "VerifierAlreadyRegistered"
+    msg = "Verifier is already registered"
+
+
+class VerifierNotRegistered(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6061, "Verifier is not registered")
+
+    code = 6061
+    name = "VerifierNotRegistered"
+    msg = "Verifier is not registered"
+
+
+class TooManyVerifiers(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6062, "Too many registered verifiers")
+
+    code = 6062
+    name = "TooManyVerifiers"
+    msg = "Too many registered verifiers"
+
+
+class InvalidVerifierThreshold(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6063, "Threshold must be between 1 and the number of registered verifiers")
+
+    code = 6063
+    name = "InvalidVerifierThreshold"
+    msg = "Threshold must be between 1 and the number of registered verifiers"
+
+
+class QuorumNotMet(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6064, "Not enough registered verifiers co-signed the result")
+
+    code = 6064
+    name = "QuorumNotMet"
+    msg = "Not enough registered verifiers co-signed the result"
+
+
+class TooManyEd25519Instructions(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6065, "Too many Ed25519 instructions in the transaction")
+
+    code = 6065
+    name = "TooManyEd25519Instructions"
+    msg = "Too many Ed25519 instructions in the transaction"
+
+
+class TooManySecp256r1Instructions(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6066, "Too many Secp256r1 instructions in the transaction")
+
+    code = 6066
+    name = "TooManySecp256r1Instructions"
+    msg = "Too many Secp256r1 instructions in the transaction"
+
+
+class BadSecp256r1Accounts(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6067, "Bad Secp256r1 accounts")
+
+    code = 6067
+    name = "BadSecp256r1Accounts"
+    msg = "Bad Secp256r1 accounts"
+
+
+class InvalidSecp256r1Key(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6068, "Secp256r1 verifier key must be a 33-byte compressed P-256 point")
+
+    code = 6068
+    name = "InvalidSecp256r1Key"
+    msg = "Secp256r1 verifier key must be a 33-byte compressed P-256 point"
+
+
+class TooManyRotations(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6069, "Too many verifier key rotations inside the grace period")
+
+    code = 6069
+    name = "TooManyRotations"
+    msg = "Too many verifier key rotations inside the grace period"
+
+
+class InvalidGracePeriod(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6070, "Grace period must be between 0 and 24 hours")
+
+    code = 6070
+    name = "InvalidGracePeriod"
+    msg = "Grace period must be between 0 and 24 hours"
+
+
+class InvalidDomain(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6071, "Domain must be 1-32 lowercase ASCII letters, digits, '-' or '.', null-padded")
+
+    code = 6071
+    name = "InvalidDomain"
+    msg = "Domain must be 1-32 lowercase ASCII letters, digits, '-' or '.', null-padded"
+
+
+class NotDomainOwner(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6072, "Signer is not the domain owner")
+
+    code = 6072
+    name = "NotDomainOwner"
+    msg = "Signer is not the domain owner"
+
+
+class VkHashRequired(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6073, "Domain pins a verification key - result must carry vk_hash (V5+)")
+
+    code = 6073
+    name = "VkHashRequired"
+    msg = "Domain pins a verification key - result must carry vk_hash (V5+)"
+
+
+class InvalidChallengeWindow(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6074, "Challenge window must be between 0 and 7 days")
+
+    code = 6074
+    name = "InvalidChallengeWindow"
+    msg = "Challenge window must be between 0 and 7 days"
+
+
+class InvalidRateLimit(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6075, "Domain rate limit must be between 0 and 600 verifications per minute")
+
+    code = 6075
+    name = "InvalidRateLimit"
+    msg = "Domain rate limit must be between 0 and 600 verifications per minute"
+
+
+class NotDomainOperator(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6076, "Signer is neither the domain owner nor its operator")
+
+    code = 6076
+    name = "NotDomainOperator"
+    msg = "Signer is neither the domain owner nor its operator"
+
+
+class NotDomainTreasurer(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6077, "Signer is neither the domain owner nor its treasurer")
+
+    code = 6077
+    name = "NotDomainTreasurer"
+    msg = "Signer is neither the domain owner nor its treasurer"
+
+
+class InvalidDomainRoleKey(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6078, "Domain owner can't be set to the default key")
+
+    code = 6078
+    name = "InvalidDomainRoleKey"
+    msg = "Domain owner can't be set to the default key"
+
+
+class DomainPaused(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6079, "Domain is paused")
+
+    code = 6079
+    name = "DomainPaused"
+    msg = "Domain is paused"
+
+
+class TooManyOrigins(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6080, "Too many registered origins")
+
+    code = 6080
+    name = "TooManyOrigins"
+    msg = "Too many registered origins"
+
+
+class OriginNotAllowed(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6081, "Origin is not registered for the domain")
+
+    code = 6081
+    name = "OriginNotAllowed"
+    msg = "Origin is not registered for the domain"
+
+
+class ChallengeWindowClosed(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6082, "Verification has already finalized and can no longer be challenged")
+
+    code = 6082
+    name = "ChallengeWindowClosed"
+    msg = "Verification has already finalized and can no longer be challenged"
+
+
+class InvalidChallenge(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6083, "Challenge evidence does not dispute this verification")
+
+    code = 6083
+    name = "InvalidChallenge"
+    msg = "Challenge evidence does not dispute this verification"
+
+
+class FeeNotConfigured(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6084, "Fee is not configured")
+
+    code = 6084
+    name = "FeeNotConfigured"
+    msg = "Fee is not configured"
+
+
+class OracleFeedMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6085, "Price update is for a different feed than the mint's configured feed")
+
+    code = 6085
+    name = "OracleFeedMismatch"
+    msg = "Price update is for a different feed than the mint's configured feed"
+
+
+class InvalidOraclePrice(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6086, "Price update account is invalid, unverified, or non-positive")
+
+    code = 6086
+    name = "InvalidOraclePrice"
+    msg = "Price update account is invalid, unverified, or non-positive"
+
+
+class OraclePriceStale(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6087, "Oracle price is too old")
+
+    code = 6087
+    name = "OraclePriceStale"
+    msg = "Oracle price is too old"
+
+
+class OraclePriceUncertain(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6088, "Oracle price confidence interval is too wide")
+
+    code = 6088
+    name = "OraclePriceUncertain"
+    msg = "Oracle price confidence interval is too wide"
+
+
+class PriceQuoteOverflow(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6089, "Price quote overflowed")
+
+    code = 6089
+    name = "PriceQuoteOverflow"
+    msg = "Price quote overflowed"
+
+
+class FeeExceedsMaxAmount(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6090, "Quoted fee exceeds the payer's maximum amount")
+
+    code = 6090
+    name = "FeeExceedsMaxAmount"
+    msg = "Quoted fee exceeds the payer's maximum amount"
+
+
+class InvalidFeeShare(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6091, "Domain fee share must be at most 50%")
+
+    code = 6091
+    name = "InvalidFeeShare"
+    msg = "Domain fee share must be at most 50%"
+
+
+class SessionExpired(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6092, "Session has expired")
+
+    code = 6092
+    name = "SessionExpired"
+    msg = "Session has expired"
+
+
+class SessionRevoked(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6093, "Session was revoked by a global logout")
+
+    code = 6093
+    name = "SessionRevoked"
+    msg = "Session was revoked by a global logout"
+
+
+class SessionPending(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6094, "Session is pending until its challenge window ends")
+
+    code = 6094
+    name = "SessionPending"
+    msg = "Session is pending until its challenge window ends"
+
+
+class RateLimited(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6095, "Fee payer exceeded its verification rate limit - retry once the window slides")
+
+    code = 6095
+    name = "RateLimited"
+    msg = "Fee payer exceeded its verification rate limit - retry once the window slides"
+
+
+class InvalidAssuranceTier(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6096, "Assurance tier is not defined (tiers are added in order, up to 8)")
+
+    code = 6096
+    name = "InvalidAssuranceTier"
+    msg = "Assurance tier is not defined (tiers are added in order, up to 8)"
+
+
+class InvalidResultAge(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6097, "Maximum result age must be at most 1 hour (and non-zero for a tier)")
+
+    code = 6097
+    name = "InvalidResultAge"
+    msg = "Maximum result age must be at most 1 hour (and non-zero for a tier)"
+
+
+class TimestampInFuture(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6098, "Verification result timestamp is in the future")
+
+    code = 6098
+    name = "TimestampInFuture"
+    msg = "Verification result timestamp is in the future"
+
+
+class InvalidPointsRoot(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6099, "Points root must cover at least one leaf")
+
+    code = 6099
+    name = "InvalidPointsRoot"
+    msg = "Points root must cover at least one leaf"
+
+
+class InvalidPointsProof(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6100, "Merkle proof doesn't match the published points root")
+
+    code = 6100
+    name = "InvalidPointsProof"
+    msg = "Merkle proof doesn't match the published points root"
+
+
+class PersonhoodProviderAlreadyRegistered(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6101, "Personhood provider is already registered")
+
+    code = 6101
+    name = "PersonhoodProviderAlreadyRegistered"
+    msg = "Personhood provider is already registered"
+
+
+class PersonhoodProviderNotRegistered(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6102, "Personhood provider is not registered")
+
+    code = 6102
+    name = "PersonhoodProviderNotRegistered"
+    msg = "Personhood provider is not registered"
+
+
+class TooManyPersonhoodProviders(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6103, "Too many personhood providers")
+
+    code = 6103
+    name = "TooManyPersonhoodProviders"
+    msg = "Too many personhood providers"
+
+
+class InvalidPersonhoodScore(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6104, "Personhood score must be at most 100")
+
+    code = 6104
+    name = "InvalidPersonhoodScore"
+    msg = "Personhood score must be at most 100"
+
+
+class StalePersonhoodAttestation(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6105, "Personhood attestation is expired, future-dated, or older than the attached one")
+
+    code = 6105
+    name = "StalePersonhoodAttestation"
+    msg = "Personhood attestation is expired, future-dated, or older than the attached one"
+
+
+class MissingPersonhoodSignature(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6106, "No Ed25519 instruction carries the provider's signature over the attestation")
+
+    code = 6106
+    name = "MissingPersonhoodSignature"
+    msg = "No Ed25519 instruction carries the provider's signature over the attestation"
+
+
+class AttestationPreimageMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6107, "Attestation fields don't match the archived hash")
+
+    code = 6107
+    name = "AttestationPreimageMismatch"
+    msg = "Attestation fields don't match the archived hash"
+
+
+class MigrationInProgress(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6108, "A migration is already in progress")
+
+    code = 6108
+    name = "MigrationInProgress"
+    msg = "A migration is already in progress"
+
+
+class NoMigrationInProgress(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6109, "No migration is in progress")
+
+    code = 6109
+    name = "NoMigrationInProgress"
+    msg = "No migration is in progress"
+
+
+class InvalidMigrationPlan(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6110, "Migration needs a newer version, at most 4 distinct unstarted steps and a future deadline")
+
+    code = 6110
+    name = "InvalidMigrationPlan"
+    msg = "Migration needs a newer version, at most 4 distinct unstarted steps and a future deadline"
+
+
+class MigrationIncomplete(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6111, "Required migration steps are unfinished and the deadline hasn't passed")
+
+    code = 6111
+    name = "MigrationIncomplete"
+    msg = "Required migration steps are unfinished and the deadline hasn't passed"
+
+
+class UnknownMigrationStep(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6112, "The open migration has no step for this account type")
+
+    code = 6112
+    name = "UnknownMigrationStep"
+    msg = "The open migration has no step for this account type"
+
+
+class UnsupportedAccountVersion(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6113, "Account version is not accepted by the current migration state")
+
+    code = 6113
+    name = "UnsupportedAccountVersion"
+    msg = "Account version is not accepted by the current migration state"
+
+
+class InvalidVerifierWeight(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6114, "Verifier weight must be at most 16")
+
+    code = 6114
+    name = "InvalidVerifierWeight"
+    msg = "Verifier weight must be at most 16"
+
+
+class VerifierNotWeighted(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6115, "Verifier has no selection weight")
+
+    code = 6115
+    name = "VerifierNotWeighted"
+    msg = "Verifier has no selection weight"
+
+
+class NoVerifierAvailable(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6116, "No weighted, live verifier is available")
+
+    code = 6116
+    name = "NoVerifierAvailable"
+    msg = "No weighted, live verifier is available"
+
+
+class MissingUserConsent(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6117, "No Ed25519 instruction carries the user's consent signature")
+
+    code = 6117
+    name = "MissingUserConsent"
+    msg = "No Ed25519 instruction carries the user's consent signature"
+
+
+class UserConsentExpired(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6118, "User consent has expired")
+
+    code = 6118
+    name = "UserConsentExpired"
+    msg = "User consent has expired"
+
+
+class InvalidSessionBounds(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6119, "Session bounds must be 0 or within the protocol's, with min <= max")
+
+    code = 6119
+    name = "InvalidSessionBounds"
+    msg = "Session bounds must be 0 or within the protocol's, with min <= max"
+
+
+class SessionExpiryOutOfBounds(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6120, "Requested session lifetime is outside the domain's bounds")
+
+    code = 6120
+    name = "SessionExpiryOutOfBounds"
+    msg = "Requested session lifetime is outside the domain's bounds"
+
+
+class ResultLengthMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6121, "Verification result length doesn't match its version's")
+
+    code = 6121
+    name = "ResultLengthMismatch"
+    msg = "Verification result length doesn't match its version's"
+
+
+class NonCanonicalBool(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6122, "Boolean fields must be encoded as 0 or 1")
+
+    code = 6122
+    name = "NonCanonicalBool"
+    msg = "Boolean fields must be encoded as 0 or 1"
+
+
+class SignatureAlreadyUsed(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6123, "Verification result signature has already been consumed")
+
+    code = 6123
+    name = "SignatureAlreadyUsed"
+    msg = "Verification result signature has already been consumed"
+
+
+class InvalidReceiptRetention(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6124, "Receipt retention must be between 1 hour and 30 days")
+
+    code = 6124
+    name = "InvalidReceiptRetention"
+    msg = "Receipt retention must be between 1 hour and 30 days"
+
+
+class ReceiptRetained(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6125, "Result receipt is still inside its retention window")
+
+    code = 6125
+    name = "ReceiptRetained"
+    msg = "Result receipt is still inside its retention window"
+
+
+class UnknownClaimType(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6126, "Unknown verification result claim type")
+
+    code = 6126
+    name = "UnknownClaimType"
+    msg = "Unknown verification result claim type"
+
+
+class ClaimTypeMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6127, "Signed message claim type doesn't match result")
+
+    code = 6127
+    name = "ClaimTypeMismatch"
+    msg = "Signed message claim type doesn't match result"
+
+
+class ClaimNotSession(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6128, "Only Auth claims open sessions")
+
+    code = 6128
+    name = "ClaimNotSession"
+    msg = "Only Auth claims open sessions"
+
+
+class ClaimNotAttestation(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6129, "Auth claims open sessions rather than attestations")
+
+    code = 6129
+    name = "ClaimNotAttestation"
+    msg = "Auth claims open sessions rather than attestations"
+
+
+class AttestationExpiryOutOfBounds(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6130, "Attestation lifetime must be at most a year")
+
+    code = 6130
+    name = "AttestationExpiryOutOfBounds"
+    msg = "Attestation lifetime must be at most a year"
+
+
+class AttestationActive(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6131, "Attestation for this claim is still active; renew it instead")
+
+    code = 6131
+    name = "AttestationActive"
+    msg = "Attestation for this claim is still active; renew it instead"
+
+
+class AttestationExpired(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6132, "Attestation has expired")
+
+    code = 6132
+    name = "AttestationExpired"
+    msg = "Attestation has expired"
+
+
+class AttestationClaimMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6133, "Renewal result is for a different claim than the attestation")
+
+    code = 6133
+    name = "AttestationClaimMismatch"
+    msg = "Renewal result is for a different claim than the attestation"
+
+
+class AttestationRevoked(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6134, "Attestation has been revoked by its issuer")
+
+    code = 6134
+    name = "AttestationRevoked"
+    msg = "Attestation has been revoked by its issuer"
+
+
+class NotAttestationIssuer(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6135, "Only the attestation's issuer can revoke it")
+
+    code = 6135
+    name = "NotAttestationIssuer"
+    msg = "Only the attestation's issuer can revoke it"
+
+
+class InvalidClaimMask(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6136, "Claim type mask has bits for Auth or unknown claim types")
+
+    code = 6136
+    name = "InvalidClaimMask"
+    msg = "Claim type mask has bits for Auth or unknown claim types"
+
+
+class UntrustedAttestor(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6137, "Domain only accepts this claim type from a registered attestor vetted for it")
+
+    code = 6137
+    name = "UntrustedAttestor"
+    msg = "Domain only accepts this claim type from a registered attestor vetted for it"
+
+
+class AttestorSuspended(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6138, "Attestor is suspended")
+
+    code = 6138
+    name = "AttestorSuspended"
+    msg = "Attestor is suspended"
+
+
+class SasBridgeMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6139, "Account doesn't match the SAS bridge's credential or schema")
+
+    code = 6139
+    name = "SasBridgeMismatch"
+    msg = "Account doesn't match the SAS bridge's credential or schema"
+
+
+class MessageTagMismatch(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6140, "Signed message doesn't start with the Veiled protocol tag")
+
+    code = 6140
+    name = "MessageTagMismatch"
+    msg = "Signed message doesn't start with the Veiled protocol tag"
+
+
+class UntaggedResult(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6141, "Result envelope is untagged - byte envelopes must be V9")
+
+    code = 6141
+    name = "UntaggedResult"
+    msg = "Result envelope is untagged - byte envelopes must be V9"
+
+
+class SessionNotExpired(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6142, "Session hasn't expired yet")
+
+    code = 6142
+    name = "SessionNotExpired"
+    msg = "Session hasn't expired yet"
+
+
+class MissingSessionCloseSignature(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6143, "Session close must be signed by the session's authority")
+
+    code = 6143
+    name = "MissingSessionCloseSignature"
+    msg = "Session close must be signed by the session's authority"
+
+
+class SessionIdle(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6144, "Session has been idle longer than its domain's idle timeout")
+
+    code = 6144
+    name = "SessionIdle"
+    msg = "Session has been idle longer than its domain's idle timeout"
+
+
+class InvalidIdleTimeout(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6145, "Idle timeout must be 0 or within the protocol's session lifetimes")
+
+    code = 6145
+    name = "InvalidIdleTimeout"
+    msg = "Idle timeout must be 0 or within the protocol's session lifetimes"
+
+
+class MissingDeviceSignature(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6146, "Session's device key did not sign this instruction")
+
+    code = 6146
+    name = "MissingDeviceSignature"
+    msg = "Session's device key did not sign this instruction"
+
+
+class InvalidDelegateScope(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6147, "Delegate scope must include at least one permission")
+
+    code = 6147
+    name = "InvalidDelegateScope"
+    msg = "Delegate scope must include at least one permission"
+
+
+class InvalidDelegateExpiry(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6148, "Delegate lifetime must be between 1 second and an hour")
+
+    code = 6148
+    name = "InvalidDelegateExpiry"
+    msg = "Delegate lifetime must be between 1 second and an hour"
+
+
+class DelegateNotAllowed(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6149, "Delegate has expired or its scope doesn't include this permission")
+
+    code = 6149
+    name = "DelegateNotAllowed"
+    msg = "Delegate has expired or its scope doesn't include this permission"
+
+
+class WrongNullifierStore(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6150, "Domain registers nullifiers in another store; use that store's instructions")
+
+    code = 6150
+    name = "WrongNullifierStore"
+    msg = "Domain registers nullifiers in another store; use that store's instructions"
+
+
+class InvalidNonInclusionProof(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6151, "Low leaf doesn't bracket the nullifier, so it may already be registered")
+
+    code = 6151
+    name = "InvalidNonInclusionProof"
+    msg = "Low leaf doesn't bracket the nullifier, so it may already be registered"
+
+
+class InvalidUsername(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6152, "Usernames are 3 to 32 ASCII letters, digits or underscores")
+
+    code = 6152
+    name = "InvalidUsername"
+    msg = "Usernames are 3 to 32 ASCII letters, digits or underscores"
+
+
+class MissingUsernameTransferSignature(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6153, "Username transfer must be signed by the username's authority")
+
+    code = 6153
+    name = "MissingUsernameTransferSignature"
+    msg = "Username transfer must be signed by the username's authority"
+
+
+class InvalidGuardianSet(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6154, "Guardian set needs 1 to 8 distinct guardians and a threshold between 1 and their count")
+
+    code = 6154
+    name = "InvalidGuardianSet"
+    msg = "Guardian set needs 1 to 8 distinct guardians and a threshold between 1 and their count"
+
+
+class NotEnoughGuardianSignatures(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6155, "Not enough guardians signed this recovery")
+
+    code = 6155
+    name = "NotEnoughGuardianSignatures"
+    msg = "Not enough guardians signed this recovery"
+
+
+class InvalidRecoveryAccounts(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6156, "Recovery accounts must be (old, new) pairs of the nullifier's sessions or grants")
+
+    code = 6156
+    name = "InvalidRecoveryAccounts"
+    msg = "Recovery accounts must be (old, new) pairs of the nullifier's sessions or grants"
+
+
+class IdentityRootFull(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6157, "Identity root already links its maximum number of nullifiers")
+
+    code = 6157
+    name = "IdentityRootFull"
+    msg = "Identity root already links its maximum number of nullifiers"
+
+
+class NullifierAlreadyLinked(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6158, "Nullifier is already linked to this identity root")
+
+    code = 6158
+    name = "NullifierAlreadyLinked"
+    msg = "Nullifier is already linked to this identity root"
+
+
+class NullifierNotLinked(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6159, "Nullifier is not linked to this identity root")
+
+    code = 6159
+    name = "NullifierNotLinked"
+    msg = "Nullifier is not linked to this identity root"
+
+
+class MissingIdentityLinkSignature(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6160, "Identity link must be signed by the identity root's owner")
+
+    code = 6160
+    name = "MissingIdentityLinkSignature"
+    msg = "Identity link must be signed by the identity root's owner"
+
+
+class NullifierBanned(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6161, "Nullifier is banned from this domain")
+
+    code = 6161
+    name = "NullifierBanned"
+    msg = "Nullifier is banned from this domain"
+
+
+class InvalidSnsNameAccount(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6162, "SNS name account missing or not the one for this .sol domain")
+
+    code = 6162
+    name = "InvalidSnsNameAccount"
+    msg = "SNS name account missing or not the one for this .sol domain"
+
+
+class NotSnsNameOwner(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6163, "Signer doesn't own the SNS name for this .sol domain")
+
+    code = 6163
+    name = "NotSnsNameOwner"
+    msg = "Signer doesn't own the SNS name for this .sol domain"
+
+
+class ReentrancyDetected(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6164, "Instruction invoked too deep in a CPI chain (possible reentrancy)")
+
+    code = 6164
+    name = "ReentrancyDetected"
+    msg = "Instruction invoked too deep in a CPI chain (possible reentrancy)"
+
+
+class InvalidHonkVk(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6165, "UltraHonk key is malformed, incomplete or doesn't match the circuit's vk_hash")
+
+    code = 6165
+    name = "InvalidHonkVk"
+    msg = "UltraHonk key is malformed, incomplete or doesn't match the circuit's vk_hash"
+
+
+class InvalidProofLength(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6166, "Upload must be the circuit's public inputs followed by one UltraHonk proof")
+
+    code = 6166
+    name = "InvalidProofLength"
+    msg = "Upload must be the circuit's public inputs followed by one UltraHonk proof"
+
+
+class ProofChunkOutOfOrder(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6167, "Proof chunk must start where the upload left off and stay within it")
+
+    code = 6167
+    name = "ProofChunkOutOfOrder"
+    msg = "Proof chunk must start where the upload left off and stay within it"
+
+
+class InvalidSessionStatus(ProgramError):
+    def __init__(self) -> None:
+        super().__init__(6168, "Verification session is not in the right state for this instruction")
+
+    code = 6168
+    name = "InvalidSessionStatus"
+    msg = "Verification session is not in the right state for this instruction"
+
+
+CustomError = typing.Union[
+    InvalidProof,
+    DuplicateNullifier,
+    ProofExpired,
+    DomainTooLong,
+    SandboxUnavailable,
+    InvalidPublicInputs,
+    OffsetMismatch,
+    InvalidInstructionData,
+    InvalidSignatureCount,
+    InvalidMessageSize,
+    ProofHashMismatch,
+    IsValidMismatch,
+    AuthorityMismatch,
+    NullifierMismatch,
+    DomainMismatch,
+    UnsupportedResultVersion,
+    PublicInputsMismatch,
+    CircuitMismatch,
+    VkHashMismatch,
+    ClusterMismatch,
+    InstanceMismatch,
+    ProofAlreadyUsed,
+    InvalidVerificationBatch,
+    InvalidResultLayout,
+    BadEd25519Program,
+    BadEd25519Accounts,
+    PermissionRevoked,
+    PermissionExpired,
+    PermissionNotGranted,
+    UnauthorizedRevocation,
+    TooManyPermissions,
+    InvalidExpiry,
+    InvalidNotificationToken,
+    NotChannelAuthority,
+    InvalidRevealDelay,
+    InvalidRevealCiphertext,
+    NotEscrowAuthority,
+    RevealNotReady,
+    RevealKeyMismatch,
+    RevealAlreadyReleased,
+    RevealWindowClosed,
+    NotVcIssuer,
+    VcRevoked,
+    AuditorRevoked,
+    AuditorExpired,
+    AccessLogMismatch,
+    InvalidCompactionBatch,
+    AccessLogTooRecent,
+    AccessLogPayerMismatch,
+    Unauthorized,
+    MintNotAccepted,
+    MintAlreadyAccepted,
+    TooManyAcceptedMints,
+    CircuitNotRegistered,
+    CircuitDeprecated,
+    CircuitAlreadyRegistered,
+    TooManyCircuits,
+    InvalidGroth16Vk,
+    InvalidGroth16Proof,
+    UnregisteredVerifier,
+    VerifierAlreadyRegistered,
+    VerifierNotRegistered,
+    TooManyVerifiers,
+    InvalidVerifierThreshold,
+    QuorumNotMet,
+    TooManyEd25519Instructions,
+    TooManySecp256r1Instructions,
+    BadSecp256r1Accounts,
+    InvalidSecp256r1Key,
+    TooManyRotations,
+    InvalidGracePeriod,
+    InvalidDomain,
+    NotDomainOwner,
+    VkHashRequired,
+    InvalidChallengeWindow,
+    InvalidRateLimit,
+    NotDomainOperator,
+    NotDomainTreasurer,
+    InvalidDomainRoleKey,
+    DomainPaused,
+    TooManyOrigins,
+    OriginNotAllowed,
+    ChallengeWindowClosed,
+    InvalidChallenge,
+    FeeNotConfigured,
+    OracleFeedMismatch,
+    InvalidOraclePrice,
+    OraclePriceStale,
+    OraclePriceUncertain,
+    PriceQuoteOverflow,
+    FeeExceedsMaxAmount,
+    InvalidFeeShare,
+    SessionExpired,
+    SessionRevoked,
+    SessionPending,
+    RateLimited,
+    InvalidAssuranceTier,
+    InvalidResultAge,
+    TimestampInFuture,
+    InvalidPointsRoot,
+    InvalidPointsProof,
+    PersonhoodProviderAlreadyRegistered,
+    PersonhoodProviderNotRegistered,
+    TooManyPersonhoodProviders,
+    InvalidPersonhoodScore,
+    StalePersonhoodAttestation,
+    MissingPersonhoodSignature,
+    AttestationPreimageMismatch,
+    MigrationInProgress,
+    NoMigrationInProgress,
+    InvalidMigrationPlan,
+    MigrationIncomplete,
+    UnknownMigrationStep,
+    UnsupportedAccountVersion,
+    InvalidVerifierWeight,
+    VerifierNotWeighted,
+    NoVerifierAvailable,
+    MissingUserConsent,
+    UserConsentExpired,
+    InvalidSessionBounds,
+    SessionExpiryOutOfBounds,
+    ResultLengthMismatch,
+    NonCanonicalBool,
+    SignatureAlreadyUsed,
+    InvalidReceiptRetention,
+    ReceiptRetained,
+    UnknownClaimType,
+    ClaimTypeMismatch,
+    ClaimNotSession,
+    ClaimNotAttestation,
+    AttestationExpiryOutOfBounds,
+    AttestationActive,
+    AttestationExpired,
+    AttestationClaimMismatch,
+    AttestationRevoked,
+    NotAttestationIssuer,
+    InvalidClaimMask,
+    UntrustedAttestor,
+    AttestorSuspended,
+    SasBridgeMismatch,
+    MessageTagMismatch,
+    UntaggedResult,
+    SessionNotExpired,
+    MissingSessionCloseSignature,
+    SessionIdle,
+    InvalidIdleTimeout,
+    MissingDeviceSignature,
+    InvalidDelegateScope,
+    InvalidDelegateExpiry,
+    DelegateNotAllowed,
+    WrongNullifierStore,
+    InvalidNonInclusionProof,
+    InvalidUsername,
+    MissingUsernameTransferSignature,
+    InvalidGuardianSet,
+    NotEnoughGuardianSignatures,
+    InvalidRecoveryAccounts,
+    IdentityRootFull,
+    NullifierAlreadyLinked,
+    NullifierNotLinked,
+    MissingIdentityLinkSignature,
+    NullifierBanned,
+    InvalidSnsNameAccount,
+    NotSnsNameOwner,
+    ReentrancyDetected,
+    InvalidHonkVk,
+    InvalidProofLength,
+    ProofChunkOutOfOrder,
+    InvalidSessionStatus,
+]
+CUSTOM_ERROR_MAP = {
+    6000: InvalidProof(),
+    6001: DuplicateNullifier(),
+    6002: ProofExpired(),
+    6003: DomainTooLong(),
+    6004: SandboxUnavailable(),
+    6005: InvalidPublicInputs(),
+    6006: OffsetMismatch(),
+    6007: InvalidInstructionData(),
+    6008: InvalidSignatureCount(),
+    6009: InvalidMessageSize(),
+    6010: ProofHashMismatch(),
+    6011: IsValidMismatch(),
+    6012: AuthorityMismatch(),
+    6013: NullifierMismatch(),
+    6014: DomainMismatch(),
+    6015: UnsupportedResultVersion(),
+    6016: PublicInputsMismatch(),
+    6017: CircuitMismatch(),
+    6018: VkHashMismatch(),
+    6019: ClusterMismatch(),
+    6020: InstanceMismatch(),
+    6021: ProofAlreadyUsed(),
+    6022: InvalidVerificationBatch(),
+    6023: InvalidResultLayout(),
+    6024: BadEd25519Program(),
+    6025: BadEd25519Accounts(),
+    6026: PermissionRevoked(),
+    6027: PermissionExpired(),
+    6028: PermissionNotGranted(),
+    6029: UnauthorizedRevocation(),
+    6030: TooManyPermissions(),
+    6031: InvalidExpiry(),
+    6032: InvalidNotificationToken(),
+    6033: NotChannelAuthority(),
+    6034: InvalidRevealDelay(),
+    6035: InvalidRevealCiphertext(),
+    6036: NotEscrowAuthority(),
+    6037: RevealNotReady(),
+    6038: RevealKeyMismatch(),
+    6039: RevealAlreadyReleased(),
+    6040: RevealWindowClosed(),
+    6041: NotVcIssuer(),
+    6042: VcRevoked(),
+    6043: AuditorRevoked(),
+    6044: AuditorExpired(),
+    6045: AccessLogMismatch(),
+    6046: InvalidCompactionBatch(),
+    6047: AccessLogTooRecent(),
+    6048: AccessLogPayerMismatch(),
+    6049: Unauthorized(),
+    6050: MintNotAccepted(),
+    6051: MintAlreadyAccepted(),
+    6052: TooManyAcceptedMints(),
+    6053: CircuitNotRegistered(),
+    6054: CircuitDeprecated(),
+    6055: CircuitAlreadyRegistered(),
+    6056: TooManyCircuits(),
+    6057: InvalidGroth16Vk(),
+    6058: InvalidGroth16Proof(),
+    6059: UnregisteredVerifier(),
+    6060: VerifierAlreadyRegistered(),
+    6061: VerifierNotRegistered(),
+    6062: TooManyVerifiers(),
+    6063: InvalidVerifierThreshold(),
+    6064: QuorumNotMet(),
+    6065: TooManyEd25519Instructions(),
+    6066: TooManySecp256r1Instructions(),
+    6067: BadSecp256r1Accounts(),
+    6068: InvalidSecp256r1Key(),
+    6069: TooManyRotations(),
+    6070: InvalidGracePeriod(),
+    6071: InvalidDomain(),
+    6072: NotDomainOwner(),
+    6073: VkHashRequired(),
+    6074: InvalidChallengeWindow(),
+    6075: InvalidRateLimit(),
+    6076: NotDomainOperator(),
+    6077: NotDomainTreasurer(),
+    6078: InvalidDomainRoleKey(),
+    6079: DomainPaused(),
+    6080: TooManyOrigins(),
+    6081: OriginNotAllowed(),
+    6082: ChallengeWindowClosed(),
+    6083: InvalidChallenge(),
+    6084: FeeNotConfigured(),
+    6085: OracleFeedMismatch(),
+    6086: InvalidOraclePrice(),
+    6087: OraclePriceStale(),
+    6088: OraclePriceUncertain(),
+    6089: PriceQuoteOverflow(),
+    6090: FeeExceedsMaxAmount(),
+    6091: InvalidFeeShare(),
+    6092: SessionExpired(),
+    6093: SessionRevoked(),
+    6094: SessionPending(),
+    6095: RateLimited(),
+    6096: InvalidAssuranceTier(),
+    6097: InvalidResultAge(),
+    6098: TimestampInFuture(),
+    6099: InvalidPointsRoot(),
+    6100: InvalidPointsProof(),
+    6101: PersonhoodProviderAlreadyRegistered(),
+    6102: PersonhoodProviderNotRegistered(),
+    6103: TooManyPersonhoodProviders(),
+    6104: InvalidPersonhoodScore(),
+    6105: StalePersonhoodAttestation(),
+    6106: MissingPersonhoodSignature(),
+    6107: AttestationPreimageMismatch(),
+    6108: MigrationInProgress(),
+    6109: NoMigrationInProgress(),
+    6110: InvalidMigrationPlan(),
+    6111: MigrationIncomplete(),
+    6112: UnknownMigrationStep(),
+    6113: UnsupportedAccountVersion(),
+    6114: InvalidVerifierWeight(),
+    6115: VerifierNotWeighted(),
+    6116: NoVerifierAvailable(),
+    6117: MissingUserConsent(),
+    6118: UserConsentExpired(),
+    6119: InvalidSessionBounds(),
+    6120: SessionExpiryOutOfBounds(),
+    6121: ResultLengthMismatch(),
+    6122: NonCanonicalBool(),
+    6123: SignatureAlreadyUsed(),
+    6124: InvalidReceiptRetention(),
+    6125: ReceiptRetained(),
+    6126: UnknownClaimType(),
+    6127: ClaimTypeMismatch(),
+    6128: ClaimNotSession(),
+    6129: ClaimNotAttestation(),
+    6130: AttestationExpiryOutOfBounds(),
+    6131: AttestationActive(),
+    6132: AttestationExpired(),
+    6133: AttestationClaimMismatch(),
+    6134: AttestationRevoked(),
+    6135: NotAttestationIssuer(),
+    6136: InvalidClaimMask(),
+    6137: UntrustedAttestor(),
+    6138: AttestorSuspended(),
+    6139: SasBridgeMismatch(),
+    6140: MessageTagMismatch(),
+    6141: UntaggedResult(),
+    6142: SessionNotExpired(),
+    6143: MissingSessionCloseSignature(),
+    6144: SessionIdle(),
+    6145: InvalidIdleTimeout(),
+    6146: MissingDeviceSignature(),
+    6147: InvalidDelegateScope(),
+    6148: InvalidDelegateExpiry(),
+    6149: DelegateNotAllowed(),
+    6150: WrongNullifierStore(),
+    6151: InvalidNonInclusionProof(),
+    6152: InvalidUsername(),
+    6153: MissingUsernameTransferSignature(),
+    6154: InvalidGuardianSet(),
+    6155: NotEnoughGuardianSignatures(),
+    6156: InvalidRecoveryAccounts(),
+    6157: IdentityRootFull(),
+    6158: NullifierAlreadyLinked(),
+    6159: NullifierNotLinked(),
+    6160: MissingIdentityLinkSignature(),
+    6161: NullifierBanned(),
+    6162: InvalidSnsNameAccount(),
+    6163: NotSnsNameOwner(),
+    6164: ReentrancyDetected(),
+    6165: InvalidHonkVk(),
+    6166: InvalidProofLength(),
+    6167: ProofChunkOutOfOrder(),
+    6168: InvalidSessionStatus(),
+}
+
+
+def from_code(code: int) -> typing.Optional[CustomError]:
+    maybe_err = CUSTOM_ERROR_MAP.get(code)
+    if maybe_err is None:
+        return None
+    return maybe_err
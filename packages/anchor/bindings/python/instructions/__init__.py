@@ -0,0 +1,117 @@
+from .acknowledge_access_log import acknowledge_access_log, AcknowledgeAccessLogAccounts
+from .add_personhood_provider import add_personhood_provider, AddPersonhoodProviderArgs, AddPersonhoodProviderAccounts
+from .add_secp256r1_verifier import add_secp256r1_verifier, AddSecp256r1VerifierArgs, AddSecp256r1VerifierAccounts
+from .add_verifier import add_verifier, AddVerifierArgs, AddVerifierAccounts
+from .advance_verification_session import advance_verification_session, AdvanceVerificationSessionArgs, AdvanceVerificationSessionAccounts
+from .archive_attestation import archive_attestation, ArchiveAttestationAccounts
+from .attach_personhood_attestation import attach_personhood_attestation, AttachPersonhoodAttestationArgs, AttachPersonhoodAttestationAccounts
+from .ban_nullifier import ban_nullifier, BanNullifierArgs, BanNullifierAccounts
+from .begin_migration import begin_migration, BeginMigrationArgs, BeginMigrationAccounts
+from .cancel_reveal_escrow import cancel_reveal_escrow, CancelRevealEscrowAccounts
+from .challenge_verification import challenge_verification, ChallengeVerificationArgs, ChallengeVerificationAccounts
+from .claim_alias import claim_alias, ClaimAliasArgs, ClaimAliasAccounts
+from .claim_domain_fees import claim_domain_fees, ClaimDomainFeesArgs, ClaimDomainFeesAccounts
+from .claim_usage_points import claim_usage_points, ClaimUsagePointsArgs, ClaimUsagePointsAccounts
+from .claim_username import claim_username, ClaimUsernameArgs, ClaimUsernameAccounts
+from .close_expired_nullifier import close_expired_nullifier, CloseExpiredNullifierAccounts
+from .close_notification_channel import close_notification_channel, CloseNotificationChannelAccounts
+from .close_result_receipt import close_result_receipt, CloseResultReceiptAccounts
+from .close_session import close_session, CloseSessionAccounts
+from .close_verification_session import close_verification_session, CloseVerificationSessionAccounts
+from .compact_access_logs import compact_access_logs, CompactAccessLogsAccounts
+from .complete_migration import complete_migration, CompleteMigrationAccounts
+from .create_domain_fee_vault_ata import create_domain_fee_vault_ata, CreateDomainFeeVaultAtaAccounts
+from .create_identity_root import create_identity_root, CreateIdentityRootAccounts
+from .create_treasury_ata import create_treasury_ata, CreateTreasuryAtaAccounts
+from .delegate_session import delegate_session, DelegateSessionArgs, DelegateSessionAccounts
+from .deprecate_circuit import deprecate_circuit, DeprecateCircuitArgs, DeprecateCircuitAccounts
+from .finalize_verification_session import finalize_verification_session, FinalizeVerificationSessionAccounts
+from .global_logout import global_logout, GlobalLogoutAccounts
+from .grant_auditor import grant_auditor, GrantAuditorArgs, GrantAuditorAccounts
+from .grant_permissions import grant_permissions, GrantPermissionsArgs, GrantPermissionsAccounts
+from .init_nullifier_tree import init_nullifier_tree, InitNullifierTreeArgs, InitNullifierTreeAccounts
+from .initialize_config import initialize_config, InitializeConfigAccounts
+from .initialize_domain_config import initialize_domain_config, InitializeDomainConfigArgs, InitializeDomainConfigAccounts
+from .issue_vc_commitment import issue_vc_commitment, IssueVcCommitmentArgs, IssueVcCommitmentAccounts
+from .link_identity_root import link_identity_root, LinkIdentityRootArgs, LinkIdentityRootAccounts
+from .log_permission_access import log_permission_access, LogPermissionAccessArgs, LogPermissionAccessAccounts
+from .migrate_grant import migrate_grant, MigrateGrantArgs, MigrateGrantAccounts
+from .migrate_nullifier import migrate_nullifier, MigrateNullifierArgs, MigrateNullifierAccounts
+from .migrate_session import migrate_session, MigrateSessionArgs, MigrateSessionAccounts
+from .mirror_attestation import mirror_attestation, MirrorAttestationAccounts
+from .open_reveal_escrow import open_reveal_escrow, OpenRevealEscrowArgs, OpenRevealEscrowAccounts
+from .open_verification_session import open_verification_session, OpenVerificationSessionArgs, OpenVerificationSessionAccounts
+from .pay_domain_fee import pay_domain_fee, PayDomainFeeArgs, PayDomainFeeAccounts
+from .pay_fee import pay_fee, PayFeeArgs, PayFeeAccounts
+from .pin_vk_hash import pin_vk_hash, PinVkHashArgs, PinVkHashAccounts
+from .publish_points_root import publish_points_root, PublishPointsRootArgs, PublishPointsRootAccounts
+from .recover_identity import recover_identity, RecoverIdentityArgs, RecoverIdentityAccounts
+from .register_attestor import register_attestor, RegisterAttestorArgs, RegisterAttestorAccounts
+from .register_circuit import register_circuit, RegisterCircuitArgs, RegisterCircuitAccounts
+from .register_domain import register_domain, RegisterDomainArgs, RegisterDomainAccounts
+from .register_groth16_vk import register_groth16_vk, RegisterGroth16VkArgs, RegisterGroth16VkAccounts
+from .register_honk_vk import register_honk_vk, RegisterHonkVkArgs, RegisterHonkVkAccounts
+from .release_reveal_escrow import release_reveal_escrow, ReleaseRevealEscrowArgs, ReleaseRevealEscrowAccounts
+from .release_username import release_username, ReleaseUsernameAccounts
+from .remove_personhood_provider import remove_personhood_provider, RemovePersonhoodProviderArgs, RemovePersonhoodProviderAccounts
+from .remove_secp256r1_verifier import remove_secp256r1_verifier, RemoveSecp256r1VerifierArgs, RemoveSecp256r1VerifierAccounts
+from .remove_verifier import remove_verifier, RemoveVerifierArgs, RemoveVerifierAccounts
+from .renew_attestation import renew_attestation, RenewAttestationArgs, RenewAttestationAccounts
+from .renew_session import renew_session, RenewSessionArgs, RenewSessionAccounts
+from .restore_attestation import restore_attestation, RestoreAttestationArgs, RestoreAttestationAccounts
+from .revoke_attestation import revoke_attestation, RevokeAttestationAccounts
+from .revoke_auditor import revoke_auditor, RevokeAuditorAccounts
+from .revoke_permissions import revoke_permissions, RevokePermissionsAccounts
+from .revoke_session_delegate import revoke_session_delegate, RevokeSessionDelegateAccounts
+from .rotate_verifier_key import rotate_verifier_key, RotateVerifierKeyArgs, RotateVerifierKeyAccounts
+from .select_verifier import select_verifier, SelectVerifierArgs, SelectVerifierAccounts
+from .set_accepted_mint import set_accepted_mint, SetAcceptedMintArgs, SetAcceptedMintAccounts
+from .set_assurance_tier import set_assurance_tier, SetAssuranceTierArgs, SetAssuranceTierAccounts
+from .set_attestor_status import set_attestor_status, SetAttestorStatusArgs, SetAttestorStatusAccounts
+from .set_challenge_window import set_challenge_window, SetChallengeWindowArgs, SetChallengeWindowAccounts
+from .set_domain_assurance_tier import set_domain_assurance_tier, SetDomainAssuranceTierArgs, SetDomainAssuranceTierAccounts
+from .set_domain_attestor_claims import set_domain_attestor_claims, SetDomainAttestorClaimsArgs, SetDomainAttestorClaimsAccounts
+from .set_domain_expiry_unit import set_domain_expiry_unit, SetDomainExpiryUnitArgs, SetDomainExpiryUnitAccounts
+from .set_domain_fee_share import set_domain_fee_share, SetDomainFeeShareArgs, SetDomainFeeShareAccounts
+from .set_domain_idle_timeout import set_domain_idle_timeout, SetDomainIdleTimeoutArgs, SetDomainIdleTimeoutAccounts
+from .set_domain_max_result_age import set_domain_max_result_age, SetDomainMaxResultAgeArgs, SetDomainMaxResultAgeAccounts
+from .set_domain_nullifier_store import set_domain_nullifier_store, SetDomainNullifierStoreArgs, SetDomainNullifierStoreAccounts
+from .set_domain_origins import set_domain_origins, SetDomainOriginsArgs, SetDomainOriginsAccounts
+from .set_domain_paused import set_domain_paused, SetDomainPausedArgs, SetDomainPausedAccounts
+from .set_domain_rate_limit import set_domain_rate_limit, SetDomainRateLimitArgs, SetDomainRateLimitAccounts
+from .set_domain_role import set_domain_role, SetDomainRoleArgs, SetDomainRoleAccounts
+from .set_domain_session_bounds import set_domain_session_bounds, SetDomainSessionBoundsArgs, SetDomainSessionBoundsAccounts
+from .set_domain_verifiers import set_domain_verifiers, SetDomainVerifiersArgs, SetDomainVerifiersAccounts
+from .set_guardians import set_guardians, SetGuardiansArgs, SetGuardiansAccounts
+from .set_notification_channel import set_notification_channel, SetNotificationChannelArgs, SetNotificationChannelAccounts
+from .set_pricing import set_pricing, SetPricingArgs, SetPricingAccounts
+from .set_receipt_retention import set_receipt_retention, SetReceiptRetentionArgs, SetReceiptRetentionAccounts
+from .set_relayer_quota import set_relayer_quota, SetRelayerQuotaArgs, SetRelayerQuotaAccounts
+from .set_sas_bridge import set_sas_bridge, SetSasBridgeArgs, SetSasBridgeAccounts
+from .set_vc_status import set_vc_status, SetVcStatusArgs, SetVcStatusAccounts
+from .set_verifier_grace_period import set_verifier_grace_period, SetVerifierGracePeriodArgs, SetVerifierGracePeriodAccounts
+from .set_verifier_threshold import set_verifier_threshold, SetVerifierThresholdArgs, SetVerifierThresholdAccounts
+from .set_verifier_weight import set_verifier_weight, SetVerifierWeightArgs, SetVerifierWeightAccounts
+from .store_attestation import store_attestation, StoreAttestationArgs, StoreAttestationAccounts
+from .sweep_treasury_ata import sweep_treasury_ata, SweepTreasuryAtaArgs, SweepTreasuryAtaAccounts
+from .top_up_rent import top_up_rent, TopUpRentAccounts
+from .touch_session import touch_session, TouchSessionAccounts
+from .transfer_username import transfer_username, TransferUsernameAccounts
+from .try_check_permission import try_check_permission, TryCheckPermissionArgs, TryCheckPermissionAccounts
+from .unban_nullifier import unban_nullifier, UnbanNullifierAccounts
+from .unlink_identity_root import unlink_identity_root, UnlinkIdentityRootArgs, UnlinkIdentityRootAccounts
+from .upload_proof_chunk import upload_proof_chunk, UploadProofChunkArgs, UploadProofChunkAccounts
+from .validate_attestation import validate_attestation, ValidateAttestationAccounts
+from .validate_session_delegate import validate_session_delegate, ValidateSessionDelegateArgs, ValidateSessionDelegateAccounts
+from .validate_session_token import validate_session_token, ValidateSessionTokenAccounts
+from .verifier_heartbeat import verifier_heartbeat, VerifierHeartbeatAccounts
+from .verify_auth import verify_auth, VerifyAuthArgs, VerifyAuthAccounts
+from .verify_auth_batch import verify_auth_batch, VerifyAuthBatchArgs, VerifyAuthBatchAccounts
+from .verify_auth_compressed import verify_auth_compressed, VerifyAuthCompressedArgs, VerifyAuthCompressedAccounts
+from .verify_auth_groth16 import verify_auth_groth16, VerifyAuthGroth16Args, VerifyAuthGroth16Accounts
+from .verify_auth_sharded import verify_auth_sharded, VerifyAuthShardedArgs, VerifyAuthShardedAccounts
+from .verify_auth_v1 import verify_auth_v1, VerifyAuthV1Args, VerifyAuthV1Accounts
+from .verify_auth_with_consent import verify_auth_with_consent, VerifyAuthWithConsentArgs, VerifyAuthWithConsentAccounts
+from .verify_auth_with_device import verify_auth_with_device, VerifyAuthWithDeviceArgs, VerifyAuthWithDeviceAccounts
+from .verify_auth_with_origin import verify_auth_with_origin, VerifyAuthWithOriginArgs, VerifyAuthWithOriginAccounts
+from .write_honk_vk import write_honk_vk, WriteHonkVkArgs, WriteHonkVkAccounts
@@ -0,0 +1,43 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class AcknowledgeAccessLogAccounts(typing.TypedDict):
+    auditor_grant: Pubkey
+    permission_access: Pubkey
+    permission_grant: Pubkey
+    auditor: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def acknowledge_access_log(
+    accounts: AcknowledgeAccessLogAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["auditor_grant"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["permission_access"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["permission_grant"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["auditor"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\x97\xd8h\xd3\x1eO\xfc\x1d"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,50 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class AdvanceVerificationSessionArgs(typing.TypedDict):
+    max_steps: int
+
+
+layout = borsh.CStruct(
+    "max_steps" / borsh.U8,
+)
+
+
+class AdvanceVerificationSessionAccounts(typing.TypedDict):
+    verification_session: Pubkey
+    honk_vk: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def advance_verification_session(
+    args: AdvanceVerificationSessionArgs,
+    accounts: AdvanceVerificationSessionAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["verification_session"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["honk_vk"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"'\x89\xb8\xac\xf5\n8\x07"
+    encoded_args = layout.build(
+        {
+            "max_steps": args["max_steps"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,40 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class ArchiveAttestationAccounts(typing.TypedDict):
+    attestation: Pubkey
+    archive: Pubkey
+    provider: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def archive_attestation(
+    accounts: ArchiveAttestationAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["attestation"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["archive"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["provider"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\x85\x9fnN5\xad\x10\xb4"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,77 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class AttachPersonhoodAttestationArgs(typing.TypedDict):
+    nullifier: list[int]
+    provider: Pubkey
+    score: int
+    issued_at: int
+    expires_at: int
+
+
+layout = borsh.CStruct(
+    "nullifier" / borsh.U8[32],
+    "provider" / BorshPubkey,
+    "score" / borsh.U16,
+    "issued_at" / borsh.I64,
+    "expires_at" / borsh.I64,
+)
+
+
+class AttachPersonhoodAttestationAccounts(typing.TypedDict):
+    attestation: Pubkey
+    archive: Pubkey
+    personhood_providers: Pubkey
+    payer: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def attach_personhood_attestation(
+    args: AttachPersonhoodAttestationArgs,
+    accounts: AttachPersonhoodAttestationAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["attestation"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["archive"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["personhood_providers"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("Sysvar1nstructions1111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(pubkey=accounts["payer"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b")7yQ\xe7\x9cO\x83"
+    encoded_args = layout.build(
+        {
+            "nullifier": args["nullifier"],
+            "provider": args["provider"],
+            "score": args["score"],
+            "issued_at": args["issued_at"],
+            "expires_at": args["expires_at"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
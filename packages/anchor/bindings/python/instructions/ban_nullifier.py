@@ -0,0 +1,59 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class BanNullifierArgs(typing.TypedDict):
+    nullifier: list[int]
+
+
+layout = borsh.CStruct(
+    "nullifier" / borsh.U8[32],
+)
+
+
+class BanNullifierAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    nullifier_ban: Pubkey
+    owner: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def ban_nullifier(
+    args: BanNullifierArgs,
+    accounts: BanNullifierAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["nullifier_ban"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b" \n\xad\xd4\xd4\x8c~\xd0"
+    encoded_args = layout.build(
+        {
+            "nullifier": args["nullifier"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,65 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from construct import Construct
+import borsh_construct as borsh
+from .. import types
+from ..program_id import PROGRAM_ID
+
+
+class BeginMigrationArgs(typing.TypedDict):
+    target_version: int
+    steps: list[types.migration_step.MigrationStep]
+    deadline: int
+
+
+layout = borsh.CStruct(
+    "target_version" / borsh.U16,
+    "steps" / borsh.Vec(typing.cast(Construct, types.migration_step.MigrationStep.layout)),
+    "deadline" / borsh.I64,
+)
+
+
+class BeginMigrationAccounts(typing.TypedDict):
+    config: Pubkey
+    migration_state: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def begin_migration(
+    args: BeginMigrationArgs,
+    accounts: BeginMigrationAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["migration_state"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xc87\xa3\xad\xa6\xb4\xf9Q"
+    encoded_args = layout.build(
+        {
+            "target_version": args["target_version"],
+            "steps": list(map(lambda item: item.to_encodable(), args["steps"])),
+            "deadline": args["deadline"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
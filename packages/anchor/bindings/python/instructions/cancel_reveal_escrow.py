@@ -0,0 +1,35 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class CancelRevealEscrowAccounts(typing.TypedDict):
+    reveal_escrow: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def cancel_reveal_escrow(
+    accounts: CancelRevealEscrowAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["reveal_escrow"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xd2y\x1d\xde\xab\xa4\xbfA"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
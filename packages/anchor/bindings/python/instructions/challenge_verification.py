@@ -0,0 +1,66 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class ChallengeVerificationArgs(typing.TypedDict):
+    evidence: bytes
+    domain: list[int]
+
+
+layout = borsh.CStruct(
+    "evidence" / borsh.Bytes,
+    "domain" / borsh.U8[32],
+)
+
+
+class ChallengeVerificationAccounts(typing.TypedDict):
+    nullifier_account: Pubkey
+    domain_config: Pubkey
+    verifier_registry: Pubkey
+    challenger: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def challenge_verification(
+    args: ChallengeVerificationArgs,
+    accounts: ChallengeVerificationAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["nullifier_account"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["verifier_registry"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("Sysvar1nstructions1111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(pubkey=accounts["challenger"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xc8FF\xaa\x8d\xf4\x1bU"
+    encoded_args = layout.build(
+        {
+            "evidence": args["evidence"],
+            "domain": args["domain"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,67 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class ClaimDomainFeesArgs(typing.TypedDict):
+    amount: int
+
+
+layout = borsh.CStruct(
+    "amount" / borsh.U64,
+)
+
+
+class ClaimDomainFeesAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    domain_fee_vault: Pubkey
+    mint: Pubkey
+    domain_fee_vault_ata: Pubkey
+    destination: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def claim_domain_fees(
+    args: ClaimDomainFeesArgs,
+    accounts: ClaimDomainFeesAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["domain_fee_vault"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["mint"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["domain_fee_vault_ata"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["destination"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"lEh\xb6\xe6\x17&\xd7"
+    encoded_args = layout.build(
+        {
+            "amount": args["amount"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,65 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from construct import Construct
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class ClaimUsagePointsArgs(typing.TypedDict):
+    nullifier: list[int]
+    amount: int
+    leaf_index: int
+    proof: list[list[int]]
+
+
+layout = borsh.CStruct(
+    "nullifier" / borsh.U8[32],
+    "amount" / borsh.U64,
+    "leaf_index" / borsh.U32,
+    "proof" / borsh.Vec(typing.cast(Construct, borsh.U8[32])),
+)
+
+
+class ClaimUsagePointsAccounts(typing.TypedDict):
+    points_root: Pubkey
+    points: Pubkey
+    payer: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def claim_usage_points(
+    args: ClaimUsagePointsArgs,
+    accounts: ClaimUsagePointsAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["points_root"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["points"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["payer"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xe9\x9b\x8eV:\xc0\xa2\xa8"
+    encoded_args = layout.build(
+        {
+            "nullifier": args["nullifier"],
+            "amount": args["amount"],
+            "leaf_index": args["leaf_index"],
+            "proof": args["proof"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
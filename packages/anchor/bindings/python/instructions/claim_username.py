@@ -0,0 +1,66 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class ClaimUsernameArgs(typing.TypedDict):
+    name: str
+
+
+layout = borsh.CStruct(
+    "name" / borsh.String,
+)
+
+
+class ClaimUsernameAccounts(typing.TypedDict):
+    nullifier_account: Pubkey
+    username: Pubkey
+    authority: Pubkey
+    logout_epoch: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def claim_username(
+    args: ClaimUsernameArgs,
+    accounts: ClaimUsernameAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["nullifier_account"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["username"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("Sysvar1nstructions1111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["logout_epoch"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xa1)c\xff\xc4S\xdd\x94"
+    encoded_args = layout.build(
+        {
+            "name": args["name"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,37 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class CloseExpiredNullifierAccounts(typing.TypedDict):
+    nullifier_account: Pubkey
+    payer: Pubkey
+    cranker: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def close_expired_nullifier(
+    accounts: CloseExpiredNullifierAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["nullifier_account"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["payer"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["cranker"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xbc\xa4\xf3\x8as\xfa\xef\xf0"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,41 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class CloseNotificationChannelAccounts(typing.TypedDict):
+    notification_channel: Pubkey
+    permission_grant: Pubkey
+    authority: Pubkey
+    closer: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def close_notification_channel(
+    accounts: CloseNotificationChannelAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["notification_channel"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["permission_grant"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["closer"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\x85a\xfecz\x02\xc4F"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
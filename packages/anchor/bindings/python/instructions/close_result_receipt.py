@@ -0,0 +1,35 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class CloseResultReceiptAccounts(typing.TypedDict):
+    result_receipt: Pubkey
+    payer: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def close_result_receipt(
+    accounts: CloseResultReceiptAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["result_receipt"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["payer"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xa1_\xd2\xe7\xcb\xda}\x8b"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,42 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class CloseSessionAccounts(typing.TypedDict):
+    nullifier_account: Pubkey
+    authority: Pubkey
+    payer: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def close_session(
+    accounts: CloseSessionAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["nullifier_account"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["payer"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("Sysvar1nstructions1111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"Dr\xb2\x8c\xde&\xf8\xd3"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,29 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class CloseVerificationSessionAccounts(typing.TypedDict):
+    verification_session: Pubkey
+    authority: Pubkey
+
+
+def close_verification_session(
+    accounts: CloseVerificationSessionAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["verification_session"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xbc\xcb&\xd3\xfa\xedBq"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
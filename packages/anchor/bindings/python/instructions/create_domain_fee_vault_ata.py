@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class CreateDomainFeeVaultAtaAccounts(typing.TypedDict):
+    config: Pubkey
+    domain_config: Pubkey
+    domain_fee_vault: Pubkey
+    mint: Pubkey
+    domain_fee_vault_ata: Pubkey
+    payer: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def create_domain_fee_vault_ata(
+    accounts: CreateDomainFeeVaultAtaAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["domain_fee_vault"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["mint"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["domain_fee_vault_ata"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["payer"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xbdM^\xfe8\x021B"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,75 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class DelegateSessionArgs(typing.TypedDict):
+    delegate: Pubkey
+    scope: int
+    expires_in: int
+
+
+layout = borsh.CStruct(
+    "delegate" / BorshPubkey,
+    "scope" / borsh.U64,
+    "expires_in" / borsh.I64,
+)
+
+
+class DelegateSessionAccounts(typing.TypedDict):
+    nullifier_account: Pubkey
+    session_delegate: Pubkey
+    authority: Pubkey
+    logout_epoch: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def delegate_session(
+    args: DelegateSessionArgs,
+    accounts: DelegateSessionAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["nullifier_account"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["session_delegate"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("Sysvar1nstructions1111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["logout_epoch"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"RSww\xc4\xdb\x05\xc5"
+    encoded_args = layout.build(
+        {
+            "delegate": args["delegate"],
+            "scope": args["scope"],
+            "expires_in": args["expires_in"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,52 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class DeprecateCircuitArgs(typing.TypedDict):
+    circuit_id: int
+
+
+layout = borsh.CStruct(
+    "circuit_id" / borsh.U32,
+)
+
+
+class DeprecateCircuitAccounts(typing.TypedDict):
+    config: Pubkey
+    circuit_registry: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def deprecate_circuit(
+    args: DeprecateCircuitArgs,
+    accounts: DeprecateCircuitAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["circuit_registry"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"=\x96\x8e\xc5\x0e\xb3r\x8e"
+    encoded_args = layout.build(
+        {
+            "circuit_id": args["circuit_id"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,54 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class FinalizeVerificationSessionAccounts(typing.TypedDict):
+    verification_session: Pubkey
+    nullifier_account: Pubkey
+    authority: Pubkey
+    circuit_registry: Pubkey
+    domain_config: Pubkey
+    nullifier_ban: Pubkey
+    protocol_stats: Pubkey
+
+
+def finalize_verification_session(
+    accounts: FinalizeVerificationSessionAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["verification_session"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["nullifier_account"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["circuit_registry"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["nullifier_ban"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["protocol_stats"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"e>\x1a5q\xe8bX"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
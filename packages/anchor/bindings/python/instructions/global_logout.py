@@ -0,0 +1,38 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class GlobalLogoutAccounts(typing.TypedDict):
+    logout_epoch: Pubkey
+    identity: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def global_logout(
+    accounts: GlobalLogoutAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["logout_epoch"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["identity"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\x11Y\x8a\xe1\x84\x94\xf2p"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
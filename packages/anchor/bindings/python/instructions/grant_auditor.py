@@ -0,0 +1,66 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+from .. import types
+from ..program_id import PROGRAM_ID
+
+
+class GrantAuditorArgs(typing.TypedDict):
+    nullifier: list[int]
+    auditor: Pubkey
+    name_hash: list[int]
+    expires_in: types.expiry_kind.ExpiryKindKind
+
+
+layout = borsh.CStruct(
+    "nullifier" / borsh.U8[32],
+    "auditor" / BorshPubkey,
+    "name_hash" / borsh.U8[32],
+    "expires_in" / types.expiry_kind.layout,
+)
+
+
+class GrantAuditorAccounts(typing.TypedDict):
+    auditor_grant: Pubkey
+    payer: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def grant_auditor(
+    args: GrantAuditorArgs,
+    accounts: GrantAuditorAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["auditor_grant"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["payer"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"'\x19\x1eBc\xdf\xd7["
+    encoded_args = layout.build(
+        {
+            "nullifier": args["nullifier"],
+            "auditor": args["auditor"],
+            "name_hash": args["name_hash"],
+            "expires_in": args["expires_in"].to_encodable(),
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
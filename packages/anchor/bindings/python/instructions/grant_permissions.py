@@ -0,0 +1,84 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+from construct import Construct
+import borsh_construct as borsh
+from .. import types
+from ..program_id import PROGRAM_ID
+
+
+class GrantPermissionsArgs(typing.TypedDict):
+    nullifier: list[int]
+    domain: list[int]
+    app_id: Pubkey
+    permissions: list[types.permission.PermissionKind]
+    expires_in: types.expiry_kind.ExpiryKindKind
+
+
+layout = borsh.CStruct(
+    "nullifier" / borsh.U8[32],
+    "domain" / borsh.U8[32],
+    "app_id" / BorshPubkey,
+    "permissions" / borsh.Vec(typing.cast(Construct, types.permission.layout)),
+    "expires_in" / types.expiry_kind.layout,
+)
+
+
+class GrantPermissionsAccounts(typing.TypedDict):
+    permission_grant: Pubkey
+    expiry_histogram: Pubkey
+    domain_config: Pubkey
+    payer: Pubkey
+    protocol_stats: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def grant_permissions(
+    args: GrantPermissionsArgs,
+    accounts: GrantPermissionsAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["permission_grant"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["expiry_histogram"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["payer"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["protocol_stats"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xe5\xd5\"\r\xab\xef\xa2G"
+    encoded_args = layout.build(
+        {
+            "nullifier": args["nullifier"],
+            "domain": args["domain"],
+            "app_id": args["app_id"],
+            "permissions": list(
+                map(lambda item: item.to_encodable(), args["permissions"])
+            ),
+            "expires_in": args["expires_in"].to_encodable(),
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,74 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class InitNullifierTreeArgs(typing.TypedDict):
+    max_depth: int
+    max_buffer_size: int
+
+
+layout = borsh.CStruct(
+    "max_depth" / borsh.U32,
+    "max_buffer_size" / borsh.U32,
+)
+
+
+class InitNullifierTreeAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    nullifier_tree: Pubkey
+    merkle_tree: Pubkey
+    owner: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def init_nullifier_tree(
+    args: InitNullifierTreeArgs,
+    accounts: InitNullifierTreeAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["nullifier_tree"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["merkle_tree"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\x1dx\xc9\xb0T\xfc\xa5\xe6"
+    encoded_args = layout.build(
+        {
+            "max_depth": args["max_depth"],
+            "max_buffer_size": args["max_buffer_size"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
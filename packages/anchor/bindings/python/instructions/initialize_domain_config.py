@@ -0,0 +1,61 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class InitializeDomainConfigArgs(typing.TypedDict):
+    domain: list[int]
+
+
+layout = borsh.CStruct(
+    "domain" / borsh.U8[32],
+)
+
+
+class InitializeDomainConfigAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    owner: Pubkey
+    sns_name_account: typing.Optional[Pubkey]
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def initialize_domain_config(
+    args: InitializeDomainConfigArgs,
+    accounts: InitializeDomainConfigAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=True),
+        (
+            AccountMeta(pubkey=accounts["sns_name_account"], is_signer=False, is_writable=False)
+            if accounts["sns_name_account"]
+            else AccountMeta(pubkey=program_id, is_signer=False, is_writable=False)
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"'*8\xd3\x95\xf7\x15W"
+    encoded_args = layout.build(
+        {
+            "domain": args["domain"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,58 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class IssueVcCommitmentArgs(typing.TypedDict):
+    nullifier: list[int]
+    vc_hash: list[int]
+
+
+layout = borsh.CStruct(
+    "nullifier" / borsh.U8[32],
+    "vc_hash" / borsh.U8[32],
+)
+
+
+class IssueVcCommitmentAccounts(typing.TypedDict):
+    vc_commitment: Pubkey
+    issuer: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def issue_vc_commitment(
+    args: IssueVcCommitmentArgs,
+    accounts: IssueVcCommitmentAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["vc_commitment"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["issuer"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"#O2\x0c`/\x00'"
+    encoded_args = layout.build(
+        {
+            "nullifier": args["nullifier"],
+            "vc_hash": args["vc_hash"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
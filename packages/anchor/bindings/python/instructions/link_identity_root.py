@@ -0,0 +1,65 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class LinkIdentityRootArgs(typing.TypedDict):
+    linkage_proof_hash: list[int]
+
+
+layout = borsh.CStruct(
+    "linkage_proof_hash" / borsh.U8[32],
+)
+
+
+class LinkIdentityRootAccounts(typing.TypedDict):
+    nullifier_account: Pubkey
+    identity_root: Pubkey
+    owner: Pubkey
+    authority: Pubkey
+    logout_epoch: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def link_identity_root(
+    args: LinkIdentityRootArgs,
+    accounts: LinkIdentityRootAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["nullifier_account"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["identity_root"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=Pubkey.from_string("Sysvar1nstructions1111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["logout_epoch"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xc4\xd1\xf2\xceR\x86\xb2]"
+    encoded_args = layout.build(
+        {
+            "linkage_proof_hash": args["linkage_proof_hash"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
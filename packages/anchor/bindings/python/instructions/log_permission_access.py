@@ -0,0 +1,70 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from .. import types
+from ..program_id import PROGRAM_ID
+
+
+class LogPermissionAccessArgs(typing.TypedDict):
+    permission_used: types.permission.PermissionKind
+    metadata: str
+    domain: list[int]
+
+
+layout = borsh.CStruct(
+    "permission_used" / types.permission.layout,
+    "metadata" / borsh.String,
+    "domain" / borsh.U8[32],
+)
+
+
+class LogPermissionAccessAccounts(typing.TypedDict):
+    permission_access: Pubkey
+    permission_grant: Pubkey
+    nullifier_ban: Pubkey
+    payer: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def log_permission_access(
+    args: LogPermissionAccessArgs,
+    accounts: LogPermissionAccessAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["permission_access"], is_signer=True, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["permission_grant"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["nullifier_ban"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["payer"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xb1vG\x0f\xdc0\xc5\xc7"
+    encoded_args = layout.build(
+        {
+            "permission_used": args["permission_used"].to_encodable(),
+            "metadata": args["metadata"],
+            "domain": args["domain"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,63 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class MigrateGrantArgs(typing.TypedDict):
+    nullifier: list[int]
+    app_id: Pubkey
+
+
+layout = borsh.CStruct(
+    "nullifier" / borsh.U8[32],
+    "app_id" / BorshPubkey,
+)
+
+
+class MigrateGrantAccounts(typing.TypedDict):
+    migration_state: Pubkey
+    permission_grant: Pubkey
+    migrator: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def migrate_grant(
+    args: MigrateGrantArgs,
+    accounts: MigrateGrantAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["migration_state"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["permission_grant"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["migrator"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xca\x84\xb3\xc1\xc1\x9c\xe4\x94"
+    encoded_args = layout.build(
+        {
+            "nullifier": args["nullifier"],
+            "app_id": args["app_id"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
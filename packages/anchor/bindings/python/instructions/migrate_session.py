@@ -0,0 +1,66 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class MigrateSessionArgs(typing.TypedDict):
+    nullifier: list[int]
+    domain: list[int]
+
+
+layout = borsh.CStruct(
+    "nullifier" / borsh.U8[32],
+    "domain" / borsh.U8[32],
+)
+
+
+class MigrateSessionAccounts(typing.TypedDict):
+    migration_state: Pubkey
+    legacy_session: Pubkey
+    nullifier_account: Pubkey
+    migrator: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def migrate_session(
+    args: MigrateSessionArgs,
+    accounts: MigrateSessionAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["migration_state"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["legacy_session"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["nullifier_account"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["migrator"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xb0\x80\x04#?\x01vl"
+    encoded_args = layout.build(
+        {
+            "nullifier": args["nullifier"],
+            "domain": args["domain"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
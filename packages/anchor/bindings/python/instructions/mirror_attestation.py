@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class MirrorAttestationAccounts(typing.TypedDict):
+    attestation: Pubkey
+    sas_bridge: Pubkey
+    sas_signer: Pubkey
+    credential: Pubkey
+    schema: Pubkey
+    sas_attestation: Pubkey
+    payer: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def mirror_attestation(
+    accounts: MirrorAttestationAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["attestation"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["sas_bridge"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["sas_signer"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["credential"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["schema"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["sas_attestation"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["payer"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("22zoJMtdu4tQc2PzL74ZUT7FrwgB1Udec8DdW4yw4BdG"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"q\xa3\x8b.\x83e&\xdf"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,65 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class OpenRevealEscrowArgs(typing.TypedDict):
+    ciphertext: bytes
+    key_commitment: list[int]
+    delay_secs: int
+
+
+layout = borsh.CStruct(
+    "ciphertext" / borsh.Bytes,
+    "key_commitment" / borsh.U8[32],
+    "delay_secs" / borsh.I64,
+)
+
+
+class OpenRevealEscrowAccounts(typing.TypedDict):
+    reveal_escrow: Pubkey
+    permission_grant: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def open_reveal_escrow(
+    args: OpenRevealEscrowArgs,
+    accounts: OpenRevealEscrowAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["reveal_escrow"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["permission_grant"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xc5\xe3H[O\x08\xd8\x88"
+    encoded_args = layout.build(
+        {
+            "ciphertext": args["ciphertext"],
+            "key_commitment": args["key_commitment"],
+            "delay_secs": args["delay_secs"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,89 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class OpenVerificationSessionArgs(typing.TypedDict):
+    circuit_id: int
+    nullifier: list[int]
+    domain: list[int]
+    proof_hash: list[int]
+    public_inputs_count: int
+
+
+layout = borsh.CStruct(
+    "circuit_id" / borsh.U32,
+    "nullifier" / borsh.U8[32],
+    "domain" / borsh.U8[32],
+    "proof_hash" / borsh.U8[32],
+    "public_inputs_count" / borsh.U16,
+)
+
+
+class OpenVerificationSessionAccounts(typing.TypedDict):
+    verification_session: Pubkey
+    nullifier_account: Pubkey
+    authority: Pubkey
+    circuit_registry: Pubkey
+    honk_vk: Pubkey
+    domain_config: Pubkey
+    nullifier_ban: Pubkey
+    payer_rate_limit: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def open_verification_session(
+    args: OpenVerificationSessionArgs,
+    accounts: OpenVerificationSessionAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["verification_session"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["nullifier_account"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["circuit_registry"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["honk_vk"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["nullifier_ban"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["payer_rate_limit"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xe9\x84\xfc\xc2\x15x\xfa\xf9"
+    encoded_args = layout.build(
+        {
+            "circuit_id": args["circuit_id"],
+            "nullifier": args["nullifier"],
+            "domain": args["domain"],
+            "proof_hash": args["proof_hash"],
+            "public_inputs_count": args["public_inputs_count"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,83 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class PayDomainFeeArgs(typing.TypedDict):
+    max_amount: int
+
+
+layout = borsh.CStruct(
+    "max_amount" / borsh.U64,
+)
+
+
+class PayDomainFeeAccounts(typing.TypedDict):
+    config: Pubkey
+    fee_share_policy: Pubkey
+    domain_config: Pubkey
+    treasury: Pubkey
+    domain_fee_vault: Pubkey
+    mint: Pubkey
+    treasury_ata: Pubkey
+    domain_fee_vault_ata: Pubkey
+    payer_token_account: Pubkey
+    price_update: Pubkey
+    payer: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def pay_domain_fee(
+    args: PayDomainFeeArgs,
+    accounts: PayDomainFeeAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["fee_share_policy"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["treasury"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["domain_fee_vault"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["mint"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["treasury_ata"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["domain_fee_vault_ata"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["payer_token_account"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["price_update"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["payer"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"I\xfa\xedz\xc8\x963v"
+    encoded_args = layout.build(
+        {
+            "max_amount": args["max_amount"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,67 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class PayFeeArgs(typing.TypedDict):
+    max_amount: int
+
+
+layout = borsh.CStruct(
+    "max_amount" / borsh.U64,
+)
+
+
+class PayFeeAccounts(typing.TypedDict):
+    config: Pubkey
+    treasury: Pubkey
+    mint: Pubkey
+    treasury_ata: Pubkey
+    payer_token_account: Pubkey
+    price_update: Pubkey
+    payer: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def pay_fee(
+    args: PayFeeArgs,
+    accounts: PayFeeAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["treasury"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["mint"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["treasury_ata"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["payer_token_account"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["price_update"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["payer"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"b\x19\x98\x00.\t\xba="
+    encoded_args = layout.build(
+        {
+            "max_amount": args["max_amount"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,50 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class PinVkHashArgs(typing.TypedDict):
+    vk_hash: list[int]
+
+
+layout = borsh.CStruct(
+    "vk_hash" / borsh.U8[32],
+)
+
+
+class PinVkHashAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    owner: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def pin_vk_hash(
+    args: PinVkHashArgs,
+    accounts: PinVkHashAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xa0\xben\x10C\xe1\x9f\xae"
+    encoded_args = layout.build(
+        {
+            "vk_hash": args["vk_hash"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,58 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class PublishPointsRootArgs(typing.TypedDict):
+    root: list[int]
+    leaf_count: int
+
+
+layout = borsh.CStruct(
+    "root" / borsh.U8[32],
+    "leaf_count" / borsh.U32,
+)
+
+
+class PublishPointsRootAccounts(typing.TypedDict):
+    config: Pubkey
+    points_root: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def publish_points_root(
+    args: PublishPointsRootArgs,
+    accounts: PublishPointsRootAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["points_root"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xec\xdf'\"\x8d\x03)E"
+    encoded_args = layout.build(
+        {
+            "root": args["root"],
+            "leaf_count": args["leaf_count"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
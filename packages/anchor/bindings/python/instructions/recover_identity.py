@@ -0,0 +1,110 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from .. import types
+from ..program_id import PROGRAM_ID
+
+
+class RecoverIdentityArgs(typing.TypedDict):
+    result: types.signed_verification_result.SignedVerificationResult
+    nullifier: list[int]
+    new_nullifier: list[int]
+    domain: list[int]
+
+
+layout = borsh.CStruct(
+    "result" / types.signed_verification_result.SignedVerificationResult.layout,
+    "nullifier" / borsh.U8[32],
+    "new_nullifier" / borsh.U8[32],
+    "domain" / borsh.U8[32],
+)
+
+
+class RecoverIdentityAccounts(typing.TypedDict):
+    guardian_set: Pubkey
+    old_nullifier_ban: Pubkey
+    new_nullifier_ban: Pubkey
+    proof_record: Pubkey
+    result_receipt: Pubkey
+    new_authority: Pubkey
+    verifier: typing.Optional[Pubkey]
+    circuit_registry: Pubkey
+    domain_config: Pubkey
+    verifier_registry: Pubkey
+    secp256r1_verifier_registry: Pubkey
+    assurance_policy: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def recover_identity(
+    args: RecoverIdentityArgs,
+    accounts: RecoverIdentityAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["guardian_set"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["old_nullifier_ban"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["new_nullifier_ban"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["proof_record"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["result_receipt"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["new_authority"], is_signer=True, is_writable=True),
+        (
+            AccountMeta(pubkey=accounts["verifier"], is_signer=False, is_writable=False)
+            if accounts["verifier"]
+            else AccountMeta(pubkey=program_id, is_signer=False, is_writable=False)
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("Sysvar1nstructions1111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["circuit_registry"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["verifier_registry"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["secp256r1_verifier_registry"],
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["assurance_policy"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xec\xbe~\xc0\x1c\xa3ft"
+    encoded_args = layout.build(
+        {
+            "result": args["result"].to_encodable(),
+            "nullifier": args["nullifier"],
+            "new_nullifier": args["new_nullifier"],
+            "domain": args["domain"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
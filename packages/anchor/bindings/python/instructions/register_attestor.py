@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class RegisterAttestorArgs(typing.TypedDict):
+    issuer: Pubkey
+    name_hash: list[int]
+    claim_mask: int
+
+
+layout = borsh.CStruct(
+    "issuer" / BorshPubkey,
+    "name_hash" / borsh.U8[32],
+    "claim_mask" / borsh.U8,
+)
+
+
+class RegisterAttestorAccounts(typing.TypedDict):
+    config: Pubkey
+    attestor: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def register_attestor(
+    args: RegisterAttestorArgs,
+    accounts: RegisterAttestorAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["attestor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"D\xc9V\xf43q\x0f^"
+    encoded_args = layout.build(
+        {
+            "issuer": args["issuer"],
+            "name_hash": args["name_hash"],
+            "claim_mask": args["claim_mask"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
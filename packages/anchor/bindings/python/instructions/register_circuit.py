@@ -0,0 +1,60 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class RegisterCircuitArgs(typing.TypedDict):
+    circuit_id: int
+    vk_hash: list[int]
+
+
+layout = borsh.CStruct(
+    "circuit_id" / borsh.U32,
+    "vk_hash" / borsh.U8[32],
+)
+
+
+class RegisterCircuitAccounts(typing.TypedDict):
+    config: Pubkey
+    circuit_registry: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def register_circuit(
+    args: RegisterCircuitArgs,
+    accounts: RegisterCircuitAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["circuit_registry"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xd0\xf7\xf1\x88Q\xa6\xce\xc7"
+    encoded_args = layout.build(
+        {
+            "circuit_id": args["circuit_id"],
+            "vk_hash": args["vk_hash"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,74 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class RegisterDomainArgs(typing.TypedDict):
+    domain: list[int]
+    verifier: typing.Optional[Pubkey]
+    default_session_secs: int
+    max_result_age_secs: int
+    max_permissions: int
+
+
+layout = borsh.CStruct(
+    "domain" / borsh.U8[32],
+    "verifier" / borsh.Option(BorshPubkey),
+    "default_session_secs" / borsh.I64,
+    "max_result_age_secs" / borsh.U32,
+    "max_permissions" / borsh.U8,
+)
+
+
+class RegisterDomainAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    owner: Pubkey
+    sns_name_account: typing.Optional[Pubkey]
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def register_domain(
+    args: RegisterDomainArgs,
+    accounts: RegisterDomainAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=True),
+        (
+            AccountMeta(pubkey=accounts["sns_name_account"], is_signer=False, is_writable=False)
+            if accounts["sns_name_account"]
+            else AccountMeta(pubkey=program_id, is_signer=False, is_writable=False)
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xec\x07\xd0\x97\xad\x95Ih"
+    encoded_args = layout.build(
+        {
+            "domain": args["domain"],
+            "verifier": args["verifier"],
+            "default_session_secs": args["default_session_secs"],
+            "max_result_age_secs": args["max_result_age_secs"],
+            "max_permissions": args["max_permissions"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,75 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from construct import Construct
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class RegisterGroth16VkArgs(typing.TypedDict):
+    circuit_id: int
+    alpha_g1: list[int]
+    beta_g2: list[int]
+    gamma_g2: list[int]
+    delta_g2: list[int]
+    ic: list[list[int]]
+
+
+layout = borsh.CStruct(
+    "circuit_id" / borsh.U32,
+    "alpha_g1" / borsh.U8[64],
+    "beta_g2" / borsh.U8[128],
+    "gamma_g2" / borsh.U8[128],
+    "delta_g2" / borsh.U8[128],
+    "ic" / borsh.Vec(typing.cast(Construct, borsh.U8[64])),
+)
+
+
+class RegisterGroth16VkAccounts(typing.TypedDict):
+    config: Pubkey
+    circuit_registry: Pubkey
+    groth16_vk: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def register_groth16_vk(
+    args: RegisterGroth16VkArgs,
+    accounts: RegisterGroth16VkAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["circuit_registry"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["groth16_vk"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xe1\x16n\x8d\x1a\xd4\x8c\x1a"
+    encoded_args = layout.build(
+        {
+            "circuit_id": args["circuit_id"],
+            "alpha_g1": args["alpha_g1"],
+            "beta_g2": args["beta_g2"],
+            "gamma_g2": args["gamma_g2"],
+            "delta_g2": args["delta_g2"],
+            "ic": args["ic"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
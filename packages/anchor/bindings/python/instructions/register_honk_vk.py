@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class RegisterHonkVkArgs(typing.TypedDict):
+    circuit_id: int
+    log_circuit_size: int
+    public_inputs_size: int
+    pub_inputs_offset: int
+
+
+layout = borsh.CStruct(
+    "circuit_id" / borsh.U32,
+    "log_circuit_size" / borsh.U8,
+    "public_inputs_size" / borsh.U32,
+    "pub_inputs_offset" / borsh.U32,
+)
+
+
+class RegisterHonkVkAccounts(typing.TypedDict):
+    config: Pubkey
+    circuit_registry: Pubkey
+    honk_vk: Pubkey
+    authority: Pubkey
+
+
+def register_honk_vk(
+    args: RegisterHonkVkArgs,
+    accounts: RegisterHonkVkAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["circuit_registry"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["honk_vk"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"(\xf1\xb8\xdf\xe6\xc5{\xdb"
+    encoded_args = layout.build(
+        {
+            "circuit_id": args["circuit_id"],
+            "log_circuit_size": args["log_circuit_size"],
+            "public_inputs_size": args["public_inputs_size"],
+            "pub_inputs_offset": args["pub_inputs_offset"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
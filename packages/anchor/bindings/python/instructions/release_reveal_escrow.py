@@ -0,0 +1,52 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class ReleaseRevealEscrowArgs(typing.TypedDict):
+    reveal_key: list[int]
+
+
+layout = borsh.CStruct(
+    "reveal_key" / borsh.U8[32],
+)
+
+
+class ReleaseRevealEscrowAccounts(typing.TypedDict):
+    reveal_escrow: Pubkey
+    permission_grant: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def release_reveal_escrow(
+    args: ReleaseRevealEscrowArgs,
+    accounts: ReleaseRevealEscrowAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["reveal_escrow"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["permission_grant"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"#\xb2\xb0\x99;MB\xd7"
+    encoded_args = layout.build(
+        {
+            "reveal_key": args["reveal_key"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
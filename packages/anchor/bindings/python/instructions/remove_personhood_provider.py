@@ -0,0 +1,53 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class RemovePersonhoodProviderArgs(typing.TypedDict):
+    provider: Pubkey
+
+
+layout = borsh.CStruct(
+    "provider" / BorshPubkey,
+)
+
+
+class RemovePersonhoodProviderAccounts(typing.TypedDict):
+    config: Pubkey
+    personhood_providers: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def remove_personhood_provider(
+    args: RemovePersonhoodProviderArgs,
+    accounts: RemovePersonhoodProviderAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["personhood_providers"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xd2\xa4\x1c6\xb4\xdf\x18R"
+    encoded_args = layout.build(
+        {
+            "provider": args["provider"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,54 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class RemoveSecp256r1VerifierArgs(typing.TypedDict):
+    verifier: list[int]
+
+
+layout = borsh.CStruct(
+    "verifier" / borsh.U8[33],
+)
+
+
+class RemoveSecp256r1VerifierAccounts(typing.TypedDict):
+    config: Pubkey
+    secp256r1_verifier_registry: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def remove_secp256r1_verifier(
+    args: RemoveSecp256r1VerifierArgs,
+    accounts: RemoveSecp256r1VerifierAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["secp256r1_verifier_registry"],
+            is_signer=False,
+            is_writable=True,
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\x88_\x9c}\xe9L\xe7>"
+    encoded_args = layout.build(
+        {
+            "verifier": args["verifier"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
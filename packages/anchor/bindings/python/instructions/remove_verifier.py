@@ -0,0 +1,53 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class RemoveVerifierArgs(typing.TypedDict):
+    verifier: Pubkey
+
+
+layout = borsh.CStruct(
+    "verifier" / BorshPubkey,
+)
+
+
+class RemoveVerifierAccounts(typing.TypedDict):
+    config: Pubkey
+    verifier_registry: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def remove_verifier(
+    args: RemoveVerifierArgs,
+    accounts: RemoveVerifierAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["verifier_registry"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xb3\t\x84\xb7\xe9\x17\xaco"
+    encoded_args = layout.build(
+        {
+            "verifier": args["verifier"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
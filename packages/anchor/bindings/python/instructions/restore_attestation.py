@@ -0,0 +1,68 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class RestoreAttestationArgs(typing.TypedDict):
+    nullifier: list[int]
+    provider: Pubkey
+    score: int
+    issued_at: int
+    expires_at: int
+
+
+layout = borsh.CStruct(
+    "nullifier" / borsh.U8[32],
+    "provider" / BorshPubkey,
+    "score" / borsh.U16,
+    "issued_at" / borsh.I64,
+    "expires_at" / borsh.I64,
+)
+
+
+class RestoreAttestationAccounts(typing.TypedDict):
+    archive: Pubkey
+    attestation: Pubkey
+    payer: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def restore_attestation(
+    args: RestoreAttestationArgs,
+    accounts: RestoreAttestationAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["archive"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["attestation"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["payer"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"`h\x9a\x01\xe0R\x83\xbf"
+    encoded_args = layout.build(
+        {
+            "nullifier": args["nullifier"],
+            "provider": args["provider"],
+            "score": args["score"],
+            "issued_at": args["issued_at"],
+            "expires_at": args["expires_at"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
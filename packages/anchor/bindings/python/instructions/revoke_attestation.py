@@ -0,0 +1,33 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class RevokeAttestationAccounts(typing.TypedDict):
+    attestation: Pubkey
+    issuer: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def revoke_attestation(
+    accounts: RevokeAttestationAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["attestation"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["issuer"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\x0c\x9cg\xa1\xc2\xf6\xd3\xb3"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
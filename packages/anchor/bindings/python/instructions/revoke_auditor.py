@@ -0,0 +1,35 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class RevokeAuditorAccounts(typing.TypedDict):
+    auditor_grant: Pubkey
+    granted_by: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def revoke_auditor(
+    accounts: RevokeAuditorAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["auditor_grant"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["granted_by"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xd3\x03\xa3X2\x85O|"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,44 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class RevokePermissionsAccounts(typing.TypedDict):
+    permission_grant: Pubkey
+    authority: Pubkey
+    protocol_stats: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def revoke_permissions(
+    accounts: RevokePermissionsAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["permission_grant"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["protocol_stats"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"s;\x88\xe3\xc6*\xbd\xec"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
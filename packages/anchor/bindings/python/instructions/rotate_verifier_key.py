@@ -0,0 +1,56 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class RotateVerifierKeyArgs(typing.TypedDict):
+    old_key: Pubkey
+    new_key: Pubkey
+
+
+layout = borsh.CStruct(
+    "old_key" / BorshPubkey,
+    "new_key" / BorshPubkey,
+)
+
+
+class RotateVerifierKeyAccounts(typing.TypedDict):
+    config: Pubkey
+    verifier_registry: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def rotate_verifier_key(
+    args: RotateVerifierKeyArgs,
+    accounts: RotateVerifierKeyAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["verifier_registry"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xabLL\x19\xd6?\x9b\x9a"
+    encoded_args = layout.build(
+        {
+            "old_key": args["old_key"],
+            "new_key": args["new_key"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
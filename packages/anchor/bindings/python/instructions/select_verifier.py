@@ -0,0 +1,46 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SelectVerifierArgs(typing.TypedDict):
+    nullifier: list[int]
+
+
+layout = borsh.CStruct(
+    "nullifier" / borsh.U8[32],
+)
+
+
+class SelectVerifierAccounts(typing.TypedDict):
+    verifier_registry: Pubkey
+    verifier_weights: Pubkey
+
+
+def select_verifier(
+    args: SelectVerifierArgs,
+    accounts: SelectVerifierAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["verifier_registry"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["verifier_weights"], is_signer=False, is_writable=False
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xc6\xc4\xe6\xe3+\x9b\xa0\x0e"
+    encoded_args = layout.build(
+        {
+            "nullifier": args["nullifier"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
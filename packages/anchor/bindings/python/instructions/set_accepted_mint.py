@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetAcceptedMintArgs(typing.TypedDict):
+    mint: Pubkey
+    price_feed_id: list[int]
+    accepted: bool
+
+
+layout = borsh.CStruct(
+    "mint" / BorshPubkey,
+    "price_feed_id" / borsh.U8[32],
+    "accepted" / borsh.Bool,
+)
+
+
+class SetAcceptedMintAccounts(typing.TypedDict):
+    config: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_accepted_mint(
+    args: SetAcceptedMintArgs,
+    accounts: SetAcceptedMintAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xb5\xfa\xed?\xfa\xbcG\xb6"
+    encoded_args = layout.build(
+        {
+            "mint": args["mint"],
+            "price_feed_id": args["price_feed_id"],
+            "accepted": args["accepted"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,60 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetAssuranceTierArgs(typing.TypedDict):
+    tier: int
+    max_result_age_secs: int
+
+
+layout = borsh.CStruct(
+    "tier" / borsh.U8,
+    "max_result_age_secs" / borsh.U32,
+)
+
+
+class SetAssuranceTierAccounts(typing.TypedDict):
+    config: Pubkey
+    assurance_policy: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_assurance_tier(
+    args: SetAssuranceTierArgs,
+    accounts: SetAssuranceTierAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["assurance_policy"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b" b:\xf9\xf9D\x1a\x0f"
+    encoded_args = layout.build(
+        {
+            "tier": args["tier"],
+            "max_result_age_secs": args["max_result_age_secs"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,51 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from .. import types
+from ..program_id import PROGRAM_ID
+
+
+class SetAttestorStatusArgs(typing.TypedDict):
+    status: types.attestor_status.AttestorStatusKind
+
+
+layout = borsh.CStruct(
+    "status" / types.attestor_status.layout,
+)
+
+
+class SetAttestorStatusAccounts(typing.TypedDict):
+    config: Pubkey
+    attestor: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_attestor_status(
+    args: SetAttestorStatusArgs,
+    accounts: SetAttestorStatusAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["attestor"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"D\x03\xd9\xa1\xc2\x8ay\x0b"
+    encoded_args = layout.build(
+        {
+            "status": args["status"].to_encodable(),
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
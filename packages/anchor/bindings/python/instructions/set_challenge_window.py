@@ -0,0 +1,50 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetChallengeWindowArgs(typing.TypedDict):
+    challenge_window_secs: int
+
+
+layout = borsh.CStruct(
+    "challenge_window_secs" / borsh.I64,
+)
+
+
+class SetChallengeWindowAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    owner: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_challenge_window(
+    args: SetChallengeWindowArgs,
+    accounts: SetChallengeWindowAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xf5\xb8o\x08%\xd1\xf5\xc7"
+    encoded_args = layout.build(
+        {
+            "challenge_window_secs": args["challenge_window_secs"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,54 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetDomainAssuranceTierArgs(typing.TypedDict):
+    assurance_tier: int
+
+
+layout = borsh.CStruct(
+    "assurance_tier" / borsh.U8,
+)
+
+
+class SetDomainAssuranceTierAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    assurance_policy: Pubkey
+    owner: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_domain_assurance_tier(
+    args: SetDomainAssuranceTierArgs,
+    accounts: SetDomainAssuranceTierAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["assurance_policy"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xc9\x89\x07\xbd*\x80=\x9f"
+    encoded_args = layout.build(
+        {
+            "assurance_tier": args["assurance_tier"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,50 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetDomainAttestorClaimsArgs(typing.TypedDict):
+    claim_mask: int
+
+
+layout = borsh.CStruct(
+    "claim_mask" / borsh.U8,
+)
+
+
+class SetDomainAttestorClaimsAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    owner: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_domain_attestor_claims(
+    args: SetDomainAttestorClaimsArgs,
+    accounts: SetDomainAttestorClaimsAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"H\xb6\xe1\x15\x0b\x96\x8f\x98"
+    encoded_args = layout.build(
+        {
+            "claim_mask": args["claim_mask"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
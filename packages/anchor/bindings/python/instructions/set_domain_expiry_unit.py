@@ -0,0 +1,51 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from .. import types
+from ..program_id import PROGRAM_ID
+
+
+class SetDomainExpiryUnitArgs(typing.TypedDict):
+    session_expiry_unit: types.expiry_unit.ExpiryUnitKind
+
+
+layout = borsh.CStruct(
+    "session_expiry_unit" / types.expiry_unit.layout,
+)
+
+
+class SetDomainExpiryUnitAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    owner: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_domain_expiry_unit(
+    args: SetDomainExpiryUnitArgs,
+    accounts: SetDomainExpiryUnitAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"E\xddW\x82J<\xc2\x89"
+    encoded_args = layout.build(
+        {
+            "session_expiry_unit": args["session_expiry_unit"].to_encodable(),
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
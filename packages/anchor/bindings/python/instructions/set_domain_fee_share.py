@@ -0,0 +1,57 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetDomainFeeShareArgs(typing.TypedDict):
+    domain_share_bps: int
+
+
+layout = borsh.CStruct(
+    "domain_share_bps" / borsh.U16,
+)
+
+
+class SetDomainFeeShareAccounts(typing.TypedDict):
+    config: Pubkey
+    fee_share_policy: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_domain_fee_share(
+    args: SetDomainFeeShareArgs,
+    accounts: SetDomainFeeShareAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["fee_share_policy"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\x10\xaa\x90\x05\x92O\xac\x14"
+    encoded_args = layout.build(
+        {
+            "domain_share_bps": args["domain_share_bps"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
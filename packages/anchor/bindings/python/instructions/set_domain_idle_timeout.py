@@ -0,0 +1,50 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetDomainIdleTimeoutArgs(typing.TypedDict):
+    idle_timeout_secs: int
+
+
+layout = borsh.CStruct(
+    "idle_timeout_secs" / borsh.I64,
+)
+
+
+class SetDomainIdleTimeoutAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    owner: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_domain_idle_timeout(
+    args: SetDomainIdleTimeoutArgs,
+    accounts: SetDomainIdleTimeoutAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"F-\xd4\xe1\xde|6\xdb"
+    encoded_args = layout.build(
+        {
+            "idle_timeout_secs": args["idle_timeout_secs"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
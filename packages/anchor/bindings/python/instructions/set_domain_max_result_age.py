@@ -0,0 +1,50 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetDomainMaxResultAgeArgs(typing.TypedDict):
+    max_result_age_secs: int
+
+
+layout = borsh.CStruct(
+    "max_result_age_secs" / borsh.U32,
+)
+
+
+class SetDomainMaxResultAgeAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    owner: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_domain_max_result_age(
+    args: SetDomainMaxResultAgeArgs,
+    accounts: SetDomainMaxResultAgeAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"[\x84\xb6\x98\x94\x97n\xd9"
+    encoded_args = layout.build(
+        {
+            "max_result_age_secs": args["max_result_age_secs"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,51 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from .. import types
+from ..program_id import PROGRAM_ID
+
+
+class SetDomainNullifierStoreArgs(typing.TypedDict):
+    nullifier_store: types.nullifier_store.NullifierStoreKind
+
+
+layout = borsh.CStruct(
+    "nullifier_store" / types.nullifier_store.layout,
+)
+
+
+class SetDomainNullifierStoreAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    owner: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_domain_nullifier_store(
+    args: SetDomainNullifierStoreArgs,
+    accounts: SetDomainNullifierStoreAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"ev\xce\xb72\x1c\x18\xa6"
+    encoded_args = layout.build(
+        {
+            "nullifier_store": args["nullifier_store"].to_encodable(),
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,51 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from construct import Construct
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetDomainOriginsArgs(typing.TypedDict):
+    origins: list[list[int]]
+
+
+layout = borsh.CStruct(
+    "origins" / borsh.Vec(typing.cast(Construct, borsh.U8[32])),
+)
+
+
+class SetDomainOriginsAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    owner: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_domain_origins(
+    args: SetDomainOriginsArgs,
+    accounts: SetDomainOriginsAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"1\xa6\xf0\x04,\xf8\xd4\xbd"
+    encoded_args = layout.build(
+        {
+            "origins": args["origins"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,50 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetDomainPausedArgs(typing.TypedDict):
+    paused: bool
+
+
+layout = borsh.CStruct(
+    "paused" / borsh.Bool,
+)
+
+
+class SetDomainPausedAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_domain_paused(
+    args: SetDomainPausedArgs,
+    accounts: SetDomainPausedAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"4Q0\xed\x14\xdc\xa4\xbc"
+    encoded_args = layout.build(
+        {
+            "paused": args["paused"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
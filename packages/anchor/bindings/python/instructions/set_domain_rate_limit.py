@@ -0,0 +1,50 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetDomainRateLimitArgs(typing.TypedDict):
+    rate_limit: int
+
+
+layout = borsh.CStruct(
+    "rate_limit" / borsh.U32,
+)
+
+
+class SetDomainRateLimitAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    owner: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_domain_rate_limit(
+    args: SetDomainRateLimitArgs,
+    accounts: SetDomainRateLimitAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"]\xc1\xd6\x9f\xdes\x16\x91"
+    encoded_args = layout.build(
+        {
+            "rate_limit": args["rate_limit"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+from .. import types
+from ..program_id import PROGRAM_ID
+
+
+class SetDomainRoleArgs(typing.TypedDict):
+    role: types.domain_role.DomainRoleKind
+    key: Pubkey
+
+
+layout = borsh.CStruct(
+    "role" / types.domain_role.layout,
+    "key" / BorshPubkey,
+)
+
+
+class SetDomainRoleAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    owner: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_domain_role(
+    args: SetDomainRoleArgs,
+    accounts: SetDomainRoleAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xb8\x1f\xa9V\xdd\xff\xcc."
+    encoded_args = layout.build(
+        {
+            "role": args["role"].to_encodable(),
+            "key": args["key"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,53 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetDomainSessionBoundsArgs(typing.TypedDict):
+    min_session_secs: int
+    max_session_secs: int
+
+
+layout = borsh.CStruct(
+    "min_session_secs" / borsh.I64,
+    "max_session_secs" / borsh.I64,
+)
+
+
+class SetDomainSessionBoundsAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    owner: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_domain_session_bounds(
+    args: SetDomainSessionBoundsArgs,
+    accounts: SetDomainSessionBoundsAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xc6m@'\xf0X\x02\xe9"
+    encoded_args = layout.build(
+        {
+            "min_session_secs": args["min_session_secs"],
+            "max_session_secs": args["max_session_secs"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
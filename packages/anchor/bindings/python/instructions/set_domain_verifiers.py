@@ -0,0 +1,52 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+from construct import Construct
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetDomainVerifiersArgs(typing.TypedDict):
+    verifiers: list[Pubkey]
+
+
+layout = borsh.CStruct(
+    "verifiers" / borsh.Vec(typing.cast(Construct, BorshPubkey)),
+)
+
+
+class SetDomainVerifiersAccounts(typing.TypedDict):
+    domain_config: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_domain_verifiers(
+    args: SetDomainVerifiersArgs,
+    accounts: SetDomainVerifiersAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"7=i\x9aiI\xdb\x8a"
+    encoded_args = layout.build(
+        {
+            "verifiers": args["verifiers"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,71 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+from construct import Construct
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetGuardiansArgs(typing.TypedDict):
+    guardians: list[Pubkey]
+    threshold: int
+
+
+layout = borsh.CStruct(
+    "guardians" / borsh.Vec(typing.cast(Construct, BorshPubkey)),
+    "threshold" / borsh.U8,
+)
+
+
+class SetGuardiansAccounts(typing.TypedDict):
+    nullifier_account: Pubkey
+    guardian_set: Pubkey
+    authority: Pubkey
+    logout_epoch: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_guardians(
+    args: SetGuardiansArgs,
+    accounts: SetGuardiansAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["nullifier_account"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["guardian_set"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("Sysvar1nstructions1111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["logout_epoch"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xa6E\x8c\xb7\x9d\xa9\xfd("
+    encoded_args = layout.build(
+        {
+            "guardians": args["guardians"],
+            "threshold": args["threshold"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetNotificationChannelArgs(typing.TypedDict):
+    channel_commitment: list[int]
+    encrypted_token: bytes
+
+
+layout = borsh.CStruct(
+    "channel_commitment" / borsh.U8[32],
+    "encrypted_token" / borsh.Bytes,
+)
+
+
+class SetNotificationChannelAccounts(typing.TypedDict):
+    notification_channel: Pubkey
+    permission_grant: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_notification_channel(
+    args: SetNotificationChannelArgs,
+    accounts: SetNotificationChannelAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["notification_channel"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["permission_grant"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"H$\x8c\x86\x90\xaeK\xb6"
+    encoded_args = layout.build(
+        {
+            "channel_commitment": args["channel_commitment"],
+            "encrypted_token": args["encrypted_token"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
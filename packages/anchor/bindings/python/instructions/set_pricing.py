@@ -0,0 +1,54 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetPricingArgs(typing.TypedDict):
+    fee_usd_micros: int
+    max_price_age_secs: int
+    max_price_conf_bps: int
+
+
+layout = borsh.CStruct(
+    "fee_usd_micros" / borsh.U64,
+    "max_price_age_secs" / borsh.U64,
+    "max_price_conf_bps" / borsh.U16,
+)
+
+
+class SetPricingAccounts(typing.TypedDict):
+    config: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_pricing(
+    args: SetPricingArgs,
+    accounts: SetPricingAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"4\xc8\x9d\xc0\x12\x869{"
+    encoded_args = layout.build(
+        {
+            "fee_usd_micros": args["fee_usd_micros"],
+            "max_price_age_secs": args["max_price_age_secs"],
+            "max_price_conf_bps": args["max_price_conf_bps"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,52 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetReceiptRetentionArgs(typing.TypedDict):
+    receipt_retention_secs: int
+
+
+layout = borsh.CStruct(
+    "receipt_retention_secs" / borsh.I64,
+)
+
+
+class SetReceiptRetentionAccounts(typing.TypedDict):
+    config: Pubkey
+    verifier_registry: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_receipt_retention(
+    args: SetReceiptRetentionArgs,
+    accounts: SetReceiptRetentionAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["verifier_registry"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"O`\x8e\x16B`p\xb9"
+    encoded_args = layout.build(
+        {
+            "receipt_retention_secs": args["receipt_retention_secs"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
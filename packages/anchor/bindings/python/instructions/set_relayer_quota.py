@@ -0,0 +1,61 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetRelayerQuotaArgs(typing.TypedDict):
+    relayer: Pubkey
+    quota: int
+
+
+layout = borsh.CStruct(
+    "relayer" / BorshPubkey,
+    "quota" / borsh.U32,
+)
+
+
+class SetRelayerQuotaAccounts(typing.TypedDict):
+    config: Pubkey
+    payer_rate_limit: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_relayer_quota(
+    args: SetRelayerQuotaArgs,
+    accounts: SetRelayerQuotaAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["payer_rate_limit"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xd4t0Z7\xf9\x8b\xa6"
+    encoded_args = layout.build(
+        {
+            "relayer": args["relayer"],
+            "quota": args["quota"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
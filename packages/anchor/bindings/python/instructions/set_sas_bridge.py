@@ -0,0 +1,59 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetSasBridgeArgs(typing.TypedDict):
+    credential: Pubkey
+    schema: Pubkey
+
+
+layout = borsh.CStruct(
+    "credential" / BorshPubkey,
+    "schema" / BorshPubkey,
+)
+
+
+class SetSasBridgeAccounts(typing.TypedDict):
+    config: Pubkey
+    sas_bridge: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_sas_bridge(
+    args: SetSasBridgeArgs,
+    accounts: SetSasBridgeAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["sas_bridge"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"24\x02\xbd\x17\xfa5\xc1"
+    encoded_args = layout.build(
+        {
+            "credential": args["credential"],
+            "schema": args["schema"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
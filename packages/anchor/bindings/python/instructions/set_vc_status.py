@@ -0,0 +1,51 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from .. import types
+from ..program_id import PROGRAM_ID
+
+
+class SetVcStatusArgs(typing.TypedDict):
+    status: types.vc_status.VcStatusKind
+
+
+layout = borsh.CStruct(
+    "status" / types.vc_status.layout,
+)
+
+
+class SetVcStatusAccounts(typing.TypedDict):
+    vc_commitment: Pubkey
+    issuer: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_vc_status(
+    args: SetVcStatusArgs,
+    accounts: SetVcStatusAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["vc_commitment"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["issuer"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xe2\x93Oi\"\xa7X\xb3"
+    encoded_args = layout.build(
+        {
+            "status": args["status"].to_encodable(),
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
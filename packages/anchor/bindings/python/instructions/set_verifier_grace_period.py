@@ -0,0 +1,52 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetVerifierGracePeriodArgs(typing.TypedDict):
+    grace_period_secs: int
+
+
+layout = borsh.CStruct(
+    "grace_period_secs" / borsh.I64,
+)
+
+
+class SetVerifierGracePeriodAccounts(typing.TypedDict):
+    config: Pubkey
+    verifier_registry: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_verifier_grace_period(
+    args: SetVerifierGracePeriodArgs,
+    accounts: SetVerifierGracePeriodAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["verifier_registry"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xb8p\xd5\xe2\xc8\x91>\xbe"
+    encoded_args = layout.build(
+        {
+            "grace_period_secs": args["grace_period_secs"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,65 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SetVerifierWeightArgs(typing.TypedDict):
+    verifier: Pubkey
+    weight: int
+
+
+layout = borsh.CStruct(
+    "verifier" / BorshPubkey,
+    "weight" / borsh.U8,
+)
+
+
+class SetVerifierWeightAccounts(typing.TypedDict):
+    config: Pubkey
+    verifier_registry: Pubkey
+    verifier_weights: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def set_verifier_weight(
+    args: SetVerifierWeightArgs,
+    accounts: SetVerifierWeightAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["verifier_registry"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["verifier_weights"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xf9Q\xb9[k\xbe\xd3\x08"
+    encoded_args = layout.build(
+        {
+            "verifier": args["verifier"],
+            "weight": args["weight"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,61 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class SweepTreasuryAtaArgs(typing.TypedDict):
+    amount: int
+
+
+layout = borsh.CStruct(
+    "amount" / borsh.U64,
+)
+
+
+class SweepTreasuryAtaAccounts(typing.TypedDict):
+    config: Pubkey
+    treasury: Pubkey
+    mint: Pubkey
+    treasury_ata: Pubkey
+    destination: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def sweep_treasury_ata(
+    args: SweepTreasuryAtaArgs,
+    accounts: SweepTreasuryAtaAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["treasury"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["mint"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["treasury_ata"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["destination"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=Pubkey.from_string("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\x81\x1a|\xc7d2[\x0b"
+    encoded_args = layout.build(
+        {
+            "amount": args["amount"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,48 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class TransferUsernameAccounts(typing.TypedDict):
+    username: Pubkey
+    authority: Pubkey
+    new_session: Pubkey
+    new_authority: Pubkey
+    logout_epoch: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def transfer_username(
+    accounts: TransferUsernameAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["username"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["authority"], is_signer=False, is_writable=False),
+        AccountMeta(pubkey=accounts["new_session"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["new_authority"], is_signer=True, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("Sysvar1nstructions1111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["logout_epoch"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xf9\xdbx\xa8\x1e\xb1&)"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,49 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from .. import types
+from ..program_id import PROGRAM_ID
+
+
+class TryCheckPermissionArgs(typing.TypedDict):
+    permission: types.permission.PermissionKind
+
+
+layout = borsh.CStruct(
+    "permission" / types.permission.layout,
+)
+
+
+class TryCheckPermissionAccounts(typing.TypedDict):
+    permission_grant: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def try_check_permission(
+    args: TryCheckPermissionArgs,
+    accounts: TryCheckPermissionAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["permission_grant"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xa9\xce\x9f\x95\x92?\x96*"
+    encoded_args = layout.build(
+        {
+            "permission": args["permission"].to_encodable(),
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
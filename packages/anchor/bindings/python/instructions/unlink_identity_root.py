@@ -0,0 +1,50 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class UnlinkIdentityRootArgs(typing.TypedDict):
+    nullifier: list[int]
+
+
+layout = borsh.CStruct(
+    "nullifier" / borsh.U8[32],
+)
+
+
+class UnlinkIdentityRootAccounts(typing.TypedDict):
+    identity_root: Pubkey
+    owner: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def unlink_identity_root(
+    args: UnlinkIdentityRootArgs,
+    accounts: UnlinkIdentityRootAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["identity_root"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["owner"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"Q\x87\x15\x9b\xe1\xf0\x98\x13"
+    encoded_args = layout.build(
+        {
+            "nullifier": args["nullifier"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
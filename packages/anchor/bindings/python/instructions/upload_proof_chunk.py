@@ -0,0 +1,52 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class UploadProofChunkArgs(typing.TypedDict):
+    offset: int
+    chunk: bytes
+
+
+layout = borsh.CStruct(
+    "offset" / borsh.U32,
+    "chunk" / borsh.Bytes,
+)
+
+
+class UploadProofChunkAccounts(typing.TypedDict):
+    verification_session: Pubkey
+    authority: Pubkey
+
+
+def upload_proof_chunk(
+    args: UploadProofChunkArgs,
+    accounts: UploadProofChunkAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["verification_session"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"<\xd7X/\xa8k{\x96"
+    encoded_args = layout.build(
+        {
+            "offset": args["offset"],
+            "chunk": args["chunk"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,25 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class ValidateAttestationAccounts(typing.TypedDict):
+    attestation: Pubkey
+
+
+def validate_attestation(
+    accounts: ValidateAttestationAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["attestation"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xfa\xe8$\x8f\x95\x01I\xdc"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,57 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from .. import types
+from ..program_id import PROGRAM_ID
+
+
+class ValidateSessionDelegateArgs(typing.TypedDict):
+    permission: types.permission.PermissionKind
+
+
+layout = borsh.CStruct(
+    "permission" / types.permission.layout,
+)
+
+
+class ValidateSessionDelegateAccounts(typing.TypedDict):
+    session_delegate: Pubkey
+    nullifier_account: Pubkey
+    delegate: Pubkey
+    logout_epoch: Pubkey
+    nullifier_ban: Pubkey
+
+
+def validate_session_delegate(
+    args: ValidateSessionDelegateArgs,
+    accounts: ValidateSessionDelegateAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["session_delegate"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["nullifier_account"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["delegate"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["logout_epoch"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["nullifier_ban"], is_signer=False, is_writable=False
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"u\x13\x1exRf\x84\xe5"
+    encoded_args = layout.build(
+        {
+            "permission": args["permission"].to_encodable(),
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
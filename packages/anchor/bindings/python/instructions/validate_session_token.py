@@ -0,0 +1,35 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class ValidateSessionTokenAccounts(typing.TypedDict):
+    nullifier_account: Pubkey
+    logout_epoch: Pubkey
+    nullifier_ban: Pubkey
+
+
+def validate_session_token(
+    accounts: ValidateSessionTokenAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["nullifier_account"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["logout_epoch"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["nullifier_ban"], is_signer=False, is_writable=False
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xad\x1f\xb6\xa9\xbaN\xfci"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
@@ -0,0 +1,29 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from ..program_id import PROGRAM_ID
+
+
+class VerifierHeartbeatAccounts(typing.TypedDict):
+    verifier_weights: Pubkey
+    verifier: Pubkey
+
+
+def verifier_heartbeat(
+    accounts: VerifierHeartbeatAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["verifier_weights"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["verifier"], is_signer=True, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\x19\xee\xdd\x0e\xfa\x94\x00\x8c"
+    encoded_args = b""
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
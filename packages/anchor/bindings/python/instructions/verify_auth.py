@@ -0,0 +1,122 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+import borsh_construct as borsh
+from .. import types
+from ..program_id import PROGRAM_ID
+
+
+class VerifyAuthArgs(typing.TypedDict):
+    result: types.signed_verification_result.SignedVerificationResult
+    nullifier: list[int]
+    domain: list[int]
+    expires_in: int
+
+
+layout = borsh.CStruct(
+    "result" / types.signed_verification_result.SignedVerificationResult.layout,
+    "nullifier" / borsh.U8[32],
+    "domain" / borsh.U8[32],
+    "expires_in" / borsh.I64,
+)
+
+
+class VerifyAuthAccounts(typing.TypedDict):
+    nullifier_account: Pubkey
+    proof_record: Pubkey
+    result_receipt: Pubkey
+    authority: Pubkey
+    verifier: typing.Optional[Pubkey]
+    circuit_registry: Pubkey
+    domain_config: Pubkey
+    nullifier_ban: Pubkey
+    logout_epoch: typing.Optional[Pubkey]
+    verifier_registry: Pubkey
+    secp256r1_verifier_registry: Pubkey
+    assurance_policy: Pubkey
+    payer_rate_limit: Pubkey
+    protocol_stats: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def verify_auth(
+    args: VerifyAuthArgs,
+    accounts: VerifyAuthAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["nullifier_account"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["proof_record"], is_signer=False, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["result_receipt"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        (
+            AccountMeta(pubkey=accounts["verifier"], is_signer=False, is_writable=False)
+            if accounts["verifier"]
+            else AccountMeta(pubkey=program_id, is_signer=False, is_writable=False)
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("Sysvar1nstructions1111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["circuit_registry"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["nullifier_ban"], is_signer=False, is_writable=False
+        ),
+        (
+            AccountMeta(pubkey=accounts["logout_epoch"], is_signer=False, is_writable=False)
+            if accounts["logout_epoch"]
+            else AccountMeta(pubkey=program_id, is_signer=False, is_writable=False)
+        ),
+        AccountMeta(
+            pubkey=accounts["verifier_registry"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["secp256r1_verifier_registry"],
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["assurance_policy"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["payer_rate_limit"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["protocol_stats"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\xb8\xd1\x12\x11B\xf5PL"
+    encoded_args = layout.build(
+        {
+            "result": args["result"].to_encodable(),
+            "nullifier": args["nullifier"],
+            "domain": args["domain"],
+            "expires_in": args["expires_in"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
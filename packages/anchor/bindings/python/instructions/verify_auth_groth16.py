@@ -0,0 +1,84 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from construct import Construct
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class VerifyAuthGroth16Args(typing.TypedDict):
+    circuit_id: int
+    proof: list[int]
+    public_inputs: list[list[int]]
+    nullifier: list[int]
+    domain: list[int]
+
+
+layout = borsh.CStruct(
+    "circuit_id" / borsh.U32,
+    "proof" / borsh.U8[256],
+    "public_inputs" / borsh.Vec(typing.cast(Construct, borsh.U8[32])),
+    "nullifier" / borsh.U8[32],
+    "domain" / borsh.U8[32],
+)
+
+
+class VerifyAuthGroth16Accounts(typing.TypedDict):
+    nullifier_account: Pubkey
+    authority: Pubkey
+    circuit_registry: Pubkey
+    groth16_vk: Pubkey
+    domain_config: Pubkey
+    nullifier_ban: Pubkey
+    payer_rate_limit: Pubkey
+    protocol_stats: Pubkey
+
+
+def verify_auth_groth16(
+    args: VerifyAuthGroth16Args,
+    accounts: VerifyAuthGroth16Accounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(
+            pubkey=accounts["nullifier_account"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=True),
+        AccountMeta(
+            pubkey=accounts["circuit_registry"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["groth16_vk"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["domain_config"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["nullifier_ban"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(
+            pubkey=accounts["payer_rate_limit"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=accounts["protocol_stats"], is_signer=False, is_writable=True
+        ),
+        AccountMeta(
+            pubkey=Pubkey.from_string("11111111111111111111111111111111"),
+            is_signer=False,
+            is_writable=False,
+        ),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\x12\xa7\xaeL\x12\xba\xf3\x02"
+    encoded_args = layout.build(
+        {
+            "circuit_id": args["circuit_id"],
+            "proof": args["proof"],
+            "public_inputs": args["public_inputs"],
+            "nullifier": args["nullifier"],
+            "domain": args["domain"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
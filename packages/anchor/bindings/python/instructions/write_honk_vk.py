@@ -0,0 +1,61 @@
+from __future__ import annotations
+import typing
+from solders.pubkey import Pubkey
+from solders.instruction import Instruction, AccountMeta
+from construct import Construct
+import borsh_construct as borsh
+from ..program_id import PROGRAM_ID
+
+
+class WriteHonkVkArgs(typing.TypedDict):
+    circuit_id: int
+    offset: int
+    commitments: list[list[int]]
+
+
+layout = borsh.CStruct(
+    "circuit_id" / borsh.U32,
+    "offset" / borsh.U8,
+    "commitments" / borsh.Vec(typing.cast(Construct, borsh.U8[64])),
+)
+
+
+class WriteHonkVkAccounts(typing.TypedDict):
+    config: Pubkey
+    circuit_registry: Pubkey
+    honk_vk: Pubkey
+    authority: Pubkey
+    event_authority: Pubkey
+    program: Pubkey
+
+
+def write_honk_vk(
+    args: WriteHonkVkArgs,
+    accounts: WriteHonkVkAccounts,
+    program_id: Pubkey = PROGRAM_ID,
+    remaining_accounts: typing.Optional[typing.List[AccountMeta]] = None,
+) -> Instruction:
+    keys: list[AccountMeta] = [
+        AccountMeta(pubkey=accounts["config"], is_signer=False, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["circuit_registry"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["honk_vk"], is_signer=False, is_writable=True),
+        AccountMeta(pubkey=accounts["authority"], is_signer=True, is_writable=False),
+        AccountMeta(
+            pubkey=accounts["event_authority"], is_signer=False, is_writable=False
+        ),
+        AccountMeta(pubkey=accounts["program"], is_signer=False, is_writable=False),
+    ]
+    if remaining_accounts is not None:
+        keys += remaining_accounts
+    identifier = b"\x80\xc7\xaa\x9cF\xafn!"
+    encoded_args = layout.build(
+        {
+            "circuit_id": args["circuit_id"],
+            "offset": args["offset"],
+            "commitments": args["commitments"],
+        }
+    )
+    data = identifier + encoded_args
+    return Instruction(program_id, data, keys)
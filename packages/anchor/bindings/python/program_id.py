@@ -0,0 +1,3 @@
+from solders.pubkey import Pubkey
+
+PROGRAM_ID = Pubkey.from_string("H6apEGZAw23AKUeqCX41wkDv2LVwX3Ec8oYPip7k3xzA")
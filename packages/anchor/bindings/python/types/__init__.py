@@ -0,0 +1,243 @@
+import typing
+from . import accepted_mint
+from .accepted_mint import AcceptedMint, AcceptedMintJSON
+from . import accepted_mint_updated_event
+from .accepted_mint_updated_event import AcceptedMintUpdatedEvent, AcceptedMintUpdatedEventJSON
+from . import access_log_acknowledged_event
+from .access_log_acknowledged_event import AccessLogAcknowledgedEvent, AccessLogAcknowledgedEventJSON
+from . import access_logs_compacted_event
+from .access_logs_compacted_event import AccessLogsCompactedEvent, AccessLogsCompactedEventJSON
+from . import alias_claimed_event
+from .alias_claimed_event import AliasClaimedEvent, AliasClaimedEventJSON
+from . import assurance_tier_set_event
+from .assurance_tier_set_event import AssuranceTierSetEvent, AssuranceTierSetEventJSON
+from . import attestation_mirrored_event
+from .attestation_mirrored_event import AttestationMirroredEvent, AttestationMirroredEventJSON
+from . import attestation_renewed_event
+from .attestation_renewed_event import AttestationRenewedEvent, AttestationRenewedEventJSON
+from . import attestation_revoked_event
+from .attestation_revoked_event import AttestationRevokedEvent, AttestationRevokedEventJSON
+from . import attestation_stored_event
+from .attestation_stored_event import AttestationStoredEvent, AttestationStoredEventJSON
+from . import attestor_registered_event
+from .attestor_registered_event import AttestorRegisteredEvent, AttestorRegisteredEventJSON
+from . import attestor_status
+from .attestor_status import AttestorStatusKind, AttestorStatusJSON
+from . import attestor_status_set_event
+from .attestor_status_set_event import AttestorStatusSetEvent, AttestorStatusSetEventJSON
+from . import auditor_granted_event
+from .auditor_granted_event import AuditorGrantedEvent, AuditorGrantedEventJSON
+from . import auditor_revoked_event
+from .auditor_revoked_event import AuditorRevokedEvent, AuditorRevokedEventJSON
+from . import auth_verified_event
+from .auth_verified_event import AuthVerifiedEvent, AuthVerifiedEventJSON
+from . import challenge_window_set_event
+from .challenge_window_set_event import ChallengeWindowSetEvent, ChallengeWindowSetEventJSON
+from . import circuit_deprecated_event
+from .circuit_deprecated_event import CircuitDeprecatedEvent, CircuitDeprecatedEventJSON
+from . import circuit_entry
+from .circuit_entry import CircuitEntry, CircuitEntryJSON
+from . import circuit_registered_event
+from .circuit_registered_event import CircuitRegisteredEvent, CircuitRegisteredEventJSON
+from . import claim_type
+from .claim_type import ClaimTypeKind, ClaimTypeJSON
+from . import compressed_nullifier_registered_event
+from .compressed_nullifier_registered_event import CompressedNullifierRegisteredEvent, CompressedNullifierRegisteredEventJSON
+from . import domain_assurance_tier_set_event
+from .domain_assurance_tier_set_event import DomainAssuranceTierSetEvent, DomainAssuranceTierSetEventJSON
+from . import domain_attestor_claims_set_event
+from .domain_attestor_claims_set_event import DomainAttestorClaimsSetEvent, DomainAttestorClaimsSetEventJSON
+from . import domain_config_initialized_event
+from .domain_config_initialized_event import DomainConfigInitializedEvent, DomainConfigInitializedEventJSON
+from . import domain_expiry_unit_set_event
+from .domain_expiry_unit_set_event import DomainExpiryUnitSetEvent, DomainExpiryUnitSetEventJSON
+from . import domain_fee_paid_event
+from .domain_fee_paid_event import DomainFeePaidEvent, DomainFeePaidEventJSON
+from . import domain_fee_share_set_event
+from .domain_fee_share_set_event import DomainFeeShareSetEvent, DomainFeeShareSetEventJSON
+from . import domain_fee_vault_ata_created_event
+from .domain_fee_vault_ata_created_event import DomainFeeVaultAtaCreatedEvent, DomainFeeVaultAtaCreatedEventJSON
+from . import domain_fees_claimed_event
+from .domain_fees_claimed_event import DomainFeesClaimedEvent, DomainFeesClaimedEventJSON
+from . import domain_idle_timeout_set_event
+from .domain_idle_timeout_set_event import DomainIdleTimeoutSetEvent, DomainIdleTimeoutSetEventJSON
+from . import domain_max_result_age_set_event
+from .domain_max_result_age_set_event import DomainMaxResultAgeSetEvent, DomainMaxResultAgeSetEventJSON
+from . import domain_nullifier_store_set_event
+from .domain_nullifier_store_set_event import DomainNullifierStoreSetEvent, DomainNullifierStoreSetEventJSON
+from . import domain_origins_set_event
+from .domain_origins_set_event import DomainOriginsSetEvent, DomainOriginsSetEventJSON
+from . import domain_paused_set_event
+from .domain_paused_set_event import DomainPausedSetEvent, DomainPausedSetEventJSON
+from . import domain_rate_limit_set_event
+from .domain_rate_limit_set_event import DomainRateLimitSetEvent, DomainRateLimitSetEventJSON
+from . import domain_registered_event
+from .domain_registered_event import DomainRegisteredEvent, DomainRegisteredEventJSON
+from . import domain_role
+from .domain_role import DomainRoleKind, DomainRoleJSON
+from . import domain_role_set_event
+from .domain_role_set_event import DomainRoleSetEvent, DomainRoleSetEventJSON
+from . import domain_session_bounds_set_event
+from .domain_session_bounds_set_event import DomainSessionBoundsSetEvent, DomainSessionBoundsSetEventJSON
+from . import domain_verifiers_set_event
+from .domain_verifiers_set_event import DomainVerifiersSetEvent, DomainVerifiersSetEventJSON
+from . import expired_nullifier_closed_event
+from .expired_nullifier_closed_event import ExpiredNullifierClosedEvent, ExpiredNullifierClosedEventJSON
+from . import expiry_kind
+from .expiry_kind import ExpiryKindKind, ExpiryKindJSON
+from . import expiry_unit
+from .expiry_unit import ExpiryUnitKind, ExpiryUnitJSON
+from . import fee_paid_event
+from .fee_paid_event import FeePaidEvent, FeePaidEventJSON
+from . import global_logout_event
+from .global_logout_event import GlobalLogoutEvent, GlobalLogoutEventJSON
+from . import groth16_vk_registered_event
+from .groth16_vk_registered_event import Groth16VkRegisteredEvent, Groth16VkRegisteredEventJSON
+from . import guardians_set_event
+from .guardians_set_event import GuardiansSetEvent, GuardiansSetEventJSON
+from . import honk_vk_registered_event
+from .honk_vk_registered_event import HonkVkRegisteredEvent, HonkVkRegisteredEventJSON
+from . import identity_recovered_event
+from .identity_recovered_event import IdentityRecoveredEvent, IdentityRecoveredEventJSON
+from . import identity_root_created_event
+from .identity_root_created_event import IdentityRootCreatedEvent, IdentityRootCreatedEventJSON
+from . import identity_root_linked_event
+from .identity_root_linked_event import IdentityRootLinkedEvent, IdentityRootLinkedEventJSON
+from . import identity_root_unlinked_event
+from .identity_root_unlinked_event import IdentityRootUnlinkedEvent, IdentityRootUnlinkedEventJSON
+from . import migrated_account
+from .migrated_account import MigratedAccountKind, MigratedAccountJSON
+from . import migration_begun_event
+from .migration_begun_event import MigrationBegunEvent, MigrationBegunEventJSON
+from . import migration_completed_event
+from .migration_completed_event import MigrationCompletedEvent, MigrationCompletedEventJSON
+from . import migration_progress_event
+from .migration_progress_event import MigrationProgressEvent, MigrationProgressEventJSON
+from . import migration_step
+from .migration_step import MigrationStep, MigrationStepJSON
+from . import non_inclusion_proof
+from .non_inclusion_proof import NonInclusionProof, NonInclusionProofJSON
+from . import notification_channel_closed_event
+from .notification_channel_closed_event import NotificationChannelClosedEvent, NotificationChannelClosedEventJSON
+from . import notification_channel_set_event
+from .notification_channel_set_event import NotificationChannelSetEvent, NotificationChannelSetEventJSON
+from . import nullifier_banned_event
+from .nullifier_banned_event import NullifierBannedEvent, NullifierBannedEventJSON
+from . import nullifier_migrated_event
+from .nullifier_migrated_event import NullifierMigratedEvent, NullifierMigratedEventJSON
+from . import nullifier_store
+from .nullifier_store import NullifierStoreKind, NullifierStoreJSON
+from . import nullifier_tree_initialized_event
+from .nullifier_tree_initialized_event import NullifierTreeInitializedEvent, NullifierTreeInitializedEventJSON
+from . import nullifier_unbanned_event
+from .nullifier_unbanned_event import NullifierUnbannedEvent, NullifierUnbannedEventJSON
+from . import permission
+from .permission import PermissionKind, PermissionJSON
+from . import permission_accessed_event
+from .permission_accessed_event import PermissionAccessedEvent, PermissionAccessedEventJSON
+from . import permission_denial_reason
+from .permission_denial_reason import PermissionDenialReasonKind, PermissionDenialReasonJSON
+from . import permission_denied_event
+from .permission_denied_event import PermissionDeniedEvent, PermissionDeniedEventJSON
+from . import permission_granted_event
+from .permission_granted_event import PermissionGrantedEvent, PermissionGrantedEventJSON
+from . import permission_revoked_event
+from .permission_revoked_event import PermissionRevokedEvent, PermissionRevokedEventJSON
+from . import personhood_attestation_archived_event
+from .personhood_attestation_archived_event import PersonhoodAttestationArchivedEvent, PersonhoodAttestationArchivedEventJSON
+from . import personhood_attestation_restored_event
+from .personhood_attestation_restored_event import PersonhoodAttestationRestoredEvent, PersonhoodAttestationRestoredEventJSON
+from . import personhood_attested_event
+from .personhood_attested_event import PersonhoodAttestedEvent, PersonhoodAttestedEventJSON
+from . import personhood_provider_added_event
+from .personhood_provider_added_event import PersonhoodProviderAddedEvent, PersonhoodProviderAddedEventJSON
+from . import personhood_provider_removed_event
+from .personhood_provider_removed_event import PersonhoodProviderRemovedEvent, PersonhoodProviderRemovedEventJSON
+from . import points_root_published_event
+from .points_root_published_event import PointsRootPublishedEvent, PointsRootPublishedEventJSON
+from . import pricing_updated_event
+from .pricing_updated_event import PricingUpdatedEvent, PricingUpdatedEventJSON
+from . import receipt_retention_set_event
+from .receipt_retention_set_event import ReceiptRetentionSetEvent, ReceiptRetentionSetEventJSON
+from . import relayer_quota_set_event
+from .relayer_quota_set_event import RelayerQuotaSetEvent, RelayerQuotaSetEventJSON
+from . import rent_topped_up_event
+from .rent_topped_up_event import RentToppedUpEvent, RentToppedUpEventJSON
+from . import result_receipt_closed_event
+from .result_receipt_closed_event import ResultReceiptClosedEvent, ResultReceiptClosedEventJSON
+from . import reveal_escrow_cancelled_event
+from .reveal_escrow_cancelled_event import RevealEscrowCancelledEvent, RevealEscrowCancelledEventJSON
+from . import reveal_escrow_opened_event
+from .reveal_escrow_opened_event import RevealEscrowOpenedEvent, RevealEscrowOpenedEventJSON
+from . import reveal_escrow_released_event
+from .reveal_escrow_released_event import RevealEscrowReleasedEvent, RevealEscrowReleasedEventJSON
+from . import root_member
+from .root_member import RootMember, RootMemberJSON
+from . import sas_bridge_set_event
+from .sas_bridge_set_event import SasBridgeSetEvent, SasBridgeSetEventJSON
+from . import secp256r1_verifier_added_event
+from .secp256r1_verifier_added_event import Secp256r1VerifierAddedEvent, Secp256r1VerifierAddedEventJSON
+from . import secp256r1_verifier_removed_event
+from .secp256r1_verifier_removed_event import Secp256r1VerifierRemovedEvent, Secp256r1VerifierRemovedEventJSON
+from . import session_closed_event
+from .session_closed_event import SessionClosedEvent, SessionClosedEventJSON
+from . import session_delegate_revoked_event
+from .session_delegate_revoked_event import SessionDelegateRevokedEvent, SessionDelegateRevokedEventJSON
+from . import session_delegated_event
+from .session_delegated_event import SessionDelegatedEvent, SessionDelegatedEventJSON
+from . import session_details
+from .session_details import SessionDetails, SessionDetailsJSON
+from . import session_renewed_event
+from .session_renewed_event import SessionRenewedEvent, SessionRenewedEventJSON
+from . import sharded_nullifier_registered_event
+from .sharded_nullifier_registered_event import ShardedNullifierRegisteredEvent, ShardedNullifierRegisteredEventJSON
+from . import signed_verification_result
+from .signed_verification_result import SignedVerificationResult, SignedVerificationResultJSON
+from . import treasury_ata_created_event
+from .treasury_ata_created_event import TreasuryAtaCreatedEvent, TreasuryAtaCreatedEventJSON
+from . import treasury_swept_event
+from .treasury_swept_event import TreasurySweptEvent, TreasurySweptEventJSON
+from . import usage_points_claimed_event
+from .usage_points_claimed_event import UsagePointsClaimedEvent, UsagePointsClaimedEventJSON
+from . import user_consent
+from .user_consent import UserConsent, UserConsentJSON
+from . import username_claimed_event
+from .username_claimed_event import UsernameClaimedEvent, UsernameClaimedEventJSON
+from . import username_released_event
+from .username_released_event import UsernameReleasedEvent, UsernameReleasedEventJSON
+from . import username_transferred_event
+from .username_transferred_event import UsernameTransferredEvent, UsernameTransferredEventJSON
+from . import vc_commitment_issued_event
+from .vc_commitment_issued_event import VcCommitmentIssuedEvent, VcCommitmentIssuedEventJSON
+from . import vc_status
+from .vc_status import VcStatusKind, VcStatusJSON
+from . import vc_status_changed_event
+from .vc_status_changed_event import VcStatusChangedEvent, VcStatusChangedEventJSON
+from . import verification_challenged_event
+from .verification_challenged_event import VerificationChallengedEvent, VerificationChallengedEventJSON
+from . import verification_session_opened_event
+from .verification_session_opened_event import VerificationSessionOpenedEvent, VerificationSessionOpenedEventJSON
+from . import verification_session_resolved_event
+from .verification_session_resolved_event import VerificationSessionResolvedEvent, VerificationSessionResolvedEventJSON
+from . import verification_status
+from .verification_status import VerificationStatusKind, VerificationStatusJSON
+from . import verifier_added_event
+from .verifier_added_event import VerifierAddedEvent, VerifierAddedEventJSON
+from . import verifier_grace_period_set_event
+from .verifier_grace_period_set_event import VerifierGracePeriodSetEvent, VerifierGracePeriodSetEventJSON
+from . import verifier_key_rotated_event
+from .verifier_key_rotated_event import VerifierKeyRotatedEvent, VerifierKeyRotatedEventJSON
+from . import verifier_removed_event
+from .verifier_removed_event import VerifierRemovedEvent, VerifierRemovedEventJSON
+from . import verifier_rotation
+from .verifier_rotation import VerifierRotation, VerifierRotationJSON
+from . import verifier_threshold_set_event
+from .verifier_threshold_set_event import VerifierThresholdSetEvent, VerifierThresholdSetEventJSON
+from . import verifier_weight
+from .verifier_weight import VerifierWeight, VerifierWeightJSON
+from . import verifier_weight_set_event
+from .verifier_weight_set_event import VerifierWeightSetEvent, VerifierWeightSetEventJSON
+from . import verify_auth_entry
+from .verify_auth_entry import VerifyAuthEntry, VerifyAuthEntryJSON
+from . import vk_hash_pinned_event
+from .vk_hash_pinned_event import VkHashPinnedEvent, VkHashPinnedEventJSON
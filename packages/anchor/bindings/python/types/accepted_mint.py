@@ -0,0 +1,48 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class AcceptedMintJSON(typing.TypedDict):
+    mint: str
+    price_feed_id: list[int]
+
+
+@dataclass
+class AcceptedMint:
+    layout: typing.ClassVar = borsh.CStruct(
+        "mint" / BorshPubkey,
+        "price_feed_id" / borsh.U8[32],
+    )
+    mint: Pubkey
+    price_feed_id: list[int]
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "AcceptedMint":
+        return cls(
+            mint=obj.mint,
+            price_feed_id=obj.price_feed_id,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "mint": self.mint,
+            "price_feed_id": self.price_feed_id,
+        }
+
+    def to_json(self) -> AcceptedMintJSON:
+        return {
+            "mint": str(self.mint),
+            "price_feed_id": self.price_feed_id,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AcceptedMintJSON) -> "AcceptedMint":
+        return cls(
+            mint=Pubkey.from_string(obj["mint"]),
+            price_feed_id=obj["price_feed_id"],
+        )
@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class AcceptedMintUpdatedEventJSON(typing.TypedDict):
+    mint: str
+    price_feed_id: list[int]
+    accepted: bool
+
+
+@dataclass
+class AcceptedMintUpdatedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "mint" / BorshPubkey,
+        "price_feed_id" / borsh.U8[32],
+        "accepted" / borsh.Bool,
+    )
+    mint: Pubkey
+    price_feed_id: list[int]
+    accepted: bool
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "AcceptedMintUpdatedEvent":
+        return cls(
+            mint=obj.mint,
+            price_feed_id=obj.price_feed_id,
+            accepted=obj.accepted,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "mint": self.mint,
+            "price_feed_id": self.price_feed_id,
+            "accepted": self.accepted,
+        }
+
+    def to_json(self) -> AcceptedMintUpdatedEventJSON:
+        return {
+            "mint": str(self.mint),
+            "price_feed_id": self.price_feed_id,
+            "accepted": self.accepted,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AcceptedMintUpdatedEventJSON) -> "AcceptedMintUpdatedEvent":
+        return cls(
+            mint=Pubkey.from_string(obj["mint"]),
+            price_feed_id=obj["price_feed_id"],
+            accepted=obj["accepted"],
+        )
@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class AccessLogAcknowledgedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    auditor: str
+    permission_access: str
+    acknowledged_at: int
+
+
+@dataclass
+class AccessLogAcknowledgedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "auditor" / BorshPubkey,
+        "permission_access" / BorshPubkey,
+        "acknowledged_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    auditor: Pubkey
+    permission_access: Pubkey
+    acknowledged_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "AccessLogAcknowledgedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            auditor=obj.auditor,
+            permission_access=obj.permission_access,
+            acknowledged_at=obj.acknowledged_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "auditor": self.auditor,
+            "permission_access": self.permission_access,
+            "acknowledged_at": self.acknowledged_at,
+        }
+
+    def to_json(self) -> AccessLogAcknowledgedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "auditor": str(self.auditor),
+            "permission_access": str(self.permission_access),
+            "acknowledged_at": self.acknowledged_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AccessLogAcknowledgedEventJSON) -> "AccessLogAcknowledgedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            auditor=Pubkey.from_string(obj["auditor"]),
+            permission_access=Pubkey.from_string(obj["permission_access"]),
+            acknowledged_at=obj["acknowledged_at"],
+        )
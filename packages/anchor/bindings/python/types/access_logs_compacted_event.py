@@ -0,0 +1,79 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from construct import Construct
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class AccessLogsCompactedEventJSON(typing.TypedDict):
+    permission_grant: str
+    access_logs: list[str]
+    batch_root: list[int]
+    summary_root: list[int]
+    compacted_count: int
+    reward: int
+
+
+@dataclass
+class AccessLogsCompactedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "permission_grant" / BorshPubkey,
+        "access_logs" / borsh.Vec(typing.cast(Construct, BorshPubkey)),
+        "batch_root" / borsh.U8[32],
+        "summary_root" / borsh.U8[32],
+        "compacted_count" / borsh.U64,
+        "reward" / borsh.U64,
+    )
+    permission_grant: Pubkey
+    access_logs: list[Pubkey]
+    batch_root: list[int]
+    summary_root: list[int]
+    compacted_count: int
+    reward: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "AccessLogsCompactedEvent":
+        return cls(
+            permission_grant=obj.permission_grant,
+            access_logs=obj.access_logs,
+            batch_root=obj.batch_root,
+            summary_root=obj.summary_root,
+            compacted_count=obj.compacted_count,
+            reward=obj.reward,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "permission_grant": self.permission_grant,
+            "access_logs": self.access_logs,
+            "batch_root": self.batch_root,
+            "summary_root": self.summary_root,
+            "compacted_count": self.compacted_count,
+            "reward": self.reward,
+        }
+
+    def to_json(self) -> AccessLogsCompactedEventJSON:
+        return {
+            "permission_grant": str(self.permission_grant),
+            "access_logs": list(map(lambda item: str(item), self.access_logs)),
+            "batch_root": self.batch_root,
+            "summary_root": self.summary_root,
+            "compacted_count": self.compacted_count,
+            "reward": self.reward,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AccessLogsCompactedEventJSON) -> "AccessLogsCompactedEvent":
+        return cls(
+            permission_grant=Pubkey.from_string(obj["permission_grant"]),
+            access_logs=list(
+                map(lambda item: Pubkey.from_string(item), obj["access_logs"])
+            ),
+            batch_root=obj["batch_root"],
+            summary_root=obj["summary_root"],
+            compacted_count=obj["compacted_count"],
+            reward=obj["reward"],
+        )
@@ -0,0 +1,60 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class AliasClaimedEventJSON(typing.TypedDict):
+    handle_hash: list[int]
+    nullifier: list[int]
+    domain: list[int]
+    claimed_at: int
+
+
+@dataclass
+class AliasClaimedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "handle_hash" / borsh.U8[32],
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "claimed_at" / borsh.I64,
+    )
+    handle_hash: list[int]
+    nullifier: list[int]
+    domain: list[int]
+    claimed_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "AliasClaimedEvent":
+        return cls(
+            handle_hash=obj.handle_hash,
+            nullifier=obj.nullifier,
+            domain=obj.domain,
+            claimed_at=obj.claimed_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "handle_hash": self.handle_hash,
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "claimed_at": self.claimed_at,
+        }
+
+    def to_json(self) -> AliasClaimedEventJSON:
+        return {
+            "handle_hash": self.handle_hash,
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "claimed_at": self.claimed_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AliasClaimedEventJSON) -> "AliasClaimedEvent":
+        return cls(
+            handle_hash=obj["handle_hash"],
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+            claimed_at=obj["claimed_at"],
+        )
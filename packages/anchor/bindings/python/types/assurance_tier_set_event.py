@@ -0,0 +1,46 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class AssuranceTierSetEventJSON(typing.TypedDict):
+    tier: int
+    max_result_age_secs: int
+
+
+@dataclass
+class AssuranceTierSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "tier" / borsh.U8,
+        "max_result_age_secs" / borsh.U32,
+    )
+    tier: int
+    max_result_age_secs: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "AssuranceTierSetEvent":
+        return cls(
+            tier=obj.tier,
+            max_result_age_secs=obj.max_result_age_secs,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "tier": self.tier,
+            "max_result_age_secs": self.max_result_age_secs,
+        }
+
+    def to_json(self) -> AssuranceTierSetEventJSON:
+        return {
+            "tier": self.tier,
+            "max_result_age_secs": self.max_result_age_secs,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AssuranceTierSetEventJSON) -> "AssuranceTierSetEvent":
+        return cls(
+            tier=obj["tier"],
+            max_result_age_secs=obj["max_result_age_secs"],
+        )
@@ -0,0 +1,72 @@
+from . import (
+    claim_type,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class AttestationMirroredEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    claim_type: claim_type.ClaimTypeJSON
+    domain: list[int]
+    sas_attestation: str
+    expires_at: int
+
+
+@dataclass
+class AttestationMirroredEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "claim_type" / claim_type.layout,
+        "domain" / borsh.U8[32],
+        "sas_attestation" / BorshPubkey,
+        "expires_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    claim_type: claim_type.ClaimTypeKind
+    domain: list[int]
+    sas_attestation: Pubkey
+    expires_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "AttestationMirroredEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            claim_type=claim_type.from_decoded(obj.claim_type),
+            domain=obj.domain,
+            sas_attestation=obj.sas_attestation,
+            expires_at=obj.expires_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "claim_type": self.claim_type.to_encodable(),
+            "domain": self.domain,
+            "sas_attestation": self.sas_attestation,
+            "expires_at": self.expires_at,
+        }
+
+    def to_json(self) -> AttestationMirroredEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "claim_type": self.claim_type.to_json(),
+            "domain": self.domain,
+            "sas_attestation": str(self.sas_attestation),
+            "expires_at": self.expires_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AttestationMirroredEventJSON) -> "AttestationMirroredEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            claim_type=claim_type.from_json(obj["claim_type"]),
+            domain=obj["domain"],
+            sas_attestation=Pubkey.from_string(obj["sas_attestation"]),
+            expires_at=obj["expires_at"],
+        )
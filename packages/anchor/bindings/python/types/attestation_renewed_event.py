@@ -0,0 +1,79 @@
+from . import (
+    claim_type,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class AttestationRenewedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    claim_type: claim_type.ClaimTypeJSON
+    domain: list[int]
+    claim_data_hash: list[int]
+    issuer: str
+    expires_at: int
+
+
+@dataclass
+class AttestationRenewedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "claim_type" / claim_type.layout,
+        "domain" / borsh.U8[32],
+        "claim_data_hash" / borsh.U8[32],
+        "issuer" / BorshPubkey,
+        "expires_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    claim_type: claim_type.ClaimTypeKind
+    domain: list[int]
+    claim_data_hash: list[int]
+    issuer: Pubkey
+    expires_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "AttestationRenewedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            claim_type=claim_type.from_decoded(obj.claim_type),
+            domain=obj.domain,
+            claim_data_hash=obj.claim_data_hash,
+            issuer=obj.issuer,
+            expires_at=obj.expires_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "claim_type": self.claim_type.to_encodable(),
+            "domain": self.domain,
+            "claim_data_hash": self.claim_data_hash,
+            "issuer": self.issuer,
+            "expires_at": self.expires_at,
+        }
+
+    def to_json(self) -> AttestationRenewedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "claim_type": self.claim_type.to_json(),
+            "domain": self.domain,
+            "claim_data_hash": self.claim_data_hash,
+            "issuer": str(self.issuer),
+            "expires_at": self.expires_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AttestationRenewedEventJSON) -> "AttestationRenewedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            claim_type=claim_type.from_json(obj["claim_type"]),
+            domain=obj["domain"],
+            claim_data_hash=obj["claim_data_hash"],
+            issuer=Pubkey.from_string(obj["issuer"]),
+            expires_at=obj["expires_at"],
+        )
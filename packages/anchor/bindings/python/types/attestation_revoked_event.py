@@ -0,0 +1,72 @@
+from . import (
+    claim_type,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class AttestationRevokedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    claim_type: claim_type.ClaimTypeJSON
+    domain: list[int]
+    issuer: str
+    revoked_at: int
+
+
+@dataclass
+class AttestationRevokedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "claim_type" / claim_type.layout,
+        "domain" / borsh.U8[32],
+        "issuer" / BorshPubkey,
+        "revoked_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    claim_type: claim_type.ClaimTypeKind
+    domain: list[int]
+    issuer: Pubkey
+    revoked_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "AttestationRevokedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            claim_type=claim_type.from_decoded(obj.claim_type),
+            domain=obj.domain,
+            issuer=obj.issuer,
+            revoked_at=obj.revoked_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "claim_type": self.claim_type.to_encodable(),
+            "domain": self.domain,
+            "issuer": self.issuer,
+            "revoked_at": self.revoked_at,
+        }
+
+    def to_json(self) -> AttestationRevokedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "claim_type": self.claim_type.to_json(),
+            "domain": self.domain,
+            "issuer": str(self.issuer),
+            "revoked_at": self.revoked_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AttestationRevokedEventJSON) -> "AttestationRevokedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            claim_type=claim_type.from_json(obj["claim_type"]),
+            domain=obj["domain"],
+            issuer=Pubkey.from_string(obj["issuer"]),
+            revoked_at=obj["revoked_at"],
+        )
@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class AttestorRegisteredEventJSON(typing.TypedDict):
+    issuer: str
+    name_hash: list[int]
+    claim_mask: int
+
+
+@dataclass
+class AttestorRegisteredEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "issuer" / BorshPubkey,
+        "name_hash" / borsh.U8[32],
+        "claim_mask" / borsh.U8,
+    )
+    issuer: Pubkey
+    name_hash: list[int]
+    claim_mask: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "AttestorRegisteredEvent":
+        return cls(
+            issuer=obj.issuer,
+            name_hash=obj.name_hash,
+            claim_mask=obj.claim_mask,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "issuer": self.issuer,
+            "name_hash": self.name_hash,
+            "claim_mask": self.claim_mask,
+        }
+
+    def to_json(self) -> AttestorRegisteredEventJSON:
+        return {
+            "issuer": str(self.issuer),
+            "name_hash": self.name_hash,
+            "claim_mask": self.claim_mask,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AttestorRegisteredEventJSON) -> "AttestorRegisteredEvent":
+        return cls(
+            issuer=Pubkey.from_string(obj["issuer"]),
+            name_hash=obj["name_hash"],
+            claim_mask=obj["claim_mask"],
+        )
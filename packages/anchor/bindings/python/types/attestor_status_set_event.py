@@ -0,0 +1,51 @@
+from . import (
+    attestor_status,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class AttestorStatusSetEventJSON(typing.TypedDict):
+    issuer: str
+    status: attestor_status.AttestorStatusJSON
+
+
+@dataclass
+class AttestorStatusSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "issuer" / BorshPubkey,
+        "status" / attestor_status.layout,
+    )
+    issuer: Pubkey
+    status: attestor_status.AttestorStatusKind
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "AttestorStatusSetEvent":
+        return cls(
+            issuer=obj.issuer,
+            status=attestor_status.from_decoded(obj.status),
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "issuer": self.issuer,
+            "status": self.status.to_encodable(),
+        }
+
+    def to_json(self) -> AttestorStatusSetEventJSON:
+        return {
+            "issuer": str(self.issuer),
+            "status": self.status.to_json(),
+        }
+
+    @classmethod
+    def from_json(cls, obj: AttestorStatusSetEventJSON) -> "AttestorStatusSetEvent":
+        return cls(
+            issuer=Pubkey.from_string(obj["issuer"]),
+            status=attestor_status.from_json(obj["status"]),
+        )
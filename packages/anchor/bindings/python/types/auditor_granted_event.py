@@ -0,0 +1,72 @@
+from . import (
+    expiry_kind,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class AuditorGrantedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    auditor: str
+    name_hash: list[int]
+    granted_at: int
+    expires_at: expiry_kind.ExpiryKindJSON
+
+
+@dataclass
+class AuditorGrantedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "auditor" / BorshPubkey,
+        "name_hash" / borsh.U8[32],
+        "granted_at" / borsh.I64,
+        "expires_at" / expiry_kind.layout,
+    )
+    nullifier: list[int]
+    auditor: Pubkey
+    name_hash: list[int]
+    granted_at: int
+    expires_at: expiry_kind.ExpiryKindKind
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "AuditorGrantedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            auditor=obj.auditor,
+            name_hash=obj.name_hash,
+            granted_at=obj.granted_at,
+            expires_at=expiry_kind.from_decoded(obj.expires_at),
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "auditor": self.auditor,
+            "name_hash": self.name_hash,
+            "granted_at": self.granted_at,
+            "expires_at": self.expires_at.to_encodable(),
+        }
+
+    def to_json(self) -> AuditorGrantedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "auditor": str(self.auditor),
+            "name_hash": self.name_hash,
+            "granted_at": self.granted_at,
+            "expires_at": self.expires_at.to_json(),
+        }
+
+    @classmethod
+    def from_json(cls, obj: AuditorGrantedEventJSON) -> "AuditorGrantedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            auditor=Pubkey.from_string(obj["auditor"]),
+            name_hash=obj["name_hash"],
+            granted_at=obj["granted_at"],
+            expires_at=expiry_kind.from_json(obj["expires_at"]),
+        )
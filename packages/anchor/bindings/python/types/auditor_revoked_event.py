@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class AuditorRevokedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    auditor: str
+    revoked_at: int
+
+
+@dataclass
+class AuditorRevokedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "auditor" / BorshPubkey,
+        "revoked_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    auditor: Pubkey
+    revoked_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "AuditorRevokedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            auditor=obj.auditor,
+            revoked_at=obj.revoked_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "auditor": self.auditor,
+            "revoked_at": self.revoked_at,
+        }
+
+    def to_json(self) -> AuditorRevokedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "auditor": str(self.auditor),
+            "revoked_at": self.revoked_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: AuditorRevokedEventJSON) -> "AuditorRevokedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            auditor=Pubkey.from_string(obj["auditor"]),
+            revoked_at=obj["revoked_at"],
+        )
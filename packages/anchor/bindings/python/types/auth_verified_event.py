@@ -0,0 +1,63 @@
+from . import (
+    expiry_kind,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class AuthVerifiedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    domain: list[int]
+    proof_hash: list[int]
+    expires_at: expiry_kind.ExpiryKindJSON
+
+
+@dataclass
+class AuthVerifiedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "proof_hash" / borsh.U8[32],
+        "expires_at" / expiry_kind.layout,
+    )
+    nullifier: list[int]
+    domain: list[int]
+    proof_hash: list[int]
+    expires_at: expiry_kind.ExpiryKindKind
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "AuthVerifiedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            domain=obj.domain,
+            proof_hash=obj.proof_hash,
+            expires_at=expiry_kind.from_decoded(obj.expires_at),
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "proof_hash": self.proof_hash,
+            "expires_at": self.expires_at.to_encodable(),
+        }
+
+    def to_json(self) -> AuthVerifiedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "proof_hash": self.proof_hash,
+            "expires_at": self.expires_at.to_json(),
+        }
+
+    @classmethod
+    def from_json(cls, obj: AuthVerifiedEventJSON) -> "AuthVerifiedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+            proof_hash=obj["proof_hash"],
+            expires_at=expiry_kind.from_json(obj["expires_at"]),
+        )
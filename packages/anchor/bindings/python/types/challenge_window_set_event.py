@@ -0,0 +1,46 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class ChallengeWindowSetEventJSON(typing.TypedDict):
+    domain: list[int]
+    challenge_window_secs: int
+
+
+@dataclass
+class ChallengeWindowSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "challenge_window_secs" / borsh.I64,
+    )
+    domain: list[int]
+    challenge_window_secs: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "ChallengeWindowSetEvent":
+        return cls(
+            domain=obj.domain,
+            challenge_window_secs=obj.challenge_window_secs,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "challenge_window_secs": self.challenge_window_secs,
+        }
+
+    def to_json(self) -> ChallengeWindowSetEventJSON:
+        return {
+            "domain": self.domain,
+            "challenge_window_secs": self.challenge_window_secs,
+        }
+
+    @classmethod
+    def from_json(cls, obj: ChallengeWindowSetEventJSON) -> "ChallengeWindowSetEvent":
+        return cls(
+            domain=obj["domain"],
+            challenge_window_secs=obj["challenge_window_secs"],
+        )
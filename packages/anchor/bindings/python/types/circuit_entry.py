@@ -0,0 +1,53 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class CircuitEntryJSON(typing.TypedDict):
+    circuit_id: int
+    vk_hash: list[int]
+    deprecated: bool
+
+
+@dataclass
+class CircuitEntry:
+    layout: typing.ClassVar = borsh.CStruct(
+        "circuit_id" / borsh.U32,
+        "vk_hash" / borsh.U8[32],
+        "deprecated" / borsh.Bool,
+    )
+    circuit_id: int
+    vk_hash: list[int]
+    deprecated: bool
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "CircuitEntry":
+        return cls(
+            circuit_id=obj.circuit_id,
+            vk_hash=obj.vk_hash,
+            deprecated=obj.deprecated,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "circuit_id": self.circuit_id,
+            "vk_hash": self.vk_hash,
+            "deprecated": self.deprecated,
+        }
+
+    def to_json(self) -> CircuitEntryJSON:
+        return {
+            "circuit_id": self.circuit_id,
+            "vk_hash": self.vk_hash,
+            "deprecated": self.deprecated,
+        }
+
+    @classmethod
+    def from_json(cls, obj: CircuitEntryJSON) -> "CircuitEntry":
+        return cls(
+            circuit_id=obj["circuit_id"],
+            vk_hash=obj["vk_hash"],
+            deprecated=obj["deprecated"],
+        )
@@ -0,0 +1,46 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class CircuitRegisteredEventJSON(typing.TypedDict):
+    circuit_id: int
+    vk_hash: list[int]
+
+
+@dataclass
+class CircuitRegisteredEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "circuit_id" / borsh.U32,
+        "vk_hash" / borsh.U8[32],
+    )
+    circuit_id: int
+    vk_hash: list[int]
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "CircuitRegisteredEvent":
+        return cls(
+            circuit_id=obj.circuit_id,
+            vk_hash=obj.vk_hash,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "circuit_id": self.circuit_id,
+            "vk_hash": self.vk_hash,
+        }
+
+    def to_json(self) -> CircuitRegisteredEventJSON:
+        return {
+            "circuit_id": self.circuit_id,
+            "vk_hash": self.vk_hash,
+        }
+
+    @classmethod
+    def from_json(cls, obj: CircuitRegisteredEventJSON) -> "CircuitRegisteredEvent":
+        return cls(
+            circuit_id=obj["circuit_id"],
+            vk_hash=obj["vk_hash"],
+        )
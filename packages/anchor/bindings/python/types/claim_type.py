@@ -0,0 +1,142 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from anchorpy.borsh_extension import EnumForCodegen
+import borsh_construct as borsh
+
+
+class AuthJSON(typing.TypedDict):
+    kind: typing.Literal["Auth"]
+
+
+@dataclass
+class Auth:
+    discriminator: typing.ClassVar = 0
+    kind: typing.ClassVar = "Auth"
+
+    @classmethod
+    def to_json(cls) -> AuthJSON:
+        return AuthJSON(
+            kind="Auth",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Auth": {},
+        }
+
+
+class BalanceThresholdJSON(typing.TypedDict):
+    kind: typing.Literal["BalanceThreshold"]
+
+
+@dataclass
+class BalanceThreshold:
+    discriminator: typing.ClassVar = 1
+    kind: typing.ClassVar = "BalanceThreshold"
+
+    @classmethod
+    def to_json(cls) -> BalanceThresholdJSON:
+        return BalanceThresholdJSON(
+            kind="BalanceThreshold",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "BalanceThreshold": {},
+        }
+
+
+class AgeOverJSON(typing.TypedDict):
+    kind: typing.Literal["AgeOver"]
+
+
+@dataclass
+class AgeOver:
+    discriminator: typing.ClassVar = 2
+    kind: typing.ClassVar = "AgeOver"
+
+    @classmethod
+    def to_json(cls) -> AgeOverJSON:
+        return AgeOverJSON(
+            kind="AgeOver",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "AgeOver": {},
+        }
+
+
+class NftOwnershipJSON(typing.TypedDict):
+    kind: typing.Literal["NftOwnership"]
+
+
+@dataclass
+class NftOwnership:
+    discriminator: typing.ClassVar = 3
+    kind: typing.ClassVar = "NftOwnership"
+
+    @classmethod
+    def to_json(cls) -> NftOwnershipJSON:
+        return NftOwnershipJSON(
+            kind="NftOwnership",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "NftOwnership": {},
+        }
+
+
+ClaimTypeKind = typing.Union[
+    Auth,
+    BalanceThreshold,
+    AgeOver,
+    NftOwnership,
+]
+ClaimTypeJSON = typing.Union[
+    AuthJSON,
+    BalanceThresholdJSON,
+    AgeOverJSON,
+    NftOwnershipJSON,
+]
+
+
+def from_decoded(obj: dict) -> ClaimTypeKind:
+    if not isinstance(obj, dict):
+        raise ValueError("Invalid enum object")
+    if "Auth" in obj:
+        return Auth()
+    if "BalanceThreshold" in obj:
+        return BalanceThreshold()
+    if "AgeOver" in obj:
+        return AgeOver()
+    if "NftOwnership" in obj:
+        return NftOwnership()
+    raise ValueError("Invalid enum object")
+
+
+def from_json(obj: ClaimTypeJSON) -> ClaimTypeKind:
+    if obj["kind"] == "Auth":
+        return Auth()
+    if obj["kind"] == "BalanceThreshold":
+        return BalanceThreshold()
+    if obj["kind"] == "AgeOver":
+        return AgeOver()
+    if obj["kind"] == "NftOwnership":
+        return NftOwnership()
+    kind = obj["kind"]
+    raise ValueError(f"Unrecognized enum kind: {kind}")
+
+
+layout = EnumForCodegen(
+    "Auth" / borsh.CStruct(),
+    "BalanceThreshold" / borsh.CStruct(),
+    "AgeOver" / borsh.CStruct(),
+    "NftOwnership" / borsh.CStruct(),
+)
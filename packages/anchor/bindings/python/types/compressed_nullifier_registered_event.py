@@ -0,0 +1,88 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class CompressedNullifierRegisteredEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    domain: list[int]
+    proof_hash: list[int]
+    leaf_index: int
+    low_index: int
+    next_index: int
+    next_value: list[int]
+    registered_at: int
+
+
+@dataclass
+class CompressedNullifierRegisteredEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "proof_hash" / borsh.U8[32],
+        "leaf_index" / borsh.U32,
+        "low_index" / borsh.U32,
+        "next_index" / borsh.U32,
+        "next_value" / borsh.U8[32],
+        "registered_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    domain: list[int]
+    proof_hash: list[int]
+    leaf_index: int
+    low_index: int
+    next_index: int
+    next_value: list[int]
+    registered_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "CompressedNullifierRegisteredEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            domain=obj.domain,
+            proof_hash=obj.proof_hash,
+            leaf_index=obj.leaf_index,
+            low_index=obj.low_index,
+            next_index=obj.next_index,
+            next_value=obj.next_value,
+            registered_at=obj.registered_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "proof_hash": self.proof_hash,
+            "leaf_index": self.leaf_index,
+            "low_index": self.low_index,
+            "next_index": self.next_index,
+            "next_value": self.next_value,
+            "registered_at": self.registered_at,
+        }
+
+    def to_json(self) -> CompressedNullifierRegisteredEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "proof_hash": self.proof_hash,
+            "leaf_index": self.leaf_index,
+            "low_index": self.low_index,
+            "next_index": self.next_index,
+            "next_value": self.next_value,
+            "registered_at": self.registered_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: CompressedNullifierRegisteredEventJSON) -> "CompressedNullifierRegisteredEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+            proof_hash=obj["proof_hash"],
+            leaf_index=obj["leaf_index"],
+            low_index=obj["low_index"],
+            next_index=obj["next_index"],
+            next_value=obj["next_value"],
+            registered_at=obj["registered_at"],
+        )
@@ -0,0 +1,46 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class DomainAssuranceTierSetEventJSON(typing.TypedDict):
+    domain: list[int]
+    assurance_tier: int
+
+
+@dataclass
+class DomainAssuranceTierSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "assurance_tier" / borsh.U8,
+    )
+    domain: list[int]
+    assurance_tier: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainAssuranceTierSetEvent":
+        return cls(
+            domain=obj.domain,
+            assurance_tier=obj.assurance_tier,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "assurance_tier": self.assurance_tier,
+        }
+
+    def to_json(self) -> DomainAssuranceTierSetEventJSON:
+        return {
+            "domain": self.domain,
+            "assurance_tier": self.assurance_tier,
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainAssuranceTierSetEventJSON) -> "DomainAssuranceTierSetEvent":
+        return cls(
+            domain=obj["domain"],
+            assurance_tier=obj["assurance_tier"],
+        )
@@ -0,0 +1,46 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class DomainAttestorClaimsSetEventJSON(typing.TypedDict):
+    domain: list[int]
+    claim_mask: int
+
+
+@dataclass
+class DomainAttestorClaimsSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "claim_mask" / borsh.U8,
+    )
+    domain: list[int]
+    claim_mask: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainAttestorClaimsSetEvent":
+        return cls(
+            domain=obj.domain,
+            claim_mask=obj.claim_mask,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "claim_mask": self.claim_mask,
+        }
+
+    def to_json(self) -> DomainAttestorClaimsSetEventJSON:
+        return {
+            "domain": self.domain,
+            "claim_mask": self.claim_mask,
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainAttestorClaimsSetEventJSON) -> "DomainAttestorClaimsSetEvent":
+        return cls(
+            domain=obj["domain"],
+            claim_mask=obj["claim_mask"],
+        )
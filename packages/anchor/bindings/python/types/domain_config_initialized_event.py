@@ -0,0 +1,48 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class DomainConfigInitializedEventJSON(typing.TypedDict):
+    domain: list[int]
+    owner: str
+
+
+@dataclass
+class DomainConfigInitializedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "owner" / BorshPubkey,
+    )
+    domain: list[int]
+    owner: Pubkey
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainConfigInitializedEvent":
+        return cls(
+            domain=obj.domain,
+            owner=obj.owner,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "owner": self.owner,
+        }
+
+    def to_json(self) -> DomainConfigInitializedEventJSON:
+        return {
+            "domain": self.domain,
+            "owner": str(self.owner),
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainConfigInitializedEventJSON) -> "DomainConfigInitializedEvent":
+        return cls(
+            domain=obj["domain"],
+            owner=Pubkey.from_string(obj["owner"]),
+        )
@@ -0,0 +1,49 @@
+from . import (
+    expiry_unit,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class DomainExpiryUnitSetEventJSON(typing.TypedDict):
+    domain: list[int]
+    session_expiry_unit: expiry_unit.ExpiryUnitJSON
+
+
+@dataclass
+class DomainExpiryUnitSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "session_expiry_unit" / expiry_unit.layout,
+    )
+    domain: list[int]
+    session_expiry_unit: expiry_unit.ExpiryUnitKind
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainExpiryUnitSetEvent":
+        return cls(
+            domain=obj.domain,
+            session_expiry_unit=expiry_unit.from_decoded(obj.session_expiry_unit),
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "session_expiry_unit": self.session_expiry_unit.to_encodable(),
+        }
+
+    def to_json(self) -> DomainExpiryUnitSetEventJSON:
+        return {
+            "domain": self.domain,
+            "session_expiry_unit": self.session_expiry_unit.to_json(),
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainExpiryUnitSetEventJSON) -> "DomainExpiryUnitSetEvent":
+        return cls(
+            domain=obj["domain"],
+            session_expiry_unit=expiry_unit.from_json(obj["session_expiry_unit"]),
+        )
@@ -0,0 +1,97 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class DomainFeePaidEventJSON(typing.TypedDict):
+    payer: str
+    domain: list[int]
+    mint: str
+    fee_usd_micros: int
+    amount: int
+    domain_amount: int
+    price: int
+    exponent: int
+    publish_time: int
+
+
+@dataclass
+class DomainFeePaidEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "payer" / BorshPubkey,
+        "domain" / borsh.U8[32],
+        "mint" / BorshPubkey,
+        "fee_usd_micros" / borsh.U64,
+        "amount" / borsh.U64,
+        "domain_amount" / borsh.U64,
+        "price" / borsh.I64,
+        "exponent" / borsh.I32,
+        "publish_time" / borsh.I64,
+    )
+    payer: Pubkey
+    domain: list[int]
+    mint: Pubkey
+    fee_usd_micros: int
+    amount: int
+    domain_amount: int
+    price: int
+    exponent: int
+    publish_time: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainFeePaidEvent":
+        return cls(
+            payer=obj.payer,
+            domain=obj.domain,
+            mint=obj.mint,
+            fee_usd_micros=obj.fee_usd_micros,
+            amount=obj.amount,
+            domain_amount=obj.domain_amount,
+            price=obj.price,
+            exponent=obj.exponent,
+            publish_time=obj.publish_time,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "payer": self.payer,
+            "domain": self.domain,
+            "mint": self.mint,
+            "fee_usd_micros": self.fee_usd_micros,
+            "amount": self.amount,
+            "domain_amount": self.domain_amount,
+            "price": self.price,
+            "exponent": self.exponent,
+            "publish_time": self.publish_time,
+        }
+
+    def to_json(self) -> DomainFeePaidEventJSON:
+        return {
+            "payer": str(self.payer),
+            "domain": self.domain,
+            "mint": str(self.mint),
+            "fee_usd_micros": self.fee_usd_micros,
+            "amount": self.amount,
+            "domain_amount": self.domain_amount,
+            "price": self.price,
+            "exponent": self.exponent,
+            "publish_time": self.publish_time,
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainFeePaidEventJSON) -> "DomainFeePaidEvent":
+        return cls(
+            payer=Pubkey.from_string(obj["payer"]),
+            domain=obj["domain"],
+            mint=Pubkey.from_string(obj["mint"]),
+            fee_usd_micros=obj["fee_usd_micros"],
+            amount=obj["amount"],
+            domain_amount=obj["domain_amount"],
+            price=obj["price"],
+            exponent=obj["exponent"],
+            publish_time=obj["publish_time"],
+        )
@@ -0,0 +1,39 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class DomainFeeShareSetEventJSON(typing.TypedDict):
+    domain_share_bps: int
+
+
+@dataclass
+class DomainFeeShareSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain_share_bps" / borsh.U16,
+    )
+    domain_share_bps: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainFeeShareSetEvent":
+        return cls(
+            domain_share_bps=obj.domain_share_bps,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain_share_bps": self.domain_share_bps,
+        }
+
+    def to_json(self) -> DomainFeeShareSetEventJSON:
+        return {
+            "domain_share_bps": self.domain_share_bps,
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainFeeShareSetEventJSON) -> "DomainFeeShareSetEvent":
+        return cls(
+            domain_share_bps=obj["domain_share_bps"],
+        )
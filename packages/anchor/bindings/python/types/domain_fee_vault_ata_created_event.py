@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class DomainFeeVaultAtaCreatedEventJSON(typing.TypedDict):
+    domain: list[int]
+    mint: str
+    domain_fee_vault_ata: str
+
+
+@dataclass
+class DomainFeeVaultAtaCreatedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "mint" / BorshPubkey,
+        "domain_fee_vault_ata" / BorshPubkey,
+    )
+    domain: list[int]
+    mint: Pubkey
+    domain_fee_vault_ata: Pubkey
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainFeeVaultAtaCreatedEvent":
+        return cls(
+            domain=obj.domain,
+            mint=obj.mint,
+            domain_fee_vault_ata=obj.domain_fee_vault_ata,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "mint": self.mint,
+            "domain_fee_vault_ata": self.domain_fee_vault_ata,
+        }
+
+    def to_json(self) -> DomainFeeVaultAtaCreatedEventJSON:
+        return {
+            "domain": self.domain,
+            "mint": str(self.mint),
+            "domain_fee_vault_ata": str(self.domain_fee_vault_ata),
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainFeeVaultAtaCreatedEventJSON) -> "DomainFeeVaultAtaCreatedEvent":
+        return cls(
+            domain=obj["domain"],
+            mint=Pubkey.from_string(obj["mint"]),
+            domain_fee_vault_ata=Pubkey.from_string(obj["domain_fee_vault_ata"]),
+        )
@@ -0,0 +1,69 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class DomainFeesClaimedEventJSON(typing.TypedDict):
+    domain: list[int]
+    mint: str
+    destination: str
+    authority: str
+    amount: int
+
+
+@dataclass
+class DomainFeesClaimedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "mint" / BorshPubkey,
+        "destination" / BorshPubkey,
+        "authority" / BorshPubkey,
+        "amount" / borsh.U64,
+    )
+    domain: list[int]
+    mint: Pubkey
+    destination: Pubkey
+    authority: Pubkey
+    amount: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainFeesClaimedEvent":
+        return cls(
+            domain=obj.domain,
+            mint=obj.mint,
+            destination=obj.destination,
+            authority=obj.authority,
+            amount=obj.amount,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "mint": self.mint,
+            "destination": self.destination,
+            "authority": self.authority,
+            "amount": self.amount,
+        }
+
+    def to_json(self) -> DomainFeesClaimedEventJSON:
+        return {
+            "domain": self.domain,
+            "mint": str(self.mint),
+            "destination": str(self.destination),
+            "authority": str(self.authority),
+            "amount": self.amount,
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainFeesClaimedEventJSON) -> "DomainFeesClaimedEvent":
+        return cls(
+            domain=obj["domain"],
+            mint=Pubkey.from_string(obj["mint"]),
+            destination=Pubkey.from_string(obj["destination"]),
+            authority=Pubkey.from_string(obj["authority"]),
+            amount=obj["amount"],
+        )
@@ -0,0 +1,46 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class DomainIdleTimeoutSetEventJSON(typing.TypedDict):
+    domain: list[int]
+    idle_timeout_secs: int
+
+
+@dataclass
+class DomainIdleTimeoutSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "idle_timeout_secs" / borsh.I64,
+    )
+    domain: list[int]
+    idle_timeout_secs: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainIdleTimeoutSetEvent":
+        return cls(
+            domain=obj.domain,
+            idle_timeout_secs=obj.idle_timeout_secs,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "idle_timeout_secs": self.idle_timeout_secs,
+        }
+
+    def to_json(self) -> DomainIdleTimeoutSetEventJSON:
+        return {
+            "domain": self.domain,
+            "idle_timeout_secs": self.idle_timeout_secs,
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainIdleTimeoutSetEventJSON) -> "DomainIdleTimeoutSetEvent":
+        return cls(
+            domain=obj["domain"],
+            idle_timeout_secs=obj["idle_timeout_secs"],
+        )
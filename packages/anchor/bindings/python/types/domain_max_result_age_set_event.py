@@ -0,0 +1,46 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class DomainMaxResultAgeSetEventJSON(typing.TypedDict):
+    domain: list[int]
+    max_result_age_secs: int
+
+
+@dataclass
+class DomainMaxResultAgeSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "max_result_age_secs" / borsh.U32,
+    )
+    domain: list[int]
+    max_result_age_secs: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainMaxResultAgeSetEvent":
+        return cls(
+            domain=obj.domain,
+            max_result_age_secs=obj.max_result_age_secs,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "max_result_age_secs": self.max_result_age_secs,
+        }
+
+    def to_json(self) -> DomainMaxResultAgeSetEventJSON:
+        return {
+            "domain": self.domain,
+            "max_result_age_secs": self.max_result_age_secs,
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainMaxResultAgeSetEventJSON) -> "DomainMaxResultAgeSetEvent":
+        return cls(
+            domain=obj["domain"],
+            max_result_age_secs=obj["max_result_age_secs"],
+        )
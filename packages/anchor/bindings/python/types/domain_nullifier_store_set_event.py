@@ -0,0 +1,49 @@
+from . import (
+    nullifier_store,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class DomainNullifierStoreSetEventJSON(typing.TypedDict):
+    domain: list[int]
+    nullifier_store: nullifier_store.NullifierStoreJSON
+
+
+@dataclass
+class DomainNullifierStoreSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "nullifier_store" / nullifier_store.layout,
+    )
+    domain: list[int]
+    nullifier_store: nullifier_store.NullifierStoreKind
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainNullifierStoreSetEvent":
+        return cls(
+            domain=obj.domain,
+            nullifier_store=nullifier_store.from_decoded(obj.nullifier_store),
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "nullifier_store": self.nullifier_store.to_encodable(),
+        }
+
+    def to_json(self) -> DomainNullifierStoreSetEventJSON:
+        return {
+            "domain": self.domain,
+            "nullifier_store": self.nullifier_store.to_json(),
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainNullifierStoreSetEventJSON) -> "DomainNullifierStoreSetEvent":
+        return cls(
+            domain=obj["domain"],
+            nullifier_store=nullifier_store.from_json(obj["nullifier_store"]),
+        )
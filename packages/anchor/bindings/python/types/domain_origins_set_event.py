@@ -0,0 +1,47 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from construct import Construct
+import borsh_construct as borsh
+
+
+class DomainOriginsSetEventJSON(typing.TypedDict):
+    domain: list[int]
+    origins: list[list[int]]
+
+
+@dataclass
+class DomainOriginsSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "origins" / borsh.Vec(typing.cast(Construct, borsh.U8[32])),
+    )
+    domain: list[int]
+    origins: list[list[int]]
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainOriginsSetEvent":
+        return cls(
+            domain=obj.domain,
+            origins=obj.origins,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "origins": self.origins,
+        }
+
+    def to_json(self) -> DomainOriginsSetEventJSON:
+        return {
+            "domain": self.domain,
+            "origins": self.origins,
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainOriginsSetEventJSON) -> "DomainOriginsSetEvent":
+        return cls(
+            domain=obj["domain"],
+            origins=obj["origins"],
+        )
@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class DomainPausedSetEventJSON(typing.TypedDict):
+    domain: list[int]
+    paused: bool
+    authority: str
+
+
+@dataclass
+class DomainPausedSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "paused" / borsh.Bool,
+        "authority" / BorshPubkey,
+    )
+    domain: list[int]
+    paused: bool
+    authority: Pubkey
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainPausedSetEvent":
+        return cls(
+            domain=obj.domain,
+            paused=obj.paused,
+            authority=obj.authority,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "paused": self.paused,
+            "authority": self.authority,
+        }
+
+    def to_json(self) -> DomainPausedSetEventJSON:
+        return {
+            "domain": self.domain,
+            "paused": self.paused,
+            "authority": str(self.authority),
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainPausedSetEventJSON) -> "DomainPausedSetEvent":
+        return cls(
+            domain=obj["domain"],
+            paused=obj["paused"],
+            authority=Pubkey.from_string(obj["authority"]),
+        )
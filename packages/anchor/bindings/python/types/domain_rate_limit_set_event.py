@@ -0,0 +1,46 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class DomainRateLimitSetEventJSON(typing.TypedDict):
+    domain: list[int]
+    rate_limit: int
+
+
+@dataclass
+class DomainRateLimitSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "rate_limit" / borsh.U32,
+    )
+    domain: list[int]
+    rate_limit: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainRateLimitSetEvent":
+        return cls(
+            domain=obj.domain,
+            rate_limit=obj.rate_limit,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "rate_limit": self.rate_limit,
+        }
+
+    def to_json(self) -> DomainRateLimitSetEventJSON:
+        return {
+            "domain": self.domain,
+            "rate_limit": self.rate_limit,
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainRateLimitSetEventJSON) -> "DomainRateLimitSetEvent":
+        return cls(
+            domain=obj["domain"],
+            rate_limit=obj["rate_limit"],
+        )
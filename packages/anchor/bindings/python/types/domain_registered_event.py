@@ -0,0 +1,76 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class DomainRegisteredEventJSON(typing.TypedDict):
+    domain: list[int]
+    owner: str
+    verifier: typing.Optional[str]
+    default_session_secs: int
+    max_result_age_secs: int
+    max_permissions: int
+
+
+@dataclass
+class DomainRegisteredEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "owner" / BorshPubkey,
+        "verifier" / borsh.Option(BorshPubkey),
+        "default_session_secs" / borsh.I64,
+        "max_result_age_secs" / borsh.U32,
+        "max_permissions" / borsh.U8,
+    )
+    domain: list[int]
+    owner: Pubkey
+    verifier: typing.Optional[Pubkey]
+    default_session_secs: int
+    max_result_age_secs: int
+    max_permissions: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainRegisteredEvent":
+        return cls(
+            domain=obj.domain,
+            owner=obj.owner,
+            verifier=obj.verifier,
+            default_session_secs=obj.default_session_secs,
+            max_result_age_secs=obj.max_result_age_secs,
+            max_permissions=obj.max_permissions,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "owner": self.owner,
+            "verifier": self.verifier,
+            "default_session_secs": self.default_session_secs,
+            "max_result_age_secs": self.max_result_age_secs,
+            "max_permissions": self.max_permissions,
+        }
+
+    def to_json(self) -> DomainRegisteredEventJSON:
+        return {
+            "domain": self.domain,
+            "owner": str(self.owner),
+            "verifier": (None if self.verifier is None else str(self.verifier)),
+            "default_session_secs": self.default_session_secs,
+            "max_result_age_secs": self.max_result_age_secs,
+            "max_permissions": self.max_permissions,
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainRegisteredEventJSON) -> "DomainRegisteredEvent":
+        return cls(
+            domain=obj["domain"],
+            owner=Pubkey.from_string(obj["owner"]),
+            verifier=(None if obj["verifier"] is None else Pubkey.from_string(obj["verifier"])),
+            default_session_secs=obj["default_session_secs"],
+            max_result_age_secs=obj["max_result_age_secs"],
+            max_permissions=obj["max_permissions"],
+        )
@@ -0,0 +1,113 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from anchorpy.borsh_extension import EnumForCodegen
+import borsh_construct as borsh
+
+
+class OwnerJSON(typing.TypedDict):
+    kind: typing.Literal["Owner"]
+
+
+@dataclass
+class Owner:
+    discriminator: typing.ClassVar = 0
+    kind: typing.ClassVar = "Owner"
+
+    @classmethod
+    def to_json(cls) -> OwnerJSON:
+        return OwnerJSON(
+            kind="Owner",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Owner": {},
+        }
+
+
+class OperatorJSON(typing.TypedDict):
+    kind: typing.Literal["Operator"]
+
+
+@dataclass
+class Operator:
+    discriminator: typing.ClassVar = 1
+    kind: typing.ClassVar = "Operator"
+
+    @classmethod
+    def to_json(cls) -> OperatorJSON:
+        return OperatorJSON(
+            kind="Operator",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Operator": {},
+        }
+
+
+class TreasurerJSON(typing.TypedDict):
+    kind: typing.Literal["Treasurer"]
+
+
+@dataclass
+class Treasurer:
+    discriminator: typing.ClassVar = 2
+    kind: typing.ClassVar = "Treasurer"
+
+    @classmethod
+    def to_json(cls) -> TreasurerJSON:
+        return TreasurerJSON(
+            kind="Treasurer",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Treasurer": {},
+        }
+
+
+DomainRoleKind = typing.Union[
+    Owner,
+    Operator,
+    Treasurer,
+]
+DomainRoleJSON = typing.Union[
+    OwnerJSON,
+    OperatorJSON,
+    TreasurerJSON,
+]
+
+
+def from_decoded(obj: dict) -> DomainRoleKind:
+    if not isinstance(obj, dict):
+        raise ValueError("Invalid enum object")
+    if "Owner" in obj:
+        return Owner()
+    if "Operator" in obj:
+        return Operator()
+    if "Treasurer" in obj:
+        return Treasurer()
+    raise ValueError("Invalid enum object")
+
+
+def from_json(obj: DomainRoleJSON) -> DomainRoleKind:
+    if obj["kind"] == "Owner":
+        return Owner()
+    if obj["kind"] == "Operator":
+        return Operator()
+    if obj["kind"] == "Treasurer":
+        return Treasurer()
+    kind = obj["kind"]
+    raise ValueError(f"Unrecognized enum kind: {kind}")
+
+
+layout = EnumForCodegen(
+    "Owner" / borsh.CStruct(),
+    "Operator" / borsh.CStruct(),
+    "Treasurer" / borsh.CStruct(),
+)
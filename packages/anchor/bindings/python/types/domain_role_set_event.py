@@ -0,0 +1,58 @@
+from . import (
+    domain_role,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class DomainRoleSetEventJSON(typing.TypedDict):
+    domain: list[int]
+    role: domain_role.DomainRoleJSON
+    key: str
+
+
+@dataclass
+class DomainRoleSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "role" / domain_role.layout,
+        "key" / BorshPubkey,
+    )
+    domain: list[int]
+    role: domain_role.DomainRoleKind
+    key: Pubkey
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainRoleSetEvent":
+        return cls(
+            domain=obj.domain,
+            role=domain_role.from_decoded(obj.role),
+            key=obj.key,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "role": self.role.to_encodable(),
+            "key": self.key,
+        }
+
+    def to_json(self) -> DomainRoleSetEventJSON:
+        return {
+            "domain": self.domain,
+            "role": self.role.to_json(),
+            "key": str(self.key),
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainRoleSetEventJSON) -> "DomainRoleSetEvent":
+        return cls(
+            domain=obj["domain"],
+            role=domain_role.from_json(obj["role"]),
+            key=Pubkey.from_string(obj["key"]),
+        )
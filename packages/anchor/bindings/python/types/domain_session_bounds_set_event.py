@@ -0,0 +1,53 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class DomainSessionBoundsSetEventJSON(typing.TypedDict):
+    domain: list[int]
+    min_session_secs: int
+    max_session_secs: int
+
+
+@dataclass
+class DomainSessionBoundsSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "min_session_secs" / borsh.I64,
+        "max_session_secs" / borsh.I64,
+    )
+    domain: list[int]
+    min_session_secs: int
+    max_session_secs: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainSessionBoundsSetEvent":
+        return cls(
+            domain=obj.domain,
+            min_session_secs=obj.min_session_secs,
+            max_session_secs=obj.max_session_secs,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "min_session_secs": self.min_session_secs,
+            "max_session_secs": self.max_session_secs,
+        }
+
+    def to_json(self) -> DomainSessionBoundsSetEventJSON:
+        return {
+            "domain": self.domain,
+            "min_session_secs": self.min_session_secs,
+            "max_session_secs": self.max_session_secs,
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainSessionBoundsSetEventJSON) -> "DomainSessionBoundsSetEvent":
+        return cls(
+            domain=obj["domain"],
+            min_session_secs=obj["min_session_secs"],
+            max_session_secs=obj["max_session_secs"],
+        )
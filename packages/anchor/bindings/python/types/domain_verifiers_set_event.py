@@ -0,0 +1,51 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from construct import Construct
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class DomainVerifiersSetEventJSON(typing.TypedDict):
+    domain: list[int]
+    verifiers: list[str]
+
+
+@dataclass
+class DomainVerifiersSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "verifiers" / borsh.Vec(typing.cast(Construct, BorshPubkey)),
+    )
+    domain: list[int]
+    verifiers: list[Pubkey]
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "DomainVerifiersSetEvent":
+        return cls(
+            domain=obj.domain,
+            verifiers=obj.verifiers,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "verifiers": self.verifiers,
+        }
+
+    def to_json(self) -> DomainVerifiersSetEventJSON:
+        return {
+            "domain": self.domain,
+            "verifiers": list(map(lambda item: str(item), self.verifiers)),
+        }
+
+    @classmethod
+    def from_json(cls, obj: DomainVerifiersSetEventJSON) -> "DomainVerifiersSetEvent":
+        return cls(
+            domain=obj["domain"],
+            verifiers=list(
+                map(lambda item: Pubkey.from_string(item), obj["verifiers"])
+            ),
+        )
@@ -0,0 +1,69 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class ExpiredNullifierClosedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    payer: str
+    cranker: str
+    reward: int
+    closed_at: int
+
+
+@dataclass
+class ExpiredNullifierClosedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "payer" / BorshPubkey,
+        "cranker" / BorshPubkey,
+        "reward" / borsh.U64,
+        "closed_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    payer: Pubkey
+    cranker: Pubkey
+    reward: int
+    closed_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "ExpiredNullifierClosedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            payer=obj.payer,
+            cranker=obj.cranker,
+            reward=obj.reward,
+            closed_at=obj.closed_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "payer": self.payer,
+            "cranker": self.cranker,
+            "reward": self.reward,
+            "closed_at": self.closed_at,
+        }
+
+    def to_json(self) -> ExpiredNullifierClosedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "payer": str(self.payer),
+            "cranker": str(self.cranker),
+            "reward": self.reward,
+            "closed_at": self.closed_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: ExpiredNullifierClosedEventJSON) -> "ExpiredNullifierClosedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            payer=Pubkey.from_string(obj["payer"]),
+            cranker=Pubkey.from_string(obj["cranker"]),
+            reward=obj["reward"],
+            closed_at=obj["closed_at"],
+        )
@@ -0,0 +1,102 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from anchorpy.borsh_extension import EnumForCodegen
+import borsh_construct as borsh
+
+
+UnixTimeJSONValue = tuple[int]
+UnixTimeValue = tuple[int]
+
+
+class UnixTimeJSON(typing.TypedDict):
+    value: UnixTimeJSONValue
+    kind: typing.Literal["UnixTime"]
+
+
+@dataclass
+class UnixTime:
+    discriminator: typing.ClassVar = 0
+    kind: typing.ClassVar = "UnixTime"
+    value: UnixTimeValue
+
+    def to_json(self) -> UnixTimeJSON:
+        return UnixTimeJSON(
+            kind="UnixTime",
+            value=(self.value[0],),
+        )
+
+    def to_encodable(self) -> dict:
+        return {
+            "UnixTime": {
+                "item_0": self.value[0],
+            },
+        }
+
+
+SlotJSONValue = tuple[int]
+SlotValue = tuple[int]
+
+
+class SlotJSON(typing.TypedDict):
+    value: SlotJSONValue
+    kind: typing.Literal["Slot"]
+
+
+@dataclass
+class Slot:
+    discriminator: typing.ClassVar = 1
+    kind: typing.ClassVar = "Slot"
+    value: SlotValue
+
+    def to_json(self) -> SlotJSON:
+        return SlotJSON(
+            kind="Slot",
+            value=(self.value[0],),
+        )
+
+    def to_encodable(self) -> dict:
+        return {
+            "Slot": {
+                "item_0": self.value[0],
+            },
+        }
+
+
+ExpiryKindKind = typing.Union[
+    UnixTime,
+    Slot,
+]
+ExpiryKindJSON = typing.Union[
+    UnixTimeJSON,
+    SlotJSON,
+]
+
+
+def from_decoded(obj: dict) -> ExpiryKindKind:
+    if not isinstance(obj, dict):
+        raise ValueError("Invalid enum object")
+    if "UnixTime" in obj:
+        val = obj["UnixTime"]
+        return UnixTime((val["item_0"],))
+    if "Slot" in obj:
+        val = obj["Slot"]
+        return Slot((val["item_0"],))
+    raise ValueError("Invalid enum object")
+
+
+def from_json(obj: ExpiryKindJSON) -> ExpiryKindKind:
+    if obj["kind"] == "UnixTime":
+        unix_time_json_value = typing.cast(UnixTimeJSONValue, obj["value"])
+        return UnixTime((unix_time_json_value[0],))
+    if obj["kind"] == "Slot":
+        slot_json_value = typing.cast(SlotJSONValue, obj["value"])
+        return Slot((slot_json_value[0],))
+    kind = obj["kind"]
+    raise ValueError(f"Unrecognized enum kind: {kind}")
+
+
+layout = EnumForCodegen(
+    "UnixTime" / borsh.CStruct("item_0" / borsh.I64),
+    "Slot" / borsh.CStruct("item_0" / borsh.U64),
+)
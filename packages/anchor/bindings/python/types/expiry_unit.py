@@ -0,0 +1,84 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from anchorpy.borsh_extension import EnumForCodegen
+import borsh_construct as borsh
+
+
+class UnixTimeJSON(typing.TypedDict):
+    kind: typing.Literal["UnixTime"]
+
+
+@dataclass
+class UnixTime:
+    discriminator: typing.ClassVar = 0
+    kind: typing.ClassVar = "UnixTime"
+
+    @classmethod
+    def to_json(cls) -> UnixTimeJSON:
+        return UnixTimeJSON(
+            kind="UnixTime",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "UnixTime": {},
+        }
+
+
+class SlotJSON(typing.TypedDict):
+    kind: typing.Literal["Slot"]
+
+
+@dataclass
+class Slot:
+    discriminator: typing.ClassVar = 1
+    kind: typing.ClassVar = "Slot"
+
+    @classmethod
+    def to_json(cls) -> SlotJSON:
+        return SlotJSON(
+            kind="Slot",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Slot": {},
+        }
+
+
+ExpiryUnitKind = typing.Union[
+    UnixTime,
+    Slot,
+]
+ExpiryUnitJSON = typing.Union[
+    UnixTimeJSON,
+    SlotJSON,
+]
+
+
+def from_decoded(obj: dict) -> ExpiryUnitKind:
+    if not isinstance(obj, dict):
+        raise ValueError("Invalid enum object")
+    if "UnixTime" in obj:
+        return UnixTime()
+    if "Slot" in obj:
+        return Slot()
+    raise ValueError("Invalid enum object")
+
+
+def from_json(obj: ExpiryUnitJSON) -> ExpiryUnitKind:
+    if obj["kind"] == "UnixTime":
+        return UnixTime()
+    if obj["kind"] == "Slot":
+        return Slot()
+    kind = obj["kind"]
+    raise ValueError(f"Unrecognized enum kind: {kind}")
+
+
+layout = EnumForCodegen(
+    "UnixTime" / borsh.CStruct(),
+    "Slot" / borsh.CStruct(),
+)
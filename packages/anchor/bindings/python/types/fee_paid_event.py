@@ -0,0 +1,83 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class FeePaidEventJSON(typing.TypedDict):
+    payer: str
+    mint: str
+    fee_usd_micros: int
+    amount: int
+    price: int
+    exponent: int
+    publish_time: int
+
+
+@dataclass
+class FeePaidEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "payer" / BorshPubkey,
+        "mint" / BorshPubkey,
+        "fee_usd_micros" / borsh.U64,
+        "amount" / borsh.U64,
+        "price" / borsh.I64,
+        "exponent" / borsh.I32,
+        "publish_time" / borsh.I64,
+    )
+    payer: Pubkey
+    mint: Pubkey
+    fee_usd_micros: int
+    amount: int
+    price: int
+    exponent: int
+    publish_time: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "FeePaidEvent":
+        return cls(
+            payer=obj.payer,
+            mint=obj.mint,
+            fee_usd_micros=obj.fee_usd_micros,
+            amount=obj.amount,
+            price=obj.price,
+            exponent=obj.exponent,
+            publish_time=obj.publish_time,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "payer": self.payer,
+            "mint": self.mint,
+            "fee_usd_micros": self.fee_usd_micros,
+            "amount": self.amount,
+            "price": self.price,
+            "exponent": self.exponent,
+            "publish_time": self.publish_time,
+        }
+
+    def to_json(self) -> FeePaidEventJSON:
+        return {
+            "payer": str(self.payer),
+            "mint": str(self.mint),
+            "fee_usd_micros": self.fee_usd_micros,
+            "amount": self.amount,
+            "price": self.price,
+            "exponent": self.exponent,
+            "publish_time": self.publish_time,
+        }
+
+    @classmethod
+    def from_json(cls, obj: FeePaidEventJSON) -> "FeePaidEvent":
+        return cls(
+            payer=Pubkey.from_string(obj["payer"]),
+            mint=Pubkey.from_string(obj["mint"]),
+            fee_usd_micros=obj["fee_usd_micros"],
+            amount=obj["amount"],
+            price=obj["price"],
+            exponent=obj["exponent"],
+            publish_time=obj["publish_time"],
+        )
@@ -0,0 +1,48 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class GlobalLogoutEventJSON(typing.TypedDict):
+    identity: str
+    logged_out_at: int
+
+
+@dataclass
+class GlobalLogoutEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "identity" / BorshPubkey,
+        "logged_out_at" / borsh.I64,
+    )
+    identity: Pubkey
+    logged_out_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "GlobalLogoutEvent":
+        return cls(
+            identity=obj.identity,
+            logged_out_at=obj.logged_out_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "identity": self.identity,
+            "logged_out_at": self.logged_out_at,
+        }
+
+    def to_json(self) -> GlobalLogoutEventJSON:
+        return {
+            "identity": str(self.identity),
+            "logged_out_at": self.logged_out_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: GlobalLogoutEventJSON) -> "GlobalLogoutEvent":
+        return cls(
+            identity=Pubkey.from_string(obj["identity"]),
+            logged_out_at=obj["logged_out_at"],
+        )
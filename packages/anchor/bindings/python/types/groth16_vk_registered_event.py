@@ -0,0 +1,53 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class Groth16VkRegisteredEventJSON(typing.TypedDict):
+    circuit_id: int
+    vk_hash: list[int]
+    public_inputs: int
+
+
+@dataclass
+class Groth16VkRegisteredEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "circuit_id" / borsh.U32,
+        "vk_hash" / borsh.U8[32],
+        "public_inputs" / borsh.U8,
+    )
+    circuit_id: int
+    vk_hash: list[int]
+    public_inputs: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "Groth16VkRegisteredEvent":
+        return cls(
+            circuit_id=obj.circuit_id,
+            vk_hash=obj.vk_hash,
+            public_inputs=obj.public_inputs,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "circuit_id": self.circuit_id,
+            "vk_hash": self.vk_hash,
+            "public_inputs": self.public_inputs,
+        }
+
+    def to_json(self) -> Groth16VkRegisteredEventJSON:
+        return {
+            "circuit_id": self.circuit_id,
+            "vk_hash": self.vk_hash,
+            "public_inputs": self.public_inputs,
+        }
+
+    @classmethod
+    def from_json(cls, obj: Groth16VkRegisteredEventJSON) -> "Groth16VkRegisteredEvent":
+        return cls(
+            circuit_id=obj["circuit_id"],
+            vk_hash=obj["vk_hash"],
+            public_inputs=obj["public_inputs"],
+        )
@@ -0,0 +1,72 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from construct import Construct
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class GuardiansSetEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    guardians: list[str]
+    threshold: int
+    nonce: int
+    updated_at: int
+
+
+@dataclass
+class GuardiansSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "guardians" / borsh.Vec(typing.cast(Construct, BorshPubkey)),
+        "threshold" / borsh.U8,
+        "nonce" / borsh.U64,
+        "updated_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    guardians: list[Pubkey]
+    threshold: int
+    nonce: int
+    updated_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "GuardiansSetEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            guardians=obj.guardians,
+            threshold=obj.threshold,
+            nonce=obj.nonce,
+            updated_at=obj.updated_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "guardians": self.guardians,
+            "threshold": self.threshold,
+            "nonce": self.nonce,
+            "updated_at": self.updated_at,
+        }
+
+    def to_json(self) -> GuardiansSetEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "guardians": list(map(lambda item: str(item), self.guardians)),
+            "threshold": self.threshold,
+            "nonce": self.nonce,
+            "updated_at": self.updated_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: GuardiansSetEventJSON) -> "GuardiansSetEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            guardians=list(
+                map(lambda item: Pubkey.from_string(item), obj["guardians"])
+            ),
+            threshold=obj["threshold"],
+            nonce=obj["nonce"],
+            updated_at=obj["updated_at"],
+        )
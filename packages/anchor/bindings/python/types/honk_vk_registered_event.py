@@ -0,0 +1,60 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class HonkVkRegisteredEventJSON(typing.TypedDict):
+    circuit_id: int
+    vk_hash: list[int]
+    log_circuit_size: int
+    public_inputs_size: int
+
+
+@dataclass
+class HonkVkRegisteredEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "circuit_id" / borsh.U32,
+        "vk_hash" / borsh.U8[32],
+        "log_circuit_size" / borsh.U8,
+        "public_inputs_size" / borsh.U32,
+    )
+    circuit_id: int
+    vk_hash: list[int]
+    log_circuit_size: int
+    public_inputs_size: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "HonkVkRegisteredEvent":
+        return cls(
+            circuit_id=obj.circuit_id,
+            vk_hash=obj.vk_hash,
+            log_circuit_size=obj.log_circuit_size,
+            public_inputs_size=obj.public_inputs_size,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "circuit_id": self.circuit_id,
+            "vk_hash": self.vk_hash,
+            "log_circuit_size": self.log_circuit_size,
+            "public_inputs_size": self.public_inputs_size,
+        }
+
+    def to_json(self) -> HonkVkRegisteredEventJSON:
+        return {
+            "circuit_id": self.circuit_id,
+            "vk_hash": self.vk_hash,
+            "log_circuit_size": self.log_circuit_size,
+            "public_inputs_size": self.public_inputs_size,
+        }
+
+    @classmethod
+    def from_json(cls, obj: HonkVkRegisteredEventJSON) -> "HonkVkRegisteredEvent":
+        return cls(
+            circuit_id=obj["circuit_id"],
+            vk_hash=obj["vk_hash"],
+            log_circuit_size=obj["log_circuit_size"],
+            public_inputs_size=obj["public_inputs_size"],
+        )
@@ -0,0 +1,97 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class IdentityRecoveredEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    new_nullifier: list[int]
+    domain: list[int]
+    proof_hash: list[int]
+    new_authority: str
+    sessions: int
+    grants: int
+    approvals: int
+    recovered_at: int
+
+
+@dataclass
+class IdentityRecoveredEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "new_nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "proof_hash" / borsh.U8[32],
+        "new_authority" / BorshPubkey,
+        "sessions" / borsh.U32,
+        "grants" / borsh.U32,
+        "approvals" / borsh.U8,
+        "recovered_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    new_nullifier: list[int]
+    domain: list[int]
+    proof_hash: list[int]
+    new_authority: Pubkey
+    sessions: int
+    grants: int
+    approvals: int
+    recovered_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "IdentityRecoveredEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            new_nullifier=obj.new_nullifier,
+            domain=obj.domain,
+            proof_hash=obj.proof_hash,
+            new_authority=obj.new_authority,
+            sessions=obj.sessions,
+            grants=obj.grants,
+            approvals=obj.approvals,
+            recovered_at=obj.recovered_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "new_nullifier": self.new_nullifier,
+            "domain": self.domain,
+            "proof_hash": self.proof_hash,
+            "new_authority": self.new_authority,
+            "sessions": self.sessions,
+            "grants": self.grants,
+            "approvals": self.approvals,
+            "recovered_at": self.recovered_at,
+        }
+
+    def to_json(self) -> IdentityRecoveredEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "new_nullifier": self.new_nullifier,
+            "domain": self.domain,
+            "proof_hash": self.proof_hash,
+            "new_authority": str(self.new_authority),
+            "sessions": self.sessions,
+            "grants": self.grants,
+            "approvals": self.approvals,
+            "recovered_at": self.recovered_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: IdentityRecoveredEventJSON) -> "IdentityRecoveredEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            new_nullifier=obj["new_nullifier"],
+            domain=obj["domain"],
+            proof_hash=obj["proof_hash"],
+            new_authority=Pubkey.from_string(obj["new_authority"]),
+            sessions=obj["sessions"],
+            grants=obj["grants"],
+            approvals=obj["approvals"],
+            recovered_at=obj["recovered_at"],
+        )
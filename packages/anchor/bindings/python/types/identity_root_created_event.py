@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class IdentityRootCreatedEventJSON(typing.TypedDict):
+    root: str
+    owner: str
+    created_at: int
+
+
+@dataclass
+class IdentityRootCreatedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "root" / BorshPubkey,
+        "owner" / BorshPubkey,
+        "created_at" / borsh.I64,
+    )
+    root: Pubkey
+    owner: Pubkey
+    created_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "IdentityRootCreatedEvent":
+        return cls(
+            root=obj.root,
+            owner=obj.owner,
+            created_at=obj.created_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "root": self.root,
+            "owner": self.owner,
+            "created_at": self.created_at,
+        }
+
+    def to_json(self) -> IdentityRootCreatedEventJSON:
+        return {
+            "root": str(self.root),
+            "owner": str(self.owner),
+            "created_at": self.created_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: IdentityRootCreatedEventJSON) -> "IdentityRootCreatedEvent":
+        return cls(
+            root=Pubkey.from_string(obj["root"]),
+            owner=Pubkey.from_string(obj["owner"]),
+            created_at=obj["created_at"],
+        )
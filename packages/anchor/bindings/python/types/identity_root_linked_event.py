@@ -0,0 +1,69 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class IdentityRootLinkedEventJSON(typing.TypedDict):
+    root: str
+    nullifier: list[int]
+    domain: list[int]
+    linkage_proof_hash: list[int]
+    linked_at: int
+
+
+@dataclass
+class IdentityRootLinkedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "root" / BorshPubkey,
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "linkage_proof_hash" / borsh.U8[32],
+        "linked_at" / borsh.I64,
+    )
+    root: Pubkey
+    nullifier: list[int]
+    domain: list[int]
+    linkage_proof_hash: list[int]
+    linked_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "IdentityRootLinkedEvent":
+        return cls(
+            root=obj.root,
+            nullifier=obj.nullifier,
+            domain=obj.domain,
+            linkage_proof_hash=obj.linkage_proof_hash,
+            linked_at=obj.linked_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "root": self.root,
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "linkage_proof_hash": self.linkage_proof_hash,
+            "linked_at": self.linked_at,
+        }
+
+    def to_json(self) -> IdentityRootLinkedEventJSON:
+        return {
+            "root": str(self.root),
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "linkage_proof_hash": self.linkage_proof_hash,
+            "linked_at": self.linked_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: IdentityRootLinkedEventJSON) -> "IdentityRootLinkedEvent":
+        return cls(
+            root=Pubkey.from_string(obj["root"]),
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+            linkage_proof_hash=obj["linkage_proof_hash"],
+            linked_at=obj["linked_at"],
+        )
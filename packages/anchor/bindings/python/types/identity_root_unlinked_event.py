@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class IdentityRootUnlinkedEventJSON(typing.TypedDict):
+    root: str
+    nullifier: list[int]
+    unlinked_at: int
+
+
+@dataclass
+class IdentityRootUnlinkedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "root" / BorshPubkey,
+        "nullifier" / borsh.U8[32],
+        "unlinked_at" / borsh.I64,
+    )
+    root: Pubkey
+    nullifier: list[int]
+    unlinked_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "IdentityRootUnlinkedEvent":
+        return cls(
+            root=obj.root,
+            nullifier=obj.nullifier,
+            unlinked_at=obj.unlinked_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "root": self.root,
+            "nullifier": self.nullifier,
+            "unlinked_at": self.unlinked_at,
+        }
+
+    def to_json(self) -> IdentityRootUnlinkedEventJSON:
+        return {
+            "root": str(self.root),
+            "nullifier": self.nullifier,
+            "unlinked_at": self.unlinked_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: IdentityRootUnlinkedEventJSON) -> "IdentityRootUnlinkedEvent":
+        return cls(
+            root=Pubkey.from_string(obj["root"]),
+            nullifier=obj["nullifier"],
+            unlinked_at=obj["unlinked_at"],
+        )
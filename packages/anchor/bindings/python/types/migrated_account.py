@@ -0,0 +1,142 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from anchorpy.borsh_extension import EnumForCodegen
+import borsh_construct as borsh
+
+
+class SessionJSON(typing.TypedDict):
+    kind: typing.Literal["Session"]
+
+
+@dataclass
+class Session:
+    discriminator: typing.ClassVar = 0
+    kind: typing.ClassVar = "Session"
+
+    @classmethod
+    def to_json(cls) -> SessionJSON:
+        return SessionJSON(
+            kind="Session",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Session": {},
+        }
+
+
+class ProofRecordJSON(typing.TypedDict):
+    kind: typing.Literal["ProofRecord"]
+
+
+@dataclass
+class ProofRecord:
+    discriminator: typing.ClassVar = 1
+    kind: typing.ClassVar = "ProofRecord"
+
+    @classmethod
+    def to_json(cls) -> ProofRecordJSON:
+        return ProofRecordJSON(
+            kind="ProofRecord",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "ProofRecord": {},
+        }
+
+
+class PermissionGrantJSON(typing.TypedDict):
+    kind: typing.Literal["PermissionGrant"]
+
+
+@dataclass
+class PermissionGrant:
+    discriminator: typing.ClassVar = 2
+    kind: typing.ClassVar = "PermissionGrant"
+
+    @classmethod
+    def to_json(cls) -> PermissionGrantJSON:
+        return PermissionGrantJSON(
+            kind="PermissionGrant",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "PermissionGrant": {},
+        }
+
+
+class DomainConfigJSON(typing.TypedDict):
+    kind: typing.Literal["DomainConfig"]
+
+
+@dataclass
+class DomainConfig:
+    discriminator: typing.ClassVar = 3
+    kind: typing.ClassVar = "DomainConfig"
+
+    @classmethod
+    def to_json(cls) -> DomainConfigJSON:
+        return DomainConfigJSON(
+            kind="DomainConfig",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "DomainConfig": {},
+        }
+
+
+MigratedAccountKind = typing.Union[
+    Session,
+    ProofRecord,
+    PermissionGrant,
+    DomainConfig,
+]
+MigratedAccountJSON = typing.Union[
+    SessionJSON,
+    ProofRecordJSON,
+    PermissionGrantJSON,
+    DomainConfigJSON,
+]
+
+
+def from_decoded(obj: dict) -> MigratedAccountKind:
+    if not isinstance(obj, dict):
+        raise ValueError("Invalid enum object")
+    if "Session" in obj:
+        return Session()
+    if "ProofRecord" in obj:
+        return ProofRecord()
+    if "PermissionGrant" in obj:
+        return PermissionGrant()
+    if "DomainConfig" in obj:
+        return DomainConfig()
+    raise ValueError("Invalid enum object")
+
+
+def from_json(obj: MigratedAccountJSON) -> MigratedAccountKind:
+    if obj["kind"] == "Session":
+        return Session()
+    if obj["kind"] == "ProofRecord":
+        return ProofRecord()
+    if obj["kind"] == "PermissionGrant":
+        return PermissionGrant()
+    if obj["kind"] == "DomainConfig":
+        return DomainConfig()
+    kind = obj["kind"]
+    raise ValueError(f"Unrecognized enum kind: {kind}")
+
+
+layout = EnumForCodegen(
+    "Session" / borsh.CStruct(),
+    "ProofRecord" / borsh.CStruct(),
+    "PermissionGrant" / borsh.CStruct(),
+    "DomainConfig" / borsh.CStruct(),
+)
@@ -0,0 +1,68 @@
+from . import (
+    migration_step,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from construct import Construct
+import borsh_construct as borsh
+
+
+class MigrationBegunEventJSON(typing.TypedDict):
+    version: int
+    target_version: int
+    steps: list[migration_step.MigrationStepJSON]
+    deadline: int
+
+
+@dataclass
+class MigrationBegunEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "version" / borsh.U16,
+        "target_version" / borsh.U16,
+        "steps" / borsh.Vec(typing.cast(Construct, migration_step.MigrationStep.layout)),
+        "deadline" / borsh.I64,
+    )
+    version: int
+    target_version: int
+    steps: list[migration_step.MigrationStep]
+    deadline: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "MigrationBegunEvent":
+        return cls(
+            version=obj.version,
+            target_version=obj.target_version,
+            steps=list(
+                map(lambda item: migration_step.MigrationStep.from_decoded(item), obj.steps)
+            ),
+            deadline=obj.deadline,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "version": self.version,
+            "target_version": self.target_version,
+            "steps": list(map(lambda item: item.to_encodable(), self.steps)),
+            "deadline": self.deadline,
+        }
+
+    def to_json(self) -> MigrationBegunEventJSON:
+        return {
+            "version": self.version,
+            "target_version": self.target_version,
+            "steps": list(map(lambda item: item.to_json(), self.steps)),
+            "deadline": self.deadline,
+        }
+
+    @classmethod
+    def from_json(cls, obj: MigrationBegunEventJSON) -> "MigrationBegunEvent":
+        return cls(
+            version=obj["version"],
+            target_version=obj["target_version"],
+            steps=list(
+                map(lambda item: migration_step.MigrationStep.from_json(item), obj["steps"])
+            ),
+            deadline=obj["deadline"],
+        )
@@ -0,0 +1,61 @@
+from . import (
+    migration_step,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from construct import Construct
+import borsh_construct as borsh
+
+
+class MigrationCompletedEventJSON(typing.TypedDict):
+    version: int
+    steps: list[migration_step.MigrationStepJSON]
+    forced: bool
+
+
+@dataclass
+class MigrationCompletedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "version" / borsh.U16,
+        "steps" / borsh.Vec(typing.cast(Construct, migration_step.MigrationStep.layout)),
+        "forced" / borsh.Bool,
+    )
+    version: int
+    steps: list[migration_step.MigrationStep]
+    forced: bool
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "MigrationCompletedEvent":
+        return cls(
+            version=obj.version,
+            steps=list(
+                map(lambda item: migration_step.MigrationStep.from_decoded(item), obj.steps)
+            ),
+            forced=obj.forced,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "version": self.version,
+            "steps": list(map(lambda item: item.to_encodable(), self.steps)),
+            "forced": self.forced,
+        }
+
+    def to_json(self) -> MigrationCompletedEventJSON:
+        return {
+            "version": self.version,
+            "steps": list(map(lambda item: item.to_json(), self.steps)),
+            "forced": self.forced,
+        }
+
+    @classmethod
+    def from_json(cls, obj: MigrationCompletedEventJSON) -> "MigrationCompletedEvent":
+        return cls(
+            version=obj["version"],
+            steps=list(
+                map(lambda item: migration_step.MigrationStep.from_json(item), obj["steps"])
+            ),
+            forced=obj["forced"],
+        )
@@ -0,0 +1,63 @@
+from . import (
+    migrated_account,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class MigrationProgressEventJSON(typing.TypedDict):
+    target_version: int
+    account: migrated_account.MigratedAccountJSON
+    migrated: int
+    total: int
+
+
+@dataclass
+class MigrationProgressEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "target_version" / borsh.U16,
+        "account" / migrated_account.layout,
+        "migrated" / borsh.U64,
+        "total" / borsh.U64,
+    )
+    target_version: int
+    account: migrated_account.MigratedAccountKind
+    migrated: int
+    total: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "MigrationProgressEvent":
+        return cls(
+            target_version=obj.target_version,
+            account=migrated_account.from_decoded(obj.account),
+            migrated=obj.migrated,
+            total=obj.total,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "target_version": self.target_version,
+            "account": self.account.to_encodable(),
+            "migrated": self.migrated,
+            "total": self.total,
+        }
+
+    def to_json(self) -> MigrationProgressEventJSON:
+        return {
+            "target_version": self.target_version,
+            "account": self.account.to_json(),
+            "migrated": self.migrated,
+            "total": self.total,
+        }
+
+    @classmethod
+    def from_json(cls, obj: MigrationProgressEventJSON) -> "MigrationProgressEvent":
+        return cls(
+            target_version=obj["target_version"],
+            account=migrated_account.from_json(obj["account"]),
+            migrated=obj["migrated"],
+            total=obj["total"],
+        )
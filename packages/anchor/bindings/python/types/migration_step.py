@@ -0,0 +1,63 @@
+from . import (
+    migrated_account,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class MigrationStepJSON(typing.TypedDict):
+    account: migrated_account.MigratedAccountJSON
+    required: bool
+    total: int
+    migrated: int
+
+
+@dataclass
+class MigrationStep:
+    layout: typing.ClassVar = borsh.CStruct(
+        "account" / migrated_account.layout,
+        "required" / borsh.Bool,
+        "total" / borsh.U64,
+        "migrated" / borsh.U64,
+    )
+    account: migrated_account.MigratedAccountKind
+    required: bool
+    total: int
+    migrated: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "MigrationStep":
+        return cls(
+            account=migrated_account.from_decoded(obj.account),
+            required=obj.required,
+            total=obj.total,
+            migrated=obj.migrated,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "account": self.account.to_encodable(),
+            "required": self.required,
+            "total": self.total,
+            "migrated": self.migrated,
+        }
+
+    def to_json(self) -> MigrationStepJSON:
+        return {
+            "account": self.account.to_json(),
+            "required": self.required,
+            "total": self.total,
+            "migrated": self.migrated,
+        }
+
+    @classmethod
+    def from_json(cls, obj: MigrationStepJSON) -> "MigrationStep":
+        return cls(
+            account=migrated_account.from_json(obj["account"]),
+            required=obj["required"],
+            total=obj["total"],
+            migrated=obj["migrated"],
+        )
@@ -0,0 +1,67 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class NonInclusionProofJSON(typing.TypedDict):
+    root: list[int]
+    low_index: int
+    low_value: list[int]
+    low_next_index: int
+    low_next_value: list[int]
+
+
+@dataclass
+class NonInclusionProof:
+    layout: typing.ClassVar = borsh.CStruct(
+        "root" / borsh.U8[32],
+        "low_index" / borsh.U32,
+        "low_value" / borsh.U8[32],
+        "low_next_index" / borsh.U32,
+        "low_next_value" / borsh.U8[32],
+    )
+    root: list[int]
+    low_index: int
+    low_value: list[int]
+    low_next_index: int
+    low_next_value: list[int]
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "NonInclusionProof":
+        return cls(
+            root=obj.root,
+            low_index=obj.low_index,
+            low_value=obj.low_value,
+            low_next_index=obj.low_next_index,
+            low_next_value=obj.low_next_value,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "root": self.root,
+            "low_index": self.low_index,
+            "low_value": self.low_value,
+            "low_next_index": self.low_next_index,
+            "low_next_value": self.low_next_value,
+        }
+
+    def to_json(self) -> NonInclusionProofJSON:
+        return {
+            "root": self.root,
+            "low_index": self.low_index,
+            "low_value": self.low_value,
+            "low_next_index": self.low_next_index,
+            "low_next_value": self.low_next_value,
+        }
+
+    @classmethod
+    def from_json(cls, obj: NonInclusionProofJSON) -> "NonInclusionProof":
+        return cls(
+            root=obj["root"],
+            low_index=obj["low_index"],
+            low_value=obj["low_value"],
+            low_next_index=obj["low_next_index"],
+            low_next_value=obj["low_next_value"],
+        )
@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class NotificationChannelClosedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    app_id: str
+    closed_at: int
+
+
+@dataclass
+class NotificationChannelClosedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "app_id" / BorshPubkey,
+        "closed_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    app_id: Pubkey
+    closed_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "NotificationChannelClosedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            app_id=obj.app_id,
+            closed_at=obj.closed_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": self.app_id,
+            "closed_at": self.closed_at,
+        }
+
+    def to_json(self) -> NotificationChannelClosedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": str(self.app_id),
+            "closed_at": self.closed_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: NotificationChannelClosedEventJSON) -> "NotificationChannelClosedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            app_id=Pubkey.from_string(obj["app_id"]),
+            closed_at=obj["closed_at"],
+        )
@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class NotificationChannelSetEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    app_id: str
+    channel_commitment: list[int]
+    updated_at: int
+
+
+@dataclass
+class NotificationChannelSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "app_id" / BorshPubkey,
+        "channel_commitment" / borsh.U8[32],
+        "updated_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    app_id: Pubkey
+    channel_commitment: list[int]
+    updated_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "NotificationChannelSetEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            app_id=obj.app_id,
+            channel_commitment=obj.channel_commitment,
+            updated_at=obj.updated_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": self.app_id,
+            "channel_commitment": self.channel_commitment,
+            "updated_at": self.updated_at,
+        }
+
+    def to_json(self) -> NotificationChannelSetEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": str(self.app_id),
+            "channel_commitment": self.channel_commitment,
+            "updated_at": self.updated_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: NotificationChannelSetEventJSON) -> "NotificationChannelSetEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            app_id=Pubkey.from_string(obj["app_id"]),
+            channel_commitment=obj["channel_commitment"],
+            updated_at=obj["updated_at"],
+        )
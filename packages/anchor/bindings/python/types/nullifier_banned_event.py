@@ -0,0 +1,53 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class NullifierBannedEventJSON(typing.TypedDict):
+    domain: list[int]
+    nullifier: list[int]
+    banned_at: int
+
+
+@dataclass
+class NullifierBannedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "nullifier" / borsh.U8[32],
+        "banned_at" / borsh.I64,
+    )
+    domain: list[int]
+    nullifier: list[int]
+    banned_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "NullifierBannedEvent":
+        return cls(
+            domain=obj.domain,
+            nullifier=obj.nullifier,
+            banned_at=obj.banned_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "nullifier": self.nullifier,
+            "banned_at": self.banned_at,
+        }
+
+    def to_json(self) -> NullifierBannedEventJSON:
+        return {
+            "domain": self.domain,
+            "nullifier": self.nullifier,
+            "banned_at": self.banned_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: NullifierBannedEventJSON) -> "NullifierBannedEvent":
+        return cls(
+            domain=obj["domain"],
+            nullifier=obj["nullifier"],
+            banned_at=obj["banned_at"],
+        )
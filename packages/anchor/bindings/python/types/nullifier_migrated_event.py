@@ -0,0 +1,77 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from construct import Construct
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class NullifierMigratedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    successor: list[int]
+    domain: list[int]
+    proof_hash: list[int]
+    grants: list[str]
+    migrated_at: int
+
+
+@dataclass
+class NullifierMigratedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "successor" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "proof_hash" / borsh.U8[32],
+        "grants" / borsh.Vec(typing.cast(Construct, BorshPubkey)),
+        "migrated_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    successor: list[int]
+    domain: list[int]
+    proof_hash: list[int]
+    grants: list[Pubkey]
+    migrated_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "NullifierMigratedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            successor=obj.successor,
+            domain=obj.domain,
+            proof_hash=obj.proof_hash,
+            grants=obj.grants,
+            migrated_at=obj.migrated_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "successor": self.successor,
+            "domain": self.domain,
+            "proof_hash": self.proof_hash,
+            "grants": self.grants,
+            "migrated_at": self.migrated_at,
+        }
+
+    def to_json(self) -> NullifierMigratedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "successor": self.successor,
+            "domain": self.domain,
+            "proof_hash": self.proof_hash,
+            "grants": list(map(lambda item: str(item), self.grants)),
+            "migrated_at": self.migrated_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: NullifierMigratedEventJSON) -> "NullifierMigratedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            successor=obj["successor"],
+            domain=obj["domain"],
+            proof_hash=obj["proof_hash"],
+            grants=list(map(lambda item: Pubkey.from_string(item), obj["grants"])),
+            migrated_at=obj["migrated_at"],
+        )
@@ -0,0 +1,113 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from anchorpy.borsh_extension import EnumForCodegen
+import borsh_construct as borsh
+
+
+class AccountsJSON(typing.TypedDict):
+    kind: typing.Literal["Accounts"]
+
+
+@dataclass
+class Accounts:
+    discriminator: typing.ClassVar = 0
+    kind: typing.ClassVar = "Accounts"
+
+    @classmethod
+    def to_json(cls) -> AccountsJSON:
+        return AccountsJSON(
+            kind="Accounts",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Accounts": {},
+        }
+
+
+class BitmapJSON(typing.TypedDict):
+    kind: typing.Literal["Bitmap"]
+
+
+@dataclass
+class Bitmap:
+    discriminator: typing.ClassVar = 1
+    kind: typing.ClassVar = "Bitmap"
+
+    @classmethod
+    def to_json(cls) -> BitmapJSON:
+        return BitmapJSON(
+            kind="Bitmap",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Bitmap": {},
+        }
+
+
+class CompressedJSON(typing.TypedDict):
+    kind: typing.Literal["Compressed"]
+
+
+@dataclass
+class Compressed:
+    discriminator: typing.ClassVar = 2
+    kind: typing.ClassVar = "Compressed"
+
+    @classmethod
+    def to_json(cls) -> CompressedJSON:
+        return CompressedJSON(
+            kind="Compressed",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Compressed": {},
+        }
+
+
+NullifierStoreKind = typing.Union[
+    Accounts,
+    Bitmap,
+    Compressed,
+]
+NullifierStoreJSON = typing.Union[
+    AccountsJSON,
+    BitmapJSON,
+    CompressedJSON,
+]
+
+
+def from_decoded(obj: dict) -> NullifierStoreKind:
+    if not isinstance(obj, dict):
+        raise ValueError("Invalid enum object")
+    if "Accounts" in obj:
+        return Accounts()
+    if "Bitmap" in obj:
+        return Bitmap()
+    if "Compressed" in obj:
+        return Compressed()
+    raise ValueError("Invalid enum object")
+
+
+def from_json(obj: NullifierStoreJSON) -> NullifierStoreKind:
+    if obj["kind"] == "Accounts":
+        return Accounts()
+    if obj["kind"] == "Bitmap":
+        return Bitmap()
+    if obj["kind"] == "Compressed":
+        return Compressed()
+    kind = obj["kind"]
+    raise ValueError(f"Unrecognized enum kind: {kind}")
+
+
+layout = EnumForCodegen(
+    "Accounts" / borsh.CStruct(),
+    "Bitmap" / borsh.CStruct(),
+    "Compressed" / borsh.CStruct(),
+)
@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class NullifierTreeInitializedEventJSON(typing.TypedDict):
+    domain: list[int]
+    merkle_tree: str
+    max_depth: int
+    max_buffer_size: int
+
+
+@dataclass
+class NullifierTreeInitializedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "merkle_tree" / BorshPubkey,
+        "max_depth" / borsh.U32,
+        "max_buffer_size" / borsh.U32,
+    )
+    domain: list[int]
+    merkle_tree: Pubkey
+    max_depth: int
+    max_buffer_size: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "NullifierTreeInitializedEvent":
+        return cls(
+            domain=obj.domain,
+            merkle_tree=obj.merkle_tree,
+            max_depth=obj.max_depth,
+            max_buffer_size=obj.max_buffer_size,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "merkle_tree": self.merkle_tree,
+            "max_depth": self.max_depth,
+            "max_buffer_size": self.max_buffer_size,
+        }
+
+    def to_json(self) -> NullifierTreeInitializedEventJSON:
+        return {
+            "domain": self.domain,
+            "merkle_tree": str(self.merkle_tree),
+            "max_depth": self.max_depth,
+            "max_buffer_size": self.max_buffer_size,
+        }
+
+    @classmethod
+    def from_json(cls, obj: NullifierTreeInitializedEventJSON) -> "NullifierTreeInitializedEvent":
+        return cls(
+            domain=obj["domain"],
+            merkle_tree=Pubkey.from_string(obj["merkle_tree"]),
+            max_depth=obj["max_depth"],
+            max_buffer_size=obj["max_buffer_size"],
+        )
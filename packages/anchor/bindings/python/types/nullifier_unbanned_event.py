@@ -0,0 +1,53 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class NullifierUnbannedEventJSON(typing.TypedDict):
+    domain: list[int]
+    nullifier: list[int]
+    unbanned_at: int
+
+
+@dataclass
+class NullifierUnbannedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "nullifier" / borsh.U8[32],
+        "unbanned_at" / borsh.I64,
+    )
+    domain: list[int]
+    nullifier: list[int]
+    unbanned_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "NullifierUnbannedEvent":
+        return cls(
+            domain=obj.domain,
+            nullifier=obj.nullifier,
+            unbanned_at=obj.unbanned_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "nullifier": self.nullifier,
+            "unbanned_at": self.unbanned_at,
+        }
+
+    def to_json(self) -> NullifierUnbannedEventJSON:
+        return {
+            "domain": self.domain,
+            "nullifier": self.nullifier,
+            "unbanned_at": self.unbanned_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: NullifierUnbannedEventJSON) -> "NullifierUnbannedEvent":
+        return cls(
+            domain=obj["domain"],
+            nullifier=obj["nullifier"],
+            unbanned_at=obj["unbanned_at"],
+        )
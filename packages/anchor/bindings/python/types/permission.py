@@ -0,0 +1,287 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from anchorpy.borsh_extension import EnumForCodegen
+import borsh_construct as borsh
+
+
+class RevealWalletAddressJSON(typing.TypedDict):
+    kind: typing.Literal["RevealWalletAddress"]
+
+
+@dataclass
+class RevealWalletAddress:
+    discriminator: typing.ClassVar = 0
+    kind: typing.ClassVar = "RevealWalletAddress"
+
+    @classmethod
+    def to_json(cls) -> RevealWalletAddressJSON:
+        return RevealWalletAddressJSON(
+            kind="RevealWalletAddress",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "RevealWalletAddress": {},
+        }
+
+
+class RevealExactBalanceJSON(typing.TypedDict):
+    kind: typing.Literal["RevealExactBalance"]
+
+
+@dataclass
+class RevealExactBalance:
+    discriminator: typing.ClassVar = 1
+    kind: typing.ClassVar = "RevealExactBalance"
+
+    @classmethod
+    def to_json(cls) -> RevealExactBalanceJSON:
+        return RevealExactBalanceJSON(
+            kind="RevealExactBalance",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "RevealExactBalance": {},
+        }
+
+
+class RevealTokenBalancesJSON(typing.TypedDict):
+    kind: typing.Literal["RevealTokenBalances"]
+
+
+@dataclass
+class RevealTokenBalances:
+    discriminator: typing.ClassVar = 2
+    kind: typing.ClassVar = "RevealTokenBalances"
+
+    @classmethod
+    def to_json(cls) -> RevealTokenBalancesJSON:
+        return RevealTokenBalancesJSON(
+            kind="RevealTokenBalances",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "RevealTokenBalances": {},
+        }
+
+
+class RevealNFTListJSON(typing.TypedDict):
+    kind: typing.Literal["RevealNFTList"]
+
+
+@dataclass
+class RevealNFTList:
+    discriminator: typing.ClassVar = 3
+    kind: typing.ClassVar = "RevealNFTList"
+
+    @classmethod
+    def to_json(cls) -> RevealNFTListJSON:
+        return RevealNFTListJSON(
+            kind="RevealNFTList",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "RevealNFTList": {},
+        }
+
+
+class RevealTransactionHistoryJSON(typing.TypedDict):
+    kind: typing.Literal["RevealTransactionHistory"]
+
+
+@dataclass
+class RevealTransactionHistory:
+    discriminator: typing.ClassVar = 4
+    kind: typing.ClassVar = "RevealTransactionHistory"
+
+    @classmethod
+    def to_json(cls) -> RevealTransactionHistoryJSON:
+        return RevealTransactionHistoryJSON(
+            kind="RevealTransactionHistory",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "RevealTransactionHistory": {},
+        }
+
+
+class RevealStakingPositionsJSON(typing.TypedDict):
+    kind: typing.Literal["RevealStakingPositions"]
+
+
+@dataclass
+class RevealStakingPositions:
+    discriminator: typing.ClassVar = 5
+    kind: typing.ClassVar = "RevealStakingPositions"
+
+    @classmethod
+    def to_json(cls) -> RevealStakingPositionsJSON:
+        return RevealStakingPositionsJSON(
+            kind="RevealStakingPositions",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "RevealStakingPositions": {},
+        }
+
+
+class RevealDeFiPositionsJSON(typing.TypedDict):
+    kind: typing.Literal["RevealDeFiPositions"]
+
+
+@dataclass
+class RevealDeFiPositions:
+    discriminator: typing.ClassVar = 6
+    kind: typing.ClassVar = "RevealDeFiPositions"
+
+    @classmethod
+    def to_json(cls) -> RevealDeFiPositionsJSON:
+        return RevealDeFiPositionsJSON(
+            kind="RevealDeFiPositions",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "RevealDeFiPositions": {},
+        }
+
+
+class SignTransactionsJSON(typing.TypedDict):
+    kind: typing.Literal["SignTransactions"]
+
+
+@dataclass
+class SignTransactions:
+    discriminator: typing.ClassVar = 7
+    kind: typing.ClassVar = "SignTransactions"
+
+    @classmethod
+    def to_json(cls) -> SignTransactionsJSON:
+        return SignTransactionsJSON(
+            kind="SignTransactions",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "SignTransactions": {},
+        }
+
+
+class ReceiveNotificationsJSON(typing.TypedDict):
+    kind: typing.Literal["ReceiveNotifications"]
+
+
+@dataclass
+class ReceiveNotifications:
+    discriminator: typing.ClassVar = 8
+    kind: typing.ClassVar = "ReceiveNotifications"
+
+    @classmethod
+    def to_json(cls) -> ReceiveNotificationsJSON:
+        return ReceiveNotificationsJSON(
+            kind="ReceiveNotifications",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "ReceiveNotifications": {},
+        }
+
+
+PermissionKind = typing.Union[
+    RevealWalletAddress,
+    RevealExactBalance,
+    RevealTokenBalances,
+    RevealNFTList,
+    RevealTransactionHistory,
+    RevealStakingPositions,
+    RevealDeFiPositions,
+    SignTransactions,
+    ReceiveNotifications,
+]
+PermissionJSON = typing.Union[
+    RevealWalletAddressJSON,
+    RevealExactBalanceJSON,
+    RevealTokenBalancesJSON,
+    RevealNFTListJSON,
+    RevealTransactionHistoryJSON,
+    RevealStakingPositionsJSON,
+    RevealDeFiPositionsJSON,
+    SignTransactionsJSON,
+    ReceiveNotificationsJSON,
+]
+
+
+def from_decoded(obj: dict) -> PermissionKind:
+    if not isinstance(obj, dict):
+        raise ValueError("Invalid enum object")
+    if "RevealWalletAddress" in obj:
+        return RevealWalletAddress()
+    if "RevealExactBalance" in obj:
+        return RevealExactBalance()
+    if "RevealTokenBalances" in obj:
+        return RevealTokenBalances()
+    if "RevealNFTList" in obj:
+        return RevealNFTList()
+    if "RevealTransactionHistory" in obj:
+        return RevealTransactionHistory()
+    if "RevealStakingPositions" in obj:
+        return RevealStakingPositions()
+    if "RevealDeFiPositions" in obj:
+        return RevealDeFiPositions()
+    if "SignTransactions" in obj:
+        return SignTransactions()
+    if "ReceiveNotifications" in obj:
+        return ReceiveNotifications()
+    raise ValueError("Invalid enum object")
+
+
+def from_json(obj: PermissionJSON) -> PermissionKind:
+    if obj["kind"] == "RevealWalletAddress":
+        return RevealWalletAddress()
+    if obj["kind"] == "RevealExactBalance":
+        return RevealExactBalance()
+    if obj["kind"] == "RevealTokenBalances":
+        return RevealTokenBalances()
+    if obj["kind"] == "RevealNFTList":
+        return RevealNFTList()
+    if obj["kind"] == "RevealTransactionHistory":
+        return RevealTransactionHistory()
+    if obj["kind"] == "RevealStakingPositions":
+        return RevealStakingPositions()
+    if obj["kind"] == "RevealDeFiPositions":
+        return RevealDeFiPositions()
+    if obj["kind"] == "SignTransactions":
+        return SignTransactions()
+    if obj["kind"] == "ReceiveNotifications":
+        return ReceiveNotifications()
+    kind = obj["kind"]
+    raise ValueError(f"Unrecognized enum kind: {kind}")
+
+
+layout = EnumForCodegen(
+    "RevealWalletAddress" / borsh.CStruct(),
+    "RevealExactBalance" / borsh.CStruct(),
+    "RevealTokenBalances" / borsh.CStruct(),
+    "RevealNFTList" / borsh.CStruct(),
+    "RevealTransactionHistory" / borsh.CStruct(),
+    "RevealStakingPositions" / borsh.CStruct(),
+    "RevealDeFiPositions" / borsh.CStruct(),
+    "SignTransactions" / borsh.CStruct(),
+    "ReceiveNotifications" / borsh.CStruct(),
+)
@@ -0,0 +1,72 @@
+from . import (
+    permission,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class PermissionAccessedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    app_id: str
+    permission: permission.PermissionJSON
+    permission_mask: int
+    accessed_at: int
+
+
+@dataclass
+class PermissionAccessedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "app_id" / BorshPubkey,
+        "permission" / permission.layout,
+        "permission_mask" / borsh.U64,
+        "accessed_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    app_id: Pubkey
+    permission: permission.PermissionKind
+    permission_mask: int
+    accessed_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "PermissionAccessedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            app_id=obj.app_id,
+            permission=permission.from_decoded(obj.permission),
+            permission_mask=obj.permission_mask,
+            accessed_at=obj.accessed_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": self.app_id,
+            "permission": self.permission.to_encodable(),
+            "permission_mask": self.permission_mask,
+            "accessed_at": self.accessed_at,
+        }
+
+    def to_json(self) -> PermissionAccessedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": str(self.app_id),
+            "permission": self.permission.to_json(),
+            "permission_mask": self.permission_mask,
+            "accessed_at": self.accessed_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: PermissionAccessedEventJSON) -> "PermissionAccessedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            app_id=Pubkey.from_string(obj["app_id"]),
+            permission=permission.from_json(obj["permission"]),
+            permission_mask=obj["permission_mask"],
+            accessed_at=obj["accessed_at"],
+        )
@@ -0,0 +1,113 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from anchorpy.borsh_extension import EnumForCodegen
+import borsh_construct as borsh
+
+
+class RevokedJSON(typing.TypedDict):
+    kind: typing.Literal["Revoked"]
+
+
+@dataclass
+class Revoked:
+    discriminator: typing.ClassVar = 0
+    kind: typing.ClassVar = "Revoked"
+
+    @classmethod
+    def to_json(cls) -> RevokedJSON:
+        return RevokedJSON(
+            kind="Revoked",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Revoked": {},
+        }
+
+
+class ExpiredJSON(typing.TypedDict):
+    kind: typing.Literal["Expired"]
+
+
+@dataclass
+class Expired:
+    discriminator: typing.ClassVar = 1
+    kind: typing.ClassVar = "Expired"
+
+    @classmethod
+    def to_json(cls) -> ExpiredJSON:
+        return ExpiredJSON(
+            kind="Expired",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Expired": {},
+        }
+
+
+class NotGrantedJSON(typing.TypedDict):
+    kind: typing.Literal["NotGranted"]
+
+
+@dataclass
+class NotGranted:
+    discriminator: typing.ClassVar = 2
+    kind: typing.ClassVar = "NotGranted"
+
+    @classmethod
+    def to_json(cls) -> NotGrantedJSON:
+        return NotGrantedJSON(
+            kind="NotGranted",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "NotGranted": {},
+        }
+
+
+PermissionDenialReasonKind = typing.Union[
+    Revoked,
+    Expired,
+    NotGranted,
+]
+PermissionDenialReasonJSON = typing.Union[
+    RevokedJSON,
+    ExpiredJSON,
+    NotGrantedJSON,
+]
+
+
+def from_decoded(obj: dict) -> PermissionDenialReasonKind:
+    if not isinstance(obj, dict):
+        raise ValueError("Invalid enum object")
+    if "Revoked" in obj:
+        return Revoked()
+    if "Expired" in obj:
+        return Expired()
+    if "NotGranted" in obj:
+        return NotGranted()
+    raise ValueError("Invalid enum object")
+
+
+def from_json(obj: PermissionDenialReasonJSON) -> PermissionDenialReasonKind:
+    if obj["kind"] == "Revoked":
+        return Revoked()
+    if obj["kind"] == "Expired":
+        return Expired()
+    if obj["kind"] == "NotGranted":
+        return NotGranted()
+    kind = obj["kind"]
+    raise ValueError(f"Unrecognized enum kind: {kind}")
+
+
+layout = EnumForCodegen(
+    "Revoked" / borsh.CStruct(),
+    "Expired" / borsh.CStruct(),
+    "NotGranted" / borsh.CStruct(),
+)
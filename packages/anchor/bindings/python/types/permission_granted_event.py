@@ -0,0 +1,87 @@
+from . import (
+    expiry_kind,
+    permission,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from construct import Construct
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class PermissionGrantedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    app_id: str
+    permissions: list[permission.PermissionJSON]
+    permission_mask: int
+    granted_at: int
+    expires_at: expiry_kind.ExpiryKindJSON
+
+
+@dataclass
+class PermissionGrantedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "app_id" / BorshPubkey,
+        "permissions" / borsh.Vec(typing.cast(Construct, permission.layout)),
+        "permission_mask" / borsh.U64,
+        "granted_at" / borsh.I64,
+        "expires_at" / expiry_kind.layout,
+    )
+    nullifier: list[int]
+    app_id: Pubkey
+    permissions: list[permission.PermissionKind]
+    permission_mask: int
+    granted_at: int
+    expires_at: expiry_kind.ExpiryKindKind
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "PermissionGrantedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            app_id=obj.app_id,
+            permissions=list(
+                map(lambda item: permission.from_decoded(item), obj.permissions)
+            ),
+            permission_mask=obj.permission_mask,
+            granted_at=obj.granted_at,
+            expires_at=expiry_kind.from_decoded(obj.expires_at),
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": self.app_id,
+            "permissions": list(
+                map(lambda item: item.to_encodable(), self.permissions)
+            ),
+            "permission_mask": self.permission_mask,
+            "granted_at": self.granted_at,
+            "expires_at": self.expires_at.to_encodable(),
+        }
+
+    def to_json(self) -> PermissionGrantedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": str(self.app_id),
+            "permissions": list(map(lambda item: item.to_json(), self.permissions)),
+            "permission_mask": self.permission_mask,
+            "granted_at": self.granted_at,
+            "expires_at": self.expires_at.to_json(),
+        }
+
+    @classmethod
+    def from_json(cls, obj: PermissionGrantedEventJSON) -> "PermissionGrantedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            app_id=Pubkey.from_string(obj["app_id"]),
+            permissions=list(
+                map(lambda item: permission.from_json(item), obj["permissions"])
+            ),
+            permission_mask=obj["permission_mask"],
+            granted_at=obj["granted_at"],
+            expires_at=expiry_kind.from_json(obj["expires_at"]),
+        )
@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class PermissionRevokedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    app_id: str
+    revoked_at: int
+
+
+@dataclass
+class PermissionRevokedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "app_id" / BorshPubkey,
+        "revoked_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    app_id: Pubkey
+    revoked_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "PermissionRevokedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            app_id=obj.app_id,
+            revoked_at=obj.revoked_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": self.app_id,
+            "revoked_at": self.revoked_at,
+        }
+
+    def to_json(self) -> PermissionRevokedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": str(self.app_id),
+            "revoked_at": self.revoked_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: PermissionRevokedEventJSON) -> "PermissionRevokedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            app_id=Pubkey.from_string(obj["app_id"]),
+            revoked_at=obj["revoked_at"],
+        )
@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class PersonhoodAttestationArchivedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    provider: str
+    attestation_hash: list[int]
+    archived_at: int
+
+
+@dataclass
+class PersonhoodAttestationArchivedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "provider" / BorshPubkey,
+        "attestation_hash" / borsh.U8[32],
+        "archived_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    provider: Pubkey
+    attestation_hash: list[int]
+    archived_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "PersonhoodAttestationArchivedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            provider=obj.provider,
+            attestation_hash=obj.attestation_hash,
+            archived_at=obj.archived_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "provider": self.provider,
+            "attestation_hash": self.attestation_hash,
+            "archived_at": self.archived_at,
+        }
+
+    def to_json(self) -> PersonhoodAttestationArchivedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "provider": str(self.provider),
+            "attestation_hash": self.attestation_hash,
+            "archived_at": self.archived_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: PersonhoodAttestationArchivedEventJSON) -> "PersonhoodAttestationArchivedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            provider=Pubkey.from_string(obj["provider"]),
+            attestation_hash=obj["attestation_hash"],
+            archived_at=obj["archived_at"],
+        )
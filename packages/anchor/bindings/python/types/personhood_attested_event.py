@@ -0,0 +1,69 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class PersonhoodAttestedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    provider: str
+    score: int
+    issued_at: int
+    expires_at: int
+
+
+@dataclass
+class PersonhoodAttestedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "provider" / BorshPubkey,
+        "score" / borsh.U16,
+        "issued_at" / borsh.I64,
+        "expires_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    provider: Pubkey
+    score: int
+    issued_at: int
+    expires_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "PersonhoodAttestedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            provider=obj.provider,
+            score=obj.score,
+            issued_at=obj.issued_at,
+            expires_at=obj.expires_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "provider": self.provider,
+            "score": self.score,
+            "issued_at": self.issued_at,
+            "expires_at": self.expires_at,
+        }
+
+    def to_json(self) -> PersonhoodAttestedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "provider": str(self.provider),
+            "score": self.score,
+            "issued_at": self.issued_at,
+            "expires_at": self.expires_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: PersonhoodAttestedEventJSON) -> "PersonhoodAttestedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            provider=Pubkey.from_string(obj["provider"]),
+            score=obj["score"],
+            issued_at=obj["issued_at"],
+            expires_at=obj["expires_at"],
+        )
@@ -0,0 +1,41 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class PersonhoodProviderRemovedEventJSON(typing.TypedDict):
+    provider: str
+
+
+@dataclass
+class PersonhoodProviderRemovedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "provider" / BorshPubkey,
+    )
+    provider: Pubkey
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "PersonhoodProviderRemovedEvent":
+        return cls(
+            provider=obj.provider,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "provider": self.provider,
+        }
+
+    def to_json(self) -> PersonhoodProviderRemovedEventJSON:
+        return {
+            "provider": str(self.provider),
+        }
+
+    @classmethod
+    def from_json(cls, obj: PersonhoodProviderRemovedEventJSON) -> "PersonhoodProviderRemovedEvent":
+        return cls(
+            provider=Pubkey.from_string(obj["provider"]),
+        )
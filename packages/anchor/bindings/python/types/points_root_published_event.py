@@ -0,0 +1,53 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class PointsRootPublishedEventJSON(typing.TypedDict):
+    root: list[int]
+    leaf_count: int
+    published_at: int
+
+
+@dataclass
+class PointsRootPublishedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "root" / borsh.U8[32],
+        "leaf_count" / borsh.U32,
+        "published_at" / borsh.I64,
+    )
+    root: list[int]
+    leaf_count: int
+    published_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "PointsRootPublishedEvent":
+        return cls(
+            root=obj.root,
+            leaf_count=obj.leaf_count,
+            published_at=obj.published_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "root": self.root,
+            "leaf_count": self.leaf_count,
+            "published_at": self.published_at,
+        }
+
+    def to_json(self) -> PointsRootPublishedEventJSON:
+        return {
+            "root": self.root,
+            "leaf_count": self.leaf_count,
+            "published_at": self.published_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: PointsRootPublishedEventJSON) -> "PointsRootPublishedEvent":
+        return cls(
+            root=obj["root"],
+            leaf_count=obj["leaf_count"],
+            published_at=obj["published_at"],
+        )
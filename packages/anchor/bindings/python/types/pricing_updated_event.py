@@ -0,0 +1,53 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class PricingUpdatedEventJSON(typing.TypedDict):
+    fee_usd_micros: int
+    max_price_age_secs: int
+    max_price_conf_bps: int
+
+
+@dataclass
+class PricingUpdatedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "fee_usd_micros" / borsh.U64,
+        "max_price_age_secs" / borsh.U64,
+        "max_price_conf_bps" / borsh.U16,
+    )
+    fee_usd_micros: int
+    max_price_age_secs: int
+    max_price_conf_bps: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "PricingUpdatedEvent":
+        return cls(
+            fee_usd_micros=obj.fee_usd_micros,
+            max_price_age_secs=obj.max_price_age_secs,
+            max_price_conf_bps=obj.max_price_conf_bps,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "fee_usd_micros": self.fee_usd_micros,
+            "max_price_age_secs": self.max_price_age_secs,
+            "max_price_conf_bps": self.max_price_conf_bps,
+        }
+
+    def to_json(self) -> PricingUpdatedEventJSON:
+        return {
+            "fee_usd_micros": self.fee_usd_micros,
+            "max_price_age_secs": self.max_price_age_secs,
+            "max_price_conf_bps": self.max_price_conf_bps,
+        }
+
+    @classmethod
+    def from_json(cls, obj: PricingUpdatedEventJSON) -> "PricingUpdatedEvent":
+        return cls(
+            fee_usd_micros=obj["fee_usd_micros"],
+            max_price_age_secs=obj["max_price_age_secs"],
+            max_price_conf_bps=obj["max_price_conf_bps"],
+        )
@@ -0,0 +1,39 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class ReceiptRetentionSetEventJSON(typing.TypedDict):
+    receipt_retention_secs: int
+
+
+@dataclass
+class ReceiptRetentionSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "receipt_retention_secs" / borsh.I64,
+    )
+    receipt_retention_secs: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "ReceiptRetentionSetEvent":
+        return cls(
+            receipt_retention_secs=obj.receipt_retention_secs,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "receipt_retention_secs": self.receipt_retention_secs,
+        }
+
+    def to_json(self) -> ReceiptRetentionSetEventJSON:
+        return {
+            "receipt_retention_secs": self.receipt_retention_secs,
+        }
+
+    @classmethod
+    def from_json(cls, obj: ReceiptRetentionSetEventJSON) -> "ReceiptRetentionSetEvent":
+        return cls(
+            receipt_retention_secs=obj["receipt_retention_secs"],
+        )
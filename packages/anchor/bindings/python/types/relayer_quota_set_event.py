@@ -0,0 +1,48 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class RelayerQuotaSetEventJSON(typing.TypedDict):
+    relayer: str
+    quota: int
+
+
+@dataclass
+class RelayerQuotaSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "relayer" / BorshPubkey,
+        "quota" / borsh.U32,
+    )
+    relayer: Pubkey
+    quota: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "RelayerQuotaSetEvent":
+        return cls(
+            relayer=obj.relayer,
+            quota=obj.quota,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "relayer": self.relayer,
+            "quota": self.quota,
+        }
+
+    def to_json(self) -> RelayerQuotaSetEventJSON:
+        return {
+            "relayer": str(self.relayer),
+            "quota": self.quota,
+        }
+
+    @classmethod
+    def from_json(cls, obj: RelayerQuotaSetEventJSON) -> "RelayerQuotaSetEvent":
+        return cls(
+            relayer=Pubkey.from_string(obj["relayer"]),
+            quota=obj["quota"],
+        )
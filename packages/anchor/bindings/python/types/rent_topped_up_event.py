@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class RentToppedUpEventJSON(typing.TypedDict):
+    account: str
+    payer: str
+    lamports: int
+
+
+@dataclass
+class RentToppedUpEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "account" / BorshPubkey,
+        "payer" / BorshPubkey,
+        "lamports" / borsh.U64,
+    )
+    account: Pubkey
+    payer: Pubkey
+    lamports: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "RentToppedUpEvent":
+        return cls(
+            account=obj.account,
+            payer=obj.payer,
+            lamports=obj.lamports,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "account": self.account,
+            "payer": self.payer,
+            "lamports": self.lamports,
+        }
+
+    def to_json(self) -> RentToppedUpEventJSON:
+        return {
+            "account": str(self.account),
+            "payer": str(self.payer),
+            "lamports": self.lamports,
+        }
+
+    @classmethod
+    def from_json(cls, obj: RentToppedUpEventJSON) -> "RentToppedUpEvent":
+        return cls(
+            account=Pubkey.from_string(obj["account"]),
+            payer=Pubkey.from_string(obj["payer"]),
+            lamports=obj["lamports"],
+        )
@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class ResultReceiptClosedEventJSON(typing.TypedDict):
+    signature_hash: list[int]
+    payer: str
+    closed_at: int
+
+
+@dataclass
+class ResultReceiptClosedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "signature_hash" / borsh.U8[32],
+        "payer" / BorshPubkey,
+        "closed_at" / borsh.I64,
+    )
+    signature_hash: list[int]
+    payer: Pubkey
+    closed_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "ResultReceiptClosedEvent":
+        return cls(
+            signature_hash=obj.signature_hash,
+            payer=obj.payer,
+            closed_at=obj.closed_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "signature_hash": self.signature_hash,
+            "payer": self.payer,
+            "closed_at": self.closed_at,
+        }
+
+    def to_json(self) -> ResultReceiptClosedEventJSON:
+        return {
+            "signature_hash": self.signature_hash,
+            "payer": str(self.payer),
+            "closed_at": self.closed_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: ResultReceiptClosedEventJSON) -> "ResultReceiptClosedEvent":
+        return cls(
+            signature_hash=obj["signature_hash"],
+            payer=Pubkey.from_string(obj["payer"]),
+            closed_at=obj["closed_at"],
+        )
@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class RevealEscrowCancelledEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    app_id: str
+    released: bool
+    cancelled_at: int
+
+
+@dataclass
+class RevealEscrowCancelledEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "app_id" / BorshPubkey,
+        "released" / borsh.Bool,
+        "cancelled_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    app_id: Pubkey
+    released: bool
+    cancelled_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "RevealEscrowCancelledEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            app_id=obj.app_id,
+            released=obj.released,
+            cancelled_at=obj.cancelled_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": self.app_id,
+            "released": self.released,
+            "cancelled_at": self.cancelled_at,
+        }
+
+    def to_json(self) -> RevealEscrowCancelledEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": str(self.app_id),
+            "released": self.released,
+            "cancelled_at": self.cancelled_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: RevealEscrowCancelledEventJSON) -> "RevealEscrowCancelledEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            app_id=Pubkey.from_string(obj["app_id"]),
+            released=obj["released"],
+            cancelled_at=obj["cancelled_at"],
+        )
@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class RevealEscrowOpenedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    app_id: str
+    key_commitment: list[int]
+    reveals_at: int
+
+
+@dataclass
+class RevealEscrowOpenedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "app_id" / BorshPubkey,
+        "key_commitment" / borsh.U8[32],
+        "reveals_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    app_id: Pubkey
+    key_commitment: list[int]
+    reveals_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "RevealEscrowOpenedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            app_id=obj.app_id,
+            key_commitment=obj.key_commitment,
+            reveals_at=obj.reveals_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": self.app_id,
+            "key_commitment": self.key_commitment,
+            "reveals_at": self.reveals_at,
+        }
+
+    def to_json(self) -> RevealEscrowOpenedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": str(self.app_id),
+            "key_commitment": self.key_commitment,
+            "reveals_at": self.reveals_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: RevealEscrowOpenedEventJSON) -> "RevealEscrowOpenedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            app_id=Pubkey.from_string(obj["app_id"]),
+            key_commitment=obj["key_commitment"],
+            reveals_at=obj["reveals_at"],
+        )
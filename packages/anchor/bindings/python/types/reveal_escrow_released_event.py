@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class RevealEscrowReleasedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    app_id: str
+    reveal_key: list[int]
+    released_at: int
+
+
+@dataclass
+class RevealEscrowReleasedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "app_id" / BorshPubkey,
+        "reveal_key" / borsh.U8[32],
+        "released_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    app_id: Pubkey
+    reveal_key: list[int]
+    released_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "RevealEscrowReleasedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            app_id=obj.app_id,
+            reveal_key=obj.reveal_key,
+            released_at=obj.released_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": self.app_id,
+            "reveal_key": self.reveal_key,
+            "released_at": self.released_at,
+        }
+
+    def to_json(self) -> RevealEscrowReleasedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "app_id": str(self.app_id),
+            "reveal_key": self.reveal_key,
+            "released_at": self.released_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: RevealEscrowReleasedEventJSON) -> "RevealEscrowReleasedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            app_id=Pubkey.from_string(obj["app_id"]),
+            reveal_key=obj["reveal_key"],
+            released_at=obj["released_at"],
+        )
@@ -0,0 +1,60 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class RootMemberJSON(typing.TypedDict):
+    nullifier: list[int]
+    domain: list[int]
+    linkage_proof_hash: list[int]
+    linked_at: int
+
+
+@dataclass
+class RootMember:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "linkage_proof_hash" / borsh.U8[32],
+        "linked_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    domain: list[int]
+    linkage_proof_hash: list[int]
+    linked_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "RootMember":
+        return cls(
+            nullifier=obj.nullifier,
+            domain=obj.domain,
+            linkage_proof_hash=obj.linkage_proof_hash,
+            linked_at=obj.linked_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "linkage_proof_hash": self.linkage_proof_hash,
+            "linked_at": self.linked_at,
+        }
+
+    def to_json(self) -> RootMemberJSON:
+        return {
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "linkage_proof_hash": self.linkage_proof_hash,
+            "linked_at": self.linked_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: RootMemberJSON) -> "RootMember":
+        return cls(
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+            linkage_proof_hash=obj["linkage_proof_hash"],
+            linked_at=obj["linked_at"],
+        )
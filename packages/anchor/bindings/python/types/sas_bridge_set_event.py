@@ -0,0 +1,48 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class SasBridgeSetEventJSON(typing.TypedDict):
+    credential: str
+    schema: str
+
+
+@dataclass
+class SasBridgeSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "credential" / BorshPubkey,
+        "schema" / BorshPubkey,
+    )
+    credential: Pubkey
+    schema: Pubkey
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "SasBridgeSetEvent":
+        return cls(
+            credential=obj.credential,
+            schema=obj.schema,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "credential": self.credential,
+            "schema": self.schema,
+        }
+
+    def to_json(self) -> SasBridgeSetEventJSON:
+        return {
+            "credential": str(self.credential),
+            "schema": str(self.schema),
+        }
+
+    @classmethod
+    def from_json(cls, obj: SasBridgeSetEventJSON) -> "SasBridgeSetEvent":
+        return cls(
+            credential=Pubkey.from_string(obj["credential"]),
+            schema=Pubkey.from_string(obj["schema"]),
+        )
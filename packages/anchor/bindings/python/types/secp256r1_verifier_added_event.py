@@ -0,0 +1,39 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class Secp256r1VerifierAddedEventJSON(typing.TypedDict):
+    verifier: list[int]
+
+
+@dataclass
+class Secp256r1VerifierAddedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "verifier" / borsh.U8[33],
+    )
+    verifier: list[int]
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "Secp256r1VerifierAddedEvent":
+        return cls(
+            verifier=obj.verifier,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "verifier": self.verifier,
+        }
+
+    def to_json(self) -> Secp256r1VerifierAddedEventJSON:
+        return {
+            "verifier": self.verifier,
+        }
+
+    @classmethod
+    def from_json(cls, obj: Secp256r1VerifierAddedEventJSON) -> "Secp256r1VerifierAddedEvent":
+        return cls(
+            verifier=obj["verifier"],
+        )
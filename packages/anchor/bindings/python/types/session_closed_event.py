@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class SessionClosedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    domain: list[int]
+    authority: str
+    closed_at: int
+
+
+@dataclass
+class SessionClosedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "authority" / BorshPubkey,
+        "closed_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    domain: list[int]
+    authority: Pubkey
+    closed_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "SessionClosedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            domain=obj.domain,
+            authority=obj.authority,
+            closed_at=obj.closed_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "authority": self.authority,
+            "closed_at": self.closed_at,
+        }
+
+    def to_json(self) -> SessionClosedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "authority": str(self.authority),
+            "closed_at": self.closed_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: SessionClosedEventJSON) -> "SessionClosedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+            authority=Pubkey.from_string(obj["authority"]),
+            closed_at=obj["closed_at"],
+        )
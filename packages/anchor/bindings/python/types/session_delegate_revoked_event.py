@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class SessionDelegateRevokedEventJSON(typing.TypedDict):
+    session: str
+    delegate: str
+    revoked_at: int
+
+
+@dataclass
+class SessionDelegateRevokedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "session" / BorshPubkey,
+        "delegate" / BorshPubkey,
+        "revoked_at" / borsh.I64,
+    )
+    session: Pubkey
+    delegate: Pubkey
+    revoked_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "SessionDelegateRevokedEvent":
+        return cls(
+            session=obj.session,
+            delegate=obj.delegate,
+            revoked_at=obj.revoked_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "session": self.session,
+            "delegate": self.delegate,
+            "revoked_at": self.revoked_at,
+        }
+
+    def to_json(self) -> SessionDelegateRevokedEventJSON:
+        return {
+            "session": str(self.session),
+            "delegate": str(self.delegate),
+            "revoked_at": self.revoked_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: SessionDelegateRevokedEventJSON) -> "SessionDelegateRevokedEvent":
+        return cls(
+            session=Pubkey.from_string(obj["session"]),
+            delegate=Pubkey.from_string(obj["delegate"]),
+            revoked_at=obj["revoked_at"],
+        )
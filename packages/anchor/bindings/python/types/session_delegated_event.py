@@ -0,0 +1,69 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class SessionDelegatedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    session: str
+    delegate: str
+    scope: int
+    expires_at: int
+
+
+@dataclass
+class SessionDelegatedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "session" / BorshPubkey,
+        "delegate" / BorshPubkey,
+        "scope" / borsh.U64,
+        "expires_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    session: Pubkey
+    delegate: Pubkey
+    scope: int
+    expires_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "SessionDelegatedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            session=obj.session,
+            delegate=obj.delegate,
+            scope=obj.scope,
+            expires_at=obj.expires_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "session": self.session,
+            "delegate": self.delegate,
+            "scope": self.scope,
+            "expires_at": self.expires_at,
+        }
+
+    def to_json(self) -> SessionDelegatedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "session": str(self.session),
+            "delegate": str(self.delegate),
+            "scope": self.scope,
+            "expires_at": self.expires_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: SessionDelegatedEventJSON) -> "SessionDelegatedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            session=Pubkey.from_string(obj["session"]),
+            delegate=Pubkey.from_string(obj["delegate"]),
+            scope=obj["scope"],
+            expires_at=obj["expires_at"],
+        )
@@ -0,0 +1,58 @@
+from . import (
+    expiry_kind,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class SessionDetailsJSON(typing.TypedDict):
+    nullifier_account: str
+    created_at: int
+    expires_at: expiry_kind.ExpiryKindJSON
+
+
+@dataclass
+class SessionDetails:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier_account" / BorshPubkey,
+        "created_at" / borsh.I64,
+        "expires_at" / expiry_kind.layout,
+    )
+    nullifier_account: Pubkey
+    created_at: int
+    expires_at: expiry_kind.ExpiryKindKind
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "SessionDetails":
+        return cls(
+            nullifier_account=obj.nullifier_account,
+            created_at=obj.created_at,
+            expires_at=expiry_kind.from_decoded(obj.expires_at),
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier_account": self.nullifier_account,
+            "created_at": self.created_at,
+            "expires_at": self.expires_at.to_encodable(),
+        }
+
+    def to_json(self) -> SessionDetailsJSON:
+        return {
+            "nullifier_account": str(self.nullifier_account),
+            "created_at": self.created_at,
+            "expires_at": self.expires_at.to_json(),
+        }
+
+    @classmethod
+    def from_json(cls, obj: SessionDetailsJSON) -> "SessionDetails":
+        return cls(
+            nullifier_account=Pubkey.from_string(obj["nullifier_account"]),
+            created_at=obj["created_at"],
+            expires_at=expiry_kind.from_json(obj["expires_at"]),
+        )
@@ -0,0 +1,67 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class ShardedNullifierRegisteredEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    domain: list[int]
+    proof_hash: list[int]
+    shard: int
+    registered_at: int
+
+
+@dataclass
+class ShardedNullifierRegisteredEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "proof_hash" / borsh.U8[32],
+        "shard" / borsh.U16,
+        "registered_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    domain: list[int]
+    proof_hash: list[int]
+    shard: int
+    registered_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "ShardedNullifierRegisteredEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            domain=obj.domain,
+            proof_hash=obj.proof_hash,
+            shard=obj.shard,
+            registered_at=obj.registered_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "proof_hash": self.proof_hash,
+            "shard": self.shard,
+            "registered_at": self.registered_at,
+        }
+
+    def to_json(self) -> ShardedNullifierRegisteredEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "proof_hash": self.proof_hash,
+            "shard": self.shard,
+            "registered_at": self.registered_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: ShardedNullifierRegisteredEventJSON) -> "ShardedNullifierRegisteredEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+            proof_hash=obj["proof_hash"],
+            shard=obj["shard"],
+            registered_at=obj["registered_at"],
+        )
@@ -0,0 +1,126 @@
+from . import (
+    claim_type,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class SignedVerificationResultJSON(typing.TypedDict):
+    is_valid: bool
+    proof_hash: list[int]
+    timestamp: int
+    nullifier: list[int]
+    domain: list[int]
+    public_inputs_hash: typing.Optional[list[int]]
+    circuit_id: typing.Optional[int]
+    vk_hash: typing.Optional[list[int]]
+    cluster_id: typing.Optional[list[int]]
+    instance_id: typing.Optional[list[int]]
+    claim_type: typing.Optional[claim_type.ClaimTypeJSON]
+    tagged: bool
+    signature: list[int]
+
+
+@dataclass
+class SignedVerificationResult:
+    layout: typing.ClassVar = borsh.CStruct(
+        "is_valid" / borsh.Bool,
+        "proof_hash" / borsh.U8[32],
+        "timestamp" / borsh.U64,
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "public_inputs_hash" / borsh.Option(borsh.U8[32]),
+        "circuit_id" / borsh.Option(borsh.U32),
+        "vk_hash" / borsh.Option(borsh.U8[32]),
+        "cluster_id" / borsh.Option(borsh.U8[32]),
+        "instance_id" / borsh.Option(borsh.U8[32]),
+        "claim_type" / borsh.Option(claim_type.layout),
+        "tagged" / borsh.Bool,
+        "signature" / borsh.U8[64],
+    )
+    is_valid: bool
+    proof_hash: list[int]
+    timestamp: int
+    nullifier: list[int]
+    domain: list[int]
+    public_inputs_hash: typing.Optional[list[int]]
+    circuit_id: typing.Optional[int]
+    vk_hash: typing.Optional[list[int]]
+    cluster_id: typing.Optional[list[int]]
+    instance_id: typing.Optional[list[int]]
+    claim_type: typing.Optional[claim_type.ClaimTypeKind]
+    tagged: bool
+    signature: list[int]
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "SignedVerificationResult":
+        return cls(
+            is_valid=obj.is_valid,
+            proof_hash=obj.proof_hash,
+            timestamp=obj.timestamp,
+            nullifier=obj.nullifier,
+            domain=obj.domain,
+            public_inputs_hash=obj.public_inputs_hash,
+            circuit_id=obj.circuit_id,
+            vk_hash=obj.vk_hash,
+            cluster_id=obj.cluster_id,
+            instance_id=obj.instance_id,
+            claim_type=(None if obj.claim_type is None else claim_type.from_decoded(obj.claim_type)),
+            tagged=obj.tagged,
+            signature=obj.signature,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "is_valid": self.is_valid,
+            "proof_hash": self.proof_hash,
+            "timestamp": self.timestamp,
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "public_inputs_hash": self.public_inputs_hash,
+            "circuit_id": self.circuit_id,
+            "vk_hash": self.vk_hash,
+            "cluster_id": self.cluster_id,
+            "instance_id": self.instance_id,
+            "claim_type": (None if self.claim_type is None else self.claim_type.to_encodable()),
+            "tagged": self.tagged,
+            "signature": self.signature,
+        }
+
+    def to_json(self) -> SignedVerificationResultJSON:
+        return {
+            "is_valid": self.is_valid,
+            "proof_hash": self.proof_hash,
+            "timestamp": self.timestamp,
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "public_inputs_hash": self.public_inputs_hash,
+            "circuit_id": self.circuit_id,
+            "vk_hash": self.vk_hash,
+            "cluster_id": self.cluster_id,
+            "instance_id": self.instance_id,
+            "claim_type": (None if self.claim_type is None else self.claim_type.to_json()),
+            "tagged": self.tagged,
+            "signature": self.signature,
+        }
+
+    @classmethod
+    def from_json(cls, obj: SignedVerificationResultJSON) -> "SignedVerificationResult":
+        return cls(
+            is_valid=obj["is_valid"],
+            proof_hash=obj["proof_hash"],
+            timestamp=obj["timestamp"],
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+            public_inputs_hash=obj["public_inputs_hash"],
+            circuit_id=obj["circuit_id"],
+            vk_hash=obj["vk_hash"],
+            cluster_id=obj["cluster_id"],
+            instance_id=obj["instance_id"],
+            claim_type=(None if obj["claim_type"] is None else claim_type.from_json(obj["claim_type"])),
+            tagged=obj["tagged"],
+            signature=obj["signature"],
+        )
@@ -0,0 +1,48 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class TreasuryAtaCreatedEventJSON(typing.TypedDict):
+    mint: str
+    treasury_ata: str
+
+
+@dataclass
+class TreasuryAtaCreatedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "mint" / BorshPubkey,
+        "treasury_ata" / BorshPubkey,
+    )
+    mint: Pubkey
+    treasury_ata: Pubkey
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "TreasuryAtaCreatedEvent":
+        return cls(
+            mint=obj.mint,
+            treasury_ata=obj.treasury_ata,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "mint": self.mint,
+            "treasury_ata": self.treasury_ata,
+        }
+
+    def to_json(self) -> TreasuryAtaCreatedEventJSON:
+        return {
+            "mint": str(self.mint),
+            "treasury_ata": str(self.treasury_ata),
+        }
+
+    @classmethod
+    def from_json(cls, obj: TreasuryAtaCreatedEventJSON) -> "TreasuryAtaCreatedEvent":
+        return cls(
+            mint=Pubkey.from_string(obj["mint"]),
+            treasury_ata=Pubkey.from_string(obj["treasury_ata"]),
+        )
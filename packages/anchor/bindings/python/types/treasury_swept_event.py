@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class TreasurySweptEventJSON(typing.TypedDict):
+    mint: str
+    destination: str
+    amount: int
+
+
+@dataclass
+class TreasurySweptEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "mint" / BorshPubkey,
+        "destination" / BorshPubkey,
+        "amount" / borsh.U64,
+    )
+    mint: Pubkey
+    destination: Pubkey
+    amount: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "TreasurySweptEvent":
+        return cls(
+            mint=obj.mint,
+            destination=obj.destination,
+            amount=obj.amount,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "mint": self.mint,
+            "destination": self.destination,
+            "amount": self.amount,
+        }
+
+    def to_json(self) -> TreasurySweptEventJSON:
+        return {
+            "mint": str(self.mint),
+            "destination": str(self.destination),
+            "amount": self.amount,
+        }
+
+    @classmethod
+    def from_json(cls, obj: TreasurySweptEventJSON) -> "TreasurySweptEvent":
+        return cls(
+            mint=Pubkey.from_string(obj["mint"]),
+            destination=Pubkey.from_string(obj["destination"]),
+            amount=obj["amount"],
+        )
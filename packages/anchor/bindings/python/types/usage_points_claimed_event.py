@@ -0,0 +1,60 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class UsagePointsClaimedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    amount: int
+    root: list[int]
+    claimed_at: int
+
+
+@dataclass
+class UsagePointsClaimedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "amount" / borsh.U64,
+        "root" / borsh.U8[32],
+        "claimed_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    amount: int
+    root: list[int]
+    claimed_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "UsagePointsClaimedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            amount=obj.amount,
+            root=obj.root,
+            claimed_at=obj.claimed_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "amount": self.amount,
+            "root": self.root,
+            "claimed_at": self.claimed_at,
+        }
+
+    def to_json(self) -> UsagePointsClaimedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "amount": self.amount,
+            "root": self.root,
+            "claimed_at": self.claimed_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: UsagePointsClaimedEventJSON) -> "UsagePointsClaimedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            amount=obj["amount"],
+            root=obj["root"],
+            claimed_at=obj["claimed_at"],
+        )
@@ -0,0 +1,48 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class UserConsentJSON(typing.TypedDict):
+    user: str
+    expires_at: int
+
+
+@dataclass
+class UserConsent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "user" / BorshPubkey,
+        "expires_at" / borsh.I64,
+    )
+    user: Pubkey
+    expires_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "UserConsent":
+        return cls(
+            user=obj.user,
+            expires_at=obj.expires_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "user": self.user,
+            "expires_at": self.expires_at,
+        }
+
+    def to_json(self) -> UserConsentJSON:
+        return {
+            "user": str(self.user),
+            "expires_at": self.expires_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: UserConsentJSON) -> "UserConsent":
+        return cls(
+            user=Pubkey.from_string(obj["user"]),
+            expires_at=obj["expires_at"],
+        )
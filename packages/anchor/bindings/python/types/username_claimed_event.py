@@ -0,0 +1,60 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class UsernameClaimedEventJSON(typing.TypedDict):
+    name: list[int]
+    nullifier: list[int]
+    domain: list[int]
+    claimed_at: int
+
+
+@dataclass
+class UsernameClaimedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "name" / borsh.U8[32],
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "claimed_at" / borsh.I64,
+    )
+    name: list[int]
+    nullifier: list[int]
+    domain: list[int]
+    claimed_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "UsernameClaimedEvent":
+        return cls(
+            name=obj.name,
+            nullifier=obj.nullifier,
+            domain=obj.domain,
+            claimed_at=obj.claimed_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "name": self.name,
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "claimed_at": self.claimed_at,
+        }
+
+    def to_json(self) -> UsernameClaimedEventJSON:
+        return {
+            "name": self.name,
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "claimed_at": self.claimed_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: UsernameClaimedEventJSON) -> "UsernameClaimedEvent":
+        return cls(
+            name=obj["name"],
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+            claimed_at=obj["claimed_at"],
+        )
@@ -0,0 +1,53 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class UsernameReleasedEventJSON(typing.TypedDict):
+    name: list[int]
+    nullifier: list[int]
+    released_at: int
+
+
+@dataclass
+class UsernameReleasedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "name" / borsh.U8[32],
+        "nullifier" / borsh.U8[32],
+        "released_at" / borsh.I64,
+    )
+    name: list[int]
+    nullifier: list[int]
+    released_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "UsernameReleasedEvent":
+        return cls(
+            name=obj.name,
+            nullifier=obj.nullifier,
+            released_at=obj.released_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "name": self.name,
+            "nullifier": self.nullifier,
+            "released_at": self.released_at,
+        }
+
+    def to_json(self) -> UsernameReleasedEventJSON:
+        return {
+            "name": self.name,
+            "nullifier": self.nullifier,
+            "released_at": self.released_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: UsernameReleasedEventJSON) -> "UsernameReleasedEvent":
+        return cls(
+            name=obj["name"],
+            nullifier=obj["nullifier"],
+            released_at=obj["released_at"],
+        )
@@ -0,0 +1,60 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class UsernameTransferredEventJSON(typing.TypedDict):
+    name: list[int]
+    from_nullifier: list[int]
+    to_nullifier: list[int]
+    transferred_at: int
+
+
+@dataclass
+class UsernameTransferredEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "name" / borsh.U8[32],
+        "from_nullifier" / borsh.U8[32],
+        "to_nullifier" / borsh.U8[32],
+        "transferred_at" / borsh.I64,
+    )
+    name: list[int]
+    from_nullifier: list[int]
+    to_nullifier: list[int]
+    transferred_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "UsernameTransferredEvent":
+        return cls(
+            name=obj.name,
+            from_nullifier=obj.from_nullifier,
+            to_nullifier=obj.to_nullifier,
+            transferred_at=obj.transferred_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "name": self.name,
+            "from_nullifier": self.from_nullifier,
+            "to_nullifier": self.to_nullifier,
+            "transferred_at": self.transferred_at,
+        }
+
+    def to_json(self) -> UsernameTransferredEventJSON:
+        return {
+            "name": self.name,
+            "from_nullifier": self.from_nullifier,
+            "to_nullifier": self.to_nullifier,
+            "transferred_at": self.transferred_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: UsernameTransferredEventJSON) -> "UsernameTransferredEvent":
+        return cls(
+            name=obj["name"],
+            from_nullifier=obj["from_nullifier"],
+            to_nullifier=obj["to_nullifier"],
+            transferred_at=obj["transferred_at"],
+        )
@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class VcCommitmentIssuedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    issuer: str
+    vc_hash: list[int]
+    issued_at: int
+
+
+@dataclass
+class VcCommitmentIssuedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "issuer" / BorshPubkey,
+        "vc_hash" / borsh.U8[32],
+        "issued_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    issuer: Pubkey
+    vc_hash: list[int]
+    issued_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "VcCommitmentIssuedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            issuer=obj.issuer,
+            vc_hash=obj.vc_hash,
+            issued_at=obj.issued_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "issuer": self.issuer,
+            "vc_hash": self.vc_hash,
+            "issued_at": self.issued_at,
+        }
+
+    def to_json(self) -> VcCommitmentIssuedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "issuer": str(self.issuer),
+            "vc_hash": self.vc_hash,
+            "issued_at": self.issued_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VcCommitmentIssuedEventJSON) -> "VcCommitmentIssuedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            issuer=Pubkey.from_string(obj["issuer"]),
+            vc_hash=obj["vc_hash"],
+            issued_at=obj["issued_at"],
+        )
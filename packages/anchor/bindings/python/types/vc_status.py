@@ -0,0 +1,113 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from anchorpy.borsh_extension import EnumForCodegen
+import borsh_construct as borsh
+
+
+class ActiveJSON(typing.TypedDict):
+    kind: typing.Literal["Active"]
+
+
+@dataclass
+class Active:
+    discriminator: typing.ClassVar = 0
+    kind: typing.ClassVar = "Active"
+
+    @classmethod
+    def to_json(cls) -> ActiveJSON:
+        return ActiveJSON(
+            kind="Active",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Active": {},
+        }
+
+
+class SuspendedJSON(typing.TypedDict):
+    kind: typing.Literal["Suspended"]
+
+
+@dataclass
+class Suspended:
+    discriminator: typing.ClassVar = 1
+    kind: typing.ClassVar = "Suspended"
+
+    @classmethod
+    def to_json(cls) -> SuspendedJSON:
+        return SuspendedJSON(
+            kind="Suspended",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Suspended": {},
+        }
+
+
+class RevokedJSON(typing.TypedDict):
+    kind: typing.Literal["Revoked"]
+
+
+@dataclass
+class Revoked:
+    discriminator: typing.ClassVar = 2
+    kind: typing.ClassVar = "Revoked"
+
+    @classmethod
+    def to_json(cls) -> RevokedJSON:
+        return RevokedJSON(
+            kind="Revoked",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Revoked": {},
+        }
+
+
+VcStatusKind = typing.Union[
+    Active,
+    Suspended,
+    Revoked,
+]
+VcStatusJSON = typing.Union[
+    ActiveJSON,
+    SuspendedJSON,
+    RevokedJSON,
+]
+
+
+def from_decoded(obj: dict) -> VcStatusKind:
+    if not isinstance(obj, dict):
+        raise ValueError("Invalid enum object")
+    if "Active" in obj:
+        return Active()
+    if "Suspended" in obj:
+        return Suspended()
+    if "Revoked" in obj:
+        return Revoked()
+    raise ValueError("Invalid enum object")
+
+
+def from_json(obj: VcStatusJSON) -> VcStatusKind:
+    if obj["kind"] == "Active":
+        return Active()
+    if obj["kind"] == "Suspended":
+        return Suspended()
+    if obj["kind"] == "Revoked":
+        return Revoked()
+    kind = obj["kind"]
+    raise ValueError(f"Unrecognized enum kind: {kind}")
+
+
+layout = EnumForCodegen(
+    "Active" / borsh.CStruct(),
+    "Suspended" / borsh.CStruct(),
+    "Revoked" / borsh.CStruct(),
+)
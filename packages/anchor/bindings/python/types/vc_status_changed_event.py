@@ -0,0 +1,72 @@
+from . import (
+    vc_status,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class VcStatusChangedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    issuer: str
+    vc_hash: list[int]
+    status: vc_status.VcStatusJSON
+    updated_at: int
+
+
+@dataclass
+class VcStatusChangedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "issuer" / BorshPubkey,
+        "vc_hash" / borsh.U8[32],
+        "status" / vc_status.layout,
+        "updated_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    issuer: Pubkey
+    vc_hash: list[int]
+    status: vc_status.VcStatusKind
+    updated_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "VcStatusChangedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            issuer=obj.issuer,
+            vc_hash=obj.vc_hash,
+            status=vc_status.from_decoded(obj.status),
+            updated_at=obj.updated_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "issuer": self.issuer,
+            "vc_hash": self.vc_hash,
+            "status": self.status.to_encodable(),
+            "updated_at": self.updated_at,
+        }
+
+    def to_json(self) -> VcStatusChangedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "issuer": str(self.issuer),
+            "vc_hash": self.vc_hash,
+            "status": self.status.to_json(),
+            "updated_at": self.updated_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VcStatusChangedEventJSON) -> "VcStatusChangedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            issuer=Pubkey.from_string(obj["issuer"]),
+            vc_hash=obj["vc_hash"],
+            status=vc_status.from_json(obj["status"]),
+            updated_at=obj["updated_at"],
+        )
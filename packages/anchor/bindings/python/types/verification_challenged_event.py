@@ -0,0 +1,69 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class VerificationChallengedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    domain: list[int]
+    proof_hash: list[int]
+    challenger: str
+    challenged_at: int
+
+
+@dataclass
+class VerificationChallengedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "proof_hash" / borsh.U8[32],
+        "challenger" / BorshPubkey,
+        "challenged_at" / borsh.I64,
+    )
+    nullifier: list[int]
+    domain: list[int]
+    proof_hash: list[int]
+    challenger: Pubkey
+    challenged_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "VerificationChallengedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            domain=obj.domain,
+            proof_hash=obj.proof_hash,
+            challenger=obj.challenger,
+            challenged_at=obj.challenged_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "proof_hash": self.proof_hash,
+            "challenger": self.challenger,
+            "challenged_at": self.challenged_at,
+        }
+
+    def to_json(self) -> VerificationChallengedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "proof_hash": self.proof_hash,
+            "challenger": str(self.challenger),
+            "challenged_at": self.challenged_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VerificationChallengedEventJSON) -> "VerificationChallengedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+            proof_hash=obj["proof_hash"],
+            challenger=Pubkey.from_string(obj["challenger"]),
+            challenged_at=obj["challenged_at"],
+        )
@@ -0,0 +1,76 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class VerificationSessionOpenedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    domain: list[int]
+    circuit_id: int
+    authority: str
+    proof_len: int
+    steps: int
+
+
+@dataclass
+class VerificationSessionOpenedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+        "circuit_id" / borsh.U32,
+        "authority" / BorshPubkey,
+        "proof_len" / borsh.U32,
+        "steps" / borsh.U32,
+    )
+    nullifier: list[int]
+    domain: list[int]
+    circuit_id: int
+    authority: Pubkey
+    proof_len: int
+    steps: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "VerificationSessionOpenedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            domain=obj.domain,
+            circuit_id=obj.circuit_id,
+            authority=obj.authority,
+            proof_len=obj.proof_len,
+            steps=obj.steps,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "circuit_id": self.circuit_id,
+            "authority": self.authority,
+            "proof_len": self.proof_len,
+            "steps": self.steps,
+        }
+
+    def to_json(self) -> VerificationSessionOpenedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+            "circuit_id": self.circuit_id,
+            "authority": str(self.authority),
+            "proof_len": self.proof_len,
+            "steps": self.steps,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VerificationSessionOpenedEventJSON) -> "VerificationSessionOpenedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+            circuit_id=obj["circuit_id"],
+            authority=Pubkey.from_string(obj["authority"]),
+            proof_len=obj["proof_len"],
+            steps=obj["steps"],
+        )
@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class VerificationSessionResolvedEventJSON(typing.TypedDict):
+    nullifier: list[int]
+    authority: str
+    verified: bool
+    steps: int
+
+
+@dataclass
+class VerificationSessionResolvedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "nullifier" / borsh.U8[32],
+        "authority" / BorshPubkey,
+        "verified" / borsh.Bool,
+        "steps" / borsh.U32,
+    )
+    nullifier: list[int]
+    authority: Pubkey
+    verified: bool
+    steps: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "VerificationSessionResolvedEvent":
+        return cls(
+            nullifier=obj.nullifier,
+            authority=obj.authority,
+            verified=obj.verified,
+            steps=obj.steps,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "nullifier": self.nullifier,
+            "authority": self.authority,
+            "verified": self.verified,
+            "steps": self.steps,
+        }
+
+    def to_json(self) -> VerificationSessionResolvedEventJSON:
+        return {
+            "nullifier": self.nullifier,
+            "authority": str(self.authority),
+            "verified": self.verified,
+            "steps": self.steps,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VerificationSessionResolvedEventJSON) -> "VerificationSessionResolvedEvent":
+        return cls(
+            nullifier=obj["nullifier"],
+            authority=Pubkey.from_string(obj["authority"]),
+            verified=obj["verified"],
+            steps=obj["steps"],
+        )
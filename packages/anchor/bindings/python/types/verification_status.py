@@ -0,0 +1,142 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from anchorpy.borsh_extension import EnumForCodegen
+import borsh_construct as borsh
+
+
+class UploadingJSON(typing.TypedDict):
+    kind: typing.Literal["Uploading"]
+
+
+@dataclass
+class Uploading:
+    discriminator: typing.ClassVar = 0
+    kind: typing.ClassVar = "Uploading"
+
+    @classmethod
+    def to_json(cls) -> UploadingJSON:
+        return UploadingJSON(
+            kind="Uploading",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Uploading": {},
+        }
+
+
+class VerifyingJSON(typing.TypedDict):
+    kind: typing.Literal["Verifying"]
+
+
+@dataclass
+class Verifying:
+    discriminator: typing.ClassVar = 1
+    kind: typing.ClassVar = "Verifying"
+
+    @classmethod
+    def to_json(cls) -> VerifyingJSON:
+        return VerifyingJSON(
+            kind="Verifying",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Verifying": {},
+        }
+
+
+class VerifiedJSON(typing.TypedDict):
+    kind: typing.Literal["Verified"]
+
+
+@dataclass
+class Verified:
+    discriminator: typing.ClassVar = 2
+    kind: typing.ClassVar = "Verified"
+
+    @classmethod
+    def to_json(cls) -> VerifiedJSON:
+        return VerifiedJSON(
+            kind="Verified",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Verified": {},
+        }
+
+
+class RejectedJSON(typing.TypedDict):
+    kind: typing.Literal["Rejected"]
+
+
+@dataclass
+class Rejected:
+    discriminator: typing.ClassVar = 3
+    kind: typing.ClassVar = "Rejected"
+
+    @classmethod
+    def to_json(cls) -> RejectedJSON:
+        return RejectedJSON(
+            kind="Rejected",
+        )
+
+    @classmethod
+    def to_encodable(cls) -> dict:
+        return {
+            "Rejected": {},
+        }
+
+
+VerificationStatusKind = typing.Union[
+    Uploading,
+    Verifying,
+    Verified,
+    Rejected,
+]
+VerificationStatusJSON = typing.Union[
+    UploadingJSON,
+    VerifyingJSON,
+    VerifiedJSON,
+    RejectedJSON,
+]
+
+
+def from_decoded(obj: dict) -> VerificationStatusKind:
+    if not isinstance(obj, dict):
+        raise ValueError("Invalid enum object")
+    if "Uploading" in obj:
+        return Uploading()
+    if "Verifying" in obj:
+        return Verifying()
+    if "Verified" in obj:
+        return Verified()
+    if "Rejected" in obj:
+        return Rejected()
+    raise ValueError("Invalid enum object")
+
+
+def from_json(obj: VerificationStatusJSON) -> VerificationStatusKind:
+    if obj["kind"] == "Uploading":
+        return Uploading()
+    if obj["kind"] == "Verifying":
+        return Verifying()
+    if obj["kind"] == "Verified":
+        return Verified()
+    if obj["kind"] == "Rejected":
+        return Rejected()
+    kind = obj["kind"]
+    raise ValueError(f"Unrecognized enum kind: {kind}")
+
+
+layout = EnumForCodegen(
+    "Uploading" / borsh.CStruct(),
+    "Verifying" / borsh.CStruct(),
+    "Verified" / borsh.CStruct(),
+    "Rejected" / borsh.CStruct(),
+)
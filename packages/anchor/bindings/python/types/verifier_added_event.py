@@ -0,0 +1,41 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class VerifierAddedEventJSON(typing.TypedDict):
+    verifier: str
+
+
+@dataclass
+class VerifierAddedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "verifier" / BorshPubkey,
+    )
+    verifier: Pubkey
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "VerifierAddedEvent":
+        return cls(
+            verifier=obj.verifier,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "verifier": self.verifier,
+        }
+
+    def to_json(self) -> VerifierAddedEventJSON:
+        return {
+            "verifier": str(self.verifier),
+        }
+
+    @classmethod
+    def from_json(cls, obj: VerifierAddedEventJSON) -> "VerifierAddedEvent":
+        return cls(
+            verifier=Pubkey.from_string(obj["verifier"]),
+        )
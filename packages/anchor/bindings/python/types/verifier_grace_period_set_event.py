@@ -0,0 +1,39 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class VerifierGracePeriodSetEventJSON(typing.TypedDict):
+    grace_period_secs: int
+
+
+@dataclass
+class VerifierGracePeriodSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "grace_period_secs" / borsh.I64,
+    )
+    grace_period_secs: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "VerifierGracePeriodSetEvent":
+        return cls(
+            grace_period_secs=obj.grace_period_secs,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "grace_period_secs": self.grace_period_secs,
+        }
+
+    def to_json(self) -> VerifierGracePeriodSetEventJSON:
+        return {
+            "grace_period_secs": self.grace_period_secs,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VerifierGracePeriodSetEventJSON) -> "VerifierGracePeriodSetEvent":
+        return cls(
+            grace_period_secs=obj["grace_period_secs"],
+        )
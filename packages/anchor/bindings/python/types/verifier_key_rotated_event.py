@@ -0,0 +1,62 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class VerifierKeyRotatedEventJSON(typing.TypedDict):
+    old_key: str
+    new_key: str
+    rotated_at: int
+    grace_ends_at: int
+
+
+@dataclass
+class VerifierKeyRotatedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "old_key" / BorshPubkey,
+        "new_key" / BorshPubkey,
+        "rotated_at" / borsh.I64,
+        "grace_ends_at" / borsh.I64,
+    )
+    old_key: Pubkey
+    new_key: Pubkey
+    rotated_at: int
+    grace_ends_at: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "VerifierKeyRotatedEvent":
+        return cls(
+            old_key=obj.old_key,
+            new_key=obj.new_key,
+            rotated_at=obj.rotated_at,
+            grace_ends_at=obj.grace_ends_at,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "old_key": self.old_key,
+            "new_key": self.new_key,
+            "rotated_at": self.rotated_at,
+            "grace_ends_at": self.grace_ends_at,
+        }
+
+    def to_json(self) -> VerifierKeyRotatedEventJSON:
+        return {
+            "old_key": str(self.old_key),
+            "new_key": str(self.new_key),
+            "rotated_at": self.rotated_at,
+            "grace_ends_at": self.grace_ends_at,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VerifierKeyRotatedEventJSON) -> "VerifierKeyRotatedEvent":
+        return cls(
+            old_key=Pubkey.from_string(obj["old_key"]),
+            new_key=Pubkey.from_string(obj["new_key"]),
+            rotated_at=obj["rotated_at"],
+            grace_ends_at=obj["grace_ends_at"],
+        )
@@ -0,0 +1,41 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class VerifierRemovedEventJSON(typing.TypedDict):
+    verifier: str
+
+
+@dataclass
+class VerifierRemovedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "verifier" / BorshPubkey,
+    )
+    verifier: Pubkey
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "VerifierRemovedEvent":
+        return cls(
+            verifier=obj.verifier,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "verifier": self.verifier,
+        }
+
+    def to_json(self) -> VerifierRemovedEventJSON:
+        return {
+            "verifier": str(self.verifier),
+        }
+
+    @classmethod
+    def from_json(cls, obj: VerifierRemovedEventJSON) -> "VerifierRemovedEvent":
+        return cls(
+            verifier=Pubkey.from_string(obj["verifier"]),
+        )
@@ -0,0 +1,39 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class VerifierThresholdSetEventJSON(typing.TypedDict):
+    threshold: int
+
+
+@dataclass
+class VerifierThresholdSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "threshold" / borsh.U8,
+    )
+    threshold: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "VerifierThresholdSetEvent":
+        return cls(
+            threshold=obj.threshold,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "threshold": self.threshold,
+        }
+
+    def to_json(self) -> VerifierThresholdSetEventJSON:
+        return {
+            "threshold": self.threshold,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VerifierThresholdSetEventJSON) -> "VerifierThresholdSetEvent":
+        return cls(
+            threshold=obj["threshold"],
+        )
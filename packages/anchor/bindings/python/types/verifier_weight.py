@@ -0,0 +1,55 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class VerifierWeightJSON(typing.TypedDict):
+    verifier: str
+    weight: int
+    last_heartbeat: int
+
+
+@dataclass
+class VerifierWeight:
+    layout: typing.ClassVar = borsh.CStruct(
+        "verifier" / BorshPubkey,
+        "weight" / borsh.U8,
+        "last_heartbeat" / borsh.I64,
+    )
+    verifier: Pubkey
+    weight: int
+    last_heartbeat: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "VerifierWeight":
+        return cls(
+            verifier=obj.verifier,
+            weight=obj.weight,
+            last_heartbeat=obj.last_heartbeat,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "verifier": self.verifier,
+            "weight": self.weight,
+            "last_heartbeat": self.last_heartbeat,
+        }
+
+    def to_json(self) -> VerifierWeightJSON:
+        return {
+            "verifier": str(self.verifier),
+            "weight": self.weight,
+            "last_heartbeat": self.last_heartbeat,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VerifierWeightJSON) -> "VerifierWeight":
+        return cls(
+            verifier=Pubkey.from_string(obj["verifier"]),
+            weight=obj["weight"],
+            last_heartbeat=obj["last_heartbeat"],
+        )
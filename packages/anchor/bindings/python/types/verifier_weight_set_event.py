@@ -0,0 +1,48 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+from solders.pubkey import Pubkey
+from anchorpy.borsh_extension import BorshPubkey
+import borsh_construct as borsh
+
+
+class VerifierWeightSetEventJSON(typing.TypedDict):
+    verifier: str
+    weight: int
+
+
+@dataclass
+class VerifierWeightSetEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "verifier" / BorshPubkey,
+        "weight" / borsh.U8,
+    )
+    verifier: Pubkey
+    weight: int
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "VerifierWeightSetEvent":
+        return cls(
+            verifier=obj.verifier,
+            weight=obj.weight,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "verifier": self.verifier,
+            "weight": self.weight,
+        }
+
+    def to_json(self) -> VerifierWeightSetEventJSON:
+        return {
+            "verifier": str(self.verifier),
+            "weight": self.weight,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VerifierWeightSetEventJSON) -> "VerifierWeightSetEvent":
+        return cls(
+            verifier=Pubkey.from_string(obj["verifier"]),
+            weight=obj["weight"],
+        )
@@ -0,0 +1,56 @@
+from . import (
+    signed_verification_result,
+)
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class VerifyAuthEntryJSON(typing.TypedDict):
+    result: signed_verification_result.SignedVerificationResultJSON
+    nullifier: list[int]
+    domain: list[int]
+
+
+@dataclass
+class VerifyAuthEntry:
+    layout: typing.ClassVar = borsh.CStruct(
+        "result" / signed_verification_result.SignedVerificationResult.layout,
+        "nullifier" / borsh.U8[32],
+        "domain" / borsh.U8[32],
+    )
+    result: signed_verification_result.SignedVerificationResult
+    nullifier: list[int]
+    domain: list[int]
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "VerifyAuthEntry":
+        return cls(
+            result=signed_verification_result.SignedVerificationResult.from_decoded(obj.result),
+            nullifier=obj.nullifier,
+            domain=obj.domain,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "result": self.result.to_encodable(),
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+        }
+
+    def to_json(self) -> VerifyAuthEntryJSON:
+        return {
+            "result": self.result.to_json(),
+            "nullifier": self.nullifier,
+            "domain": self.domain,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VerifyAuthEntryJSON) -> "VerifyAuthEntry":
+        return cls(
+            result=signed_verification_result.SignedVerificationResult.from_json(obj["result"]),
+            nullifier=obj["nullifier"],
+            domain=obj["domain"],
+        )
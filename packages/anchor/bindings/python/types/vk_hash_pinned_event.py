@@ -0,0 +1,46 @@
+from __future__ import annotations
+import typing
+from dataclasses import dataclass
+from construct import Container
+import borsh_construct as borsh
+
+
+class VkHashPinnedEventJSON(typing.TypedDict):
+    domain: list[int]
+    vk_hash: list[int]
+
+
+@dataclass
+class VkHashPinnedEvent:
+    layout: typing.ClassVar = borsh.CStruct(
+        "domain" / borsh.U8[32],
+        "vk_hash" / borsh.U8[32],
+    )
+    domain: list[int]
+    vk_hash: list[int]
+
+    @classmethod
+    def from_decoded(cls, obj: Container) -> "VkHashPinnedEvent":
+        return cls(
+            domain=obj.domain,
+            vk_hash=obj.vk_hash,
+        )
+
+    def to_encodable(self) -> dict[str, typing.Any]:
+        return {
+            "domain": self.domain,
+            "vk_hash": self.vk_hash,
+        }
+
+    def to_json(self) -> VkHashPinnedEventJSON:
+        return {
+            "domain": self.domain,
+            "vk_hash": self.vk_hash,
+        }
+
+    @classmethod
+    def from_json(cls, obj: VkHashPinnedEventJSON) -> "VkHashPinnedEvent":
+        return cls(
+            domain=obj["domain"],
+            vk_hash=obj["vk_hash"],
+        )
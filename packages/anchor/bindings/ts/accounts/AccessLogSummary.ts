@@ -0,0 +1,129 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AccessLogSummaryFields {
+  permissionGrant: PublicKey
+  root: Array<number>
+  compactedCount: BN
+  batchCount: BN
+  lastAccessedAt: BN
+  bump: number
+}
+
+export interface AccessLogSummaryJSON {
+  permissionGrant: string
+  root: Array<number>
+  compactedCount: string
+  batchCount: string
+  lastAccessedAt: string
+  bump: number
+}
+
+export class AccessLogSummary {
+  readonly permissionGrant: PublicKey
+  readonly root: Array<number>
+  readonly compactedCount: BN
+  readonly batchCount: BN
+  readonly lastAccessedAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    247, 49, 84, 248, 134, 13, 85, 205,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("permissionGrant"),
+    borsh.array(borsh.u8(), 32, "root"),
+    borsh.u64("compactedCount"),
+    borsh.u64("batchCount"),
+    borsh.i64("lastAccessedAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: AccessLogSummaryFields) {
+    this.permissionGrant = fields.permissionGrant
+    this.root = fields.root
+    this.compactedCount = fields.compactedCount
+    this.batchCount = fields.batchCount
+    this.lastAccessedAt = fields.lastAccessedAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<AccessLogSummary | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<AccessLogSummary | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): AccessLogSummary {
+    if (!data.slice(0, 8).equals(AccessLogSummary.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = AccessLogSummary.layout.decode(data.slice(8))
+
+    return new AccessLogSummary({
+      permissionGrant: dec.permissionGrant,
+      root: dec.root,
+      compactedCount: dec.compactedCount,
+      batchCount: dec.batchCount,
+      lastAccessedAt: dec.lastAccessedAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): AccessLogSummaryJSON {
+    return {
+      permissionGrant: this.permissionGrant.toString(),
+      root: this.root,
+      compactedCount: this.compactedCount.toString(),
+      batchCount: this.batchCount.toString(),
+      lastAccessedAt: this.lastAccessedAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: AccessLogSummaryJSON): AccessLogSummary {
+    return new AccessLogSummary({
+      permissionGrant: new PublicKey(obj.permissionGrant),
+      root: obj.root,
+      compactedCount: new BN(obj.compactedCount),
+      batchCount: new BN(obj.batchCount),
+      lastAccessedAt: new BN(obj.lastAccessedAt),
+      bump: obj.bump,
+    })
+  }
+}
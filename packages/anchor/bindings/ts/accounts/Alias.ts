@@ -0,0 +1,129 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AliasFields {
+  handleHash: Array<number>
+  nullifier: Array<number>
+  domain: Array<number>
+  authority: PublicKey
+  claimedAt: BN
+  bump: number
+}
+
+export interface AliasJSON {
+  handleHash: Array<number>
+  nullifier: Array<number>
+  domain: Array<number>
+  authority: string
+  claimedAt: string
+  bump: number
+}
+
+export class Alias {
+  readonly handleHash: Array<number>
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly authority: PublicKey
+  readonly claimedAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    175, 23, 49, 34, 113, 79, 229, 204,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "handleHash"),
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    borsh.array(borsh.u8(), 32, "domain"),
+    borsh.publicKey("authority"),
+    borsh.i64("claimedAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: AliasFields) {
+    this.handleHash = fields.handleHash
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.authority = fields.authority
+    this.claimedAt = fields.claimedAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Alias | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Alias | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Alias {
+    if (!data.slice(0, 8).equals(Alias.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Alias.layout.decode(data.slice(8))
+
+    return new Alias({
+      handleHash: dec.handleHash,
+      nullifier: dec.nullifier,
+      domain: dec.domain,
+      authority: dec.authority,
+      claimedAt: dec.claimedAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): AliasJSON {
+    return {
+      handleHash: this.handleHash,
+      nullifier: this.nullifier,
+      domain: this.domain,
+      authority: this.authority.toString(),
+      claimedAt: this.claimedAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: AliasJSON): Alias {
+    return new Alias({
+      handleHash: obj.handleHash,
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      authority: new PublicKey(obj.authority),
+      claimedAt: new BN(obj.claimedAt),
+      bump: obj.bump,
+    })
+  }
+}
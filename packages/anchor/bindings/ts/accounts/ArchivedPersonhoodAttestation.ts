@@ -0,0 +1,113 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ArchivedPersonhoodAttestationFields {
+  attestationHash: Array<number>
+  issuedAt: BN
+  archivedAt: BN
+  bump: number
+}
+
+export interface ArchivedPersonhoodAttestationJSON {
+  attestationHash: Array<number>
+  issuedAt: string
+  archivedAt: string
+  bump: number
+}
+
+export class ArchivedPersonhoodAttestation {
+  readonly attestationHash: Array<number>
+  readonly issuedAt: BN
+  readonly archivedAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    74, 40, 220, 136, 230, 244, 179, 228,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "attestationHash"),
+    borsh.i64("issuedAt"),
+    borsh.i64("archivedAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: ArchivedPersonhoodAttestationFields) {
+    this.attestationHash = fields.attestationHash
+    this.issuedAt = fields.issuedAt
+    this.archivedAt = fields.archivedAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<ArchivedPersonhoodAttestation | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<ArchivedPersonhoodAttestation | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): ArchivedPersonhoodAttestation {
+    if (!data.slice(0, 8).equals(ArchivedPersonhoodAttestation.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = ArchivedPersonhoodAttestation.layout.decode(data.slice(8))
+
+    return new ArchivedPersonhoodAttestation({
+      attestationHash: dec.attestationHash,
+      issuedAt: dec.issuedAt,
+      archivedAt: dec.archivedAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): ArchivedPersonhoodAttestationJSON {
+    return {
+      attestationHash: this.attestationHash,
+      issuedAt: this.issuedAt.toString(),
+      archivedAt: this.archivedAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: ArchivedPersonhoodAttestationJSON): ArchivedPersonhoodAttestation {
+    return new ArchivedPersonhoodAttestation({
+      attestationHash: obj.attestationHash,
+      issuedAt: new BN(obj.issuedAt),
+      archivedAt: new BN(obj.archivedAt),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,97 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AssurancePolicyFields {
+  maxResultAgeSecs: Array<number>
+  bump: number
+}
+
+export interface AssurancePolicyJSON {
+  maxResultAgeSecs: Array<number>
+  bump: number
+}
+
+export class AssurancePolicy {
+  readonly maxResultAgeSecs: Array<number>
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    68, 157, 233, 110, 175, 252, 7, 81,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.vec(borsh.u32(), "maxResultAgeSecs"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: AssurancePolicyFields) {
+    this.maxResultAgeSecs = fields.maxResultAgeSecs
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<AssurancePolicy | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<AssurancePolicy | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): AssurancePolicy {
+    if (!data.slice(0, 8).equals(AssurancePolicy.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = AssurancePolicy.layout.decode(data.slice(8))
+
+    return new AssurancePolicy({
+      maxResultAgeSecs: dec.maxResultAgeSecs,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): AssurancePolicyJSON {
+    return {
+      maxResultAgeSecs: this.maxResultAgeSecs,
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: AssurancePolicyJSON): AssurancePolicy {
+    return new AssurancePolicy({
+      maxResultAgeSecs: obj.maxResultAgeSecs,
+      bump: obj.bump,
+    })
+  }
+}
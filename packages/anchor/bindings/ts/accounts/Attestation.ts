@@ -0,0 +1,161 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AttestationFields {
+  nullifier: Array<number>
+  claimType: types.ClaimTypeKind
+  domain: Array<number>
+  claimDataHash: Array<number>
+  circuitId: number
+  issuer: PublicKey
+  issuedAt: BN
+  expiresAt: BN
+  bump: number
+  revokedAt: BN
+}
+
+export interface AttestationJSON {
+  nullifier: Array<number>
+  claimType: types.ClaimTypeJSON
+  domain: Array<number>
+  claimDataHash: Array<number>
+  circuitId: number
+  issuer: string
+  issuedAt: string
+  expiresAt: string
+  bump: number
+  revokedAt: string
+}
+
+export class Attestation {
+  readonly nullifier: Array<number>
+  readonly claimType: types.ClaimTypeKind
+  readonly domain: Array<number>
+  readonly claimDataHash: Array<number>
+  readonly circuitId: number
+  readonly issuer: PublicKey
+  readonly issuedAt: BN
+  readonly expiresAt: BN
+  readonly bump: number
+  readonly revokedAt: BN
+
+  static readonly discriminator = Buffer.from([
+    152, 125, 183, 86, 36, 146, 121, 73,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    types.ClaimType.layout("claimType"),
+    borsh.array(borsh.u8(), 32, "domain"),
+    borsh.array(borsh.u8(), 32, "claimDataHash"),
+    borsh.u32("circuitId"),
+    borsh.publicKey("issuer"),
+    borsh.i64("issuedAt"),
+    borsh.i64("expiresAt"),
+    borsh.u8("bump"),
+    borsh.i64("revokedAt"),
+  ])
+
+  constructor(fields: AttestationFields) {
+    this.nullifier = fields.nullifier
+    this.claimType = fields.claimType
+    this.domain = fields.domain
+    this.claimDataHash = fields.claimDataHash
+    this.circuitId = fields.circuitId
+    this.issuer = fields.issuer
+    this.issuedAt = fields.issuedAt
+    this.expiresAt = fields.expiresAt
+    this.bump = fields.bump
+    this.revokedAt = fields.revokedAt
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Attestation | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Attestation | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Attestation {
+    if (!data.slice(0, 8).equals(Attestation.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Attestation.layout.decode(data.slice(8))
+
+    return new Attestation({
+      nullifier: dec.nullifier,
+      claimType: types.ClaimType.fromDecoded(dec.claimType),
+      domain: dec.domain,
+      claimDataHash: dec.claimDataHash,
+      circuitId: dec.circuitId,
+      issuer: dec.issuer,
+      issuedAt: dec.issuedAt,
+      expiresAt: dec.expiresAt,
+      bump: dec.bump,
+      revokedAt: dec.revokedAt,
+    })
+  }
+
+  toJSON(): AttestationJSON {
+    return {
+      nullifier: this.nullifier,
+      claimType: this.claimType.toJSON(),
+      domain: this.domain,
+      claimDataHash: this.claimDataHash,
+      circuitId: this.circuitId,
+      issuer: this.issuer.toString(),
+      issuedAt: this.issuedAt.toString(),
+      expiresAt: this.expiresAt.toString(),
+      bump: this.bump,
+      revokedAt: this.revokedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: AttestationJSON): Attestation {
+    return new Attestation({
+      nullifier: obj.nullifier,
+      claimType: types.ClaimType.fromJSON(obj.claimType),
+      domain: obj.domain,
+      claimDataHash: obj.claimDataHash,
+      circuitId: obj.circuitId,
+      issuer: new PublicKey(obj.issuer),
+      issuedAt: new BN(obj.issuedAt),
+      expiresAt: new BN(obj.expiresAt),
+      bump: obj.bump,
+      revokedAt: new BN(obj.revokedAt),
+    })
+  }
+}
@@ -0,0 +1,129 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AttestorFields {
+  issuer: PublicKey
+  nameHash: Array<number>
+  claimMask: number
+  status: types.AttestorStatusKind
+  registeredAt: BN
+  bump: number
+}
+
+export interface AttestorJSON {
+  issuer: string
+  nameHash: Array<number>
+  claimMask: number
+  status: types.AttestorStatusJSON
+  registeredAt: string
+  bump: number
+}
+
+export class Attestor {
+  readonly issuer: PublicKey
+  readonly nameHash: Array<number>
+  readonly claimMask: number
+  readonly status: types.AttestorStatusKind
+  readonly registeredAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    253, 240, 76, 196, 16, 53, 239, 173,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("issuer"),
+    borsh.array(borsh.u8(), 32, "nameHash"),
+    borsh.u8("claimMask"),
+    types.AttestorStatus.layout("status"),
+    borsh.i64("registeredAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: AttestorFields) {
+    this.issuer = fields.issuer
+    this.nameHash = fields.nameHash
+    this.claimMask = fields.claimMask
+    this.status = fields.status
+    this.registeredAt = fields.registeredAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Attestor | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Attestor | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Attestor {
+    if (!data.slice(0, 8).equals(Attestor.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Attestor.layout.decode(data.slice(8))
+
+    return new Attestor({
+      issuer: dec.issuer,
+      nameHash: dec.nameHash,
+      claimMask: dec.claimMask,
+      status: types.AttestorStatus.fromDecoded(dec.status),
+      registeredAt: dec.registeredAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): AttestorJSON {
+    return {
+      issuer: this.issuer.toString(),
+      nameHash: this.nameHash,
+      claimMask: this.claimMask,
+      status: this.status.toJSON(),
+      registeredAt: this.registeredAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: AttestorJSON): Attestor {
+    return new Attestor({
+      issuer: new PublicKey(obj.issuer),
+      nameHash: obj.nameHash,
+      claimMask: obj.claimMask,
+      status: types.AttestorStatus.fromJSON(obj.status),
+      registeredAt: new BN(obj.registeredAt),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,145 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AuditorGrantFields {
+  nullifier: Array<number>
+  auditor: PublicKey
+  nameHash: Array<number>
+  grantedBy: PublicKey
+  grantedAt: BN
+  expiresAt: types.ExpiryKindKind
+  revoked: boolean
+  bump: number
+}
+
+export interface AuditorGrantJSON {
+  nullifier: Array<number>
+  auditor: string
+  nameHash: Array<number>
+  grantedBy: string
+  grantedAt: string
+  expiresAt: types.ExpiryKindJSON
+  revoked: boolean
+  bump: number
+}
+
+export class AuditorGrant {
+  readonly nullifier: Array<number>
+  readonly auditor: PublicKey
+  readonly nameHash: Array<number>
+  readonly grantedBy: PublicKey
+  readonly grantedAt: BN
+  readonly expiresAt: types.ExpiryKindKind
+  readonly revoked: boolean
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    18, 180, 34, 217, 251, 105, 167, 40,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    borsh.publicKey("auditor"),
+    borsh.array(borsh.u8(), 32, "nameHash"),
+    borsh.publicKey("grantedBy"),
+    borsh.i64("grantedAt"),
+    types.ExpiryKind.layout("expiresAt"),
+    borsh.bool("revoked"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: AuditorGrantFields) {
+    this.nullifier = fields.nullifier
+    this.auditor = fields.auditor
+    this.nameHash = fields.nameHash
+    this.grantedBy = fields.grantedBy
+    this.grantedAt = fields.grantedAt
+    this.expiresAt = fields.expiresAt
+    this.revoked = fields.revoked
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<AuditorGrant | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<AuditorGrant | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): AuditorGrant {
+    if (!data.slice(0, 8).equals(AuditorGrant.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = AuditorGrant.layout.decode(data.slice(8))
+
+    return new AuditorGrant({
+      nullifier: dec.nullifier,
+      auditor: dec.auditor,
+      nameHash: dec.nameHash,
+      grantedBy: dec.grantedBy,
+      grantedAt: dec.grantedAt,
+      expiresAt: types.ExpiryKind.fromDecoded(dec.expiresAt),
+      revoked: dec.revoked,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): AuditorGrantJSON {
+    return {
+      nullifier: this.nullifier,
+      auditor: this.auditor.toString(),
+      nameHash: this.nameHash,
+      grantedBy: this.grantedBy.toString(),
+      grantedAt: this.grantedAt.toString(),
+      expiresAt: this.expiresAt.toJSON(),
+      revoked: this.revoked,
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: AuditorGrantJSON): AuditorGrant {
+    return new AuditorGrant({
+      nullifier: obj.nullifier,
+      auditor: new PublicKey(obj.auditor),
+      nameHash: obj.nameHash,
+      grantedBy: new PublicKey(obj.grantedBy),
+      grantedAt: new BN(obj.grantedAt),
+      expiresAt: types.ExpiryKind.fromJSON(obj.expiresAt),
+      revoked: obj.revoked,
+      bump: obj.bump,
+    })
+  }
+}
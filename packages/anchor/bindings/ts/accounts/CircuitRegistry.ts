@@ -0,0 +1,101 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CircuitRegistryFields {
+  circuits: Array<types.CircuitEntry>
+  bump: number
+}
+
+export interface CircuitRegistryJSON {
+  circuits: Array<types.CircuitEntryJSON>
+  bump: number
+}
+
+export class CircuitRegistry {
+  readonly circuits: Array<types.CircuitEntry>
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    248, 61, 106, 55, 138, 152, 88, 37,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.vec(types.CircuitEntry.layout(), "circuits"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: CircuitRegistryFields) {
+    this.circuits = fields.circuits
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<CircuitRegistry | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<CircuitRegistry | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): CircuitRegistry {
+    if (!data.slice(0, 8).equals(CircuitRegistry.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = CircuitRegistry.layout.decode(data.slice(8))
+
+    return new CircuitRegistry({
+      circuits: dec.circuits.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.CircuitEntry.fromDecoded(item)
+      ),
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): CircuitRegistryJSON {
+    return {
+      circuits: this.circuits.map((item) => item.toJSON()),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: CircuitRegistryJSON): CircuitRegistry {
+    return new CircuitRegistry({
+      circuits: obj.circuits.map((item) => types.CircuitEntry.fromJSON(item)),
+      bump: obj.bump,
+    })
+  }
+}
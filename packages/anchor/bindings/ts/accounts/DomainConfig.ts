@@ -0,0 +1,249 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface DomainConfigFields {
+  domain: Array<number>
+  owner: PublicKey
+  pinnedVkHash: Array<number>
+  verifiers: Array<PublicKey>
+  bump: number
+  challengeWindowSecs: BN
+  rateLimit: number
+  assuranceTier: number
+  maxResultAgeSecs: number
+  operator: PublicKey
+  treasurer: PublicKey
+  paused: boolean
+  allowedOrigins: Array<Array<number>>
+  minSessionSecs: BN
+  maxSessionSecs: BN
+  attestorClaimMask: number
+  idleTimeoutSecs: BN
+  nullifierStore: types.NullifierStoreKind
+  maxPermissions: number
+  sessionExpiryUnit: types.ExpiryUnitKind
+  defaultSessionSecs: BN
+}
+
+export interface DomainConfigJSON {
+  domain: Array<number>
+  owner: string
+  pinnedVkHash: Array<number>
+  verifiers: Array<string>
+  bump: number
+  challengeWindowSecs: string
+  rateLimit: number
+  assuranceTier: number
+  maxResultAgeSecs: number
+  operator: string
+  treasurer: string
+  paused: boolean
+  allowedOrigins: Array<Array<number>>
+  minSessionSecs: string
+  maxSessionSecs: string
+  attestorClaimMask: number
+  idleTimeoutSecs: string
+  nullifierStore: types.NullifierStoreJSON
+  maxPermissions: number
+  sessionExpiryUnit: types.ExpiryUnitJSON
+  defaultSessionSecs: string
+}
+
+export class DomainConfig {
+  readonly domain: Array<number>
+  readonly owner: PublicKey
+  readonly pinnedVkHash: Array<number>
+  readonly verifiers: Array<PublicKey>
+  readonly bump: number
+  readonly challengeWindowSecs: BN
+  readonly rateLimit: number
+  readonly assuranceTier: number
+  readonly maxResultAgeSecs: number
+  readonly operator: PublicKey
+  readonly treasurer: PublicKey
+  readonly paused: boolean
+  readonly allowedOrigins: Array<Array<number>>
+  readonly minSessionSecs: BN
+  readonly maxSessionSecs: BN
+  readonly attestorClaimMask: number
+  readonly idleTimeoutSecs: BN
+  readonly nullifierStore: types.NullifierStoreKind
+  readonly maxPermissions: number
+  readonly sessionExpiryUnit: types.ExpiryUnitKind
+  readonly defaultSessionSecs: BN
+
+  static readonly discriminator = Buffer.from([
+    201, 232, 212, 229, 59, 241, 106, 197,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "domain"),
+    borsh.publicKey("owner"),
+    borsh.array(borsh.u8(), 32, "pinnedVkHash"),
+    borsh.vec(borsh.publicKey(), "verifiers"),
+    borsh.u8("bump"),
+    borsh.i64("challengeWindowSecs"),
+    borsh.u32("rateLimit"),
+    borsh.u8("assuranceTier"),
+    borsh.u32("maxResultAgeSecs"),
+    borsh.publicKey("operator"),
+    borsh.publicKey("treasurer"),
+    borsh.bool("paused"),
+    borsh.vec(borsh.array(borsh.u8(), 32), "allowedOrigins"),
+    borsh.i64("minSessionSecs"),
+    borsh.i64("maxSessionSecs"),
+    borsh.u8("attestorClaimMask"),
+    borsh.i64("idleTimeoutSecs"),
+    types.NullifierStore.layout("nullifierStore"),
+    borsh.u8("maxPermissions"),
+    types.ExpiryUnit.layout("sessionExpiryUnit"),
+    borsh.i64("defaultSessionSecs"),
+  ])
+
+  constructor(fields: DomainConfigFields) {
+    this.domain = fields.domain
+    this.owner = fields.owner
+    this.pinnedVkHash = fields.pinnedVkHash
+    this.verifiers = fields.verifiers
+    this.bump = fields.bump
+    this.challengeWindowSecs = fields.challengeWindowSecs
+    this.rateLimit = fields.rateLimit
+    this.assuranceTier = fields.assuranceTier
+    this.maxResultAgeSecs = fields.maxResultAgeSecs
+    this.operator = fields.operator
+    this.treasurer = fields.treasurer
+    this.paused = fields.paused
+    this.allowedOrigins = fields.allowedOrigins
+    this.minSessionSecs = fields.minSessionSecs
+    this.maxSessionSecs = fields.maxSessionSecs
+    this.attestorClaimMask = fields.attestorClaimMask
+    this.idleTimeoutSecs = fields.idleTimeoutSecs
+    this.nullifierStore = fields.nullifierStore
+    this.maxPermissions = fields.maxPermissions
+    this.sessionExpiryUnit = fields.sessionExpiryUnit
+    this.defaultSessionSecs = fields.defaultSessionSecs
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<DomainConfig | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<DomainConfig | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): DomainConfig {
+    if (!data.slice(0, 8).equals(DomainConfig.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = DomainConfig.layout.decode(data.slice(8))
+
+    return new DomainConfig({
+      domain: dec.domain,
+      owner: dec.owner,
+      pinnedVkHash: dec.pinnedVkHash,
+      verifiers: dec.verifiers,
+      bump: dec.bump,
+      challengeWindowSecs: dec.challengeWindowSecs,
+      rateLimit: dec.rateLimit,
+      assuranceTier: dec.assuranceTier,
+      maxResultAgeSecs: dec.maxResultAgeSecs,
+      operator: dec.operator,
+      treasurer: dec.treasurer,
+      paused: dec.paused,
+      allowedOrigins: dec.allowedOrigins,
+      minSessionSecs: dec.minSessionSecs,
+      maxSessionSecs: dec.maxSessionSecs,
+      attestorClaimMask: dec.attestorClaimMask,
+      idleTimeoutSecs: dec.idleTimeoutSecs,
+      nullifierStore: types.NullifierStore.fromDecoded(dec.nullifierStore),
+      maxPermissions: dec.maxPermissions,
+      sessionExpiryUnit: types.ExpiryUnit.fromDecoded(dec.sessionExpiryUnit),
+      defaultSessionSecs: dec.defaultSessionSecs,
+    })
+  }
+
+  toJSON(): DomainConfigJSON {
+    return {
+      domain: this.domain,
+      owner: this.owner.toString(),
+      pinnedVkHash: this.pinnedVkHash,
+      verifiers: this.verifiers.map((item) => item.toString()),
+      bump: this.bump,
+      challengeWindowSecs: this.challengeWindowSecs.toString(),
+      rateLimit: this.rateLimit,
+      assuranceTier: this.assuranceTier,
+      maxResultAgeSecs: this.maxResultAgeSecs,
+      operator: this.operator.toString(),
+      treasurer: this.treasurer.toString(),
+      paused: this.paused,
+      allowedOrigins: this.allowedOrigins,
+      minSessionSecs: this.minSessionSecs.toString(),
+      maxSessionSecs: this.maxSessionSecs.toString(),
+      attestorClaimMask: this.attestorClaimMask,
+      idleTimeoutSecs: this.idleTimeoutSecs.toString(),
+      nullifierStore: this.nullifierStore.toJSON(),
+      maxPermissions: this.maxPermissions,
+      sessionExpiryUnit: this.sessionExpiryUnit.toJSON(),
+      defaultSessionSecs: this.defaultSessionSecs.toString(),
+    }
+  }
+
+  static fromJSON(obj: DomainConfigJSON): DomainConfig {
+    return new DomainConfig({
+      domain: obj.domain,
+      owner: new PublicKey(obj.owner),
+      pinnedVkHash: obj.pinnedVkHash,
+      verifiers: obj.verifiers.map((item) => new PublicKey(item)),
+      bump: obj.bump,
+      challengeWindowSecs: new BN(obj.challengeWindowSecs),
+      rateLimit: obj.rateLimit,
+      assuranceTier: obj.assuranceTier,
+      maxResultAgeSecs: obj.maxResultAgeSecs,
+      operator: new PublicKey(obj.operator),
+      treasurer: new PublicKey(obj.treasurer),
+      paused: obj.paused,
+      allowedOrigins: obj.allowedOrigins,
+      minSessionSecs: new BN(obj.minSessionSecs),
+      maxSessionSecs: new BN(obj.maxSessionSecs),
+      attestorClaimMask: obj.attestorClaimMask,
+      idleTimeoutSecs: new BN(obj.idleTimeoutSecs),
+      nullifierStore: types.NullifierStore.fromJSON(obj.nullifierStore),
+      maxPermissions: obj.maxPermissions,
+      sessionExpiryUnit: types.ExpiryUnit.fromJSON(obj.sessionExpiryUnit),
+      defaultSessionSecs: new BN(obj.defaultSessionSecs),
+    })
+  }
+}
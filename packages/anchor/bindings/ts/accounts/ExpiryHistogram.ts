@@ -0,0 +1,113 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ExpiryHistogramFields {
+  domain: Array<number>
+  buckets: Array<BN>
+  slotGrants: BN
+  bump: number
+}
+
+export interface ExpiryHistogramJSON {
+  domain: Array<number>
+  buckets: Array<string>
+  slotGrants: string
+  bump: number
+}
+
+export class ExpiryHistogram {
+  readonly domain: Array<number>
+  readonly buckets: Array<BN>
+  readonly slotGrants: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    129, 225, 9, 129, 186, 118, 16, 125,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "domain"),
+    borsh.array(borsh.u64(), 7, "buckets"),
+    borsh.u64("slotGrants"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: ExpiryHistogramFields) {
+    this.domain = fields.domain
+    this.buckets = fields.buckets
+    this.slotGrants = fields.slotGrants
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<ExpiryHistogram | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<ExpiryHistogram | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): ExpiryHistogram {
+    if (!data.slice(0, 8).equals(ExpiryHistogram.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = ExpiryHistogram.layout.decode(data.slice(8))
+
+    return new ExpiryHistogram({
+      domain: dec.domain,
+      buckets: dec.buckets,
+      slotGrants: dec.slotGrants,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): ExpiryHistogramJSON {
+    return {
+      domain: this.domain,
+      buckets: this.buckets.map((item) => item.toString()),
+      slotGrants: this.slotGrants.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: ExpiryHistogramJSON): ExpiryHistogram {
+    return new ExpiryHistogram({
+      domain: obj.domain,
+      buckets: obj.buckets.map((item) => new BN(item)),
+      slotGrants: new BN(obj.slotGrants),
+      bump: obj.bump,
+    })
+  }
+}
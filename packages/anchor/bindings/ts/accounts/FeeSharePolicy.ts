@@ -0,0 +1,97 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface FeeSharePolicyFields {
+  domainShareBps: number
+  bump: number
+}
+
+export interface FeeSharePolicyJSON {
+  domainShareBps: number
+  bump: number
+}
+
+export class FeeSharePolicy {
+  readonly domainShareBps: number
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    78, 118, 108, 56, 161, 212, 244, 46,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u16("domainShareBps"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: FeeSharePolicyFields) {
+    this.domainShareBps = fields.domainShareBps
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<FeeSharePolicy | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<FeeSharePolicy | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): FeeSharePolicy {
+    if (!data.slice(0, 8).equals(FeeSharePolicy.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = FeeSharePolicy.layout.decode(data.slice(8))
+
+    return new FeeSharePolicy({
+      domainShareBps: dec.domainShareBps,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): FeeSharePolicyJSON {
+    return {
+      domainShareBps: this.domainShareBps,
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: FeeSharePolicyJSON): FeeSharePolicy {
+    return new FeeSharePolicy({
+      domainShareBps: obj.domainShareBps,
+      bump: obj.bump,
+    })
+  }
+}
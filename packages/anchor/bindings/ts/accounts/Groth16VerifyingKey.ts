@@ -0,0 +1,135 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface Groth16VerifyingKeyFields {
+  circuitId: number
+  alphaG1: Array<number>
+  betaG2: Array<number>
+  gammaG2: Array<number>
+  deltaG2: Array<number>
+  ic: Array<Array<number>>
+  bump: number
+}
+
+export interface Groth16VerifyingKeyJSON {
+  circuitId: number
+  alphaG1: Array<number>
+  betaG2: Array<number>
+  gammaG2: Array<number>
+  deltaG2: Array<number>
+  ic: Array<Array<number>>
+  bump: number
+}
+
+export class Groth16VerifyingKey {
+  readonly circuitId: number
+  readonly alphaG1: Array<number>
+  readonly betaG2: Array<number>
+  readonly gammaG2: Array<number>
+  readonly deltaG2: Array<number>
+  readonly ic: Array<Array<number>>
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([14, 89, 2, 163, 52, 90, 23, 160])
+
+  static readonly layout = borsh.struct([
+    borsh.u32("circuitId"),
+    borsh.array(borsh.u8(), 64, "alphaG1"),
+    borsh.array(borsh.u8(), 128, "betaG2"),
+    borsh.array(borsh.u8(), 128, "gammaG2"),
+    borsh.array(borsh.u8(), 128, "deltaG2"),
+    borsh.vec(borsh.array(borsh.u8(), 64), "ic"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: Groth16VerifyingKeyFields) {
+    this.circuitId = fields.circuitId
+    this.alphaG1 = fields.alphaG1
+    this.betaG2 = fields.betaG2
+    this.gammaG2 = fields.gammaG2
+    this.deltaG2 = fields.deltaG2
+    this.ic = fields.ic
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Groth16VerifyingKey | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Groth16VerifyingKey | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Groth16VerifyingKey {
+    if (!data.slice(0, 8).equals(Groth16VerifyingKey.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Groth16VerifyingKey.layout.decode(data.slice(8))
+
+    return new Groth16VerifyingKey({
+      circuitId: dec.circuitId,
+      alphaG1: dec.alphaG1,
+      betaG2: dec.betaG2,
+      gammaG2: dec.gammaG2,
+      deltaG2: dec.deltaG2,
+      ic: dec.ic,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): Groth16VerifyingKeyJSON {
+    return {
+      circuitId: this.circuitId,
+      alphaG1: this.alphaG1,
+      betaG2: this.betaG2,
+      gammaG2: this.gammaG2,
+      deltaG2: this.deltaG2,
+      ic: this.ic,
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: Groth16VerifyingKeyJSON): Groth16VerifyingKey {
+    return new Groth16VerifyingKey({
+      circuitId: obj.circuitId,
+      alphaG1: obj.alphaG1,
+      betaG2: obj.betaG2,
+      gammaG2: obj.gammaG2,
+      deltaG2: obj.deltaG2,
+      ic: obj.ic,
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,129 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface GuardianSetFields {
+  nullifier: Array<number>
+  guardians: Array<PublicKey>
+  threshold: number
+  nonce: BN
+  updatedAt: BN
+  bump: number
+}
+
+export interface GuardianSetJSON {
+  nullifier: Array<number>
+  guardians: Array<string>
+  threshold: number
+  nonce: string
+  updatedAt: string
+  bump: number
+}
+
+export class GuardianSet {
+  readonly nullifier: Array<number>
+  readonly guardians: Array<PublicKey>
+  readonly threshold: number
+  readonly nonce: BN
+  readonly updatedAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    120, 77, 74, 98, 34, 83, 96, 125,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    borsh.vec(borsh.publicKey(), "guardians"),
+    borsh.u8("threshold"),
+    borsh.u64("nonce"),
+    borsh.i64("updatedAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: GuardianSetFields) {
+    this.nullifier = fields.nullifier
+    this.guardians = fields.guardians
+    this.threshold = fields.threshold
+    this.nonce = fields.nonce
+    this.updatedAt = fields.updatedAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<GuardianSet | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<GuardianSet | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): GuardianSet {
+    if (!data.slice(0, 8).equals(GuardianSet.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = GuardianSet.layout.decode(data.slice(8))
+
+    return new GuardianSet({
+      nullifier: dec.nullifier,
+      guardians: dec.guardians,
+      threshold: dec.threshold,
+      nonce: dec.nonce,
+      updatedAt: dec.updatedAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): GuardianSetJSON {
+    return {
+      nullifier: this.nullifier,
+      guardians: this.guardians.map((item) => item.toString()),
+      threshold: this.threshold,
+      nonce: this.nonce.toString(),
+      updatedAt: this.updatedAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: GuardianSetJSON): GuardianSet {
+    return new GuardianSet({
+      nullifier: obj.nullifier,
+      guardians: obj.guardians.map((item) => new PublicKey(item)),
+      threshold: obj.threshold,
+      nonce: new BN(obj.nonce),
+      updatedAt: new BN(obj.updatedAt),
+      bump: obj.bump,
+    })
+  }
+}
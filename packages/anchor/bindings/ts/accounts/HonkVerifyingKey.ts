@@ -0,0 +1,137 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface HonkVerifyingKeyFields {
+  circuitId: number
+  logCircuitSize: number
+  publicInputsSize: number
+  pubInputsOffset: number
+  commitments: Array<Array<number>>
+  written: number
+  bump: number
+}
+
+export interface HonkVerifyingKeyJSON {
+  circuitId: number
+  logCircuitSize: number
+  publicInputsSize: number
+  pubInputsOffset: number
+  commitments: Array<Array<number>>
+  written: number
+  bump: number
+}
+
+export class HonkVerifyingKey {
+  readonly circuitId: number
+  readonly logCircuitSize: number
+  readonly publicInputsSize: number
+  readonly pubInputsOffset: number
+  readonly commitments: Array<Array<number>>
+  readonly written: number
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    218, 139, 32, 254, 173, 203, 141, 130,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u32("circuitId"),
+    borsh.u8("logCircuitSize"),
+    borsh.u32("publicInputsSize"),
+    borsh.u32("pubInputsOffset"),
+    borsh.array(borsh.array(borsh.u8(), 64), 27, "commitments"),
+    borsh.u32("written"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: HonkVerifyingKeyFields) {
+    this.circuitId = fields.circuitId
+    this.logCircuitSize = fields.logCircuitSize
+    this.publicInputsSize = fields.publicInputsSize
+    this.pubInputsOffset = fields.pubInputsOffset
+    this.commitments = fields.commitments
+    this.written = fields.written
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<HonkVerifyingKey | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<HonkVerifyingKey | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): HonkVerifyingKey {
+    if (!data.slice(0, 8).equals(HonkVerifyingKey.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = HonkVerifyingKey.layout.decode(data.slice(8))
+
+    return new HonkVerifyingKey({
+      circuitId: dec.circuitId,
+      logCircuitSize: dec.logCircuitSize,
+      publicInputsSize: dec.publicInputsSize,
+      pubInputsOffset: dec.pubInputsOffset,
+      commitments: dec.commitments,
+      written: dec.written,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): HonkVerifyingKeyJSON {
+    return {
+      circuitId: this.circuitId,
+      logCircuitSize: this.logCircuitSize,
+      publicInputsSize: this.publicInputsSize,
+      pubInputsOffset: this.pubInputsOffset,
+      commitments: this.commitments,
+      written: this.written,
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: HonkVerifyingKeyJSON): HonkVerifyingKey {
+    return new HonkVerifyingKey({
+      circuitId: obj.circuitId,
+      logCircuitSize: obj.logCircuitSize,
+      publicInputsSize: obj.publicInputsSize,
+      pubInputsOffset: obj.pubInputsOffset,
+      commitments: obj.commitments,
+      written: obj.written,
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,117 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface IdentityRootFields {
+  owner: PublicKey
+  members: Array<types.RootMember>
+  createdAt: BN
+  bump: number
+}
+
+export interface IdentityRootJSON {
+  owner: string
+  members: Array<types.RootMemberJSON>
+  createdAt: string
+  bump: number
+}
+
+export class IdentityRoot {
+  readonly owner: PublicKey
+  readonly members: Array<types.RootMember>
+  readonly createdAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    174, 192, 112, 51, 48, 57, 211, 195,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("owner"),
+    borsh.vec(types.RootMember.layout(), "members"),
+    borsh.i64("createdAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: IdentityRootFields) {
+    this.owner = fields.owner
+    this.members = fields.members
+    this.createdAt = fields.createdAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<IdentityRoot | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<IdentityRoot | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): IdentityRoot {
+    if (!data.slice(0, 8).equals(IdentityRoot.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = IdentityRoot.layout.decode(data.slice(8))
+
+    return new IdentityRoot({
+      owner: dec.owner,
+      members: dec.members.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.RootMember.fromDecoded(item)
+      ),
+      createdAt: dec.createdAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): IdentityRootJSON {
+    return {
+      owner: this.owner.toString(),
+      members: this.members.map((item) => item.toJSON()),
+      createdAt: this.createdAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: IdentityRootJSON): IdentityRoot {
+    return new IdentityRoot({
+      owner: new PublicKey(obj.owner),
+      members: obj.members.map((item) => types.RootMember.fromJSON(item)),
+      createdAt: new BN(obj.createdAt),
+      bump: obj.bump,
+    })
+  }
+}
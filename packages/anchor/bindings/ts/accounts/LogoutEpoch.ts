@@ -0,0 +1,105 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface LogoutEpochFields {
+  identity: PublicKey
+  loggedOutAt: BN
+  bump: number
+}
+
+export interface LogoutEpochJSON {
+  identity: string
+  loggedOutAt: string
+  bump: number
+}
+
+export class LogoutEpoch {
+  readonly identity: PublicKey
+  readonly loggedOutAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    238, 25, 18, 99, 237, 246, 107, 188,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("identity"),
+    borsh.i64("loggedOutAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: LogoutEpochFields) {
+    this.identity = fields.identity
+    this.loggedOutAt = fields.loggedOutAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<LogoutEpoch | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<LogoutEpoch | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): LogoutEpoch {
+    if (!data.slice(0, 8).equals(LogoutEpoch.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = LogoutEpoch.layout.decode(data.slice(8))
+
+    return new LogoutEpoch({
+      identity: dec.identity,
+      loggedOutAt: dec.loggedOutAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): LogoutEpochJSON {
+    return {
+      identity: this.identity.toString(),
+      loggedOutAt: this.loggedOutAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: LogoutEpochJSON): LogoutEpoch {
+    return new LogoutEpoch({
+      identity: new PublicKey(obj.identity),
+      loggedOutAt: new BN(obj.loggedOutAt),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,133 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface MigrationStateFields {
+  version: number
+  targetVersion: number
+  steps: Array<types.MigrationStep>
+  startedAt: BN
+  deadline: BN
+  bump: number
+}
+
+export interface MigrationStateJSON {
+  version: number
+  targetVersion: number
+  steps: Array<types.MigrationStepJSON>
+  startedAt: string
+  deadline: string
+  bump: number
+}
+
+export class MigrationState {
+  readonly version: number
+  readonly targetVersion: number
+  readonly steps: Array<types.MigrationStep>
+  readonly startedAt: BN
+  readonly deadline: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    95, 146, 135, 64, 145, 25, 197, 115,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u16("version"),
+    borsh.u16("targetVersion"),
+    borsh.vec(types.MigrationStep.layout(), "steps"),
+    borsh.i64("startedAt"),
+    borsh.i64("deadline"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: MigrationStateFields) {
+    this.version = fields.version
+    this.targetVersion = fields.targetVersion
+    this.steps = fields.steps
+    this.startedAt = fields.startedAt
+    this.deadline = fields.deadline
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<MigrationState | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<MigrationState | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): MigrationState {
+    if (!data.slice(0, 8).equals(MigrationState.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = MigrationState.layout.decode(data.slice(8))
+
+    return new MigrationState({
+      version: dec.version,
+      targetVersion: dec.targetVersion,
+      steps: dec.steps.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.MigrationStep.fromDecoded(item)
+      ),
+      startedAt: dec.startedAt,
+      deadline: dec.deadline,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): MigrationStateJSON {
+    return {
+      version: this.version,
+      targetVersion: this.targetVersion,
+      steps: this.steps.map((item) => item.toJSON()),
+      startedAt: this.startedAt.toString(),
+      deadline: this.deadline.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: MigrationStateJSON): MigrationState {
+    return new MigrationState({
+      version: obj.version,
+      targetVersion: obj.targetVersion,
+      steps: obj.steps.map((item) => types.MigrationStep.fromJSON(item)),
+      startedAt: new BN(obj.startedAt),
+      deadline: new BN(obj.deadline),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,137 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface NotificationChannelFields {
+  nullifier: Array<number>
+  appId: PublicKey
+  authority: PublicKey
+  channelCommitment: Array<number>
+  encryptedToken: Uint8Array
+  updatedAt: BN
+  bump: number
+}
+
+export interface NotificationChannelJSON {
+  nullifier: Array<number>
+  appId: string
+  authority: string
+  channelCommitment: Array<number>
+  encryptedToken: Array<number>
+  updatedAt: string
+  bump: number
+}
+
+export class NotificationChannel {
+  readonly nullifier: Array<number>
+  readonly appId: PublicKey
+  readonly authority: PublicKey
+  readonly channelCommitment: Array<number>
+  readonly encryptedToken: Uint8Array
+  readonly updatedAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    200, 219, 134, 10, 125, 169, 114, 80,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    borsh.publicKey("appId"),
+    borsh.publicKey("authority"),
+    borsh.array(borsh.u8(), 32, "channelCommitment"),
+    borsh.vecU8("encryptedToken"),
+    borsh.i64("updatedAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: NotificationChannelFields) {
+    this.nullifier = fields.nullifier
+    this.appId = fields.appId
+    this.authority = fields.authority
+    this.channelCommitment = fields.channelCommitment
+    this.encryptedToken = fields.encryptedToken
+    this.updatedAt = fields.updatedAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<NotificationChannel | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<NotificationChannel | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): NotificationChannel {
+    if (!data.slice(0, 8).equals(NotificationChannel.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = NotificationChannel.layout.decode(data.slice(8))
+
+    return new NotificationChannel({
+      nullifier: dec.nullifier,
+      appId: dec.appId,
+      authority: dec.authority,
+      channelCommitment: dec.channelCommitment,
+      encryptedToken: new Uint8Array(dec.encryptedToken.buffer, dec.encryptedToken.byteOffset, dec.encryptedToken.length),
+      updatedAt: dec.updatedAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): NotificationChannelJSON {
+    return {
+      nullifier: this.nullifier,
+      appId: this.appId.toString(),
+      authority: this.authority.toString(),
+      channelCommitment: this.channelCommitment,
+      encryptedToken: Array.from(this.encryptedToken.values()),
+      updatedAt: this.updatedAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: NotificationChannelJSON): NotificationChannel {
+    return new NotificationChannel({
+      nullifier: obj.nullifier,
+      appId: new PublicKey(obj.appId),
+      authority: new PublicKey(obj.authority),
+      channelCommitment: obj.channelCommitment,
+      encryptedToken: Uint8Array.from(obj.encryptedToken),
+      updatedAt: new BN(obj.updatedAt),
+      bump: obj.bump,
+    })
+  }
+}
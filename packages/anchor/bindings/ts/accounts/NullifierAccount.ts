@@ -0,0 +1,217 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface NullifierAccountFields {
+  nullifier: Array<number>
+  domain: Array<number>
+  domainLen: number
+  authority: PublicKey
+  createdAt: BN
+  lastActiveAt: BN
+  idleTimeoutSecs: BN
+  expiresAt: types.ExpiryKindKind
+  publicInputsHash: Array<number>
+  circuitId: number
+  proofHash: Array<number>
+  verifiedAt: BN
+  verifier: PublicKey
+  finalizesAt: BN
+  payer: PublicKey
+  deviceKey: PublicKey
+  bump: number
+}
+
+export interface NullifierAccountJSON {
+  nullifier: Array<number>
+  domain: Array<number>
+  domainLen: number
+  authority: string
+  createdAt: string
+  lastActiveAt: string
+  idleTimeoutSecs: string
+  expiresAt: types.ExpiryKindJSON
+  publicInputsHash: Array<number>
+  circuitId: number
+  proofHash: Array<number>
+  verifiedAt: string
+  verifier: string
+  finalizesAt: string
+  payer: string
+  deviceKey: string
+  bump: number
+}
+
+export class NullifierAccount {
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly domainLen: number
+  readonly authority: PublicKey
+  readonly createdAt: BN
+  readonly lastActiveAt: BN
+  readonly idleTimeoutSecs: BN
+  readonly expiresAt: types.ExpiryKindKind
+  readonly publicInputsHash: Array<number>
+  readonly circuitId: number
+  readonly proofHash: Array<number>
+  readonly verifiedAt: BN
+  readonly verifier: PublicKey
+  readonly finalizesAt: BN
+  readonly payer: PublicKey
+  readonly deviceKey: PublicKey
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    250, 31, 238, 177, 213, 98, 48, 172,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    borsh.array(borsh.u8(), 32, "domain"),
+    borsh.u8("domainLen"),
+    borsh.publicKey("authority"),
+    borsh.i64("createdAt"),
+    borsh.i64("lastActiveAt"),
+    borsh.i64("idleTimeoutSecs"),
+    types.ExpiryKind.layout("expiresAt"),
+    borsh.array(borsh.u8(), 32, "publicInputsHash"),
+    borsh.u32("circuitId"),
+    borsh.array(borsh.u8(), 32, "proofHash"),
+    borsh.i64("verifiedAt"),
+    borsh.publicKey("verifier"),
+    borsh.i64("finalizesAt"),
+    borsh.publicKey("payer"),
+    borsh.publicKey("deviceKey"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: NullifierAccountFields) {
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.domainLen = fields.domainLen
+    this.authority = fields.authority
+    this.createdAt = fields.createdAt
+    this.lastActiveAt = fields.lastActiveAt
+    this.idleTimeoutSecs = fields.idleTimeoutSecs
+    this.expiresAt = fields.expiresAt
+    this.publicInputsHash = fields.publicInputsHash
+    this.circuitId = fields.circuitId
+    this.proofHash = fields.proofHash
+    this.verifiedAt = fields.verifiedAt
+    this.verifier = fields.verifier
+    this.finalizesAt = fields.finalizesAt
+    this.payer = fields.payer
+    this.deviceKey = fields.deviceKey
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<NullifierAccount | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<NullifierAccount | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): NullifierAccount {
+    if (!data.slice(0, 8).equals(NullifierAccount.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = NullifierAccount.layout.decode(data.slice(8))
+
+    return new NullifierAccount({
+      nullifier: dec.nullifier,
+      domain: dec.domain,
+      domainLen: dec.domainLen,
+      authority: dec.authority,
+      createdAt: dec.createdAt,
+      lastActiveAt: dec.lastActiveAt,
+      idleTimeoutSecs: dec.idleTimeoutSecs,
+      expiresAt: types.ExpiryKind.fromDecoded(dec.expiresAt),
+      publicInputsHash: dec.publicInputsHash,
+      circuitId: dec.circuitId,
+      proofHash: dec.proofHash,
+      verifiedAt: dec.verifiedAt,
+      verifier: dec.verifier,
+      finalizesAt: dec.finalizesAt,
+      payer: dec.payer,
+      deviceKey: dec.deviceKey,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): NullifierAccountJSON {
+    return {
+      nullifier: this.nullifier,
+      domain: this.domain,
+      domainLen: this.domainLen,
+      authority: this.authority.toString(),
+      createdAt: this.createdAt.toString(),
+      lastActiveAt: this.lastActiveAt.toString(),
+      idleTimeoutSecs: this.idleTimeoutSecs.toString(),
+      expiresAt: this.expiresAt.toJSON(),
+      publicInputsHash: this.publicInputsHash,
+      circuitId: this.circuitId,
+      proofHash: this.proofHash,
+      verifiedAt: this.verifiedAt.toString(),
+      verifier: this.verifier.toString(),
+      finalizesAt: this.finalizesAt.toString(),
+      payer: this.payer.toString(),
+      deviceKey: this.deviceKey.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: NullifierAccountJSON): NullifierAccount {
+    return new NullifierAccount({
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      domainLen: obj.domainLen,
+      authority: new PublicKey(obj.authority),
+      createdAt: new BN(obj.createdAt),
+      lastActiveAt: new BN(obj.lastActiveAt),
+      idleTimeoutSecs: new BN(obj.idleTimeoutSecs),
+      expiresAt: types.ExpiryKind.fromJSON(obj.expiresAt),
+      publicInputsHash: obj.publicInputsHash,
+      circuitId: obj.circuitId,
+      proofHash: obj.proofHash,
+      verifiedAt: new BN(obj.verifiedAt),
+      verifier: new PublicKey(obj.verifier),
+      finalizesAt: new BN(obj.finalizesAt),
+      payer: new PublicKey(obj.payer),
+      deviceKey: new PublicKey(obj.deviceKey),
+      bump: obj.bump,
+    })
+  }
+}
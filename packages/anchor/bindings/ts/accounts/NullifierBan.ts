@@ -0,0 +1,121 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface NullifierBanFields {
+  domain: Array<number>
+  nullifier: Array<number>
+  bannedBy: PublicKey
+  bannedAt: BN
+  bump: number
+}
+
+export interface NullifierBanJSON {
+  domain: Array<number>
+  nullifier: Array<number>
+  bannedBy: string
+  bannedAt: string
+  bump: number
+}
+
+export class NullifierBan {
+  readonly domain: Array<number>
+  readonly nullifier: Array<number>
+  readonly bannedBy: PublicKey
+  readonly bannedAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    69, 159, 117, 230, 31, 14, 205, 198,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "domain"),
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    borsh.publicKey("bannedBy"),
+    borsh.i64("bannedAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: NullifierBanFields) {
+    this.domain = fields.domain
+    this.nullifier = fields.nullifier
+    this.bannedBy = fields.bannedBy
+    this.bannedAt = fields.bannedAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<NullifierBan | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<NullifierBan | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): NullifierBan {
+    if (!data.slice(0, 8).equals(NullifierBan.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = NullifierBan.layout.decode(data.slice(8))
+
+    return new NullifierBan({
+      domain: dec.domain,
+      nullifier: dec.nullifier,
+      bannedBy: dec.bannedBy,
+      bannedAt: dec.bannedAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): NullifierBanJSON {
+    return {
+      domain: this.domain,
+      nullifier: this.nullifier,
+      bannedBy: this.bannedBy.toString(),
+      bannedAt: this.bannedAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: NullifierBanJSON): NullifierBan {
+    return new NullifierBan({
+      domain: obj.domain,
+      nullifier: obj.nullifier,
+      bannedBy: new PublicKey(obj.bannedBy),
+      bannedAt: new BN(obj.bannedAt),
+      bump: obj.bump,
+    })
+  }
+}
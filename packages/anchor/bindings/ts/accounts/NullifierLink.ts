@@ -0,0 +1,137 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface NullifierLinkFields {
+  nullifier: Array<number>
+  successor: Array<number>
+  domain: Array<number>
+  proofHash: Array<number>
+  authority: PublicKey
+  linkedAt: BN
+  bump: number
+}
+
+export interface NullifierLinkJSON {
+  nullifier: Array<number>
+  successor: Array<number>
+  domain: Array<number>
+  proofHash: Array<number>
+  authority: string
+  linkedAt: string
+  bump: number
+}
+
+export class NullifierLink {
+  readonly nullifier: Array<number>
+  readonly successor: Array<number>
+  readonly domain: Array<number>
+  readonly proofHash: Array<number>
+  readonly authority: PublicKey
+  readonly linkedAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    204, 186, 225, 208, 214, 19, 180, 81,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    borsh.array(borsh.u8(), 32, "successor"),
+    borsh.array(borsh.u8(), 32, "domain"),
+    borsh.array(borsh.u8(), 32, "proofHash"),
+    borsh.publicKey("authority"),
+    borsh.i64("linkedAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: NullifierLinkFields) {
+    this.nullifier = fields.nullifier
+    this.successor = fields.successor
+    this.domain = fields.domain
+    this.proofHash = fields.proofHash
+    this.authority = fields.authority
+    this.linkedAt = fields.linkedAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<NullifierLink | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<NullifierLink | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): NullifierLink {
+    if (!data.slice(0, 8).equals(NullifierLink.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = NullifierLink.layout.decode(data.slice(8))
+
+    return new NullifierLink({
+      nullifier: dec.nullifier,
+      successor: dec.successor,
+      domain: dec.domain,
+      proofHash: dec.proofHash,
+      authority: dec.authority,
+      linkedAt: dec.linkedAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): NullifierLinkJSON {
+    return {
+      nullifier: this.nullifier,
+      successor: this.successor,
+      domain: this.domain,
+      proofHash: this.proofHash,
+      authority: this.authority.toString(),
+      linkedAt: this.linkedAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: NullifierLinkJSON): NullifierLink {
+    return new NullifierLink({
+      nullifier: obj.nullifier,
+      successor: obj.successor,
+      domain: obj.domain,
+      proofHash: obj.proofHash,
+      authority: new PublicKey(obj.authority),
+      linkedAt: new BN(obj.linkedAt),
+      bump: obj.bump,
+    })
+  }
+}
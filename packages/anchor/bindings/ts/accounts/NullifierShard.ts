@@ -0,0 +1,129 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface NullifierShardFields {
+  bits: Array<number>
+  domain: Array<number>
+  registered: number
+  shard: number
+  bump: number
+  padding: Array<number>
+}
+
+export interface NullifierShardJSON {
+  bits: Array<number>
+  domain: Array<number>
+  registered: number
+  shard: number
+  bump: number
+  padding: Array<number>
+}
+
+export class NullifierShard {
+  readonly bits: Array<number>
+  readonly domain: Array<number>
+  readonly registered: number
+  readonly shard: number
+  readonly bump: number
+  readonly padding: Array<number>
+
+  static readonly discriminator = Buffer.from([
+    197, 157, 1, 146, 156, 137, 211, 61,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 1024, "bits"),
+    borsh.array(borsh.u8(), 32, "domain"),
+    borsh.u32("registered"),
+    borsh.u16("shard"),
+    borsh.u8("bump"),
+    borsh.array(borsh.u8(), 1, "padding"),
+  ])
+
+  constructor(fields: NullifierShardFields) {
+    this.bits = fields.bits
+    this.domain = fields.domain
+    this.registered = fields.registered
+    this.shard = fields.shard
+    this.bump = fields.bump
+    this.padding = fields.padding
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<NullifierShard | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<NullifierShard | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): NullifierShard {
+    if (!data.slice(0, 8).equals(NullifierShard.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = NullifierShard.layout.decode(data.slice(8))
+
+    return new NullifierShard({
+      bits: dec.bits,
+      domain: dec.domain,
+      registered: dec.registered,
+      shard: dec.shard,
+      bump: dec.bump,
+      padding: dec.padding,
+    })
+  }
+
+  toJSON(): NullifierShardJSON {
+    return {
+      bits: this.bits,
+      domain: this.domain,
+      registered: this.registered,
+      shard: this.shard,
+      bump: this.bump,
+      padding: this.padding,
+    }
+  }
+
+  static fromJSON(obj: NullifierShardJSON): NullifierShard {
+    return new NullifierShard({
+      bits: obj.bits,
+      domain: obj.domain,
+      registered: obj.registered,
+      shard: obj.shard,
+      bump: obj.bump,
+      padding: obj.padding,
+    })
+  }
+}
@@ -0,0 +1,113 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface NullifierTreeFields {
+  domain: Array<number>
+  merkleTree: PublicKey
+  nextIndex: number
+  bump: number
+}
+
+export interface NullifierTreeJSON {
+  domain: Array<number>
+  merkleTree: string
+  nextIndex: number
+  bump: number
+}
+
+export class NullifierTree {
+  readonly domain: Array<number>
+  readonly merkleTree: PublicKey
+  readonly nextIndex: number
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    219, 121, 3, 224, 21, 113, 216, 116,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "domain"),
+    borsh.publicKey("merkleTree"),
+    borsh.u32("nextIndex"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: NullifierTreeFields) {
+    this.domain = fields.domain
+    this.merkleTree = fields.merkleTree
+    this.nextIndex = fields.nextIndex
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<NullifierTree | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<NullifierTree | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): NullifierTree {
+    if (!data.slice(0, 8).equals(NullifierTree.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = NullifierTree.layout.decode(data.slice(8))
+
+    return new NullifierTree({
+      domain: dec.domain,
+      merkleTree: dec.merkleTree,
+      nextIndex: dec.nextIndex,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): NullifierTreeJSON {
+    return {
+      domain: this.domain,
+      merkleTree: this.merkleTree.toString(),
+      nextIndex: this.nextIndex,
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: NullifierTreeJSON): NullifierTree {
+    return new NullifierTree({
+      domain: obj.domain,
+      merkleTree: new PublicKey(obj.merkleTree),
+      nextIndex: obj.nextIndex,
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,129 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface PayerRateLimitFields {
+  payer: PublicKey
+  currentBucket: BN
+  buckets: Array<number>
+  quota: number
+  bump: number
+  padding: Array<number>
+}
+
+export interface PayerRateLimitJSON {
+  payer: string
+  currentBucket: string
+  buckets: Array<number>
+  quota: number
+  bump: number
+  padding: Array<number>
+}
+
+export class PayerRateLimit {
+  readonly payer: PublicKey
+  readonly currentBucket: BN
+  readonly buckets: Array<number>
+  readonly quota: number
+  readonly bump: number
+  readonly padding: Array<number>
+
+  static readonly discriminator = Buffer.from([
+    92, 192, 189, 53, 254, 82, 49, 62,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("payer"),
+    borsh.i64("currentBucket"),
+    borsh.array(borsh.u32(), 6, "buckets"),
+    borsh.u32("quota"),
+    borsh.u8("bump"),
+    borsh.array(borsh.u8(), 3, "padding"),
+  ])
+
+  constructor(fields: PayerRateLimitFields) {
+    this.payer = fields.payer
+    this.currentBucket = fields.currentBucket
+    this.buckets = fields.buckets
+    this.quota = fields.quota
+    this.bump = fields.bump
+    this.padding = fields.padding
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<PayerRateLimit | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<PayerRateLimit | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): PayerRateLimit {
+    if (!data.slice(0, 8).equals(PayerRateLimit.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = PayerRateLimit.layout.decode(data.slice(8))
+
+    return new PayerRateLimit({
+      payer: dec.payer,
+      currentBucket: dec.currentBucket,
+      buckets: dec.buckets,
+      quota: dec.quota,
+      bump: dec.bump,
+      padding: dec.padding,
+    })
+  }
+
+  toJSON(): PayerRateLimitJSON {
+    return {
+      payer: this.payer.toString(),
+      currentBucket: this.currentBucket.toString(),
+      buckets: this.buckets,
+      quota: this.quota,
+      bump: this.bump,
+      padding: this.padding,
+    }
+  }
+
+  static fromJSON(obj: PayerRateLimitJSON): PayerRateLimit {
+    return new PayerRateLimit({
+      payer: new PublicKey(obj.payer),
+      currentBucket: new BN(obj.currentBucket),
+      buckets: obj.buckets,
+      quota: obj.quota,
+      bump: obj.bump,
+      padding: obj.padding,
+    })
+  }
+}
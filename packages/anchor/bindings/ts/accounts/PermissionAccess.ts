@@ -0,0 +1,121 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface PermissionAccessFields {
+  permissionGrant: PublicKey
+  accessedAt: BN
+  permissionUsed: types.PermissionKind
+  metadata: string
+  payer: PublicKey
+}
+
+export interface PermissionAccessJSON {
+  permissionGrant: string
+  accessedAt: string
+  permissionUsed: types.PermissionJSON
+  metadata: string
+  payer: string
+}
+
+export class PermissionAccess {
+  readonly permissionGrant: PublicKey
+  readonly accessedAt: BN
+  readonly permissionUsed: types.PermissionKind
+  readonly metadata: string
+  readonly payer: PublicKey
+
+  static readonly discriminator = Buffer.from([
+    31, 40, 88, 182, 105, 142, 232, 36,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("permissionGrant"),
+    borsh.i64("accessedAt"),
+    types.Permission.layout("permissionUsed"),
+    borsh.str("metadata"),
+    borsh.publicKey("payer"),
+  ])
+
+  constructor(fields: PermissionAccessFields) {
+    this.permissionGrant = fields.permissionGrant
+    this.accessedAt = fields.accessedAt
+    this.permissionUsed = fields.permissionUsed
+    this.metadata = fields.metadata
+    this.payer = fields.payer
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<PermissionAccess | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<PermissionAccess | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): PermissionAccess {
+    if (!data.slice(0, 8).equals(PermissionAccess.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = PermissionAccess.layout.decode(data.slice(8))
+
+    return new PermissionAccess({
+      permissionGrant: dec.permissionGrant,
+      accessedAt: dec.accessedAt,
+      permissionUsed: types.Permission.fromDecoded(dec.permissionUsed),
+      metadata: dec.metadata,
+      payer: dec.payer,
+    })
+  }
+
+  toJSON(): PermissionAccessJSON {
+    return {
+      permissionGrant: this.permissionGrant.toString(),
+      accessedAt: this.accessedAt.toString(),
+      permissionUsed: this.permissionUsed.toJSON(),
+      metadata: this.metadata,
+      payer: this.payer.toString(),
+    }
+  }
+
+  static fromJSON(obj: PermissionAccessJSON): PermissionAccess {
+    return new PermissionAccess({
+      permissionGrant: new PublicKey(obj.permissionGrant),
+      accessedAt: new BN(obj.accessedAt),
+      permissionUsed: types.Permission.fromJSON(obj.permissionUsed),
+      metadata: obj.metadata,
+      payer: new PublicKey(obj.payer),
+    })
+  }
+}
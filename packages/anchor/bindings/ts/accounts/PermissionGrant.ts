@@ -0,0 +1,157 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface PermissionGrantFields {
+  nullifier: Array<number>
+  appId: PublicKey
+  permissions: Array<types.PermissionKind>
+  grantedAt: BN
+  expiresAt: types.ExpiryKindKind
+  revoked: boolean
+  bump: number
+  permissionMask: BN
+  successor: Array<number>
+}
+
+export interface PermissionGrantJSON {
+  nullifier: Array<number>
+  appId: string
+  permissions: Array<types.PermissionJSON>
+  grantedAt: string
+  expiresAt: types.ExpiryKindJSON
+  revoked: boolean
+  bump: number
+  permissionMask: string
+  successor: Array<number>
+}
+
+export class PermissionGrant {
+  readonly nullifier: Array<number>
+  readonly appId: PublicKey
+  readonly permissions: Array<types.PermissionKind>
+  readonly grantedAt: BN
+  readonly expiresAt: types.ExpiryKindKind
+  readonly revoked: boolean
+  readonly bump: number
+  readonly permissionMask: BN
+  readonly successor: Array<number>
+
+  static readonly discriminator = Buffer.from([
+    224, 114, 132, 200, 84, 176, 71, 71,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    borsh.publicKey("appId"),
+    borsh.vec(types.Permission.layout(), "permissions"),
+    borsh.i64("grantedAt"),
+    types.ExpiryKind.layout("expiresAt"),
+    borsh.bool("revoked"),
+    borsh.u8("bump"),
+    borsh.u64("permissionMask"),
+    borsh.array(borsh.u8(), 32, "successor"),
+  ])
+
+  constructor(fields: PermissionGrantFields) {
+    this.nullifier = fields.nullifier
+    this.appId = fields.appId
+    this.permissions = fields.permissions
+    this.grantedAt = fields.grantedAt
+    this.expiresAt = fields.expiresAt
+    this.revoked = fields.revoked
+    this.bump = fields.bump
+    this.permissionMask = fields.permissionMask
+    this.successor = fields.successor
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<PermissionGrant | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<PermissionGrant | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): PermissionGrant {
+    if (!data.slice(0, 8).equals(PermissionGrant.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = PermissionGrant.layout.decode(data.slice(8))
+
+    return new PermissionGrant({
+      nullifier: dec.nullifier,
+      appId: dec.appId,
+      permissions: dec.permissions.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.Permission.fromDecoded(item)
+      ),
+      grantedAt: dec.grantedAt,
+      expiresAt: types.ExpiryKind.fromDecoded(dec.expiresAt),
+      revoked: dec.revoked,
+      bump: dec.bump,
+      permissionMask: dec.permissionMask,
+      successor: dec.successor,
+    })
+  }
+
+  toJSON(): PermissionGrantJSON {
+    return {
+      nullifier: this.nullifier,
+      appId: this.appId.toString(),
+      permissions: this.permissions.map((item) => item.toJSON()),
+      grantedAt: this.grantedAt.toString(),
+      expiresAt: this.expiresAt.toJSON(),
+      revoked: this.revoked,
+      bump: this.bump,
+      permissionMask: this.permissionMask.toString(),
+      successor: this.successor,
+    }
+  }
+
+  static fromJSON(obj: PermissionGrantJSON): PermissionGrant {
+    return new PermissionGrant({
+      nullifier: obj.nullifier,
+      appId: new PublicKey(obj.appId),
+      permissions: obj.permissions.map((item) => types.Permission.fromJSON(item)),
+      grantedAt: new BN(obj.grantedAt),
+      expiresAt: types.ExpiryKind.fromJSON(obj.expiresAt),
+      revoked: obj.revoked,
+      bump: obj.bump,
+      permissionMask: new BN(obj.permissionMask),
+      successor: obj.successor,
+    })
+  }
+}
@@ -0,0 +1,129 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface PersonhoodAttestationFields {
+  nullifier: Array<number>
+  provider: PublicKey
+  score: number
+  issuedAt: BN
+  expiresAt: BN
+  bump: number
+}
+
+export interface PersonhoodAttestationJSON {
+  nullifier: Array<number>
+  provider: string
+  score: number
+  issuedAt: string
+  expiresAt: string
+  bump: number
+}
+
+export class PersonhoodAttestation {
+  readonly nullifier: Array<number>
+  readonly provider: PublicKey
+  readonly score: number
+  readonly issuedAt: BN
+  readonly expiresAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    133, 103, 115, 254, 161, 136, 144, 234,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    borsh.publicKey("provider"),
+    borsh.u16("score"),
+    borsh.i64("issuedAt"),
+    borsh.i64("expiresAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: PersonhoodAttestationFields) {
+    this.nullifier = fields.nullifier
+    this.provider = fields.provider
+    this.score = fields.score
+    this.issuedAt = fields.issuedAt
+    this.expiresAt = fields.expiresAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<PersonhoodAttestation | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<PersonhoodAttestation | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): PersonhoodAttestation {
+    if (!data.slice(0, 8).equals(PersonhoodAttestation.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = PersonhoodAttestation.layout.decode(data.slice(8))
+
+    return new PersonhoodAttestation({
+      nullifier: dec.nullifier,
+      provider: dec.provider,
+      score: dec.score,
+      issuedAt: dec.issuedAt,
+      expiresAt: dec.expiresAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): PersonhoodAttestationJSON {
+    return {
+      nullifier: this.nullifier,
+      provider: this.provider.toString(),
+      score: this.score,
+      issuedAt: this.issuedAt.toString(),
+      expiresAt: this.expiresAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: PersonhoodAttestationJSON): PersonhoodAttestation {
+    return new PersonhoodAttestation({
+      nullifier: obj.nullifier,
+      provider: new PublicKey(obj.provider),
+      score: obj.score,
+      issuedAt: new BN(obj.issuedAt),
+      expiresAt: new BN(obj.expiresAt),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,97 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface PersonhoodProviderRegistryFields {
+  providers: Array<PublicKey>
+  bump: number
+}
+
+export interface PersonhoodProviderRegistryJSON {
+  providers: Array<string>
+  bump: number
+}
+
+export class PersonhoodProviderRegistry {
+  readonly providers: Array<PublicKey>
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    27, 15, 158, 150, 46, 233, 22, 194,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.vec(borsh.publicKey(), "providers"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: PersonhoodProviderRegistryFields) {
+    this.providers = fields.providers
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<PersonhoodProviderRegistry | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<PersonhoodProviderRegistry | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): PersonhoodProviderRegistry {
+    if (!data.slice(0, 8).equals(PersonhoodProviderRegistry.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = PersonhoodProviderRegistry.layout.decode(data.slice(8))
+
+    return new PersonhoodProviderRegistry({
+      providers: dec.providers,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): PersonhoodProviderRegistryJSON {
+    return {
+      providers: this.providers.map((item) => item.toString()),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: PersonhoodProviderRegistryJSON): PersonhoodProviderRegistry {
+    return new PersonhoodProviderRegistry({
+      providers: obj.providers.map((item) => new PublicKey(item)),
+      bump: obj.bump,
+    })
+  }
+}
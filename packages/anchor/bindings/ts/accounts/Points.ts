@@ -0,0 +1,113 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface PointsFields {
+  nullifier: Array<number>
+  balance: BN
+  claimedAt: BN
+  bump: number
+}
+
+export interface PointsJSON {
+  nullifier: Array<number>
+  balance: string
+  claimedAt: string
+  bump: number
+}
+
+export class Points {
+  readonly nullifier: Array<number>
+  readonly balance: BN
+  readonly claimedAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    132, 81, 12, 146, 255, 233, 97, 242,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    borsh.u64("balance"),
+    borsh.i64("claimedAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: PointsFields) {
+    this.nullifier = fields.nullifier
+    this.balance = fields.balance
+    this.claimedAt = fields.claimedAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Points | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Points | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Points {
+    if (!data.slice(0, 8).equals(Points.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Points.layout.decode(data.slice(8))
+
+    return new Points({
+      nullifier: dec.nullifier,
+      balance: dec.balance,
+      claimedAt: dec.claimedAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): PointsJSON {
+    return {
+      nullifier: this.nullifier,
+      balance: this.balance.toString(),
+      claimedAt: this.claimedAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: PointsJSON): Points {
+    return new Points({
+      nullifier: obj.nullifier,
+      balance: new BN(obj.balance),
+      claimedAt: new BN(obj.claimedAt),
+      bump: obj.bump,
+    })
+  }
+}
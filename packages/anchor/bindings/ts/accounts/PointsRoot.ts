@@ -0,0 +1,113 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface PointsRootFields {
+  root: Array<number>
+  leafCount: number
+  publishedAt: BN
+  bump: number
+}
+
+export interface PointsRootJSON {
+  root: Array<number>
+  leafCount: number
+  publishedAt: string
+  bump: number
+}
+
+export class PointsRoot {
+  readonly root: Array<number>
+  readonly leafCount: number
+  readonly publishedAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    32, 107, 79, 72, 240, 114, 150, 122,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "root"),
+    borsh.u32("leafCount"),
+    borsh.i64("publishedAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: PointsRootFields) {
+    this.root = fields.root
+    this.leafCount = fields.leafCount
+    this.publishedAt = fields.publishedAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<PointsRoot | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<PointsRoot | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): PointsRoot {
+    if (!data.slice(0, 8).equals(PointsRoot.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = PointsRoot.layout.decode(data.slice(8))
+
+    return new PointsRoot({
+      root: dec.root,
+      leafCount: dec.leafCount,
+      publishedAt: dec.publishedAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): PointsRootJSON {
+    return {
+      root: this.root,
+      leafCount: this.leafCount,
+      publishedAt: this.publishedAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: PointsRootJSON): PointsRoot {
+    return new PointsRoot({
+      root: obj.root,
+      leafCount: obj.leafCount,
+      publishedAt: new BN(obj.publishedAt),
+      bump: obj.bump,
+    })
+  }
+}
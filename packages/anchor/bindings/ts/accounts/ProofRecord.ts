@@ -0,0 +1,121 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ProofRecordFields {
+  proofHash: Array<number>
+  nullifier: Array<number>
+  domain: Array<number>
+  createdAt: BN
+  bump: number
+}
+
+export interface ProofRecordJSON {
+  proofHash: Array<number>
+  nullifier: Array<number>
+  domain: Array<number>
+  createdAt: string
+  bump: number
+}
+
+export class ProofRecord {
+  readonly proofHash: Array<number>
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly createdAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    237, 59, 155, 172, 204, 117, 87, 44,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "proofHash"),
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    borsh.array(borsh.u8(), 32, "domain"),
+    borsh.i64("createdAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: ProofRecordFields) {
+    this.proofHash = fields.proofHash
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.createdAt = fields.createdAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<ProofRecord | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<ProofRecord | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): ProofRecord {
+    if (!data.slice(0, 8).equals(ProofRecord.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = ProofRecord.layout.decode(data.slice(8))
+
+    return new ProofRecord({
+      proofHash: dec.proofHash,
+      nullifier: dec.nullifier,
+      domain: dec.domain,
+      createdAt: dec.createdAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): ProofRecordJSON {
+    return {
+      proofHash: this.proofHash,
+      nullifier: this.nullifier,
+      domain: this.domain,
+      createdAt: this.createdAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: ProofRecordJSON): ProofRecord {
+    return new ProofRecord({
+      proofHash: obj.proofHash,
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      createdAt: new BN(obj.createdAt),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,133 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ProtocolConfigFields {
+  authority: PublicKey
+  acceptedMints: Array<types.AcceptedMint>
+  feeUsdMicros: BN
+  maxPriceAgeSecs: BN
+  maxPriceConfBps: number
+  bump: number
+}
+
+export interface ProtocolConfigJSON {
+  authority: string
+  acceptedMints: Array<types.AcceptedMintJSON>
+  feeUsdMicros: string
+  maxPriceAgeSecs: string
+  maxPriceConfBps: number
+  bump: number
+}
+
+export class ProtocolConfig {
+  readonly authority: PublicKey
+  readonly acceptedMints: Array<types.AcceptedMint>
+  readonly feeUsdMicros: BN
+  readonly maxPriceAgeSecs: BN
+  readonly maxPriceConfBps: number
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    207, 91, 250, 28, 152, 179, 215, 209,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("authority"),
+    borsh.vec(types.AcceptedMint.layout(), "acceptedMints"),
+    borsh.u64("feeUsdMicros"),
+    borsh.u64("maxPriceAgeSecs"),
+    borsh.u16("maxPriceConfBps"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: ProtocolConfigFields) {
+    this.authority = fields.authority
+    this.acceptedMints = fields.acceptedMints
+    this.feeUsdMicros = fields.feeUsdMicros
+    this.maxPriceAgeSecs = fields.maxPriceAgeSecs
+    this.maxPriceConfBps = fields.maxPriceConfBps
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<ProtocolConfig | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<ProtocolConfig | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): ProtocolConfig {
+    if (!data.slice(0, 8).equals(ProtocolConfig.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = ProtocolConfig.layout.decode(data.slice(8))
+
+    return new ProtocolConfig({
+      authority: dec.authority,
+      acceptedMints: dec.acceptedMints.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.AcceptedMint.fromDecoded(item)
+      ),
+      feeUsdMicros: dec.feeUsdMicros,
+      maxPriceAgeSecs: dec.maxPriceAgeSecs,
+      maxPriceConfBps: dec.maxPriceConfBps,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): ProtocolConfigJSON {
+    return {
+      authority: this.authority.toString(),
+      acceptedMints: this.acceptedMints.map((item) => item.toJSON()),
+      feeUsdMicros: this.feeUsdMicros.toString(),
+      maxPriceAgeSecs: this.maxPriceAgeSecs.toString(),
+      maxPriceConfBps: this.maxPriceConfBps,
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: ProtocolConfigJSON): ProtocolConfig {
+    return new ProtocolConfig({
+      authority: new PublicKey(obj.authority),
+      acceptedMints: obj.acceptedMints.map((item) => types.AcceptedMint.fromJSON(item)),
+      feeUsdMicros: new BN(obj.feeUsdMicros),
+      maxPriceAgeSecs: new BN(obj.maxPriceAgeSecs),
+      maxPriceConfBps: obj.maxPriceConfBps,
+      bump: obj.bump,
+    })
+  }
+}
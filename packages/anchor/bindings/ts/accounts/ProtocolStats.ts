@@ -0,0 +1,121 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ProtocolStatsFields {
+  totalVerifications: BN
+  totalGrants: BN
+  totalRevocations: BN
+  totalAttestations: BN
+  bump: number
+}
+
+export interface ProtocolStatsJSON {
+  totalVerifications: string
+  totalGrants: string
+  totalRevocations: string
+  totalAttestations: string
+  bump: number
+}
+
+export class ProtocolStats {
+  readonly totalVerifications: BN
+  readonly totalGrants: BN
+  readonly totalRevocations: BN
+  readonly totalAttestations: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    133, 227, 216, 241, 235, 172, 35, 31,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.u64("totalVerifications"),
+    borsh.u64("totalGrants"),
+    borsh.u64("totalRevocations"),
+    borsh.u64("totalAttestations"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: ProtocolStatsFields) {
+    this.totalVerifications = fields.totalVerifications
+    this.totalGrants = fields.totalGrants
+    this.totalRevocations = fields.totalRevocations
+    this.totalAttestations = fields.totalAttestations
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<ProtocolStats | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<ProtocolStats | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): ProtocolStats {
+    if (!data.slice(0, 8).equals(ProtocolStats.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = ProtocolStats.layout.decode(data.slice(8))
+
+    return new ProtocolStats({
+      totalVerifications: dec.totalVerifications,
+      totalGrants: dec.totalGrants,
+      totalRevocations: dec.totalRevocations,
+      totalAttestations: dec.totalAttestations,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): ProtocolStatsJSON {
+    return {
+      totalVerifications: this.totalVerifications.toString(),
+      totalGrants: this.totalGrants.toString(),
+      totalRevocations: this.totalRevocations.toString(),
+      totalAttestations: this.totalAttestations.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: ProtocolStatsJSON): ProtocolStats {
+    return new ProtocolStats({
+      totalVerifications: new BN(obj.totalVerifications),
+      totalGrants: new BN(obj.totalGrants),
+      totalRevocations: new BN(obj.totalRevocations),
+      totalAttestations: new BN(obj.totalAttestations),
+      bump: obj.bump,
+    })
+  }
+}
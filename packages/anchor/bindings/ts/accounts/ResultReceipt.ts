@@ -0,0 +1,121 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ResultReceiptFields {
+  signatureHash: Array<number>
+  payer: PublicKey
+  consumedAt: BN
+  retainUntil: BN
+  bump: number
+}
+
+export interface ResultReceiptJSON {
+  signatureHash: Array<number>
+  payer: string
+  consumedAt: string
+  retainUntil: string
+  bump: number
+}
+
+export class ResultReceipt {
+  readonly signatureHash: Array<number>
+  readonly payer: PublicKey
+  readonly consumedAt: BN
+  readonly retainUntil: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    181, 239, 156, 8, 213, 129, 130, 222,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "signatureHash"),
+    borsh.publicKey("payer"),
+    borsh.i64("consumedAt"),
+    borsh.i64("retainUntil"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: ResultReceiptFields) {
+    this.signatureHash = fields.signatureHash
+    this.payer = fields.payer
+    this.consumedAt = fields.consumedAt
+    this.retainUntil = fields.retainUntil
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<ResultReceipt | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<ResultReceipt | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): ResultReceipt {
+    if (!data.slice(0, 8).equals(ResultReceipt.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = ResultReceipt.layout.decode(data.slice(8))
+
+    return new ResultReceipt({
+      signatureHash: dec.signatureHash,
+      payer: dec.payer,
+      consumedAt: dec.consumedAt,
+      retainUntil: dec.retainUntil,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): ResultReceiptJSON {
+    return {
+      signatureHash: this.signatureHash,
+      payer: this.payer.toString(),
+      consumedAt: this.consumedAt.toString(),
+      retainUntil: this.retainUntil.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: ResultReceiptJSON): ResultReceipt {
+    return new ResultReceipt({
+      signatureHash: obj.signatureHash,
+      payer: new PublicKey(obj.payer),
+      consumedAt: new BN(obj.consumedAt),
+      retainUntil: new BN(obj.retainUntil),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,161 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RevealEscrowFields {
+  nullifier: Array<number>
+  appId: PublicKey
+  authority: PublicKey
+  ciphertext: Uint8Array
+  keyCommitment: Array<number>
+  revealKey: Array<number>
+  openedAt: BN
+  revealsAt: BN
+  released: boolean
+  bump: number
+}
+
+export interface RevealEscrowJSON {
+  nullifier: Array<number>
+  appId: string
+  authority: string
+  ciphertext: Array<number>
+  keyCommitment: Array<number>
+  revealKey: Array<number>
+  openedAt: string
+  revealsAt: string
+  released: boolean
+  bump: number
+}
+
+export class RevealEscrow {
+  readonly nullifier: Array<number>
+  readonly appId: PublicKey
+  readonly authority: PublicKey
+  readonly ciphertext: Uint8Array
+  readonly keyCommitment: Array<number>
+  readonly revealKey: Array<number>
+  readonly openedAt: BN
+  readonly revealsAt: BN
+  readonly released: boolean
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    201, 113, 96, 179, 54, 214, 216, 106,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    borsh.publicKey("appId"),
+    borsh.publicKey("authority"),
+    borsh.vecU8("ciphertext"),
+    borsh.array(borsh.u8(), 32, "keyCommitment"),
+    borsh.array(borsh.u8(), 32, "revealKey"),
+    borsh.i64("openedAt"),
+    borsh.i64("revealsAt"),
+    borsh.bool("released"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: RevealEscrowFields) {
+    this.nullifier = fields.nullifier
+    this.appId = fields.appId
+    this.authority = fields.authority
+    this.ciphertext = fields.ciphertext
+    this.keyCommitment = fields.keyCommitment
+    this.revealKey = fields.revealKey
+    this.openedAt = fields.openedAt
+    this.revealsAt = fields.revealsAt
+    this.released = fields.released
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<RevealEscrow | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<RevealEscrow | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): RevealEscrow {
+    if (!data.slice(0, 8).equals(RevealEscrow.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = RevealEscrow.layout.decode(data.slice(8))
+
+    return new RevealEscrow({
+      nullifier: dec.nullifier,
+      appId: dec.appId,
+      authority: dec.authority,
+      ciphertext: new Uint8Array(dec.ciphertext.buffer, dec.ciphertext.byteOffset, dec.ciphertext.length),
+      keyCommitment: dec.keyCommitment,
+      revealKey: dec.revealKey,
+      openedAt: dec.openedAt,
+      revealsAt: dec.revealsAt,
+      released: dec.released,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): RevealEscrowJSON {
+    return {
+      nullifier: this.nullifier,
+      appId: this.appId.toString(),
+      authority: this.authority.toString(),
+      ciphertext: Array.from(this.ciphertext.values()),
+      keyCommitment: this.keyCommitment,
+      revealKey: this.revealKey,
+      openedAt: this.openedAt.toString(),
+      revealsAt: this.revealsAt.toString(),
+      released: this.released,
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: RevealEscrowJSON): RevealEscrow {
+    return new RevealEscrow({
+      nullifier: obj.nullifier,
+      appId: new PublicKey(obj.appId),
+      authority: new PublicKey(obj.authority),
+      ciphertext: Uint8Array.from(obj.ciphertext),
+      keyCommitment: obj.keyCommitment,
+      revealKey: obj.revealKey,
+      openedAt: new BN(obj.openedAt),
+      revealsAt: new BN(obj.revealsAt),
+      released: obj.released,
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,105 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SasBridgeFields {
+  credential: PublicKey
+  schema: PublicKey
+  bump: number
+}
+
+export interface SasBridgeJSON {
+  credential: string
+  schema: string
+  bump: number
+}
+
+export class SasBridge {
+  readonly credential: PublicKey
+  readonly schema: PublicKey
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    13, 156, 25, 194, 119, 133, 61, 175,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("credential"),
+    borsh.publicKey("schema"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: SasBridgeFields) {
+    this.credential = fields.credential
+    this.schema = fields.schema
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<SasBridge | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<SasBridge | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): SasBridge {
+    if (!data.slice(0, 8).equals(SasBridge.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = SasBridge.layout.decode(data.slice(8))
+
+    return new SasBridge({
+      credential: dec.credential,
+      schema: dec.schema,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): SasBridgeJSON {
+    return {
+      credential: this.credential.toString(),
+      schema: this.schema.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: SasBridgeJSON): SasBridge {
+    return new SasBridge({
+      credential: new PublicKey(obj.credential),
+      schema: new PublicKey(obj.schema),
+      bump: obj.bump,
+    })
+  }
+}
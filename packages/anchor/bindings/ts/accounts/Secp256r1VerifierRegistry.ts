@@ -0,0 +1,97 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface Secp256r1VerifierRegistryFields {
+  verifiers: Array<Array<number>>
+  bump: number
+}
+
+export interface Secp256r1VerifierRegistryJSON {
+  verifiers: Array<Array<number>>
+  bump: number
+}
+
+export class Secp256r1VerifierRegistry {
+  readonly verifiers: Array<Array<number>>
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    32, 218, 187, 170, 29, 62, 57, 175,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.vec(borsh.array(borsh.u8(), 33), "verifiers"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: Secp256r1VerifierRegistryFields) {
+    this.verifiers = fields.verifiers
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Secp256r1VerifierRegistry | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Secp256r1VerifierRegistry | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Secp256r1VerifierRegistry {
+    if (!data.slice(0, 8).equals(Secp256r1VerifierRegistry.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Secp256r1VerifierRegistry.layout.decode(data.slice(8))
+
+    return new Secp256r1VerifierRegistry({
+      verifiers: dec.verifiers,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): Secp256r1VerifierRegistryJSON {
+    return {
+      verifiers: this.verifiers,
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: Secp256r1VerifierRegistryJSON): Secp256r1VerifierRegistry {
+    return new Secp256r1VerifierRegistry({
+      verifiers: obj.verifiers,
+      bump: obj.bump,
+    })
+  }
+}
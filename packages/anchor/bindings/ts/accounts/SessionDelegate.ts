@@ -0,0 +1,137 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SessionDelegateFields {
+  session: PublicKey
+  delegate: PublicKey
+  scope: BN
+  createdAt: BN
+  expiresAt: BN
+  authority: PublicKey
+  bump: number
+}
+
+export interface SessionDelegateJSON {
+  session: string
+  delegate: string
+  scope: string
+  createdAt: string
+  expiresAt: string
+  authority: string
+  bump: number
+}
+
+export class SessionDelegate {
+  readonly session: PublicKey
+  readonly delegate: PublicKey
+  readonly scope: BN
+  readonly createdAt: BN
+  readonly expiresAt: BN
+  readonly authority: PublicKey
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    236, 108, 128, 200, 59, 199, 240, 74,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("session"),
+    borsh.publicKey("delegate"),
+    borsh.u64("scope"),
+    borsh.i64("createdAt"),
+    borsh.i64("expiresAt"),
+    borsh.publicKey("authority"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: SessionDelegateFields) {
+    this.session = fields.session
+    this.delegate = fields.delegate
+    this.scope = fields.scope
+    this.createdAt = fields.createdAt
+    this.expiresAt = fields.expiresAt
+    this.authority = fields.authority
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<SessionDelegate | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<SessionDelegate | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): SessionDelegate {
+    if (!data.slice(0, 8).equals(SessionDelegate.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = SessionDelegate.layout.decode(data.slice(8))
+
+    return new SessionDelegate({
+      session: dec.session,
+      delegate: dec.delegate,
+      scope: dec.scope,
+      createdAt: dec.createdAt,
+      expiresAt: dec.expiresAt,
+      authority: dec.authority,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): SessionDelegateJSON {
+    return {
+      session: this.session.toString(),
+      delegate: this.delegate.toString(),
+      scope: this.scope.toString(),
+      createdAt: this.createdAt.toString(),
+      expiresAt: this.expiresAt.toString(),
+      authority: this.authority.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: SessionDelegateJSON): SessionDelegate {
+    return new SessionDelegate({
+      session: new PublicKey(obj.session),
+      delegate: new PublicKey(obj.delegate),
+      scope: new BN(obj.scope),
+      createdAt: new BN(obj.createdAt),
+      expiresAt: new BN(obj.expiresAt),
+      authority: new PublicKey(obj.authority),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,137 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface UsernameFields {
+  name: Array<number>
+  nullifier: Array<number>
+  domain: Array<number>
+  authority: PublicKey
+  claimedAt: BN
+  transferredAt: BN
+  bump: number
+}
+
+export interface UsernameJSON {
+  name: Array<number>
+  nullifier: Array<number>
+  domain: Array<number>
+  authority: string
+  claimedAt: string
+  transferredAt: string
+  bump: number
+}
+
+export class Username {
+  readonly name: Array<number>
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly authority: PublicKey
+  readonly claimedAt: BN
+  readonly transferredAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    149, 84, 41, 80, 177, 203, 232, 168,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "name"),
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    borsh.array(borsh.u8(), 32, "domain"),
+    borsh.publicKey("authority"),
+    borsh.i64("claimedAt"),
+    borsh.i64("transferredAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: UsernameFields) {
+    this.name = fields.name
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.authority = fields.authority
+    this.claimedAt = fields.claimedAt
+    this.transferredAt = fields.transferredAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Username | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<Username | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): Username {
+    if (!data.slice(0, 8).equals(Username.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = Username.layout.decode(data.slice(8))
+
+    return new Username({
+      name: dec.name,
+      nullifier: dec.nullifier,
+      domain: dec.domain,
+      authority: dec.authority,
+      claimedAt: dec.claimedAt,
+      transferredAt: dec.transferredAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): UsernameJSON {
+    return {
+      name: this.name,
+      nullifier: this.nullifier,
+      domain: this.domain,
+      authority: this.authority.toString(),
+      claimedAt: this.claimedAt.toString(),
+      transferredAt: this.transferredAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: UsernameJSON): Username {
+    return new Username({
+      name: obj.name,
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      authority: new PublicKey(obj.authority),
+      claimedAt: new BN(obj.claimedAt),
+      transferredAt: new BN(obj.transferredAt),
+      bump: obj.bump,
+    })
+  }
+}
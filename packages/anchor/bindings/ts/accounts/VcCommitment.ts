@@ -0,0 +1,137 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface VcCommitmentFields {
+  nullifier: Array<number>
+  issuer: PublicKey
+  vcHash: Array<number>
+  status: types.VcStatusKind
+  issuedAt: BN
+  updatedAt: BN
+  bump: number
+}
+
+export interface VcCommitmentJSON {
+  nullifier: Array<number>
+  issuer: string
+  vcHash: Array<number>
+  status: types.VcStatusJSON
+  issuedAt: string
+  updatedAt: string
+  bump: number
+}
+
+export class VcCommitment {
+  readonly nullifier: Array<number>
+  readonly issuer: PublicKey
+  readonly vcHash: Array<number>
+  readonly status: types.VcStatusKind
+  readonly issuedAt: BN
+  readonly updatedAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    148, 122, 22, 134, 45, 135, 238, 229,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    borsh.publicKey("issuer"),
+    borsh.array(borsh.u8(), 32, "vcHash"),
+    types.VcStatus.layout("status"),
+    borsh.i64("issuedAt"),
+    borsh.i64("updatedAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: VcCommitmentFields) {
+    this.nullifier = fields.nullifier
+    this.issuer = fields.issuer
+    this.vcHash = fields.vcHash
+    this.status = fields.status
+    this.issuedAt = fields.issuedAt
+    this.updatedAt = fields.updatedAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<VcCommitment | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<VcCommitment | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): VcCommitment {
+    if (!data.slice(0, 8).equals(VcCommitment.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = VcCommitment.layout.decode(data.slice(8))
+
+    return new VcCommitment({
+      nullifier: dec.nullifier,
+      issuer: dec.issuer,
+      vcHash: dec.vcHash,
+      status: types.VcStatus.fromDecoded(dec.status),
+      issuedAt: dec.issuedAt,
+      updatedAt: dec.updatedAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): VcCommitmentJSON {
+    return {
+      nullifier: this.nullifier,
+      issuer: this.issuer.toString(),
+      vcHash: this.vcHash,
+      status: this.status.toJSON(),
+      issuedAt: this.issuedAt.toString(),
+      updatedAt: this.updatedAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: VcCommitmentJSON): VcCommitment {
+    return new VcCommitment({
+      nullifier: obj.nullifier,
+      issuer: new PublicKey(obj.issuer),
+      vcHash: obj.vcHash,
+      status: types.VcStatus.fromJSON(obj.status),
+      issuedAt: new BN(obj.issuedAt),
+      updatedAt: new BN(obj.updatedAt),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,177 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface VerificationSessionFields {
+  authority: PublicKey
+  nullifier: Array<number>
+  domain: Array<number>
+  circuitId: number
+  proofHash: Array<number>
+  proofLen: number
+  publicInputsCount: number
+  bytesReceived: number
+  status: types.VerificationStatusKind
+  step: number
+  openedAt: BN
+  bump: number
+}
+
+export interface VerificationSessionJSON {
+  authority: string
+  nullifier: Array<number>
+  domain: Array<number>
+  circuitId: number
+  proofHash: Array<number>
+  proofLen: number
+  publicInputsCount: number
+  bytesReceived: number
+  status: types.VerificationStatusJSON
+  step: number
+  openedAt: string
+  bump: number
+}
+
+export class VerificationSession {
+  readonly authority: PublicKey
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly circuitId: number
+  readonly proofHash: Array<number>
+  readonly proofLen: number
+  readonly publicInputsCount: number
+  readonly bytesReceived: number
+  readonly status: types.VerificationStatusKind
+  readonly step: number
+  readonly openedAt: BN
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    247, 69, 199, 26, 112, 195, 243, 254,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.publicKey("authority"),
+    borsh.array(borsh.u8(), 32, "nullifier"),
+    borsh.array(borsh.u8(), 32, "domain"),
+    borsh.u32("circuitId"),
+    borsh.array(borsh.u8(), 32, "proofHash"),
+    borsh.u32("proofLen"),
+    borsh.u16("publicInputsCount"),
+    borsh.u32("bytesReceived"),
+    types.VerificationStatus.layout("status"),
+    borsh.u32("step"),
+    borsh.i64("openedAt"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: VerificationSessionFields) {
+    this.authority = fields.authority
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.circuitId = fields.circuitId
+    this.proofHash = fields.proofHash
+    this.proofLen = fields.proofLen
+    this.publicInputsCount = fields.publicInputsCount
+    this.bytesReceived = fields.bytesReceived
+    this.status = fields.status
+    this.step = fields.step
+    this.openedAt = fields.openedAt
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<VerificationSession | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<VerificationSession | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): VerificationSession {
+    if (!data.slice(0, 8).equals(VerificationSession.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = VerificationSession.layout.decode(data.slice(8))
+
+    return new VerificationSession({
+      authority: dec.authority,
+      nullifier: dec.nullifier,
+      domain: dec.domain,
+      circuitId: dec.circuitId,
+      proofHash: dec.proofHash,
+      proofLen: dec.proofLen,
+      publicInputsCount: dec.publicInputsCount,
+      bytesReceived: dec.bytesReceived,
+      status: types.VerificationStatus.fromDecoded(dec.status),
+      step: dec.step,
+      openedAt: dec.openedAt,
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): VerificationSessionJSON {
+    return {
+      authority: this.authority.toString(),
+      nullifier: this.nullifier,
+      domain: this.domain,
+      circuitId: this.circuitId,
+      proofHash: this.proofHash,
+      proofLen: this.proofLen,
+      publicInputsCount: this.publicInputsCount,
+      bytesReceived: this.bytesReceived,
+      status: this.status.toJSON(),
+      step: this.step,
+      openedAt: this.openedAt.toString(),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: VerificationSessionJSON): VerificationSession {
+    return new VerificationSession({
+      authority: new PublicKey(obj.authority),
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      circuitId: obj.circuitId,
+      proofHash: obj.proofHash,
+      proofLen: obj.proofLen,
+      publicInputsCount: obj.publicInputsCount,
+      bytesReceived: obj.bytesReceived,
+      status: types.VerificationStatus.fromJSON(obj.status),
+      step: obj.step,
+      openedAt: new BN(obj.openedAt),
+      bump: obj.bump,
+    })
+  }
+}
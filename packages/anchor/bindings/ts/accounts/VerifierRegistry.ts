@@ -0,0 +1,133 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface VerifierRegistryFields {
+  verifiers: Array<PublicKey>
+  threshold: number
+  bump: number
+  rotations: Array<types.VerifierRotation>
+  gracePeriodSecs: BN
+  receiptRetentionSecs: BN
+}
+
+export interface VerifierRegistryJSON {
+  verifiers: Array<string>
+  threshold: number
+  bump: number
+  rotations: Array<types.VerifierRotationJSON>
+  gracePeriodSecs: string
+  receiptRetentionSecs: string
+}
+
+export class VerifierRegistry {
+  readonly verifiers: Array<PublicKey>
+  readonly threshold: number
+  readonly bump: number
+  readonly rotations: Array<types.VerifierRotation>
+  readonly gracePeriodSecs: BN
+  readonly receiptRetentionSecs: BN
+
+  static readonly discriminator = Buffer.from([
+    21, 219, 168, 135, 51, 182, 88, 129,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.vec(borsh.publicKey(), "verifiers"),
+    borsh.u8("threshold"),
+    borsh.u8("bump"),
+    borsh.vec(types.VerifierRotation.layout(), "rotations"),
+    borsh.i64("gracePeriodSecs"),
+    borsh.i64("receiptRetentionSecs"),
+  ])
+
+  constructor(fields: VerifierRegistryFields) {
+    this.verifiers = fields.verifiers
+    this.threshold = fields.threshold
+    this.bump = fields.bump
+    this.rotations = fields.rotations
+    this.gracePeriodSecs = fields.gracePeriodSecs
+    this.receiptRetentionSecs = fields.receiptRetentionSecs
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<VerifierRegistry | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<VerifierRegistry | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): VerifierRegistry {
+    if (!data.slice(0, 8).equals(VerifierRegistry.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = VerifierRegistry.layout.decode(data.slice(8))
+
+    return new VerifierRegistry({
+      verifiers: dec.verifiers,
+      threshold: dec.threshold,
+      bump: dec.bump,
+      rotations: dec.rotations.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.VerifierRotation.fromDecoded(item)
+      ),
+      gracePeriodSecs: dec.gracePeriodSecs,
+      receiptRetentionSecs: dec.receiptRetentionSecs,
+    })
+  }
+
+  toJSON(): VerifierRegistryJSON {
+    return {
+      verifiers: this.verifiers.map((item) => item.toString()),
+      threshold: this.threshold,
+      bump: this.bump,
+      rotations: this.rotations.map((item) => item.toJSON()),
+      gracePeriodSecs: this.gracePeriodSecs.toString(),
+      receiptRetentionSecs: this.receiptRetentionSecs.toString(),
+    }
+  }
+
+  static fromJSON(obj: VerifierRegistryJSON): VerifierRegistry {
+    return new VerifierRegistry({
+      verifiers: obj.verifiers.map((item) => new PublicKey(item)),
+      threshold: obj.threshold,
+      bump: obj.bump,
+      rotations: obj.rotations.map((item) => types.VerifierRotation.fromJSON(item)),
+      gracePeriodSecs: new BN(obj.gracePeriodSecs),
+      receiptRetentionSecs: new BN(obj.receiptRetentionSecs),
+    })
+  }
+}
@@ -0,0 +1,101 @@
+import { PublicKey, Connection } from "@solana/web3.js"
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface VerifierWeightsFields {
+  entries: Array<types.VerifierWeight>
+  bump: number
+}
+
+export interface VerifierWeightsJSON {
+  entries: Array<types.VerifierWeightJSON>
+  bump: number
+}
+
+export class VerifierWeights {
+  readonly entries: Array<types.VerifierWeight>
+  readonly bump: number
+
+  static readonly discriminator = Buffer.from([
+    193, 184, 76, 142, 15, 144, 58, 194,
+  ])
+
+  static readonly layout = borsh.struct([
+    borsh.vec(types.VerifierWeight.layout(), "entries"),
+    borsh.u8("bump"),
+  ])
+
+  constructor(fields: VerifierWeightsFields) {
+    this.entries = fields.entries
+    this.bump = fields.bump
+  }
+
+  static async fetch(
+    c: Connection,
+    address: PublicKey,
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<VerifierWeights | null> {
+    const info = await c.getAccountInfo(address)
+
+    if (info === null) {
+      return null
+    }
+    if (!info.owner.equals(programId)) {
+      throw new Error("account doesn't belong to this program")
+    }
+
+    return this.decode(info.data)
+  }
+
+  static async fetchMultiple(
+    c: Connection,
+    addresses: PublicKey[],
+    programId: PublicKey = PROGRAM_ID
+  ): Promise<Array<VerifierWeights | null>> {
+    const infos = await c.getMultipleAccountsInfo(addresses)
+
+    return infos.map((info) => {
+      if (info === null) {
+        return null
+      }
+      if (!info.owner.equals(programId)) {
+        throw new Error("account doesn't belong to this program")
+      }
+
+      return this.decode(info.data)
+    })
+  }
+
+  static decode(data: Buffer): VerifierWeights {
+    if (!data.slice(0, 8).equals(VerifierWeights.discriminator)) {
+      throw new Error("invalid account discriminator")
+    }
+
+    const dec = VerifierWeights.layout.decode(data.slice(8))
+
+    return new VerifierWeights({
+      entries: dec.entries.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.VerifierWeight.fromDecoded(item)
+      ),
+      bump: dec.bump,
+    })
+  }
+
+  toJSON(): VerifierWeightsJSON {
+    return {
+      entries: this.entries.map((item) => item.toJSON()),
+      bump: this.bump,
+    }
+  }
+
+  static fromJSON(obj: VerifierWeightsJSON): VerifierWeights {
+    return new VerifierWeights({
+      entries: obj.entries.map((item) => types.VerifierWeight.fromJSON(item)),
+      bump: obj.bump,
+    })
+  }
+}
@@ -0,0 +1,86 @@
+export { AccessLogSummary } from "./AccessLogSummary"
+export type { AccessLogSummaryFields, AccessLogSummaryJSON } from "./AccessLogSummary"
+export { Alias } from "./Alias"
+export type { AliasFields, AliasJSON } from "./Alias"
+export { ArchivedPersonhoodAttestation } from "./ArchivedPersonhoodAttestation"
+export type { ArchivedPersonhoodAttestationFields, ArchivedPersonhoodAttestationJSON } from "./ArchivedPersonhoodAttestation"
+export { AssurancePolicy } from "./AssurancePolicy"
+export type { AssurancePolicyFields, AssurancePolicyJSON } from "./AssurancePolicy"
+export { Attestation } from "./Attestation"
+export type { AttestationFields, AttestationJSON } from "./Attestation"
+export { Attestor } from "./Attestor"
+export type { AttestorFields, AttestorJSON } from "./Attestor"
+export { AuditorGrant } from "./AuditorGrant"
+export type { AuditorGrantFields, AuditorGrantJSON } from "./AuditorGrant"
+export { CircuitRegistry } from "./CircuitRegistry"
+export type { CircuitRegistryFields, CircuitRegistryJSON } from "./CircuitRegistry"
+export { DomainConfig } from "./DomainConfig"
+export type { DomainConfigFields, DomainConfigJSON } from "./DomainConfig"
+export { ExpiryHistogram } from "./ExpiryHistogram"
+export type { ExpiryHistogramFields, ExpiryHistogramJSON } from "./ExpiryHistogram"
+export { FeeSharePolicy } from "./FeeSharePolicy"
+export type { FeeSharePolicyFields, FeeSharePolicyJSON } from "./FeeSharePolicy"
+export { Groth16VerifyingKey } from "./Groth16VerifyingKey"
+export type { Groth16VerifyingKeyFields, Groth16VerifyingKeyJSON } from "./Groth16VerifyingKey"
+export { GuardianSet } from "./GuardianSet"
+export type { GuardianSetFields, GuardianSetJSON } from "./GuardianSet"
+export { HonkVerifyingKey } from "./HonkVerifyingKey"
+export type { HonkVerifyingKeyFields, HonkVerifyingKeyJSON } from "./HonkVerifyingKey"
+export { IdentityRoot } from "./IdentityRoot"
+export type { IdentityRootFields, IdentityRootJSON } from "./IdentityRoot"
+export { LogoutEpoch } from "./LogoutEpoch"
+export type { LogoutEpochFields, LogoutEpochJSON } from "./LogoutEpoch"
+export { MigrationState } from "./MigrationState"
+export type { MigrationStateFields, MigrationStateJSON } from "./MigrationState"
+export { NotificationChannel } from "./NotificationChannel"
+export type { NotificationChannelFields, NotificationChannelJSON } from "./NotificationChannel"
+export { NullifierAccount } from "./NullifierAccount"
+export type { NullifierAccountFields, NullifierAccountJSON } from "./NullifierAccount"
+export { NullifierBan } from "./NullifierBan"
+export type { NullifierBanFields, NullifierBanJSON } from "./NullifierBan"
+export { NullifierLink } from "./NullifierLink"
+export type { NullifierLinkFields, NullifierLinkJSON } from "./NullifierLink"
+export { NullifierShard } from "./NullifierShard"
+export type { NullifierShardFields, NullifierShardJSON } from "./NullifierShard"
+export { NullifierTree } from "./NullifierTree"
+export type { NullifierTreeFields, NullifierTreeJSON } from "./NullifierTree"
+export { PayerRateLimit } from "./PayerRateLimit"
+export type { PayerRateLimitFields, PayerRateLimitJSON } from "./PayerRateLimit"
+export { PermissionAccess } from "./PermissionAccess"
+export type { PermissionAccessFields, PermissionAccessJSON } from "./PermissionAccess"
+export { PermissionGrant } from "./PermissionGrant"
+export type { PermissionGrantFields, PermissionGrantJSON } from "./PermissionGrant"
+export { PersonhoodAttestation } from "./PersonhoodAttestation"
+export type { PersonhoodAttestationFields, PersonhoodAttestationJSON } from "./PersonhoodAttestation"
+export { PersonhoodProviderRegistry } from "./PersonhoodProviderRegistry"
+export type { PersonhoodProviderRegistryFields, PersonhoodProviderRegistryJSON } from "./PersonhoodProviderRegistry"
+export { Points } from "./Points"
+export type { PointsFields, PointsJSON } from "./Points"
+export { PointsRoot } from "./PointsRoot"
+export type { PointsRootFields, PointsRootJSON } from "./PointsRoot"
+export { ProofRecord } from "./ProofRecord"
+export type { ProofRecordFields, ProofRecordJSON } from "./ProofRecord"
+export { ProtocolConfig } from "./ProtocolConfig"
+export type { ProtocolConfigFields, ProtocolConfigJSON } from "./ProtocolConfig"
+export { ProtocolStats } from "./ProtocolStats"
+export type { ProtocolStatsFields, ProtocolStatsJSON } from "./ProtocolStats"
+export { ResultReceipt } from "./ResultReceipt"
+export type { ResultReceiptFields, ResultReceiptJSON } from "./ResultReceipt"
+export { RevealEscrow } from "./RevealEscrow"
+export type { RevealEscrowFields, RevealEscrowJSON } from "./RevealEscrow"
+export { SasBridge } from "./SasBridge"
+export type { SasBridgeFields, SasBridgeJSON } from "./SasBridge"
+export { Secp256r1VerifierRegistry } from "./Secp256r1VerifierRegistry"
+export type { Secp256r1VerifierRegistryFields, Secp256r1VerifierRegistryJSON } from "./Secp256r1VerifierRegistry"
+export { SessionDelegate } from "./SessionDelegate"
+export type { SessionDelegateFields, SessionDelegateJSON } from "./SessionDelegate"
+export { Username } from "./Username"
+export type { UsernameFields, UsernameJSON } from "./Username"
+export { VcCommitment } from "./VcCommitment"
+export type { VcCommitmentFields, VcCommitmentJSON } from "./VcCommitment"
+export { VerificationSession } from "./VerificationSession"
+export type { VerificationSessionFields, VerificationSessionJSON } from "./VerificationSession"
+export { VerifierRegistry } from "./VerifierRegistry"
+export type { VerifierRegistryFields, VerifierRegistryJSON } from "./VerifierRegistry"
+export { VerifierWeights } from "./VerifierWeights"
+export type { VerifierWeightsFields, VerifierWeightsJSON } from "./VerifierWeights"
@@ -0,0 +1,2374 @@
+export type CustomError =
+  | InvalidProof
+  | DuplicateNullifier
+  | ProofExpired
+  | DomainTooLong
+  | SandboxUnavailable
+  | InvalidPublicInputs
+  | OffsetMismatch
+  | InvalidInstructionData
+  | InvalidSignatureCount
+  | InvalidMessageSize
+  | ProofHashMismatch
+  | IsValidMismatch
+  | AuthorityMismatch
+  | NullifierMismatch
+  | DomainMismatch
+  | UnsupportedResultVersion
+  | PublicInputsMismatch
+  | CircuitMismatch
+  | VkHashMismatch
+  | ClusterMismatch
+  | InstanceMismatch
+  | ProofAlreadyUsed
+  | InvalidVerificationBatch
+  | InvalidResultLayout
+  | BadEd25519Program
+  | BadEd25519Accounts
+  | PermissionRevoked
+  | PermissionExpired
+  | PermissionNotGranted
+  | UnauthorizedRevocation
+  | TooManyPermissions
+  | InvalidExpiry
+  | InvalidNotificationToken
+  | NotChannelAuthority
+  | InvalidRevealDelay
+  | InvalidRevealCiphertext
+  | NotEscrowAuthority
+  | RevealNotReady
+  | RevealKeyMismatch
+  | RevealAlreadyReleased
+  | RevealWindowClosed
+  | NotVcIssuer
+  | VcRevoked
+  | AuditorRevoked
+  | AuditorExpired
+  | AccessLogMismatch
+  | InvalidCompactionBatch
+  | AccessLogTooRecent
+  | AccessLogPayerMismatch
+  | Unauthorized
+  | MintNotAccepted
+  | MintAlreadyAccepted
+  | TooManyAcceptedMints
+  | CircuitNotRegistered
+  | CircuitDeprecated
+  | CircuitAlreadyRegistered
+  | TooManyCircuits
+  | InvalidGroth16Vk
+  | InvalidGroth16Proof
+  | UnregisteredVerifier
+  | VerifierAlreadyRegistered
+  | VerifierNotRegistered
+  | TooManyVerifiers
+  | InvalidVerifierThreshold
+  | QuorumNotMet
+  | TooManyEd25519Instructions
+  | TooManySecp256r1Instructions
+  | BadSecp256r1Accounts
+  | InvalidSecp256r1Key
+  | TooManyRotations
+  | InvalidGracePeriod
+  | InvalidDomain
+  | NotDomainOwner
+  | VkHashRequired
+  | InvalidChallengeWindow
+  | InvalidRateLimit
+  | NotDomainOperator
+  | NotDomainTreasurer
+  | InvalidDomainRoleKey
+  | DomainPaused
+  | TooManyOrigins
+  | OriginNotAllowed
+  | ChallengeWindowClosed
+  | InvalidChallenge
+  | FeeNotConfigured
+  | OracleFeedMismatch
+  | InvalidOraclePrice
+  | OraclePriceStale
+  | OraclePriceUncertain
+  | PriceQuoteOverflow
+  | FeeExceedsMaxAmount
+  | InvalidFeeShare
+  | SessionExpired
+  | SessionRevoked
+  | SessionPending
+  | RateLimited
+  | InvalidAssuranceTier
+  | InvalidResultAge
+  | TimestampInFuture
+  | InvalidPointsRoot
+  | InvalidPointsProof
+  | PersonhoodProviderAlreadyRegistered
+  | PersonhoodProviderNotRegistered
+  | TooManyPersonhoodProviders
+  | InvalidPersonhoodScore
+  | StalePersonhoodAttestation
+  | MissingPersonhoodSignature
+  | AttestationPreimageMismatch
+  | MigrationInProgress
+  | NoMigrationInProgress
+  | InvalidMigrationPlan
+  | MigrationIncomplete
+  | UnknownMigrationStep
+  | UnsupportedAccountVersion
+  | InvalidVerifierWeight
+  | VerifierNotWeighted
+  | NoVerifierAvailable
+  | MissingUserConsent
+  | UserConsentExpired
+  | InvalidSessionBounds
+  | SessionExpiryOutOfBounds
+  | ResultLengthMismatch
+  | NonCanonicalBool
+  | SignatureAlreadyUsed
+  | InvalidReceiptRetention
+  | ReceiptRetained
+  | UnknownClaimType
+  | ClaimTypeMismatch
+  | ClaimNotSession
+  | ClaimNotAttestation
+  | AttestationExpiryOutOfBounds
+  | AttestationActive
+  | AttestationExpired
+  | AttestationClaimMismatch
+  | AttestationRevoked
+  | NotAttestationIssuer
+  | InvalidClaimMask
+  | UntrustedAttestor
+  | AttestorSuspended
+  | SasBridgeMismatch
+  | MessageTagMismatch
+  | UntaggedResult
+  | SessionNotExpired
+  | MissingSessionCloseSignature
+  | SessionIdle
+  | InvalidIdleTimeout
+  | MissingDeviceSignature
+  | InvalidDelegateScope
+  | InvalidDelegateExpiry
+  | DelegateNotAllowed
+  | WrongNullifierStore
+  | InvalidNonInclusionProof
+  | InvalidUsername
+  | MissingUsernameTransferSignature
+  | InvalidGuardianSet
+  | NotEnoughGuardianSignatures
+  | InvalidRecoveryAccounts
+  | IdentityRootFull
+  | NullifierAlreadyLinked
+  | NullifierNotLinked
+  | MissingIdentityLinkSignature
+  | NullifierBanned
+  | InvalidSnsNameAccount
+  | NotSnsNameOwner
+  | ReentrancyDetected
+  | InvalidHonkVk
+  | InvalidProofLength
+  | ProofChunkOutOfOrder
+  | InvalidSessionStatus
+
+export class InvalidProof extends Error {
+  static readonly code = 6000
+  readonly code = 6000
+  readonly name = "InvalidProof"
+  readonly msg = "Invalid proof"
+
+  constructor(readonly logs?: string[]) {
+    super("6000: Invalid proof")
+  }
+}
+
+export class DuplicateNullifier extends Error {
+  static readonly code = 6001
+  readonly code = 6001
+  readonly name = "DuplicateNullifier"
+  readonly msg = "Nullifier already used"
+
+  constructor(readonly logs?: string[]) {
+    super("6001: Nullifier already used")
+  }
+}
+
+export class ProofExpired extends Error {
+  static readonly code = 6002
+  readonly code = 6002
+  readonly name = "ProofExpired"
+  readonly msg = "Proof expired"
+
+  constructor(readonly logs?: string[]) {
+    super("6002: Proof expired")
+  }
+}
+
+export class DomainTooLong extends Error {
+  static readonly code = 6003
+  readonly code = 6003
+  readonly name = "DomainTooLong"
+  readonly msg = "Domain string exceeds maximum length of 255 characters"
+
+  constructor(readonly logs?: string[]) {
+    super("6003: Domain string exceeds maximum length of 255 characters")
+  }
+}
+
+export class SandboxUnavailable extends Error {
+  static readonly code = 6004
+  readonly code = 6004
+  readonly name = "SandboxUnavailable"
+  readonly msg = "The sandbox domain is only available on devnet builds"
+
+  constructor(readonly logs?: string[]) {
+    super("6004: The sandbox domain is only available on devnet builds")
+  }
+}
+
+export class InvalidPublicInputs extends Error {
+  static readonly code = 6005
+  readonly code = 6005
+  readonly name = "InvalidPublicInputs"
+  readonly msg = "Invalid public inputs"
+
+  constructor(readonly logs?: string[]) {
+    super("6005: Invalid public inputs")
+  }
+}
+
+export class OffsetMismatch extends Error {
+  static readonly code = 6006
+  readonly code = 6006
+  readonly name = "OffsetMismatch"
+  readonly msg = "Offset mismatch - points to wrong instruction"
+
+  constructor(readonly logs?: string[]) {
+    super("6006: Offset mismatch - points to wrong instruction")
+  }
+}
+
+export class InvalidInstructionData extends Error {
+  static readonly code = 6007
+  readonly code = 6007
+  readonly name = "InvalidInstructionData"
+  readonly msg = "Invalid instruction data"
+
+  constructor(readonly logs?: string[]) {
+    super("6007: Invalid instruction data")
+  }
+}
+
+export class InvalidSignatureCount extends Error {
+  static readonly code = 6008
+  readonly code = 6008
+  readonly name = "InvalidSignatureCount"
+  readonly msg = "Invalid signature count"
+
+  constructor(readonly logs?: string[]) {
+    super("6008: Invalid signature count")
+  }
+}
+
+export class InvalidMessageSize extends Error {
+  static readonly code = 6009
+  readonly code = 6009
+  readonly name = "InvalidMessageSize"
+  readonly msg = "Invalid message size"
+
+  constructor(readonly logs?: string[]) {
+    super("6009: Invalid message size")
+  }
+}
+
+export class ProofHashMismatch extends Error {
+  static readonly code = 6010
+  readonly code = 6010
+  readonly name = "ProofHashMismatch"
+  readonly msg = "Proof hash mismatch"
+
+  constructor(readonly logs?: string[]) {
+    super("6010: Proof hash mismatch")
+  }
+}
+
+export class IsValidMismatch extends Error {
+  static readonly code = 6011
+  readonly code = 6011
+  readonly name = "IsValidMismatch"
+  readonly msg = "Is valid mismatch"
+
+  constructor(readonly logs?: string[]) {
+    super("6011: Is valid mismatch")
+  }
+}
+
+export class AuthorityMismatch extends Error {
+  static readonly code = 6012
+  readonly code = 6012
+  readonly name = "AuthorityMismatch"
+  readonly msg = "Authority public key mismatch"
+
+  constructor(readonly logs?: string[]) {
+    super("6012: Authority public key mismatch")
+  }
+}
+
+export class NullifierMismatch extends Error {
+  static readonly code = 6013
+  readonly code = 6013
+  readonly name = "NullifierMismatch"
+  readonly msg = "Nullifier mismatch - result was signed for a different nullifier"
+
+  constructor(readonly logs?: string[]) {
+    super("6013: Nullifier mismatch - result was signed for a different nullifier")
+  }
+}
+
+export class DomainMismatch extends Error {
+  static readonly code = 6014
+  readonly code = 6014
+  readonly name = "DomainMismatch"
+  readonly msg = "Domain mismatch - result was signed for a different domain"
+
+  constructor(readonly logs?: string[]) {
+    super("6014: Domain mismatch - result was signed for a different domain")
+  }
+}
+
+export class UnsupportedResultVersion extends Error {
+  static readonly code = 6015
+  readonly code = 6015
+  readonly name = "UnsupportedResultVersion"
+  readonly msg = "Unsupported verification result version"
+
+  constructor(readonly logs?: string[]) {
+    super("6015: Unsupported verification result version")
+  }
+}
+
+export class PublicInputsMismatch extends Error {
+  static readonly code = 6016
+  readonly code = 6016
+  readonly name = "PublicInputsMismatch"
+  readonly msg = "Public inputs hash mismatch - result was signed for different public inputs"
+
+  constructor(readonly logs?: string[]) {
+    super("6016: Public inputs hash mismatch - result was signed for different public inputs")
+  }
+}
+
+export class CircuitMismatch extends Error {
+  static readonly code = 6017
+  readonly code = 6017
+  readonly name = "CircuitMismatch"
+  readonly msg = "Circuit mismatch - result was signed for a different circuit"
+
+  constructor(readonly logs?: string[]) {
+    super("6017: Circuit mismatch - result was signed for a different circuit")
+  }
+}
+
+export class VkHashMismatch extends Error {
+  static readonly code = 6018
+  readonly code = 6018
+  readonly name = "VkHashMismatch"
+  readonly msg = "Verification key hash mismatch"
+
+  constructor(readonly logs?: string[]) {
+    super("6018: Verification key hash mismatch")
+  }
+}
+
+export class ClusterMismatch extends Error {
+  static readonly code = 6019
+  readonly code = 6019
+  readonly name = "ClusterMismatch"
+  readonly msg = "Verification result was signed for a different cluster"
+
+  constructor(readonly logs?: string[]) {
+    super("6019: Verification result was signed for a different cluster")
+  }
+}
+
+export class InstanceMismatch extends Error {
+  static readonly code = 6020
+  readonly code = 6020
+  readonly name = "InstanceMismatch"
+  readonly msg = "Verification result was signed for a different Veiled instance"
+
+  constructor(readonly logs?: string[]) {
+    super("6020: Verification result was signed for a different Veiled instance")
+  }
+}
+
+export class ProofAlreadyUsed extends Error {
+  static readonly code = 6021
+  readonly code = 6021
+  readonly name = "ProofAlreadyUsed"
+  readonly msg = "Proof has already been used to register a nullifier"
+
+  constructor(readonly logs?: string[]) {
+    super("6021: Proof has already been used to register a nullifier")
+  }
+}
+
+export class InvalidVerificationBatch extends Error {
+  static readonly code = 6022
+  readonly code = 6022
+  readonly name = "InvalidVerificationBatch"
+  readonly msg = "Verification batch must be 1-4 results, each with its nullifier, proof record, domain config and result receipt accounts"
+
+  constructor(readonly logs?: string[]) {
+    super("6022: Verification batch must be 1-4 results, each with its nullifier, proof record, domain config and result receipt accounts")
+  }
+}
+
+export class InvalidResultLayout extends Error {
+  static readonly code = 6023
+  readonly code = 6023
+  readonly name = "InvalidResultLayout"
+  readonly msg = "Verification result fields must extend the message in order"
+
+  constructor(readonly logs?: string[]) {
+    super("6023: Verification result fields must extend the message in order")
+  }
+}
+
+export class BadEd25519Program extends Error {
+  static readonly code = 6024
+  readonly code = 6024
+  readonly name = "BadEd25519Program"
+  readonly msg = "Expected Ed25519 program"
+
+  constructor(readonly logs?: string[]) {
+    super("6024: Expected Ed25519 program")
+  }
+}
+
+export class BadEd25519Accounts extends Error {
+  static readonly code = 6025
+  readonly code = 6025
+  readonly name = "BadEd25519Accounts"
+  readonly msg = "Bad Ed25519 accounts"
+
+  constructor(readonly logs?: string[]) {
+    super("6025: Bad Ed25519 accounts")
+  }
+}
+
+export class PermissionRevoked extends Error {
+  static readonly code = 6026
+  readonly code = 6026
+  readonly name = "PermissionRevoked"
+  readonly msg = "Permission has been revoked"
+
+  constructor(readonly logs?: string[]) {
+    super("6026: Permission has been revoked")
+  }
+}
+
+export class PermissionExpired extends Error {
+  static readonly code = 6027
+  readonly code = 6027
+  readonly name = "PermissionExpired"
+  readonly msg = "Permission has expired"
+
+  constructor(readonly logs?: string[]) {
+    super("6027: Permission has expired")
+  }
+}
+
+export class PermissionNotGranted extends Error {
+  static readonly code = 6028
+  readonly code = 6028
+  readonly name = "PermissionNotGranted"
+  readonly msg = "Permission not granted"
+
+  constructor(readonly logs?: string[]) {
+    super("6028: Permission not granted")
+  }
+}
+
+export class UnauthorizedRevocation extends Error {
+  static readonly code = 6029
+  readonly code = 6029
+  readonly name = "UnauthorizedRevocation"
+  readonly msg = "Unauthorized to revoke this permission"
+
+  constructor(readonly logs?: string[]) {
+    super("6029: Unauthorized to revoke this permission")
+  }
+}
+
+export class TooManyPermissions extends Error {
+  static readonly code = 6030
+  readonly code = 6030
+  readonly name = "TooManyPermissions"
+  readonly msg = "Too many permissions requested"
+
+  constructor(readonly logs?: string[]) {
+    super("6030: Too many permissions requested")
+  }
+}
+
+export class InvalidExpiry extends Error {
+  static readonly code = 6031
+  readonly code = 6031
+  readonly name = "InvalidExpiry"
+  readonly msg = "Expiry duration must be positive and within range"
+
+  constructor(readonly logs?: string[]) {
+    super("6031: Expiry duration must be positive and within range")
+  }
+}
+
+export class InvalidNotificationToken extends Error {
+  static readonly code = 6032
+  readonly code = 6032
+  readonly name = "InvalidNotificationToken"
+  readonly msg = "Encrypted notification token must be 1-256 bytes"
+
+  constructor(readonly logs?: string[]) {
+    super("6032: Encrypted notification token must be 1-256 bytes")
+  }
+}
+
+export class NotChannelAuthority extends Error {
+  static readonly code = 6033
+  readonly code = 6033
+  readonly name = "NotChannelAuthority"
+  readonly msg = "Signer does not own this notification channel"
+
+  constructor(readonly logs?: string[]) {
+    super("6033: Signer does not own this notification channel")
+  }
+}
+
+export class InvalidRevealDelay extends Error {
+  static readonly code = 6034
+  readonly code = 6034
+  readonly name = "InvalidRevealDelay"
+  readonly msg = "Reveal delay must be positive and at most 90 days"
+
+  constructor(readonly logs?: string[]) {
+    super("6034: Reveal delay must be positive and at most 90 days")
+  }
+}
+
+export class InvalidRevealCiphertext extends Error {
+  static readonly code = 6035
+  readonly code = 6035
+  readonly name = "InvalidRevealCiphertext"
+  readonly msg = "Escrowed ciphertext must be 1-128 bytes"
+
+  constructor(readonly logs?: string[]) {
+    super("6035: Escrowed ciphertext must be 1-128 bytes")
+  }
+}
+
+export class NotEscrowAuthority extends Error {
+  static readonly code = 6036
+  readonly code = 6036
+  readonly name = "NotEscrowAuthority"
+  readonly msg = "Signer does not own this reveal escrow"
+
+  constructor(readonly logs?: string[]) {
+    super("6036: Signer does not own this reveal escrow")
+  }
+}
+
+export class RevealNotReady extends Error {
+  static readonly code = 6037
+  readonly code = 6037
+  readonly name = "RevealNotReady"
+  readonly msg = "Reveal delay has not passed yet"
+
+  constructor(readonly logs?: string[]) {
+    super("6037: Reveal delay has not passed yet")
+  }
+}
+
+export class RevealKeyMismatch extends Error {
+  static readonly code = 6038
+  readonly code = 6038
+  readonly name = "RevealKeyMismatch"
+  readonly msg = "Reveal key does not match the escrow's commitment"
+
+  constructor(readonly logs?: string[]) {
+    super("6038: Reveal key does not match the escrow's commitment")
+  }
+}
+
+export class RevealAlreadyReleased extends Error {
+  static readonly code = 6039
+  readonly code = 6039
+  readonly name = "RevealAlreadyReleased"
+  readonly msg = "Reveal escrow has already been released"
+
+  constructor(readonly logs?: string[]) {
+    super("6039: Reveal escrow has already been released")
+  }
+}
+
+export class RevealWindowClosed extends Error {
+  static readonly code = 6040
+  readonly code = 6040
+  readonly name = "RevealWindowClosed"
+  readonly msg = "Reveal delay is over - the escrow can no longer be cancelled"
+
+  constructor(readonly logs?: string[]) {
+    super("6040: Reveal delay is over - the escrow can no longer be cancelled")
+  }
+}
+
+export class NotVcIssuer extends Error {
+  static readonly code = 6041
+  readonly code = 6041
+  readonly name = "NotVcIssuer"
+  readonly msg = "Signer is not the credential's issuer"
+
+  constructor(readonly logs?: string[]) {
+    super("6041: Signer is not the credential's issuer")
+  }
+}
+
+export class VcRevoked extends Error {
+  static readonly code = 6042
+  readonly code = 6042
+  readonly name = "VcRevoked"
+  readonly msg = "Credential has been revoked; its status can no longer change"
+
+  constructor(readonly logs?: string[]) {
+    super("6042: Credential has been revoked; its status can no longer change")
+  }
+}
+
+export class AuditorRevoked extends Error {
+  static readonly code = 6043
+  readonly code = 6043
+  readonly name = "AuditorRevoked"
+  readonly msg = "Auditor grant has been revoked"
+
+  constructor(readonly logs?: string[]) {
+    super("6043: Auditor grant has been revoked")
+  }
+}
+
+export class AuditorExpired extends Error {
+  static readonly code = 6044
+  readonly code = 6044
+  readonly name = "AuditorExpired"
+  readonly msg = "Auditor grant has expired"
+
+  constructor(readonly logs?: string[]) {
+    super("6044: Auditor grant has expired")
+  }
+}
+
+export class AccessLogMismatch extends Error {
+  static readonly code = 6045
+  readonly code = 6045
+  readonly name = "AccessLogMismatch"
+  readonly msg = "Access log does not belong to this permission grant"
+
+  constructor(readonly logs?: string[]) {
+    super("6045: Access log does not belong to this permission grant")
+  }
+}
+
+export class InvalidCompactionBatch extends Error {
+  static readonly code = 6046
+  readonly code = 6046
+  readonly name = "InvalidCompactionBatch"
+  readonly msg = "Compaction batch must be 1-16 (access log, payer) pairs"
+
+  constructor(readonly logs?: string[]) {
+    super("6046: Compaction batch must be 1-16 (access log, payer) pairs")
+  }
+}
+
+export class AccessLogTooRecent extends Error {
+  static readonly code = 6047
+  readonly code = 6047
+  readonly name = "AccessLogTooRecent"
+  readonly msg = "Access log is too recent to compact"
+
+  constructor(readonly logs?: string[]) {
+    super("6047: Access log is too recent to compact")
+  }
+}
+
+export class AccessLogPayerMismatch extends Error {
+  static readonly code = 6048
+  readonly code = 6048
+  readonly name = "AccessLogPayerMismatch"
+  readonly msg = "Refund account does not match the access log payer"
+
+  constructor(readonly logs?: string[]) {
+    super("6048: Refund account does not match the access log payer")
+  }
+}
+
+export class Unauthorized extends Error {
+  static readonly code = 6049
+  readonly code = 6049
+  readonly name = "Unauthorized"
+  readonly msg = "Signer is not the protocol authority"
+
+  constructor(readonly logs?: string[]) {
+    super("6049: Signer is not the protocol authority")
+  }
+}
+
+export class MintNotAccepted extends Error {
+  static readonly code = 6050
+  readonly code = 6050
+  readonly name = "MintNotAccepted"
+  readonly msg = "Mint is not accepted for payments"
+
+  constructor(readonly logs?: string[]) {
+    super("6050: Mint is not accepted for payments")
+  }
+}
+
+export class MintAlreadyAccepted extends Error {
+  static readonly code = 6051
+  readonly code = 6051
+  readonly name = "MintAlreadyAccepted"
+  readonly msg = "Mint is already accepted"
+
+  constructor(readonly logs?: string[]) {
+    super("6051: Mint is already accepted")
+  }
+}
+
+export class TooManyAcceptedMints extends Error {
+  static readonly code = 6052
+  readonly code = 6052
+  readonly name = "TooManyAcceptedMints"
+  readonly msg = "Too many accepted mints"
+
+  constructor(readonly logs?: string[]) {
+    super("6052: Too many accepted mints")
+  }
+}
+
+export class CircuitNotRegistered extends Error {
+  static readonly code = 6053
+  readonly code = 6053
+  readonly name = "CircuitNotRegistered"
+  readonly msg = "Circuit is not registered"
+
+  constructor(readonly logs?: string[]) {
+    super("6053: Circuit is not registered")
+  }
+}
+
+export class CircuitDeprecated extends Error {
+  static readonly code = 6054
+  readonly code = 6054
+  readonly name = "CircuitDeprecated"
+  readonly msg = "Circuit has been deprecated"
+
+  constructor(readonly logs?: string[]) {
+    super("6054: Circuit has been deprecated")
+  }
+}
+
+export class CircuitAlreadyRegistered extends Error {
+  static readonly code = 6055
+  readonly code = 6055
+  readonly name = "CircuitAlreadyRegistered"
+  readonly msg = "Circuit ID is already registered"
+
+  constructor(readonly logs?: string[]) {
+    super("6055: Circuit ID is already registered")
+  }
+}
+
+export class TooManyCircuits extends Error {
+  static readonly code = 6056
+  readonly code = 6056
+  readonly name = "TooManyCircuits"
+  readonly msg = "Too many registered circuits"
+
+  constructor(readonly logs?: string[]) {
+    super("6056: Too many registered circuits")
+  }
+}
+
+export class InvalidGroth16Vk extends Error {
+  static readonly code = 6057
+  readonly code = 6057
+  readonly name = "InvalidGroth16Vk"
+  readonly msg = "Groth16 verifying key needs 2 to 4 public inputs and the circuit's vk_hash"
+
+  constructor(readonly logs?: string[]) {
+    super("6057: Groth16 verifying key needs 2 to 4 public inputs and the circuit's vk_hash")
+  }
+}
+
+export class InvalidGroth16Proof extends Error {
+  static readonly code = 6058
+  readonly code = 6058
+  readonly name = "InvalidGroth16Proof"
+  readonly msg = "Groth16 proof failed to verify"
+
+  constructor(readonly logs?: string[]) {
+    super("6058: Groth16 proof failed to verify")
+  }
+}
+
+export class UnregisteredVerifier extends Error {
+  static readonly code = 6059
+  readonly code = 6059
+  readonly name = "UnregisteredVerifier"
+  readonly msg = "Verification result was not signed by a registered verifier"
+
+  constructor(readonly logs?: string[]) {
+    super("6059: Verification result was not signed by a registered verifier")
+  }
+}
+
+export class VerifierAlreadyRegistered extends Error {
+  static readonly code = 6060
+  readonly code = 6060
+  readonly name = "VerifierAlreadyRegistered"
+  readonly msg = "Verifier is already registered"
+
+  constructor(readonly logs?: string[]) {
+    super("6060: Verifier is already registered")
+  }
+}
+
+export class VerifierNotRegistered extends Error {
+  static readonly code = 6061
+  readonly code = 6061
+  readonly name = "VerifierNotRegistered"
+  readonly msg = "Verifier is not registered"
+
+  constructor(readonly logs?: string[]) {
+    super("6061: Verifier is not registered")
+  }
+}
+
+export class TooManyVerifiers extends Error {
+  static readonly code = 6062
+  readonly code = 6062
+  readonly name = "TooManyVerifiers"
+  readonly msg = "Too many registered verifiers"
+
+  constructor(readonly logs?: string[]) {
+    super("6062: Too many registered verifiers")
+  }
+}
+
+export class InvalidVerifierThreshold extends Error {
+  static readonly code = 6063
+  readonly code = 6063
+  readonly name = "InvalidVerifierThreshold"
+  readonly msg = "Threshold must be between 1 and the number of registered verifiers"
+
+  constructor(readonly logs?: string[]) {
+    super("6063: Threshold must be between 1 and the number of registered verifiers")
+  }
+}
+
+export class QuorumNotMet extends Error {
+  static readonly code = 6064
+  readonly code = 6064
+  readonly name = "QuorumNotMet"
+  readonly msg = "Not enough registered verifiers co-signed the result"
+
+  constructor(readonly logs?: string[]) {
+    super("6064: Not enough registered verifiers co-signed the result")
+  }
+}
+
+export class TooManyEd25519Instructions extends Error {
+  static readonly code = 6065
+  readonly code = 6065
+  readonly name = "TooManyEd25519Instructions"
+  readonly msg = "Too many Ed25519 instructions in the transaction"
+
+  constructor(readonly logs?: string[]) {
+    super("6065: Too many Ed25519 instructions in the transaction")
+  }
+}
+
+export class TooManySecp256r1Instructions extends Error {
+  static readonly code = 6066
+  readonly code = 6066
+  readonly name = "TooManySecp256r1Instructions"
+  readonly msg = "Too many Secp256r1 instructions in the transaction"
+
+  constructor(readonly logs?: string[]) {
+    super("6066: Too many Secp256r1 instructions in the transaction")
+  }
+}
+
+export class BadSecp256r1Accounts extends Error {
+  static readonly code = 6067
+  readonly code = 6067
+  readonly name = "BadSecp256r1Accounts"
+  readonly msg = "Bad Secp256r1 accounts"
+
+  constructor(readonly logs?: string[]) {
+    super("6067: Bad Secp256r1 accounts")
+  }
+}
+
+export class InvalidSecp256r1Key extends Error {
+  static readonly code = 6068
+  readonly code = 6068
+  readonly name = "InvalidSecp256r1Key"
+  readonly msg = "Secp256r1 verifier key must be a 33-byte compressed P-256 point"
+
+  constructor(readonly logs?: string[]) {
+    super("6068: Secp256r1 verifier key must be a 33-byte compressed P-256 point")
+  }
+}
+
+export class TooManyRotations extends Error {
+  static readonly code = 6069
+  readonly code = 6069
+  readonly name = "TooManyRotations"
+  readonly msg = "Too many verifier key rotations inside the grace period"
+
+  constructor(readonly logs?: string[]) {
+    super("6069: Too many verifier key rotations inside the grace period")
+  }
+}
+
+export class InvalidGracePeriod extends Error {
+  static readonly code = 6070
+  readonly code = 6070
+  readonly name = "InvalidGracePeriod"
+  readonly msg = "Grace period must be between 0 and 24 hours"
+
+  constructor(readonly logs?: string[]) {
+    super("6070: Grace period must be between 0 and 24 hours")
+  }
+}
+
+export class InvalidDomain extends Error {
+  static readonly code = 6071
+  readonly code = 6071
+  readonly name = "InvalidDomain"
+  readonly msg = "Domain must be 1-32 lowercase ASCII letters, digits, '-' or '.', null-padded"
+
+  constructor(readonly logs?: string[]) {
+    super("6071: Domain must be 1-32 lowercase ASCII letters, digits, '-' or '.', null-padded")
+  }
+}
+
+export class NotDomainOwner extends Error {
+  static readonly code = 6072
+  readonly code = 6072
+  readonly name = "NotDomainOwner"
+  readonly msg = "Signer is not the domain owner"
+
+  constructor(readonly logs?: string[]) {
+    super("6072: Signer is not the domain owner")
+  }
+}
+
+export class VkHashRequired extends Error {
+  static readonly code = 6073
+  readonly code = 6073
+  readonly name = "VkHashRequired"
+  readonly msg = "Domain pins a verification key - result must carry vk_hash (V5+)"
+
+  constructor(readonly logs?: string[]) {
+    super("6073: Domain pins a verification key - result must carry vk_hash (V5+)")
+  }
+}
+
+export class InvalidChallengeWindow extends Error {
+  static readonly code = 6074
+  readonly code = 6074
+  readonly name = "InvalidChallengeWindow"
+  readonly msg = "Challenge window must be between 0 and 7 days"
+
+  constructor(readonly logs?: string[]) {
+    super("6074: Challenge window must be between 0 and 7 days")
+  }
+}
+
+export class InvalidRateLimit extends Error {
+  static readonly code = 6075
+  readonly code = 6075
+  readonly name = "InvalidRateLimit"
+  readonly msg = "Domain rate limit must be between 0 and 600 verifications per minute"
+
+  constructor(readonly logs?: string[]) {
+    super("6075: Domain rate limit must be between 0 and 600 verifications per minute")
+  }
+}
+
+export class NotDomainOperator extends Error {
+  static readonly code = 6076
+  readonly code = 6076
+  readonly name = "NotDomainOperator"
+  readonly msg = "Signer is neither the domain owner nor its operator"
+
+  constructor(readonly logs?: string[]) {
+    super("6076: Signer is neither the domain owner nor its operator")
+  }
+}
+
+export class NotDomainTreasurer extends Error {
+  static readonly code = 6077
+  readonly code = 6077
+  readonly name = "NotDomainTreasurer"
+  readonly msg = "Signer is neither the domain owner nor its treasurer"
+
+  constructor(readonly logs?: string[]) {
+    super("6077: Signer is neither the domain owner nor its treasurer")
+  }
+}
+
+export class InvalidDomainRoleKey extends Error {
+  static readonly code = 6078
+  readonly code = 6078
+  readonly name = "InvalidDomainRoleKey"
+  readonly msg = "Domain owner can't be set to the default key"
+
+  constructor(readonly logs?: string[]) {
+    super("6078: Domain owner can't be set to the default key")
+  }
+}
+
+export class DomainPaused extends Error {
+  static readonly code = 6079
+  readonly code = 6079
+  readonly name = "DomainPaused"
+  readonly msg = "Domain is paused"
+
+  constructor(readonly logs?: string[]) {
+    super("6079: Domain is paused")
+  }
+}
+
+export class TooManyOrigins extends Error {
+  static readonly code = 6080
+  readonly code = 6080
+  readonly name = "TooManyOrigins"
+  readonly msg = "Too many registered origins"
+
+  constructor(readonly logs?: string[]) {
+    super("6080: Too many registered origins")
+  }
+}
+
+export class OriginNotAllowed extends Error {
+  static readonly code = 6081
+  readonly code = 6081
+  readonly name = "OriginNotAllowed"
+  readonly msg = "Origin is not registered for the domain"
+
+  constructor(readonly logs?: string[]) {
+    super("6081: Origin is not registered for the domain")
+  }
+}
+
+export class ChallengeWindowClosed extends Error {
+  static readonly code = 6082
+  readonly code = 6082
+  readonly name = "ChallengeWindowClosed"
+  readonly msg = "Verification has already finalized and can no longer be challenged"
+
+  constructor(readonly logs?: string[]) {
+    super("6082: Verification has already finalized and can no longer be challenged")
+  }
+}
+
+export class InvalidChallenge extends Error {
+  static readonly code = 6083
+  readonly code = 6083
+  readonly name = "InvalidChallenge"
+  readonly msg = "Challenge evidence does not dispute this verification"
+
+  constructor(readonly logs?: string[]) {
+    super("6083: Challenge evidence does not dispute this verification")
+  }
+}
+
+export class FeeNotConfigured extends Error {
+  static readonly code = 6084
+  readonly code = 6084
+  readonly name = "FeeNotConfigured"
+  readonly msg = "Fee is not configured"
+
+  constructor(readonly logs?: string[]) {
+    super("6084: Fee is not configured")
+  }
+}
+
+export class OracleFeedMismatch extends Error {
+  static readonly code = 6085
+  readonly code = 6085
+  readonly name = "OracleFeedMismatch"
+  readonly msg = "Price update is for a different feed than the mint's configured feed"
+
+  constructor(readonly logs?: string[]) {
+    super("6085: Price update is for a different feed than the mint's configured feed")
+  }
+}
+
+export class InvalidOraclePrice extends Error {
+  static readonly code = 6086
+  readonly code = 6086
+  readonly name = "InvalidOraclePrice"
+  readonly msg = "Price update account is invalid, unverified, or non-positive"
+
+  constructor(readonly logs?: string[]) {
+    super("6086: Price update account is invalid, unverified, or non-positive")
+  }
+}
+
+export class OraclePriceStale extends Error {
+  static readonly code = 6087
+  readonly code = 6087
+  readonly name = "OraclePriceStale"
+  readonly msg = "Oracle price is too old"
+
+  constructor(readonly logs?: string[]) {
+    super("6087: Oracle price is too old")
+  }
+}
+
+export class OraclePriceUncertain extends Error {
+  static readonly code = 6088
+  readonly code = 6088
+  readonly name = "OraclePriceUncertain"
+  readonly msg = "Oracle price confidence interval is too wide"
+
+  constructor(readonly logs?: string[]) {
+    super("6088: Oracle price confidence interval is too wide")
+  }
+}
+
+export class PriceQuoteOverflow extends Error {
+  static readonly code = 6089
+  readonly code = 6089
+  readonly name = "PriceQuoteOverflow"
+  readonly msg = "Price quote overflowed"
+
+  constructor(readonly logs?: string[]) {
+    super("6089: Price quote overflowed")
+  }
+}
+
+export class FeeExceedsMaxAmount extends Error {
+  static readonly code = 6090
+  readonly code = 6090
+  readonly name = "FeeExceedsMaxAmount"
+  readonly msg = "Quoted fee exceeds the payer's maximum amount"
+
+  constructor(readonly logs?: string[]) {
+    super("6090: Quoted fee exceeds the payer's maximum amount")
+  }
+}
+
+export class InvalidFeeShare extends Error {
+  static readonly code = 6091
+  readonly code = 6091
+  readonly name = "InvalidFeeShare"
+  readonly msg = "Domain fee share must be at most 50%"
+
+  constructor(readonly logs?: string[]) {
+    super("6091: Domain fee share must be at most 50%")
+  }
+}
+
+export class SessionExpired extends Error {
+  static readonly code = 6092
+  readonly code = 6092
+  readonly name = "SessionExpired"
+  readonly msg = "Session has expired"
+
+  constructor(readonly logs?: string[]) {
+    super("6092: Session has expired")
+  }
+}
+
+export class SessionRevoked extends Error {
+  static readonly code = 6093
+  readonly code = 6093
+  readonly name = "SessionRevoked"
+  readonly msg = "Session was revoked by a global logout"
+
+  constructor(readonly logs?: string[]) {
+    super("6093: Session was revoked by a global logout")
+  }
+}
+
+export class SessionPending extends Error {
+  static readonly code = 6094
+  readonly code = 6094
+  readonly name = "SessionPending"
+  readonly msg = "Session is pending until its challenge window ends"
+
+  constructor(readonly logs?: string[]) {
+    super("6094: Session is pending until its challenge window ends")
+  }
+}
+
+export class RateLimited extends Error {
+  static readonly code = 6095
+  readonly code = 6095
+  readonly name = "RateLimited"
+  readonly msg = "Fee payer exceeded its verification rate limit - retry once the window slides"
+
+  constructor(readonly logs?: string[]) {
+    super("6095: Fee payer exceeded its verification rate limit - retry once the window slides")
+  }
+}
+
+export class InvalidAssuranceTier extends Error {
+  static readonly code = 6096
+  readonly code = 6096
+  readonly name = "InvalidAssuranceTier"
+  readonly msg = "Assurance tier is not defined (tiers are added in order, up to 8)"
+
+  constructor(readonly logs?: string[]) {
+    super("6096: Assurance tier is not defined (tiers are added in order, up to 8)")
+  }
+}
+
+export class InvalidResultAge extends Error {
+  static readonly code = 6097
+  readonly code = 6097
+  readonly name = "InvalidResultAge"
+  readonly msg = "Maximum result age must be at most 1 hour (and non-zero for a tier)"
+
+  constructor(readonly logs?: string[]) {
+    super("6097: Maximum result age must be at most 1 hour (and non-zero for a tier)")
+  }
+}
+
+export class TimestampInFuture extends Error {
+  static readonly code = 6098
+  readonly code = 6098
+  readonly name = "TimestampInFuture"
+  readonly msg = "Verification result timestamp is in the future"
+
+  constructor(readonly logs?: string[]) {
+    super("6098: Verification result timestamp is in the future")
+  }
+}
+
+export class InvalidPointsRoot extends Error {
+  static readonly code = 6099
+  readonly code = 6099
+  readonly name = "InvalidPointsRoot"
+  readonly msg = "Points root must cover at least one leaf"
+
+  constructor(readonly logs?: string[]) {
+    super("6099: Points root must cover at least one leaf")
+  }
+}
+
+export class InvalidPointsProof extends Error {
+  static readonly code = 6100
+  readonly code = 6100
+  readonly name = "InvalidPointsProof"
+  readonly msg = "Merkle proof doesn't match the published points root"
+
+  constructor(readonly logs?: string[]) {
+    super("6100: Merkle proof doesn't match the published points root")
+  }
+}
+
+export class PersonhoodProviderAlreadyRegistered extends Error {
+  static readonly code = 6101
+  readonly code = 6101
+  readonly name = "PersonhoodProviderAlreadyRegistered"
+  readonly msg = "Personhood provider is already registered"
+
+  constructor(readonly logs?: string[]) {
+    super("6101: Personhood provider is already registered")
+  }
+}
+
+export class PersonhoodProviderNotRegistered extends Error {
+  static readonly code = 6102
+  readonly code = 6102
+  readonly name = "PersonhoodProviderNotRegistered"
+  readonly msg = "Personhood provider is not registered"
+
+  constructor(readonly logs?: string[]) {
+    super("6102: Personhood provider is not registered")
+  }
+}
+
+export class TooManyPersonhoodProviders extends Error {
+  static readonly code = 6103
+  readonly code = 6103
+  readonly name = "TooManyPersonhoodProviders"
+  readonly msg = "Too many personhood providers"
+
+  constructor(readonly logs?: string[]) {
+    super("6103: Too many personhood providers")
+  }
+}
+
+export class InvalidPersonhoodScore extends Error {
+  static readonly code = 6104
+  readonly code = 6104
+  readonly name = "InvalidPersonhoodScore"
+  readonly msg = "Personhood score must be at most 100"
+
+  constructor(readonly logs?: string[]) {
+    super("6104: Personhood score must be at most 100")
+  }
+}
+
+export class StalePersonhoodAttestation extends Error {
+  static readonly code = 6105
+  readonly code = 6105
+  readonly name = "StalePersonhoodAttestation"
+  readonly msg = "Personhood attestation is expired, future-dated, or older than the attached one"
+
+  constructor(readonly logs?: string[]) {
+    super("6105: Personhood attestation is expired, future-dated, or older than the attached one")
+  }
+}
+
+export class MissingPersonhoodSignature extends Error {
+  static readonly code = 6106
+  readonly code = 6106
+  readonly name = "MissingPersonhoodSignature"
+  readonly msg = "No Ed25519 instruction carries the provider's signature over the attestation"
+
+  constructor(readonly logs?: string[]) {
+    super("6106: No Ed25519 instruction carries the provider's signature over the attestation")
+  }
+}
+
+export class AttestationPreimageMismatch extends Error {
+  static readonly code = 6107
+  readonly code = 6107
+  readonly name = "AttestationPreimageMismatch"
+  readonly msg = "Attestation fields don't match the archived hash"
+
+  constructor(readonly logs?: string[]) {
+    super("6107: Attestation fields don't match the archived hash")
+  }
+}
+
+export class MigrationInProgress extends Error {
+  static readonly code = 6108
+  readonly code = 6108
+  readonly name = "MigrationInProgress"
+  readonly msg = "A migration is already in progress"
+
+  constructor(readonly logs?: string[]) {
+    super("6108: A migration is already in progress")
+  }
+}
+
+export class NoMigrationInProgress extends Error {
+  static readonly code = 6109
+  readonly code = 6109
+  readonly name = "NoMigrationInProgress"
+  readonly msg = "No migration is in progress"
+
+  constructor(readonly logs?: string[]) {
+    super("6109: No migration is in progress")
+  }
+}
+
+export class InvalidMigrationPlan extends Error {
+  static readonly code = 6110
+  readonly code = 6110
+  readonly name = "InvalidMigrationPlan"
+  readonly msg = "Migration needs a newer version, at most 4 distinct unstarted steps and a future deadline"
+
+  constructor(readonly logs?: string[]) {
+    super("6110: Migration needs a newer version, at most 4 distinct unstarted steps and a future deadline")
+  }
+}
+
+export class MigrationIncomplete extends Error {
+  static readonly code = 6111
+  readonly code = 6111
+  readonly name = "MigrationIncomplete"
+  readonly msg = "Required migration steps are unfinished and the deadline hasn't passed"
+
+  constructor(readonly logs?: string[]) {
+    super("6111: Required migration steps are unfinished and the deadline hasn't passed")
+  }
+}
+
+export class UnknownMigrationStep extends Error {
+  static readonly code = 6112
+  readonly code = 6112
+  readonly name = "UnknownMigrationStep"
+  readonly msg = "The open migration has no step for this account type"
+
+  constructor(readonly logs?: string[]) {
+    super("6112: The open migration has no step for this account type")
+  }
+}
+
+export class UnsupportedAccountVersion extends Error {
+  static readonly code = 6113
+  readonly code = 6113
+  readonly name = "UnsupportedAccountVersion"
+  readonly msg = "Account version is not accepted by the current migration state"
+
+  constructor(readonly logs?: string[]) {
+    super("6113: Account version is not accepted by the current migration state")
+  }
+}
+
+export class InvalidVerifierWeight extends Error {
+  static readonly code = 6114
+  readonly code = 6114
+  readonly name = "InvalidVerifierWeight"
+  readonly msg = "Verifier weight must be at most 16"
+
+  constructor(readonly logs?: string[]) {
+    super("6114: Verifier weight must be at most 16")
+  }
+}
+
+export class VerifierNotWeighted extends Error {
+  static readonly code = 6115
+  readonly code = 6115
+  readonly name = "VerifierNotWeighted"
+  readonly msg = "Verifier has no selection weight"
+
+  constructor(readonly logs?: string[]) {
+    super("6115: Verifier has no selection weight")
+  }
+}
+
+export class NoVerifierAvailable extends Error {
+  static readonly code = 6116
+  readonly code = 6116
+  readonly name = "NoVerifierAvailable"
+  readonly msg = "No weighted, live verifier is available"
+
+  constructor(readonly logs?: string[]) {
+    super("6116: No weighted, live verifier is available")
+  }
+}
+
+export class MissingUserConsent extends Error {
+  static readonly code = 6117
+  readonly code = 6117
+  readonly name = "MissingUserConsent"
+  readonly msg = "No Ed25519 instruction carries the user's consent signature"
+
+  constructor(readonly logs?: string[]) {
+    super("6117: No Ed25519 instruction carries the user's consent signature")
+  }
+}
+
+export class UserConsentExpired extends Error {
+  static readonly code = 6118
+  readonly code = 6118
+  readonly name = "UserConsentExpired"
+  readonly msg = "User consent has expired"
+
+  constructor(readonly logs?: string[]) {
+    super("6118: User consent has expired")
+  }
+}
+
+export class InvalidSessionBounds extends Error {
+  static readonly code = 6119
+  readonly code = 6119
+  readonly name = "InvalidSessionBounds"
+  readonly msg = "Session bounds must be 0 or within the protocol's, with min <= max"
+
+  constructor(readonly logs?: string[]) {
+    super("6119: Session bounds must be 0 or within the protocol's, with min <= max")
+  }
+}
+
+export class SessionExpiryOutOfBounds extends Error {
+  static readonly code = 6120
+  readonly code = 6120
+  readonly name = "SessionExpiryOutOfBounds"
+  readonly msg = "Requested session lifetime is outside the domain's bounds"
+
+  constructor(readonly logs?: string[]) {
+    super("6120: Requested session lifetime is outside the domain's bounds")
+  }
+}
+
+export class ResultLengthMismatch extends Error {
+  static readonly code = 6121
+  readonly code = 6121
+  readonly name = "ResultLengthMismatch"
+  readonly msg = "Verification result length doesn't match its version's"
+
+  constructor(readonly logs?: string[]) {
+    super("6121: Verification result length doesn't match its version's")
+  }
+}
+
+export class NonCanonicalBool extends Error {
+  static readonly code = 6122
+  readonly code = 6122
+  readonly name = "NonCanonicalBool"
+  readonly msg = "Boolean fields must be encoded as 0 or 1"
+
+  constructor(readonly logs?: string[]) {
+    super("6122: Boolean fields must be encoded as 0 or 1")
+  }
+}
+
+export class SignatureAlreadyUsed extends Error {
+  static readonly code = 6123
+  readonly code = 6123
+  readonly name = "SignatureAlreadyUsed"
+  readonly msg = "Verification result signature has already been consumed"
+
+  constructor(readonly logs?: string[]) {
+    super("6123: Verification result signature has already been consumed")
+  }
+}
+
+export class InvalidReceiptRetention extends Error {
+  static readonly code = 6124
+  readonly code = 6124
+  readonly name = "InvalidReceiptRetention"
+  readonly msg = "Receipt retention must be between 1 hour and 30 days"
+
+  constructor(readonly logs?: string[]) {
+    super("6124: Receipt retention must be between 1 hour and 30 days")
+  }
+}
+
+export class ReceiptRetained extends Error {
+  static readonly code = 6125
+  readonly code = 6125
+  readonly name = "ReceiptRetained"
+  readonly msg = "Result receipt is still inside its retention window"
+
+  constructor(readonly logs?: string[]) {
+    super("6125: Result receipt is still inside its retention window")
+  }
+}
+
+export class UnknownClaimType extends Error {
+  static readonly code = 6126
+  readonly code = 6126
+  readonly name = "UnknownClaimType"
+  readonly msg = "Unknown verification result claim type"
+
+  constructor(readonly logs?: string[]) {
+    super("6126: Unknown verification result claim type")
+  }
+}
+
+export class ClaimTypeMismatch extends Error {
+  static readonly code = 6127
+  readonly code = 6127
+  readonly name = "ClaimTypeMismatch"
+  readonly msg = "Signed message claim type doesn't match result"
+
+  constructor(readonly logs?: string[]) {
+    super("6127: Signed message claim type doesn't match result")
+  }
+}
+
+export class ClaimNotSession extends Error {
+  static readonly code = 6128
+  readonly code = 6128
+  readonly name = "ClaimNotSession"
+  readonly msg = "Only Auth claims open sessions"
+
+  constructor(readonly logs?: string[]) {
+    super("6128: Only Auth claims open sessions")
+  }
+}
+
+export class ClaimNotAttestation extends Error {
+  static readonly code = 6129
+  readonly code = 6129
+  readonly name = "ClaimNotAttestation"
+  readonly msg = "Auth claims open sessions rather than attestations"
+
+  constructor(readonly logs?: string[]) {
+    super("6129: Auth claims open sessions rather than attestations")
+  }
+}
+
+export class AttestationExpiryOutOfBounds extends Error {
+  static readonly code = 6130
+  readonly code = 6130
+  readonly name = "AttestationExpiryOutOfBounds"
+  readonly msg = "Attestation lifetime must be at most a year"
+
+  constructor(readonly logs?: string[]) {
+    super("6130: Attestation lifetime must be at most a year")
+  }
+}
+
+export class AttestationActive extends Error {
+  static readonly code = 6131
+  readonly code = 6131
+  readonly name = "AttestationActive"
+  readonly msg = "Attestation for this claim is still active; renew it instead"
+
+  constructor(readonly logs?: string[]) {
+    super("6131: Attestation for this claim is still active; renew it instead")
+  }
+}
+
+export class AttestationExpired extends Error {
+  static readonly code = 6132
+  readonly code = 6132
+  readonly name = "AttestationExpired"
+  readonly msg = "Attestation has expired"
+
+  constructor(readonly logs?: string[]) {
+    super("6132: Attestation has expired")
+  }
+}
+
+export class AttestationClaimMismatch extends Error {
+  static readonly code = 6133
+  readonly code = 6133
+  readonly name = "AttestationClaimMismatch"
+  readonly msg = "Renewal result is for a different claim than the attestation"
+
+  constructor(readonly logs?: string[]) {
+    super("6133: Renewal result is for a different claim than the attestation")
+  }
+}
+
+export class AttestationRevoked extends Error {
+  static readonly code = 6134
+  readonly code = 6134
+  readonly name = "AttestationRevoked"
+  readonly msg = "Attestation has been revoked by its issuer"
+
+  constructor(readonly logs?: string[]) {
+    super("6134: Attestation has been revoked by its issuer")
+  }
+}
+
+export class NotAttestationIssuer extends Error {
+  static readonly code = 6135
+  readonly code = 6135
+  readonly name = "NotAttestationIssuer"
+  readonly msg = "Only the attestation's issuer can revoke it"
+
+  constructor(readonly logs?: string[]) {
+    super("6135: Only the attestation's issuer can revoke it")
+  }
+}
+
+export class InvalidClaimMask extends Error {
+  static readonly code = 6136
+  readonly code = 6136
+  readonly name = "InvalidClaimMask"
+  readonly msg = "Claim type mask has bits for Auth or unknown claim types"
+
+  constructor(readonly logs?: string[]) {
+    super("6136: Claim type mask has bits for Auth or unknown claim types")
+  }
+}
+
+export class UntrustedAttestor extends Error {
+  static readonly code = 6137
+  readonly code = 6137
+  readonly name = "UntrustedAttestor"
+  readonly msg = "Domain only accepts this claim type from a registered attestor vetted for it"
+
+  constructor(readonly logs?: string[]) {
+    super("6137: Domain only accepts this claim type from a registered attestor vetted for it")
+  }
+}
+
+export class AttestorSuspended extends Error {
+  static readonly code = 6138
+  readonly code = 6138
+  readonly name = "AttestorSuspended"
+  readonly msg = "Attestor is suspended"
+
+  constructor(readonly logs?: string[]) {
+    super("6138: Attestor is suspended")
+  }
+}
+
+export class SasBridgeMismatch extends Error {
+  static readonly code = 6139
+  readonly code = 6139
+  readonly name = "SasBridgeMismatch"
+  readonly msg = "Account doesn't match the SAS bridge's credential or schema"
+
+  constructor(readonly logs?: string[]) {
+    super("6139: Account doesn't match the SAS bridge's credential or schema")
+  }
+}
+
+export class MessageTagMismatch extends Error {
+  static readonly code = 6140
+  readonly code = 6140
+  readonly name = "MessageTagMismatch"
+  readonly msg = "Signed message doesn't start with the Veiled protocol tag"
+
+  constructor(readonly logs?: string[]) {
+    super("6140: Signed message doesn't start with the Veiled protocol tag")
+  }
+}
+
+export class UntaggedResult extends Error {
+  static readonly code = 6141
+  readonly code = 6141
+  readonly name = "UntaggedResult"
+  readonly msg = "Result envelope is untagged - byte envelopes must be V9"
+
+  constructor(readonly logs?: string[]) {
+    super("6141: Result envelope is untagged - byte envelopes must be V9")
+  }
+}
+
+export class SessionNotExpired extends Error {
+  static readonly code = 6142
+  readonly code = 6142
+  readonly name = "SessionNotExpired"
+  readonly msg = "Session hasn't expired yet"
+
+  constructor(readonly logs?: string[]) {
+    super("6142: Session hasn't expired yet")
+  }
+}
+
+export class MissingSessionCloseSignature extends Error {
+  static readonly code = 6143
+  readonly code = 6143
+  readonly name = "MissingSessionCloseSignature"
+  readonly msg = "Session close must be signed by the session's authority"
+
+  constructor(readonly logs?: string[]) {
+    super("6143: Session close must be signed by the session's authority")
+  }
+}
+
+export class SessionIdle extends Error {
+  static readonly code = 6144
+  readonly code = 6144
+  readonly name = "SessionIdle"
+  readonly msg = "Session has been idle longer than its domain's idle timeout"
+
+  constructor(readonly logs?: string[]) {
+    super("6144: Session has been idle longer than its domain's idle timeout")
+  }
+}
+
+export class InvalidIdleTimeout extends Error {
+  static readonly code = 6145
+  readonly code = 6145
+  readonly name = "InvalidIdleTimeout"
+  readonly msg = "Idle timeout must be 0 or within the protocol's session lifetimes"
+
+  constructor(readonly logs?: string[]) {
+    super("6145: Idle timeout must be 0 or within the protocol's session lifetimes")
+  }
+}
+
+export class MissingDeviceSignature extends Error {
+  static readonly code = 6146
+  readonly code = 6146
+  readonly name = "MissingDeviceSignature"
+  readonly msg = "Session's device key did not sign this instruction"
+
+  constructor(readonly logs?: string[]) {
+    super("6146: Session's device key did not sign this instruction")
+  }
+}
+
+export class InvalidDelegateScope extends Error {
+  static readonly code = 6147
+  readonly code = 6147
+  readonly name = "InvalidDelegateScope"
+  readonly msg = "Delegate scope must include at least one permission"
+
+  constructor(readonly logs?: string[]) {
+    super("6147: Delegate scope must include at least one permission")
+  }
+}
+
+export class InvalidDelegateExpiry extends Error {
+  static readonly code = 6148
+  readonly code = 6148
+  readonly name = "InvalidDelegateExpiry"
+  readonly msg = "Delegate lifetime must be between 1 second and an hour"
+
+  constructor(readonly logs?: string[]) {
+    super("6148: Delegate lifetime must be between 1 second and an hour")
+  }
+}
+
+export class DelegateNotAllowed extends Error {
+  static readonly code = 6149
+  readonly code = 6149
+  readonly name = "DelegateNotAllowed"
+  readonly msg = "Delegate has expired or its scope doesn't include this permission"
+
+  constructor(readonly logs?: string[]) {
+    super("6149: Delegate has expired or its scope doesn't include this permission")
+  }
+}
+
+export class WrongNullifierStore extends Error {
+  static readonly code = 6150
+  readonly code = 6150
+  readonly name = "WrongNullifierStore"
+  readonly msg = "Domain registers nullifiers in another store; use that store's instructions"
+
+  constructor(readonly logs?: string[]) {
+    super("6150: Domain registers nullifiers in another store; use that store's instructions")
+  }
+}
+
+export class InvalidNonInclusionProof extends Error {
+  static readonly code = 6151
+  readonly code = 6151
+  readonly name = "InvalidNonInclusionProof"
+  readonly msg = "Low leaf doesn't bracket the nullifier, so it may already be registered"
+
+  constructor(readonly logs?: string[]) {
+    super("6151: Low leaf doesn't bracket the nullifier, so it may already be registered")
+  }
+}
+
+export class InvalidUsername extends Error {
+  static readonly code = 6152
+  readonly code = 6152
+  readonly name = "InvalidUsername"
+  readonly msg = "Usernames are 3 to 32 ASCII letters, digits or underscores"
+
+  constructor(readonly logs?: string[]) {
+    super("6152: Usernames are 3 to 32 ASCII letters, digits or underscores")
+  }
+}
+
+export class MissingUsernameTransferSignature extends Error {
+  static readonly code = 6153
+  readonly code = 6153
+  readonly name = "MissingUsernameTransferSignature"
+  readonly msg = "Username transfer must be signed by the username's authority"
+
+  constructor(readonly logs?: string[]) {
+    super("6153: Username transfer must be signed by the username's authority")
+  }
+}
+
+export class InvalidGuardianSet extends Error {
+  static readonly code = 6154
+  readonly code = 6154
+  readonly name = "InvalidGuardianSet"
+  readonly msg = "Guardian set needs 1 to 8 distinct guardians and a threshold between 1 and their count"
+
+  constructor(readonly logs?: string[]) {
+    super("6154: Guardian set needs 1 to 8 distinct guardians and a threshold between 1 and their count")
+  }
+}
+
+export class NotEnoughGuardianSignatures extends Error {
+  static readonly code = 6155
+  readonly code = 6155
+  readonly name = "NotEnoughGuardianSignatures"
+  readonly msg = "Not enough guardians signed this recovery"
+
+  constructor(readonly logs?: string[]) {
+    super("6155: Not enough guardians signed this recovery")
+  }
+}
+
+export class InvalidRecoveryAccounts extends Error {
+  static readonly code = 6156
+  readonly code = 6156
+  readonly name = "InvalidRecoveryAccounts"
+  readonly msg = "Recovery accounts must be (old, new) pairs of the nullifier's sessions or grants"
+
+  constructor(readonly logs?: string[]) {
+    super("6156: Recovery accounts must be (old, new) pairs of the nullifier's sessions or grants")
+  }
+}
+
+export class IdentityRootFull extends Error {
+  static readonly code = 6157
+  readonly code = 6157
+  readonly name = "IdentityRootFull"
+  readonly msg = "Identity root already links its maximum number of nullifiers"
+
+  constructor(readonly logs?: string[]) {
+    super("6157: Identity root already links its maximum number of nullifiers")
+  }
+}
+
+export class NullifierAlreadyLinked extends Error {
+  static readonly code = 6158
+  readonly code = 6158
+  readonly name = "NullifierAlreadyLinked"
+  readonly msg = "Nullifier is already linked to this identity root"
+
+  constructor(readonly logs?: string[]) {
+    super("6158: Nullifier is already linked to this identity root")
+  }
+}
+
+export class NullifierNotLinked extends Error {
+  static readonly code = 6159
+  readonly code = 6159
+  readonly name = "NullifierNotLinked"
+  readonly msg = "Nullifier is not linked to this identity root"
+
+  constructor(readonly logs?: string[]) {
+    super("6159: Nullifier is not linked to this identity root")
+  }
+}
+
+export class MissingIdentityLinkSignature extends Error {
+  static readonly code = 6160
+  readonly code = 6160
+  readonly name = "MissingIdentityLinkSignature"
+  readonly msg = "Identity link must be signed by the identity root's owner"
+
+  constructor(readonly logs?: string[]) {
+    super("6160: Identity link must be signed by the identity root's owner")
+  }
+}
+
+export class NullifierBanned extends Error {
+  static readonly code = 6161
+  readonly code = 6161
+  readonly name = "NullifierBanned"
+  readonly msg = "Nullifier is banned from this domain"
+
+  constructor(readonly logs?: string[]) {
+    super("6161: Nullifier is banned from this domain")
+  }
+}
+
+export class InvalidSnsNameAccount extends Error {
+  static readonly code = 6162
+  readonly code = 6162
+  readonly name = "InvalidSnsNameAccount"
+  readonly msg = "SNS name account missing or not the one for this .sol domain"
+
+  constructor(readonly logs?: string[]) {
+    super("6162: SNS name account missing or not the one for this .sol domain")
+  }
+}
+
+export class NotSnsNameOwner extends Error {
+  static readonly code = 6163
+  readonly code = 6163
+  readonly name = "NotSnsNameOwner"
+  readonly msg = "Signer doesn't own the SNS name for this .sol domain"
+
+  constructor(readonly logs?: string[]) {
+    super("6163: Signer doesn't own the SNS name for this .sol domain")
+  }
+}
+
+export class ReentrancyDetected extends Error {
+  static readonly code = 6164
+  readonly code = 6164
+  readonly name = "ReentrancyDetected"
+  readonly msg = "Instruction invoked too deep in a CPI chain (possible reentrancy)"
+
+  constructor(readonly logs?: string[]) {
+    super("6164: Instruction invoked too deep in a CPI chain (possible reentrancy)")
+  }
+}
+
+export class InvalidHonkVk extends Error {
+  static readonly code = 6165
+  readonly code = 6165
+  readonly name = "InvalidHonkVk"
+  readonly msg = "UltraHonk key is malformed, incomplete or doesn't match the circuit's vk_hash"
+
+  constructor(readonly logs?: string[]) {
+    super("6165: UltraHonk key is malformed, incomplete or doesn't match the circuit's vk_hash")
+  }
+}
+
+export class InvalidProofLength extends Error {
+  static readonly code = 6166
+  readonly code = 6166
+  readonly name = "InvalidProofLength"
+  readonly msg = "Upload must be the circuit's public inputs followed by one UltraHonk proof"
+
+  constructor(readonly logs?: string[]) {
+    super("6166: Upload must be the circuit's public inputs followed by one UltraHonk proof")
+  }
+}
+
+export class ProofChunkOutOfOrder extends Error {
+  static readonly code = 6167
+  readonly code = 6167
+  readonly name = "ProofChunkOutOfOrder"
+  readonly msg = "Proof chunk must start where the upload left off and stay within it"
+
+  constructor(readonly logs?: string[]) {
+    super("6167: Proof chunk must start where the upload left off and stay within it")
+  }
+}
+
+export class InvalidSessionStatus extends Error {
+  static readonly code = 6168
+  readonly code = 6168
+  readonly name = "InvalidSessionStatus"
+  readonly msg = "Verification session is not in the right state for this instruction"
+
+  constructor(readonly logs?: string[]) {
+    super("6168: Verification session is not in the right state for this instruction")
+  }
+}
+
+export function fromCode(code: number, logs?: string[]): CustomError | null {
+  switch (code) {
+    case 6000:
+      return new InvalidProof(logs)
+    case 6001:
+      return new DuplicateNullifier(logs)
+    case 6002:
+      return new ProofExpired(logs)
+    case 6003:
+      return new DomainTooLong(logs)
+    case 6004:
+      return new SandboxUnavailable(logs)
+    case 6005:
+      return new InvalidPublicInputs(logs)
+    case 6006:
+      return new OffsetMismatch(logs)
+    case 6007:
+      return new InvalidInstructionData(logs)
+    case 6008:
+      return new InvalidSignatureCount(logs)
+    case 6009:
+      return new InvalidMessageSize(logs)
+    case 6010:
+      return new ProofHashMismatch(logs)
+    case 6011:
+      return new IsValidMismatch(logs)
+    case 6012:
+      return new AuthorityMismatch(logs)
+    case 6013:
+      return new NullifierMismatch(logs)
+    case 6014:
+      return new DomainMismatch(logs)
+    case 6015:
+      return new UnsupportedResultVersion(logs)
+    case 6016:
+      return new PublicInputsMismatch(logs)
+    case 6017:
+      return new CircuitMismatch(logs)
+    case 6018:
+      return new VkHashMismatch(logs)
+    case 6019:
+      return new ClusterMismatch(logs)
+    case 6020:
+      return new InstanceMismatch(logs)
+    case 6021:
+      return new ProofAlreadyUsed(logs)
+    case 6022:
+      return new InvalidVerificationBatch(logs)
+    case 6023:
+      return new InvalidResultLayout(logs)
+    case 6024:
+      return new BadEd25519Program(logs)
+    case 6025:
+      return new BadEd25519Accounts(logs)
+    case 6026:
+      return new PermissionRevoked(logs)
+    case 6027:
+      return new PermissionExpired(logs)
+    case 6028:
+      return new PermissionNotGranted(logs)
+    case 6029:
+      return new UnauthorizedRevocation(logs)
+    case 6030:
+      return new TooManyPermissions(logs)
+    case 6031:
+      return new InvalidExpiry(logs)
+    case 6032:
+      return new InvalidNotificationToken(logs)
+    case 6033:
+      return new NotChannelAuthority(logs)
+    case 6034:
+      return new InvalidRevealDelay(logs)
+    case 6035:
+      return new InvalidRevealCiphertext(logs)
+    case 6036:
+      return new NotEscrowAuthority(logs)
+    case 6037:
+      return new RevealNotReady(logs)
+    case 6038:
+      return new RevealKeyMismatch(logs)
+    case 6039:
+      return new RevealAlreadyReleased(logs)
+    case 6040:
+      return new RevealWindowClosed(logs)
+    case 6041:
+      return new NotVcIssuer(logs)
+    case 6042:
+      return new VcRevoked(logs)
+    case 6043:
+      return new AuditorRevoked(logs)
+    case 6044:
+      return new AuditorExpired(logs)
+    case 6045:
+      return new AccessLogMismatch(logs)
+    case 6046:
+      return new InvalidCompactionBatch(logs)
+    case 6047:
+      return new AccessLogTooRecent(logs)
+    case 6048:
+      return new AccessLogPayerMismatch(logs)
+    case 6049:
+      return new Unauthorized(logs)
+    case 6050:
+      return new MintNotAccepted(logs)
+    case 6051:
+      return new MintAlreadyAccepted(logs)
+    case 6052:
+      return new TooManyAcceptedMints(logs)
+    case 6053:
+      return new CircuitNotRegistered(logs)
+    case 6054:
+      return new CircuitDeprecated(logs)
+    case 6055:
+      return new CircuitAlreadyRegistered(logs)
+    case 6056:
+      return new TooManyCircuits(logs)
+    case 6057:
+      return new InvalidGroth16Vk(logs)
+    case 6058:
+      return new InvalidGroth16Proof(logs)
+    case 6059:
+      return new UnregisteredVerifier(logs)
+    case 6060:
+      return new VerifierAlreadyRegistered(logs)
+    case 6061:
+      return new VerifierNotRegistered(logs)
+    case 6062:
+      return new TooManyVerifiers(logs)
+    case 6063:
+      return new InvalidVerifierThreshold(logs)
+    case 6064:
+      return new QuorumNotMet(logs)
+    case 6065:
+      return new TooManyEd25519Instructions(logs)
+    case 6066:
+      return new TooManySecp256r1Instructions(logs)
+    case 6067:
+      return new BadSecp256r1Accounts(logs)
+    case 6068:
+      return new InvalidSecp256r1Key(logs)
+    case 6069:
+      return new TooManyRotations(logs)
+    case 6070:
+      return new InvalidGracePeriod(logs)
+    case 6071:
+      return new InvalidDomain(logs)
+    case 6072:
+      return new NotDomainOwner(logs)
+    case 6073:
+      return new VkHashRequired(logs)
+    case 6074:
+      return new InvalidChallengeWindow(logs)
+    case 6075:
+      return new InvalidRateLimit(logs)
+    case 6076:
+      return new NotDomainOperator(logs)
+    case 6077:
+      return new NotDomainTreasurer(logs)
+    case 6078:
+      return new InvalidDomainRoleKey(logs)
+    case 6079:
+      return new DomainPaused(logs)
+    case 6080:
+      return new TooManyOrigins(logs)
+    case 6081:
+      return new OriginNotAllowed(logs)
+    case 6082:
+      return new ChallengeWindowClosed(logs)
+    case 6083:
+      return new InvalidChallenge(logs)
+    case 6084:
+      return new FeeNotConfigured(logs)
+    case 6085:
+      return new OracleFeedMismatch(logs)
+    case 6086:
+      return new InvalidOraclePrice(logs)
+    case 6087:
+      return new OraclePriceStale(logs)
+    case 6088:
+      return new OraclePriceUncertain(logs)
+    case 6089:
+      return new PriceQuoteOverflow(logs)
+    case 6090:
+      return new FeeExceedsMaxAmount(logs)
+    case 6091:
+      return new InvalidFeeShare(logs)
+    case 6092:
+      return new SessionExpired(logs)
+    case 6093:
+      return new SessionRevoked(logs)
+    case 6094:
+      return new SessionPending(logs)
+    case 6095:
+      return new RateLimited(logs)
+    case 6096:
+      return new InvalidAssuranceTier(logs)
+    case 6097:
+      return new InvalidResultAge(logs)
+    case 6098:
+      return new TimestampInFuture(logs)
+    case 6099:
+      return new InvalidPointsRoot(logs)
+    case 6100:
+      return new InvalidPointsProof(logs)
+    case 6101:
+      return new PersonhoodProviderAlreadyRegistered(logs)
+    case 6102:
+      return new PersonhoodProviderNotRegistered(logs)
+    case 6103:
+      return new TooManyPersonhoodProviders(logs)
+    case 6104:
+      return new InvalidPersonhoodScore(logs)
+    case 6105:
+      return new StalePersonhoodAttestation(logs)
+    case 6106:
+      return new MissingPersonhoodSignature(logs)
+    case 6107:
+      return new AttestationPreimageMismatch(logs)
+    case 6108:
+      return new MigrationInProgress(logs)
+    case 6109:
+      return new NoMigrationInProgress(logs)
+    case 6110:
+      return new InvalidMigrationPlan(logs)
+    case 6111:
+      return new MigrationIncomplete(logs)
+    case 6112:
+      return new UnknownMigrationStep(logs)
+    case 6113:
+      return new UnsupportedAccountVersion(logs)
+    case 6114:
+      return new InvalidVerifierWeight(logs)
+    case 6115:
+      return new VerifierNotWeighted(logs)
+    case 6116:
+      return new NoVerifierAvailable(logs)
+    case 6117:
+      return new MissingUserConsent(logs)
+    case 6118:
+      return new UserConsentExpired(logs)
+    case 6119:
+      return new InvalidSessionBounds(logs)
+    case 6120:
+      return new SessionExpiryOutOfBounds(logs)
+    case 6121:
+      return new ResultLengthMismatch(logs)
+    case 6122:
+      return new NonCanonicalBool(logs)
+    case 6123:
+      return new SignatureAlreadyUsed(logs)
+    case 6124:
+      return new InvalidReceiptRetention(logs)
+    case 6125:
+      return new ReceiptRetained(logs)
+    case 6126:
+      return new UnknownClaimType(logs)
+    case 6127:
+      return new ClaimTypeMismatch(logs)
+    case 6128:
+      return new ClaimNotSession(logs)
+    case 6129:
+      return new ClaimNotAttestation(logs)
+    case 6130:
+      return new AttestationExpiryOutOfBounds(logs)
+    case 6131:
+      return new AttestationActive(logs)
+    case 6132:
+      return new AttestationExpired(logs)
+    case 6133:
+      return new AttestationClaimMismatch(logs)
+    case 6134:
+      return new AttestationRevoked(logs)
+    case 6135:
+      return new NotAttestationIssuer(logs)
+    case 6136:
+      return new InvalidClaimMask(logs)
+    case 6137:
+      return new UntrustedAttestor(logs)
+    case 6138:
+      return new AttestorSuspended(logs)
+    case 6139:
+      return new SasBridgeMismatch(logs)
+    case 6140:
+      return new MessageTagMismatch(logs)
+    case 6141:
+      return new UntaggedResult(logs)
+    case 6142:
+      return new SessionNotExpired(logs)
+    case 6143:
+      return new MissingSessionCloseSignature(logs)
+    case 6144:
+      return new SessionIdle(logs)
+    case 6145:
+      return new InvalidIdleTimeout(logs)
+    case 6146:
+      return new MissingDeviceSignature(logs)
+    case 6147:
+      return new InvalidDelegateScope(logs)
+    case 6148:
+      return new InvalidDelegateExpiry(logs)
+    case 6149:
+      return new DelegateNotAllowed(logs)
+    case 6150:
+      return new WrongNullifierStore(logs)
+    case 6151:
+      return new InvalidNonInclusionProof(logs)
+    case 6152:
+      return new InvalidUsername(logs)
+    case 6153:
+      return new MissingUsernameTransferSignature(logs)
+    case 6154:
+      return new InvalidGuardianSet(logs)
+    case 6155:
+      return new NotEnoughGuardianSignatures(logs)
+    case 6156:
+      return new InvalidRecoveryAccounts(logs)
+    case 6157:
+      return new IdentityRootFull(logs)
+    case 6158:
+      return new NullifierAlreadyLinked(logs)
+    case 6159:
+      return new NullifierNotLinked(logs)
+    case 6160:
+      return new MissingIdentityLinkSignature(logs)
+    case 6161:
+      return new NullifierBanned(logs)
+    case 6162:
+      return new InvalidSnsNameAccount(logs)
+    case 6163:
+      return new NotSnsNameOwner(logs)
+    case 6164:
+      return new ReentrancyDetected(logs)
+    case 6165:
+      return new InvalidHonkVk(logs)
+    case 6166:
+      return new InvalidProofLength(logs)
+    case 6167:
+      return new ProofChunkOutOfOrder(logs)
+    case 6168:
+      return new InvalidSessionStatus(logs)
+  }
+
+  return null
+}
@@ -0,0 +1,32 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AcknowledgeAccessLogAccounts {
+  auditorGrant: PublicKey
+  permissionAccess: PublicKey
+  permissionGrant: PublicKey
+  auditor: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export function acknowledgeAccessLog(
+  accounts: AcknowledgeAccessLogAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.auditorGrant, isSigner: false, isWritable: false },
+    { pubkey: accounts.permissionAccess, isSigner: false, isWritable: false },
+    { pubkey: accounts.permissionGrant, isSigner: false, isWritable: false },
+    { pubkey: accounts.auditor, isSigner: true, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([151, 216, 104, 211, 30, 79, 252, 29])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
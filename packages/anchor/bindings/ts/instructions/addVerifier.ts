@@ -0,0 +1,48 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AddVerifierArgs {
+  verifier: PublicKey
+}
+
+export interface AddVerifierAccounts {
+  config: PublicKey
+  verifierRegistry: PublicKey
+  authority: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.publicKey("verifier"),
+])
+
+export function addVerifier(
+  args: AddVerifierArgs,
+  accounts: AddVerifierAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.verifierRegistry, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([165, 72, 135, 225, 67, 181, 255, 135])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      verifier: args.verifier,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,44 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface AdvanceVerificationSessionArgs {
+  maxSteps: number
+}
+
+export interface AdvanceVerificationSessionAccounts {
+  verificationSession: PublicKey
+  honkVk: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u8("maxSteps"),
+])
+
+export function advanceVerificationSession(
+  args: AdvanceVerificationSessionArgs,
+  accounts: AdvanceVerificationSessionAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.verificationSession, isSigner: false, isWritable: true },
+    { pubkey: accounts.honkVk, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([39, 137, 184, 172, 245, 10, 56, 7])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      maxSteps: args.maxSteps,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,32 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ArchiveAttestationAccounts {
+  attestation: PublicKey
+  archive: PublicKey
+  provider: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export function archiveAttestation(
+  accounts: ArchiveAttestationAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.attestation, isSigner: false, isWritable: true },
+    { pubkey: accounts.archive, isSigner: false, isWritable: true },
+    { pubkey: accounts.provider, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([133, 159, 110, 78, 53, 173, 16, 180])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,54 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface BeginMigrationArgs {
+  targetVersion: number
+  steps: Array<types.MigrationStep>
+  deadline: BN
+}
+
+export interface BeginMigrationAccounts {
+  config: PublicKey
+  migrationState: PublicKey
+  authority: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u16("targetVersion"),
+  borsh.vec(types.MigrationStep.layout(), "steps"),
+  borsh.i64("deadline"),
+])
+
+export function beginMigration(
+  args: BeginMigrationArgs,
+  accounts: BeginMigrationAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.migrationState, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([200, 55, 163, 173, 166, 180, 249, 81])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      targetVersion: args.targetVersion,
+      steps: args.steps.map((item) => types.MigrationStep.toEncodable(item)),
+      deadline: args.deadline,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,53 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ChallengeVerificationArgs {
+  evidence: Uint8Array
+  domain: Array<number>
+}
+
+export interface ChallengeVerificationAccounts {
+  nullifierAccount: PublicKey
+  domainConfig: PublicKey
+  verifierRegistry: PublicKey
+  instructionsSysvar: PublicKey
+  challenger: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.vecU8("evidence"),
+  borsh.array(borsh.u8(), 32, "domain"),
+])
+
+export function challengeVerification(
+  args: ChallengeVerificationArgs,
+  accounts: ChallengeVerificationAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.nullifierAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.verifierRegistry, isSigner: false, isWritable: false },
+    { pubkey: accounts.instructionsSysvar, isSigner: false, isWritable: false },
+    { pubkey: accounts.challenger, isSigner: true, isWritable: true },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([200, 70, 70, 170, 141, 244, 27, 85])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      evidence: Buffer.from(args.evidence.buffer, args.evidence.byteOffset, args.evidence.length),
+      domain: args.domain,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,54 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClaimDomainFeesArgs {
+  amount: BN
+}
+
+export interface ClaimDomainFeesAccounts {
+  domainConfig: PublicKey
+  domainFeeVault: PublicKey
+  mint: PublicKey
+  domainFeeVaultAta: PublicKey
+  destination: PublicKey
+  authority: PublicKey
+  tokenProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("amount"),
+])
+
+export function claimDomainFees(
+  args: ClaimDomainFeesArgs,
+  accounts: ClaimDomainFeesAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.domainFeeVault, isSigner: false, isWritable: false },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
+    { pubkey: accounts.domainFeeVaultAta, isSigner: false, isWritable: true },
+    { pubkey: accounts.destination, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([108, 69, 104, 182, 230, 23, 38, 215])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      amount: args.amount,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
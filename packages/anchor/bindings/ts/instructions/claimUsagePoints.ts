@@ -0,0 +1,57 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClaimUsagePointsArgs {
+  nullifier: Array<number>
+  amount: BN
+  leafIndex: number
+  proof: Array<Array<number>>
+}
+
+export interface ClaimUsagePointsAccounts {
+  pointsRoot: PublicKey
+  points: PublicKey
+  payer: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.array(borsh.u8(), 32, "nullifier"),
+  borsh.u64("amount"),
+  borsh.u32("leafIndex"),
+  borsh.vec(borsh.array(borsh.u8(), 32), "proof"),
+])
+
+export function claimUsagePoints(
+  args: ClaimUsagePointsArgs,
+  accounts: ClaimUsagePointsAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.pointsRoot, isSigner: false, isWritable: false },
+    { pubkey: accounts.points, isSigner: false, isWritable: true },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([233, 155, 142, 86, 58, 192, 162, 168])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      nullifier: args.nullifier,
+      amount: args.amount,
+      leafIndex: args.leafIndex,
+      proof: args.proof,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
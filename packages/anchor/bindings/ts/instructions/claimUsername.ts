@@ -0,0 +1,52 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ClaimUsernameArgs {
+  name: string
+}
+
+export interface ClaimUsernameAccounts {
+  nullifierAccount: PublicKey
+  username: PublicKey
+  authority: PublicKey
+  instructionsSysvar: PublicKey
+  logoutEpoch: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.str("name"),
+])
+
+export function claimUsername(
+  args: ClaimUsernameArgs,
+  accounts: ClaimUsernameAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.nullifierAccount, isSigner: false, isWritable: false },
+    { pubkey: accounts.username, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.instructionsSysvar, isSigner: false, isWritable: false },
+    { pubkey: accounts.logoutEpoch, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([161, 41, 99, 255, 196, 83, 221, 148])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      name: args.name,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,30 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CloseExpiredNullifierAccounts {
+  nullifierAccount: PublicKey
+  payer: PublicKey
+  cranker: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export function closeExpiredNullifier(
+  accounts: CloseExpiredNullifierAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.nullifierAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.payer, isSigner: false, isWritable: true },
+    { pubkey: accounts.cranker, isSigner: true, isWritable: true },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([188, 164, 243, 138, 115, 250, 239, 240])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,32 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CloseNotificationChannelAccounts {
+  notificationChannel: PublicKey
+  permissionGrant: PublicKey
+  authority: PublicKey
+  closer: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export function closeNotificationChannel(
+  accounts: CloseNotificationChannelAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.notificationChannel, isSigner: false, isWritable: true },
+    { pubkey: accounts.permissionGrant, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: false, isWritable: true },
+    { pubkey: accounts.closer, isSigner: true, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([133, 97, 254, 99, 122, 2, 196, 70])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
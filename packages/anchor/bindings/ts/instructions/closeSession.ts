@@ -0,0 +1,32 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface CloseSessionAccounts {
+  nullifierAccount: PublicKey
+  authority: PublicKey
+  payer: PublicKey
+  instructionsSysvar: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export function closeSession(
+  accounts: CloseSessionAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.nullifierAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: false, isWritable: false },
+    { pubkey: accounts.payer, isSigner: false, isWritable: true },
+    { pubkey: accounts.instructionsSysvar, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([68, 114, 178, 140, 222, 38, 248, 211])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,58 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface DelegateSessionArgs {
+  delegate: PublicKey
+  scope: BN
+  expiresIn: BN
+}
+
+export interface DelegateSessionAccounts {
+  nullifierAccount: PublicKey
+  sessionDelegate: PublicKey
+  authority: PublicKey
+  instructionsSysvar: PublicKey
+  logoutEpoch: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.publicKey("delegate"),
+  borsh.u64("scope"),
+  borsh.i64("expiresIn"),
+])
+
+export function delegateSession(
+  args: DelegateSessionArgs,
+  accounts: DelegateSessionAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.nullifierAccount, isSigner: false, isWritable: false },
+    { pubkey: accounts.sessionDelegate, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.instructionsSysvar, isSigner: false, isWritable: false },
+    { pubkey: accounts.logoutEpoch, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([82, 83, 119, 119, 196, 219, 5, 197])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      delegate: args.delegate,
+      scope: args.scope,
+      expiresIn: args.expiresIn,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,36 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface FinalizeVerificationSessionAccounts {
+  verificationSession: PublicKey
+  nullifierAccount: PublicKey
+  authority: PublicKey
+  circuitRegistry: PublicKey
+  domainConfig: PublicKey
+  nullifierBan: PublicKey
+  protocolStats: PublicKey
+  systemProgram: PublicKey
+}
+
+export function finalizeVerificationSession(
+  accounts: FinalizeVerificationSessionAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.verificationSession, isSigner: false, isWritable: true },
+    { pubkey: accounts.nullifierAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.circuitRegistry, isSigner: false, isWritable: false },
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.nullifierBan, isSigner: false, isWritable: false },
+    { pubkey: accounts.protocolStats, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([101, 62, 26, 53, 113, 232, 98, 88])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,55 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface GrantAuditorArgs {
+  nullifier: Array<number>
+  auditor: PublicKey
+  nameHash: Array<number>
+  expiresIn: types.ExpiryKindKind
+}
+
+export interface GrantAuditorAccounts {
+  auditorGrant: PublicKey
+  payer: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.array(borsh.u8(), 32, "nullifier"),
+  borsh.publicKey("auditor"),
+  borsh.array(borsh.u8(), 32, "nameHash"),
+  types.ExpiryKind.layout("expiresIn"),
+])
+
+export function grantAuditor(
+  args: GrantAuditorArgs,
+  accounts: GrantAuditorAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.auditorGrant, isSigner: false, isWritable: true },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([39, 25, 30, 66, 99, 223, 215, 91])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      nullifier: args.nullifier,
+      auditor: args.auditor,
+      nameHash: args.nameHash,
+      expiresIn: args.expiresIn.toEncodable(),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
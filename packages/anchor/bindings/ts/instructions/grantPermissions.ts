@@ -0,0 +1,64 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface GrantPermissionsArgs {
+  nullifier: Array<number>
+  domain: Array<number>
+  appId: PublicKey
+  permissions: Array<types.PermissionKind>
+  expiresIn: types.ExpiryKindKind
+}
+
+export interface GrantPermissionsAccounts {
+  permissionGrant: PublicKey
+  expiryHistogram: PublicKey
+  domainConfig: PublicKey
+  payer: PublicKey
+  protocolStats: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.array(borsh.u8(), 32, "nullifier"),
+  borsh.array(borsh.u8(), 32, "domain"),
+  borsh.publicKey("appId"),
+  borsh.vec(types.Permission.layout(), "permissions"),
+  types.ExpiryKind.layout("expiresIn"),
+])
+
+export function grantPermissions(
+  args: GrantPermissionsArgs,
+  accounts: GrantPermissionsAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.permissionGrant, isSigner: false, isWritable: true },
+    { pubkey: accounts.expiryHistogram, isSigner: false, isWritable: true },
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.protocolStats, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([229, 213, 34, 13, 171, 239, 162, 71])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      nullifier: args.nullifier,
+      domain: args.domain,
+      appId: args.appId,
+      permissions: args.permissions.map((item) => item.toEncodable()),
+      expiresIn: args.expiresIn.toEncodable(),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
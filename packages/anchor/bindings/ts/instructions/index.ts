@@ -0,0 +1,234 @@
+export { acknowledgeAccessLog } from "./acknowledgeAccessLog"
+export type { AcknowledgeAccessLogAccounts } from "./acknowledgeAccessLog"
+export { addPersonhoodProvider } from "./addPersonhoodProvider"
+export type { AddPersonhoodProviderArgs, AddPersonhoodProviderAccounts } from "./addPersonhoodProvider"
+export { addSecp256r1Verifier } from "./addSecp256r1Verifier"
+export type { AddSecp256r1VerifierArgs, AddSecp256r1VerifierAccounts } from "./addSecp256r1Verifier"
+export { addVerifier } from "./addVerifier"
+export type { AddVerifierArgs, AddVerifierAccounts } from "./addVerifier"
+export { advanceVerificationSession } from "./advanceVerificationSession"
+export type { AdvanceVerificationSessionArgs, AdvanceVerificationSessionAccounts } from "./advanceVerificationSession"
+export { archiveAttestation } from "./archiveAttestation"
+export type { ArchiveAttestationAccounts } from "./archiveAttestation"
+export { attachPersonhoodAttestation } from "./attachPersonhoodAttestation"
+export type { AttachPersonhoodAttestationArgs, AttachPersonhoodAttestationAccounts } from "./attachPersonhoodAttestation"
+export { banNullifier } from "./banNullifier"
+export type { BanNullifierArgs, BanNullifierAccounts } from "./banNullifier"
+export { beginMigration } from "./beginMigration"
+export type { BeginMigrationArgs, BeginMigrationAccounts } from "./beginMigration"
+export { cancelRevealEscrow } from "./cancelRevealEscrow"
+export type { CancelRevealEscrowAccounts } from "./cancelRevealEscrow"
+export { challengeVerification } from "./challengeVerification"
+export type { ChallengeVerificationArgs, ChallengeVerificationAccounts } from "./challengeVerification"
+export { claimAlias } from "./claimAlias"
+export type { ClaimAliasArgs, ClaimAliasAccounts } from "./claimAlias"
+export { claimDomainFees } from "./claimDomainFees"
+export type { ClaimDomainFeesArgs, ClaimDomainFeesAccounts } from "./claimDomainFees"
+export { claimUsagePoints } from "./claimUsagePoints"
+export type { ClaimUsagePointsArgs, ClaimUsagePointsAccounts } from "./claimUsagePoints"
+export { claimUsername } from "./claimUsername"
+export type { ClaimUsernameArgs, ClaimUsernameAccounts } from "./claimUsername"
+export { closeExpiredNullifier } from "./closeExpiredNullifier"
+export type { CloseExpiredNullifierAccounts } from "./closeExpiredNullifier"
+export { closeNotificationChannel } from "./closeNotificationChannel"
+export type { CloseNotificationChannelAccounts } from "./closeNotificationChannel"
+export { closeResultReceipt } from "./closeResultReceipt"
+export type { CloseResultReceiptAccounts } from "./closeResultReceipt"
+export { closeSession } from "./closeSession"
+export type { CloseSessionAccounts } from "./closeSession"
+export { closeVerificationSession } from "./closeVerificationSession"
+export type { CloseVerificationSessionAccounts } from "./closeVerificationSession"
+export { compactAccessLogs } from "./compactAccessLogs"
+export type { CompactAccessLogsAccounts } from "./compactAccessLogs"
+export { completeMigration } from "./completeMigration"
+export type { CompleteMigrationAccounts } from "./completeMigration"
+export { createDomainFeeVaultAta } from "./createDomainFeeVaultAta"
+export type { CreateDomainFeeVaultAtaAccounts } from "./createDomainFeeVaultAta"
+export { createIdentityRoot } from "./createIdentityRoot"
+export type { CreateIdentityRootAccounts } from "./createIdentityRoot"
+export { createTreasuryAta } from "./createTreasuryAta"
+export type { CreateTreasuryAtaAccounts } from "./createTreasuryAta"
+export { delegateSession } from "./delegateSession"
+export type { DelegateSessionArgs, DelegateSessionAccounts } from "./delegateSession"
+export { deprecateCircuit } from "./deprecateCircuit"
+export type { DeprecateCircuitArgs, DeprecateCircuitAccounts } from "./deprecateCircuit"
+export { finalizeVerificationSession } from "./finalizeVerificationSession"
+export type { FinalizeVerificationSessionAccounts } from "./finalizeVerificationSession"
+export { globalLogout } from "./globalLogout"
+export type { GlobalLogoutAccounts } from "./globalLogout"
+export { grantAuditor } from "./grantAuditor"
+export type { GrantAuditorArgs, GrantAuditorAccounts } from "./grantAuditor"
+export { grantPermissions } from "./grantPermissions"
+export type { GrantPermissionsArgs, GrantPermissionsAccounts } from "./grantPermissions"
+export { initNullifierTree } from "./initNullifierTree"
+export type { InitNullifierTreeArgs, InitNullifierTreeAccounts } from "./initNullifierTree"
+export { initializeConfig } from "./initializeConfig"
+export type { InitializeConfigAccounts } from "./initializeConfig"
+export { initializeDomainConfig } from "./initializeDomainConfig"
+export type { InitializeDomainConfigArgs, InitializeDomainConfigAccounts } from "./initializeDomainConfig"
+export { issueVcCommitment } from "./issueVcCommitment"
+export type { IssueVcCommitmentArgs, IssueVcCommitmentAccounts } from "./issueVcCommitment"
+export { linkIdentityRoot } from "./linkIdentityRoot"
+export type { LinkIdentityRootArgs, LinkIdentityRootAccounts } from "./linkIdentityRoot"
+export { logPermissionAccess } from "./logPermissionAccess"
+export type { LogPermissionAccessArgs, LogPermissionAccessAccounts } from "./logPermissionAccess"
+export { migrateGrant } from "./migrateGrant"
+export type { MigrateGrantArgs, MigrateGrantAccounts } from "./migrateGrant"
+export { migrateNullifier } from "./migrateNullifier"
+export type { MigrateNullifierArgs, MigrateNullifierAccounts } from "./migrateNullifier"
+export { migrateSession } from "./migrateSession"
+export type { MigrateSessionArgs, MigrateSessionAccounts } from "./migrateSession"
+export { mirrorAttestation } from "./mirrorAttestation"
+export type { MirrorAttestationAccounts } from "./mirrorAttestation"
+export { openRevealEscrow } from "./openRevealEscrow"
+export type { OpenRevealEscrowArgs, OpenRevealEscrowAccounts } from "./openRevealEscrow"
+export { openVerificationSession } from "./openVerificationSession"
+export type { OpenVerificationSessionArgs, OpenVerificationSessionAccounts } from "./openVerificationSession"
+export { payDomainFee } from "./payDomainFee"
+export type { PayDomainFeeArgs, PayDomainFeeAccounts } from "./payDomainFee"
+export { payFee } from "./payFee"
+export type { PayFeeArgs, PayFeeAccounts } from "./payFee"
+export { pinVkHash } from "./pinVkHash"
+export type { PinVkHashArgs, PinVkHashAccounts } from "./pinVkHash"
+export { publishPointsRoot } from "./publishPointsRoot"
+export type { PublishPointsRootArgs, PublishPointsRootAccounts } from "./publishPointsRoot"
+export { recoverIdentity } from "./recoverIdentity"
+export type { RecoverIdentityArgs, RecoverIdentityAccounts } from "./recoverIdentity"
+export { registerAttestor } from "./registerAttestor"
+export type { RegisterAttestorArgs, RegisterAttestorAccounts } from "./registerAttestor"
+export { registerCircuit } from "./registerCircuit"
+export type { RegisterCircuitArgs, RegisterCircuitAccounts } from "./registerCircuit"
+export { registerDomain } from "./registerDomain"
+export type { RegisterDomainArgs, RegisterDomainAccounts } from "./registerDomain"
+export { registerGroth16Vk } from "./registerGroth16Vk"
+export type { RegisterGroth16VkArgs, RegisterGroth16VkAccounts } from "./registerGroth16Vk"
+export { registerHonkVk } from "./registerHonkVk"
+export type { RegisterHonkVkArgs, RegisterHonkVkAccounts } from "./registerHonkVk"
+export { releaseRevealEscrow } from "./releaseRevealEscrow"
+export type { ReleaseRevealEscrowArgs, ReleaseRevealEscrowAccounts } from "./releaseRevealEscrow"
+export { releaseUsername } from "./releaseUsername"
+export type { ReleaseUsernameAccounts } from "./releaseUsername"
+export { removePersonhoodProvider } from "./removePersonhoodProvider"
+export type { RemovePersonhoodProviderArgs, RemovePersonhoodProviderAccounts } from "./removePersonhoodProvider"
+export { removeSecp256r1Verifier } from "./removeSecp256r1Verifier"
+export type { RemoveSecp256r1VerifierArgs, RemoveSecp256r1VerifierAccounts } from "./removeSecp256r1Verifier"
+export { removeVerifier } from "./removeVerifier"
+export type { RemoveVerifierArgs, RemoveVerifierAccounts } from "./removeVerifier"
+export { renewAttestation } from "./renewAttestation"
+export type { RenewAttestationArgs, RenewAttestationAccounts } from "./renewAttestation"
+export { renewSession } from "./renewSession"
+export type { RenewSessionArgs, RenewSessionAccounts } from "./renewSession"
+export { restoreAttestation } from "./restoreAttestation"
+export type { RestoreAttestationArgs, RestoreAttestationAccounts } from "./restoreAttestation"
+export { revokeAttestation } from "./revokeAttestation"
+export type { RevokeAttestationAccounts } from "./revokeAttestation"
+export { revokeAuditor } from "./revokeAuditor"
+export type { RevokeAuditorAccounts } from "./revokeAuditor"
+export { revokePermissions } from "./revokePermissions"
+export type { RevokePermissionsAccounts } from "./revokePermissions"
+export { revokeSessionDelegate } from "./revokeSessionDelegate"
+export type { RevokeSessionDelegateAccounts } from "./revokeSessionDelegate"
+export { rotateVerifierKey } from "./rotateVerifierKey"
+export type { RotateVerifierKeyArgs, RotateVerifierKeyAccounts } from "./rotateVerifierKey"
+export { selectVerifier } from "./selectVerifier"
+export type { SelectVerifierArgs, SelectVerifierAccounts } from "./selectVerifier"
+export { setAcceptedMint } from "./setAcceptedMint"
+export type { SetAcceptedMintArgs, SetAcceptedMintAccounts } from "./setAcceptedMint"
+export { setAssuranceTier } from "./setAssuranceTier"
+export type { SetAssuranceTierArgs, SetAssuranceTierAccounts } from "./setAssuranceTier"
+export { setAttestorStatus } from "./setAttestorStatus"
+export type { SetAttestorStatusArgs, SetAttestorStatusAccounts } from "./setAttestorStatus"
+export { setChallengeWindow } from "./setChallengeWindow"
+export type { SetChallengeWindowArgs, SetChallengeWindowAccounts } from "./setChallengeWindow"
+export { setDomainAssuranceTier } from "./setDomainAssuranceTier"
+export type { SetDomainAssuranceTierArgs, SetDomainAssuranceTierAccounts } from "./setDomainAssuranceTier"
+export { setDomainAttestorClaims } from "./setDomainAttestorClaims"
+export type { SetDomainAttestorClaimsArgs, SetDomainAttestorClaimsAccounts } from "./setDomainAttestorClaims"
+export { setDomainExpiryUnit } from "./setDomainExpiryUnit"
+export type { SetDomainExpiryUnitArgs, SetDomainExpiryUnitAccounts } from "./setDomainExpiryUnit"
+export { setDomainFeeShare } from "./setDomainFeeShare"
+export type { SetDomainFeeShareArgs, SetDomainFeeShareAccounts } from "./setDomainFeeShare"
+export { setDomainIdleTimeout } from "./setDomainIdleTimeout"
+export type { SetDomainIdleTimeoutArgs, SetDomainIdleTimeoutAccounts } from "./setDomainIdleTimeout"
+export { setDomainMaxResultAge } from "./setDomainMaxResultAge"
+export type { SetDomainMaxResultAgeArgs, SetDomainMaxResultAgeAccounts } from "./setDomainMaxResultAge"
+export { setDomainNullifierStore } from "./setDomainNullifierStore"
+export type { SetDomainNullifierStoreArgs, SetDomainNullifierStoreAccounts } from "./setDomainNullifierStore"
+export { setDomainOrigins } from "./setDomainOrigins"
+export type { SetDomainOriginsArgs, SetDomainOriginsAccounts } from "./setDomainOrigins"
+export { setDomainPaused } from "./setDomainPaused"
+export type { SetDomainPausedArgs, SetDomainPausedAccounts } from "./setDomainPaused"
+export { setDomainRateLimit } from "./setDomainRateLimit"
+export type { SetDomainRateLimitArgs, SetDomainRateLimitAccounts } from "./setDomainRateLimit"
+export { setDomainRole } from "./setDomainRole"
+export type { SetDomainRoleArgs, SetDomainRoleAccounts } from "./setDomainRole"
+export { setDomainSessionBounds } from "./setDomainSessionBounds"
+export type { SetDomainSessionBoundsArgs, SetDomainSessionBoundsAccounts } from "./setDomainSessionBounds"
+export { setDomainVerifiers } from "./setDomainVerifiers"
+export type { SetDomainVerifiersArgs, SetDomainVerifiersAccounts } from "./setDomainVerifiers"
+export { setGuardians } from "./setGuardians"
+export type { SetGuardiansArgs, SetGuardiansAccounts } from "./setGuardians"
+export { setNotificationChannel } from "./setNotificationChannel"
+export type { SetNotificationChannelArgs, SetNotificationChannelAccounts } from "./setNotificationChannel"
+export { setPricing } from "./setPricing"
+export type { SetPricingArgs, SetPricingAccounts } from "./setPricing"
+export { setReceiptRetention } from "./setReceiptRetention"
+export type { SetReceiptRetentionArgs, SetReceiptRetentionAccounts } from "./setReceiptRetention"
+export { setRelayerQuota } from "./setRelayerQuota"
+export type { SetRelayerQuotaArgs, SetRelayerQuotaAccounts } from "./setRelayerQuota"
+export { setSasBridge } from "./setSasBridge"
+export type { SetSasBridgeArgs, SetSasBridgeAccounts } from "./setSasBridge"
+export { setVcStatus } from "./setVcStatus"
+export type { SetVcStatusArgs, SetVcStatusAccounts } from "./setVcStatus"
+export { setVerifierGracePeriod } from "./setVerifierGracePeriod"
+export type { SetVerifierGracePeriodArgs, SetVerifierGracePeriodAccounts } from "./setVerifierGracePeriod"
+export { setVerifierThreshold } from "./setVerifierThreshold"
+export type { SetVerifierThresholdArgs, SetVerifierThresholdAccounts } from "./setVerifierThreshold"
+export { setVerifierWeight } from "./setVerifierWeight"
+export type { SetVerifierWeightArgs, SetVerifierWeightAccounts } from "./setVerifierWeight"
+export { storeAttestation } from "./storeAttestation"
+export type { StoreAttestationArgs, StoreAttestationAccounts } from "./storeAttestation"
+export { sweepTreasuryAta } from "./sweepTreasuryAta"
+export type { SweepTreasuryAtaArgs, SweepTreasuryAtaAccounts } from "./sweepTreasuryAta"
+export { topUpRent } from "./topUpRent"
+export type { TopUpRentAccounts } from "./topUpRent"
+export { touchSession } from "./touchSession"
+export type { TouchSessionAccounts } from "./touchSession"
+export { transferUsername } from "./transferUsername"
+export type { TransferUsernameAccounts } from "./transferUsername"
+export { tryCheckPermission } from "./tryCheckPermission"
+export type { TryCheckPermissionArgs, TryCheckPermissionAccounts } from "./tryCheckPermission"
+export { unbanNullifier } from "./unbanNullifier"
+export type { UnbanNullifierAccounts } from "./unbanNullifier"
+export { unlinkIdentityRoot } from "./unlinkIdentityRoot"
+export type { UnlinkIdentityRootArgs, UnlinkIdentityRootAccounts } from "./unlinkIdentityRoot"
+export { uploadProofChunk } from "./uploadProofChunk"
+export type { UploadProofChunkArgs, UploadProofChunkAccounts } from "./uploadProofChunk"
+export { validateAttestation } from "./validateAttestation"
+export type { ValidateAttestationAccounts } from "./validateAttestation"
+export { validateSessionDelegate } from "./validateSessionDelegate"
+export type { ValidateSessionDelegateArgs, ValidateSessionDelegateAccounts } from "./validateSessionDelegate"
+export { validateSessionToken } from "./validateSessionToken"
+export type { ValidateSessionTokenAccounts } from "./validateSessionToken"
+export { verifierHeartbeat } from "./verifierHeartbeat"
+export type { VerifierHeartbeatAccounts } from "./verifierHeartbeat"
+export { verifyAuth } from "./verifyAuth"
+export type { VerifyAuthArgs, VerifyAuthAccounts } from "./verifyAuth"
+export { verifyAuthBatch } from "./verifyAuthBatch"
+export type { VerifyAuthBatchArgs, VerifyAuthBatchAccounts } from "./verifyAuthBatch"
+export { verifyAuthCompressed } from "./verifyAuthCompressed"
+export type { VerifyAuthCompressedArgs, VerifyAuthCompressedAccounts } from "./verifyAuthCompressed"
+export { verifyAuthGroth16 } from "./verifyAuthGroth16"
+export type { VerifyAuthGroth16Args, VerifyAuthGroth16Accounts } from "./verifyAuthGroth16"
+export { verifyAuthSharded } from "./verifyAuthSharded"
+export type { VerifyAuthShardedArgs, VerifyAuthShardedAccounts } from "./verifyAuthSharded"
+export { verifyAuthV1 } from "./verifyAuthV1"
+export type { VerifyAuthV1Args, VerifyAuthV1Accounts } from "./verifyAuthV1"
+export { verifyAuthWithConsent } from "./verifyAuthWithConsent"
+export type { VerifyAuthWithConsentArgs, VerifyAuthWithConsentAccounts } from "./verifyAuthWithConsent"
+export { verifyAuthWithDevice } from "./verifyAuthWithDevice"
+export type { VerifyAuthWithDeviceArgs, VerifyAuthWithDeviceAccounts } from "./verifyAuthWithDevice"
+export { verifyAuthWithOrigin } from "./verifyAuthWithOrigin"
+export type { VerifyAuthWithOriginArgs, VerifyAuthWithOriginAccounts } from "./verifyAuthWithOrigin"
+export { writeHonkVk } from "./writeHonkVk"
+export type { WriteHonkVkArgs, WriteHonkVkAccounts } from "./writeHonkVk"
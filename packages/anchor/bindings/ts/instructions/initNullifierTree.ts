@@ -0,0 +1,57 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface InitNullifierTreeArgs {
+  maxDepth: number
+  maxBufferSize: number
+}
+
+export interface InitNullifierTreeAccounts {
+  domainConfig: PublicKey
+  nullifierTree: PublicKey
+  merkleTree: PublicKey
+  owner: PublicKey
+  compressionProgram: PublicKey
+  noopProgram: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u32("maxDepth"),
+  borsh.u32("maxBufferSize"),
+])
+
+export function initNullifierTree(
+  args: InitNullifierTreeArgs,
+  accounts: InitNullifierTreeAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.nullifierTree, isSigner: false, isWritable: true },
+    { pubkey: accounts.merkleTree, isSigner: false, isWritable: true },
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    { pubkey: accounts.compressionProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.noopProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([29, 120, 201, 176, 84, 252, 165, 230])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      maxDepth: args.maxDepth,
+      maxBufferSize: args.maxBufferSize,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
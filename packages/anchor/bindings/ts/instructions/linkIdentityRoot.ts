@@ -0,0 +1,52 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface LinkIdentityRootArgs {
+  linkageProofHash: Array<number>
+}
+
+export interface LinkIdentityRootAccounts {
+  nullifierAccount: PublicKey
+  identityRoot: PublicKey
+  owner: PublicKey
+  authority: PublicKey
+  instructionsSysvar: PublicKey
+  logoutEpoch: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.array(borsh.u8(), 32, "linkageProofHash"),
+])
+
+export function linkIdentityRoot(
+  args: LinkIdentityRootArgs,
+  accounts: LinkIdentityRootAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.nullifierAccount, isSigner: false, isWritable: false },
+    { pubkey: accounts.identityRoot, isSigner: false, isWritable: true },
+    { pubkey: accounts.owner, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+    { pubkey: accounts.instructionsSysvar, isSigner: false, isWritable: false },
+    { pubkey: accounts.logoutEpoch, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([196, 209, 242, 206, 82, 134, 178, 93])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      linkageProofHash: args.linkageProofHash,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,56 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface LogPermissionAccessArgs {
+  permissionUsed: types.PermissionKind
+  metadata: string
+  domain: Array<number>
+}
+
+export interface LogPermissionAccessAccounts {
+  permissionAccess: PublicKey
+  permissionGrant: PublicKey
+  nullifierBan: PublicKey
+  payer: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.Permission.layout("permissionUsed"),
+  borsh.str("metadata"),
+  borsh.array(borsh.u8(), 32, "domain"),
+])
+
+export function logPermissionAccess(
+  args: LogPermissionAccessArgs,
+  accounts: LogPermissionAccessAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.permissionAccess, isSigner: true, isWritable: true },
+    { pubkey: accounts.permissionGrant, isSigner: false, isWritable: false },
+    { pubkey: accounts.nullifierBan, isSigner: false, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([177, 118, 71, 15, 220, 48, 197, 199])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      permissionUsed: args.permissionUsed.toEncodable(),
+      metadata: args.metadata,
+      domain: args.domain,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,94 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface MigrateNullifierArgs {
+  result: types.SignedVerificationResult
+  newNullifier: Array<number>
+  expiresIn: BN
+}
+
+export interface MigrateNullifierAccounts {
+  oldSession: PublicKey
+  newSession: PublicKey
+  nullifierLink: PublicKey
+  proofRecord: PublicKey
+  resultReceipt: PublicKey
+  authority: PublicKey
+  payer: PublicKey
+  verifier?: PublicKey
+  instructionsSysvar: PublicKey
+  logoutEpoch: PublicKey
+  circuitRegistry: PublicKey
+  domainConfig: PublicKey
+  oldNullifierBan: PublicKey
+  newNullifierBan: PublicKey
+  verifierRegistry: PublicKey
+  secp256r1VerifierRegistry: PublicKey
+  assurancePolicy: PublicKey
+  payerRateLimit: PublicKey
+  protocolStats: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.SignedVerificationResult.layout("result"),
+  borsh.array(borsh.u8(), 32, "newNullifier"),
+  borsh.i64("expiresIn"),
+])
+
+export function migrateNullifier(
+  args: MigrateNullifierArgs,
+  accounts: MigrateNullifierAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.oldSession, isSigner: false, isWritable: true },
+    { pubkey: accounts.newSession, isSigner: false, isWritable: true },
+    { pubkey: accounts.nullifierLink, isSigner: false, isWritable: true },
+    { pubkey: accounts.proofRecord, isSigner: false, isWritable: true },
+    { pubkey: accounts.resultReceipt, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.payer, isSigner: false, isWritable: true },
+    {
+      pubkey: accounts.verifier || programId,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.instructionsSysvar, isSigner: false, isWritable: false },
+    { pubkey: accounts.logoutEpoch, isSigner: false, isWritable: false },
+    { pubkey: accounts.circuitRegistry, isSigner: false, isWritable: false },
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.oldNullifierBan, isSigner: false, isWritable: false },
+    { pubkey: accounts.newNullifierBan, isSigner: false, isWritable: false },
+    { pubkey: accounts.verifierRegistry, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.secp256r1VerifierRegistry,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.assurancePolicy, isSigner: false, isWritable: false },
+    { pubkey: accounts.payerRateLimit, isSigner: false, isWritable: true },
+    { pubkey: accounts.protocolStats, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([12, 32, 189, 131, 43, 104, 250, 234])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      result: types.SignedVerificationResult.toEncodable(args.result),
+      newNullifier: args.newNullifier,
+      expiresIn: args.expiresIn,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,42 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface MirrorAttestationAccounts {
+  attestation: PublicKey
+  sasBridge: PublicKey
+  sasSigner: PublicKey
+  credential: PublicKey
+  schema: PublicKey
+  sasAttestation: PublicKey
+  payer: PublicKey
+  sasProgram: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export function mirrorAttestation(
+  accounts: MirrorAttestationAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.attestation, isSigner: false, isWritable: false },
+    { pubkey: accounts.sasBridge, isSigner: false, isWritable: false },
+    { pubkey: accounts.sasSigner, isSigner: false, isWritable: false },
+    { pubkey: accounts.credential, isSigner: false, isWritable: false },
+    { pubkey: accounts.schema, isSigner: false, isWritable: false },
+    { pubkey: accounts.sasAttestation, isSigner: false, isWritable: true },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.sasProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([113, 163, 139, 46, 131, 101, 38, 223])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,54 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface OpenRevealEscrowArgs {
+  ciphertext: Uint8Array
+  keyCommitment: Array<number>
+  delaySecs: BN
+}
+
+export interface OpenRevealEscrowAccounts {
+  revealEscrow: PublicKey
+  permissionGrant: PublicKey
+  authority: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.vecU8("ciphertext"),
+  borsh.array(borsh.u8(), 32, "keyCommitment"),
+  borsh.i64("delaySecs"),
+])
+
+export function openRevealEscrow(
+  args: OpenRevealEscrowArgs,
+  accounts: OpenRevealEscrowAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.revealEscrow, isSigner: false, isWritable: true },
+    { pubkey: accounts.permissionGrant, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([197, 227, 72, 91, 79, 8, 216, 136])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      ciphertext: Buffer.from(args.ciphertext.buffer, args.ciphertext.byteOffset, args.ciphertext.length),
+      keyCommitment: args.keyCommitment,
+      delaySecs: args.delaySecs,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,70 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface OpenVerificationSessionArgs {
+  circuitId: number
+  nullifier: Array<number>
+  domain: Array<number>
+  proofHash: Array<number>
+  publicInputsCount: number
+}
+
+export interface OpenVerificationSessionAccounts {
+  verificationSession: PublicKey
+  nullifierAccount: PublicKey
+  authority: PublicKey
+  circuitRegistry: PublicKey
+  honkVk: PublicKey
+  domainConfig: PublicKey
+  nullifierBan: PublicKey
+  payerRateLimit: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u32("circuitId"),
+  borsh.array(borsh.u8(), 32, "nullifier"),
+  borsh.array(borsh.u8(), 32, "domain"),
+  borsh.array(borsh.u8(), 32, "proofHash"),
+  borsh.u16("publicInputsCount"),
+])
+
+export function openVerificationSession(
+  args: OpenVerificationSessionArgs,
+  accounts: OpenVerificationSessionAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.verificationSession, isSigner: false, isWritable: true },
+    { pubkey: accounts.nullifierAccount, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.circuitRegistry, isSigner: false, isWritable: false },
+    { pubkey: accounts.honkVk, isSigner: false, isWritable: false },
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.nullifierBan, isSigner: false, isWritable: false },
+    { pubkey: accounts.payerRateLimit, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([233, 132, 252, 194, 21, 120, 250, 249])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      circuitId: args.circuitId,
+      nullifier: args.nullifier,
+      domain: args.domain,
+      proofHash: args.proofHash,
+      publicInputsCount: args.publicInputsCount,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
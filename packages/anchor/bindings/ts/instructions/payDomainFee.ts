@@ -0,0 +1,64 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface PayDomainFeeArgs {
+  maxAmount: BN
+}
+
+export interface PayDomainFeeAccounts {
+  config: PublicKey
+  feeSharePolicy: PublicKey
+  domainConfig: PublicKey
+  treasury: PublicKey
+  domainFeeVault: PublicKey
+  mint: PublicKey
+  treasuryAta: PublicKey
+  domainFeeVaultAta: PublicKey
+  payerTokenAccount: PublicKey
+  priceUpdate: PublicKey
+  payer: PublicKey
+  tokenProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("maxAmount"),
+])
+
+export function payDomainFee(
+  args: PayDomainFeeArgs,
+  accounts: PayDomainFeeAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.feeSharePolicy, isSigner: false, isWritable: false },
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.treasury, isSigner: false, isWritable: false },
+    { pubkey: accounts.domainFeeVault, isSigner: false, isWritable: false },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
+    { pubkey: accounts.treasuryAta, isSigner: false, isWritable: true },
+    { pubkey: accounts.domainFeeVaultAta, isSigner: false, isWritable: true },
+    { pubkey: accounts.payerTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.priceUpdate, isSigner: false, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([73, 250, 237, 122, 200, 150, 51, 118])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      maxAmount: args.maxAmount,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
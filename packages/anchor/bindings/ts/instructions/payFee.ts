@@ -0,0 +1,56 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface PayFeeArgs {
+  maxAmount: BN
+}
+
+export interface PayFeeAccounts {
+  config: PublicKey
+  treasury: PublicKey
+  mint: PublicKey
+  treasuryAta: PublicKey
+  payerTokenAccount: PublicKey
+  priceUpdate: PublicKey
+  payer: PublicKey
+  tokenProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("maxAmount"),
+])
+
+export function payFee(
+  args: PayFeeArgs,
+  accounts: PayFeeAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.treasury, isSigner: false, isWritable: false },
+    { pubkey: accounts.mint, isSigner: false, isWritable: false },
+    { pubkey: accounts.treasuryAta, isSigner: false, isWritable: true },
+    { pubkey: accounts.payerTokenAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.priceUpdate, isSigner: false, isWritable: false },
+    { pubkey: accounts.payer, isSigner: true, isWritable: false },
+    { pubkey: accounts.tokenProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([98, 25, 152, 0, 46, 9, 186, 61])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      maxAmount: args.maxAmount,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
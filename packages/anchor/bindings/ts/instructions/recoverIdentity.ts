@@ -0,0 +1,85 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RecoverIdentityArgs {
+  result: types.SignedVerificationResult
+  nullifier: Array<number>
+  newNullifier: Array<number>
+  domain: Array<number>
+}
+
+export interface RecoverIdentityAccounts {
+  guardianSet: PublicKey
+  oldNullifierBan: PublicKey
+  newNullifierBan: PublicKey
+  proofRecord: PublicKey
+  resultReceipt: PublicKey
+  newAuthority: PublicKey
+  verifier?: PublicKey
+  instructionsSysvar: PublicKey
+  circuitRegistry: PublicKey
+  domainConfig: PublicKey
+  verifierRegistry: PublicKey
+  secp256r1VerifierRegistry: PublicKey
+  assurancePolicy: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.SignedVerificationResult.layout("result"),
+  borsh.array(borsh.u8(), 32, "nullifier"),
+  borsh.array(borsh.u8(), 32, "newNullifier"),
+  borsh.array(borsh.u8(), 32, "domain"),
+])
+
+export function recoverIdentity(
+  args: RecoverIdentityArgs,
+  accounts: RecoverIdentityAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.guardianSet, isSigner: false, isWritable: true },
+    { pubkey: accounts.oldNullifierBan, isSigner: false, isWritable: false },
+    { pubkey: accounts.newNullifierBan, isSigner: false, isWritable: false },
+    { pubkey: accounts.proofRecord, isSigner: false, isWritable: true },
+    { pubkey: accounts.resultReceipt, isSigner: false, isWritable: true },
+    { pubkey: accounts.newAuthority, isSigner: true, isWritable: true },
+    {
+      pubkey: accounts.verifier || programId,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.instructionsSysvar, isSigner: false, isWritable: false },
+    { pubkey: accounts.circuitRegistry, isSigner: false, isWritable: false },
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.verifierRegistry, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.secp256r1VerifierRegistry,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.assurancePolicy, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([236, 190, 126, 192, 28, 163, 102, 116])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      result: types.SignedVerificationResult.toEncodable(args.result),
+      nullifier: args.nullifier,
+      newNullifier: args.newNullifier,
+      domain: args.domain,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,54 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RegisterAttestorArgs {
+  issuer: PublicKey
+  nameHash: Array<number>
+  claimMask: number
+}
+
+export interface RegisterAttestorAccounts {
+  config: PublicKey
+  attestor: PublicKey
+  authority: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.publicKey("issuer"),
+  borsh.array(borsh.u8(), 32, "nameHash"),
+  borsh.u8("claimMask"),
+])
+
+export function registerAttestor(
+  args: RegisterAttestorArgs,
+  accounts: RegisterAttestorAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.attestor, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([68, 201, 86, 244, 51, 113, 15, 94])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      issuer: args.issuer,
+      nameHash: args.nameHash,
+      claimMask: args.claimMask,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,64 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RegisterDomainArgs {
+  domain: Array<number>
+  verifier: PublicKey | null
+  defaultSessionSecs: BN
+  maxResultAgeSecs: number
+  maxPermissions: number
+}
+
+export interface RegisterDomainAccounts {
+  domainConfig: PublicKey
+  owner: PublicKey
+  snsNameAccount?: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.array(borsh.u8(), 32, "domain"),
+  borsh.option(borsh.publicKey(), "verifier"),
+  borsh.i64("defaultSessionSecs"),
+  borsh.u32("maxResultAgeSecs"),
+  borsh.u8("maxPermissions"),
+])
+
+export function registerDomain(
+  args: RegisterDomainArgs,
+  accounts: RegisterDomainAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.owner, isSigner: true, isWritable: true },
+    {
+      pubkey: accounts.snsNameAccount || programId,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([236, 7, 208, 151, 173, 149, 73, 104])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      domain: args.domain,
+      verifier: args.verifier,
+      defaultSessionSecs: args.defaultSessionSecs,
+      maxResultAgeSecs: args.maxResultAgeSecs,
+      maxPermissions: args.maxPermissions,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
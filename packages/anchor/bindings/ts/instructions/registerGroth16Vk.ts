@@ -0,0 +1,65 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RegisterGroth16VkArgs {
+  circuitId: number
+  alphaG1: Array<number>
+  betaG2: Array<number>
+  gammaG2: Array<number>
+  deltaG2: Array<number>
+  ic: Array<Array<number>>
+}
+
+export interface RegisterGroth16VkAccounts {
+  config: PublicKey
+  circuitRegistry: PublicKey
+  groth16Vk: PublicKey
+  authority: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u32("circuitId"),
+  borsh.array(borsh.u8(), 64, "alphaG1"),
+  borsh.array(borsh.u8(), 128, "betaG2"),
+  borsh.array(borsh.u8(), 128, "gammaG2"),
+  borsh.array(borsh.u8(), 128, "deltaG2"),
+  borsh.vec(borsh.array(borsh.u8(), 64), "ic"),
+])
+
+export function registerGroth16Vk(
+  args: RegisterGroth16VkArgs,
+  accounts: RegisterGroth16VkAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.circuitRegistry, isSigner: false, isWritable: false },
+    { pubkey: accounts.groth16Vk, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([225, 22, 110, 141, 26, 212, 140, 26])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      circuitId: args.circuitId,
+      alphaG1: args.alphaG1,
+      betaG2: args.betaG2,
+      gammaG2: args.gammaG2,
+      deltaG2: args.deltaG2,
+      ic: args.ic,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
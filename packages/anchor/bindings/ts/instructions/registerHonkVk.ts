@@ -0,0 +1,55 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RegisterHonkVkArgs {
+  circuitId: number
+  logCircuitSize: number
+  publicInputsSize: number
+  pubInputsOffset: number
+}
+
+export interface RegisterHonkVkAccounts {
+  config: PublicKey
+  circuitRegistry: PublicKey
+  honkVk: PublicKey
+  authority: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u32("circuitId"),
+  borsh.u8("logCircuitSize"),
+  borsh.u32("publicInputsSize"),
+  borsh.u32("pubInputsOffset"),
+])
+
+export function registerHonkVk(
+  args: RegisterHonkVkArgs,
+  accounts: RegisterHonkVkAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.circuitRegistry, isSigner: false, isWritable: false },
+    { pubkey: accounts.honkVk, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([40, 241, 184, 223, 230, 197, 123, 219])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      circuitId: args.circuitId,
+      logCircuitSize: args.logCircuitSize,
+      publicInputsSize: args.publicInputsSize,
+      pubInputsOffset: args.pubInputsOffset,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,50 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RemoveSecp256r1VerifierArgs {
+  verifier: Array<number>
+}
+
+export interface RemoveSecp256r1VerifierAccounts {
+  config: PublicKey
+  secp256r1VerifierRegistry: PublicKey
+  authority: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.array(borsh.u8(), 33, "verifier"),
+])
+
+export function removeSecp256r1Verifier(
+  args: RemoveSecp256r1VerifierArgs,
+  accounts: RemoveSecp256r1VerifierAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.secp256r1VerifierRegistry,
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([136, 95, 156, 125, 233, 76, 231, 62])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      verifier: args.verifier,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
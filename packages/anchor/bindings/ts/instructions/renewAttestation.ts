@@ -0,0 +1,79 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RenewAttestationArgs {
+  result: types.SignedVerificationResult
+  expiresIn: BN
+}
+
+export interface RenewAttestationAccounts {
+  attestation: PublicKey
+  resultReceipt: PublicKey
+  authority: PublicKey
+  verifier?: PublicKey
+  attestor?: PublicKey
+  instructionsSysvar: PublicKey
+  circuitRegistry: PublicKey
+  domainConfig: PublicKey
+  verifierRegistry: PublicKey
+  secp256r1VerifierRegistry: PublicKey
+  assurancePolicy: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.SignedVerificationResult.layout("result"),
+  borsh.i64("expiresIn"),
+])
+
+export function renewAttestation(
+  args: RenewAttestationArgs,
+  accounts: RenewAttestationAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.attestation, isSigner: false, isWritable: true },
+    { pubkey: accounts.resultReceipt, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    {
+      pubkey: accounts.verifier || programId,
+      isSigner: false,
+      isWritable: false,
+    },
+    {
+      pubkey: accounts.attestor || programId,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.instructionsSysvar, isSigner: false, isWritable: false },
+    { pubkey: accounts.circuitRegistry, isSigner: false, isWritable: false },
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.verifierRegistry, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.secp256r1VerifierRegistry,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.assurancePolicy, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([209, 173, 109, 25, 255, 94, 203, 222])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      result: types.SignedVerificationResult.toEncodable(args.result),
+      expiresIn: args.expiresIn,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,60 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RestoreAttestationArgs {
+  nullifier: Array<number>
+  provider: PublicKey
+  score: number
+  issuedAt: BN
+  expiresAt: BN
+}
+
+export interface RestoreAttestationAccounts {
+  archive: PublicKey
+  attestation: PublicKey
+  payer: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.array(borsh.u8(), 32, "nullifier"),
+  borsh.publicKey("provider"),
+  borsh.u16("score"),
+  borsh.i64("issuedAt"),
+  borsh.i64("expiresAt"),
+])
+
+export function restoreAttestation(
+  args: RestoreAttestationArgs,
+  accounts: RestoreAttestationAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.archive, isSigner: false, isWritable: true },
+    { pubkey: accounts.attestation, isSigner: false, isWritable: true },
+    { pubkey: accounts.payer, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([96, 104, 154, 1, 224, 82, 131, 191])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      nullifier: args.nullifier,
+      provider: args.provider,
+      score: args.score,
+      issuedAt: args.issuedAt,
+      expiresAt: args.expiresAt,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,49 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface RotateVerifierKeyArgs {
+  oldKey: PublicKey
+  newKey: PublicKey
+}
+
+export interface RotateVerifierKeyAccounts {
+  config: PublicKey
+  verifierRegistry: PublicKey
+  authority: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.publicKey("oldKey"),
+  borsh.publicKey("newKey"),
+])
+
+export function rotateVerifierKey(
+  args: RotateVerifierKeyArgs,
+  accounts: RotateVerifierKeyAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.config, isSigner: false, isWritable: false },
+    { pubkey: accounts.verifierRegistry, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([171, 76, 76, 25, 214, 63, 155, 154])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      oldKey: args.oldKey,
+      newKey: args.newKey,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
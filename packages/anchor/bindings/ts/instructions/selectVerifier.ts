@@ -0,0 +1,40 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SelectVerifierArgs {
+  nullifier: Array<number>
+}
+
+export interface SelectVerifierAccounts {
+  verifierRegistry: PublicKey
+  verifierWeights: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.array(borsh.u8(), 32, "nullifier"),
+])
+
+export function selectVerifier(
+  args: SelectVerifierArgs,
+  accounts: SelectVerifierAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.verifierRegistry, isSigner: false, isWritable: false },
+    { pubkey: accounts.verifierWeights, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([198, 196, 230, 227, 43, 155, 160, 14])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      nullifier: args.nullifier,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
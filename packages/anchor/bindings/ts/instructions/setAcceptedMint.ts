@@ -0,0 +1,50 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetAcceptedMintArgs {
+  mint: PublicKey
+  priceFeedId: Array<number>
+  accepted: boolean
+}
+
+export interface SetAcceptedMintAccounts {
+  config: PublicKey
+  authority: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.publicKey("mint"),
+  borsh.array(borsh.u8(), 32, "priceFeedId"),
+  borsh.bool("accepted"),
+])
+
+export function setAcceptedMint(
+  args: SetAcceptedMintArgs,
+  accounts: SetAcceptedMintAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.config, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([181, 250, 237, 63, 250, 188, 71, 182])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      mint: args.mint,
+      priceFeedId: args.priceFeedId,
+      accepted: args.accepted,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
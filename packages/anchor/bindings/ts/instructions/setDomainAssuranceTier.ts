@@ -0,0 +1,46 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetDomainAssuranceTierArgs {
+  assuranceTier: number
+}
+
+export interface SetDomainAssuranceTierAccounts {
+  domainConfig: PublicKey
+  assurancePolicy: PublicKey
+  owner: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u8("assuranceTier"),
+])
+
+export function setDomainAssuranceTier(
+  args: SetDomainAssuranceTierArgs,
+  accounts: SetDomainAssuranceTierAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.assurancePolicy, isSigner: false, isWritable: false },
+    { pubkey: accounts.owner, isSigner: true, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([201, 137, 7, 189, 42, 128, 61, 159])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      assuranceTier: args.assuranceTier,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,44 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetDomainExpiryUnitArgs {
+  sessionExpiryUnit: types.ExpiryUnitKind
+}
+
+export interface SetDomainExpiryUnitAccounts {
+  domainConfig: PublicKey
+  owner: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.ExpiryUnit.layout("sessionExpiryUnit"),
+])
+
+export function setDomainExpiryUnit(
+  args: SetDomainExpiryUnitArgs,
+  accounts: SetDomainExpiryUnitAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.owner, isSigner: true, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([69, 221, 87, 130, 74, 60, 194, 137])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      sessionExpiryUnit: args.sessionExpiryUnit.toEncodable(),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
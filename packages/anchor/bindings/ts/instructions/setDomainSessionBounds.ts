@@ -0,0 +1,47 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetDomainSessionBoundsArgs {
+  minSessionSecs: BN
+  maxSessionSecs: BN
+}
+
+export interface SetDomainSessionBoundsAccounts {
+  domainConfig: PublicKey
+  owner: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.i64("minSessionSecs"),
+  borsh.i64("maxSessionSecs"),
+])
+
+export function setDomainSessionBounds(
+  args: SetDomainSessionBoundsArgs,
+  accounts: SetDomainSessionBoundsAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: true },
+    { pubkey: accounts.owner, isSigner: true, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([198, 109, 64, 39, 240, 88, 2, 233])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      minSessionSecs: args.minSessionSecs,
+      maxSessionSecs: args.maxSessionSecs,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
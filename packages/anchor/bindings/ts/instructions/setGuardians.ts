@@ -0,0 +1,55 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetGuardiansArgs {
+  guardians: Array<PublicKey>
+  threshold: number
+}
+
+export interface SetGuardiansAccounts {
+  nullifierAccount: PublicKey
+  guardianSet: PublicKey
+  authority: PublicKey
+  instructionsSysvar: PublicKey
+  logoutEpoch: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.vec(borsh.publicKey(), "guardians"),
+  borsh.u8("threshold"),
+])
+
+export function setGuardians(
+  args: SetGuardiansArgs,
+  accounts: SetGuardiansAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.nullifierAccount, isSigner: false, isWritable: false },
+    { pubkey: accounts.guardianSet, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.instructionsSysvar, isSigner: false, isWritable: false },
+    { pubkey: accounts.logoutEpoch, isSigner: false, isWritable: false },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([166, 69, 140, 183, 157, 169, 253, 40])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      guardians: args.guardians,
+      threshold: args.threshold,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,51 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetNotificationChannelArgs {
+  channelCommitment: Array<number>
+  encryptedToken: Uint8Array
+}
+
+export interface SetNotificationChannelAccounts {
+  notificationChannel: PublicKey
+  permissionGrant: PublicKey
+  authority: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.array(borsh.u8(), 32, "channelCommitment"),
+  borsh.vecU8("encryptedToken"),
+])
+
+export function setNotificationChannel(
+  args: SetNotificationChannelArgs,
+  accounts: SetNotificationChannelAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.notificationChannel, isSigner: false, isWritable: true },
+    { pubkey: accounts.permissionGrant, isSigner: false, isWritable: false },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([72, 36, 140, 134, 144, 174, 75, 182])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      channelCommitment: args.channelCommitment,
+      encryptedToken: Buffer.from(args.encryptedToken.buffer, args.encryptedToken.byteOffset, args.encryptedToken.length),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
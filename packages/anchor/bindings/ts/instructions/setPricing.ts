@@ -0,0 +1,50 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface SetPricingArgs {
+  feeUsdMicros: BN
+  maxPriceAgeSecs: BN
+  maxPriceConfBps: number
+}
+
+export interface SetPricingAccounts {
+  config: PublicKey
+  authority: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u64("feeUsdMicros"),
+  borsh.u64("maxPriceAgeSecs"),
+  borsh.u16("maxPriceConfBps"),
+])
+
+export function setPricing(
+  args: SetPricingArgs,
+  accounts: SetPricingAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.config, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([52, 200, 157, 192, 18, 134, 57, 123])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      feeUsdMicros: args.feeUsdMicros,
+      maxPriceAgeSecs: args.maxPriceAgeSecs,
+      maxPriceConfBps: args.maxPriceConfBps,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,87 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface StoreAttestationArgs {
+  result: types.SignedVerificationResult
+  nullifier: Array<number>
+  domain: Array<number>
+  expiresIn: BN
+}
+
+export interface StoreAttestationAccounts {
+  attestation: PublicKey
+  resultReceipt: PublicKey
+  authority: PublicKey
+  verifier?: PublicKey
+  attestor?: PublicKey
+  instructionsSysvar: PublicKey
+  circuitRegistry: PublicKey
+  domainConfig: PublicKey
+  verifierRegistry: PublicKey
+  secp256r1VerifierRegistry: PublicKey
+  assurancePolicy: PublicKey
+  protocolStats: PublicKey
+  systemProgram: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.SignedVerificationResult.layout("result"),
+  borsh.array(borsh.u8(), 32, "nullifier"),
+  borsh.array(borsh.u8(), 32, "domain"),
+  borsh.i64("expiresIn"),
+])
+
+export function storeAttestation(
+  args: StoreAttestationArgs,
+  accounts: StoreAttestationAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.attestation, isSigner: false, isWritable: true },
+    { pubkey: accounts.resultReceipt, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    {
+      pubkey: accounts.verifier || programId,
+      isSigner: false,
+      isWritable: false,
+    },
+    {
+      pubkey: accounts.attestor || programId,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.instructionsSysvar, isSigner: false, isWritable: false },
+    { pubkey: accounts.circuitRegistry, isSigner: false, isWritable: false },
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.verifierRegistry, isSigner: false, isWritable: false },
+    {
+      pubkey: accounts.secp256r1VerifierRegistry,
+      isSigner: false,
+      isWritable: false,
+    },
+    { pubkey: accounts.assurancePolicy, isSigner: false, isWritable: false },
+    { pubkey: accounts.protocolStats, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([162, 94, 215, 216, 225, 16, 16, 179])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      result: types.SignedVerificationResult.toEncodable(args.result),
+      nullifier: args.nullifier,
+      domain: args.domain,
+      expiresIn: args.expiresIn,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
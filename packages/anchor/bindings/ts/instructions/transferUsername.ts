@@ -0,0 +1,36 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface TransferUsernameAccounts {
+  username: PublicKey
+  authority: PublicKey
+  newSession: PublicKey
+  newAuthority: PublicKey
+  instructionsSysvar: PublicKey
+  logoutEpoch: PublicKey
+  eventAuthority: PublicKey
+  program: PublicKey
+}
+
+export function transferUsername(
+  accounts: TransferUsernameAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.username, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: false, isWritable: false },
+    { pubkey: accounts.newSession, isSigner: false, isWritable: false },
+    { pubkey: accounts.newAuthority, isSigner: true, isWritable: false },
+    { pubkey: accounts.instructionsSysvar, isSigner: false, isWritable: false },
+    { pubkey: accounts.logoutEpoch, isSigner: false, isWritable: false },
+    { pubkey: accounts.eventAuthority, isSigner: false, isWritable: false },
+    { pubkey: accounts.program, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([249, 219, 120, 168, 30, 177, 38, 41])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,45 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface UploadProofChunkArgs {
+  offset: number
+  chunk: Uint8Array
+}
+
+export interface UploadProofChunkAccounts {
+  verificationSession: PublicKey
+  authority: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u32("offset"),
+  borsh.vecU8("chunk"),
+])
+
+export function uploadProofChunk(
+  args: UploadProofChunkArgs,
+  accounts: UploadProofChunkAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.verificationSession, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([60, 215, 88, 47, 168, 107, 123, 150])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      offset: args.offset,
+      chunk: Buffer.from(args.chunk.buffer, args.chunk.byteOffset, args.chunk.length),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,46 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface ValidateSessionDelegateArgs {
+  permission: types.PermissionKind
+}
+
+export interface ValidateSessionDelegateAccounts {
+  sessionDelegate: PublicKey
+  nullifierAccount: PublicKey
+  delegate: PublicKey
+  logoutEpoch: PublicKey
+  nullifierBan: PublicKey
+}
+
+export const layout = borsh.struct([
+  types.Permission.layout("permission"),
+])
+
+export function validateSessionDelegate(
+  args: ValidateSessionDelegateArgs,
+  accounts: ValidateSessionDelegateAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.sessionDelegate, isSigner: false, isWritable: false },
+    { pubkey: accounts.nullifierAccount, isSigner: false, isWritable: false },
+    { pubkey: accounts.delegate, isSigner: true, isWritable: false },
+    { pubkey: accounts.logoutEpoch, isSigner: false, isWritable: false },
+    { pubkey: accounts.nullifierBan, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([117, 19, 30, 120, 82, 102, 132, 229])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      permission: args.permission.toEncodable(),
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,24 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface VerifierHeartbeatAccounts {
+  verifierWeights: PublicKey
+  verifier: PublicKey
+}
+
+export function verifierHeartbeat(
+  accounts: VerifierHeartbeatAccounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.verifierWeights, isSigner: false, isWritable: true },
+    { pubkey: accounts.verifier, isSigner: true, isWritable: false },
+  ]
+  const identifier = Buffer.from([25, 238, 221, 14, 250, 148, 0, 140])
+  const data = identifier
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
@@ -0,0 +1,66 @@
+import { TransactionInstruction, PublicKey, AccountMeta } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import { PROGRAM_ID } from "../programId"
+
+export interface VerifyAuthGroth16Args {
+  circuitId: number
+  proof: Array<number>
+  publicInputs: Array<Array<number>>
+  nullifier: Array<number>
+  domain: Array<number>
+}
+
+export interface VerifyAuthGroth16Accounts {
+  nullifierAccount: PublicKey
+  authority: PublicKey
+  circuitRegistry: PublicKey
+  groth16Vk: PublicKey
+  domainConfig: PublicKey
+  nullifierBan: PublicKey
+  payerRateLimit: PublicKey
+  protocolStats: PublicKey
+  systemProgram: PublicKey
+}
+
+export const layout = borsh.struct([
+  borsh.u32("circuitId"),
+  borsh.array(borsh.u8(), 256, "proof"),
+  borsh.vec(borsh.array(borsh.u8(), 32), "publicInputs"),
+  borsh.array(borsh.u8(), 32, "nullifier"),
+  borsh.array(borsh.u8(), 32, "domain"),
+])
+
+export function verifyAuthGroth16(
+  args: VerifyAuthGroth16Args,
+  accounts: VerifyAuthGroth16Accounts,
+  programId: PublicKey = PROGRAM_ID
+) {
+  const keys: Array<AccountMeta> = [
+    { pubkey: accounts.nullifierAccount, isSigner: false, isWritable: true },
+    { pubkey: accounts.authority, isSigner: true, isWritable: true },
+    { pubkey: accounts.circuitRegistry, isSigner: false, isWritable: false },
+    { pubkey: accounts.groth16Vk, isSigner: false, isWritable: false },
+    { pubkey: accounts.domainConfig, isSigner: false, isWritable: false },
+    { pubkey: accounts.nullifierBan, isSigner: false, isWritable: false },
+    { pubkey: accounts.payerRateLimit, isSigner: false, isWritable: true },
+    { pubkey: accounts.protocolStats, isSigner: false, isWritable: true },
+    { pubkey: accounts.systemProgram, isSigner: false, isWritable: false },
+  ]
+  const identifier = Buffer.from([18, 167, 174, 76, 18, 186, 243, 2])
+  const buffer = Buffer.alloc(1000)
+  const len = layout.encode(
+    {
+      circuitId: args.circuitId,
+      proof: args.proof,
+      publicInputs: args.publicInputs,
+      nullifier: args.nullifier,
+      domain: args.domain,
+    },
+    buffer
+  )
+  const data = Buffer.concat([identifier, buffer]).slice(0, 8 + len)
+  const ix = new TransactionInstruction({ keys, programId, data })
+  return ix
+}
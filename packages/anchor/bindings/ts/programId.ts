@@ -0,0 +1,9 @@
+import { PublicKey } from "@solana/web3.js"
+
+// Program ID passed with the cli --program-id flag when running the code generator. Do not edit, it will get overwritten.
+export const PROGRAM_ID_CLI = new PublicKey(
+  "H6apEGZAw23AKUeqCX41wkDv2LVwX3Ec8oYPip7k3xzA"
+)
+
+// This constant will not get overwritten on subsequent code generations and it's safe to modify it's value.
+export const PROGRAM_ID: PublicKey = PROGRAM_ID_CLI
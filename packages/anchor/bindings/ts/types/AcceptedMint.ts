@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AcceptedMintFields {
+  mint: PublicKey
+  priceFeedId: Array<number>
+}
+
+export interface AcceptedMintJSON {
+  mint: string
+  priceFeedId: Array<number>
+}
+
+export class AcceptedMint {
+  readonly mint: PublicKey
+  readonly priceFeedId: Array<number>
+
+  constructor(fields: AcceptedMintFields) {
+    this.mint = fields.mint
+    this.priceFeedId = fields.priceFeedId
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("mint"),
+        borsh.array(borsh.u8(), 32, "priceFeedId"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AcceptedMint({
+      mint: obj.mint,
+      priceFeedId: obj.priceFeedId,
+    })
+  }
+
+  static toEncodable(fields: AcceptedMintFields) {
+    return {
+      mint: fields.mint,
+      priceFeedId: fields.priceFeedId,
+    }
+  }
+
+  toJSON(): AcceptedMintJSON {
+    return {
+      mint: this.mint.toString(),
+      priceFeedId: this.priceFeedId,
+    }
+  }
+
+  static fromJSON(obj: AcceptedMintJSON): AcceptedMint {
+    return new AcceptedMint({
+      mint: new PublicKey(obj.mint),
+      priceFeedId: obj.priceFeedId,
+    })
+  }
+
+  toEncodable() {
+    return AcceptedMint.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AcceptedMintUpdatedEventFields {
+  mint: PublicKey
+  priceFeedId: Array<number>
+  accepted: boolean
+}
+
+export interface AcceptedMintUpdatedEventJSON {
+  mint: string
+  priceFeedId: Array<number>
+  accepted: boolean
+}
+
+export class AcceptedMintUpdatedEvent {
+  readonly mint: PublicKey
+  readonly priceFeedId: Array<number>
+  readonly accepted: boolean
+
+  constructor(fields: AcceptedMintUpdatedEventFields) {
+    this.mint = fields.mint
+    this.priceFeedId = fields.priceFeedId
+    this.accepted = fields.accepted
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("mint"),
+        borsh.array(borsh.u8(), 32, "priceFeedId"),
+        borsh.bool("accepted"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AcceptedMintUpdatedEvent({
+      mint: obj.mint,
+      priceFeedId: obj.priceFeedId,
+      accepted: obj.accepted,
+    })
+  }
+
+  static toEncodable(fields: AcceptedMintUpdatedEventFields) {
+    return {
+      mint: fields.mint,
+      priceFeedId: fields.priceFeedId,
+      accepted: fields.accepted,
+    }
+  }
+
+  toJSON(): AcceptedMintUpdatedEventJSON {
+    return {
+      mint: this.mint.toString(),
+      priceFeedId: this.priceFeedId,
+      accepted: this.accepted,
+    }
+  }
+
+  static fromJSON(obj: AcceptedMintUpdatedEventJSON): AcceptedMintUpdatedEvent {
+    return new AcceptedMintUpdatedEvent({
+      mint: new PublicKey(obj.mint),
+      priceFeedId: obj.priceFeedId,
+      accepted: obj.accepted,
+    })
+  }
+
+  toEncodable() {
+    return AcceptedMintUpdatedEvent.toEncodable(this)
+  }
+}
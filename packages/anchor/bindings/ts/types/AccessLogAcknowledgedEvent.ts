@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AccessLogAcknowledgedEventFields {
+  nullifier: Array<number>
+  auditor: PublicKey
+  permissionAccess: PublicKey
+  acknowledgedAt: BN
+}
+
+export interface AccessLogAcknowledgedEventJSON {
+  nullifier: Array<number>
+  auditor: string
+  permissionAccess: string
+  acknowledgedAt: string
+}
+
+export class AccessLogAcknowledgedEvent {
+  readonly nullifier: Array<number>
+  readonly auditor: PublicKey
+  readonly permissionAccess: PublicKey
+  readonly acknowledgedAt: BN
+
+  constructor(fields: AccessLogAcknowledgedEventFields) {
+    this.nullifier = fields.nullifier
+    this.auditor = fields.auditor
+    this.permissionAccess = fields.permissionAccess
+    this.acknowledgedAt = fields.acknowledgedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("auditor"),
+        borsh.publicKey("permissionAccess"),
+        borsh.i64("acknowledgedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AccessLogAcknowledgedEvent({
+      nullifier: obj.nullifier,
+      auditor: obj.auditor,
+      permissionAccess: obj.permissionAccess,
+      acknowledgedAt: obj.acknowledgedAt,
+    })
+  }
+
+  static toEncodable(fields: AccessLogAcknowledgedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      auditor: fields.auditor,
+      permissionAccess: fields.permissionAccess,
+      acknowledgedAt: fields.acknowledgedAt,
+    }
+  }
+
+  toJSON(): AccessLogAcknowledgedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      auditor: this.auditor.toString(),
+      permissionAccess: this.permissionAccess.toString(),
+      acknowledgedAt: this.acknowledgedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: AccessLogAcknowledgedEventJSON): AccessLogAcknowledgedEvent {
+    return new AccessLogAcknowledgedEvent({
+      nullifier: obj.nullifier,
+      auditor: new PublicKey(obj.auditor),
+      permissionAccess: new PublicKey(obj.permissionAccess),
+      acknowledgedAt: new BN(obj.acknowledgedAt),
+    })
+  }
+
+  toEncodable() {
+    return AccessLogAcknowledgedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,102 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AccessLogsCompactedEventFields {
+  permissionGrant: PublicKey
+  accessLogs: Array<PublicKey>
+  batchRoot: Array<number>
+  summaryRoot: Array<number>
+  compactedCount: BN
+  reward: BN
+}
+
+export interface AccessLogsCompactedEventJSON {
+  permissionGrant: string
+  accessLogs: Array<string>
+  batchRoot: Array<number>
+  summaryRoot: Array<number>
+  compactedCount: string
+  reward: string
+}
+
+export class AccessLogsCompactedEvent {
+  readonly permissionGrant: PublicKey
+  readonly accessLogs: Array<PublicKey>
+  readonly batchRoot: Array<number>
+  readonly summaryRoot: Array<number>
+  readonly compactedCount: BN
+  readonly reward: BN
+
+  constructor(fields: AccessLogsCompactedEventFields) {
+    this.permissionGrant = fields.permissionGrant
+    this.accessLogs = fields.accessLogs
+    this.batchRoot = fields.batchRoot
+    this.summaryRoot = fields.summaryRoot
+    this.compactedCount = fields.compactedCount
+    this.reward = fields.reward
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("permissionGrant"),
+        borsh.vec(borsh.publicKey(), "accessLogs"),
+        borsh.array(borsh.u8(), 32, "batchRoot"),
+        borsh.array(borsh.u8(), 32, "summaryRoot"),
+        borsh.u64("compactedCount"),
+        borsh.u64("reward"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AccessLogsCompactedEvent({
+      permissionGrant: obj.permissionGrant,
+      accessLogs: obj.accessLogs,
+      batchRoot: obj.batchRoot,
+      summaryRoot: obj.summaryRoot,
+      compactedCount: obj.compactedCount,
+      reward: obj.reward,
+    })
+  }
+
+  static toEncodable(fields: AccessLogsCompactedEventFields) {
+    return {
+      permissionGrant: fields.permissionGrant,
+      accessLogs: fields.accessLogs,
+      batchRoot: fields.batchRoot,
+      summaryRoot: fields.summaryRoot,
+      compactedCount: fields.compactedCount,
+      reward: fields.reward,
+    }
+  }
+
+  toJSON(): AccessLogsCompactedEventJSON {
+    return {
+      permissionGrant: this.permissionGrant.toString(),
+      accessLogs: this.accessLogs.map((item) => item.toString()),
+      batchRoot: this.batchRoot,
+      summaryRoot: this.summaryRoot,
+      compactedCount: this.compactedCount.toString(),
+      reward: this.reward.toString(),
+    }
+  }
+
+  static fromJSON(obj: AccessLogsCompactedEventJSON): AccessLogsCompactedEvent {
+    return new AccessLogsCompactedEvent({
+      permissionGrant: new PublicKey(obj.permissionGrant),
+      accessLogs: obj.accessLogs.map((item) => new PublicKey(item)),
+      batchRoot: obj.batchRoot,
+      summaryRoot: obj.summaryRoot,
+      compactedCount: new BN(obj.compactedCount),
+      reward: new BN(obj.reward),
+    })
+  }
+
+  toEncodable() {
+    return AccessLogsCompactedEvent.toEncodable(this)
+  }
+}
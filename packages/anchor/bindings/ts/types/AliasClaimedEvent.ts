@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AliasClaimedEventFields {
+  handleHash: Array<number>
+  nullifier: Array<number>
+  domain: Array<number>
+  claimedAt: BN
+}
+
+export interface AliasClaimedEventJSON {
+  handleHash: Array<number>
+  nullifier: Array<number>
+  domain: Array<number>
+  claimedAt: string
+}
+
+export class AliasClaimedEvent {
+  readonly handleHash: Array<number>
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly claimedAt: BN
+
+  constructor(fields: AliasClaimedEventFields) {
+    this.handleHash = fields.handleHash
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.claimedAt = fields.claimedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "handleHash"),
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.i64("claimedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AliasClaimedEvent({
+      handleHash: obj.handleHash,
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      claimedAt: obj.claimedAt,
+    })
+  }
+
+  static toEncodable(fields: AliasClaimedEventFields) {
+    return {
+      handleHash: fields.handleHash,
+      nullifier: fields.nullifier,
+      domain: fields.domain,
+      claimedAt: fields.claimedAt,
+    }
+  }
+
+  toJSON(): AliasClaimedEventJSON {
+    return {
+      handleHash: this.handleHash,
+      nullifier: this.nullifier,
+      domain: this.domain,
+      claimedAt: this.claimedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: AliasClaimedEventJSON): AliasClaimedEvent {
+    return new AliasClaimedEvent({
+      handleHash: obj.handleHash,
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      claimedAt: new BN(obj.claimedAt),
+    })
+  }
+
+  toEncodable() {
+    return AliasClaimedEvent.toEncodable(this)
+  }
+}
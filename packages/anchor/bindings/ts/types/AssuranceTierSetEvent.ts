@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AssuranceTierSetEventFields {
+  tier: number
+  maxResultAgeSecs: number
+}
+
+export interface AssuranceTierSetEventJSON {
+  tier: number
+  maxResultAgeSecs: number
+}
+
+export class AssuranceTierSetEvent {
+  readonly tier: number
+  readonly maxResultAgeSecs: number
+
+  constructor(fields: AssuranceTierSetEventFields) {
+    this.tier = fields.tier
+    this.maxResultAgeSecs = fields.maxResultAgeSecs
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u8("tier"),
+        borsh.u32("maxResultAgeSecs"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AssuranceTierSetEvent({
+      tier: obj.tier,
+      maxResultAgeSecs: obj.maxResultAgeSecs,
+    })
+  }
+
+  static toEncodable(fields: AssuranceTierSetEventFields) {
+    return {
+      tier: fields.tier,
+      maxResultAgeSecs: fields.maxResultAgeSecs,
+    }
+  }
+
+  toJSON(): AssuranceTierSetEventJSON {
+    return {
+      tier: this.tier,
+      maxResultAgeSecs: this.maxResultAgeSecs,
+    }
+  }
+
+  static fromJSON(obj: AssuranceTierSetEventJSON): AssuranceTierSetEvent {
+    return new AssuranceTierSetEvent({
+      tier: obj.tier,
+      maxResultAgeSecs: obj.maxResultAgeSecs,
+    })
+  }
+
+  toEncodable() {
+    return AssuranceTierSetEvent.toEncodable(this)
+  }
+}
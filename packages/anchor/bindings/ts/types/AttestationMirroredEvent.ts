@@ -0,0 +1,93 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AttestationMirroredEventFields {
+  nullifier: Array<number>
+  claimType: types.ClaimTypeKind
+  domain: Array<number>
+  sasAttestation: PublicKey
+  expiresAt: BN
+}
+
+export interface AttestationMirroredEventJSON {
+  nullifier: Array<number>
+  claimType: types.ClaimTypeJSON
+  domain: Array<number>
+  sasAttestation: string
+  expiresAt: string
+}
+
+export class AttestationMirroredEvent {
+  readonly nullifier: Array<number>
+  readonly claimType: types.ClaimTypeKind
+  readonly domain: Array<number>
+  readonly sasAttestation: PublicKey
+  readonly expiresAt: BN
+
+  constructor(fields: AttestationMirroredEventFields) {
+    this.nullifier = fields.nullifier
+    this.claimType = fields.claimType
+    this.domain = fields.domain
+    this.sasAttestation = fields.sasAttestation
+    this.expiresAt = fields.expiresAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        types.ClaimType.layout("claimType"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.publicKey("sasAttestation"),
+        borsh.i64("expiresAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AttestationMirroredEvent({
+      nullifier: obj.nullifier,
+      claimType: types.ClaimType.fromDecoded(obj.claimType),
+      domain: obj.domain,
+      sasAttestation: obj.sasAttestation,
+      expiresAt: obj.expiresAt,
+    })
+  }
+
+  static toEncodable(fields: AttestationMirroredEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      claimType: fields.claimType.toEncodable(),
+      domain: fields.domain,
+      sasAttestation: fields.sasAttestation,
+      expiresAt: fields.expiresAt,
+    }
+  }
+
+  toJSON(): AttestationMirroredEventJSON {
+    return {
+      nullifier: this.nullifier,
+      claimType: this.claimType.toJSON(),
+      domain: this.domain,
+      sasAttestation: this.sasAttestation.toString(),
+      expiresAt: this.expiresAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: AttestationMirroredEventJSON): AttestationMirroredEvent {
+    return new AttestationMirroredEvent({
+      nullifier: obj.nullifier,
+      claimType: types.ClaimType.fromJSON(obj.claimType),
+      domain: obj.domain,
+      sasAttestation: new PublicKey(obj.sasAttestation),
+      expiresAt: new BN(obj.expiresAt),
+    })
+  }
+
+  toEncodable() {
+    return AttestationMirroredEvent.toEncodable(this)
+  }
+}
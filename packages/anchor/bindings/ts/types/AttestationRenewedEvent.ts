@@ -0,0 +1,102 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AttestationRenewedEventFields {
+  nullifier: Array<number>
+  claimType: types.ClaimTypeKind
+  domain: Array<number>
+  claimDataHash: Array<number>
+  issuer: PublicKey
+  expiresAt: BN
+}
+
+export interface AttestationRenewedEventJSON {
+  nullifier: Array<number>
+  claimType: types.ClaimTypeJSON
+  domain: Array<number>
+  claimDataHash: Array<number>
+  issuer: string
+  expiresAt: string
+}
+
+export class AttestationRenewedEvent {
+  readonly nullifier: Array<number>
+  readonly claimType: types.ClaimTypeKind
+  readonly domain: Array<number>
+  readonly claimDataHash: Array<number>
+  readonly issuer: PublicKey
+  readonly expiresAt: BN
+
+  constructor(fields: AttestationRenewedEventFields) {
+    this.nullifier = fields.nullifier
+    this.claimType = fields.claimType
+    this.domain = fields.domain
+    this.claimDataHash = fields.claimDataHash
+    this.issuer = fields.issuer
+    this.expiresAt = fields.expiresAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        types.ClaimType.layout("claimType"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.array(borsh.u8(), 32, "claimDataHash"),
+        borsh.publicKey("issuer"),
+        borsh.i64("expiresAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AttestationRenewedEvent({
+      nullifier: obj.nullifier,
+      claimType: types.ClaimType.fromDecoded(obj.claimType),
+      domain: obj.domain,
+      claimDataHash: obj.claimDataHash,
+      issuer: obj.issuer,
+      expiresAt: obj.expiresAt,
+    })
+  }
+
+  static toEncodable(fields: AttestationRenewedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      claimType: fields.claimType.toEncodable(),
+      domain: fields.domain,
+      claimDataHash: fields.claimDataHash,
+      issuer: fields.issuer,
+      expiresAt: fields.expiresAt,
+    }
+  }
+
+  toJSON(): AttestationRenewedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      claimType: this.claimType.toJSON(),
+      domain: this.domain,
+      claimDataHash: this.claimDataHash,
+      issuer: this.issuer.toString(),
+      expiresAt: this.expiresAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: AttestationRenewedEventJSON): AttestationRenewedEvent {
+    return new AttestationRenewedEvent({
+      nullifier: obj.nullifier,
+      claimType: types.ClaimType.fromJSON(obj.claimType),
+      domain: obj.domain,
+      claimDataHash: obj.claimDataHash,
+      issuer: new PublicKey(obj.issuer),
+      expiresAt: new BN(obj.expiresAt),
+    })
+  }
+
+  toEncodable() {
+    return AttestationRenewedEvent.toEncodable(this)
+  }
+}
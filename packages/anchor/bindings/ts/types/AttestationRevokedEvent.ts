@@ -0,0 +1,93 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AttestationRevokedEventFields {
+  nullifier: Array<number>
+  claimType: types.ClaimTypeKind
+  domain: Array<number>
+  issuer: PublicKey
+  revokedAt: BN
+}
+
+export interface AttestationRevokedEventJSON {
+  nullifier: Array<number>
+  claimType: types.ClaimTypeJSON
+  domain: Array<number>
+  issuer: string
+  revokedAt: string
+}
+
+export class AttestationRevokedEvent {
+  readonly nullifier: Array<number>
+  readonly claimType: types.ClaimTypeKind
+  readonly domain: Array<number>
+  readonly issuer: PublicKey
+  readonly revokedAt: BN
+
+  constructor(fields: AttestationRevokedEventFields) {
+    this.nullifier = fields.nullifier
+    this.claimType = fields.claimType
+    this.domain = fields.domain
+    this.issuer = fields.issuer
+    this.revokedAt = fields.revokedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        types.ClaimType.layout("claimType"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.publicKey("issuer"),
+        borsh.i64("revokedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AttestationRevokedEvent({
+      nullifier: obj.nullifier,
+      claimType: types.ClaimType.fromDecoded(obj.claimType),
+      domain: obj.domain,
+      issuer: obj.issuer,
+      revokedAt: obj.revokedAt,
+    })
+  }
+
+  static toEncodable(fields: AttestationRevokedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      claimType: fields.claimType.toEncodable(),
+      domain: fields.domain,
+      issuer: fields.issuer,
+      revokedAt: fields.revokedAt,
+    }
+  }
+
+  toJSON(): AttestationRevokedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      claimType: this.claimType.toJSON(),
+      domain: this.domain,
+      issuer: this.issuer.toString(),
+      revokedAt: this.revokedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: AttestationRevokedEventJSON): AttestationRevokedEvent {
+    return new AttestationRevokedEvent({
+      nullifier: obj.nullifier,
+      claimType: types.ClaimType.fromJSON(obj.claimType),
+      domain: obj.domain,
+      issuer: new PublicKey(obj.issuer),
+      revokedAt: new BN(obj.revokedAt),
+    })
+  }
+
+  toEncodable() {
+    return AttestationRevokedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AttestorRegisteredEventFields {
+  issuer: PublicKey
+  nameHash: Array<number>
+  claimMask: number
+}
+
+export interface AttestorRegisteredEventJSON {
+  issuer: string
+  nameHash: Array<number>
+  claimMask: number
+}
+
+export class AttestorRegisteredEvent {
+  readonly issuer: PublicKey
+  readonly nameHash: Array<number>
+  readonly claimMask: number
+
+  constructor(fields: AttestorRegisteredEventFields) {
+    this.issuer = fields.issuer
+    this.nameHash = fields.nameHash
+    this.claimMask = fields.claimMask
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("issuer"),
+        borsh.array(borsh.u8(), 32, "nameHash"),
+        borsh.u8("claimMask"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AttestorRegisteredEvent({
+      issuer: obj.issuer,
+      nameHash: obj.nameHash,
+      claimMask: obj.claimMask,
+    })
+  }
+
+  static toEncodable(fields: AttestorRegisteredEventFields) {
+    return {
+      issuer: fields.issuer,
+      nameHash: fields.nameHash,
+      claimMask: fields.claimMask,
+    }
+  }
+
+  toJSON(): AttestorRegisteredEventJSON {
+    return {
+      issuer: this.issuer.toString(),
+      nameHash: this.nameHash,
+      claimMask: this.claimMask,
+    }
+  }
+
+  static fromJSON(obj: AttestorRegisteredEventJSON): AttestorRegisteredEvent {
+    return new AttestorRegisteredEvent({
+      issuer: new PublicKey(obj.issuer),
+      nameHash: obj.nameHash,
+      claimMask: obj.claimMask,
+    })
+  }
+
+  toEncodable() {
+    return AttestorRegisteredEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,88 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ActiveJSON {
+  kind: "Active"
+}
+
+export class Active {
+  static readonly discriminator = 0
+  static readonly kind = "Active"
+  readonly discriminator = 0
+  readonly kind = "Active"
+
+  toJSON(): ActiveJSON {
+    return {
+      kind: "Active",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Active: {},
+    }
+  }
+}
+
+export interface SuspendedJSON {
+  kind: "Suspended"
+}
+
+export class Suspended {
+  static readonly discriminator = 1
+  static readonly kind = "Suspended"
+  readonly discriminator = 1
+  readonly kind = "Suspended"
+
+  toJSON(): SuspendedJSON {
+    return {
+      kind: "Suspended",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Suspended: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.AttestorStatusKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Active" in obj) {
+    return new Active()
+  }
+  if ("Suspended" in obj) {
+    return new Suspended()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.AttestorStatusJSON): types.AttestorStatusKind {
+  switch (obj.kind) {
+    case "Active": {
+      return new Active()
+    }
+    case "Suspended": {
+      return new Suspended()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Active"),
+    borsh.struct([], "Suspended"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
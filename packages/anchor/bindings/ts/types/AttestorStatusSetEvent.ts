@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AttestorStatusSetEventFields {
+  issuer: PublicKey
+  status: types.AttestorStatusKind
+}
+
+export interface AttestorStatusSetEventJSON {
+  issuer: string
+  status: types.AttestorStatusJSON
+}
+
+export class AttestorStatusSetEvent {
+  readonly issuer: PublicKey
+  readonly status: types.AttestorStatusKind
+
+  constructor(fields: AttestorStatusSetEventFields) {
+    this.issuer = fields.issuer
+    this.status = fields.status
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("issuer"),
+        types.AttestorStatus.layout("status"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AttestorStatusSetEvent({
+      issuer: obj.issuer,
+      status: types.AttestorStatus.fromDecoded(obj.status),
+    })
+  }
+
+  static toEncodable(fields: AttestorStatusSetEventFields) {
+    return {
+      issuer: fields.issuer,
+      status: fields.status.toEncodable(),
+    }
+  }
+
+  toJSON(): AttestorStatusSetEventJSON {
+    return {
+      issuer: this.issuer.toString(),
+      status: this.status.toJSON(),
+    }
+  }
+
+  static fromJSON(obj: AttestorStatusSetEventJSON): AttestorStatusSetEvent {
+    return new AttestorStatusSetEvent({
+      issuer: new PublicKey(obj.issuer),
+      status: types.AttestorStatus.fromJSON(obj.status),
+    })
+  }
+
+  toEncodable() {
+    return AttestorStatusSetEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,93 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AuditorGrantedEventFields {
+  nullifier: Array<number>
+  auditor: PublicKey
+  nameHash: Array<number>
+  grantedAt: BN
+  expiresAt: types.ExpiryKindKind
+}
+
+export interface AuditorGrantedEventJSON {
+  nullifier: Array<number>
+  auditor: string
+  nameHash: Array<number>
+  grantedAt: string
+  expiresAt: types.ExpiryKindJSON
+}
+
+export class AuditorGrantedEvent {
+  readonly nullifier: Array<number>
+  readonly auditor: PublicKey
+  readonly nameHash: Array<number>
+  readonly grantedAt: BN
+  readonly expiresAt: types.ExpiryKindKind
+
+  constructor(fields: AuditorGrantedEventFields) {
+    this.nullifier = fields.nullifier
+    this.auditor = fields.auditor
+    this.nameHash = fields.nameHash
+    this.grantedAt = fields.grantedAt
+    this.expiresAt = fields.expiresAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("auditor"),
+        borsh.array(borsh.u8(), 32, "nameHash"),
+        borsh.i64("grantedAt"),
+        types.ExpiryKind.layout("expiresAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AuditorGrantedEvent({
+      nullifier: obj.nullifier,
+      auditor: obj.auditor,
+      nameHash: obj.nameHash,
+      grantedAt: obj.grantedAt,
+      expiresAt: types.ExpiryKind.fromDecoded(obj.expiresAt),
+    })
+  }
+
+  static toEncodable(fields: AuditorGrantedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      auditor: fields.auditor,
+      nameHash: fields.nameHash,
+      grantedAt: fields.grantedAt,
+      expiresAt: fields.expiresAt.toEncodable(),
+    }
+  }
+
+  toJSON(): AuditorGrantedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      auditor: this.auditor.toString(),
+      nameHash: this.nameHash,
+      grantedAt: this.grantedAt.toString(),
+      expiresAt: this.expiresAt.toJSON(),
+    }
+  }
+
+  static fromJSON(obj: AuditorGrantedEventJSON): AuditorGrantedEvent {
+    return new AuditorGrantedEvent({
+      nullifier: obj.nullifier,
+      auditor: new PublicKey(obj.auditor),
+      nameHash: obj.nameHash,
+      grantedAt: new BN(obj.grantedAt),
+      expiresAt: types.ExpiryKind.fromJSON(obj.expiresAt),
+    })
+  }
+
+  toEncodable() {
+    return AuditorGrantedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AuditorRevokedEventFields {
+  nullifier: Array<number>
+  auditor: PublicKey
+  revokedAt: BN
+}
+
+export interface AuditorRevokedEventJSON {
+  nullifier: Array<number>
+  auditor: string
+  revokedAt: string
+}
+
+export class AuditorRevokedEvent {
+  readonly nullifier: Array<number>
+  readonly auditor: PublicKey
+  readonly revokedAt: BN
+
+  constructor(fields: AuditorRevokedEventFields) {
+    this.nullifier = fields.nullifier
+    this.auditor = fields.auditor
+    this.revokedAt = fields.revokedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("auditor"),
+        borsh.i64("revokedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AuditorRevokedEvent({
+      nullifier: obj.nullifier,
+      auditor: obj.auditor,
+      revokedAt: obj.revokedAt,
+    })
+  }
+
+  static toEncodable(fields: AuditorRevokedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      auditor: fields.auditor,
+      revokedAt: fields.revokedAt,
+    }
+  }
+
+  toJSON(): AuditorRevokedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      auditor: this.auditor.toString(),
+      revokedAt: this.revokedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: AuditorRevokedEventJSON): AuditorRevokedEvent {
+    return new AuditorRevokedEvent({
+      nullifier: obj.nullifier,
+      auditor: new PublicKey(obj.auditor),
+      revokedAt: new BN(obj.revokedAt),
+    })
+  }
+
+  toEncodable() {
+    return AuditorRevokedEvent.toEncodable(this)
+  }
+}
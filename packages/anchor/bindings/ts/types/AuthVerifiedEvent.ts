@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AuthVerifiedEventFields {
+  nullifier: Array<number>
+  domain: Array<number>
+  proofHash: Array<number>
+  expiresAt: types.ExpiryKindKind
+}
+
+export interface AuthVerifiedEventJSON {
+  nullifier: Array<number>
+  domain: Array<number>
+  proofHash: Array<number>
+  expiresAt: types.ExpiryKindJSON
+}
+
+export class AuthVerifiedEvent {
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly proofHash: Array<number>
+  readonly expiresAt: types.ExpiryKindKind
+
+  constructor(fields: AuthVerifiedEventFields) {
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.proofHash = fields.proofHash
+    this.expiresAt = fields.expiresAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.array(borsh.u8(), 32, "proofHash"),
+        types.ExpiryKind.layout("expiresAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new AuthVerifiedEvent({
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      proofHash: obj.proofHash,
+      expiresAt: types.ExpiryKind.fromDecoded(obj.expiresAt),
+    })
+  }
+
+  static toEncodable(fields: AuthVerifiedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      domain: fields.domain,
+      proofHash: fields.proofHash,
+      expiresAt: fields.expiresAt.toEncodable(),
+    }
+  }
+
+  toJSON(): AuthVerifiedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      domain: this.domain,
+      proofHash: this.proofHash,
+      expiresAt: this.expiresAt.toJSON(),
+    }
+  }
+
+  static fromJSON(obj: AuthVerifiedEventJSON): AuthVerifiedEvent {
+    return new AuthVerifiedEvent({
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      proofHash: obj.proofHash,
+      expiresAt: types.ExpiryKind.fromJSON(obj.expiresAt),
+    })
+  }
+
+  toEncodable() {
+    return AuthVerifiedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ChallengeWindowSetEventFields {
+  domain: Array<number>
+  challengeWindowSecs: BN
+}
+
+export interface ChallengeWindowSetEventJSON {
+  domain: Array<number>
+  challengeWindowSecs: string
+}
+
+export class ChallengeWindowSetEvent {
+  readonly domain: Array<number>
+  readonly challengeWindowSecs: BN
+
+  constructor(fields: ChallengeWindowSetEventFields) {
+    this.domain = fields.domain
+    this.challengeWindowSecs = fields.challengeWindowSecs
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.i64("challengeWindowSecs"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new ChallengeWindowSetEvent({
+      domain: obj.domain,
+      challengeWindowSecs: obj.challengeWindowSecs,
+    })
+  }
+
+  static toEncodable(fields: ChallengeWindowSetEventFields) {
+    return {
+      domain: fields.domain,
+      challengeWindowSecs: fields.challengeWindowSecs,
+    }
+  }
+
+  toJSON(): ChallengeWindowSetEventJSON {
+    return {
+      domain: this.domain,
+      challengeWindowSecs: this.challengeWindowSecs.toString(),
+    }
+  }
+
+  static fromJSON(obj: ChallengeWindowSetEventJSON): ChallengeWindowSetEvent {
+    return new ChallengeWindowSetEvent({
+      domain: obj.domain,
+      challengeWindowSecs: new BN(obj.challengeWindowSecs),
+    })
+  }
+
+  toEncodable() {
+    return ChallengeWindowSetEvent.toEncodable(this)
+  }
+}
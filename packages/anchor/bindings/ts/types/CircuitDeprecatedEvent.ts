@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface CircuitDeprecatedEventFields {
+  circuitId: number
+  vkHash: Array<number>
+}
+
+export interface CircuitDeprecatedEventJSON {
+  circuitId: number
+  vkHash: Array<number>
+}
+
+export class CircuitDeprecatedEvent {
+  readonly circuitId: number
+  readonly vkHash: Array<number>
+
+  constructor(fields: CircuitDeprecatedEventFields) {
+    this.circuitId = fields.circuitId
+    this.vkHash = fields.vkHash
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u32("circuitId"),
+        borsh.array(borsh.u8(), 32, "vkHash"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new CircuitDeprecatedEvent({
+      circuitId: obj.circuitId,
+      vkHash: obj.vkHash,
+    })
+  }
+
+  static toEncodable(fields: CircuitDeprecatedEventFields) {
+    return {
+      circuitId: fields.circuitId,
+      vkHash: fields.vkHash,
+    }
+  }
+
+  toJSON(): CircuitDeprecatedEventJSON {
+    return {
+      circuitId: this.circuitId,
+      vkHash: this.vkHash,
+    }
+  }
+
+  static fromJSON(obj: CircuitDeprecatedEventJSON): CircuitDeprecatedEvent {
+    return new CircuitDeprecatedEvent({
+      circuitId: obj.circuitId,
+      vkHash: obj.vkHash,
+    })
+  }
+
+  toEncodable() {
+    return CircuitDeprecatedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface CircuitEntryFields {
+  circuitId: number
+  vkHash: Array<number>
+  deprecated: boolean
+}
+
+export interface CircuitEntryJSON {
+  circuitId: number
+  vkHash: Array<number>
+  deprecated: boolean
+}
+
+export class CircuitEntry {
+  readonly circuitId: number
+  readonly vkHash: Array<number>
+  readonly deprecated: boolean
+
+  constructor(fields: CircuitEntryFields) {
+    this.circuitId = fields.circuitId
+    this.vkHash = fields.vkHash
+    this.deprecated = fields.deprecated
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u32("circuitId"),
+        borsh.array(borsh.u8(), 32, "vkHash"),
+        borsh.bool("deprecated"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new CircuitEntry({
+      circuitId: obj.circuitId,
+      vkHash: obj.vkHash,
+      deprecated: obj.deprecated,
+    })
+  }
+
+  static toEncodable(fields: CircuitEntryFields) {
+    return {
+      circuitId: fields.circuitId,
+      vkHash: fields.vkHash,
+      deprecated: fields.deprecated,
+    }
+  }
+
+  toJSON(): CircuitEntryJSON {
+    return {
+      circuitId: this.circuitId,
+      vkHash: this.vkHash,
+      deprecated: this.deprecated,
+    }
+  }
+
+  static fromJSON(obj: CircuitEntryJSON): CircuitEntry {
+    return new CircuitEntry({
+      circuitId: obj.circuitId,
+      vkHash: obj.vkHash,
+      deprecated: obj.deprecated,
+    })
+  }
+
+  toEncodable() {
+    return CircuitEntry.toEncodable(this)
+  }
+}
@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface CircuitRegisteredEventFields {
+  circuitId: number
+  vkHash: Array<number>
+}
+
+export interface CircuitRegisteredEventJSON {
+  circuitId: number
+  vkHash: Array<number>
+}
+
+export class CircuitRegisteredEvent {
+  readonly circuitId: number
+  readonly vkHash: Array<number>
+
+  constructor(fields: CircuitRegisteredEventFields) {
+    this.circuitId = fields.circuitId
+    this.vkHash = fields.vkHash
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u32("circuitId"),
+        borsh.array(borsh.u8(), 32, "vkHash"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new CircuitRegisteredEvent({
+      circuitId: obj.circuitId,
+      vkHash: obj.vkHash,
+    })
+  }
+
+  static toEncodable(fields: CircuitRegisteredEventFields) {
+    return {
+      circuitId: fields.circuitId,
+      vkHash: fields.vkHash,
+    }
+  }
+
+  toJSON(): CircuitRegisteredEventJSON {
+    return {
+      circuitId: this.circuitId,
+      vkHash: this.vkHash,
+    }
+  }
+
+  static fromJSON(obj: CircuitRegisteredEventJSON): CircuitRegisteredEvent {
+    return new CircuitRegisteredEvent({
+      circuitId: obj.circuitId,
+      vkHash: obj.vkHash,
+    })
+  }
+
+  toEncodable() {
+    return CircuitRegisteredEvent.toEncodable(this)
+  }
+}
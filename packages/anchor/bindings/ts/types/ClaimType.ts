@@ -0,0 +1,148 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AuthJSON {
+  kind: "Auth"
+}
+
+export class Auth {
+  static readonly discriminator = 0
+  static readonly kind = "Auth"
+  readonly discriminator = 0
+  readonly kind = "Auth"
+
+  toJSON(): AuthJSON {
+    return {
+      kind: "Auth",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Auth: {},
+    }
+  }
+}
+
+export interface BalanceThresholdJSON {
+  kind: "BalanceThreshold"
+}
+
+export class BalanceThreshold {
+  static readonly discriminator = 1
+  static readonly kind = "BalanceThreshold"
+  readonly discriminator = 1
+  readonly kind = "BalanceThreshold"
+
+  toJSON(): BalanceThresholdJSON {
+    return {
+      kind: "BalanceThreshold",
+    }
+  }
+
+  toEncodable() {
+    return {
+      BalanceThreshold: {},
+    }
+  }
+}
+
+export interface AgeOverJSON {
+  kind: "AgeOver"
+}
+
+export class AgeOver {
+  static readonly discriminator = 2
+  static readonly kind = "AgeOver"
+  readonly discriminator = 2
+  readonly kind = "AgeOver"
+
+  toJSON(): AgeOverJSON {
+    return {
+      kind: "AgeOver",
+    }
+  }
+
+  toEncodable() {
+    return {
+      AgeOver: {},
+    }
+  }
+}
+
+export interface NftOwnershipJSON {
+  kind: "NftOwnership"
+}
+
+export class NftOwnership {
+  static readonly discriminator = 3
+  static readonly kind = "NftOwnership"
+  readonly discriminator = 3
+  readonly kind = "NftOwnership"
+
+  toJSON(): NftOwnershipJSON {
+    return {
+      kind: "NftOwnership",
+    }
+  }
+
+  toEncodable() {
+    return {
+      NftOwnership: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.ClaimTypeKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Auth" in obj) {
+    return new Auth()
+  }
+  if ("BalanceThreshold" in obj) {
+    return new BalanceThreshold()
+  }
+  if ("AgeOver" in obj) {
+    return new AgeOver()
+  }
+  if ("NftOwnership" in obj) {
+    return new NftOwnership()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.ClaimTypeJSON): types.ClaimTypeKind {
+  switch (obj.kind) {
+    case "Auth": {
+      return new Auth()
+    }
+    case "BalanceThreshold": {
+      return new BalanceThreshold()
+    }
+    case "AgeOver": {
+      return new AgeOver()
+    }
+    case "NftOwnership": {
+      return new NftOwnership()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Auth"),
+    borsh.struct([], "BalanceThreshold"),
+    borsh.struct([], "AgeOver"),
+    borsh.struct([], "NftOwnership"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
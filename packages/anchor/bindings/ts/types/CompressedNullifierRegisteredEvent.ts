@@ -0,0 +1,120 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface CompressedNullifierRegisteredEventFields {
+  nullifier: Array<number>
+  domain: Array<number>
+  proofHash: Array<number>
+  leafIndex: number
+  lowIndex: number
+  nextIndex: number
+  nextValue: Array<number>
+  registeredAt: BN
+}
+
+export interface CompressedNullifierRegisteredEventJSON {
+  nullifier: Array<number>
+  domain: Array<number>
+  proofHash: Array<number>
+  leafIndex: number
+  lowIndex: number
+  nextIndex: number
+  nextValue: Array<number>
+  registeredAt: string
+}
+
+export class CompressedNullifierRegisteredEvent {
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly proofHash: Array<number>
+  readonly leafIndex: number
+  readonly lowIndex: number
+  readonly nextIndex: number
+  readonly nextValue: Array<number>
+  readonly registeredAt: BN
+
+  constructor(fields: CompressedNullifierRegisteredEventFields) {
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.proofHash = fields.proofHash
+    this.leafIndex = fields.leafIndex
+    this.lowIndex = fields.lowIndex
+    this.nextIndex = fields.nextIndex
+    this.nextValue = fields.nextValue
+    this.registeredAt = fields.registeredAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.array(borsh.u8(), 32, "proofHash"),
+        borsh.u32("leafIndex"),
+        borsh.u32("lowIndex"),
+        borsh.u32("nextIndex"),
+        borsh.array(borsh.u8(), 32, "nextValue"),
+        borsh.i64("registeredAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new CompressedNullifierRegisteredEvent({
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      proofHash: obj.proofHash,
+      leafIndex: obj.leafIndex,
+      lowIndex: obj.lowIndex,
+      nextIndex: obj.nextIndex,
+      nextValue: obj.nextValue,
+      registeredAt: obj.registeredAt,
+    })
+  }
+
+  static toEncodable(fields: CompressedNullifierRegisteredEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      domain: fields.domain,
+      proofHash: fields.proofHash,
+      leafIndex: fields.leafIndex,
+      lowIndex: fields.lowIndex,
+      nextIndex: fields.nextIndex,
+      nextValue: fields.nextValue,
+      registeredAt: fields.registeredAt,
+    }
+  }
+
+  toJSON(): CompressedNullifierRegisteredEventJSON {
+    return {
+      nullifier: this.nullifier,
+      domain: this.domain,
+      proofHash: this.proofHash,
+      leafIndex: this.leafIndex,
+      lowIndex: this.lowIndex,
+      nextIndex: this.nextIndex,
+      nextValue: this.nextValue,
+      registeredAt: this.registeredAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: CompressedNullifierRegisteredEventJSON): CompressedNullifierRegisteredEvent {
+    return new CompressedNullifierRegisteredEvent({
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      proofHash: obj.proofHash,
+      leafIndex: obj.leafIndex,
+      lowIndex: obj.lowIndex,
+      nextIndex: obj.nextIndex,
+      nextValue: obj.nextValue,
+      registeredAt: new BN(obj.registeredAt),
+    })
+  }
+
+  toEncodable() {
+    return CompressedNullifierRegisteredEvent.toEncodable(this)
+  }
+}
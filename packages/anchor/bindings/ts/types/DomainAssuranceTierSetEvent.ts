@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainAssuranceTierSetEventFields {
+  domain: Array<number>
+  assuranceTier: number
+}
+
+export interface DomainAssuranceTierSetEventJSON {
+  domain: Array<number>
+  assuranceTier: number
+}
+
+export class DomainAssuranceTierSetEvent {
+  readonly domain: Array<number>
+  readonly assuranceTier: number
+
+  constructor(fields: DomainAssuranceTierSetEventFields) {
+    this.domain = fields.domain
+    this.assuranceTier = fields.assuranceTier
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.u8("assuranceTier"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainAssuranceTierSetEvent({
+      domain: obj.domain,
+      assuranceTier: obj.assuranceTier,
+    })
+  }
+
+  static toEncodable(fields: DomainAssuranceTierSetEventFields) {
+    return {
+      domain: fields.domain,
+      assuranceTier: fields.assuranceTier,
+    }
+  }
+
+  toJSON(): DomainAssuranceTierSetEventJSON {
+    return {
+      domain: this.domain,
+      assuranceTier: this.assuranceTier,
+    }
+  }
+
+  static fromJSON(obj: DomainAssuranceTierSetEventJSON): DomainAssuranceTierSetEvent {
+    return new DomainAssuranceTierSetEvent({
+      domain: obj.domain,
+      assuranceTier: obj.assuranceTier,
+    })
+  }
+
+  toEncodable() {
+    return DomainAssuranceTierSetEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainAttestorClaimsSetEventFields {
+  domain: Array<number>
+  claimMask: number
+}
+
+export interface DomainAttestorClaimsSetEventJSON {
+  domain: Array<number>
+  claimMask: number
+}
+
+export class DomainAttestorClaimsSetEvent {
+  readonly domain: Array<number>
+  readonly claimMask: number
+
+  constructor(fields: DomainAttestorClaimsSetEventFields) {
+    this.domain = fields.domain
+    this.claimMask = fields.claimMask
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.u8("claimMask"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainAttestorClaimsSetEvent({
+      domain: obj.domain,
+      claimMask: obj.claimMask,
+    })
+  }
+
+  static toEncodable(fields: DomainAttestorClaimsSetEventFields) {
+    return {
+      domain: fields.domain,
+      claimMask: fields.claimMask,
+    }
+  }
+
+  toJSON(): DomainAttestorClaimsSetEventJSON {
+    return {
+      domain: this.domain,
+      claimMask: this.claimMask,
+    }
+  }
+
+  static fromJSON(obj: DomainAttestorClaimsSetEventJSON): DomainAttestorClaimsSetEvent {
+    return new DomainAttestorClaimsSetEvent({
+      domain: obj.domain,
+      claimMask: obj.claimMask,
+    })
+  }
+
+  toEncodable() {
+    return DomainAttestorClaimsSetEvent.toEncodable(this)
+  }
+}
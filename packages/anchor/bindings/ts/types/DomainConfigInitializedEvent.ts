@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainConfigInitializedEventFields {
+  domain: Array<number>
+  owner: PublicKey
+}
+
+export interface DomainConfigInitializedEventJSON {
+  domain: Array<number>
+  owner: string
+}
+
+export class DomainConfigInitializedEvent {
+  readonly domain: Array<number>
+  readonly owner: PublicKey
+
+  constructor(fields: DomainConfigInitializedEventFields) {
+    this.domain = fields.domain
+    this.owner = fields.owner
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.publicKey("owner"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainConfigInitializedEvent({
+      domain: obj.domain,
+      owner: obj.owner,
+    })
+  }
+
+  static toEncodable(fields: DomainConfigInitializedEventFields) {
+    return {
+      domain: fields.domain,
+      owner: fields.owner,
+    }
+  }
+
+  toJSON(): DomainConfigInitializedEventJSON {
+    return {
+      domain: this.domain,
+      owner: this.owner.toString(),
+    }
+  }
+
+  static fromJSON(obj: DomainConfigInitializedEventJSON): DomainConfigInitializedEvent {
+    return new DomainConfigInitializedEvent({
+      domain: obj.domain,
+      owner: new PublicKey(obj.owner),
+    })
+  }
+
+  toEncodable() {
+    return DomainConfigInitializedEvent.toEncodable(this)
+  }
+}
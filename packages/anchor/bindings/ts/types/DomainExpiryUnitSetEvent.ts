@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainExpiryUnitSetEventFields {
+  domain: Array<number>
+  sessionExpiryUnit: types.ExpiryUnitKind
+}
+
+export interface DomainExpiryUnitSetEventJSON {
+  domain: Array<number>
+  sessionExpiryUnit: types.ExpiryUnitJSON
+}
+
+export class DomainExpiryUnitSetEvent {
+  readonly domain: Array<number>
+  readonly sessionExpiryUnit: types.ExpiryUnitKind
+
+  constructor(fields: DomainExpiryUnitSetEventFields) {
+    this.domain = fields.domain
+    this.sessionExpiryUnit = fields.sessionExpiryUnit
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        types.ExpiryUnit.layout("sessionExpiryUnit"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainExpiryUnitSetEvent({
+      domain: obj.domain,
+      sessionExpiryUnit: types.ExpiryUnit.fromDecoded(obj.sessionExpiryUnit),
+    })
+  }
+
+  static toEncodable(fields: DomainExpiryUnitSetEventFields) {
+    return {
+      domain: fields.domain,
+      sessionExpiryUnit: fields.sessionExpiryUnit.toEncodable(),
+    }
+  }
+
+  toJSON(): DomainExpiryUnitSetEventJSON {
+    return {
+      domain: this.domain,
+      sessionExpiryUnit: this.sessionExpiryUnit.toJSON(),
+    }
+  }
+
+  static fromJSON(obj: DomainExpiryUnitSetEventJSON): DomainExpiryUnitSetEvent {
+    return new DomainExpiryUnitSetEvent({
+      domain: obj.domain,
+      sessionExpiryUnit: types.ExpiryUnit.fromJSON(obj.sessionExpiryUnit),
+    })
+  }
+
+  toEncodable() {
+    return DomainExpiryUnitSetEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,129 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainFeePaidEventFields {
+  payer: PublicKey
+  domain: Array<number>
+  mint: PublicKey
+  feeUsdMicros: BN
+  amount: BN
+  domainAmount: BN
+  price: BN
+  exponent: number
+  publishTime: BN
+}
+
+export interface DomainFeePaidEventJSON {
+  payer: string
+  domain: Array<number>
+  mint: string
+  feeUsdMicros: string
+  amount: string
+  domainAmount: string
+  price: string
+  exponent: number
+  publishTime: string
+}
+
+export class DomainFeePaidEvent {
+  readonly payer: PublicKey
+  readonly domain: Array<number>
+  readonly mint: PublicKey
+  readonly feeUsdMicros: BN
+  readonly amount: BN
+  readonly domainAmount: BN
+  readonly price: BN
+  readonly exponent: number
+  readonly publishTime: BN
+
+  constructor(fields: DomainFeePaidEventFields) {
+    this.payer = fields.payer
+    this.domain = fields.domain
+    this.mint = fields.mint
+    this.feeUsdMicros = fields.feeUsdMicros
+    this.amount = fields.amount
+    this.domainAmount = fields.domainAmount
+    this.price = fields.price
+    this.exponent = fields.exponent
+    this.publishTime = fields.publishTime
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("payer"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.publicKey("mint"),
+        borsh.u64("feeUsdMicros"),
+        borsh.u64("amount"),
+        borsh.u64("domainAmount"),
+        borsh.i64("price"),
+        borsh.i32("exponent"),
+        borsh.i64("publishTime"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainFeePaidEvent({
+      payer: obj.payer,
+      domain: obj.domain,
+      mint: obj.mint,
+      feeUsdMicros: obj.feeUsdMicros,
+      amount: obj.amount,
+      domainAmount: obj.domainAmount,
+      price: obj.price,
+      exponent: obj.exponent,
+      publishTime: obj.publishTime,
+    })
+  }
+
+  static toEncodable(fields: DomainFeePaidEventFields) {
+    return {
+      payer: fields.payer,
+      domain: fields.domain,
+      mint: fields.mint,
+      feeUsdMicros: fields.feeUsdMicros,
+      amount: fields.amount,
+      domainAmount: fields.domainAmount,
+      price: fields.price,
+      exponent: fields.exponent,
+      publishTime: fields.publishTime,
+    }
+  }
+
+  toJSON(): DomainFeePaidEventJSON {
+    return {
+      payer: this.payer.toString(),
+      domain: this.domain,
+      mint: this.mint.toString(),
+      feeUsdMicros: this.feeUsdMicros.toString(),
+      amount: this.amount.toString(),
+      domainAmount: this.domainAmount.toString(),
+      price: this.price.toString(),
+      exponent: this.exponent,
+      publishTime: this.publishTime.toString(),
+    }
+  }
+
+  static fromJSON(obj: DomainFeePaidEventJSON): DomainFeePaidEvent {
+    return new DomainFeePaidEvent({
+      payer: new PublicKey(obj.payer),
+      domain: obj.domain,
+      mint: new PublicKey(obj.mint),
+      feeUsdMicros: new BN(obj.feeUsdMicros),
+      amount: new BN(obj.amount),
+      domainAmount: new BN(obj.domainAmount),
+      price: new BN(obj.price),
+      exponent: obj.exponent,
+      publishTime: new BN(obj.publishTime),
+    })
+  }
+
+  toEncodable() {
+    return DomainFeePaidEvent.toEncodable(this)
+  }
+}
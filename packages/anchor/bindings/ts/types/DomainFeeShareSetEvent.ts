@@ -0,0 +1,57 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainFeeShareSetEventFields {
+  domainShareBps: number
+}
+
+export interface DomainFeeShareSetEventJSON {
+  domainShareBps: number
+}
+
+export class DomainFeeShareSetEvent {
+  readonly domainShareBps: number
+
+  constructor(fields: DomainFeeShareSetEventFields) {
+    this.domainShareBps = fields.domainShareBps
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u16("domainShareBps"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainFeeShareSetEvent({
+      domainShareBps: obj.domainShareBps,
+    })
+  }
+
+  static toEncodable(fields: DomainFeeShareSetEventFields) {
+    return {
+      domainShareBps: fields.domainShareBps,
+    }
+  }
+
+  toJSON(): DomainFeeShareSetEventJSON {
+    return {
+      domainShareBps: this.domainShareBps,
+    }
+  }
+
+  static fromJSON(obj: DomainFeeShareSetEventJSON): DomainFeeShareSetEvent {
+    return new DomainFeeShareSetEvent({
+      domainShareBps: obj.domainShareBps,
+    })
+  }
+
+  toEncodable() {
+    return DomainFeeShareSetEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainFeeVaultAtaCreatedEventFields {
+  domain: Array<number>
+  mint: PublicKey
+  domainFeeVaultAta: PublicKey
+}
+
+export interface DomainFeeVaultAtaCreatedEventJSON {
+  domain: Array<number>
+  mint: string
+  domainFeeVaultAta: string
+}
+
+export class DomainFeeVaultAtaCreatedEvent {
+  readonly domain: Array<number>
+  readonly mint: PublicKey
+  readonly domainFeeVaultAta: PublicKey
+
+  constructor(fields: DomainFeeVaultAtaCreatedEventFields) {
+    this.domain = fields.domain
+    this.mint = fields.mint
+    this.domainFeeVaultAta = fields.domainFeeVaultAta
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.publicKey("mint"),
+        borsh.publicKey("domainFeeVaultAta"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainFeeVaultAtaCreatedEvent({
+      domain: obj.domain,
+      mint: obj.mint,
+      domainFeeVaultAta: obj.domainFeeVaultAta,
+    })
+  }
+
+  static toEncodable(fields: DomainFeeVaultAtaCreatedEventFields) {
+    return {
+      domain: fields.domain,
+      mint: fields.mint,
+      domainFeeVaultAta: fields.domainFeeVaultAta,
+    }
+  }
+
+  toJSON(): DomainFeeVaultAtaCreatedEventJSON {
+    return {
+      domain: this.domain,
+      mint: this.mint.toString(),
+      domainFeeVaultAta: this.domainFeeVaultAta.toString(),
+    }
+  }
+
+  static fromJSON(obj: DomainFeeVaultAtaCreatedEventJSON): DomainFeeVaultAtaCreatedEvent {
+    return new DomainFeeVaultAtaCreatedEvent({
+      domain: obj.domain,
+      mint: new PublicKey(obj.mint),
+      domainFeeVaultAta: new PublicKey(obj.domainFeeVaultAta),
+    })
+  }
+
+  toEncodable() {
+    return DomainFeeVaultAtaCreatedEvent.toEncodable(this)
+  }
+}
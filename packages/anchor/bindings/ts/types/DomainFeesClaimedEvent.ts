@@ -0,0 +1,93 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainFeesClaimedEventFields {
+  domain: Array<number>
+  mint: PublicKey
+  destination: PublicKey
+  authority: PublicKey
+  amount: BN
+}
+
+export interface DomainFeesClaimedEventJSON {
+  domain: Array<number>
+  mint: string
+  destination: string
+  authority: string
+  amount: string
+}
+
+export class DomainFeesClaimedEvent {
+  readonly domain: Array<number>
+  readonly mint: PublicKey
+  readonly destination: PublicKey
+  readonly authority: PublicKey
+  readonly amount: BN
+
+  constructor(fields: DomainFeesClaimedEventFields) {
+    this.domain = fields.domain
+    this.mint = fields.mint
+    this.destination = fields.destination
+    this.authority = fields.authority
+    this.amount = fields.amount
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.publicKey("mint"),
+        borsh.publicKey("destination"),
+        borsh.publicKey("authority"),
+        borsh.u64("amount"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainFeesClaimedEvent({
+      domain: obj.domain,
+      mint: obj.mint,
+      destination: obj.destination,
+      authority: obj.authority,
+      amount: obj.amount,
+    })
+  }
+
+  static toEncodable(fields: DomainFeesClaimedEventFields) {
+    return {
+      domain: fields.domain,
+      mint: fields.mint,
+      destination: fields.destination,
+      authority: fields.authority,
+      amount: fields.amount,
+    }
+  }
+
+  toJSON(): DomainFeesClaimedEventJSON {
+    return {
+      domain: this.domain,
+      mint: this.mint.toString(),
+      destination: this.destination.toString(),
+      authority: this.authority.toString(),
+      amount: this.amount.toString(),
+    }
+  }
+
+  static fromJSON(obj: DomainFeesClaimedEventJSON): DomainFeesClaimedEvent {
+    return new DomainFeesClaimedEvent({
+      domain: obj.domain,
+      mint: new PublicKey(obj.mint),
+      destination: new PublicKey(obj.destination),
+      authority: new PublicKey(obj.authority),
+      amount: new BN(obj.amount),
+    })
+  }
+
+  toEncodable() {
+    return DomainFeesClaimedEvent.toEncodable(this)
+  }
+}
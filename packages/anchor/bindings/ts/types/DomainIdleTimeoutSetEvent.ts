@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainIdleTimeoutSetEventFields {
+  domain: Array<number>
+  idleTimeoutSecs: BN
+}
+
+export interface DomainIdleTimeoutSetEventJSON {
+  domain: Array<number>
+  idleTimeoutSecs: string
+}
+
+export class DomainIdleTimeoutSetEvent {
+  readonly domain: Array<number>
+  readonly idleTimeoutSecs: BN
+
+  constructor(fields: DomainIdleTimeoutSetEventFields) {
+    this.domain = fields.domain
+    this.idleTimeoutSecs = fields.idleTimeoutSecs
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.i64("idleTimeoutSecs"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainIdleTimeoutSetEvent({
+      domain: obj.domain,
+      idleTimeoutSecs: obj.idleTimeoutSecs,
+    })
+  }
+
+  static toEncodable(fields: DomainIdleTimeoutSetEventFields) {
+    return {
+      domain: fields.domain,
+      idleTimeoutSecs: fields.idleTimeoutSecs,
+    }
+  }
+
+  toJSON(): DomainIdleTimeoutSetEventJSON {
+    return {
+      domain: this.domain,
+      idleTimeoutSecs: this.idleTimeoutSecs.toString(),
+    }
+  }
+
+  static fromJSON(obj: DomainIdleTimeoutSetEventJSON): DomainIdleTimeoutSetEvent {
+    return new DomainIdleTimeoutSetEvent({
+      domain: obj.domain,
+      idleTimeoutSecs: new BN(obj.idleTimeoutSecs),
+    })
+  }
+
+  toEncodable() {
+    return DomainIdleTimeoutSetEvent.toEncodable(this)
+  }
+}
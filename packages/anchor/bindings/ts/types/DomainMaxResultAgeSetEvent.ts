@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainMaxResultAgeSetEventFields {
+  domain: Array<number>
+  maxResultAgeSecs: number
+}
+
+export interface DomainMaxResultAgeSetEventJSON {
+  domain: Array<number>
+  maxResultAgeSecs: number
+}
+
+export class DomainMaxResultAgeSetEvent {
+  readonly domain: Array<number>
+  readonly maxResultAgeSecs: number
+
+  constructor(fields: DomainMaxResultAgeSetEventFields) {
+    this.domain = fields.domain
+    this.maxResultAgeSecs = fields.maxResultAgeSecs
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.u32("maxResultAgeSecs"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainMaxResultAgeSetEvent({
+      domain: obj.domain,
+      maxResultAgeSecs: obj.maxResultAgeSecs,
+    })
+  }
+
+  static toEncodable(fields: DomainMaxResultAgeSetEventFields) {
+    return {
+      domain: fields.domain,
+      maxResultAgeSecs: fields.maxResultAgeSecs,
+    }
+  }
+
+  toJSON(): DomainMaxResultAgeSetEventJSON {
+    return {
+      domain: this.domain,
+      maxResultAgeSecs: this.maxResultAgeSecs,
+    }
+  }
+
+  static fromJSON(obj: DomainMaxResultAgeSetEventJSON): DomainMaxResultAgeSetEvent {
+    return new DomainMaxResultAgeSetEvent({
+      domain: obj.domain,
+      maxResultAgeSecs: obj.maxResultAgeSecs,
+    })
+  }
+
+  toEncodable() {
+    return DomainMaxResultAgeSetEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainNullifierStoreSetEventFields {
+  domain: Array<number>
+  nullifierStore: types.NullifierStoreKind
+}
+
+export interface DomainNullifierStoreSetEventJSON {
+  domain: Array<number>
+  nullifierStore: types.NullifierStoreJSON
+}
+
+export class DomainNullifierStoreSetEvent {
+  readonly domain: Array<number>
+  readonly nullifierStore: types.NullifierStoreKind
+
+  constructor(fields: DomainNullifierStoreSetEventFields) {
+    this.domain = fields.domain
+    this.nullifierStore = fields.nullifierStore
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        types.NullifierStore.layout("nullifierStore"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainNullifierStoreSetEvent({
+      domain: obj.domain,
+      nullifierStore: types.NullifierStore.fromDecoded(obj.nullifierStore),
+    })
+  }
+
+  static toEncodable(fields: DomainNullifierStoreSetEventFields) {
+    return {
+      domain: fields.domain,
+      nullifierStore: fields.nullifierStore.toEncodable(),
+    }
+  }
+
+  toJSON(): DomainNullifierStoreSetEventJSON {
+    return {
+      domain: this.domain,
+      nullifierStore: this.nullifierStore.toJSON(),
+    }
+  }
+
+  static fromJSON(obj: DomainNullifierStoreSetEventJSON): DomainNullifierStoreSetEvent {
+    return new DomainNullifierStoreSetEvent({
+      domain: obj.domain,
+      nullifierStore: types.NullifierStore.fromJSON(obj.nullifierStore),
+    })
+  }
+
+  toEncodable() {
+    return DomainNullifierStoreSetEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainOriginsSetEventFields {
+  domain: Array<number>
+  origins: Array<Array<number>>
+}
+
+export interface DomainOriginsSetEventJSON {
+  domain: Array<number>
+  origins: Array<Array<number>>
+}
+
+export class DomainOriginsSetEvent {
+  readonly domain: Array<number>
+  readonly origins: Array<Array<number>>
+
+  constructor(fields: DomainOriginsSetEventFields) {
+    this.domain = fields.domain
+    this.origins = fields.origins
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.vec(borsh.array(borsh.u8(), 32), "origins"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainOriginsSetEvent({
+      domain: obj.domain,
+      origins: obj.origins,
+    })
+  }
+
+  static toEncodable(fields: DomainOriginsSetEventFields) {
+    return {
+      domain: fields.domain,
+      origins: fields.origins,
+    }
+  }
+
+  toJSON(): DomainOriginsSetEventJSON {
+    return {
+      domain: this.domain,
+      origins: this.origins,
+    }
+  }
+
+  static fromJSON(obj: DomainOriginsSetEventJSON): DomainOriginsSetEvent {
+    return new DomainOriginsSetEvent({
+      domain: obj.domain,
+      origins: obj.origins,
+    })
+  }
+
+  toEncodable() {
+    return DomainOriginsSetEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainPausedSetEventFields {
+  domain: Array<number>
+  paused: boolean
+  authority: PublicKey
+}
+
+export interface DomainPausedSetEventJSON {
+  domain: Array<number>
+  paused: boolean
+  authority: string
+}
+
+export class DomainPausedSetEvent {
+  readonly domain: Array<number>
+  readonly paused: boolean
+  readonly authority: PublicKey
+
+  constructor(fields: DomainPausedSetEventFields) {
+    this.domain = fields.domain
+    this.paused = fields.paused
+    this.authority = fields.authority
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.bool("paused"),
+        borsh.publicKey("authority"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainPausedSetEvent({
+      domain: obj.domain,
+      paused: obj.paused,
+      authority: obj.authority,
+    })
+  }
+
+  static toEncodable(fields: DomainPausedSetEventFields) {
+    return {
+      domain: fields.domain,
+      paused: fields.paused,
+      authority: fields.authority,
+    }
+  }
+
+  toJSON(): DomainPausedSetEventJSON {
+    return {
+      domain: this.domain,
+      paused: this.paused,
+      authority: this.authority.toString(),
+    }
+  }
+
+  static fromJSON(obj: DomainPausedSetEventJSON): DomainPausedSetEvent {
+    return new DomainPausedSetEvent({
+      domain: obj.domain,
+      paused: obj.paused,
+      authority: new PublicKey(obj.authority),
+    })
+  }
+
+  toEncodable() {
+    return DomainPausedSetEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainRateLimitSetEventFields {
+  domain: Array<number>
+  rateLimit: number
+}
+
+export interface DomainRateLimitSetEventJSON {
+  domain: Array<number>
+  rateLimit: number
+}
+
+export class DomainRateLimitSetEvent {
+  readonly domain: Array<number>
+  readonly rateLimit: number
+
+  constructor(fields: DomainRateLimitSetEventFields) {
+    this.domain = fields.domain
+    this.rateLimit = fields.rateLimit
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.u32("rateLimit"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainRateLimitSetEvent({
+      domain: obj.domain,
+      rateLimit: obj.rateLimit,
+    })
+  }
+
+  static toEncodable(fields: DomainRateLimitSetEventFields) {
+    return {
+      domain: fields.domain,
+      rateLimit: fields.rateLimit,
+    }
+  }
+
+  toJSON(): DomainRateLimitSetEventJSON {
+    return {
+      domain: this.domain,
+      rateLimit: this.rateLimit,
+    }
+  }
+
+  static fromJSON(obj: DomainRateLimitSetEventJSON): DomainRateLimitSetEvent {
+    return new DomainRateLimitSetEvent({
+      domain: obj.domain,
+      rateLimit: obj.rateLimit,
+    })
+  }
+
+  toEncodable() {
+    return DomainRateLimitSetEvent.toEncodable(this)
+  }
+}
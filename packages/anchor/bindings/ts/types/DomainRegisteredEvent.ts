@@ -0,0 +1,102 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainRegisteredEventFields {
+  domain: Array<number>
+  owner: PublicKey
+  verifier: PublicKey | null
+  defaultSessionSecs: BN
+  maxResultAgeSecs: number
+  maxPermissions: number
+}
+
+export interface DomainRegisteredEventJSON {
+  domain: Array<number>
+  owner: string
+  verifier: string | null
+  defaultSessionSecs: string
+  maxResultAgeSecs: number
+  maxPermissions: number
+}
+
+export class DomainRegisteredEvent {
+  readonly domain: Array<number>
+  readonly owner: PublicKey
+  readonly verifier: PublicKey | null
+  readonly defaultSessionSecs: BN
+  readonly maxResultAgeSecs: number
+  readonly maxPermissions: number
+
+  constructor(fields: DomainRegisteredEventFields) {
+    this.domain = fields.domain
+    this.owner = fields.owner
+    this.verifier = fields.verifier
+    this.defaultSessionSecs = fields.defaultSessionSecs
+    this.maxResultAgeSecs = fields.maxResultAgeSecs
+    this.maxPermissions = fields.maxPermissions
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.publicKey("owner"),
+        borsh.option(borsh.publicKey(), "verifier"),
+        borsh.i64("defaultSessionSecs"),
+        borsh.u32("maxResultAgeSecs"),
+        borsh.u8("maxPermissions"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainRegisteredEvent({
+      domain: obj.domain,
+      owner: obj.owner,
+      verifier: obj.verifier,
+      defaultSessionSecs: obj.defaultSessionSecs,
+      maxResultAgeSecs: obj.maxResultAgeSecs,
+      maxPermissions: obj.maxPermissions,
+    })
+  }
+
+  static toEncodable(fields: DomainRegisteredEventFields) {
+    return {
+      domain: fields.domain,
+      owner: fields.owner,
+      verifier: fields.verifier,
+      defaultSessionSecs: fields.defaultSessionSecs,
+      maxResultAgeSecs: fields.maxResultAgeSecs,
+      maxPermissions: fields.maxPermissions,
+    }
+  }
+
+  toJSON(): DomainRegisteredEventJSON {
+    return {
+      domain: this.domain,
+      owner: this.owner.toString(),
+      verifier: (this.verifier && this.verifier.toString()) || null,
+      defaultSessionSecs: this.defaultSessionSecs.toString(),
+      maxResultAgeSecs: this.maxResultAgeSecs,
+      maxPermissions: this.maxPermissions,
+    }
+  }
+
+  static fromJSON(obj: DomainRegisteredEventJSON): DomainRegisteredEvent {
+    return new DomainRegisteredEvent({
+      domain: obj.domain,
+      owner: new PublicKey(obj.owner),
+      verifier: (obj.verifier && new PublicKey(obj.verifier)) || null,
+      defaultSessionSecs: new BN(obj.defaultSessionSecs),
+      maxResultAgeSecs: obj.maxResultAgeSecs,
+      maxPermissions: obj.maxPermissions,
+    })
+  }
+
+  toEncodable() {
+    return DomainRegisteredEvent.toEncodable(this)
+  }
+}
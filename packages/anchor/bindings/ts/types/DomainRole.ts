@@ -0,0 +1,118 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface OwnerJSON {
+  kind: "Owner"
+}
+
+export class Owner {
+  static readonly discriminator = 0
+  static readonly kind = "Owner"
+  readonly discriminator = 0
+  readonly kind = "Owner"
+
+  toJSON(): OwnerJSON {
+    return {
+      kind: "Owner",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Owner: {},
+    }
+  }
+}
+
+export interface OperatorJSON {
+  kind: "Operator"
+}
+
+export class Operator {
+  static readonly discriminator = 1
+  static readonly kind = "Operator"
+  readonly discriminator = 1
+  readonly kind = "Operator"
+
+  toJSON(): OperatorJSON {
+    return {
+      kind: "Operator",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Operator: {},
+    }
+  }
+}
+
+export interface TreasurerJSON {
+  kind: "Treasurer"
+}
+
+export class Treasurer {
+  static readonly discriminator = 2
+  static readonly kind = "Treasurer"
+  readonly discriminator = 2
+  readonly kind = "Treasurer"
+
+  toJSON(): TreasurerJSON {
+    return {
+      kind: "Treasurer",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Treasurer: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.DomainRoleKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Owner" in obj) {
+    return new Owner()
+  }
+  if ("Operator" in obj) {
+    return new Operator()
+  }
+  if ("Treasurer" in obj) {
+    return new Treasurer()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.DomainRoleJSON): types.DomainRoleKind {
+  switch (obj.kind) {
+    case "Owner": {
+      return new Owner()
+    }
+    case "Operator": {
+      return new Operator()
+    }
+    case "Treasurer": {
+      return new Treasurer()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Owner"),
+    borsh.struct([], "Operator"),
+    borsh.struct([], "Treasurer"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
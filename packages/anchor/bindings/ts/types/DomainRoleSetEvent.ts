@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainRoleSetEventFields {
+  domain: Array<number>
+  role: types.DomainRoleKind
+  key: PublicKey
+}
+
+export interface DomainRoleSetEventJSON {
+  domain: Array<number>
+  role: types.DomainRoleJSON
+  key: string
+}
+
+export class DomainRoleSetEvent {
+  readonly domain: Array<number>
+  readonly role: types.DomainRoleKind
+  readonly key: PublicKey
+
+  constructor(fields: DomainRoleSetEventFields) {
+    this.domain = fields.domain
+    this.role = fields.role
+    this.key = fields.key
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        types.DomainRole.layout("role"),
+        borsh.publicKey("key"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainRoleSetEvent({
+      domain: obj.domain,
+      role: types.DomainRole.fromDecoded(obj.role),
+      key: obj.key,
+    })
+  }
+
+  static toEncodable(fields: DomainRoleSetEventFields) {
+    return {
+      domain: fields.domain,
+      role: fields.role.toEncodable(),
+      key: fields.key,
+    }
+  }
+
+  toJSON(): DomainRoleSetEventJSON {
+    return {
+      domain: this.domain,
+      role: this.role.toJSON(),
+      key: this.key.toString(),
+    }
+  }
+
+  static fromJSON(obj: DomainRoleSetEventJSON): DomainRoleSetEvent {
+    return new DomainRoleSetEvent({
+      domain: obj.domain,
+      role: types.DomainRole.fromJSON(obj.role),
+      key: new PublicKey(obj.key),
+    })
+  }
+
+  toEncodable() {
+    return DomainRoleSetEvent.toEncodable(this)
+  }
+}
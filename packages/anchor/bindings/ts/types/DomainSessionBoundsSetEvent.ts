@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainSessionBoundsSetEventFields {
+  domain: Array<number>
+  minSessionSecs: BN
+  maxSessionSecs: BN
+}
+
+export interface DomainSessionBoundsSetEventJSON {
+  domain: Array<number>
+  minSessionSecs: string
+  maxSessionSecs: string
+}
+
+export class DomainSessionBoundsSetEvent {
+  readonly domain: Array<number>
+  readonly minSessionSecs: BN
+  readonly maxSessionSecs: BN
+
+  constructor(fields: DomainSessionBoundsSetEventFields) {
+    this.domain = fields.domain
+    this.minSessionSecs = fields.minSessionSecs
+    this.maxSessionSecs = fields.maxSessionSecs
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.i64("minSessionSecs"),
+        borsh.i64("maxSessionSecs"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainSessionBoundsSetEvent({
+      domain: obj.domain,
+      minSessionSecs: obj.minSessionSecs,
+      maxSessionSecs: obj.maxSessionSecs,
+    })
+  }
+
+  static toEncodable(fields: DomainSessionBoundsSetEventFields) {
+    return {
+      domain: fields.domain,
+      minSessionSecs: fields.minSessionSecs,
+      maxSessionSecs: fields.maxSessionSecs,
+    }
+  }
+
+  toJSON(): DomainSessionBoundsSetEventJSON {
+    return {
+      domain: this.domain,
+      minSessionSecs: this.minSessionSecs.toString(),
+      maxSessionSecs: this.maxSessionSecs.toString(),
+    }
+  }
+
+  static fromJSON(obj: DomainSessionBoundsSetEventJSON): DomainSessionBoundsSetEvent {
+    return new DomainSessionBoundsSetEvent({
+      domain: obj.domain,
+      minSessionSecs: new BN(obj.minSessionSecs),
+      maxSessionSecs: new BN(obj.maxSessionSecs),
+    })
+  }
+
+  toEncodable() {
+    return DomainSessionBoundsSetEvent.toEncodable(this)
+  }
+}
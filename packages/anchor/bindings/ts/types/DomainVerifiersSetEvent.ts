@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface DomainVerifiersSetEventFields {
+  domain: Array<number>
+  verifiers: Array<PublicKey>
+}
+
+export interface DomainVerifiersSetEventJSON {
+  domain: Array<number>
+  verifiers: Array<string>
+}
+
+export class DomainVerifiersSetEvent {
+  readonly domain: Array<number>
+  readonly verifiers: Array<PublicKey>
+
+  constructor(fields: DomainVerifiersSetEventFields) {
+    this.domain = fields.domain
+    this.verifiers = fields.verifiers
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.vec(borsh.publicKey(), "verifiers"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new DomainVerifiersSetEvent({
+      domain: obj.domain,
+      verifiers: obj.verifiers,
+    })
+  }
+
+  static toEncodable(fields: DomainVerifiersSetEventFields) {
+    return {
+      domain: fields.domain,
+      verifiers: fields.verifiers,
+    }
+  }
+
+  toJSON(): DomainVerifiersSetEventJSON {
+    return {
+      domain: this.domain,
+      verifiers: this.verifiers.map((item) => item.toString()),
+    }
+  }
+
+  static fromJSON(obj: DomainVerifiersSetEventJSON): DomainVerifiersSetEvent {
+    return new DomainVerifiersSetEvent({
+      domain: obj.domain,
+      verifiers: obj.verifiers.map((item) => new PublicKey(item)),
+    })
+  }
+
+  toEncodable() {
+    return DomainVerifiersSetEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,93 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ExpiredNullifierClosedEventFields {
+  nullifier: Array<number>
+  payer: PublicKey
+  cranker: PublicKey
+  reward: BN
+  closedAt: BN
+}
+
+export interface ExpiredNullifierClosedEventJSON {
+  nullifier: Array<number>
+  payer: string
+  cranker: string
+  reward: string
+  closedAt: string
+}
+
+export class ExpiredNullifierClosedEvent {
+  readonly nullifier: Array<number>
+  readonly payer: PublicKey
+  readonly cranker: PublicKey
+  readonly reward: BN
+  readonly closedAt: BN
+
+  constructor(fields: ExpiredNullifierClosedEventFields) {
+    this.nullifier = fields.nullifier
+    this.payer = fields.payer
+    this.cranker = fields.cranker
+    this.reward = fields.reward
+    this.closedAt = fields.closedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("payer"),
+        borsh.publicKey("cranker"),
+        borsh.u64("reward"),
+        borsh.i64("closedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new ExpiredNullifierClosedEvent({
+      nullifier: obj.nullifier,
+      payer: obj.payer,
+      cranker: obj.cranker,
+      reward: obj.reward,
+      closedAt: obj.closedAt,
+    })
+  }
+
+  static toEncodable(fields: ExpiredNullifierClosedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      payer: fields.payer,
+      cranker: fields.cranker,
+      reward: fields.reward,
+      closedAt: fields.closedAt,
+    }
+  }
+
+  toJSON(): ExpiredNullifierClosedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      payer: this.payer.toString(),
+      cranker: this.cranker.toString(),
+      reward: this.reward.toString(),
+      closedAt: this.closedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: ExpiredNullifierClosedEventJSON): ExpiredNullifierClosedEvent {
+    return new ExpiredNullifierClosedEvent({
+      nullifier: obj.nullifier,
+      payer: new PublicKey(obj.payer),
+      cranker: new PublicKey(obj.cranker),
+      reward: new BN(obj.reward),
+      closedAt: new BN(obj.closedAt),
+    })
+  }
+
+  toEncodable() {
+    return ExpiredNullifierClosedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,114 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export type UnixTimeFields = [BN]
+export type UnixTimeValue = [BN]
+
+export interface UnixTimeJSON {
+  kind: "UnixTime"
+  value: [string]
+}
+
+export class UnixTime {
+  static readonly discriminator = 0
+  static readonly kind = "UnixTime"
+  readonly discriminator = 0
+  readonly kind = "UnixTime"
+  readonly value: UnixTimeValue
+
+  constructor(value: UnixTimeFields) {
+    this.value = [value[0]]
+  }
+
+  toJSON(): UnixTimeJSON {
+    return {
+      kind: "UnixTime",
+      value: [this.value[0].toString()],
+    }
+  }
+
+  toEncodable() {
+    return {
+      UnixTime: {
+        _0: this.value[0],
+      },
+    }
+  }
+}
+
+export type SlotFields = [BN]
+export type SlotValue = [BN]
+
+export interface SlotJSON {
+  kind: "Slot"
+  value: [string]
+}
+
+export class Slot {
+  static readonly discriminator = 1
+  static readonly kind = "Slot"
+  readonly discriminator = 1
+  readonly kind = "Slot"
+  readonly value: SlotValue
+
+  constructor(value: SlotFields) {
+    this.value = [value[0]]
+  }
+
+  toJSON(): SlotJSON {
+    return {
+      kind: "Slot",
+      value: [this.value[0].toString()],
+    }
+  }
+
+  toEncodable() {
+    return {
+      Slot: {
+        _0: this.value[0],
+      },
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.ExpiryKindKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("UnixTime" in obj) {
+    const val = obj["UnixTime"]
+    return new UnixTime([val["_0"]])
+  }
+  if ("Slot" in obj) {
+    const val = obj["Slot"]
+    return new Slot([val["_0"]])
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.ExpiryKindJSON): types.ExpiryKindKind {
+  switch (obj.kind) {
+    case "UnixTime": {
+      return new UnixTime([new BN(obj.value[0])])
+    }
+    case "Slot": {
+      return new Slot([new BN(obj.value[0])])
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([borsh.i64("_0")], "UnixTime"),
+    borsh.struct([borsh.u64("_0")], "Slot"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
@@ -0,0 +1,88 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface UnixTimeJSON {
+  kind: "UnixTime"
+}
+
+export class UnixTime {
+  static readonly discriminator = 0
+  static readonly kind = "UnixTime"
+  readonly discriminator = 0
+  readonly kind = "UnixTime"
+
+  toJSON(): UnixTimeJSON {
+    return {
+      kind: "UnixTime",
+    }
+  }
+
+  toEncodable() {
+    return {
+      UnixTime: {},
+    }
+  }
+}
+
+export interface SlotJSON {
+  kind: "Slot"
+}
+
+export class Slot {
+  static readonly discriminator = 1
+  static readonly kind = "Slot"
+  readonly discriminator = 1
+  readonly kind = "Slot"
+
+  toJSON(): SlotJSON {
+    return {
+      kind: "Slot",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Slot: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.ExpiryUnitKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("UnixTime" in obj) {
+    return new UnixTime()
+  }
+  if ("Slot" in obj) {
+    return new Slot()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.ExpiryUnitJSON): types.ExpiryUnitKind {
+  switch (obj.kind) {
+    case "UnixTime": {
+      return new UnixTime()
+    }
+    case "Slot": {
+      return new Slot()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "UnixTime"),
+    borsh.struct([], "Slot"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
@@ -0,0 +1,111 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface FeePaidEventFields {
+  payer: PublicKey
+  mint: PublicKey
+  feeUsdMicros: BN
+  amount: BN
+  price: BN
+  exponent: number
+  publishTime: BN
+}
+
+export interface FeePaidEventJSON {
+  payer: string
+  mint: string
+  feeUsdMicros: string
+  amount: string
+  price: string
+  exponent: number
+  publishTime: string
+}
+
+export class FeePaidEvent {
+  readonly payer: PublicKey
+  readonly mint: PublicKey
+  readonly feeUsdMicros: BN
+  readonly amount: BN
+  readonly price: BN
+  readonly exponent: number
+  readonly publishTime: BN
+
+  constructor(fields: FeePaidEventFields) {
+    this.payer = fields.payer
+    this.mint = fields.mint
+    this.feeUsdMicros = fields.feeUsdMicros
+    this.amount = fields.amount
+    this.price = fields.price
+    this.exponent = fields.exponent
+    this.publishTime = fields.publishTime
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("payer"),
+        borsh.publicKey("mint"),
+        borsh.u64("feeUsdMicros"),
+        borsh.u64("amount"),
+        borsh.i64("price"),
+        borsh.i32("exponent"),
+        borsh.i64("publishTime"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new FeePaidEvent({
+      payer: obj.payer,
+      mint: obj.mint,
+      feeUsdMicros: obj.feeUsdMicros,
+      amount: obj.amount,
+      price: obj.price,
+      exponent: obj.exponent,
+      publishTime: obj.publishTime,
+    })
+  }
+
+  static toEncodable(fields: FeePaidEventFields) {
+    return {
+      payer: fields.payer,
+      mint: fields.mint,
+      feeUsdMicros: fields.feeUsdMicros,
+      amount: fields.amount,
+      price: fields.price,
+      exponent: fields.exponent,
+      publishTime: fields.publishTime,
+    }
+  }
+
+  toJSON(): FeePaidEventJSON {
+    return {
+      payer: this.payer.toString(),
+      mint: this.mint.toString(),
+      feeUsdMicros: this.feeUsdMicros.toString(),
+      amount: this.amount.toString(),
+      price: this.price.toString(),
+      exponent: this.exponent,
+      publishTime: this.publishTime.toString(),
+    }
+  }
+
+  static fromJSON(obj: FeePaidEventJSON): FeePaidEvent {
+    return new FeePaidEvent({
+      payer: new PublicKey(obj.payer),
+      mint: new PublicKey(obj.mint),
+      feeUsdMicros: new BN(obj.feeUsdMicros),
+      amount: new BN(obj.amount),
+      price: new BN(obj.price),
+      exponent: obj.exponent,
+      publishTime: new BN(obj.publishTime),
+    })
+  }
+
+  toEncodable() {
+    return FeePaidEvent.toEncodable(this)
+  }
+}
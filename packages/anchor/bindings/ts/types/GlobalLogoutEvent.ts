@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface GlobalLogoutEventFields {
+  identity: PublicKey
+  loggedOutAt: BN
+}
+
+export interface GlobalLogoutEventJSON {
+  identity: string
+  loggedOutAt: string
+}
+
+export class GlobalLogoutEvent {
+  readonly identity: PublicKey
+  readonly loggedOutAt: BN
+
+  constructor(fields: GlobalLogoutEventFields) {
+    this.identity = fields.identity
+    this.loggedOutAt = fields.loggedOutAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("identity"),
+        borsh.i64("loggedOutAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new GlobalLogoutEvent({
+      identity: obj.identity,
+      loggedOutAt: obj.loggedOutAt,
+    })
+  }
+
+  static toEncodable(fields: GlobalLogoutEventFields) {
+    return {
+      identity: fields.identity,
+      loggedOutAt: fields.loggedOutAt,
+    }
+  }
+
+  toJSON(): GlobalLogoutEventJSON {
+    return {
+      identity: this.identity.toString(),
+      loggedOutAt: this.loggedOutAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: GlobalLogoutEventJSON): GlobalLogoutEvent {
+    return new GlobalLogoutEvent({
+      identity: new PublicKey(obj.identity),
+      loggedOutAt: new BN(obj.loggedOutAt),
+    })
+  }
+
+  toEncodable() {
+    return GlobalLogoutEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface Groth16VkRegisteredEventFields {
+  circuitId: number
+  vkHash: Array<number>
+  publicInputs: number
+}
+
+export interface Groth16VkRegisteredEventJSON {
+  circuitId: number
+  vkHash: Array<number>
+  publicInputs: number
+}
+
+export class Groth16VkRegisteredEvent {
+  readonly circuitId: number
+  readonly vkHash: Array<number>
+  readonly publicInputs: number
+
+  constructor(fields: Groth16VkRegisteredEventFields) {
+    this.circuitId = fields.circuitId
+    this.vkHash = fields.vkHash
+    this.publicInputs = fields.publicInputs
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u32("circuitId"),
+        borsh.array(borsh.u8(), 32, "vkHash"),
+        borsh.u8("publicInputs"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new Groth16VkRegisteredEvent({
+      circuitId: obj.circuitId,
+      vkHash: obj.vkHash,
+      publicInputs: obj.publicInputs,
+    })
+  }
+
+  static toEncodable(fields: Groth16VkRegisteredEventFields) {
+    return {
+      circuitId: fields.circuitId,
+      vkHash: fields.vkHash,
+      publicInputs: fields.publicInputs,
+    }
+  }
+
+  toJSON(): Groth16VkRegisteredEventJSON {
+    return {
+      circuitId: this.circuitId,
+      vkHash: this.vkHash,
+      publicInputs: this.publicInputs,
+    }
+  }
+
+  static fromJSON(obj: Groth16VkRegisteredEventJSON): Groth16VkRegisteredEvent {
+    return new Groth16VkRegisteredEvent({
+      circuitId: obj.circuitId,
+      vkHash: obj.vkHash,
+      publicInputs: obj.publicInputs,
+    })
+  }
+
+  toEncodable() {
+    return Groth16VkRegisteredEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,93 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface GuardiansSetEventFields {
+  nullifier: Array<number>
+  guardians: Array<PublicKey>
+  threshold: number
+  nonce: BN
+  updatedAt: BN
+}
+
+export interface GuardiansSetEventJSON {
+  nullifier: Array<number>
+  guardians: Array<string>
+  threshold: number
+  nonce: string
+  updatedAt: string
+}
+
+export class GuardiansSetEvent {
+  readonly nullifier: Array<number>
+  readonly guardians: Array<PublicKey>
+  readonly threshold: number
+  readonly nonce: BN
+  readonly updatedAt: BN
+
+  constructor(fields: GuardiansSetEventFields) {
+    this.nullifier = fields.nullifier
+    this.guardians = fields.guardians
+    this.threshold = fields.threshold
+    this.nonce = fields.nonce
+    this.updatedAt = fields.updatedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.vec(borsh.publicKey(), "guardians"),
+        borsh.u8("threshold"),
+        borsh.u64("nonce"),
+        borsh.i64("updatedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new GuardiansSetEvent({
+      nullifier: obj.nullifier,
+      guardians: obj.guardians,
+      threshold: obj.threshold,
+      nonce: obj.nonce,
+      updatedAt: obj.updatedAt,
+    })
+  }
+
+  static toEncodable(fields: GuardiansSetEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      guardians: fields.guardians,
+      threshold: fields.threshold,
+      nonce: fields.nonce,
+      updatedAt: fields.updatedAt,
+    }
+  }
+
+  toJSON(): GuardiansSetEventJSON {
+    return {
+      nullifier: this.nullifier,
+      guardians: this.guardians.map((item) => item.toString()),
+      threshold: this.threshold,
+      nonce: this.nonce.toString(),
+      updatedAt: this.updatedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: GuardiansSetEventJSON): GuardiansSetEvent {
+    return new GuardiansSetEvent({
+      nullifier: obj.nullifier,
+      guardians: obj.guardians.map((item) => new PublicKey(item)),
+      threshold: obj.threshold,
+      nonce: new BN(obj.nonce),
+      updatedAt: new BN(obj.updatedAt),
+    })
+  }
+
+  toEncodable() {
+    return GuardiansSetEvent.toEncodable(this)
+  }
+}
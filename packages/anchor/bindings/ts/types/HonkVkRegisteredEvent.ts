@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface HonkVkRegisteredEventFields {
+  circuitId: number
+  vkHash: Array<number>
+  logCircuitSize: number
+  publicInputsSize: number
+}
+
+export interface HonkVkRegisteredEventJSON {
+  circuitId: number
+  vkHash: Array<number>
+  logCircuitSize: number
+  publicInputsSize: number
+}
+
+export class HonkVkRegisteredEvent {
+  readonly circuitId: number
+  readonly vkHash: Array<number>
+  readonly logCircuitSize: number
+  readonly publicInputsSize: number
+
+  constructor(fields: HonkVkRegisteredEventFields) {
+    this.circuitId = fields.circuitId
+    this.vkHash = fields.vkHash
+    this.logCircuitSize = fields.logCircuitSize
+    this.publicInputsSize = fields.publicInputsSize
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u32("circuitId"),
+        borsh.array(borsh.u8(), 32, "vkHash"),
+        borsh.u8("logCircuitSize"),
+        borsh.u32("publicInputsSize"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new HonkVkRegisteredEvent({
+      circuitId: obj.circuitId,
+      vkHash: obj.vkHash,
+      logCircuitSize: obj.logCircuitSize,
+      publicInputsSize: obj.publicInputsSize,
+    })
+  }
+
+  static toEncodable(fields: HonkVkRegisteredEventFields) {
+    return {
+      circuitId: fields.circuitId,
+      vkHash: fields.vkHash,
+      logCircuitSize: fields.logCircuitSize,
+      publicInputsSize: fields.publicInputsSize,
+    }
+  }
+
+  toJSON(): HonkVkRegisteredEventJSON {
+    return {
+      circuitId: this.circuitId,
+      vkHash: this.vkHash,
+      logCircuitSize: this.logCircuitSize,
+      publicInputsSize: this.publicInputsSize,
+    }
+  }
+
+  static fromJSON(obj: HonkVkRegisteredEventJSON): HonkVkRegisteredEvent {
+    return new HonkVkRegisteredEvent({
+      circuitId: obj.circuitId,
+      vkHash: obj.vkHash,
+      logCircuitSize: obj.logCircuitSize,
+      publicInputsSize: obj.publicInputsSize,
+    })
+  }
+
+  toEncodable() {
+    return HonkVkRegisteredEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,129 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface IdentityRecoveredEventFields {
+  nullifier: Array<number>
+  newNullifier: Array<number>
+  domain: Array<number>
+  proofHash: Array<number>
+  newAuthority: PublicKey
+  sessions: number
+  grants: number
+  approvals: number
+  recoveredAt: BN
+}
+
+export interface IdentityRecoveredEventJSON {
+  nullifier: Array<number>
+  newNullifier: Array<number>
+  domain: Array<number>
+  proofHash: Array<number>
+  newAuthority: string
+  sessions: number
+  grants: number
+  approvals: number
+  recoveredAt: string
+}
+
+export class IdentityRecoveredEvent {
+  readonly nullifier: Array<number>
+  readonly newNullifier: Array<number>
+  readonly domain: Array<number>
+  readonly proofHash: Array<number>
+  readonly newAuthority: PublicKey
+  readonly sessions: number
+  readonly grants: number
+  readonly approvals: number
+  readonly recoveredAt: BN
+
+  constructor(fields: IdentityRecoveredEventFields) {
+    this.nullifier = fields.nullifier
+    this.newNullifier = fields.newNullifier
+    this.domain = fields.domain
+    this.proofHash = fields.proofHash
+    this.newAuthority = fields.newAuthority
+    this.sessions = fields.sessions
+    this.grants = fields.grants
+    this.approvals = fields.approvals
+    this.recoveredAt = fields.recoveredAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.array(borsh.u8(), 32, "newNullifier"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.array(borsh.u8(), 32, "proofHash"),
+        borsh.publicKey("newAuthority"),
+        borsh.u32("sessions"),
+        borsh.u32("grants"),
+        borsh.u8("approvals"),
+        borsh.i64("recoveredAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new IdentityRecoveredEvent({
+      nullifier: obj.nullifier,
+      newNullifier: obj.newNullifier,
+      domain: obj.domain,
+      proofHash: obj.proofHash,
+      newAuthority: obj.newAuthority,
+      sessions: obj.sessions,
+      grants: obj.grants,
+      approvals: obj.approvals,
+      recoveredAt: obj.recoveredAt,
+    })
+  }
+
+  static toEncodable(fields: IdentityRecoveredEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      newNullifier: fields.newNullifier,
+      domain: fields.domain,
+      proofHash: fields.proofHash,
+      newAuthority: fields.newAuthority,
+      sessions: fields.sessions,
+      grants: fields.grants,
+      approvals: fields.approvals,
+      recoveredAt: fields.recoveredAt,
+    }
+  }
+
+  toJSON(): IdentityRecoveredEventJSON {
+    return {
+      nullifier: this.nullifier,
+      newNullifier: this.newNullifier,
+      domain: this.domain,
+      proofHash: this.proofHash,
+      newAuthority: this.newAuthority.toString(),
+      sessions: this.sessions,
+      grants: this.grants,
+      approvals: this.approvals,
+      recoveredAt: this.recoveredAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: IdentityRecoveredEventJSON): IdentityRecoveredEvent {
+    return new IdentityRecoveredEvent({
+      nullifier: obj.nullifier,
+      newNullifier: obj.newNullifier,
+      domain: obj.domain,
+      proofHash: obj.proofHash,
+      newAuthority: new PublicKey(obj.newAuthority),
+      sessions: obj.sessions,
+      grants: obj.grants,
+      approvals: obj.approvals,
+      recoveredAt: new BN(obj.recoveredAt),
+    })
+  }
+
+  toEncodable() {
+    return IdentityRecoveredEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface IdentityRootCreatedEventFields {
+  root: PublicKey
+  owner: PublicKey
+  createdAt: BN
+}
+
+export interface IdentityRootCreatedEventJSON {
+  root: string
+  owner: string
+  createdAt: string
+}
+
+export class IdentityRootCreatedEvent {
+  readonly root: PublicKey
+  readonly owner: PublicKey
+  readonly createdAt: BN
+
+  constructor(fields: IdentityRootCreatedEventFields) {
+    this.root = fields.root
+    this.owner = fields.owner
+    this.createdAt = fields.createdAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("root"),
+        borsh.publicKey("owner"),
+        borsh.i64("createdAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new IdentityRootCreatedEvent({
+      root: obj.root,
+      owner: obj.owner,
+      createdAt: obj.createdAt,
+    })
+  }
+
+  static toEncodable(fields: IdentityRootCreatedEventFields) {
+    return {
+      root: fields.root,
+      owner: fields.owner,
+      createdAt: fields.createdAt,
+    }
+  }
+
+  toJSON(): IdentityRootCreatedEventJSON {
+    return {
+      root: this.root.toString(),
+      owner: this.owner.toString(),
+      createdAt: this.createdAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: IdentityRootCreatedEventJSON): IdentityRootCreatedEvent {
+    return new IdentityRootCreatedEvent({
+      root: new PublicKey(obj.root),
+      owner: new PublicKey(obj.owner),
+      createdAt: new BN(obj.createdAt),
+    })
+  }
+
+  toEncodable() {
+    return IdentityRootCreatedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,93 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface IdentityRootLinkedEventFields {
+  root: PublicKey
+  nullifier: Array<number>
+  domain: Array<number>
+  linkageProofHash: Array<number>
+  linkedAt: BN
+}
+
+export interface IdentityRootLinkedEventJSON {
+  root: string
+  nullifier: Array<number>
+  domain: Array<number>
+  linkageProofHash: Array<number>
+  linkedAt: string
+}
+
+export class IdentityRootLinkedEvent {
+  readonly root: PublicKey
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly linkageProofHash: Array<number>
+  readonly linkedAt: BN
+
+  constructor(fields: IdentityRootLinkedEventFields) {
+    this.root = fields.root
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.linkageProofHash = fields.linkageProofHash
+    this.linkedAt = fields.linkedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("root"),
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.array(borsh.u8(), 32, "linkageProofHash"),
+        borsh.i64("linkedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new IdentityRootLinkedEvent({
+      root: obj.root,
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      linkageProofHash: obj.linkageProofHash,
+      linkedAt: obj.linkedAt,
+    })
+  }
+
+  static toEncodable(fields: IdentityRootLinkedEventFields) {
+    return {
+      root: fields.root,
+      nullifier: fields.nullifier,
+      domain: fields.domain,
+      linkageProofHash: fields.linkageProofHash,
+      linkedAt: fields.linkedAt,
+    }
+  }
+
+  toJSON(): IdentityRootLinkedEventJSON {
+    return {
+      root: this.root.toString(),
+      nullifier: this.nullifier,
+      domain: this.domain,
+      linkageProofHash: this.linkageProofHash,
+      linkedAt: this.linkedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: IdentityRootLinkedEventJSON): IdentityRootLinkedEvent {
+    return new IdentityRootLinkedEvent({
+      root: new PublicKey(obj.root),
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      linkageProofHash: obj.linkageProofHash,
+      linkedAt: new BN(obj.linkedAt),
+    })
+  }
+
+  toEncodable() {
+    return IdentityRootLinkedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface IdentityRootUnlinkedEventFields {
+  root: PublicKey
+  nullifier: Array<number>
+  unlinkedAt: BN
+}
+
+export interface IdentityRootUnlinkedEventJSON {
+  root: string
+  nullifier: Array<number>
+  unlinkedAt: string
+}
+
+export class IdentityRootUnlinkedEvent {
+  readonly root: PublicKey
+  readonly nullifier: Array<number>
+  readonly unlinkedAt: BN
+
+  constructor(fields: IdentityRootUnlinkedEventFields) {
+    this.root = fields.root
+    this.nullifier = fields.nullifier
+    this.unlinkedAt = fields.unlinkedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("root"),
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.i64("unlinkedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new IdentityRootUnlinkedEvent({
+      root: obj.root,
+      nullifier: obj.nullifier,
+      unlinkedAt: obj.unlinkedAt,
+    })
+  }
+
+  static toEncodable(fields: IdentityRootUnlinkedEventFields) {
+    return {
+      root: fields.root,
+      nullifier: fields.nullifier,
+      unlinkedAt: fields.unlinkedAt,
+    }
+  }
+
+  toJSON(): IdentityRootUnlinkedEventJSON {
+    return {
+      root: this.root.toString(),
+      nullifier: this.nullifier,
+      unlinkedAt: this.unlinkedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: IdentityRootUnlinkedEventJSON): IdentityRootUnlinkedEvent {
+    return new IdentityRootUnlinkedEvent({
+      root: new PublicKey(obj.root),
+      nullifier: obj.nullifier,
+      unlinkedAt: new BN(obj.unlinkedAt),
+    })
+  }
+
+  toEncodable() {
+    return IdentityRootUnlinkedEvent.toEncodable(this)
+  }
+}
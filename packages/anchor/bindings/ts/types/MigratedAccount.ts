@@ -0,0 +1,148 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SessionJSON {
+  kind: "Session"
+}
+
+export class Session {
+  static readonly discriminator = 0
+  static readonly kind = "Session"
+  readonly discriminator = 0
+  readonly kind = "Session"
+
+  toJSON(): SessionJSON {
+    return {
+      kind: "Session",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Session: {},
+    }
+  }
+}
+
+export interface ProofRecordJSON {
+  kind: "ProofRecord"
+}
+
+export class ProofRecord {
+  static readonly discriminator = 1
+  static readonly kind = "ProofRecord"
+  readonly discriminator = 1
+  readonly kind = "ProofRecord"
+
+  toJSON(): ProofRecordJSON {
+    return {
+      kind: "ProofRecord",
+    }
+  }
+
+  toEncodable() {
+    return {
+      ProofRecord: {},
+    }
+  }
+}
+
+export interface PermissionGrantJSON {
+  kind: "PermissionGrant"
+}
+
+export class PermissionGrant {
+  static readonly discriminator = 2
+  static readonly kind = "PermissionGrant"
+  readonly discriminator = 2
+  readonly kind = "PermissionGrant"
+
+  toJSON(): PermissionGrantJSON {
+    return {
+      kind: "PermissionGrant",
+    }
+  }
+
+  toEncodable() {
+    return {
+      PermissionGrant: {},
+    }
+  }
+}
+
+export interface DomainConfigJSON {
+  kind: "DomainConfig"
+}
+
+export class DomainConfig {
+  static readonly discriminator = 3
+  static readonly kind = "DomainConfig"
+  readonly discriminator = 3
+  readonly kind = "DomainConfig"
+
+  toJSON(): DomainConfigJSON {
+    return {
+      kind: "DomainConfig",
+    }
+  }
+
+  toEncodable() {
+    return {
+      DomainConfig: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.MigratedAccountKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Session" in obj) {
+    return new Session()
+  }
+  if ("ProofRecord" in obj) {
+    return new ProofRecord()
+  }
+  if ("PermissionGrant" in obj) {
+    return new PermissionGrant()
+  }
+  if ("DomainConfig" in obj) {
+    return new DomainConfig()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.MigratedAccountJSON): types.MigratedAccountKind {
+  switch (obj.kind) {
+    case "Session": {
+      return new Session()
+    }
+    case "ProofRecord": {
+      return new ProofRecord()
+    }
+    case "PermissionGrant": {
+      return new PermissionGrant()
+    }
+    case "DomainConfig": {
+      return new DomainConfig()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Session"),
+    borsh.struct([], "ProofRecord"),
+    borsh.struct([], "PermissionGrant"),
+    borsh.struct([], "DomainConfig"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
@@ -0,0 +1,88 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface MigrationBegunEventFields {
+  version: number
+  targetVersion: number
+  steps: Array<types.MigrationStep>
+  deadline: BN
+}
+
+export interface MigrationBegunEventJSON {
+  version: number
+  targetVersion: number
+  steps: Array<types.MigrationStepJSON>
+  deadline: string
+}
+
+export class MigrationBegunEvent {
+  readonly version: number
+  readonly targetVersion: number
+  readonly steps: Array<types.MigrationStep>
+  readonly deadline: BN
+
+  constructor(fields: MigrationBegunEventFields) {
+    this.version = fields.version
+    this.targetVersion = fields.targetVersion
+    this.steps = fields.steps
+    this.deadline = fields.deadline
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u16("version"),
+        borsh.u16("targetVersion"),
+        borsh.vec(types.MigrationStep.layout(), "steps"),
+        borsh.i64("deadline"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new MigrationBegunEvent({
+      version: obj.version,
+      targetVersion: obj.targetVersion,
+      steps: obj.steps.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.MigrationStep.fromDecoded(item)
+      ),
+      deadline: obj.deadline,
+    })
+  }
+
+  static toEncodable(fields: MigrationBegunEventFields) {
+    return {
+      version: fields.version,
+      targetVersion: fields.targetVersion,
+      steps: fields.steps.map((item) => types.MigrationStep.toEncodable(item)),
+      deadline: fields.deadline,
+    }
+  }
+
+  toJSON(): MigrationBegunEventJSON {
+    return {
+      version: this.version,
+      targetVersion: this.targetVersion,
+      steps: this.steps.map((item) => item.toJSON()),
+      deadline: this.deadline.toString(),
+    }
+  }
+
+  static fromJSON(obj: MigrationBegunEventJSON): MigrationBegunEvent {
+    return new MigrationBegunEvent({
+      version: obj.version,
+      targetVersion: obj.targetVersion,
+      steps: obj.steps.map((item) => types.MigrationStep.fromJSON(item)),
+      deadline: new BN(obj.deadline),
+    })
+  }
+
+  toEncodable() {
+    return MigrationBegunEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,79 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface MigrationCompletedEventFields {
+  version: number
+  steps: Array<types.MigrationStep>
+  forced: boolean
+}
+
+export interface MigrationCompletedEventJSON {
+  version: number
+  steps: Array<types.MigrationStepJSON>
+  forced: boolean
+}
+
+export class MigrationCompletedEvent {
+  readonly version: number
+  readonly steps: Array<types.MigrationStep>
+  readonly forced: boolean
+
+  constructor(fields: MigrationCompletedEventFields) {
+    this.version = fields.version
+    this.steps = fields.steps
+    this.forced = fields.forced
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u16("version"),
+        borsh.vec(types.MigrationStep.layout(), "steps"),
+        borsh.bool("forced"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new MigrationCompletedEvent({
+      version: obj.version,
+      steps: obj.steps.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.MigrationStep.fromDecoded(item)
+      ),
+      forced: obj.forced,
+    })
+  }
+
+  static toEncodable(fields: MigrationCompletedEventFields) {
+    return {
+      version: fields.version,
+      steps: fields.steps.map((item) => types.MigrationStep.toEncodable(item)),
+      forced: fields.forced,
+    }
+  }
+
+  toJSON(): MigrationCompletedEventJSON {
+    return {
+      version: this.version,
+      steps: this.steps.map((item) => item.toJSON()),
+      forced: this.forced,
+    }
+  }
+
+  static fromJSON(obj: MigrationCompletedEventJSON): MigrationCompletedEvent {
+    return new MigrationCompletedEvent({
+      version: obj.version,
+      steps: obj.steps.map((item) => types.MigrationStep.fromJSON(item)),
+      forced: obj.forced,
+    })
+  }
+
+  toEncodable() {
+    return MigrationCompletedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface MigrationProgressEventFields {
+  targetVersion: number
+  account: types.MigratedAccountKind
+  migrated: BN
+  total: BN
+}
+
+export interface MigrationProgressEventJSON {
+  targetVersion: number
+  account: types.MigratedAccountJSON
+  migrated: string
+  total: string
+}
+
+export class MigrationProgressEvent {
+  readonly targetVersion: number
+  readonly account: types.MigratedAccountKind
+  readonly migrated: BN
+  readonly total: BN
+
+  constructor(fields: MigrationProgressEventFields) {
+    this.targetVersion = fields.targetVersion
+    this.account = fields.account
+    this.migrated = fields.migrated
+    this.total = fields.total
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u16("targetVersion"),
+        types.MigratedAccount.layout("account"),
+        borsh.u64("migrated"),
+        borsh.u64("total"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new MigrationProgressEvent({
+      targetVersion: obj.targetVersion,
+      account: types.MigratedAccount.fromDecoded(obj.account),
+      migrated: obj.migrated,
+      total: obj.total,
+    })
+  }
+
+  static toEncodable(fields: MigrationProgressEventFields) {
+    return {
+      targetVersion: fields.targetVersion,
+      account: fields.account.toEncodable(),
+      migrated: fields.migrated,
+      total: fields.total,
+    }
+  }
+
+  toJSON(): MigrationProgressEventJSON {
+    return {
+      targetVersion: this.targetVersion,
+      account: this.account.toJSON(),
+      migrated: this.migrated.toString(),
+      total: this.total.toString(),
+    }
+  }
+
+  static fromJSON(obj: MigrationProgressEventJSON): MigrationProgressEvent {
+    return new MigrationProgressEvent({
+      targetVersion: obj.targetVersion,
+      account: types.MigratedAccount.fromJSON(obj.account),
+      migrated: new BN(obj.migrated),
+      total: new BN(obj.total),
+    })
+  }
+
+  toEncodable() {
+    return MigrationProgressEvent.toEncodable(this)
+  }
+}
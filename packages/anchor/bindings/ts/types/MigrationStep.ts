@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface MigrationStepFields {
+  account: types.MigratedAccountKind
+  required: boolean
+  total: BN
+  migrated: BN
+}
+
+export interface MigrationStepJSON {
+  account: types.MigratedAccountJSON
+  required: boolean
+  total: string
+  migrated: string
+}
+
+export class MigrationStep {
+  readonly account: types.MigratedAccountKind
+  readonly required: boolean
+  readonly total: BN
+  readonly migrated: BN
+
+  constructor(fields: MigrationStepFields) {
+    this.account = fields.account
+    this.required = fields.required
+    this.total = fields.total
+    this.migrated = fields.migrated
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        types.MigratedAccount.layout("account"),
+        borsh.bool("required"),
+        borsh.u64("total"),
+        borsh.u64("migrated"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new MigrationStep({
+      account: types.MigratedAccount.fromDecoded(obj.account),
+      required: obj.required,
+      total: obj.total,
+      migrated: obj.migrated,
+    })
+  }
+
+  static toEncodable(fields: MigrationStepFields) {
+    return {
+      account: fields.account.toEncodable(),
+      required: fields.required,
+      total: fields.total,
+      migrated: fields.migrated,
+    }
+  }
+
+  toJSON(): MigrationStepJSON {
+    return {
+      account: this.account.toJSON(),
+      required: this.required,
+      total: this.total.toString(),
+      migrated: this.migrated.toString(),
+    }
+  }
+
+  static fromJSON(obj: MigrationStepJSON): MigrationStep {
+    return new MigrationStep({
+      account: types.MigratedAccount.fromJSON(obj.account),
+      required: obj.required,
+      total: new BN(obj.total),
+      migrated: new BN(obj.migrated),
+    })
+  }
+
+  toEncodable() {
+    return MigrationStep.toEncodable(this)
+  }
+}
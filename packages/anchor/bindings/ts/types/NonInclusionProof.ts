@@ -0,0 +1,93 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface NonInclusionProofFields {
+  root: Array<number>
+  lowIndex: number
+  lowValue: Array<number>
+  lowNextIndex: number
+  lowNextValue: Array<number>
+}
+
+export interface NonInclusionProofJSON {
+  root: Array<number>
+  lowIndex: number
+  lowValue: Array<number>
+  lowNextIndex: number
+  lowNextValue: Array<number>
+}
+
+export class NonInclusionProof {
+  readonly root: Array<number>
+  readonly lowIndex: number
+  readonly lowValue: Array<number>
+  readonly lowNextIndex: number
+  readonly lowNextValue: Array<number>
+
+  constructor(fields: NonInclusionProofFields) {
+    this.root = fields.root
+    this.lowIndex = fields.lowIndex
+    this.lowValue = fields.lowValue
+    this.lowNextIndex = fields.lowNextIndex
+    this.lowNextValue = fields.lowNextValue
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "root"),
+        borsh.u32("lowIndex"),
+        borsh.array(borsh.u8(), 32, "lowValue"),
+        borsh.u32("lowNextIndex"),
+        borsh.array(borsh.u8(), 32, "lowNextValue"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new NonInclusionProof({
+      root: obj.root,
+      lowIndex: obj.lowIndex,
+      lowValue: obj.lowValue,
+      lowNextIndex: obj.lowNextIndex,
+      lowNextValue: obj.lowNextValue,
+    })
+  }
+
+  static toEncodable(fields: NonInclusionProofFields) {
+    return {
+      root: fields.root,
+      lowIndex: fields.lowIndex,
+      lowValue: fields.lowValue,
+      lowNextIndex: fields.lowNextIndex,
+      lowNextValue: fields.lowNextValue,
+    }
+  }
+
+  toJSON(): NonInclusionProofJSON {
+    return {
+      root: this.root,
+      lowIndex: this.lowIndex,
+      lowValue: this.lowValue,
+      lowNextIndex: this.lowNextIndex,
+      lowNextValue: this.lowNextValue,
+    }
+  }
+
+  static fromJSON(obj: NonInclusionProofJSON): NonInclusionProof {
+    return new NonInclusionProof({
+      root: obj.root,
+      lowIndex: obj.lowIndex,
+      lowValue: obj.lowValue,
+      lowNextIndex: obj.lowNextIndex,
+      lowNextValue: obj.lowNextValue,
+    })
+  }
+
+  toEncodable() {
+    return NonInclusionProof.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface NotificationChannelClosedEventFields {
+  nullifier: Array<number>
+  appId: PublicKey
+  closedAt: BN
+}
+
+export interface NotificationChannelClosedEventJSON {
+  nullifier: Array<number>
+  appId: string
+  closedAt: string
+}
+
+export class NotificationChannelClosedEvent {
+  readonly nullifier: Array<number>
+  readonly appId: PublicKey
+  readonly closedAt: BN
+
+  constructor(fields: NotificationChannelClosedEventFields) {
+    this.nullifier = fields.nullifier
+    this.appId = fields.appId
+    this.closedAt = fields.closedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("appId"),
+        borsh.i64("closedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new NotificationChannelClosedEvent({
+      nullifier: obj.nullifier,
+      appId: obj.appId,
+      closedAt: obj.closedAt,
+    })
+  }
+
+  static toEncodable(fields: NotificationChannelClosedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      appId: fields.appId,
+      closedAt: fields.closedAt,
+    }
+  }
+
+  toJSON(): NotificationChannelClosedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      appId: this.appId.toString(),
+      closedAt: this.closedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: NotificationChannelClosedEventJSON): NotificationChannelClosedEvent {
+    return new NotificationChannelClosedEvent({
+      nullifier: obj.nullifier,
+      appId: new PublicKey(obj.appId),
+      closedAt: new BN(obj.closedAt),
+    })
+  }
+
+  toEncodable() {
+    return NotificationChannelClosedEvent.toEncodable(this)
+  }
+}
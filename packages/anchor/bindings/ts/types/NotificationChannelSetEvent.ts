@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface NotificationChannelSetEventFields {
+  nullifier: Array<number>
+  appId: PublicKey
+  channelCommitment: Array<number>
+  updatedAt: BN
+}
+
+export interface NotificationChannelSetEventJSON {
+  nullifier: Array<number>
+  appId: string
+  channelCommitment: Array<number>
+  updatedAt: string
+}
+
+export class NotificationChannelSetEvent {
+  readonly nullifier: Array<number>
+  readonly appId: PublicKey
+  readonly channelCommitment: Array<number>
+  readonly updatedAt: BN
+
+  constructor(fields: NotificationChannelSetEventFields) {
+    this.nullifier = fields.nullifier
+    this.appId = fields.appId
+    this.channelCommitment = fields.channelCommitment
+    this.updatedAt = fields.updatedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("appId"),
+        borsh.array(borsh.u8(), 32, "channelCommitment"),
+        borsh.i64("updatedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new NotificationChannelSetEvent({
+      nullifier: obj.nullifier,
+      appId: obj.appId,
+      channelCommitment: obj.channelCommitment,
+      updatedAt: obj.updatedAt,
+    })
+  }
+
+  static toEncodable(fields: NotificationChannelSetEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      appId: fields.appId,
+      channelCommitment: fields.channelCommitment,
+      updatedAt: fields.updatedAt,
+    }
+  }
+
+  toJSON(): NotificationChannelSetEventJSON {
+    return {
+      nullifier: this.nullifier,
+      appId: this.appId.toString(),
+      channelCommitment: this.channelCommitment,
+      updatedAt: this.updatedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: NotificationChannelSetEventJSON): NotificationChannelSetEvent {
+    return new NotificationChannelSetEvent({
+      nullifier: obj.nullifier,
+      appId: new PublicKey(obj.appId),
+      channelCommitment: obj.channelCommitment,
+      updatedAt: new BN(obj.updatedAt),
+    })
+  }
+
+  toEncodable() {
+    return NotificationChannelSetEvent.toEncodable(this)
+  }
+}
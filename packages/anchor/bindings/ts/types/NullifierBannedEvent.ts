@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface NullifierBannedEventFields {
+  domain: Array<number>
+  nullifier: Array<number>
+  bannedAt: BN
+}
+
+export interface NullifierBannedEventJSON {
+  domain: Array<number>
+  nullifier: Array<number>
+  bannedAt: string
+}
+
+export class NullifierBannedEvent {
+  readonly domain: Array<number>
+  readonly nullifier: Array<number>
+  readonly bannedAt: BN
+
+  constructor(fields: NullifierBannedEventFields) {
+    this.domain = fields.domain
+    this.nullifier = fields.nullifier
+    this.bannedAt = fields.bannedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.i64("bannedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new NullifierBannedEvent({
+      domain: obj.domain,
+      nullifier: obj.nullifier,
+      bannedAt: obj.bannedAt,
+    })
+  }
+
+  static toEncodable(fields: NullifierBannedEventFields) {
+    return {
+      domain: fields.domain,
+      nullifier: fields.nullifier,
+      bannedAt: fields.bannedAt,
+    }
+  }
+
+  toJSON(): NullifierBannedEventJSON {
+    return {
+      domain: this.domain,
+      nullifier: this.nullifier,
+      bannedAt: this.bannedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: NullifierBannedEventJSON): NullifierBannedEvent {
+    return new NullifierBannedEvent({
+      domain: obj.domain,
+      nullifier: obj.nullifier,
+      bannedAt: new BN(obj.bannedAt),
+    })
+  }
+
+  toEncodable() {
+    return NullifierBannedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,102 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface NullifierMigratedEventFields {
+  nullifier: Array<number>
+  successor: Array<number>
+  domain: Array<number>
+  proofHash: Array<number>
+  grants: Array<PublicKey>
+  migratedAt: BN
+}
+
+export interface NullifierMigratedEventJSON {
+  nullifier: Array<number>
+  successor: Array<number>
+  domain: Array<number>
+  proofHash: Array<number>
+  grants: Array<string>
+  migratedAt: string
+}
+
+export class NullifierMigratedEvent {
+  readonly nullifier: Array<number>
+  readonly successor: Array<number>
+  readonly domain: Array<number>
+  readonly proofHash: Array<number>
+  readonly grants: Array<PublicKey>
+  readonly migratedAt: BN
+
+  constructor(fields: NullifierMigratedEventFields) {
+    this.nullifier = fields.nullifier
+    this.successor = fields.successor
+    this.domain = fields.domain
+    this.proofHash = fields.proofHash
+    this.grants = fields.grants
+    this.migratedAt = fields.migratedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.array(borsh.u8(), 32, "successor"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.array(borsh.u8(), 32, "proofHash"),
+        borsh.vec(borsh.publicKey(), "grants"),
+        borsh.i64("migratedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new NullifierMigratedEvent({
+      nullifier: obj.nullifier,
+      successor: obj.successor,
+      domain: obj.domain,
+      proofHash: obj.proofHash,
+      grants: obj.grants,
+      migratedAt: obj.migratedAt,
+    })
+  }
+
+  static toEncodable(fields: NullifierMigratedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      successor: fields.successor,
+      domain: fields.domain,
+      proofHash: fields.proofHash,
+      grants: fields.grants,
+      migratedAt: fields.migratedAt,
+    }
+  }
+
+  toJSON(): NullifierMigratedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      successor: this.successor,
+      domain: this.domain,
+      proofHash: this.proofHash,
+      grants: this.grants.map((item) => item.toString()),
+      migratedAt: this.migratedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: NullifierMigratedEventJSON): NullifierMigratedEvent {
+    return new NullifierMigratedEvent({
+      nullifier: obj.nullifier,
+      successor: obj.successor,
+      domain: obj.domain,
+      proofHash: obj.proofHash,
+      grants: obj.grants.map((item) => new PublicKey(item)),
+      migratedAt: new BN(obj.migratedAt),
+    })
+  }
+
+  toEncodable() {
+    return NullifierMigratedEvent.toEncodable(this)
+  }
+}
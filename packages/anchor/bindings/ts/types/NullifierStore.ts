@@ -0,0 +1,118 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface AccountsJSON {
+  kind: "Accounts"
+}
+
+export class Accounts {
+  static readonly discriminator = 0
+  static readonly kind = "Accounts"
+  readonly discriminator = 0
+  readonly kind = "Accounts"
+
+  toJSON(): AccountsJSON {
+    return {
+      kind: "Accounts",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Accounts: {},
+    }
+  }
+}
+
+export interface BitmapJSON {
+  kind: "Bitmap"
+}
+
+export class Bitmap {
+  static readonly discriminator = 1
+  static readonly kind = "Bitmap"
+  readonly discriminator = 1
+  readonly kind = "Bitmap"
+
+  toJSON(): BitmapJSON {
+    return {
+      kind: "Bitmap",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Bitmap: {},
+    }
+  }
+}
+
+export interface CompressedJSON {
+  kind: "Compressed"
+}
+
+export class Compressed {
+  static readonly discriminator = 2
+  static readonly kind = "Compressed"
+  readonly discriminator = 2
+  readonly kind = "Compressed"
+
+  toJSON(): CompressedJSON {
+    return {
+      kind: "Compressed",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Compressed: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.NullifierStoreKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Accounts" in obj) {
+    return new Accounts()
+  }
+  if ("Bitmap" in obj) {
+    return new Bitmap()
+  }
+  if ("Compressed" in obj) {
+    return new Compressed()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.NullifierStoreJSON): types.NullifierStoreKind {
+  switch (obj.kind) {
+    case "Accounts": {
+      return new Accounts()
+    }
+    case "Bitmap": {
+      return new Bitmap()
+    }
+    case "Compressed": {
+      return new Compressed()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Accounts"),
+    borsh.struct([], "Bitmap"),
+    borsh.struct([], "Compressed"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
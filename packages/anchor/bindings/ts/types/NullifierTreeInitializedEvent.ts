@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface NullifierTreeInitializedEventFields {
+  domain: Array<number>
+  merkleTree: PublicKey
+  maxDepth: number
+  maxBufferSize: number
+}
+
+export interface NullifierTreeInitializedEventJSON {
+  domain: Array<number>
+  merkleTree: string
+  maxDepth: number
+  maxBufferSize: number
+}
+
+export class NullifierTreeInitializedEvent {
+  readonly domain: Array<number>
+  readonly merkleTree: PublicKey
+  readonly maxDepth: number
+  readonly maxBufferSize: number
+
+  constructor(fields: NullifierTreeInitializedEventFields) {
+    this.domain = fields.domain
+    this.merkleTree = fields.merkleTree
+    this.maxDepth = fields.maxDepth
+    this.maxBufferSize = fields.maxBufferSize
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.publicKey("merkleTree"),
+        borsh.u32("maxDepth"),
+        borsh.u32("maxBufferSize"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new NullifierTreeInitializedEvent({
+      domain: obj.domain,
+      merkleTree: obj.merkleTree,
+      maxDepth: obj.maxDepth,
+      maxBufferSize: obj.maxBufferSize,
+    })
+  }
+
+  static toEncodable(fields: NullifierTreeInitializedEventFields) {
+    return {
+      domain: fields.domain,
+      merkleTree: fields.merkleTree,
+      maxDepth: fields.maxDepth,
+      maxBufferSize: fields.maxBufferSize,
+    }
+  }
+
+  toJSON(): NullifierTreeInitializedEventJSON {
+    return {
+      domain: this.domain,
+      merkleTree: this.merkleTree.toString(),
+      maxDepth: this.maxDepth,
+      maxBufferSize: this.maxBufferSize,
+    }
+  }
+
+  static fromJSON(obj: NullifierTreeInitializedEventJSON): NullifierTreeInitializedEvent {
+    return new NullifierTreeInitializedEvent({
+      domain: obj.domain,
+      merkleTree: new PublicKey(obj.merkleTree),
+      maxDepth: obj.maxDepth,
+      maxBufferSize: obj.maxBufferSize,
+    })
+  }
+
+  toEncodable() {
+    return NullifierTreeInitializedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface NullifierUnbannedEventFields {
+  domain: Array<number>
+  nullifier: Array<number>
+  unbannedAt: BN
+}
+
+export interface NullifierUnbannedEventJSON {
+  domain: Array<number>
+  nullifier: Array<number>
+  unbannedAt: string
+}
+
+export class NullifierUnbannedEvent {
+  readonly domain: Array<number>
+  readonly nullifier: Array<number>
+  readonly unbannedAt: BN
+
+  constructor(fields: NullifierUnbannedEventFields) {
+    this.domain = fields.domain
+    this.nullifier = fields.nullifier
+    this.unbannedAt = fields.unbannedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.i64("unbannedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new NullifierUnbannedEvent({
+      domain: obj.domain,
+      nullifier: obj.nullifier,
+      unbannedAt: obj.unbannedAt,
+    })
+  }
+
+  static toEncodable(fields: NullifierUnbannedEventFields) {
+    return {
+      domain: fields.domain,
+      nullifier: fields.nullifier,
+      unbannedAt: fields.unbannedAt,
+    }
+  }
+
+  toJSON(): NullifierUnbannedEventJSON {
+    return {
+      domain: this.domain,
+      nullifier: this.nullifier,
+      unbannedAt: this.unbannedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: NullifierUnbannedEventJSON): NullifierUnbannedEvent {
+    return new NullifierUnbannedEvent({
+      domain: obj.domain,
+      nullifier: obj.nullifier,
+      unbannedAt: new BN(obj.unbannedAt),
+    })
+  }
+
+  toEncodable() {
+    return NullifierUnbannedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,298 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface RevealWalletAddressJSON {
+  kind: "RevealWalletAddress"
+}
+
+export class RevealWalletAddress {
+  static readonly discriminator = 0
+  static readonly kind = "RevealWalletAddress"
+  readonly discriminator = 0
+  readonly kind = "RevealWalletAddress"
+
+  toJSON(): RevealWalletAddressJSON {
+    return {
+      kind: "RevealWalletAddress",
+    }
+  }
+
+  toEncodable() {
+    return {
+      RevealWalletAddress: {},
+    }
+  }
+}
+
+export interface RevealExactBalanceJSON {
+  kind: "RevealExactBalance"
+}
+
+export class RevealExactBalance {
+  static readonly discriminator = 1
+  static readonly kind = "RevealExactBalance"
+  readonly discriminator = 1
+  readonly kind = "RevealExactBalance"
+
+  toJSON(): RevealExactBalanceJSON {
+    return {
+      kind: "RevealExactBalance",
+    }
+  }
+
+  toEncodable() {
+    return {
+      RevealExactBalance: {},
+    }
+  }
+}
+
+export interface RevealTokenBalancesJSON {
+  kind: "RevealTokenBalances"
+}
+
+export class RevealTokenBalances {
+  static readonly discriminator = 2
+  static readonly kind = "RevealTokenBalances"
+  readonly discriminator = 2
+  readonly kind = "RevealTokenBalances"
+
+  toJSON(): RevealTokenBalancesJSON {
+    return {
+      kind: "RevealTokenBalances",
+    }
+  }
+
+  toEncodable() {
+    return {
+      RevealTokenBalances: {},
+    }
+  }
+}
+
+export interface RevealNFTListJSON {
+  kind: "RevealNFTList"
+}
+
+export class RevealNFTList {
+  static readonly discriminator = 3
+  static readonly kind = "RevealNFTList"
+  readonly discriminator = 3
+  readonly kind = "RevealNFTList"
+
+  toJSON(): RevealNFTListJSON {
+    return {
+      kind: "RevealNFTList",
+    }
+  }
+
+  toEncodable() {
+    return {
+      RevealNFTList: {},
+    }
+  }
+}
+
+export interface RevealTransactionHistoryJSON {
+  kind: "RevealTransactionHistory"
+}
+
+export class RevealTransactionHistory {
+  static readonly discriminator = 4
+  static readonly kind = "RevealTransactionHistory"
+  readonly discriminator = 4
+  readonly kind = "RevealTransactionHistory"
+
+  toJSON(): RevealTransactionHistoryJSON {
+    return {
+      kind: "RevealTransactionHistory",
+    }
+  }
+
+  toEncodable() {
+    return {
+      RevealTransactionHistory: {},
+    }
+  }
+}
+
+export interface RevealStakingPositionsJSON {
+  kind: "RevealStakingPositions"
+}
+
+export class RevealStakingPositions {
+  static readonly discriminator = 5
+  static readonly kind = "RevealStakingPositions"
+  readonly discriminator = 5
+  readonly kind = "RevealStakingPositions"
+
+  toJSON(): RevealStakingPositionsJSON {
+    return {
+      kind: "RevealStakingPositions",
+    }
+  }
+
+  toEncodable() {
+    return {
+      RevealStakingPositions: {},
+    }
+  }
+}
+
+export interface RevealDeFiPositionsJSON {
+  kind: "RevealDeFiPositions"
+}
+
+export class RevealDeFiPositions {
+  static readonly discriminator = 6
+  static readonly kind = "RevealDeFiPositions"
+  readonly discriminator = 6
+  readonly kind = "RevealDeFiPositions"
+
+  toJSON(): RevealDeFiPositionsJSON {
+    return {
+      kind: "RevealDeFiPositions",
+    }
+  }
+
+  toEncodable() {
+    return {
+      RevealDeFiPositions: {},
+    }
+  }
+}
+
+export interface SignTransactionsJSON {
+  kind: "SignTransactions"
+}
+
+export class SignTransactions {
+  static readonly discriminator = 7
+  static readonly kind = "SignTransactions"
+  readonly discriminator = 7
+  readonly kind = "SignTransactions"
+
+  toJSON(): SignTransactionsJSON {
+    return {
+      kind: "SignTransactions",
+    }
+  }
+
+  toEncodable() {
+    return {
+      SignTransactions: {},
+    }
+  }
+}
+
+export interface ReceiveNotificationsJSON {
+  kind: "ReceiveNotifications"
+}
+
+export class ReceiveNotifications {
+  static readonly discriminator = 8
+  static readonly kind = "ReceiveNotifications"
+  readonly discriminator = 8
+  readonly kind = "ReceiveNotifications"
+
+  toJSON(): ReceiveNotificationsJSON {
+    return {
+      kind: "ReceiveNotifications",
+    }
+  }
+
+  toEncodable() {
+    return {
+      ReceiveNotifications: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.PermissionKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("RevealWalletAddress" in obj) {
+    return new RevealWalletAddress()
+  }
+  if ("RevealExactBalance" in obj) {
+    return new RevealExactBalance()
+  }
+  if ("RevealTokenBalances" in obj) {
+    return new RevealTokenBalances()
+  }
+  if ("RevealNFTList" in obj) {
+    return new RevealNFTList()
+  }
+  if ("RevealTransactionHistory" in obj) {
+    return new RevealTransactionHistory()
+  }
+  if ("RevealStakingPositions" in obj) {
+    return new RevealStakingPositions()
+  }
+  if ("RevealDeFiPositions" in obj) {
+    return new RevealDeFiPositions()
+  }
+  if ("SignTransactions" in obj) {
+    return new SignTransactions()
+  }
+  if ("ReceiveNotifications" in obj) {
+    return new ReceiveNotifications()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.PermissionJSON): types.PermissionKind {
+  switch (obj.kind) {
+    case "RevealWalletAddress": {
+      return new RevealWalletAddress()
+    }
+    case "RevealExactBalance": {
+      return new RevealExactBalance()
+    }
+    case "RevealTokenBalances": {
+      return new RevealTokenBalances()
+    }
+    case "RevealNFTList": {
+      return new RevealNFTList()
+    }
+    case "RevealTransactionHistory": {
+      return new RevealTransactionHistory()
+    }
+    case "RevealStakingPositions": {
+      return new RevealStakingPositions()
+    }
+    case "RevealDeFiPositions": {
+      return new RevealDeFiPositions()
+    }
+    case "SignTransactions": {
+      return new SignTransactions()
+    }
+    case "ReceiveNotifications": {
+      return new ReceiveNotifications()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "RevealWalletAddress"),
+    borsh.struct([], "RevealExactBalance"),
+    borsh.struct([], "RevealTokenBalances"),
+    borsh.struct([], "RevealNFTList"),
+    borsh.struct([], "RevealTransactionHistory"),
+    borsh.struct([], "RevealStakingPositions"),
+    borsh.struct([], "RevealDeFiPositions"),
+    borsh.struct([], "SignTransactions"),
+    borsh.struct([], "ReceiveNotifications"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
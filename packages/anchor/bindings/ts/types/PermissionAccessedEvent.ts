@@ -0,0 +1,93 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface PermissionAccessedEventFields {
+  nullifier: Array<number>
+  appId: PublicKey
+  permission: types.PermissionKind
+  permissionMask: BN
+  accessedAt: BN
+}
+
+export interface PermissionAccessedEventJSON {
+  nullifier: Array<number>
+  appId: string
+  permission: types.PermissionJSON
+  permissionMask: string
+  accessedAt: string
+}
+
+export class PermissionAccessedEvent {
+  readonly nullifier: Array<number>
+  readonly appId: PublicKey
+  readonly permission: types.PermissionKind
+  readonly permissionMask: BN
+  readonly accessedAt: BN
+
+  constructor(fields: PermissionAccessedEventFields) {
+    this.nullifier = fields.nullifier
+    this.appId = fields.appId
+    this.permission = fields.permission
+    this.permissionMask = fields.permissionMask
+    this.accessedAt = fields.accessedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("appId"),
+        types.Permission.layout("permission"),
+        borsh.u64("permissionMask"),
+        borsh.i64("accessedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new PermissionAccessedEvent({
+      nullifier: obj.nullifier,
+      appId: obj.appId,
+      permission: types.Permission.fromDecoded(obj.permission),
+      permissionMask: obj.permissionMask,
+      accessedAt: obj.accessedAt,
+    })
+  }
+
+  static toEncodable(fields: PermissionAccessedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      appId: fields.appId,
+      permission: fields.permission.toEncodable(),
+      permissionMask: fields.permissionMask,
+      accessedAt: fields.accessedAt,
+    }
+  }
+
+  toJSON(): PermissionAccessedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      appId: this.appId.toString(),
+      permission: this.permission.toJSON(),
+      permissionMask: this.permissionMask.toString(),
+      accessedAt: this.accessedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: PermissionAccessedEventJSON): PermissionAccessedEvent {
+    return new PermissionAccessedEvent({
+      nullifier: obj.nullifier,
+      appId: new PublicKey(obj.appId),
+      permission: types.Permission.fromJSON(obj.permission),
+      permissionMask: new BN(obj.permissionMask),
+      accessedAt: new BN(obj.accessedAt),
+    })
+  }
+
+  toEncodable() {
+    return PermissionAccessedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,118 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface RevokedJSON {
+  kind: "Revoked"
+}
+
+export class Revoked {
+  static readonly discriminator = 0
+  static readonly kind = "Revoked"
+  readonly discriminator = 0
+  readonly kind = "Revoked"
+
+  toJSON(): RevokedJSON {
+    return {
+      kind: "Revoked",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Revoked: {},
+    }
+  }
+}
+
+export interface ExpiredJSON {
+  kind: "Expired"
+}
+
+export class Expired {
+  static readonly discriminator = 1
+  static readonly kind = "Expired"
+  readonly discriminator = 1
+  readonly kind = "Expired"
+
+  toJSON(): ExpiredJSON {
+    return {
+      kind: "Expired",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Expired: {},
+    }
+  }
+}
+
+export interface NotGrantedJSON {
+  kind: "NotGranted"
+}
+
+export class NotGranted {
+  static readonly discriminator = 2
+  static readonly kind = "NotGranted"
+  readonly discriminator = 2
+  readonly kind = "NotGranted"
+
+  toJSON(): NotGrantedJSON {
+    return {
+      kind: "NotGranted",
+    }
+  }
+
+  toEncodable() {
+    return {
+      NotGranted: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.PermissionDenialReasonKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Revoked" in obj) {
+    return new Revoked()
+  }
+  if ("Expired" in obj) {
+    return new Expired()
+  }
+  if ("NotGranted" in obj) {
+    return new NotGranted()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.PermissionDenialReasonJSON): types.PermissionDenialReasonKind {
+  switch (obj.kind) {
+    case "Revoked": {
+      return new Revoked()
+    }
+    case "Expired": {
+      return new Expired()
+    }
+    case "NotGranted": {
+      return new NotGranted()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Revoked"),
+    borsh.struct([], "Expired"),
+    borsh.struct([], "NotGranted"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
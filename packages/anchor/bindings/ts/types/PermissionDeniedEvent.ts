@@ -0,0 +1,102 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface PermissionDeniedEventFields {
+  nullifier: Array<number>
+  appId: PublicKey
+  permission: types.PermissionKind
+  permissionMask: BN
+  reason: types.PermissionDenialReasonKind
+  checkedAt: BN
+}
+
+export interface PermissionDeniedEventJSON {
+  nullifier: Array<number>
+  appId: string
+  permission: types.PermissionJSON
+  permissionMask: string
+  reason: types.PermissionDenialReasonJSON
+  checkedAt: string
+}
+
+export class PermissionDeniedEvent {
+  readonly nullifier: Array<number>
+  readonly appId: PublicKey
+  readonly permission: types.PermissionKind
+  readonly permissionMask: BN
+  readonly reason: types.PermissionDenialReasonKind
+  readonly checkedAt: BN
+
+  constructor(fields: PermissionDeniedEventFields) {
+    this.nullifier = fields.nullifier
+    this.appId = fields.appId
+    this.permission = fields.permission
+    this.permissionMask = fields.permissionMask
+    this.reason = fields.reason
+    this.checkedAt = fields.checkedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("appId"),
+        types.Permission.layout("permission"),
+        borsh.u64("permissionMask"),
+        types.PermissionDenialReason.layout("reason"),
+        borsh.i64("checkedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new PermissionDeniedEvent({
+      nullifier: obj.nullifier,
+      appId: obj.appId,
+      permission: types.Permission.fromDecoded(obj.permission),
+      permissionMask: obj.permissionMask,
+      reason: types.PermissionDenialReason.fromDecoded(obj.reason),
+      checkedAt: obj.checkedAt,
+    })
+  }
+
+  static toEncodable(fields: PermissionDeniedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      appId: fields.appId,
+      permission: fields.permission.toEncodable(),
+      permissionMask: fields.permissionMask,
+      reason: fields.reason.toEncodable(),
+      checkedAt: fields.checkedAt,
+    }
+  }
+
+  toJSON(): PermissionDeniedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      appId: this.appId.toString(),
+      permission: this.permission.toJSON(),
+      permissionMask: this.permissionMask.toString(),
+      reason: this.reason.toJSON(),
+      checkedAt: this.checkedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: PermissionDeniedEventJSON): PermissionDeniedEvent {
+    return new PermissionDeniedEvent({
+      nullifier: obj.nullifier,
+      appId: new PublicKey(obj.appId),
+      permission: types.Permission.fromJSON(obj.permission),
+      permissionMask: new BN(obj.permissionMask),
+      reason: types.PermissionDenialReason.fromJSON(obj.reason),
+      checkedAt: new BN(obj.checkedAt),
+    })
+  }
+
+  toEncodable() {
+    return PermissionDeniedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,106 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface PermissionGrantedEventFields {
+  nullifier: Array<number>
+  appId: PublicKey
+  permissions: Array<types.PermissionKind>
+  permissionMask: BN
+  grantedAt: BN
+  expiresAt: types.ExpiryKindKind
+}
+
+export interface PermissionGrantedEventJSON {
+  nullifier: Array<number>
+  appId: string
+  permissions: Array<types.PermissionJSON>
+  permissionMask: string
+  grantedAt: string
+  expiresAt: types.ExpiryKindJSON
+}
+
+export class PermissionGrantedEvent {
+  readonly nullifier: Array<number>
+  readonly appId: PublicKey
+  readonly permissions: Array<types.PermissionKind>
+  readonly permissionMask: BN
+  readonly grantedAt: BN
+  readonly expiresAt: types.ExpiryKindKind
+
+  constructor(fields: PermissionGrantedEventFields) {
+    this.nullifier = fields.nullifier
+    this.appId = fields.appId
+    this.permissions = fields.permissions
+    this.permissionMask = fields.permissionMask
+    this.grantedAt = fields.grantedAt
+    this.expiresAt = fields.expiresAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("appId"),
+        borsh.vec(types.Permission.layout(), "permissions"),
+        borsh.u64("permissionMask"),
+        borsh.i64("grantedAt"),
+        types.ExpiryKind.layout("expiresAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new PermissionGrantedEvent({
+      nullifier: obj.nullifier,
+      appId: obj.appId,
+      permissions: obj.permissions.map(
+        (
+          item: any /* eslint-disable-line @typescript-eslint/no-explicit-any */
+        ) => types.Permission.fromDecoded(item)
+      ),
+      permissionMask: obj.permissionMask,
+      grantedAt: obj.grantedAt,
+      expiresAt: types.ExpiryKind.fromDecoded(obj.expiresAt),
+    })
+  }
+
+  static toEncodable(fields: PermissionGrantedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      appId: fields.appId,
+      permissions: fields.permissions.map((item) => item.toEncodable()),
+      permissionMask: fields.permissionMask,
+      grantedAt: fields.grantedAt,
+      expiresAt: fields.expiresAt.toEncodable(),
+    }
+  }
+
+  toJSON(): PermissionGrantedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      appId: this.appId.toString(),
+      permissions: this.permissions.map((item) => item.toJSON()),
+      permissionMask: this.permissionMask.toString(),
+      grantedAt: this.grantedAt.toString(),
+      expiresAt: this.expiresAt.toJSON(),
+    }
+  }
+
+  static fromJSON(obj: PermissionGrantedEventJSON): PermissionGrantedEvent {
+    return new PermissionGrantedEvent({
+      nullifier: obj.nullifier,
+      appId: new PublicKey(obj.appId),
+      permissions: obj.permissions.map((item) => types.Permission.fromJSON(item)),
+      permissionMask: new BN(obj.permissionMask),
+      grantedAt: new BN(obj.grantedAt),
+      expiresAt: types.ExpiryKind.fromJSON(obj.expiresAt),
+    })
+  }
+
+  toEncodable() {
+    return PermissionGrantedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface PermissionRevokedEventFields {
+  nullifier: Array<number>
+  appId: PublicKey
+  revokedAt: BN
+}
+
+export interface PermissionRevokedEventJSON {
+  nullifier: Array<number>
+  appId: string
+  revokedAt: string
+}
+
+export class PermissionRevokedEvent {
+  readonly nullifier: Array<number>
+  readonly appId: PublicKey
+  readonly revokedAt: BN
+
+  constructor(fields: PermissionRevokedEventFields) {
+    this.nullifier = fields.nullifier
+    this.appId = fields.appId
+    this.revokedAt = fields.revokedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("appId"),
+        borsh.i64("revokedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new PermissionRevokedEvent({
+      nullifier: obj.nullifier,
+      appId: obj.appId,
+      revokedAt: obj.revokedAt,
+    })
+  }
+
+  static toEncodable(fields: PermissionRevokedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      appId: fields.appId,
+      revokedAt: fields.revokedAt,
+    }
+  }
+
+  toJSON(): PermissionRevokedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      appId: this.appId.toString(),
+      revokedAt: this.revokedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: PermissionRevokedEventJSON): PermissionRevokedEvent {
+    return new PermissionRevokedEvent({
+      nullifier: obj.nullifier,
+      appId: new PublicKey(obj.appId),
+      revokedAt: new BN(obj.revokedAt),
+    })
+  }
+
+  toEncodable() {
+    return PermissionRevokedEvent.toEncodable(this)
+  }
+}
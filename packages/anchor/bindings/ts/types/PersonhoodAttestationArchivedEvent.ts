@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface PersonhoodAttestationArchivedEventFields {
+  nullifier: Array<number>
+  provider: PublicKey
+  attestationHash: Array<number>
+  archivedAt: BN
+}
+
+export interface PersonhoodAttestationArchivedEventJSON {
+  nullifier: Array<number>
+  provider: string
+  attestationHash: Array<number>
+  archivedAt: string
+}
+
+export class PersonhoodAttestationArchivedEvent {
+  readonly nullifier: Array<number>
+  readonly provider: PublicKey
+  readonly attestationHash: Array<number>
+  readonly archivedAt: BN
+
+  constructor(fields: PersonhoodAttestationArchivedEventFields) {
+    this.nullifier = fields.nullifier
+    this.provider = fields.provider
+    this.attestationHash = fields.attestationHash
+    this.archivedAt = fields.archivedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("provider"),
+        borsh.array(borsh.u8(), 32, "attestationHash"),
+        borsh.i64("archivedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new PersonhoodAttestationArchivedEvent({
+      nullifier: obj.nullifier,
+      provider: obj.provider,
+      attestationHash: obj.attestationHash,
+      archivedAt: obj.archivedAt,
+    })
+  }
+
+  static toEncodable(fields: PersonhoodAttestationArchivedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      provider: fields.provider,
+      attestationHash: fields.attestationHash,
+      archivedAt: fields.archivedAt,
+    }
+  }
+
+  toJSON(): PersonhoodAttestationArchivedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      provider: this.provider.toString(),
+      attestationHash: this.attestationHash,
+      archivedAt: this.archivedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: PersonhoodAttestationArchivedEventJSON): PersonhoodAttestationArchivedEvent {
+    return new PersonhoodAttestationArchivedEvent({
+      nullifier: obj.nullifier,
+      provider: new PublicKey(obj.provider),
+      attestationHash: obj.attestationHash,
+      archivedAt: new BN(obj.archivedAt),
+    })
+  }
+
+  toEncodable() {
+    return PersonhoodAttestationArchivedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,93 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface PersonhoodAttestationRestoredEventFields {
+  nullifier: Array<number>
+  provider: PublicKey
+  score: number
+  issuedAt: BN
+  expiresAt: BN
+}
+
+export interface PersonhoodAttestationRestoredEventJSON {
+  nullifier: Array<number>
+  provider: string
+  score: number
+  issuedAt: string
+  expiresAt: string
+}
+
+export class PersonhoodAttestationRestoredEvent {
+  readonly nullifier: Array<number>
+  readonly provider: PublicKey
+  readonly score: number
+  readonly issuedAt: BN
+  readonly expiresAt: BN
+
+  constructor(fields: PersonhoodAttestationRestoredEventFields) {
+    this.nullifier = fields.nullifier
+    this.provider = fields.provider
+    this.score = fields.score
+    this.issuedAt = fields.issuedAt
+    this.expiresAt = fields.expiresAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("provider"),
+        borsh.u16("score"),
+        borsh.i64("issuedAt"),
+        borsh.i64("expiresAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new PersonhoodAttestationRestoredEvent({
+      nullifier: obj.nullifier,
+      provider: obj.provider,
+      score: obj.score,
+      issuedAt: obj.issuedAt,
+      expiresAt: obj.expiresAt,
+    })
+  }
+
+  static toEncodable(fields: PersonhoodAttestationRestoredEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      provider: fields.provider,
+      score: fields.score,
+      issuedAt: fields.issuedAt,
+      expiresAt: fields.expiresAt,
+    }
+  }
+
+  toJSON(): PersonhoodAttestationRestoredEventJSON {
+    return {
+      nullifier: this.nullifier,
+      provider: this.provider.toString(),
+      score: this.score,
+      issuedAt: this.issuedAt.toString(),
+      expiresAt: this.expiresAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: PersonhoodAttestationRestoredEventJSON): PersonhoodAttestationRestoredEvent {
+    return new PersonhoodAttestationRestoredEvent({
+      nullifier: obj.nullifier,
+      provider: new PublicKey(obj.provider),
+      score: obj.score,
+      issuedAt: new BN(obj.issuedAt),
+      expiresAt: new BN(obj.expiresAt),
+    })
+  }
+
+  toEncodable() {
+    return PersonhoodAttestationRestoredEvent.toEncodable(this)
+  }
+}
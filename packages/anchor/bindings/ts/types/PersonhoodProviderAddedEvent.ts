@@ -0,0 +1,57 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface PersonhoodProviderAddedEventFields {
+  provider: PublicKey
+}
+
+export interface PersonhoodProviderAddedEventJSON {
+  provider: string
+}
+
+export class PersonhoodProviderAddedEvent {
+  readonly provider: PublicKey
+
+  constructor(fields: PersonhoodProviderAddedEventFields) {
+    this.provider = fields.provider
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("provider"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new PersonhoodProviderAddedEvent({
+      provider: obj.provider,
+    })
+  }
+
+  static toEncodable(fields: PersonhoodProviderAddedEventFields) {
+    return {
+      provider: fields.provider,
+    }
+  }
+
+  toJSON(): PersonhoodProviderAddedEventJSON {
+    return {
+      provider: this.provider.toString(),
+    }
+  }
+
+  static fromJSON(obj: PersonhoodProviderAddedEventJSON): PersonhoodProviderAddedEvent {
+    return new PersonhoodProviderAddedEvent({
+      provider: new PublicKey(obj.provider),
+    })
+  }
+
+  toEncodable() {
+    return PersonhoodProviderAddedEvent.toEncodable(this)
+  }
+}
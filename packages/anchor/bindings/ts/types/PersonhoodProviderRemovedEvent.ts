@@ -0,0 +1,57 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface PersonhoodProviderRemovedEventFields {
+  provider: PublicKey
+}
+
+export interface PersonhoodProviderRemovedEventJSON {
+  provider: string
+}
+
+export class PersonhoodProviderRemovedEvent {
+  readonly provider: PublicKey
+
+  constructor(fields: PersonhoodProviderRemovedEventFields) {
+    this.provider = fields.provider
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("provider"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new PersonhoodProviderRemovedEvent({
+      provider: obj.provider,
+    })
+  }
+
+  static toEncodable(fields: PersonhoodProviderRemovedEventFields) {
+    return {
+      provider: fields.provider,
+    }
+  }
+
+  toJSON(): PersonhoodProviderRemovedEventJSON {
+    return {
+      provider: this.provider.toString(),
+    }
+  }
+
+  static fromJSON(obj: PersonhoodProviderRemovedEventJSON): PersonhoodProviderRemovedEvent {
+    return new PersonhoodProviderRemovedEvent({
+      provider: new PublicKey(obj.provider),
+    })
+  }
+
+  toEncodable() {
+    return PersonhoodProviderRemovedEvent.toEncodable(this)
+  }
+}
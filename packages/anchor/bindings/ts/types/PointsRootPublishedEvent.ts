@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface PointsRootPublishedEventFields {
+  root: Array<number>
+  leafCount: number
+  publishedAt: BN
+}
+
+export interface PointsRootPublishedEventJSON {
+  root: Array<number>
+  leafCount: number
+  publishedAt: string
+}
+
+export class PointsRootPublishedEvent {
+  readonly root: Array<number>
+  readonly leafCount: number
+  readonly publishedAt: BN
+
+  constructor(fields: PointsRootPublishedEventFields) {
+    this.root = fields.root
+    this.leafCount = fields.leafCount
+    this.publishedAt = fields.publishedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "root"),
+        borsh.u32("leafCount"),
+        borsh.i64("publishedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new PointsRootPublishedEvent({
+      root: obj.root,
+      leafCount: obj.leafCount,
+      publishedAt: obj.publishedAt,
+    })
+  }
+
+  static toEncodable(fields: PointsRootPublishedEventFields) {
+    return {
+      root: fields.root,
+      leafCount: fields.leafCount,
+      publishedAt: fields.publishedAt,
+    }
+  }
+
+  toJSON(): PointsRootPublishedEventJSON {
+    return {
+      root: this.root,
+      leafCount: this.leafCount,
+      publishedAt: this.publishedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: PointsRootPublishedEventJSON): PointsRootPublishedEvent {
+    return new PointsRootPublishedEvent({
+      root: obj.root,
+      leafCount: obj.leafCount,
+      publishedAt: new BN(obj.publishedAt),
+    })
+  }
+
+  toEncodable() {
+    return PointsRootPublishedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface PricingUpdatedEventFields {
+  feeUsdMicros: BN
+  maxPriceAgeSecs: BN
+  maxPriceConfBps: number
+}
+
+export interface PricingUpdatedEventJSON {
+  feeUsdMicros: string
+  maxPriceAgeSecs: string
+  maxPriceConfBps: number
+}
+
+export class PricingUpdatedEvent {
+  readonly feeUsdMicros: BN
+  readonly maxPriceAgeSecs: BN
+  readonly maxPriceConfBps: number
+
+  constructor(fields: PricingUpdatedEventFields) {
+    this.feeUsdMicros = fields.feeUsdMicros
+    this.maxPriceAgeSecs = fields.maxPriceAgeSecs
+    this.maxPriceConfBps = fields.maxPriceConfBps
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u64("feeUsdMicros"),
+        borsh.u64("maxPriceAgeSecs"),
+        borsh.u16("maxPriceConfBps"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new PricingUpdatedEvent({
+      feeUsdMicros: obj.feeUsdMicros,
+      maxPriceAgeSecs: obj.maxPriceAgeSecs,
+      maxPriceConfBps: obj.maxPriceConfBps,
+    })
+  }
+
+  static toEncodable(fields: PricingUpdatedEventFields) {
+    return {
+      feeUsdMicros: fields.feeUsdMicros,
+      maxPriceAgeSecs: fields.maxPriceAgeSecs,
+      maxPriceConfBps: fields.maxPriceConfBps,
+    }
+  }
+
+  toJSON(): PricingUpdatedEventJSON {
+    return {
+      feeUsdMicros: this.feeUsdMicros.toString(),
+      maxPriceAgeSecs: this.maxPriceAgeSecs.toString(),
+      maxPriceConfBps: this.maxPriceConfBps,
+    }
+  }
+
+  static fromJSON(obj: PricingUpdatedEventJSON): PricingUpdatedEvent {
+    return new PricingUpdatedEvent({
+      feeUsdMicros: new BN(obj.feeUsdMicros),
+      maxPriceAgeSecs: new BN(obj.maxPriceAgeSecs),
+      maxPriceConfBps: obj.maxPriceConfBps,
+    })
+  }
+
+  toEncodable() {
+    return PricingUpdatedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,57 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ReceiptRetentionSetEventFields {
+  receiptRetentionSecs: BN
+}
+
+export interface ReceiptRetentionSetEventJSON {
+  receiptRetentionSecs: string
+}
+
+export class ReceiptRetentionSetEvent {
+  readonly receiptRetentionSecs: BN
+
+  constructor(fields: ReceiptRetentionSetEventFields) {
+    this.receiptRetentionSecs = fields.receiptRetentionSecs
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.i64("receiptRetentionSecs"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new ReceiptRetentionSetEvent({
+      receiptRetentionSecs: obj.receiptRetentionSecs,
+    })
+  }
+
+  static toEncodable(fields: ReceiptRetentionSetEventFields) {
+    return {
+      receiptRetentionSecs: fields.receiptRetentionSecs,
+    }
+  }
+
+  toJSON(): ReceiptRetentionSetEventJSON {
+    return {
+      receiptRetentionSecs: this.receiptRetentionSecs.toString(),
+    }
+  }
+
+  static fromJSON(obj: ReceiptRetentionSetEventJSON): ReceiptRetentionSetEvent {
+    return new ReceiptRetentionSetEvent({
+      receiptRetentionSecs: new BN(obj.receiptRetentionSecs),
+    })
+  }
+
+  toEncodable() {
+    return ReceiptRetentionSetEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface RelayerQuotaSetEventFields {
+  relayer: PublicKey
+  quota: number
+}
+
+export interface RelayerQuotaSetEventJSON {
+  relayer: string
+  quota: number
+}
+
+export class RelayerQuotaSetEvent {
+  readonly relayer: PublicKey
+  readonly quota: number
+
+  constructor(fields: RelayerQuotaSetEventFields) {
+    this.relayer = fields.relayer
+    this.quota = fields.quota
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("relayer"),
+        borsh.u32("quota"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new RelayerQuotaSetEvent({
+      relayer: obj.relayer,
+      quota: obj.quota,
+    })
+  }
+
+  static toEncodable(fields: RelayerQuotaSetEventFields) {
+    return {
+      relayer: fields.relayer,
+      quota: fields.quota,
+    }
+  }
+
+  toJSON(): RelayerQuotaSetEventJSON {
+    return {
+      relayer: this.relayer.toString(),
+      quota: this.quota,
+    }
+  }
+
+  static fromJSON(obj: RelayerQuotaSetEventJSON): RelayerQuotaSetEvent {
+    return new RelayerQuotaSetEvent({
+      relayer: new PublicKey(obj.relayer),
+      quota: obj.quota,
+    })
+  }
+
+  toEncodable() {
+    return RelayerQuotaSetEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface RentToppedUpEventFields {
+  account: PublicKey
+  payer: PublicKey
+  lamports: BN
+}
+
+export interface RentToppedUpEventJSON {
+  account: string
+  payer: string
+  lamports: string
+}
+
+export class RentToppedUpEvent {
+  readonly account: PublicKey
+  readonly payer: PublicKey
+  readonly lamports: BN
+
+  constructor(fields: RentToppedUpEventFields) {
+    this.account = fields.account
+    this.payer = fields.payer
+    this.lamports = fields.lamports
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("account"),
+        borsh.publicKey("payer"),
+        borsh.u64("lamports"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new RentToppedUpEvent({
+      account: obj.account,
+      payer: obj.payer,
+      lamports: obj.lamports,
+    })
+  }
+
+  static toEncodable(fields: RentToppedUpEventFields) {
+    return {
+      account: fields.account,
+      payer: fields.payer,
+      lamports: fields.lamports,
+    }
+  }
+
+  toJSON(): RentToppedUpEventJSON {
+    return {
+      account: this.account.toString(),
+      payer: this.payer.toString(),
+      lamports: this.lamports.toString(),
+    }
+  }
+
+  static fromJSON(obj: RentToppedUpEventJSON): RentToppedUpEvent {
+    return new RentToppedUpEvent({
+      account: new PublicKey(obj.account),
+      payer: new PublicKey(obj.payer),
+      lamports: new BN(obj.lamports),
+    })
+  }
+
+  toEncodable() {
+    return RentToppedUpEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ResultReceiptClosedEventFields {
+  signatureHash: Array<number>
+  payer: PublicKey
+  closedAt: BN
+}
+
+export interface ResultReceiptClosedEventJSON {
+  signatureHash: Array<number>
+  payer: string
+  closedAt: string
+}
+
+export class ResultReceiptClosedEvent {
+  readonly signatureHash: Array<number>
+  readonly payer: PublicKey
+  readonly closedAt: BN
+
+  constructor(fields: ResultReceiptClosedEventFields) {
+    this.signatureHash = fields.signatureHash
+    this.payer = fields.payer
+    this.closedAt = fields.closedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "signatureHash"),
+        borsh.publicKey("payer"),
+        borsh.i64("closedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new ResultReceiptClosedEvent({
+      signatureHash: obj.signatureHash,
+      payer: obj.payer,
+      closedAt: obj.closedAt,
+    })
+  }
+
+  static toEncodable(fields: ResultReceiptClosedEventFields) {
+    return {
+      signatureHash: fields.signatureHash,
+      payer: fields.payer,
+      closedAt: fields.closedAt,
+    }
+  }
+
+  toJSON(): ResultReceiptClosedEventJSON {
+    return {
+      signatureHash: this.signatureHash,
+      payer: this.payer.toString(),
+      closedAt: this.closedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: ResultReceiptClosedEventJSON): ResultReceiptClosedEvent {
+    return new ResultReceiptClosedEvent({
+      signatureHash: obj.signatureHash,
+      payer: new PublicKey(obj.payer),
+      closedAt: new BN(obj.closedAt),
+    })
+  }
+
+  toEncodable() {
+    return ResultReceiptClosedEvent.toEncodable(this)
+  }
+}
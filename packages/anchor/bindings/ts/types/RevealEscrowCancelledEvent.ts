@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface RevealEscrowCancelledEventFields {
+  nullifier: Array<number>
+  appId: PublicKey
+  released: boolean
+  cancelledAt: BN
+}
+
+export interface RevealEscrowCancelledEventJSON {
+  nullifier: Array<number>
+  appId: string
+  released: boolean
+  cancelledAt: string
+}
+
+export class RevealEscrowCancelledEvent {
+  readonly nullifier: Array<number>
+  readonly appId: PublicKey
+  readonly released: boolean
+  readonly cancelledAt: BN
+
+  constructor(fields: RevealEscrowCancelledEventFields) {
+    this.nullifier = fields.nullifier
+    this.appId = fields.appId
+    this.released = fields.released
+    this.cancelledAt = fields.cancelledAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("appId"),
+        borsh.bool("released"),
+        borsh.i64("cancelledAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new RevealEscrowCancelledEvent({
+      nullifier: obj.nullifier,
+      appId: obj.appId,
+      released: obj.released,
+      cancelledAt: obj.cancelledAt,
+    })
+  }
+
+  static toEncodable(fields: RevealEscrowCancelledEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      appId: fields.appId,
+      released: fields.released,
+      cancelledAt: fields.cancelledAt,
+    }
+  }
+
+  toJSON(): RevealEscrowCancelledEventJSON {
+    return {
+      nullifier: this.nullifier,
+      appId: this.appId.toString(),
+      released: this.released,
+      cancelledAt: this.cancelledAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: RevealEscrowCancelledEventJSON): RevealEscrowCancelledEvent {
+    return new RevealEscrowCancelledEvent({
+      nullifier: obj.nullifier,
+      appId: new PublicKey(obj.appId),
+      released: obj.released,
+      cancelledAt: new BN(obj.cancelledAt),
+    })
+  }
+
+  toEncodable() {
+    return RevealEscrowCancelledEvent.toEncodable(this)
+  }
+}
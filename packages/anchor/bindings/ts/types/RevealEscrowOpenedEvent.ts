@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface RevealEscrowOpenedEventFields {
+  nullifier: Array<number>
+  appId: PublicKey
+  keyCommitment: Array<number>
+  revealsAt: BN
+}
+
+export interface RevealEscrowOpenedEventJSON {
+  nullifier: Array<number>
+  appId: string
+  keyCommitment: Array<number>
+  revealsAt: string
+}
+
+export class RevealEscrowOpenedEvent {
+  readonly nullifier: Array<number>
+  readonly appId: PublicKey
+  readonly keyCommitment: Array<number>
+  readonly revealsAt: BN
+
+  constructor(fields: RevealEscrowOpenedEventFields) {
+    this.nullifier = fields.nullifier
+    this.appId = fields.appId
+    this.keyCommitment = fields.keyCommitment
+    this.revealsAt = fields.revealsAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("appId"),
+        borsh.array(borsh.u8(), 32, "keyCommitment"),
+        borsh.i64("revealsAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new RevealEscrowOpenedEvent({
+      nullifier: obj.nullifier,
+      appId: obj.appId,
+      keyCommitment: obj.keyCommitment,
+      revealsAt: obj.revealsAt,
+    })
+  }
+
+  static toEncodable(fields: RevealEscrowOpenedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      appId: fields.appId,
+      keyCommitment: fields.keyCommitment,
+      revealsAt: fields.revealsAt,
+    }
+  }
+
+  toJSON(): RevealEscrowOpenedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      appId: this.appId.toString(),
+      keyCommitment: this.keyCommitment,
+      revealsAt: this.revealsAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: RevealEscrowOpenedEventJSON): RevealEscrowOpenedEvent {
+    return new RevealEscrowOpenedEvent({
+      nullifier: obj.nullifier,
+      appId: new PublicKey(obj.appId),
+      keyCommitment: obj.keyCommitment,
+      revealsAt: new BN(obj.revealsAt),
+    })
+  }
+
+  toEncodable() {
+    return RevealEscrowOpenedEvent.toEncodable(this)
+  }
+}
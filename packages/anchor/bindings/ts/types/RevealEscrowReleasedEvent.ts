@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface RevealEscrowReleasedEventFields {
+  nullifier: Array<number>
+  appId: PublicKey
+  revealKey: Array<number>
+  releasedAt: BN
+}
+
+export interface RevealEscrowReleasedEventJSON {
+  nullifier: Array<number>
+  appId: string
+  revealKey: Array<number>
+  releasedAt: string
+}
+
+export class RevealEscrowReleasedEvent {
+  readonly nullifier: Array<number>
+  readonly appId: PublicKey
+  readonly revealKey: Array<number>
+  readonly releasedAt: BN
+
+  constructor(fields: RevealEscrowReleasedEventFields) {
+    this.nullifier = fields.nullifier
+    this.appId = fields.appId
+    this.revealKey = fields.revealKey
+    this.releasedAt = fields.releasedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("appId"),
+        borsh.array(borsh.u8(), 32, "revealKey"),
+        borsh.i64("releasedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new RevealEscrowReleasedEvent({
+      nullifier: obj.nullifier,
+      appId: obj.appId,
+      revealKey: obj.revealKey,
+      releasedAt: obj.releasedAt,
+    })
+  }
+
+  static toEncodable(fields: RevealEscrowReleasedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      appId: fields.appId,
+      revealKey: fields.revealKey,
+      releasedAt: fields.releasedAt,
+    }
+  }
+
+  toJSON(): RevealEscrowReleasedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      appId: this.appId.toString(),
+      revealKey: this.revealKey,
+      releasedAt: this.releasedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: RevealEscrowReleasedEventJSON): RevealEscrowReleasedEvent {
+    return new RevealEscrowReleasedEvent({
+      nullifier: obj.nullifier,
+      appId: new PublicKey(obj.appId),
+      revealKey: obj.revealKey,
+      releasedAt: new BN(obj.releasedAt),
+    })
+  }
+
+  toEncodable() {
+    return RevealEscrowReleasedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface RootMemberFields {
+  nullifier: Array<number>
+  domain: Array<number>
+  linkageProofHash: Array<number>
+  linkedAt: BN
+}
+
+export interface RootMemberJSON {
+  nullifier: Array<number>
+  domain: Array<number>
+  linkageProofHash: Array<number>
+  linkedAt: string
+}
+
+export class RootMember {
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly linkageProofHash: Array<number>
+  readonly linkedAt: BN
+
+  constructor(fields: RootMemberFields) {
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.linkageProofHash = fields.linkageProofHash
+    this.linkedAt = fields.linkedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.array(borsh.u8(), 32, "linkageProofHash"),
+        borsh.i64("linkedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new RootMember({
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      linkageProofHash: obj.linkageProofHash,
+      linkedAt: obj.linkedAt,
+    })
+  }
+
+  static toEncodable(fields: RootMemberFields) {
+    return {
+      nullifier: fields.nullifier,
+      domain: fields.domain,
+      linkageProofHash: fields.linkageProofHash,
+      linkedAt: fields.linkedAt,
+    }
+  }
+
+  toJSON(): RootMemberJSON {
+    return {
+      nullifier: this.nullifier,
+      domain: this.domain,
+      linkageProofHash: this.linkageProofHash,
+      linkedAt: this.linkedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: RootMemberJSON): RootMember {
+    return new RootMember({
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      linkageProofHash: obj.linkageProofHash,
+      linkedAt: new BN(obj.linkedAt),
+    })
+  }
+
+  toEncodable() {
+    return RootMember.toEncodable(this)
+  }
+}
@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SasBridgeSetEventFields {
+  credential: PublicKey
+  schema: PublicKey
+}
+
+export interface SasBridgeSetEventJSON {
+  credential: string
+  schema: string
+}
+
+export class SasBridgeSetEvent {
+  readonly credential: PublicKey
+  readonly schema: PublicKey
+
+  constructor(fields: SasBridgeSetEventFields) {
+    this.credential = fields.credential
+    this.schema = fields.schema
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("credential"),
+        borsh.publicKey("schema"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SasBridgeSetEvent({
+      credential: obj.credential,
+      schema: obj.schema,
+    })
+  }
+
+  static toEncodable(fields: SasBridgeSetEventFields) {
+    return {
+      credential: fields.credential,
+      schema: fields.schema,
+    }
+  }
+
+  toJSON(): SasBridgeSetEventJSON {
+    return {
+      credential: this.credential.toString(),
+      schema: this.schema.toString(),
+    }
+  }
+
+  static fromJSON(obj: SasBridgeSetEventJSON): SasBridgeSetEvent {
+    return new SasBridgeSetEvent({
+      credential: new PublicKey(obj.credential),
+      schema: new PublicKey(obj.schema),
+    })
+  }
+
+  toEncodable() {
+    return SasBridgeSetEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,57 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface Secp256r1VerifierAddedEventFields {
+  verifier: Array<number>
+}
+
+export interface Secp256r1VerifierAddedEventJSON {
+  verifier: Array<number>
+}
+
+export class Secp256r1VerifierAddedEvent {
+  readonly verifier: Array<number>
+
+  constructor(fields: Secp256r1VerifierAddedEventFields) {
+    this.verifier = fields.verifier
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 33, "verifier"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new Secp256r1VerifierAddedEvent({
+      verifier: obj.verifier,
+    })
+  }
+
+  static toEncodable(fields: Secp256r1VerifierAddedEventFields) {
+    return {
+      verifier: fields.verifier,
+    }
+  }
+
+  toJSON(): Secp256r1VerifierAddedEventJSON {
+    return {
+      verifier: this.verifier,
+    }
+  }
+
+  static fromJSON(obj: Secp256r1VerifierAddedEventJSON): Secp256r1VerifierAddedEvent {
+    return new Secp256r1VerifierAddedEvent({
+      verifier: obj.verifier,
+    })
+  }
+
+  toEncodable() {
+    return Secp256r1VerifierAddedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SessionClosedEventFields {
+  nullifier: Array<number>
+  domain: Array<number>
+  authority: PublicKey
+  closedAt: BN
+}
+
+export interface SessionClosedEventJSON {
+  nullifier: Array<number>
+  domain: Array<number>
+  authority: string
+  closedAt: string
+}
+
+export class SessionClosedEvent {
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly authority: PublicKey
+  readonly closedAt: BN
+
+  constructor(fields: SessionClosedEventFields) {
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.authority = fields.authority
+    this.closedAt = fields.closedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.publicKey("authority"),
+        borsh.i64("closedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SessionClosedEvent({
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      authority: obj.authority,
+      closedAt: obj.closedAt,
+    })
+  }
+
+  static toEncodable(fields: SessionClosedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      domain: fields.domain,
+      authority: fields.authority,
+      closedAt: fields.closedAt,
+    }
+  }
+
+  toJSON(): SessionClosedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      domain: this.domain,
+      authority: this.authority.toString(),
+      closedAt: this.closedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: SessionClosedEventJSON): SessionClosedEvent {
+    return new SessionClosedEvent({
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      authority: new PublicKey(obj.authority),
+      closedAt: new BN(obj.closedAt),
+    })
+  }
+
+  toEncodable() {
+    return SessionClosedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SessionDelegateRevokedEventFields {
+  session: PublicKey
+  delegate: PublicKey
+  revokedAt: BN
+}
+
+export interface SessionDelegateRevokedEventJSON {
+  session: string
+  delegate: string
+  revokedAt: string
+}
+
+export class SessionDelegateRevokedEvent {
+  readonly session: PublicKey
+  readonly delegate: PublicKey
+  readonly revokedAt: BN
+
+  constructor(fields: SessionDelegateRevokedEventFields) {
+    this.session = fields.session
+    this.delegate = fields.delegate
+    this.revokedAt = fields.revokedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("session"),
+        borsh.publicKey("delegate"),
+        borsh.i64("revokedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SessionDelegateRevokedEvent({
+      session: obj.session,
+      delegate: obj.delegate,
+      revokedAt: obj.revokedAt,
+    })
+  }
+
+  static toEncodable(fields: SessionDelegateRevokedEventFields) {
+    return {
+      session: fields.session,
+      delegate: fields.delegate,
+      revokedAt: fields.revokedAt,
+    }
+  }
+
+  toJSON(): SessionDelegateRevokedEventJSON {
+    return {
+      session: this.session.toString(),
+      delegate: this.delegate.toString(),
+      revokedAt: this.revokedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: SessionDelegateRevokedEventJSON): SessionDelegateRevokedEvent {
+    return new SessionDelegateRevokedEvent({
+      session: new PublicKey(obj.session),
+      delegate: new PublicKey(obj.delegate),
+      revokedAt: new BN(obj.revokedAt),
+    })
+  }
+
+  toEncodable() {
+    return SessionDelegateRevokedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,93 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SessionDelegatedEventFields {
+  nullifier: Array<number>
+  session: PublicKey
+  delegate: PublicKey
+  scope: BN
+  expiresAt: BN
+}
+
+export interface SessionDelegatedEventJSON {
+  nullifier: Array<number>
+  session: string
+  delegate: string
+  scope: string
+  expiresAt: string
+}
+
+export class SessionDelegatedEvent {
+  readonly nullifier: Array<number>
+  readonly session: PublicKey
+  readonly delegate: PublicKey
+  readonly scope: BN
+  readonly expiresAt: BN
+
+  constructor(fields: SessionDelegatedEventFields) {
+    this.nullifier = fields.nullifier
+    this.session = fields.session
+    this.delegate = fields.delegate
+    this.scope = fields.scope
+    this.expiresAt = fields.expiresAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("session"),
+        borsh.publicKey("delegate"),
+        borsh.u64("scope"),
+        borsh.i64("expiresAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SessionDelegatedEvent({
+      nullifier: obj.nullifier,
+      session: obj.session,
+      delegate: obj.delegate,
+      scope: obj.scope,
+      expiresAt: obj.expiresAt,
+    })
+  }
+
+  static toEncodable(fields: SessionDelegatedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      session: fields.session,
+      delegate: fields.delegate,
+      scope: fields.scope,
+      expiresAt: fields.expiresAt,
+    }
+  }
+
+  toJSON(): SessionDelegatedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      session: this.session.toString(),
+      delegate: this.delegate.toString(),
+      scope: this.scope.toString(),
+      expiresAt: this.expiresAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: SessionDelegatedEventJSON): SessionDelegatedEvent {
+    return new SessionDelegatedEvent({
+      nullifier: obj.nullifier,
+      session: new PublicKey(obj.session),
+      delegate: new PublicKey(obj.delegate),
+      scope: new BN(obj.scope),
+      expiresAt: new BN(obj.expiresAt),
+    })
+  }
+
+  toEncodable() {
+    return SessionDelegatedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SessionDetailsFields {
+  nullifierAccount: PublicKey
+  createdAt: BN
+  expiresAt: types.ExpiryKindKind
+}
+
+export interface SessionDetailsJSON {
+  nullifierAccount: string
+  createdAt: string
+  expiresAt: types.ExpiryKindJSON
+}
+
+export class SessionDetails {
+  readonly nullifierAccount: PublicKey
+  readonly createdAt: BN
+  readonly expiresAt: types.ExpiryKindKind
+
+  constructor(fields: SessionDetailsFields) {
+    this.nullifierAccount = fields.nullifierAccount
+    this.createdAt = fields.createdAt
+    this.expiresAt = fields.expiresAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("nullifierAccount"),
+        borsh.i64("createdAt"),
+        types.ExpiryKind.layout("expiresAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SessionDetails({
+      nullifierAccount: obj.nullifierAccount,
+      createdAt: obj.createdAt,
+      expiresAt: types.ExpiryKind.fromDecoded(obj.expiresAt),
+    })
+  }
+
+  static toEncodable(fields: SessionDetailsFields) {
+    return {
+      nullifierAccount: fields.nullifierAccount,
+      createdAt: fields.createdAt,
+      expiresAt: fields.expiresAt.toEncodable(),
+    }
+  }
+
+  toJSON(): SessionDetailsJSON {
+    return {
+      nullifierAccount: this.nullifierAccount.toString(),
+      createdAt: this.createdAt.toString(),
+      expiresAt: this.expiresAt.toJSON(),
+    }
+  }
+
+  static fromJSON(obj: SessionDetailsJSON): SessionDetails {
+    return new SessionDetails({
+      nullifierAccount: new PublicKey(obj.nullifierAccount),
+      createdAt: new BN(obj.createdAt),
+      expiresAt: types.ExpiryKind.fromJSON(obj.expiresAt),
+    })
+  }
+
+  toEncodable() {
+    return SessionDetails.toEncodable(this)
+  }
+}
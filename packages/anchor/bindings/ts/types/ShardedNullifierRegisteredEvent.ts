@@ -0,0 +1,93 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface ShardedNullifierRegisteredEventFields {
+  nullifier: Array<number>
+  domain: Array<number>
+  proofHash: Array<number>
+  shard: number
+  registeredAt: BN
+}
+
+export interface ShardedNullifierRegisteredEventJSON {
+  nullifier: Array<number>
+  domain: Array<number>
+  proofHash: Array<number>
+  shard: number
+  registeredAt: string
+}
+
+export class ShardedNullifierRegisteredEvent {
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly proofHash: Array<number>
+  readonly shard: number
+  readonly registeredAt: BN
+
+  constructor(fields: ShardedNullifierRegisteredEventFields) {
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.proofHash = fields.proofHash
+    this.shard = fields.shard
+    this.registeredAt = fields.registeredAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.array(borsh.u8(), 32, "proofHash"),
+        borsh.u16("shard"),
+        borsh.i64("registeredAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new ShardedNullifierRegisteredEvent({
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      proofHash: obj.proofHash,
+      shard: obj.shard,
+      registeredAt: obj.registeredAt,
+    })
+  }
+
+  static toEncodable(fields: ShardedNullifierRegisteredEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      domain: fields.domain,
+      proofHash: fields.proofHash,
+      shard: fields.shard,
+      registeredAt: fields.registeredAt,
+    }
+  }
+
+  toJSON(): ShardedNullifierRegisteredEventJSON {
+    return {
+      nullifier: this.nullifier,
+      domain: this.domain,
+      proofHash: this.proofHash,
+      shard: this.shard,
+      registeredAt: this.registeredAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: ShardedNullifierRegisteredEventJSON): ShardedNullifierRegisteredEvent {
+    return new ShardedNullifierRegisteredEvent({
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      proofHash: obj.proofHash,
+      shard: obj.shard,
+      registeredAt: new BN(obj.registeredAt),
+    })
+  }
+
+  toEncodable() {
+    return ShardedNullifierRegisteredEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,165 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface SignedVerificationResultFields {
+  isValid: boolean
+  proofHash: Array<number>
+  timestamp: BN
+  nullifier: Array<number>
+  domain: Array<number>
+  publicInputsHash: Array<number> | null
+  circuitId: number | null
+  vkHash: Array<number> | null
+  clusterId: Array<number> | null
+  instanceId: Array<number> | null
+  claimType: types.ClaimTypeKind | null
+  tagged: boolean
+  signature: Array<number>
+}
+
+export interface SignedVerificationResultJSON {
+  isValid: boolean
+  proofHash: Array<number>
+  timestamp: string
+  nullifier: Array<number>
+  domain: Array<number>
+  publicInputsHash: Array<number> | null
+  circuitId: number | null
+  vkHash: Array<number> | null
+  clusterId: Array<number> | null
+  instanceId: Array<number> | null
+  claimType: types.ClaimTypeJSON | null
+  tagged: boolean
+  signature: Array<number>
+}
+
+export class SignedVerificationResult {
+  readonly isValid: boolean
+  readonly proofHash: Array<number>
+  readonly timestamp: BN
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly publicInputsHash: Array<number> | null
+  readonly circuitId: number | null
+  readonly vkHash: Array<number> | null
+  readonly clusterId: Array<number> | null
+  readonly instanceId: Array<number> | null
+  readonly claimType: types.ClaimTypeKind | null
+  readonly tagged: boolean
+  readonly signature: Array<number>
+
+  constructor(fields: SignedVerificationResultFields) {
+    this.isValid = fields.isValid
+    this.proofHash = fields.proofHash
+    this.timestamp = fields.timestamp
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.publicInputsHash = fields.publicInputsHash
+    this.circuitId = fields.circuitId
+    this.vkHash = fields.vkHash
+    this.clusterId = fields.clusterId
+    this.instanceId = fields.instanceId
+    this.claimType = fields.claimType
+    this.tagged = fields.tagged
+    this.signature = fields.signature
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.bool("isValid"),
+        borsh.array(borsh.u8(), 32, "proofHash"),
+        borsh.u64("timestamp"),
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.option(borsh.array(borsh.u8(), 32), "publicInputsHash"),
+        borsh.option(borsh.u32(), "circuitId"),
+        borsh.option(borsh.array(borsh.u8(), 32), "vkHash"),
+        borsh.option(borsh.array(borsh.u8(), 32), "clusterId"),
+        borsh.option(borsh.array(borsh.u8(), 32), "instanceId"),
+        borsh.option(types.ClaimType.layout(), "claimType"),
+        borsh.bool("tagged"),
+        borsh.array(borsh.u8(), 64, "signature"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new SignedVerificationResult({
+      isValid: obj.isValid,
+      proofHash: obj.proofHash,
+      timestamp: obj.timestamp,
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      publicInputsHash: obj.publicInputsHash,
+      circuitId: obj.circuitId,
+      vkHash: obj.vkHash,
+      clusterId: obj.clusterId,
+      instanceId: obj.instanceId,
+      claimType: (obj.claimType && types.ClaimType.fromDecoded(obj.claimType)) || null,
+      tagged: obj.tagged,
+      signature: obj.signature,
+    })
+  }
+
+  static toEncodable(fields: SignedVerificationResultFields) {
+    return {
+      isValid: fields.isValid,
+      proofHash: fields.proofHash,
+      timestamp: fields.timestamp,
+      nullifier: fields.nullifier,
+      domain: fields.domain,
+      publicInputsHash: fields.publicInputsHash,
+      circuitId: fields.circuitId,
+      vkHash: fields.vkHash,
+      clusterId: fields.clusterId,
+      instanceId: fields.instanceId,
+      claimType: (fields.claimType && fields.claimType.toEncodable()) || null,
+      tagged: fields.tagged,
+      signature: fields.signature,
+    }
+  }
+
+  toJSON(): SignedVerificationResultJSON {
+    return {
+      isValid: this.isValid,
+      proofHash: this.proofHash,
+      timestamp: this.timestamp.toString(),
+      nullifier: this.nullifier,
+      domain: this.domain,
+      publicInputsHash: this.publicInputsHash,
+      circuitId: this.circuitId,
+      vkHash: this.vkHash,
+      clusterId: this.clusterId,
+      instanceId: this.instanceId,
+      claimType: (this.claimType && this.claimType.toJSON()) || null,
+      tagged: this.tagged,
+      signature: this.signature,
+    }
+  }
+
+  static fromJSON(obj: SignedVerificationResultJSON): SignedVerificationResult {
+    return new SignedVerificationResult({
+      isValid: obj.isValid,
+      proofHash: obj.proofHash,
+      timestamp: new BN(obj.timestamp),
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      publicInputsHash: obj.publicInputsHash,
+      circuitId: obj.circuitId,
+      vkHash: obj.vkHash,
+      clusterId: obj.clusterId,
+      instanceId: obj.instanceId,
+      claimType: (obj.claimType && types.ClaimType.fromJSON(obj.claimType)) || null,
+      tagged: obj.tagged,
+      signature: obj.signature,
+    })
+  }
+
+  toEncodable() {
+    return SignedVerificationResult.toEncodable(this)
+  }
+}
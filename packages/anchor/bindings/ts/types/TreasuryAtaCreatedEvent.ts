@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface TreasuryAtaCreatedEventFields {
+  mint: PublicKey
+  treasuryAta: PublicKey
+}
+
+export interface TreasuryAtaCreatedEventJSON {
+  mint: string
+  treasuryAta: string
+}
+
+export class TreasuryAtaCreatedEvent {
+  readonly mint: PublicKey
+  readonly treasuryAta: PublicKey
+
+  constructor(fields: TreasuryAtaCreatedEventFields) {
+    this.mint = fields.mint
+    this.treasuryAta = fields.treasuryAta
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("mint"),
+        borsh.publicKey("treasuryAta"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new TreasuryAtaCreatedEvent({
+      mint: obj.mint,
+      treasuryAta: obj.treasuryAta,
+    })
+  }
+
+  static toEncodable(fields: TreasuryAtaCreatedEventFields) {
+    return {
+      mint: fields.mint,
+      treasuryAta: fields.treasuryAta,
+    }
+  }
+
+  toJSON(): TreasuryAtaCreatedEventJSON {
+    return {
+      mint: this.mint.toString(),
+      treasuryAta: this.treasuryAta.toString(),
+    }
+  }
+
+  static fromJSON(obj: TreasuryAtaCreatedEventJSON): TreasuryAtaCreatedEvent {
+    return new TreasuryAtaCreatedEvent({
+      mint: new PublicKey(obj.mint),
+      treasuryAta: new PublicKey(obj.treasuryAta),
+    })
+  }
+
+  toEncodable() {
+    return TreasuryAtaCreatedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface TreasurySweptEventFields {
+  mint: PublicKey
+  destination: PublicKey
+  amount: BN
+}
+
+export interface TreasurySweptEventJSON {
+  mint: string
+  destination: string
+  amount: string
+}
+
+export class TreasurySweptEvent {
+  readonly mint: PublicKey
+  readonly destination: PublicKey
+  readonly amount: BN
+
+  constructor(fields: TreasurySweptEventFields) {
+    this.mint = fields.mint
+    this.destination = fields.destination
+    this.amount = fields.amount
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("mint"),
+        borsh.publicKey("destination"),
+        borsh.u64("amount"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new TreasurySweptEvent({
+      mint: obj.mint,
+      destination: obj.destination,
+      amount: obj.amount,
+    })
+  }
+
+  static toEncodable(fields: TreasurySweptEventFields) {
+    return {
+      mint: fields.mint,
+      destination: fields.destination,
+      amount: fields.amount,
+    }
+  }
+
+  toJSON(): TreasurySweptEventJSON {
+    return {
+      mint: this.mint.toString(),
+      destination: this.destination.toString(),
+      amount: this.amount.toString(),
+    }
+  }
+
+  static fromJSON(obj: TreasurySweptEventJSON): TreasurySweptEvent {
+    return new TreasurySweptEvent({
+      mint: new PublicKey(obj.mint),
+      destination: new PublicKey(obj.destination),
+      amount: new BN(obj.amount),
+    })
+  }
+
+  toEncodable() {
+    return TreasurySweptEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface UsagePointsClaimedEventFields {
+  nullifier: Array<number>
+  amount: BN
+  root: Array<number>
+  claimedAt: BN
+}
+
+export interface UsagePointsClaimedEventJSON {
+  nullifier: Array<number>
+  amount: string
+  root: Array<number>
+  claimedAt: string
+}
+
+export class UsagePointsClaimedEvent {
+  readonly nullifier: Array<number>
+  readonly amount: BN
+  readonly root: Array<number>
+  readonly claimedAt: BN
+
+  constructor(fields: UsagePointsClaimedEventFields) {
+    this.nullifier = fields.nullifier
+    this.amount = fields.amount
+    this.root = fields.root
+    this.claimedAt = fields.claimedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.u64("amount"),
+        borsh.array(borsh.u8(), 32, "root"),
+        borsh.i64("claimedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UsagePointsClaimedEvent({
+      nullifier: obj.nullifier,
+      amount: obj.amount,
+      root: obj.root,
+      claimedAt: obj.claimedAt,
+    })
+  }
+
+  static toEncodable(fields: UsagePointsClaimedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      amount: fields.amount,
+      root: fields.root,
+      claimedAt: fields.claimedAt,
+    }
+  }
+
+  toJSON(): UsagePointsClaimedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      amount: this.amount.toString(),
+      root: this.root,
+      claimedAt: this.claimedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: UsagePointsClaimedEventJSON): UsagePointsClaimedEvent {
+    return new UsagePointsClaimedEvent({
+      nullifier: obj.nullifier,
+      amount: new BN(obj.amount),
+      root: obj.root,
+      claimedAt: new BN(obj.claimedAt),
+    })
+  }
+
+  toEncodable() {
+    return UsagePointsClaimedEvent.toEncodable(this)
+  }
+}
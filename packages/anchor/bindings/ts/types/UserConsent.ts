@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface UserConsentFields {
+  user: PublicKey
+  expiresAt: BN
+}
+
+export interface UserConsentJSON {
+  user: string
+  expiresAt: string
+}
+
+export class UserConsent {
+  readonly user: PublicKey
+  readonly expiresAt: BN
+
+  constructor(fields: UserConsentFields) {
+    this.user = fields.user
+    this.expiresAt = fields.expiresAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("user"),
+        borsh.i64("expiresAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UserConsent({
+      user: obj.user,
+      expiresAt: obj.expiresAt,
+    })
+  }
+
+  static toEncodable(fields: UserConsentFields) {
+    return {
+      user: fields.user,
+      expiresAt: fields.expiresAt,
+    }
+  }
+
+  toJSON(): UserConsentJSON {
+    return {
+      user: this.user.toString(),
+      expiresAt: this.expiresAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: UserConsentJSON): UserConsent {
+    return new UserConsent({
+      user: new PublicKey(obj.user),
+      expiresAt: new BN(obj.expiresAt),
+    })
+  }
+
+  toEncodable() {
+    return UserConsent.toEncodable(this)
+  }
+}
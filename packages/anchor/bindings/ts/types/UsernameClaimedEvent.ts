@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface UsernameClaimedEventFields {
+  name: Array<number>
+  nullifier: Array<number>
+  domain: Array<number>
+  claimedAt: BN
+}
+
+export interface UsernameClaimedEventJSON {
+  name: Array<number>
+  nullifier: Array<number>
+  domain: Array<number>
+  claimedAt: string
+}
+
+export class UsernameClaimedEvent {
+  readonly name: Array<number>
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly claimedAt: BN
+
+  constructor(fields: UsernameClaimedEventFields) {
+    this.name = fields.name
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.claimedAt = fields.claimedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "name"),
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.i64("claimedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UsernameClaimedEvent({
+      name: obj.name,
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      claimedAt: obj.claimedAt,
+    })
+  }
+
+  static toEncodable(fields: UsernameClaimedEventFields) {
+    return {
+      name: fields.name,
+      nullifier: fields.nullifier,
+      domain: fields.domain,
+      claimedAt: fields.claimedAt,
+    }
+  }
+
+  toJSON(): UsernameClaimedEventJSON {
+    return {
+      name: this.name,
+      nullifier: this.nullifier,
+      domain: this.domain,
+      claimedAt: this.claimedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: UsernameClaimedEventJSON): UsernameClaimedEvent {
+    return new UsernameClaimedEvent({
+      name: obj.name,
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      claimedAt: new BN(obj.claimedAt),
+    })
+  }
+
+  toEncodable() {
+    return UsernameClaimedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface UsernameReleasedEventFields {
+  name: Array<number>
+  nullifier: Array<number>
+  releasedAt: BN
+}
+
+export interface UsernameReleasedEventJSON {
+  name: Array<number>
+  nullifier: Array<number>
+  releasedAt: string
+}
+
+export class UsernameReleasedEvent {
+  readonly name: Array<number>
+  readonly nullifier: Array<number>
+  readonly releasedAt: BN
+
+  constructor(fields: UsernameReleasedEventFields) {
+    this.name = fields.name
+    this.nullifier = fields.nullifier
+    this.releasedAt = fields.releasedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "name"),
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.i64("releasedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UsernameReleasedEvent({
+      name: obj.name,
+      nullifier: obj.nullifier,
+      releasedAt: obj.releasedAt,
+    })
+  }
+
+  static toEncodable(fields: UsernameReleasedEventFields) {
+    return {
+      name: fields.name,
+      nullifier: fields.nullifier,
+      releasedAt: fields.releasedAt,
+    }
+  }
+
+  toJSON(): UsernameReleasedEventJSON {
+    return {
+      name: this.name,
+      nullifier: this.nullifier,
+      releasedAt: this.releasedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: UsernameReleasedEventJSON): UsernameReleasedEvent {
+    return new UsernameReleasedEvent({
+      name: obj.name,
+      nullifier: obj.nullifier,
+      releasedAt: new BN(obj.releasedAt),
+    })
+  }
+
+  toEncodable() {
+    return UsernameReleasedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface UsernameTransferredEventFields {
+  name: Array<number>
+  fromNullifier: Array<number>
+  toNullifier: Array<number>
+  transferredAt: BN
+}
+
+export interface UsernameTransferredEventJSON {
+  name: Array<number>
+  fromNullifier: Array<number>
+  toNullifier: Array<number>
+  transferredAt: string
+}
+
+export class UsernameTransferredEvent {
+  readonly name: Array<number>
+  readonly fromNullifier: Array<number>
+  readonly toNullifier: Array<number>
+  readonly transferredAt: BN
+
+  constructor(fields: UsernameTransferredEventFields) {
+    this.name = fields.name
+    this.fromNullifier = fields.fromNullifier
+    this.toNullifier = fields.toNullifier
+    this.transferredAt = fields.transferredAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "name"),
+        borsh.array(borsh.u8(), 32, "fromNullifier"),
+        borsh.array(borsh.u8(), 32, "toNullifier"),
+        borsh.i64("transferredAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new UsernameTransferredEvent({
+      name: obj.name,
+      fromNullifier: obj.fromNullifier,
+      toNullifier: obj.toNullifier,
+      transferredAt: obj.transferredAt,
+    })
+  }
+
+  static toEncodable(fields: UsernameTransferredEventFields) {
+    return {
+      name: fields.name,
+      fromNullifier: fields.fromNullifier,
+      toNullifier: fields.toNullifier,
+      transferredAt: fields.transferredAt,
+    }
+  }
+
+  toJSON(): UsernameTransferredEventJSON {
+    return {
+      name: this.name,
+      fromNullifier: this.fromNullifier,
+      toNullifier: this.toNullifier,
+      transferredAt: this.transferredAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: UsernameTransferredEventJSON): UsernameTransferredEvent {
+    return new UsernameTransferredEvent({
+      name: obj.name,
+      fromNullifier: obj.fromNullifier,
+      toNullifier: obj.toNullifier,
+      transferredAt: new BN(obj.transferredAt),
+    })
+  }
+
+  toEncodable() {
+    return UsernameTransferredEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VcCommitmentIssuedEventFields {
+  nullifier: Array<number>
+  issuer: PublicKey
+  vcHash: Array<number>
+  issuedAt: BN
+}
+
+export interface VcCommitmentIssuedEventJSON {
+  nullifier: Array<number>
+  issuer: string
+  vcHash: Array<number>
+  issuedAt: string
+}
+
+export class VcCommitmentIssuedEvent {
+  readonly nullifier: Array<number>
+  readonly issuer: PublicKey
+  readonly vcHash: Array<number>
+  readonly issuedAt: BN
+
+  constructor(fields: VcCommitmentIssuedEventFields) {
+    this.nullifier = fields.nullifier
+    this.issuer = fields.issuer
+    this.vcHash = fields.vcHash
+    this.issuedAt = fields.issuedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("issuer"),
+        borsh.array(borsh.u8(), 32, "vcHash"),
+        borsh.i64("issuedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VcCommitmentIssuedEvent({
+      nullifier: obj.nullifier,
+      issuer: obj.issuer,
+      vcHash: obj.vcHash,
+      issuedAt: obj.issuedAt,
+    })
+  }
+
+  static toEncodable(fields: VcCommitmentIssuedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      issuer: fields.issuer,
+      vcHash: fields.vcHash,
+      issuedAt: fields.issuedAt,
+    }
+  }
+
+  toJSON(): VcCommitmentIssuedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      issuer: this.issuer.toString(),
+      vcHash: this.vcHash,
+      issuedAt: this.issuedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: VcCommitmentIssuedEventJSON): VcCommitmentIssuedEvent {
+    return new VcCommitmentIssuedEvent({
+      nullifier: obj.nullifier,
+      issuer: new PublicKey(obj.issuer),
+      vcHash: obj.vcHash,
+      issuedAt: new BN(obj.issuedAt),
+    })
+  }
+
+  toEncodable() {
+    return VcCommitmentIssuedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,93 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VcStatusChangedEventFields {
+  nullifier: Array<number>
+  issuer: PublicKey
+  vcHash: Array<number>
+  status: types.VcStatusKind
+  updatedAt: BN
+}
+
+export interface VcStatusChangedEventJSON {
+  nullifier: Array<number>
+  issuer: string
+  vcHash: Array<number>
+  status: types.VcStatusJSON
+  updatedAt: string
+}
+
+export class VcStatusChangedEvent {
+  readonly nullifier: Array<number>
+  readonly issuer: PublicKey
+  readonly vcHash: Array<number>
+  readonly status: types.VcStatusKind
+  readonly updatedAt: BN
+
+  constructor(fields: VcStatusChangedEventFields) {
+    this.nullifier = fields.nullifier
+    this.issuer = fields.issuer
+    this.vcHash = fields.vcHash
+    this.status = fields.status
+    this.updatedAt = fields.updatedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("issuer"),
+        borsh.array(borsh.u8(), 32, "vcHash"),
+        types.VcStatus.layout("status"),
+        borsh.i64("updatedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VcStatusChangedEvent({
+      nullifier: obj.nullifier,
+      issuer: obj.issuer,
+      vcHash: obj.vcHash,
+      status: types.VcStatus.fromDecoded(obj.status),
+      updatedAt: obj.updatedAt,
+    })
+  }
+
+  static toEncodable(fields: VcStatusChangedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      issuer: fields.issuer,
+      vcHash: fields.vcHash,
+      status: fields.status.toEncodable(),
+      updatedAt: fields.updatedAt,
+    }
+  }
+
+  toJSON(): VcStatusChangedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      issuer: this.issuer.toString(),
+      vcHash: this.vcHash,
+      status: this.status.toJSON(),
+      updatedAt: this.updatedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: VcStatusChangedEventJSON): VcStatusChangedEvent {
+    return new VcStatusChangedEvent({
+      nullifier: obj.nullifier,
+      issuer: new PublicKey(obj.issuer),
+      vcHash: obj.vcHash,
+      status: types.VcStatus.fromJSON(obj.status),
+      updatedAt: new BN(obj.updatedAt),
+    })
+  }
+
+  toEncodable() {
+    return VcStatusChangedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,93 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VerificationChallengedEventFields {
+  nullifier: Array<number>
+  domain: Array<number>
+  proofHash: Array<number>
+  challenger: PublicKey
+  challengedAt: BN
+}
+
+export interface VerificationChallengedEventJSON {
+  nullifier: Array<number>
+  domain: Array<number>
+  proofHash: Array<number>
+  challenger: string
+  challengedAt: string
+}
+
+export class VerificationChallengedEvent {
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly proofHash: Array<number>
+  readonly challenger: PublicKey
+  readonly challengedAt: BN
+
+  constructor(fields: VerificationChallengedEventFields) {
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.proofHash = fields.proofHash
+    this.challenger = fields.challenger
+    this.challengedAt = fields.challengedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.array(borsh.u8(), 32, "proofHash"),
+        borsh.publicKey("challenger"),
+        borsh.i64("challengedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VerificationChallengedEvent({
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      proofHash: obj.proofHash,
+      challenger: obj.challenger,
+      challengedAt: obj.challengedAt,
+    })
+  }
+
+  static toEncodable(fields: VerificationChallengedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      domain: fields.domain,
+      proofHash: fields.proofHash,
+      challenger: fields.challenger,
+      challengedAt: fields.challengedAt,
+    }
+  }
+
+  toJSON(): VerificationChallengedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      domain: this.domain,
+      proofHash: this.proofHash,
+      challenger: this.challenger.toString(),
+      challengedAt: this.challengedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: VerificationChallengedEventJSON): VerificationChallengedEvent {
+    return new VerificationChallengedEvent({
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      proofHash: obj.proofHash,
+      challenger: new PublicKey(obj.challenger),
+      challengedAt: new BN(obj.challengedAt),
+    })
+  }
+
+  toEncodable() {
+    return VerificationChallengedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,102 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VerificationSessionOpenedEventFields {
+  nullifier: Array<number>
+  domain: Array<number>
+  circuitId: number
+  authority: PublicKey
+  proofLen: number
+  steps: number
+}
+
+export interface VerificationSessionOpenedEventJSON {
+  nullifier: Array<number>
+  domain: Array<number>
+  circuitId: number
+  authority: string
+  proofLen: number
+  steps: number
+}
+
+export class VerificationSessionOpenedEvent {
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+  readonly circuitId: number
+  readonly authority: PublicKey
+  readonly proofLen: number
+  readonly steps: number
+
+  constructor(fields: VerificationSessionOpenedEventFields) {
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+    this.circuitId = fields.circuitId
+    this.authority = fields.authority
+    this.proofLen = fields.proofLen
+    this.steps = fields.steps
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.u32("circuitId"),
+        borsh.publicKey("authority"),
+        borsh.u32("proofLen"),
+        borsh.u32("steps"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VerificationSessionOpenedEvent({
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      circuitId: obj.circuitId,
+      authority: obj.authority,
+      proofLen: obj.proofLen,
+      steps: obj.steps,
+    })
+  }
+
+  static toEncodable(fields: VerificationSessionOpenedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      domain: fields.domain,
+      circuitId: fields.circuitId,
+      authority: fields.authority,
+      proofLen: fields.proofLen,
+      steps: fields.steps,
+    }
+  }
+
+  toJSON(): VerificationSessionOpenedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      domain: this.domain,
+      circuitId: this.circuitId,
+      authority: this.authority.toString(),
+      proofLen: this.proofLen,
+      steps: this.steps,
+    }
+  }
+
+  static fromJSON(obj: VerificationSessionOpenedEventJSON): VerificationSessionOpenedEvent {
+    return new VerificationSessionOpenedEvent({
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+      circuitId: obj.circuitId,
+      authority: new PublicKey(obj.authority),
+      proofLen: obj.proofLen,
+      steps: obj.steps,
+    })
+  }
+
+  toEncodable() {
+    return VerificationSessionOpenedEvent.toEncodable(this)
+  }
+}
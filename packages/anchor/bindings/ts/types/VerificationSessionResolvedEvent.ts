@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VerificationSessionResolvedEventFields {
+  nullifier: Array<number>
+  authority: PublicKey
+  verified: boolean
+  steps: number
+}
+
+export interface VerificationSessionResolvedEventJSON {
+  nullifier: Array<number>
+  authority: string
+  verified: boolean
+  steps: number
+}
+
+export class VerificationSessionResolvedEvent {
+  readonly nullifier: Array<number>
+  readonly authority: PublicKey
+  readonly verified: boolean
+  readonly steps: number
+
+  constructor(fields: VerificationSessionResolvedEventFields) {
+    this.nullifier = fields.nullifier
+    this.authority = fields.authority
+    this.verified = fields.verified
+    this.steps = fields.steps
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.publicKey("authority"),
+        borsh.bool("verified"),
+        borsh.u32("steps"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VerificationSessionResolvedEvent({
+      nullifier: obj.nullifier,
+      authority: obj.authority,
+      verified: obj.verified,
+      steps: obj.steps,
+    })
+  }
+
+  static toEncodable(fields: VerificationSessionResolvedEventFields) {
+    return {
+      nullifier: fields.nullifier,
+      authority: fields.authority,
+      verified: fields.verified,
+      steps: fields.steps,
+    }
+  }
+
+  toJSON(): VerificationSessionResolvedEventJSON {
+    return {
+      nullifier: this.nullifier,
+      authority: this.authority.toString(),
+      verified: this.verified,
+      steps: this.steps,
+    }
+  }
+
+  static fromJSON(obj: VerificationSessionResolvedEventJSON): VerificationSessionResolvedEvent {
+    return new VerificationSessionResolvedEvent({
+      nullifier: obj.nullifier,
+      authority: new PublicKey(obj.authority),
+      verified: obj.verified,
+      steps: obj.steps,
+    })
+  }
+
+  toEncodable() {
+    return VerificationSessionResolvedEvent.toEncodable(this)
+  }
+}
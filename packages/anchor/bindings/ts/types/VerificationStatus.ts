@@ -0,0 +1,148 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface UploadingJSON {
+  kind: "Uploading"
+}
+
+export class Uploading {
+  static readonly discriminator = 0
+  static readonly kind = "Uploading"
+  readonly discriminator = 0
+  readonly kind = "Uploading"
+
+  toJSON(): UploadingJSON {
+    return {
+      kind: "Uploading",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Uploading: {},
+    }
+  }
+}
+
+export interface VerifyingJSON {
+  kind: "Verifying"
+}
+
+export class Verifying {
+  static readonly discriminator = 1
+  static readonly kind = "Verifying"
+  readonly discriminator = 1
+  readonly kind = "Verifying"
+
+  toJSON(): VerifyingJSON {
+    return {
+      kind: "Verifying",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Verifying: {},
+    }
+  }
+}
+
+export interface VerifiedJSON {
+  kind: "Verified"
+}
+
+export class Verified {
+  static readonly discriminator = 2
+  static readonly kind = "Verified"
+  readonly discriminator = 2
+  readonly kind = "Verified"
+
+  toJSON(): VerifiedJSON {
+    return {
+      kind: "Verified",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Verified: {},
+    }
+  }
+}
+
+export interface RejectedJSON {
+  kind: "Rejected"
+}
+
+export class Rejected {
+  static readonly discriminator = 3
+  static readonly kind = "Rejected"
+  readonly discriminator = 3
+  readonly kind = "Rejected"
+
+  toJSON(): RejectedJSON {
+    return {
+      kind: "Rejected",
+    }
+  }
+
+  toEncodable() {
+    return {
+      Rejected: {},
+    }
+  }
+}
+
+// eslint-disable-next-line @typescript-eslint/no-explicit-any
+export function fromDecoded(obj: any): types.VerificationStatusKind {
+  if (typeof obj !== "object") {
+    throw new Error("Invalid enum object")
+  }
+
+  if ("Uploading" in obj) {
+    return new Uploading()
+  }
+  if ("Verifying" in obj) {
+    return new Verifying()
+  }
+  if ("Verified" in obj) {
+    return new Verified()
+  }
+  if ("Rejected" in obj) {
+    return new Rejected()
+  }
+
+  throw new Error("Invalid enum object")
+}
+
+export function fromJSON(obj: types.VerificationStatusJSON): types.VerificationStatusKind {
+  switch (obj.kind) {
+    case "Uploading": {
+      return new Uploading()
+    }
+    case "Verifying": {
+      return new Verifying()
+    }
+    case "Verified": {
+      return new Verified()
+    }
+    case "Rejected": {
+      return new Rejected()
+    }
+  }
+}
+
+export function layout(property?: string) {
+  const ret = borsh.rustEnum([
+    borsh.struct([], "Uploading"),
+    borsh.struct([], "Verifying"),
+    borsh.struct([], "Verified"),
+    borsh.struct([], "Rejected"),
+  ])
+  if (property !== undefined) {
+    return ret.replicate(property)
+  }
+  return ret
+}
@@ -0,0 +1,57 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VerifierAddedEventFields {
+  verifier: PublicKey
+}
+
+export interface VerifierAddedEventJSON {
+  verifier: string
+}
+
+export class VerifierAddedEvent {
+  readonly verifier: PublicKey
+
+  constructor(fields: VerifierAddedEventFields) {
+    this.verifier = fields.verifier
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("verifier"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VerifierAddedEvent({
+      verifier: obj.verifier,
+    })
+  }
+
+  static toEncodable(fields: VerifierAddedEventFields) {
+    return {
+      verifier: fields.verifier,
+    }
+  }
+
+  toJSON(): VerifierAddedEventJSON {
+    return {
+      verifier: this.verifier.toString(),
+    }
+  }
+
+  static fromJSON(obj: VerifierAddedEventJSON): VerifierAddedEvent {
+    return new VerifierAddedEvent({
+      verifier: new PublicKey(obj.verifier),
+    })
+  }
+
+  toEncodable() {
+    return VerifierAddedEvent.toEncodable(this)
+  }
+}
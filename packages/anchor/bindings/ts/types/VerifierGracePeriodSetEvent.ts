@@ -0,0 +1,57 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VerifierGracePeriodSetEventFields {
+  gracePeriodSecs: BN
+}
+
+export interface VerifierGracePeriodSetEventJSON {
+  gracePeriodSecs: string
+}
+
+export class VerifierGracePeriodSetEvent {
+  readonly gracePeriodSecs: BN
+
+  constructor(fields: VerifierGracePeriodSetEventFields) {
+    this.gracePeriodSecs = fields.gracePeriodSecs
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.i64("gracePeriodSecs"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VerifierGracePeriodSetEvent({
+      gracePeriodSecs: obj.gracePeriodSecs,
+    })
+  }
+
+  static toEncodable(fields: VerifierGracePeriodSetEventFields) {
+    return {
+      gracePeriodSecs: fields.gracePeriodSecs,
+    }
+  }
+
+  toJSON(): VerifierGracePeriodSetEventJSON {
+    return {
+      gracePeriodSecs: this.gracePeriodSecs.toString(),
+    }
+  }
+
+  static fromJSON(obj: VerifierGracePeriodSetEventJSON): VerifierGracePeriodSetEvent {
+    return new VerifierGracePeriodSetEvent({
+      gracePeriodSecs: new BN(obj.gracePeriodSecs),
+    })
+  }
+
+  toEncodable() {
+    return VerifierGracePeriodSetEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,84 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VerifierKeyRotatedEventFields {
+  oldKey: PublicKey
+  newKey: PublicKey
+  rotatedAt: BN
+  graceEndsAt: BN
+}
+
+export interface VerifierKeyRotatedEventJSON {
+  oldKey: string
+  newKey: string
+  rotatedAt: string
+  graceEndsAt: string
+}
+
+export class VerifierKeyRotatedEvent {
+  readonly oldKey: PublicKey
+  readonly newKey: PublicKey
+  readonly rotatedAt: BN
+  readonly graceEndsAt: BN
+
+  constructor(fields: VerifierKeyRotatedEventFields) {
+    this.oldKey = fields.oldKey
+    this.newKey = fields.newKey
+    this.rotatedAt = fields.rotatedAt
+    this.graceEndsAt = fields.graceEndsAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("oldKey"),
+        borsh.publicKey("newKey"),
+        borsh.i64("rotatedAt"),
+        borsh.i64("graceEndsAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VerifierKeyRotatedEvent({
+      oldKey: obj.oldKey,
+      newKey: obj.newKey,
+      rotatedAt: obj.rotatedAt,
+      graceEndsAt: obj.graceEndsAt,
+    })
+  }
+
+  static toEncodable(fields: VerifierKeyRotatedEventFields) {
+    return {
+      oldKey: fields.oldKey,
+      newKey: fields.newKey,
+      rotatedAt: fields.rotatedAt,
+      graceEndsAt: fields.graceEndsAt,
+    }
+  }
+
+  toJSON(): VerifierKeyRotatedEventJSON {
+    return {
+      oldKey: this.oldKey.toString(),
+      newKey: this.newKey.toString(),
+      rotatedAt: this.rotatedAt.toString(),
+      graceEndsAt: this.graceEndsAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: VerifierKeyRotatedEventJSON): VerifierKeyRotatedEvent {
+    return new VerifierKeyRotatedEvent({
+      oldKey: new PublicKey(obj.oldKey),
+      newKey: new PublicKey(obj.newKey),
+      rotatedAt: new BN(obj.rotatedAt),
+      graceEndsAt: new BN(obj.graceEndsAt),
+    })
+  }
+
+  toEncodable() {
+    return VerifierKeyRotatedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,57 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VerifierRemovedEventFields {
+  verifier: PublicKey
+}
+
+export interface VerifierRemovedEventJSON {
+  verifier: string
+}
+
+export class VerifierRemovedEvent {
+  readonly verifier: PublicKey
+
+  constructor(fields: VerifierRemovedEventFields) {
+    this.verifier = fields.verifier
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("verifier"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VerifierRemovedEvent({
+      verifier: obj.verifier,
+    })
+  }
+
+  static toEncodable(fields: VerifierRemovedEventFields) {
+    return {
+      verifier: fields.verifier,
+    }
+  }
+
+  toJSON(): VerifierRemovedEventJSON {
+    return {
+      verifier: this.verifier.toString(),
+    }
+  }
+
+  static fromJSON(obj: VerifierRemovedEventJSON): VerifierRemovedEvent {
+    return new VerifierRemovedEvent({
+      verifier: new PublicKey(obj.verifier),
+    })
+  }
+
+  toEncodable() {
+    return VerifierRemovedEvent.toEncodable(this)
+  }
+}
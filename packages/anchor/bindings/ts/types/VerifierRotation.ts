@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VerifierRotationFields {
+  oldKey: PublicKey
+  newKey: PublicKey
+  rotatedAt: BN
+}
+
+export interface VerifierRotationJSON {
+  oldKey: string
+  newKey: string
+  rotatedAt: string
+}
+
+export class VerifierRotation {
+  readonly oldKey: PublicKey
+  readonly newKey: PublicKey
+  readonly rotatedAt: BN
+
+  constructor(fields: VerifierRotationFields) {
+    this.oldKey = fields.oldKey
+    this.newKey = fields.newKey
+    this.rotatedAt = fields.rotatedAt
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("oldKey"),
+        borsh.publicKey("newKey"),
+        borsh.i64("rotatedAt"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VerifierRotation({
+      oldKey: obj.oldKey,
+      newKey: obj.newKey,
+      rotatedAt: obj.rotatedAt,
+    })
+  }
+
+  static toEncodable(fields: VerifierRotationFields) {
+    return {
+      oldKey: fields.oldKey,
+      newKey: fields.newKey,
+      rotatedAt: fields.rotatedAt,
+    }
+  }
+
+  toJSON(): VerifierRotationJSON {
+    return {
+      oldKey: this.oldKey.toString(),
+      newKey: this.newKey.toString(),
+      rotatedAt: this.rotatedAt.toString(),
+    }
+  }
+
+  static fromJSON(obj: VerifierRotationJSON): VerifierRotation {
+    return new VerifierRotation({
+      oldKey: new PublicKey(obj.oldKey),
+      newKey: new PublicKey(obj.newKey),
+      rotatedAt: new BN(obj.rotatedAt),
+    })
+  }
+
+  toEncodable() {
+    return VerifierRotation.toEncodable(this)
+  }
+}
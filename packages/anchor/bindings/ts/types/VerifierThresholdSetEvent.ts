@@ -0,0 +1,57 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VerifierThresholdSetEventFields {
+  threshold: number
+}
+
+export interface VerifierThresholdSetEventJSON {
+  threshold: number
+}
+
+export class VerifierThresholdSetEvent {
+  readonly threshold: number
+
+  constructor(fields: VerifierThresholdSetEventFields) {
+    this.threshold = fields.threshold
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.u8("threshold"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VerifierThresholdSetEvent({
+      threshold: obj.threshold,
+    })
+  }
+
+  static toEncodable(fields: VerifierThresholdSetEventFields) {
+    return {
+      threshold: fields.threshold,
+    }
+  }
+
+  toJSON(): VerifierThresholdSetEventJSON {
+    return {
+      threshold: this.threshold,
+    }
+  }
+
+  static fromJSON(obj: VerifierThresholdSetEventJSON): VerifierThresholdSetEvent {
+    return new VerifierThresholdSetEvent({
+      threshold: obj.threshold,
+    })
+  }
+
+  toEncodable() {
+    return VerifierThresholdSetEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VerifierWeightFields {
+  verifier: PublicKey
+  weight: number
+  lastHeartbeat: BN
+}
+
+export interface VerifierWeightJSON {
+  verifier: string
+  weight: number
+  lastHeartbeat: string
+}
+
+export class VerifierWeight {
+  readonly verifier: PublicKey
+  readonly weight: number
+  readonly lastHeartbeat: BN
+
+  constructor(fields: VerifierWeightFields) {
+    this.verifier = fields.verifier
+    this.weight = fields.weight
+    this.lastHeartbeat = fields.lastHeartbeat
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("verifier"),
+        borsh.u8("weight"),
+        borsh.i64("lastHeartbeat"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VerifierWeight({
+      verifier: obj.verifier,
+      weight: obj.weight,
+      lastHeartbeat: obj.lastHeartbeat,
+    })
+  }
+
+  static toEncodable(fields: VerifierWeightFields) {
+    return {
+      verifier: fields.verifier,
+      weight: fields.weight,
+      lastHeartbeat: fields.lastHeartbeat,
+    }
+  }
+
+  toJSON(): VerifierWeightJSON {
+    return {
+      verifier: this.verifier.toString(),
+      weight: this.weight,
+      lastHeartbeat: this.lastHeartbeat.toString(),
+    }
+  }
+
+  static fromJSON(obj: VerifierWeightJSON): VerifierWeight {
+    return new VerifierWeight({
+      verifier: new PublicKey(obj.verifier),
+      weight: obj.weight,
+      lastHeartbeat: new BN(obj.lastHeartbeat),
+    })
+  }
+
+  toEncodable() {
+    return VerifierWeight.toEncodable(this)
+  }
+}
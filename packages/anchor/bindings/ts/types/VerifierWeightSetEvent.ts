@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VerifierWeightSetEventFields {
+  verifier: PublicKey
+  weight: number
+}
+
+export interface VerifierWeightSetEventJSON {
+  verifier: string
+  weight: number
+}
+
+export class VerifierWeightSetEvent {
+  readonly verifier: PublicKey
+  readonly weight: number
+
+  constructor(fields: VerifierWeightSetEventFields) {
+    this.verifier = fields.verifier
+    this.weight = fields.weight
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.publicKey("verifier"),
+        borsh.u8("weight"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VerifierWeightSetEvent({
+      verifier: obj.verifier,
+      weight: obj.weight,
+    })
+  }
+
+  static toEncodable(fields: VerifierWeightSetEventFields) {
+    return {
+      verifier: fields.verifier,
+      weight: fields.weight,
+    }
+  }
+
+  toJSON(): VerifierWeightSetEventJSON {
+    return {
+      verifier: this.verifier.toString(),
+      weight: this.weight,
+    }
+  }
+
+  static fromJSON(obj: VerifierWeightSetEventJSON): VerifierWeightSetEvent {
+    return new VerifierWeightSetEvent({
+      verifier: new PublicKey(obj.verifier),
+      weight: obj.weight,
+    })
+  }
+
+  toEncodable() {
+    return VerifierWeightSetEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,75 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VerifyAuthEntryFields {
+  result: types.SignedVerificationResult
+  nullifier: Array<number>
+  domain: Array<number>
+}
+
+export interface VerifyAuthEntryJSON {
+  result: types.SignedVerificationResultJSON
+  nullifier: Array<number>
+  domain: Array<number>
+}
+
+export class VerifyAuthEntry {
+  readonly result: types.SignedVerificationResult
+  readonly nullifier: Array<number>
+  readonly domain: Array<number>
+
+  constructor(fields: VerifyAuthEntryFields) {
+    this.result = fields.result
+    this.nullifier = fields.nullifier
+    this.domain = fields.domain
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        types.SignedVerificationResult.layout("result"),
+        borsh.array(borsh.u8(), 32, "nullifier"),
+        borsh.array(borsh.u8(), 32, "domain"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VerifyAuthEntry({
+      result: types.SignedVerificationResult.fromDecoded(obj.result),
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+    })
+  }
+
+  static toEncodable(fields: VerifyAuthEntryFields) {
+    return {
+      result: types.SignedVerificationResult.toEncodable(fields.result),
+      nullifier: fields.nullifier,
+      domain: fields.domain,
+    }
+  }
+
+  toJSON(): VerifyAuthEntryJSON {
+    return {
+      result: this.result.toJSON(),
+      nullifier: this.nullifier,
+      domain: this.domain,
+    }
+  }
+
+  static fromJSON(obj: VerifyAuthEntryJSON): VerifyAuthEntry {
+    return new VerifyAuthEntry({
+      result: types.SignedVerificationResult.fromJSON(obj.result),
+      nullifier: obj.nullifier,
+      domain: obj.domain,
+    })
+  }
+
+  toEncodable() {
+    return VerifyAuthEntry.toEncodable(this)
+  }
+}
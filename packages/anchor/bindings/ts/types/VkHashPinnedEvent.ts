@@ -0,0 +1,66 @@
+import { PublicKey } from "@solana/web3.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import BN from "bn.js" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as types from "../types" // eslint-disable-line @typescript-eslint/no-unused-vars
+import * as borsh from "@coral-xyz/borsh"
+
+export interface VkHashPinnedEventFields {
+  domain: Array<number>
+  vkHash: Array<number>
+}
+
+export interface VkHashPinnedEventJSON {
+  domain: Array<number>
+  vkHash: Array<number>
+}
+
+export class VkHashPinnedEvent {
+  readonly domain: Array<number>
+  readonly vkHash: Array<number>
+
+  constructor(fields: VkHashPinnedEventFields) {
+    this.domain = fields.domain
+    this.vkHash = fields.vkHash
+  }
+
+  static layout(property?: string) {
+    return borsh.struct(
+      [
+        borsh.array(borsh.u8(), 32, "domain"),
+        borsh.array(borsh.u8(), 32, "vkHash"),
+      ], property
+    )
+  }
+
+  // eslint-disable-next-line @typescript-eslint/no-explicit-any
+  static fromDecoded(obj: any) {
+    return new VkHashPinnedEvent({
+      domain: obj.domain,
+      vkHash: obj.vkHash,
+    })
+  }
+
+  static toEncodable(fields: VkHashPinnedEventFields) {
+    return {
+      domain: fields.domain,
+      vkHash: fields.vkHash,
+    }
+  }
+
+  toJSON(): VkHashPinnedEventJSON {
+    return {
+      domain: this.domain,
+      vkHash: this.vkHash,
+    }
+  }
+
+  static fromJSON(obj: VkHashPinnedEventJSON): VkHashPinnedEvent {
+    return new VkHashPinnedEvent({
+      domain: obj.domain,
+      vkHash: obj.vkHash,
+    })
+  }
+
+  toEncodable() {
+    return VkHashPinnedEvent.toEncodable(this)
+  }
+}
@@ -0,0 +1,375 @@
+export { AcceptedMint } from "./AcceptedMint"
+export type { AcceptedMintFields, AcceptedMintJSON } from "./AcceptedMint"
+export { AcceptedMintUpdatedEvent } from "./AcceptedMintUpdatedEvent"
+export type { AcceptedMintUpdatedEventFields, AcceptedMintUpdatedEventJSON } from "./AcceptedMintUpdatedEvent"
+export { AccessLogAcknowledgedEvent } from "./AccessLogAcknowledgedEvent"
+export type { AccessLogAcknowledgedEventFields, AccessLogAcknowledgedEventJSON } from "./AccessLogAcknowledgedEvent"
+export { AccessLogsCompactedEvent } from "./AccessLogsCompactedEvent"
+export type { AccessLogsCompactedEventFields, AccessLogsCompactedEventJSON } from "./AccessLogsCompactedEvent"
+export { AliasClaimedEvent } from "./AliasClaimedEvent"
+export type { AliasClaimedEventFields, AliasClaimedEventJSON } from "./AliasClaimedEvent"
+export { AssuranceTierSetEvent } from "./AssuranceTierSetEvent"
+export type { AssuranceTierSetEventFields, AssuranceTierSetEventJSON } from "./AssuranceTierSetEvent"
+export { AttestationMirroredEvent } from "./AttestationMirroredEvent"
+export type { AttestationMirroredEventFields, AttestationMirroredEventJSON } from "./AttestationMirroredEvent"
+export { AttestationRenewedEvent } from "./AttestationRenewedEvent"
+export type { AttestationRenewedEventFields, AttestationRenewedEventJSON } from "./AttestationRenewedEvent"
+export { AttestationRevokedEvent } from "./AttestationRevokedEvent"
+export type { AttestationRevokedEventFields, AttestationRevokedEventJSON } from "./AttestationRevokedEvent"
+export { AttestationStoredEvent } from "./AttestationStoredEvent"
+export type { AttestationStoredEventFields, AttestationStoredEventJSON } from "./AttestationStoredEvent"
+export { AttestorRegisteredEvent } from "./AttestorRegisteredEvent"
+export type { AttestorRegisteredEventFields, AttestorRegisteredEventJSON } from "./AttestorRegisteredEvent"
+import * as AttestorStatus from "./AttestorStatus"
+
+export { AttestorStatus }
+
+export type AttestorStatusKind =
+  | AttestorStatus.Active
+  | AttestorStatus.Suspended
+export type AttestorStatusJSON =
+  | AttestorStatus.ActiveJSON
+  | AttestorStatus.SuspendedJSON
+
+export { AttestorStatusSetEvent } from "./AttestorStatusSetEvent"
+export type { AttestorStatusSetEventFields, AttestorStatusSetEventJSON } from "./AttestorStatusSetEvent"
+export { AuditorGrantedEvent } from "./AuditorGrantedEvent"
+export type { AuditorGrantedEventFields, AuditorGrantedEventJSON } from "./AuditorGrantedEvent"
+export { AuditorRevokedEvent } from "./AuditorRevokedEvent"
+export type { AuditorRevokedEventFields, AuditorRevokedEventJSON } from "./AuditorRevokedEvent"
+export { AuthVerifiedEvent } from "./AuthVerifiedEvent"
+export type { AuthVerifiedEventFields, AuthVerifiedEventJSON } from "./AuthVerifiedEvent"
+export { ChallengeWindowSetEvent } from "./ChallengeWindowSetEvent"
+export type { ChallengeWindowSetEventFields, ChallengeWindowSetEventJSON } from "./ChallengeWindowSetEvent"
+export { CircuitDeprecatedEvent } from "./CircuitDeprecatedEvent"
+export type { CircuitDeprecatedEventFields, CircuitDeprecatedEventJSON } from "./CircuitDeprecatedEvent"
+export { CircuitEntry } from "./CircuitEntry"
+export type { CircuitEntryFields, CircuitEntryJSON } from "./CircuitEntry"
+export { CircuitRegisteredEvent } from "./CircuitRegisteredEvent"
+export type { CircuitRegisteredEventFields, CircuitRegisteredEventJSON } from "./CircuitRegisteredEvent"
+import * as ClaimType from "./ClaimType"
+
+export { ClaimType }
+
+export type ClaimTypeKind =
+  | ClaimType.Auth
+  | ClaimType.BalanceThreshold
+  | ClaimType.AgeOver
+  | ClaimType.NftOwnership
+export type ClaimTypeJSON =
+  | ClaimType.AuthJSON
+  | ClaimType.BalanceThresholdJSON
+  | ClaimType.AgeOverJSON
+  | ClaimType.NftOwnershipJSON
+
+export { CompressedNullifierRegisteredEvent } from "./CompressedNullifierRegisteredEvent"
+export type { CompressedNullifierRegisteredEventFields, CompressedNullifierRegisteredEventJSON } from "./CompressedNullifierRegisteredEvent"
+export { DomainAssuranceTierSetEvent } from "./DomainAssuranceTierSetEvent"
+export type { DomainAssuranceTierSetEventFields, DomainAssuranceTierSetEventJSON } from "./DomainAssuranceTierSetEvent"
+export { DomainAttestorClaimsSetEvent } from "./DomainAttestorClaimsSetEvent"
+export type { DomainAttestorClaimsSetEventFields, DomainAttestorClaimsSetEventJSON } from "./DomainAttestorClaimsSetEvent"
+export { DomainConfigInitializedEvent } from "./DomainConfigInitializedEvent"
+export type { DomainConfigInitializedEventFields, DomainConfigInitializedEventJSON } from "./DomainConfigInitializedEvent"
+export { DomainExpiryUnitSetEvent } from "./DomainExpiryUnitSetEvent"
+export type { DomainExpiryUnitSetEventFields, DomainExpiryUnitSetEventJSON } from "./DomainExpiryUnitSetEvent"
+export { DomainFeePaidEvent } from "./DomainFeePaidEvent"
+export type { DomainFeePaidEventFields, DomainFeePaidEventJSON } from "./DomainFeePaidEvent"
+export { DomainFeeShareSetEvent } from "./DomainFeeShareSetEvent"
+export type { DomainFeeShareSetEventFields, DomainFeeShareSetEventJSON } from "./DomainFeeShareSetEvent"
+export { DomainFeeVaultAtaCreatedEvent } from "./DomainFeeVaultAtaCreatedEvent"
+export type { DomainFeeVaultAtaCreatedEventFields, DomainFeeVaultAtaCreatedEventJSON } from "./DomainFeeVaultAtaCreatedEvent"
+export { DomainFeesClaimedEvent } from "./DomainFeesClaimedEvent"
+export type { DomainFeesClaimedEventFields, DomainFeesClaimedEventJSON } from "./DomainFeesClaimedEvent"
+export { DomainIdleTimeoutSetEvent } from "./DomainIdleTimeoutSetEvent"
+export type { DomainIdleTimeoutSetEventFields, DomainIdleTimeoutSetEventJSON } from "./DomainIdleTimeoutSetEvent"
+export { DomainMaxResultAgeSetEvent } from "./DomainMaxResultAgeSetEvent"
+export type { DomainMaxResultAgeSetEventFields, DomainMaxResultAgeSetEventJSON } from "./DomainMaxResultAgeSetEvent"
+export { DomainNullifierStoreSetEvent } from "./DomainNullifierStoreSetEvent"
+export type { DomainNullifierStoreSetEventFields, DomainNullifierStoreSetEventJSON } from "./DomainNullifierStoreSetEvent"
+export { DomainOriginsSetEvent } from "./DomainOriginsSetEvent"
+export type { DomainOriginsSetEventFields, DomainOriginsSetEventJSON } from "./DomainOriginsSetEvent"
+export { DomainPausedSetEvent } from "./DomainPausedSetEvent"
+export type { DomainPausedSetEventFields, DomainPausedSetEventJSON } from "./DomainPausedSetEvent"
+export { DomainRateLimitSetEvent } from "./DomainRateLimitSetEvent"
+export type { DomainRateLimitSetEventFields, DomainRateLimitSetEventJSON } from "./DomainRateLimitSetEvent"
+export { DomainRegisteredEvent } from "./DomainRegisteredEvent"
+export type { DomainRegisteredEventFields, DomainRegisteredEventJSON } from "./DomainRegisteredEvent"
+import * as DomainRole from "./DomainRole"
+
+export { DomainRole }
+
+export type DomainRoleKind =
+  | DomainRole.Owner
+  | DomainRole.Operator
+  | DomainRole.Treasurer
+export type DomainRoleJSON =
+  | DomainRole.OwnerJSON
+  | DomainRole.OperatorJSON
+  | DomainRole.TreasurerJSON
+
+export { DomainRoleSetEvent } from "./DomainRoleSetEvent"
+export type { DomainRoleSetEventFields, DomainRoleSetEventJSON } from "./DomainRoleSetEvent"
+export { DomainSessionBoundsSetEvent } from "./DomainSessionBoundsSetEvent"
+export type { DomainSessionBoundsSetEventFields, DomainSessionBoundsSetEventJSON } from "./DomainSessionBoundsSetEvent"
+export { DomainVerifiersSetEvent } from "./DomainVerifiersSetEvent"
+export type { DomainVerifiersSetEventFields, DomainVerifiersSetEventJSON } from "./DomainVerifiersSetEvent"
+export { ExpiredNullifierClosedEvent } from "./ExpiredNullifierClosedEvent"
+export type { ExpiredNullifierClosedEventFields, ExpiredNullifierClosedEventJSON } from "./ExpiredNullifierClosedEvent"
+import * as ExpiryKind from "./ExpiryKind"
+
+export { ExpiryKind }
+
+export type ExpiryKindKind =
+  | ExpiryKind.UnixTime
+  | ExpiryKind.Slot
+export type ExpiryKindJSON =
+  | ExpiryKind.UnixTimeJSON
+  | ExpiryKind.SlotJSON
+
+import * as ExpiryUnit from "./ExpiryUnit"
+
+export { ExpiryUnit }
+
+export type ExpiryUnitKind =
+  | ExpiryUnit.UnixTime
+  | ExpiryUnit.Slot
+export type ExpiryUnitJSON =
+  | ExpiryUnit.UnixTimeJSON
+  | ExpiryUnit.SlotJSON
+
+export { FeePaidEvent } from "./FeePaidEvent"
+export type { FeePaidEventFields, FeePaidEventJSON } from "./FeePaidEvent"
+export { GlobalLogoutEvent } from "./GlobalLogoutEvent"
+export type { GlobalLogoutEventFields, GlobalLogoutEventJSON } from "./GlobalLogoutEvent"
+export { Groth16VkRegisteredEvent } from "./Groth16VkRegisteredEvent"
+export type { Groth16VkRegisteredEventFields, Groth16VkRegisteredEventJSON } from "./Groth16VkRegisteredEvent"
+export { GuardiansSetEvent } from "./GuardiansSetEvent"
+export type { GuardiansSetEventFields, GuardiansSetEventJSON } from "./GuardiansSetEvent"
+export { HonkVkRegisteredEvent } from "./HonkVkRegisteredEvent"
+export type { HonkVkRegisteredEventFields, HonkVkRegisteredEventJSON } from "./HonkVkRegisteredEvent"
+export { IdentityRecoveredEvent } from "./IdentityRecoveredEvent"
+export type { IdentityRecoveredEventFields, IdentityRecoveredEventJSON } from "./IdentityRecoveredEvent"
+export { IdentityRootCreatedEvent } from "./IdentityRootCreatedEvent"
+export type { IdentityRootCreatedEventFields, IdentityRootCreatedEventJSON } from "./IdentityRootCreatedEvent"
+export { IdentityRootLinkedEvent } from "./IdentityRootLinkedEvent"
+export type { IdentityRootLinkedEventFields, IdentityRootLinkedEventJSON } from "./IdentityRootLinkedEvent"
+export { IdentityRootUnlinkedEvent } from "./IdentityRootUnlinkedEvent"
+export type { IdentityRootUnlinkedEventFields, IdentityRootUnlinkedEventJSON } from "./IdentityRootUnlinkedEvent"
+import * as MigratedAccount from "./MigratedAccount"
+
+export { MigratedAccount }
+
+export type MigratedAccountKind =
+  | MigratedAccount.Session
+  | MigratedAccount.ProofRecord
+  | MigratedAccount.PermissionGrant
+  | MigratedAccount.DomainConfig
+export type MigratedAccountJSON =
+  | MigratedAccount.SessionJSON
+  | MigratedAccount.ProofRecordJSON
+  | MigratedAccount.PermissionGrantJSON
+  | MigratedAccount.DomainConfigJSON
+
+export { MigrationBegunEvent } from "./MigrationBegunEvent"
+export type { MigrationBegunEventFields, MigrationBegunEventJSON } from "./MigrationBegunEvent"
+export { MigrationCompletedEvent } from "./MigrationCompletedEvent"
+export type { MigrationCompletedEventFields, MigrationCompletedEventJSON } from "./MigrationCompletedEvent"
+export { MigrationProgressEvent } from "./MigrationProgressEvent"
+export type { MigrationProgressEventFields, MigrationProgressEventJSON } from "./MigrationProgressEvent"
+export { MigrationStep } from "./MigrationStep"
+export type { MigrationStepFields, MigrationStepJSON } from "./MigrationStep"
+export { NonInclusionProof } from "./NonInclusionProof"
+export type { NonInclusionProofFields, NonInclusionProofJSON } from "./NonInclusionProof"
+export { NotificationChannelClosedEvent } from "./NotificationChannelClosedEvent"
+export type { NotificationChannelClosedEventFields, NotificationChannelClosedEventJSON } from "./NotificationChannelClosedEvent"
+export { NotificationChannelSetEvent } from "./NotificationChannelSetEvent"
+export type { NotificationChannelSetEventFields, NotificationChannelSetEventJSON } from "./NotificationChannelSetEvent"
+export { NullifierBannedEvent } from "./NullifierBannedEvent"
+export type { NullifierBannedEventFields, NullifierBannedEventJSON } from "./NullifierBannedEvent"
+export { NullifierMigratedEvent } from "./NullifierMigratedEvent"
+export type { NullifierMigratedEventFields, NullifierMigratedEventJSON } from "./NullifierMigratedEvent"
+import * as NullifierStore from "./NullifierStore"
+
+export { NullifierStore }
+
+export type NullifierStoreKind =
+  | NullifierStore.Accounts
+  | NullifierStore.Bitmap
+  | NullifierStore.Compressed
+export type NullifierStoreJSON =
+  | NullifierStore.AccountsJSON
+  | NullifierStore.BitmapJSON
+  | NullifierStore.CompressedJSON
+
+export { NullifierTreeInitializedEvent } from "./NullifierTreeInitializedEvent"
+export type { NullifierTreeInitializedEventFields, NullifierTreeInitializedEventJSON } from "./NullifierTreeInitializedEvent"
+export { NullifierUnbannedEvent } from "./NullifierUnbannedEvent"
+export type { NullifierUnbannedEventFields, NullifierUnbannedEventJSON } from "./NullifierUnbannedEvent"
+import * as Permission from "./Permission"
+
+export { Permission }
+
+export type PermissionKind =
+  | Permission.RevealWalletAddress
+  | Permission.RevealExactBalance
+  | Permission.RevealTokenBalances
+  | Permission.RevealNFTList
+  | Permission.RevealTransactionHistory
+  | Permission.RevealStakingPositions
+  | Permission.RevealDeFiPositions
+  | Permission.SignTransactions
+  | Permission.ReceiveNotifications
+export type PermissionJSON =
+  | Permission.RevealWalletAddressJSON
+  | Permission.RevealExactBalanceJSON
+  | Permission.RevealTokenBalancesJSON
+  | Permission.RevealNFTListJSON
+  | Permission.RevealTransactionHistoryJSON
+  | Permission.RevealStakingPositionsJSON
+  | Permission.RevealDeFiPositionsJSON
+  | Permission.SignTransactionsJSON
+  | Permission.ReceiveNotificationsJSON
+
+export { PermissionAccessedEvent } from "./PermissionAccessedEvent"
+export type { PermissionAccessedEventFields, PermissionAccessedEventJSON } from "./PermissionAccessedEvent"
+import * as PermissionDenialReason from "./PermissionDenialReason"
+
+export { PermissionDenialReason }
+
+export type PermissionDenialReasonKind =
+  | PermissionDenialReason.Revoked
+  | PermissionDenialReason.Expired
+  | PermissionDenialReason.NotGranted
+export type PermissionDenialReasonJSON =
+  | PermissionDenialReason.RevokedJSON
+  | PermissionDenialReason.ExpiredJSON
+  | PermissionDenialReason.NotGrantedJSON
+
+export { PermissionDeniedEvent } from "./PermissionDeniedEvent"
+export type { PermissionDeniedEventFields, PermissionDeniedEventJSON } from "./PermissionDeniedEvent"
+export { PermissionGrantedEvent } from "./PermissionGrantedEvent"
+export type { PermissionGrantedEventFields, PermissionGrantedEventJSON } from "./PermissionGrantedEvent"
+export { PermissionRevokedEvent } from "./PermissionRevokedEvent"
+export type { PermissionRevokedEventFields, PermissionRevokedEventJSON } from "./PermissionRevokedEvent"
+export { PersonhoodAttestationArchivedEvent } from "./PersonhoodAttestationArchivedEvent"
+export type { PersonhoodAttestationArchivedEventFields, PersonhoodAttestationArchivedEventJSON } from "./PersonhoodAttestationArchivedEvent"
+export { PersonhoodAttestationRestoredEvent } from "./PersonhoodAttestationRestoredEvent"
+export type { PersonhoodAttestationRestoredEventFields, PersonhoodAttestationRestoredEventJSON } from "./PersonhoodAttestationRestoredEvent"
+export { PersonhoodAttestedEvent } from "./PersonhoodAttestedEvent"
+export type { PersonhoodAttestedEventFields, PersonhoodAttestedEventJSON } from "./PersonhoodAttestedEvent"
+export { PersonhoodProviderAddedEvent } from "./PersonhoodProviderAddedEvent"
+export type { PersonhoodProviderAddedEventFields, PersonhoodProviderAddedEventJSON } from "./PersonhoodProviderAddedEvent"
+export { PersonhoodProviderRemovedEvent } from "./PersonhoodProviderRemovedEvent"
+export type { PersonhoodProviderRemovedEventFields, PersonhoodProviderRemovedEventJSON } from "./PersonhoodProviderRemovedEvent"
+export { PointsRootPublishedEvent } from "./PointsRootPublishedEvent"
+export type { PointsRootPublishedEventFields, PointsRootPublishedEventJSON } from "./PointsRootPublishedEvent"
+export { PricingUpdatedEvent } from "./PricingUpdatedEvent"
+export type { PricingUpdatedEventFields, PricingUpdatedEventJSON } from "./PricingUpdatedEvent"
+export { ReceiptRetentionSetEvent } from "./ReceiptRetentionSetEvent"
+export type { ReceiptRetentionSetEventFields, ReceiptRetentionSetEventJSON } from "./ReceiptRetentionSetEvent"
+export { RelayerQuotaSetEvent } from "./RelayerQuotaSetEvent"
+export type { RelayerQuotaSetEventFields, RelayerQuotaSetEventJSON } from "./RelayerQuotaSetEvent"
+export { RentToppedUpEvent } from "./RentToppedUpEvent"
+export type { RentToppedUpEventFields, RentToppedUpEventJSON } from "./RentToppedUpEvent"
+export { ResultReceiptClosedEvent } from "./ResultReceiptClosedEvent"
+export type { ResultReceiptClosedEventFields, ResultReceiptClosedEventJSON } from "./ResultReceiptClosedEvent"
+export { RevealEscrowCancelledEvent } from "./RevealEscrowCancelledEvent"
+export type { RevealEscrowCancelledEventFields, RevealEscrowCancelledEventJSON } from "./RevealEscrowCancelledEvent"
+export { RevealEscrowOpenedEvent } from "./RevealEscrowOpenedEvent"
+export type { RevealEscrowOpenedEventFields, RevealEscrowOpenedEventJSON } from "./RevealEscrowOpenedEvent"
+export { RevealEscrowReleasedEvent } from "./RevealEscrowReleasedEvent"
+export type { RevealEscrowReleasedEventFields, RevealEscrowReleasedEventJSON } from "./RevealEscrowReleasedEvent"
+export { RootMember } from "./RootMember"
+export type { RootMemberFields, RootMemberJSON } from "./RootMember"
+export { SasBridgeSetEvent } from "./SasBridgeSetEvent"
+export type { SasBridgeSetEventFields, SasBridgeSetEventJSON } from "./SasBridgeSetEvent"
+export { Secp256r1VerifierAddedEvent } from "./Secp256r1VerifierAddedEvent"
+export type { Secp256r1VerifierAddedEventFields, Secp256r1VerifierAddedEventJSON } from "./Secp256r1VerifierAddedEvent"
+export { Secp256r1VerifierRemovedEvent } from "./Secp256r1VerifierRemovedEvent"
+export type { Secp256r1VerifierRemovedEventFields, Secp256r1VerifierRemovedEventJSON } from "./Secp256r1VerifierRemovedEvent"
+export { SessionClosedEvent } from "./SessionClosedEvent"
+export type { SessionClosedEventFields, SessionClosedEventJSON } from "./SessionClosedEvent"
+export { SessionDelegateRevokedEvent } from "./SessionDelegateRevokedEvent"
+export type { SessionDelegateRevokedEventFields, SessionDelegateRevokedEventJSON } from "./SessionDelegateRevokedEvent"
+export { SessionDelegatedEvent } from "./SessionDelegatedEvent"
+export type { SessionDelegatedEventFields, SessionDelegatedEventJSON } from "./SessionDelegatedEvent"
+export { SessionDetails } from "./SessionDetails"
+export type { SessionDetailsFields, SessionDetailsJSON } from "./SessionDetails"
+export { SessionRenewedEvent } from "./SessionRenewedEvent"
+export type { SessionRenewedEventFields, SessionRenewedEventJSON } from "./SessionRenewedEvent"
+export { ShardedNullifierRegisteredEvent } from "./ShardedNullifierRegisteredEvent"
+export type { ShardedNullifierRegisteredEventFields, ShardedNullifierRegisteredEventJSON } from "./ShardedNullifierRegisteredEvent"
+export { SignedVerificationResult } from "./SignedVerificationResult"
+export type { SignedVerificationResultFields, SignedVerificationResultJSON } from "./SignedVerificationResult"
+export { TreasuryAtaCreatedEvent } from "./TreasuryAtaCreatedEvent"
+export type { TreasuryAtaCreatedEventFields, TreasuryAtaCreatedEventJSON } from "./TreasuryAtaCreatedEvent"
+export { TreasurySweptEvent } from "./TreasurySweptEvent"
+export type { TreasurySweptEventFields, TreasurySweptEventJSON } from "./TreasurySweptEvent"
+export { UsagePointsClaimedEvent } from "./UsagePointsClaimedEvent"
+export type { UsagePointsClaimedEventFields, UsagePointsClaimedEventJSON } from "./UsagePointsClaimedEvent"
+export { UserConsent } from "./UserConsent"
+export type { UserConsentFields, UserConsentJSON } from "./UserConsent"
+export { UsernameClaimedEvent } from "./UsernameClaimedEvent"
+export type { UsernameClaimedEventFields, UsernameClaimedEventJSON } from "./UsernameClaimedEvent"
+export { UsernameReleasedEvent } from "./UsernameReleasedEvent"
+export type { UsernameReleasedEventFields, UsernameReleasedEventJSON } from "./UsernameReleasedEvent"
+export { UsernameTransferredEvent } from "./UsernameTransferredEvent"
+export type { UsernameTransferredEventFields, UsernameTransferredEventJSON } from "./UsernameTransferredEvent"
+export { VcCommitmentIssuedEvent } from "./VcCommitmentIssuedEvent"
+export type { VcCommitmentIssuedEventFields, VcCommitmentIssuedEventJSON } from "./VcCommitmentIssuedEvent"
+import * as VcStatus from "./VcStatus"
+
+export { VcStatus }
+
+export type VcStatusKind =
+  | VcStatus.Active
+  | VcStatus.Suspended
+  | VcStatus.Revoked
+export type VcStatusJSON =
+  | VcStatus.ActiveJSON
+  | VcStatus.SuspendedJSON
+  | VcStatus.RevokedJSON
+
+export { VcStatusChangedEvent } from "./VcStatusChangedEvent"
+export type { VcStatusChangedEventFields, VcStatusChangedEventJSON } from "./VcStatusChangedEvent"
+export { VerificationChallengedEvent } from "./VerificationChallengedEvent"
+export type { VerificationChallengedEventFields, VerificationChallengedEventJSON } from "./VerificationChallengedEvent"
+export { VerificationSessionOpenedEvent } from "./VerificationSessionOpenedEvent"
+export type { VerificationSessionOpenedEventFields, VerificationSessionOpenedEventJSON } from "./VerificationSessionOpenedEvent"
+export { VerificationSessionResolvedEvent } from "./VerificationSessionResolvedEvent"
+export type { VerificationSessionResolvedEventFields, VerificationSessionResolvedEventJSON } from "./VerificationSessionResolvedEvent"
+import * as VerificationStatus from "./VerificationStatus"
+
+export { VerificationStatus }
+
+export type VerificationStatusKind =
+  | VerificationStatus.Uploading
+  | VerificationStatus.Verifying
+  | VerificationStatus.Verified
+  | VerificationStatus.Rejected
+export type VerificationStatusJSON =
+  | VerificationStatus.UploadingJSON
+  | VerificationStatus.VerifyingJSON
+  | VerificationStatus.VerifiedJSON
+  | VerificationStatus.RejectedJSON
+
+export { VerifierAddedEvent } from "./VerifierAddedEvent"
+export type { VerifierAddedEventFields, VerifierAddedEventJSON } from "./VerifierAddedEvent"
+export { VerifierGracePeriodSetEvent } from "./VerifierGracePeriodSetEvent"
+export type { VerifierGracePeriodSetEventFields, VerifierGracePeriodSetEventJSON } from "./VerifierGracePeriodSetEvent"
+export { VerifierKeyRotatedEvent } from "./VerifierKeyRotatedEvent"
+export type { VerifierKeyRotatedEventFields, VerifierKeyRotatedEventJSON } from "./VerifierKeyRotatedEvent"
+export { VerifierRemovedEvent } from "./VerifierRemovedEvent"
+export type { VerifierRemovedEventFields, VerifierRemovedEventJSON } from "./VerifierRemovedEvent"
+export { VerifierRotation } from "./VerifierRotation"
+export type { VerifierRotationFields, VerifierRotationJSON } from "./VerifierRotation"
+export { VerifierThresholdSetEvent } from "./VerifierThresholdSetEvent"
+export type { VerifierThresholdSetEventFields, VerifierThresholdSetEventJSON } from "./VerifierThresholdSetEvent"
+export { VerifierWeight } from "./VerifierWeight"
+export type { VerifierWeightFields, VerifierWeightJSON } from "./VerifierWeight"
+export { VerifierWeightSetEvent } from "./VerifierWeightSetEvent"
+export type { VerifierWeightSetEventFields, VerifierWeightSetEventJSON } from "./VerifierWeightSetEvent"
+export { VerifyAuthEntry } from "./VerifyAuthEntry"
+export type { VerifyAuthEntryFields, VerifyAuthEntryJSON } from "./VerifyAuthEntry"
+export { VkHashPinnedEvent } from "./VkHashPinnedEvent"
+export type { VkHashPinnedEventFields, VkHashPinnedEventJSON } from "./VkHashPinnedEvent"
@@ -0,0 +1,377 @@
+// * Precompile signature introspection
+// *
+// * Solana programs can't afford to verify signatures themselves: the runtime verifies
+// * Ed25519Program and Secp256r1 precompile instructions before any instruction runs, and a
+// * program reads them back through the instructions sysvar to check they cover the
+// * (pubkey, message) pairs it expects. This crate is the hardened parsing of that layout that
+// * Veiled uses (offset indices, bounds, entry counts), without Anchor or Veiled dependencies, so
+// * other programs can reuse it instead of re-implementing it.
+// *
+// * Precompile instruction data layout:
+// * [num_signatures: u8][padding: u8][SignatureOffsets * num_signatures][...data blobs...]
+// *
+// * SignatureOffsets (14 bytes, little-endian u16 fields):
+// * - signature_offset
+// * - signature_instruction_index
+// * - public_key_offset
+// * - public_key_instruction_index
+// * - message_data_offset
+// * - message_data_size
+// * - message_instruction_index
+// *
+// * Only entries whose offsets all point into their own instruction (index u16::MAX) are
+// * accepted; an entry pointing into another instruction could borrow a message or key the
+// * precompile never tied to its signature.
+
+use core::fmt;
+use solana_account_info::AccountInfo;
+use solana_instruction::Instruction;
+use solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked};
+use solana_pubkey::Pubkey;
+
+// * Ed25519 signature verification program id (Solana built-in program)
+// * Base58: Ed25519SigVerify111111111111111111111111111
+pub const ED25519_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
+    0x03, 0x7d, 0x46, 0xd6, 0x7c, 0x93, 0xfb, 0xbe, 0x12, 0xf9, 0x42, 0x8f, 0x83, 0x8d, 0x40, 0xff,
+    0x05, 0x70, 0x74, 0x49, 0x27, 0xf4, 0x8a, 0x64, 0xfc, 0xca, 0x70, 0x44, 0x80, 0x00, 0x00, 0x00,
+]);
+
+// * Secp256r1 signature verification program id (SIMD-0075 precompile)
+// * Base58: Secp256r1SigVerify1111111111111111111111111
+pub const SECP256R1_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
+    0x06, 0x92, 0x0d, 0xec, 0x2f, 0xea, 0x71, 0xb5, 0xb7, 0x23, 0x81, 0x4d, 0x74, 0x2d, 0xa9, 0x03,
+    0x1c, 0x83, 0xe7, 0x5f, 0xdb, 0x79, 0x5d, 0x56, 0x8e, 0x75, 0x47, 0x80, 0x20, 0x00, 0x00, 0x00,
+]);
+
+/// * Most signatures parsed out of one precompile instruction
+/// * Wallets and relayers batch several signatures into a single instruction
+pub const MAX_SIGNATURES_PER_INSTRUCTION: usize = 8;
+
+const HEADER_LEN: usize = 16;
+const TABLE_START: usize = 2;
+const ENTRY_LEN: usize = 14;
+const SIG_LEN: usize = 64;
+
+/// * Raw public key and signature of one precompile entry
+pub type SignatureEntry<const PUBKEY_LEN: usize> = ([u8; PUBKEY_LEN], [u8; 64]);
+
+/// * Why a transaction's precompile instructions couldn't be read or trusted
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IntrospectError {
+    /// * The instructions sysvar couldn't be read (wrong account or malformed data)
+    SysvarUnavailable,
+    /// * Header, offsets table or an entry's slices fall outside the instruction data
+    InvalidInstructionData,
+    /// * Signature count is zero or above MAX_SIGNATURES_PER_INSTRUCTION
+    InvalidSignatureCount,
+    /// * An entry points into another instruction's data
+    OffsetMismatch,
+    /// * A precompile instruction carries accounts (precompiles are stateless)
+    UnexpectedAccounts,
+    /// * More precompile instructions than the caller's bound
+    TooManyInstructions,
+}
+
+impl fmt::Display for IntrospectError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::SysvarUnavailable => "instructions sysvar unavailable",
+            Self::InvalidInstructionData => "invalid precompile instruction data",
+            Self::InvalidSignatureCount => "invalid precompile signature count",
+            Self::OffsetMismatch => "precompile offsets point into another instruction",
+            Self::UnexpectedAccounts => "precompile instruction carries accounts",
+            Self::TooManyInstructions => "too many precompile instructions",
+        })
+    }
+}
+
+impl std::error::Error for IntrospectError {}
+
+/// * Why signature_entries found no usable entry: the layout, or the caller's message check
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Rejected<E> {
+    Layout(IntrospectError),
+    Message(E),
+}
+
+impl<E> From<IntrospectError> for Rejected<E> {
+    fn from(error: IntrospectError) -> Self {
+        Self::Layout(error)
+    }
+}
+
+/// * Indices of every instruction in the transaction except the current one
+/// * The runtime verifies precompile instructions before executing any instruction, so a
+/// * signature instruction placed after this one is as binding as one placed before it.
+pub fn other_instruction_indices(
+    instructions_sysvar: &AccountInfo,
+) -> Result<impl Iterator<Item = usize>, IntrospectError> {
+    let current_index = load_current_index_checked(instructions_sysvar)
+        .map_err(|_| IntrospectError::SysvarUnavailable)?;
+
+    // * The sysvar data starts with the instruction count (u16, little-endian)
+    let data = instructions_sysvar
+        .try_borrow_data()
+        .map_err(|_| IntrospectError::SysvarUnavailable)?;
+    if data.len() < 2 {
+        return Err(IntrospectError::SysvarUnavailable);
+    }
+    let num_instructions = u16::from_le_bytes([data[0], data[1]]);
+
+    Ok((0..num_instructions)
+        .filter(move |&idx| idx != current_index)
+        .map(usize::from))
+}
+
+/// * Parses a precompile instruction and returns the raw public key and signature of every
+/// * entry whose message `check_message` accepts. Ed25519Program and the Secp256r1 precompile
+/// * share this layout; only the public key length differs (32, or 33 for compressed P-256 keys).
+/// *
+/// * Wallets and relayers batch unrelated signatures into one instruction, so entries that
+/// * fail the per-entry checks are skipped. The precompile still verifies all of them. When
+/// * no entry passes, the first entry's error is returned.
+/// *
+/// * Security validations performed:
+/// * 1. Header length validation (minimum 16 bytes)
+/// * 2. Signature count validation (1 to MAX_SIGNATURES_PER_INSTRUCTION, table in bounds)
+/// * 3-5. Per entry, see entry_parts
+/// * 6+. Per entry, whatever `check_message` requires of the signed message
+pub fn signature_entries<const PUBKEY_LEN: usize, E>(
+    ix: &Instruction,
+    check_message: impl Fn(&[u8]) -> Result<(), E>,
+) -> Result<Vec<SignatureEntry<PUBKEY_LEN>>, Rejected<E>> {
+    let data = ix.data.as_slice();
+
+    // * SECURITY CHECK 1: Validate header length
+    if data.len() < HEADER_LEN {
+        return Err(IntrospectError::InvalidInstructionData.into());
+    }
+
+    // * SECURITY CHECK 2: Validate signature count and that the offsets table fits
+    let num_signatures = data[0] as usize;
+    if !(1..=MAX_SIGNATURES_PER_INSTRUCTION).contains(&num_signatures) {
+        return Err(IntrospectError::InvalidSignatureCount.into());
+    }
+    let table_end = TABLE_START + num_signatures * ENTRY_LEN;
+    if data.len() < table_end {
+        return Err(IntrospectError::InvalidInstructionData.into());
+    }
+
+    let mut matches = Vec::with_capacity(num_signatures);
+    let mut first_error = None;
+    for entry in 0..num_signatures {
+        let base = TABLE_START + entry * ENTRY_LEN;
+        let parts = entry_parts::<PUBKEY_LEN>(data, base, table_end)
+            .map_err(Rejected::Layout)
+            .and_then(|(entry, message)| {
+                check_message(message).map_err(Rejected::Message)?;
+                Ok(entry)
+            });
+        match parts {
+            Ok(parts) => matches.push(parts),
+            Err(error) => {
+                first_error.get_or_insert(error);
+            }
+        }
+    }
+
+    match first_error {
+        Some(error) if matches.is_empty() => Err(error),
+        _ => Ok(matches),
+    }
+}
+
+/// * Parses the offsets entry at `base`, returning its public key and signature, and the message
+/// *
+/// * Security validations performed:
+/// * 3. CRITICAL: Offset index validation (all must == u16::MAX for current instruction)
+/// * 4. Bounds checking (all offsets past the offsets table)
+/// * 5. Bounds checking (all slices within the instruction data)
+fn entry_parts<const PUBKEY_LEN: usize>(
+    data: &[u8],
+    base: usize,
+    header_len: usize,
+) -> Result<(SignatureEntry<PUBKEY_LEN>, &[u8]), IntrospectError> {
+    let signature_offset = u16::from_le_bytes([data[base], data[base + 1]]) as usize;
+    let signature_ix_idx = u16::from_le_bytes([data[base + 2], data[base + 3]]);
+    let public_key_offset = u16::from_le_bytes([data[base + 4], data[base + 5]]) as usize;
+    let public_key_ix_idx = u16::from_le_bytes([data[base + 6], data[base + 7]]);
+    let message_offset = u16::from_le_bytes([data[base + 8], data[base + 9]]) as usize;
+    let message_size = u16::from_le_bytes([data[base + 10], data[base + 11]]) as usize;
+    let message_ix_idx = u16::from_le_bytes([data[base + 12], data[base + 13]]);
+
+    // * SECURITY CHECK 3: CRITICAL - Validate all offsets point to current instruction
+    // * All offset indices MUST == u16::MAX (current instruction sentinel)
+    // * This prevents attackers from pointing to data in other instructions
+    if signature_ix_idx != u16::MAX || public_key_ix_idx != u16::MAX || message_ix_idx != u16::MAX {
+        return Err(IntrospectError::OffsetMismatch);
+    }
+
+    // * SECURITY CHECK 4: Bounds check all offsets
+    // * All offsets must be past the header and offsets table
+    if signature_offset < header_len
+        || public_key_offset < header_len
+        || message_offset < header_len
+    {
+        return Err(IntrospectError::InvalidInstructionData);
+    }
+
+    // * SECURITY CHECK 5: Bounds check all slices
+    if data.len() < signature_offset + SIG_LEN
+        || data.len() < public_key_offset + PUBKEY_LEN
+        || data.len() < message_offset + message_size
+    {
+        return Err(IntrospectError::InvalidInstructionData);
+    }
+
+    let mut public_key = [0u8; PUBKEY_LEN];
+    public_key.copy_from_slice(&data[public_key_offset..public_key_offset + PUBKEY_LEN]);
+    let mut signature = [0u8; SIG_LEN];
+    signature.copy_from_slice(&data[signature_offset..signature_offset + SIG_LEN]);
+    Ok((
+        (public_key, signature),
+        &data[message_offset..message_offset + message_size],
+    ))
+}
+
+/// * Whether any entry of an Ed25519Program instruction is `signer` over exactly `message`
+/// * Other entries and instructions (e.g. another instruction's signatures) never match
+pub fn signs(ix: &Instruction, signer: &Pubkey, message: &[u8]) -> bool {
+    let exact = |signed: &[u8]| if signed == message { Ok(()) } else { Err(()) };
+    signature_entries::<32, ()>(ix, exact).is_ok_and(|entries| {
+        entries
+            .iter()
+            .any(|(public_key, _)| public_key == &signer.to_bytes())
+    })
+}
+
+/// * Whether an Ed25519Program instruction elsewhere in the transaction verifies exactly
+/// * `message` under `signer`, inspecting at most `max_instructions` Ed25519 instructions
+/// * Single-key claims (attestations, consent signatures) need nothing more than this
+pub fn find_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+    max_instructions: usize,
+) -> Result<bool, IntrospectError> {
+    position_ed25519_signature(instructions_sysvar, signer, message, max_instructions)
+        .map(|index| index.is_some())
+}
+
+/// * find_ed25519_signature, returning the index of the instruction carrying the signature
+/// * For callers that must keep that instruction out of another signature scan
+pub fn position_ed25519_signature(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+    max_instructions: usize,
+) -> Result<Option<usize>, IntrospectError> {
+    let mut ed25519_count = 0usize;
+    for idx in other_instruction_indices(instructions_sysvar)? {
+        let ix = load_instruction_at_checked(idx, instructions_sysvar)
+            .map_err(|_| IntrospectError::InvalidInstructionData)?;
+        if ix.program_id != ED25519_PROGRAM_ID {
+            continue;
+        }
+
+        if !ix.accounts.is_empty() {
+            return Err(IntrospectError::UnexpectedAccounts);
+        }
+        ed25519_count += 1;
+        if ed25519_count > max_instructions {
+            return Err(IntrospectError::TooManyInstructions);
+        }
+
+        if signs(&ix, signer, message) {
+            return Ok(Some(idx));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // * Helper: one-entry Ed25519Program instruction with every offset index set to `ix_index`
+    fn ed25519_ix(ix_index: u16, signer: &Pubkey, message: &[u8]) -> Instruction {
+        let mut data = vec![1u8, 0u8];
+        for value in [
+            16u16,
+            ix_index,
+            80,
+            ix_index,
+            112,
+            message.len() as u16,
+            ix_index,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(&[7u8; 64]);
+        data.extend_from_slice(signer.as_ref());
+        data.extend_from_slice(message);
+        Instruction {
+            program_id: ED25519_PROGRAM_ID,
+            accounts: vec![],
+            data,
+        }
+    }
+
+    #[test]
+    fn test_signs_exact_message_only() {
+        let signer = Pubkey::new_unique();
+        let message = b"veiled:test".to_vec();
+        let ix = ed25519_ix(u16::MAX, &signer, &message);
+
+        assert!(signs(&ix, &signer, &message));
+        assert!(!signs(&ix, &Pubkey::new_unique(), &message));
+        assert!(!signs(&ix, &signer, b"veiled:other"));
+        assert!(!signs(&ix, &signer, b"veiled:test!"));
+    }
+
+    #[test]
+    fn test_signs_rejects_data_in_other_instructions() {
+        let signer = Pubkey::new_unique();
+        let message = b"veiled:test".to_vec();
+
+        assert!(!signs(&ed25519_ix(0, &signer, &message), &signer, &message));
+    }
+
+    #[test]
+    fn test_signature_entries_bounds() {
+        let signer = Pubkey::new_unique();
+        let mut ix = ed25519_ix(u16::MAX, &signer, b"veiled:test");
+        let accept = |_: &[u8]| Ok::<(), ()>(());
+
+        let entries = signature_entries::<32, ()>(&ix, accept).unwrap();
+        assert_eq!(entries, vec![(signer.to_bytes(), [7u8; 64])]);
+
+        // * Message running past the end of the data
+        ix.data.truncate(ix.data.len() - 1);
+        assert_eq!(
+            signature_entries::<32, ()>(&ix, accept),
+            Err(Rejected::Layout(IntrospectError::InvalidInstructionData))
+        );
+    }
+
+    #[test]
+    fn test_signature_entries_skips_rejected_entries() {
+        let signer = Pubkey::new_unique();
+        let ix = ed25519_ix(u16::MAX, &signer, b"veiled:test");
+
+        // * The caller's error comes back when no entry passes its check
+        let reject = |_: &[u8]| Err::<(), &str>("wrong message");
+        assert_eq!(
+            signature_entries::<32, &str>(&ix, reject),
+            Err(Rejected::Message("wrong message"))
+        );
+
+        let mut bad_count = ix.clone();
+        bad_count.data[0] = (MAX_SIGNATURES_PER_INSTRUCTION + 1) as u8;
+        assert_eq!(
+            signature_entries::<32, ()>(&bad_count, |_| Ok(())),
+            Err(Rejected::Layout(IntrospectError::InvalidSignatureCount))
+        );
+    }
+}
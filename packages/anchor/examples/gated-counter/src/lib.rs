@@ -0,0 +1,173 @@
+// * Gated Counter example program
+// * Living integration docs for programs consuming Veiled via CPI
+// *
+// * `increment` only succeeds for a caller holding:
+// * 1. A live Veiled session (NullifierAccount): checked by CPI to validate_session_token,
+// *    which rejects expired sessions, sessions revoked by a global logout and nullifiers
+// *    banned from the session's domain
+// * 2. A PermissionGrant from that session's nullifier to this counter's app_id that includes
+// *    REQUIRED_PERMISSION: checked by CPI to try_check_permission, whose bool return value
+// *    lets the program pick its own error instead of aborting inside Veiled
+// *
+// * Veiled owns and validates both accounts; this program only checks they belong together.
+
+use anchor_lang::prelude::*;
+use veiled::cpi::accounts::{TryCheckPermission, ValidateSessionToken};
+use veiled::program::Veiled;
+use veiled::state::permission::{Permission, PermissionGrant};
+use veiled::NullifierAccount;
+
+declare_id!("D56xiLjyZS9eftxLjzWig1sx4xbyEogkXKdF7JiPdzCg");
+
+/// * Permission a session must have granted this app to increment
+pub const REQUIRED_PERMISSION: Permission = Permission::RevealWalletAddress;
+
+#[program]
+pub mod gated_counter {
+    use super::*;
+
+    /// * Create a counter gated on grants to `app_id`
+    pub fn initialize(ctx: Context<Initialize>, app_id: Pubkey) -> Result<()> {
+        let counter = &mut ctx.accounts.counter;
+        counter.app_id = app_id;
+        counter.count = 0;
+        counter.bump = ctx.bumps.counter;
+        Ok(())
+    }
+
+    /// * Increment the counter on behalf of a Veiled session holding REQUIRED_PERMISSION
+    pub fn increment(ctx: Context<Increment>) -> Result<()> {
+        let veiled_program = ctx.accounts.veiled_program.to_account_info();
+
+        // * 1. Session is unexpired, not globally logged out and not banned (aborts the
+        // *    transaction otherwise)
+        veiled::cpi::validate_session_token(CpiContext::new(
+            veiled_program.clone(),
+            ValidateSessionToken {
+                nullifier_account: ctx.accounts.session.to_account_info(),
+                logout_epoch: ctx.accounts.logout_epoch.to_account_info(),
+                nullifier_ban: ctx.accounts.nullifier_ban.to_account_info(),
+            },
+        ))?;
+
+        // * 2. The grant was issued by this session's nullifier to this counter's app
+        let session = &ctx.accounts.session;
+        let grant = &ctx.accounts.permission_grant;
+        require!(
+            grant.nullifier == session.nullifier,
+            GatedCounterError::GrantSessionMismatch
+        );
+        require_keys_eq!(
+            grant.app_id,
+            ctx.accounts.counter.app_id,
+            GatedCounterError::GrantAppMismatch
+        );
+
+        // * 3. The grant includes the permission and is neither revoked nor expired
+        let allowed = veiled::cpi::try_check_permission(
+            CpiContext::new(
+                veiled_program,
+                TryCheckPermission {
+                    permission_grant: grant.to_account_info(),
+                    event_authority: ctx.accounts.veiled_event_authority.to_account_info(),
+                    program: ctx.accounts.veiled_program.to_account_info(),
+                },
+            ),
+            REQUIRED_PERMISSION,
+        )?
+        .get();
+        require!(allowed, GatedCounterError::PermissionDenied);
+
+        let counter = &mut ctx.accounts.counter;
+        counter.count = counter.count.saturating_add(1);
+
+        emit!(CounterIncrementedEvent {
+            nullifier: session.nullifier,
+            count: counter.count,
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+#[instruction(app_id: Pubkey)]
+pub struct Initialize<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Counter::MAX_SIZE,
+        seeds = [b"counter", app_id.as_ref()],
+        bump
+    )]
+    pub counter: Account<'info, Counter>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct Increment<'info> {
+    #[account(
+        mut,
+        seeds = [b"counter", counter.app_id.as_ref()],
+        bump = counter.bump
+    )]
+    pub counter: Account<'info, Counter>,
+
+    // * Account<T> checks both are owned by the Veiled program
+    pub session: Account<'info, NullifierAccount>,
+    pub permission_grant: Account<'info, PermissionGrant>,
+
+    /// CHECK: * Veiled's LogoutEpoch PDA for the session's authority; validate_session_token
+    /// * checks its address, so it may not exist yet
+    pub logout_epoch: UncheckedAccount<'info>,
+
+    /// CHECK: * Veiled's NullifierBan PDA for the session's domain and nullifier;
+    /// * validate_session_token checks its address, and it only exists while banned
+    pub nullifier_ban: UncheckedAccount<'info>,
+
+    /// CHECK: * Veiled's event authority PDA; try_check_permission emits denials through it
+    #[account(seeds = [b"__event_authority"], bump, seeds::program = veiled_program.key())]
+    pub veiled_event_authority: UncheckedAccount<'info>,
+
+    pub veiled_program: Program<'info, Veiled>,
+}
+
+#[account]
+pub struct Counter {
+    /// * Veiled app ID grants must be issued to
+    pub app_id: Pubkey,
+
+    pub count: u64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl Counter {
+    pub const MAX_SIZE: usize =
+        32 + // app_id
+        8 +  // count
+        1;   // bump
+}
+
+#[event]
+pub struct CounterIncrementedEvent {
+    pub nullifier: [u8; 32],
+    pub count: u64,
+}
+
+#[error_code]
+pub enum GatedCounterError {
+    #[msg("Permission grant was not issued by this session")]
+    GrantSessionMismatch,
+
+    #[msg("Permission grant is for a different app")]
+    GrantAppMismatch,
+
+    #[msg("Session has not granted the required permission")]
+    PermissionDenied,
+}
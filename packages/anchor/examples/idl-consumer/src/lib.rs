@@ -0,0 +1,179 @@
+// * IDL consumer compile test
+// * Builds against Veiled the way an unrelated downstream program would: through
+// * `declare_program!(veiled)` and the committed IDL (../../idls/veiled.json), with no path
+// * dependency on programs/veiled. If a change to Veiled's instructions, accounts, events or
+// * types can't be consumed this way, this crate stops compiling.
+// *
+// * The IDL is refreshed by `cargo xtask bindings`; `--check` fails when it is stale, so the
+// * two together keep the IDL current and CPI-consumable.
+
+use anchor_lang::prelude::*;
+
+declare_id!("B7gCxgEyNbyTzQvYcz8QZm9ptcuFuXVoaDAkR5Q1XWLn");
+
+declare_program!(veiled);
+
+use veiled::accounts::{NullifierAccount, PermissionGrant};
+use veiled::cpi::accounts::{TryCheckPermission, ValidateSessionToken, VerifyAuth};
+use veiled::program::Veiled;
+use veiled::types::{Permission, SignedVerificationResult};
+
+#[program]
+pub mod idl_consumer {
+    use super::*;
+
+    /// * Register a Veiled session through CPI; the verifier's Ed25519 instruction stays in the
+    /// * outer transaction, where verify_auth finds it
+    /// * verify_auth returns the session it created, so there's nothing to fetch afterwards
+    pub fn register(
+        ctx: Context<Register>,
+        result: SignedVerificationResult,
+        nullifier: [u8; 32],
+        domain: [u8; 32],
+    ) -> Result<()> {
+        let accounts = &ctx.accounts;
+        let session = veiled::cpi::verify_auth(
+            CpiContext::new(
+                accounts.veiled_program.to_account_info(),
+                VerifyAuth {
+                    nullifier_account: accounts.nullifier_account.to_account_info(),
+                    proof_record: accounts.proof_record.to_account_info(),
+                    result_receipt: accounts.result_receipt.to_account_info(),
+                    authority: accounts.authority.to_account_info(),
+                    verifier: None,
+                    instructions_sysvar: accounts.instructions_sysvar.to_account_info(),
+                    circuit_registry: accounts.circuit_registry.to_account_info(),
+                    domain_config: accounts.domain_config.to_account_info(),
+                    nullifier_ban: accounts.nullifier_ban.to_account_info(),
+                    verifier_registry: accounts.verifier_registry.to_account_info(),
+                    secp256r1_verifier_registry: accounts
+                        .secp256r1_verifier_registry
+                        .to_account_info(),
+                    assurance_policy: accounts.assurance_policy.to_account_info(),
+                    payer_rate_limit: accounts.payer_rate_limit.to_account_info(),
+                    protocol_stats: accounts.protocol_stats.to_account_info(),
+                    system_program: accounts.system_program.to_account_info(),
+                    event_authority: accounts.veiled_event_authority.to_account_info(),
+                    program: accounts.veiled_program.to_account_info(),
+                },
+            ),
+            result,
+            nullifier,
+            domain,
+            0, // * The longest session the domain allows
+        )?
+        .get();
+        require_keys_eq!(
+            session.nullifier_account,
+            accounts.nullifier_account.key(),
+            IdlConsumerError::UnexpectedSession
+        );
+        msg!("Session registered at {}", session.created_at);
+
+        Ok(())
+    }
+
+    /// * Same gate as examples/gated-counter, using the IDL-generated account and CPI types
+    pub fn check_access(ctx: Context<CheckAccess>) -> Result<()> {
+        let veiled_program = ctx.accounts.veiled_program.to_account_info();
+
+        veiled::cpi::validate_session_token(CpiContext::new(
+            veiled_program.clone(),
+            ValidateSessionToken {
+                nullifier_account: ctx.accounts.session.to_account_info(),
+                logout_epoch: ctx.accounts.logout_epoch.to_account_info(),
+                nullifier_ban: ctx.accounts.nullifier_ban.to_account_info(),
+            },
+        ))?;
+
+        require!(
+            ctx.accounts.permission_grant.nullifier == ctx.accounts.session.nullifier,
+            IdlConsumerError::GrantSessionMismatch
+        );
+
+        let allowed = veiled::cpi::try_check_permission(
+            CpiContext::new(
+                veiled_program,
+                TryCheckPermission {
+                    permission_grant: ctx.accounts.permission_grant.to_account_info(),
+                    event_authority: ctx.accounts.veiled_event_authority.to_account_info(),
+                    program: ctx.accounts.veiled_program.to_account_info(),
+                },
+            ),
+            Permission::RevealWalletAddress,
+        )?
+        .get();
+        require!(allowed, IdlConsumerError::PermissionDenied);
+
+        Ok(())
+    }
+}
+
+#[derive(Accounts)]
+pub struct Register<'info> {
+    /// CHECK: * Veiled validates every account it is handed
+    #[account(mut)]
+    pub nullifier_account: UncheckedAccount<'info>,
+    /// CHECK: * See nullifier_account
+    #[account(mut)]
+    pub proof_record: UncheckedAccount<'info>,
+    /// CHECK: * See nullifier_account
+    #[account(mut)]
+    pub result_receipt: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    /// CHECK: * See nullifier_account
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    /// CHECK: * See nullifier_account
+    pub circuit_registry: UncheckedAccount<'info>,
+    /// CHECK: * See nullifier_account
+    pub domain_config: UncheckedAccount<'info>,
+    /// CHECK: * See nullifier_account
+    pub nullifier_ban: UncheckedAccount<'info>,
+    /// CHECK: * See nullifier_account
+    pub verifier_registry: UncheckedAccount<'info>,
+    /// CHECK: * See nullifier_account
+    pub secp256r1_verifier_registry: UncheckedAccount<'info>,
+    /// CHECK: * See nullifier_account
+    pub assurance_policy: UncheckedAccount<'info>,
+    /// CHECK: * See nullifier_account
+    #[account(mut)]
+    pub payer_rate_limit: UncheckedAccount<'info>,
+    /// CHECK: * See nullifier_account
+    #[account(mut)]
+    pub protocol_stats: UncheckedAccount<'info>,
+    /// CHECK: * See nullifier_account
+    pub veiled_event_authority: UncheckedAccount<'info>,
+    pub veiled_program: Program<'info, Veiled>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CheckAccess<'info> {
+    // * IDL-generated account types check the Veiled owner and discriminator
+    pub session: Account<'info, NullifierAccount>,
+    pub permission_grant: Account<'info, PermissionGrant>,
+
+    /// CHECK: * Veiled's LogoutEpoch PDA; validate_session_token checks its address
+    pub logout_epoch: UncheckedAccount<'info>,
+
+    /// CHECK: * Veiled's NullifierBan PDA; validate_session_token checks its address
+    pub nullifier_ban: UncheckedAccount<'info>,
+
+    /// CHECK: * Veiled's event authority PDA; Veiled checks its address
+    pub veiled_event_authority: UncheckedAccount<'info>,
+
+    pub veiled_program: Program<'info, Veiled>,
+}
+
+#[error_code]
+pub enum IdlConsumerError {
+    #[msg("Permission grant was not issued by this session")]
+    GrantSessionMismatch,
+
+    #[msg("Session has not granted the required permission")]
+    PermissionDenied,
+
+    #[msg("verify_auth registered a different session account")]
+    UnexpectedSession,
+}
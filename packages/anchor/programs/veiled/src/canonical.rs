@@ -0,0 +1,449 @@
+// * Canonical JSON / Display rendering for account and event types
+// *
+// * Off-chain only: compiled with `--features canonical-json` and never for SBF targets.
+// * The CLI, indexer and support tooling all render through these impls, so the same
+// * account always produces the same bytes:
+// * - Field names are the Rust / IDL field names (snake_case); object keys are sorted
+// * - Pubkeys are base58, fixed-size byte arrays are lowercase hex
+// * - Enums use their IDL variant names: "RevealWalletAddress", {"UnixTime":1700000000}
+// * - Display is the compact JSON form
+
+use anchor_lang::prelude::Pubkey;
+use serde::{Serialize, Serializer};
+use serde_json::{json, Map, Value};
+
+use crate::instructions::*;
+use crate::state::alias::Alias;
+use crate::state::attestation::Attestation;
+use crate::state::attestor::{Attestor, AttestorStatus};
+use crate::state::auditor::AuditorGrant;
+use crate::state::ban::NullifierBan;
+use crate::state::circuit::{CircuitEntry, CircuitRegistry};
+use crate::state::config::{AcceptedMint, AssurancePolicy, FeeSharePolicy, ProtocolConfig};
+use crate::state::credential::{VcCommitment, VcStatus};
+use crate::state::domain::{DomainConfig, DomainRole, NullifierStore};
+use crate::state::expiry::{ExpiryKind, ExpiryUnit};
+use crate::state::groth16::Groth16VerifyingKey;
+use crate::state::histogram::ExpiryHistogram;
+use crate::state::honk::HonkVerifyingKey;
+use crate::state::identity_root::{IdentityRoot, RootMember};
+use crate::state::migration::{
+    MigratedAccount, MigrationProgressEvent, MigrationState, MigrationStep,
+};
+use crate::state::notification::NotificationChannel;
+use crate::state::nullifier_link::NullifierLink;
+use crate::state::nullifier_shard::NullifierShard;
+use crate::state::nullifier_tree::NullifierTree;
+use crate::state::permission::{
+    AccessLogSummary, Permission, PermissionAccess, PermissionDenialReason, PermissionGrant,
+};
+use crate::state::personhood::{
+    ArchivedPersonhoodAttestation, PersonhoodAttestation, PersonhoodProviderRegistry,
+};
+use crate::state::points::{Points, PointsRoot};
+use crate::state::proof_record::ProofRecord;
+use crate::state::rate_limit::PayerRateLimit;
+use crate::state::recovery::GuardianSet;
+use crate::state::result_receipt::ResultReceipt;
+use crate::state::reveal_escrow::RevealEscrow;
+use crate::state::sas_bridge::SasBridge;
+use crate::state::session::{LogoutEpoch, SessionDelegate};
+use crate::state::stats::ProtocolStats;
+use crate::state::username::Username;
+use crate::state::verification_result::ClaimType;
+use crate::state::verification_session::{VerificationSession, VerificationStatus};
+use crate::state::verifier::{
+    Secp256r1VerifierRegistry, VerifierRegistry, VerifierRotation, VerifierWeight, VerifierWeights,
+};
+use crate::{AuthVerifiedEvent, NullifierAccount};
+
+/// * Canonical JSON value of a field or type
+pub trait Canonical {
+    fn canonical(&self) -> Value;
+}
+
+macro_rules! canonical_scalars {
+    ($($ty:ty),* $(,)?) => {$(
+        impl Canonical for $ty {
+            fn canonical(&self) -> Value {
+                json!(self)
+            }
+        }
+    )*};
+}
+
+canonical_scalars!(bool, u8, u16, u32, u64, i32, i64, String);
+
+impl Canonical for Pubkey {
+    fn canonical(&self) -> Value {
+        Value::String(self.to_string())
+    }
+}
+
+impl<const N: usize> Canonical for [u8; N] {
+    fn canonical(&self) -> Value {
+        Value::String(hex::encode(self))
+    }
+}
+
+impl<const N: usize> Canonical for [[u8; 64]; N] {
+    fn canonical(&self) -> Value {
+        Value::Array(self.iter().map(Canonical::canonical).collect())
+    }
+}
+
+impl<const N: usize> Canonical for [u32; N] {
+    fn canonical(&self) -> Value {
+        json!(self.as_slice())
+    }
+}
+
+impl<const N: usize> Canonical for [u64; N] {
+    fn canonical(&self) -> Value {
+        json!(self.as_slice())
+    }
+}
+
+impl<T: Canonical> Canonical for Option<T> {
+    fn canonical(&self) -> Value {
+        self.as_ref().map_or(Value::Null, Canonical::canonical)
+    }
+}
+
+impl<T: Canonical> Canonical for Vec<T> {
+    fn canonical(&self) -> Value {
+        Value::Array(self.iter().map(Canonical::canonical).collect())
+    }
+}
+
+// * Unit-only enums render as their variant name
+impl Canonical for Permission {
+    fn canonical(&self) -> Value {
+        Value::String(format!("{self:?}"))
+    }
+}
+
+impl Canonical for PermissionDenialReason {
+    fn canonical(&self) -> Value {
+        Value::String(format!("{self:?}"))
+    }
+}
+
+impl Canonical for VcStatus {
+    fn canonical(&self) -> Value {
+        Value::String(format!("{self:?}"))
+    }
+}
+
+impl Canonical for ClaimType {
+    fn canonical(&self) -> Value {
+        Value::String(format!("{self:?}"))
+    }
+}
+
+impl Canonical for AttestorStatus {
+    fn canonical(&self) -> Value {
+        Value::String(format!("{self:?}"))
+    }
+}
+
+impl Canonical for DomainRole {
+    fn canonical(&self) -> Value {
+        Value::String(format!("{self:?}"))
+    }
+}
+
+impl Canonical for NullifierStore {
+    fn canonical(&self) -> Value {
+        Value::String(format!("{self:?}"))
+    }
+}
+
+impl Canonical for MigratedAccount {
+    fn canonical(&self) -> Value {
+        Value::String(format!("{self:?}"))
+    }
+}
+
+impl Canonical for ExpiryUnit {
+    fn canonical(&self) -> Value {
+        Value::String(format!("{self:?}"))
+    }
+}
+
+impl Canonical for VerificationStatus {
+    fn canonical(&self) -> Value {
+        Value::String(format!("{self:?}"))
+    }
+}
+
+impl Canonical for ExpiryKind {
+    fn canonical(&self) -> Value {
+        match self {
+            ExpiryKind::UnixTime(timestamp) => json!({ "UnixTime": timestamp }),
+            ExpiryKind::Slot(slot) => json!({ "Slot": slot }),
+        }
+    }
+}
+
+/// * serde::Serialize and Display through Canonical
+macro_rules! canonical_render {
+    ($($ty:ident),* $(,)?) => {$(
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.canonical().serialize(serializer)
+            }
+        }
+
+        impl core::fmt::Display for $ty {
+            fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+                write!(f, "{}", self.canonical())
+            }
+        }
+    )*};
+}
+
+/// * Canonical object for each struct, keyed by field name
+/// * Fields are destructured without `..`, so a new field that isn't listed here fails to compile
+macro_rules! canonical_structs {
+    ($($ty:ident { $($field:ident),* $(,)? })*) => {$(
+        impl Canonical for $ty {
+            fn canonical(&self) -> Value {
+                let $ty { $($field),* } = self;
+                let mut object = Map::new();
+                $(object.insert(stringify!($field).to_string(), $field.canonical());)*
+                Value::Object(object)
+            }
+        }
+
+        canonical_render!($ty);
+    )*};
+}
+
+canonical_render!(
+    Permission,
+    PermissionDenialReason,
+    VcStatus,
+    ClaimType,
+    AttestorStatus,
+    DomainRole,
+    NullifierStore,
+    MigratedAccount,
+    ExpiryUnit,
+    VerificationStatus,
+    ExpiryKind
+);
+
+// * Accounts and nested types
+canonical_structs! {
+    AccessLogSummary { permission_grant, root, compacted_count, batch_count, last_accessed_at, bump }
+    AcceptedMint { mint, price_feed_id }
+    Alias { handle_hash, nullifier, domain, authority, claimed_at, bump }
+    ArchivedPersonhoodAttestation { attestation_hash, issued_at, archived_at, bump }
+    AssurancePolicy { max_result_age_secs, bump }
+    Attestation { nullifier, claim_type, domain, claim_data_hash, circuit_id, issuer, issued_at, expires_at, bump, revoked_at }
+    Attestor { issuer, name_hash, claim_mask, status, registered_at, bump }
+    AuditorGrant { nullifier, auditor, name_hash, granted_by, granted_at, expires_at, revoked, bump }
+    CircuitEntry { circuit_id, vk_hash, deprecated }
+    CircuitRegistry { circuits, bump }
+    DomainConfig { domain, owner, pinned_vk_hash, verifiers, bump, challenge_window_secs, rate_limit, assurance_tier, max_result_age_secs, operator, treasurer, paused, allowed_origins, min_session_secs, max_session_secs, attestor_claim_mask, idle_timeout_secs, nullifier_store, max_permissions, session_expiry_unit, default_session_secs }
+    ExpiryHistogram { domain, buckets, slot_grants, bump }
+    FeeSharePolicy { domain_share_bps, bump }
+    Groth16VerifyingKey { circuit_id, alpha_g1, beta_g2, gamma_g2, delta_g2, ic, bump }
+    GuardianSet { nullifier, guardians, threshold, nonce, updated_at, bump }
+    HonkVerifyingKey { circuit_id, log_circuit_size, public_inputs_size, pub_inputs_offset, commitments, written, bump }
+    IdentityRoot { owner, members, created_at, bump }
+    LogoutEpoch { identity, logged_out_at, bump }
+    MigrationState { version, target_version, steps, started_at, deadline, bump }
+    MigrationStep { account, required, total, migrated }
+    NotificationChannel { nullifier, app_id, authority, channel_commitment, encrypted_token, updated_at, bump }
+    NullifierAccount { nullifier, domain, domain_len, authority, created_at, last_active_at, idle_timeout_secs, expires_at, public_inputs_hash, circuit_id, proof_hash, verified_at, verifier, finalizes_at, payer, device_key, bump }
+    NullifierBan { domain, nullifier, banned_by, banned_at, bump }
+    NullifierLink { nullifier, successor, domain, proof_hash, authority, linked_at, bump }
+    NullifierShard { bits, domain, registered, shard, bump, padding }
+    NullifierTree { domain, merkle_tree, next_index, bump }
+    PayerRateLimit { payer, current_bucket, buckets, quota, bump, padding }
+    PermissionAccess { permission_grant, accessed_at, permission_used, metadata, payer }
+    PermissionGrant { nullifier, app_id, permissions, granted_at, expires_at, revoked, bump, permission_mask, successor }
+    PersonhoodAttestation { nullifier, provider, score, issued_at, expires_at, bump }
+    PersonhoodProviderRegistry { providers, bump }
+    Points { nullifier, balance, claimed_at, bump }
+    PointsRoot { root, leaf_count, published_at, bump }
+    ProofRecord { proof_hash, nullifier, domain, created_at, bump }
+    ProtocolConfig { authority, accepted_mints, fee_usd_micros, max_price_age_secs, max_price_conf_bps, bump }
+    ProtocolStats { total_verifications, total_grants, total_revocations, total_attestations, bump }
+    ResultReceipt { signature_hash, payer, consumed_at, retain_until, bump }
+    RevealEscrow { nullifier, app_id, authority, ciphertext, key_commitment, reveal_key, opened_at, reveals_at, released, bump }
+    RootMember { nullifier, domain, linkage_proof_hash, linked_at }
+    SasBridge { credential, schema, bump }
+    Secp256r1VerifierRegistry { verifiers, bump }
+    SessionDelegate { session, delegate, scope, created_at, expires_at, authority, bump }
+    Username { name, nullifier, domain, authority, claimed_at, transferred_at, bump }
+    VcCommitment { nullifier, issuer, vc_hash, status, issued_at, updated_at, bump }
+    VerificationSession { authority, nullifier, domain, circuit_id, proof_hash, proof_len, public_inputs_count, bytes_received, status, step, opened_at, bump }
+    VerifierRegistry { verifiers, threshold, bump, rotations, grace_period_secs, receipt_retention_secs }
+    VerifierRotation { old_key, new_key, rotated_at }
+    VerifierWeight { verifier, weight, last_heartbeat }
+    VerifierWeights { entries, bump }
+}
+
+// * Events
+canonical_structs! {
+    AccessLogAcknowledgedEvent { nullifier, auditor, permission_access, acknowledged_at }
+    AccessLogsCompactedEvent { permission_grant, access_logs, batch_root, summary_root, compacted_count, reward }
+    AcceptedMintUpdatedEvent { mint, price_feed_id, accepted }
+    AliasClaimedEvent { handle_hash, nullifier, domain, claimed_at }
+    AssuranceTierSetEvent { tier, max_result_age_secs }
+    AttestationMirroredEvent { nullifier, claim_type, domain, sas_attestation, expires_at }
+    AttestationRenewedEvent { nullifier, claim_type, domain, claim_data_hash, issuer, expires_at }
+    AttestationRevokedEvent { nullifier, claim_type, domain, issuer, revoked_at }
+    AttestationStoredEvent { nullifier, claim_type, domain, claim_data_hash, issuer, expires_at }
+    AttestorRegisteredEvent { issuer, name_hash, claim_mask }
+    AttestorStatusSetEvent { issuer, status }
+    AuditorGrantedEvent { nullifier, auditor, name_hash, granted_at, expires_at }
+    AuditorRevokedEvent { nullifier, auditor, revoked_at }
+    AuthVerifiedEvent { nullifier, domain, proof_hash, expires_at }
+    ChallengeWindowSetEvent { domain, challenge_window_secs }
+    CircuitDeprecatedEvent { circuit_id, vk_hash }
+    CircuitRegisteredEvent { circuit_id, vk_hash }
+    CompressedNullifierRegisteredEvent { nullifier, domain, proof_hash, leaf_index, low_index, next_index, next_value, registered_at }
+    DomainAssuranceTierSetEvent { domain, assurance_tier }
+    DomainAttestorClaimsSetEvent { domain, claim_mask }
+    DomainConfigInitializedEvent { domain, owner }
+    DomainExpiryUnitSetEvent { domain, session_expiry_unit }
+    DomainFeePaidEvent { payer, domain, mint, fee_usd_micros, amount, domain_amount, price, exponent, publish_time }
+    DomainFeeShareSetEvent { domain_share_bps }
+    DomainFeeVaultAtaCreatedEvent { domain, mint, domain_fee_vault_ata }
+    DomainFeesClaimedEvent { domain, mint, destination, authority, amount }
+    DomainIdleTimeoutSetEvent { domain, idle_timeout_secs }
+    DomainMaxResultAgeSetEvent { domain, max_result_age_secs }
+    DomainNullifierStoreSetEvent { domain, nullifier_store }
+    DomainOriginsSetEvent { domain, origins }
+    DomainPausedSetEvent { domain, paused, authority }
+    DomainRateLimitSetEvent { domain, rate_limit }
+    DomainRegisteredEvent { domain, owner, verifier, default_session_secs, max_result_age_secs, max_permissions }
+    DomainRoleSetEvent { domain, role, key }
+    DomainSessionBoundsSetEvent { domain, min_session_secs, max_session_secs }
+    DomainVerifiersSetEvent { domain, verifiers }
+    ExpiredNullifierClosedEvent { nullifier, payer, cranker, reward, closed_at }
+    FeePaidEvent { payer, mint, fee_usd_micros, amount, price, exponent, publish_time }
+    GlobalLogoutEvent { identity, logged_out_at }
+    Groth16VkRegisteredEvent { circuit_id, vk_hash, public_inputs }
+    GuardiansSetEvent { nullifier, guardians, threshold, nonce, updated_at }
+    HonkVkRegisteredEvent { circuit_id, vk_hash, log_circuit_size, public_inputs_size }
+    IdentityRecoveredEvent { nullifier, new_nullifier, domain, proof_hash, new_authority, sessions, grants, approvals, recovered_at }
+    IdentityRootCreatedEvent { root, owner, created_at }
+    IdentityRootLinkedEvent { root, nullifier, domain, linkage_proof_hash, linked_at }
+    IdentityRootUnlinkedEvent { root, nullifier, unlinked_at }
+    MigrationBegunEvent { version, target_version, steps, deadline }
+    MigrationCompletedEvent { version, steps, forced }
+    MigrationProgressEvent { target_version, account, migrated, total }
+    NotificationChannelClosedEvent { nullifier, app_id, closed_at }
+    NotificationChannelSetEvent { nullifier, app_id, channel_commitment, updated_at }
+    NullifierBannedEvent { domain, nullifier, banned_at }
+    NullifierMigratedEvent { nullifier, successor, domain, proof_hash, grants, migrated_at }
+    NullifierTreeInitializedEvent { domain, merkle_tree, max_depth, max_buffer_size }
+    NullifierUnbannedEvent { domain, nullifier, unbanned_at }
+    PermissionAccessedEvent { nullifier, app_id, permission, permission_mask, accessed_at }
+    PermissionDeniedEvent { nullifier, app_id, permission, permission_mask, reason, checked_at }
+    PermissionGrantedEvent { nullifier, app_id, permissions, permission_mask, granted_at, expires_at }
+    PermissionRevokedEvent { nullifier, app_id, revoked_at }
+    PersonhoodAttestationArchivedEvent { nullifier, provider, attestation_hash, archived_at }
+    PersonhoodAttestationRestoredEvent { nullifier, provider, score, issued_at, expires_at }
+    PersonhoodAttestedEvent { nullifier, provider, score, issued_at, expires_at }
+    PersonhoodProviderAddedEvent { provider }
+    PersonhoodProviderRemovedEvent { provider }
+    PointsRootPublishedEvent { root, leaf_count, published_at }
+    PricingUpdatedEvent { fee_usd_micros, max_price_age_secs, max_price_conf_bps }
+    ReceiptRetentionSetEvent { receipt_retention_secs }
+    RelayerQuotaSetEvent { relayer, quota }
+    RentToppedUpEvent { account, payer, lamports }
+    ResultReceiptClosedEvent { signature_hash, payer, closed_at }
+    RevealEscrowCancelledEvent { nullifier, app_id, released, cancelled_at }
+    RevealEscrowOpenedEvent { nullifier, app_id, key_commitment, reveals_at }
+    RevealEscrowReleasedEvent { nullifier, app_id, reveal_key, released_at }
+    SasBridgeSetEvent { credential, schema }
+    Secp256r1VerifierAddedEvent { verifier }
+    Secp256r1VerifierRemovedEvent { verifier }
+    SessionClosedEvent { nullifier, domain, authority, closed_at }
+    SessionDelegateRevokedEvent { session, delegate, revoked_at }
+    SessionDelegatedEvent { nullifier, session, delegate, scope, expires_at }
+    SessionRenewedEvent { nullifier, domain, proof_hash, expires_at }
+    ShardedNullifierRegisteredEvent { nullifier, domain, proof_hash, shard, registered_at }
+    TreasuryAtaCreatedEvent { mint, treasury_ata }
+    TreasurySweptEvent { mint, destination, amount }
+    UsagePointsClaimedEvent { nullifier, amount, root, claimed_at }
+    UsernameClaimedEvent { name, nullifier, domain, claimed_at }
+    UsernameReleasedEvent { name, nullifier, released_at }
+    UsernameTransferredEvent { name, from_nullifier, to_nullifier, transferred_at }
+    VcCommitmentIssuedEvent { nullifier, issuer, vc_hash, issued_at }
+    VcStatusChangedEvent { nullifier, issuer, vc_hash, status, updated_at }
+    VerificationChallengedEvent { nullifier, domain, proof_hash, challenger, challenged_at }
+    VerificationSessionOpenedEvent { nullifier, domain, circuit_id, authority, proof_len, steps }
+    VerificationSessionResolvedEvent { nullifier, authority, verified, steps }
+    VerifierAddedEvent { verifier }
+    VerifierGracePeriodSetEvent { grace_period_secs }
+    VerifierKeyRotatedEvent { old_key, new_key, rotated_at, grace_ends_at }
+    VerifierRemovedEvent { verifier }
+    VerifierThresholdSetEvent { threshold }
+    VerifierWeightSetEvent { verifier, weight }
+    VkHashPinnedEvent { domain, vk_hash }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_canonical_nullifier_account() {
+        let mut domain = [0u8; 32];
+        domain[..11].copy_from_slice(b"example.com");
+        let account = NullifierAccount {
+            nullifier: [0xab; 32],
+            domain,
+            domain_len: 11,
+            authority: Pubkey::new_from_array([0u8; 32]),
+            created_at: 1_700_000_000,
+            last_active_at: 1_700_000_000,
+            idle_timeout_secs: 0,
+            expires_at: ExpiryKind::UnixTime(1_702_592_000),
+            public_inputs_hash: [0u8; 32],
+            circuit_id: 1,
+            proof_hash: [0u8; 32],
+            verified_at: 1_700_000_000,
+            verifier: Pubkey::new_from_array([0u8; 32]),
+            finalizes_at: 0,
+            payer: Pubkey::new_from_array([0u8; 32]),
+            device_key: Pubkey::new_from_array([0u8; 32]),
+            bump: 255,
+        };
+
+        let expected = format!(
+            "{{\"authority\":\"11111111111111111111111111111111\",\"bump\":255,\"circuit_id\":1,\
+             \"created_at\":1700000000,\"device_key\":\"11111111111111111111111111111111\",\
+             \"domain\":\"{}\",\"domain_len\":11,\
+             \"expires_at\":{{\"UnixTime\":1702592000}},\"finalizes_at\":0,\
+             \"idle_timeout_secs\":0,\"last_active_at\":1700000000,\
+             \"nullifier\":\"{}\",\"payer\":\"11111111111111111111111111111111\",\
+             \"proof_hash\":\"{}\",\"public_inputs_hash\":\"{}\",\
+             \"verified_at\":1700000000,\
+             \"verifier\":\"11111111111111111111111111111111\"}}",
+            hex::encode(b"example.com") + &"00".repeat(21),
+            "ab".repeat(32),
+            "00".repeat(32),
+            "00".repeat(32)
+        );
+        assert_eq!(account.domain_str(), "example.com");
+        assert_eq!(account.to_string(), expected);
+        assert_eq!(serde_json::to_string(&account).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_canonical_enums() {
+        assert_eq!(
+            Permission::RevealWalletAddress.to_string(),
+            "\"RevealWalletAddress\""
+        );
+        assert_eq!(ExpiryKind::Slot(42).to_string(), "{\"Slot\":42}");
+    }
+}
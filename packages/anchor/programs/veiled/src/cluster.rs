@@ -0,0 +1,22 @@
+// * Cluster binding
+// *
+// * V6 verification results sign the cluster they were issued for, so a result signed for
+// * devnet can't be replayed on mainnet against the same program ID (and vice versa).
+// * Programs can't read the genesis hash on-chain, so the cluster is fixed at build time:
+// * devnet builds (`--features devnet`) bind to devnet, every other build to mainnet-beta.
+// *
+// * Cluster IDs are CAIP-2 Solana chain references: the first 32 base58 characters of the
+// * cluster's genesis hash.
+
+/// * Mainnet-beta (genesis 5eykt4UsFv8P8NJdTREpY1vzqKqZKvdpKuc147dw2N9d)
+pub const MAINNET_BETA: [u8; 32] = *b"5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp";
+
+/// * Devnet (genesis EtWTRABZaYq6iMfeYKouRu166VU2xqa1wcaWoxPkrZBG)
+pub const DEVNET: [u8; 32] = *b"EtWTRABZaYq6iMfeYKouRu166VU2xqa1";
+
+/// * Cluster this build accepts V6 results for
+pub const CLUSTER_ID: [u8; 32] = if cfg!(feature = "devnet") {
+    DEVNET
+} else {
+    MAINNET_BETA
+};
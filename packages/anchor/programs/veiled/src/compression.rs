@@ -0,0 +1,138 @@
+// * SPL Account Compression bridge
+// *
+// * Builds the concurrent Merkle tree instructions the compressed nullifier store invokes
+// * (init_nullifier_tree, verify_auth_compressed). Encoded by hand rather than through the
+// * spl-account-compression crate, which pins Anchor and Solana versions that conflict with our
+// * git Anchor dependency (same reason as sas.rs and oracle.rs).
+// *
+// * Every instruction takes: merkle_tree (w) || authority (s) || noop program
+// * and Anchor-encoded data: sha256("global:<name>")[..8] || args (Borsh)
+// *   init_empty_merkle_tree: max_depth (u32) || max_buffer_size (u32)
+// *   append:                 leaf (32)
+// *   replace_leaf:           root (32) || previous_leaf (32) || new_leaf (32) || index (u32)
+// *                           followed by the proof nodes as readonly remaining accounts
+// *
+// * The tree account is allocated by the client (owned by the compression program) before
+// * init_empty_merkle_tree; its size depends on max_depth, max_buffer_size and canopy.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+
+// * SPL Account Compression program id
+pub const COMPRESSION_PROGRAM_ID: Pubkey =
+    anchor_lang::pubkey!("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+// * SPL Noop program id (the compression program logs changelogs through it)
+pub const NOOP_PROGRAM_ID: Pubkey =
+    anchor_lang::pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+
+// * sha256("global:init_empty_merkle_tree")[..8]
+const INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR: [u8; 8] = [191, 11, 119, 7, 180, 107, 220, 110];
+
+// * sha256("global:append")[..8]
+const APPEND_DISCRIMINATOR: [u8; 8] = [149, 120, 18, 222, 236, 225, 88, 203];
+
+// * sha256("global:replace_leaf")[..8]
+const REPLACE_LEAF_DISCRIMINATOR: [u8; 8] = [204, 165, 76, 100, 73, 147, 0, 128];
+
+/// * Initialize the client-allocated `merkle_tree` with `authority` as its tree authority
+pub fn init_empty_merkle_tree(
+    merkle_tree: &Pubkey,
+    authority: &Pubkey,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Instruction {
+    let mut data = Vec::with_capacity(8 + 4 + 4);
+    data.extend_from_slice(&INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR);
+    data.extend_from_slice(&max_depth.to_le_bytes());
+    data.extend_from_slice(&max_buffer_size.to_le_bytes());
+    tree_instruction(merkle_tree, authority, data, vec![])
+}
+
+/// * Append `leaf` at the tree's next index
+pub fn append(merkle_tree: &Pubkey, authority: &Pubkey, leaf: &[u8; 32]) -> Instruction {
+    let mut data = Vec::with_capacity(8 + 32);
+    data.extend_from_slice(&APPEND_DISCRIMINATOR);
+    data.extend_from_slice(leaf);
+    tree_instruction(merkle_tree, authority, data, vec![])
+}
+
+/// * Replace `previous_leaf` at `index` with `new_leaf`; the compression program checks
+/// * `proof` (sibling nodes from the leaf up) against `root` or a recent changelog entry
+pub fn replace_leaf(
+    merkle_tree: &Pubkey,
+    authority: &Pubkey,
+    root: &[u8; 32],
+    previous_leaf: &[u8; 32],
+    new_leaf: &[u8; 32],
+    index: u32,
+    proof: &[AccountInfo],
+) -> Instruction {
+    let mut data = Vec::with_capacity(8 + 32 * 3 + 4);
+    data.extend_from_slice(&REPLACE_LEAF_DISCRIMINATOR);
+    data.extend_from_slice(root);
+    data.extend_from_slice(previous_leaf);
+    data.extend_from_slice(new_leaf);
+    data.extend_from_slice(&index.to_le_bytes());
+    let proof = proof
+        .iter()
+        .map(|node| AccountMeta::new_readonly(node.key(), false))
+        .collect();
+    tree_instruction(merkle_tree, authority, data, proof)
+}
+
+fn tree_instruction(
+    merkle_tree: &Pubkey,
+    authority: &Pubkey,
+    data: Vec<u8>,
+    proof: Vec<AccountMeta>,
+) -> Instruction {
+    let mut accounts = vec![
+        AccountMeta::new(*merkle_tree, false),
+        AccountMeta::new_readonly(*authority, true),
+        AccountMeta::new_readonly(NOOP_PROGRAM_ID, false),
+    ];
+    accounts.extend(proof);
+    Instruction {
+        program_id: COMPRESSION_PROGRAM_ID,
+        accounts,
+        data,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_sha256_hasher::hashv;
+
+    #[test]
+    fn test_discriminators() {
+        for (name, discriminator) in [
+            (
+                "init_empty_merkle_tree",
+                INIT_EMPTY_MERKLE_TREE_DISCRIMINATOR,
+            ),
+            ("append", APPEND_DISCRIMINATOR),
+            ("replace_leaf", REPLACE_LEAF_DISCRIMINATOR),
+        ] {
+            let preimage = format!("global:{name}");
+            assert_eq!(hashv(&[preimage.as_bytes()]).to_bytes()[..8], discriminator);
+        }
+    }
+
+    #[test]
+    fn test_replace_leaf_encoding() {
+        let tree = Pubkey::new_unique();
+        let authority = Pubkey::new_unique();
+        let ix = replace_leaf(&tree, &authority, &[1; 32], &[2; 32], &[3; 32], 7, &[]);
+
+        assert_eq!(ix.program_id, COMPRESSION_PROGRAM_ID);
+        assert_eq!(ix.data.len(), 8 + 32 * 3 + 4);
+        assert_eq!(&ix.data[8..40], &[1; 32]);
+        assert_eq!(&ix.data[104..], &7u32.to_le_bytes());
+        assert_eq!(ix.accounts.len(), 3);
+        assert!(ix.accounts[0].is_writable && !ix.accounts[0].is_signer);
+        assert!(ix.accounts[1].is_signer);
+        assert_eq!(ix.accounts[2].pubkey, NOOP_PROGRAM_ID);
+    }
+}
@@ -0,0 +1,28 @@
+// * CPI depth / reentrancy guard
+// *
+// * State-mutating instructions that will call out to other programs (observer hooks on grant
+// * and revoke, transfer hooks on fee payments) must not be re-entered while their callback
+// * runs. The runtime already rejects indirect reentrancy (A -> B -> A), but that is a
+// * runtime policy rather than something this program checks, so the guarded instructions
+// * also bound their own invocation depth.
+// *
+// * A guarded instruction runs either at the top level of the transaction or through a single
+// * CPI from an integrating program. Any callback it makes runs one level deeper, so a callback
+// * that calls back into a guarded instruction is always past MAX_STACK_HEIGHT.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, TRANSACTION_LEVEL_STACK_HEIGHT};
+
+use crate::errors::VeiledError;
+
+/// * Deepest invocation a guarded instruction accepts: top level, or one CPI below it
+pub const MAX_STACK_HEIGHT: usize = TRANSACTION_LEVEL_STACK_HEIGHT + 1;
+
+/// * Fail when invoked deeper than MAX_STACK_HEIGHT (i.e. from inside another CPI chain)
+pub fn require_not_reentrant() -> Result<()> {
+    require!(
+        get_stack_height() <= MAX_STACK_HEIGHT,
+        VeiledError::ReentrancyDetected
+    );
+    Ok(())
+}
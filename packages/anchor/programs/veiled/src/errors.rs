@@ -16,6 +16,9 @@ pub enum VeiledError {
     #[msg("Domain string exceeds maximum length of 255 characters")]
     DomainTooLong,
 
+    #[msg("The sandbox domain is only available on devnet builds")]
+    SandboxUnavailable,
+
     #[msg("Invalid public inputs")]
     InvalidPublicInputs,
 
@@ -41,6 +44,39 @@ pub enum VeiledError {
     #[msg("Authority public key mismatch")]
     AuthorityMismatch,
 
+    #[msg("Nullifier mismatch - result was signed for a different nullifier")]
+    NullifierMismatch,
+
+    #[msg("Domain mismatch - result was signed for a different domain")]
+    DomainMismatch,
+
+    #[msg("Unsupported verification result version")]
+    UnsupportedResultVersion,
+
+    #[msg("Public inputs hash mismatch - result was signed for different public inputs")]
+    PublicInputsMismatch,
+
+    #[msg("Circuit mismatch - result was signed for a different circuit")]
+    CircuitMismatch,
+
+    #[msg("Verification key hash mismatch")]
+    VkHashMismatch,
+
+    #[msg("Verification result was signed for a different cluster")]
+    ClusterMismatch,
+
+    #[msg("Verification result was signed for a different Veiled instance")]
+    InstanceMismatch,
+
+    #[msg("Proof has already been used to register a nullifier")]
+    ProofAlreadyUsed,
+
+    #[msg("Verification batch must be 1-4 results, each with its nullifier, proof record, domain config and result receipt accounts")]
+    InvalidVerificationBatch,
+
+    #[msg("Verification result fields must extend the message in order")]
+    InvalidResultLayout,
+
     #[msg("Expected Ed25519 program")]
     BadEd25519Program,
 
@@ -62,4 +98,457 @@ pub enum VeiledError {
 
     #[msg("Too many permissions requested")]
     TooManyPermissions,
+
+    #[msg("Expiry duration must be positive and within range")]
+    InvalidExpiry,
+
+    // * Notification channel errors
+    #[msg("Encrypted notification token must be 1-256 bytes")]
+    InvalidNotificationToken,
+
+    #[msg("Signer does not own this notification channel")]
+    NotChannelAuthority,
+
+    // * Reveal escrow errors
+    #[msg("Reveal delay must be positive and at most 90 days")]
+    InvalidRevealDelay,
+
+    #[msg("Escrowed ciphertext must be 1-128 bytes")]
+    InvalidRevealCiphertext,
+
+    #[msg("Signer does not own this reveal escrow")]
+    NotEscrowAuthority,
+
+    #[msg("Reveal delay has not passed yet")]
+    RevealNotReady,
+
+    #[msg("Reveal key does not match the escrow's commitment")]
+    RevealKeyMismatch,
+
+    #[msg("Reveal escrow has already been released")]
+    RevealAlreadyReleased,
+
+    #[msg("Reveal delay is over - the escrow can no longer be cancelled")]
+    RevealWindowClosed,
+
+    // * Verifiable credential errors
+    #[msg("Signer is not the credential's issuer")]
+    NotVcIssuer,
+
+    #[msg("Credential has been revoked; its status can no longer change")]
+    VcRevoked,
+
+    // * Auditor errors
+    #[msg("Auditor grant has been revoked")]
+    AuditorRevoked,
+
+    #[msg("Auditor grant has expired")]
+    AuditorExpired,
+
+    #[msg("Access log does not belong to this permission grant")]
+    AccessLogMismatch,
+
+    // * Access log compaction errors
+    #[msg("Compaction batch must be 1-16 (access log, payer) pairs")]
+    InvalidCompactionBatch,
+
+    #[msg("Access log is too recent to compact")]
+    AccessLogTooRecent,
+
+    #[msg("Refund account does not match the access log payer")]
+    AccessLogPayerMismatch,
+
+    // * Config and treasury errors
+    #[msg("Signer is not the protocol authority")]
+    Unauthorized,
+
+    #[msg("Mint is not accepted for payments")]
+    MintNotAccepted,
+
+    #[msg("Mint is already accepted")]
+    MintAlreadyAccepted,
+
+    #[msg("Too many accepted mints")]
+    TooManyAcceptedMints,
+
+    // * Circuit registry errors
+    #[msg("Circuit is not registered")]
+    CircuitNotRegistered,
+
+    #[msg("Circuit has been deprecated")]
+    CircuitDeprecated,
+
+    #[msg("Circuit ID is already registered")]
+    CircuitAlreadyRegistered,
+
+    #[msg("Too many registered circuits")]
+    TooManyCircuits,
+
+    // * Groth16 errors
+    #[msg("Groth16 verifying key needs 2 to 4 public inputs and the circuit's vk_hash")]
+    InvalidGroth16Vk,
+
+    #[msg("Groth16 proof failed to verify")]
+    InvalidGroth16Proof,
+
+    // * Verifier registry errors
+    #[msg("Verification result was not signed by a registered verifier")]
+    UnregisteredVerifier,
+
+    #[msg("Verifier is already registered")]
+    VerifierAlreadyRegistered,
+
+    #[msg("Verifier is not registered")]
+    VerifierNotRegistered,
+
+    #[msg("Too many registered verifiers")]
+    TooManyVerifiers,
+
+    #[msg("Threshold must be between 1 and the number of registered verifiers")]
+    InvalidVerifierThreshold,
+
+    #[msg("Not enough registered verifiers co-signed the result")]
+    QuorumNotMet,
+
+    #[msg("Too many Ed25519 instructions in the transaction")]
+    TooManyEd25519Instructions,
+
+    #[msg("Too many Secp256r1 instructions in the transaction")]
+    TooManySecp256r1Instructions,
+
+    #[msg("Bad Secp256r1 accounts")]
+    BadSecp256r1Accounts,
+
+    #[msg("Secp256r1 verifier key must be a 33-byte compressed P-256 point")]
+    InvalidSecp256r1Key,
+
+    #[msg("Too many verifier key rotations inside the grace period")]
+    TooManyRotations,
+
+    #[msg("Grace period must be between 0 and 24 hours")]
+    InvalidGracePeriod,
+
+    // * Domain config errors
+    #[msg("Domain must be 1-32 lowercase ASCII letters, digits, '-' or '.', null-padded")]
+    InvalidDomain,
+
+    #[msg("Signer is not the domain owner")]
+    NotDomainOwner,
+
+    #[msg("Domain pins a verification key - result must carry vk_hash (V5+)")]
+    VkHashRequired,
+
+    #[msg("Challenge window must be between 0 and 7 days")]
+    InvalidChallengeWindow,
+
+    #[msg("Domain rate limit must be between 0 and 600 verifications per minute")]
+    InvalidRateLimit,
+
+    #[msg("Signer is neither the domain owner nor its operator")]
+    NotDomainOperator,
+
+    #[msg("Signer is neither the domain owner nor its treasurer")]
+    NotDomainTreasurer,
+
+    #[msg("Domain owner can't be set to the default key")]
+    InvalidDomainRoleKey,
+
+    #[msg("Domain is paused")]
+    DomainPaused,
+
+    #[msg("Too many registered origins")]
+    TooManyOrigins,
+
+    #[msg("Origin is not registered for the domain")]
+    OriginNotAllowed,
+
+    // * Optimistic verification errors
+    #[msg("Verification has already finalized and can no longer be challenged")]
+    ChallengeWindowClosed,
+
+    #[msg("Challenge evidence does not dispute this verification")]
+    InvalidChallenge,
+
+    // * Oracle pricing errors
+    #[msg("Fee is not configured")]
+    FeeNotConfigured,
+
+    #[msg("Price update is for a different feed than the mint's configured feed")]
+    OracleFeedMismatch,
+
+    #[msg("Price update account is invalid, unverified, or non-positive")]
+    InvalidOraclePrice,
+
+    #[msg("Oracle price is too old")]
+    OraclePriceStale,
+
+    #[msg("Oracle price confidence interval is too wide")]
+    OraclePriceUncertain,
+
+    #[msg("Price quote overflowed")]
+    PriceQuoteOverflow,
+
+    #[msg("Quoted fee exceeds the payer's maximum amount")]
+    FeeExceedsMaxAmount,
+
+    #[msg("Domain fee share must be at most 50%")]
+    InvalidFeeShare,
+
+    // * Session errors
+    #[msg("Session has expired")]
+    SessionExpired,
+
+    #[msg("Session was revoked by a global logout")]
+    SessionRevoked,
+
+    #[msg("Session is pending until its challenge window ends")]
+    SessionPending,
+
+    // * Rate limit errors
+    #[msg("Fee payer exceeded its verification rate limit - retry once the window slides")]
+    RateLimited,
+
+    // * Assurance policy errors
+    #[msg("Assurance tier is not defined (tiers are added in order, up to 8)")]
+    InvalidAssuranceTier,
+
+    #[msg("Maximum result age must be at most 1 hour (and non-zero for a tier)")]
+    InvalidResultAge,
+
+    #[msg("Verification result timestamp is in the future")]
+    TimestampInFuture,
+
+    // * Governance points errors
+    #[msg("Points root must cover at least one leaf")]
+    InvalidPointsRoot,
+
+    #[msg("Merkle proof doesn't match the published points root")]
+    InvalidPointsProof,
+
+    // * Personhood errors
+    #[msg("Personhood provider is already registered")]
+    PersonhoodProviderAlreadyRegistered,
+
+    #[msg("Personhood provider is not registered")]
+    PersonhoodProviderNotRegistered,
+
+    #[msg("Too many personhood providers")]
+    TooManyPersonhoodProviders,
+
+    #[msg("Personhood score must be at most 100")]
+    InvalidPersonhoodScore,
+
+    #[msg("Personhood attestation is expired, future-dated, or older than the attached one")]
+    StalePersonhoodAttestation,
+
+    #[msg("No Ed25519 instruction carries the provider's signature over the attestation")]
+    MissingPersonhoodSignature,
+
+    #[msg("Attestation fields don't match the archived hash")]
+    AttestationPreimageMismatch,
+
+    // * Migration errors
+    #[msg("A migration is already in progress")]
+    MigrationInProgress,
+
+    #[msg("No migration is in progress")]
+    NoMigrationInProgress,
+
+    #[msg(
+        "Migration needs a newer version, at most 4 distinct unstarted steps and a future deadline"
+    )]
+    InvalidMigrationPlan,
+
+    #[msg("Required migration steps are unfinished and the deadline hasn't passed")]
+    MigrationIncomplete,
+
+    #[msg("The open migration has no step for this account type")]
+    UnknownMigrationStep,
+
+    #[msg("Account version is not accepted by the current migration state")]
+    UnsupportedAccountVersion,
+
+    // * Verifier selection errors
+    #[msg("Verifier weight must be at most 16")]
+    InvalidVerifierWeight,
+
+    #[msg("Verifier has no selection weight")]
+    VerifierNotWeighted,
+
+    #[msg("No weighted, live verifier is available")]
+    NoVerifierAvailable,
+
+    // * User consent errors
+    #[msg("No Ed25519 instruction carries the user's consent signature")]
+    MissingUserConsent,
+
+    #[msg("User consent has expired")]
+    UserConsentExpired,
+
+    // * Session expiry errors
+    #[msg("Session bounds must be 0 or within the protocol's, with min <= max")]
+    InvalidSessionBounds,
+
+    #[msg("Requested session lifetime is outside the domain's bounds")]
+    SessionExpiryOutOfBounds,
+
+    // * Result encoding errors
+    #[msg("Verification result length doesn't match its version's")]
+    ResultLengthMismatch,
+
+    #[msg("Boolean fields must be encoded as 0 or 1")]
+    NonCanonicalBool,
+
+    // * Result receipt errors
+    #[msg("Verification result signature has already been consumed")]
+    SignatureAlreadyUsed,
+
+    #[msg("Receipt retention must be between 1 hour and 30 days")]
+    InvalidReceiptRetention,
+
+    #[msg("Result receipt is still inside its retention window")]
+    ReceiptRetained,
+
+    // * Claim type errors
+    #[msg("Unknown verification result claim type")]
+    UnknownClaimType,
+
+    #[msg("Signed message claim type doesn't match result")]
+    ClaimTypeMismatch,
+
+    #[msg("Only Auth claims open sessions")]
+    ClaimNotSession,
+
+    // * Attestation errors
+    #[msg("Auth claims open sessions rather than attestations")]
+    ClaimNotAttestation,
+
+    #[msg("Attestation lifetime must be at most a year")]
+    AttestationExpiryOutOfBounds,
+
+    #[msg("Attestation for this claim is still active; renew it instead")]
+    AttestationActive,
+
+    #[msg("Attestation has expired")]
+    AttestationExpired,
+
+    #[msg("Renewal result is for a different claim than the attestation")]
+    AttestationClaimMismatch,
+
+    #[msg("Attestation has been revoked by its issuer")]
+    AttestationRevoked,
+
+    #[msg("Only the attestation's issuer can revoke it")]
+    NotAttestationIssuer,
+
+    // * Attestor errors
+    #[msg("Claim type mask has bits for Auth or unknown claim types")]
+    InvalidClaimMask,
+
+    #[msg("Domain only accepts this claim type from a registered attestor vetted for it")]
+    UntrustedAttestor,
+
+    #[msg("Attestor is suspended")]
+    AttestorSuspended,
+
+    #[msg("Account doesn't match the SAS bridge's credential or schema")]
+    SasBridgeMismatch,
+
+    // * Message tag errors
+    #[msg("Signed message doesn't start with the Veiled protocol tag")]
+    MessageTagMismatch,
+    #[msg("Result envelope is untagged - byte envelopes must be V9")]
+    UntaggedResult,
+
+    // * Nullifier cleanup errors
+    #[msg("Session hasn't expired yet")]
+    SessionNotExpired,
+
+    #[msg("Session close must be signed by the session's authority")]
+    MissingSessionCloseSignature,
+
+    // * Session heartbeat errors
+    #[msg("Session has been idle longer than its domain's idle timeout")]
+    SessionIdle,
+
+    #[msg("Idle timeout must be 0 or within the protocol's session lifetimes")]
+    InvalidIdleTimeout,
+
+    // * Device key errors
+    #[msg("Session's device key did not sign this instruction")]
+    MissingDeviceSignature,
+
+    // * Session delegate errors
+    #[msg("Delegate scope must include at least one permission")]
+    InvalidDelegateScope,
+
+    #[msg("Delegate lifetime must be between 1 second and an hour")]
+    InvalidDelegateExpiry,
+
+    #[msg("Delegate has expired or its scope doesn't include this permission")]
+    DelegateNotAllowed,
+
+    // * Nullifier store errors
+    #[msg("Domain registers nullifiers in another store; use that store's instructions")]
+    WrongNullifierStore,
+
+    #[msg("Low leaf doesn't bracket the nullifier, so it may already be registered")]
+    InvalidNonInclusionProof,
+
+    // * Username errors
+    #[msg("Usernames are 3 to 32 ASCII letters, digits or underscores")]
+    InvalidUsername,
+
+    #[msg("Username transfer must be signed by the username's authority")]
+    MissingUsernameTransferSignature,
+
+    // * Guardian recovery errors
+    #[msg("Guardian set needs 1 to 8 distinct guardians and a threshold between 1 and their count")]
+    InvalidGuardianSet,
+
+    #[msg("Not enough guardians signed this recovery")]
+    NotEnoughGuardianSignatures,
+
+    #[msg("Recovery accounts must be (old, new) pairs of the nullifier's sessions or grants")]
+    InvalidRecoveryAccounts,
+
+    // * Identity root errors
+    #[msg("Identity root already links its maximum number of nullifiers")]
+    IdentityRootFull,
+
+    #[msg("Nullifier is already linked to this identity root")]
+    NullifierAlreadyLinked,
+
+    #[msg("Nullifier is not linked to this identity root")]
+    NullifierNotLinked,
+
+    #[msg("Identity link must be signed by the identity root's owner")]
+    MissingIdentityLinkSignature,
+
+    // * Nullifier ban errors
+    #[msg("Nullifier is banned from this domain")]
+    NullifierBanned,
+
+    // * SNS errors
+    #[msg("SNS name account missing or not the one for this .sol domain")]
+    InvalidSnsNameAccount,
+    #[msg("Signer doesn't own the SNS name for this .sol domain")]
+    NotSnsNameOwner,
+
+    // * Reentrancy errors
+    #[msg("Instruction invoked too deep in a CPI chain (possible reentrancy)")]
+    ReentrancyDetected,
+
+    // * On-chain UltraHonk verification errors
+    #[msg("UltraHonk key is malformed, incomplete or doesn't match the circuit's vk_hash")]
+    InvalidHonkVk,
+
+    #[msg("Upload must be the circuit's public inputs followed by one UltraHonk proof")]
+    InvalidProofLength,
+
+    #[msg("Proof chunk must start where the upload left off and stay within it")]
+    ProofChunkOutOfOrder,
+
+    #[msg("Verification session is not in the right state for this instruction")]
+    InvalidSessionStatus,
 }
@@ -0,0 +1,380 @@
+// * BN254 scalar field arithmetic
+// *
+// * The alt_bn128 syscalls only cover curve operations, so the UltraHonk verifier (honk.rs)
+// * does its scalar arithmetic here: Montgomery multiplication over four 64-bit limbs, with
+// * inversion by the binary extended Euclidean algorithm (far cheaper on-chain than a
+// * Fermat exponentiation). Values travel as 32-byte big-endian scalars, like everywhere
+// * else in the program.
+
+use std::ops::{Add, Mul, Neg, Sub};
+
+/// * r, little-endian limbs
+const MODULUS: [u64; 4] = [
+    0x43e1f593f0000001,
+    0x2833e84879b97091,
+    0xb85045b68181585d,
+    0x30644e72e131a029,
+];
+
+/// * -r^-1 mod 2^64
+const INV: u64 = 0xc2e1f593efffffff;
+
+/// * R mod r with R = 2^256 (one in Montgomery form)
+const R: [u64; 4] = [
+    0xac96341c4ffffffb,
+    0x36fc76959f60cd29,
+    0x666ea36f7879462e,
+    0x0e0a77c19a07df2f,
+];
+
+/// * R^2 mod r (converts into Montgomery form)
+const R2: [u64; 4] = [
+    0x1bb8e645ae216da7,
+    0x53fe3ab1e35c59e3,
+    0x8c49833d53bb8085,
+    0x0216d0b17f4e44a5,
+];
+
+/// * R^3 mod r (turns the plain inverse of a Montgomery value back into Montgomery form)
+const R3: [u64; 4] = [
+    0x5e94d8e1b4bf0040,
+    0x2a489cbe1cfbb6b8,
+    0x893cc664a19fcfed,
+    0x0cf8594b7fcc657c,
+];
+
+/// * Element of the BN254 scalar field, kept in Montgomery form
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Fr([u64; 4]);
+
+impl Fr {
+    pub const ZERO: Fr = Fr([0; 4]);
+    pub const ONE: Fr = Fr(R);
+
+    pub fn from_u64(value: u64) -> Fr {
+        Fr(mont_mul(&[value, 0, 0, 0], &R2))
+    }
+
+    /// * Canonical big-endian scalar; None when it isn't below r
+    pub fn from_be_bytes(bytes: &[u8; 32]) -> Option<Fr> {
+        let limbs = be_to_limbs(bytes);
+        (!geq(&limbs, &MODULUS)).then(|| Fr(mont_mul(&limbs, &R2)))
+    }
+
+    /// * Any 32 bytes reduced mod r (how bb turns transcript hashes into challenges)
+    pub fn from_be_bytes_reduced(bytes: &[u8; 32]) -> Fr {
+        let mut limbs = be_to_limbs(bytes);
+        // * 2^256 < 6r, so at most five subtractions
+        while geq(&limbs, &MODULUS) {
+            limbs = sub_limbs(&limbs, &MODULUS).0;
+        }
+        Fr(mont_mul(&limbs, &R2))
+    }
+
+    pub fn to_be_bytes(self) -> [u8; 32] {
+        let limbs = mont_mul(&self.0, &[1, 0, 0, 0]);
+        let mut bytes = [0u8; 32];
+        for (i, limb) in limbs.iter().enumerate() {
+            bytes[24 - 8 * i..32 - 8 * i].copy_from_slice(&limb.to_be_bytes());
+        }
+        bytes
+    }
+
+    /// * Low and high 128 bits of the canonical value (bb splits one hash into two challenges)
+    pub fn split(&self) -> (Fr, Fr) {
+        let bytes = self.to_be_bytes();
+        let mut lo = [0u8; 32];
+        let mut hi = [0u8; 32];
+        lo[16..].copy_from_slice(&bytes[16..]);
+        hi[16..].copy_from_slice(&bytes[..16]);
+        (
+            Fr::from_be_bytes_reduced(&lo),
+            Fr::from_be_bytes_reduced(&hi),
+        )
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.0 == [0; 4]
+    }
+
+    pub fn square(&self) -> Fr {
+        *self * *self
+    }
+
+    /// * None for zero
+    pub fn inverse(&self) -> Option<Fr> {
+        if self.is_zero() {
+            return None;
+        }
+
+        // * Binary extended Euclid on the raw limbs: u * x1 == self and v * x2 == self
+        // * (mod r) hold throughout, so x ends up as self^-1 = a^-1 * R^-1
+        let one = [1, 0, 0, 0];
+        let (mut u, mut v) = (self.0, MODULUS);
+        let (mut x1, mut x2) = (one, [0; 4]);
+        while u != one && v != one {
+            while u[0] & 1 == 0 {
+                u = shr1(&u);
+                x1 = half(&x1);
+            }
+            while v[0] & 1 == 0 {
+                v = shr1(&v);
+                x2 = half(&x2);
+            }
+            if geq(&u, &v) {
+                u = sub_limbs(&u, &v).0;
+                x1 = sub_mod(&x1, &x2);
+            } else {
+                v = sub_limbs(&v, &u).0;
+                x2 = sub_mod(&x2, &x1);
+            }
+        }
+
+        let inverse = if u == one { x1 } else { x2 };
+        Some(Fr(mont_mul(&inverse, &R3)))
+    }
+
+    /// * Invert every element with a single inversion; false (values untouched) if any is zero
+    pub fn batch_invert(values: &mut [Fr]) -> bool {
+        let mut products = Vec::with_capacity(values.len());
+        let mut acc = Fr::ONE;
+        for value in values.iter() {
+            products.push(acc);
+            acc = acc * *value;
+        }
+
+        let Some(mut inverse) = acc.inverse() else {
+            return false;
+        };
+        for (value, product) in values.iter_mut().zip(products).rev() {
+            let next = inverse * *value;
+            *value = inverse * product;
+            inverse = next;
+        }
+        true
+    }
+}
+
+impl Add for Fr {
+    type Output = Fr;
+
+    fn add(self, other: Fr) -> Fr {
+        let (sum, carry) = add_limbs(&self.0, &other.0);
+        // * Both below r < 2^254, so the sum never carries out; one subtraction reduces it
+        debug_assert!(!carry);
+        if geq(&sum, &MODULUS) {
+            Fr(sub_limbs(&sum, &MODULUS).0)
+        } else {
+            Fr(sum)
+        }
+    }
+}
+
+impl Sub for Fr {
+    type Output = Fr;
+
+    fn sub(self, other: Fr) -> Fr {
+        Fr(sub_mod(&self.0, &other.0))
+    }
+}
+
+impl Neg for Fr {
+    type Output = Fr;
+
+    fn neg(self) -> Fr {
+        Fr::ZERO - self
+    }
+}
+
+impl Mul for Fr {
+    type Output = Fr;
+
+    fn mul(self, other: Fr) -> Fr {
+        Fr(mont_mul(&self.0, &other.0))
+    }
+}
+
+fn be_to_limbs(bytes: &[u8; 32]) -> [u64; 4] {
+    let mut limbs = [0u64; 4];
+    for (i, limb) in limbs.iter_mut().enumerate() {
+        *limb = u64::from_be_bytes(bytes[24 - 8 * i..32 - 8 * i].try_into().unwrap());
+    }
+    limbs
+}
+
+fn geq(a: &[u64; 4], b: &[u64; 4]) -> bool {
+    for i in (0..4).rev() {
+        if a[i] != b[i] {
+            return a[i] > b[i];
+        }
+    }
+    true
+}
+
+fn add_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut sum = [0u64; 4];
+    let mut carry = false;
+    for i in 0..4 {
+        let (s, c1) = a[i].overflowing_add(b[i]);
+        let (s, c2) = s.overflowing_add(u64::from(carry));
+        sum[i] = s;
+        carry = c1 || c2;
+    }
+    (sum, carry)
+}
+
+fn sub_limbs(a: &[u64; 4], b: &[u64; 4]) -> ([u64; 4], bool) {
+    let mut difference = [0u64; 4];
+    let mut borrow = false;
+    for i in 0..4 {
+        let (d, b1) = a[i].overflowing_sub(b[i]);
+        let (d, b2) = d.overflowing_sub(u64::from(borrow));
+        difference[i] = d;
+        borrow = b1 || b2;
+    }
+    (difference, borrow)
+}
+
+/// * a - b mod r for a, b < r
+fn sub_mod(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let (difference, borrow) = sub_limbs(a, b);
+    if borrow {
+        add_limbs(&difference, &MODULUS).0
+    } else {
+        difference
+    }
+}
+
+fn shr1(a: &[u64; 4]) -> [u64; 4] {
+    [
+        (a[0] >> 1) | (a[1] << 63),
+        (a[1] >> 1) | (a[2] << 63),
+        (a[2] >> 1) | (a[3] << 63),
+        a[3] >> 1,
+    ]
+}
+
+/// * a / 2 mod r for a < r (an odd a becomes (a + r) / 2, which can't overflow)
+fn half(a: &[u64; 4]) -> [u64; 4] {
+    if a[0] & 1 == 0 {
+        shr1(a)
+    } else {
+        shr1(&add_limbs(a, &MODULUS).0)
+    }
+}
+
+/// * a * b * R^-1 mod r (CIOS)
+fn mont_mul(a: &[u64; 4], b: &[u64; 4]) -> [u64; 4] {
+    let mut t = [0u64; 6];
+    for &bi in b {
+        let mut carry = 0u128;
+        for j in 0..4 {
+            let s = u128::from(t[j]) + u128::from(a[j]) * u128::from(bi) + carry;
+            t[j] = s as u64;
+            carry = s >> 64;
+        }
+        let s = u128::from(t[4]) + carry;
+        t[4] = s as u64;
+        t[5] = (s >> 64) as u64;
+
+        let m = t[0].wrapping_mul(INV);
+        let mut carry = (u128::from(t[0]) + u128::from(m) * u128::from(MODULUS[0])) >> 64;
+        for j in 1..4 {
+            let s = u128::from(t[j]) + u128::from(m) * u128::from(MODULUS[j]) + carry;
+            t[j - 1] = s as u64;
+            carry = s >> 64;
+        }
+        let s = u128::from(t[4]) + carry;
+        t[3] = s as u64;
+        t[4] = t[5] + (s >> 64) as u64;
+    }
+
+    // * The result is below 2r; one conditional subtraction brings it under r
+    let result = [t[0], t[1], t[2], t[3]];
+    if t[4] != 0 || geq(&result, &MODULUS) {
+        sub_limbs(&result, &MODULUS).0
+    } else {
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ff::{BigInteger, Field, PrimeField};
+    use ark_std::rand::{Rng, SeedableRng};
+
+    fn to_ark(value: Fr) -> ark_bn254::Fr {
+        ark_bn254::Fr::from_be_bytes_mod_order(&value.to_be_bytes())
+    }
+
+    fn from_ark(value: ark_bn254::Fr) -> Fr {
+        Fr::from_be_bytes(&value.into_bigint().to_bytes_be().try_into().unwrap()).unwrap()
+    }
+
+    // * Test: Field operations agree with arkworks on random values
+    #[test]
+    fn test_matches_arkworks() {
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(11);
+        for _ in 0..200 {
+            let a: ark_bn254::Fr = rng.gen();
+            let b: ark_bn254::Fr = rng.gen();
+            let (x, y) = (from_ark(a), from_ark(b));
+
+            assert_eq!(to_ark(x + y), a + b);
+            assert_eq!(to_ark(x - y), a - b);
+            assert_eq!(to_ark(-x), -a);
+            assert_eq!(to_ark(x * y), a * b);
+            assert_eq!(to_ark(x.inverse().unwrap()), a.inverse().unwrap());
+
+            let bytes: [u8; 32] = rng.gen();
+            assert_eq!(
+                to_ark(Fr::from_be_bytes_reduced(&bytes)),
+                ark_bn254::Fr::from_be_bytes_mod_order(&bytes)
+            );
+        }
+
+        assert_eq!(Fr::ZERO.inverse(), None);
+        assert_eq!(Fr::ONE.inverse(), Some(Fr::ONE));
+        assert_eq!(to_ark(Fr::from_u64(9)), ark_bn254::Fr::from(9u64));
+        assert_eq!(to_ark(-Fr::ONE + Fr::ONE), ark_bn254::Fr::from(0u64));
+    }
+
+    // * Test: Canonical parsing rejects r and above; reduction and splitting agree
+    #[test]
+    fn test_bytes() {
+        let mut modulus = [0u8; 32];
+        for (i, limb) in MODULUS.iter().enumerate() {
+            modulus[24 - 8 * i..32 - 8 * i].copy_from_slice(&limb.to_be_bytes());
+        }
+        assert_eq!(Fr::from_be_bytes(&modulus), None);
+        assert_eq!(Fr::from_be_bytes_reduced(&modulus), Fr::ZERO);
+        assert_eq!(
+            Fr::from_be_bytes_reduced(&[0xff; 32]).to_be_bytes()[0],
+            0x0e
+        );
+
+        let mut value = [0u8; 32];
+        value[0] = 0x12;
+        value[31] = 0x34;
+        let (lo, hi) = Fr::from_be_bytes(&value).unwrap().split();
+        assert_eq!(lo, Fr::from_u64(0x34));
+        assert_eq!(hi.to_be_bytes()[16], 0x12);
+        assert_eq!(Fr::from_be_bytes(&value).unwrap().to_be_bytes(), value);
+    }
+
+    // * Test: Batch inversion matches one-by-one inversion and refuses zero
+    #[test]
+    fn test_batch_invert() {
+        let values: Vec<Fr> = (1..6).map(Fr::from_u64).collect();
+        let mut inverted = values.clone();
+        assert!(Fr::batch_invert(&mut inverted));
+        for (value, inverse) in values.iter().zip(&inverted) {
+            assert_eq!(*value * *inverse, Fr::ONE);
+        }
+
+        let mut with_zero = vec![Fr::ONE, Fr::ZERO];
+        assert!(!Fr::batch_invert(&mut with_zero));
+        assert_eq!(with_zero, vec![Fr::ONE, Fr::ZERO]);
+    }
+}
@@ -0,0 +1,38 @@
+// * Deployment genesis
+// *
+// * What sets one Veiled deployment apart from another, in one place, so white-label
+// * instances (their own declare_id!, same source) can be deployed without forking byte
+// * layouts:
+// * - INSTANCE_ID: the program ID. V7 results and user consents sign it, so a result or consent
+// *   issued for one instance can't be replayed on another that trusts the same verifier keys.
+// *   Account addresses need no instance seed: every PDA already derives from the program ID.
+// * - CLUSTER_ID: the cluster the build accepts V6+ results for (see cluster.rs)
+// * - The defaults a fresh deployment starts from. Each is the fallback for a setting the admin
+// *   or domain owners can later change in their own accounts, so instances tune them at runtime
+// *   rather than by editing this file.
+
+/// * Instance this build accepts V7 results and user consents for
+pub const INSTANCE_ID: [u8; 32] = crate::ID.to_bytes();
+
+/// * Oldest oracle price accepted for a payment (set_pricing)
+pub const DEFAULT_MAX_PRICE_AGE_SECS: u64 = 60;
+
+/// * Widest oracle confidence interval accepted, in basis points (set_pricing)
+pub const DEFAULT_MAX_PRICE_CONF_BPS: u16 = 100;
+
+/// * Staleness window for tiers without one (set_assurance_tier)
+pub const DEFAULT_MAX_RESULT_AGE_SECS: u32 = 5 * 60;
+
+/// * Verifications per payer per minute on domains that don't set a limit (set_domain_rate_limit)
+pub const DEFAULT_RATE_LIMIT: u32 = 120;
+
+/// * Session lifetime when verify_auth's caller doesn't ask for one, and the longest it may ask
+/// * for (domains can lower it with set_domain_session_bounds)
+pub const DEFAULT_SESSION_EXPIRY_SECS: i64 = 30 * 24 * 60 * 60;
+
+/// * How long a consumed result's receipt is kept before it can be closed
+/// * (set_receipt_retention)
+pub const DEFAULT_RECEIPT_RETENTION_SECS: i64 = 24 * 60 * 60;
+
+/// * Shortest session verify_auth's caller may ask for
+pub const MIN_SESSION_EXPIRY_SECS: i64 = 60;
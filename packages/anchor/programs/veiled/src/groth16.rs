@@ -0,0 +1,272 @@
+// * Native Groth16 verification (BN254)
+// *
+// * Checks a Groth16 proof on-chain with the alt_bn128 syscalls, so small circuits need no
+// * off-chain verifier signature at all. Points use the EIP-197 encoding the syscalls expect
+// * (what snarkjs and groth16-solana export):
+// *   G1: be(x) || be(y)                         (64 bytes)
+// *   G2: be(x1) || be(x0) || be(y1) || be(y0)   (128 bytes)
+// * Public inputs are big-endian BN254 scalars, each below the scalar field modulus.
+// *
+// * A proof (A, B, C) is valid when
+// *   e(-A, B) * e(alpha, beta) * e(vk_x, gamma) * e(C, delta) == 1
+// * with vk_x = ic[0] + sum(input[i] * ic[i + 1]).
+
+use anchor_lang::prelude::*;
+use solana_bn254::prelude::{alt_bn128_addition, alt_bn128_multiplication, alt_bn128_pairing};
+use solana_sha256_hasher::hashv;
+
+use crate::errors::VeiledError;
+use crate::state::groth16::Groth16VerifyingKey;
+
+/// * Serialized proof: [64 bytes: A (G1)] [128 bytes: B (G2)] [64 bytes: C (G1)]
+pub const PROOF_SIZE: usize = 64 + 128 + 64;
+
+/// * BN254 base field modulus q, big-endian (G1 negation: -(x, y) = (x, q - y))
+pub const BASE_FIELD_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x97, 0x81, 0x6a, 0x91, 0x68, 0x71, 0xca, 0x8d, 0x3c, 0x20, 0x8c, 0x16, 0xd8, 0x7c, 0xfd, 0x47,
+];
+
+/// * BN254 scalar field modulus r, big-endian (public inputs must be below it)
+const SCALAR_FIELD_MODULUS: [u8; 32] = [
+    0x30, 0x64, 0x4e, 0x72, 0xe1, 0x31, 0xa0, 0x29, 0xb8, 0x50, 0x45, 0xb6, 0x81, 0x81, 0x58, 0x5d,
+    0x28, 0x33, 0xe8, 0x48, 0x79, 0xb9, 0x70, 0x91, 0x43, 0xe1, 0xf5, 0x93, 0xf0, 0x00, 0x00, 0x01,
+];
+
+/// * Big-endian 1: the pairing syscall's output when the product is the identity
+pub const PAIRING_ONE: [u8; 32] = {
+    let mut one = [0u8; 32];
+    one[31] = 1;
+    one
+};
+
+/// * A Groth16 proof split into its three points
+pub struct Groth16Proof {
+    pub a: [u8; 64],
+    pub b: [u8; 128],
+    pub c: [u8; 64],
+}
+
+impl Groth16Proof {
+    pub fn from_bytes(bytes: &[u8; PROOF_SIZE]) -> Self {
+        let mut a = [0u8; 64];
+        let mut b = [0u8; 128];
+        let mut c = [0u8; 64];
+        a.copy_from_slice(&bytes[..64]);
+        b.copy_from_slice(&bytes[64..192]);
+        c.copy_from_slice(&bytes[192..]);
+        Self { a, b, c }
+    }
+}
+
+/// * Whether a 32-byte big-endian value is a canonical BN254 scalar
+pub fn is_scalar(value: &[u8; 32]) -> bool {
+    value < &SCALAR_FIELD_MODULUS
+}
+
+/// * Public input a circuit exposes for `domain`: sha256 of the null-padded domain with the
+/// * top byte cleared, so it always fits the scalar field
+pub fn domain_input(domain: &[u8; 32]) -> [u8; 32] {
+    let mut input = hashv(&[domain]).to_bytes();
+    input[0] = 0;
+    input
+}
+
+/// * Verify `proof` against `vk` for `public_inputs` (one per vk.ic entry after the first)
+pub fn verify(
+    vk: &Groth16VerifyingKey,
+    proof: &Groth16Proof,
+    public_inputs: &[[u8; 32]],
+) -> Result<()> {
+    require!(
+        public_inputs.len() + 1 == vk.ic.len(),
+        VeiledError::InvalidPublicInputs
+    );
+    require!(
+        public_inputs.iter().all(is_scalar),
+        VeiledError::InvalidPublicInputs
+    );
+
+    // * vk_x = ic[0] + sum(input[i] * ic[i + 1])
+    let mut vk_x = vk.ic[0].to_vec();
+    for (input, point) in public_inputs.iter().zip(&vk.ic[1..]) {
+        let term = alt_bn128_multiplication(&[point.as_slice(), input].concat())
+            .map_err(|_| VeiledError::InvalidGroth16Proof)?;
+        vk_x = alt_bn128_addition(&[vk_x.as_slice(), &term].concat())
+            .map_err(|_| VeiledError::InvalidGroth16Proof)?;
+    }
+
+    let pairing_input = [
+        negate_g1(&proof.a)?.as_slice(),
+        &proof.b,
+        &vk.alpha_g1,
+        &vk.beta_g2,
+        &vk_x,
+        &vk.gamma_g2,
+        &proof.c,
+        &vk.delta_g2,
+    ]
+    .concat();
+
+    let result = alt_bn128_pairing(&pairing_input).map_err(|_| VeiledError::InvalidGroth16Proof)?;
+    require!(
+        result.as_slice() == PAIRING_ONE,
+        VeiledError::InvalidGroth16Proof
+    );
+
+    Ok(())
+}
+
+/// * -(x, y) = (x, q - y); the point at infinity (all zeros) is its own negation
+pub fn negate_g1(point: &[u8; 64]) -> Result<[u8; 64]> {
+    let mut negated = *point;
+    if point.iter().all(|&b| b == 0) {
+        return Ok(negated);
+    }
+
+    let y: &[u8; 32] = point[32..].try_into().unwrap();
+    require!(y < &BASE_FIELD_MODULUS, VeiledError::InvalidGroth16Proof);
+
+    // * Big-endian q - y (no underflow since y < q)
+    let mut borrow = 0u16;
+    for i in (0..32).rev() {
+        let (difference, underflow) =
+            u16::from(BASE_FIELD_MODULUS[i]).overflowing_sub(u16::from(y[i]) + borrow);
+        negated[32 + i] = difference as u8;
+        borrow = u16::from(underflow);
+    }
+
+    Ok(negated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::{Bn254, Fr, G1Affine, G2Affine};
+    use ark_ec::AffineRepr;
+    use ark_ff::{BigInteger, PrimeField};
+    use ark_groth16::Groth16;
+    use ark_relations::lc;
+    use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+    use ark_snark::SNARK;
+    use ark_std::rand::SeedableRng;
+
+    // * x * x == y with x private and (y, z) public; z is unconstrained, like the domain input
+    struct SquareCircuit {
+        x: Option<Fr>,
+        y: Option<Fr>,
+        z: Option<Fr>,
+    }
+
+    impl ConstraintSynthesizer<Fr> for SquareCircuit {
+        fn generate_constraints(
+            self,
+            cs: ConstraintSystemRef<Fr>,
+        ) -> core::result::Result<(), SynthesisError> {
+            let x = cs.new_witness_variable(|| self.x.ok_or(SynthesisError::AssignmentMissing))?;
+            let y = cs.new_input_variable(|| self.y.ok_or(SynthesisError::AssignmentMissing))?;
+            let z = cs.new_input_variable(|| self.z.ok_or(SynthesisError::AssignmentMissing))?;
+            cs.enforce_constraint(lc!() + x, lc!() + x, lc!() + y)?;
+            cs.enforce_constraint(lc!() + z, lc!(), lc!())?;
+            Ok(())
+        }
+    }
+
+    fn be_field<F: PrimeField>(value: F) -> [u8; 32] {
+        value.into_bigint().to_bytes_be().try_into().unwrap()
+    }
+
+    fn g1(point: G1Affine) -> [u8; 64] {
+        [be_field(point.x), be_field(point.y)]
+            .concat()
+            .try_into()
+            .unwrap()
+    }
+
+    fn g2(point: G2Affine) -> [u8; 128] {
+        [
+            be_field(point.x.c1),
+            be_field(point.x.c0),
+            be_field(point.y.c1),
+            be_field(point.y.c0),
+        ]
+        .concat()
+        .try_into()
+        .unwrap()
+    }
+
+    fn setup() -> (Groth16VerifyingKey, Groth16Proof, [[u8; 32]; 2]) {
+        let mut rng = ark_std::rand::rngs::StdRng::seed_from_u64(7);
+        let blank = SquareCircuit {
+            x: None,
+            y: None,
+            z: None,
+        };
+        let (pk, vk) = Groth16::<Bn254>::circuit_specific_setup(blank, &mut rng).unwrap();
+
+        let (x, y, z) = (Fr::from(3u64), Fr::from(9u64), Fr::from(42u64));
+        let circuit = SquareCircuit {
+            x: Some(x),
+            y: Some(y),
+            z: Some(z),
+        };
+        let proof = Groth16::<Bn254>::prove(&pk, circuit, &mut rng).unwrap();
+
+        let vk = Groth16VerifyingKey {
+            circuit_id: 1,
+            alpha_g1: g1(vk.alpha_g1),
+            beta_g2: g2(vk.beta_g2),
+            gamma_g2: g2(vk.gamma_g2),
+            delta_g2: g2(vk.delta_g2),
+            ic: vk.gamma_abc_g1.into_iter().map(g1).collect(),
+            bump: 255,
+        };
+        let proof = Groth16Proof {
+            a: g1(proof.a),
+            b: g2(proof.b),
+            c: g1(proof.c),
+        };
+        (vk, proof, [be_field(y), be_field(z)])
+    }
+
+    // * Test: An arkworks proof verifies through the syscall encoding; tampering fails
+    #[test]
+    fn test_verify_groth16() {
+        let (vk, proof, inputs) = setup();
+        assert!(verify(&vk, &proof, &inputs).is_ok());
+
+        // * Wrong public input
+        let mut wrong = inputs;
+        wrong[1][31] ^= 1;
+        assert!(verify(&vk, &proof, &wrong).is_err());
+
+        // * Wrong number of public inputs
+        assert!(verify(&vk, &proof, &inputs[..1]).is_err());
+
+        // * Non-canonical scalar (input + r would otherwise alias input)
+        let mut aliased = inputs;
+        aliased[0] = SCALAR_FIELD_MODULUS;
+        assert!(verify(&vk, &proof, &aliased).is_err());
+
+        // * Swapped points
+        let swapped = Groth16Proof {
+            a: proof.c,
+            b: proof.b,
+            c: proof.a,
+        };
+        assert!(verify(&vk, &swapped, &inputs).is_err());
+    }
+
+    // * Test: Negation matches arkworks and domain inputs are always scalars
+    #[test]
+    fn test_negate_g1_and_domain_input() {
+        let point = G1Affine::generator();
+        assert_eq!(negate_g1(&g1(point)).unwrap(), g1(-point));
+        assert_eq!(negate_g1(&[0u8; 64]).unwrap(), [0u8; 64]);
+
+        let mut domain = [0u8; 32];
+        domain[..11].copy_from_slice(b"example.com");
+        assert!(is_scalar(&domain_input(&domain)));
+        assert!(is_scalar(&domain_input(&[0xff; 32])));
+    }
+}
@@ -0,0 +1,1348 @@
+// * Chunked UltraHonk verifier
+// *
+// * Step engine behind advance_verification_session. Each call to `step` does one bounded
+// * unit of work, so a proof is verified across as many instructions (and transactions) as
+// * it needs while staying inside the per-instruction compute budget. Work carried between
+// * steps lives in the session's scratch area.
+// *
+// * Verifies bb's keccak-transcript, non-ZK UltraHonk proofs (UltraKeccakFlavor, bb 0.82 proof
+// * layout, padded to CONST_PROOF_SIZE_LOG_N rounds), the same checks bb's Solidity verifier
+// * makes. The pairing point object is bound into the transcript and the permutation argument
+// * but not aggregated into the final pairing, so circuits that verify other proofs
+// * recursively aren't supported.
+// *
+// * Phases, in step order:
+// *   BindPublicInputs  public_inputs[0] = nullifier, public_inputs[1] = groth16::domain_input(domain),
+// *                     every public input a canonical BN254 scalar
+// *   Transcript        Fiat-Shamir challenges (keccak) and the public input delta
+// *   SumcheckRound     one round per step, log_circuit_size steps
+// *   Relations         all subrelations at the sumcheck point against the final round target
+// *   Shplemini         Gemini fold evaluations and the Shplonk batching scalars
+// *   Msm               the batched commitment, MSM_TERMS_PER_STEP points per step
+// *   Pairing           final KZG pairing check via alt_bn128
+// *
+// * A proof that fails any check moves the session to Rejected; only malformed sessions or keys
+// * (which the instructions rule out) return errors.
+
+use anchor_lang::prelude::*;
+use solana_bn254::prelude::{alt_bn128_addition, alt_bn128_multiplication, alt_bn128_pairing};
+use solana_keccak_hasher::hashv;
+
+use crate::errors::VeiledError;
+use crate::fr::Fr;
+use crate::groth16;
+use crate::state::honk::HonkVerifyingKey;
+use crate::state::verification_session::VerificationSession;
+
+/// * Sumcheck rounds and Gemini folds every proof carries, whatever the circuit size
+pub const CONST_PROOF_SIZE_LOG_N: usize = 28;
+
+/// * Public inputs bb appends to every circuit's own (the recursion pairing points)
+pub const PAIRING_POINT_OBJECT_LENGTH: usize = 16;
+
+const NUMBER_OF_ENTITIES: usize = 40;
+const NUMBER_UNSHIFTED: usize = 35;
+const NUMBER_OF_SUBRELATIONS: usize = 26;
+const NUMBER_OF_ALPHAS: usize = NUMBER_OF_SUBRELATIONS - 1;
+const BATCHED_RELATION_PARTIAL_LENGTH: usize = 8;
+
+// * Proof layout in 32-byte fields. Commitments take four: x_0, x_1, y_0, y_1 with
+// * x = x_0 + x_1 * 2^136
+const PAIRING_POINTS: usize = 0;
+const W1: usize = PAIRING_POINTS + PAIRING_POINT_OBJECT_LENGTH;
+const W2: usize = W1 + 4;
+const W3: usize = W2 + 4;
+const LOOKUP_READ_COUNTS: usize = W3 + 4;
+const LOOKUP_READ_TAGS: usize = LOOKUP_READ_COUNTS + 4;
+const W4: usize = LOOKUP_READ_TAGS + 4;
+const LOOKUP_INVERSES: usize = W4 + 4;
+const Z_PERM: usize = LOOKUP_INVERSES + 4;
+const SUMCHECK_UNIVARIATES: usize = Z_PERM + 4;
+const SUMCHECK_EVALUATIONS: usize =
+    SUMCHECK_UNIVARIATES + CONST_PROOF_SIZE_LOG_N * BATCHED_RELATION_PARTIAL_LENGTH;
+const GEMINI_FOLD_COMMS: usize = SUMCHECK_EVALUATIONS + NUMBER_OF_ENTITIES;
+const GEMINI_A_EVALUATIONS: usize = GEMINI_FOLD_COMMS + (CONST_PROOF_SIZE_LOG_N - 1) * 4;
+const SHPLONK_Q: usize = GEMINI_A_EVALUATIONS + CONST_PROOF_SIZE_LOG_N;
+const KZG_QUOTIENT: usize = SHPLONK_Q + 4;
+const PROOF_FIELDS: usize = KZG_QUOTIENT + 4;
+
+/// * Serialized proof size (456 fields)
+pub const PROOF_SIZE: usize = PROOF_FIELDS * 32;
+
+/// * Points folded into the batched commitment per Msm step (~4.2k CU each)
+const MSM_TERMS_PER_STEP: usize = 24;
+
+/// * shplonk Q, the entities, the fold commitments, the generator and the KZG quotient
+const MSM_TERMS: usize = 1 + NUMBER_OF_ENTITIES + (CONST_PROOF_SIZE_LOG_N - 1) + 2;
+const MSM_STEPS: usize = MSM_TERMS.div_ceil(MSM_TERMS_PER_STEP);
+
+/// * Sumcheck evaluation order (bb's UltraFlavor entities)
+mod entity {
+    pub const Q_M: usize = 0;
+    pub const Q_C: usize = 1;
+    pub const Q_L: usize = 2;
+    pub const Q_R: usize = 3;
+    pub const Q_O: usize = 4;
+    pub const Q_4: usize = 5;
+    pub const Q_LOOKUP: usize = 6;
+    pub const Q_ARITH: usize = 7;
+    pub const Q_RANGE: usize = 8;
+    pub const Q_ELLIPTIC: usize = 9;
+    pub const Q_AUX: usize = 10;
+    pub const Q_POSEIDON2_EXTERNAL: usize = 11;
+    pub const Q_POSEIDON2_INTERNAL: usize = 12;
+    pub const SIGMA_1: usize = 13;
+    pub const ID_1: usize = 17;
+    pub const TABLE_1: usize = 21;
+    pub const TABLE_2: usize = 22;
+    pub const TABLE_3: usize = 23;
+    pub const TABLE_4: usize = 24;
+    pub const LAGRANGE_FIRST: usize = 25;
+    pub const LAGRANGE_LAST: usize = 26;
+    pub const W_L: usize = 27;
+    pub const W_R: usize = 28;
+    pub const W_O: usize = 29;
+    pub const W_4: usize = 30;
+    pub const Z_PERM: usize = 31;
+    pub const LOOKUP_INVERSES: usize = 32;
+    pub const LOOKUP_READ_COUNTS: usize = 33;
+    pub const LOOKUP_READ_TAGS: usize = 34;
+    pub const W_L_SHIFT: usize = 35;
+    pub const W_R_SHIFT: usize = 36;
+    pub const W_O_SHIFT: usize = 37;
+    pub const W_4_SHIFT: usize = 38;
+    pub const Z_PERM_SHIFT: usize = 39;
+}
+
+/// * Scratch layout, in 32-byte slots
+mod slot {
+    use super::*;
+
+    pub const ETA: usize = 0;
+    pub const ETA_TWO: usize = 1;
+    pub const ETA_THREE: usize = 2;
+    pub const BETA: usize = 3;
+    pub const GAMMA: usize = 4;
+    pub const PUBLIC_INPUTS_DELTA: usize = 5;
+    pub const ALPHAS: usize = 6;
+    pub const GATE_CHALLENGES: usize = ALPHAS + NUMBER_OF_ALPHAS;
+    pub const SUMCHECK_CHALLENGES: usize = GATE_CHALLENGES + CONST_PROOF_SIZE_LOG_N;
+    pub const RHO: usize = SUMCHECK_CHALLENGES + CONST_PROOF_SIZE_LOG_N;
+    pub const GEMINI_R: usize = RHO + 1;
+    pub const SHPLONK_NU: usize = GEMINI_R + 1;
+    pub const SHPLONK_Z: usize = SHPLONK_NU + 1;
+    pub const ROUND_TARGET: usize = SHPLONK_Z + 1;
+    pub const POW_PARTIAL: usize = ROUND_TARGET + 1;
+    pub const UNSHIFTED_SCALAR: usize = POW_PARTIAL + 1;
+    pub const SHIFTED_SCALAR: usize = UNSHIFTED_SCALAR + 1;
+    pub const CONSTANT_TERM: usize = SHIFTED_SCALAR + 1;
+    /// * rho^k for the next entity term of the MSM
+    pub const BATCHING_CHALLENGE: usize = CONSTANT_TERM + 1;
+    pub const GEMINI_SCALARS: usize = BATCHING_CHALLENGE + 1;
+    /// * Running MSM sum, a G1 point over two slots
+    pub const ACCUMULATOR: usize = GEMINI_SCALARS + CONST_PROOF_SIZE_LOG_N - 1;
+    pub const COUNT: usize = ACCUMULATOR + 2;
+}
+
+const _: () = assert!(slot::COUNT * 32 <= VerificationSession::SCRATCH_SIZE);
+
+/// * Poseidon2 (t = 4) internal matrix diagonal
+const INTERNAL_MATRIX_DIAGONAL: [[u8; 32]; 4] = [
+    be_hex("10dc6e9c006ea38b04b1e03b4bd9490c0d03f98929ca1d7fb56821fd19d3b6e7"),
+    be_hex("0c28145b6a44df3e0149b3d0a30b3bb599df9756d4dd9b84a86b38cfb45a740b"),
+    be_hex("00544b8338791518b2c7645a50392798b21f75bb60e3596170067d00141cac15"),
+    be_hex("222c01175718386f2e2e82eb122789e352e105a3b8fa852613bc534433ee428b"),
+];
+
+/// * G2 generator, EIP-197 encoded
+const G2_GENERATOR: [[u8; 32]; 4] = [
+    be_hex("198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2"),
+    be_hex("1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed"),
+    be_hex("090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b"),
+    be_hex("12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa"),
+];
+
+/// * [x]_2 from the Aztec ignition SRS, EIP-197 encoded
+const SRS_G2_X: [[u8; 32]; 4] = [
+    be_hex("260e01b251f6f1c7e7ff4e580791dee8ea51d87a358e038b4efe30fac09383c1"),
+    be_hex("0118c4d5b837bcc2bc89b5b398b5974e9f5944073b32078b7e231fec938883b0"),
+    be_hex("04fc6369f7110fe3d25156c1bb9a72859cf2a04641f99ba4ee413c80da6a5fe4"),
+    be_hex("22febda3c0c0632a56475b4214e5615e11e6dd3f96e6cea2854a87d4dacc5e55"),
+];
+
+/// * Big-endian 32 bytes from 64 hex digits, at compile time
+const fn be_hex(hex: &str) -> [u8; 32] {
+    const fn nibble(c: u8) -> u8 {
+        match c {
+            b'0'..=b'9' => c - b'0',
+            b'a'..=b'f' => c - b'a' + 10,
+            _ => panic!("not a hex digit"),
+        }
+    }
+
+    let hex = hex.as_bytes();
+    assert!(hex.len() == 64);
+    let mut bytes = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        bytes[i] = (nibble(hex[2 * i]) << 4) | nibble(hex[2 * i + 1]);
+        i += 1;
+    }
+    bytes
+}
+
+/// * What a step decided
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StepOutcome {
+    Continue,
+    Accept,
+    Reject,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    BindPublicInputs,
+    Transcript,
+    SumcheckRound(usize),
+    Relations,
+    Shplemini,
+    Msm(usize),
+    Pairing,
+}
+
+impl Phase {
+    fn at(step: u32, log_n: usize) -> Option<Phase> {
+        let step = step as usize;
+        let relations = 2 + log_n;
+        let msm = relations + 2;
+        let pairing = msm + MSM_STEPS;
+        Some(match step {
+            0 => Phase::BindPublicInputs,
+            1 => Phase::Transcript,
+            _ if step < relations => Phase::SumcheckRound(step - 2),
+            _ if step == relations => Phase::Relations,
+            _ if step == relations + 1 => Phase::Shplemini,
+            _ if step < pairing => Phase::Msm(step - msm),
+            _ if step == pairing => Phase::Pairing,
+            _ => return None,
+        })
+    }
+}
+
+/// * Steps a proof takes against `vk` when every check passes
+pub fn total_steps(vk: &HonkVerifyingKey) -> u32 {
+    (5 + usize::from(vk.log_circuit_size) + MSM_STEPS) as u32
+}
+
+/// * Run verifier step `session.step` over the uploaded bytes
+pub fn step(
+    session: &VerificationSession,
+    vk: &HonkVerifyingKey,
+    upload: &[u8],
+    scratch: &mut [u8],
+) -> Result<StepOutcome> {
+    let log_n = usize::from(vk.log_circuit_size);
+    require!(
+        vk.circuit_id == session.circuit_id
+            && vk.is_complete()
+            && (1..=CONST_PROOF_SIZE_LOG_N).contains(&log_n)
+            && vk.public_inputs_size as usize
+                == usize::from(session.public_inputs_count) + PAIRING_POINT_OBJECT_LENGTH,
+        VeiledError::InvalidHonkVk
+    );
+    require!(
+        upload.len() == session.public_inputs_len() + PROOF_SIZE
+            && scratch.len() >= slot::COUNT * 32,
+        VeiledError::InvalidProofLength
+    );
+    let phase =
+        Phase::at(session.step, log_n).ok_or_else(|| error!(VeiledError::InvalidSessionStatus))?;
+
+    let (public_inputs, proof) = upload.split_at(session.public_inputs_len());
+    let proof = Proof {
+        public_inputs,
+        fields: proof,
+    };
+    let mut scratch = Scratch(scratch);
+
+    let outcome = match phase {
+        Phase::BindPublicInputs => bind_public_inputs(session, &proof),
+        Phase::Transcript => transcript(vk, &proof, &mut scratch),
+        Phase::SumcheckRound(round) => sumcheck_round(round, &proof, &mut scratch),
+        Phase::Relations => relations(&proof, &mut scratch),
+        Phase::Shplemini => shplemini(log_n, &proof, &mut scratch),
+        Phase::Msm(chunk) => msm(chunk, log_n, vk, &proof, &mut scratch),
+        Phase::Pairing => pairing(&proof, &scratch, &SRS_G2_X),
+    };
+    Ok(outcome.unwrap_or(StepOutcome::Reject))
+}
+
+/// * Public inputs followed by the proof fields
+struct Proof<'a> {
+    public_inputs: &'a [u8],
+    fields: &'a [u8],
+}
+
+impl<'a> Proof<'a> {
+    /// * `count` consecutive raw fields, as they are hashed into the transcript
+    fn words(&self, field: usize, count: usize) -> &'a [u8] {
+        &self.fields[field * 32..(field + count) * 32]
+    }
+
+    /// * A canonical scalar field; None (reject) for anything at or above r
+    fn fr(&self, field: usize) -> Option<Fr> {
+        Fr::from_be_bytes(self.words(field, 1).try_into().unwrap())
+    }
+
+    fn frs(&self, field: usize, count: usize) -> Option<Vec<Fr>> {
+        (field..field + count).map(|i| self.fr(i)).collect()
+    }
+
+    /// * Commitment at `field` as an EIP-197 G1 point; coordinates must be below q
+    fn point(&self, field: usize) -> Option<[u8; 64]> {
+        let limbs = self.words(field, 4);
+        let mut point = [0u8; 64];
+        for (coordinate, limbs) in point.chunks_exact_mut(32).zip(limbs.chunks_exact(64)) {
+            // * Low limb below 2^136 and high limb below 2^120, so x_0 + x_1 * 2^136 fits
+            let (lo, hi) = limbs.split_at(32);
+            if lo[..15].iter().chain(&hi[..17]).any(|&b| b != 0) {
+                return None;
+            }
+            coordinate[..15].copy_from_slice(&hi[17..]);
+            coordinate[15..].copy_from_slice(&lo[15..]);
+            if *coordinate >= groth16::BASE_FIELD_MODULUS[..] {
+                return None;
+            }
+        }
+        Some(point)
+    }
+}
+
+/// * Verifier state in the session's scratch area, one scalar per 32-byte slot
+struct Scratch<'a>(&'a mut [u8]);
+
+impl Scratch<'_> {
+    fn get(&self, slot: usize) -> Fr {
+        Fr::from_be_bytes_reduced(self.0[slot * 32..(slot + 1) * 32].try_into().unwrap())
+    }
+
+    fn set(&mut self, slot: usize, value: Fr) {
+        self.0[slot * 32..(slot + 1) * 32].copy_from_slice(&value.to_be_bytes());
+    }
+
+    fn point(&self, slot: usize) -> [u8; 64] {
+        self.0[slot * 32..(slot + 2) * 32].try_into().unwrap()
+    }
+
+    fn set_point(&mut self, slot: usize, point: &[u8; 64]) {
+        self.0[slot * 32..(slot + 2) * 32].copy_from_slice(point);
+    }
+}
+
+/// * The proof must be about the session's nullifier and domain
+fn bind_public_inputs(session: &VerificationSession, proof: &Proof) -> Option<StepOutcome> {
+    let public_inputs: Vec<[u8; 32]> = proof
+        .public_inputs
+        .chunks_exact(32)
+        .map(|input| input.try_into().unwrap())
+        .collect();
+
+    let bound = public_inputs.len() >= 2
+        && public_inputs.iter().all(groth16::is_scalar)
+        && public_inputs[0] == session.nullifier
+        && public_inputs[1] == groth16::domain_input(&session.domain);
+
+    bound.then_some(StepOutcome::Continue)
+}
+
+/// * Next challenge: keccak of the previous one and the new transcript data, reduced mod r
+fn challenge(previous: Fr, data: &[u8]) -> Fr {
+    Fr::from_be_bytes_reduced(&hashv(&[&previous.to_be_bytes(), data]).to_bytes())
+}
+
+fn be_u64(value: u64) -> [u8; 32] {
+    let mut bytes = [0u8; 32];
+    bytes[24..].copy_from_slice(&value.to_be_bytes());
+    bytes
+}
+
+/// * Every Fiat-Shamir challenge, in bb's transcript order, plus the public input delta
+fn transcript(vk: &HonkVerifyingKey, proof: &Proof, scratch: &mut Scratch) -> Option<StepOutcome> {
+    let circuit_size = 1u64 << vk.log_circuit_size;
+    let round0 = hashv(&[
+        &be_u64(circuit_size),
+        &be_u64(u64::from(vk.public_inputs_size)),
+        &be_u64(u64::from(vk.pub_inputs_offset)),
+        proof.public_inputs,
+        proof.words(PAIRING_POINTS, PAIRING_POINT_OBJECT_LENGTH),
+        proof.words(W1, 12), // * w1, w2, w3
+    ]);
+    let previous = Fr::from_be_bytes_reduced(&round0.to_bytes());
+    let (eta, eta_two) = previous.split();
+    let previous = challenge(previous, &[]);
+    scratch.set(slot::ETA, eta);
+    scratch.set(slot::ETA_TWO, eta_two);
+    scratch.set(slot::ETA_THREE, previous.split().0);
+
+    // * lookup read counts, lookup read tags, w4
+    let previous = challenge(previous, proof.words(LOOKUP_READ_COUNTS, 12));
+    let (beta, gamma) = previous.split();
+    scratch.set(slot::BETA, beta);
+    scratch.set(slot::GAMMA, gamma);
+
+    // * lookup inverses, z_perm
+    let mut previous = challenge(previous, proof.words(LOOKUP_INVERSES, 8));
+    for pair in 0..NUMBER_OF_ALPHAS.div_ceil(2) {
+        if pair > 0 {
+            previous = challenge(previous, &[]);
+        }
+        let (lo, hi) = previous.split();
+        scratch.set(slot::ALPHAS + 2 * pair, lo);
+        if 2 * pair + 1 < NUMBER_OF_ALPHAS {
+            scratch.set(slot::ALPHAS + 2 * pair + 1, hi);
+        }
+    }
+
+    for round in 0..CONST_PROOF_SIZE_LOG_N {
+        previous = challenge(previous, &[]);
+        scratch.set(slot::GATE_CHALLENGES + round, previous.split().0);
+    }
+
+    for round in 0..CONST_PROOF_SIZE_LOG_N {
+        let univariate = proof.words(
+            SUMCHECK_UNIVARIATES + round * BATCHED_RELATION_PARTIAL_LENGTH,
+            BATCHED_RELATION_PARTIAL_LENGTH,
+        );
+        previous = challenge(previous, univariate);
+        scratch.set(slot::SUMCHECK_CHALLENGES + round, previous.split().0);
+    }
+
+    previous = challenge(
+        previous,
+        proof.words(SUMCHECK_EVALUATIONS, NUMBER_OF_ENTITIES),
+    );
+    scratch.set(slot::RHO, previous.split().0);
+
+    previous = challenge(
+        previous,
+        proof.words(GEMINI_FOLD_COMMS, (CONST_PROOF_SIZE_LOG_N - 1) * 4),
+    );
+    scratch.set(slot::GEMINI_R, previous.split().0);
+
+    previous = challenge(
+        previous,
+        proof.words(GEMINI_A_EVALUATIONS, CONST_PROOF_SIZE_LOG_N),
+    );
+    scratch.set(slot::SHPLONK_NU, previous.split().0);
+
+    previous = challenge(previous, proof.words(SHPLONK_Q, 4));
+    scratch.set(slot::SHPLONK_Z, previous.split().0);
+
+    // * Public input delta: the permutation argument's correction for the public inputs
+    // * (the circuit's own, then the pairing point object)
+    let n = Fr::from_u64(circuit_size);
+    let offset = Fr::from_u64(u64::from(vk.pub_inputs_offset));
+    let mut numerator_acc = gamma + beta * (n + offset);
+    let mut denominator_acc = gamma - beta * (offset + Fr::ONE);
+    let (mut numerator, mut denominator) = (Fr::ONE, Fr::ONE);
+    let inputs = proof.public_inputs.chunks_exact(32).chain(
+        proof
+            .words(PAIRING_POINTS, PAIRING_POINT_OBJECT_LENGTH)
+            .chunks_exact(32),
+    );
+    for input in inputs {
+        let input = Fr::from_be_bytes(input.try_into().unwrap())?;
+        numerator = numerator * (numerator_acc + input);
+        denominator = denominator * (denominator_acc + input);
+        numerator_acc = numerator_acc + beta;
+        denominator_acc = denominator_acc - beta;
+    }
+    scratch.set(
+        slot::PUBLIC_INPUTS_DELTA,
+        numerator * denominator.inverse()?,
+    );
+
+    scratch.set(slot::ROUND_TARGET, Fr::ZERO);
+    scratch.set(slot::POW_PARTIAL, Fr::ONE);
+    Some(StepOutcome::Continue)
+}
+
+/// * Sumcheck round: u(0) + u(1) must hit the running target, which moves to u(challenge)
+fn sumcheck_round(round: usize, proof: &Proof, scratch: &mut Scratch) -> Option<StepOutcome> {
+    let univariate = proof.frs(
+        SUMCHECK_UNIVARIATES + round * BATCHED_RELATION_PARTIAL_LENGTH,
+        BATCHED_RELATION_PARTIAL_LENGTH,
+    )?;
+    if univariate[0] + univariate[1] != scratch.get(slot::ROUND_TARGET) {
+        return None;
+    }
+
+    let challenge = scratch.get(slot::SUMCHECK_CHALLENGES + round);
+    scratch.set(slot::ROUND_TARGET, next_target(&univariate, challenge)?);
+
+    // * Partial evaluation of the pow polynomial at the challenges so far
+    let gate_challenge = scratch.get(slot::GATE_CHALLENGES + round);
+    let pow = scratch.get(slot::POW_PARTIAL) * (Fr::ONE + challenge * (gate_challenge - Fr::ONE));
+    scratch.set(slot::POW_PARTIAL, pow);
+    Some(StepOutcome::Continue)
+}
+
+/// * Barycentric evaluation at `challenge` of the univariate given on 0..8; None if the
+/// * challenge lands on the domain
+fn next_target(univariate: &[Fr], challenge: Fr) -> Option<Fr> {
+    // * prod_{j != i} (i - j) for i in 0..8
+    const DENOMINATORS: [i64; BATCHED_RELATION_PARTIAL_LENGTH] =
+        [-5040, 720, -240, 144, -144, 240, -720, 5040];
+
+    let mut numerator = Fr::ONE;
+    let mut inverses = Vec::with_capacity(BATCHED_RELATION_PARTIAL_LENGTH);
+    for (i, denominator) in DENOMINATORS.iter().enumerate() {
+        let point = challenge - Fr::from_u64(i as u64);
+        numerator = numerator * point;
+        let denominator = Fr::from_u64(denominator.unsigned_abs());
+        let denominator = if DENOMINATORS[i] < 0 {
+            -denominator
+        } else {
+            denominator
+        };
+        inverses.push(denominator * point);
+    }
+    if !Fr::batch_invert(&mut inverses) {
+        return None;
+    }
+
+    let sum = univariate
+        .iter()
+        .zip(&inverses)
+        .fold(Fr::ZERO, |sum, (value, inverse)| sum + *value * *inverse);
+    Some(sum * numerator)
+}
+
+/// * Challenges the relations are evaluated with
+struct RelationParameters {
+    eta: Fr,
+    eta_two: Fr,
+    eta_three: Fr,
+    beta: Fr,
+    gamma: Fr,
+    public_inputs_delta: Fr,
+}
+
+/// * The batched relation at the sumcheck point must equal the final round target
+fn relations(proof: &Proof, scratch: &mut Scratch) -> Option<StepOutcome> {
+    let evaluations = proof.frs(SUMCHECK_EVALUATIONS, NUMBER_OF_ENTITIES)?;
+    let parameters = RelationParameters {
+        eta: scratch.get(slot::ETA),
+        eta_two: scratch.get(slot::ETA_TWO),
+        eta_three: scratch.get(slot::ETA_THREE),
+        beta: scratch.get(slot::BETA),
+        gamma: scratch.get(slot::GAMMA),
+        public_inputs_delta: scratch.get(slot::PUBLIC_INPUTS_DELTA),
+    };
+    let subrelations =
+        evaluate_subrelations(&evaluations, &parameters, scratch.get(slot::POW_PARTIAL));
+
+    // * evals[0] + sum(evals[i] * alphas[i - 1])
+    let batched = subrelations
+        .iter()
+        .skip(1)
+        .enumerate()
+        .fold(subrelations[0], |acc, (i, evaluation)| {
+            acc + *evaluation * scratch.get(slot::ALPHAS + i)
+        });
+
+    (batched == scratch.get(slot::ROUND_TARGET)).then_some(StepOutcome::Continue)
+}
+
+/// * bb's Ultra relations (arithmetic, permutation, log-derivative lookup, delta range,
+/// * elliptic, auxiliary, Poseidon2 external and internal), each scaled by `pow` except the
+/// * lookup's linearly dependent second subrelation
+fn evaluate_subrelations(p: &[Fr], rp: &RelationParameters, pow: Fr) -> Vec<Fr> {
+    use entity::*;
+
+    let mut evals = vec![Fr::ZERO; NUMBER_OF_SUBRELATIONS];
+    let c = Fr::from_u64;
+    let neg_half = -c(2).inverse().unwrap();
+
+    // * Arithmetic (0, 1)
+    let q_arith = p[Q_ARITH];
+    let accum = (q_arith - c(3)) * (p[Q_M] * p[W_R] * p[W_L]) * neg_half
+        + p[Q_L] * p[W_L]
+        + p[Q_R] * p[W_R]
+        + p[Q_O] * p[W_O]
+        + p[Q_4] * p[W_4]
+        + p[Q_C]
+        + (q_arith - Fr::ONE) * p[W_4_SHIFT];
+    evals[0] = accum * q_arith * pow;
+    evals[1] = (p[W_L] + p[W_4] - p[W_L_SHIFT] + p[Q_M])
+        * (q_arith - c(2))
+        * (q_arith - Fr::ONE)
+        * q_arith
+        * pow;
+
+    // * Permutation (2, 3)
+    let wires = [p[W_L], p[W_R], p[W_O], p[W_4]];
+    let (mut numerator, mut denominator) = (Fr::ONE, Fr::ONE);
+    for (i, wire) in wires.iter().enumerate() {
+        numerator = numerator * (*wire + p[ID_1 + i] * rp.beta + rp.gamma);
+        denominator = denominator * (*wire + p[SIGMA_1 + i] * rp.beta + rp.gamma);
+    }
+    evals[2] = ((p[Z_PERM] + p[LAGRANGE_FIRST]) * numerator
+        - (p[Z_PERM_SHIFT] + p[LAGRANGE_LAST] * rp.public_inputs_delta) * denominator)
+        * pow;
+    evals[3] = p[LAGRANGE_LAST] * p[Z_PERM_SHIFT] * pow;
+
+    // * Log-derivative lookup (4, 5)
+    let write_term = p[TABLE_1]
+        + rp.gamma
+        + p[TABLE_2] * rp.eta
+        + p[TABLE_3] * rp.eta_two
+        + p[TABLE_4] * rp.eta_three;
+    let derived_entry_1 = p[W_L] + rp.gamma + p[Q_R] * p[W_L_SHIFT];
+    let derived_entry_2 = p[W_R] + p[Q_M] * p[W_R_SHIFT];
+    let derived_entry_3 = p[W_O] + p[Q_C] * p[W_O_SHIFT];
+    let read_term = derived_entry_1
+        + derived_entry_2 * rp.eta
+        + derived_entry_3 * rp.eta_two
+        + p[Q_O] * rp.eta_three;
+    let read_inverse = p[LOOKUP_INVERSES] * write_term;
+    let write_inverse = p[LOOKUP_INVERSES] * read_term;
+    let inverse_exists_xor = p[LOOKUP_READ_TAGS] + p[Q_LOOKUP] - p[LOOKUP_READ_TAGS] * p[Q_LOOKUP];
+    evals[4] = (read_term * write_term * p[LOOKUP_INVERSES] - inverse_exists_xor) * pow;
+    evals[5] = p[Q_LOOKUP] * read_inverse - p[LOOKUP_READ_COUNTS] * write_inverse;
+
+    // * Delta range (6 - 9): consecutive wires differ by 0 to 3
+    let deltas = [
+        p[W_R] - p[W_L],
+        p[W_O] - p[W_R],
+        p[W_4] - p[W_O],
+        p[W_L_SHIFT] - p[W_4],
+    ];
+    for (i, delta) in deltas.iter().enumerate() {
+        evals[6 + i] =
+            *delta * (*delta - Fr::ONE) * (*delta - c(2)) * (*delta - c(3)) * p[Q_RANGE] * pow;
+    }
+
+    // * Elliptic (10, 11): Grumpkin addition or doubling
+    let (x_1, y_1) = (p[W_R], p[W_O]);
+    let (x_2, y_2) = (p[W_L_SHIFT], p[W_4_SHIFT]);
+    let (x_3, y_3) = (p[W_R_SHIFT], p[W_O_SHIFT]);
+    let q_sign = p[Q_L];
+    let q_is_double = p[Q_M];
+    let q_elliptic = p[Q_ELLIPTIC] * pow;
+    let x_diff = x_2 - x_1;
+    let y1_sqr = y_1 * y_1;
+    let y1y2 = y_1 * y_2 * q_sign;
+    let x_add_identity = (x_3 + x_2 + x_1) * x_diff * x_diff - y_2 * y_2 - y1_sqr + y1y2 + y1y2;
+    let y_add_identity = (y_1 + y_3) * x_diff + (x_3 - x_1) * (y_2 * q_sign - y_1);
+    // * x1^3 = y1^2 - b with b = -17
+    let x_pow_4 = (y1_sqr + c(17)) * x_1;
+    let x_double_identity = (x_3 + x_1 + x_1) * (y1_sqr * c(4)) - x_pow_4 * c(9);
+    let y_double_identity = (x_1 + x_1 + x_1) * x_1 * (x_1 - x_3) - (y_1 + y_1) * (y_1 + y_3);
+    let not_double = Fr::ONE - q_is_double;
+    evals[10] =
+        x_add_identity * q_elliptic * not_double + x_double_identity * q_elliptic * q_is_double;
+    evals[11] =
+        y_add_identity * q_elliptic * not_double + y_double_identity * q_elliptic * q_is_double;
+
+    // * Auxiliary (12 - 17): non-native field arithmetic, limb accumulation, ROM and RAM
+    let limb_size = c(1 << 34) * c(1 << 34);
+    let sublimb_shift = c(1 << 14);
+    let mut limb_subproduct = p[W_L] * p[W_R_SHIFT] + p[W_L_SHIFT] * p[W_R];
+    let non_native_field_gate_2 = ((p[W_L] * p[W_4] + p[W_R] * p[W_O] - p[W_O_SHIFT]) * limb_size
+        - p[W_4_SHIFT]
+        + limb_subproduct)
+        * p[Q_4];
+    limb_subproduct = limb_subproduct * limb_size + p[W_L_SHIFT] * p[W_R_SHIFT];
+    let non_native_field_gate_1 = (limb_subproduct - (p[W_O] + p[W_4])) * p[Q_O];
+    let non_native_field_gate_3 =
+        (limb_subproduct + p[W_4] - (p[W_O_SHIFT] + p[W_4_SHIFT])) * p[Q_M];
+    let non_native_field_identity =
+        (non_native_field_gate_1 + non_native_field_gate_2 + non_native_field_gate_3) * p[Q_R];
+
+    let limb_accumulator_1 = ((((p[W_R_SHIFT] * sublimb_shift + p[W_L_SHIFT]) * sublimb_shift
+        + p[W_O])
+        * sublimb_shift
+        + p[W_R])
+        * sublimb_shift
+        + p[W_L]
+        - p[W_4])
+        * p[Q_4];
+    let limb_accumulator_2 = ((((p[W_O_SHIFT] * sublimb_shift + p[W_R_SHIFT]) * sublimb_shift
+        + p[W_L_SHIFT])
+        * sublimb_shift
+        + p[W_4])
+        * sublimb_shift
+        + p[W_O]
+        - p[W_4_SHIFT])
+        * p[Q_M];
+    let limb_accumulator_identity = (limb_accumulator_1 + limb_accumulator_2) * p[Q_O];
+
+    let partial_record_check =
+        p[W_O] * rp.eta_three + p[W_R] * rp.eta_two + p[W_L] * rp.eta + p[Q_C];
+    let memory_record_check = partial_record_check - p[W_4];
+
+    // * ROM consistency
+    let index_delta = p[W_L_SHIFT] - p[W_L];
+    let record_delta = p[W_4_SHIFT] - p[W_4];
+    let index_is_monotonically_increasing = index_delta * index_delta - index_delta;
+    let adjacent_values_match_if_adjacent_indices_match = (Fr::ONE - index_delta) * record_delta;
+    let q_aux = p[Q_AUX] * pow;
+    let q_one_by_two = p[Q_L] * p[Q_R];
+    evals[13] = adjacent_values_match_if_adjacent_indices_match * q_one_by_two * q_aux;
+    evals[14] = index_is_monotonically_increasing * q_one_by_two * q_aux;
+    let rom_consistency_check_identity = memory_record_check * q_one_by_two;
+
+    // * RAM consistency
+    let access_type = p[W_4] - partial_record_check;
+    let access_check = access_type * access_type - access_type;
+    let next_gate_access_type = p[W_4_SHIFT]
+        - (p[W_O_SHIFT] * rp.eta_three + p[W_R_SHIFT] * rp.eta_two + p[W_L_SHIFT] * rp.eta);
+    let value_delta = p[W_O_SHIFT] - p[W_O];
+    let adjacent_values_match_if_adjacent_indices_match_and_next_access_is_a_read_operation =
+        (Fr::ONE - index_delta) * value_delta * (Fr::ONE - next_gate_access_type);
+    let next_gate_access_type_is_boolean =
+        next_gate_access_type * next_gate_access_type - next_gate_access_type;
+    evals[15] = adjacent_values_match_if_adjacent_indices_match_and_next_access_is_a_read_operation
+        * q_arith
+        * q_aux;
+    evals[16] = index_is_monotonically_increasing * q_arith * q_aux;
+    evals[17] = next_gate_access_type_is_boolean * q_arith * q_aux;
+    let ram_consistency_check_identity = access_check * q_arith;
+
+    // * RAM timestamps
+    let timestamp_delta = p[W_R_SHIFT] - p[W_R];
+    let ram_timestamp_check_identity = (Fr::ONE - index_delta) * timestamp_delta - p[W_O];
+
+    let memory_identity = rom_consistency_check_identity
+        + ram_timestamp_check_identity * (p[Q_4] * p[Q_L])
+        + memory_record_check * (p[Q_M] * p[Q_L])
+        + ram_consistency_check_identity;
+    evals[12] = (memory_identity + non_native_field_identity + limb_accumulator_identity) * q_aux;
+
+    // * Poseidon2 external round (18 - 21)
+    let s = [
+        p[W_L] + p[Q_L],
+        p[W_R] + p[Q_R],
+        p[W_O] + p[Q_O],
+        p[W_4] + p[Q_4],
+    ];
+    let u: Vec<Fr> = s.iter().map(|s| s.square().square() * *s).collect();
+    let t0 = u[0] + u[1];
+    let t1 = u[2] + u[3];
+    let t2 = u[1] + u[1] + t1;
+    let t3 = u[3] + u[3] + t0;
+    let v4 = t1 + t1 + t1 + t1 + t3;
+    let v2 = t0 + t0 + t0 + t0 + t2;
+    let v1 = t3 + v2;
+    let v3 = t2 + v4;
+    let q_pos_by_scaling = p[Q_POSEIDON2_EXTERNAL] * pow;
+    let shifts = [p[W_L_SHIFT], p[W_R_SHIFT], p[W_O_SHIFT], p[W_4_SHIFT]];
+    for (i, v) in [v1, v2, v3, v4].iter().enumerate() {
+        evals[18 + i] = q_pos_by_scaling * (*v - shifts[i]);
+    }
+
+    // * Poseidon2 internal round (22 - 25)
+    let u1 = s[0].square().square() * s[0];
+    let u = [u1, p[W_R], p[W_O], p[W_4]];
+    let u_sum = u[0] + u[1] + u[2] + u[3];
+    let q_pos_by_scaling = p[Q_POSEIDON2_INTERNAL] * pow;
+    for i in 0..4 {
+        let diagonal = Fr::from_be_bytes(&INTERNAL_MATRIX_DIAGONAL[i]).unwrap();
+        evals[22 + i] = q_pos_by_scaling * (u[i] * diagonal + u_sum - shifts[i]);
+    }
+
+    evals
+}
+
+/// * Shplemini: reduce the Gemini folds and every claimed evaluation to scalars for one MSM
+fn shplemini(log_n: usize, proof: &Proof, scratch: &mut Scratch) -> Option<StepOutcome> {
+    let evaluations = proof.frs(SUMCHECK_EVALUATIONS, NUMBER_OF_ENTITIES)?;
+    let gemini_a = proof.frs(GEMINI_A_EVALUATIONS, CONST_PROOF_SIZE_LOG_N)?;
+    let rho = scratch.get(slot::RHO);
+    let r = scratch.get(slot::GEMINI_R);
+    let nu = scratch.get(slot::SHPLONK_NU);
+    let z = scratch.get(slot::SHPLONK_Z);
+    let u = |i: usize| scratch.get(slot::SUMCHECK_CHALLENGES + i);
+
+    // * r^(2^i)
+    let mut powers = Vec::with_capacity(log_n);
+    powers.push(r);
+    for i in 1..log_n {
+        powers.push(powers[i - 1].square());
+    }
+
+    // * One batch inversion: z -/+ r^(2^i) for every used round, r, and the fold denominators
+    let mut inverses = Vec::with_capacity(3 * log_n + 1);
+    for power in &powers {
+        inverses.push(z - *power);
+        inverses.push(z + *power);
+    }
+    inverses.push(r);
+    for (i, power) in powers.iter().enumerate().take(log_n) {
+        inverses.push(*power * (Fr::ONE - u(i)) + u(i));
+    }
+    if !Fr::batch_invert(&mut inverses) {
+        return None;
+    }
+    let pos_inverse = |i: usize| inverses[2 * i];
+    let neg_inverse = |i: usize| inverses[2 * i + 1];
+    let r_inverse = inverses[2 * log_n];
+    let fold_inverse = |i: usize| inverses[2 * log_n + 1 + i];
+
+    let unshifted_scalar = pos_inverse(0) + nu * neg_inverse(0);
+    let shifted_scalar = r_inverse * (pos_inverse(0) - nu * neg_inverse(0));
+
+    let mut batching_challenge = Fr::ONE;
+    let mut batched_evaluation = Fr::ZERO;
+    for evaluation in &evaluations {
+        batched_evaluation = batched_evaluation + *evaluation * batching_challenge;
+        batching_challenge = batching_challenge * rho;
+    }
+
+    // * Fold the batched evaluation back down to A_i(r^(2^i)) for each Gemini round
+    let mut fold_pos = vec![Fr::ZERO; log_n];
+    let mut acc = batched_evaluation;
+    for i in (0..log_n).rev() {
+        let (power, u) = (powers[i], u(i));
+        acc = (power * acc * Fr::from_u64(2) - gemini_a[i] * (power * (Fr::ONE - u) - u))
+            * fold_inverse(i);
+        fold_pos[i] = acc;
+    }
+
+    let mut constant_term = fold_pos[0] * pos_inverse(0) + gemini_a[0] * nu * neg_inverse(0);
+    let mut batching_challenge = nu.square();
+    for i in 0..CONST_PROOF_SIZE_LOG_N - 1 {
+        // * Rounds past the circuit size are padding and carry no weight
+        let scalar = if i + 1 < log_n {
+            let scaling_pos = batching_challenge * pos_inverse(i + 1);
+            let scaling_neg = batching_challenge * nu * neg_inverse(i + 1);
+            constant_term =
+                constant_term + scaling_neg * gemini_a[i + 1] + scaling_pos * fold_pos[i + 1];
+            batching_challenge = batching_challenge * nu * nu;
+            -(scaling_neg + scaling_pos)
+        } else {
+            Fr::ZERO
+        };
+        scratch.set(slot::GEMINI_SCALARS + i, scalar);
+    }
+
+    scratch.set(slot::UNSHIFTED_SCALAR, unshifted_scalar);
+    scratch.set(slot::SHIFTED_SCALAR, shifted_scalar);
+    scratch.set(slot::CONSTANT_TERM, constant_term);
+    scratch.set(slot::BATCHING_CHALLENGE, Fr::ONE);
+    Some(StepOutcome::Continue)
+}
+
+/// * Commitment behind sumcheck entity `index`: the key's 27, then the proof's witness
+/// * commitments, then the shifted ones (which reuse w1 - w4 and z_perm)
+fn entity_commitment(index: usize, vk: &HonkVerifyingKey, proof: &Proof) -> Option<[u8; 64]> {
+    const WITNESSES: [usize; 8] = [
+        W1,
+        W2,
+        W3,
+        W4,
+        Z_PERM,
+        LOOKUP_INVERSES,
+        LOOKUP_READ_COUNTS,
+        LOOKUP_READ_TAGS,
+    ];
+    match index {
+        _ if index < HonkVerifyingKey::COMMITMENTS => Some(vk.commitments[index]),
+        _ if index < NUMBER_UNSHIFTED => {
+            proof.point(WITNESSES[index - HonkVerifyingKey::COMMITMENTS])
+        }
+        _ => proof.point(WITNESSES[index - NUMBER_UNSHIFTED]),
+    }
+}
+
+/// * MSM term `term` as (scalar, point); advances the running rho power for entity terms
+fn msm_term(
+    term: usize,
+    vk: &HonkVerifyingKey,
+    proof: &Proof,
+    scratch: &mut Scratch,
+) -> Option<(Fr, [u8; 64])> {
+    const ENTITIES_END: usize = 1 + NUMBER_OF_ENTITIES;
+    const FOLDS_END: usize = ENTITIES_END + CONST_PROOF_SIZE_LOG_N - 1;
+    const G1_GENERATOR: [u8; 64] = {
+        let mut point = [0u8; 64];
+        point[31] = 1;
+        point[63] = 2;
+        point
+    };
+
+    Some(match term {
+        0 => (Fr::ONE, proof.point(SHPLONK_Q)?),
+        _ if term < ENTITIES_END => {
+            let index = term - 1;
+            let scalar = if index < NUMBER_UNSHIFTED {
+                scratch.get(slot::UNSHIFTED_SCALAR)
+            } else {
+                scratch.get(slot::SHIFTED_SCALAR)
+            };
+            let batching_challenge = scratch.get(slot::BATCHING_CHALLENGE);
+            scratch.set(
+                slot::BATCHING_CHALLENGE,
+                batching_challenge * scratch.get(slot::RHO),
+            );
+            (
+                -scalar * batching_challenge,
+                entity_commitment(index, vk, proof)?,
+            )
+        }
+        _ if term < FOLDS_END => {
+            let round = term - ENTITIES_END;
+            (
+                scratch.get(slot::GEMINI_SCALARS + round),
+                proof.point(GEMINI_FOLD_COMMS + 4 * round)?,
+            )
+        }
+        _ if term == FOLDS_END => (scratch.get(slot::CONSTANT_TERM), G1_GENERATOR),
+        _ => (scratch.get(slot::SHPLONK_Z), proof.point(KZG_QUOTIENT)?),
+    })
+}
+
+/// * Add the next MSM_TERMS_PER_STEP terms into the accumulator
+fn msm(
+    chunk: usize,
+    log_n: usize,
+    vk: &HonkVerifyingKey,
+    proof: &Proof,
+    scratch: &mut Scratch,
+) -> Option<StepOutcome> {
+    let first_padding_fold = 1 + NUMBER_OF_ENTITIES + log_n.saturating_sub(1);
+    let last_fold = NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N - 1;
+    let mut accumulator = scratch.point(slot::ACCUMULATOR);
+    let end = MSM_TERMS.min((chunk + 1) * MSM_TERMS_PER_STEP);
+    for term in chunk * MSM_TERMS_PER_STEP..end {
+        // * Padding folds have zero scalars, so their commitments aren't even parsed
+        if (first_padding_fold..=last_fold).contains(&term) {
+            continue;
+        }
+        let (scalar, point) = msm_term(term, vk, proof, scratch)?;
+        if scalar.is_zero() {
+            continue;
+        }
+        let product =
+            alt_bn128_multiplication(&[point.as_slice(), &scalar.to_be_bytes()].concat()).ok()?;
+        let sum = alt_bn128_addition(&[accumulator.as_slice(), &product].concat()).ok()?;
+        accumulator = sum.try_into().ok()?;
+    }
+    scratch.set_point(slot::ACCUMULATOR, &accumulator);
+    Some(StepOutcome::Continue)
+}
+
+/// * e(P_0, [1]_2) * e(-W, [x]_2) == 1 with P_0 the batched commitment and W the KZG quotient
+fn pairing(proof: &Proof, scratch: &Scratch, srs_g2_x: &[[u8; 32]; 4]) -> Option<StepOutcome> {
+    let quotient = groth16::negate_g1(&proof.point(KZG_QUOTIENT)?).ok()?;
+    let input = [
+        scratch.point(slot::ACCUMULATOR).as_slice(),
+        &G2_GENERATOR.concat(),
+        &quotient,
+        &srs_g2_x.concat(),
+    ]
+    .concat();
+
+    let result = alt_bn128_pairing(&input).ok()?;
+    Some(if result.as_slice() == groth16::PAIRING_ONE {
+        StepOutcome::Accept
+    } else {
+        StepOutcome::Reject
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::verification_session::VerificationStatus;
+    use ark_bn254::{G1Affine, G1Projective, G2Affine};
+    use ark_ec::{AffineRepr, CurveGroup};
+    use ark_ff::{BigInteger, PrimeField};
+
+    const LOG_N: u8 = 3;
+
+    fn nullifier() -> [u8; 32] {
+        let mut nullifier = [7u8; 32];
+        nullifier[0] = 0;
+        nullifier
+    }
+
+    fn domain() -> [u8; 32] {
+        let mut domain = [0u8; 32];
+        domain[..11].copy_from_slice(b"example.com");
+        domain
+    }
+
+    fn session() -> VerificationSession {
+        VerificationSession {
+            authority: Pubkey::default(),
+            nullifier: nullifier(),
+            domain: domain(),
+            circuit_id: 1,
+            proof_hash: [0; 32],
+            proof_len: (2 * 32 + PROOF_SIZE) as u32,
+            public_inputs_count: 2,
+            bytes_received: (2 * 32 + PROOF_SIZE) as u32,
+            status: VerificationStatus::Verifying,
+            step: 0,
+            opened_at: 0,
+            bump: 255,
+        }
+    }
+
+    // * Every commitment the point at infinity, so only the scalars matter
+    fn vk() -> HonkVerifyingKey {
+        HonkVerifyingKey {
+            circuit_id: 1,
+            log_circuit_size: LOG_N,
+            public_inputs_size: (2 + PAIRING_POINT_OBJECT_LENGTH) as u32,
+            pub_inputs_offset: 1,
+            commitments: [[0; 64]; HonkVerifyingKey::COMMITMENTS],
+            written: (1 << HonkVerifyingKey::COMMITMENTS) - 1,
+            bump: 255,
+        }
+    }
+
+    /// * Public inputs bound to the session, followed by an all-zero proof
+    fn upload() -> Vec<u8> {
+        [
+            nullifier().as_slice(),
+            &groth16::domain_input(&domain()),
+            &[0u8; PROOF_SIZE],
+        ]
+        .concat()
+    }
+
+    fn set_field(upload: &mut [u8], field: usize, value: Fr) {
+        let start = 64 + field * 32;
+        upload[start..start + 32].copy_from_slice(&value.to_be_bytes());
+    }
+
+    fn set_point(upload: &mut [u8], field: usize, point: G1Affine) {
+        let limbs = |value: ark_bn254::Fq| {
+            let bytes = value.into_bigint().to_bytes_be();
+            let mut lo = [0u8; 32];
+            let mut hi = [0u8; 32];
+            lo[15..].copy_from_slice(&bytes[15..]);
+            hi[17..].copy_from_slice(&bytes[..15]);
+            [lo, hi]
+        };
+        let start = 64 + field * 32;
+        let words = [limbs(point.x), limbs(point.y)].concat().concat();
+        upload[start..start + 128].copy_from_slice(&words);
+    }
+
+    /// * Run steps from `from` until the proof is accepted or rejected (or `until` is hit)
+    fn run(
+        session: &VerificationSession,
+        vk: &HonkVerifyingKey,
+        upload: &[u8],
+        scratch: &mut [u8],
+        until: u32,
+    ) -> (StepOutcome, u32) {
+        let mut session = VerificationSession { ..*session };
+        while session.step < until {
+            match step(&session, vk, upload, scratch).unwrap() {
+                StepOutcome::Continue => session.step += 1,
+                outcome => return (outcome, session.step),
+            }
+        }
+        (StepOutcome::Continue, session.step)
+    }
+
+    fn read(scratch: &[u8], slot: usize) -> Fr {
+        Scratch(&mut scratch.to_vec()).get(slot)
+    }
+
+    // * Test: Public inputs must carry the session's nullifier and domain
+    #[test]
+    fn test_bind_public_inputs() {
+        let (session, vk) = (session(), vk());
+        let mut scratch = vec![0u8; VerificationSession::SCRATCH_SIZE];
+        let upload = upload();
+        assert_eq!(
+            step(&session, &vk, &upload, &mut scratch).unwrap(),
+            StepOutcome::Continue
+        );
+
+        let mut other_domain = upload.clone();
+        other_domain[63] ^= 1;
+        assert_eq!(
+            step(&session, &vk, &other_domain, &mut scratch).unwrap(),
+            StepOutcome::Reject
+        );
+
+        // * A truncated upload or a key for another circuit is an error, not a rejection
+        assert!(step(&session, &vk, &upload[..upload.len() - 1], &mut scratch).is_err());
+        let other_vk = HonkVerifyingKey {
+            circuit_id: 2,
+            ..vk.clone()
+        };
+        assert!(step(&session, &other_vk, &upload, &mut scratch).is_err());
+        let incomplete_vk = HonkVerifyingKey {
+            written: 1,
+            ..vk.clone()
+        };
+        assert!(step(&session, &incomplete_vk, &upload, &mut scratch).is_err());
+    }
+
+    // * Test: The barycentric next target matches direct Lagrange interpolation
+    #[test]
+    fn test_next_target() {
+        // * f(x) = 3x^7 - 5x^3 + x + 9 sampled on 0..8
+        let f = |x: Fr| {
+            let x3 = x * x * x;
+            Fr::from_u64(3) * x3 * x3 * x - Fr::from_u64(5) * x3 + x + Fr::from_u64(9)
+        };
+        let univariate: Vec<Fr> = (0..8).map(|i| f(Fr::from_u64(i))).collect();
+        let challenge = Fr::from_be_bytes_reduced(&[0x5a; 32]);
+        assert_eq!(next_target(&univariate, challenge), Some(f(challenge)));
+
+        // * A challenge on the domain has no barycentric form
+        assert_eq!(next_target(&univariate, Fr::from_u64(3)), None);
+    }
+
+    // * Test: The all-zero proof against an all-infinity key runs every phase and verifies;
+    // * any change to a checked value rejects it at the right step
+    #[test]
+    fn test_trivial_proof_runs_every_phase() {
+        let (session, vk) = (session(), vk());
+        let total = total_steps(&vk);
+        let mut scratch = vec![0u8; VerificationSession::SCRATCH_SIZE];
+        assert_eq!(
+            run(&session, &vk, &upload(), &mut scratch, u32::MAX),
+            (StepOutcome::Accept, total - 1)
+        );
+
+        // * Past the last step there is nothing left to run
+        let done = VerificationSession {
+            step: total,
+            ..session.clone()
+        };
+        assert!(step(&done, &vk, &upload(), &mut scratch).is_err());
+
+        // * Sumcheck round 1 no longer adds up
+        let mut tampered = upload();
+        set_field(&mut tampered, SUMCHECK_UNIVARIATES + 8, Fr::ONE);
+        let mut scratch = vec![0u8; VerificationSession::SCRATCH_SIZE];
+        assert_eq!(
+            run(&session, &vk, &tampered, &mut scratch, u32::MAX),
+            (StepOutcome::Reject, 3)
+        );
+
+        // * Non-canonical evaluation
+        let mut tampered = upload();
+        tampered[64 + SUMCHECK_EVALUATIONS * 32..][..32].fill(0xff);
+        let mut scratch = vec![0u8; VerificationSession::SCRATCH_SIZE];
+        assert_eq!(
+            run(&session, &vk, &tampered, &mut scratch, u32::MAX),
+            (StepOutcome::Reject, 2 + u32::from(LOG_N))
+        );
+
+        // * Commitment coordinate that overflows its limb
+        let mut tampered = upload();
+        tampered[64 + SHPLONK_Q * 32] = 1;
+        let mut scratch = vec![0u8; VerificationSession::SCRATCH_SIZE];
+        assert_eq!(
+            run(&session, &vk, &tampered, &mut scratch, u32::MAX),
+            (StepOutcome::Reject, 4 + u32::from(LOG_N))
+        );
+    }
+
+    // * Test: A nonzero sumcheck that adds up in every round and matches the arithmetic
+    // * relation passes sumcheck and relations, then fails the opening
+    #[test]
+    fn test_consistent_sumcheck_fails_pairing() {
+        let (session, vk) = (session(), vk());
+        let mut upload = upload();
+
+        // * Each round's u(0) + u(1) hits the previous target; later challenges only depend
+        // * on earlier rounds, so the transcript is rerun as the rounds are filled in
+        let mut target = Fr::ZERO;
+        for round in 0..usize::from(LOG_N) {
+            let base = SUMCHECK_UNIVARIATES + round * BATCHED_RELATION_PARTIAL_LENGTH;
+            let first = Fr::from_u64(11 + round as u64);
+            set_field(&mut upload, base, first);
+            set_field(&mut upload, base + 1, target - first);
+            for i in 2..BATCHED_RELATION_PARTIAL_LENGTH {
+                set_field(&mut upload, base + i, Fr::from_u64((round * 8 + i) as u64));
+            }
+
+            let mut scratch = vec![0u8; VerificationSession::SCRATCH_SIZE];
+            run(&session, &vk, &upload, &mut scratch, 2);
+            let univariate = Proof {
+                public_inputs: &upload[..64],
+                fields: &upload[64..],
+            }
+            .frs(base, BATCHED_RELATION_PARTIAL_LENGTH)
+            .unwrap();
+            let challenge = read(&scratch, slot::SUMCHECK_CHALLENGES + round);
+            target = next_target(&univariate, challenge).unwrap();
+        }
+
+        // * q_arith = 1 leaves q_c * pow as the only nonzero subrelation
+        let mut scratch = vec![0u8; VerificationSession::SCRATCH_SIZE];
+        let relations_step = 2 + u32::from(LOG_N);
+        run(&session, &vk, &upload, &mut scratch, relations_step);
+        let pow = read(&scratch, slot::POW_PARTIAL);
+        set_field(&mut upload, SUMCHECK_EVALUATIONS + entity::Q_ARITH, Fr::ONE);
+        set_field(
+            &mut upload,
+            SUMCHECK_EVALUATIONS + entity::Q_C,
+            target * pow.inverse().unwrap(),
+        );
+
+        let mut scratch = vec![0u8; VerificationSession::SCRATCH_SIZE];
+        assert_eq!(
+            run(&session, &vk, &upload, &mut scratch, relations_step + 1),
+            (StepOutcome::Continue, relations_step + 1)
+        );
+        let mut scratch = vec![0u8; VerificationSession::SCRATCH_SIZE];
+        assert_eq!(
+            run(&session, &vk, &upload, &mut scratch, u32::MAX),
+            (StepOutcome::Reject, total_steps(&vk) - 1)
+        );
+
+        // * Off by one in the final target
+        set_field(&mut upload, SUMCHECK_EVALUATIONS + entity::Q_C, Fr::ZERO);
+        let mut scratch = vec![0u8; VerificationSession::SCRATCH_SIZE];
+        assert_eq!(
+            run(&session, &vk, &upload, &mut scratch, u32::MAX),
+            (StepOutcome::Reject, relations_step)
+        );
+    }
+
+    // * Test: With a known SRS secret the KZG quotient can be solved for, and the batched
+    // * commitment built by the MSM steps then passes the pairing; any other quotient fails
+    #[test]
+    fn test_opening_with_known_srs() {
+        let (session, mut vk) = (session(), vk());
+        let mut upload = upload();
+        let generator = G1Affine::generator();
+        let fr = |value: u64| ark_bn254::Fr::from(value);
+
+        // * Commitments are known multiples of the generator
+        let mut logs = vec![ark_bn254::Fr::from(0u64); MSM_TERMS];
+        logs[0] = fr(3);
+        set_point(&mut upload, SHPLONK_Q, (generator * logs[0]).into_affine());
+        for (i, commitment) in vk.commitments.iter_mut().enumerate() {
+            logs[1 + i] = fr(100 + i as u64);
+            let point = (generator * logs[1 + i]).into_affine();
+            let bytes = [point.x.into_bigint(), point.y.into_bigint()]
+                .iter()
+                .flat_map(|c| c.to_bytes_be())
+                .collect::<Vec<u8>>();
+            commitment.copy_from_slice(&bytes);
+        }
+        let witnesses = [
+            W1,
+            W2,
+            W3,
+            W4,
+            Z_PERM,
+            LOOKUP_INVERSES,
+            LOOKUP_READ_COUNTS,
+            LOOKUP_READ_TAGS,
+        ];
+        for (i, field) in witnesses.iter().enumerate() {
+            let log = fr(200 + i as u64);
+            set_point(&mut upload, *field, (generator * log).into_affine());
+            logs[1 + HonkVerifyingKey::COMMITMENTS + i] = log;
+            if i < 5 {
+                logs[1 + NUMBER_UNSHIFTED + i] = log;
+            }
+        }
+        for round in 0..usize::from(LOG_N) - 1 {
+            let log = fr(300 + round as u64);
+            set_point(
+                &mut upload,
+                GEMINI_FOLD_COMMS + 4 * round,
+                (generator * log).into_affine(),
+            );
+            logs[1 + NUMBER_OF_ENTITIES + round] = log;
+        }
+        logs[1 + NUMBER_OF_ENTITIES + CONST_PROOF_SIZE_LOG_N - 1] = fr(1);
+        set_field(&mut upload, GEMINI_A_EVALUATIONS, Fr::from_u64(5));
+
+        // * Batched commitment without the quotient term: A = a * G
+        let msm_start = 4 + u32::from(LOG_N);
+        let mut scratch = vec![0u8; VerificationSession::SCRATCH_SIZE];
+        run(&session, &vk, &upload, &mut scratch, msm_start);
+        let mut term_scratch = scratch.clone();
+        let mut terms = Scratch(&mut term_scratch);
+        let proof = Proof {
+            public_inputs: &upload[..64],
+            fields: &upload[64..],
+        };
+        let mut a = ark_bn254::Fr::from(0u64);
+        for (term, log) in logs.iter().enumerate().take(MSM_TERMS - 1) {
+            let (scalar, _) = msm_term(term, &vk, &proof, &mut terms).unwrap();
+            a += ark_bn254::Fr::from_be_bytes_mod_order(&scalar.to_be_bytes()) * log;
+        }
+        let z =
+            ark_bn254::Fr::from_be_bytes_mod_order(&read(&scratch, slot::SHPLONK_Z).to_be_bytes());
+
+        // * P_0 = A + z * W must equal x * W: W = a / (x - z) * G
+        let x = fr(0x1234_5678);
+        let w = a / (x - z);
+        set_point(&mut upload, KZG_QUOTIENT, (generator * w).into_affine());
+        let srs_g2_x = {
+            let point: G2Affine = (G2Affine::generator() * x).into_affine();
+            let be = |value: ark_bn254::Fq| -> [u8; 32] {
+                value.into_bigint().to_bytes_be().try_into().unwrap()
+            };
+            [
+                be(point.x.c1),
+                be(point.x.c0),
+                be(point.y.c1),
+                be(point.y.c0),
+            ]
+        };
+
+        // * The quotient isn't hashed, so the challenges and scalars stay the same
+        let pairing_step = total_steps(&vk) - 1;
+        let mut scratch = vec![0u8; VerificationSession::SCRATCH_SIZE];
+        run(&session, &vk, &upload, &mut scratch, pairing_step);
+        let proof = Proof {
+            public_inputs: &upload[..64],
+            fields: &upload[64..],
+        };
+        let accumulator = scratch[slot::ACCUMULATOR * 32..(slot::ACCUMULATOR + 2) * 32].to_vec();
+        let expected = G1Projective::from(generator) * (a + z * w);
+        let expected = expected.into_affine();
+        let expected_bytes = [expected.x.into_bigint(), expected.y.into_bigint()]
+            .iter()
+            .flat_map(|c| c.to_bytes_be())
+            .collect::<Vec<u8>>();
+        assert_eq!(accumulator, expected_bytes);
+
+        let scratch = Scratch(&mut scratch);
+        assert_eq!(
+            pairing(&proof, &scratch, &srs_g2_x),
+            Some(StepOutcome::Accept)
+        );
+
+        // * Against the real SRS the same proof fails
+        assert_eq!(
+            pairing(&proof, &scratch, &SRS_G2_X),
+            Some(StepOutcome::Reject)
+        );
+
+        // * A different quotient fails
+        let mut tampered = upload.clone();
+        set_point(
+            &mut tampered,
+            KZG_QUOTIENT,
+            (generator * (w + fr(1))).into_affine(),
+        );
+        let proof = Proof {
+            public_inputs: &tampered[..64],
+            fields: &tampered[64..],
+        };
+        assert_eq!(
+            pairing(&proof, &scratch, &srs_g2_x),
+            Some(StepOutcome::Reject)
+        );
+    }
+}
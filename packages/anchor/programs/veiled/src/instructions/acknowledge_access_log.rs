@@ -0,0 +1,60 @@
+// * Acknowledge access log instruction
+// * Lets an authorized auditor record that it reviewed an access log entry
+// * Grants no data permissions - only emits an acknowledgement
+
+use crate::errors::VeiledError;
+use crate::state::auditor::*;
+use crate::state::permission::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AcknowledgeAccessLog<'info> {
+    #[account(
+        seeds = [
+            b"auditor",
+            permission_grant.nullifier.as_ref(),
+            auditor.key().as_ref()
+        ],
+        bump = auditor_grant.bump
+    )]
+    pub auditor_grant: Account<'info, AuditorGrant>,
+
+    #[account(
+        constraint = permission_access.permission_grant == permission_grant.key()
+            @ VeiledError::AccessLogMismatch
+    )]
+    pub permission_access: Account<'info, PermissionAccess>,
+
+    pub permission_grant: Account<'info, PermissionGrant>,
+
+    pub auditor: Signer<'info>,
+}
+
+pub fn handle_acknowledge_access_log(ctx: Context<AcknowledgeAccessLog>) -> Result<()> {
+    let auditor_grant = &ctx.accounts.auditor_grant;
+    let clock = Clock::get()?;
+
+    require!(!auditor_grant.revoked, VeiledError::AuditorRevoked);
+    require!(
+        !auditor_grant.expires_at.has_expired(&clock),
+        VeiledError::AuditorExpired
+    );
+
+    emit_cpi!(AccessLogAcknowledgedEvent {
+        nullifier: auditor_grant.nullifier,
+        auditor: auditor_grant.auditor,
+        permission_access: ctx.accounts.permission_access.key(),
+        acknowledged_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AccessLogAcknowledgedEvent {
+    pub nullifier: [u8; 32],
+    pub auditor: Pubkey,
+    pub permission_access: Pubkey,
+    pub acknowledged_at: i64,
+}
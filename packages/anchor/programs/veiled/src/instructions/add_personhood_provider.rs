@@ -0,0 +1,60 @@
+// * Add personhood provider instruction
+// * Lets a proof-of-personhood provider's key sign attestations (admin only)
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::personhood::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddPersonhoodProvider<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PersonhoodProviderRegistry::MAX_SIZE,
+        seeds = [b"personhood_providers"],
+        bump
+    )]
+    pub personhood_providers: Account<'info, PersonhoodProviderRegistry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_add_personhood_provider(
+    ctx: Context<AddPersonhoodProvider>,
+    provider: Pubkey,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.personhood_providers;
+    registry.bump = ctx.bumps.personhood_providers;
+
+    require!(
+        !registry.is_registered(&provider),
+        VeiledError::PersonhoodProviderAlreadyRegistered
+    );
+    require!(
+        registry.providers.len() < PersonhoodProviderRegistry::MAX_PROVIDERS,
+        VeiledError::TooManyPersonhoodProviders
+    );
+
+    registry.providers.push(provider);
+
+    emit_cpi!(PersonhoodProviderAddedEvent { provider });
+
+    Ok(())
+}
+
+#[event]
+pub struct PersonhoodProviderAddedEvent {
+    pub provider: Pubkey,
+}
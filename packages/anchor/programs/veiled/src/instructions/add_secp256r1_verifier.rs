@@ -0,0 +1,65 @@
+// * Add secp256r1 verifier instruction
+// * Lets a passkey / WebAuthn-device key co-sign results accepted by verify_auth (admin only)
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::verifier::*;
+use crate::ultrahonk::SECP256R1_PUBKEY_LEN;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddSecp256r1Verifier<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Secp256r1VerifierRegistry::MAX_SIZE,
+        seeds = [b"secp256r1_verifier_registry"],
+        bump
+    )]
+    pub secp256r1_verifier_registry: Account<'info, Secp256r1VerifierRegistry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_add_secp256r1_verifier(
+    ctx: Context<AddSecp256r1Verifier>,
+    verifier: [u8; SECP256R1_PUBKEY_LEN],
+) -> Result<()> {
+    let registry = &mut ctx.accounts.secp256r1_verifier_registry;
+    registry.bump = ctx.bumps.secp256r1_verifier_registry;
+
+    require!(
+        Secp256r1VerifierRegistry::is_compressed_key(&verifier),
+        VeiledError::InvalidSecp256r1Key
+    );
+    require!(
+        !registry.verifiers.contains(&verifier),
+        VeiledError::VerifierAlreadyRegistered
+    );
+    require!(
+        registry.verifiers.len() < Secp256r1VerifierRegistry::MAX_VERIFIERS,
+        VeiledError::TooManyVerifiers
+    );
+
+    registry.verifiers.push(verifier);
+
+    emit_cpi!(Secp256r1VerifierAddedEvent { verifier });
+
+    Ok(())
+}
+
+#[event]
+pub struct Secp256r1VerifierAddedEvent {
+    pub verifier: [u8; SECP256R1_PUBKEY_LEN],
+}
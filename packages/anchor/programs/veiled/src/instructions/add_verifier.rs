@@ -0,0 +1,57 @@
+// * Add verifier instruction
+// * Allows a verifier key to sign results accepted by verify_auth (admin only)
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::verifier::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AddVerifier<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + VerifierRegistry::MAX_SIZE,
+        seeds = [b"verifier_registry"],
+        bump
+    )]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_add_verifier(ctx: Context<AddVerifier>, verifier: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.verifier_registry;
+    registry.bump = ctx.bumps.verifier_registry;
+
+    require!(
+        !registry.is_registered(&verifier),
+        VeiledError::VerifierAlreadyRegistered
+    );
+    require!(
+        registry.verifiers.len() < VerifierRegistry::MAX_VERIFIERS,
+        VeiledError::TooManyVerifiers
+    );
+
+    registry.verifiers.push(verifier);
+
+    emit_cpi!(VerifierAddedEvent { verifier });
+
+    Ok(())
+}
+
+#[event]
+pub struct VerifierAddedEvent {
+    pub verifier: Pubkey,
+}
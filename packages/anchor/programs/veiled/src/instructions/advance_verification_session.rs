@@ -0,0 +1,84 @@
+// * Advance verification session instruction
+// * Runs up to `max_steps` verifier steps on a fully uploaded session (permissionless, so
+// * anyone can crank). Each step is bounded; callers size max_steps to their compute budget
+// * (a single step per instruction always fits).
+
+use crate::errors::VeiledError;
+use crate::honk::{self, StepOutcome};
+use crate::state::honk::HonkVerifyingKey;
+use crate::state::verification_session::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct AdvanceVerificationSession<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"verification_session",
+            verification_session.authority.as_ref(),
+            verification_session.nullifier.as_ref()
+        ],
+        bump = verification_session.bump
+    )]
+    pub verification_session: Account<'info, VerificationSession>,
+
+    // * Boxed: at ~1.8 KB the key would crowd the SBF stack frame
+    #[account(
+        seeds = [b"honk_vk", verification_session.circuit_id.to_le_bytes().as_ref()],
+        bump = honk_vk.bump
+    )]
+    pub honk_vk: Box<Account<'info, HonkVerifyingKey>>,
+}
+
+pub fn handle_advance_verification_session(
+    ctx: Context<AdvanceVerificationSession>,
+    max_steps: u8,
+) -> Result<()> {
+    let session = &mut ctx.accounts.verification_session;
+    require!(
+        session.status == VerificationStatus::Verifying,
+        VeiledError::InvalidSessionStatus
+    );
+
+    {
+        // * Scratch and upload live past the header, which Anchor writes back on exit
+        let info = session.to_account_info();
+        let mut data = info.try_borrow_mut_data()?;
+        let (head, upload) = data.split_at_mut(VerificationSession::UPLOAD_OFFSET);
+        let scratch = &mut head[VerificationSession::SCRATCH_OFFSET..];
+
+        for _ in 0..max_steps {
+            match honk::step(session, &ctx.accounts.honk_vk, upload, scratch)? {
+                StepOutcome::Continue => session.step += 1,
+                StepOutcome::Accept => {
+                    session.status = VerificationStatus::Verified;
+                    break;
+                }
+                StepOutcome::Reject => {
+                    session.status = VerificationStatus::Rejected;
+                    break;
+                }
+            }
+        }
+    }
+
+    if session.status != VerificationStatus::Verifying {
+        emit_cpi!(VerificationSessionResolvedEvent {
+            nullifier: session.nullifier,
+            authority: session.authority,
+            verified: session.status == VerificationStatus::Verified,
+            steps: session.step + 1,
+        });
+    }
+
+    Ok(())
+}
+
+#[event]
+pub struct VerificationSessionResolvedEvent {
+    pub nullifier: [u8; 32],
+    pub authority: Pubkey,
+    pub verified: bool,
+    pub steps: u32,
+}
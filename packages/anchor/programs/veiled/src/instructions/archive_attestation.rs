@@ -0,0 +1,67 @@
+// * Archive attestation instruction
+// * Moves a personhood attestation into cold storage: the full account is closed and only a
+// * hash of its fields is kept, for claims that must stay provable for years but are rarely
+// * read. Signed by the attestation's provider, who receives the freed rent; anyone holding
+// * the fields can bring it back with restore_attestation.
+
+use crate::state::personhood::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ArchiveAttestation<'info> {
+    #[account(
+        mut,
+        close = provider,
+        seeds = [b"personhood", attestation.nullifier.as_ref(), provider.key().as_ref()],
+        bump = attestation.bump
+    )]
+    pub attestation: Account<'info, PersonhoodAttestation>,
+
+    // * Replaces an earlier archive; attach only accepts attestations newer than it
+    #[account(
+        init_if_needed,
+        payer = provider,
+        space = 8 + ArchivedPersonhoodAttestation::MAX_SIZE,
+        seeds = [b"personhood_archive", attestation.nullifier.as_ref(), provider.key().as_ref()],
+        bump
+    )]
+    pub archive: Account<'info, ArchivedPersonhoodAttestation>,
+
+    #[account(mut)]
+    pub provider: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_archive_attestation(ctx: Context<ArchiveAttestation>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let attestation = &ctx.accounts.attestation;
+    let attestation_hash = attestation.archive_hash();
+
+    ctx.accounts
+        .archive
+        .set_inner(ArchivedPersonhoodAttestation {
+            attestation_hash,
+            issued_at: attestation.issued_at,
+            archived_at: now,
+            bump: ctx.bumps.archive,
+        });
+
+    emit_cpi!(PersonhoodAttestationArchivedEvent {
+        nullifier: attestation.nullifier,
+        provider: attestation.provider,
+        attestation_hash,
+        archived_at: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PersonhoodAttestationArchivedEvent {
+    pub nullifier: [u8; 32],
+    pub provider: Pubkey,
+    pub attestation_hash: [u8; 32],
+    pub archived_at: i64,
+}
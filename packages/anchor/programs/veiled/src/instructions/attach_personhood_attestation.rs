@@ -0,0 +1,112 @@
+// * Attach personhood attestation instruction
+// * Binds a registered proof-of-personhood provider's signed score to a nullifier, so domains
+// * wanting extra sybil resistance can require one on top of ZK auth. Anyone may submit it
+// * (and pay the rent); the provider's Ed25519 signature over
+// * PersonhoodAttestation::message must sit in an Ed25519Program instruction in the same
+// * transaction. A newer attestation from the same provider replaces the attached one, or
+// * stands beside an archived one (see archive_attestation).
+
+use crate::errors::VeiledError;
+use crate::sig_introspection;
+use crate::state::load_optional;
+use crate::state::personhood::*;
+use crate::ultrahonk::MAX_CLOCK_SKEW_SECS;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nullifier: [u8; 32], provider: Pubkey)]
+pub struct AttachPersonhoodAttestation<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + PersonhoodAttestation::MAX_SIZE,
+        seeds = [b"personhood", nullifier.as_ref(), provider.as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, PersonhoodAttestation>,
+
+    /// CHECK: * ArchivedPersonhoodAttestation PDA; may not exist
+    #[account(seeds = [b"personhood_archive", nullifier.as_ref(), provider.as_ref()], bump)]
+    pub archive: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"personhood_providers"], bump = personhood_providers.bump)]
+    pub personhood_providers: Account<'info, PersonhoodProviderRegistry>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_attach_personhood_attestation(
+    ctx: Context<AttachPersonhoodAttestation>,
+    nullifier: [u8; 32],
+    provider: Pubkey,
+    score: u16,
+    issued_at: i64,
+    expires_at: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        ctx.accounts.personhood_providers.is_registered(&provider),
+        VeiledError::PersonhoodProviderNotRegistered
+    );
+    require!(
+        score <= PersonhoodAttestation::MAX_SCORE,
+        VeiledError::InvalidPersonhoodScore
+    );
+
+    // * Still valid, not dated ahead, and not a replay of an older claim over a newer one
+    // * (attached or archived)
+    let archived_issued_at = load_optional::<ArchivedPersonhoodAttestation>(&ctx.accounts.archive)?
+        .map_or(0, |archived| archived.issued_at);
+    let attestation = &mut ctx.accounts.attestation;
+    require!(
+        expires_at > now
+            && issued_at <= now.saturating_add(MAX_CLOCK_SKEW_SECS)
+            && issued_at > attestation.issued_at
+            && issued_at > archived_issued_at,
+        VeiledError::StalePersonhoodAttestation
+    );
+
+    sig_introspection::require_signed(
+        &ctx.accounts.instructions_sysvar,
+        &provider,
+        &PersonhoodAttestation::message(&nullifier, score, issued_at, expires_at),
+        VeiledError::MissingPersonhoodSignature,
+    )?;
+
+    attestation.set_inner(PersonhoodAttestation {
+        nullifier,
+        provider,
+        score,
+        issued_at,
+        expires_at,
+        bump: ctx.bumps.attestation,
+    });
+
+    emit_cpi!(PersonhoodAttestedEvent {
+        nullifier,
+        provider,
+        score,
+        issued_at,
+        expires_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PersonhoodAttestedEvent {
+    pub nullifier: [u8; 32],
+    pub provider: Pubkey,
+    pub score: u16,
+    pub issued_at: i64,
+    pub expires_at: i64,
+}
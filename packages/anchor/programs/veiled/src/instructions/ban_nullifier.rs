@@ -0,0 +1,66 @@
+// * Ban nullifier instruction
+// * A domain's owner bans a nullifier from the domain (see state/ban.rs): verify_auth won't
+// * register it there and log_permission_access won't log its grants' use. Sessions it already
+// * holds run until they expire or are closed.
+
+use crate::errors::VeiledError;
+use crate::state::ban::NullifierBan;
+use crate::state::domain::DomainConfig;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nullifier: [u8; 32])]
+pub struct BanNullifier<'info> {
+    #[account(
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        has_one = owner @ VeiledError::NotDomainOwner
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + NullifierBan::MAX_SIZE,
+        seeds = [
+            b"nullifier_ban",
+            domain_config.domain.as_ref(),
+            nullifier.as_ref()
+        ],
+        bump
+    )]
+    pub nullifier_ban: Account<'info, NullifierBan>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_ban_nullifier(ctx: Context<BanNullifier>, nullifier: [u8; 32]) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let domain = ctx.accounts.domain_config.domain;
+    ctx.accounts.nullifier_ban.set_inner(NullifierBan {
+        domain,
+        nullifier,
+        banned_by: ctx.accounts.owner.key(),
+        banned_at: now,
+        bump: ctx.bumps.nullifier_ban,
+    });
+
+    emit_cpi!(NullifierBannedEvent {
+        domain,
+        nullifier,
+        banned_at: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct NullifierBannedEvent {
+    pub domain: [u8; 32],
+    pub nullifier: [u8; 32],
+    pub banned_at: i64,
+}
@@ -0,0 +1,82 @@
+// * Begin migration instruction
+// * Opens a migration window to `target_version` (admin only): until complete_migration,
+// * instructions accept accounts on either version and migrate_* steps count their progress
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::migration::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct BeginMigration<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + MigrationState::MAX_SIZE,
+        seeds = [b"migration_state"],
+        bump
+    )]
+    pub migration_state: Account<'info, MigrationState>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_begin_migration(
+    ctx: Context<BeginMigration>,
+    target_version: u16,
+    steps: Vec<MigrationStep>,
+    deadline: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let migration = &mut ctx.accounts.migration_state;
+    migration.bump = ctx.bumps.migration_state;
+
+    require!(!migration.in_progress(), VeiledError::MigrationInProgress);
+
+    // * One step per account type, so progress can't be split across duplicates
+    let distinct = steps
+        .iter()
+        .enumerate()
+        .all(|(i, step)| steps[..i].iter().all(|other| other.account != step.account));
+    require!(
+        target_version > migration.version
+            && steps.len() <= MigrationState::MAX_STEPS
+            && distinct
+            && steps.iter().all(|step| step.migrated == 0)
+            && deadline > now,
+        VeiledError::InvalidMigrationPlan
+    );
+
+    migration.target_version = target_version;
+    migration.steps = steps;
+    migration.started_at = now;
+    migration.deadline = deadline;
+
+    emit_cpi!(MigrationBegunEvent {
+        version: migration.version,
+        target_version,
+        steps: migration.steps.clone(),
+        deadline,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct MigrationBegunEvent {
+    pub version: u16,
+    pub target_version: u16,
+    pub steps: Vec<MigrationStep>,
+    pub deadline: i64,
+}
@@ -0,0 +1,56 @@
+// * Cancel reveal escrow instruction
+// * The user backs out of a pending reveal before its delay runs out, deleting the
+// * ciphertext. Once released, the escrow can also be closed to reclaim its rent.
+// * Between reveals_at and the release the commitment stands and can't be cancelled.
+
+use crate::errors::VeiledError;
+use crate::state::reveal_escrow::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CancelRevealEscrow<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"reveal_escrow",
+            reveal_escrow.nullifier.as_ref(),
+            reveal_escrow.app_id.as_ref()
+        ],
+        bump = reveal_escrow.bump,
+        has_one = authority @ VeiledError::NotEscrowAuthority
+    )]
+    pub reveal_escrow: Account<'info, RevealEscrow>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_cancel_reveal_escrow(ctx: Context<CancelRevealEscrow>) -> Result<()> {
+    let escrow = &ctx.accounts.reveal_escrow;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(
+        escrow.released || now < escrow.reveals_at,
+        VeiledError::RevealWindowClosed
+    );
+
+    emit_cpi!(RevealEscrowCancelledEvent {
+        nullifier: escrow.nullifier,
+        app_id: escrow.app_id,
+        released: escrow.released,
+        cancelled_at: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RevealEscrowCancelledEvent {
+    pub nullifier: [u8; 32],
+    pub app_id: Pubkey,
+    /// * False when the reveal was called off; true when a released escrow was just closed
+    pub released: bool,
+    pub cancelled_at: i64,
+}
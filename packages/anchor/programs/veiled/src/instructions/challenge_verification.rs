@@ -0,0 +1,107 @@
+// * Challenge verification instruction
+// * Optimistic verification: sessions on domains with a challenge window stay pending until
+// * it passes. Anyone holding evidence that the proof was invalid - a result for the same
+// * proof, nullifier and domain signed by an accepted verifier with is_valid = false - can
+// * void the session before then. One dissenting verifier is enough, even under a quorum.
+// * The session account is closed to the challenger, so its rent rewards the challenge.
+
+use crate::errors::VeiledError;
+use crate::state::domain::DomainConfig;
+use crate::state::verifier::VerifierRegistry;
+use crate::ultrahonk::VerificationResult;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(evidence: Vec<u8>, domain: [u8; 32])]
+pub struct ChallengeVerification<'info> {
+    #[account(
+        mut,
+        close = challenger,
+        seeds = [
+            b"nullifier",
+            nullifier_account.nullifier.as_ref(),
+            nullifier_account.domain_seed().as_ref()
+        ],
+        bump = nullifier_account.bump
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    /// CHECK: * DomainConfig PDA for `domain`; may not exist
+    #[account(seeds = [b"domain_config", DomainConfig::seed(&domain).as_ref()], bump)]
+    pub domain_config: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"verifier_registry"], bump = verifier_registry.bump)]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub challenger: Signer<'info>,
+}
+
+pub fn handle_challenge_verification(
+    ctx: Context<ChallengeVerification>,
+    evidence: Vec<u8>,
+    domain: [u8; 32],
+) -> Result<()> {
+    let session = &ctx.accounts.nullifier_account;
+    let now = Clock::get()?.unix_timestamp;
+
+    require!(!session.is_final(now), VeiledError::ChallengeWindowClosed);
+
+    // * `domain` picks the DomainConfig, so it must be the session's own
+    require!(session.domain == domain, VeiledError::DomainMismatch);
+
+    // * Evidence must reject exactly the proof this session was created from
+    let evidence = VerificationResult::from_instruction_data(&evidence)
+        .map_err(|_| VeiledError::InvalidChallenge)?;
+    require!(
+        !evidence.is_valid
+            && evidence.nullifier == session.nullifier
+            && evidence.domain == domain
+            && evidence.proof_hash == session.proof_hash,
+        VeiledError::InvalidChallenge
+    );
+
+    // * Signed by a key verify_auth would have accepted for this domain
+    let domain_config = DomainConfig::load(&ctx.accounts.domain_config)?;
+    let registry = &ctx.accounts.verifier_registry;
+    let (verifiers, retiring) = match domain_config
+        .as_ref()
+        .and_then(DomainConfig::designated_verifiers)
+    {
+        Some(domain_verifiers) => (domain_verifiers, Vec::new()),
+        None => (registry.verifiers.as_slice(), registry.retiring_keys(now)),
+    };
+    evidence.validate_signature(
+        verifiers,
+        &retiring,
+        &[],
+        1,
+        &ctx.accounts.instructions_sysvar,
+        None,
+    )?;
+
+    emit_cpi!(VerificationChallengedEvent {
+        nullifier: session.nullifier,
+        domain,
+        proof_hash: session.proof_hash,
+        challenger: ctx.accounts.challenger.key(),
+        challenged_at: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VerificationChallengedEvent {
+    pub nullifier: [u8; 32],
+    pub domain: [u8; 32],
+    pub proof_hash: [u8; 32],
+    pub challenger: Pubkey,
+    pub challenged_at: i64,
+}
@@ -0,0 +1,92 @@
+// * Claim alias instruction
+// * An active session's authority claims a handle for the session's nullifier (see
+// * state/alias.rs). Handles are first come, first served: the Alias PDA is keyed by the
+// * handle's hash alone, so a taken handle fails to initialize.
+// *
+// * Device-bound sessions (verify_auth_with_device) also need the device's DeviceProof.
+
+use crate::errors::VeiledError;
+use crate::state::alias::Alias;
+use crate::state::session::DeviceProof;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(handle_hash: [u8; 32])]
+pub struct ClaimAlias<'info> {
+    #[account(
+        seeds = [
+            b"nullifier",
+            nullifier_account.nullifier.as_ref(),
+            nullifier_account.domain_seed().as_ref()
+        ],
+        bump = nullifier_account.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Alias::MAX_SIZE,
+        seeds = [b"alias", handle_hash.as_ref()],
+        bump
+    )]
+    pub alias: Account<'info, Alias>,
+
+    // * Session's authority (has_one); pays the alias's rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// CHECK: * LogoutEpoch PDA of the session's authority; may not exist yet
+    /// * Address is pinned by seeds so callers can't substitute another identity's epoch
+    #[account(
+        seeds = [b"logout_epoch", nullifier_account.authority.as_ref()],
+        bump
+    )]
+    pub logout_epoch: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_claim_alias(ctx: Context<ClaimAlias>, handle_hash: [u8; 32]) -> Result<()> {
+    let clock = Clock::get()?;
+    let session = &ctx.accounts.nullifier_account;
+
+    session.require_usable(&clock, &ctx.accounts.logout_epoch)?;
+    session.prove_device(
+        &ctx.accounts.instructions_sysvar,
+        &DeviceProof::message(&session.nullifier, session.last_active_at),
+    )?;
+
+    ctx.accounts.alias.set_inner(Alias {
+        handle_hash,
+        nullifier: session.nullifier,
+        domain: session.domain,
+        authority: session.authority,
+        claimed_at: clock.unix_timestamp,
+        bump: ctx.bumps.alias,
+    });
+
+    emit_cpi!(AliasClaimedEvent {
+        handle_hash,
+        nullifier: session.nullifier,
+        domain: session.domain,
+        claimed_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AliasClaimedEvent {
+    pub handle_hash: [u8; 32],
+    pub nullifier: [u8; 32],
+    pub domain: [u8; 32],
+    pub claimed_at: i64,
+}
@@ -0,0 +1,87 @@
+// * Claim domain fees instruction
+// * Moves a domain's fee share out of its fee vault ATA (domain treasurer or owner)
+
+use crate::errors::VeiledError;
+use crate::state::domain::DomainConfig;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, Mint, Token, TransferChecked};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ClaimDomainFees<'info> {
+    #[account(
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        constraint = domain_config.is_treasurer(&authority.key()) @ VeiledError::NotDomainTreasurer
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    /// CHECK: * Domain fee vault PDA - signs the transfer via seeds
+    #[account(seeds = [b"domain_fee_vault", domain_config.domain.as_ref()], bump)]
+    pub domain_fee_vault: UncheckedAccount<'info>,
+
+    /// CHECK: * SPL Token mint; transfer_checked validates it against both token accounts
+    #[account(owner = token_program.key())]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: * Address pinned to the domain fee vault ATA for `mint`
+    #[account(
+        mut,
+        address = get_associated_token_address(&domain_fee_vault.key(), &mint.key())
+    )]
+    pub domain_fee_vault_ata: UncheckedAccount<'info>,
+
+    /// CHECK: * Any token account for `mint`; validated by the token program
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_claim_domain_fees(ctx: Context<ClaimDomainFees>, amount: u64) -> Result<()> {
+    let decimals = {
+        let mint_data = ctx.accounts.mint.try_borrow_data()?;
+        Mint::try_deserialize(&mut &mint_data[..])?.decimals
+    };
+
+    let domain = ctx.accounts.domain_config.domain;
+    let vault_bump = ctx.bumps.domain_fee_vault;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"domain_fee_vault", domain.as_ref(), &[vault_bump]]];
+
+    token::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.domain_fee_vault_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.domain_fee_vault.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )?;
+
+    emit_cpi!(DomainFeesClaimedEvent {
+        domain,
+        mint: ctx.accounts.mint.key(),
+        destination: ctx.accounts.destination.key(),
+        authority: ctx.accounts.authority.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainFeesClaimedEvent {
+    pub domain: [u8; 32],
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub authority: Pubkey,
+    pub amount: u64,
+}
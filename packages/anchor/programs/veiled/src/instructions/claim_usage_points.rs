@@ -0,0 +1,70 @@
+// * Claim usage points instruction
+// * One-time claim of a nullifier's governance points against the published usage root
+// *
+// * Anyone may submit a claim and pays for the Points account: points are credited to the
+// * nullifier, not to the submitter, so claiming through a relayer doesn't link the two.
+
+use crate::errors::VeiledError;
+use crate::state::points::{Points, PointsRoot};
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nullifier: [u8; 32])]
+pub struct ClaimUsagePoints<'info> {
+    #[account(seeds = [b"points_root"], bump = points_root.bump)]
+    pub points_root: Account<'info, PointsRoot>,
+
+    // * `init` makes the claim one-time: a second claim fails on the existing account
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Points::MAX_SIZE,
+        seeds = [b"points", nullifier.as_ref()],
+        bump
+    )]
+    pub points: Account<'info, Points>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_claim_usage_points(
+    ctx: Context<ClaimUsagePoints>,
+    nullifier: [u8; 32],
+    amount: u64,
+    leaf_index: u32,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(
+        ctx.accounts
+            .points_root
+            .contains(&nullifier, amount, leaf_index, &proof),
+        VeiledError::InvalidPointsProof
+    );
+
+    let points = &mut ctx.accounts.points;
+    points.nullifier = nullifier;
+    points.balance = amount;
+    points.claimed_at = Clock::get()?.unix_timestamp;
+    points.bump = ctx.bumps.points;
+
+    emit_cpi!(UsagePointsClaimedEvent {
+        nullifier,
+        amount,
+        root: ctx.accounts.points_root.root,
+        claimed_at: points.claimed_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct UsagePointsClaimedEvent {
+    pub nullifier: [u8; 32],
+    pub amount: u64,
+    pub root: [u8; 32],
+    pub claimed_at: i64,
+}
@@ -0,0 +1,95 @@
+// * Claim username instruction
+// * An active session's authority claims a username for the session's nullifier. The
+// * Username PDA is seeded by the normalized name (see state/username.rs), so a name is
+// * claimed once whatever its case; a taken name fails to initialize.
+// *
+// * Device-bound sessions (verify_auth_with_device) also need the device's DeviceProof.
+
+use crate::errors::VeiledError;
+use crate::state::session::DeviceProof;
+use crate::state::username::Username;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(name: String)]
+pub struct ClaimUsername<'info> {
+    #[account(
+        seeds = [
+            b"nullifier",
+            nullifier_account.nullifier.as_ref(),
+            nullifier_account.domain_seed().as_ref()
+        ],
+        bump = nullifier_account.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Username::MAX_SIZE,
+        seeds = [b"username", Username::seed(&name).as_ref()],
+        bump
+    )]
+    pub username: Account<'info, Username>,
+
+    // * Session's authority (has_one); pays the username's rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// CHECK: * LogoutEpoch PDA of the session's authority; may not exist yet
+    /// * Address is pinned by seeds so callers can't substitute another identity's epoch
+    #[account(
+        seeds = [b"logout_epoch", nullifier_account.authority.as_ref()],
+        bump
+    )]
+    pub logout_epoch: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_claim_username(ctx: Context<ClaimUsername>, name: String) -> Result<()> {
+    let clock = Clock::get()?;
+    let session = &ctx.accounts.nullifier_account;
+    let name = Username::normalize(&name)?;
+
+    session.require_usable(&clock, &ctx.accounts.logout_epoch)?;
+    session.prove_device(
+        &ctx.accounts.instructions_sysvar,
+        &DeviceProof::message(&session.nullifier, session.last_active_at),
+    )?;
+
+    ctx.accounts.username.set_inner(Username {
+        name,
+        nullifier: session.nullifier,
+        domain: session.domain,
+        authority: session.authority,
+        claimed_at: clock.unix_timestamp,
+        transferred_at: 0,
+        bump: ctx.bumps.username,
+    });
+
+    emit_cpi!(UsernameClaimedEvent {
+        name,
+        nullifier: session.nullifier,
+        domain: session.domain,
+        claimed_at: clock.unix_timestamp,
+    });
+
+    msg!("✓ Username {} claimed", ctx.accounts.username.name_str());
+    Ok(())
+}
+
+#[event]
+pub struct UsernameClaimedEvent {
+    pub name: [u8; 32],
+    pub nullifier: [u8; 32],
+    pub domain: [u8; 32],
+    pub claimed_at: i64,
+}
@@ -0,0 +1,66 @@
+// * Close expired nullifier instruction
+// * Permissionless - once a session has expired anyone may close its NullifierAccount; the
+// * caller keeps CLOSE_REWARD_BPS of the reclaimed rent, the rest goes back to the payer
+// *
+// * The nullifier can register a new session afterwards, as it could once the session expired
+// * (see verify_auth); proofs stay spent through their ProofRecord
+
+use crate::errors::VeiledError;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseExpiredNullifier<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            b"nullifier",
+            nullifier_account.nullifier.as_ref(),
+            nullifier_account.domain_seed().as_ref()
+        ],
+        bump = nullifier_account.bump,
+        has_one = payer
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    /// CHECK: * Rent refund destination; pinned to the session's payer by has_one
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+}
+
+pub fn handle_close_expired_nullifier(ctx: Context<CloseExpiredNullifier>) -> Result<()> {
+    let clock = Clock::get()?;
+    let session = &ctx.accounts.nullifier_account;
+    // * Sessions past their domain's idle timeout are over too
+    require!(!session.is_active(&clock), VeiledError::SessionNotExpired);
+
+    // * Pay the cranker its share; `close = payer` sends the rest back on exit
+    let session_info = session.to_account_info();
+    let reward = session_info.lamports() * NullifierAccount::CLOSE_REWARD_BPS / 10_000;
+    **session_info.try_borrow_mut_lamports()? -= reward;
+    **ctx.accounts.cranker.try_borrow_mut_lamports()? += reward;
+
+    emit_cpi!(ExpiredNullifierClosedEvent {
+        nullifier: session.nullifier,
+        payer: session.payer,
+        cranker: ctx.accounts.cranker.key(),
+        reward,
+        closed_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ExpiredNullifierClosedEvent {
+    pub nullifier: [u8; 32],
+    pub payer: Pubkey,
+    pub cranker: Pubkey,
+    pub reward: u64,
+    pub closed_at: i64,
+}
@@ -0,0 +1,72 @@
+// * Close notification channel instruction
+// * The channel's authority can close it at any time. Once the ReceiveNotifications grant
+// * is revoked, expired or dropped, anyone can close it, so a revocation also removes the
+// * token from chain state. Rent always returns to the authority.
+
+use crate::errors::VeiledError;
+use crate::state::notification::*;
+use crate::state::permission::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseNotificationChannel<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"notification_channel",
+            notification_channel.nullifier.as_ref(),
+            notification_channel.app_id.as_ref()
+        ],
+        bump = notification_channel.bump,
+        has_one = authority @ VeiledError::NotChannelAuthority
+    )]
+    pub notification_channel: Account<'info, NotificationChannel>,
+
+    #[account(
+        seeds = [
+            b"permission",
+            notification_channel.nullifier.as_ref(),
+            notification_channel.app_id.as_ref()
+        ],
+        bump = permission_grant.bump
+    )]
+    pub permission_grant: Account<'info, PermissionGrant>,
+
+    /// CHECK: * Receives the rent; must match the channel's authority (has_one above)
+    #[account(mut)]
+    pub authority: UncheckedAccount<'info>,
+
+    pub closer: Signer<'info>,
+}
+
+pub fn handle_close_notification_channel(ctx: Context<CloseNotificationChannel>) -> Result<()> {
+    let clock = Clock::get()?;
+    let permission_live = ctx
+        .accounts
+        .permission_grant
+        .denial_reason(Permission::ReceiveNotifications, &clock)
+        .is_none();
+
+    require!(
+        !permission_live || ctx.accounts.closer.key() == ctx.accounts.authority.key(),
+        VeiledError::NotChannelAuthority
+    );
+
+    let channel = &ctx.accounts.notification_channel;
+    emit_cpi!(NotificationChannelClosedEvent {
+        nullifier: channel.nullifier,
+        app_id: channel.app_id,
+        closed_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct NotificationChannelClosedEvent {
+    pub nullifier: [u8; 32],
+    pub app_id: Pubkey,
+    pub closed_at: i64,
+}
@@ -0,0 +1,45 @@
+// * Close result receipt instruction
+// * Permissionless - once a receipt's retention window has passed its signature is too stale
+// * to verify again, so anyone may close it and its rent goes back to the original payer
+
+use crate::errors::VeiledError;
+use crate::state::result_receipt::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseResultReceipt<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"result_receipt", result_receipt.signature_hash.as_ref()],
+        bump = result_receipt.bump,
+        has_one = payer
+    )]
+    pub result_receipt: Account<'info, ResultReceipt>,
+
+    /// CHECK: * Rent refund destination; pinned to the receipt's payer by has_one
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+}
+
+pub fn handle_close_result_receipt(ctx: Context<CloseResultReceipt>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let receipt = &ctx.accounts.result_receipt;
+    require!(now >= receipt.retain_until, VeiledError::ReceiptRetained);
+
+    emit_cpi!(ResultReceiptClosedEvent {
+        signature_hash: receipt.signature_hash,
+        payer: receipt.payer,
+        closed_at: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ResultReceiptClosedEvent {
+    pub signature_hash: [u8; 32],
+    pub payer: Pubkey,
+    pub closed_at: i64,
+}
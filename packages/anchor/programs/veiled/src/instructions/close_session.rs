@@ -0,0 +1,71 @@
+// * Close session instruction
+// * Explicit logout: the session's authority closes its NullifierAccount before expiry and the
+// * rent goes back to whoever paid it. The authority either signs the transaction or, when a
+// * relayer submits, signs a SessionCloseRequest in an Ed25519Program instruction.
+// *
+// * Apps follow SessionClosedEvent to drop server-side sessions; the nullifier can register
+// * a new session afterwards.
+
+use crate::errors::VeiledError;
+use crate::sig_introspection;
+use crate::state::session::SessionCloseRequest;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CloseSession<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            b"nullifier",
+            nullifier_account.nullifier.as_ref(),
+            nullifier_account.domain_seed().as_ref()
+        ],
+        bump = nullifier_account.bump,
+        has_one = authority @ VeiledError::Unauthorized,
+        has_one = payer
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    /// CHECK: * Session's authority; must sign the transaction or a SessionCloseRequest
+    pub authority: UncheckedAccount<'info>,
+
+    /// CHECK: * Rent refund destination; pinned to the session's payer by has_one
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+}
+
+pub fn handle_close_session(ctx: Context<CloseSession>) -> Result<()> {
+    let session = &ctx.accounts.nullifier_account;
+    if !ctx.accounts.authority.is_signer {
+        sig_introspection::require_signed(
+            &ctx.accounts.instructions_sysvar,
+            &session.authority,
+            &SessionCloseRequest::message(&session.nullifier, session.created_at),
+            VeiledError::MissingSessionCloseSignature,
+        )?;
+    }
+
+    emit_cpi!(SessionClosedEvent {
+        nullifier: session.nullifier,
+        domain: session.domain,
+        authority: session.authority,
+        closed_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SessionClosedEvent {
+    pub nullifier: [u8; 32],
+    pub domain: [u8; 32],
+    pub authority: Pubkey,
+    pub closed_at: i64,
+}
@@ -0,0 +1,31 @@
+// * Close verification session instruction
+// * Abandons a verification session in any state and refunds all of its rent (upload
+// * included) to the authority that opened it, e.g. after a bad upload, a rejected proof, or
+// * the nullifier being registered another way
+
+use crate::errors::VeiledError;
+use crate::state::verification_session::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct CloseVerificationSession<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"verification_session",
+            authority.key().as_ref(),
+            verification_session.nullifier.as_ref()
+        ],
+        bump = verification_session.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub verification_session: Account<'info, VerificationSession>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_close_verification_session(_ctx: Context<CloseVerificationSession>) -> Result<()> {
+    Ok(())
+}
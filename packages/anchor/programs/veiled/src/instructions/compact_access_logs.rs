@@ -0,0 +1,131 @@
+// * Compact access logs instruction
+// * Folds old PermissionAccess entries for one grant into its AccessLogSummary and closes them
+// * Permissionless - the caller keeps REWARD_BPS of the reclaimed rent, the rest goes back to each entry's payer
+// *
+// * remaining_accounts: [permission_access, payer] pairs, up to AccessLogSummary::MAX_BATCH entries
+
+use crate::errors::VeiledError;
+use crate::merkle;
+use crate::state::permission::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use solana_sha256_hasher::hashv;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CompactAccessLogs<'info> {
+    pub permission_grant: Account<'info, PermissionGrant>,
+
+    #[account(
+        init_if_needed,
+        payer = cranker,
+        space = 8 + AccessLogSummary::MAX_SIZE,
+        seeds = [b"access_summary", permission_grant.key().as_ref()],
+        bump
+    )]
+    pub summary: Account<'info, AccessLogSummary>,
+
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_compact_access_logs(ctx: Context<CompactAccessLogs>) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    let pairs = remaining.chunks_exact(2);
+    require!(
+        pairs.len() > 0
+            && pairs.len() <= AccessLogSummary::MAX_BATCH
+            && pairs.remainder().is_empty(),
+        VeiledError::InvalidCompactionBatch
+    );
+
+    let permission_grant = ctx.accounts.permission_grant.key();
+    let cranker = ctx.accounts.cranker.to_account_info();
+    let clock = Clock::get()?;
+
+    let mut leaves = Vec::with_capacity(pairs.len());
+    let mut access_logs = Vec::with_capacity(pairs.len());
+    let mut last_accessed_at = ctx.accounts.summary.last_accessed_at;
+    let mut reward = 0u64;
+
+    for pair in pairs {
+        let (access_info, payer_info) = (&pair[0], &pair[1]);
+
+        // * Owner + discriminator check; an entry closed earlier in this batch fails here
+        require_keys_eq!(
+            *access_info.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        let access = PermissionAccess::try_deserialize(&mut &access_info.try_borrow_data()?[..])?;
+        require!(
+            access.permission_grant == permission_grant,
+            VeiledError::AccessLogMismatch
+        );
+        require!(
+            access.payer == payer_info.key(),
+            VeiledError::AccessLogPayerMismatch
+        );
+        require!(
+            clock.unix_timestamp - access.accessed_at >= PermissionAccess::COMPACTION_MIN_AGE_SECS,
+            VeiledError::AccessLogTooRecent
+        );
+
+        leaves.push(merkle::hash_leaf(&[
+            access_info.key.as_ref(),
+            permission_grant.as_ref(),
+            &access.accessed_at.to_le_bytes(),
+            &[access.permission_used as u8],
+            access.metadata.as_bytes(),
+        ]));
+        access_logs.push(access_info.key());
+        last_accessed_at = last_accessed_at.max(access.accessed_at);
+
+        // * Close: split lamports between cranker and original payer, then hand back to the system program
+        let lamports = access_info.lamports();
+        let entry_reward = lamports * AccessLogSummary::REWARD_BPS / 10_000;
+        reward += entry_reward;
+
+        **access_info.try_borrow_mut_lamports()? = 0;
+        **payer_info.try_borrow_mut_lamports()? += lamports - entry_reward;
+        **cranker.try_borrow_mut_lamports()? += entry_reward;
+
+        access_info.assign(&system_program::ID);
+        access_info.resize(0)?;
+    }
+
+    let batch_root = merkle::merkle_root(&leaves);
+
+    let summary = &mut ctx.accounts.summary;
+    if summary.batch_count == 0 {
+        summary.permission_grant = permission_grant;
+        summary.bump = ctx.bumps.summary;
+    }
+    summary.root = hashv(&[&summary.root, &batch_root]).to_bytes();
+    summary.compacted_count += leaves.len() as u64;
+    summary.batch_count += 1;
+    summary.last_accessed_at = last_accessed_at;
+
+    emit_cpi!(AccessLogsCompactedEvent {
+        permission_grant,
+        access_logs,
+        batch_root,
+        summary_root: summary.root,
+        compacted_count: summary.compacted_count,
+        reward,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AccessLogsCompactedEvent {
+    pub permission_grant: Pubkey,
+    pub access_logs: Vec<Pubkey>,
+    pub batch_root: [u8; 32],
+    pub summary_root: [u8; 32],
+    pub compacted_count: u64,
+    pub reward: u64,
+}
@@ -0,0 +1,61 @@
+// * Complete migration instruction
+// * Closes the open migration window (admin only) once every required step has migrated all of
+// * its accounts, or its deadline has passed; from then on only the new version is accepted
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::migration::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CompleteMigration<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"migration_state"],
+        bump = migration_state.bump
+    )]
+    pub migration_state: Account<'info, MigrationState>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_complete_migration(ctx: Context<CompleteMigration>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let migration = &mut ctx.accounts.migration_state;
+
+    require!(migration.in_progress(), VeiledError::NoMigrationInProgress);
+    require!(
+        migration.can_complete(now),
+        VeiledError::MigrationIncomplete
+    );
+
+    // * Final counters go out with the event; a deadline completion flags unfinished steps
+    let event = MigrationCompletedEvent {
+        version: migration.target_version,
+        steps: migration.steps.clone(),
+        forced: !migration.required_steps_done(),
+    };
+    migration.version = migration.target_version;
+    migration.steps.clear();
+
+    emit_cpi!(event);
+
+    Ok(())
+}
+
+#[event]
+pub struct MigrationCompletedEvent {
+    pub version: u16,
+    pub steps: Vec<MigrationStep>,
+
+    /// * Completed at the deadline with required steps unfinished
+    pub forced: bool,
+}
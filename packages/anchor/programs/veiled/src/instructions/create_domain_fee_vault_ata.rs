@@ -0,0 +1,81 @@
+// * Create domain fee vault ATA instruction
+// * Initializes a domain fee vault's associated token account for an accepted mint
+// * Permissionless - anyone may pay to open it
+// *
+// * The vault is a data-less PDA per domain, like the treasury; its ATAs hold the domain's fee
+// * share until the treasurer claims it (claim_domain_fees).
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::domain::DomainConfig;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{self, get_associated_token_address, AssociatedToken, Create};
+use anchor_spl::token::Token;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateDomainFeeVaultAta<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_accepted_mint(&mint.key()) @ VeiledError::MintNotAccepted
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    /// CHECK: * Domain fee vault PDA - owns the domain's fee token accounts, holds no data
+    #[account(seeds = [b"domain_fee_vault", domain_config.domain.as_ref()], bump)]
+    pub domain_fee_vault: UncheckedAccount<'info>,
+
+    /// CHECK: * Must be an SPL Token mint (validated by owner and by the ATA program)
+    #[account(owner = token_program.key())]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: * Created by the associated token program; address pinned to the vault ATA
+    #[account(
+        mut,
+        address = get_associated_token_address(&domain_fee_vault.key(), &mint.key())
+    )]
+    pub domain_fee_vault_ata: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_domain_fee_vault_ata(ctx: Context<CreateDomainFeeVaultAta>) -> Result<()> {
+    associated_token::create(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        Create {
+            payer: ctx.accounts.payer.to_account_info(),
+            associated_token: ctx.accounts.domain_fee_vault_ata.to_account_info(),
+            authority: ctx.accounts.domain_fee_vault.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    ))?;
+
+    emit_cpi!(DomainFeeVaultAtaCreatedEvent {
+        domain: ctx.accounts.domain_config.domain,
+        mint: ctx.accounts.mint.key(),
+        domain_fee_vault_ata: ctx.accounts.domain_fee_vault_ata.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainFeeVaultAtaCreatedEvent {
+    pub domain: [u8; 32],
+    pub mint: Pubkey,
+    pub domain_fee_vault_ata: Pubkey,
+}
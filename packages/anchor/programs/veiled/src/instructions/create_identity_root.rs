@@ -0,0 +1,51 @@
+// * Create identity root instruction
+// * The signer creates an empty IdentityRoot it owns (see state/identity_root.rs). Nothing is
+// * linked until link_identity_root; a key separate from any session's wallet keeps the root
+// * from linking those wallets too.
+
+use crate::state::identity_root::IdentityRoot;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateIdentityRoot<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + IdentityRoot::MAX_SIZE,
+        seeds = [b"identity_root", owner.key().as_ref()],
+        bump
+    )]
+    pub identity_root: Account<'info, IdentityRoot>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_identity_root(ctx: Context<CreateIdentityRoot>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let owner = ctx.accounts.owner.key();
+    ctx.accounts.identity_root.set_inner(IdentityRoot {
+        owner,
+        members: Vec::new(),
+        created_at: now,
+        bump: ctx.bumps.identity_root,
+    });
+
+    emit_cpi!(IdentityRootCreatedEvent {
+        root: ctx.accounts.identity_root.key(),
+        owner,
+        created_at: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct IdentityRootCreatedEvent {
+    pub root: Pubkey,
+    pub owner: Pubkey,
+    pub created_at: i64,
+}
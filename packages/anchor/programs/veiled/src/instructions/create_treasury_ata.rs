@@ -0,0 +1,72 @@
+// * Create treasury ATA instruction
+// * Initializes the program treasury's associated token account for an accepted mint
+// * Permissionless - anyone may pay to open it
+// *
+// * Note: Token accounts are taken as UncheckedAccount rather than Account<Mint>/Account<TokenAccount>
+// * because anchor-spl's IDL support requires the token_2022 feature, which we don't enable.
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::{self, get_associated_token_address, AssociatedToken, Create};
+use anchor_spl::token::Token;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct CreateTreasuryAta<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        constraint = config.is_accepted_mint(&mint.key()) @ VeiledError::MintNotAccepted
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: * Treasury PDA - owns the program's token accounts, holds no data
+    #[account(seeds = [b"treasury"], bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// CHECK: * Must be an SPL Token mint (validated by owner and by the ATA program)
+    #[account(owner = token_program.key())]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: * Created by the associated token program; address pinned to the treasury ATA
+    #[account(
+        mut,
+        address = get_associated_token_address(&treasury.key(), &mint.key())
+    )]
+    pub treasury_ata: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_create_treasury_ata(ctx: Context<CreateTreasuryAta>) -> Result<()> {
+    associated_token::create(CpiContext::new(
+        ctx.accounts.associated_token_program.to_account_info(),
+        Create {
+            payer: ctx.accounts.payer.to_account_info(),
+            associated_token: ctx.accounts.treasury_ata.to_account_info(),
+            authority: ctx.accounts.treasury.to_account_info(),
+            mint: ctx.accounts.mint.to_account_info(),
+            system_program: ctx.accounts.system_program.to_account_info(),
+            token_program: ctx.accounts.token_program.to_account_info(),
+        },
+    ))?;
+
+    emit_cpi!(TreasuryAtaCreatedEvent {
+        mint: ctx.accounts.mint.key(),
+        treasury_ata: ctx.accounts.treasury_ata.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TreasuryAtaCreatedEvent {
+    pub mint: Pubkey,
+    pub treasury_ata: Pubkey,
+}
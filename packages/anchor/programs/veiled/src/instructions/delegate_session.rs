@@ -0,0 +1,106 @@
+// * Delegate session instruction
+// * An active session's authority mints a SessionDelegate: a child key that may act for the
+// * session within a narrower permission scope, for at most an hour (15 minutes by default) and
+// * never past the session's own expiry. The authority revokes it with
+// * revoke_session_delegate; gated programs check it with validate_session_delegate.
+// *
+// * Device-bound sessions (verify_auth_with_device) also need the device's DeviceProof.
+
+use crate::errors::VeiledError;
+use crate::state::session::{DeviceProof, SessionDelegate};
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct DelegateSession<'info> {
+    #[account(
+        seeds = [
+            b"nullifier",
+            nullifier_account.nullifier.as_ref(),
+            nullifier_account.domain_seed().as_ref()
+        ],
+        bump = nullifier_account.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SessionDelegate::MAX_SIZE,
+        seeds = [
+            b"session_delegate",
+            nullifier_account.key().as_ref(),
+            delegate.as_ref()
+        ],
+        bump
+    )]
+    pub session_delegate: Account<'info, SessionDelegate>,
+
+    // * Session's authority (has_one); pays the delegate's rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// CHECK: * LogoutEpoch PDA of the session's authority; may not exist yet
+    /// * Address is pinned by seeds so callers can't substitute another identity's epoch
+    #[account(
+        seeds = [b"logout_epoch", nullifier_account.authority.as_ref()],
+        bump
+    )]
+    pub logout_epoch: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_delegate_session(
+    ctx: Context<DelegateSession>,
+    delegate: Pubkey,
+    scope: u64,
+    expires_in: i64, // * Seconds; 0 = SessionDelegate::DEFAULT_EXPIRY_SECONDS
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let session = &ctx.accounts.nullifier_account;
+
+    session.require_usable(&clock, &ctx.accounts.logout_epoch)?;
+    require!(scope != 0, VeiledError::InvalidDelegateScope);
+    session.prove_device(
+        &ctx.accounts.instructions_sysvar,
+        &DeviceProof::message(&session.nullifier, session.last_active_at),
+    )?;
+
+    let expires_at = SessionDelegate::expiry(clock.unix_timestamp, expires_in, session.expires_at)?;
+    ctx.accounts.session_delegate.set_inner(SessionDelegate {
+        session: session.key(),
+        delegate,
+        scope,
+        created_at: clock.unix_timestamp,
+        expires_at,
+        authority: session.authority,
+        bump: ctx.bumps.session_delegate,
+    });
+
+    emit_cpi!(SessionDelegatedEvent {
+        nullifier: session.nullifier,
+        session: session.key(),
+        delegate,
+        scope,
+        expires_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SessionDelegatedEvent {
+    pub nullifier: [u8; 32],
+    pub session: Pubkey,
+    pub delegate: Pubkey,
+    pub scope: u64,
+    pub expires_at: i64,
+}
@@ -0,0 +1,52 @@
+// * Deprecate circuit instruction
+// * Stops verify_auth from accepting results for a circuit (admin only; not reversible)
+
+use crate::errors::VeiledError;
+use crate::state::circuit::*;
+use crate::state::config::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct DeprecateCircuit<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"circuit_registry"],
+        bump = circuit_registry.bump
+    )]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_deprecate_circuit(ctx: Context<DeprecateCircuit>, circuit_id: u32) -> Result<()> {
+    let registry = &mut ctx.accounts.circuit_registry;
+
+    let circuit = registry
+        .circuits
+        .iter_mut()
+        .find(|circuit| circuit.circuit_id == circuit_id)
+        .ok_or(VeiledError::CircuitNotRegistered)?;
+    require!(!circuit.deprecated, VeiledError::CircuitDeprecated);
+    circuit.deprecated = true;
+
+    emit_cpi!(CircuitDeprecatedEvent {
+        circuit_id,
+        vk_hash: circuit.vk_hash,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CircuitDeprecatedEvent {
+    pub circuit_id: u32,
+    pub vk_hash: [u8; 32],
+}
@@ -0,0 +1,147 @@
+// * Finalize verification session instruction
+// * Registers the nullifier of a session whose proof verified on-chain, exactly like
+// * verify_auth_groth16 would, and closes the session back to its authority. Circuit, domain
+// * and ban checks run again in case they changed while the session was running.
+
+use crate::errors::VeiledError;
+use crate::state::ban::NullifierBan;
+use crate::state::circuit::CircuitRegistry;
+use crate::state::domain::{DomainConfig, NullifierStore};
+use crate::state::stats::{ProtocolStats, StatsCounter};
+use crate::state::verification_session::*;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+#[derive(Accounts)]
+pub struct FinalizeVerificationSession<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"verification_session",
+            authority.key().as_ref(),
+            verification_session.nullifier.as_ref()
+        ],
+        bump = verification_session.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub verification_session: Account<'info, VerificationSession>,
+
+    // * Same nullifier PDA verify_auth uses, so a nullifier registers once per domain across
+    // * every path
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + NullifierAccount::MAX_SIZE,
+        seeds = [
+            b"nullifier",
+            verification_session.nullifier.as_ref(),
+            NullifierAccount::domain_hash(&verification_session.domain).as_ref()
+        ],
+        bump
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    /// CHECK: * DomainConfig PDA for the session's domain; may not exist
+    #[account(
+        seeds = [
+            b"domain_config",
+            DomainConfig::seed(&verification_session.domain).as_ref()
+        ],
+        bump
+    )]
+    pub domain_config: UncheckedAccount<'info>,
+
+    /// CHECK: * NullifierBan PDA for the session's domain and nullifier
+    #[account(
+        seeds = [
+            b"nullifier_ban",
+            verification_session.domain.as_ref(),
+            verification_session.nullifier.as_ref()
+        ],
+        bump
+    )]
+    pub nullifier_ban: UncheckedAccount<'info>,
+
+    // * Program-wide totals (see state/stats.rs)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProtocolStats::MAX_SIZE,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_finalize_verification_session(
+    ctx: Context<FinalizeVerificationSession>,
+) -> Result<()> {
+    let session = &ctx.accounts.verification_session;
+    require!(
+        session.status == VerificationStatus::Verified,
+        VeiledError::InvalidSessionStatus
+    );
+
+    let domain_str = DomainConfig::validate_domain(&session.domain)?;
+    let circuit = ctx
+        .accounts
+        .circuit_registry
+        .require_active(session.circuit_id)?;
+    let domain_config = DomainConfig::load(&ctx.accounts.domain_config)?;
+    if let Some(domain_config) = &domain_config {
+        domain_config.require_not_paused()?;
+        domain_config.require_nullifier_store(NullifierStore::Accounts)?;
+        domain_config.check_vk_hash(Some(&circuit.vk_hash))?;
+    }
+    NullifierBan::require_not_banned(&ctx.accounts.nullifier_ban)?;
+
+    let public_inputs_hash = {
+        let info = session.to_account_info();
+        let data = info.try_borrow_data()?;
+        let start = VerificationSession::UPLOAD_OFFSET;
+        hashv(&[&data[start..start + session.public_inputs_len()]]).to_bytes()
+    };
+
+    // * Replay protection, as in verify_auth
+    let nullifier_account = &mut ctx.accounts.nullifier_account;
+    require!(
+        nullifier_account.created_at == 0,
+        VeiledError::DuplicateNullifier
+    );
+
+    // * Verified on-chain, so there is nothing to challenge: final immediately
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp;
+    nullifier_account.nullifier = session.nullifier;
+    nullifier_account.domain = session.domain;
+    nullifier_account.domain_len = domain_str.len() as u8;
+    nullifier_account.authority = session.authority;
+    nullifier_account.created_at = current_timestamp;
+    nullifier_account.last_active_at = current_timestamp;
+    nullifier_account.idle_timeout_secs = DomainConfig::idle_timeout(domain_config.as_ref());
+    nullifier_account.public_inputs_hash = public_inputs_hash;
+    nullifier_account.circuit_id = session.circuit_id;
+    nullifier_account.proof_hash = session.proof_hash;
+    nullifier_account.verified_at = current_timestamp;
+    nullifier_account.verifier = Pubkey::default();
+    nullifier_account.finalizes_at = 0;
+    nullifier_account.payer = ctx.accounts.authority.key();
+    nullifier_account.bump = ctx.bumps.nullifier_account;
+    nullifier_account.expires_at =
+        DomainConfig::session_expires_at(domain_config.as_ref(), 0, &clock)?;
+    ctx.accounts
+        .protocol_stats
+        .record(ctx.bumps.protocol_stats, StatsCounter::Verifications, 1);
+
+    Ok(())
+}
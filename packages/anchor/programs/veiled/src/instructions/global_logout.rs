@@ -0,0 +1,46 @@
+// * Global logout instruction
+// * Lets a wallet revoke every session it created, across all domains, in one call
+
+use crate::state::session::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct GlobalLogout<'info> {
+    #[account(
+        init_if_needed,
+        payer = identity,
+        space = 8 + LogoutEpoch::MAX_SIZE,
+        seeds = [b"logout_epoch", identity.key().as_ref()],
+        bump
+    )]
+    pub logout_epoch: Account<'info, LogoutEpoch>,
+
+    /// * The master identity (wallet) - signing proves ownership
+    #[account(mut)]
+    pub identity: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_global_logout(ctx: Context<GlobalLogout>) -> Result<()> {
+    let logout_epoch = &mut ctx.accounts.logout_epoch;
+    let clock = Clock::get()?;
+
+    logout_epoch.identity = ctx.accounts.identity.key();
+    logout_epoch.logged_out_at = clock.unix_timestamp;
+    logout_epoch.bump = ctx.bumps.logout_epoch;
+
+    emit_cpi!(GlobalLogoutEvent {
+        identity: logout_epoch.identity,
+        logged_out_at: logout_epoch.logged_out_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct GlobalLogoutEvent {
+    pub identity: Pubkey,
+    pub logged_out_at: i64,
+}
@@ -0,0 +1,68 @@
+// * Grant auditor instruction
+// * Authorizes a named auditor key to acknowledge a user's access logs
+
+use crate::state::auditor::*;
+use crate::state::expiry::ExpiryKind;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nullifier: [u8; 32], auditor: Pubkey)]
+pub struct GrantAuditor<'info> {
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + AuditorGrant::MAX_SIZE,
+        seeds = [
+            b"auditor",
+            nullifier.as_ref(),
+            auditor.as_ref()
+        ],
+        bump
+    )]
+    pub auditor_grant: Account<'info, AuditorGrant>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_grant_auditor(
+    ctx: Context<GrantAuditor>,
+    nullifier: [u8; 32],
+    auditor: Pubkey,
+    name_hash: [u8; 32],
+    expires_in: ExpiryKind, // * Duration in seconds (UnixTime) or slots (Slot)
+) -> Result<()> {
+    let auditor_grant = &mut ctx.accounts.auditor_grant;
+    let clock = Clock::get()?;
+
+    auditor_grant.nullifier = nullifier;
+    auditor_grant.auditor = auditor;
+    auditor_grant.name_hash = name_hash;
+    auditor_grant.granted_by = ctx.accounts.payer.key();
+    auditor_grant.granted_at = clock.unix_timestamp;
+    auditor_grant.expires_at = ExpiryKind::from_duration(expires_in, &clock)?;
+    auditor_grant.revoked = false;
+    auditor_grant.bump = ctx.bumps.auditor_grant;
+
+    emit_cpi!(AuditorGrantedEvent {
+        nullifier,
+        auditor,
+        name_hash,
+        granted_at: clock.unix_timestamp,
+        expires_at: auditor_grant.expires_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AuditorGrantedEvent {
+    pub nullifier: [u8; 32],
+    pub auditor: Pubkey,
+    pub name_hash: [u8; 32],
+    pub granted_at: i64,
+    pub expires_at: ExpiryKind,
+}
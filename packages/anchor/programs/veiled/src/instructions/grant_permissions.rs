@@ -1,11 +1,21 @@
 // * Grant permissions instruction
 // * Allows apps to request and users to grant specific permissions
+// * A grant carries at most the permission cap of the `domain` it's made in (register_domain),
+// * or the protocol limit when the domain has no config, and its duration is counted in that
+// * domain's ExpiryHistogram
 
+use crate::cpi_guard;
+use crate::errors::VeiledError;
+use crate::state::domain::DomainConfig;
+use crate::state::expiry::ExpiryKind;
+use crate::state::histogram::ExpiryHistogram;
 use crate::state::permission::*;
+use crate::state::stats::{ProtocolStats, StatsCounter};
 use anchor_lang::prelude::*;
 
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(nullifier: [u8; 32], app_id: Pubkey)]
+#[instruction(nullifier: [u8; 32], domain: [u8; 32], app_id: Pubkey)]
 pub struct GrantPermissions<'info> {
     #[account(
         init_if_needed,
@@ -20,23 +30,53 @@ pub struct GrantPermissions<'info> {
     )]
     pub permission_grant: Account<'info, PermissionGrant>,
 
+    // * Per-domain grant duration counts for protocol analytics, keyed like DomainConfig
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ExpiryHistogram::MAX_SIZE,
+        seeds = [b"expiry_histogram", DomainConfig::seed(&domain).as_ref()],
+        bump
+    )]
+    pub expiry_histogram: Account<'info, ExpiryHistogram>,
+
+    /// CHECK: * DomainConfig PDA for `domain`; may not exist yet
+    /// * Address is pinned by seeds so callers can't dodge a domain's permission cap
+    #[account(seeds = [b"domain_config", DomainConfig::seed(&domain).as_ref()], bump)]
+    pub domain_config: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub payer: Signer<'info>,
 
+    // * Program-wide totals (see state/stats.rs)
+    #[account(
+        init_if_needed,
+        payer = payer,
+        space = 8 + ProtocolStats::MAX_SIZE,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
     pub system_program: Program<'info, System>,
 }
 
 pub fn handle_grant_permissions(
     ctx: Context<GrantPermissions>,
     nullifier: [u8; 32],
+    domain: [u8; 32],
     app_id: Pubkey,
     permissions: Vec<Permission>,
-    expires_in: i64, // * Duration in seconds
+    expires_in: ExpiryKind, // * Duration in seconds (UnixTime) or slots (Slot)
 ) -> Result<()> {
-    // * Validate permissions count (prevent DoS)
+    // * No re-entry from observer callbacks (see cpi_guard.rs)
+    cpi_guard::require_not_reentrant()?;
+
+    // * Validate permissions count against the domain's cap (prevent DoS)
+    let domain_config = DomainConfig::load(&ctx.accounts.domain_config)?;
     require!(
-        permissions.len() <= 10,
-        crate::errors::VeiledError::TooManyPermissions
+        permissions.len() <= DomainConfig::permission_limit(domain_config.as_ref()),
+        VeiledError::TooManyPermissions
     );
 
     let permission_grant = &mut ctx.accounts.permission_grant;
@@ -44,16 +84,27 @@ pub fn handle_grant_permissions(
 
     permission_grant.nullifier = nullifier;
     permission_grant.app_id = app_id;
+    permission_grant.permission_mask = PermissionGrant::mask_of(&permissions);
     permission_grant.permissions = permissions.clone();
     permission_grant.granted_at = clock.unix_timestamp;
-    permission_grant.expires_at = clock.unix_timestamp + expires_in;
+    permission_grant.expires_at = ExpiryKind::from_duration(expires_in, &clock)?;
     permission_grant.revoked = false;
     permission_grant.bump = ctx.bumps.permission_grant;
 
-    emit!(PermissionGrantedEvent {
+    // * Every (re-)grant is a fresh duration choice, so each one is counted
+    let expiry_histogram = &mut ctx.accounts.expiry_histogram;
+    expiry_histogram.domain = domain;
+    expiry_histogram.bump = ctx.bumps.expiry_histogram;
+    expiry_histogram.record(expires_in);
+    ctx.accounts
+        .protocol_stats
+        .record(ctx.bumps.protocol_stats, StatsCounter::Grants, 1);
+
+    emit_cpi!(PermissionGrantedEvent {
         nullifier,
         app_id,
         permissions,
+        permission_mask: permission_grant.permission_mask,
         granted_at: clock.unix_timestamp,
         expires_at: permission_grant.expires_at,
     });
@@ -66,6 +117,7 @@ pub struct PermissionGrantedEvent {
     pub nullifier: [u8; 32],
     pub app_id: Pubkey,
     pub permissions: Vec<Permission>,
+    pub permission_mask: u64,
     pub granted_at: i64,
-    pub expires_at: i64,
+    pub expires_at: ExpiryKind,
 }
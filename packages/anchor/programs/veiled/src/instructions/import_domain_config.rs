@@ -0,0 +1,51 @@
+// * Import domain config instruction
+// * Devnet builds only (admin): recreates a DomainConfig from a state snapshot after a cluster
+// * reset (see `bun run cli restore-state`). Overwrites an existing config, so a restore can be
+// * rerun after a partial failure.
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::domain::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(snapshot: DomainConfig)]
+pub struct ImportDomainConfig<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + DomainConfig::MAX_SIZE,
+        seeds = [b"domain_config", DomainConfig::seed(&snapshot.domain).as_ref()],
+        bump
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_import_domain_config(
+    ctx: Context<ImportDomainConfig>,
+    snapshot: DomainConfig,
+) -> Result<()> {
+    // * Same bound set_domain_verifiers enforces; more wouldn't fit the account
+    require!(
+        snapshot.verifiers.len() <= DomainConfig::MAX_VERIFIERS,
+        VeiledError::TooManyVerifiers
+    );
+
+    ctx.accounts.domain_config.set_inner(DomainConfig {
+        bump: ctx.bumps.domain_config,
+        ..snapshot
+    });
+    Ok(())
+}
@@ -0,0 +1,54 @@
+// * Import permission grant instruction
+// * Devnet builds only (admin): recreates a PermissionGrant from a state snapshot after a
+// * cluster reset. Overwrites an existing grant for the same nullifier and app.
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::permission::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(snapshot: PermissionGrant)]
+pub struct ImportPermissionGrant<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PermissionGrant::MAX_SIZE,
+        seeds = [
+            b"permission",
+            snapshot.nullifier.as_ref(),
+            snapshot.app_id.as_ref()
+        ],
+        bump
+    )]
+    pub permission_grant: Account<'info, PermissionGrant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_import_permission_grant(
+    ctx: Context<ImportPermissionGrant>,
+    snapshot: PermissionGrant,
+) -> Result<()> {
+    // * Same bound grant_permissions enforces; more wouldn't fit the account
+    require!(
+        snapshot.permissions.len() <= PermissionGrant::MAX_PERMISSIONS,
+        VeiledError::TooManyPermissions
+    );
+
+    ctx.accounts.permission_grant.set_inner(PermissionGrant {
+        bump: ctx.bumps.permission_grant,
+        ..snapshot
+    });
+    Ok(())
+}
@@ -0,0 +1,44 @@
+// * Import proof record instruction
+// * Devnet builds only (admin): recreates a ProofRecord from a state snapshot after a cluster
+// * reset, so restored proofs still can't register a second nullifier
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::proof_record::ProofRecord;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(snapshot: ProofRecord)]
+pub struct ImportProofRecord<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProofRecord::MAX_SIZE,
+        seeds = [b"proof_record", snapshot.proof_hash.as_ref()],
+        bump
+    )]
+    pub proof_record: Account<'info, ProofRecord>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_import_proof_record(
+    ctx: Context<ImportProofRecord>,
+    snapshot: ProofRecord,
+) -> Result<()> {
+    ctx.accounts.proof_record.set_inner(ProofRecord {
+        bump: ctx.bumps.proof_record,
+        ..snapshot
+    });
+    Ok(())
+}
@@ -0,0 +1,52 @@
+// * Import session instruction
+// * Devnet builds only (admin): recreates a session (NullifierAccount) from a state snapshot
+// * after a cluster reset. Overwrites an existing session at the same nullifier.
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(snapshot: NullifierAccount)]
+pub struct ImportSession<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + NullifierAccount::MAX_SIZE,
+        seeds = [
+            b"nullifier",
+            snapshot.nullifier.as_ref(),
+            snapshot.domain_seed().as_ref()
+        ],
+        bump
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_import_session(
+    ctx: Context<ImportSession>,
+    mut snapshot: NullifierAccount,
+) -> Result<()> {
+    require!(
+        snapshot.domain_len as usize <= snapshot.domain.len(),
+        VeiledError::DomainTooLong
+    );
+
+    // * Keep the canonical bump whatever the snapshot carries; later seeds checks rely on it
+    snapshot.bump = ctx.bumps.nullifier_account;
+    ctx.accounts.nullifier_account.set_inner(snapshot);
+    Ok(())
+}
@@ -0,0 +1,111 @@
+// * Init nullifier tree instruction
+// * Sets up a domain's compressed nullifier store (owner only): creates its NullifierTree,
+// * initializes the client-allocated SPL Account Compression tree under it and appends the
+// * zero sentinel leaf (see state/nullifier_tree.rs). Verifications start using it once the
+// * owner switches the domain to NullifierStore::Compressed.
+// *
+// * max_depth and max_buffer_size must be a pair the compression program supports; depth 20
+// * holds about a million nullifiers, depth 30 about a billion.
+
+use crate::compression;
+use crate::errors::VeiledError;
+use crate::state::domain::DomainConfig;
+use crate::state::nullifier_tree::NullifierTree;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct InitNullifierTree<'info> {
+    #[account(
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        has_one = owner @ VeiledError::NotDomainOwner
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + NullifierTree::MAX_SIZE,
+        seeds = [b"nullifier_tree", domain_config.domain.as_ref()],
+        bump
+    )]
+    pub nullifier_tree: Account<'info, NullifierTree>,
+
+    /// CHECK: * Tree account, allocated by the client and owned by the compression program
+    #[account(mut, owner = compression::COMPRESSION_PROGRAM_ID)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: * SPL Account Compression program
+    #[account(address = compression::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: * SPL Noop program the compression program logs through
+    #[account(address = compression::NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_init_nullifier_tree(
+    ctx: Context<InitNullifierTree>,
+    max_depth: u32,
+    max_buffer_size: u32,
+) -> Result<()> {
+    let domain = ctx.accounts.domain_config.domain;
+    let tree_authority = ctx.accounts.nullifier_tree.key();
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let bump = ctx.bumps.nullifier_tree;
+    let accounts = [
+        ctx.accounts.merkle_tree.to_account_info(),
+        ctx.accounts.nullifier_tree.to_account_info(),
+        ctx.accounts.noop_program.to_account_info(),
+        ctx.accounts.compression_program.to_account_info(),
+    ];
+    let signer_seeds: &[&[u8]] = &[b"nullifier_tree", domain.as_ref(), &[bump]];
+
+    invoke_signed(
+        &compression::init_empty_merkle_tree(
+            &merkle_tree,
+            &tree_authority,
+            max_depth,
+            max_buffer_size,
+        ),
+        &accounts,
+        &[signer_seeds],
+    )?;
+    let sentinel = NullifierTree::leaf(&[0u8; 32], 0, &[0u8; 32]);
+    invoke_signed(
+        &compression::append(&merkle_tree, &tree_authority, &sentinel),
+        &accounts,
+        &[signer_seeds],
+    )?;
+
+    ctx.accounts.nullifier_tree.set_inner(NullifierTree {
+        domain,
+        merkle_tree,
+        next_index: 1,
+        bump,
+    });
+
+    emit_cpi!(NullifierTreeInitializedEvent {
+        domain,
+        merkle_tree,
+        max_depth,
+        max_buffer_size,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct NullifierTreeInitializedEvent {
+    pub domain: [u8; 32],
+    pub merkle_tree: Pubkey,
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+}
@@ -0,0 +1,36 @@
+// * Initialize config instruction
+// * Creates the singleton ProtocolConfig; run once at deployment
+
+use crate::state::config::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct InitializeConfig<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ProtocolConfig::MAX_SIZE,
+        seeds = [b"config"],
+        bump
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// * Becomes the protocol admin
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.authority = ctx.accounts.authority.key();
+    config.accepted_mints = Vec::new();
+    config.fee_usd_micros = 0;
+    config.max_price_age_secs = ProtocolConfig::DEFAULT_MAX_PRICE_AGE_SECS;
+    config.max_price_conf_bps = ProtocolConfig::DEFAULT_MAX_PRICE_CONF_BPS;
+    config.bump = ctx.bumps.config;
+
+    Ok(())
+}
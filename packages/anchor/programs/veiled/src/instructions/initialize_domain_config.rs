@@ -0,0 +1,62 @@
+// * Initialize domain config instruction
+// * Creates the DomainConfig for a domain; the signer becomes its owner
+// * A `.sol` domain's signer must own its SNS name (see sns.rs)
+
+use crate::sns;
+use crate::state::domain::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(domain: [u8; 32])]
+pub struct InitializeDomainConfig<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + DomainConfig::MAX_SIZE,
+        seeds = [b"domain_config", DomainConfig::seed(&domain).as_ref()],
+        bump
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: * SNS name account the domain falls under; required for `.sol` domains only
+    /// * Checked by the handler against the name's address and its owner (see sns.rs)
+    pub sns_name_account: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_initialize_domain_config(
+    ctx: Context<InitializeDomainConfig>,
+    domain: [u8; 32],
+) -> Result<()> {
+    DomainConfig::validate_domain(&domain)?;
+    sns::require_domain_owner(
+        &domain,
+        ctx.accounts.sns_name_account.as_deref(),
+        ctx.accounts.owner.key,
+    )?;
+
+    let domain_config = &mut ctx.accounts.domain_config;
+    domain_config.domain = domain;
+    domain_config.owner = ctx.accounts.owner.key();
+    domain_config.pinned_vk_hash = [0u8; 32];
+    domain_config.verifiers = Vec::new();
+    domain_config.bump = ctx.bumps.domain_config;
+
+    emit_cpi!(DomainConfigInitializedEvent {
+        domain,
+        owner: domain_config.owner,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainConfigInitializedEvent {
+    pub domain: [u8; 32],
+    pub owner: Pubkey,
+}
@@ -0,0 +1,63 @@
+// * Issue VC commitment instruction
+// * Lets a credential issuer anchor the hash of a W3C Verifiable Credential to a holder's
+// * nullifier; the commitment starts Active and the issuer maintains its status afterwards
+
+use crate::state::credential::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nullifier: [u8; 32], vc_hash: [u8; 32])]
+pub struct IssueVcCommitment<'info> {
+    #[account(
+        init,
+        payer = issuer,
+        space = 8 + VcCommitment::MAX_SIZE,
+        seeds = [
+            b"vc_commitment",
+            issuer.key().as_ref(),
+            vc_hash.as_ref()
+        ],
+        bump
+    )]
+    pub vc_commitment: Account<'info, VcCommitment>,
+
+    #[account(mut)]
+    pub issuer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_issue_vc_commitment(
+    ctx: Context<IssueVcCommitment>,
+    nullifier: [u8; 32],
+    vc_hash: [u8; 32],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let commitment = &mut ctx.accounts.vc_commitment;
+    commitment.nullifier = nullifier;
+    commitment.issuer = ctx.accounts.issuer.key();
+    commitment.vc_hash = vc_hash;
+    commitment.status = VcStatus::Active;
+    commitment.issued_at = now;
+    commitment.updated_at = now;
+    commitment.bump = ctx.bumps.vc_commitment;
+
+    emit_cpi!(VcCommitmentIssuedEvent {
+        nullifier,
+        issuer: commitment.issuer,
+        vc_hash,
+        issued_at: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VcCommitmentIssuedEvent {
+    pub nullifier: [u8; 32],
+    pub issuer: Pubkey,
+    pub vc_hash: [u8; 32],
+    pub issued_at: i64,
+}
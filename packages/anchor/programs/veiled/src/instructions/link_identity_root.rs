@@ -0,0 +1,110 @@
+// * Link identity root instruction
+// * Adds an active session's nullifier to an IdentityRoot. The session's authority signs the
+// * transaction; the root's owner either signs it too or, when it's a different wallet, signs
+// * an IdentityLink in an Ed25519Program instruction. The linkage proof hash is stored as given
+// * for apps to check (see state/identity_root.rs).
+// *
+// * Device-bound sessions (verify_auth_with_device) also need the device's DeviceProof.
+
+use crate::errors::VeiledError;
+use crate::sig_introspection;
+use crate::state::identity_root::{IdentityLink, IdentityRoot, RootMember};
+use crate::state::session::DeviceProof;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct LinkIdentityRoot<'info> {
+    #[account(
+        seeds = [
+            b"nullifier",
+            nullifier_account.nullifier.as_ref(),
+            nullifier_account.domain_seed().as_ref()
+        ],
+        bump = nullifier_account.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    #[account(
+        mut,
+        seeds = [b"identity_root", owner.key().as_ref()],
+        bump = identity_root.bump,
+        has_one = owner
+    )]
+    pub identity_root: Account<'info, IdentityRoot>,
+
+    /// CHECK: * Root's owner; must sign the transaction or an IdentityLink
+    pub owner: UncheckedAccount<'info>,
+
+    // * Session's authority (has_one)
+    pub authority: Signer<'info>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// CHECK: * LogoutEpoch PDA of the session's authority; may not exist yet
+    /// * Address is pinned by seeds so callers can't substitute another identity's epoch
+    #[account(
+        seeds = [b"logout_epoch", nullifier_account.authority.as_ref()],
+        bump
+    )]
+    pub logout_epoch: UncheckedAccount<'info>,
+}
+
+pub fn handle_link_identity_root(
+    ctx: Context<LinkIdentityRoot>,
+    linkage_proof_hash: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let session = &ctx.accounts.nullifier_account;
+
+    session.require_usable(&clock, &ctx.accounts.logout_epoch)?;
+    session.prove_device(
+        &ctx.accounts.instructions_sysvar,
+        &DeviceProof::message(&session.nullifier, session.last_active_at),
+    )?;
+
+    let root = ctx.accounts.identity_root.key();
+    if !ctx.accounts.owner.is_signer {
+        sig_introspection::require_signed(
+            &ctx.accounts.instructions_sysvar,
+            &ctx.accounts.owner.key(),
+            &IdentityLink::message(
+                &root,
+                &session.nullifier,
+                &session.domain,
+                &linkage_proof_hash,
+            ),
+            VeiledError::MissingIdentityLinkSignature,
+        )?;
+    }
+
+    ctx.accounts.identity_root.link(RootMember {
+        nullifier: session.nullifier,
+        domain: session.domain,
+        linkage_proof_hash,
+        linked_at: clock.unix_timestamp,
+    })?;
+
+    emit_cpi!(IdentityRootLinkedEvent {
+        root,
+        nullifier: session.nullifier,
+        domain: session.domain,
+        linkage_proof_hash,
+        linked_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct IdentityRootLinkedEvent {
+    pub root: Pubkey,
+    pub nullifier: [u8; 32],
+    pub domain: [u8; 32],
+    pub linkage_proof_hash: [u8; 32],
+    pub linked_at: i64,
+}
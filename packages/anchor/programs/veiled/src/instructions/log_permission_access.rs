@@ -1,10 +1,14 @@
 // * Log permission access instruction
 // * Creates audit log entries when permissions are actually used
+// * Refused while the grant's nullifier is banned from the domain (see state/ban.rs)
 
 use anchor_lang::prelude::*;
+use crate::state::ban::NullifierBan;
 use crate::state::permission::*;
 
+#[event_cpi]
 #[derive(Accounts)]
+#[instruction(permission_used: Permission, metadata: String, domain: [u8; 32])]
 pub struct LogPermissionAccess<'info> {
     #[account(
         init,
@@ -15,6 +19,17 @@ pub struct LogPermissionAccess<'info> {
     
     pub permission_grant: Account<'info, PermissionGrant>,
     
+    /// CHECK: * NullifierBan PDA for `domain` and the grant's nullifier; exists only while banned
+    #[account(
+        seeds = [
+            b"nullifier_ban",
+            domain.as_ref(),
+            permission_grant.nullifier.as_ref()
+        ],
+        bump
+    )]
+    pub nullifier_ban: UncheckedAccount<'info>,
+    
     #[account(mut)]
     pub payer: Signer<'info>,
     
@@ -25,24 +40,16 @@ pub fn handle_log_permission_access(
     ctx: Context<LogPermissionAccess>,
     permission_used: Permission,
     metadata: String,
+    _domain: [u8; 32],
 ) -> Result<()> {
     let permission_grant = &ctx.accounts.permission_grant;
+    let clock = Clock::get()?;
+    NullifierBan::require_not_banned(&ctx.accounts.nullifier_ban)?;
     
     // * Verify permission exists and is valid
-    require!(
-        !permission_grant.revoked,
-        crate::errors::VeiledError::PermissionRevoked
-    );
-    
-    require!(
-        permission_grant.expires_at > Clock::get()?.unix_timestamp,
-        crate::errors::VeiledError::PermissionExpired
-    );
-    
-    require!(
-        permission_grant.permissions.contains(&permission_used),
-        crate::errors::VeiledError::PermissionNotGranted
-    );
+    if let Some(reason) = permission_grant.denial_reason(permission_used, &clock) {
+        return Err(crate::errors::VeiledError::from(reason).into());
+    }
     
     // * Validate metadata length
     require!(
@@ -52,14 +59,16 @@ pub fn handle_log_permission_access(
     
     let access = &mut ctx.accounts.permission_access;
     access.permission_grant = permission_grant.key();
-    access.accessed_at = Clock::get()?.unix_timestamp;
+    access.accessed_at = clock.unix_timestamp;
     access.permission_used = permission_used;
     access.metadata = metadata;
+    access.payer = ctx.accounts.payer.key();
     
-    emit!(PermissionAccessedEvent {
+    emit_cpi!(PermissionAccessedEvent {
         nullifier: permission_grant.nullifier,
         app_id: permission_grant.app_id,
         permission: permission_used,
+        permission_mask: permission_grant.permission_mask,
         accessed_at: access.accessed_at,
     });
     
@@ -71,5 +80,6 @@ pub struct PermissionAccessedEvent {
     pub nullifier: [u8; 32],
     pub app_id: Pubkey,
     pub permission: Permission,
+    pub permission_mask: u64,
     pub accessed_at: i64,
 }
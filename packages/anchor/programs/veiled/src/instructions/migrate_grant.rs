@@ -0,0 +1,185 @@
+// * Migrate grant instruction
+// * Rewrites a PermissionGrant still in the deployed layout in place, at its own PDA
+// * [b"permission", nullifier, app_id], counted by an open migration's PermissionGrant step.
+// * Permissionless: the caller pays the rent for the larger account.
+// *
+// * Deployed grants store expires_at as a unix-time i64 and have no permission_mask, so they
+// * don't deserialize as PermissionGrant; they are read through LegacyPermissionGrant, and
+// * the expiry becomes ExpiryKind::UnixTime.
+
+use crate::state::expiry::ExpiryKind;
+use crate::state::migration::*;
+use crate::state::permission::{Permission, PermissionGrant};
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nullifier: [u8; 32], app_id: Pubkey)]
+pub struct MigrateGrant<'info> {
+    #[account(mut, seeds = [b"migration_state"], bump = migration_state.bump)]
+    pub migration_state: Account<'info, MigrationState>,
+
+    /// CHECK: * Legacy grant; the handler reads it as LegacyPermissionGrant and rewrites it
+    #[account(
+        mut,
+        owner = crate::ID,
+        seeds = [b"permission", nullifier.as_ref(), app_id.as_ref()],
+        bump
+    )]
+    pub permission_grant: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub migrator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// * PermissionGrant as deployed before slot expiry (same discriminator)
+#[derive(AnchorDeserialize, Debug)]
+pub struct LegacyPermissionGrant {
+    pub nullifier: [u8; 32],
+    pub app_id: Pubkey,
+    pub permissions: Vec<Permission>,
+    pub granted_at: i64,
+    pub expires_at: i64, // * Unix timestamp
+    pub revoked: bool,
+    pub bump: u8,
+}
+
+impl LegacyPermissionGrant {
+    /// * Size the deployed grant_permissions allocated whatever the permission count:
+    /// * discriminator, nullifier, app_id, Vec (4-byte length + up to 10), granted_at,
+    /// * expires_at, revoked, bump
+    pub const LEN: usize = 8 + 32 + 32 + (4 + 10) + 8 + 8 + 1 + 1;
+
+    /// * Identified by discriminator and exact length; current grants share the discriminator
+    /// * but are larger. Shorter permission lists leave zero padding at the end.
+    pub fn try_from_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= 8 && data[..8] == *PermissionGrant::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        require!(data.len() == Self::LEN, ErrorCode::AccountDidNotDeserialize);
+        Self::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+}
+
+pub fn handle_migrate_grant(
+    ctx: Context<MigrateGrant>,
+    _nullifier: [u8; 32],
+    _app_id: Pubkey,
+) -> Result<()> {
+    let grant_info = ctx.accounts.permission_grant.to_account_info();
+    let legacy = LegacyPermissionGrant::try_from_data(&grant_info.try_borrow_data()?)?;
+
+    let progress = ctx
+        .accounts
+        .migration_state
+        .record_migrated(MigratedAccount::PermissionGrant, 1)?;
+
+    // * Fund the larger layout before growing into it
+    let space = 8 + PermissionGrant::MAX_SIZE;
+    let shortfall = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(grant_info.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.migrator.to_account_info(),
+                    to: grant_info.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    grant_info.resize(space)?;
+
+    let grant = PermissionGrant {
+        nullifier: legacy.nullifier,
+        app_id: legacy.app_id,
+        permission_mask: PermissionGrant::mask_of(&legacy.permissions),
+        permissions: legacy.permissions,
+        granted_at: legacy.granted_at,
+        expires_at: ExpiryKind::UnixTime(legacy.expires_at),
+        revoked: legacy.revoked,
+        bump: legacy.bump,
+        successor: [0; 32],
+    };
+    grant.try_serialize(&mut &mut grant_info.try_borrow_mut_data()?[..])?;
+
+    emit_cpi!(progress);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// * PermissionGrant exactly as the deployed program declared it
+    #[derive(AnchorSerialize)]
+    struct BaselinePermissionGrant {
+        nullifier: [u8; 32],
+        app_id: Pubkey,
+        permissions: Vec<Permission>,
+        granted_at: i64,
+        expires_at: i64,
+        revoked: bool,
+        bump: u8,
+    }
+
+    /// * Account data the deployed grant_permissions wrote for `permissions`
+    fn baseline_data(permissions: Vec<Permission>) -> Vec<u8> {
+        let mut data = PermissionGrant::DISCRIMINATOR.to_vec();
+        BaselinePermissionGrant {
+            nullifier: [4u8; 32],
+            app_id: Pubkey::new_from_array([5u8; 32]),
+            permissions,
+            granted_at: 1_700_000_000,
+            expires_at: 1_700_086_400,
+            revoked: false,
+            bump: 254,
+        }
+        .serialize(&mut data)
+        .unwrap();
+        data.resize(LegacyPermissionGrant::LEN, 0);
+        data
+    }
+
+    #[test]
+    fn test_parses_baseline_layout() {
+        let permissions = vec![
+            Permission::RevealWalletAddress,
+            Permission::RevealExactBalance,
+        ];
+        let legacy =
+            LegacyPermissionGrant::try_from_data(&baseline_data(permissions.clone())).unwrap();
+        assert_eq!(legacy.nullifier, [4u8; 32]);
+        assert_eq!(legacy.app_id, Pubkey::new_from_array([5u8; 32]));
+        assert_eq!(legacy.permissions, permissions);
+        assert_eq!(legacy.granted_at, 1_700_000_000);
+        assert_eq!(legacy.expires_at, 1_700_086_400);
+        assert!(!legacy.revoked);
+        assert_eq!(legacy.bump, 254);
+    }
+
+    #[test]
+    fn test_rejects_other_layouts() {
+        // * A current-layout grant shares the discriminator but not the size
+        let mut current = baseline_data(vec![Permission::RevealWalletAddress]);
+        current.resize(8 + PermissionGrant::MAX_SIZE, 0);
+        assert_eq!(
+            LegacyPermissionGrant::try_from_data(&current).unwrap_err(),
+            ErrorCode::AccountDidNotDeserialize.into()
+        );
+
+        let mut other = baseline_data(vec![Permission::RevealWalletAddress]);
+        other[0] ^= 1;
+        assert_eq!(
+            LegacyPermissionGrant::try_from_data(&other).unwrap_err(),
+            ErrorCode::AccountDiscriminatorMismatch.into()
+        );
+    }
+}
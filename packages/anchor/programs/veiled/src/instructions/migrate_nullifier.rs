@@ -0,0 +1,304 @@
+// * Migrate nullifier instruction
+// * Retires a session's nullifier in favour of a new one, e.g. after a circuit or secret
+// * rotation. The old session's authority submits a fresh verifier-signed result for the new
+// * nullifier in the same domain; it gets the checks verify_auth applies (see verification.rs),
+// * registers a session for the new nullifier, and the old session closes with its rent going
+// * back to whoever paid it. It counts against the authority's rate limit and the protocol's
+// * verification total like any other session.
+// *
+// * The NullifierLink it leaves at [b"nullifier_link", old] is the continuity record (see
+// * state/nullifier_link.rs). Grants aren't moved: pass the old nullifier's PermissionGrants as
+// * writable remaining_accounts and each gets the successor written into it (and its app id
+// * listed in NullifierMigratedEvent), so each app can re-key its grant to the successor.
+// *
+// * Device-bound sessions (verify_auth_with_device) also need the device's DeviceProof.
+
+use crate::errors::VeiledError;
+use crate::state::ban::NullifierBan;
+use crate::state::circuit::CircuitRegistry;
+use crate::state::domain::{DomainConfig, NullifierStore};
+use crate::state::nullifier_link::NullifierLink;
+use crate::state::permission::PermissionGrant;
+use crate::state::proof_record::ProofRecord;
+use crate::state::rate_limit::PayerRateLimit;
+use crate::state::session::{DeviceProof, SessionDetails};
+use crate::state::stats::{ProtocolStats, StatsCounter};
+use crate::state::verification_result::SignedVerificationResult;
+use crate::state::verifier::VerifierRegistry;
+use crate::ultrahonk::VerificationResult;
+use crate::verification::ResultChecker;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(result: SignedVerificationResult, new_nullifier: [u8; 32])]
+pub struct MigrateNullifier<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [
+            b"nullifier",
+            old_session.nullifier.as_ref(),
+            old_session.domain_seed().as_ref()
+        ],
+        bump = old_session.bump,
+        has_one = authority @ VeiledError::Unauthorized,
+        has_one = payer
+    )]
+    pub old_session: Account<'info, NullifierAccount>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + NullifierAccount::MAX_SIZE,
+        seeds = [
+            b"nullifier",
+            new_nullifier.as_ref(),
+            old_session.domain_seed().as_ref()
+        ],
+        bump
+    )]
+    pub new_session: Account<'info, NullifierAccount>,
+
+    // * One successor per nullifier: a second migration fails to initialize
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + NullifierLink::MAX_SIZE,
+        seeds = [b"nullifier_link", old_session.nullifier.as_ref()],
+        bump
+    )]
+    pub nullifier_link: Account<'info, NullifierLink>,
+
+    // * Same proof record verify_auth keeps, so a proof registers once
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProofRecord::MAX_SIZE,
+        seeds = [b"proof_record", result.proof_hash.as_ref()],
+        bump
+    )]
+    pub proof_record: Account<'info, ProofRecord>,
+
+    /// CHECK: * Created by the handler, as Anchor can't derive a seed hashed from an argument
+    /// * ResultReceipt PDA [b"result_receipt", sha256(signature)], spent on first use
+    #[account(mut)]
+    pub result_receipt: UncheckedAccount<'info>,
+
+    // * Old session's authority (has_one); pays for the new accounts and keeps the new session
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: * Old session's rent refund destination; pinned to its payer by has_one
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    /// CHECK: * Verifier that signed the result, when it isn't the fee-paying authority
+    /// * Must be registered and among the result's Ed25519 signers; omit to use the authority
+    pub verifier: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// CHECK: * LogoutEpoch PDA of the session's authority; may not exist yet
+    /// * Address is pinned by seeds so callers can't substitute another identity's epoch
+    #[account(
+        seeds = [b"logout_epoch", old_session.authority.as_ref()],
+        bump
+    )]
+    pub logout_epoch: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    /// CHECK: * DomainConfig PDA for the session's domain; may not exist yet
+    /// * Address is pinned by seeds so callers can't dodge a domain's pins by omitting it
+    #[account(seeds = [b"domain_config", DomainConfig::seed(&old_session.domain).as_ref()], bump)]
+    pub domain_config: UncheckedAccount<'info>,
+
+    /// CHECK: * NullifierBan PDA for the old nullifier in its domain; exists only while banned
+    #[account(
+        seeds = [
+            b"nullifier_ban",
+            old_session.domain.as_ref(),
+            old_session.nullifier.as_ref()
+        ],
+        bump
+    )]
+    pub old_nullifier_ban: UncheckedAccount<'info>,
+
+    /// CHECK: * NullifierBan PDA for `new_nullifier` in the same domain; exists only while banned
+    #[account(
+        seeds = [b"nullifier_ban", old_session.domain.as_ref(), new_nullifier.as_ref()],
+        bump
+    )]
+    pub new_nullifier_ban: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"verifier_registry"], bump = verifier_registry.bump)]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    /// CHECK: * Secp256r1VerifierRegistry PDA (passkey verifiers); may not exist yet
+    #[account(seeds = [b"secp256r1_verifier_registry"], bump)]
+    pub secp256r1_verifier_registry: UncheckedAccount<'info>,
+
+    /// CHECK: * AssurancePolicy PDA (staleness window per tier); may not exist yet
+    #[account(seeds = [b"assurance_policy"], bump)]
+    pub assurance_policy: UncheckedAccount<'info>,
+
+    // * Same sliding-window counter verify_auth charges
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PayerRateLimit::MAX_SIZE,
+        seeds = [b"rate_limit", authority.key().as_ref()],
+        bump
+    )]
+    pub payer_rate_limit: AccountLoader<'info, PayerRateLimit>,
+
+    // * Program-wide totals (see state/stats.rs)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProtocolStats::MAX_SIZE,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_migrate_nullifier(
+    ctx: Context<MigrateNullifier>,
+    result: SignedVerificationResult,
+    new_nullifier: [u8; 32],
+    expires_in: i64,
+) -> Result<SessionDetails> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    let old_session = &ctx.accounts.old_session;
+
+    old_session.require_usable(&clock, &ctx.accounts.logout_epoch)?;
+    old_session.prove_device(
+        &ctx.accounts.instructions_sysvar,
+        &DeviceProof::message(&old_session.nullifier, old_session.last_active_at),
+    )?;
+
+    let domain_config = DomainConfig::load(&ctx.accounts.domain_config)?;
+    if let Some(domain_config) = &domain_config {
+        domain_config.require_not_paused()?;
+        domain_config.require_nullifier_store(NullifierStore::Accounts)?;
+    }
+    // * Neither side may be banned from the domain, so migrating can't shed a ban
+    NullifierBan::require_not_banned(&ctx.accounts.old_nullifier_ban)?;
+    NullifierBan::require_not_banned(&ctx.accounts.new_nullifier_ban)?;
+    let expires_at = DomainConfig::session_expires_at(domain_config.as_ref(), expires_in, &clock)?;
+    PayerRateLimit::enforce(
+        &ctx.accounts.payer_rate_limit,
+        ctx.accounts.authority.key(),
+        ctx.bumps.payer_rate_limit,
+        domain_config.as_ref().map_or(0, |config| config.rate_limit),
+        now,
+    )?;
+
+    let mut checker = ResultChecker::new(
+        ctx.accounts.authority.key(),
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.circuit_registry,
+        &ctx.accounts.verifier_registry,
+        &ctx.accounts.secp256r1_verifier_registry,
+        &ctx.accounts.assurance_policy,
+        now,
+    )?;
+    if let Some(verifier) = &ctx.accounts.verifier {
+        checker.name_verifier(verifier.key());
+    }
+    let accepted = checker.check(
+        VerificationResult::try_from(result)?,
+        new_nullifier,
+        old_session.domain,
+        domain_config.as_ref(),
+    )?;
+    accepted.require_session_claim()?;
+
+    // * Grants of the old nullifier are marked with the successor, for apps to re-key;
+    // * anything else is refused
+    let mut grants = Vec::with_capacity(ctx.remaining_accounts.len());
+    for grant_info in ctx.remaining_accounts {
+        require_keys_eq!(
+            *grant_info.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        require!(grant_info.is_writable, ErrorCode::AccountNotMutable);
+        let mut grant = PermissionGrant::try_deserialize(&mut &grant_info.try_borrow_data()?[..])?;
+        require!(
+            grant.nullifier == old_session.nullifier,
+            VeiledError::NullifierMismatch
+        );
+        grant.successor = new_nullifier;
+        grant.try_serialize(&mut &mut grant_info.try_borrow_mut_data()?[..])?;
+        grants.push(grant.app_id);
+    }
+
+    let proof_record = &mut ctx.accounts.proof_record;
+    require!(proof_record.created_at == 0, VeiledError::ProofAlreadyUsed);
+    proof_record.set_inner(accepted.proof_record(ctx.bumps.proof_record, now));
+
+    accepted.consume_receipt(
+        &ctx.accounts.result_receipt,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        &ctx.accounts.verifier_registry,
+        now,
+    )?;
+
+    let authority = ctx.accounts.authority.key();
+    let session =
+        accepted.into_session(authority, authority, ctx.bumps.new_session, now, expires_at);
+    let details = SessionDetails {
+        nullifier_account: ctx.accounts.new_session.key(),
+        created_at: session.created_at,
+        expires_at: session.expires_at,
+    };
+    let proof_hash = session.proof_hash;
+    ctx.accounts.new_session.set_inner(session);
+    ctx.accounts
+        .protocol_stats
+        .record(ctx.bumps.protocol_stats, StatsCounter::Verifications, 1);
+
+    let old_session = &ctx.accounts.old_session;
+    ctx.accounts.nullifier_link.set_inner(NullifierLink {
+        nullifier: old_session.nullifier,
+        successor: new_nullifier,
+        domain: old_session.domain,
+        proof_hash,
+        authority,
+        linked_at: now,
+        bump: ctx.bumps.nullifier_link,
+    });
+
+    emit_cpi!(NullifierMigratedEvent {
+        nullifier: old_session.nullifier,
+        successor: new_nullifier,
+        domain: old_session.domain,
+        proof_hash,
+        grants,
+        migrated_at: now,
+    });
+
+    msg!("✓ Nullifier migrated");
+    Ok(details)
+}
+
+#[event]
+pub struct NullifierMigratedEvent {
+    pub nullifier: [u8; 32],
+    pub successor: [u8; 32],
+    pub domain: [u8; 32],
+    pub proof_hash: [u8; 32],
+    pub grants: Vec<Pubkey>,
+    pub migrated_at: i64,
+}
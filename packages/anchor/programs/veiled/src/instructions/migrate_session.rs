@@ -0,0 +1,192 @@
+// * Migrate session instruction
+// * Moves a session from the legacy nullifier PDA [b"nullifier", nullifier] to the
+// * domain-scoped one [b"nullifier", nullifier, sha256(domain)], counted by an open migration's
+// * Session step. Permissionless: the caller funds the new account and becomes its payer.
+// *
+// * Legacy sessions are the deployed NullifierAccount layout, read through
+// * LegacyNullifierAccount: a String domain and a unix-time i64 expiry, nothing else. They
+// * record no authority or payer, so the migrated session has no authority (gates that need
+// * its signature stay closed, validate_session_token works until expiry) and the legacy rent
+// * goes to the migrator toward the new account.
+
+use crate::errors::VeiledError;
+use crate::state::expiry::ExpiryKind;
+use crate::state::migration::*;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nullifier: [u8; 32], domain: [u8; 32])]
+pub struct MigrateSession<'info> {
+    #[account(mut, seeds = [b"migration_state"], bump = migration_state.bump)]
+    pub migration_state: Account<'info, MigrationState>,
+
+    /// CHECK: * Legacy session; the handler reads it as LegacyNullifierAccount and closes it
+    #[account(mut, owner = crate::ID, seeds = [b"nullifier", nullifier.as_ref()], bump)]
+    pub legacy_session: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = migrator,
+        space = 8 + NullifierAccount::MAX_SIZE,
+        seeds = [
+            b"nullifier",
+            nullifier.as_ref(),
+            NullifierAccount::domain_hash(&domain).as_ref()
+        ],
+        bump
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    #[account(mut)]
+    pub migrator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// * NullifierAccount as deployed before the domain-scoped seeds (same discriminator)
+#[derive(AnchorDeserialize, Debug)]
+pub struct LegacyNullifierAccount {
+    pub nullifier: [u8; 32],
+    pub domain: String,
+    pub created_at: i64,
+    pub expires_at: i64, // * Unix timestamp
+}
+
+impl LegacyNullifierAccount {
+    /// * Size the deployed verify_auth allocated whatever the domain's length: discriminator,
+    /// * nullifier, String (4-byte length + up to 32 bytes), created_at, expires_at
+    pub const LEN: usize = 8 + 32 + 4 + 32 + 8 + 8;
+
+    /// * Identified by discriminator and exact length; current NullifierAccounts share the
+    /// * discriminator but are larger. Shorter domains leave zero padding at the end.
+    pub fn try_from_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= 8 && data[..8] == *NullifierAccount::DISCRIMINATOR,
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        require!(data.len() == Self::LEN, ErrorCode::AccountDidNotDeserialize);
+        Self::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::AccountDidNotDeserialize.into())
+    }
+
+    /// * Null-padded domain, as NullifierAccount stores it
+    pub fn padded_domain(&self) -> Result<[u8; 32]> {
+        let bytes = self.domain.as_bytes();
+        require!(bytes.len() <= 32, VeiledError::DomainTooLong);
+        let mut domain = [0u8; 32];
+        domain[..bytes.len()].copy_from_slice(bytes);
+        Ok(domain)
+    }
+}
+
+pub fn handle_migrate_session(
+    ctx: Context<MigrateSession>,
+    nullifier: [u8; 32],
+    domain: [u8; 32],
+) -> Result<()> {
+    let legacy_info = ctx.accounts.legacy_session.to_account_info();
+    let legacy = LegacyNullifierAccount::try_from_data(&legacy_info.try_borrow_data()?)?;
+    require!(
+        legacy.padded_domain()? == domain,
+        VeiledError::DomainMismatch
+    );
+
+    let progress = ctx
+        .accounts
+        .migration_state
+        .record_migrated(MigratedAccount::Session, 1)?;
+
+    ctx.accounts.nullifier_account.set_inner(NullifierAccount {
+        nullifier,
+        domain,
+        domain_len: legacy.domain.len() as u8,
+        authority: Pubkey::default(),
+        created_at: legacy.created_at,
+        last_active_at: legacy.created_at,
+        idle_timeout_secs: 0,
+        expires_at: ExpiryKind::UnixTime(legacy.expires_at),
+        public_inputs_hash: [0u8; 32],
+        circuit_id: 0,
+        proof_hash: [0u8; 32],
+        verified_at: legacy.created_at,
+        verifier: Pubkey::default(),
+        finalizes_at: 0,
+        payer: ctx.accounts.migrator.key(),
+        device_key: Pubkey::default(),
+        bump: ctx.bumps.nullifier_account,
+    });
+
+    // * Close the legacy account: rent to the migrator, then hand it to the system program
+    let migrator_info = ctx.accounts.migrator.to_account_info();
+    **migrator_info.try_borrow_mut_lamports()? += legacy_info.lamports();
+    **legacy_info.try_borrow_mut_lamports()? = 0;
+    legacy_info.assign(&system_program::ID);
+    legacy_info.resize(0)?;
+
+    emit_cpi!(progress);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// * NullifierAccount exactly as the deployed program declared it
+    #[derive(AnchorSerialize)]
+    struct BaselineNullifierAccount {
+        nullifier: [u8; 32],
+        domain: String,
+        created_at: i64,
+        expires_at: i64,
+    }
+
+    /// * Account data the deployed verify_auth wrote for `domain`
+    fn baseline_data(domain: &str) -> Vec<u8> {
+        let mut data = NullifierAccount::DISCRIMINATOR.to_vec();
+        BaselineNullifierAccount {
+            nullifier: [4u8; 32],
+            domain: domain.to_string(),
+            created_at: 1_700_000_000,
+            expires_at: 1_702_592_000,
+        }
+        .serialize(&mut data)
+        .unwrap();
+        data.resize(LegacyNullifierAccount::LEN, 0);
+        data
+    }
+
+    #[test]
+    fn test_parses_baseline_layout() {
+        for domain in ["example.com", "a-domain-of-exactly-32-bytes.com"] {
+            let legacy = LegacyNullifierAccount::try_from_data(&baseline_data(domain)).unwrap();
+            assert_eq!(legacy.nullifier, [4u8; 32]);
+            assert_eq!(legacy.domain, domain);
+            assert_eq!(legacy.created_at, 1_700_000_000);
+            assert_eq!(legacy.expires_at, 1_702_592_000);
+
+            let padded = legacy.padded_domain().unwrap();
+            assert_eq!(&padded[..domain.len()], domain.as_bytes());
+            assert!(padded[domain.len()..].iter().all(|&b| b == 0));
+        }
+    }
+
+    #[test]
+    fn test_rejects_other_layouts() {
+        // * A current-layout session shares the discriminator but not the size
+        let mut current = baseline_data("example.com");
+        current.resize(8 + NullifierAccount::MAX_SIZE, 0);
+        assert_eq!(
+            LegacyNullifierAccount::try_from_data(&current).unwrap_err(),
+            ErrorCode::AccountDidNotDeserialize.into()
+        );
+
+        let mut other = baseline_data("example.com");
+        other[0] ^= 1;
+        assert_eq!(
+            LegacyNullifierAccount::try_from_data(&other).unwrap_err(),
+            ErrorCode::AccountDiscriminatorMismatch.into()
+        );
+    }
+}
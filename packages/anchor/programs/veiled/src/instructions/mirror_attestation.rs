@@ -0,0 +1,113 @@
+// * Mirror attestation instruction
+// * Copies an active Veiled attestation into the Solana Attestation Service under the bridge's
+// * credential and schema (see sas.rs), so SAS-aware programs and wallets can read claims
+// * proven through Veiled. Anyone may submit and pays the SAS account's rent.
+// *
+// * The mirror carries the attestation's expiry as of mirroring. A renewed attestation is
+// * mirrored again to a fresh SAS account; a revocation isn't propagated, so readers that must
+// * honor revocation check the Veiled attestation too (validate_attestation).
+
+use crate::errors::VeiledError;
+use crate::sas;
+use crate::state::attestation::Attestation;
+use crate::state::sas_bridge::SasBridge;
+use crate::state::verification_result::ClaimType;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct MirrorAttestation<'info> {
+    #[account(
+        seeds = [
+            b"attestation",
+            attestation.nullifier.as_ref(),
+            attestation.claim_type.seed(),
+            attestation.domain.as_ref()
+        ],
+        bump = attestation.bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    #[account(seeds = [b"sas_bridge"], bump = sas_bridge.bump)]
+    pub sas_bridge: Account<'info, SasBridge>,
+
+    /// CHECK: * Authorized signer on the bridge's SAS credential; signs the CPI, holds nothing
+    #[account(seeds = [b"sas_signer"], bump)]
+    pub sas_signer: UncheckedAccount<'info>,
+
+    /// CHECK: * SAS credential; checked by the SAS program
+    #[account(address = sas_bridge.credential @ VeiledError::SasBridgeMismatch)]
+    pub credential: UncheckedAccount<'info>,
+
+    /// CHECK: * SAS schema; checked by the SAS program
+    #[account(address = sas_bridge.schema @ VeiledError::SasBridgeMismatch)]
+    pub schema: UncheckedAccount<'info>,
+
+    /// CHECK: * SAS attestation PDA for sas::nonce(attestation, issued_at); created by SAS
+    #[account(mut)]
+    pub sas_attestation: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: * Solana Attestation Service program
+    #[account(address = sas::SAS_PROGRAM_ID)]
+    pub sas_program: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_mirror_attestation(ctx: Context<MirrorAttestation>) -> Result<()> {
+    let attestation = &ctx.accounts.attestation;
+    attestation.require_active(Clock::get()?.unix_timestamp)?;
+
+    let nonce = sas::nonce(&attestation.key(), attestation.issued_at);
+    let (ix, sas_attestation) = sas::create_attestation(
+        &ctx.accounts.payer.key(),
+        &ctx.accounts.sas_signer.key(),
+        &ctx.accounts.sas_bridge,
+        &nonce,
+        &sas::attestation_data(attestation),
+        attestation.expires_at,
+    );
+    require_keys_eq!(
+        ctx.accounts.sas_attestation.key(),
+        sas_attestation,
+        ErrorCode::ConstraintSeeds
+    );
+
+    invoke_signed(
+        &ix,
+        &[
+            ctx.accounts.payer.to_account_info(),
+            ctx.accounts.sas_signer.to_account_info(),
+            ctx.accounts.credential.to_account_info(),
+            ctx.accounts.schema.to_account_info(),
+            ctx.accounts.sas_attestation.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+            ctx.accounts.sas_program.to_account_info(),
+        ],
+        &[&[b"sas_signer", &[ctx.bumps.sas_signer]]],
+    )?;
+
+    emit_cpi!(AttestationMirroredEvent {
+        nullifier: attestation.nullifier,
+        claim_type: attestation.claim_type,
+        domain: attestation.domain,
+        sas_attestation,
+        expires_at: attestation.expires_at,
+    });
+
+    msg!("✓ Attestation mirrored to SAS: {}", sas_attestation);
+    Ok(())
+}
+
+#[event]
+pub struct AttestationMirroredEvent {
+    pub nullifier: [u8; 32],
+    pub claim_type: ClaimType,
+    pub domain: [u8; 32],
+    pub sas_attestation: Pubkey,
+    pub expires_at: i64,
+}
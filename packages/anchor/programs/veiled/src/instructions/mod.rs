@@ -1,10 +1,244 @@
 // * Instruction modules
 // * Re-export everything from each module so Anchor's #[program] macro can find Accounts structs
+pub mod acknowledge_access_log;
+pub mod add_personhood_provider;
+pub mod add_secp256r1_verifier;
+pub mod add_verifier;
+pub mod advance_verification_session;
+pub mod archive_attestation;
+pub mod attach_personhood_attestation;
+pub mod ban_nullifier;
+pub mod begin_migration;
+pub mod cancel_reveal_escrow;
+pub mod challenge_verification;
+pub mod claim_alias;
+pub mod claim_domain_fees;
+pub mod claim_usage_points;
+pub mod claim_username;
+pub mod close_expired_nullifier;
+pub mod close_notification_channel;
+pub mod close_result_receipt;
+pub mod close_session;
+pub mod close_verification_session;
+pub mod compact_access_logs;
+pub mod complete_migration;
+pub mod create_domain_fee_vault_ata;
+pub mod create_identity_root;
+pub mod create_treasury_ata;
+pub mod delegate_session;
+pub mod deprecate_circuit;
+pub mod finalize_verification_session;
+pub mod global_logout;
+pub mod grant_auditor;
 pub mod grant_permissions;
+#[cfg(feature = "devnet")]
+pub mod import_domain_config;
+#[cfg(feature = "devnet")]
+pub mod import_permission_grant;
+#[cfg(feature = "devnet")]
+pub mod import_proof_record;
+#[cfg(feature = "devnet")]
+pub mod import_session;
+pub mod init_nullifier_tree;
+pub mod initialize_config;
+pub mod initialize_domain_config;
+pub mod issue_vc_commitment;
+pub mod link_identity_root;
 pub mod log_permission_access;
+pub mod migrate_grant;
+pub mod migrate_nullifier;
+pub mod migrate_session;
+pub mod mirror_attestation;
+pub mod open_reveal_escrow;
+pub mod open_verification_session;
+pub mod pay_domain_fee;
+pub mod pay_fee;
+pub mod pin_vk_hash;
+pub mod publish_points_root;
+pub mod recover_identity;
+pub mod register_attestor;
+pub mod register_circuit;
+pub mod register_domain;
+pub mod register_groth16_vk;
+pub mod register_honk_vk;
+pub mod release_reveal_escrow;
+pub mod release_username;
+pub mod remove_personhood_provider;
+pub mod remove_secp256r1_verifier;
+pub mod remove_verifier;
+pub mod renew_attestation;
+pub mod renew_session;
+pub mod restore_attestation;
+pub mod revoke_attestation;
+pub mod revoke_auditor;
 pub mod revoke_permissions;
+pub mod revoke_session_delegate;
+pub mod rotate_verifier_key;
+pub mod select_verifier;
+pub mod set_accepted_mint;
+pub mod set_assurance_tier;
+pub mod set_attestor_status;
+pub mod set_challenge_window;
+pub mod set_domain_assurance_tier;
+pub mod set_domain_attestor_claims;
+pub mod set_domain_expiry_unit;
+pub mod set_domain_fee_share;
+pub mod set_domain_idle_timeout;
+pub mod set_domain_max_result_age;
+pub mod set_domain_nullifier_store;
+pub mod set_domain_origins;
+pub mod set_domain_paused;
+pub mod set_domain_rate_limit;
+pub mod set_domain_role;
+pub mod set_domain_session_bounds;
+pub mod set_domain_verifiers;
+pub mod set_guardians;
+pub mod set_notification_channel;
+pub mod set_pricing;
+pub mod set_receipt_retention;
+pub mod set_relayer_quota;
+pub mod set_sas_bridge;
+pub mod set_vc_status;
+pub mod set_verifier_grace_period;
+pub mod set_verifier_threshold;
+pub mod set_verifier_weight;
+pub mod store_attestation;
+pub mod sweep_treasury_ata;
+pub mod top_up_rent;
+pub mod touch_session;
+pub mod transfer_username;
+pub mod try_check_permission;
+pub mod unban_nullifier;
+pub mod unlink_identity_root;
+pub mod upload_proof_chunk;
+pub mod validate_attestation;
+pub mod validate_session_delegate;
+pub mod validate_session_token;
+pub mod verifier_heartbeat;
+pub mod verify_auth_batch;
+pub mod verify_auth_compressed;
+pub mod verify_auth_groth16;
+pub mod verify_auth_sharded;
+pub mod write_honk_vk;
 
 // * Re-export Accounts structs and handlers from each module
+pub use acknowledge_access_log::*;
+pub use add_personhood_provider::*;
+pub use add_secp256r1_verifier::*;
+pub use add_verifier::*;
+pub use advance_verification_session::*;
+pub use archive_attestation::*;
+pub use attach_personhood_attestation::*;
+pub use ban_nullifier::*;
+pub use begin_migration::*;
+pub use cancel_reveal_escrow::*;
+pub use challenge_verification::*;
+pub use claim_alias::*;
+pub use claim_domain_fees::*;
+pub use claim_usage_points::*;
+pub use claim_username::*;
+pub use close_expired_nullifier::*;
+pub use close_notification_channel::*;
+pub use close_result_receipt::*;
+pub use close_session::*;
+pub use close_verification_session::*;
+pub use compact_access_logs::*;
+pub use complete_migration::*;
+pub use create_domain_fee_vault_ata::*;
+pub use create_identity_root::*;
+pub use create_treasury_ata::*;
+pub use delegate_session::*;
+pub use deprecate_circuit::*;
+pub use finalize_verification_session::*;
+pub use global_logout::*;
+pub use grant_auditor::*;
 pub use grant_permissions::*;
+#[cfg(feature = "devnet")]
+pub use import_domain_config::*;
+#[cfg(feature = "devnet")]
+pub use import_permission_grant::*;
+#[cfg(feature = "devnet")]
+pub use import_proof_record::*;
+#[cfg(feature = "devnet")]
+pub use import_session::*;
+pub use init_nullifier_tree::*;
+pub use initialize_config::*;
+pub use initialize_domain_config::*;
+pub use issue_vc_commitment::*;
+pub use link_identity_root::*;
 pub use log_permission_access::*;
+pub use migrate_grant::*;
+pub use migrate_nullifier::*;
+pub use migrate_session::*;
+pub use mirror_attestation::*;
+pub use open_reveal_escrow::*;
+pub use open_verification_session::*;
+pub use pay_domain_fee::*;
+pub use pay_fee::*;
+pub use pin_vk_hash::*;
+pub use publish_points_root::*;
+pub use recover_identity::*;
+pub use register_attestor::*;
+pub use register_circuit::*;
+pub use register_domain::*;
+pub use register_groth16_vk::*;
+pub use register_honk_vk::*;
+pub use release_reveal_escrow::*;
+pub use release_username::*;
+pub use remove_personhood_provider::*;
+pub use remove_secp256r1_verifier::*;
+pub use remove_verifier::*;
+pub use renew_attestation::*;
+pub use renew_session::*;
+pub use restore_attestation::*;
+pub use revoke_attestation::*;
+pub use revoke_auditor::*;
 pub use revoke_permissions::*;
+pub use revoke_session_delegate::*;
+pub use rotate_verifier_key::*;
+pub use select_verifier::*;
+pub use set_accepted_mint::*;
+pub use set_assurance_tier::*;
+pub use set_attestor_status::*;
+pub use set_challenge_window::*;
+pub use set_domain_assurance_tier::*;
+pub use set_domain_attestor_claims::*;
+pub use set_domain_expiry_unit::*;
+pub use set_domain_fee_share::*;
+pub use set_domain_idle_timeout::*;
+pub use set_domain_max_result_age::*;
+pub use set_domain_nullifier_store::*;
+pub use set_domain_origins::*;
+pub use set_domain_paused::*;
+pub use set_domain_rate_limit::*;
+pub use set_domain_role::*;
+pub use set_domain_session_bounds::*;
+pub use set_domain_verifiers::*;
+pub use set_guardians::*;
+pub use set_notification_channel::*;
+pub use set_pricing::*;
+pub use set_receipt_retention::*;
+pub use set_relayer_quota::*;
+pub use set_sas_bridge::*;
+pub use set_vc_status::*;
+pub use set_verifier_grace_period::*;
+pub use set_verifier_threshold::*;
+pub use set_verifier_weight::*;
+pub use store_attestation::*;
+pub use sweep_treasury_ata::*;
+pub use top_up_rent::*;
+pub use touch_session::*;
+pub use transfer_username::*;
+pub use try_check_permission::*;
+pub use unban_nullifier::*;
+pub use unlink_identity_root::*;
+pub use upload_proof_chunk::*;
+pub use validate_attestation::*;
+pub use validate_session_delegate::*;
+pub use validate_session_token::*;
+pub use verifier_heartbeat::*;
+pub use verify_auth_batch::*;
+pub use verify_auth_compressed::*;
+pub use verify_auth_groth16::*;
+pub use verify_auth_sharded::*;
+pub use write_honk_vk::*;
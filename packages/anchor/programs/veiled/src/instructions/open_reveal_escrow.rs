@@ -0,0 +1,94 @@
+// * Open reveal escrow instruction
+// * Alternative RevealWalletAddress flow: instead of handing the address over directly, the
+// * user escrows it encrypted and commits to the decryption key, to be released only after
+// * `delay_secs` (e.g. escalating to KYC only if a dispute is still open a week later).
+
+use crate::errors::VeiledError;
+use crate::state::permission::*;
+use crate::state::reveal_escrow::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct OpenRevealEscrow<'info> {
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RevealEscrow::MAX_SIZE,
+        seeds = [
+            b"reveal_escrow",
+            permission_grant.nullifier.as_ref(),
+            permission_grant.app_id.as_ref()
+        ],
+        bump
+    )]
+    pub reveal_escrow: Account<'info, RevealEscrow>,
+
+    #[account(
+        seeds = [
+            b"permission",
+            permission_grant.nullifier.as_ref(),
+            permission_grant.app_id.as_ref()
+        ],
+        bump = permission_grant.bump
+    )]
+    pub permission_grant: Account<'info, PermissionGrant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_open_reveal_escrow(
+    ctx: Context<OpenRevealEscrow>,
+    ciphertext: Vec<u8>,
+    key_commitment: [u8; 32],
+    delay_secs: i64,
+) -> Result<()> {
+    let permission_grant = &ctx.accounts.permission_grant;
+    let clock = Clock::get()?;
+
+    // * The app must hold the reveal permission the escrow stands in for
+    if let Some(reason) = permission_grant.denial_reason(Permission::RevealWalletAddress, &clock) {
+        return Err(VeiledError::from(reason).into());
+    }
+
+    require!(
+        delay_secs > 0 && delay_secs <= RevealEscrow::MAX_DELAY_SECS,
+        VeiledError::InvalidRevealDelay
+    );
+    require!(
+        !ciphertext.is_empty() && ciphertext.len() <= RevealEscrow::MAX_CIPHERTEXT_LEN,
+        VeiledError::InvalidRevealCiphertext
+    );
+
+    let escrow = &mut ctx.accounts.reveal_escrow;
+    escrow.nullifier = permission_grant.nullifier;
+    escrow.app_id = permission_grant.app_id;
+    escrow.authority = ctx.accounts.authority.key();
+    escrow.ciphertext = ciphertext;
+    escrow.key_commitment = key_commitment;
+    escrow.reveal_key = [0; 32];
+    escrow.opened_at = clock.unix_timestamp;
+    escrow.reveals_at = clock.unix_timestamp + delay_secs;
+    escrow.released = false;
+    escrow.bump = ctx.bumps.reveal_escrow;
+
+    emit_cpi!(RevealEscrowOpenedEvent {
+        nullifier: escrow.nullifier,
+        app_id: escrow.app_id,
+        key_commitment,
+        reveals_at: escrow.reveals_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RevealEscrowOpenedEvent {
+    pub nullifier: [u8; 32],
+    pub app_id: Pubkey,
+    pub key_commitment: [u8; 32],
+    pub reveals_at: i64,
+}
@@ -0,0 +1,168 @@
+// * Open verification session instruction
+// * Starts a chunked on-chain UltraHonk verification (see honk.rs): commits to the hash of
+// * the upload, checks the circuit, key and domain up front, and counts against the payer's
+// * rate limit like any other verification. Sessions are keyed by their authority too, so
+// * nobody can hold another user's nullifier hostage with a session of their own.
+
+use crate::errors::VeiledError;
+use crate::honk::{self, PAIRING_POINT_OBJECT_LENGTH, PROOF_SIZE};
+use crate::sandbox;
+use crate::state::ban::NullifierBan;
+use crate::state::circuit::CircuitRegistry;
+use crate::state::domain::{DomainConfig, NullifierStore};
+use crate::state::honk::HonkVerifyingKey;
+use crate::state::rate_limit::PayerRateLimit;
+use crate::state::verification_session::*;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(circuit_id: u32, nullifier: [u8; 32], domain: [u8; 32])]
+pub struct OpenVerificationSession<'info> {
+    // * Header and scratch only; upload_proof_chunk grows the account as the upload arrives
+    #[account(
+        init,
+        payer = authority,
+        space = VerificationSession::UPLOAD_OFFSET,
+        seeds = [
+            b"verification_session",
+            authority.key().as_ref(),
+            nullifier.as_ref()
+        ],
+        bump
+    )]
+    pub verification_session: Account<'info, VerificationSession>,
+
+    /// CHECK: * NullifierAccount PDA for `nullifier` and `domain`; must not exist yet
+    #[account(
+        seeds = [
+            b"nullifier",
+            nullifier.as_ref(),
+            NullifierAccount::domain_hash(&domain).as_ref()
+        ],
+        bump
+    )]
+    pub nullifier_account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    // * Boxed: at ~1.8 KB the key would crowd the SBF stack frame
+    #[account(
+        seeds = [b"honk_vk", circuit_id.to_le_bytes().as_ref()],
+        bump = honk_vk.bump
+    )]
+    pub honk_vk: Box<Account<'info, HonkVerifyingKey>>,
+
+    /// CHECK: * DomainConfig PDA for `domain`; may not exist yet
+    #[account(seeds = [b"domain_config", DomainConfig::seed(&domain).as_ref()], bump)]
+    pub domain_config: UncheckedAccount<'info>,
+
+    /// CHECK: * NullifierBan PDA for `domain` and `nullifier`; exists only while it's banned
+    #[account(seeds = [b"nullifier_ban", domain.as_ref(), nullifier.as_ref()], bump)]
+    pub nullifier_ban: UncheckedAccount<'info>,
+
+    // * Same per-payer counter verify_auth enforces
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PayerRateLimit::MAX_SIZE,
+        seeds = [b"rate_limit", authority.key().as_ref()],
+        bump
+    )]
+    pub payer_rate_limit: AccountLoader<'info, PayerRateLimit>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_open_verification_session(
+    ctx: Context<OpenVerificationSession>,
+    circuit_id: u32,
+    nullifier: [u8; 32],
+    domain: [u8; 32],
+    proof_hash: [u8; 32],
+    public_inputs_count: u16,
+) -> Result<()> {
+    // * Same canonical form verify_auth accepts
+    let domain_str = DomainConfig::validate_domain(&domain)?;
+    require!(
+        !sandbox::is_sandbox_domain(domain_str.as_bytes()) || sandbox::ENABLED,
+        VeiledError::SandboxUnavailable
+    );
+
+    require!(
+        ctx.accounts.nullifier_account.data_is_empty(),
+        VeiledError::DuplicateNullifier
+    );
+
+    // * The key must be complete and expect exactly these public inputs (the nullifier and
+    // * domain first), followed by one proof
+    let vk = &ctx.accounts.honk_vk;
+    require!(vk.is_complete(), VeiledError::InvalidHonkVk);
+    require!(
+        public_inputs_count >= 2
+            && vk.public_inputs_size as usize
+                == usize::from(public_inputs_count) + PAIRING_POINT_OBJECT_LENGTH,
+        VeiledError::InvalidProofLength
+    );
+    let proof_len = (usize::from(public_inputs_count) * 32 + PROOF_SIZE) as u32;
+
+    let domain_config = DomainConfig::load(&ctx.accounts.domain_config)?;
+    let circuit = ctx.accounts.circuit_registry.require_active(circuit_id)?;
+    if let Some(domain_config) = &domain_config {
+        domain_config.require_not_paused()?;
+        domain_config.require_nullifier_store(NullifierStore::Accounts)?;
+        domain_config.check_origin(None)?;
+        domain_config.check_vk_hash(Some(&circuit.vk_hash))?;
+    }
+    NullifierBan::require_not_banned(&ctx.accounts.nullifier_ban)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    PayerRateLimit::enforce(
+        &ctx.accounts.payer_rate_limit,
+        ctx.accounts.authority.key(),
+        ctx.bumps.payer_rate_limit,
+        domain_config.as_ref().map_or(0, |config| config.rate_limit),
+        now,
+    )?;
+
+    let session = &mut ctx.accounts.verification_session;
+    session.authority = ctx.accounts.authority.key();
+    session.nullifier = nullifier;
+    session.domain = domain;
+    session.circuit_id = circuit_id;
+    session.proof_hash = proof_hash;
+    session.proof_len = proof_len;
+    session.public_inputs_count = public_inputs_count;
+    session.bytes_received = 0;
+    session.status = VerificationStatus::Uploading;
+    session.step = 0;
+    session.opened_at = now;
+    session.bump = ctx.bumps.verification_session;
+
+    emit_cpi!(VerificationSessionOpenedEvent {
+        nullifier,
+        domain,
+        circuit_id,
+        authority: session.authority,
+        proof_len,
+        steps: honk::total_steps(&ctx.accounts.honk_vk),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VerificationSessionOpenedEvent {
+    pub nullifier: [u8; 32],
+    pub domain: [u8; 32],
+    pub circuit_id: u32,
+    pub authority: Pubkey,
+    pub proof_len: u32,
+    /// * advance_verification_session steps a valid proof takes
+    pub steps: u32,
+}
@@ -0,0 +1,142 @@
+// * Pay domain fee instruction
+// * Pays the USD-quoted protocol fee for activity on a domain, in any accepted mint
+// *
+// * Same quote as pay_fee, but the fee is split: the FeeSharePolicy's share goes to the
+// * domain's fee vault for its treasurer to claim, the rest to the treasury.
+
+use crate::cpi_guard;
+use crate::instructions::pay_fee::FeeQuote;
+use crate::oracle::PYTH_RECEIVER_PROGRAM_ID;
+use crate::state;
+use crate::state::config::*;
+use crate::state::domain::DomainConfig;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, Token, TransferChecked};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PayDomainFee<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: * FeeSharePolicy PDA; may not exist (treasury keeps the whole fee)
+    #[account(seeds = [b"fee_share_policy"], bump)]
+    pub fee_share_policy: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    /// CHECK: * Treasury PDA - owner of the treasury ATA
+    #[account(seeds = [b"treasury"], bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// CHECK: * Domain fee vault PDA - owner of the vault ATA
+    #[account(seeds = [b"domain_fee_vault", domain_config.domain.as_ref()], bump)]
+    pub domain_fee_vault: UncheckedAccount<'info>,
+
+    /// CHECK: * SPL Token mint; must be on the allowlist (checked in handler)
+    #[account(owner = token_program.key())]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: * Address pinned to the treasury ATA for `mint`
+    #[account(
+        mut,
+        address = get_associated_token_address(&treasury.key(), &mint.key())
+    )]
+    pub treasury_ata: UncheckedAccount<'info>,
+
+    /// CHECK: * Address pinned to the domain fee vault ATA for `mint`
+    #[account(
+        mut,
+        address = get_associated_token_address(&domain_fee_vault.key(), &mint.key())
+    )]
+    pub domain_fee_vault_ata: UncheckedAccount<'info>,
+
+    /// CHECK: * Payer's token account for `mint`; validated by the token program
+    #[account(mut)]
+    pub payer_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: * Pyth PriceUpdateV2 account; owner checked here, feed id checked in handler
+    #[account(owner = PYTH_RECEIVER_PROGRAM_ID)]
+    pub price_update: UncheckedAccount<'info>,
+
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_pay_domain_fee(ctx: Context<PayDomainFee>, max_amount: u64) -> Result<()> {
+    // * No re-entry from transfer hook callbacks (see cpi_guard.rs)
+    cpi_guard::require_not_reentrant()?;
+
+    let config = &ctx.accounts.config;
+    let mint_key = ctx.accounts.mint.key();
+
+    let FeeQuote {
+        amount,
+        decimals,
+        price,
+    } = FeeQuote::new(
+        config,
+        &ctx.accounts.mint,
+        &ctx.accounts.price_update,
+        max_amount,
+    )?;
+
+    let policy = state::load_optional::<FeeSharePolicy>(&ctx.accounts.fee_share_policy)?;
+    let (protocol_amount, domain_amount) = FeeSharePolicy::split(policy.as_ref(), amount);
+
+    let transfers = [
+        (&ctx.accounts.treasury_ata, protocol_amount),
+        (&ctx.accounts.domain_fee_vault_ata, domain_amount),
+    ];
+    for (destination, amount) in transfers {
+        if amount == 0 {
+            continue;
+        }
+        token::transfer_checked(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                TransferChecked {
+                    from: ctx.accounts.payer_token_account.to_account_info(),
+                    mint: ctx.accounts.mint.to_account_info(),
+                    to: destination.to_account_info(),
+                    authority: ctx.accounts.payer.to_account_info(),
+                },
+            ),
+            amount,
+            decimals,
+        )?;
+    }
+
+    emit_cpi!(DomainFeePaidEvent {
+        payer: ctx.accounts.payer.key(),
+        domain: ctx.accounts.domain_config.domain,
+        mint: mint_key,
+        fee_usd_micros: config.fee_usd_micros,
+        amount,
+        domain_amount,
+        price: price.price,
+        exponent: price.exponent,
+        publish_time: price.publish_time,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainFeePaidEvent {
+    pub payer: Pubkey,
+    pub domain: [u8; 32],
+    pub mint: Pubkey,
+    pub fee_usd_micros: u64,
+    pub amount: u64,
+    pub domain_amount: u64,
+    pub price: i64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
@@ -0,0 +1,153 @@
+// * Pay fee instruction
+// * Pays the USD-quoted protocol fee in any accepted mint
+// *
+// * The token amount is computed at payment time from the mint's Pyth price feed,
+// * so the fee stays stable in USD terms regardless of token volatility.
+// * `max_amount` bounds what the payer is willing to spend (slippage protection).
+
+use crate::cpi_guard;
+use crate::errors::VeiledError;
+use crate::oracle::{OraclePrice, PYTH_RECEIVER_PROGRAM_ID};
+use crate::state::config::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, Mint, Token, TransferChecked};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PayFee<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: * Treasury PDA - owner of the destination ATA
+    #[account(seeds = [b"treasury"], bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// CHECK: * SPL Token mint; must be on the allowlist (checked in handler)
+    #[account(owner = token_program.key())]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: * Address pinned to the treasury ATA for `mint`
+    #[account(
+        mut,
+        address = get_associated_token_address(&treasury.key(), &mint.key())
+    )]
+    pub treasury_ata: UncheckedAccount<'info>,
+
+    /// CHECK: * Payer's token account for `mint`; validated by the token program
+    #[account(mut)]
+    pub payer_token_account: UncheckedAccount<'info>,
+
+    /// CHECK: * Pyth PriceUpdateV2 account; owner checked here, feed id checked in handler
+    #[account(owner = PYTH_RECEIVER_PROGRAM_ID)]
+    pub price_update: UncheckedAccount<'info>,
+
+    pub payer: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_pay_fee(ctx: Context<PayFee>, max_amount: u64) -> Result<()> {
+    // * No re-entry from transfer hook callbacks (see cpi_guard.rs)
+    cpi_guard::require_not_reentrant()?;
+
+    let config = &ctx.accounts.config;
+    let mint_key = ctx.accounts.mint.key();
+
+    let FeeQuote {
+        amount,
+        decimals,
+        price,
+    } = FeeQuote::new(
+        config,
+        &ctx.accounts.mint,
+        &ctx.accounts.price_update,
+        max_amount,
+    )?;
+
+    token::transfer_checked(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.payer_token_account.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.treasury_ata.to_account_info(),
+                authority: ctx.accounts.payer.to_account_info(),
+            },
+        ),
+        amount,
+        decimals,
+    )?;
+
+    emit_cpi!(FeePaidEvent {
+        payer: ctx.accounts.payer.key(),
+        mint: mint_key,
+        fee_usd_micros: config.fee_usd_micros,
+        amount,
+        price: price.price,
+        exponent: price.exponent,
+        publish_time: price.publish_time,
+    });
+
+    Ok(())
+}
+
+/// * Token amount of the protocol fee in a mint, priced when the fee is paid
+pub(crate) struct FeeQuote {
+    pub amount: u64,
+    pub decimals: u8,
+    pub price: OraclePrice,
+}
+
+impl FeeQuote {
+    /// * Quote the configured USD fee in `mint` from its Pyth price, bounded by `max_amount`
+    pub fn new(
+        config: &ProtocolConfig,
+        mint: &AccountInfo,
+        price_update: &AccountInfo,
+        max_amount: u64,
+    ) -> Result<Self> {
+        require!(config.fee_usd_micros > 0, VeiledError::FeeNotConfigured);
+
+        let accepted_mint = config
+            .accepted_mint(mint.key)
+            .ok_or(VeiledError::MintNotAccepted)?;
+
+        // * Read the price at payment time
+        let price = {
+            let data = price_update.try_borrow_data()?;
+            OraclePrice::from_price_update(&data, &accepted_mint.price_feed_id)?
+        };
+        let clock = Clock::get()?;
+        price.check(
+            clock.unix_timestamp,
+            config.max_price_age_secs,
+            config.max_price_conf_bps,
+        )?;
+
+        let decimals = {
+            let mint_data = mint.try_borrow_data()?;
+            Mint::try_deserialize(&mut &mint_data[..])?.decimals
+        };
+
+        let amount = price.quote_token_amount(config.fee_usd_micros, decimals)?;
+        require!(amount <= max_amount, VeiledError::FeeExceedsMaxAmount);
+
+        Ok(Self {
+            amount,
+            decimals,
+            price,
+        })
+    }
+}
+
+#[event]
+pub struct FeePaidEvent {
+    pub payer: Pubkey,
+    pub mint: Pubkey,
+    pub fee_usd_micros: u64,
+    pub amount: u64,
+    pub price: i64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
@@ -0,0 +1,38 @@
+// * Pin verification key hash instruction
+// * Sets the verification key hash verify_auth requires for a domain (owner only; zero unpins)
+
+use crate::errors::VeiledError;
+use crate::state::domain::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PinVkHash<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        has_one = owner @ VeiledError::NotDomainOwner
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_pin_vk_hash(ctx: Context<PinVkHash>, vk_hash: [u8; 32]) -> Result<()> {
+    let domain_config = &mut ctx.accounts.domain_config;
+    domain_config.pinned_vk_hash = vk_hash;
+
+    emit_cpi!(VkHashPinnedEvent {
+        domain: domain_config.domain,
+        vk_hash,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VkHashPinnedEvent {
+    pub domain: [u8; 32],
+    pub vk_hash: [u8; 32],
+}
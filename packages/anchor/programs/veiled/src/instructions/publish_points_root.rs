@@ -0,0 +1,63 @@
+// * Publish points root instruction
+// * Publishes the Merkle root of historical usage that early users claim governance points
+// * against (admin only). Republishing replaces the tree; nullifiers that already claimed
+// * keep their balance and can't claim again.
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::points::PointsRoot;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct PublishPointsRoot<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PointsRoot::MAX_SIZE,
+        seeds = [b"points_root"],
+        bump
+    )]
+    pub points_root: Account<'info, PointsRoot>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_publish_points_root(
+    ctx: Context<PublishPointsRoot>,
+    root: [u8; 32],
+    leaf_count: u32,
+) -> Result<()> {
+    require!(leaf_count > 0, VeiledError::InvalidPointsRoot);
+
+    let points_root = &mut ctx.accounts.points_root;
+    points_root.root = root;
+    points_root.leaf_count = leaf_count;
+    points_root.published_at = Clock::get()?.unix_timestamp;
+    points_root.bump = ctx.bumps.points_root;
+
+    emit_cpi!(PointsRootPublishedEvent {
+        root,
+        leaf_count,
+        published_at: points_root.published_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PointsRootPublishedEvent {
+    pub root: [u8; 32],
+    pub leaf_count: u32,
+    pub published_at: i64,
+}
@@ -0,0 +1,321 @@
+// * Recover identity instruction
+// * Moves a nullifier's sessions and permission grants to a new nullifier once `threshold` of
+// * its guardians (set_guardians) have signed a GuardianRecovery message for it in this
+// * transaction. For a user who lost the wallet (or secret) behind the old nullifier.
+// *
+// * Guardians can't pick the new nullifier on their own: the new authority also submits a fresh
+// * verifier-signed result for `new_nullifier` in `domain`. It gets the checks verify_auth
+// * applies (see verification.rs), and its proof and receipt are spent as in migrate_nullifier.
+// * Neither nullifier may be banned from `domain`.
+// *
+// * remaining_accounts: [old, new] pairs, one per moved account. `old` is a NullifierAccount (in
+// * `domain`) or PermissionGrant of the old nullifier; `new` is the same PDA derived for the new
+// * nullifier and is created here. Recovered sessions belong to the new authority and lose their
+// * device binding, since the device went with the wallet.
+// *
+// * Approvals are spent with the guardian set, which closes to the new authority, so everything
+// * the nullifier holds moves in one transaction (a lookup table fits many pairs). The new
+// * authority funds the new accounts and takes the old accounts' rent.
+
+use crate::errors::VeiledError;
+use crate::pda::{create_pda, write_account};
+use crate::state::ban::NullifierBan;
+use crate::state::circuit::CircuitRegistry;
+use crate::state::domain::{DomainConfig, NullifierStore};
+use crate::state::permission::PermissionGrant;
+use crate::state::proof_record::ProofRecord;
+use crate::state::recovery::{GuardianRecovery, GuardianSet};
+use crate::state::verification_result::SignedVerificationResult;
+use crate::state::verifier::VerifierRegistry;
+use crate::ultrahonk::VerificationResult;
+use crate::verification::ResultChecker;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(
+    result: SignedVerificationResult,
+    nullifier: [u8; 32],
+    new_nullifier: [u8; 32],
+    domain: [u8; 32]
+)]
+pub struct RecoverIdentity<'info> {
+    #[account(
+        mut,
+        close = new_authority,
+        seeds = [b"guardian_set", nullifier.as_ref()],
+        bump = guardian_set.bump
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    /// CHECK: * NullifierBan PDA for `domain` and `nullifier`; exists only while banned
+    #[account(seeds = [b"nullifier_ban", domain.as_ref(), nullifier.as_ref()], bump)]
+    pub old_nullifier_ban: UncheckedAccount<'info>,
+
+    /// CHECK: * NullifierBan PDA for `domain` and `new_nullifier`; exists only while banned
+    #[account(seeds = [b"nullifier_ban", domain.as_ref(), new_nullifier.as_ref()], bump)]
+    pub new_nullifier_ban: UncheckedAccount<'info>,
+
+    // * Same proof record verify_auth keeps, so a proof registers once
+    #[account(
+        init_if_needed,
+        payer = new_authority,
+        space = 8 + ProofRecord::MAX_SIZE,
+        seeds = [b"proof_record", result.proof_hash.as_ref()],
+        bump
+    )]
+    pub proof_record: Account<'info, ProofRecord>,
+
+    /// CHECK: * Created by the handler, as Anchor can't derive a seed hashed from an argument
+    /// * ResultReceipt PDA [b"result_receipt", sha256(signature)], spent on first use
+    #[account(mut)]
+    pub result_receipt: UncheckedAccount<'info>,
+
+    // * Authority of the recovered sessions; pays for the new accounts
+    #[account(mut)]
+    pub new_authority: Signer<'info>,
+
+    /// CHECK: * Verifier that signed the result, when it isn't the new authority
+    /// * Must be registered and among the result's Ed25519 signers; omit to use the new authority
+    pub verifier: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    /// CHECK: * DomainConfig PDA for `domain`; may not exist yet
+    /// * Address is pinned by seeds so callers can't dodge a domain's pins by omitting it
+    #[account(seeds = [b"domain_config", DomainConfig::seed(&domain).as_ref()], bump)]
+    pub domain_config: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"verifier_registry"], bump = verifier_registry.bump)]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    /// CHECK: * Secp256r1VerifierRegistry PDA (passkey verifiers); may not exist yet
+    #[account(seeds = [b"secp256r1_verifier_registry"], bump)]
+    pub secp256r1_verifier_registry: UncheckedAccount<'info>,
+
+    /// CHECK: * AssurancePolicy PDA (staleness window per tier); may not exist yet
+    #[account(seeds = [b"assurance_policy"], bump)]
+    pub assurance_policy: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_recover_identity<'info>(
+    ctx: Context<'_, '_, '_, 'info, RecoverIdentity<'info>>,
+    result: SignedVerificationResult,
+    nullifier: [u8; 32],
+    new_nullifier: [u8; 32],
+    domain: [u8; 32],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+
+    // * Neither side may be banned from the domain, so recovery can't shed a ban
+    NullifierBan::require_not_banned(&ctx.accounts.old_nullifier_ban)?;
+    NullifierBan::require_not_banned(&ctx.accounts.new_nullifier_ban)?;
+
+    let domain_config = DomainConfig::load(&ctx.accounts.domain_config)?;
+    if let Some(domain_config) = &domain_config {
+        domain_config.require_not_paused()?;
+        domain_config.require_nullifier_store(NullifierStore::Accounts)?;
+    }
+
+    // * The new nullifier must be proven for the domain, as in migrate_nullifier
+    let mut checker = ResultChecker::new(
+        ctx.accounts.new_authority.key(),
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.circuit_registry,
+        &ctx.accounts.verifier_registry,
+        &ctx.accounts.secp256r1_verifier_registry,
+        &ctx.accounts.assurance_policy,
+        now,
+    )?;
+    if let Some(verifier) = &ctx.accounts.verifier {
+        checker.name_verifier(verifier.key());
+    }
+    let accepted = checker.check(
+        VerificationResult::try_from(result)?,
+        new_nullifier,
+        domain,
+        domain_config.as_ref(),
+    )?;
+    accepted.require_session_claim()?;
+
+    let proof_record = &mut ctx.accounts.proof_record;
+    require!(proof_record.created_at == 0, VeiledError::ProofAlreadyUsed);
+    proof_record.set_inner(accepted.proof_record(ctx.bumps.proof_record, now));
+    accepted.consume_receipt(
+        &ctx.accounts.result_receipt,
+        &ctx.accounts.new_authority,
+        &ctx.accounts.system_program,
+        &ctx.accounts.verifier_registry,
+        now,
+    )?;
+
+    let guardian_set = &ctx.accounts.guardian_set;
+    let new_authority = &ctx.accounts.new_authority;
+
+    let approvals = guardian_set.approvals(
+        &ctx.accounts.instructions_sysvar,
+        &GuardianRecovery::message(
+            &nullifier,
+            &new_nullifier,
+            &new_authority.key(),
+            guardian_set.nonce,
+        ),
+    )?;
+    require!(
+        approvals >= guardian_set.threshold,
+        VeiledError::NotEnoughGuardianSignatures
+    );
+
+    let pairs = ctx.remaining_accounts.chunks_exact(2);
+    require!(
+        pairs.len() > 0 && pairs.remainder().is_empty(),
+        VeiledError::InvalidRecoveryAccounts
+    );
+
+    let mut sessions = 0u32;
+    let mut grants = 0u32;
+    for pair in pairs {
+        let (old_info, new_info) = (&pair[0], &pair[1]);
+        require_keys_eq!(
+            *old_info.owner,
+            crate::ID,
+            ErrorCode::AccountOwnedByWrongProgram
+        );
+        let data = old_info.try_borrow_data()?;
+        let discriminator = data.get(..8).ok_or(VeiledError::InvalidRecoveryAccounts)?;
+
+        if discriminator == NullifierAccount::DISCRIMINATOR {
+            let session = NullifierAccount::try_deserialize(&mut &data[..])?;
+            drop(data);
+            // * Only sessions in the domain whose bans were checked
+            require!(
+                session.domain == domain,
+                VeiledError::InvalidRecoveryAccounts
+            );
+            let domain_seed = session.domain_seed();
+            require_old_address(
+                old_info,
+                &session.nullifier,
+                &nullifier,
+                &[b"nullifier", nullifier.as_ref(), domain_seed.as_ref()],
+                session.bump,
+            )?;
+            let bump = create_pda(
+                new_authority,
+                new_info,
+                &ctx.accounts.system_program,
+                &[b"nullifier", new_nullifier.as_ref(), domain_seed.as_ref()],
+                8 + NullifierAccount::MAX_SIZE,
+                VeiledError::DuplicateNullifier,
+            )?;
+            write_account(
+                new_info,
+                &NullifierAccount {
+                    nullifier: new_nullifier,
+                    authority: new_authority.key(),
+                    payer: new_authority.key(),
+                    last_active_at: now,
+                    device_key: Pubkey::default(),
+                    bump,
+                    ..session
+                },
+            )?;
+            sessions += 1;
+        } else if discriminator == PermissionGrant::DISCRIMINATOR {
+            let grant = PermissionGrant::try_deserialize(&mut &data[..])?;
+            drop(data);
+            require_old_address(
+                old_info,
+                &grant.nullifier,
+                &nullifier,
+                &[b"permission", nullifier.as_ref(), grant.app_id.as_ref()],
+                grant.bump,
+            )?;
+            let bump = create_pda(
+                new_authority,
+                new_info,
+                &ctx.accounts.system_program,
+                &[b"permission", new_nullifier.as_ref(), grant.app_id.as_ref()],
+                8 + PermissionGrant::MAX_SIZE,
+                VeiledError::InvalidRecoveryAccounts,
+            )?;
+            write_account(
+                new_info,
+                &PermissionGrant {
+                    nullifier: new_nullifier,
+                    bump,
+                    ..grant
+                },
+            )?;
+            grants += 1;
+        } else {
+            return err!(VeiledError::InvalidRecoveryAccounts);
+        }
+
+        // * Close the old account: rent to the new authority, then hand it to the system program
+        **new_authority.try_borrow_mut_lamports()? += old_info.lamports();
+        **old_info.try_borrow_mut_lamports()? = 0;
+        old_info.assign(&system_program::ID);
+        old_info.resize(0)?;
+    }
+
+    emit_cpi!(IdentityRecoveredEvent {
+        nullifier,
+        new_nullifier,
+        domain,
+        proof_hash: accepted.result.proof_hash,
+        new_authority: new_authority.key(),
+        sessions,
+        grants,
+        approvals,
+        recovered_at: now,
+    });
+
+    msg!("✓ Identity recovered");
+    Ok(())
+}
+
+/// * The old account must be the old nullifier's own PDA, not a look-alike elsewhere
+fn require_old_address(
+    old_info: &AccountInfo,
+    stored_nullifier: &[u8; 32],
+    nullifier: &[u8; 32],
+    seeds: &[&[u8]],
+    bump: u8,
+) -> Result<()> {
+    require!(
+        stored_nullifier == nullifier,
+        VeiledError::InvalidRecoveryAccounts
+    );
+    let bump_seed = [bump];
+    let address = Pubkey::create_program_address(&[seeds, &[&bump_seed[..]]].concat(), &crate::ID)
+        .map_err(|_| VeiledError::InvalidRecoveryAccounts)?;
+    require_keys_eq!(
+        old_info.key(),
+        address,
+        VeiledError::InvalidRecoveryAccounts
+    );
+    Ok(())
+}
+
+#[event]
+pub struct IdentityRecoveredEvent {
+    pub nullifier: [u8; 32],
+    pub new_nullifier: [u8; 32],
+    pub domain: [u8; 32],
+    pub proof_hash: [u8; 32],
+    pub new_authority: Pubkey,
+    pub sessions: u32,
+    pub grants: u32,
+    pub approvals: u8,
+    pub recovered_at: i64,
+}
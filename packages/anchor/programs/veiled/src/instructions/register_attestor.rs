@@ -0,0 +1,72 @@
+// * Register attestor instruction
+// * Vets an external attestation provider's key for the claim types it may issue (admin only);
+// * registering an existing attestor again updates its name and claim types
+
+use crate::errors::VeiledError;
+use crate::state::attestor::*;
+use crate::state::config::*;
+use crate::state::verification_result::ClaimType;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(issuer: Pubkey)]
+pub struct RegisterAttestor<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Attestor::MAX_SIZE,
+        seeds = [b"attestor", issuer.as_ref()],
+        bump
+    )]
+    pub attestor: Account<'info, Attestor>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_register_attestor(
+    ctx: Context<RegisterAttestor>,
+    issuer: Pubkey,
+    name_hash: [u8; 32],
+    claim_mask: u8,
+) -> Result<()> {
+    require!(
+        claim_mask != 0 && claim_mask & !ClaimType::ATTESTATION_BITS == 0,
+        VeiledError::InvalidClaimMask
+    );
+
+    let attestor = &mut ctx.accounts.attestor;
+    if attestor.issuer == Pubkey::default() {
+        attestor.issuer = issuer;
+        attestor.status = AttestorStatus::Active;
+        attestor.registered_at = Clock::get()?.unix_timestamp;
+        attestor.bump = ctx.bumps.attestor;
+    }
+    attestor.name_hash = name_hash;
+    attestor.claim_mask = claim_mask;
+
+    emit_cpi!(AttestorRegisteredEvent {
+        issuer,
+        name_hash,
+        claim_mask,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AttestorRegisteredEvent {
+    pub issuer: Pubkey,
+    pub name_hash: [u8; 32],
+    pub claim_mask: u8,
+}
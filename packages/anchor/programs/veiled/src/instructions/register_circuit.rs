@@ -0,0 +1,70 @@
+// * Register circuit instruction
+// * Adds a circuit ID and its verification-key hash to the CircuitRegistry (admin only)
+
+use crate::errors::VeiledError;
+use crate::state::circuit::*;
+use crate::state::config::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RegisterCircuit<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + CircuitRegistry::MAX_SIZE,
+        seeds = [b"circuit_registry"],
+        bump
+    )]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_register_circuit(
+    ctx: Context<RegisterCircuit>,
+    circuit_id: u32,
+    vk_hash: [u8; 32],
+) -> Result<()> {
+    let registry = &mut ctx.accounts.circuit_registry;
+    registry.bump = ctx.bumps.circuit_registry;
+
+    // * IDs are never reassigned, even after deprecation, so old results can't match a new circuit
+    require!(
+        registry.circuit(circuit_id).is_none(),
+        VeiledError::CircuitAlreadyRegistered
+    );
+    require!(
+        registry.circuits.len() < CircuitRegistry::MAX_CIRCUITS,
+        VeiledError::TooManyCircuits
+    );
+
+    registry.circuits.push(CircuitEntry {
+        circuit_id,
+        vk_hash,
+        deprecated: false,
+    });
+
+    emit_cpi!(CircuitRegisteredEvent {
+        circuit_id,
+        vk_hash,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct CircuitRegisteredEvent {
+    pub circuit_id: u32,
+    pub vk_hash: [u8; 32],
+}
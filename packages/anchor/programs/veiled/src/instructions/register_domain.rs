@@ -0,0 +1,122 @@
+// * Register domain instruction
+// * Creates a domain's DomainConfig with its core settings in one step: the signer becomes the
+// * owner, and the verifier, default session lifetime, staleness window and permission cap are
+// * set up front rather than through the individual setters. The default lifetime only applies
+// * to sessions that don't ask for one; the session bounds stay at the protocol's (see
+// * set_domain_session_bounds). verify_auth and the other verification flows load the config
+// * whenever it exists, and grant_permissions enforces the permission cap.
+// *
+// * The config is keyed by the domain hash ([b"domain_config", sha256(domain)], see
+// * DomainConfig::seed), the address verify_auth derives too, so registered and initialized
+// * configs are the same account and a domain can only be registered once. A `.sol` domain can
+// * only be registered by the owner of its SNS name (see sns.rs).
+
+use crate::errors::VeiledError;
+use crate::sns;
+use crate::state::config::AssurancePolicy;
+use crate::state::domain::*;
+use crate::state::expiry::ExpiryUnit;
+use crate::state::permission::PermissionGrant;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(domain: [u8; 32])]
+pub struct RegisterDomain<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = 8 + DomainConfig::MAX_SIZE,
+        seeds = [b"domain_config", DomainConfig::seed(&domain).as_ref()],
+        bump
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    /// CHECK: * SNS name account the domain falls under; required for `.sol` domains only
+    /// * Checked by the handler against the name's address and its owner (see sns.rs)
+    pub sns_name_account: Option<UncheckedAccount<'info>>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_register_domain(
+    ctx: Context<RegisterDomain>,
+    domain: [u8; 32],
+    verifier: Option<Pubkey>,
+    default_session_secs: i64,
+    max_result_age_secs: u32,
+    max_permissions: u8,
+) -> Result<()> {
+    DomainConfig::validate_domain(&domain)?;
+    sns::require_domain_owner(
+        &domain,
+        ctx.accounts.sns_name_account.as_deref(),
+        ctx.accounts.owner.key,
+    )?;
+    // * Same bounds set_domain_session_bounds, set_domain_max_result_age and
+    // * import_permission_grant enforce
+    require!(
+        default_session_secs == 0
+            || (NullifierAccount::MIN_EXPIRY_SECONDS..=NullifierAccount::MAX_EXPIRY_SECONDS)
+                .contains(&default_session_secs),
+        VeiledError::InvalidSessionBounds
+    );
+    require!(
+        max_result_age_secs <= AssurancePolicy::MAX_RESULT_AGE_SECS,
+        VeiledError::InvalidResultAge
+    );
+    require!(
+        usize::from(max_permissions) <= PermissionGrant::MAX_PERMISSIONS,
+        VeiledError::TooManyPermissions
+    );
+
+    let owner = ctx.accounts.owner.key();
+    ctx.accounts.domain_config.set_inner(DomainConfig {
+        domain,
+        owner,
+        pinned_vk_hash: [0u8; 32],
+        verifiers: verifier.into_iter().collect(),
+        bump: ctx.bumps.domain_config,
+        challenge_window_secs: 0,
+        rate_limit: 0,
+        assurance_tier: 0,
+        max_result_age_secs,
+        operator: Pubkey::default(),
+        treasurer: Pubkey::default(),
+        paused: false,
+        allowed_origins: Vec::new(),
+        min_session_secs: 0,
+        max_session_secs: 0,
+        attestor_claim_mask: 0,
+        idle_timeout_secs: 0,
+        nullifier_store: NullifierStore::Accounts,
+        max_permissions,
+        session_expiry_unit: ExpiryUnit::UnixTime,
+        default_session_secs,
+    });
+
+    emit_cpi!(DomainRegisteredEvent {
+        domain,
+        owner,
+        verifier,
+        default_session_secs,
+        max_result_age_secs,
+        max_permissions,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainRegisteredEvent {
+    pub domain: [u8; 32],
+    pub owner: Pubkey,
+    pub verifier: Option<Pubkey>,
+    pub default_session_secs: i64,
+    pub max_result_age_secs: u32,
+    pub max_permissions: u8,
+}
@@ -0,0 +1,91 @@
+// * Register Groth16 verifying key instruction
+// * Stores the on-chain verifying key for a registered circuit so verify_auth_groth16 can
+// * check its proofs natively (admin only). The key must hash to the circuit's vk_hash, and
+// * like circuit IDs it can't be replaced once stored.
+
+use crate::errors::VeiledError;
+use crate::state::circuit::*;
+use crate::state::config::*;
+use crate::state::groth16::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(circuit_id: u32)]
+pub struct RegisterGroth16Vk<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Groth16VerifyingKey::MAX_SIZE,
+        seeds = [b"groth16_vk", circuit_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub groth16_vk: Account<'info, Groth16VerifyingKey>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_register_groth16_vk(
+    ctx: Context<RegisterGroth16Vk>,
+    circuit_id: u32,
+    alpha_g1: [u8; 64],
+    beta_g2: [u8; 128],
+    gamma_g2: [u8; 128],
+    delta_g2: [u8; 128],
+    ic: Vec<[u8; 64]>,
+) -> Result<()> {
+    let registered_vk_hash = ctx
+        .accounts
+        .circuit_registry
+        .require_active(circuit_id)?
+        .vk_hash;
+
+    // * Nullifier and domain come first, plus up to two circuit-specific inputs
+    let public_inputs = ic.len().saturating_sub(1);
+    require!(
+        (Groth16VerifyingKey::MIN_PUBLIC_INPUTS..=Groth16VerifyingKey::MAX_PUBLIC_INPUTS)
+            .contains(&public_inputs),
+        VeiledError::InvalidGroth16Vk
+    );
+
+    let vk = &mut ctx.accounts.groth16_vk;
+    vk.circuit_id = circuit_id;
+    vk.alpha_g1 = alpha_g1;
+    vk.beta_g2 = beta_g2;
+    vk.gamma_g2 = gamma_g2;
+    vk.delta_g2 = delta_g2;
+    vk.ic = ic;
+    vk.bump = ctx.bumps.groth16_vk;
+
+    // * Ties the key to what the admin registered, so domains pinning vk_hash pin this key
+    let vk_hash = vk.hash();
+    require!(vk_hash == registered_vk_hash, VeiledError::InvalidGroth16Vk);
+
+    emit_cpi!(Groth16VkRegisteredEvent {
+        circuit_id,
+        vk_hash,
+        public_inputs: public_inputs as u8,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct Groth16VkRegisteredEvent {
+    pub circuit_id: u32,
+    pub vk_hash: [u8; 32],
+    pub public_inputs: u8,
+}
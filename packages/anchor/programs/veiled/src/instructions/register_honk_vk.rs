@@ -0,0 +1,69 @@
+// * Register UltraHonk verifying key instruction
+// * Creates the on-chain verifying key for a registered circuit that verification sessions
+// * check proofs against (admin only). Only the header fits here; write_honk_vk fills in the
+// * commitments, and the key can't be used or replaced once they match the circuit's vk_hash.
+
+use crate::errors::VeiledError;
+use crate::honk::{CONST_PROOF_SIZE_LOG_N, PAIRING_POINT_OBJECT_LENGTH};
+use crate::state::circuit::*;
+use crate::state::config::*;
+use crate::state::honk::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(circuit_id: u32)]
+pub struct RegisterHonkVk<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    // * Boxed: at ~1.8 KB the key would crowd the SBF stack frame
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + HonkVerifyingKey::MAX_SIZE,
+        seeds = [b"honk_vk", circuit_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub honk_vk: Box<Account<'info, HonkVerifyingKey>>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_register_honk_vk(
+    ctx: Context<RegisterHonkVk>,
+    circuit_id: u32,
+    log_circuit_size: u8,
+    public_inputs_size: u32,
+    pub_inputs_offset: u32,
+) -> Result<()> {
+    ctx.accounts.circuit_registry.require_active(circuit_id)?;
+
+    // * Nullifier and domain come first, then the pairing point object bb appends
+    require!(
+        (1..=CONST_PROOF_SIZE_LOG_N).contains(&usize::from(log_circuit_size))
+            && public_inputs_size as usize >= 2 + PAIRING_POINT_OBJECT_LENGTH
+            && public_inputs_size < 1 << log_circuit_size,
+        VeiledError::InvalidHonkVk
+    );
+
+    let vk = &mut ctx.accounts.honk_vk;
+    vk.circuit_id = circuit_id;
+    vk.log_circuit_size = log_circuit_size;
+    vk.public_inputs_size = public_inputs_size;
+    vk.pub_inputs_offset = pub_inputs_offset;
+    vk.commitments = [[0u8; 64]; HonkVerifyingKey::COMMITMENTS];
+    vk.written = 0;
+    vk.bump = ctx.bumps.honk_vk;
+
+    Ok(())
+}
@@ -0,0 +1,82 @@
+// * Release reveal escrow instruction
+// * Publishes the escrow's decryption key once the delay has passed, so the app can decrypt
+// * the wallet address. Permissionless: the user's client, or a keeper it handed the key to,
+// * can submit it. The key must match the commitment and the grant must still be live.
+
+use crate::errors::VeiledError;
+use crate::state::permission::*;
+use crate::state::reveal_escrow::*;
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReleaseRevealEscrow<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"reveal_escrow",
+            reveal_escrow.nullifier.as_ref(),
+            reveal_escrow.app_id.as_ref()
+        ],
+        bump = reveal_escrow.bump
+    )]
+    pub reveal_escrow: Account<'info, RevealEscrow>,
+
+    #[account(
+        seeds = [
+            b"permission",
+            reveal_escrow.nullifier.as_ref(),
+            reveal_escrow.app_id.as_ref()
+        ],
+        bump = permission_grant.bump
+    )]
+    pub permission_grant: Account<'info, PermissionGrant>,
+}
+
+pub fn handle_release_reveal_escrow(
+    ctx: Context<ReleaseRevealEscrow>,
+    reveal_key: [u8; 32],
+) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // * Revoking the grant during the delay blocks the release
+    if let Some(reason) = ctx
+        .accounts
+        .permission_grant
+        .denial_reason(Permission::RevealWalletAddress, &clock)
+    {
+        return Err(VeiledError::from(reason).into());
+    }
+
+    let escrow = &mut ctx.accounts.reveal_escrow;
+    require!(!escrow.released, VeiledError::RevealAlreadyReleased);
+    require!(
+        clock.unix_timestamp >= escrow.reveals_at,
+        VeiledError::RevealNotReady
+    );
+    require!(
+        hashv(&[&reveal_key]).to_bytes() == escrow.key_commitment,
+        VeiledError::RevealKeyMismatch
+    );
+
+    escrow.reveal_key = reveal_key;
+    escrow.released = true;
+
+    emit_cpi!(RevealEscrowReleasedEvent {
+        nullifier: escrow.nullifier,
+        app_id: escrow.app_id,
+        reveal_key,
+        released_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RevealEscrowReleasedEvent {
+    pub nullifier: [u8; 32],
+    pub app_id: Pubkey,
+    pub reveal_key: [u8; 32],
+    pub released_at: i64,
+}
@@ -0,0 +1,42 @@
+// * Release username instruction
+// * The holder gives a username up: the account closes, its rent goes back to the authority
+// * and the name can be claimed again by anyone.
+
+use crate::errors::VeiledError;
+use crate::state::username::Username;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct ReleaseUsername<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"username", username.name.as_ref()],
+        bump = username.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub username: Account<'info, Username>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_release_username(ctx: Context<ReleaseUsername>) -> Result<()> {
+    let username = &ctx.accounts.username;
+
+    emit_cpi!(UsernameReleasedEvent {
+        name: username.name,
+        nullifier: username.nullifier,
+        released_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct UsernameReleasedEvent {
+    pub name: [u8; 32],
+    pub nullifier: [u8; 32],
+    pub released_at: i64,
+}
@@ -0,0 +1,51 @@
+// * Remove personhood provider instruction
+// * Stops accepting a provider's attestations (admin only); scores it already attached read
+// * as zero from then on (see PersonhoodAttestation::current_score)
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::personhood::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemovePersonhoodProvider<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"personhood_providers"],
+        bump = personhood_providers.bump
+    )]
+    pub personhood_providers: Account<'info, PersonhoodProviderRegistry>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_remove_personhood_provider(
+    ctx: Context<RemovePersonhoodProvider>,
+    provider: Pubkey,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.personhood_providers;
+
+    let index = registry
+        .providers
+        .iter()
+        .position(|registered| registered == &provider)
+        .ok_or(VeiledError::PersonhoodProviderNotRegistered)?;
+    registry.providers.swap_remove(index);
+
+    emit_cpi!(PersonhoodProviderRemovedEvent { provider });
+
+    Ok(())
+}
+
+#[event]
+pub struct PersonhoodProviderRemovedEvent {
+    pub provider: Pubkey,
+}
@@ -0,0 +1,51 @@
+// * Remove secp256r1 verifier instruction
+// * Stops verify_auth from counting signatures by a passkey verifier key (admin only)
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::verifier::*;
+use crate::ultrahonk::SECP256R1_PUBKEY_LEN;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveSecp256r1Verifier<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"secp256r1_verifier_registry"],
+        bump = secp256r1_verifier_registry.bump
+    )]
+    pub secp256r1_verifier_registry: Account<'info, Secp256r1VerifierRegistry>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_remove_secp256r1_verifier(
+    ctx: Context<RemoveSecp256r1Verifier>,
+    verifier: [u8; SECP256R1_PUBKEY_LEN],
+) -> Result<()> {
+    let registry = &mut ctx.accounts.secp256r1_verifier_registry;
+
+    let index = registry
+        .verifiers
+        .iter()
+        .position(|registered| registered == &verifier)
+        .ok_or(VeiledError::VerifierNotRegistered)?;
+    registry.verifiers.swap_remove(index);
+
+    emit_cpi!(Secp256r1VerifierRemovedEvent { verifier });
+
+    Ok(())
+}
+
+#[event]
+pub struct Secp256r1VerifierRemovedEvent {
+    pub verifier: [u8; SECP256R1_PUBKEY_LEN],
+}
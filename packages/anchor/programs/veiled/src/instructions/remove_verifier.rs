@@ -0,0 +1,53 @@
+// * Remove verifier instruction
+// * Stops verify_auth from accepting results signed by a verifier key (admin only)
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::verifier::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RemoveVerifier<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"verifier_registry"],
+        bump = verifier_registry.bump
+    )]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_remove_verifier(ctx: Context<RemoveVerifier>, verifier: Pubkey) -> Result<()> {
+    let registry = &mut ctx.accounts.verifier_registry;
+
+    let index = registry
+        .verifiers
+        .iter()
+        .position(|registered| registered == &verifier)
+        .ok_or(VeiledError::VerifierNotRegistered)?;
+    registry.verifiers.swap_remove(index);
+
+    // * Lower the threshold first; otherwise no result could reach quorum
+    require!(
+        registry.verifiers.len() >= registry.required_signatures(),
+        VeiledError::InvalidVerifierThreshold
+    );
+
+    emit_cpi!(VerifierRemovedEvent { verifier });
+
+    Ok(())
+}
+
+#[event]
+pub struct VerifierRemovedEvent {
+    pub verifier: Pubkey,
+}
@@ -0,0 +1,158 @@
+// * Renew attestation instruction
+// * Extends an attestation from a fresh verifier-signed result for the same claim, so
+// * long-lived credentials (KYC, age) are refreshed in place rather than re-created. The result
+// * gets the same checks store_attestation applies and is spent through its result receipt.
+
+use crate::errors::VeiledError;
+use crate::state::attestation::Attestation;
+use crate::state::attestor::Attestor;
+use crate::state::circuit::CircuitRegistry;
+use crate::state::domain::DomainConfig;
+use crate::state::verification_result::{ClaimType, SignedVerificationResult};
+use crate::state::verifier::VerifierRegistry;
+use crate::ultrahonk::VerificationResult;
+use crate::verification::ResultChecker;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RenewAttestation<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"attestation",
+            attestation.nullifier.as_ref(),
+            attestation.claim_type.seed(),
+            attestation.domain.as_ref()
+        ],
+        bump = attestation.bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    /// CHECK: * Created by the handler, as Anchor can't derive a seed hashed from an argument
+    /// * ResultReceipt PDA [b"result_receipt", sha256(signature)], spent on first use
+    #[account(mut)]
+    pub result_receipt: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: * Verifier that signed the result, when it isn't the fee-paying authority
+    /// * Must be registered and among the result's Ed25519 signers; omit to use the authority
+    pub verifier: Option<UncheckedAccount<'info>>,
+
+    // * Issuer's Attestor registration; required when the domain restricts the claim type
+    #[account(seeds = [b"attestor", attestor.issuer.as_ref()], bump = attestor.bump)]
+    pub attestor: Option<Account<'info, Attestor>>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    /// CHECK: * DomainConfig PDA for the attestation's domain; may not exist yet
+    /// * Address is pinned by seeds so callers can't dodge a domain's pins by omitting it
+    #[account(seeds = [b"domain_config", DomainConfig::seed(&attestation.domain).as_ref()], bump)]
+    pub domain_config: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"verifier_registry"], bump = verifier_registry.bump)]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    /// CHECK: * Secp256r1VerifierRegistry PDA (passkey verifiers); may not exist yet
+    #[account(seeds = [b"secp256r1_verifier_registry"], bump)]
+    pub secp256r1_verifier_registry: UncheckedAccount<'info>,
+
+    /// CHECK: * AssurancePolicy PDA (staleness window per tier); may not exist yet
+    #[account(seeds = [b"assurance_policy"], bump)]
+    pub assurance_policy: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_renew_attestation(
+    ctx: Context<RenewAttestation>,
+    result: SignedVerificationResult,
+    expires_in: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let expires_at = Attestation::expiry(now, expires_in)?;
+    let attestation = &ctx.accounts.attestation;
+    require!(!attestation.is_revoked(), VeiledError::AttestationRevoked);
+    let (nullifier, domain, claim_type) = (
+        attestation.nullifier,
+        attestation.domain,
+        attestation.claim_type,
+    );
+    // * Renewal only ever extends; the fresh result replaces what was proven and by whom
+    let expires_at = expires_at.max(attestation.expires_at);
+    let bump = attestation.bump;
+
+    let domain_config = DomainConfig::load(&ctx.accounts.domain_config)?;
+    if let Some(domain_config) = &domain_config {
+        domain_config.require_not_paused()?;
+    }
+
+    let mut checker = ResultChecker::new(
+        ctx.accounts.authority.key(),
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.circuit_registry,
+        &ctx.accounts.verifier_registry,
+        &ctx.accounts.secp256r1_verifier_registry,
+        &ctx.accounts.assurance_policy,
+        now,
+    )?;
+    if let Some(verifier) = &ctx.accounts.verifier {
+        checker.name_verifier(verifier.key());
+    }
+    // * Bound to the attestation's nullifier and domain like any result to its arguments
+    let accepted = checker.check(
+        VerificationResult::try_from(result)?,
+        nullifier,
+        domain,
+        domain_config.as_ref(),
+    )?;
+    require!(
+        accepted.claim_type == claim_type,
+        VeiledError::AttestationClaimMismatch
+    );
+    Attestor::check_issuer(
+        domain_config.as_ref(),
+        ctx.accounts.attestor.as_deref(),
+        &accepted.verifier,
+        accepted.claim_type,
+    )?;
+
+    accepted.consume_receipt(
+        &ctx.accounts.result_receipt,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        &ctx.accounts.verifier_registry,
+        now,
+    )?;
+
+    let attestation = accepted.attestation(bump, now, expires_at);
+    emit_cpi!(AttestationRenewedEvent {
+        nullifier,
+        claim_type,
+        domain,
+        claim_data_hash: attestation.claim_data_hash,
+        issuer: attestation.issuer,
+        expires_at,
+    });
+    ctx.accounts.attestation.set_inner(attestation);
+
+    msg!("✓ Attestation renewed, expires at {}", expires_at);
+    Ok(())
+}
+
+#[event]
+pub struct AttestationRenewedEvent {
+    pub nullifier: [u8; 32],
+    pub claim_type: ClaimType,
+    pub domain: [u8; 32],
+    pub claim_data_hash: [u8; 32],
+    pub issuer: Pubkey,
+    pub expires_at: i64,
+}
@@ -0,0 +1,209 @@
+// * Renew session instruction
+// * Extends a session from a fresh verifier-signed result for the same nullifier and domain,
+// * so a user keeps one NullifierAccount rather than re-registering before it lapses. The
+// * result gets the same checks verify_auth applies (see verification.rs), including the
+// * domain's nullifier store and ban; its proof registers through a ProofRecord and the result
+// * is spent through its receipt.
+// *
+// * Only the session's authority may renew it, and only while the session is usable
+// * (NullifierAccount::require_usable): a pending session can't swap the proof a challenge
+// * would dispute, a logged-out one stays revoked, and a lapsed one is replaced through
+// * verify_auth instead. The session keeps its authority, payer, created_at and device key;
+// * the proof fields move to the fresh result.
+
+use crate::errors::VeiledError;
+use crate::state::ban::NullifierBan;
+use crate::state::circuit::CircuitRegistry;
+use crate::state::domain::{DomainConfig, NullifierStore};
+use crate::state::expiry::ExpiryKind;
+use crate::state::proof_record::ProofRecord;
+use crate::state::session::SessionDetails;
+use crate::state::verification_result::SignedVerificationResult;
+use crate::state::verifier::VerifierRegistry;
+use crate::ultrahonk::VerificationResult;
+use crate::verification::ResultChecker;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(result: SignedVerificationResult, domain: [u8; 32])]
+pub struct RenewSession<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"nullifier",
+            nullifier_account.nullifier.as_ref(),
+            nullifier_account.domain_seed().as_ref()
+        ],
+        bump = nullifier_account.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    // * Same proof record verify_auth keeps, so a proof renews (or registers) once
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProofRecord::MAX_SIZE,
+        seeds = [b"proof_record", result.proof_hash.as_ref()],
+        bump
+    )]
+    pub proof_record: Account<'info, ProofRecord>,
+
+    /// CHECK: * Created by the handler, as Anchor can't derive a seed hashed from an argument
+    /// * ResultReceipt PDA [b"result_receipt", sha256(signature)], spent on first use
+    #[account(mut)]
+    pub result_receipt: UncheckedAccount<'info>,
+
+    // * Session's authority (has_one); pays for the proof record and receipt
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: * Verifier that signed the result, when it isn't the fee-paying authority
+    /// * Must be registered and among the result's Ed25519 signers; omit to use the authority
+    pub verifier: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    /// CHECK: * DomainConfig PDA for `domain`; may not exist yet
+    /// * Address is pinned by seeds so callers can't dodge a domain's pins by omitting it
+    #[account(seeds = [b"domain_config", DomainConfig::seed(&domain).as_ref()], bump)]
+    pub domain_config: UncheckedAccount<'info>,
+
+    /// CHECK: * NullifierBan PDA for `domain` and the session's nullifier; exists only while banned
+    #[account(
+        seeds = [
+            b"nullifier_ban",
+            domain.as_ref(),
+            nullifier_account.nullifier.as_ref()
+        ],
+        bump
+    )]
+    pub nullifier_ban: UncheckedAccount<'info>,
+
+    /// CHECK: * LogoutEpoch PDA of the session's authority; may not exist yet
+    /// * Address is pinned by seeds so callers can't substitute another identity's epoch
+    #[account(
+        seeds = [b"logout_epoch", nullifier_account.authority.as_ref()],
+        bump
+    )]
+    pub logout_epoch: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"verifier_registry"], bump = verifier_registry.bump)]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    /// CHECK: * Secp256r1VerifierRegistry PDA (passkey verifiers); may not exist yet
+    #[account(seeds = [b"secp256r1_verifier_registry"], bump)]
+    pub secp256r1_verifier_registry: UncheckedAccount<'info>,
+
+    /// CHECK: * AssurancePolicy PDA (staleness window per tier); may not exist yet
+    #[account(seeds = [b"assurance_policy"], bump)]
+    pub assurance_policy: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_renew_session(
+    ctx: Context<RenewSession>,
+    result: SignedVerificationResult,
+    domain: [u8; 32],
+    expires_in: i64,
+) -> Result<SessionDetails> {
+    let clock = Clock::get()?;
+    let now = clock.unix_timestamp;
+    ctx.accounts
+        .nullifier_account
+        .require_usable(&clock, &ctx.accounts.logout_epoch)?;
+
+    let domain_config = DomainConfig::load(&ctx.accounts.domain_config)?;
+    if let Some(domain_config) = &domain_config {
+        domain_config.require_not_paused()?;
+        domain_config.require_nullifier_store(NullifierStore::Accounts)?;
+    }
+    NullifierBan::require_not_banned(&ctx.accounts.nullifier_ban)?;
+    let expires_at = DomainConfig::session_expires_at(domain_config.as_ref(), expires_in, &clock)?;
+
+    let mut checker = ResultChecker::new(
+        ctx.accounts.authority.key(),
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.circuit_registry,
+        &ctx.accounts.verifier_registry,
+        &ctx.accounts.secp256r1_verifier_registry,
+        &ctx.accounts.assurance_policy,
+        now,
+    )?;
+    if let Some(verifier) = &ctx.accounts.verifier {
+        checker.name_verifier(verifier.key());
+    }
+    let accepted = checker.check(
+        VerificationResult::try_from(result)?,
+        ctx.accounts.nullifier_account.nullifier,
+        domain,
+        domain_config.as_ref(),
+    )?;
+    accepted.require_session_claim()?;
+
+    // * A result for another domain can't stretch this one's session
+    require!(
+        accepted.domain == ctx.accounts.nullifier_account.domain,
+        VeiledError::DomainMismatch
+    );
+
+    let proof_record = &mut ctx.accounts.proof_record;
+    require!(proof_record.created_at == 0, VeiledError::ProofAlreadyUsed);
+    proof_record.set_inner(accepted.proof_record(ctx.bumps.proof_record, now));
+
+    accepted.consume_receipt(
+        &ctx.accounts.result_receipt,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        &ctx.accounts.verifier_registry,
+        now,
+    )?;
+
+    let session = &mut ctx.accounts.nullifier_account;
+    let renewed = accepted.into_session(
+        session.authority,
+        session.payer,
+        session.bump,
+        now,
+        expires_at,
+    );
+    // * Never shortens the session, unless the domain has since switched expiry units
+    let expires_at = session.expires_at.extend(renewed.expires_at);
+    let created_at = session.created_at;
+    let device_key = session.device_key;
+    emit_cpi!(SessionRenewedEvent {
+        nullifier: session.nullifier,
+        domain,
+        proof_hash: renewed.proof_hash,
+        expires_at,
+    });
+    session.set_inner(NullifierAccount {
+        created_at,
+        expires_at,
+        device_key,
+        ..renewed
+    });
+
+    msg!("✓ Session renewed");
+    Ok(SessionDetails {
+        nullifier_account: session.key(),
+        created_at,
+        expires_at,
+    })
+}
+
+#[event]
+pub struct SessionRenewedEvent {
+    pub nullifier: [u8; 32],
+    pub domain: [u8; 32],
+    pub proof_hash: [u8; 32],
+    pub expires_at: ExpiryKind,
+}
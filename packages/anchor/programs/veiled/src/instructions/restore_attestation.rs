@@ -0,0 +1,80 @@
+// * Restore attestation instruction
+// * Brings an archived personhood attestation back from its fields (anyone may submit and pay
+// * the rent; the archive's rent is refunded to them). The provider signed these fields when
+// * the attestation was attached, so matching the archived hash is enough; the restored
+// * attestation reads like any other (current_score is zero if it has since expired).
+
+use crate::errors::VeiledError;
+use crate::state::personhood::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(nullifier: [u8; 32], provider: Pubkey)]
+pub struct RestoreAttestation<'info> {
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"personhood_archive", nullifier.as_ref(), provider.as_ref()],
+        bump = archive.bump
+    )]
+    pub archive: Account<'info, ArchivedPersonhoodAttestation>,
+
+    // * init fails if a newer attestation was attached while this one was archived
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + PersonhoodAttestation::MAX_SIZE,
+        seeds = [b"personhood", nullifier.as_ref(), provider.as_ref()],
+        bump
+    )]
+    pub attestation: Account<'info, PersonhoodAttestation>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_restore_attestation(
+    ctx: Context<RestoreAttestation>,
+    nullifier: [u8; 32],
+    provider: Pubkey,
+    score: u16,
+    issued_at: i64,
+    expires_at: i64,
+) -> Result<()> {
+    let restored = PersonhoodAttestation {
+        nullifier,
+        provider,
+        score,
+        issued_at,
+        expires_at,
+        bump: ctx.bumps.attestation,
+    };
+    require!(
+        restored.archive_hash() == ctx.accounts.archive.attestation_hash,
+        VeiledError::AttestationPreimageMismatch
+    );
+
+    ctx.accounts.attestation.set_inner(restored);
+
+    emit_cpi!(PersonhoodAttestationRestoredEvent {
+        nullifier,
+        provider,
+        score,
+        issued_at,
+        expires_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PersonhoodAttestationRestoredEvent {
+    pub nullifier: [u8; 32],
+    pub provider: Pubkey,
+    pub score: u16,
+    pub issued_at: i64,
+    pub expires_at: i64,
+}
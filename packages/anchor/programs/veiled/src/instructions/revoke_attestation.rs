@@ -0,0 +1,54 @@
+// * Revoke attestation instruction
+// * The verifier that issued an attestation withdraws it, e.g. for a compromised KYC record
+// * Revocation is final: the attestation can't be renewed or stored over afterwards
+
+use crate::errors::VeiledError;
+use crate::state::attestation::*;
+use crate::state::verification_result::ClaimType;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeAttestation<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"attestation",
+            attestation.nullifier.as_ref(),
+            attestation.claim_type.seed(),
+            attestation.domain.as_ref()
+        ],
+        bump = attestation.bump,
+        has_one = issuer @ VeiledError::NotAttestationIssuer
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    pub issuer: Signer<'info>,
+}
+
+pub fn handle_revoke_attestation(ctx: Context<RevokeAttestation>) -> Result<()> {
+    let attestation = &mut ctx.accounts.attestation;
+    require!(!attestation.is_revoked(), VeiledError::AttestationRevoked);
+
+    let now = Clock::get()?.unix_timestamp;
+    attestation.revoked_at = now;
+
+    emit_cpi!(AttestationRevokedEvent {
+        nullifier: attestation.nullifier,
+        claim_type: attestation.claim_type,
+        domain: attestation.domain,
+        issuer: attestation.issuer,
+        revoked_at: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AttestationRevokedEvent {
+    pub nullifier: [u8; 32],
+    pub claim_type: ClaimType,
+    pub domain: [u8; 32],
+    pub issuer: Pubkey,
+    pub revoked_at: i64,
+}
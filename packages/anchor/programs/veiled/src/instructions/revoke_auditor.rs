@@ -0,0 +1,41 @@
+// * Revoke auditor instruction
+// * Allows the granting wallet to withdraw an auditor's review rights
+
+use crate::errors::VeiledError;
+use crate::state::auditor::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeAuditor<'info> {
+    #[account(
+        mut,
+        has_one = granted_by @ VeiledError::UnauthorizedRevocation
+    )]
+    pub auditor_grant: Account<'info, AuditorGrant>,
+
+    /// * Must be the wallet that created the grant
+    pub granted_by: Signer<'info>,
+}
+
+pub fn handle_revoke_auditor(ctx: Context<RevokeAuditor>) -> Result<()> {
+    let auditor_grant = &mut ctx.accounts.auditor_grant;
+
+    // * Mark as revoked
+    auditor_grant.revoked = true;
+
+    emit_cpi!(AuditorRevokedEvent {
+        nullifier: auditor_grant.nullifier,
+        auditor: auditor_grant.auditor,
+        revoked_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AuditorRevokedEvent {
+    pub nullifier: [u8; 32],
+    pub auditor: Pubkey,
+    pub revoked_at: i64,
+}
@@ -2,8 +2,11 @@
 // * Allows users to revoke previously granted permissions
 
 use anchor_lang::prelude::*;
+use crate::cpi_guard;
 use crate::state::permission::*;
+use crate::state::stats::{ProtocolStats, StatsCounter};
 
+#[event_cpi]
 #[derive(Accounts)]
 pub struct RevokePermissions<'info> {
     #[account(mut)]
@@ -12,18 +15,39 @@ pub struct RevokePermissions<'info> {
     /// * Authority must be the payer (user who granted permissions)
     /// * In practice, this should be verified via nullifier ownership proof
     /// * For now, we allow any signer to revoke (can be tightened later)
+    #[account(mut)]
     pub authority: Signer<'info>,
+
+    // * Program-wide totals (see state/stats.rs)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProtocolStats::MAX_SIZE,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    pub system_program: Program<'info, System>,
 }
 
 pub fn handle_revoke_permissions(
     ctx: Context<RevokePermissions>,
 ) -> Result<()> {
+    // * No re-entry from observer callbacks (see cpi_guard.rs)
+    cpi_guard::require_not_reentrant()?;
+
     let permission_grant = &mut ctx.accounts.permission_grant;
     
-    // * Mark as revoked
+    // * Mark as revoked; revoking a revoked grant again isn't counted
+    if !permission_grant.revoked {
+        ctx.accounts
+            .protocol_stats
+            .record(ctx.bumps.protocol_stats, StatsCounter::Revocations, 1);
+    }
     permission_grant.revoked = true;
     
-    emit!(PermissionRevokedEvent {
+    emit_cpi!(PermissionRevokedEvent {
         nullifier: permission_grant.nullifier,
         app_id: permission_grant.app_id,
         revoked_at: Clock::get()?.unix_timestamp,
@@ -0,0 +1,46 @@
+// * Revoke session delegate instruction
+// * The parent session's authority withdraws a SessionDelegate before it expires; the account
+// * closes and its rent goes back to the authority. Also tidies up expired delegates.
+
+use crate::errors::VeiledError;
+use crate::state::session::SessionDelegate;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RevokeSessionDelegate<'info> {
+    #[account(
+        mut,
+        close = authority,
+        seeds = [
+            b"session_delegate",
+            session_delegate.session.as_ref(),
+            session_delegate.delegate.as_ref()
+        ],
+        bump = session_delegate.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub session_delegate: Account<'info, SessionDelegate>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_revoke_session_delegate(ctx: Context<RevokeSessionDelegate>) -> Result<()> {
+    let session_delegate = &ctx.accounts.session_delegate;
+
+    emit_cpi!(SessionDelegateRevokedEvent {
+        session: session_delegate.session,
+        delegate: session_delegate.delegate,
+        revoked_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct SessionDelegateRevokedEvent {
+    pub session: Pubkey,
+    pub delegate: Pubkey,
+    pub revoked_at: i64,
+}
@@ -0,0 +1,75 @@
+// * Rotate verifier key instruction
+// * Replaces a registered verifier key in place (admin only). The old key keeps signing for
+// * the new one until the registry's grace period ends, so in-flight results stay valid.
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::verifier::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct RotateVerifierKey<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"verifier_registry"],
+        bump = verifier_registry.bump
+    )]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_rotate_verifier_key(
+    ctx: Context<RotateVerifierKey>,
+    old_key: Pubkey,
+    new_key: Pubkey,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.verifier_registry;
+    let rotated_at = Clock::get()?.unix_timestamp;
+
+    require!(
+        !registry.is_registered(&new_key),
+        VeiledError::VerifierAlreadyRegistered
+    );
+    let index = registry
+        .verifiers
+        .iter()
+        .position(|registered| registered == &old_key)
+        .ok_or(VeiledError::VerifierNotRegistered)?;
+
+    // * Same slot, so the verifier count and threshold are unaffected
+    registry.verifiers[index] = new_key;
+    require!(
+        registry.record_rotation(VerifierRotation {
+            old_key,
+            new_key,
+            rotated_at,
+        }),
+        VeiledError::TooManyRotations
+    );
+
+    emit_cpi!(VerifierKeyRotatedEvent {
+        old_key,
+        new_key,
+        rotated_at,
+        grace_ends_at: rotated_at.saturating_add(registry.grace_period_secs),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VerifierKeyRotatedEvent {
+    pub old_key: Pubkey,
+    pub new_key: Pubkey,
+    pub rotated_at: i64,
+    pub grace_ends_at: i64,
+}
@@ -0,0 +1,28 @@
+// * Select verifier instruction
+// * Read-only view: the verifier a client should send `nullifier`'s proof to, returned via
+// * return data (the SDK's selectVerifier computes the same pick off-chain)
+
+use crate::errors::VeiledError;
+use crate::state::verifier::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SelectVerifier<'info> {
+    #[account(seeds = [b"verifier_registry"], bump = verifier_registry.bump)]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    #[account(seeds = [b"verifier_weights"], bump = verifier_weights.bump)]
+    pub verifier_weights: Account<'info, VerifierWeights>,
+}
+
+pub fn handle_select_verifier(ctx: Context<SelectVerifier>, nullifier: [u8; 32]) -> Result<Pubkey> {
+    let now = Clock::get()?.unix_timestamp;
+
+    let verifier = ctx
+        .accounts
+        .verifier_weights
+        .select(&ctx.accounts.verifier_registry, &nullifier, now)
+        .ok_or(VeiledError::NoVerifierAvailable)?;
+
+    Ok(verifier)
+}
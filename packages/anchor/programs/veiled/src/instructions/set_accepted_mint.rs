@@ -0,0 +1,64 @@
+// * Set accepted mint instruction
+// * Adds or removes an SPL mint (and its USD price feed) from the fee payment allowlist
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetAcceptedMint<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_set_accepted_mint(
+    ctx: Context<SetAcceptedMint>,
+    mint: Pubkey,
+    price_feed_id: [u8; 32],
+    accepted: bool,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    if accepted {
+        require!(
+            !config.is_accepted_mint(&mint),
+            VeiledError::MintAlreadyAccepted
+        );
+        require!(
+            config.accepted_mints.len() < ProtocolConfig::MAX_ACCEPTED_MINTS,
+            VeiledError::TooManyAcceptedMints
+        );
+        config.accepted_mints.push(AcceptedMint {
+            mint,
+            price_feed_id,
+        });
+    } else {
+        require!(config.is_accepted_mint(&mint), VeiledError::MintNotAccepted);
+        config
+            .accepted_mints
+            .retain(|accepted_mint| accepted_mint.mint != mint);
+    }
+
+    emit_cpi!(AcceptedMintUpdatedEvent {
+        mint,
+        price_feed_id,
+        accepted,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AcceptedMintUpdatedEvent {
+    pub mint: Pubkey,
+    pub price_feed_id: [u8; 32],
+    pub accepted: bool,
+}
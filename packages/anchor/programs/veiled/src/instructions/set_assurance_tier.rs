@@ -0,0 +1,71 @@
+// * Set assurance tier instruction
+// * Defines how old a verification result may be for domains on an assurance tier (admin only),
+// * e.g. 60 seconds for high-assurance domains and 10 minutes for consumer ones
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetAssuranceTier<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + AssurancePolicy::MAX_SIZE,
+        seeds = [b"assurance_policy"],
+        bump
+    )]
+    pub assurance_policy: Account<'info, AssurancePolicy>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_set_assurance_tier(
+    ctx: Context<SetAssuranceTier>,
+    tier: u8,
+    max_result_age_secs: u32,
+) -> Result<()> {
+    let policy = &mut ctx.accounts.assurance_policy;
+    policy.bump = ctx.bumps.assurance_policy;
+
+    require!(
+        max_result_age_secs > 0 && max_result_age_secs <= AssurancePolicy::MAX_RESULT_AGE_SECS,
+        VeiledError::InvalidResultAge
+    );
+
+    // * Tiers are defined in order, so the table has no gaps; existing tiers can be retuned
+    let index = usize::from(tier);
+    require!(
+        index <= policy.max_result_age_secs.len() && index < AssurancePolicy::MAX_TIERS,
+        VeiledError::InvalidAssuranceTier
+    );
+    if index == policy.max_result_age_secs.len() {
+        policy.max_result_age_secs.push(max_result_age_secs);
+    } else {
+        policy.max_result_age_secs[index] = max_result_age_secs;
+    }
+
+    emit_cpi!(AssuranceTierSetEvent {
+        tier,
+        max_result_age_secs,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AssuranceTierSetEvent {
+    pub tier: u8,
+    pub max_result_age_secs: u32,
+}
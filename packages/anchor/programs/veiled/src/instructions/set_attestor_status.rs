@@ -0,0 +1,49 @@
+// * Set attestor status instruction
+// * Suspends or reinstates a registered attestor (admin only); while suspended, domains that
+// * restrict its claim types take no new attestations from it
+
+use crate::errors::VeiledError;
+use crate::state::attestor::*;
+use crate::state::config::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetAttestorStatus<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"attestor", attestor.issuer.as_ref()],
+        bump = attestor.bump
+    )]
+    pub attestor: Account<'info, Attestor>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_set_attestor_status(
+    ctx: Context<SetAttestorStatus>,
+    status: AttestorStatus,
+) -> Result<()> {
+    let attestor = &mut ctx.accounts.attestor;
+    attestor.status = status;
+
+    emit_cpi!(AttestorStatusSetEvent {
+        issuer: attestor.issuer,
+        status,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct AttestorStatusSetEvent {
+    pub issuer: Pubkey,
+    pub status: AttestorStatus,
+}
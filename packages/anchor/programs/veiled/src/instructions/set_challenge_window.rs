@@ -0,0 +1,47 @@
+// * Set challenge window instruction
+// * Turns on optimistic verification for a domain (owner only; zero turns it off)
+// * Only affects sessions created afterwards
+
+use crate::errors::VeiledError;
+use crate::state::domain::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetChallengeWindow<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        has_one = owner @ VeiledError::NotDomainOwner
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_set_challenge_window(
+    ctx: Context<SetChallengeWindow>,
+    challenge_window_secs: i64,
+) -> Result<()> {
+    require!(
+        (0..=DomainConfig::MAX_CHALLENGE_WINDOW_SECS).contains(&challenge_window_secs),
+        VeiledError::InvalidChallengeWindow
+    );
+
+    let domain_config = &mut ctx.accounts.domain_config;
+    domain_config.challenge_window_secs = challenge_window_secs;
+
+    emit_cpi!(ChallengeWindowSetEvent {
+        domain: domain_config.domain,
+        challenge_window_secs,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ChallengeWindowSetEvent {
+    pub domain: [u8; 32],
+    pub challenge_window_secs: i64,
+}
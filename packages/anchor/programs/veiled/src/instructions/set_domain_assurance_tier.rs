@@ -0,0 +1,52 @@
+// * Set domain assurance tier instruction
+// * Puts a domain on one of the AssurancePolicy tiers, setting how fresh its verification
+// * results must be (owner only; tier 0 is the default)
+
+use crate::errors::VeiledError;
+use crate::state::config::AssurancePolicy;
+use crate::state::domain::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDomainAssuranceTier<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        has_one = owner @ VeiledError::NotDomainOwner
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    #[account(seeds = [b"assurance_policy"], bump = assurance_policy.bump)]
+    pub assurance_policy: Account<'info, AssurancePolicy>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_set_domain_assurance_tier(
+    ctx: Context<SetDomainAssuranceTier>,
+    assurance_tier: u8,
+) -> Result<()> {
+    // * Only tiers the admin defined; unknown tiers would silently get the default window
+    require!(
+        usize::from(assurance_tier) < ctx.accounts.assurance_policy.max_result_age_secs.len(),
+        VeiledError::InvalidAssuranceTier
+    );
+
+    let domain_config = &mut ctx.accounts.domain_config;
+    domain_config.assurance_tier = assurance_tier;
+
+    emit_cpi!(DomainAssuranceTierSetEvent {
+        domain: domain_config.domain,
+        assurance_tier,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainAssuranceTierSetEvent {
+    pub domain: [u8; 32],
+    pub assurance_tier: u8,
+}
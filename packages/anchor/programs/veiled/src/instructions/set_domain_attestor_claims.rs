@@ -0,0 +1,50 @@
+// * Set domain attestor claims instruction
+// * Picks the claim types a domain only accepts from registered attestors, e.g. so a lending
+// * app takes balance proofs from any verifier but KYC-backed age claims only from vetted
+// * providers (owner only; zero accepts every claim from any verifier)
+
+use crate::errors::VeiledError;
+use crate::state::domain::*;
+use crate::state::verification_result::ClaimType;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDomainAttestorClaims<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        has_one = owner @ VeiledError::NotDomainOwner
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_set_domain_attestor_claims(
+    ctx: Context<SetDomainAttestorClaims>,
+    claim_mask: u8,
+) -> Result<()> {
+    // * Logins open sessions and never reach an attestor check
+    require!(
+        claim_mask & !ClaimType::ATTESTATION_BITS == 0,
+        VeiledError::InvalidClaimMask
+    );
+
+    let domain_config = &mut ctx.accounts.domain_config;
+    domain_config.attestor_claim_mask = claim_mask;
+
+    emit_cpi!(DomainAttestorClaimsSetEvent {
+        domain: domain_config.domain,
+        claim_mask,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainAttestorClaimsSetEvent {
+    pub domain: [u8; 32],
+    pub claim_mask: u8,
+}
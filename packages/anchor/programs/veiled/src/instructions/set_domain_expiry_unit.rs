@@ -0,0 +1,47 @@
+// * Set domain expiry unit instruction
+// * Chooses whether the domain's sessions expire by unix time or slot height (owner only), for
+// * domains that would rather not trust the cluster's wall clock. Session lifetimes and bounds
+// * stay in seconds and are converted to slots at ExpiryKind::MS_PER_SLOT.
+// *
+// * Sessions take the unit when they're created or renewed; existing sessions keep theirs
+// * until their next renewal.
+
+use crate::errors::VeiledError;
+use crate::state::domain::*;
+use crate::state::expiry::ExpiryUnit;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDomainExpiryUnit<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        has_one = owner @ VeiledError::NotDomainOwner
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_set_domain_expiry_unit(
+    ctx: Context<SetDomainExpiryUnit>,
+    session_expiry_unit: ExpiryUnit,
+) -> Result<()> {
+    let domain_config = &mut ctx.accounts.domain_config;
+    domain_config.session_expiry_unit = session_expiry_unit;
+
+    emit_cpi!(DomainExpiryUnitSetEvent {
+        domain: domain_config.domain,
+        session_expiry_unit,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainExpiryUnitSetEvent {
+    pub domain: [u8; 32],
+    pub session_expiry_unit: ExpiryUnit,
+}
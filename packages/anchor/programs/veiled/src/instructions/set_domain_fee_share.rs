@@ -0,0 +1,55 @@
+// * Set domain fee share instruction
+// * Sets the cut of domain-attributed fees paid into each domain's fee vault (admin only), so
+// * apps routing users through Veiled earn part of the fees they generate
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDomainFeeShare<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + FeeSharePolicy::MAX_SIZE,
+        seeds = [b"fee_share_policy"],
+        bump
+    )]
+    pub fee_share_policy: Account<'info, FeeSharePolicy>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_set_domain_fee_share(
+    ctx: Context<SetDomainFeeShare>,
+    domain_share_bps: u16,
+) -> Result<()> {
+    require!(
+        domain_share_bps <= FeeSharePolicy::MAX_DOMAIN_SHARE_BPS,
+        VeiledError::InvalidFeeShare
+    );
+
+    let policy = &mut ctx.accounts.fee_share_policy;
+    policy.domain_share_bps = domain_share_bps;
+    policy.bump = ctx.bumps.fee_share_policy;
+
+    emit_cpi!(DomainFeeShareSetEvent { domain_share_bps });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainFeeShareSetEvent {
+    pub domain_share_bps: u16,
+}
@@ -0,0 +1,54 @@
+// * Set domain idle timeout instruction
+// * Ends the domain's sessions early once they go this long without a touch_session, so
+// * abandoned sessions don't stay usable until their absolute expiry (owner only; 0 = off)
+// *
+// * Sessions take the timeout when they're created, like their expiry; changing it only
+// * affects sessions registered afterwards.
+
+use crate::errors::VeiledError;
+use crate::state::domain::*;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDomainIdleTimeout<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        has_one = owner @ VeiledError::NotDomainOwner
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_set_domain_idle_timeout(
+    ctx: Context<SetDomainIdleTimeout>,
+    idle_timeout_secs: i64,
+) -> Result<()> {
+    // * Within the protocol's session lifetimes, so a timeout can't outlast every session
+    require!(
+        idle_timeout_secs == 0
+            || (NullifierAccount::MIN_EXPIRY_SECONDS..=NullifierAccount::MAX_EXPIRY_SECONDS)
+                .contains(&idle_timeout_secs),
+        VeiledError::InvalidIdleTimeout
+    );
+
+    let domain_config = &mut ctx.accounts.domain_config;
+    domain_config.idle_timeout_secs = idle_timeout_secs;
+
+    emit_cpi!(DomainIdleTimeoutSetEvent {
+        domain: domain_config.domain,
+        idle_timeout_secs,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainIdleTimeoutSetEvent {
+    pub domain: [u8; 32],
+    pub idle_timeout_secs: i64,
+}
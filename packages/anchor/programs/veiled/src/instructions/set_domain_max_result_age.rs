@@ -0,0 +1,49 @@
+// * Set domain max result age instruction
+// * Gives a domain its own verification result staleness window, e.g. for slow relays or
+// * offline signing flows (owner only; zero goes back to the domain's assurance tier)
+
+use crate::errors::VeiledError;
+use crate::state::config::AssurancePolicy;
+use crate::state::domain::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDomainMaxResultAge<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        has_one = owner @ VeiledError::NotDomainOwner
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_set_domain_max_result_age(
+    ctx: Context<SetDomainMaxResultAge>,
+    max_result_age_secs: u32,
+) -> Result<()> {
+    // * Same bound as the tiers, so no domain keeps results replayable for long
+    require!(
+        max_result_age_secs <= AssurancePolicy::MAX_RESULT_AGE_SECS,
+        VeiledError::InvalidResultAge
+    );
+
+    let domain_config = &mut ctx.accounts.domain_config;
+    domain_config.max_result_age_secs = max_result_age_secs;
+
+    emit_cpi!(DomainMaxResultAgeSetEvent {
+        domain: domain_config.domain,
+        max_result_age_secs,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainMaxResultAgeSetEvent {
+    pub domain: [u8; 32],
+    pub max_result_age_secs: u32,
+}
@@ -0,0 +1,49 @@
+// * Set domain nullifier store instruction
+// * Chooses where the domain's verifications register nullifiers (owner only):
+// *   Accounts   - a NullifierAccount session per nullifier (verify_auth and the session paths)
+// *   Bitmap     - a bit in a NullifierShard (verify_auth_sharded), for high-volume domains that
+// *                only need one-time registration
+// *   Compressed - a leaf in the domain's NullifierTree (verify_auth_compressed), for domains
+// *                too large even for shards; init_nullifier_tree must run first
+// *
+// * Registrations don't carry over between stores, so a nullifier registered under one store
+// * can register once more after a switch; choose the store before the domain goes live.
+
+use crate::errors::VeiledError;
+use crate::state::domain::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDomainNullifierStore<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        has_one = owner @ VeiledError::NotDomainOwner
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_set_domain_nullifier_store(
+    ctx: Context<SetDomainNullifierStore>,
+    nullifier_store: NullifierStore,
+) -> Result<()> {
+    let domain_config = &mut ctx.accounts.domain_config;
+    domain_config.nullifier_store = nullifier_store;
+
+    emit_cpi!(DomainNullifierStoreSetEvent {
+        domain: domain_config.domain,
+        nullifier_store,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainNullifierStoreSetEvent {
+    pub domain: [u8; 32],
+    pub nullifier_store: NullifierStore,
+}
@@ -0,0 +1,47 @@
+// * Set domain origins instruction
+// * Registers the origin hashes (web origin / mobile bundle id) allowed to start auth for a
+// * domain (owner only; empty clears)
+
+use crate::errors::VeiledError;
+use crate::state::domain::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDomainOrigins<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        has_one = owner @ VeiledError::NotDomainOwner
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_set_domain_origins(
+    ctx: Context<SetDomainOrigins>,
+    origins: Vec<[u8; 32]>,
+) -> Result<()> {
+    require!(
+        origins.len() <= DomainConfig::MAX_ORIGINS,
+        VeiledError::TooManyOrigins
+    );
+
+    let domain_config = &mut ctx.accounts.domain_config;
+    domain_config.allowed_origins = origins;
+
+    emit_cpi!(DomainOriginsSetEvent {
+        domain: domain_config.domain,
+        origins: domain_config.allowed_origins.clone(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainOriginsSetEvent {
+    pub domain: [u8; 32],
+    pub origins: Vec<[u8; 32]>,
+}
@@ -0,0 +1,41 @@
+// * Set domain paused instruction
+// * Stops (or resumes) new verifications for a domain, e.g. while its verifier keys are
+// * rotated after a compromise (owner or operator). Existing sessions are untouched.
+
+use crate::errors::VeiledError;
+use crate::state::domain::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDomainPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        constraint = domain_config.is_operator(authority.key) @ VeiledError::NotDomainOperator
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_set_domain_paused(ctx: Context<SetDomainPaused>, paused: bool) -> Result<()> {
+    let domain_config = &mut ctx.accounts.domain_config;
+    domain_config.paused = paused;
+
+    emit_cpi!(DomainPausedSetEvent {
+        domain: domain_config.domain,
+        paused,
+        authority: ctx.accounts.authority.key(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainPausedSetEvent {
+    pub domain: [u8; 32],
+    pub paused: bool,
+    pub authority: Pubkey,
+}
@@ -0,0 +1,49 @@
+// * Set domain rate limit instruction
+// * Sets how many verifications one fee payer may submit per minute for a domain (owner only;
+// * zero falls back to the protocol default). Allowlisted relayers keep their own quota.
+
+use crate::errors::VeiledError;
+use crate::state::domain::*;
+use crate::state::rate_limit::PayerRateLimit;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDomainRateLimit<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        has_one = owner @ VeiledError::NotDomainOwner
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_set_domain_rate_limit(
+    ctx: Context<SetDomainRateLimit>,
+    rate_limit: u32,
+) -> Result<()> {
+    // * Capped so a domain can't lift the global flood protection for its payers
+    require!(
+        rate_limit <= PayerRateLimit::MAX_DOMAIN_LIMIT,
+        VeiledError::InvalidRateLimit
+    );
+
+    let domain_config = &mut ctx.accounts.domain_config;
+    domain_config.rate_limit = rate_limit;
+
+    emit_cpi!(DomainRateLimitSetEvent {
+        domain: domain_config.domain,
+        rate_limit,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainRateLimitSetEvent {
+    pub domain: [u8; 32],
+    pub rate_limit: u32,
+}
@@ -0,0 +1,54 @@
+// * Set domain role instruction
+// * Assigns a domain's delegated keys (owner only): the operator rotates verifiers and pauses
+// * the domain, the treasurer withdraws its fee share. Also transfers ownership.
+
+use crate::errors::VeiledError;
+use crate::state::domain::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDomainRole<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        has_one = owner @ VeiledError::NotDomainOwner
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_set_domain_role(
+    ctx: Context<SetDomainRole>,
+    role: DomainRole,
+    key: Pubkey,
+) -> Result<()> {
+    let domain_config = &mut ctx.accounts.domain_config;
+
+    // * Delegated roles clear with the default key; ownership can only move
+    match role {
+        DomainRole::Owner => {
+            require!(key != Pubkey::default(), VeiledError::InvalidDomainRoleKey);
+            domain_config.owner = key;
+        }
+        DomainRole::Operator => domain_config.operator = key,
+        DomainRole::Treasurer => domain_config.treasurer = key,
+    }
+
+    emit_cpi!(DomainRoleSetEvent {
+        domain: domain_config.domain,
+        role,
+        key,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainRoleSetEvent {
+    pub domain: [u8; 32],
+    pub role: DomainRole,
+    pub key: Pubkey,
+}
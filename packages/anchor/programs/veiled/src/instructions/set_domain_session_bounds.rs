@@ -0,0 +1,58 @@
+// * Set domain session bounds instruction
+// * Limits the session lifetimes callers may request with verify_auth's `expires_in`, e.g. so
+// * a banking domain never issues month-long sessions (owner only; zero uses the protocol bound)
+
+use crate::errors::VeiledError;
+use crate::state::domain::*;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDomainSessionBounds<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        has_one = owner @ VeiledError::NotDomainOwner
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_set_domain_session_bounds(
+    ctx: Context<SetDomainSessionBounds>,
+    min_session_secs: i64,
+    max_session_secs: i64,
+) -> Result<()> {
+    // * Each bound stays within the protocol's, and the range can't be empty
+    let protocol = NullifierAccount::MIN_EXPIRY_SECONDS..=NullifierAccount::MAX_EXPIRY_SECONDS;
+    require!(
+        [min_session_secs, max_session_secs]
+            .iter()
+            .all(|secs| *secs == 0 || protocol.contains(secs)),
+        VeiledError::InvalidSessionBounds
+    );
+
+    let domain_config = &mut ctx.accounts.domain_config;
+    domain_config.min_session_secs = min_session_secs;
+    domain_config.max_session_secs = max_session_secs;
+    let (min, max) = domain_config.session_bounds();
+    require!(min <= max, VeiledError::InvalidSessionBounds);
+
+    emit_cpi!(DomainSessionBoundsSetEvent {
+        domain: domain_config.domain,
+        min_session_secs,
+        max_session_secs,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainSessionBoundsSetEvent {
+    pub domain: [u8; 32],
+    pub min_session_secs: i64,
+    pub max_session_secs: i64,
+}
@@ -0,0 +1,47 @@
+// * Set domain verifiers instruction
+// * Designates the keys allowed to sign a domain's verification results (owner or operator;
+// * empty clears)
+
+use crate::errors::VeiledError;
+use crate::state::domain::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetDomainVerifiers<'info> {
+    #[account(
+        mut,
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        constraint = domain_config.is_operator(authority.key) @ VeiledError::NotDomainOperator
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_set_domain_verifiers(
+    ctx: Context<SetDomainVerifiers>,
+    verifiers: Vec<Pubkey>,
+) -> Result<()> {
+    require!(
+        verifiers.len() <= DomainConfig::MAX_VERIFIERS,
+        VeiledError::TooManyVerifiers
+    );
+
+    let domain_config = &mut ctx.accounts.domain_config;
+    domain_config.verifiers = verifiers;
+
+    emit_cpi!(DomainVerifiersSetEvent {
+        domain: domain_config.domain,
+        verifiers: domain_config.verifiers.clone(),
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct DomainVerifiersSetEvent {
+    pub domain: [u8; 32],
+    pub verifiers: Vec<Pubkey>,
+}
@@ -0,0 +1,100 @@
+// * Set guardians instruction
+// * An active session's authority names the guardians that can later recover the session's
+// * nullifier (see state/recovery.rs and recover_identity). Calling it again replaces the set;
+// * either way the set's nonce moves on, so approvals gathered for the old set stop counting.
+// *
+// * Device-bound sessions (verify_auth_with_device) also need the device's DeviceProof.
+
+use crate::errors::VeiledError;
+use crate::state::recovery::GuardianSet;
+use crate::state::session::DeviceProof;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetGuardians<'info> {
+    #[account(
+        seeds = [
+            b"nullifier",
+            nullifier_account.nullifier.as_ref(),
+            nullifier_account.domain_seed().as_ref()
+        ],
+        bump = nullifier_account.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + GuardianSet::MAX_SIZE,
+        seeds = [b"guardian_set", nullifier_account.nullifier.as_ref()],
+        bump
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+
+    // * Session's authority (has_one); pays the guardian set's rent
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// CHECK: * LogoutEpoch PDA of the session's authority; may not exist yet
+    /// * Address is pinned by seeds so callers can't substitute another identity's epoch
+    #[account(
+        seeds = [b"logout_epoch", nullifier_account.authority.as_ref()],
+        bump
+    )]
+    pub logout_epoch: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_set_guardians(
+    ctx: Context<SetGuardians>,
+    guardians: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let session = &ctx.accounts.nullifier_account;
+
+    session.require_usable(&clock, &ctx.accounts.logout_epoch)?;
+    session.prove_device(
+        &ctx.accounts.instructions_sysvar,
+        &DeviceProof::message(&session.nullifier, session.last_active_at),
+    )?;
+    GuardianSet::validate(&guardians, threshold)?;
+
+    let guardian_set = &mut ctx.accounts.guardian_set;
+    let nonce = guardian_set.nonce + 1;
+    guardian_set.set_inner(GuardianSet {
+        nullifier: session.nullifier,
+        guardians: guardians.clone(),
+        threshold,
+        nonce,
+        updated_at: clock.unix_timestamp,
+        bump: ctx.bumps.guardian_set,
+    });
+
+    emit_cpi!(GuardiansSetEvent {
+        nullifier: session.nullifier,
+        guardians,
+        threshold,
+        nonce,
+        updated_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct GuardiansSetEvent {
+    pub nullifier: [u8; 32],
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+    pub nonce: u64,
+    pub updated_at: i64,
+}
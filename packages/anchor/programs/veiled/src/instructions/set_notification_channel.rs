@@ -0,0 +1,96 @@
+// * Set notification channel instruction
+// * Stores (or replaces) the encrypted push channel an app may use to reach a user.
+// * Requires a live grant of ReceiveNotifications to that app; revoking the grant
+// * lets anyone close the channel (see close_notification_channel).
+
+use crate::errors::VeiledError;
+use crate::state::notification::*;
+use crate::state::permission::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetNotificationChannel<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + NotificationChannel::MAX_SIZE,
+        seeds = [
+            b"notification_channel",
+            permission_grant.nullifier.as_ref(),
+            permission_grant.app_id.as_ref()
+        ],
+        bump
+    )]
+    pub notification_channel: Account<'info, NotificationChannel>,
+
+    #[account(
+        seeds = [
+            b"permission",
+            permission_grant.nullifier.as_ref(),
+            permission_grant.app_id.as_ref()
+        ],
+        bump = permission_grant.bump
+    )]
+    pub permission_grant: Account<'info, PermissionGrant>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_set_notification_channel(
+    ctx: Context<SetNotificationChannel>,
+    channel_commitment: [u8; 32],
+    encrypted_token: Vec<u8>,
+) -> Result<()> {
+    let permission_grant = &ctx.accounts.permission_grant;
+    let clock = Clock::get()?;
+
+    if let Some(reason) = permission_grant.denial_reason(Permission::ReceiveNotifications, &clock) {
+        return Err(VeiledError::from(reason).into());
+    }
+
+    require!(
+        !encrypted_token.is_empty() && encrypted_token.len() <= NotificationChannel::MAX_TOKEN_LEN,
+        VeiledError::InvalidNotificationToken
+    );
+
+    let channel = &mut ctx.accounts.notification_channel;
+    let authority = ctx.accounts.authority.key();
+
+    // * The first writer owns the channel; nobody else can redirect it
+    if channel.authority == Pubkey::default() {
+        channel.authority = authority;
+    }
+    require_keys_eq!(
+        channel.authority,
+        authority,
+        VeiledError::NotChannelAuthority
+    );
+
+    channel.nullifier = permission_grant.nullifier;
+    channel.app_id = permission_grant.app_id;
+    channel.channel_commitment = channel_commitment;
+    channel.encrypted_token = encrypted_token;
+    channel.updated_at = clock.unix_timestamp;
+    channel.bump = ctx.bumps.notification_channel;
+
+    emit_cpi!(NotificationChannelSetEvent {
+        nullifier: channel.nullifier,
+        app_id: channel.app_id,
+        channel_commitment,
+        updated_at: channel.updated_at,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct NotificationChannelSetEvent {
+    pub nullifier: [u8; 32],
+    pub app_id: Pubkey,
+    pub channel_commitment: [u8; 32],
+    pub updated_at: i64,
+}
@@ -0,0 +1,48 @@
+// * Set pricing instruction
+// * Updates the USD-quoted fee and the oracle staleness/confidence bounds (admin only)
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetPricing<'info> {
+    #[account(
+        mut,
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_set_pricing(
+    ctx: Context<SetPricing>,
+    fee_usd_micros: u64,
+    max_price_age_secs: u64,
+    max_price_conf_bps: u16,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+
+    config.fee_usd_micros = fee_usd_micros;
+    config.max_price_age_secs = max_price_age_secs;
+    config.max_price_conf_bps = max_price_conf_bps;
+
+    emit_cpi!(PricingUpdatedEvent {
+        fee_usd_micros,
+        max_price_age_secs,
+        max_price_conf_bps,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct PricingUpdatedEvent {
+    pub fee_usd_micros: u64,
+    pub max_price_age_secs: u64,
+    pub max_price_conf_bps: u16,
+}
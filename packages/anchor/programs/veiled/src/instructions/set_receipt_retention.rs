@@ -0,0 +1,54 @@
+// * Set receipt retention instruction
+// * Sets how long verify_auth keeps a consumed result's receipt before it can be closed
+// * (admin only)
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::verifier::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetReceiptRetention<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"verifier_registry"],
+        bump = verifier_registry.bump
+    )]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_set_receipt_retention(
+    ctx: Context<SetReceiptRetention>,
+    receipt_retention_secs: i64,
+) -> Result<()> {
+    require!(
+        (VerifierRegistry::MIN_RECEIPT_RETENTION_SECS
+            ..=VerifierRegistry::MAX_RECEIPT_RETENTION_SECS)
+            .contains(&receipt_retention_secs),
+        VeiledError::InvalidReceiptRetention
+    );
+
+    // * Only receipts consumed from now on; existing ones keep their retain_until
+    ctx.accounts.verifier_registry.receipt_retention_secs = receipt_retention_secs;
+
+    emit_cpi!(ReceiptRetentionSetEvent {
+        receipt_retention_secs
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct ReceiptRetentionSetEvent {
+    pub receipt_retention_secs: i64,
+}
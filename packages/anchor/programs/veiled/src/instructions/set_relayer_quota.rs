@@ -0,0 +1,56 @@
+// * Set relayer quota instruction
+// * Allowlists a relayer key with its own verifications-per-minute quota, replacing domain
+// * limits for everything it pays for (admin only; zero removes the exemption)
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::rate_limit::PayerRateLimit;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(relayer: Pubkey)]
+pub struct SetRelayerQuota<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    // * Same PDA verify_auth counts the relayer's submissions in
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PayerRateLimit::MAX_SIZE,
+        seeds = [b"rate_limit", relayer.as_ref()],
+        bump
+    )]
+    pub payer_rate_limit: AccountLoader<'info, PayerRateLimit>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_set_relayer_quota(
+    ctx: Context<SetRelayerQuota>,
+    relayer: Pubkey,
+    quota: u32,
+) -> Result<()> {
+    let mut counter = PayerRateLimit::load_or_init(&ctx.accounts.payer_rate_limit)?;
+    counter.payer = relayer;
+    counter.bump = ctx.bumps.payer_rate_limit;
+    counter.quota = quota;
+
+    emit_cpi!(RelayerQuotaSetEvent { relayer, quota });
+
+    Ok(())
+}
+
+#[event]
+pub struct RelayerQuotaSetEvent {
+    pub relayer: Pubkey,
+    pub quota: u32,
+}
@@ -0,0 +1,56 @@
+// * Set SAS bridge instruction
+// * Points mirror_attestation at a Solana Attestation Service credential and schema (admin only)
+// * The credential must list the [b"sas_signer"] PDA as an authorized signer, or SAS rejects
+// * every mirror
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::sas_bridge::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetSasBridge<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + SasBridge::MAX_SIZE,
+        seeds = [b"sas_bridge"],
+        bump
+    )]
+    pub sas_bridge: Account<'info, SasBridge>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_set_sas_bridge(
+    ctx: Context<SetSasBridge>,
+    credential: Pubkey,
+    schema: Pubkey,
+) -> Result<()> {
+    ctx.accounts.sas_bridge.set_inner(SasBridge {
+        credential,
+        schema,
+        bump: ctx.bumps.sas_bridge,
+    });
+
+    emit_cpi!(SasBridgeSetEvent { credential, schema });
+
+    Ok(())
+}
+
+#[event]
+pub struct SasBridgeSetEvent {
+    pub credential: Pubkey,
+    pub schema: Pubkey,
+}
@@ -0,0 +1,56 @@
+// * Set VC status instruction
+// * The issuer suspends, reactivates or revokes a credential it anchored
+// * Revocation is final, as in a W3C revocation status list
+
+use crate::errors::VeiledError;
+use crate::state::credential::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetVcStatus<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"vc_commitment",
+            vc_commitment.issuer.as_ref(),
+            vc_commitment.vc_hash.as_ref()
+        ],
+        bump = vc_commitment.bump,
+        has_one = issuer @ VeiledError::NotVcIssuer
+    )]
+    pub vc_commitment: Account<'info, VcCommitment>,
+
+    pub issuer: Signer<'info>,
+}
+
+pub fn handle_set_vc_status(ctx: Context<SetVcStatus>, status: VcStatus) -> Result<()> {
+    let commitment = &mut ctx.accounts.vc_commitment;
+    require!(
+        commitment.status != VcStatus::Revoked,
+        VeiledError::VcRevoked
+    );
+
+    let now = Clock::get()?.unix_timestamp;
+    commitment.status = status;
+    commitment.updated_at = now;
+
+    emit_cpi!(VcStatusChangedEvent {
+        nullifier: commitment.nullifier,
+        issuer: commitment.issuer,
+        vc_hash: commitment.vc_hash,
+        status,
+        updated_at: now,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct VcStatusChangedEvent {
+    pub nullifier: [u8; 32],
+    pub issuer: Pubkey,
+    pub vc_hash: [u8; 32],
+    pub status: VcStatus,
+    pub updated_at: i64,
+}
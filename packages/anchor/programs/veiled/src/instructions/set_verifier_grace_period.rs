@@ -0,0 +1,49 @@
+// * Set verifier grace period instruction
+// * Sets how long a rotated-out verifier key stays accepted by verify_auth (admin only)
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::verifier::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetVerifierGracePeriod<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"verifier_registry"],
+        bump = verifier_registry.bump
+    )]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_set_verifier_grace_period(
+    ctx: Context<SetVerifierGracePeriod>,
+    grace_period_secs: i64,
+) -> Result<()> {
+    require!(
+        (0..=VerifierRegistry::MAX_GRACE_PERIOD_SECS).contains(&grace_period_secs),
+        VeiledError::InvalidGracePeriod
+    );
+
+    // * Applies to past rotations too: shortening it ends their grace early
+    ctx.accounts.verifier_registry.grace_period_secs = grace_period_secs;
+
+    emit_cpi!(VerifierGracePeriodSetEvent { grace_period_secs });
+
+    Ok(())
+}
+
+#[event]
+pub struct VerifierGracePeriodSetEvent {
+    pub grace_period_secs: i64,
+}
@@ -0,0 +1,54 @@
+// * Set verifier threshold instruction
+// * Sets how many registered verifiers must co-sign a result for verify_auth (admin only)
+
+use crate::errors::VeiledError;
+use crate::sig_introspection::MAX_ED25519_INSTRUCTIONS;
+use crate::state::config::*;
+use crate::state::verifier::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetVerifierThreshold<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"verifier_registry"],
+        bump = verifier_registry.bump
+    )]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_set_verifier_threshold(
+    ctx: Context<SetVerifierThreshold>,
+    threshold: u8,
+) -> Result<()> {
+    let registry = &mut ctx.accounts.verifier_registry;
+
+    // * Must be reachable: enough verifiers registered, and co-signatures fit in one transaction
+    let threshold_len = usize::from(threshold);
+    require!(
+        threshold >= 1
+            && threshold_len <= registry.verifiers.len()
+            && threshold_len <= MAX_ED25519_INSTRUCTIONS,
+        VeiledError::InvalidVerifierThreshold
+    );
+    registry.threshold = threshold;
+
+    emit_cpi!(VerifierThresholdSetEvent { threshold });
+
+    Ok(())
+}
+
+#[event]
+pub struct VerifierThresholdSetEvent {
+    pub threshold: u8,
+}
@@ -0,0 +1,68 @@
+// * Set verifier weight instruction
+// * Sets a registered verifier's share of client selections; 0 stops selecting it (admin only)
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use crate::state::verifier::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SetVerifierWeight<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(seeds = [b"verifier_registry"], bump = verifier_registry.bump)]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + VerifierWeights::MAX_SIZE,
+        seeds = [b"verifier_weights"],
+        bump
+    )]
+    pub verifier_weights: Account<'info, VerifierWeights>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_set_verifier_weight(
+    ctx: Context<SetVerifierWeight>,
+    verifier: Pubkey,
+    weight: u8,
+) -> Result<()> {
+    require!(
+        weight <= VerifierWeights::MAX_WEIGHT,
+        VeiledError::InvalidVerifierWeight
+    );
+    // * Removing a weight is allowed after the key left the registry
+    require!(
+        weight == 0 || ctx.accounts.verifier_registry.is_registered(&verifier),
+        VeiledError::VerifierNotRegistered
+    );
+
+    let weights = &mut ctx.accounts.verifier_weights;
+    weights.bump = ctx.bumps.verifier_weights;
+    require!(
+        weights.set_weight(verifier, weight),
+        VeiledError::TooManyVerifiers
+    );
+
+    emit_cpi!(VerifierWeightSetEvent { verifier, weight });
+
+    Ok(())
+}
+
+#[event]
+pub struct VerifierWeightSetEvent {
+    pub verifier: Pubkey,
+    pub weight: u8,
+}
@@ -0,0 +1,180 @@
+// * Store attestation instruction
+// * Records a verifier-signed non-login claim (see ClaimType) as an Attestation PDA, so Veiled
+// * can hold balance, age and NFT ownership proofs alongside sessions. The result gets the same
+// * checks verify_auth applies (see verification.rs) and is spent through its result receipt.
+// *
+// * An expired attestation can be stored over; renew_attestation extends one in place.
+
+use crate::errors::VeiledError;
+use crate::state::attestation::Attestation;
+use crate::state::attestor::Attestor;
+use crate::state::circuit::CircuitRegistry;
+use crate::state::domain::DomainConfig;
+use crate::state::stats::{ProtocolStats, StatsCounter};
+use crate::state::verification_result::{ClaimType, SignedVerificationResult};
+use crate::state::verifier::VerifierRegistry;
+use crate::ultrahonk::VerificationResult;
+use crate::verification::ResultChecker;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(result: SignedVerificationResult, nullifier: [u8; 32], domain: [u8; 32])]
+pub struct StoreAttestation<'info> {
+    // * One attestation per nullifier, claim type and domain; replaced only once expired
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Attestation::MAX_SIZE,
+        seeds = [
+            b"attestation",
+            nullifier.as_ref(),
+            result.claim().seed(),
+            domain.as_ref()
+        ],
+        bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+
+    /// CHECK: * Created by the handler, as Anchor can't derive a seed hashed from an argument
+    /// * ResultReceipt PDA [b"result_receipt", sha256(signature)], spent on first use
+    #[account(mut)]
+    pub result_receipt: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: * Verifier that signed the result, when it isn't the fee-paying authority
+    /// * Must be registered and among the result's Ed25519 signers; omit to use the authority
+    pub verifier: Option<UncheckedAccount<'info>>,
+
+    // * Issuer's Attestor registration; required when the domain restricts the claim type
+    #[account(seeds = [b"attestor", attestor.issuer.as_ref()], bump = attestor.bump)]
+    pub attestor: Option<Account<'info, Attestor>>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    /// CHECK: * DomainConfig PDA for `domain`; may not exist yet
+    /// * Address is pinned by seeds so callers can't dodge a domain's pins by omitting it
+    #[account(seeds = [b"domain_config", DomainConfig::seed(&domain).as_ref()], bump)]
+    pub domain_config: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"verifier_registry"], bump = verifier_registry.bump)]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    /// CHECK: * Secp256r1VerifierRegistry PDA (passkey verifiers); may not exist yet
+    #[account(seeds = [b"secp256r1_verifier_registry"], bump)]
+    pub secp256r1_verifier_registry: UncheckedAccount<'info>,
+
+    /// CHECK: * AssurancePolicy PDA (staleness window per tier); may not exist yet
+    #[account(seeds = [b"assurance_policy"], bump)]
+    pub assurance_policy: UncheckedAccount<'info>,
+
+    // * Program-wide totals (see state/stats.rs)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProtocolStats::MAX_SIZE,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_store_attestation(
+    ctx: Context<StoreAttestation>,
+    result: SignedVerificationResult,
+    nullifier: [u8; 32],
+    domain: [u8; 32],
+    expires_in: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    // * A revoked record stays revoked; storing over it would undo the issuer's revocation
+    require!(
+        !ctx.accounts.attestation.is_revoked(),
+        VeiledError::AttestationRevoked
+    );
+    require!(
+        !ctx.accounts.attestation.is_active(now),
+        VeiledError::AttestationActive
+    );
+    let expires_at = Attestation::expiry(now, expires_in)?;
+
+    let domain_config = DomainConfig::load(&ctx.accounts.domain_config)?;
+    if let Some(domain_config) = &domain_config {
+        domain_config.require_not_paused()?;
+    }
+
+    let mut checker = ResultChecker::new(
+        ctx.accounts.authority.key(),
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.circuit_registry,
+        &ctx.accounts.verifier_registry,
+        &ctx.accounts.secp256r1_verifier_registry,
+        &ctx.accounts.assurance_policy,
+        now,
+    )?;
+    if let Some(verifier) = &ctx.accounts.verifier {
+        checker.name_verifier(verifier.key());
+    }
+    let accepted = checker.check(
+        VerificationResult::try_from(result)?,
+        nullifier,
+        domain,
+        domain_config.as_ref(),
+    )?;
+
+    // * Logins register sessions through verify_auth; every other claim lands here
+    require!(
+        !accepted.claim_type.opens_session(),
+        VeiledError::ClaimNotAttestation
+    );
+    Attestor::check_issuer(
+        domain_config.as_ref(),
+        ctx.accounts.attestor.as_deref(),
+        &accepted.verifier,
+        accepted.claim_type,
+    )?;
+
+    accepted.consume_receipt(
+        &ctx.accounts.result_receipt,
+        &ctx.accounts.authority,
+        &ctx.accounts.system_program,
+        &ctx.accounts.verifier_registry,
+        now,
+    )?;
+
+    let attestation = accepted.attestation(ctx.bumps.attestation, now, expires_at);
+    emit_cpi!(AttestationStoredEvent {
+        nullifier,
+        claim_type: attestation.claim_type,
+        domain,
+        claim_data_hash: attestation.claim_data_hash,
+        issuer: attestation.issuer,
+        expires_at,
+    });
+    ctx.accounts.attestation.set_inner(attestation);
+    ctx.accounts
+        .protocol_stats
+        .record(ctx.bumps.protocol_stats, StatsCounter::Attestations, 1);
+
+    msg!("✓ Attestation stored, expires at {}", expires_at);
+    Ok(())
+}
+
+#[event]
+pub struct AttestationStoredEvent {
+    pub nullifier: [u8; 32],
+    pub claim_type: ClaimType,
+    pub domain: [u8; 32],
+    pub claim_data_hash: [u8; 32],
+    pub issuer: Pubkey,
+    pub expires_at: i64,
+}
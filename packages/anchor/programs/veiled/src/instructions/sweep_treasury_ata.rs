@@ -0,0 +1,82 @@
+// * Sweep treasury ATA instruction
+// * Moves collected SPL fees out of a treasury ATA (admin only)
+
+use crate::errors::VeiledError;
+use crate::state::config::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{self, Mint, Token, TransferChecked};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct SweepTreasuryAta<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    /// CHECK: * Treasury PDA - signs the transfer via seeds
+    #[account(seeds = [b"treasury"], bump)]
+    pub treasury: UncheckedAccount<'info>,
+
+    /// CHECK: * SPL Token mint; transfer_checked validates it against both token accounts
+    #[account(owner = token_program.key())]
+    pub mint: UncheckedAccount<'info>,
+
+    /// CHECK: * Address pinned to the treasury ATA for `mint`
+    #[account(
+        mut,
+        address = get_associated_token_address(&treasury.key(), &mint.key())
+    )]
+    pub treasury_ata: UncheckedAccount<'info>,
+
+    /// CHECK: * Any token account for `mint`; validated by the token program
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+
+    pub authority: Signer<'info>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+pub fn handle_sweep_treasury_ata(ctx: Context<SweepTreasuryAta>, amount: u64) -> Result<()> {
+    let decimals = {
+        let mint_data = ctx.accounts.mint.try_borrow_data()?;
+        Mint::try_deserialize(&mut &mint_data[..])?.decimals
+    };
+
+    let treasury_bump = ctx.bumps.treasury;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"treasury", &[treasury_bump]]];
+
+    token::transfer_checked(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            TransferChecked {
+                from: ctx.accounts.treasury_ata.to_account_info(),
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.destination.to_account_info(),
+                authority: ctx.accounts.treasury.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        amount,
+        decimals,
+    )?;
+
+    emit_cpi!(TreasurySweptEvent {
+        mint: ctx.accounts.mint.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct TreasurySweptEvent {
+    pub mint: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,55 @@
+// * Top up rent instruction
+// * Brings a Veiled-owned account back to rent exemption after it grew via realloc (permissionless)
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TopUpRent<'info> {
+    /// CHECK: * Any Veiled-owned account; only its lamports are credited
+    #[account(mut, owner = crate::ID)]
+    pub account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_top_up_rent(ctx: Context<TopUpRent>) -> Result<()> {
+    let account = ctx.accounts.account.to_account_info();
+    let required = Rent::get()?.minimum_balance(account.data_len());
+    let shortfall = required.saturating_sub(account.lamports());
+
+    // * Already exempt: nothing to do, so cranks can call this blindly
+    if shortfall == 0 {
+        return Ok(());
+    }
+
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.payer.to_account_info(),
+                to: account,
+            },
+        ),
+        shortfall,
+    )?;
+
+    emit_cpi!(RentToppedUpEvent {
+        account: ctx.accounts.account.key(),
+        payer: ctx.accounts.payer.key(),
+        lamports: shortfall,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct RentToppedUpEvent {
+    pub account: Pubkey,
+    pub payer: Pubkey,
+    pub lamports: u64,
+}
@@ -0,0 +1,56 @@
+// * Touch session instruction
+// * Heartbeat: the session's authority records activity, so a domain's idle timeout (see
+// * set_domain_idle_timeout) counts from now. Sessions without an idle timeout just keep
+// * last_active_at current for apps that read it.
+// *
+// * Sessions bound to a device (verify_auth_with_device) also need the device's DeviceProof
+// * over the current last_active_at, so a stolen authority key alone can't keep one alive.
+
+use crate::errors::VeiledError;
+use crate::state::session::DeviceProof;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct TouchSession<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"nullifier",
+            nullifier_account.nullifier.as_ref(),
+            nullifier_account.domain_seed().as_ref()
+        ],
+        bump = nullifier_account.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// CHECK: * LogoutEpoch PDA of the session's authority; may not exist yet
+    /// * Address is pinned by seeds so callers can't substitute another identity's epoch
+    #[account(
+        seeds = [b"logout_epoch", nullifier_account.authority.as_ref()],
+        bump
+    )]
+    pub logout_epoch: UncheckedAccount<'info>,
+}
+
+pub fn handle_touch_session(ctx: Context<TouchSession>) -> Result<()> {
+    let clock = Clock::get()?;
+    let session = &mut ctx.accounts.nullifier_account;
+
+    // * An idle session is over; only a fresh verification brings it back
+    session.require_usable(&clock, &ctx.accounts.logout_epoch)?;
+    session.prove_device(
+        &ctx.accounts.instructions_sysvar,
+        &DeviceProof::message(&session.nullifier, session.last_active_at),
+    )?;
+
+    session.last_active_at = clock.unix_timestamp;
+    Ok(())
+}
@@ -0,0 +1,102 @@
+// * Transfer username instruction
+// * Identity rotation: moves a username to another nullifier without releasing it, so nobody
+// * can grab the name in between. Two proofs are needed:
+// *   - the holder's consent: its authority signs the transaction, or signs a UsernameTransfer
+// *     in an Ed25519Program instruction (e.g. when a relayer submits)
+// *   - the new identity's: an active session for the new nullifier, whose authority signs
+// *     (plus its DeviceProof if the session is device-bound)
+// *
+// * The name then belongs to the new session's nullifier, domain and authority.
+
+use crate::errors::VeiledError;
+use crate::sig_introspection;
+use crate::state::session::DeviceProof;
+use crate::state::username::{Username, UsernameTransfer};
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TransferUsername<'info> {
+    #[account(
+        mut,
+        seeds = [b"username", username.name.as_ref()],
+        bump = username.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub username: Account<'info, Username>,
+
+    /// CHECK: * Username's authority; must sign the transaction or a UsernameTransfer
+    pub authority: UncheckedAccount<'info>,
+
+    // * Session of the nullifier taking the name over
+    #[account(
+        seeds = [
+            b"nullifier",
+            new_session.nullifier.as_ref(),
+            new_session.domain_seed().as_ref()
+        ],
+        bump = new_session.bump,
+        constraint = new_session.authority == new_authority.key() @ VeiledError::Unauthorized
+    )]
+    pub new_session: Account<'info, NullifierAccount>,
+
+    pub new_authority: Signer<'info>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    /// CHECK: * LogoutEpoch PDA of the session's authority; may not exist yet
+    /// * Address is pinned by seeds so callers can't substitute another identity's epoch
+    #[account(
+        seeds = [b"logout_epoch", new_session.authority.as_ref()],
+        bump
+    )]
+    pub logout_epoch: UncheckedAccount<'info>,
+}
+
+pub fn handle_transfer_username(ctx: Context<TransferUsername>) -> Result<()> {
+    let clock = Clock::get()?;
+    let session = &ctx.accounts.new_session;
+    let username = &ctx.accounts.username;
+
+    if !ctx.accounts.authority.is_signer {
+        sig_introspection::require_signed(
+            &ctx.accounts.instructions_sysvar,
+            &username.authority,
+            &UsernameTransfer::message(&username.name, &session.nullifier, username.transferred_at),
+            VeiledError::MissingUsernameTransferSignature,
+        )?;
+    }
+
+    session.require_usable(&clock, &ctx.accounts.logout_epoch)?;
+    session.prove_device(
+        &ctx.accounts.instructions_sysvar,
+        &DeviceProof::message(&session.nullifier, session.last_active_at),
+    )?;
+
+    let from_nullifier = username.nullifier;
+    let username = &mut ctx.accounts.username;
+    username.nullifier = session.nullifier;
+    username.domain = session.domain;
+    username.authority = session.authority;
+    username.transferred_at = clock.unix_timestamp;
+
+    emit_cpi!(UsernameTransferredEvent {
+        name: username.name,
+        from_nullifier,
+        to_nullifier: session.nullifier,
+        transferred_at: clock.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct UsernameTransferredEvent {
+    pub name: [u8; 32],
+    pub from_nullifier: [u8; 32],
+    pub to_nullifier: [u8; 32],
+    pub transferred_at: i64,
+}
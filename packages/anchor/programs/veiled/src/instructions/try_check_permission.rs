@@ -0,0 +1,49 @@
+// * Try check permission instruction
+// * Non-aborting permission check for composite transactions
+// *
+// * Returns the result via return data (Anchor sets it from the handler's return value)
+// * and emits PermissionDeniedEvent on denial instead of failing the transaction,
+// * so a calling program can branch on the outcome.
+
+use crate::state::permission::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct TryCheckPermission<'info> {
+    pub permission_grant: Account<'info, PermissionGrant>,
+}
+
+pub fn handle_try_check_permission(
+    ctx: Context<TryCheckPermission>,
+    permission: Permission,
+) -> Result<bool> {
+    let permission_grant = &ctx.accounts.permission_grant;
+    let clock = Clock::get()?;
+
+    match permission_grant.denial_reason(permission, &clock) {
+        None => Ok(true),
+        Some(reason) => {
+            emit_cpi!(PermissionDeniedEvent {
+                nullifier: permission_grant.nullifier,
+                app_id: permission_grant.app_id,
+                permission,
+                permission_mask: permission_grant.permission_mask,
+                reason,
+                checked_at: clock.unix_timestamp,
+            });
+
+            Ok(false)
+        }
+    }
+}
+
+#[event]
+pub struct PermissionDeniedEvent {
+    pub nullifier: [u8; 32],
+    pub app_id: Pubkey,
+    pub permission: Permission,
+    pub permission_mask: u64,
+    pub reason: PermissionDenialReason,
+    pub checked_at: i64,
+}
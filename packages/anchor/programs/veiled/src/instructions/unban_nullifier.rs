@@ -0,0 +1,51 @@
+// * Unban nullifier instruction
+// * A domain's owner lifts a ban by closing its NullifierBan; the rent goes back to the owner.
+
+use crate::errors::VeiledError;
+use crate::state::ban::NullifierBan;
+use crate::state::domain::DomainConfig;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnbanNullifier<'info> {
+    #[account(
+        seeds = [b"domain_config", DomainConfig::seed(&domain_config.domain).as_ref()],
+        bump = domain_config.bump,
+        has_one = owner @ VeiledError::NotDomainOwner
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    #[account(
+        mut,
+        close = owner,
+        seeds = [
+            b"nullifier_ban",
+            domain_config.domain.as_ref(),
+            nullifier_ban.nullifier.as_ref()
+        ],
+        bump = nullifier_ban.bump
+    )]
+    pub nullifier_ban: Account<'info, NullifierBan>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_unban_nullifier(ctx: Context<UnbanNullifier>) -> Result<()> {
+    let ban = &ctx.accounts.nullifier_ban;
+    emit_cpi!(NullifierUnbannedEvent {
+        domain: ban.domain,
+        nullifier: ban.nullifier,
+        unbanned_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct NullifierUnbannedEvent {
+    pub domain: [u8; 32],
+    pub nullifier: [u8; 32],
+    pub unbanned_at: i64,
+}
@@ -0,0 +1,42 @@
+// * Unlink identity root instruction
+// * The root's owner drops a linked nullifier, so apps stop treating it as part of the
+// * identity. Events already emitted for the link stay on record.
+
+use crate::state::identity_root::IdentityRoot;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct UnlinkIdentityRoot<'info> {
+    #[account(
+        mut,
+        seeds = [b"identity_root", owner.key().as_ref()],
+        bump = identity_root.bump,
+        has_one = owner
+    )]
+    pub identity_root: Account<'info, IdentityRoot>,
+
+    pub owner: Signer<'info>,
+}
+
+pub fn handle_unlink_identity_root(
+    ctx: Context<UnlinkIdentityRoot>,
+    nullifier: [u8; 32],
+) -> Result<()> {
+    ctx.accounts.identity_root.unlink(&nullifier)?;
+
+    emit_cpi!(IdentityRootUnlinkedEvent {
+        root: ctx.accounts.identity_root.key(),
+        nullifier,
+        unlinked_at: Clock::get()?.unix_timestamp,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct IdentityRootUnlinkedEvent {
+    pub root: Pubkey,
+    pub nullifier: [u8; 32],
+    pub unlinked_at: i64,
+}
@@ -0,0 +1,93 @@
+// * Upload proof chunk instruction
+// * Appends the next chunk of a verification session's upload, growing the account as it goes.
+// * The last chunk must make the upload hash to the committed proof_hash; the session then
+// * moves on to verification.
+
+use crate::errors::VeiledError;
+use crate::state::verification_session::*;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use solana_sha256_hasher::hashv;
+
+#[derive(Accounts)]
+pub struct UploadProofChunk<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"verification_session",
+            authority.key().as_ref(),
+            verification_session.nullifier.as_ref()
+        ],
+        bump = verification_session.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub verification_session: Account<'info, VerificationSession>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_upload_proof_chunk(
+    ctx: Context<UploadProofChunk>,
+    offset: u32,
+    chunk: Vec<u8>,
+) -> Result<()> {
+    let session = &mut ctx.accounts.verification_session;
+    require!(
+        session.status == VerificationStatus::Uploading,
+        VeiledError::InvalidSessionStatus
+    );
+
+    // * In order only, so bytes_received always describes a contiguous prefix
+    let end = offset
+        .checked_add(chunk.len() as u32)
+        .filter(|&end| {
+            !chunk.is_empty() && offset == session.bytes_received && end <= session.proof_len
+        })
+        .ok_or(VeiledError::ProofChunkOutOfOrder)?;
+
+    // * Grow the account to hold the chunk; the uploader pays the extra rent
+    let info = session.to_account_info();
+    let new_len = VerificationSession::UPLOAD_OFFSET + end as usize;
+    if info.data_len() < new_len {
+        info.resize(new_len)?;
+        let shortfall = Rent::get()?
+            .minimum_balance(new_len)
+            .saturating_sub(info.lamports());
+        if shortfall > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.authority.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                shortfall,
+            )?;
+        }
+    }
+
+    {
+        let mut data = info.try_borrow_mut_data()?;
+        let start = VerificationSession::UPLOAD_OFFSET + offset as usize;
+        data[start..start + chunk.len()].copy_from_slice(&chunk);
+
+        if end == session.proof_len {
+            let upload = &data[VerificationSession::UPLOAD_OFFSET..new_len];
+            require!(
+                hashv(&[upload]).to_bytes() == session.proof_hash,
+                VeiledError::ProofHashMismatch
+            );
+        }
+    }
+
+    session.bytes_received = end;
+    if end == session.proof_len {
+        session.status = VerificationStatus::Verifying;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+// * Validate attestation instruction
+// * Read-only check that an attestation's claim still holds
+// * Intended to be called directly or via CPI before actions gated on a claim
+
+use crate::state::attestation::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ValidateAttestation<'info> {
+    #[account(
+        seeds = [
+            b"attestation",
+            attestation.nullifier.as_ref(),
+            attestation.claim_type.seed(),
+            attestation.domain.as_ref()
+        ],
+        bump = attestation.bump
+    )]
+    pub attestation: Account<'info, Attestation>,
+}
+
+pub fn handle_validate_attestation(ctx: Context<ValidateAttestation>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    ctx.accounts.attestation.require_active(now)
+}
@@ -0,0 +1,71 @@
+// * Validate session delegate instruction
+// * Read-only check, like validate_session_token, that a delegate key may act for its parent
+// * session: the delegate signs, is unexpired and unrevoked, and its scope includes
+// * `permission`; the parent session must itself pass validate_session_token's checks
+// * Intended to be called via CPI before actions a delegate performs
+
+use crate::errors::VeiledError;
+use crate::state::ban::NullifierBan;
+use crate::state::permission::Permission;
+use crate::state::session::*;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ValidateSessionDelegate<'info> {
+    #[account(
+        seeds = [
+            b"session_delegate",
+            nullifier_account.key().as_ref(),
+            delegate.key().as_ref()
+        ],
+        bump = session_delegate.bump
+    )]
+    pub session_delegate: Account<'info, SessionDelegate>,
+
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    pub delegate: Signer<'info>,
+
+    /// CHECK: * LogoutEpoch PDA of the session's authority; may not exist yet
+    /// * Address is pinned by seeds so callers can't substitute another identity's epoch
+    #[account(
+        seeds = [b"logout_epoch", nullifier_account.authority.as_ref()],
+        bump
+    )]
+    pub logout_epoch: UncheckedAccount<'info>,
+
+    /// CHECK: * NullifierBan PDA for the session's domain and nullifier; exists only while banned
+    #[account(
+        seeds = [
+            b"nullifier_ban",
+            nullifier_account.domain.as_ref(),
+            nullifier_account.nullifier.as_ref()
+        ],
+        bump
+    )]
+    pub nullifier_ban: UncheckedAccount<'info>,
+}
+
+pub fn handle_validate_session_delegate(
+    ctx: Context<ValidateSessionDelegate>,
+    permission: Permission,
+) -> Result<()> {
+    let session = &ctx.accounts.nullifier_account;
+    let clock = Clock::get()?;
+
+    let session_delegate = &ctx.accounts.session_delegate;
+    require!(
+        session_delegate.allows(permission, clock.unix_timestamp),
+        VeiledError::DelegateNotAllowed
+    );
+    // * Minted for an earlier session at this address (closed and registered again)
+    require!(
+        session_delegate.created_at >= session.created_at,
+        VeiledError::DelegateNotAllowed
+    );
+
+    // * Same checks as validate_session_token, so a delegate never outlives its parent
+    session.require_usable(&clock, &ctx.accounts.logout_epoch)?;
+    NullifierBan::require_not_banned(&ctx.accounts.nullifier_ban)
+}
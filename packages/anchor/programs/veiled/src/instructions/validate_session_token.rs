@@ -0,0 +1,40 @@
+// * Validate session token instruction
+// * Read-only check that a session (NullifierAccount) is still usable: unexpired, not idle
+// * past its domain's timeout, final, not revoked, and its nullifier not banned from the domain
+// * Intended to be called directly or via CPI before gated actions
+
+use crate::state::ban::NullifierBan;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ValidateSessionToken<'info> {
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    /// CHECK: * LogoutEpoch PDA of the session's authority; may not exist yet
+    /// * Address is pinned by seeds so callers can't substitute another identity's epoch
+    #[account(
+        seeds = [b"logout_epoch", nullifier_account.authority.as_ref()],
+        bump
+    )]
+    pub logout_epoch: UncheckedAccount<'info>,
+
+    /// CHECK: * NullifierBan PDA for the session's domain and nullifier; exists only while banned
+    #[account(
+        seeds = [
+            b"nullifier_ban",
+            nullifier_account.domain.as_ref(),
+            nullifier_account.nullifier.as_ref()
+        ],
+        bump
+    )]
+    pub nullifier_ban: UncheckedAccount<'info>,
+}
+
+pub fn handle_validate_session_token(ctx: Context<ValidateSessionToken>) -> Result<()> {
+    let session = &ctx.accounts.nullifier_account;
+    let clock = Clock::get()?;
+
+    session.require_usable(&clock, &ctx.accounts.logout_epoch)?;
+    NullifierBan::require_not_banned(&ctx.accounts.nullifier_ban)
+}
@@ -0,0 +1,31 @@
+// * Verifier heartbeat instruction
+// * Signed by a weighted verifier key to mark it live for selection
+// * No event: heartbeats are frequent and the timestamp is readable from VerifierWeights
+
+use crate::errors::VeiledError;
+use crate::state::verifier::*;
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct VerifierHeartbeat<'info> {
+    #[account(
+        mut,
+        seeds = [b"verifier_weights"],
+        bump = verifier_weights.bump
+    )]
+    pub verifier_weights: Account<'info, VerifierWeights>,
+
+    pub verifier: Signer<'info>,
+}
+
+pub fn handle_verifier_heartbeat(ctx: Context<VerifierHeartbeat>) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let entry = ctx
+        .accounts
+        .verifier_weights
+        .entry_mut(&ctx.accounts.verifier.key())
+        .ok_or(VeiledError::VerifierNotWeighted)?;
+    entry.last_heartbeat = now;
+
+    Ok(())
+}
@@ -0,0 +1,220 @@
+// * Verify auth (batch) instruction
+// * Registers several verification results in one transaction, e.g. a wallet onboarding flow
+// * authenticating to a few domains at once. Each entry runs through verify_auth's own
+// * verify_and_register (see verification.rs), so it gets the same checks, bans included, and
+// * replaces an expired session the same way; signatures may sit in one Ed25519 instruction or
+// * several.
+// *
+// * remaining_accounts: [nullifier_account, proof_record, domain_config, nullifier_ban,
+// * result_receipt, logout_epoch] per entry, in entry order
+// *   nullifier_account  writable, PDA [b"nullifier", nullifier, sha256(domain)]
+// *   proof_record       writable, PDA [b"proof_record", proof_hash]
+// *   domain_config      PDA [b"domain_config", sha256(domain)]; may not exist yet
+// *   nullifier_ban      PDA [b"nullifier_ban", domain, nullifier]; exists only while banned
+// *   result_receipt     writable, PDA [b"result_receipt", sha256(signature)]
+// *   logout_epoch       PDA [b"logout_epoch", authority] of the session being replaced; only
+// *                      read while that session is live (see NullifierAccount::is_live)
+// *
+// * The whole batch fails if any entry does, so no entry registers on its own.
+
+use crate::errors::VeiledError;
+use crate::pda::load_or_create_pda;
+use crate::state::circuit::CircuitRegistry;
+use crate::state::domain::DomainConfig;
+use crate::state::proof_record::ProofRecord;
+use crate::state::rate_limit::PayerRateLimit;
+use crate::state::stats::ProtocolStats;
+use crate::state::verification_result::SignedVerificationResult;
+use crate::state::verifier::VerifierRegistry;
+use crate::ultrahonk::VerificationResult;
+use crate::verification::VerifyAuthAccounts;
+use crate::{AuthVerifiedEvent, NullifierAccount};
+use anchor_lang::prelude::*;
+
+/// * Entries per batch; a transaction can't fit more results and their signatures anyway
+pub const MAX_BATCH_ENTRIES: usize = 4;
+
+/// * remaining_accounts per entry
+const ACCOUNTS_PER_ENTRY: usize = 6;
+
+/// * One verify_auth call's arguments
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VerifyAuthEntry {
+    pub result: SignedVerificationResult,
+    pub nullifier: [u8; 32],
+    pub domain: [u8; 32],
+}
+
+#[event_cpi]
+#[derive(Accounts)]
+pub struct VerifyAuthBatch<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: * Verifier that signed the results, when it isn't the fee-paying authority
+    /// * Must be registered and among each result's Ed25519 signers; omit to use the authority
+    pub verifier: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    #[account(seeds = [b"verifier_registry"], bump = verifier_registry.bump)]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    /// CHECK: * Secp256r1VerifierRegistry PDA (passkey verifiers); may not exist yet
+    #[account(seeds = [b"secp256r1_verifier_registry"], bump)]
+    pub secp256r1_verifier_registry: UncheckedAccount<'info>,
+
+    /// CHECK: * AssurancePolicy PDA (staleness window per tier); may not exist yet
+    #[account(seeds = [b"assurance_policy"], bump)]
+    pub assurance_policy: UncheckedAccount<'info>,
+
+    // * Each entry counts towards the payer's limit, as if submitted on its own
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PayerRateLimit::MAX_SIZE,
+        seeds = [b"rate_limit", authority.key().as_ref()],
+        bump
+    )]
+    pub payer_rate_limit: AccountLoader<'info, PayerRateLimit>,
+
+    // * Program-wide totals (see state/stats.rs)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProtocolStats::MAX_SIZE,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_verify_auth_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, VerifyAuthBatch<'info>>,
+    entries: Vec<VerifyAuthEntry>,
+) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    require!(
+        !entries.is_empty()
+            && entries.len() <= MAX_BATCH_ENTRIES
+            && remaining.len() == entries.len() * ACCOUNTS_PER_ENTRY,
+        VeiledError::InvalidVerificationBatch
+    );
+
+    let verifier = ctx.accounts.verifier.as_ref().map(Key::key);
+    for (entry, accounts) in entries
+        .iter()
+        .zip(remaining.chunks_exact(ACCOUNTS_PER_ENTRY))
+    {
+        let (
+            nullifier_info,
+            proof_record_info,
+            domain_config_info,
+            nullifier_ban_info,
+            result_receipt_info,
+            logout_epoch_info,
+        ) = (
+            &accounts[0],
+            &accounts[1],
+            &accounts[2],
+            &accounts[3],
+            &accounts[4],
+            &accounts[5],
+        );
+
+        // * Pinned like verify_auth's seeds, so an entry can't dodge its domain's pins or bans
+        let (domain_config_key, _) = Pubkey::find_program_address(
+            &[b"domain_config", DomainConfig::seed(&entry.domain).as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            domain_config_info.key(),
+            domain_config_key,
+            VeiledError::InvalidVerificationBatch
+        );
+        let (nullifier_ban_key, _) = Pubkey::find_program_address(
+            &[
+                b"nullifier_ban",
+                entry.domain.as_ref(),
+                entry.nullifier.as_ref(),
+            ],
+            &crate::ID,
+        );
+        require_keys_eq!(
+            nullifier_ban_info.key(),
+            nullifier_ban_key,
+            VeiledError::InvalidVerificationBatch
+        );
+
+        // * init_if_needed, as verify_auth declares them
+        let domain_hash = NullifierAccount::domain_hash(&entry.domain);
+        let (mut nullifier_account, nullifier_account_bump) = load_or_create_pda::<NullifierAccount>(
+            &ctx.accounts.authority,
+            nullifier_info,
+            &ctx.accounts.system_program,
+            &[b"nullifier", entry.nullifier.as_ref(), domain_hash.as_ref()],
+            8 + NullifierAccount::MAX_SIZE,
+        )?;
+        let (mut proof_record, proof_record_bump) = load_or_create_pda::<ProofRecord>(
+            &ctx.accounts.authority,
+            proof_record_info,
+            &ctx.accounts.system_program,
+            &[b"proof_record", entry.result.proof_hash.as_ref()],
+            8 + ProofRecord::MAX_SIZE,
+        )?;
+
+        // * Batched sessions get the longest lifetime each domain allows
+        let session = VerifyAuthAccounts {
+            nullifier_account: &mut nullifier_account,
+            nullifier_account_bump,
+            proof_record: &mut proof_record,
+            proof_record_bump,
+            result_receipt: result_receipt_info,
+            authority: &ctx.accounts.authority,
+            verifier,
+            instructions_sysvar: &ctx.accounts.instructions_sysvar,
+            circuit_registry: &ctx.accounts.circuit_registry,
+            domain_config: domain_config_info,
+            nullifier_ban: nullifier_ban_info,
+            logout_epoch: Some(logout_epoch_info),
+            verifier_registry: &ctx.accounts.verifier_registry,
+            secp256r1_verifier_registry: &ctx.accounts.secp256r1_verifier_registry,
+            assurance_policy: &ctx.accounts.assurance_policy,
+            payer_rate_limit: &ctx.accounts.payer_rate_limit,
+            payer_rate_limit_bump: ctx.bumps.payer_rate_limit,
+            protocol_stats: &mut ctx.accounts.protocol_stats,
+            protocol_stats_bump: ctx.bumps.protocol_stats,
+            system_program: &ctx.accounts.system_program,
+        }
+        .verify_and_register(
+            VerificationResult::try_from(entry.result.clone())?,
+            entry.nullifier,
+            entry.domain,
+            None,
+            None,
+            0,
+        )?;
+
+        // * Written back now, so a later entry for the same nullifier or proof sees this one
+        // * (and fails as a second verify_auth call would)
+        nullifier_account.exit(&crate::ID)?;
+        proof_record.exit(&crate::ID)?;
+
+        emit_cpi!(AuthVerifiedEvent {
+            nullifier: entry.nullifier,
+            domain: entry.domain,
+            proof_hash: entry.result.proof_hash,
+            expires_at: session.expires_at,
+        });
+    }
+
+    msg!("✓ Registered {} verifications", entries.len());
+    Ok(())
+}
@@ -0,0 +1,230 @@
+// * Verify auth (compressed) instruction
+// * verify_auth for domains using the compressed nullifier store (NullifierStore::Compressed):
+// * the result gets the same checks (see verification.rs), and the nullifier becomes a leaf of
+// * the domain's NullifierTree instead of an account, so a registration costs no rent.
+// *
+// * The caller proves the nullifier isn't registered with its low leaf (NonInclusionProof)
+// * and that leaf's Merkle path:
+// *   1. the low leaf must bracket the nullifier (low_value < nullifier < low_next_value)
+// *   2. replace_leaf swaps it for (low_value, next_index, nullifier); the compression program
+// *      rejects the swap unless the path proves the low leaf is in the tree
+// *   3. append adds (nullifier, low_next_index, low_next_value) at next_index
+// * Indexers rebuild the leaves from CompressedNullifierRegisteredEvent to serve proofs.
+// *
+// * remaining_accounts: the low leaf's proof nodes (sibling hashes as pubkeys, leaf up; fewer
+// * when the tree keeps a canopy)
+// *
+// * Like the bitmap store, registration is permanent and there is no session, ProofRecord or
+// * ResultReceipt: a replayed result is refused because its nullifier is already in the tree.
+
+use crate::compression;
+use crate::state::ban::NullifierBan;
+use crate::state::circuit::CircuitRegistry;
+use crate::state::domain::{DomainConfig, NullifierStore};
+use crate::state::nullifier_tree::{NonInclusionProof, NullifierTree};
+use crate::state::rate_limit::PayerRateLimit;
+use crate::state::stats::{ProtocolStats, StatsCounter};
+use crate::state::verification_result::SignedVerificationResult;
+use crate::state::verifier::VerifierRegistry;
+use crate::ultrahonk::VerificationResult;
+use crate::verification::ResultChecker;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(result: SignedVerificationResult, nullifier: [u8; 32], domain: [u8; 32])]
+pub struct VerifyAuthCompressed<'info> {
+    #[account(
+        mut,
+        seeds = [b"nullifier_tree", domain.as_ref()],
+        bump = nullifier_tree.bump,
+        has_one = merkle_tree
+    )]
+    pub nullifier_tree: Account<'info, NullifierTree>,
+
+    /// CHECK: * The domain's tree (has_one); checked and updated by the compression program
+    #[account(mut)]
+    pub merkle_tree: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: * Verifier that signed the result, when it isn't the fee-paying authority
+    /// * Must be registered and among the result's Ed25519 signers; omit to use the authority
+    pub verifier: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    // * Must exist: only domains that chose the compressed store register here
+    #[account(
+        seeds = [b"domain_config", DomainConfig::seed(&domain).as_ref()],
+        bump = domain_config.bump
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    /// CHECK: * NullifierBan PDA for `domain` and `nullifier`; exists only while it's banned
+    #[account(seeds = [b"nullifier_ban", domain.as_ref(), nullifier.as_ref()], bump)]
+    pub nullifier_ban: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"verifier_registry"], bump = verifier_registry.bump)]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    /// CHECK: * Secp256r1VerifierRegistry PDA (passkey verifiers); may not exist yet
+    #[account(seeds = [b"secp256r1_verifier_registry"], bump)]
+    pub secp256r1_verifier_registry: UncheckedAccount<'info>,
+
+    /// CHECK: * AssurancePolicy PDA (staleness window per tier); may not exist yet
+    #[account(seeds = [b"assurance_policy"], bump)]
+    pub assurance_policy: UncheckedAccount<'info>,
+
+    // * Same per-payer counter verify_auth enforces
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PayerRateLimit::MAX_SIZE,
+        seeds = [b"rate_limit", authority.key().as_ref()],
+        bump
+    )]
+    pub payer_rate_limit: AccountLoader<'info, PayerRateLimit>,
+
+    /// CHECK: * SPL Account Compression program
+    #[account(address = compression::COMPRESSION_PROGRAM_ID)]
+    pub compression_program: UncheckedAccount<'info>,
+
+    /// CHECK: * SPL Noop program the compression program logs through
+    #[account(address = compression::NOOP_PROGRAM_ID)]
+    pub noop_program: UncheckedAccount<'info>,
+
+    // * Program-wide totals (see state/stats.rs)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProtocolStats::MAX_SIZE,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_verify_auth_compressed<'info>(
+    ctx: Context<'_, '_, '_, 'info, VerifyAuthCompressed<'info>>,
+    result: SignedVerificationResult,
+    nullifier: [u8; 32],
+    domain: [u8; 32],
+    proof: NonInclusionProof,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let domain_config: &DomainConfig = &ctx.accounts.domain_config;
+    domain_config.require_not_paused()?;
+    domain_config.require_nullifier_store(NullifierStore::Compressed)?;
+    domain_config.check_origin(None)?;
+    NullifierBan::require_not_banned(&ctx.accounts.nullifier_ban)?;
+
+    PayerRateLimit::enforce(
+        &ctx.accounts.payer_rate_limit,
+        ctx.accounts.authority.key(),
+        ctx.bumps.payer_rate_limit,
+        domain_config.rate_limit,
+        now,
+    )?;
+
+    let mut checker = ResultChecker::new(
+        ctx.accounts.authority.key(),
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.circuit_registry,
+        &ctx.accounts.verifier_registry,
+        &ctx.accounts.secp256r1_verifier_registry,
+        &ctx.accounts.assurance_policy,
+        now,
+    )?;
+    if let Some(verifier) = &ctx.accounts.verifier {
+        checker.name_verifier(verifier.key());
+    }
+    let accepted = checker.check(
+        VerificationResult::try_from(result)?,
+        nullifier,
+        domain,
+        Some(domain_config),
+    )?;
+    accepted.require_session_claim()?;
+
+    proof.check(&nullifier)?;
+
+    let tree = &ctx.accounts.nullifier_tree;
+    let tree_authority = tree.key();
+    let merkle_tree = ctx.accounts.merkle_tree.key();
+    let leaf_index = tree.next_index;
+    let signer_seeds: &[&[u8]] = &[b"nullifier_tree", domain.as_ref(), &[tree.bump]];
+
+    // * Point the low leaf at the nullifier; fails unless the low leaf is in the tree
+    let mut accounts = vec![
+        ctx.accounts.merkle_tree.to_account_info(),
+        tree.to_account_info(),
+        ctx.accounts.noop_program.to_account_info(),
+        ctx.accounts.compression_program.to_account_info(),
+    ];
+    accounts.extend(ctx.remaining_accounts.iter().cloned());
+    invoke_signed(
+        &compression::replace_leaf(
+            &merkle_tree,
+            &tree_authority,
+            &proof.root,
+            &proof.low_leaf(),
+            &NullifierTree::leaf(&proof.low_value, leaf_index, &nullifier),
+            proof.low_index,
+            ctx.remaining_accounts,
+        ),
+        &accounts,
+        &[signer_seeds],
+    )?;
+
+    // * ...and insert the nullifier where the low leaf used to point
+    let leaf = NullifierTree::leaf(&nullifier, proof.low_next_index, &proof.low_next_value);
+    invoke_signed(
+        &compression::append(&merkle_tree, &tree_authority, &leaf),
+        &accounts[..4],
+        &[signer_seeds],
+    )?;
+
+    // * Can't overflow: the compression program refuses appends once the tree (depth <= 30) fills
+    ctx.accounts.nullifier_tree.next_index += 1;
+    ctx.accounts
+        .protocol_stats
+        .record(ctx.bumps.protocol_stats, StatsCounter::Verifications, 1);
+
+    emit_cpi!(CompressedNullifierRegisteredEvent {
+        nullifier,
+        domain,
+        proof_hash: accepted.result.proof_hash,
+        leaf_index,
+        low_index: proof.low_index,
+        next_index: proof.low_next_index,
+        next_value: proof.low_next_value,
+        registered_at: now,
+    });
+
+    msg!("✓ Nullifier appended to tree at leaf {}", leaf_index);
+    Ok(())
+}
+
+/// * Enough for indexers to rebuild the tree: leaf `leaf_index` is (nullifier, next_index,
+/// * next_value), and leaf `low_index` now points at it
+#[event]
+pub struct CompressedNullifierRegisteredEvent {
+    pub nullifier: [u8; 32],
+    pub domain: [u8; 32],
+    pub proof_hash: [u8; 32],
+    pub leaf_index: u32,
+    pub low_index: u32,
+    pub next_index: u32,
+    pub next_value: [u8; 32],
+    pub registered_at: i64,
+}
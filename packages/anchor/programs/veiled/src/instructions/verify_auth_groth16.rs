@@ -0,0 +1,180 @@
+// * Verify auth (Groth16) instruction
+// * Native verification path: checks a Groth16 proof on-chain with the alt_bn128 syscalls and
+// * registers the nullifier, with no off-chain verifier signature involved. Meant for circuits
+// * small enough to fit the compute budget (up to 4 public inputs, ~120k CU).
+// *
+// * The circuit's first two public inputs bind the session:
+// *   public_inputs[0] = nullifier (must already be a BN254 scalar)
+// *   public_inputs[1] = groth16::domain_input(domain)
+// *
+// * Anyone may submit and pays for the session; the submitter becomes its authority.
+// * The proof itself was checked, so sessions are final immediately, even on domains
+// * using optimistic verification.
+
+use crate::errors::VeiledError;
+use crate::groth16::{self, Groth16Proof, PROOF_SIZE};
+use crate::sandbox;
+use crate::state::ban::NullifierBan;
+use crate::state::circuit::CircuitRegistry;
+use crate::state::domain::{DomainConfig, NullifierStore};
+use crate::state::groth16::Groth16VerifyingKey;
+use crate::state::rate_limit::PayerRateLimit;
+use crate::state::stats::{ProtocolStats, StatsCounter};
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+#[derive(Accounts)]
+#[instruction(circuit_id: u32, proof: [u8; PROOF_SIZE], public_inputs: Vec<[u8; 32]>, nullifier: [u8; 32], domain: [u8; 32])]
+pub struct VerifyAuthGroth16<'info> {
+    // * Same nullifier PDA verify_auth uses, so a nullifier registers once per domain across
+    // * both paths
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + NullifierAccount::MAX_SIZE,
+        seeds = [
+            b"nullifier",
+            nullifier.as_ref(),
+            NullifierAccount::domain_hash(&domain).as_ref()
+        ],
+        bump
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    #[account(
+        seeds = [b"groth16_vk", circuit_id.to_le_bytes().as_ref()],
+        bump = groth16_vk.bump
+    )]
+    pub groth16_vk: Account<'info, Groth16VerifyingKey>,
+
+    /// CHECK: * DomainConfig PDA for `domain`; may not exist yet
+    /// * Address is pinned by seeds so callers can't dodge a domain's pins by omitting it
+    #[account(seeds = [b"domain_config", DomainConfig::seed(&domain).as_ref()], bump)]
+    pub domain_config: UncheckedAccount<'info>,
+
+    /// CHECK: * NullifierBan PDA for `domain` and `nullifier`; exists only while it's banned
+    #[account(seeds = [b"nullifier_ban", domain.as_ref(), nullifier.as_ref()], bump)]
+    pub nullifier_ban: UncheckedAccount<'info>,
+
+    // * Same per-payer counter verify_auth enforces
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PayerRateLimit::MAX_SIZE,
+        seeds = [b"rate_limit", authority.key().as_ref()],
+        bump
+    )]
+    pub payer_rate_limit: AccountLoader<'info, PayerRateLimit>,
+
+    // * Program-wide totals (see state/stats.rs)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProtocolStats::MAX_SIZE,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_verify_auth_groth16(
+    ctx: Context<VerifyAuthGroth16>,
+    circuit_id: u32,
+    proof: [u8; PROOF_SIZE],
+    public_inputs: Vec<[u8; 32]>,
+    nullifier: [u8; 32],
+    domain: [u8; 32],
+) -> Result<()> {
+    // * Same canonical form verify_auth accepts
+    let domain_str = DomainConfig::validate_domain(&domain)?;
+
+    // * The sandbox domain is reserved for devnet builds on every path
+    require!(
+        !sandbox::is_sandbox_domain(domain_str.as_bytes()) || sandbox::ENABLED,
+        VeiledError::SandboxUnavailable
+    );
+
+    let domain_config = DomainConfig::load(&ctx.accounts.domain_config)?;
+    if let Some(domain_config) = &domain_config {
+        domain_config.require_not_paused()?;
+        domain_config.require_nullifier_store(NullifierStore::Accounts)?;
+        domain_config.check_origin(None)?;
+    }
+    NullifierBan::require_not_banned(&ctx.accounts.nullifier_ban)?;
+    let clock = Clock::get()?;
+    let current_timestamp = clock.unix_timestamp;
+
+    PayerRateLimit::enforce(
+        &ctx.accounts.payer_rate_limit,
+        ctx.accounts.authority.key(),
+        ctx.bumps.payer_rate_limit,
+        domain_config.as_ref().map_or(0, |config| config.rate_limit),
+        current_timestamp,
+    )?;
+
+    // * The circuit must still be active, and a domain pinning a key only takes that circuit
+    let circuit = ctx.accounts.circuit_registry.require_active(circuit_id)?;
+    if let Some(domain_config) = &domain_config {
+        domain_config.check_vk_hash(Some(&circuit.vk_hash))?;
+    }
+
+    // * The proof must be about this nullifier and domain
+    require!(
+        public_inputs.len() >= Groth16VerifyingKey::MIN_PUBLIC_INPUTS
+            && public_inputs[0] == nullifier
+            && public_inputs[1] == groth16::domain_input(&domain),
+        VeiledError::InvalidPublicInputs
+    );
+
+    groth16::verify(
+        &ctx.accounts.groth16_vk,
+        &Groth16Proof::from_bytes(&proof),
+        &public_inputs,
+    )?;
+
+    msg!("✓ Groth16 proof verified on-chain");
+
+    // * Replay protection, as in verify_auth
+    let nullifier_account = &mut ctx.accounts.nullifier_account;
+    require!(
+        nullifier_account.created_at == 0,
+        VeiledError::DuplicateNullifier
+    );
+
+    msg!("Nullifier: {:?}", nullifier);
+    msg!("Domain: {}", domain_str);
+
+    let public_inputs_bytes: Vec<&[u8]> =
+        public_inputs.iter().map(|input| input.as_slice()).collect();
+    nullifier_account.nullifier = nullifier;
+    nullifier_account.domain = domain;
+    nullifier_account.domain_len = domain_str.len() as u8;
+    nullifier_account.authority = ctx.accounts.authority.key();
+    nullifier_account.created_at = current_timestamp;
+    nullifier_account.last_active_at = current_timestamp;
+    nullifier_account.idle_timeout_secs = DomainConfig::idle_timeout(domain_config.as_ref());
+    nullifier_account.public_inputs_hash = hashv(&public_inputs_bytes).to_bytes();
+    nullifier_account.circuit_id = circuit_id;
+    nullifier_account.proof_hash = hashv(&[&proof]).to_bytes();
+    nullifier_account.verified_at = current_timestamp;
+    nullifier_account.verifier = Pubkey::default();
+    nullifier_account.finalizes_at = 0;
+    nullifier_account.payer = ctx.accounts.authority.key();
+    nullifier_account.bump = ctx.bumps.nullifier_account;
+    nullifier_account.expires_at =
+        DomainConfig::session_expires_at(domain_config.as_ref(), 0, &clock)?;
+    ctx.accounts
+        .protocol_stats
+        .record(ctx.bumps.protocol_stats, StatsCounter::Verifications, 1);
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+// * Verify auth (sharded) instruction
+// * verify_auth for domains using the bitmap nullifier store (NullifierStore::Bitmap): the
+// * result gets the same checks (see verification.rs), but instead of a NullifierAccount the
+// * nullifier sets one bit in its NullifierShard, so a registration costs a bit rather than a
+// * rent-exempt account. Only the first registration of a shard pays for it.
+// *
+// * There is no session: registration is permanent and the nullifier can't register on the
+// * domain again. A replayed result is refused by its nullifier's bit, so neither a
+// * ProofRecord nor a ResultReceipt is kept.
+
+use crate::errors::VeiledError;
+use crate::state::ban::NullifierBan;
+use crate::state::circuit::CircuitRegistry;
+use crate::state::domain::{DomainConfig, NullifierStore};
+use crate::state::nullifier_shard::NullifierShard;
+use crate::state::rate_limit::PayerRateLimit;
+use crate::state::stats::{ProtocolStats, StatsCounter};
+use crate::state::verification_result::SignedVerificationResult;
+use crate::state::verifier::VerifierRegistry;
+use crate::ultrahonk::VerificationResult;
+use crate::verification::ResultChecker;
+use crate::NullifierAccount;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(result: SignedVerificationResult, nullifier: [u8; 32], domain: [u8; 32])]
+pub struct VerifyAuthSharded<'info> {
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + NullifierShard::MAX_SIZE,
+        seeds = [
+            b"nullifier_shard",
+            NullifierAccount::domain_hash(&domain).as_ref(),
+            NullifierShard::seed(&nullifier).as_ref()
+        ],
+        bump
+    )]
+    pub nullifier_shard: AccountLoader<'info, NullifierShard>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: * Verifier that signed the result, when it isn't the fee-paying authority
+    /// * Must be registered and among the result's Ed25519 signers; omit to use the authority
+    pub verifier: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    // * Must exist: only domains that chose the bitmap store register here
+    #[account(
+        seeds = [b"domain_config", DomainConfig::seed(&domain).as_ref()],
+        bump = domain_config.bump
+    )]
+    pub domain_config: Account<'info, DomainConfig>,
+
+    /// CHECK: * NullifierBan PDA for `domain` and `nullifier`; exists only while it's banned
+    #[account(seeds = [b"nullifier_ban", domain.as_ref(), nullifier.as_ref()], bump)]
+    pub nullifier_ban: UncheckedAccount<'info>,
+
+    #[account(seeds = [b"verifier_registry"], bump = verifier_registry.bump)]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    /// CHECK: * Secp256r1VerifierRegistry PDA (passkey verifiers); may not exist yet
+    #[account(seeds = [b"secp256r1_verifier_registry"], bump)]
+    pub secp256r1_verifier_registry: UncheckedAccount<'info>,
+
+    /// CHECK: * AssurancePolicy PDA (staleness window per tier); may not exist yet
+    #[account(seeds = [b"assurance_policy"], bump)]
+    pub assurance_policy: UncheckedAccount<'info>,
+
+    // * Same per-payer counter verify_auth enforces
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PayerRateLimit::MAX_SIZE,
+        seeds = [b"rate_limit", authority.key().as_ref()],
+        bump
+    )]
+    pub payer_rate_limit: AccountLoader<'info, PayerRateLimit>,
+
+    // * Program-wide totals (see state/stats.rs)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProtocolStats::MAX_SIZE,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handle_verify_auth_sharded(
+    ctx: Context<VerifyAuthSharded>,
+    result: SignedVerificationResult,
+    nullifier: [u8; 32],
+    domain: [u8; 32],
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let domain_config: &DomainConfig = &ctx.accounts.domain_config;
+    domain_config.require_not_paused()?;
+    domain_config.require_nullifier_store(NullifierStore::Bitmap)?;
+    domain_config.check_origin(None)?;
+    NullifierBan::require_not_banned(&ctx.accounts.nullifier_ban)?;
+
+    PayerRateLimit::enforce(
+        &ctx.accounts.payer_rate_limit,
+        ctx.accounts.authority.key(),
+        ctx.bumps.payer_rate_limit,
+        domain_config.rate_limit,
+        now,
+    )?;
+
+    let mut checker = ResultChecker::new(
+        ctx.accounts.authority.key(),
+        &ctx.accounts.instructions_sysvar,
+        &ctx.accounts.circuit_registry,
+        &ctx.accounts.verifier_registry,
+        &ctx.accounts.secp256r1_verifier_registry,
+        &ctx.accounts.assurance_policy,
+        now,
+    )?;
+    if let Some(verifier) = &ctx.accounts.verifier {
+        checker.name_verifier(verifier.key());
+    }
+    let accepted = checker.check(
+        VerificationResult::try_from(result)?,
+        nullifier,
+        domain,
+        Some(domain_config),
+    )?;
+    accepted.require_session_claim()?;
+
+    let (shard_index, slot) = NullifierShard::position(&nullifier);
+    {
+        let mut shard = NullifierShard::load_or_init(&ctx.accounts.nullifier_shard)?;
+        shard.domain = domain;
+        shard.shard = shard_index;
+        shard.bump = ctx.bumps.nullifier_shard;
+        require!(shard.register(slot), VeiledError::DuplicateNullifier);
+    }
+    ctx.accounts
+        .protocol_stats
+        .record(ctx.bumps.protocol_stats, StatsCounter::Verifications, 1);
+
+    emit_cpi!(ShardedNullifierRegisteredEvent {
+        nullifier,
+        domain,
+        proof_hash: accepted.result.proof_hash,
+        shard: shard_index,
+        registered_at: now,
+    });
+
+    msg!("✓ Nullifier registered in shard {}", shard_index);
+    Ok(())
+}
+
+#[event]
+pub struct ShardedNullifierRegisteredEvent {
+    pub nullifier: [u8; 32],
+    pub domain: [u8; 32],
+    pub proof_hash: [u8; 32],
+    pub shard: u16,
+    pub registered_at: i64,
+}
@@ -0,0 +1,84 @@
+// * Write UltraHonk verifying key instruction
+// * Stores a run of commitments into a registered UltraHonk key (admin only). Runs may be
+// * rewritten until the key is complete; the write that completes it must make the key hash
+// * to the circuit's vk_hash, after which it is frozen.
+
+use crate::errors::VeiledError;
+use crate::state::circuit::*;
+use crate::state::config::*;
+use crate::state::honk::*;
+use anchor_lang::prelude::*;
+
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(circuit_id: u32)]
+pub struct WriteHonkVk<'info> {
+    #[account(
+        seeds = [b"config"],
+        bump = config.bump,
+        has_one = authority @ VeiledError::Unauthorized
+    )]
+    pub config: Account<'info, ProtocolConfig>,
+
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    // * Boxed: at ~1.8 KB the key would crowd the SBF stack frame
+    #[account(
+        mut,
+        seeds = [b"honk_vk", circuit_id.to_le_bytes().as_ref()],
+        bump = honk_vk.bump
+    )]
+    pub honk_vk: Box<Account<'info, HonkVerifyingKey>>,
+
+    pub authority: Signer<'info>,
+}
+
+pub fn handle_write_honk_vk(
+    ctx: Context<WriteHonkVk>,
+    circuit_id: u32,
+    offset: u8,
+    commitments: Vec<[u8; 64]>,
+) -> Result<()> {
+    let registered_vk_hash = ctx
+        .accounts
+        .circuit_registry
+        .require_active(circuit_id)?
+        .vk_hash;
+
+    let vk = &mut ctx.accounts.honk_vk;
+    let offset = usize::from(offset);
+    require!(
+        !vk.is_complete()
+            && !commitments.is_empty()
+            && commitments.len() <= HonkVerifyingKey::MAX_COMMITMENTS_PER_WRITE
+            && offset + commitments.len() <= HonkVerifyingKey::COMMITMENTS,
+        VeiledError::InvalidHonkVk
+    );
+
+    vk.commitments[offset..offset + commitments.len()].copy_from_slice(&commitments);
+    if !vk.mark_written(offset, commitments.len()) {
+        return Ok(());
+    }
+
+    // * Ties the key to what the admin registered, so domains pinning vk_hash pin this key
+    let vk_hash = vk.hash();
+    require!(vk_hash == registered_vk_hash, VeiledError::InvalidHonkVk);
+
+    emit_cpi!(HonkVkRegisteredEvent {
+        circuit_id,
+        vk_hash,
+        log_circuit_size: vk.log_circuit_size,
+        public_inputs_size: vk.public_inputs_size,
+    });
+
+    Ok(())
+}
+
+#[event]
+pub struct HonkVkRegisteredEvent {
+    pub circuit_id: u32,
+    pub vk_hash: [u8; 32],
+    pub log_circuit_size: u8,
+    pub public_inputs_size: u32,
+}
@@ -14,14 +14,43 @@
 // * 5. Program validates signature and stores result
 
 use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
 
+#[cfg(all(feature = "canonical-json", not(target_os = "solana")))]
+pub mod canonical; // * Off-chain JSON / Display rendering (see canonical.rs)
+mod cluster;
+mod compression;
+mod cpi_guard;
 mod errors;
+mod fr;
+mod genesis;
+mod groth16;
+mod honk;
 pub mod instructions; // * Must be pub for Anchor macro to access
-mod state;
+mod localnet;
+mod merkle;
+mod oracle;
+mod pda;
+mod sandbox;
+mod sas;
+mod sig_introspection;
+mod sns;
+pub mod state; // * Pub so CPI callers can load Veiled accounts and build instruction args
 mod ultrahonk;
+mod verification;
 
-use errors::VeiledError;
+use state::attestor::AttestorStatus;
+use state::circuit::CircuitRegistry;
+use state::domain::{DomainConfig, DomainRole};
+use state::expiry::ExpiryKind;
+use state::proof_record::ProofRecord;
+use state::rate_limit::PayerRateLimit;
+use state::session::{SessionDetails, UserConsent};
+use state::stats::ProtocolStats;
+use state::verification_result::SignedVerificationResult;
+use state::verifier::VerifierRegistry;
 use ultrahonk::VerificationResult;
+use verification::VerifyAuthAccounts;
 
 // * Re-export everything from instructions module at crate root
 // * This fixes the "__client_accounts_instructions" unresolved import error
@@ -32,131 +61,589 @@ declare_id!("H6apEGZAw23AKUeqCX41wkDv2LVwX3Ec8oYPip7k3xzA");
 
 // * Define VerifyAuth at crate root (before #[program] block) so macro can find it
 // * This Accounts struct is used by verify_auth instruction handler
+#[event_cpi]
 #[derive(Accounts)]
-#[instruction(verification_result: Vec<u8>, nullifier: [u8; 32], domain: [u8; 32])]
+#[instruction(result: SignedVerificationResult, nullifier: [u8; 32], domain: [u8; 32])]
 pub struct VerifyAuth<'info> {
-    // * PDA for nullifier account - deterministic address per nullifier
+    // * PDA for nullifier account - deterministic address per nullifier and domain
+    // * Uses init_if_needed to handle account creation
+    // * The instruction logic checks if account was already used
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + NullifierAccount::MAX_SIZE,
+        // * PDA keyed by nullifier and domain for replay protection
+        seeds = [
+            b"nullifier",
+            nullifier.as_ref(),
+            NullifierAccount::domain_hash(&domain).as_ref()
+        ],
+        bump
+    )]
+    pub nullifier_account: Account<'info, NullifierAccount>,
+
+    // * PDA keyed by the result's proof hash, so one proof registers one nullifier and domain
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProofRecord::MAX_SIZE,
+        seeds = [b"proof_record", result.proof_hash.as_ref()],
+        bump
+    )]
+    pub proof_record: Account<'info, ProofRecord>,
+
+    /// CHECK: * Created by the handler, as Anchor can't derive a seed hashed from an argument
+    /// * ResultReceipt PDA [b"result_receipt", sha256(signature)], spent on first use
+    #[account(mut)]
+    pub result_receipt: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: * Verifier that signed the result, when it isn't the fee-paying authority
+    /// * Must be registered and among the result's Ed25519 signers; omit to use the authority
+    pub verifier: Option<UncheckedAccount<'info>>,
+
+    /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
+    #[account(address = solana_instructions_sysvar::id())]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+
+    // * Registered circuits; the result's circuit must be listed and not deprecated
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    /// CHECK: * DomainConfig PDA for `domain`; may not exist yet
+    /// * Address is pinned by seeds so callers can't dodge a domain's pins by omitting it
+    #[account(seeds = [b"domain_config", DomainConfig::seed(&domain).as_ref()], bump)]
+    pub domain_config: UncheckedAccount<'info>,
+
+    /// CHECK: * NullifierBan PDA for `domain` and `nullifier`; exists only while it's banned
+    #[account(seeds = [b"nullifier_ban", domain.as_ref(), nullifier.as_ref()], bump)]
+    pub nullifier_ban: UncheckedAccount<'info>,
+
+    /// CHECK: * LogoutEpoch PDA of the authority of the session being replaced, if any
+    /// * Checked by the handler (see NullifierAccount::is_live), so a session revoked by
+    /// * global_logout can be replaced straight away; omit to treat it as live until it expires
+    pub logout_epoch: Option<UncheckedAccount<'info>>,
+
+    // * Verifier keys allowed to sign results
+    #[account(seeds = [b"verifier_registry"], bump = verifier_registry.bump)]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    /// CHECK: * Secp256r1VerifierRegistry PDA (passkey verifiers); may not exist yet
+    #[account(seeds = [b"secp256r1_verifier_registry"], bump)]
+    pub secp256r1_verifier_registry: UncheckedAccount<'info>,
+
+    /// CHECK: * AssurancePolicy PDA (staleness window per tier); may not exist yet
+    #[account(seeds = [b"assurance_policy"], bump)]
+    pub assurance_policy: UncheckedAccount<'info>,
+
+    // * Sliding-window count of the fee payer's recent verifications
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PayerRateLimit::MAX_SIZE,
+        seeds = [b"rate_limit", authority.key().as_ref()],
+        bump
+    )]
+    pub payer_rate_limit: AccountLoader<'info, PayerRateLimit>,
+
+    // * Program-wide totals (see state/stats.rs)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProtocolStats::MAX_SIZE,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+// * verify_auth_v1's accounts: VerifyAuth's, with the proof record keyed from the byte envelope
+#[event_cpi]
+#[derive(Accounts)]
+#[instruction(verification_result: Vec<u8>, nullifier: [u8; 32], domain: [u8; 32])]
+pub struct VerifyAuthV1<'info> {
+    // * PDA for nullifier account - deterministic address per nullifier and domain
     // * Uses init_if_needed to handle account creation
     // * The instruction logic checks if account was already used
     #[account(
         init_if_needed,
         payer = authority,
-        space = 8 + 32 + 4 + 32 + 8 + 8, // * 8 discriminator + 32 nullifier + 4 String len + 32 domain max + 8 created_at + 8 expires_at
-        // * PDA keyed by nullifier for replay protection
-        seeds = [b"nullifier", nullifier.as_ref()],
+        space = 8 + NullifierAccount::MAX_SIZE,
+        // * PDA keyed by nullifier and domain for replay protection
+        seeds = [
+            b"nullifier",
+            nullifier.as_ref(),
+            NullifierAccount::domain_hash(&domain).as_ref()
+        ],
         bump
     )]
     pub nullifier_account: Account<'info, NullifierAccount>,
 
+    // * PDA keyed by the result's proof hash, so one proof registers one nullifier and domain
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProofRecord::MAX_SIZE,
+        seeds = [b"proof_record", ultrahonk::proof_hash_seed(&verification_result)],
+        bump
+    )]
+    pub proof_record: Account<'info, ProofRecord>,
+
+    /// CHECK: * Created by the handler, as Anchor can't derive a seed hashed from an argument
+    /// * ResultReceipt PDA [b"result_receipt", sha256(signature)], spent on first use
+    #[account(mut)]
+    pub result_receipt: UncheckedAccount<'info>,
+
     #[account(mut)]
     pub authority: Signer<'info>,
 
+    /// CHECK: * Verifier that signed the result, when it isn't the fee-paying authority
+    /// * Must be registered and among the result's Ed25519 signers; omit to use the authority
+    pub verifier: Option<UncheckedAccount<'info>>,
+
     /// CHECK: * Instructions sysvar used for Ed25519Program instruction introspection
     #[account(address = solana_instructions_sysvar::id())]
     pub instructions_sysvar: UncheckedAccount<'info>,
 
+    // * Registered circuits; the result's circuit must be listed and not deprecated
+    #[account(seeds = [b"circuit_registry"], bump = circuit_registry.bump)]
+    pub circuit_registry: Account<'info, CircuitRegistry>,
+
+    /// CHECK: * DomainConfig PDA for `domain`; may not exist yet
+    /// * Address is pinned by seeds so callers can't dodge a domain's pins by omitting it
+    #[account(seeds = [b"domain_config", DomainConfig::seed(&domain).as_ref()], bump)]
+    pub domain_config: UncheckedAccount<'info>,
+
+    /// CHECK: * NullifierBan PDA for `domain` and `nullifier`; exists only while it's banned
+    #[account(seeds = [b"nullifier_ban", domain.as_ref(), nullifier.as_ref()], bump)]
+    pub nullifier_ban: UncheckedAccount<'info>,
+
+    /// CHECK: * LogoutEpoch PDA of the authority of the session being replaced, if any
+    /// * Checked by the handler (see NullifierAccount::is_live), so a session revoked by
+    /// * global_logout can be replaced straight away; omit to treat it as live until it expires
+    pub logout_epoch: Option<UncheckedAccount<'info>>,
+
+    // * Verifier keys allowed to sign results
+    #[account(seeds = [b"verifier_registry"], bump = verifier_registry.bump)]
+    pub verifier_registry: Account<'info, VerifierRegistry>,
+
+    /// CHECK: * Secp256r1VerifierRegistry PDA (passkey verifiers); may not exist yet
+    #[account(seeds = [b"secp256r1_verifier_registry"], bump)]
+    pub secp256r1_verifier_registry: UncheckedAccount<'info>,
+
+    /// CHECK: * AssurancePolicy PDA (staleness window per tier); may not exist yet
+    #[account(seeds = [b"assurance_policy"], bump)]
+    pub assurance_policy: UncheckedAccount<'info>,
+
+    // * Sliding-window count of the fee payer's recent verifications
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + PayerRateLimit::MAX_SIZE,
+        seeds = [b"rate_limit", authority.key().as_ref()],
+        bump
+    )]
+    pub payer_rate_limit: AccountLoader<'info, PayerRateLimit>,
+
+    // * Program-wide totals (see state/stats.rs)
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + ProtocolStats::MAX_SIZE,
+        seeds = [b"protocol_stats"],
+        bump
+    )]
+    pub protocol_stats: Account<'info, ProtocolStats>,
+
     pub system_program: Program<'info, System>,
 }
 
+// * Both verify_auth instructions share one implementation (see verification.rs)
+macro_rules! verify_auth_accounts {
+    ($accounts:ty, $bumps:ty) => {
+        impl<'info> $accounts {
+            fn verification(&mut self, bumps: &$bumps) -> VerifyAuthAccounts<'_, 'info> {
+                VerifyAuthAccounts {
+                    nullifier_account: &mut self.nullifier_account,
+                    nullifier_account_bump: bumps.nullifier_account,
+                    proof_record: &mut self.proof_record,
+                    proof_record_bump: bumps.proof_record,
+                    result_receipt: &self.result_receipt,
+                    authority: &self.authority,
+                    verifier: self.verifier.as_ref().map(|verifier| verifier.key()),
+                    instructions_sysvar: &self.instructions_sysvar,
+                    circuit_registry: &self.circuit_registry,
+                    domain_config: &self.domain_config,
+                    nullifier_ban: &self.nullifier_ban,
+                    logout_epoch: self.logout_epoch.as_ref().map(|epoch| epoch.as_ref()),
+                    verifier_registry: &self.verifier_registry,
+                    secp256r1_verifier_registry: &self.secp256r1_verifier_registry,
+                    assurance_policy: &self.assurance_policy,
+                    payer_rate_limit: &self.payer_rate_limit,
+                    payer_rate_limit_bump: bumps.payer_rate_limit,
+                    protocol_stats: &mut self.protocol_stats,
+                    protocol_stats_bump: bumps.protocol_stats,
+                    system_program: &self.system_program,
+                }
+            }
+        }
+    };
+}
+
+verify_auth_accounts!(VerifyAuth<'info>, VerifyAuthBumps);
+verify_auth_accounts!(VerifyAuthV1<'info>, VerifyAuthV1Bumps);
+
 #[program]
 pub mod veiled {
     use super::*;
 
     // * Main instruction: Store verification result and register nullifier
     // *
-    // * verification_result: Pre-verified result from client (105 bytes)
-    // *   Format: [1 byte: is_valid] [32 bytes: proof_hash] [8 bytes: timestamp] [64 bytes: signature]
+    // * result: Verifier-signed result, field by field (see state::verification_result)
     // * nullifier: Domain-scoped nullifier for replay protection
     // * domain: Application domain identifier (max 32 bytes to minimize memory)
+    // *         `sandbox` is reserved for devnet builds with relaxed checks (see sandbox.rs)
+    // * expires_in: Session lifetime in seconds, within the domain's bounds (see
+    // *             set_domain_session_bounds); 0 = the domain's default
+    // *
+    // * Returns the session PDA, created_at and expires_at via return data
     pub fn verify_auth(
         ctx: Context<VerifyAuth>,
-        verification_result: Vec<u8>,
+        result: SignedVerificationResult,
         nullifier: [u8; 32],
         domain: [u8; 32], // * Fixed-size array to avoid Vec/String allocation
-    ) -> Result<()> {
-        // * Find actual domain length (null-terminated or full array)
-        let domain_len = domain.iter().position(|&b| b == 0).unwrap_or(32);
-        require!(
-            domain_len > 0 && domain_len <= 32,
-            VeiledError::DomainTooLong
-        );
+        expires_in: i64,
+    ) -> Result<SessionDetails> {
+        let result = VerificationResult::try_from(result)?;
+        let proof_hash = result.proof_hash;
+        let session = ctx
+            .accounts
+            .verification(&ctx.bumps)
+            .verify_and_register(result, nullifier, domain, None, None, expires_in)?;
+
+        emit_cpi!(AuthVerifiedEvent {
+            nullifier,
+            domain,
+            proof_hash,
+            expires_at: session.expires_at,
+        });
+        Ok(session)
+    }
 
-        // * Convert domain to String only when storing (use stack-allocated slice)
-        let domain_slice = &domain[..domain_len];
-        let domain_str = core::str::from_utf8(domain_slice)
-            .map_err(|_| VeiledError::DomainTooLong)?
-            .to_string(); // * Only allocate String when storing
+    // * verify_auth taking the result as a byte envelope, for clients built before the typed
+    // * argument
+    // *
+    // * verification_result: Pre-verified result from client
+    // *   V9 (303 bytes): [1 byte: version = 9] V8 layout; the signed message is prefixed with
+    // *                   "veiled:v1" (see ultrahonk::MESSAGE_TAG)
+    // *   V8 (303 bytes): [1 byte: version = 8] V7 layout with [1 byte: claim_type] after instance_id
+    // *                   (only Auth claims open sessions, see ClaimType)
+    // *   V7 (302 bytes): [1 byte: version = 7] V6 layout with [32 bytes: instance_id] after cluster_id
+    // *                   (must be this program ID, see genesis.rs)
+    // *   V6 (270 bytes): [1 byte: version = 6] V5 layout with [32 bytes: cluster_id] after vk_hash
+    // *                   (must be this build's cluster, see cluster.rs)
+    // *   V5 (238 bytes): [1 byte: version = 5] V4 layout with [32 bytes: vk_hash] after circuit_id
+    // *   V4 (206 bytes): [1 byte: version = 4] V3 layout with [4 bytes: circuit_id] after public_inputs_hash
+    // *   V3 (202 bytes): [1 byte: version = 3] V1 body with [32 bytes: public_inputs_hash] before the signature
+    // *   V2 (170 bytes): [1 byte: version = 2] followed by the V1 body
+    // *   V1 (169 bytes): [1 byte: is_valid] [32 bytes: proof_hash] [8 bytes: timestamp] [32 bytes: nullifier]
+    // *                   [32 bytes: domain] [64 bytes: signature]
+    // *   Only V9 is accepted: an untagged signature could have been made for another protocol,
+    // *   so V1-V8 envelopes are rejected with UntaggedResult (they're listed for the layout V9
+    // *   builds on, and can still be submitted through verify_auth's typed argument). Unknown
+    // *   versions are rejected too (see ultrahonk::VerificationResult::from_instruction_data),
+    // *   as are lengths other than the version's and is_valid bytes other than 0 or 1
+    pub fn verify_auth_v1(
+        ctx: Context<VerifyAuthV1>,
+        verification_result: Vec<u8>,
+        nullifier: [u8; 32],
+        domain: [u8; 32],
+    ) -> Result<SessionDetails> {
+        // * Encoding errors surface as themselves (wrong length, non-canonical bool, version)
+        let result = VerificationResult::from_instruction_data(&verification_result)?;
+        let proof_hash = result.proof_hash;
+        let session = ctx
+            .accounts
+            .verification(&ctx.bumps)
+            .verify_and_register(result, nullifier, domain, None, None, 0)?;
 
-        // * Parse verification result
-        let result = VerificationResult::from_instruction_data(&verification_result)
-            .map_err(|_| VeiledError::InvalidProof)?;
+        emit_cpi!(AuthVerifiedEvent {
+            nullifier,
+            domain,
+            proof_hash,
+            expires_at: session.expires_at,
+        });
+        Ok(session)
+    }
 
-        // * Validate signature via Ed25519Program instruction present in tx
-        result.validate_signature(
-            ctx.accounts.authority.key,
-            &ctx.accounts.instructions_sysvar,
+    /// * verify_auth naming the origin (SHA256 of the web origin or mobile bundle id) that
+    /// * started auth; domains that register origins with set_domain_origins only accept
+    /// * verifications through here, with one of them
+    pub fn verify_auth_with_origin(
+        ctx: Context<VerifyAuth>,
+        result: SignedVerificationResult,
+        nullifier: [u8; 32],
+        domain: [u8; 32],
+        origin_hash: Option<[u8; 32]>,
+    ) -> Result<SessionDetails> {
+        let result = VerificationResult::try_from(result)?;
+        let proof_hash = result.proof_hash;
+        let session = ctx.accounts.verification(&ctx.bumps).verify_and_register(
+            result,
+            nullifier,
+            domain,
+            origin_hash,
+            None,
+            0,
         )?;
 
-        // * Check if verification result is recent (not stale)
-        let current_timestamp = Clock::get()?.unix_timestamp;
-        result.is_recent(current_timestamp)?;
+        emit_cpi!(AuthVerifiedEvent {
+            nullifier,
+            domain,
+            proof_hash,
+            expires_at: session.expires_at,
+        });
+        Ok(session)
+    }
 
-        // * Only accept valid proofs
-        require!(result.is_valid, VeiledError::InvalidProof);
+    /// * verify_auth binding the session to a device key (e.g. a key held in the device's secure
+    /// * enclave); instructions that call NullifierAccount::prove_device then need a signature
+    /// * from it too, and renew_session keeps it
+    pub fn verify_auth_with_device(
+        ctx: Context<VerifyAuth>,
+        result: SignedVerificationResult,
+        nullifier: [u8; 32],
+        domain: [u8; 32],
+        device_key: Pubkey,
+        expires_in: i64,
+    ) -> Result<SessionDetails> {
+        let result = VerificationResult::try_from(result)?;
+        let proof_hash = result.proof_hash;
+        let session = ctx
+            .accounts
+            .verification(&ctx.bumps)
+            .verify_and_register(result, nullifier, domain, None, None, expires_in)?;
+        ctx.accounts.nullifier_account.device_key = device_key;
 
-        msg!("✓ Proof verified off-chain and validated on-chain");
-        msg!("  Proof hash: {:?}", result.proof_hash);
-        msg!("  Verified at: {}", result.timestamp);
+        emit_cpi!(AuthVerifiedEvent {
+            nullifier,
+            domain,
+            proof_hash,
+            expires_at: session.expires_at,
+        });
+        Ok(session)
+    }
 
-        // * Check if nullifier has already been used
-        // * With init_if_needed, account might already exist
-        // * Check nullifier value first (more specific check)
-        let nullifier_account = &mut ctx.accounts.nullifier_account;
+    /// * verify_auth submitted by a relayer on a user's behalf: the user's wallet signs
+    /// * UserConsent::message (nullifier, domain, expiry) in an Ed25519Program instruction and
+    /// * the session's authority is the user, while the relayer pays fees and rent
+    pub fn verify_auth_with_consent(
+        ctx: Context<VerifyAuth>,
+        result: SignedVerificationResult,
+        nullifier: [u8; 32],
+        domain: [u8; 32],
+        consent: UserConsent,
+    ) -> Result<SessionDetails> {
+        let result = VerificationResult::try_from(result)?;
+        let proof_hash = result.proof_hash;
+        let session = ctx.accounts.verification(&ctx.bumps).verify_and_register(
+            result,
+            nullifier,
+            domain,
+            None,
+            Some(consent),
+            0,
+        )?;
 
-        // * Check if this exact nullifier was already used (replay protection)
-        // * This is the primary check - if nullifier matches and account is initialized, reject
-        if nullifier_account.nullifier != [0u8; 32] && nullifier_account.nullifier == nullifier {
-            return Err(VeiledError::DuplicateNullifier.into());
-        }
+        emit_cpi!(AuthVerifiedEvent {
+            nullifier,
+            domain,
+            proof_hash,
+            expires_at: session.expires_at,
+        });
+        Ok(session)
+    }
 
-        // * Additional check: if account was already initialized with a different nullifier
-        // * This handles edge cases where account exists but nullifier doesn't match
-        // * (Shouldn't happen with proper PDA seeds, but safety check)
-        if nullifier_account.created_at != 0 && nullifier_account.nullifier != nullifier {
-            // * Account exists but with different nullifier - this is an error state
-            // * For now, we'll allow it (could be from a previous test)
-            // * In production, this shouldn't happen with proper PDA seeds
-        }
+    /// * verify_auth for up to MAX_BATCH_ENTRIES results in one transaction (e.g. several domains
+    /// * at onboarding); remaining_accounts carry each entry's nullifier, proof record, domain
+    /// * config, nullifier ban, result receipt and logout epoch PDAs
+    pub fn verify_auth_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, VerifyAuthBatch<'info>>,
+        entries: Vec<VerifyAuthEntry>,
+    ) -> Result<()> {
+        handle_verify_auth_batch(ctx, entries)
+    }
+
+    /// * Store a verifier-signed non-login claim (V8 claim_type other than Auth) as an
+    /// * Attestation PDA, lasting expires_in seconds (0 = Attestation::MAX_LIFETIME_SECS)
+    pub fn store_attestation(
+        ctx: Context<StoreAttestation>,
+        result: SignedVerificationResult,
+        nullifier: [u8; 32],
+        domain: [u8; 32],
+        expires_in: i64,
+    ) -> Result<()> {
+        handle_store_attestation(ctx, result, nullifier, domain, expires_in)
+    }
+
+    /// * Extend an attestation from a fresh signed result for the same claim, lasting
+    /// * expires_in seconds from now (0 = Attestation::MAX_LIFETIME_SECS); never shortens it
+    pub fn renew_attestation(
+        ctx: Context<RenewAttestation>,
+        result: SignedVerificationResult,
+        expires_in: i64,
+    ) -> Result<()> {
+        handle_renew_attestation(ctx, result, expires_in)
+    }
 
-        msg!("Nullifier: {:?}", nullifier);
-        msg!("Domain: {}", domain_str);
+    /// * Revoke an attestation (issuer only); revocation is final
+    pub fn revoke_attestation(ctx: Context<RevokeAttestation>) -> Result<()> {
+        handle_revoke_attestation(ctx)
+    }
 
-        // * Store nullifier in PDA account
-        let current_timestamp = Clock::get()?.unix_timestamp;
-        nullifier_account.nullifier = nullifier;
-        nullifier_account.domain = domain_str;
-        nullifier_account.created_at = current_timestamp;
+    /// * Check that an attestation's claim hasn't expired or been revoked
+    pub fn validate_attestation(ctx: Context<ValidateAttestation>) -> Result<()> {
+        handle_validate_attestation(ctx)
+    }
 
-        // * Set expiry timestamp (default: 30 days from now)
-        // * Expiry can be customized per domain/application if needed
-        const DEFAULT_EXPIRY_SECONDS: i64 = 30 * 24 * 60 * 60; // * 30 days
-        nullifier_account.expires_at = current_timestamp + DEFAULT_EXPIRY_SECONDS;
+    /// * Register (or update) an external attestor's key, name hash and the claim types it may
+    /// * issue, as a ClaimType::bit mask (admin only)
+    pub fn register_attestor(
+        ctx: Context<RegisterAttestor>,
+        issuer: Pubkey,
+        name_hash: [u8; 32],
+        claim_mask: u8,
+    ) -> Result<()> {
+        handle_register_attestor(ctx, issuer, name_hash, claim_mask)
+    }
 
-        Ok(())
+    /// * Suspend or reinstate a registered attestor (admin only)
+    pub fn set_attestor_status(
+        ctx: Context<SetAttestorStatus>,
+        status: AttestorStatus,
+    ) -> Result<()> {
+        handle_set_attestor_status(ctx, status)
+    }
+
+    /// * Point mirror_attestation at a Solana Attestation Service credential and schema
+    /// * (admin only)
+    pub fn set_sas_bridge(
+        ctx: Context<SetSasBridge>,
+        credential: Pubkey,
+        schema: Pubkey,
+    ) -> Result<()> {
+        handle_set_sas_bridge(ctx, credential, schema)
+    }
+
+    /// * Copy an active attestation into the Solana Attestation Service (anyone may submit)
+    pub fn mirror_attestation(ctx: Context<MirrorAttestation>) -> Result<()> {
+        handle_mirror_attestation(ctx)
+    }
+
+    /// * verify_auth for domains using the bitmap nullifier store: sets the nullifier's bit in
+    /// * its NullifierShard instead of registering a session
+    pub fn verify_auth_sharded(
+        ctx: Context<VerifyAuthSharded>,
+        result: SignedVerificationResult,
+        nullifier: [u8; 32],
+        domain: [u8; 32],
+    ) -> Result<()> {
+        handle_verify_auth_sharded(ctx, result, nullifier, domain)
+    }
+
+    /// * verify_auth for domains using the compressed nullifier store: inserts the nullifier
+    /// * into the domain's NullifierTree (remaining accounts: the low leaf's proof nodes)
+    pub fn verify_auth_compressed<'info>(
+        ctx: Context<'_, '_, '_, 'info, VerifyAuthCompressed<'info>>,
+        result: SignedVerificationResult,
+        nullifier: [u8; 32],
+        domain: [u8; 32],
+        proof: state::nullifier_tree::NonInclusionProof,
+    ) -> Result<()> {
+        handle_verify_auth_compressed(ctx, result, nullifier, domain, proof)
+    }
+
+    /// * Native alternative to verify_auth: check a Groth16 proof on-chain (alt_bn128 syscalls)
+    /// * against the circuit's registered verifying key; no verifier signature needed
+    /// * public_inputs must start with the nullifier and groth16::domain_input(domain)
+    pub fn verify_auth_groth16(
+        ctx: Context<VerifyAuthGroth16>,
+        circuit_id: u32,
+        proof: [u8; 256],
+        public_inputs: Vec<[u8; 32]>,
+        nullifier: [u8; 32],
+        domain: [u8; 32],
+    ) -> Result<()> {
+        handle_verify_auth_groth16(ctx, circuit_id, proof, public_inputs, nullifier, domain)
+    }
+
+    // * Chunked on-chain UltraHonk verification (see honk.rs)
+
+    /// * Start verifying an UltraHonk proof on-chain; commits to the upload's SHA256
+    /// * The upload is the public inputs (nullifier, domain input, ...) followed by the proof
+    pub fn open_verification_session(
+        ctx: Context<OpenVerificationSession>,
+        circuit_id: u32,
+        nullifier: [u8; 32],
+        domain: [u8; 32],
+        proof_hash: [u8; 32],
+        public_inputs_count: u16,
+    ) -> Result<()> {
+        handle_open_verification_session(
+            ctx,
+            circuit_id,
+            nullifier,
+            domain,
+            proof_hash,
+            public_inputs_count,
+        )
+    }
+
+    /// * Append the next chunk of a session's upload (in order)
+    pub fn upload_proof_chunk(
+        ctx: Context<UploadProofChunk>,
+        offset: u32,
+        chunk: Vec<u8>,
+    ) -> Result<()> {
+        handle_upload_proof_chunk(ctx, offset, chunk)
+    }
+
+    /// * Run up to max_steps verifier steps on an uploaded session (anyone may crank)
+    pub fn advance_verification_session(
+        ctx: Context<AdvanceVerificationSession>,
+        max_steps: u8,
+    ) -> Result<()> {
+        handle_advance_verification_session(ctx, max_steps)
+    }
+
+    /// * Register the nullifier of a session whose proof verified, closing the session
+    pub fn finalize_verification_session(ctx: Context<FinalizeVerificationSession>) -> Result<()> {
+        handle_finalize_verification_session(ctx)
+    }
+
+    /// * Abandon a verification session and reclaim its rent
+    pub fn close_verification_session(ctx: Context<CloseVerificationSession>) -> Result<()> {
+        handle_close_verification_session(ctx)
     }
 
     // * Permission system instructions
 
     /// * Grant permissions to an app
     /// * Creates a PermissionGrant account that stores what permissions were granted
+    /// * domain: Domain the grant is made in; its config's permission cap applies
     pub fn grant_permissions(
         ctx: Context<GrantPermissions>,
         nullifier: [u8; 32],
+        domain: [u8; 32],
         app_id: Pubkey,
         permissions: Vec<state::permission::Permission>,
-        expires_in: i64,
+        expires_in: ExpiryKind,
     ) -> Result<()> {
-        handle_grant_permissions(ctx, nullifier, app_id, permissions, expires_in)
+        handle_grant_permissions(ctx, nullifier, domain, app_id, permissions, expires_in)
     }
 
     /// * Revoke previously granted permissions
@@ -167,19 +654,969 @@ pub mod veiled {
 
     /// * Log when a permission is actually accessed
     /// * Creates an audit trail entry in PermissionAccess account
+    /// * domain: Domain the access happens in; refused while the grant's nullifier is banned there
     pub fn log_permission_access(
         ctx: Context<LogPermissionAccess>,
         permission_used: state::permission::Permission,
         metadata: String,
+        domain: [u8; 32],
+    ) -> Result<()> {
+        handle_log_permission_access(ctx, permission_used, metadata, domain)
+    }
+
+    /// * Check a permission without failing the transaction
+    /// * Returns true/false via return data and emits PermissionDeniedEvent on denial
+    pub fn try_check_permission(
+        ctx: Context<TryCheckPermission>,
+        permission: state::permission::Permission,
+    ) -> Result<bool> {
+        handle_try_check_permission(ctx, permission)
+    }
+
+    /// * Fold old PermissionAccess entries into the grant's AccessLogSummary and close them
+    /// * Permissionless; remaining_accounts are (permission_access, payer) pairs
+    pub fn compact_access_logs(ctx: Context<CompactAccessLogs>) -> Result<()> {
+        handle_compact_access_logs(ctx)
+    }
+
+    /// * Store the encrypted push channel an app may use to message this nullifier
+    /// * Requires a live ReceiveNotifications grant to the app
+    pub fn set_notification_channel(
+        ctx: Context<SetNotificationChannel>,
+        channel_commitment: [u8; 32],
+        encrypted_token: Vec<u8>,
+    ) -> Result<()> {
+        handle_set_notification_channel(ctx, channel_commitment, encrypted_token)
+    }
+
+    /// * Close a notification channel (its authority anytime, anyone once the grant lapses)
+    pub fn close_notification_channel(ctx: Context<CloseNotificationChannel>) -> Result<()> {
+        handle_close_notification_channel(ctx)
+    }
+
+    /// * Escrow an encrypted wallet address for an app, released only after delay_secs
+    /// * Alternative to revealing directly under a RevealWalletAddress grant
+    pub fn open_reveal_escrow(
+        ctx: Context<OpenRevealEscrow>,
+        ciphertext: Vec<u8>,
+        key_commitment: [u8; 32],
+        delay_secs: i64,
+    ) -> Result<()> {
+        handle_open_reveal_escrow(ctx, ciphertext, key_commitment, delay_secs)
+    }
+
+    /// * Publish a reveal escrow's key once its delay has passed (permissionless)
+    pub fn release_reveal_escrow(
+        ctx: Context<ReleaseRevealEscrow>,
+        reveal_key: [u8; 32],
+    ) -> Result<()> {
+        handle_release_reveal_escrow(ctx, reveal_key)
+    }
+
+    /// * Cancel a pending reveal escrow before its delay runs out, or close a released one
+    pub fn cancel_reveal_escrow(ctx: Context<CancelRevealEscrow>) -> Result<()> {
+        handle_cancel_reveal_escrow(ctx)
+    }
+
+    // * Auditor instructions
+
+    /// * Authorize a named auditor key to acknowledge a user's access logs
+    /// * Auditors receive no app-level data permissions
+    pub fn grant_auditor(
+        ctx: Context<GrantAuditor>,
+        nullifier: [u8; 32],
+        auditor: Pubkey,
+        name_hash: [u8; 32],
+        expires_in: ExpiryKind,
+    ) -> Result<()> {
+        handle_grant_auditor(ctx, nullifier, auditor, name_hash, expires_in)
+    }
+
+    /// * Revoke a previously granted auditor
+    pub fn revoke_auditor(ctx: Context<RevokeAuditor>) -> Result<()> {
+        handle_revoke_auditor(ctx)
+    }
+
+    /// * Record an auditor's acknowledgement of an access log entry
+    pub fn acknowledge_access_log(ctx: Context<AcknowledgeAccessLog>) -> Result<()> {
+        handle_acknowledge_access_log(ctx)
+    }
+
+    // * Verifiable credential status registry
+
+    /// * Anchor the hash of an externally issued W3C Verifiable Credential to a nullifier
+    /// * The signer becomes the issuer of record; the commitment starts Active
+    pub fn issue_vc_commitment(
+        ctx: Context<IssueVcCommitment>,
+        nullifier: [u8; 32],
+        vc_hash: [u8; 32],
+    ) -> Result<()> {
+        handle_issue_vc_commitment(ctx, nullifier, vc_hash)
+    }
+
+    /// * Suspend, reactivate or revoke a credential (issuer only; revocation is final)
+    pub fn set_vc_status(
+        ctx: Context<SetVcStatus>,
+        status: state::credential::VcStatus,
+    ) -> Result<()> {
+        handle_set_vc_status(ctx, status)
+    }
+
+    // * Session instructions
+
+    /// * Revoke every session created by the signing wallet, across all domains
+    /// * Records a LogoutEpoch that session checks compare created_at against
+    pub fn global_logout(ctx: Context<GlobalLogout>) -> Result<()> {
+        handle_global_logout(ctx)
+    }
+
+    /// * Check that a session is unexpired, not revoked by a global logout, and its nullifier
+    /// * not banned from the domain
+    pub fn validate_session_token(ctx: Context<ValidateSessionToken>) -> Result<()> {
+        handle_validate_session_token(ctx)
+    }
+
+    /// * Record activity on a session, restarting its domain's idle timeout (session authority)
+    pub fn touch_session(ctx: Context<TouchSession>) -> Result<()> {
+        handle_touch_session(ctx)
+    }
+
+    /// * Mint a short-lived child key acting for a session within `scope` (session authority)
+    pub fn delegate_session(
+        ctx: Context<DelegateSession>,
+        delegate: Pubkey,
+        scope: u64,
+        expires_in: i64,
+    ) -> Result<()> {
+        handle_delegate_session(ctx, delegate, scope, expires_in)
+    }
+
+    /// * Revoke a session delegate and reclaim its rent (session authority)
+    pub fn revoke_session_delegate(ctx: Context<RevokeSessionDelegate>) -> Result<()> {
+        handle_revoke_session_delegate(ctx)
+    }
+
+    /// * Check that a delegate may use `permission` for its still-valid parent session
+    pub fn validate_session_delegate(
+        ctx: Context<ValidateSessionDelegate>,
+        permission: state::permission::Permission,
+    ) -> Result<()> {
+        handle_validate_session_delegate(ctx, permission)
+    }
+
+    /// * Claim a handle (by its SHA256) for an active session's nullifier (session authority)
+    pub fn claim_alias(ctx: Context<ClaimAlias>, handle_hash: [u8; 32]) -> Result<()> {
+        handle_claim_alias(ctx, handle_hash)
+    }
+
+    /// * Claim a username, unique whatever its case, for an active session's nullifier
+    /// * (session authority)
+    pub fn claim_username(ctx: Context<ClaimUsername>, name: String) -> Result<()> {
+        handle_claim_username(ctx, name)
+    }
+
+    /// * Give a username up and reclaim its rent (username authority)
+    pub fn release_username(ctx: Context<ReleaseUsername>) -> Result<()> {
+        handle_release_username(ctx)
+    }
+
+    /// * Move a username to another nullifier's active session, with the holder's consent
+    pub fn transfer_username(ctx: Context<TransferUsername>) -> Result<()> {
+        handle_transfer_username(ctx)
+    }
+
+    /// * Name the guardians (m of n) that can recover an active session's nullifier
+    /// * (session authority)
+    pub fn set_guardians(
+        ctx: Context<SetGuardians>,
+        guardians: Vec<Pubkey>,
+        threshold: u8,
     ) -> Result<()> {
-        handle_log_permission_access(ctx, permission_used, metadata)
+        handle_set_guardians(ctx, guardians, threshold)
+    }
+
+    /// * Move a nullifier's sessions in `domain` and its grants to a new nullifier with its
+    /// * guardians' signatures and a fresh signed result for the new nullifier in `domain`
+    pub fn recover_identity<'info>(
+        ctx: Context<'_, '_, '_, 'info, RecoverIdentity<'info>>,
+        result: SignedVerificationResult,
+        nullifier: [u8; 32],
+        new_nullifier: [u8; 32],
+        domain: [u8; 32],
+    ) -> Result<()> {
+        handle_recover_identity(ctx, result, nullifier, new_nullifier, domain)
+    }
+
+    /// * Create an empty identity root owned by the signer
+    pub fn create_identity_root(ctx: Context<CreateIdentityRoot>) -> Result<()> {
+        handle_create_identity_root(ctx)
+    }
+
+    /// * Link an active session's nullifier to an identity root (session authority, with the
+    /// * root owner's signature or signed IdentityLink)
+    pub fn link_identity_root(
+        ctx: Context<LinkIdentityRoot>,
+        linkage_proof_hash: [u8; 32],
+    ) -> Result<()> {
+        handle_link_identity_root(ctx, linkage_proof_hash)
+    }
+
+    /// * Drop a nullifier from an identity root (root owner)
+    pub fn unlink_identity_root(
+        ctx: Context<UnlinkIdentityRoot>,
+        nullifier: [u8; 32],
+    ) -> Result<()> {
+        handle_unlink_identity_root(ctx, nullifier)
+    }
+
+    // * Protocol config and treasury instructions
+
+    /// * Create the singleton protocol config; the signer becomes admin
+    pub fn initialize_config(ctx: Context<InitializeConfig>) -> Result<()> {
+        handle_initialize_config(ctx)
+    }
+
+    /// * Add (accepted = true) or remove an SPL mint from the payment allowlist
+    /// * price_feed_id is the Pyth feed quoting the mint in USD
+    pub fn set_accepted_mint(
+        ctx: Context<SetAcceptedMint>,
+        mint: Pubkey,
+        price_feed_id: [u8; 32],
+        accepted: bool,
+    ) -> Result<()> {
+        handle_set_accepted_mint(ctx, mint, price_feed_id, accepted)
+    }
+
+    /// * Set the USD fee (micro-USD) and oracle staleness/confidence bounds (admin only)
+    pub fn set_pricing(
+        ctx: Context<SetPricing>,
+        fee_usd_micros: u64,
+        max_price_age_secs: u64,
+        max_price_conf_bps: u16,
+    ) -> Result<()> {
+        handle_set_pricing(ctx, fee_usd_micros, max_price_age_secs, max_price_conf_bps)
+    }
+
+    /// * Register a circuit ID with its verification-key hash (admin only)
+    pub fn register_circuit(
+        ctx: Context<RegisterCircuit>,
+        circuit_id: u32,
+        vk_hash: [u8; 32],
+    ) -> Result<()> {
+        handle_register_circuit(ctx, circuit_id, vk_hash)
+    }
+
+    /// * Stop accepting verification results for a circuit (admin only; IDs are never reused)
+    pub fn deprecate_circuit(ctx: Context<DeprecateCircuit>, circuit_id: u32) -> Result<()> {
+        handle_deprecate_circuit(ctx, circuit_id)
+    }
+
+    /// * Store a registered circuit's Groth16 verifying key for verify_auth_groth16 (admin only)
+    /// * The key must hash to the circuit's vk_hash and can't be replaced afterwards
+    pub fn register_groth16_vk(
+        ctx: Context<RegisterGroth16Vk>,
+        circuit_id: u32,
+        alpha_g1: [u8; 64],
+        beta_g2: [u8; 128],
+        gamma_g2: [u8; 128],
+        delta_g2: [u8; 128],
+        ic: Vec<[u8; 64]>,
+    ) -> Result<()> {
+        handle_register_groth16_vk(ctx, circuit_id, alpha_g1, beta_g2, gamma_g2, delta_g2, ic)
+    }
+
+    /// * Create a registered circuit's UltraHonk verifying key for verification sessions
+    /// * (admin only); write_honk_vk then stores its commitments
+    pub fn register_honk_vk(
+        ctx: Context<RegisterHonkVk>,
+        circuit_id: u32,
+        log_circuit_size: u8,
+        public_inputs_size: u32,
+        pub_inputs_offset: u32,
+    ) -> Result<()> {
+        handle_register_honk_vk(
+            ctx,
+            circuit_id,
+            log_circuit_size,
+            public_inputs_size,
+            pub_inputs_offset,
+        )
+    }
+
+    /// * Store commitments [offset, offset + len) of an UltraHonk key (admin only)
+    /// * The write completing the key must make it hash to the circuit's vk_hash
+    pub fn write_honk_vk(
+        ctx: Context<WriteHonkVk>,
+        circuit_id: u32,
+        offset: u8,
+        commitments: Vec<[u8; 64]>,
+    ) -> Result<()> {
+        handle_write_honk_vk(ctx, circuit_id, offset, commitments)
+    }
+
+    /// * Allow a verifier key to sign results accepted by verify_auth (admin only)
+    pub fn add_verifier(ctx: Context<AddVerifier>, verifier: Pubkey) -> Result<()> {
+        handle_add_verifier(ctx, verifier)
+    }
+
+    /// * Revoke a verifier key; its results are rejected from then on (admin only)
+    pub fn remove_verifier(ctx: Context<RemoveVerifier>, verifier: Pubkey) -> Result<()> {
+        handle_remove_verifier(ctx, verifier)
+    }
+
+    /// * Allow a passkey (compressed secp256r1 key) to co-sign results via the Secp256r1
+    /// * precompile (admin only)
+    pub fn add_secp256r1_verifier(
+        ctx: Context<AddSecp256r1Verifier>,
+        verifier: [u8; 33],
+    ) -> Result<()> {
+        handle_add_secp256r1_verifier(ctx, verifier)
+    }
+
+    /// * Revoke a passkey verifier key (admin only)
+    pub fn remove_secp256r1_verifier(
+        ctx: Context<RemoveSecp256r1Verifier>,
+        verifier: [u8; 33],
+    ) -> Result<()> {
+        handle_remove_secp256r1_verifier(ctx, verifier)
+    }
+
+    /// * Set how many registered verifiers must co-sign a result (admin only)
+    pub fn set_verifier_threshold(
+        ctx: Context<SetVerifierThreshold>,
+        threshold: u8,
+    ) -> Result<()> {
+        handle_set_verifier_threshold(ctx, threshold)
+    }
+
+    /// * Replace a verifier key in place; the old key stays valid for the grace period (admin only)
+    pub fn rotate_verifier_key(
+        ctx: Context<RotateVerifierKey>,
+        old_key: Pubkey,
+        new_key: Pubkey,
+    ) -> Result<()> {
+        handle_rotate_verifier_key(ctx, old_key, new_key)
+    }
+
+    /// * Set how long rotated-out verifier keys stay accepted, 0 to 24 hours (admin only)
+    pub fn set_verifier_grace_period(
+        ctx: Context<SetVerifierGracePeriod>,
+        grace_period_secs: i64,
+    ) -> Result<()> {
+        handle_set_verifier_grace_period(ctx, grace_period_secs)
+    }
+
+    /// * Set how long consumed results' receipts are kept, 1 hour to 30 days (admin only)
+    pub fn set_receipt_retention(
+        ctx: Context<SetReceiptRetention>,
+        receipt_retention_secs: i64,
+    ) -> Result<()> {
+        handle_set_receipt_retention(ctx, receipt_retention_secs)
+    }
+
+    /// * Close a result receipt whose retention window has passed, refunding its payer
+    /// * (permissionless)
+    pub fn close_result_receipt(ctx: Context<CloseResultReceipt>) -> Result<()> {
+        handle_close_result_receipt(ctx)
+    }
+
+    /// * Close an expired session's nullifier account, splitting its rent between the caller
+    /// * and the payer (permissionless)
+    pub fn close_expired_nullifier(ctx: Context<CloseExpiredNullifier>) -> Result<()> {
+        handle_close_expired_nullifier(ctx)
+    }
+
+    /// * Close a session before it expires, refunding its payer (session authority, directly
+    /// * or through a signed SessionCloseRequest)
+    pub fn close_session(ctx: Context<CloseSession>) -> Result<()> {
+        handle_close_session(ctx)
+    }
+
+    /// * Extend a session from a fresh signed result for its nullifier and domain, lasting
+    /// * expires_in seconds from now (0 = the domain's default); never shortens it
+    /// * Returns the session PDA, created_at and expires_at via return data
+    pub fn renew_session(
+        ctx: Context<RenewSession>,
+        result: SignedVerificationResult,
+        domain: [u8; 32],
+        expires_in: i64,
+    ) -> Result<SessionDetails> {
+        handle_renew_session(ctx, result, domain, expires_in)
+    }
+
+    /// * Retire a session's nullifier for a new one proven by a fresh signed result in the same
+    /// * domain, linking old to new and closing the old session (session authority)
+    /// * Returns the new session PDA, created_at and expires_at via return data
+    pub fn migrate_nullifier(
+        ctx: Context<MigrateNullifier>,
+        result: SignedVerificationResult,
+        new_nullifier: [u8; 32],
+        expires_in: i64,
+    ) -> Result<SessionDetails> {
+        handle_migrate_nullifier(ctx, result, new_nullifier, expires_in)
+    }
+
+    /// * Set a registered verifier's selection weight, 1 to 16; 0 stops selecting it (admin only)
+    pub fn set_verifier_weight(
+        ctx: Context<SetVerifierWeight>,
+        verifier: Pubkey,
+        weight: u8,
+    ) -> Result<()> {
+        handle_set_verifier_weight(ctx, verifier, weight)
+    }
+
+    /// * Mark the signing verifier live for selection (verifier key only)
+    pub fn verifier_heartbeat(ctx: Context<VerifierHeartbeat>) -> Result<()> {
+        handle_verifier_heartbeat(ctx)
+    }
+
+    /// * The verifier a client should use for a nullifier, via return data
+    /// * Stake-weighted rendezvous over registered, live verifiers; no transaction needed
+    /// * (simulate it, or compute the same pick with the SDK's selectVerifier)
+    pub fn select_verifier(ctx: Context<SelectVerifier>, nullifier: [u8; 32]) -> Result<Pubkey> {
+        handle_select_verifier(ctx, nullifier)
+    }
+
+    /// * Set how old (seconds, up to an hour) a result may be on an assurance tier (admin only)
+    /// * Tiers are added in order; domains without a tier use tier 0 or the 5-minute default
+    pub fn set_assurance_tier(
+        ctx: Context<SetAssuranceTier>,
+        tier: u8,
+        max_result_age_secs: u32,
+    ) -> Result<()> {
+        handle_set_assurance_tier(ctx, tier, max_result_age_secs)
+    }
+
+    /// * Give a relayer its own verifications-per-minute quota, above domain limits (admin only)
+    /// * Zero puts it back under the domain / default limit
+    pub fn set_relayer_quota(
+        ctx: Context<SetRelayerQuota>,
+        relayer: Pubkey,
+        quota: u32,
+    ) -> Result<()> {
+        handle_set_relayer_quota(ctx, relayer, quota)
+    }
+
+    /// * Open the treasury's associated token account for an accepted mint
+    pub fn create_treasury_ata(ctx: Context<CreateTreasuryAta>) -> Result<()> {
+        handle_create_treasury_ata(ctx)
+    }
+
+    /// * Transfer collected tokens out of a treasury ATA (admin only)
+    pub fn sweep_treasury_ata(ctx: Context<SweepTreasuryAta>, amount: u64) -> Result<()> {
+        handle_sweep_treasury_ata(ctx, amount)
+    }
+
+    /// * Pay the USD-quoted fee in an accepted mint at the current oracle price
+    /// * Fails if the quoted amount exceeds max_amount
+    pub fn pay_fee(ctx: Context<PayFee>, max_amount: u64) -> Result<()> {
+        handle_pay_fee(ctx, max_amount)
+    }
+
+    /// * Set the share (basis points, at most 50%) of domain fees paid to domain fee vaults
+    /// * (admin only)
+    pub fn set_domain_fee_share(
+        ctx: Context<SetDomainFeeShare>,
+        domain_share_bps: u16,
+    ) -> Result<()> {
+        handle_set_domain_fee_share(ctx, domain_share_bps)
+    }
+
+    /// * Open a domain fee vault's associated token account for an accepted mint
+    pub fn create_domain_fee_vault_ata(ctx: Context<CreateDomainFeeVaultAta>) -> Result<()> {
+        handle_create_domain_fee_vault_ata(ctx)
+    }
+
+    /// * Pay the USD-quoted fee for activity on a domain; the domain's share goes to its fee
+    /// * vault, the rest to the treasury. Fails if the quoted amount exceeds max_amount
+    pub fn pay_domain_fee(ctx: Context<PayDomainFee>, max_amount: u64) -> Result<()> {
+        handle_pay_domain_fee(ctx, max_amount)
+    }
+
+    // * Domain config instructions
+
+    /// * Create a domain's config; the signer becomes the domain owner
+    pub fn initialize_domain_config(
+        ctx: Context<InitializeDomainConfig>,
+        domain: [u8; 32],
+    ) -> Result<()> {
+        handle_initialize_domain_config(ctx, domain)
+    }
+
+    /// * Register a domain with its verifier, default session lifetime (seconds), staleness
+    /// * window and permission cap; the signer becomes the domain owner (0 = protocol default)
+    pub fn register_domain(
+        ctx: Context<RegisterDomain>,
+        domain: [u8; 32],
+        verifier: Option<Pubkey>,
+        default_session_secs: i64,
+        max_result_age_secs: u32,
+        max_permissions: u8,
+    ) -> Result<()> {
+        handle_register_domain(
+            ctx,
+            domain,
+            verifier,
+            default_session_secs,
+            max_result_age_secs,
+            max_permissions,
+        )
+    }
+
+    /// * Pin the verification key hash verify_auth requires for the domain (zero unpins)
+    pub fn pin_vk_hash(ctx: Context<PinVkHash>, vk_hash: [u8; 32]) -> Result<()> {
+        handle_pin_vk_hash(ctx, vk_hash)
+    }
+
+    /// * Designate the verifier keys allowed to sign the domain's results (empty clears)
+    pub fn set_domain_verifiers(
+        ctx: Context<SetDomainVerifiers>,
+        verifiers: Vec<Pubkey>,
+    ) -> Result<()> {
+        handle_set_domain_verifiers(ctx, verifiers)
+    }
+
+    /// * Register the origin hashes allowed to start auth for the domain, up to 8 (empty clears)
+    /// * Owner only
+    pub fn set_domain_origins(
+        ctx: Context<SetDomainOrigins>,
+        origins: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_set_domain_origins(ctx, origins)
+    }
+
+    /// * Hold the domain's new sessions pending for a challenge window, up to 7 days (0 = off)
+    pub fn set_challenge_window(
+        ctx: Context<SetChallengeWindow>,
+        challenge_window_secs: i64,
+    ) -> Result<()> {
+        handle_set_challenge_window(ctx, challenge_window_secs)
+    }
+
+    /// * Cap verifications per fee payer per minute for the domain, up to 600 (0 = default)
+    pub fn set_domain_rate_limit(ctx: Context<SetDomainRateLimit>, rate_limit: u32) -> Result<()> {
+        handle_set_domain_rate_limit(ctx, rate_limit)
+    }
+
+    /// * Put the domain on an AssurancePolicy tier, setting how fresh its results must be
+    pub fn set_domain_assurance_tier(
+        ctx: Context<SetDomainAssuranceTier>,
+        assurance_tier: u8,
+    ) -> Result<()> {
+        handle_set_domain_assurance_tier(ctx, assurance_tier)
+    }
+
+    /// * Assign a domain role: transfer ownership, or set the operator / treasurer key
+    /// * (Pubkey::default() clears a delegated role). Owner only
+    pub fn set_domain_role(
+        ctx: Context<SetDomainRole>,
+        role: DomainRole,
+        key: Pubkey,
+    ) -> Result<()> {
+        handle_set_domain_role(ctx, role, key)
+    }
+
+    /// * Pause or resume new verifications for the domain (owner or operator)
+    pub fn set_domain_paused(ctx: Context<SetDomainPaused>, paused: bool) -> Result<()> {
+        handle_set_domain_paused(ctx, paused)
+    }
+
+    /// * Transfer the domain's collected fee share out of its fee vault ATA
+    /// * (owner or treasurer)
+    pub fn claim_domain_fees(ctx: Context<ClaimDomainFees>, amount: u64) -> Result<()> {
+        handle_claim_domain_fees(ctx, amount)
+    }
+
+    /// * Set the domain's own result staleness window, up to an hour (0 = use its tier's)
+    pub fn set_domain_max_result_age(
+        ctx: Context<SetDomainMaxResultAge>,
+        max_result_age_secs: u32,
+    ) -> Result<()> {
+        handle_set_domain_max_result_age(ctx, max_result_age_secs)
+    }
+
+    /// * Bound the session lifetimes verify_auth callers may ask for (0 = protocol bound)
+    pub fn set_domain_session_bounds(
+        ctx: Context<SetDomainSessionBounds>,
+        min_session_secs: i64,
+        max_session_secs: i64,
+    ) -> Result<()> {
+        handle_set_domain_session_bounds(ctx, min_session_secs, max_session_secs)
+    }
+
+    /// * Choose whether the domain's sessions expire by unix time or slot (owner only)
+    pub fn set_domain_expiry_unit(
+        ctx: Context<SetDomainExpiryUnit>,
+        session_expiry_unit: state::expiry::ExpiryUnit,
+    ) -> Result<()> {
+        handle_set_domain_expiry_unit(ctx, session_expiry_unit)
+    }
+
+    /// * End the domain's sessions once idle this long without a touch_session (0 = off)
+    pub fn set_domain_idle_timeout(
+        ctx: Context<SetDomainIdleTimeout>,
+        idle_timeout_secs: i64,
+    ) -> Result<()> {
+        handle_set_domain_idle_timeout(ctx, idle_timeout_secs)
+    }
+
+    /// * Choose where the domain's verifications register nullifiers (owner only)
+    pub fn set_domain_nullifier_store(
+        ctx: Context<SetDomainNullifierStore>,
+        nullifier_store: state::domain::NullifierStore,
+    ) -> Result<()> {
+        handle_set_domain_nullifier_store(ctx, nullifier_store)
+    }
+
+    /// * Set up the domain's compressed nullifier store on a client-allocated Merkle tree
+    /// * account (owner only)
+    pub fn init_nullifier_tree(
+        ctx: Context<InitNullifierTree>,
+        max_depth: u32,
+        max_buffer_size: u32,
+    ) -> Result<()> {
+        handle_init_nullifier_tree(ctx, max_depth, max_buffer_size)
+    }
+
+    /// * Ban a nullifier from the domain: every verify_auth path, its sessions' renewal and
+    /// * validation, and log_permission_access refuse it there (owner only)
+    pub fn ban_nullifier(ctx: Context<BanNullifier>, nullifier: [u8; 32]) -> Result<()> {
+        handle_ban_nullifier(ctx, nullifier)
+    }
+
+    /// * Lift a nullifier's ban from the domain (owner only)
+    pub fn unban_nullifier(ctx: Context<UnbanNullifier>) -> Result<()> {
+        handle_unban_nullifier(ctx)
+    }
+
+    /// * Claim types (ClaimType::bit mask) the domain only accepts from registered attestors
+    /// * (0 = any verifier)
+    pub fn set_domain_attestor_claims(
+        ctx: Context<SetDomainAttestorClaims>,
+        claim_mask: u8,
+    ) -> Result<()> {
+        handle_set_domain_attestor_claims(ctx, claim_mask)
+    }
+
+    /// * Void a pending session with a verifier-signed result marking its proof invalid
+    /// * Anyone may submit; the session's rent goes to the challenger
+    pub fn challenge_verification(
+        ctx: Context<ChallengeVerification>,
+        evidence: Vec<u8>,
+        domain: [u8; 32],
+    ) -> Result<()> {
+        handle_challenge_verification(ctx, evidence, domain)
+    }
+
+    // * Governance points instructions
+
+    /// * Publish the Merkle root of historical usage points (admin only)
+    /// * Leaves are merkle::hash_leaf(nullifier || points u64 LE), in tree order
+    pub fn publish_points_root(
+        ctx: Context<PublishPointsRoot>,
+        root: [u8; 32],
+        leaf_count: u32,
+    ) -> Result<()> {
+        handle_publish_points_root(ctx, root, leaf_count)
+    }
+
+    /// * Claim a nullifier's usage points once, with a Merkle proof against the published root
+    /// * Points are non-transferable and credited to the nullifier, not the payer
+    pub fn claim_usage_points(
+        ctx: Context<ClaimUsagePoints>,
+        nullifier: [u8; 32],
+        amount: u64,
+        leaf_index: u32,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        handle_claim_usage_points(ctx, nullifier, amount, leaf_index, proof)
+    }
+
+    // * Personhood instructions
+
+    /// * Accept attestations signed by a proof-of-personhood provider's key (admin only)
+    pub fn add_personhood_provider(
+        ctx: Context<AddPersonhoodProvider>,
+        provider: Pubkey,
+    ) -> Result<()> {
+        handle_add_personhood_provider(ctx, provider)
+    }
+
+    /// * Stop accepting a provider's attestations; its attached scores read as zero (admin only)
+    pub fn remove_personhood_provider(
+        ctx: Context<RemovePersonhoodProvider>,
+        provider: Pubkey,
+    ) -> Result<()> {
+        handle_remove_personhood_provider(ctx, provider)
+    }
+
+    /// * Attach a provider's signed personhood score to a nullifier (anyone may submit)
+    /// * The provider's Ed25519 signature over PersonhoodAttestation::message must be in the tx
+    pub fn attach_personhood_attestation(
+        ctx: Context<AttachPersonhoodAttestation>,
+        nullifier: [u8; 32],
+        provider: Pubkey,
+        score: u16,
+        issued_at: i64,
+        expires_at: i64,
+    ) -> Result<()> {
+        handle_attach_personhood_attestation(ctx, nullifier, provider, score, issued_at, expires_at)
+    }
+
+    /// * Move an attestation into cold storage, keeping only a hash of its fields (provider only)
+    pub fn archive_attestation(ctx: Context<ArchiveAttestation>) -> Result<()> {
+        handle_archive_attestation(ctx)
+    }
+
+    /// * Bring an archived attestation back from its fields (anyone may submit)
+    pub fn restore_attestation(
+        ctx: Context<RestoreAttestation>,
+        nullifier: [u8; 32],
+        provider: Pubkey,
+        score: u16,
+        issued_at: i64,
+        expires_at: i64,
+    ) -> Result<()> {
+        handle_restore_attestation(ctx, nullifier, provider, score, issued_at, expires_at)
+    }
+
+    // * Migration instructions
+
+    /// * Open a migration window to `target_version`, listing the account migrations it needs
+    /// * (admin only); both versions are accepted until complete_migration
+    pub fn begin_migration(
+        ctx: Context<BeginMigration>,
+        target_version: u16,
+        steps: Vec<state::migration::MigrationStep>,
+        deadline: i64,
+    ) -> Result<()> {
+        handle_begin_migration(ctx, target_version, steps, deadline)
+    }
+
+    /// * Switch to the migration's target version once required steps are at 100% or the
+    /// * deadline passed (admin only)
+    pub fn complete_migration(ctx: Context<CompleteMigration>) -> Result<()> {
+        handle_complete_migration(ctx)
+    }
+
+    /// * Move a session from its legacy nullifier-only PDA to the domain-scoped one during a
+    /// * migration with a Session step (permissionless; the caller funds the new account)
+    pub fn migrate_session(
+        ctx: Context<MigrateSession>,
+        nullifier: [u8; 32],
+        domain: [u8; 32],
+    ) -> Result<()> {
+        handle_migrate_session(ctx, nullifier, domain)
+    }
+
+    /// * Rewrite a permission grant from the deployed layout (unix-time i64 expiry, no mask)
+    /// * during a migration with a PermissionGrant step (permissionless; the caller funds the
+    /// * larger account)
+    pub fn migrate_grant(
+        ctx: Context<MigrateGrant>,
+        nullifier: [u8; 32],
+        app_id: Pubkey,
+    ) -> Result<()> {
+        handle_migrate_grant(ctx, nullifier, app_id)
+    }
+
+    // * Maintenance instructions
+
+    /// * Fund a Veiled account back to rent exemption after it grew (anyone may pay)
+    pub fn top_up_rent(ctx: Context<TopUpRent>) -> Result<()> {
+        handle_top_up_rent(ctx)
+    }
+
+    // * State import instructions (devnet builds only; see `bun run cli restore-state`)
+
+    /// * Recreate a domain's config from a snapshot (admin only)
+    #[cfg(feature = "devnet")]
+    pub fn import_domain_config(
+        ctx: Context<ImportDomainConfig>,
+        snapshot: state::domain::DomainConfig,
+    ) -> Result<()> {
+        handle_import_domain_config(ctx, snapshot)
+    }
+
+    /// * Recreate a session from a snapshot (admin only)
+    #[cfg(feature = "devnet")]
+    pub fn import_session(ctx: Context<ImportSession>, snapshot: NullifierAccount) -> Result<()> {
+        handle_import_session(ctx, snapshot)
+    }
+
+    /// * Recreate a proof record from a snapshot (admin only)
+    #[cfg(feature = "devnet")]
+    pub fn import_proof_record(
+        ctx: Context<ImportProofRecord>,
+        snapshot: ProofRecord,
+    ) -> Result<()> {
+        handle_import_proof_record(ctx, snapshot)
+    }
+
+    /// * Recreate a permission grant from a snapshot (admin only)
+    #[cfg(feature = "devnet")]
+    pub fn import_permission_grant(
+        ctx: Context<ImportPermissionGrant>,
+        snapshot: state::permission::PermissionGrant,
+    ) -> Result<()> {
+        handle_import_permission_grant(ctx, snapshot)
     }
 }
 
 #[account]
 pub struct NullifierAccount {
     pub nullifier: [u8; 32],
-    pub domain: String,
+    pub domain: [u8; 32], // * Null-padded domain (see domain_str)
+    pub domain_len: u8, // * Bytes of `domain` before the padding
+    pub authority: Pubkey, // * Wallet the session belongs to (links to its LogoutEpoch); renews and closes it
     pub created_at: i64,
-    pub expires_at: i64, // * Unix timestamp when session expires
+    pub last_active_at: i64, // * Last verification, renewal or touch_session
+    pub idle_timeout_secs: i64, // * Domain's idle timeout when the session was created (0 = none)
+    pub expires_at: ExpiryKind, // * Unix timestamp or slot when session expires
+    pub public_inputs_hash: [u8; 32], // * Hash of the verified statement's public inputs (zero for V1/V2 results)
+    pub circuit_id: u32, // * Registered circuit the session was proven with
+    pub proof_hash: [u8; 32], // * Proof the verifier vouched for (challenges must dispute this proof)
+    pub verified_at: i64, // * When the proof was verified: the result's timestamp, or on-chain verification time
+    pub verifier: Pubkey, // * Ed25519 key that signed the result (default: verified on-chain, sandbox or passkey)
+    pub finalizes_at: i64, // * Optimistic domains: pending and challengeable until then (0 = final)
+    pub payer: Pubkey, // * Paid the account's rent; refunded by close_expired_nullifier
+    pub device_key: Pubkey, // * Ed25519 key of the device the session is bound to (default: none, see prove_device)
+    pub bump: u8, // * Canonical PDA bump, reused by follow-up instructions' seeds checks
+}
+
+impl NullifierAccount {
+    /// * Session lifetime when the caller doesn't ask for one, and the longest it may ask for
+    pub const DEFAULT_EXPIRY_SECONDS: i64 = genesis::DEFAULT_SESSION_EXPIRY_SECS; // * 30 days
+    pub const MAX_EXPIRY_SECONDS: i64 = Self::DEFAULT_EXPIRY_SECONDS;
+
+    /// * Shortest session a caller may ask for
+    pub const MIN_EXPIRY_SECONDS: i64 = genesis::MIN_SESSION_EXPIRY_SECS;
+
+    /// * Share of reclaimed rent paid to whoever closes an expired session
+    pub const CLOSE_REWARD_BPS: u64 = 500; // * 5%
+
+    pub const MAX_SIZE: usize =
+        32 +              // nullifier
+        32 +              // domain
+        1 +               // domain_len
+        32 +              // authority
+        8 +               // created_at
+        8 +               // last_active_at
+        8 +               // idle_timeout_secs
+        ExpiryKind::SIZE + // expires_at
+        32 +              // public_inputs_hash
+        4 +               // circuit_id
+        32 +              // proof_hash
+        8 +               // verified_at
+        32 +              // verifier
+        8 +               // finalizes_at
+        32 +              // payer
+        32 +              // device_key
+        1;                // bump
+
+    /// * Session is active until its expiry passes in the unit it was issued in, or until it
+    /// * goes idle
+    pub fn is_active(&self, clock: &Clock) -> bool {
+        self.created_at != 0
+            && !self.expires_at.has_expired(clock)
+            && !self.is_idle(clock.unix_timestamp)
+    }
+
+    /// * No activity for the idle timeout it was created with (never, without one)
+    /// * Counts from finalization at the earliest, so a pending session can't go idle before
+    /// * it becomes usable
+    pub fn is_idle(&self, now: i64) -> bool {
+        self.idle_timeout_secs > 0
+            && now - self.last_active_at.max(self.finalizes_at) >= self.idle_timeout_secs
+    }
+
+    /// * Require the session to be usable at every session gate: not idle, unexpired, final,
+    /// * and created after its authority's last global_logout (`logout_epoch` is the
+    /// * authority's LogoutEpoch PDA, which may not exist)
+    pub fn require_usable(&self, clock: &Clock, logout_epoch: &AccountInfo) -> Result<()> {
+        let now = clock.unix_timestamp;
+        require!(!self.is_idle(now), errors::VeiledError::SessionIdle);
+        require!(self.is_active(clock), errors::VeiledError::SessionExpired);
+
+        // * Optimistic domains: not usable until the challenge window passes unchallenged
+        require!(self.is_final(now), errors::VeiledError::SessionPending);
+
+        // * Sessions created at or before the identity's last global logout are revoked
+        require!(
+            self.created_at > state::session::LogoutEpoch::load_cutoff(logout_epoch)?,
+            errors::VeiledError::SessionRevoked
+        );
+        Ok(())
+    }
+
+    /// * Whether the session still holds its nullifier against a fresh verify_auth: live until
+    /// * it expires, goes idle or is revoked by global_logout, as require_usable decides. A
+    /// * pending session stays live, so a fresh proof can't swap out the one under challenge
+    /// * `logout_epoch` is its authority's LogoutEpoch PDA, checked here since its seed is only
+    /// * known once the session is loaded; without it, a global logout isn't taken into account
+    pub fn is_live(&self, clock: &Clock, logout_epoch: Option<&AccountInfo>) -> Result<bool> {
+        if !self.is_active(clock) {
+            return Ok(false);
+        }
+        let Some(logout_epoch) = logout_epoch else {
+            return Ok(true);
+        };
+        let (address, _) = Pubkey::find_program_address(
+            &[b"logout_epoch", self.authority.as_ref()],
+            &crate::ID,
+        );
+        require_keys_eq!(logout_epoch.key(), address, ErrorCode::ConstraintSeeds);
+        Ok(self.created_at > state::session::LogoutEpoch::load_cutoff(logout_epoch)?)
+    }
+
+    /// * Last PDA seed, binding a session to its domain: sha256 of the null-padded domain
+    /// * PDA: [b"nullifier", nullifier, domain_hash(domain)]
+    pub fn domain_hash(domain: &[u8; 32]) -> [u8; 32] {
+        hashv(&[domain]).to_bytes()
+    }
+
+    /// * domain_hash of this session's domain, for instructions that only have the account
+    pub fn domain_seed(&self) -> [u8; 32] {
+        Self::domain_hash(&self.domain)
+    }
+
+    /// * Domain as a string, for logs and clients reading the account
+    pub fn domain_str(&self) -> &str {
+        let len = (self.domain_len as usize).min(self.domain.len());
+        core::str::from_utf8(&self.domain[..len]).unwrap_or_default()
+    }
+
+    /// * Past its challenge window (always, for domains without optimistic verification)
+    pub fn is_final(&self, now: i64) -> bool {
+        now >= self.finalizes_at
+    }
+
+    /// * Session was bound to a device key by verify_auth_with_device
+    pub fn has_device(&self) -> bool {
+        self.device_key != Pubkey::default()
+    }
+
+    /// * Require the bound device key to have signed `message` (see DeviceProof) in an
+    /// * Ed25519Program instruction of this transaction; sessions without a device pass
+    pub fn prove_device(&self, instructions_sysvar: &AccountInfo, message: &[u8]) -> Result<()> {
+        if !self.has_device() {
+            return Ok(());
+        }
+        sig_introspection::require_signed(
+            instructions_sysvar,
+            &self.device_key,
+            message,
+            errors::VeiledError::MissingDeviceSignature,
+        )
+    }
+}
+
+/// * A verifier-signed result registered a session (verify_auth, verify_auth_v1 and each
+/// * verify_auth_batch entry), for indexers and app backends following authentications
+#[event]
+pub struct AuthVerifiedEvent {
+    pub nullifier: [u8; 32],
+    pub domain: [u8; 32],
+    pub proof_hash: [u8; 32],
+    pub expires_at: ExpiryKind,
 }
@@ -0,0 +1,24 @@
+// * Local-dev verification bypass
+// *
+// * Localnet builds (`--features localnet-insecure`) stub out the Ed25519 introspection in
+// * verify_auth so permission and session flows can be iterated on in localnet tests without
+// * running the WASM verifier or building precompile instructions. The submitting authority
+// * (or the named `verifier` account) stands in for the result's signer, so it must be one of
+// * the keys allowed to sign for the domain. Circuit, freshness, nullifier and domain checks
+// * still apply.
+// *
+// * The feature only compiles with debug assertions on, which release profiles turn off, and
+// * never alongside `devnet`, so a deployable build can't carry it:
+// *   CARGO_PROFILE_RELEASE_DEBUG_ASSERTIONS=true cargo build-sbf --features localnet-insecure
+
+#[cfg(all(feature = "localnet-insecure", not(debug_assertions)))]
+compile_error!(
+    "`localnet-insecure` skips verifier signature checks and is for local development only; \
+     it requires debug assertions (CARGO_PROFILE_RELEASE_DEBUG_ASSERTIONS=true)"
+);
+
+#[cfg(all(feature = "localnet-insecure", feature = "devnet"))]
+compile_error!("`localnet-insecure` can't be combined with `devnet`");
+
+/// * Whether this build skips the verifier signature check
+pub const ENABLED: bool = cfg!(feature = "localnet-insecure");
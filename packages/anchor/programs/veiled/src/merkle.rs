@@ -0,0 +1,129 @@
+// * Merkle tree helpers
+// *
+// * Binary SHA-256 tree with domain-separated leaves and nodes:
+// *   leaf = sha256(0x00 || leaf_data...)
+// *   node = sha256(0x01 || left || right)
+// * An odd node at the end of a level is promoted unchanged to the next level.
+
+use solana_sha256_hasher::hashv;
+
+const LEAF_PREFIX: &[u8] = &[0x00];
+const NODE_PREFIX: &[u8] = &[0x01];
+
+/// * Hash leaf data with the leaf prefix
+pub fn hash_leaf(parts: &[&[u8]]) -> [u8; 32] {
+    let mut input: Vec<&[u8]> = Vec::with_capacity(parts.len() + 1);
+    input.push(LEAF_PREFIX);
+    input.extend_from_slice(parts);
+    hashv(&input).to_bytes()
+}
+
+/// * Hash two child nodes into their parent
+pub fn hash_node(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    hashv(&[NODE_PREFIX, left, right]).to_bytes()
+}
+
+/// * Root over already-hashed leaves (in order); all-zero root for no leaves
+pub fn merkle_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => hash_node(left, right),
+                [single] => *single,
+                _ => unreachable!(),
+            })
+            .collect();
+    }
+
+    level[0]
+}
+
+/// * Check that `leaf` sits at `index` in a tree of `leaf_count` leaves with root `root`
+/// * `proof` lists the sibling at each level from the leaf up, skipping levels where the
+/// * node was promoted without one
+pub fn verify_proof(
+    root: &[u8; 32],
+    leaf: &[u8; 32],
+    mut index: u32,
+    mut leaf_count: u32,
+    proof: &[[u8; 32]],
+) -> bool {
+    if index >= leaf_count {
+        return false;
+    }
+
+    let mut siblings = proof.iter();
+    let mut node = *leaf;
+    while leaf_count > 1 {
+        let promoted = index == leaf_count - 1 && !leaf_count.is_multiple_of(2);
+        if !promoted {
+            let Some(sibling) = siblings.next() else {
+                return false;
+            };
+            node = if index.is_multiple_of(2) {
+                hash_node(&node, sibling)
+            } else {
+                hash_node(sibling, &node)
+            };
+        }
+        index /= 2;
+        leaf_count = leaf_count.div_ceil(2);
+    }
+
+    siblings.next().is_none() && &node == root
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // * Test: Single leaf is its own root; odd leaves are promoted
+    #[test]
+    fn test_merkle_root() {
+        let a = hash_leaf(&[b"a"]);
+        let b = hash_leaf(&[b"b"]);
+        let c = hash_leaf(&[b"c"]);
+
+        assert_eq!(merkle_root(&[]), [0u8; 32]);
+        assert_eq!(merkle_root(&[a]), a);
+        assert_eq!(merkle_root(&[a, b]), hash_node(&a, &b));
+        assert_eq!(merkle_root(&[a, b, c]), hash_node(&hash_node(&a, &b), &c));
+    }
+
+    // * Test: Proofs check out for every leaf, including promoted ones
+    #[test]
+    fn test_verify_proof() {
+        let leaves: Vec<[u8; 32]> = (0u8..5).map(|i| hash_leaf(&[&[i]])).collect();
+        let root = merkle_root(&leaves);
+        let ab = hash_node(&leaves[0], &leaves[1]);
+        let cd = hash_node(&leaves[2], &leaves[3]);
+        let first = [leaves[1], cd, leaves[4]];
+        let fourth = [leaves[2], ab, leaves[4]];
+        let last = [hash_node(&ab, &cd)];
+
+        assert!(verify_proof(&root, &leaves[0], 0, 5, &first));
+        assert!(verify_proof(&root, &leaves[3], 3, 5, &fourth));
+        assert!(verify_proof(&root, &leaves[4], 4, 5, &last));
+        assert!(verify_proof(&leaves[0], &leaves[0], 0, 1, &[]));
+
+        // * Wrong position, wrong leaf, extra siblings and out-of-range indices fail
+        assert!(!verify_proof(&root, &leaves[0], 1, 5, &first));
+        assert!(!verify_proof(&root, &leaves[1], 0, 5, &first));
+        assert!(!verify_proof(&root, &leaves[4], 4, 5, &[last[0], root]));
+        assert!(!verify_proof(&root, &leaves[4], 5, 5, &last));
+    }
+
+    // * Test: Leaves and nodes are domain separated
+    #[test]
+    fn test_leaf_node_separation() {
+        let a = hash_leaf(&[b"a"]);
+        let b = hash_leaf(&[b"b"]);
+        assert_ne!(hash_node(&a, &b), hash_leaf(&[&a, &b]));
+    }
+}
@@ -0,0 +1,238 @@
+// * Pyth price feed reader
+// *
+// * Reads Pyth pull-oracle `PriceUpdateV2` accounts (owned by the Pyth receiver program)
+// * and converts USD-quoted amounts into token base units at payment time.
+// *
+// * The account is parsed by hand rather than through pyth-solana-receiver-sdk / pyth-sdk-solana:
+// * those crates pin Solana/Anchor versions that conflict with our git Anchor dependency.
+// *
+// * PriceUpdateV2 layout (Borsh):
+// *   discriminator (8) || write_authority (32) || verification_level (1 or 2)
+// *   || feed_id (32) || price (i64) || conf (u64) || exponent (i32) || publish_time (i64)
+// *   || prev_publish_time (i64) || ema_price (i64) || ema_conf (u64) || posted_slot (u64)
+
+use crate::errors::VeiledError;
+use anchor_lang::prelude::*;
+
+// * Pyth solana receiver program id
+pub const PYTH_RECEIVER_PROGRAM_ID: Pubkey =
+    anchor_lang::pubkey!("rec5EKMGg6MxZYaMdyBfgwp4d5rB9T1VQH5pJv5LtFJ");
+
+// * sha256("account:PriceUpdateV2")[..8]
+const PRICE_UPDATE_V2_DISCRIMINATOR: [u8; 8] = [34, 241, 35, 99, 157, 126, 244, 205];
+
+// * Fee amounts are quoted in micro-USD (6 decimals)
+pub const USD_DECIMALS: u32 = 6;
+
+/// * Price read from a Pyth feed
+/// * USD value of one whole token = price * 10^exponent (± conf * 10^exponent)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct OraclePrice {
+    pub price: i64,
+    pub conf: u64,
+    pub exponent: i32,
+    pub publish_time: i64,
+}
+
+impl OraclePrice {
+    /// * Parse a PriceUpdateV2 account and check it belongs to `feed_id`
+    /// * Only fully verified updates (all Wormhole guardian signatures checked) are accepted
+    pub fn from_price_update(data: &[u8], feed_id: &[u8; 32]) -> Result<Self> {
+        require!(
+            data.len() >= 8 && data[..8] == PRICE_UPDATE_V2_DISCRIMINATOR,
+            VeiledError::InvalidOraclePrice
+        );
+
+        // * Skip discriminator + write_authority
+        let mut offset = 8 + 32;
+
+        // * verification_level: Partial { num_signatures: u8 } = 0, Full = 1
+        match data.get(offset) {
+            Some(1) => offset += 1,
+            _ => return err!(VeiledError::InvalidOraclePrice),
+        }
+
+        let message = data
+            .get(offset..offset + 32 + 8 + 8 + 4 + 8)
+            .ok_or(VeiledError::InvalidOraclePrice)?;
+
+        require!(&message[..32] == feed_id, VeiledError::OracleFeedMismatch);
+
+        let price = OraclePrice {
+            price: i64::from_le_bytes(message[32..40].try_into().unwrap()),
+            conf: u64::from_le_bytes(message[40..48].try_into().unwrap()),
+            exponent: i32::from_le_bytes(message[48..52].try_into().unwrap()),
+            publish_time: i64::from_le_bytes(message[52..60].try_into().unwrap()),
+        };
+
+        require!(price.price > 0, VeiledError::InvalidOraclePrice);
+
+        Ok(price)
+    }
+
+    /// * Staleness and confidence checks
+    /// * - Published no more than `max_age_secs` before `now`
+    /// * - Confidence interval no wider than `max_conf_bps` of the price
+    pub fn check(&self, now: i64, max_age_secs: u64, max_conf_bps: u16) -> Result<()> {
+        let age = now.saturating_sub(self.publish_time);
+        require!(
+            age >= 0 && (age as u64) <= max_age_secs,
+            VeiledError::OraclePriceStale
+        );
+
+        let conf_bps = (self.conf as u128) * 10_000;
+        let max_conf = (self.price as u128) * (max_conf_bps as u128);
+        require!(conf_bps <= max_conf, VeiledError::OraclePriceUncertain);
+
+        Ok(())
+    }
+
+    /// * Convert a micro-USD amount into token base units, rounding up
+    /// * amount = usd_micros * 10^(decimals - USD_DECIMALS - exponent) / price
+    pub fn quote_token_amount(&self, usd_micros: u64, mint_decimals: u8) -> Result<u64> {
+        let scale = mint_decimals as i64 - USD_DECIMALS as i64 - self.exponent as i64;
+        let price = self.price as u128;
+
+        let (numerator, denominator) = if scale >= 0 {
+            let factor = 10u128
+                .checked_pow(scale as u32)
+                .ok_or(VeiledError::PriceQuoteOverflow)?;
+            (
+                (usd_micros as u128)
+                    .checked_mul(factor)
+                    .ok_or(VeiledError::PriceQuoteOverflow)?,
+                price,
+            )
+        } else {
+            let factor = 10u128
+                .checked_pow(scale.unsigned_abs() as u32)
+                .ok_or(VeiledError::PriceQuoteOverflow)?;
+            (
+                usd_micros as u128,
+                price
+                    .checked_mul(factor)
+                    .ok_or(VeiledError::PriceQuoteOverflow)?,
+            )
+        };
+
+        let amount = numerator.div_ceil(denominator);
+        u64::try_from(amount).map_err(|_| error!(VeiledError::PriceQuoteOverflow))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED: [u8; 32] = [7u8; 32];
+
+    // * Helper: Build a PriceUpdateV2 account body
+    fn price_update(
+        verification_level: &[u8],
+        feed_id: [u8; 32],
+        price: i64,
+        conf: u64,
+        exponent: i32,
+        publish_time: i64,
+    ) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(&PRICE_UPDATE_V2_DISCRIMINATOR);
+        data.extend_from_slice(&[0u8; 32]); // * write_authority
+        data.extend_from_slice(verification_level);
+        data.extend_from_slice(&feed_id);
+        data.extend_from_slice(&price.to_le_bytes());
+        data.extend_from_slice(&conf.to_le_bytes());
+        data.extend_from_slice(&exponent.to_le_bytes());
+        data.extend_from_slice(&publish_time.to_le_bytes());
+        data.extend_from_slice(&publish_time.to_le_bytes()); // * prev_publish_time
+        data.extend_from_slice(&price.to_le_bytes()); // * ema_price
+        data.extend_from_slice(&conf.to_le_bytes()); // * ema_conf
+        data.extend_from_slice(&0u64.to_le_bytes()); // * posted_slot
+        data
+    }
+
+    // * Test: Fully verified update parses
+    #[test]
+    fn test_parse_price_update() {
+        let data = price_update(&[1], FEED, 150_00000000, 5_000000, -8, 1_000);
+        let price = OraclePrice::from_price_update(&data, &FEED).unwrap();
+        assert_eq!(
+            price,
+            OraclePrice {
+                price: 150_00000000,
+                conf: 5_000000,
+                exponent: -8,
+                publish_time: 1_000
+            }
+        );
+    }
+
+    // * Test: Partially verified updates and foreign feeds are rejected
+    #[test]
+    fn test_reject_partial_and_wrong_feed() {
+        let partial = price_update(&[0, 5], FEED, 150_00000000, 0, -8, 1_000);
+        assert_eq!(
+            OraclePrice::from_price_update(&partial, &FEED).unwrap_err(),
+            VeiledError::InvalidOraclePrice.into()
+        );
+
+        let other_feed = price_update(&[1], [8u8; 32], 150_00000000, 0, -8, 1_000);
+        assert_eq!(
+            OraclePrice::from_price_update(&other_feed, &FEED).unwrap_err(),
+            VeiledError::OracleFeedMismatch.into()
+        );
+    }
+
+    // * Test: Staleness and confidence bounds
+    #[test]
+    fn test_staleness_and_confidence() {
+        // * $150 ± $0.75 (50 bps)
+        let price = OraclePrice {
+            price: 150_00000000,
+            conf: 75000000,
+            exponent: -8,
+            publish_time: 1_000,
+        };
+
+        assert!(price.check(1_060, 60, 50).is_ok());
+        assert_eq!(
+            price.check(1_061, 60, 50).unwrap_err(),
+            VeiledError::OraclePriceStale.into()
+        );
+        assert_eq!(
+            price.check(1_000, 60, 49).unwrap_err(),
+            VeiledError::OraclePriceUncertain.into()
+        );
+    }
+
+    // * Test: USD -> token conversion rounds up in the protocol's favour
+    #[test]
+    fn test_quote_token_amount() {
+        // * SOL at $150, 9 decimals: $1.50 = 0.01 SOL
+        let sol = OraclePrice {
+            price: 150_00000000,
+            conf: 0,
+            exponent: -8,
+            publish_time: 0,
+        };
+        assert_eq!(sol.quote_token_amount(1_500_000, 9).unwrap(), 10_000_000);
+
+        // * USDC at $1.00, 6 decimals: $1.00 = 1 USDC
+        let usdc = OraclePrice {
+            price: 100000000,
+            conf: 0,
+            exponent: -8,
+            publish_time: 0,
+        };
+        assert_eq!(usdc.quote_token_amount(1_000_000, 6).unwrap(), 1_000_000);
+
+        // * $1 at $3 per token, 0 decimals: rounds up to 1 token
+        let coarse = OraclePrice {
+            price: 3,
+            conf: 0,
+            exponent: 0,
+            publish_time: 0,
+        };
+        assert_eq!(coarse.quote_token_amount(1_000_000, 0).unwrap(), 1);
+    }
+}
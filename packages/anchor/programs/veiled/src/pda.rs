@@ -0,0 +1,123 @@
+// * Program-derived accounts created by hand
+// * For PDAs Anchor's `init` can't describe: addresses that come from remaining_accounts or
+// * from seeds computed in the handler
+
+use crate::errors::VeiledError;
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+
+/// * `init` for a PDA the handler derives itself (remaining_accounts, hashed seeds); returns
+/// * its bump. An account this program already owns fails with `in_use`
+/// * Accounts pre-funded with rent (e.g. by the SDK warm path) are topped up and taken over
+pub fn create_pda<'info>(
+    payer: &Signer<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    seeds: &[&[u8]],
+    space: usize,
+    in_use: VeiledError,
+) -> Result<u8> {
+    let (address, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(account.key(), address, ErrorCode::ConstraintSeeds);
+    if account.owner == &crate::ID {
+        return Err(in_use.into());
+    }
+    allocate_pda(payer, account, system_program, seeds, bump, space)?;
+    Ok(bump)
+}
+
+/// * Create the (unowned) PDA at `seeds` and `bump`, funded by `payer` and owned by this program
+fn allocate_pda<'info>(
+    payer: &Signer<'info>,
+    account: &AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    seeds: &[&[u8]],
+    bump: u8,
+    space: usize,
+) -> Result<()> {
+    let bump_seed = [bump];
+    let signer_seeds = [seeds, &[&bump_seed[..]]].concat();
+    let signer: &[&[&[u8]]] = &[&signer_seeds];
+    let program = system_program.to_account_info();
+    let rent = Rent::get()?.minimum_balance(space);
+
+    if account.lamports() == 0 {
+        return system_program::create_account(
+            CpiContext::new_with_signer(
+                program,
+                CreateAccount {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+                signer,
+            ),
+            rent,
+            space as u64,
+            &crate::ID,
+        );
+    }
+
+    // * create_account fails on funded addresses: top up, allocate and assign instead
+    let shortfall = rent.saturating_sub(account.lamports());
+    if shortfall > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                program.clone(),
+                Transfer {
+                    from: payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            shortfall,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new_with_signer(
+            program.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+            signer,
+        ),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new_with_signer(
+            program,
+            Assign {
+                account_to_assign: account.clone(),
+            },
+            signer,
+        ),
+        &crate::ID,
+    )
+}
+
+/// * Serialize an account (discriminator included) into a freshly created PDA
+pub fn write_account<T: AccountSerialize>(info: &AccountInfo, account: &T) -> Result<()> {
+    let mut data = info.try_borrow_mut_data()?;
+    account.try_serialize(&mut &mut data[..])
+}
+
+/// * `init_if_needed` for a PDA the handler derives itself: loads the account, first creating
+/// * it (see create_pda) with `T`'s discriminator and zeroed fields if it doesn't exist yet;
+/// * returns it with its bump. The caller persists changes with `exit`
+pub fn load_or_create_pda<'info, T>(
+    payer: &Signer<'info>,
+    account: &'info AccountInfo<'info>,
+    system_program: &Program<'info, System>,
+    seeds: &[&[u8]],
+    space: usize,
+) -> Result<(Account<'info, T>, u8)>
+where
+    T: AccountSerialize + AccountDeserialize + Discriminator + Owner + Clone,
+{
+    let (address, bump) = Pubkey::find_program_address(seeds, &crate::ID);
+    require_keys_eq!(account.key(), address, ErrorCode::ConstraintSeeds);
+    if account.owner != &crate::ID {
+        allocate_pda(payer, account, system_program, seeds, bump, space)?;
+        let discriminator = T::DISCRIMINATOR;
+        account.try_borrow_mut_data()?[..discriminator.len()].copy_from_slice(discriminator);
+    }
+    Ok((Account::try_from(account)?, bump))
+}
@@ -0,0 +1,22 @@
+// * Developer sandbox domain
+// *
+// * Devnet builds (`--features devnet`) reserve the `sandbox` domain so integrators can
+// * exercise the full verify_auth / session / permission flow before running verifier
+// * infrastructure. For sandbox results verify_auth skips:
+// * - The Ed25519 verifier signature check (any authority can submit a result)
+// * - The circuit registry check
+// * - The staleness window, so recorded fixtures can be replayed
+// *
+// * Nullifier and domain binding, is_valid, and duplicate-nullifier protection still apply.
+// * Every other build rejects the reserved domain outright, so nothing on mainnet can
+// * come to depend on the relaxed checks.
+
+/// * Reserved domain name (null-padded in the verify_auth `domain` argument)
+pub const SANDBOX_DOMAIN: &[u8] = b"sandbox";
+
+/// * Whether this build accepts the sandbox domain
+pub const ENABLED: bool = cfg!(feature = "devnet");
+
+pub fn is_sandbox_domain(domain: &[u8]) -> bool {
+    domain == SANDBOX_DOMAIN
+}
@@ -0,0 +1,154 @@
+// * Solana Attestation Service (SAS) bridge
+// *
+// * Builds the SAS CreateAttestation instruction mirror_attestation invokes, so a Veiled
+// * attestation can be read by programs and wallets that only speak SAS.
+// *
+// * The instruction is encoded by hand rather than through the SAS client crate, which pins
+// * Solana versions that conflict with our git Anchor dependency (same reason as oracle.rs).
+// *
+// * CreateAttestation (discriminator 6):
+// *   accounts: payer (w, s) || authority (s) || credential || schema || attestation (w)
+// *             || system_program
+// *   data: discriminator (1) || nonce (32) || data (u32 len + bytes) || expiry (i64)
+// *
+// * SAS attestation PDA: [b"attestation", credential, schema, nonce] under the SAS program
+// *
+// * Mirrored data follows the schema the config authority registers with set_sas_bridge:
+// *   nullifier (VecU8) || claim_type (U8) || domain (VecU8) || claim_data_hash (VecU8)
+// *   || issuer (VecU8) || circuit_id (U32)
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use solana_sha256_hasher::hashv;
+
+use crate::state::attestation::Attestation;
+use crate::state::sas_bridge::SasBridge;
+
+// * Solana Attestation Service program id
+pub const SAS_PROGRAM_ID: Pubkey =
+    anchor_lang::pubkey!("22zoJMtdu4tQc2PzL74ZUT7FrwgB1Udec8DdW4yw4BdG");
+
+const CREATE_ATTESTATION_DISCRIMINATOR: u8 = 6;
+
+/// * SAS nonce for a Veiled attestation as of its latest issue: each renewal mirrors to a
+/// * fresh SAS attestation, as SAS attestations can't be updated in place
+pub fn nonce(attestation: &Pubkey, issued_at: i64) -> Pubkey {
+    Pubkey::new_from_array(hashv(&[attestation.as_ref(), &issued_at.to_le_bytes()]).to_bytes())
+}
+
+/// * SAS attestation PDA for `nonce` under a credential's schema
+pub fn attestation_address(credential: &Pubkey, schema: &Pubkey, nonce: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[
+            b"attestation",
+            credential.as_ref(),
+            schema.as_ref(),
+            nonce.as_ref(),
+        ],
+        &SAS_PROGRAM_ID,
+    )
+    .0
+}
+
+/// * Attestation fields serialized in the bridge schema's layout
+pub fn attestation_data(attestation: &Attestation) -> Vec<u8> {
+    let mut data = Vec::with_capacity(4 * (4 + 32) + 1 + 4);
+    push_bytes(&mut data, &attestation.nullifier);
+    data.push(attestation.claim_type.to_byte());
+    push_bytes(&mut data, &attestation.domain);
+    push_bytes(&mut data, &attestation.claim_data_hash);
+    push_bytes(&mut data, attestation.issuer.as_ref());
+    data.extend_from_slice(&attestation.circuit_id.to_le_bytes());
+    data
+}
+
+// * Borsh Vec<u8>: u32 length prefix, then the bytes
+fn push_bytes(data: &mut Vec<u8>, bytes: &[u8]) {
+    data.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    data.extend_from_slice(bytes);
+}
+
+/// * CreateAttestation for `data` under the bridge's credential and schema, signed by one of
+/// * the credential's authorized signers; returns it with the SAS attestation address
+pub fn create_attestation(
+    payer: &Pubkey,
+    authority: &Pubkey,
+    bridge: &SasBridge,
+    nonce: &Pubkey,
+    data: &[u8],
+    expiry: i64,
+) -> (Instruction, Pubkey) {
+    let attestation = attestation_address(&bridge.credential, &bridge.schema, nonce);
+
+    let mut ix_data = Vec::with_capacity(1 + 32 + 4 + data.len() + 8);
+    ix_data.push(CREATE_ATTESTATION_DISCRIMINATOR);
+    ix_data.extend_from_slice(nonce.as_ref());
+    push_bytes(&mut ix_data, data);
+    ix_data.extend_from_slice(&expiry.to_le_bytes());
+
+    let ix = Instruction {
+        program_id: SAS_PROGRAM_ID,
+        accounts: vec![
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(*authority, true),
+            AccountMeta::new_readonly(bridge.credential, false),
+            AccountMeta::new_readonly(bridge.schema, false),
+            AccountMeta::new(attestation, false),
+            AccountMeta::new_readonly(anchor_lang::system_program::ID, false),
+        ],
+        data: ix_data,
+    };
+    (ix, attestation)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::verification_result::ClaimType;
+
+    #[test]
+    fn test_create_attestation_encoding() {
+        let attestation = Attestation {
+            nullifier: [1u8; 32],
+            claim_type: ClaimType::AgeOver,
+            domain: [2u8; 32],
+            claim_data_hash: [3u8; 32],
+            circuit_id: 7,
+            issuer: Pubkey::new_from_array([4u8; 32]),
+            issued_at: 1_000,
+            expires_at: 2_000,
+            bump: 255,
+            revoked_at: 0,
+        };
+        let data = attestation_data(&attestation);
+        assert_eq!(data.len(), 4 * (4 + 32) + 1 + 4);
+        assert_eq!(&data[..4], &32u32.to_le_bytes());
+        assert_eq!(data[4 + 32], ClaimType::AgeOver.to_byte());
+        assert_eq!(&data[data.len() - 4..], &7u32.to_le_bytes());
+
+        let nonce = nonce(&Pubkey::new_unique(), attestation.issued_at);
+        let bridge = SasBridge {
+            credential: Pubkey::new_unique(),
+            schema: Pubkey::new_unique(),
+            bump: 255,
+        };
+        let (ix, address) = create_attestation(
+            &Pubkey::new_unique(),
+            &Pubkey::new_unique(),
+            &bridge,
+            &nonce,
+            &data,
+            2_000,
+        );
+        assert_eq!(
+            address,
+            attestation_address(&bridge.credential, &bridge.schema, &nonce)
+        );
+        assert_eq!(ix.program_id, SAS_PROGRAM_ID);
+        assert_eq!(ix.data[0], CREATE_ATTESTATION_DISCRIMINATOR);
+        assert_eq!(&ix.data[1..33], nonce.as_ref());
+        assert_eq!(&ix.data[33..37], &(data.len() as u32).to_le_bytes());
+        assert_eq!(&ix.data[ix.data.len() - 8..], &2_000i64.to_le_bytes());
+        assert!(ix.accounts[1].is_signer && ix.accounts[4].is_writable);
+    }
+}
@@ -0,0 +1,105 @@
+// * Signed-message introspection
+// *
+// * Veiled never verifies signatures itself: the runtime verifies Ed25519Program and Secp256r1
+// * precompile instructions before any instruction runs, and Veiled reads them back through the
+// * instructions sysvar. The parsing (offset indices, bounds, entry counts) lives in the
+// * standalone veiled-sig-introspect crate (crates/veiled-sig-introspect) so other programs can
+// * depend on it; this module maps its errors onto VeiledError and adds Veiled's
+// * per-transaction bounds, so every signed instruction (verify_auth results, personhood
+// * attestations, future consent or logout signatures) gets the same checks.
+
+use crate::errors::VeiledError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::Instruction as SolanaInstruction;
+use veiled_sig_introspect::{IntrospectError, Rejected, SignatureEntry};
+
+pub use veiled_sig_introspect::{ED25519_PROGRAM_ID, SECP256R1_PROGRAM_ID};
+
+/// * Most Ed25519Program instructions one instruction will inspect in a transaction
+/// * Bounds the compute spent on co-signed (quorum) results
+pub const MAX_ED25519_INSTRUCTIONS: usize = 8;
+
+impl From<IntrospectError> for VeiledError {
+    fn from(error: IntrospectError) -> Self {
+        match error {
+            IntrospectError::SysvarUnavailable => VeiledError::InvalidProof,
+            IntrospectError::InvalidInstructionData => VeiledError::InvalidInstructionData,
+            IntrospectError::InvalidSignatureCount => VeiledError::InvalidSignatureCount,
+            IntrospectError::OffsetMismatch => VeiledError::OffsetMismatch,
+            IntrospectError::UnexpectedAccounts => VeiledError::BadEd25519Accounts,
+            IntrospectError::TooManyInstructions => VeiledError::TooManyEd25519Instructions,
+        }
+    }
+}
+
+/// * Indices of every instruction in the transaction except the current one
+/// * (see veiled_sig_introspect::other_instruction_indices)
+pub fn other_instruction_indices(
+    instructions_sysvar: &AccountInfo,
+) -> Result<impl Iterator<Item = usize>> {
+    veiled_sig_introspect::other_instruction_indices(instructions_sysvar)
+        .map_err(|error| error!(VeiledError::from(error)))
+}
+
+/// * Raw public key and signature of every entry of a precompile instruction whose message
+/// * `check_message` accepts; layout failures surface as the matching VeiledError
+/// * (see veiled_sig_introspect::signature_entries for the checks performed)
+pub fn signature_entries<const PUBKEY_LEN: usize>(
+    ix: &SolanaInstruction,
+    check_message: impl Fn(&[u8]) -> Result<()>,
+) -> Result<Vec<SignatureEntry<PUBKEY_LEN>>> {
+    veiled_sig_introspect::signature_entries::<PUBKEY_LEN, _>(ix, check_message).map_err(
+        |rejected| match rejected {
+            Rejected::Layout(error) => error!(VeiledError::from(error)),
+            Rejected::Message(error) => error,
+        },
+    )
+}
+
+/// * Require an Ed25519Program instruction elsewhere in the transaction to verify exactly
+/// * `message` under `signer`; fails with `missing` when none does
+/// * For single-key claims (e.g. personhood attestations); verify_auth's results, which need
+/// * message layout checks and co-signer counting, go through ultrahonk.rs
+pub fn require_signed(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+    missing: VeiledError,
+) -> Result<()> {
+    signed_instruction(instructions_sysvar, signer, message, missing).map(|_| ())
+}
+
+/// * require_signed, returning the index of the instruction carrying the signature
+pub fn signed_instruction(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+    missing: VeiledError,
+) -> Result<usize> {
+    // * Same per-transaction bounds verify_auth applies
+    let index = veiled_sig_introspect::position_ed25519_signature(
+        instructions_sysvar,
+        signer,
+        message,
+        MAX_ED25519_INSTRUCTIONS,
+    )
+    .map_err(|error| error!(VeiledError::from(error)))?;
+    index.ok_or_else(|| missing.into())
+}
+
+/// * Whether an Ed25519Program instruction elsewhere in the transaction verifies `message`
+/// * under `signer`; for m-of-n approvals, where one missing signature isn't an error
+pub fn is_signed(
+    instructions_sysvar: &AccountInfo,
+    signer: &Pubkey,
+    message: &[u8],
+) -> Result<bool> {
+    let index = veiled_sig_introspect::position_ed25519_signature(
+        instructions_sysvar,
+        signer,
+        message,
+        MAX_ED25519_INSTRUCTIONS,
+    )
+    .map_err(|error| error!(VeiledError::from(error)))?;
+    Ok(index.is_some())
+}
@@ -0,0 +1,103 @@
+// * Solana Name Service (SNS) ownership checks
+// *
+// * `.sol` domains are registered through SNS, so creating the DomainConfig for one requires
+// * the signer to own the SNS name it falls under; otherwise anyone could squat a `.sol`
+// * domain in the registry before its holder does. Subdomains (`app.foo.sol`) are covered by
+// * their second-level name (`foo.sol`), whose owner controls them in SNS too.
+// *
+// * Name accounts are read by hand rather than through the SNS client crate, which pins
+// * Solana versions that conflict with our git Anchor dependency (same reason as sas.rs).
+// *
+// * Name account PDA: [sha256("SPL Name Service" || name), class, parent] under the name
+// * program, with the default (zero) class and the `.sol` root as parent
+// * Name record header: parent_name (32) || owner (32) || class (32), then the name's data
+// *
+// * Tokenized names are owned by the SNS tokenizer while wrapped, so they must be unwrapped
+// * before the domain can be registered.
+
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+use crate::errors::VeiledError;
+
+// * SPL Name Service program id
+pub const NAME_PROGRAM_ID: Pubkey =
+    anchor_lang::pubkey!("namesLPneVptA9Z5rqUDD9tmTqK2Ka6sARnS9Ufa6hX");
+
+// * `.sol` top-level domain, the parent of every second-level `.sol` name
+pub const SOL_TLD: Pubkey = anchor_lang::pubkey!("58PwtjSDuFHuUkYjH9BYnnQKHfwo9reZhC2zMJv9JPkx");
+
+const HASH_PREFIX: &[u8] = b"SPL Name Service";
+const HEADER_LEN: usize = 96;
+const OWNER_OFFSET: usize = 32;
+
+/// * Second-level SNS name a padded domain falls under (`foo` for `foo.sol` and
+/// * `app.foo.sol`); None for domains outside `.sol`
+pub fn sol_name(domain: &[u8; 32]) -> Option<&str> {
+    let len = domain.iter().position(|&b| b == 0).unwrap_or(32);
+    let domain = core::str::from_utf8(&domain[..len]).ok()?;
+    let (rest, tld) = domain.rsplit_once('.')?;
+    if !tld.eq_ignore_ascii_case("sol") {
+        return None;
+    }
+    Some(rest.rsplit('.').next().unwrap_or(rest))
+}
+
+/// * SNS name account for a second-level `.sol` name
+pub fn name_account(name: &str) -> Pubkey {
+    let hashed_name = hashv(&[HASH_PREFIX, name.as_bytes()]).to_bytes();
+    Pubkey::find_program_address(
+        &[
+            hashed_name.as_ref(),
+            Pubkey::default().as_ref(),
+            SOL_TLD.as_ref(),
+        ],
+        &NAME_PROGRAM_ID,
+    )
+    .0
+}
+
+/// * Requires `owner` to own the SNS name a `.sol` domain falls under, read from
+/// * `name_account`; domains outside `.sol` need no name account
+pub fn require_domain_owner(
+    domain: &[u8; 32],
+    name_account: Option<&AccountInfo>,
+    owner: &Pubkey,
+) -> Result<()> {
+    let Some(name) = sol_name(domain) else {
+        return Ok(());
+    };
+
+    let info = name_account.ok_or(VeiledError::InvalidSnsNameAccount)?;
+    require!(
+        info.key() == self::name_account(name) && *info.owner == NAME_PROGRAM_ID,
+        VeiledError::InvalidSnsNameAccount
+    );
+    let data = info.try_borrow_data()?;
+    require!(data.len() >= HEADER_LEN, VeiledError::InvalidSnsNameAccount);
+    require!(
+        data[OWNER_OFFSET..OWNER_OFFSET + 32] == owner.to_bytes(),
+        VeiledError::NotSnsNameOwner
+    );
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn padded(domain: &str) -> [u8; 32] {
+        let mut padded = [0u8; 32];
+        padded[..domain.len()].copy_from_slice(domain.as_bytes());
+        padded
+    }
+
+    #[test]
+    fn test_sol_name() {
+        assert_eq!(sol_name(&padded("foo.sol")), Some("foo"));
+        assert_eq!(sol_name(&padded("app.foo.sol")), Some("foo"));
+        assert_eq!(sol_name(&padded("example.com")), None);
+        assert_eq!(sol_name(&padded("sol")), None);
+        assert_eq!(sol_name(&padded("console")), None);
+    }
+}
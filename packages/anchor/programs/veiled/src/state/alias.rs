@@ -0,0 +1,40 @@
+// * Alias state
+// * Pseudonymous handles: an Alias maps the SHA256 of a human-readable handle to the nullifier
+// * that claimed it, so apps can show "@alice" for a user without ever showing a wallet address.
+// * The handle itself never goes on-chain; apps resolve a handle by hashing it and fetching
+// * the PDA.
+
+use anchor_lang::prelude::*;
+
+/// * A claimed handle
+/// * PDA: [b"alias", handle_hash]
+#[account]
+pub struct Alias {
+    /// * SHA256 of the handle
+    pub handle_hash: [u8; 32],
+
+    /// * Nullifier that claimed the handle
+    pub nullifier: [u8; 32],
+
+    /// * Null-padded domain of the session the handle was claimed with
+    pub domain: [u8; 32],
+
+    /// * That session's authority
+    pub authority: Pubkey,
+
+    /// * When the handle was claimed
+    pub claimed_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl Alias {
+    pub const MAX_SIZE: usize =
+        32 + // handle_hash
+        32 + // nullifier
+        32 + // domain
+        32 + // authority
+        8 +  // claimed_at
+        1;   // bump
+}
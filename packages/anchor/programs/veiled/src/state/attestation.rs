@@ -0,0 +1,137 @@
+// * Attestation state
+// * Non-login claims (balance thresholds, age, NFT ownership) proven to a domain, stored from a
+// * verifier-signed result by store_attestation so apps can read them back without a session
+
+use anchor_lang::prelude::*;
+
+use crate::errors::VeiledError;
+use crate::state::verification_result::ClaimType;
+
+/// * A nullifier's latest proven claim of one type for a domain
+/// * PDA: [b"attestation", nullifier, claim_type, domain]
+#[account]
+pub struct Attestation {
+    pub nullifier: [u8; 32],
+
+    pub claim_type: ClaimType,
+
+    /// * Null-padded domain the claim was proven to
+    pub domain: [u8; 32],
+
+    /// * What was proven: the result's public_inputs_hash (e.g. binds the threshold or age)
+    pub claim_data_hash: [u8; 32],
+
+    /// * Registered circuit the proof was verified against
+    pub circuit_id: u32,
+
+    /// * Verifier key that signed the result (default when a passkey carried the signature)
+    pub issuer: Pubkey,
+
+    /// * Unix timestamp the attestation was stored or last renewed at
+    pub issued_at: i64,
+
+    /// * Unix timestamp the claim lapses at; renew_attestation extends it
+    pub expires_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+
+    /// * Zero unless the issuer revoked the attestation (see revoke_attestation); final
+    pub revoked_at: i64,
+}
+
+impl Attestation {
+    /// * Longest an attestation lasts, and the lifetime of ones that don't ask
+    pub const MAX_LIFETIME_SECS: i64 = 365 * 24 * 60 * 60;
+
+    pub const MAX_SIZE: usize =
+        32 + // nullifier
+        1 +  // claim_type
+        32 + // domain
+        32 + // claim_data_hash
+        4 +  // circuit_id
+        32 + // issuer
+        8 +  // issued_at
+        8 +  // expires_at
+        1 +  // bump
+        8;   // revoked_at
+
+    /// * Expiry for an attestation stored at `now`, lasting `expires_in` (0 = the maximum)
+    pub fn expiry(now: i64, expires_in: i64) -> Result<i64> {
+        let lifetime = match expires_in {
+            0 => Self::MAX_LIFETIME_SECS,
+            secs => secs,
+        };
+        require!(
+            (1..=Self::MAX_LIFETIME_SECS).contains(&lifetime),
+            VeiledError::AttestationExpiryOutOfBounds
+        );
+        Ok(now + lifetime)
+    }
+
+    pub fn is_revoked(&self) -> bool {
+        self.revoked_at != 0
+    }
+
+    pub fn is_active(&self, now: i64) -> bool {
+        !self.is_revoked() && now < self.expires_at
+    }
+
+    /// * For readers (validate_attestation and CPI callers): a revoked or expired claim no
+    /// * longer holds
+    pub fn require_active(&self, now: i64) -> Result<()> {
+        require!(!self.is_revoked(), VeiledError::AttestationRevoked);
+        require!(now < self.expires_at, VeiledError::AttestationExpired);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expiry() {
+        assert_eq!(
+            Attestation::expiry(1_000, 0).unwrap(),
+            1_000 + Attestation::MAX_LIFETIME_SECS
+        );
+        assert_eq!(Attestation::expiry(1_000, 60).unwrap(), 1_060);
+        assert_eq!(
+            Attestation::expiry(1_000, -1).unwrap_err(),
+            VeiledError::AttestationExpiryOutOfBounds.into()
+        );
+        assert_eq!(
+            Attestation::expiry(1_000, Attestation::MAX_LIFETIME_SECS + 1).unwrap_err(),
+            VeiledError::AttestationExpiryOutOfBounds.into()
+        );
+    }
+
+    #[test]
+    fn test_require_active() {
+        let mut attestation = Attestation {
+            nullifier: [1u8; 32],
+            claim_type: ClaimType::AgeOver,
+            domain: [2u8; 32],
+            claim_data_hash: [3u8; 32],
+            circuit_id: 0,
+            issuer: Pubkey::new_unique(),
+            issued_at: 1_000,
+            expires_at: 2_000,
+            bump: 255,
+            revoked_at: 0,
+        };
+        assert!(attestation.require_active(1_999).is_ok());
+        assert_eq!(
+            attestation.require_active(2_000).unwrap_err(),
+            VeiledError::AttestationExpired.into()
+        );
+
+        attestation.revoked_at = 1_500;
+        assert!(!attestation.is_active(1_600));
+        assert_eq!(
+            attestation.require_active(1_600).unwrap_err(),
+            VeiledError::AttestationRevoked.into()
+        );
+    }
+}
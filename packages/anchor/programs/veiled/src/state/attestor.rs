@@ -0,0 +1,155 @@
+// * Attestor state
+// * External attestation providers (KYC vendors, age checkers) registered by the config
+// * authority, so a domain can take a claim type only from issuers the protocol vetted
+
+use anchor_lang::prelude::*;
+
+use crate::errors::VeiledError;
+use crate::state::domain::DomainConfig;
+use crate::state::verification_result::ClaimType;
+
+/// * A registered attestation provider
+/// * PDA: [b"attestor", issuer]
+#[account]
+pub struct Attestor {
+    /// * Key the provider signs results with; becomes the issuer of attestations it backs
+    pub issuer: Pubkey,
+
+    /// * SHA256 of the provider's display name (the name itself lives off-chain)
+    pub name_hash: [u8; 32],
+
+    /// * Claim types (ClaimType::bit) the provider is vetted for
+    pub claim_mask: u8,
+
+    pub status: AttestorStatus,
+
+    /// * Unix timestamp of the first registration
+    pub registered_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttestorStatus {
+    Active,    // * Trusted for its claim types
+    Suspended, // * Temporarily untrusted; existing attestations are untouched
+}
+
+impl Attestor {
+    pub const MAX_SIZE: usize =
+        32 + // issuer
+        32 + // name_hash
+        1 +  // claim_mask
+        1 +  // status
+        8 +  // registered_at
+        1;   // bump
+
+    pub fn supports(&self, claim_type: ClaimType) -> bool {
+        self.claim_mask & claim_type.bit() != 0
+    }
+
+    /// * A domain that restricts `claim_type` (see DomainConfig::attestor_claim_mask) only takes
+    /// * it from `issuer` when `attestor` is that issuer's active registration covering the claim
+    pub fn check_issuer(
+        domain_config: Option<&DomainConfig>,
+        attestor: Option<&Attestor>,
+        issuer: &Pubkey,
+        claim_type: ClaimType,
+    ) -> Result<()> {
+        if !domain_config.is_some_and(|config| config.requires_attestor(claim_type)) {
+            return Ok(());
+        }
+
+        let attestor = attestor.ok_or(VeiledError::UntrustedAttestor)?;
+        require!(
+            &attestor.issuer == issuer && attestor.supports(claim_type),
+            VeiledError::UntrustedAttestor
+        );
+        require!(
+            attestor.status == AttestorStatus::Active,
+            VeiledError::AttestorSuspended
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::domain::NullifierStore;
+    use crate::state::expiry::ExpiryUnit;
+
+    #[test]
+    fn test_check_issuer() {
+        let issuer = Pubkey::new_unique();
+        let mut attestor = Attestor {
+            issuer,
+            name_hash: [1u8; 32],
+            claim_mask: ClaimType::AgeOver.bit(),
+            status: AttestorStatus::Active,
+            registered_at: 1_000,
+            bump: 255,
+        };
+        let mut domain_config = DomainConfig {
+            domain: [2u8; 32],
+            owner: Pubkey::new_unique(),
+            pinned_vk_hash: [0u8; 32],
+            verifiers: vec![],
+            bump: 255,
+            challenge_window_secs: 0,
+            rate_limit: 0,
+            assurance_tier: 0,
+            max_result_age_secs: 0,
+            operator: Pubkey::default(),
+            treasurer: Pubkey::default(),
+            paused: false,
+            allowed_origins: vec![],
+            min_session_secs: 0,
+            max_session_secs: 0,
+            attestor_claim_mask: 0,
+            idle_timeout_secs: 0,
+            nullifier_store: NullifierStore::Accounts,
+            max_permissions: 0,
+            session_expiry_unit: ExpiryUnit::UnixTime,
+            default_session_secs: 0,
+        };
+        let check = |config: &DomainConfig, attestor: Option<&Attestor>, claim_type| {
+            Attestor::check_issuer(Some(config), attestor, &issuer, claim_type)
+        };
+
+        // * Unrestricted domains take any issuer
+        assert!(check(&domain_config, None, ClaimType::AgeOver).is_ok());
+        assert!(Attestor::check_issuer(None, None, &issuer, ClaimType::AgeOver).is_ok());
+
+        domain_config.attestor_claim_mask = ClaimType::AgeOver.bit();
+        assert!(check(&domain_config, Some(&attestor), ClaimType::AgeOver).is_ok());
+        assert!(check(&domain_config, None, ClaimType::BalanceThreshold).is_ok());
+        assert_eq!(
+            check(&domain_config, None, ClaimType::AgeOver).unwrap_err(),
+            VeiledError::UntrustedAttestor.into()
+        );
+        assert_eq!(
+            Attestor::check_issuer(
+                Some(&domain_config),
+                Some(&attestor),
+                &Pubkey::new_unique(),
+                ClaimType::AgeOver
+            )
+            .unwrap_err(),
+            VeiledError::UntrustedAttestor.into()
+        );
+
+        domain_config.attestor_claim_mask |= ClaimType::NftOwnership.bit();
+        assert_eq!(
+            check(&domain_config, Some(&attestor), ClaimType::NftOwnership).unwrap_err(),
+            VeiledError::UntrustedAttestor.into()
+        );
+
+        attestor.status = AttestorStatus::Suspended;
+        assert_eq!(
+            check(&domain_config, Some(&attestor), ClaimType::AgeOver).unwrap_err(),
+            VeiledError::AttestorSuspended.into()
+        );
+    }
+}
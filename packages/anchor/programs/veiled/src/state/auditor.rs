@@ -0,0 +1,45 @@
+// * Auditor state types
+// * Read-acknowledgement rights over a user's access logs, without data permissions
+
+use anchor_lang::prelude::*;
+
+use super::expiry::ExpiryKind;
+
+#[account]
+pub struct AuditorGrant {
+    /// * User's nullifier whose access logs the auditor may review
+    pub nullifier: [u8; 32],
+
+    /// * Auditor key allowed to acknowledge access logs
+    pub auditor: Pubkey,
+
+    /// * Hash of the auditor's display name (e.g. sha256("Acme Compliance"))
+    pub name_hash: [u8; 32],
+
+    /// * Wallet that created the grant (only it may revoke)
+    pub granted_by: Pubkey,
+
+    /// * When the grant was created
+    pub granted_at: i64,
+
+    /// * When the grant expires (unix time or slot)
+    pub expires_at: ExpiryKind,
+
+    /// * User can revoke anytime
+    pub revoked: bool,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl AuditorGrant {
+    pub const MAX_SIZE: usize =
+        32 +               // nullifier
+        32 +               // auditor
+        32 +               // name_hash
+        32 +               // granted_by
+        8 +                // granted_at
+        ExpiryKind::SIZE + // expires_at
+        1 +                // revoked
+        1;                 // bump
+}
@@ -0,0 +1,48 @@
+// * Nullifier ban state
+// * A domain's owner can ban a nullifier from the domain: the NullifierBan PDA existing is the
+// * ban, and closing it lifts it. Every verify_auth path, renew_session, the session and
+// * delegate validators and log_permission_access take the PDA for their domain and nullifier
+// * (pinned by seeds, so it can't be swapped out) and refuse while it exists; migrate_nullifier
+// * and recover_identity take it for both the old and the new nullifier.
+
+use anchor_lang::prelude::*;
+
+use crate::errors::VeiledError;
+
+/// * A nullifier banned from one domain
+/// * PDA: [b"nullifier_ban", domain, nullifier]
+#[account]
+pub struct NullifierBan {
+    /// * Null-padded domain the ban applies to
+    pub domain: [u8; 32],
+
+    /// * Banned nullifier
+    pub nullifier: [u8; 32],
+
+    /// * Domain owner that issued the ban
+    pub banned_by: Pubkey,
+
+    /// * When the ban was issued
+    pub banned_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl NullifierBan {
+    pub const MAX_SIZE: usize =
+        32 + // domain
+        32 + // nullifier
+        32 + // banned_by
+        8 +  // banned_at
+        1;   // bump
+
+    /// * Fail if the (seeds-pinned) ban PDA exists
+    pub fn require_not_banned(info: &AccountInfo) -> Result<()> {
+        require!(
+            info.owner != &crate::ID || info.data_is_empty(),
+            VeiledError::NullifierBanned
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+// * Circuit registry state
+// * Admin-managed mapping of Noir circuit IDs to their verification-key hashes
+
+use anchor_lang::prelude::*;
+
+use crate::errors::VeiledError;
+
+#[account]
+pub struct CircuitRegistry {
+    /// * Registered circuits (auth, balance, age, ...)
+    pub circuits: Vec<CircuitEntry>,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+/// * A circuit verification results can be issued for
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct CircuitEntry {
+    pub circuit_id: u32,
+    pub vk_hash: [u8; 32], // * SHA256 of the circuit's verification key
+    pub deprecated: bool,  // * Deprecated circuits no longer verify; their IDs are never reused
+}
+
+impl CircuitEntry {
+    pub const SIZE: usize = 4 + 32 + 1;
+}
+
+impl CircuitRegistry {
+    pub const MAX_CIRCUITS: usize = 16;
+
+    // * Results without a circuit ID (V1-V3) were all issued by the original wallet-ownership circuit
+    pub const LEGACY_CIRCUIT_ID: u32 = 0;
+
+    pub const MAX_SIZE: usize =
+        (4 + Self::MAX_CIRCUITS * CircuitEntry::SIZE) + // circuits
+        1;                                               // bump
+
+    pub fn circuit(&self, circuit_id: u32) -> Option<&CircuitEntry> {
+        self.circuits
+            .iter()
+            .find(|circuit| circuit.circuit_id == circuit_id)
+    }
+
+    /// * Circuit must be registered and not deprecated
+    pub fn require_active(&self, circuit_id: u32) -> Result<&CircuitEntry> {
+        let circuit = self
+            .circuit(circuit_id)
+            .ok_or(VeiledError::CircuitNotRegistered)?;
+        require!(!circuit.deprecated, VeiledError::CircuitDeprecated);
+        Ok(circuit)
+    }
+}
@@ -0,0 +1,162 @@
+// * Protocol configuration state
+// * Singleton account holding admin authority and protocol-wide settings
+
+use crate::genesis;
+use anchor_lang::prelude::*;
+
+#[account]
+pub struct ProtocolConfig {
+    /// * Admin key allowed to change protocol settings
+    pub authority: Pubkey,
+
+    /// * SPL mints accepted for fee and subscription payments, with their USD price feeds
+    pub accepted_mints: Vec<AcceptedMint>,
+
+    /// * Fee quoted in micro-USD (6 decimals); converted to the paying mint at payment time
+    pub fee_usd_micros: u64,
+
+    /// * Oldest oracle price (seconds since publish) accepted for a payment
+    pub max_price_age_secs: u64,
+
+    /// * Widest oracle confidence interval accepted, in basis points of the price
+    pub max_price_conf_bps: u16,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+/// * Mint accepted for payments and the Pyth feed quoting it in USD
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug)]
+pub struct AcceptedMint {
+    pub mint: Pubkey,
+    pub price_feed_id: [u8; 32],
+}
+
+impl AcceptedMint {
+    pub const SIZE: usize = 32 + 32;
+}
+
+impl ProtocolConfig {
+    pub const MAX_ACCEPTED_MINTS: usize = 8;
+
+    // * Defaults applied at initialization (see genesis.rs)
+    pub const DEFAULT_MAX_PRICE_AGE_SECS: u64 = genesis::DEFAULT_MAX_PRICE_AGE_SECS;
+    pub const DEFAULT_MAX_PRICE_CONF_BPS: u16 = genesis::DEFAULT_MAX_PRICE_CONF_BPS;
+
+    pub const MAX_SIZE: usize =
+        32 +                                                  // authority
+        (4 + Self::MAX_ACCEPTED_MINTS * AcceptedMint::SIZE) + // accepted_mints
+        8 +                                                   // fee_usd_micros
+        8 +                                                   // max_price_age_secs
+        2 +                                                   // max_price_conf_bps
+        1;                                                    // bump
+
+    pub fn accepted_mint(&self, mint: &Pubkey) -> Option<&AcceptedMint> {
+        self.accepted_mints
+            .iter()
+            .find(|accepted| &accepted.mint == mint)
+    }
+
+    pub fn is_accepted_mint(&self, mint: &Pubkey) -> bool {
+        self.accepted_mint(mint).is_some()
+    }
+}
+
+/// * Staleness window for verification results, per assurance tier
+/// * Domains pick a tier (DomainConfig::assurance_tier); tiers the table doesn't define, and
+/// * deployments without a policy, use DEFAULT_MAX_RESULT_AGE_SECS
+/// * PDA: [b"assurance_policy"]
+#[account]
+pub struct AssurancePolicy {
+    /// * Oldest verification result (seconds since it was signed) accepted, indexed by tier
+    pub max_result_age_secs: Vec<u32>,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl AssurancePolicy {
+    pub const MAX_TIERS: usize = 8;
+
+    /// * Window for tiers without an entry (the fixed window verify_auth used before tiers)
+    pub const DEFAULT_MAX_RESULT_AGE_SECS: u32 = genesis::DEFAULT_MAX_RESULT_AGE_SECS;
+
+    /// * Widest window a tier may set
+    pub const MAX_RESULT_AGE_SECS: u32 = 60 * 60;
+
+    pub const MAX_SIZE: usize =
+        (4 + Self::MAX_TIERS * 4) + // max_result_age_secs
+        1;                          // bump
+
+    /// * Staleness window for `tier`, falling back to the default when `policy` doesn't set one
+    pub fn max_result_age(policy: Option<&Self>, tier: u8) -> i64 {
+        let max_age = policy
+            .and_then(|policy| policy.max_result_age_secs.get(usize::from(tier)))
+            .copied()
+            .unwrap_or(Self::DEFAULT_MAX_RESULT_AGE_SECS);
+        i64::from(max_age)
+    }
+}
+
+/// * Share of domain-attributed fees (pay_domain_fee) paid into the domain's fee vault
+/// * Deployments without a policy keep every fee in the treasury
+/// * PDA: [b"fee_share_policy"]
+#[account]
+pub struct FeeSharePolicy {
+    /// * Domain's cut of each domain fee, in basis points
+    pub domain_share_bps: u16,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl FeeSharePolicy {
+    /// * Highest share the admin can give domains; the protocol keeps at least half
+    pub const MAX_DOMAIN_SHARE_BPS: u16 = 5_000;
+
+    pub const MAX_SIZE: usize =
+        2 + // domain_share_bps
+        1;  // bump
+
+    /// * Split `amount` into (protocol, domain) portions, rounding the domain's share down
+    pub fn split(policy: Option<&Self>, amount: u64) -> (u64, u64) {
+        let share_bps = policy.map_or(0, |policy| policy.domain_share_bps);
+        let domain_amount = (u128::from(amount) * u128::from(share_bps) / 10_000) as u64;
+        (amount - domain_amount, domain_amount)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_result_age() {
+        let policy = AssurancePolicy {
+            max_result_age_secs: vec![600, 60],
+            bump: 255,
+        };
+
+        assert_eq!(AssurancePolicy::max_result_age(Some(&policy), 0), 600);
+        assert_eq!(AssurancePolicy::max_result_age(Some(&policy), 1), 60);
+
+        // * Undefined tiers and deployments without a policy keep the default window
+        assert_eq!(AssurancePolicy::max_result_age(Some(&policy), 2), 300);
+        assert_eq!(AssurancePolicy::max_result_age(None, 1), 300);
+    }
+
+    #[test]
+    fn test_fee_share_split() {
+        let policy = FeeSharePolicy {
+            domain_share_bps: 2_500,
+            bump: 255,
+        };
+
+        assert_eq!(FeeSharePolicy::split(Some(&policy), 1_000), (750, 250));
+        assert_eq!(FeeSharePolicy::split(Some(&policy), 3), (3, 0));
+        assert_eq!(FeeSharePolicy::split(Some(&policy), u64::MAX).1, u64::MAX / 4);
+
+        // * Without a policy the treasury keeps everything
+        assert_eq!(FeeSharePolicy::split(None, 1_000), (1_000, 0));
+    }
+}
@@ -0,0 +1,49 @@
+// * Verifiable credential state
+// * On-chain status registry for externally issued W3C Verifiable Credentials: the issuer
+// * anchors a credential's hash to a nullifier and keeps its status current, so verifiers
+// * can check it without the credential (or the holder's wallet) ever touching the chain
+
+use anchor_lang::prelude::*;
+
+/// * Hash of one issued credential, bound to the holder's nullifier
+/// * PDA: [b"vc_commitment", issuer, vc_hash]
+#[account]
+pub struct VcCommitment {
+    /// * Holder's nullifier (anonymous ID)
+    pub nullifier: [u8; 32],
+
+    /// * Issuer key; only it can change the status
+    pub issuer: Pubkey,
+
+    /// * SHA256 of the credential (e.g. of its canonicalized JSON-LD / JWT)
+    pub vc_hash: [u8; 32],
+
+    pub status: VcStatus,
+
+    pub issued_at: i64,
+
+    /// * Last status change (issued_at until the first one)
+    pub updated_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+/// * Credential status, mirroring the W3C status list purposes
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum VcStatus {
+    Active,
+    Suspended, // * Temporarily invalid; the issuer can reactivate it
+    Revoked,   // * Permanently invalid
+}
+
+impl VcCommitment {
+    pub const MAX_SIZE: usize =
+        32 + // nullifier
+        32 + // issuer
+        32 + // vc_hash
+        1 +  // status
+        8 +  // issued_at
+        8 +  // updated_at
+        1;   // bump
+}
@@ -0,0 +1,341 @@
+// * Domain config state
+// * Per-domain settings managed by the domain owner
+
+use anchor_lang::prelude::*;
+
+use crate::errors::VeiledError;
+use crate::state::config::AssurancePolicy;
+use crate::state::expiry::{ExpiryKind, ExpiryUnit};
+use crate::state::permission::PermissionGrant;
+use crate::state::verification_result::ClaimType;
+use crate::NullifierAccount;
+
+#[account]
+pub struct DomainConfig {
+    /// * Null-padded domain (same encoding as the verify_auth `domain` argument)
+    pub domain: [u8; 32],
+
+    /// * Key allowed to change this domain's settings and assign its roles
+    /// * `.sol` domains go to the owner of their SNS name (see sns.rs); ownership of other
+    /// * domains isn't proven yet - the first signer to create the config owns it
+    pub owner: Pubkey,
+
+    /// * Verification key hash every result for this domain must carry (zero = not pinned)
+    pub pinned_vk_hash: [u8; 32],
+
+    /// * Keys allowed to sign this domain's results, replacing the global VerifierRegistry
+    /// * Empty = results must come from a registered verifier submitting them itself
+    pub verifiers: Vec<Pubkey>,
+
+    /// * PDA bump
+    pub bump: u8,
+
+    /// * Optimistic verification: sessions stay pending (and challengeable) this long (0 = off)
+    pub challenge_window_secs: i64,
+
+    /// * Verifications one fee payer may submit per rate-limit window (0 = protocol default)
+    pub rate_limit: u32,
+
+    /// * AssurancePolicy tier setting how fresh this domain's results must be (0 = default tier)
+    pub assurance_tier: u8,
+
+    /// * Domain's own result staleness window, replacing its tier's (0 = use the tier)
+    /// * For slow relays and offline signing flows
+    pub max_result_age_secs: u32,
+
+    /// * Delegated key that may rotate the domain's verifiers and pause it (default = none)
+    pub operator: Pubkey,
+
+    /// * Delegated key that may only withdraw the domain's fee share (default = none)
+    pub treasurer: Pubkey,
+
+    /// * Paused domains accept no new verifications (owner or operator)
+    pub paused: bool,
+
+    /// * SHA256 hashes of the web origins / mobile bundle ids allowed to start auth for this
+    /// * domain (empty = any); verifications must name one (see verify_auth_with_origin)
+    pub allowed_origins: Vec<[u8; 32]>,
+
+    /// * Shortest session a verification may ask for with `expires_in` (0 = protocol minimum)
+    pub min_session_secs: i64,
+
+    /// * Longest session a verification may ask for with `expires_in` (0 = protocol maximum)
+    pub max_session_secs: i64,
+
+    /// * Claim types (ClaimType::bit) this domain only accepts from registered, active attestors
+    /// * vetted for them (see register_attestor); 0 = any accepted verifier
+    pub attestor_claim_mask: u8,
+
+    /// * Sessions registered for this domain end once idle this long (0 = no idle timeout)
+    /// * touch_session resets the clock
+    pub idle_timeout_secs: i64,
+
+    /// * Where verifications register nullifiers (see set_domain_nullifier_store)
+    pub nullifier_store: NullifierStore,
+
+    /// * Most permissions one grant_permissions call may give an app for this domain
+    /// * (0 = the protocol limit)
+    pub max_permissions: u8,
+
+    /// * Unit the domain's sessions expire in; lifetimes stay in seconds and are converted
+    /// * to slots at ExpiryKind::MS_PER_SLOT for Slot domains (see set_domain_expiry_unit)
+    pub session_expiry_unit: ExpiryUnit,
+
+    /// * Lifetime of sessions that don't ask for one (0 = the longest allowed); kept within
+    /// * the session bounds
+    pub default_session_secs: i64,
+}
+
+/// * Keys a domain owner can assign with set_domain_role
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DomainRole {
+    Owner,     // * Transfers ownership; can't be cleared
+    Operator,  // * Verifier rotation and pausing
+    Treasurer, // * Fee share withdrawals
+}
+
+/// * Where a domain's verifications register their nullifiers
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NullifierStore {
+    Accounts,   // * A NullifierAccount session per nullifier (verify_auth and friends)
+    Bitmap,     // * A bit in a NullifierShard, permanent (verify_auth_sharded)
+    Compressed, // * A leaf in the domain's NullifierTree, permanent (verify_auth_compressed)
+}
+
+impl DomainConfig {
+    pub const MAX_VERIFIERS: usize = 4;
+    pub const MAX_ORIGINS: usize = 8;
+    pub const MAX_CHALLENGE_WINDOW_SECS: i64 = 7 * 24 * 60 * 60;
+
+    pub const MAX_SIZE: usize =
+        32 +                              // domain
+        32 +                              // owner
+        32 +                              // pinned_vk_hash
+        (4 + Self::MAX_VERIFIERS * 32) + // verifiers
+        1 +                               // bump
+        8 +                               // challenge_window_secs
+        4 +                               // rate_limit
+        1 +                               // assurance_tier
+        4 +                               // max_result_age_secs
+        32 +                              // operator
+        32 +                              // treasurer
+        1 +                               // paused
+        (4 + Self::MAX_ORIGINS * 32) +    // allowed_origins
+        8 +                               // min_session_secs
+        8 +                               // max_session_secs
+        1 +                               // attestor_claim_mask
+        8 +                               // idle_timeout_secs
+        1 +                               // nullifier_store
+        1 +                               // max_permissions
+        1 +                               // session_expiry_unit
+        8;                                // default_session_secs
+
+    /// * Longest domain in bytes, the width of its null-padded encoding
+    pub const MAX_DOMAIN_LEN: usize = 32;
+
+    /// * Canonical domain form: 1 to MAX_DOMAIN_LEN lowercase ASCII letters, digits, '-' and
+    /// * '.', with no empty labels, so `App.example` and `app.example` can't name different
+    /// * sessions or configs
+    pub fn is_canonical(domain: &str) -> bool {
+        let allowed =
+            |b: u8| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-' || b == b'.';
+        (1..=Self::MAX_DOMAIN_LEN).contains(&domain.len())
+            && domain.bytes().all(allowed)
+            && domain.split('.').all(|label| !label.is_empty())
+    }
+
+    /// * Null-padded canonical form of a domain as users type it (ASCII case folded); the
+    /// * SDK's normalizeDomain applies the same rules
+    pub fn normalize(domain: &str) -> Result<[u8; 32]> {
+        let domain = domain.to_ascii_lowercase();
+        require!(Self::is_canonical(&domain), VeiledError::InvalidDomain);
+        let mut padded = [0u8; 32];
+        padded[..domain.len()].copy_from_slice(domain.as_bytes());
+        Ok(padded)
+    }
+
+    /// * Domain a null-padded argument encodes; verify_auth and the registry only accept
+    /// * canonical ones (see is_canonical)
+    pub fn validate_domain(domain: &[u8; 32]) -> Result<&str> {
+        let len = domain.iter().position(|&b| b == 0).unwrap_or(32);
+        let domain_str =
+            core::str::from_utf8(&domain[..len]).map_err(|_| VeiledError::InvalidDomain)?;
+        require!(
+            domain[len..].iter().all(|&b| b == 0) && Self::is_canonical(domain_str),
+            VeiledError::InvalidDomain
+        );
+        Ok(domain_str)
+    }
+
+    /// * Second PDA seed: sha256 of the null-padded domain, the hash sessions are keyed by
+    /// * PDA: [b"domain_config", seed(domain)]
+    pub fn seed(domain: &[u8; 32]) -> [u8; 32] {
+        NullifierAccount::domain_hash(domain)
+    }
+
+    /// * Config for the DomainConfig PDA passed in `info`, if the domain created one
+    pub fn load(info: &AccountInfo) -> Result<Option<Self>> {
+        super::load_optional::<DomainConfig>(info)
+    }
+
+    /// * A pinned domain only accepts results that carry (V5+) the pinned key hash
+    pub fn check_vk_hash(&self, vk_hash: Option<&[u8; 32]>) -> Result<()> {
+        if self.pinned_vk_hash == [0u8; 32] {
+            return Ok(());
+        }
+
+        let vk_hash = vk_hash.ok_or(VeiledError::VkHashRequired)?;
+        require!(
+            vk_hash == &self.pinned_vk_hash,
+            VeiledError::VkHashMismatch
+        );
+        Ok(())
+    }
+
+    /// * A domain with registered origins only accepts verifications naming one of them, so a
+    /// * phishing frontend can't collect sessions under the domain. Flows that carry no origin
+    /// * (None) are refused for such domains
+    pub fn check_origin(&self, origin_hash: Option<&[u8; 32]>) -> Result<()> {
+        if self.allowed_origins.is_empty() {
+            return Ok(());
+        }
+
+        require!(
+            origin_hash.is_some_and(|origin| self.allowed_origins.contains(origin)),
+            VeiledError::OriginNotAllowed
+        );
+        Ok(())
+    }
+
+    /// * Oldest result (seconds) this domain accepts: its own window, else its tier's
+    pub fn max_result_age(&self, policy: Option<&AssurancePolicy>) -> i64 {
+        if self.max_result_age_secs > 0 {
+            i64::from(self.max_result_age_secs)
+        } else {
+            AssurancePolicy::max_result_age(policy, self.assurance_tier)
+        }
+    }
+
+    /// * (shortest, longest) session lifetime this domain allows, in seconds
+    pub fn session_bounds(&self) -> (i64, i64) {
+        let or = |secs: i64, protocol: i64| if secs > 0 { secs } else { protocol };
+        (
+            or(self.min_session_secs, NullifierAccount::MIN_EXPIRY_SECONDS),
+            or(self.max_session_secs, NullifierAccount::MAX_EXPIRY_SECONDS),
+        )
+    }
+
+    /// * Lifetime (seconds) of a session registered on a domain with `config`: `expires_in`
+    /// * when it's within the domain's bounds; when it's 0, the domain's default, else the
+    /// * longest allowed
+    pub fn session_expiry(config: Option<&Self>, expires_in: i64) -> Result<i64> {
+        let (min, max) = config.map_or(
+            (
+                NullifierAccount::MIN_EXPIRY_SECONDS,
+                NullifierAccount::MAX_EXPIRY_SECONDS,
+            ),
+            Self::session_bounds,
+        );
+        if expires_in == 0 {
+            let default = config.map_or(0, |config| config.default_session_secs);
+            return Ok(if default > 0 { default.clamp(min, max) } else { max });
+        }
+
+        require!(
+            (min..=max).contains(&expires_in),
+            VeiledError::SessionExpiryOutOfBounds
+        );
+        Ok(expires_in)
+    }
+
+    /// * Expiry of a session registered now on a domain with `config`, asking for `expires_in`
+    /// * seconds (see session_expiry), in the domain's unit
+    pub fn session_expires_at(
+        config: Option<&Self>,
+        expires_in: i64,
+        clock: &Clock,
+    ) -> Result<ExpiryKind> {
+        let secs = Self::session_expiry(config, expires_in)?;
+        let unit = config.map_or(ExpiryUnit::UnixTime, |config| config.session_expiry_unit);
+        Ok(ExpiryKind::after_secs(unit, secs, clock))
+    }
+
+    /// * Most permissions one grant may carry for a domain with `config`
+    pub fn permission_limit(config: Option<&Self>) -> usize {
+        match config.map_or(0, |config| config.max_permissions) {
+            0 => PermissionGrant::MAX_PERMISSIONS,
+            limit => usize::from(limit),
+        }
+    }
+
+    /// * Idle timeout for sessions registered with `config` (0 = none)
+    pub fn idle_timeout(config: Option<&Self>) -> i64 {
+        config.map_or(0, |config| config.idle_timeout_secs)
+    }
+
+    /// * Owner, or the operator when one is assigned
+    pub fn is_operator(&self, key: &Pubkey) -> bool {
+        key == &self.owner || (self.operator != Pubkey::default() && key == &self.operator)
+    }
+
+    /// * Owner, or the treasurer when one is assigned
+    pub fn is_treasurer(&self, key: &Pubkey) -> bool {
+        key == &self.owner || (self.treasurer != Pubkey::default() && key == &self.treasurer)
+    }
+
+    pub fn require_not_paused(&self) -> Result<()> {
+        require!(!self.paused, VeiledError::DomainPaused);
+        Ok(())
+    }
+
+    /// * Verifications register through `store`'s instructions only, so a nullifier can't
+    /// * register once in each store
+    pub fn require_nullifier_store(&self, store: NullifierStore) -> Result<()> {
+        require!(
+            self.nullifier_store == store,
+            VeiledError::WrongNullifierStore
+        );
+        Ok(())
+    }
+
+    /// * Whether `claim_type` must come from a registered attestor (see Attestor::check_issuer)
+    pub fn requires_attestor(&self, claim_type: ClaimType) -> bool {
+        self.attestor_claim_mask & claim_type.bit() != 0
+    }
+
+    /// * The domain's own verifier keys, if it designated any
+    pub fn designated_verifiers(&self) -> Option<&[Pubkey]> {
+        (!self.verifiers.is_empty()).then_some(self.verifiers.as_slice())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_folds_case() {
+        let domain = DomainConfig::normalize("App.Example").unwrap();
+        assert_eq!(domain, DomainConfig::normalize("app.example").unwrap());
+        assert_eq!(
+            DomainConfig::validate_domain(&domain).unwrap(),
+            "app.example"
+        );
+    }
+
+    #[test]
+    fn test_validate_domain_rejects_non_canonical() {
+        let padded = |domain: &[u8]| {
+            let mut padded = [0u8; 32];
+            padded[..domain.len()].copy_from_slice(domain);
+            padded
+        };
+        assert!(DomainConfig::validate_domain(&padded(b"App.example")).is_err());
+        assert!(DomainConfig::validate_domain(&padded(b"app_example")).is_err());
+        assert!(DomainConfig::validate_domain(&padded(b"app..example")).is_err());
+        assert!(DomainConfig::validate_domain(&padded(b"app\0example")).is_err());
+        assert!(DomainConfig::validate_domain(&padded(b"")).is_err());
+        assert!(DomainConfig::validate_domain(&[b'a'; 32]).is_ok());
+        assert!(DomainConfig::normalize(&"a".repeat(33)).is_err());
+    }
+}
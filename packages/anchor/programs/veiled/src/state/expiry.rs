@@ -0,0 +1,117 @@
+// * Expiry representation shared by sessions and permission grants
+// * Domains choose whether they trust wall-clock time or slot height
+
+use anchor_lang::prelude::*;
+
+use crate::errors::VeiledError;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ExpiryKind {
+    /// * Expires once Clock::unix_timestamp reaches this value
+    UnixTime(i64),
+
+    /// * Expires once Clock::slot reaches this value
+    /// * Immune to unix_timestamp drift on degraded clusters
+    Slot(u64),
+}
+
+/// * Unit a domain's sessions expire in (see DomainConfig::session_expiry_unit)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ExpiryUnit {
+    UnixTime, // * Clock::unix_timestamp
+    Slot,     // * Clock::slot, for domains that don't trust wall-clock time
+}
+
+impl ExpiryKind {
+    pub const SIZE: usize = 1 + 8; // * enum tag + i64/u64 payload
+
+    /// * Nominal slot time, used to turn a lifetime in seconds into slots
+    pub const MS_PER_SLOT: u64 = 400;
+
+    /// * Absolute expiry `secs` seconds from now, in `unit`
+    pub fn after_secs(unit: ExpiryUnit, secs: i64, clock: &Clock) -> Self {
+        match unit {
+            ExpiryUnit::UnixTime => ExpiryKind::UnixTime(clock.unix_timestamp.saturating_add(secs)),
+            ExpiryUnit::Slot => {
+                let slots =
+                    u64::try_from(secs).unwrap_or(0).saturating_mul(1_000) / Self::MS_PER_SLOT;
+                ExpiryKind::Slot(clock.slot.saturating_add(slots))
+            }
+        }
+    }
+
+    /// * The later of two expiries; one in a different unit from `self` replaces it
+    pub fn extend(self, other: ExpiryKind) -> Self {
+        match (self, other) {
+            (ExpiryKind::UnixTime(current), ExpiryKind::UnixTime(new)) => {
+                ExpiryKind::UnixTime(current.max(new))
+            }
+            (ExpiryKind::Slot(current), ExpiryKind::Slot(new)) => {
+                ExpiryKind::Slot(current.max(new))
+            }
+            (_, new) => new,
+        }
+    }
+
+    /// * Resolve a relative duration (seconds for UnixTime, slots for Slot)
+    /// * into an absolute expiry measured from the current clock
+    pub fn from_duration(duration: ExpiryKind, clock: &Clock) -> Result<Self> {
+        match duration {
+            ExpiryKind::UnixTime(seconds) => {
+                require!(seconds > 0, VeiledError::InvalidExpiry);
+                let expires_at = clock
+                    .unix_timestamp
+                    .checked_add(seconds)
+                    .ok_or(VeiledError::InvalidExpiry)?;
+                Ok(ExpiryKind::UnixTime(expires_at))
+            }
+            ExpiryKind::Slot(slots) => {
+                require!(slots > 0, VeiledError::InvalidExpiry);
+                let expires_at = clock
+                    .slot
+                    .checked_add(slots)
+                    .ok_or(VeiledError::InvalidExpiry)?;
+                Ok(ExpiryKind::Slot(expires_at))
+            }
+        }
+    }
+
+    /// * Check expiry against the unit this value was issued in
+    pub fn has_expired(&self, clock: &Clock) -> bool {
+        match *self {
+            ExpiryKind::UnixTime(expires_at) => clock.unix_timestamp >= expires_at,
+            ExpiryKind::Slot(expires_at) => clock.slot >= expires_at,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_after_secs_honors_the_unit() {
+        let clock = Clock {
+            slot: 1_000,
+            unix_timestamp: 1_700_000_000,
+            ..Clock::default()
+        };
+
+        assert_eq!(
+            ExpiryKind::after_secs(ExpiryUnit::UnixTime, 3_600, &clock),
+            ExpiryKind::UnixTime(1_700_003_600)
+        );
+        assert_eq!(
+            ExpiryKind::after_secs(ExpiryUnit::Slot, 3_600, &clock),
+            ExpiryKind::Slot(1_000 + 9_000)
+        );
+        assert_eq!(
+            ExpiryKind::Slot(5).extend(ExpiryKind::Slot(3)),
+            ExpiryKind::Slot(5)
+        );
+        assert_eq!(
+            ExpiryKind::UnixTime(5).extend(ExpiryKind::Slot(3)),
+            ExpiryKind::Slot(3)
+        );
+    }
+}
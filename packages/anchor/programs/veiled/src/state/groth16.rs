@@ -0,0 +1,54 @@
+// * Groth16 verifying key state
+// * On-chain verifying key for a registered circuit that verify_auth_groth16 checks proofs against
+
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+/// * Verifying key for one circuit, points EIP-197 encoded (see groth16.rs)
+/// * PDA: [b"groth16_vk", circuit_id (u32 LE)]
+#[account]
+pub struct Groth16VerifyingKey {
+    /// * Registered circuit this key verifies
+    pub circuit_id: u32,
+
+    pub alpha_g1: [u8; 64],
+    pub beta_g2: [u8; 128],
+    pub gamma_g2: [u8; 128],
+    pub delta_g2: [u8; 128],
+
+    /// * One G1 point per public input, plus the constant term first
+    pub ic: Vec<[u8; 64]>,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl Groth16VerifyingKey {
+    /// * Keeps the key (and register_groth16_vk's arguments) inside one transaction and the
+    /// * vk_x multi-scalar multiplication well inside the compute budget
+    pub const MAX_PUBLIC_INPUTS: usize = 4;
+
+    /// * Public inputs every circuit must start with: the nullifier, then the domain
+    pub const MIN_PUBLIC_INPUTS: usize = 2;
+
+    pub const MAX_SIZE: usize =
+        4 +                                        // circuit_id
+        64 +                                       // alpha_g1
+        128 +                                      // beta_g2
+        128 +                                      // gamma_g2
+        128 +                                      // delta_g2
+        (4 + (Self::MAX_PUBLIC_INPUTS + 1) * 64) + // ic
+        1;                                         // bump
+
+    /// * SHA256 over alpha || beta || gamma || delta || ic; must match the circuit's registered vk_hash
+    pub fn hash(&self) -> [u8; 32] {
+        let mut parts: Vec<&[u8]> = vec![
+            &self.alpha_g1,
+            &self.beta_g2,
+            &self.gamma_g2,
+            &self.delta_g2,
+        ];
+        parts.extend(self.ic.iter().map(|point| point.as_slice()));
+        hashv(&parts).to_bytes()
+    }
+}
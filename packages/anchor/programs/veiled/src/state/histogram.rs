@@ -0,0 +1,82 @@
+// * Grant expiry histogram state
+// * Per-domain counts of the grant durations users actually choose, so governance can tune
+// * default / maximum expiry policy from on-chain data alone
+// * Keyed like DomainConfig: [b"expiry_histogram", sha256(domain)] (see DomainConfig::seed)
+
+use anchor_lang::prelude::*;
+
+use super::expiry::ExpiryKind;
+
+#[account]
+pub struct ExpiryHistogram {
+    /// * Null-padded domain whose grants are counted (grant_permissions' `domain`)
+    pub domain: [u8; 32],
+
+    /// * Grant counts per duration bucket (see BUCKET_UPPER_BOUNDS_SECS)
+    pub buckets: [u64; ExpiryHistogram::BUCKETS],
+
+    /// * How many of the counted grants were slot-based
+    pub slot_grants: u64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl ExpiryHistogram {
+    // * Inclusive upper bounds: 1 hour, 1 day, 7 days, 30 days, 90 days, 365 days
+    // * The last bucket counts everything longer
+    pub const BUCKET_UPPER_BOUNDS_SECS: [i64; 6] =
+        [3_600, 86_400, 604_800, 2_592_000, 7_776_000, 31_536_000];
+    pub const BUCKETS: usize = Self::BUCKET_UPPER_BOUNDS_SECS.len() + 1;
+
+    // * Slot durations are bucketed at the target slot time (400ms)
+    pub const SLOT_MS: i64 = 400;
+
+    pub const MAX_SIZE: usize =
+        32 +                 // domain
+        Self::BUCKETS * 8 +  // buckets
+        8 +                  // slot_grants
+        1;                   // bump
+
+    /// * Bucket for a relative grant duration (seconds for UnixTime, slots for Slot)
+    pub fn bucket(duration: ExpiryKind) -> usize {
+        let seconds = match duration {
+            ExpiryKind::UnixTime(seconds) => seconds,
+            ExpiryKind::Slot(slots) => {
+                i64::try_from(slots).unwrap_or(i64::MAX).saturating_mul(Self::SLOT_MS) / 1_000
+            }
+        };
+        Self::BUCKET_UPPER_BOUNDS_SECS
+            .iter()
+            .position(|&upper| seconds <= upper)
+            .unwrap_or(Self::BUCKETS - 1)
+    }
+
+    pub fn record(&mut self, duration: ExpiryKind) {
+        let bucket = &mut self.buckets[Self::bucket(duration)];
+        *bucket = bucket.saturating_add(1);
+        if matches!(duration, ExpiryKind::Slot(_)) {
+            self.slot_grants = self.slot_grants.saturating_add(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_boundaries() {
+        assert_eq!(ExpiryHistogram::bucket(ExpiryKind::UnixTime(1)), 0);
+        assert_eq!(ExpiryHistogram::bucket(ExpiryKind::UnixTime(3_600)), 0);
+        assert_eq!(ExpiryHistogram::bucket(ExpiryKind::UnixTime(3_601)), 1);
+        assert_eq!(ExpiryHistogram::bucket(ExpiryKind::UnixTime(2_592_000)), 3);
+        assert_eq!(ExpiryHistogram::bucket(ExpiryKind::UnixTime(31_536_001)), 6);
+        assert_eq!(ExpiryHistogram::bucket(ExpiryKind::UnixTime(i64::MAX)), 6);
+
+        // * 9,000 slots at 400ms = 1 hour
+        assert_eq!(ExpiryHistogram::bucket(ExpiryKind::Slot(9_000)), 0);
+        assert_eq!(ExpiryHistogram::bucket(ExpiryKind::Slot(9_003)), 1);
+        assert_eq!(ExpiryHistogram::bucket(ExpiryKind::Slot(u64::MAX)), 6);
+    }
+}
@@ -0,0 +1,79 @@
+// * UltraHonk verifying key state
+// * On-chain verifying key for a registered circuit that verification sessions check proofs
+// * against (see honk.rs). Too large for one transaction, so register_honk_vk stores the
+// * header and write_honk_vk fills in the commitments; the key is usable once all are written.
+
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+/// * bb's keccak UltraHonk verifying key; commitments are be(x) || be(y) G1 points
+/// * PDA: [b"honk_vk", circuit_id (u32 LE)]
+#[account]
+pub struct HonkVerifyingKey {
+    /// * Registered circuit this key verifies
+    pub circuit_id: u32,
+
+    /// * log2 of the circuit size (1 to honk::CONST_PROOF_SIZE_LOG_N)
+    pub log_circuit_size: u8,
+
+    /// * Public inputs as bb counts them: the circuit's own plus the pairing point object
+    pub public_inputs_size: u32,
+
+    /// * Row of the first public input in the execution trace
+    pub pub_inputs_offset: u32,
+
+    /// * Selector, permutation, table and Lagrange commitments, in bb's verifying key order
+    pub commitments: [[u8; 64]; HonkVerifyingKey::COMMITMENTS],
+
+    /// * Bit i set once commitment i has been written
+    pub written: u32,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl HonkVerifyingKey {
+    /// * qm, qc, ql, qr, qo, q4, qLookup, qArith, qRange, qElliptic, qAux, qPoseidon2External,
+    /// * qPoseidon2Internal, s1-s4, id1-id4, t1-t4, lagrangeFirst, lagrangeLast
+    pub const COMMITMENTS: usize = 27;
+
+    /// * Keeps write_honk_vk's arguments inside one transaction
+    pub const MAX_COMMITMENTS_PER_WRITE: usize = 14;
+
+    pub const MAX_SIZE: usize =
+        4 +                        // circuit_id
+        1 +                        // log_circuit_size
+        4 +                        // public_inputs_size
+        4 +                        // pub_inputs_offset
+        Self::COMMITMENTS * 64 +   // commitments
+        4 +                        // written
+        1;                         // bump
+
+    const ALL_WRITTEN: u32 = (1 << Self::COMMITMENTS) - 1;
+
+    /// * Every commitment has been written (and the key matched the circuit's vk_hash)
+    pub fn is_complete(&self) -> bool {
+        self.written == Self::ALL_WRITTEN
+    }
+
+    /// * Marks commitments [offset, offset + count) written; true if that completes the key
+    pub fn mark_written(&mut self, offset: usize, count: usize) -> bool {
+        let range = ((1u64 << count) - 1) << offset;
+        self.written |= range as u32 & Self::ALL_WRITTEN;
+        self.is_complete()
+    }
+
+    /// * SHA256 over log_circuit_size || public_inputs_size (u32 LE) || pub_inputs_offset
+    /// * (u32 LE) || commitments; must match the circuit's registered vk_hash
+    pub fn hash(&self) -> [u8; 32] {
+        let header = [
+            [self.log_circuit_size].as_slice(),
+            &self.public_inputs_size.to_le_bytes(),
+            &self.pub_inputs_offset.to_le_bytes(),
+        ]
+        .concat();
+        let mut parts: Vec<&[u8]> = vec![&header];
+        parts.extend(self.commitments.iter().map(|point| point.as_slice()));
+        hashv(&parts).to_bytes()
+    }
+}
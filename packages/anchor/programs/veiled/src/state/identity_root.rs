@@ -0,0 +1,139 @@
+// * Identity root state
+// * Opt-in linking of domain-scoped nullifiers. Nullifiers are unlinkable across domains by
+// * default; a user who wants reputation to carry between apps creates an IdentityRoot under
+// * a key they hold and links nullifiers to it. A link needs both sides: the session's
+// * authority submits it and the root's owner signs an IdentityLink for the nullifier, domain
+// * and linkage proof hash (e.g. the hash of a proof that both derive from one secret, which
+// * apps can check off-chain).
+
+use anchor_lang::prelude::*;
+
+use crate::errors::VeiledError;
+use crate::genesis;
+
+/// * One linked nullifier
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct RootMember {
+    pub nullifier: [u8; 32],
+    pub domain: [u8; 32],
+    pub linkage_proof_hash: [u8; 32],
+    pub linked_at: i64,
+}
+
+impl RootMember {
+    pub const SIZE: usize = 32 + 32 + 32 + 8;
+}
+
+/// * Nullifiers a user chose to link
+/// * PDA: [b"identity_root", owner]
+#[account]
+pub struct IdentityRoot {
+    /// * Key that manages the root and signs every link
+    pub owner: Pubkey,
+
+    /// * Linked nullifiers (up to MAX_MEMBERS)
+    pub members: Vec<RootMember>,
+
+    /// * When the root was created
+    pub created_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl IdentityRoot {
+    pub const MAX_MEMBERS: usize = 8;
+
+    pub const MAX_SIZE: usize =
+        32 +                                    // owner
+        4 + RootMember::SIZE * Self::MAX_MEMBERS + // members
+        8 +                                     // created_at
+        1;                                      // bump
+
+    /// * Link a nullifier once
+    pub fn link(&mut self, member: RootMember) -> Result<()> {
+        require!(
+            !self.members.iter().any(|m| m.nullifier == member.nullifier),
+            VeiledError::NullifierAlreadyLinked
+        );
+        require!(
+            self.members.len() < Self::MAX_MEMBERS,
+            VeiledError::IdentityRootFull
+        );
+        self.members.push(member);
+        Ok(())
+    }
+
+    /// * Drop a linked nullifier
+    pub fn unlink(&mut self, nullifier: &[u8; 32]) -> Result<()> {
+        let index = self
+            .members
+            .iter()
+            .position(|m| &m.nullifier == nullifier)
+            .ok_or(VeiledError::NullifierNotLinked)?;
+        self.members.remove(index);
+        Ok(())
+    }
+}
+
+/// * The root owner's consent to one link
+pub struct IdentityLink;
+
+impl IdentityLink {
+    /// * Domain separator, so an owner can't be tricked into signing anything else as a link
+    pub const MESSAGE_PREFIX: &'static [u8] = b"veiled:identity_link:v1";
+
+    /// * MESSAGE_PREFIX || instance_id || root || nullifier || domain || linkage_proof_hash
+    pub const MESSAGE_LEN: usize = Self::MESSAGE_PREFIX.len() + 32 + 32 + 32 + 32 + 32;
+
+    /// * Message the owner signs
+    pub fn message(
+        root: &Pubkey,
+        nullifier: &[u8; 32],
+        domain: &[u8; 32],
+        linkage_proof_hash: &[u8; 32],
+    ) -> [u8; Self::MESSAGE_LEN] {
+        let mut message = [0u8; Self::MESSAGE_LEN];
+        let prefix = Self::MESSAGE_PREFIX.len();
+        message[..prefix].copy_from_slice(Self::MESSAGE_PREFIX);
+        message[prefix..prefix + 32].copy_from_slice(&genesis::INSTANCE_ID);
+        message[prefix + 32..prefix + 64].copy_from_slice(root.as_ref());
+        message[prefix + 64..prefix + 96].copy_from_slice(nullifier);
+        message[prefix + 96..prefix + 128].copy_from_slice(domain);
+        message[prefix + 128..].copy_from_slice(linkage_proof_hash);
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn member(nullifier: u8) -> RootMember {
+        RootMember {
+            nullifier: [nullifier; 32],
+            domain: [0u8; 32],
+            linkage_proof_hash: [0u8; 32],
+            linked_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_link_and_unlink() {
+        let mut root = IdentityRoot {
+            owner: Pubkey::new_unique(),
+            members: vec![],
+            created_at: 0,
+            bump: 255,
+        };
+        for nullifier in 0..IdentityRoot::MAX_MEMBERS as u8 {
+            root.link(member(nullifier)).unwrap();
+        }
+        assert!(root.link(member(0)).is_err());
+        assert!(root.link(member(100)).is_err());
+
+        root.unlink(&[0u8; 32]).unwrap();
+        assert!(root.unlink(&[0u8; 32]).is_err());
+        assert!(root.link(member(100)).is_ok());
+    }
+}
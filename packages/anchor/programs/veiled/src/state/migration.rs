@@ -0,0 +1,202 @@
+// * Migration state
+// * Program-wide coordinator for multi-step account migrations. The admin opens a window from the
+// * current state version to the next (begin_migration), listing the account migrations it needs.
+// * While the window is open, instructions accept accounts on either version and every migrated
+// * account is counted; complete_migration only switches over once each required step reaches
+// * 100% or the deadline passes.
+// *
+// * Accounts don't store their version: a migration's migrate_* instructions, shipped with the
+// * layout change, tell the layouts apart and call record_migrated for each account they upgrade.
+
+use crate::errors::VeiledError;
+use anchor_lang::prelude::*;
+
+/// * Account type a migration step upgrades
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum MigratedAccount {
+    Session,
+    ProofRecord,
+    PermissionGrant,
+    DomainConfig,
+}
+
+/// * One account type's share of a migration
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct MigrationStep {
+    pub account: MigratedAccount,
+
+    /// * Whether complete_migration waits for this step (until the deadline)
+    pub required: bool,
+
+    /// * Accounts to migrate, counted off-chain when the migration begins
+    pub total: u64,
+
+    /// * Accounts migrated so far; zero when the migration begins
+    pub migrated: u64,
+}
+
+impl MigrationStep {
+    pub const MAX_SIZE: usize =
+        1 + // account
+        1 + // required
+        8 + // total
+        8;  // migrated
+
+    pub fn is_done(&self) -> bool {
+        self.migrated >= self.total
+    }
+}
+
+/// * Program-wide migration lifecycle
+/// * PDA: [b"migration_state"]
+#[account]
+pub struct MigrationState {
+    /// * State version accounts are on outside a migration (0 before the first one)
+    pub version: u16,
+
+    /// * Version being migrated to; equals `version` while no migration is open
+    pub target_version: u16,
+
+    /// * Open migration's steps; empty outside one
+    pub steps: Vec<MigrationStep>,
+
+    /// * Unix timestamp the open migration began at
+    pub started_at: i64,
+
+    /// * Unix timestamp after which complete_migration no longer waits for required steps
+    pub deadline: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl MigrationState {
+    pub const MAX_STEPS: usize = 4;
+
+    pub const MAX_SIZE: usize =
+        2 +                                               // version
+        2 +                                               // target_version
+        (4 + Self::MAX_STEPS * MigrationStep::MAX_SIZE) + // steps
+        8 +                                               // started_at
+        8 +                                               // deadline
+        1;                                                // bump
+
+    pub fn in_progress(&self) -> bool {
+        self.target_version != self.version
+    }
+
+    /// * Whether an account on `version` is usable: the current version, or during a migration
+    /// * also the one being migrated to
+    pub fn accepts(&self, version: u16) -> bool {
+        version == self.version || (self.in_progress() && version == self.target_version)
+    }
+
+    pub fn require_accepts(&self, version: u16) -> Result<()> {
+        require!(
+            self.accepts(version),
+            VeiledError::UnsupportedAccountVersion
+        );
+        Ok(())
+    }
+
+    /// * Whether every required step has migrated all of its accounts
+    pub fn required_steps_done(&self) -> bool {
+        self.steps
+            .iter()
+            .filter(|step| step.required)
+            .all(MigrationStep::is_done)
+    }
+
+    /// * Whether complete_migration may switch over at `now`
+    pub fn can_complete(&self, now: i64) -> bool {
+        self.in_progress() && (self.required_steps_done() || now >= self.deadline)
+    }
+
+    /// * Count `count` accounts upgraded by `account`'s step; returns the event to emit
+    pub fn record_migrated(
+        &mut self,
+        account: MigratedAccount,
+        count: u64,
+    ) -> Result<MigrationProgressEvent> {
+        require!(self.in_progress(), VeiledError::NoMigrationInProgress);
+        let step = self
+            .steps
+            .iter_mut()
+            .find(|step| step.account == account)
+            .ok_or(VeiledError::UnknownMigrationStep)?;
+        step.migrated = step.migrated.saturating_add(count);
+
+        Ok(MigrationProgressEvent {
+            target_version: self.target_version,
+            account,
+            migrated: step.migrated,
+            total: step.total,
+        })
+    }
+}
+
+/// * A migrate_* instruction upgraded accounts; emitted with each step's running counters
+#[event]
+pub struct MigrationProgressEvent {
+    pub target_version: u16,
+    pub account: MigratedAccount,
+    pub migrated: u64,
+    pub total: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn migrating(steps: Vec<MigrationStep>) -> MigrationState {
+        MigrationState {
+            version: 1,
+            target_version: 2,
+            steps,
+            started_at: 100,
+            deadline: 200,
+            bump: 255,
+        }
+    }
+
+    fn step(account: MigratedAccount, required: bool, total: u64) -> MigrationStep {
+        MigrationStep {
+            account,
+            required,
+            total,
+            migrated: 0,
+        }
+    }
+
+    #[test]
+    fn test_accepts_both_versions_only_during_migration() {
+        let mut state = migrating(vec![]);
+        assert!(state.accepts(1));
+        assert!(state.accepts(2));
+        assert!(!state.accepts(3));
+
+        // * Completed: only the new version
+        state.version = 2;
+        assert!(!state.accepts(1));
+        assert!(state.accepts(2));
+    }
+
+    #[test]
+    fn test_completion_waits_for_required_steps_or_deadline() {
+        let mut state = migrating(vec![
+            step(MigratedAccount::Session, true, 2),
+            step(MigratedAccount::PermissionGrant, false, 5),
+        ]);
+        assert!(!state.can_complete(150));
+        assert!(state.can_complete(200));
+
+        let progress = state.record_migrated(MigratedAccount::Session, 2).unwrap();
+        assert_eq!((progress.migrated, progress.total), (2, 2));
+
+        // * Optional steps don't hold completion up
+        assert!(state.can_complete(150));
+        assert!(state
+            .record_migrated(MigratedAccount::DomainConfig, 1)
+            .is_err());
+    }
+}
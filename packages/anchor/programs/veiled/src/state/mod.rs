@@ -1,2 +1,49 @@
 // * State modules
+pub mod alias;
+pub mod attestation;
+pub mod attestor;
+pub mod auditor;
+pub mod ban;
+pub mod circuit;
+pub mod config;
+pub mod credential;
+pub mod domain;
+pub mod expiry;
+pub mod groth16;
+pub mod honk;
+pub mod histogram;
+pub mod identity_root;
+pub mod migration;
+pub mod notification;
+pub mod nullifier_link;
+pub mod nullifier_shard;
+pub mod nullifier_tree;
 pub mod permission;
+pub mod personhood;
+pub mod points;
+pub mod proof_record;
+pub mod rate_limit;
+pub mod result_receipt;
+pub mod recovery;
+pub mod reveal_escrow;
+pub mod sas_bridge;
+pub mod session;
+pub mod stats;
+pub mod username;
+pub mod verification_result;
+pub mod verification_session;
+pub mod verifier;
+
+use anchor_lang::prelude::*;
+
+/// * Deserialize a program account that may not have been created yet
+/// * Callers must constrain the address (e.g. via seeds) before loading
+/// * Returns None while the address is still empty / system-owned
+pub fn load_optional<T: AccountDeserialize + Owner>(info: &AccountInfo) -> Result<Option<T>> {
+    if info.owner != &T::owner() || info.data_is_empty() {
+        return Ok(None);
+    }
+
+    let data = info.try_borrow_data()?;
+    Ok(Some(T::try_deserialize(&mut &data[..])?))
+}
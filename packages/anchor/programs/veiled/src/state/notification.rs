@@ -0,0 +1,46 @@
+// * Notification channel state
+// * Standard, revocable way for an app to message an anonymous user: the user stores an
+// * encrypted push token for one app, gated by that app's ReceiveNotifications permission
+
+use anchor_lang::prelude::*;
+
+/// * Encrypted push channel for one (nullifier, app) pair
+/// * PDA: [b"notification_channel", nullifier, app_id]
+#[account]
+pub struct NotificationChannel {
+    /// * User's nullifier (anonymous ID)
+    pub nullifier: [u8; 32],
+
+    /// * App allowed to message the user
+    pub app_id: Pubkey,
+
+    /// * Wallet that opened the channel; only it can update or close it
+    pub authority: Pubkey,
+
+    /// * Commitment to the plaintext channel (e.g. sha256(token || salt)), so the app can
+    /// * check a decrypted token without it ever appearing on-chain
+    pub channel_commitment: [u8; 32],
+
+    /// * Push token / channel endpoint, encrypted to the app's key
+    pub encrypted_token: Vec<u8>,
+
+    /// * Last time the channel was set
+    pub updated_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl NotificationChannel {
+    /// * Longest encrypted token accepted (fits an ECIES-wrapped APNs/FCM token)
+    pub const MAX_TOKEN_LEN: usize = 256;
+
+    pub const MAX_SIZE: usize =
+        32 +                        // nullifier
+        32 +                        // app_id
+        32 +                        // authority
+        32 +                        // channel_commitment
+        (4 + Self::MAX_TOKEN_LEN) + // encrypted_token
+        8 +                         // updated_at
+        1;                          // bump
+}
@@ -0,0 +1,44 @@
+// * Nullifier link state
+// * Continuity between two nullifiers of one user: when a circuit or secret rotation gives them
+// * a new nullifier, migrate_nullifier records which nullifier succeeded the old one. Apps that
+// * hold grants or reputation for the old nullifier fetch its link and follow the chain to the
+// * current one; a nullifier without a link is current.
+
+use anchor_lang::prelude::*;
+
+/// * Successor of a retired nullifier
+/// * PDA: [b"nullifier_link", nullifier]
+#[account]
+pub struct NullifierLink {
+    /// * Retired nullifier
+    pub nullifier: [u8; 32],
+
+    /// * Nullifier that replaced it (may itself have a link)
+    pub successor: [u8; 32],
+
+    /// * Null-padded domain of the migrated session
+    pub domain: [u8; 32],
+
+    /// * Proof the successor's session was verified with
+    pub proof_hash: [u8; 32],
+
+    /// * Authority of the migrated session, which consented to the link
+    pub authority: Pubkey,
+
+    /// * When the nullifier was migrated
+    pub linked_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl NullifierLink {
+    pub const MAX_SIZE: usize =
+        32 + // nullifier
+        32 + // successor
+        32 + // domain
+        32 + // proof_hash
+        32 + // authority
+        8 +  // linked_at
+        1;   // bump
+}
@@ -0,0 +1,124 @@
+// * Nullifier shard state
+// * Bitmap nullifier store for high-volume domains (NullifierStore::Bitmap): rather than a
+// * rent-exempt NullifierAccount per nullifier, a registration sets one bit in a shared shard
+
+use anchor_lang::prelude::*;
+use core::cell::RefMut;
+
+/// * SLOTS registration bits for one domain, keyed by the nullifier's low 16 bits
+/// * A bit stands for every nullifier sharing its 29-bit key, so a fresh nullifier can collide
+/// * with one registered earlier (about n / 2^29 after n registrations) and is then refused
+/// * as a duplicate; domains opt in knowing that
+/// * Zero-copy: 1KB of bits is never deserialized into a copy
+/// * PDA: [b"nullifier_shard", sha256(domain), shard (u16 little-endian)]
+#[account(zero_copy)]
+#[repr(C)]
+pub struct NullifierShard {
+    /// * One bit per slot, set once a nullifier in that slot registered
+    pub bits: [u8; NullifierShard::SLOTS / 8],
+
+    /// * Null-padded domain the shard belongs to
+    pub domain: [u8; 32],
+
+    /// * Bits set so far
+    pub registered: u32,
+
+    /// * Shard index (see position)
+    pub shard: u16,
+
+    /// * PDA bump
+    pub bump: u8,
+
+    pub padding: [u8; 1],
+}
+
+impl NullifierShard {
+    pub const SLOTS: usize = 8192;
+
+    pub const MAX_SIZE: usize =
+        Self::SLOTS / 8 + // bits
+        32 +              // domain
+        4 +               // registered
+        2 +               // shard
+        1 +               // bump
+        1;                // padding
+
+    /// * (shard, slot) of `nullifier`: the shard is its last two bytes, the slot the top 13
+    /// * bits of the two before them
+    /// * Nullifiers are BN254 field elements, whose leading big-endian bytes are biased, so
+    /// * the key comes from the low end
+    pub fn position(nullifier: &[u8; 32]) -> (u16, usize) {
+        let shard = u16::from_be_bytes([nullifier[30], nullifier[31]]);
+        let slot = u16::from_be_bytes([nullifier[28], nullifier[29]]) >> 3;
+        (shard, usize::from(slot))
+    }
+
+    /// * Shard seed of `nullifier`, as its PDA takes it
+    pub fn seed(nullifier: &[u8; 32]) -> [u8; 2] {
+        Self::position(nullifier).0.to_le_bytes()
+    }
+
+    pub fn is_registered(&self, slot: usize) -> bool {
+        self.bits[slot / 8] & (1 << (slot % 8)) != 0
+    }
+
+    /// * Set `slot`'s bit; false when it was already set
+    pub fn register(&mut self, slot: usize) -> bool {
+        if self.is_registered(slot) {
+            return false;
+        }
+        self.bits[slot / 8] |= 1 << (slot % 8);
+        self.registered = self.registered.saturating_add(1);
+        true
+    }
+
+    /// * Load an init_if_needed shard: freshly created accounts have no discriminator yet
+    pub fn load_or_init<'a>(loader: &'a AccountLoader<NullifierShard>) -> Result<RefMut<'a, Self>> {
+        let uninitialized = loader
+            .as_ref()
+            .try_borrow_data()?
+            .iter()
+            .take(8)
+            .all(|&b| b == 0);
+        if uninitialized {
+            loader.load_init()
+        } else {
+            loader.load_mut()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_position_uses_low_bytes() {
+        let mut nullifier = [0xffu8; 32];
+        nullifier[28..].copy_from_slice(&[0b1010_1010, 0b1111_1111, 0x12, 0x34]);
+        let (shard, slot) = NullifierShard::position(&nullifier);
+
+        assert_eq!(shard, 0x1234);
+        assert_eq!(slot, 0xaaff >> 3);
+        assert!(slot < NullifierShard::SLOTS);
+        assert_eq!(NullifierShard::seed(&nullifier), 0x1234u16.to_le_bytes());
+    }
+
+    #[test]
+    fn test_register_once() {
+        let mut shard = NullifierShard {
+            bits: [0; NullifierShard::SLOTS / 8],
+            domain: [0; 32],
+            registered: 0,
+            shard: 0,
+            bump: 255,
+            padding: [0; 1],
+        };
+
+        assert!(shard.register(8191));
+        assert!(shard.is_registered(8191));
+        assert!(!shard.is_registered(8190));
+        assert!(!shard.register(8191));
+        assert_eq!(shard.registered, 1);
+    }
+}
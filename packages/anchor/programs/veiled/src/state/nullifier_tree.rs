@@ -0,0 +1,109 @@
+// * Nullifier tree state
+// * Compressed nullifier store for domains with millions of authentications
+// * (NullifierStore::Compressed): nullifiers are leaves of an SPL Account Compression
+// * concurrent Merkle tree (see compression.rs), kept as an indexed Merkle tree so a
+// * registration proves the nullifier isn't there yet
+// *
+// * Each leaf is hash_leaf(value || next_index || next_value): the leaves form a linked list
+// * sorted by value (32 bytes, big-endian), and next_value zero ends the list. A nullifier is
+// * absent exactly when some leaf (its "low leaf") brackets it: value < nullifier < next_value.
+// * Leaf 0 is the zero sentinel appended when the tree is initialized.
+
+use anchor_lang::prelude::*;
+
+use crate::errors::VeiledError;
+use crate::merkle;
+
+/// * A domain's compressed nullifier tree; also its tree authority, signing the CPIs
+/// * PDA: [b"nullifier_tree", domain]
+#[account]
+pub struct NullifierTree {
+    /// * Null-padded domain the tree belongs to
+    pub domain: [u8; 32],
+
+    /// * SPL Account Compression tree holding the leaves
+    pub merkle_tree: Pubkey,
+
+    /// * Index the next leaf is appended at (leaves so far, sentinel included)
+    pub next_index: u32,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+/// * Client-supplied non-inclusion proof: the nullifier's low leaf as the tree holds it
+/// * The Merkle path of the low leaf goes in remaining_accounts (sibling nodes, leaf up)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct NonInclusionProof {
+    /// * Tree root the path was built against (or a root still in the tree's changelog)
+    pub root: [u8; 32],
+
+    /// * Position of the low leaf
+    pub low_index: u32,
+
+    /// * Low leaf's value, next_index and next_value
+    pub low_value: [u8; 32],
+    pub low_next_index: u32,
+    pub low_next_value: [u8; 32],
+}
+
+impl NullifierTree {
+    pub const MAX_SIZE: usize =
+        32 + // domain
+        32 + // merkle_tree
+        4 +  // next_index
+        1;   // bump
+
+    /// * Leaf hash of an indexed tree entry
+    pub fn leaf(value: &[u8; 32], next_index: u32, next_value: &[u8; 32]) -> [u8; 32] {
+        merkle::hash_leaf(&[value, &next_index.to_le_bytes(), next_value])
+    }
+}
+
+impl NonInclusionProof {
+    /// * Fail unless the low leaf brackets `nullifier` (which proves it absent once the
+    /// * compression program accepts the leaf's path)
+    pub fn check(&self, nullifier: &[u8; 32]) -> Result<()> {
+        let ends_list = self.low_next_value == [0u8; 32];
+        require!(
+            self.low_value < *nullifier && (ends_list || *nullifier < self.low_next_value),
+            VeiledError::InvalidNonInclusionProof
+        );
+        Ok(())
+    }
+
+    /// * Low leaf as the tree holds it now
+    pub fn low_leaf(&self) -> [u8; 32] {
+        NullifierTree::leaf(&self.low_value, self.low_next_index, &self.low_next_value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn proof(low: u8, next: u8) -> NonInclusionProof {
+        NonInclusionProof {
+            root: [0; 32],
+            low_index: 0,
+            low_value: [low; 32],
+            low_next_index: 0,
+            low_next_value: [next; 32],
+        }
+    }
+
+    #[test]
+    fn test_low_leaf_brackets_nullifier() {
+        assert!(proof(1, 3).check(&[2; 32]).is_ok());
+        assert!(proof(1, 0).check(&[2; 32]).is_ok());
+        assert!(proof(0, 0).check(&[1; 32]).is_ok());
+
+        // * Already registered, as the low leaf's or its successor's value
+        assert!(proof(2, 3).check(&[2; 32]).is_err());
+        assert!(proof(1, 2).check(&[2; 32]).is_err());
+        // * Low leaf from elsewhere in the list
+        assert!(proof(3, 4).check(&[2; 32]).is_err());
+        // * Zero is the sentinel, never a nullifier
+        assert!(proof(0, 0).check(&[0; 32]).is_err());
+    }
+}
@@ -3,6 +3,9 @@
 
 use anchor_lang::prelude::*;
 
+use super::expiry::ExpiryKind;
+use crate::errors::VeiledError;
+
 #[account]
 pub struct PermissionGrant {
     /// * User's nullifier (anonymous ID)
@@ -17,25 +20,86 @@ pub struct PermissionGrant {
     /// * When permission was granted
     pub granted_at: i64,
     
-    /// * When permission expires
-    pub expires_at: i64,
+    /// * When permission expires (unix time or slot)
+    pub expires_at: ExpiryKind,
     
     /// * User can revoke anytime
     pub revoked: bool,
     
     /// * PDA bump
     pub bump: u8,
+    
+    /// * `permissions` compiled to bits (see Permission::bit), kept in sync by grant_permissions
+    pub permission_mask: u64,
+
+    /// * Nullifier the owner migrated to (migrate_nullifier), or zeroes if it never migrated
+    pub successor: [u8; 32],
 }
 
 impl PermissionGrant {
+    /// * Protocol limit on permissions per grant; domains may set a lower one
+    pub const MAX_PERMISSIONS: usize = 10;
+
     pub const MAX_SIZE: usize = 
         32 +           // nullifier
         32 +           // app_id
-        (4 + 10 * 1) + // permissions vec (max 10)
+        (4 + Self::MAX_PERMISSIONS) + // permissions vec (1 byte each)
         8 +            // granted_at
-        8 +            // expires_at
+        ExpiryKind::SIZE + // expires_at
         1 +            // revoked
-        1;             // bump
+        1 +            // bump
+        8 +            // permission_mask
+        32;            // successor
+
+    /// * Bitmask of a permission list
+    pub fn mask_of(permissions: &[Permission]) -> u64 {
+        permissions.iter().fold(0, |mask, permission| mask | permission.bit())
+    }
+
+    /// * Whether `permission` was granted: one AND against the mask
+    /// * A zero mask (no bits set) falls back to the Vec
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        if self.permission_mask == 0 {
+            return self.permissions.contains(&permission);
+        }
+        self.permission_mask & permission.bit() != 0
+    }
+
+    /// * Why `permission` can't be used right now, or None if it can
+    /// * Checks run in order: revoked, expired, not granted
+    pub fn denial_reason(
+        &self,
+        permission: Permission,
+        clock: &Clock,
+    ) -> Option<PermissionDenialReason> {
+        if self.revoked {
+            Some(PermissionDenialReason::Revoked)
+        } else if self.expires_at.has_expired(clock) {
+            Some(PermissionDenialReason::Expired)
+        } else if !self.has_permission(permission) {
+            Some(PermissionDenialReason::NotGranted)
+        } else {
+            None
+        }
+    }
+}
+
+/// * Outcome of a failed permission check
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PermissionDenialReason {
+    Revoked,
+    Expired,
+    NotGranted,
+}
+
+impl From<PermissionDenialReason> for VeiledError {
+    fn from(reason: PermissionDenialReason) -> Self {
+        match reason {
+            PermissionDenialReason::Revoked => VeiledError::PermissionRevoked,
+            PermissionDenialReason::Expired => VeiledError::PermissionExpired,
+            PermissionDenialReason::NotGranted => VeiledError::PermissionNotGranted,
+        }
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -63,6 +127,16 @@ pub enum Permission {
     
     /// * Access to sign transactions (future)
     SignTransactions,
+    
+    /// * Message the user through their NotificationChannel for this app
+    ReceiveNotifications,
+}
+
+impl Permission {
+    /// * Bit in PermissionGrant::permission_mask (variant index; append-only, like the enum)
+    pub fn bit(self) -> u64 {
+        1 << (self as u8)
+    }
 }
 
 /// * Track every permission access (audit log)
@@ -79,6 +153,9 @@ pub struct PermissionAccess {
     
     /// * Optional: What data was accessed
     pub metadata: String,
+    
+    /// * Who paid rent for this entry (refunded when the log is compacted)
+    pub payer: Pubkey,
 }
 
 impl PermissionAccess {
@@ -86,5 +163,87 @@ impl PermissionAccess {
         32 +          // permission_grant
         8 +           // accessed_at
         1 +           // permission_used
-        (4 + 100);    // metadata (max 100 chars)
+        (4 + 100) +   // metadata (max 100 chars)
+        32;           // payer
+    
+    /// * Entries younger than this can't be compacted
+    pub const COMPACTION_MIN_AGE_SECS: i64 = 30 * 24 * 60 * 60; // * 30 days
+}
+
+/// * Compacted access history for one PermissionGrant
+/// * PDA: ["access_summary", permission_grant]
+/// *
+/// * Each compaction batch is folded in as root = sha256(root || batch_merkle_root).
+/// * Leaves are the closed entries' contents (see compact_access_logs); their keys are emitted
+/// * in AccessLogsCompactedEvent so indexed history can be checked against the root.
+#[account]
+pub struct AccessLogSummary {
+    /// * Grant whose access logs are summarized
+    pub permission_grant: Pubkey,
+    
+    /// * Hash chain over batch Merkle roots
+    pub root: [u8; 32],
+    
+    /// * Total PermissionAccess entries folded in
+    pub compacted_count: u64,
+    
+    /// * Number of compaction batches
+    pub batch_count: u64,
+    
+    /// * Newest accessed_at folded in so far
+    pub last_accessed_at: i64,
+    
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl AccessLogSummary {
+    /// * Max PermissionAccess entries per compaction call
+    pub const MAX_BATCH: usize = 16;
+    
+    /// * Share of reclaimed rent paid to whoever runs the compaction
+    pub const REWARD_BPS: u64 = 500; // * 5%
+    
+    pub const MAX_SIZE: usize = 
+        32 +          // permission_grant
+        32 +          // root
+        8 +           // compacted_count
+        8 +           // batch_count
+        8 +           // last_accessed_at
+        1;            // bump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grant(permissions: Vec<Permission>, permission_mask: u64) -> PermissionGrant {
+        PermissionGrant {
+            nullifier: [0; 32],
+            app_id: Pubkey::default(),
+            permissions,
+            granted_at: 0,
+            expires_at: ExpiryKind::UnixTime(i64::MAX),
+            revoked: false,
+            bump: 255,
+            permission_mask,
+            successor: [0; 32],
+        }
+    }
+
+    #[test]
+    fn test_permission_mask() {
+        let permissions = vec![Permission::RevealWalletAddress, Permission::ReceiveNotifications];
+        let mask = PermissionGrant::mask_of(&permissions);
+        assert_eq!(mask, 1 | 1 << 8);
+
+        let compiled = grant(permissions.clone(), mask);
+        assert!(compiled.has_permission(Permission::ReceiveNotifications));
+        assert!(!compiled.has_permission(Permission::SignTransactions));
+
+        // * Grants from before the mask still answer from the Vec
+        let legacy = grant(permissions, 0);
+        assert!(legacy.has_permission(Permission::RevealWalletAddress));
+        assert!(!legacy.has_permission(Permission::RevealExactBalance));
+    }
 }
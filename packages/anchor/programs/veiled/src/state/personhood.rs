@@ -0,0 +1,214 @@
+// * Personhood state
+// * Sybil-resistance scores from external proof-of-personhood providers, layered over ZK auth:
+// * a registered provider signs a score for a nullifier, and domains that want more than
+// * "holds a valid proof" read it back (see attach_personhood_attestation)
+
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+/// * Providers whose attestations attach_personhood_attestation accepts (admin managed)
+/// * PDA: [b"personhood_providers"]
+#[account]
+pub struct PersonhoodProviderRegistry {
+    /// * Ed25519 keys providers sign attestations with
+    pub providers: Vec<Pubkey>,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl PersonhoodProviderRegistry {
+    pub const MAX_PROVIDERS: usize = 8;
+
+    pub const MAX_SIZE: usize =
+        (4 + Self::MAX_PROVIDERS * 32) + // providers
+        1;                                // bump
+
+    pub fn is_registered(&self, provider: &Pubkey) -> bool {
+        self.providers.contains(provider)
+    }
+}
+
+/// * A provider's latest score for a nullifier
+/// * PDA: [b"personhood", nullifier, provider]
+#[account]
+pub struct PersonhoodAttestation {
+    pub nullifier: [u8; 32],
+
+    /// * Provider key that signed the attestation
+    pub provider: Pubkey,
+
+    /// * 0 (no evidence of personhood) to MAX_SCORE (strongest)
+    pub score: u16,
+
+    /// * Unix timestamp the provider issued the attestation at
+    pub issued_at: i64,
+
+    /// * Unix timestamp the provider's claim lapses at
+    pub expires_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl PersonhoodAttestation {
+    pub const MAX_SCORE: u16 = 100;
+
+    /// * Domain separator, so a provider key can't be tricked into signing anything else as one
+    pub const MESSAGE_PREFIX: &'static [u8] = b"veiled:personhood:v1";
+
+    /// * MESSAGE_PREFIX || nullifier || score || issued_at || expires_at
+    pub const MESSAGE_LEN: usize = Self::MESSAGE_PREFIX.len() + 32 + 2 + 8 + 8;
+
+    pub const MAX_SIZE: usize =
+        32 + // nullifier
+        32 + // provider
+        2 +  // score
+        8 +  // issued_at
+        8 +  // expires_at
+        1;   // bump
+
+    /// * Message a provider signs (integers little-endian)
+    pub fn message(
+        nullifier: &[u8; 32],
+        score: u16,
+        issued_at: i64,
+        expires_at: i64,
+    ) -> [u8; Self::MESSAGE_LEN] {
+        let mut message = [0u8; Self::MESSAGE_LEN];
+        let prefix = Self::MESSAGE_PREFIX.len();
+        message[..prefix].copy_from_slice(Self::MESSAGE_PREFIX);
+        message[prefix..prefix + 32].copy_from_slice(nullifier);
+        message[prefix + 32..prefix + 34].copy_from_slice(&score.to_le_bytes());
+        message[prefix + 34..prefix + 42].copy_from_slice(&issued_at.to_le_bytes());
+        message[prefix + 42..].copy_from_slice(&expires_at.to_le_bytes());
+        message
+    }
+
+    /// * Score a domain should act on: zero once the claim lapsed or its provider was removed
+    pub fn current_score(&self, registry: &PersonhoodProviderRegistry, now: i64) -> u16 {
+        if now >= self.expires_at || !registry.is_registered(&self.provider) {
+            return 0;
+        }
+        self.score
+    }
+
+    /// * Commitment kept by archive_attestation:
+    /// * SHA-256(ARCHIVE_PREFIX || nullifier || provider || score || issued_at || expires_at)
+    pub fn archive_hash(&self) -> [u8; 32] {
+        hashv(&[
+            ArchivedPersonhoodAttestation::ARCHIVE_PREFIX,
+            &self.nullifier,
+            self.provider.as_ref(),
+            &self.score.to_le_bytes(),
+            &self.issued_at.to_le_bytes(),
+            &self.expires_at.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+}
+
+/// * Cold-storage form of a PersonhoodAttestation: only a hash of its fields stays on-chain
+/// * until restore_attestation brings it back from the preimage
+/// * PDA: [b"personhood_archive", nullifier, provider]
+#[account]
+pub struct ArchivedPersonhoodAttestation {
+    /// * PersonhoodAttestation::archive_hash of the archived attestation
+    pub attestation_hash: [u8; 32],
+
+    /// * Kept in the clear so attach_personhood_attestation still rejects older claims
+    pub issued_at: i64,
+
+    /// * Unix timestamp the attestation was archived at
+    pub archived_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl ArchivedPersonhoodAttestation {
+    /// * Domain separator for archive hashes
+    pub const ARCHIVE_PREFIX: &'static [u8] = b"veiled:personhood-archive:v1";
+
+    pub const MAX_SIZE: usize =
+        32 + // attestation_hash
+        8 +  // issued_at
+        8 +  // archived_at
+        1;   // bump
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_message_layout() {
+        let message = PersonhoodAttestation::message(&[9u8; 32], 75, 1_700_000_000, 1_800_000_000);
+        let prefix = PersonhoodAttestation::MESSAGE_PREFIX.len();
+
+        assert_eq!(message.len(), PersonhoodAttestation::MESSAGE_LEN);
+        assert_eq!(&message[..prefix], PersonhoodAttestation::MESSAGE_PREFIX);
+        assert_eq!(&message[prefix..prefix + 32], &[9u8; 32]);
+        assert_eq!(&message[prefix + 32..prefix + 34], &75u16.to_le_bytes());
+        assert_eq!(
+            &message[prefix + 34..prefix + 42],
+            &1_700_000_000i64.to_le_bytes()
+        );
+        assert_eq!(&message[prefix + 42..], &1_800_000_000i64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_current_score_lapses() {
+        let provider = Pubkey::new_unique();
+        let mut registry = PersonhoodProviderRegistry {
+            providers: vec![provider],
+            bump: 255,
+        };
+        let attestation = PersonhoodAttestation {
+            nullifier: [1u8; 32],
+            provider,
+            score: 80,
+            issued_at: 100,
+            expires_at: 200,
+            bump: 255,
+        };
+
+        assert_eq!(attestation.current_score(&registry, 150), 80);
+        assert_eq!(attestation.current_score(&registry, 200), 0);
+
+        // * Removing the provider voids its scores immediately
+        registry.providers.clear();
+        assert_eq!(attestation.current_score(&registry, 150), 0);
+    }
+
+    #[test]
+    fn test_archive_hash_binds_every_field() {
+        let attestation = PersonhoodAttestation {
+            nullifier: [1u8; 32],
+            provider: Pubkey::new_unique(),
+            score: 80,
+            issued_at: 100,
+            expires_at: 200,
+            bump: 255,
+        };
+        let hash = attestation.archive_hash();
+
+        // * The bump isn't part of the claim
+        let rebumped = PersonhoodAttestation {
+            bump: 254,
+            ..attestation.clone()
+        };
+        assert_eq!(rebumped.archive_hash(), hash);
+
+        let rescored = PersonhoodAttestation {
+            score: 81,
+            ..attestation.clone()
+        };
+        let extended = PersonhoodAttestation {
+            expires_at: 201,
+            ..attestation
+        };
+        assert_ne!(rescored.archive_hash(), hash);
+        assert_ne!(extended.archive_hash(), hash);
+    }
+}
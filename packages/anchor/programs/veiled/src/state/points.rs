@@ -0,0 +1,80 @@
+// * Governance points state
+// * Usage points airdropped to early users, groundwork for progressive decentralization of the
+// * council. Points belong to nullifiers rather than wallets, so holding them doesn't link a user
+// * to their identity, and there is no instruction to transfer them.
+
+use anchor_lang::prelude::*;
+
+use crate::merkle;
+
+/// * Governance-published Merkle root over historical usage
+/// * Leaf i = merkle::hash_leaf(nullifier || points (u64 LE)), see Points::leaf
+/// * PDA: [b"points_root"]
+#[account]
+pub struct PointsRoot {
+    pub root: [u8; 32],
+
+    /// * Leaves in the tree (needed to place promoted nodes when checking proofs)
+    pub leaf_count: u32,
+
+    pub published_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl PointsRoot {
+    /// * Deepest proof accepted (trees of up to 2^32 leaves)
+    pub const MAX_PROOF_LEN: usize = 32;
+
+    pub const MAX_SIZE: usize =
+        32 + // root
+        4 +  // leaf_count
+        8 +  // published_at
+        1;   // bump
+
+    /// * Whether `nullifier` was allotted `points` at `index` in the published tree
+    pub fn contains(
+        &self,
+        nullifier: &[u8; 32],
+        points: u64,
+        index: u32,
+        proof: &[[u8; 32]],
+    ) -> bool {
+        proof.len() <= Self::MAX_PROOF_LEN
+            && merkle::verify_proof(
+                &self.root,
+                &Points::leaf(nullifier, points),
+                index,
+                self.leaf_count,
+                proof,
+            )
+    }
+}
+
+/// * Non-transferable point balance of a nullifier, created by its one-time claim
+/// * PDA: [b"points", nullifier]
+#[account]
+pub struct Points {
+    pub nullifier: [u8; 32],
+
+    pub balance: u64,
+
+    pub claimed_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl Points {
+    pub const MAX_SIZE: usize =
+        32 + // nullifier
+        8 +  // balance
+        8 +  // claimed_at
+        1;   // bump
+
+    /// * Tree leaf allotting `points` to `nullifier`
+    pub fn leaf(nullifier: &[u8; 32], points: u64) -> [u8; 32] {
+        merkle::hash_leaf(&[nullifier, &points.to_le_bytes()])
+    }
+}
@@ -0,0 +1,33 @@
+// * Proof record state
+// * Registry of proof hashes verify_auth has accepted, so one proof can't register a second
+// * nullifier or domain
+
+use anchor_lang::prelude::*;
+
+/// * First use of a proof hash
+/// * PDA: [b"proof_record", proof_hash]
+#[account]
+pub struct ProofRecord {
+    pub proof_hash: [u8; 32],
+
+    /// * Nullifier the proof registered
+    pub nullifier: [u8; 32],
+
+    /// * Null-padded domain it registered for
+    pub domain: [u8; 32],
+
+    /// * Zero until the proof is used
+    pub created_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl ProofRecord {
+    pub const MAX_SIZE: usize =
+        32 + // proof_hash
+        32 + // nullifier
+        32 + // domain
+        8 +  // created_at
+        1;   // bump
+}
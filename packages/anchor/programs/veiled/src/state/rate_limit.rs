@@ -0,0 +1,166 @@
+// * Payer rate limit state
+// * Per fee payer counter of recent verifications, so one relayer key can't flood verify_auth
+// * (creating thousands of sessions a minute and skewing per-app stats)
+
+use anchor_lang::prelude::*;
+use core::cell::RefMut;
+
+use crate::errors::VeiledError;
+use crate::genesis;
+
+/// * Sliding window of verifications paid for by one key, split into BUCKETS sub-windows
+/// * Zero-copy: touched on every verification, so it's never deserialized into a copy
+/// * PDA: [b"rate_limit", payer]
+#[account(zero_copy)]
+#[repr(C)]
+pub struct PayerRateLimit {
+    /// * Fee payer (verify_auth authority) being counted
+    pub payer: Pubkey,
+
+    /// * Index (unix_timestamp / BUCKET_SECS) of the newest bucket written
+    pub current_bucket: i64,
+
+    /// * Verifications per bucket, ring-indexed by bucket index % BUCKETS
+    pub buckets: [u32; PayerRateLimit::BUCKETS],
+
+    /// * Admin-granted quota for allowlisted relayers, replacing the domain limit (0 = none)
+    pub quota: u32,
+
+    /// * PDA bump
+    pub bump: u8,
+
+    pub padding: [u8; 3],
+}
+
+impl PayerRateLimit {
+    pub const WINDOW_SECS: i64 = 60;
+    pub const BUCKETS: usize = 6;
+    pub const BUCKET_SECS: i64 = Self::WINDOW_SECS / Self::BUCKETS as i64;
+
+    /// * Limit for domains that don't set one
+    pub const DEFAULT_LIMIT: u32 = genesis::DEFAULT_RATE_LIMIT;
+
+    /// * Highest limit a domain owner can set; only relayer quotas go above it
+    pub const MAX_DOMAIN_LIMIT: u32 = 600;
+
+    pub const MAX_SIZE: usize =
+        32 +                // payer
+        8 +                 // current_bucket
+        Self::BUCKETS * 4 + // buckets
+        4 +                 // quota
+        1 +                 // bump
+        3;                  // padding
+
+    /// * Allowed verifications per window: relayer quota, else the domain's limit, else the default
+    pub fn limit(&self, domain_limit: u32) -> u32 {
+        if self.quota > 0 {
+            self.quota
+        } else if domain_limit > 0 {
+            domain_limit
+        } else {
+            Self::DEFAULT_LIMIT
+        }
+    }
+
+    /// * Count one verification at `now` unless the trailing window already holds `limit`
+    pub fn record(&mut self, now: i64, limit: u32) -> bool {
+        let bucket = now.div_euclid(Self::BUCKET_SECS);
+
+        // * Clear the buckets that slid out of the window since the last write
+        let stale = (bucket - self.current_bucket).clamp(0, Self::BUCKETS as i64);
+        for index in (bucket - stale + 1)..=bucket {
+            self.buckets[Self::slot(index)] = 0;
+        }
+        self.current_bucket = self.current_bucket.max(bucket);
+
+        let in_window: u64 = self.buckets.iter().map(|&count| u64::from(count)).sum();
+        if in_window >= u64::from(limit) {
+            return false;
+        }
+
+        let count = &mut self.buckets[Self::slot(self.current_bucket)];
+        *count = count.saturating_add(1);
+        true
+    }
+
+    /// * Count a verification paid for by `payer`, failing once its window is full
+    pub fn enforce(
+        loader: &AccountLoader<PayerRateLimit>,
+        payer: Pubkey,
+        bump: u8,
+        domain_limit: u32,
+        now: i64,
+    ) -> Result<()> {
+        let mut counter = Self::load_or_init(loader)?;
+        counter.payer = payer;
+        counter.bump = bump;
+
+        let limit = counter.limit(domain_limit);
+        require!(counter.record(now, limit), VeiledError::RateLimited);
+        Ok(())
+    }
+
+    /// * Load an init_if_needed counter: freshly created accounts have no discriminator yet
+    pub fn load_or_init<'a>(loader: &'a AccountLoader<PayerRateLimit>) -> Result<RefMut<'a, Self>> {
+        let uninitialized = loader
+            .as_ref()
+            .try_borrow_data()?
+            .iter()
+            .take(8)
+            .all(|&b| b == 0);
+        if uninitialized {
+            loader.load_init()
+        } else {
+            loader.load_mut()
+        }
+    }
+
+    fn slot(bucket: i64) -> usize {
+        bucket.rem_euclid(Self::BUCKETS as i64) as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn counter() -> PayerRateLimit {
+        PayerRateLimit {
+            payer: Pubkey::default(),
+            current_bucket: 0,
+            buckets: [0; PayerRateLimit::BUCKETS],
+            quota: 0,
+            bump: 255,
+            padding: [0; 3],
+        }
+    }
+
+    #[test]
+    fn test_sliding_window() {
+        let mut limit = counter();
+        let start = 1_700_000_000;
+
+        assert!(limit.record(start, 2));
+        assert!(limit.record(start + 15, 2));
+        assert!(!limit.record(start + 30, 2));
+
+        // * The first verification's bucket slides out after a full window
+        assert!(limit.record(start + 60, 2));
+        assert!(!limit.record(start + 65, 2));
+
+        // * A long gap clears everything
+        assert!(limit.record(start + 10_000, 2));
+        assert!(limit.record(start + 10_000, 2));
+        assert!(!limit.record(start + 10_000, 2));
+    }
+
+    #[test]
+    fn test_limit_precedence() {
+        let mut limit = counter();
+        assert_eq!(limit.limit(0), PayerRateLimit::DEFAULT_LIMIT);
+        assert_eq!(limit.limit(10), 10);
+
+        limit.quota = 5_000;
+        assert_eq!(limit.limit(10), 5_000);
+    }
+}
@@ -0,0 +1,126 @@
+// * Guardian recovery state
+// * Social recovery for an identity whose wallet is lost: while it still holds an active
+// * session, a user names guardians for their nullifier (set_guardians); later, m of those n
+// * guardians can approve moving the nullifier's sessions and grants to a new nullifier
+// * (recover_identity).
+// *
+// * Guardians approve by signing a GuardianRecovery message in Ed25519Program instructions,
+// * checked through instruction introspection like every other signed message (see
+// * sig_introspection.rs).
+
+use anchor_lang::prelude::*;
+
+use crate::errors::VeiledError;
+use crate::genesis;
+use crate::sig_introspection;
+
+/// * Guardians of one nullifier
+/// * PDA: [b"guardian_set", nullifier]
+#[account]
+pub struct GuardianSet {
+    /// * Nullifier the guardians can recover
+    pub nullifier: [u8; 32],
+
+    /// * Guardian keys (up to MAX_GUARDIANS, distinct)
+    pub guardians: Vec<Pubkey>,
+
+    /// * Guardian signatures a recovery needs
+    pub threshold: u8,
+
+    /// * Bumped on every change, so approvals for an older set can't be replayed
+    pub nonce: u64,
+
+    /// * When the set last changed
+    pub updated_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl GuardianSet {
+    pub const MAX_GUARDIANS: usize = 8;
+
+    pub const MAX_SIZE: usize =
+        32 +                          // nullifier
+        4 + 32 * Self::MAX_GUARDIANS + // guardians
+        1 +                           // threshold
+        8 +                           // nonce
+        8 +                           // updated_at
+        1;                            // bump
+
+    /// * 1 to MAX_GUARDIANS distinct guardians and a threshold between 1 and their count
+    pub fn validate(guardians: &[Pubkey], threshold: u8) -> Result<()> {
+        let distinct = guardians
+            .iter()
+            .enumerate()
+            .all(|(i, guardian)| !guardians[..i].contains(guardian));
+        require!(
+            (1..=Self::MAX_GUARDIANS).contains(&guardians.len())
+                && distinct
+                && threshold >= 1
+                && threshold as usize <= guardians.len(),
+            VeiledError::InvalidGuardianSet
+        );
+        Ok(())
+    }
+
+    /// * Guardians that signed `message` in this transaction
+    pub fn approvals(&self, instructions_sysvar: &AccountInfo, message: &[u8]) -> Result<u8> {
+        let mut approvals = 0u8;
+        for guardian in &self.guardians {
+            if sig_introspection::is_signed(instructions_sysvar, guardian, message)? {
+                approvals += 1;
+            }
+        }
+        Ok(approvals)
+    }
+}
+
+/// * A guardian's approval of one recovery
+pub struct GuardianRecovery;
+
+impl GuardianRecovery {
+    /// * Domain separator, so a guardian can't be tricked into signing anything else as a recovery
+    pub const MESSAGE_PREFIX: &'static [u8] = b"veiled:recover_identity:v1";
+
+    /// * MESSAGE_PREFIX || instance_id || nullifier || new_nullifier || new_authority || nonce
+    pub const MESSAGE_LEN: usize = Self::MESSAGE_PREFIX.len() + 32 + 32 + 32 + 32 + 8;
+
+    /// * Message each guardian signs (nonce little-endian)
+    /// * Binds the new authority, so whoever submits can't take the recovered identity, and the
+    /// * set's nonce, so approvals die with the set they were given for
+    pub fn message(
+        nullifier: &[u8; 32],
+        new_nullifier: &[u8; 32],
+        new_authority: &Pubkey,
+        nonce: u64,
+    ) -> [u8; Self::MESSAGE_LEN] {
+        let mut message = [0u8; Self::MESSAGE_LEN];
+        let prefix = Self::MESSAGE_PREFIX.len();
+        message[..prefix].copy_from_slice(Self::MESSAGE_PREFIX);
+        message[prefix..prefix + 32].copy_from_slice(&genesis::INSTANCE_ID);
+        message[prefix + 32..prefix + 64].copy_from_slice(nullifier);
+        message[prefix + 64..prefix + 96].copy_from_slice(new_nullifier);
+        message[prefix + 96..prefix + 128].copy_from_slice(new_authority.as_ref());
+        message[prefix + 128..].copy_from_slice(&nonce.to_le_bytes());
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_guardian_set() {
+        let guardians: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        assert!(GuardianSet::validate(&guardians, 2).is_ok());
+        assert!(GuardianSet::validate(&guardians, 3).is_ok());
+        assert!(GuardianSet::validate(&guardians, 0).is_err());
+        assert!(GuardianSet::validate(&guardians, 4).is_err());
+        assert!(GuardianSet::validate(&[], 1).is_err());
+        assert!(GuardianSet::validate(&[guardians[0], guardians[0]], 1).is_err());
+        let too_many: Vec<Pubkey> = (0..9).map(|_| Pubkey::new_unique()).collect();
+        assert!(GuardianSet::validate(&too_many, 1).is_err());
+    }
+}
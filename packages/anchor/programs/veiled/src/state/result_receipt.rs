@@ -0,0 +1,73 @@
+// * Result receipt state
+// * Registry of verifier signatures verify_auth has consumed, so one signed result is spent
+// * exactly once across every instruction that accepts results, whichever accounts it uses
+
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+use crate::errors::VeiledError;
+use crate::pda;
+
+/// * First use of a signed verification result
+/// * PDA: [b"result_receipt", sha256(signature)]
+#[account]
+pub struct ResultReceipt {
+    /// * sha256 of the result's verifier signature
+    pub signature_hash: [u8; 32],
+
+    /// * Account that paid the rent; refunded when the receipt is closed
+    pub payer: Pubkey,
+
+    pub consumed_at: i64,
+
+    /// * Receipt can't be closed before this; set past the longest a result stays fresh, so
+    /// * its signature is stale by the time it could be reused
+    pub retain_until: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl ResultReceipt {
+    pub const MAX_SIZE: usize =
+        32 + // signature_hash
+        32 + // payer
+        8 +  // consumed_at
+        8 +  // retain_until
+        1;   // bump
+
+    /// * Seed for a result's receipt; signatures are 64 bytes, past the 32-byte seed limit
+    pub fn signature_hash(signature: &[u8; 64]) -> [u8; 32] {
+        hashv(&[signature.as_ref()]).to_bytes()
+    }
+
+    /// * Create the receipt for `signature`; fails with SignatureAlreadyUsed if it exists
+    pub fn consume<'info>(
+        info: &AccountInfo<'info>,
+        payer: &Signer<'info>,
+        system_program: &Program<'info, System>,
+        signature: &[u8; 64],
+        now: i64,
+        retention_secs: i64,
+    ) -> Result<()> {
+        let signature_hash = Self::signature_hash(signature);
+        let bump = pda::create_pda(
+            payer,
+            info,
+            system_program,
+            &[b"result_receipt", signature_hash.as_ref()],
+            8 + Self::MAX_SIZE,
+            VeiledError::SignatureAlreadyUsed,
+        )?;
+        pda::write_account(
+            info,
+            &Self {
+                signature_hash,
+                payer: payer.key(),
+                consumed_at: now,
+                retain_until: now + retention_secs,
+                bump,
+            },
+        )
+    }
+}
@@ -0,0 +1,62 @@
+// * Reveal escrow state
+// * Delayed RevealWalletAddress: the wallet address is escrowed encrypted and the key that
+// * opens it is committed up front, so the app only learns the address once the user's
+// * delay has run out (and the user can still back out before then)
+
+use anchor_lang::prelude::*;
+
+/// * Time-locked wallet address reveal for one (nullifier, app) pair
+/// * PDA: [b"reveal_escrow", nullifier, app_id]
+/// *
+/// * ciphertext = AEAD(reveal_key, wallet address); key_commitment = sha256(reveal_key)
+/// * reveal_key is published by release_reveal_escrow once reveals_at has passed
+#[account]
+pub struct RevealEscrow {
+    /// * User's nullifier (anonymous ID)
+    pub nullifier: [u8; 32],
+
+    /// * App the address is revealed to
+    pub app_id: Pubkey,
+
+    /// * Wallet that opened the escrow; can cancel it and gets the rent back
+    pub authority: Pubkey,
+
+    /// * Encrypted wallet address
+    pub ciphertext: Vec<u8>,
+
+    /// * sha256 of the key that decrypts `ciphertext`
+    pub key_commitment: [u8; 32],
+
+    /// * Decryption key, zero until released
+    pub reveal_key: [u8; 32],
+
+    pub opened_at: i64,
+
+    /// * Earliest time the key can be released; the user can cancel until then
+    pub reveals_at: i64,
+
+    pub released: bool,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl RevealEscrow {
+    /// * Address (32) + nonce and AEAD tag, with room for other schemes
+    pub const MAX_CIPHERTEXT_LEN: usize = 128;
+
+    /// * Longest delay a user can set
+    pub const MAX_DELAY_SECS: i64 = 90 * 24 * 60 * 60; // * 90 days
+
+    pub const MAX_SIZE: usize =
+        32 +                             // nullifier
+        32 +                             // app_id
+        32 +                             // authority
+        (4 + Self::MAX_CIPHERTEXT_LEN) + // ciphertext
+        32 +                             // key_commitment
+        32 +                             // reveal_key
+        8 +                              // opened_at
+        8 +                              // reveals_at
+        1 +                              // released
+        1;                               // bump
+}
@@ -0,0 +1,25 @@
+// * SAS bridge state
+// * Solana Attestation Service credential and schema mirror_attestation writes under (see sas.rs)
+
+use anchor_lang::prelude::*;
+
+/// * Where Veiled attestations are mirrored in SAS
+/// * PDA: [b"sas_bridge"]
+#[account]
+pub struct SasBridge {
+    /// * SAS credential that lists the [b"sas_signer"] PDA as an authorized signer
+    pub credential: Pubkey,
+
+    /// * SAS schema under the credential, laid out as sas.rs describes
+    pub schema: Pubkey,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl SasBridge {
+    pub const MAX_SIZE: usize =
+        32 + // credential
+        32 + // schema
+        1;   // bump
+}
@@ -0,0 +1,266 @@
+// * Session state types
+// * Per-identity markers that affect every session created by a wallet
+
+use anchor_lang::prelude::*;
+
+use super::expiry::ExpiryKind;
+use super::permission::Permission;
+use crate::errors::VeiledError;
+use crate::genesis;
+
+/// * "Logout everywhere" marker for a wallet identity
+/// * Any session created by this identity at or before `logged_out_at`
+/// * is treated as revoked, regardless of its domain
+#[account]
+pub struct LogoutEpoch {
+    /// * Wallet that proved ownership by signing global_logout
+    pub identity: Pubkey,
+
+    /// * Unix timestamp of the most recent global logout
+    pub logged_out_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl LogoutEpoch {
+    pub const MAX_SIZE: usize =
+        32 + // identity
+        8 +  // logged_out_at
+        1;   // bump
+
+    /// * Cutoff timestamp for the LogoutEpoch PDA passed in `info`
+    /// * Returns 0 when the identity never logged out (account not created)
+    pub fn load_cutoff(info: &AccountInfo) -> Result<i64> {
+        Ok(super::load_optional::<LogoutEpoch>(info)?
+            .map(|epoch| epoch.logged_out_at)
+            .unwrap_or(0))
+    }
+}
+
+/// * Session verify_auth registered, returned via return data so CPI callers and simulating
+/// * clients don't need to fetch the nullifier account
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct SessionDetails {
+    /// * Nullifier account (session PDA) that was created
+    pub nullifier_account: Pubkey,
+
+    /// * Unix timestamp the session was registered at
+    pub created_at: i64,
+
+    /// * When the session expires
+    pub expires_at: ExpiryKind,
+}
+
+/// * End user's consent to a session being registered for them by someone else (a relayer
+/// * paying the fees); the user's wallet signs `message` in an Ed25519Program instruction
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct UserConsent {
+    /// * Wallet that signed the consent; becomes the session's authority
+    pub user: Pubkey,
+
+    /// * Unix timestamp after which the consent can't be submitted
+    pub expires_at: i64,
+}
+
+impl UserConsent {
+    /// * Domain separator, so a wallet can't be tricked into signing anything else as consent
+    /// * v2 added instance_id; v1 consents are no longer accepted
+    pub const MESSAGE_PREFIX: &'static [u8] = b"veiled:consent:v2";
+
+    /// * MESSAGE_PREFIX || instance_id || nullifier || domain || expires_at
+    pub const MESSAGE_LEN: usize = Self::MESSAGE_PREFIX.len() + 32 + 32 + 32 + 8;
+
+    /// * Message the user signs (expires_at little-endian)
+    /// * Binds this deployment (genesis::INSTANCE_ID), so a consent can't be replayed on another
+    pub fn message(&self, nullifier: &[u8; 32], domain: &[u8; 32]) -> [u8; Self::MESSAGE_LEN] {
+        let mut message = [0u8; Self::MESSAGE_LEN];
+        let prefix = Self::MESSAGE_PREFIX.len();
+        message[..prefix].copy_from_slice(Self::MESSAGE_PREFIX);
+        message[prefix..prefix + 32].copy_from_slice(&genesis::INSTANCE_ID);
+        message[prefix + 32..prefix + 64].copy_from_slice(nullifier);
+        message[prefix + 64..prefix + 96].copy_from_slice(domain);
+        message[prefix + 96..].copy_from_slice(&self.expires_at.to_le_bytes());
+        message
+    }
+}
+
+/// * A session authority's request to close its session, submitted by someone else (a relayer
+/// * paying the fees); the authority's wallet signs `message` in an Ed25519Program instruction
+pub struct SessionCloseRequest;
+
+impl SessionCloseRequest {
+    /// * Domain separator, so a wallet can't be tricked into signing anything else as a close
+    pub const MESSAGE_PREFIX: &'static [u8] = b"veiled:close_session:v1";
+
+    /// * MESSAGE_PREFIX || instance_id || nullifier || created_at
+    pub const MESSAGE_LEN: usize = Self::MESSAGE_PREFIX.len() + 32 + 32 + 8;
+
+    /// * Message the authority signs (created_at little-endian)
+    /// * Binds the session's created_at, so a request can't close a later session registered
+    /// * for the same nullifier
+    pub fn message(nullifier: &[u8; 32], created_at: i64) -> [u8; Self::MESSAGE_LEN] {
+        let mut message = [0u8; Self::MESSAGE_LEN];
+        let prefix = Self::MESSAGE_PREFIX.len();
+        message[..prefix].copy_from_slice(Self::MESSAGE_PREFIX);
+        message[prefix..prefix + 32].copy_from_slice(&genesis::INSTANCE_ID);
+        message[prefix + 32..prefix + 64].copy_from_slice(nullifier);
+        message[prefix + 64..].copy_from_slice(&created_at.to_le_bytes());
+        message
+    }
+}
+
+/// * A session's bound device proving it's present: the device key (see
+/// * NullifierAccount::prove_device) signs `message` in an Ed25519Program instruction
+pub struct DeviceProof;
+
+impl DeviceProof {
+    /// * Domain separator, so a device can't be tricked into signing anything else as a proof
+    pub const MESSAGE_PREFIX: &'static [u8] = b"veiled:device:v1";
+
+    /// * MESSAGE_PREFIX || instance_id || nullifier || last_active_at
+    pub const MESSAGE_LEN: usize = Self::MESSAGE_PREFIX.len() + 32 + 32 + 8;
+
+    /// * Message the device signs (last_active_at little-endian)
+    /// * Binds the session's last activity, so a proof stops working once the session is touched
+    pub fn message(nullifier: &[u8; 32], last_active_at: i64) -> [u8; Self::MESSAGE_LEN] {
+        let mut message = [0u8; Self::MESSAGE_LEN];
+        let prefix = Self::MESSAGE_PREFIX.len();
+        message[..prefix].copy_from_slice(Self::MESSAGE_PREFIX);
+        message[prefix..prefix + 32].copy_from_slice(&genesis::INSTANCE_ID);
+        message[prefix + 32..prefix + 64].copy_from_slice(nullifier);
+        message[prefix + 64..].copy_from_slice(&last_active_at.to_le_bytes());
+        message
+    }
+}
+
+/// * Short-lived child key minted by a session's authority (delegate_session), e.g. for a
+/// * checkout flow; acts for the session within `scope` until it expires or the parent
+/// * revokes it
+/// * PDA: [b"session_delegate", session, delegate]
+#[account]
+pub struct SessionDelegate {
+    /// * Parent session (NullifierAccount) the delegate acts for
+    pub session: Pubkey,
+
+    /// * Child key that signs for the delegate
+    pub delegate: Pubkey,
+
+    /// * Permission bits (see Permission::bit) the delegate may act on
+    pub scope: u64,
+
+    /// * When the delegate was minted
+    pub created_at: i64,
+
+    /// * Unix timestamp the delegate stops working at; never past its parent's expiry
+    pub expires_at: i64,
+
+    /// * Parent session's authority; receives the rent back on revocation
+    pub authority: Pubkey,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl SessionDelegate {
+    /// * Delegate lifetime when the caller doesn't ask for one, and the longest it may ask for
+    pub const DEFAULT_EXPIRY_SECONDS: i64 = 15 * 60;
+    pub const MAX_EXPIRY_SECONDS: i64 = 60 * 60;
+
+    pub const MAX_SIZE: usize =
+        32 + // session
+        32 + // delegate
+        8 +  // scope
+        8 +  // created_at
+        8 +  // expires_at
+        32 + // authority
+        1;   // bump
+
+    /// * Expiry for a delegate asked to last `expires_in` seconds (0 = DEFAULT_EXPIRY_SECONDS),
+    /// * capped at the parent's expiry when that is a unix time
+    pub fn expiry(now: i64, expires_in: i64, parent_expires_at: ExpiryKind) -> Result<i64> {
+        let expires_in = if expires_in == 0 {
+            Self::DEFAULT_EXPIRY_SECONDS
+        } else {
+            expires_in
+        };
+        require!(
+            (1..=Self::MAX_EXPIRY_SECONDS).contains(&expires_in),
+            VeiledError::InvalidDelegateExpiry
+        );
+        let expires_at = now + expires_in;
+        Ok(match parent_expires_at {
+            ExpiryKind::UnixTime(parent) => expires_at.min(parent),
+            ExpiryKind::Slot(_) => expires_at,
+        })
+    }
+
+    /// * Whether the delegate may use `permission` at `now`; the parent session must be
+    /// * checked separately (see validate_session_delegate)
+    pub fn allows(&self, permission: Permission, now: i64) -> bool {
+        now < self.expires_at && self.scope & permission.bit() != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consent_message_layout() {
+        let consent = UserConsent {
+            user: Pubkey::new_unique(),
+            expires_at: 1_700_000_000,
+        };
+        let message = consent.message(&[3u8; 32], &[4u8; 32]);
+        let prefix = UserConsent::MESSAGE_PREFIX.len();
+
+        assert_eq!(message.len(), UserConsent::MESSAGE_LEN);
+        assert_eq!(&message[..prefix], UserConsent::MESSAGE_PREFIX);
+        assert_eq!(&message[prefix..prefix + 32], &crate::ID.to_bytes());
+        assert_eq!(&message[prefix + 32..prefix + 64], &[3u8; 32]);
+        assert_eq!(&message[prefix + 64..prefix + 96], &[4u8; 32]);
+        assert_eq!(&message[prefix + 96..], &1_700_000_000i64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_close_request_message_layout() {
+        let message = SessionCloseRequest::message(&[3u8; 32], 1_700_000_000);
+        let prefix = SessionCloseRequest::MESSAGE_PREFIX.len();
+
+        assert_eq!(message.len(), SessionCloseRequest::MESSAGE_LEN);
+        assert_eq!(&message[..prefix], SessionCloseRequest::MESSAGE_PREFIX);
+        assert_eq!(&message[prefix..prefix + 32], &crate::ID.to_bytes());
+        assert_eq!(&message[prefix + 32..prefix + 64], &[3u8; 32]);
+        assert_eq!(&message[prefix + 64..], &1_700_000_000i64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_device_proof_message_layout() {
+        let message = DeviceProof::message(&[3u8; 32], 1_700_000_000);
+        let prefix = DeviceProof::MESSAGE_PREFIX.len();
+
+        assert_eq!(message.len(), DeviceProof::MESSAGE_LEN);
+        assert_eq!(&message[..prefix], DeviceProof::MESSAGE_PREFIX);
+        assert_eq!(&message[prefix..prefix + 32], &crate::ID.to_bytes());
+        assert_eq!(&message[prefix + 32..prefix + 64], &[3u8; 32]);
+        assert_eq!(&message[prefix + 64..], &1_700_000_000i64.to_le_bytes());
+    }
+
+    #[test]
+    fn test_delegate_expiry_is_capped_by_parent() {
+        let now = 1_700_000_000;
+        let far = ExpiryKind::UnixTime(now + 86_400);
+
+        assert_eq!(
+            SessionDelegate::expiry(now, 0, far).unwrap(),
+            now + SessionDelegate::DEFAULT_EXPIRY_SECONDS
+        );
+        assert_eq!(
+            SessionDelegate::expiry(now, 600, ExpiryKind::UnixTime(now + 60)).unwrap(),
+            now + 60
+        );
+        assert!(SessionDelegate::expiry(now, SessionDelegate::MAX_EXPIRY_SECONDS + 1, far).is_err());
+        assert!(SessionDelegate::expiry(now, -1, far).is_err());
+    }
+}
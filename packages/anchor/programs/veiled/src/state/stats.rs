@@ -0,0 +1,81 @@
+// * Protocol stats state
+// * Program-wide running totals for dashboards and governance, kept on-chain so they don't
+// * depend on an indexer having seen every event. Created on first use by whichever
+// * instruction counts first (init_if_needed), so there is no setup step.
+// *
+// * Counters saturate rather than overflow: a full counter must never fail the instruction
+// * it's counting.
+
+use anchor_lang::prelude::*;
+
+/// * Program-wide totals
+/// * PDA: [b"protocol_stats"]
+#[account]
+pub struct ProtocolStats {
+    /// * Nullifiers registered by every verify_auth path (sessions, shards and trees)
+    pub total_verifications: u64,
+
+    /// * Permission grants, counting updates of an existing grant
+    pub total_grants: u64,
+
+    /// * Permission revocations
+    pub total_revocations: u64,
+
+    /// * Attestations stored (renewals aren't counted)
+    pub total_attestations: u64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+/// * Which ProtocolStats total an instruction adds to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StatsCounter {
+    Verifications,
+    Grants,
+    Revocations,
+    Attestations,
+}
+
+impl ProtocolStats {
+    pub const MAX_SIZE: usize =
+        8 + // total_verifications
+        8 + // total_grants
+        8 + // total_revocations
+        8 + // total_attestations
+        1;  // bump
+
+    /// * Add `count` to a total; bump is stored so the first call initializes the account
+    pub fn record(&mut self, bump: u8, counter: StatsCounter, count: u64) {
+        self.bump = bump;
+        let total = match counter {
+            StatsCounter::Verifications => &mut self.total_verifications,
+            StatsCounter::Grants => &mut self.total_grants,
+            StatsCounter::Revocations => &mut self.total_revocations,
+            StatsCounter::Attestations => &mut self.total_attestations,
+        };
+        *total = total.saturating_add(count);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_saturates() {
+        let mut stats = ProtocolStats {
+            total_verifications: u64::MAX - 1,
+            total_grants: 0,
+            total_revocations: 0,
+            total_attestations: 0,
+            bump: 0,
+        };
+        stats.record(254, StatsCounter::Verifications, 5);
+        stats.record(254, StatsCounter::Grants, 2);
+        assert_eq!(stats.total_verifications, u64::MAX);
+        assert_eq!(stats.total_grants, 2);
+        assert_eq!(stats.total_revocations, 0);
+        assert_eq!(stats.bump, 254);
+    }
+}
@@ -0,0 +1,130 @@
+// * Username state
+// * Unique pseudonymous usernames: a Username PDA is seeded by the normalized name, so names
+// * differing only in case ("Alice", "alice") share one address and only one can be claimed.
+// *
+// * Unlike an Alias (see alias.rs), the name is stored in the clear and can change hands: its
+// * holder releases it, or transfers it to a new nullifier when rotating identity.
+
+use anchor_lang::prelude::*;
+
+use crate::errors::VeiledError;
+use crate::genesis;
+
+/// * A claimed username
+/// * PDA: [b"username", Username::seed(name)]
+#[account]
+pub struct Username {
+    /// * Normalized name (see normalize), null-padded
+    pub name: [u8; 32],
+
+    /// * Nullifier the name belongs to
+    pub nullifier: [u8; 32],
+
+    /// * Null-padded domain of the session the name was claimed (or last transferred) with
+    pub domain: [u8; 32],
+
+    /// * That session's authority; releases and transfers the name
+    pub authority: Pubkey,
+
+    /// * When the name was first claimed
+    pub claimed_at: i64,
+
+    /// * When the name last changed hands (0 = never)
+    pub transferred_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl Username {
+    pub const MIN_LEN: usize = 3;
+    pub const MAX_LEN: usize = 32;
+
+    pub const MAX_SIZE: usize =
+        32 + // name
+        32 + // nullifier
+        32 + // domain
+        32 + // authority
+        8 +  // claimed_at
+        8 +  // transferred_at
+        1;   // bump
+
+    /// * Lowercased, null-padded name: MIN_LEN to MAX_LEN ASCII letters, digits or underscores
+    pub fn normalize(name: &str) -> Result<[u8; 32]> {
+        let bytes = name.as_bytes();
+        let allowed = |b: &u8| b.is_ascii_alphanumeric() || *b == b'_';
+        require!(
+            (Self::MIN_LEN..=Self::MAX_LEN).contains(&bytes.len()) && bytes.iter().all(allowed),
+            VeiledError::InvalidUsername
+        );
+        Ok(Self::seed(name))
+    }
+
+    /// * PDA seed for a name; equals normalize's output for valid names, and never panics
+    /// * on invalid ones so the address can be derived before the handler rejects them
+    pub fn seed(name: &str) -> [u8; 32] {
+        let mut seed = [0u8; 32];
+        for (slot, byte) in seed.iter_mut().zip(name.bytes()) {
+            *slot = byte.to_ascii_lowercase();
+        }
+        seed
+    }
+
+    pub fn name_str(&self) -> &str {
+        let len = self.name.iter().position(|&b| b == 0).unwrap_or(32);
+        core::str::from_utf8(&self.name[..len]).unwrap_or_default()
+    }
+}
+
+/// * The holder's consent to move a username to another nullifier, signed by its authority in
+/// * an Ed25519Program instruction when the authority doesn't sign the transaction itself
+pub struct UsernameTransfer;
+
+impl UsernameTransfer {
+    /// * Domain separator, so a wallet can't be tricked into signing anything else as a transfer
+    pub const MESSAGE_PREFIX: &'static [u8] = b"veiled:username_transfer:v1";
+
+    /// * MESSAGE_PREFIX || instance_id || name || to_nullifier || transferred_at
+    pub const MESSAGE_LEN: usize = Self::MESSAGE_PREFIX.len() + 32 + 32 + 32 + 8;
+
+    /// * Message the authority signs (transferred_at little-endian)
+    /// * Binds the name's current transferred_at, so a consent can't be replayed once the name
+    /// * has moved on
+    pub fn message(
+        name: &[u8; 32],
+        to_nullifier: &[u8; 32],
+        transferred_at: i64,
+    ) -> [u8; Self::MESSAGE_LEN] {
+        let mut message = [0u8; Self::MESSAGE_LEN];
+        let prefix = Self::MESSAGE_PREFIX.len();
+        message[..prefix].copy_from_slice(Self::MESSAGE_PREFIX);
+        message[prefix..prefix + 32].copy_from_slice(&genesis::INSTANCE_ID);
+        message[prefix + 32..prefix + 64].copy_from_slice(name);
+        message[prefix + 64..prefix + 96].copy_from_slice(to_nullifier);
+        message[prefix + 96..].copy_from_slice(&transferred_at.to_le_bytes());
+        message
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize_folds_case() {
+        let name = Username::normalize("Alice_01").unwrap();
+        assert_eq!(name, Username::normalize("alice_01").unwrap());
+        assert_eq!(name, Username::seed("ALICE_01"));
+        assert_eq!(&name[..8], b"alice_01");
+        assert!(name[8..].iter().all(|&b| b == 0));
+    }
+
+    #[test]
+    fn test_normalize_rejects_invalid_names() {
+        assert!(Username::normalize("ab").is_err());
+        assert!(Username::normalize(&"a".repeat(33)).is_err());
+        assert!(Username::normalize("alice bob").is_err());
+        assert!(Username::normalize("ålice").is_err());
+        assert!(Username::normalize(&"a".repeat(32)).is_ok());
+    }
+}
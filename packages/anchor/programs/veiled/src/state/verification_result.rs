@@ -0,0 +1,109 @@
+// * Verification result argument
+// * Typed form of a verifier-signed result, so the IDL spells out its fields instead of clients
+// * packing byte envelopes (verify_auth_v1 still takes those, see ultrahonk.rs)
+
+use anchor_lang::prelude::*;
+
+use crate::errors::VeiledError;
+
+/// * What a verification result attests to; its discriminant is the V8 message's claim_type byte
+/// * Append-only: existing byte values are signed into results already issued
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ClaimType {
+    Auth,             // * Wallet login; the only claim that opens a session (all results before V8)
+    BalanceThreshold, // * Balance at or above a threshold
+    AgeOver,          // * Holder is over an age
+    NftOwnership,     // * Holds an NFT from a collection
+}
+
+impl ClaimType {
+    /// * Bits (see ClaimType::bit) of the claims stored as attestations: all but Auth
+    pub const ATTESTATION_BITS: u8 = 0b1110;
+
+    pub fn from_byte(byte: u8) -> Result<Self> {
+        match byte {
+            0 => Ok(ClaimType::Auth),
+            1 => Ok(ClaimType::BalanceThreshold),
+            2 => Ok(ClaimType::AgeOver),
+            3 => Ok(ClaimType::NftOwnership),
+            _ => err!(VeiledError::UnknownClaimType),
+        }
+    }
+
+    /// * Byte signed into the message
+    pub fn to_byte(self) -> u8 {
+        self as u8
+    }
+
+    /// * Auth results register a nullifier session; other claims are stored as attestations
+    pub fn opens_session(self) -> bool {
+        self == ClaimType::Auth
+    }
+
+    /// * Bit in claim type masks (variant index; append-only, like the enum)
+    pub fn bit(self) -> u8 {
+        1 << (self as u8)
+    }
+
+    /// * Attestation PDA seed: the claim byte, as a slice that outlives Anchor's seed expressions
+    pub fn seed(self) -> &'static [u8] {
+        match self {
+            ClaimType::Auth => &[0],
+            ClaimType::BalanceThreshold => &[1],
+            ClaimType::AgeOver => &[2],
+            ClaimType::NftOwnership => &[3],
+        }
+    }
+}
+
+/// * Verifier-signed verification result, as passed to verify_auth
+/// * The optional fields extend the signed message in this order, each requiring the one before
+/// * it: public_inputs_hash (V3), circuit_id (V4), vk_hash (V5), cluster_id (V6), instance_id (V7),
+/// * claim_type (V8), tagged (V9)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct SignedVerificationResult {
+    pub is_valid: bool,
+
+    /// * SHA256 of the proof
+    pub proof_hash: [u8; 32],
+
+    /// * Unix timestamp when the verifier checked the proof
+    pub timestamp: u64,
+
+    /// * Nullifier the result was issued for
+    pub nullifier: [u8; 32],
+
+    /// * Null-padded domain the result was issued for
+    pub domain: [u8; 32],
+
+    /// * SHA256 of the circuit public inputs
+    pub public_inputs_hash: Option<[u8; 32]>,
+
+    /// * Registered circuit the proof was verified against
+    pub circuit_id: Option<u32>,
+
+    /// * SHA256 of the verification key used
+    pub vk_hash: Option<[u8; 32]>,
+
+    /// * Cluster the result was issued for (see cluster.rs)
+    pub cluster_id: Option<[u8; 32]>,
+
+    /// * Deployment the result was issued for (see genesis.rs)
+    pub instance_id: Option<[u8; 32]>,
+
+    /// * What the result attests to; None means Auth
+    pub claim_type: Option<ClaimType>,
+
+    /// * Whether the message is prefixed with the protocol tag (ultrahonk::MESSAGE_TAG)
+    pub tagged: bool,
+
+    /// * Ed25519 signature over the message; co-signers add their own precompile instructions
+    pub signature: [u8; 64],
+}
+
+impl SignedVerificationResult {
+    /// * Claim the result makes; results without one (before V8) are logins
+    pub fn claim(&self) -> ClaimType {
+        self.claim_type.unwrap_or(ClaimType::Auth)
+    }
+}
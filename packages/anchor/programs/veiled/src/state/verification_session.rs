@@ -0,0 +1,84 @@
+// * Verification session state
+// * Multi-transaction on-chain UltraHonk verification: a proof too large for one transaction is
+// * uploaded in chunks, verified over several instructions, then finalized into a NullifierAccount
+
+use anchor_lang::prelude::*;
+
+/// * Header of a chunked verification. The verifier's scratch area follows it at
+/// * SCRATCH_OFFSET, then the uploaded bytes at UPLOAD_OFFSET.
+/// * Uploaded bytes: public inputs (32 bytes each, big-endian) || UltraHonk proof
+/// * PDA: [b"verification_session", authority, nullifier]
+#[account]
+pub struct VerificationSession {
+    /// * Opened the session, pays its rent and becomes the NullifierAccount authority
+    pub authority: Pubkey,
+
+    pub nullifier: [u8; 32],
+
+    /// * Null-padded domain (same encoding as the verify_auth `domain` argument)
+    pub domain: [u8; 32],
+
+    /// * Registered circuit the proof is for
+    pub circuit_id: u32,
+
+    /// * SHA256 of the full upload, committed when the session is opened
+    pub proof_hash: [u8; 32],
+
+    /// * Total upload size in bytes (public inputs included)
+    pub proof_len: u32,
+
+    /// * Public inputs at the start of the upload; the first two bind the nullifier and domain
+    pub public_inputs_count: u16,
+
+    /// * Uploaded prefix length; chunks must arrive in order
+    pub bytes_received: u32,
+
+    pub status: VerificationStatus,
+
+    /// * Verifier steps completed (see honk.rs for the phase each step runs)
+    pub step: u32,
+
+    pub opened_at: i64,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum VerificationStatus {
+    Uploading, // * Waiting for proof chunks
+    Verifying, // * Upload matches proof_hash; advance_verification_session runs the verifier
+    Verified,  // * Proof accepted; finalize_verification_session registers the nullifier
+    Rejected,  // * Proof failed verification; the session can only be closed
+}
+
+impl VerificationSession {
+    pub const MAX_SIZE: usize =
+        32 + // authority
+        32 + // nullifier
+        32 + // domain
+        4 +  // circuit_id
+        32 + // proof_hash
+        4 +  // proof_len
+        2 +  // public_inputs_count
+        4 +  // bytes_received
+        1 +  // status
+        4 +  // step
+        8 +  // opened_at
+        1;   // bump
+
+    /// * Verifier state carried between steps (transcript challenges, MSM accumulator); kept
+    /// * out of the header so it is never copied onto the stack
+    pub const SCRATCH_SIZE: usize = 4096;
+
+    /// * Where the scratch area starts in the account data (after discriminator and header)
+    pub const SCRATCH_OFFSET: usize = 8 + Self::MAX_SIZE;
+
+    /// * Where the uploaded bytes start in the account data
+    pub const UPLOAD_OFFSET: usize = Self::SCRATCH_OFFSET + Self::SCRATCH_SIZE;
+
+    /// * Public inputs section of the upload
+    pub fn public_inputs_len(&self) -> usize {
+        usize::from(self.public_inputs_count) * 32
+    }
+}
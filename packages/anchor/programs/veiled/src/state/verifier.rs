@@ -0,0 +1,424 @@
+// * Verifier registry state
+// * Admin-managed allowlist of Ed25519 keys whose verification results verify_auth accepts,
+// * plus a parallel allowlist of secp256r1 (passkey) keys
+
+use anchor_lang::prelude::*;
+use solana_sha256_hasher::hashv;
+
+use crate::genesis;
+use crate::state::config::AssurancePolicy;
+use crate::ultrahonk::SECP256R1_PUBKEY_LEN;
+
+#[account]
+pub struct VerifierRegistry {
+    /// * Verifier service keys allowed to sign verification results
+    pub verifiers: Vec<Pubkey>,
+
+    /// * Distinct registered verifiers that must co-sign a result (m of n)
+    pub threshold: u8,
+
+    /// * PDA bump
+    pub bump: u8,
+
+    /// * Recent key rotations; a retired key keeps signing for its successor during the grace window
+    pub rotations: Vec<VerifierRotation>,
+
+    /// * How long a rotated-out key stays accepted (0 = rotations take effect immediately)
+    pub grace_period_secs: i64,
+
+    /// * How long a consumed result's receipt is kept before it can be closed (0 = the default)
+    pub receipt_retention_secs: i64,
+}
+
+/// * One rotate_verifier_key call
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VerifierRotation {
+    pub old_key: Pubkey,
+    pub new_key: Pubkey,
+    pub rotated_at: i64,
+}
+
+impl VerifierRotation {
+    pub const SIZE: usize = 32 + 32 + 8;
+}
+
+impl VerifierRegistry {
+    pub const MAX_VERIFIERS: usize = 16;
+    pub const MAX_ROTATIONS: usize = Self::MAX_VERIFIERS;
+
+    /// * Results are fresh for at most an hour (AssurancePolicy), so a day covers any in-flight
+    /// * result with margin
+    pub const MAX_GRACE_PERIOD_SECS: i64 = 24 * 60 * 60;
+
+    /// * Receipts outlive the widest staleness window, so a result whose receipt was closed can
+    /// * no longer pass is_recent
+    pub const MIN_RECEIPT_RETENTION_SECS: i64 = AssurancePolicy::MAX_RESULT_AGE_SECS as i64;
+    pub const MAX_RECEIPT_RETENTION_SECS: i64 = 30 * 24 * 60 * 60;
+    pub const DEFAULT_RECEIPT_RETENTION_SECS: i64 = genesis::DEFAULT_RECEIPT_RETENTION_SECS;
+
+    pub const MAX_SIZE: usize =
+        (4 + Self::MAX_VERIFIERS * 32) +                     // verifiers
+        1 +                                                  // threshold
+        1 +                                                  // bump
+        (4 + Self::MAX_ROTATIONS * VerifierRotation::SIZE) + // rotations
+        8 +                                                  // grace_period_secs
+        8;                                                   // receipt_retention_secs
+
+    pub fn is_registered(&self, verifier: &Pubkey) -> bool {
+        self.verifiers.contains(verifier)
+    }
+
+    /// * Registered, or rotated out less than grace_period_secs ago
+    pub fn is_accepted(&self, verifier: &Pubkey, now: i64) -> bool {
+        self.is_registered(verifier)
+            || self
+                .retiring_keys(now)
+                .iter()
+                .any(|(old_key, _)| old_key == verifier)
+    }
+
+    /// * Retention window for new result receipts, falling back to the default when unset
+    pub fn receipt_retention(&self) -> i64 {
+        match self.receipt_retention_secs {
+            0 => Self::DEFAULT_RECEIPT_RETENTION_SECS,
+            secs => secs,
+        }
+    }
+
+    /// * Co-signatures verify_auth requires; a fresh registry needs just one
+    pub fn required_signatures(&self) -> usize {
+        usize::from(self.threshold.max(1))
+    }
+
+    /// * Rotated-out keys still inside the grace window, each paired with the registered key
+    /// * that replaced it (following chained rotations). Keys whose successor has since been
+    /// * removed are dropped, so removing a verifier also ends its old keys' grace.
+    pub fn retiring_keys(&self, now: i64) -> Vec<(Pubkey, Pubkey)> {
+        self.rotations
+            .iter()
+            .filter(|rotation| self.in_grace(rotation, now))
+            .filter_map(|rotation| {
+                let current = self.successor(rotation.new_key)?;
+                Some((rotation.old_key, current))
+            })
+            .collect()
+    }
+
+    /// * Record a rotation, forgetting ones whose grace window has passed
+    pub fn record_rotation(&mut self, rotation: VerifierRotation) -> bool {
+        let now = rotation.rotated_at;
+        let grace_period_secs = self.grace_period_secs;
+        self.rotations
+            .retain(|existing| existing.rotated_at.saturating_add(grace_period_secs) > now);
+        if self.rotations.len() >= Self::MAX_ROTATIONS {
+            return false;
+        }
+        self.rotations.push(rotation);
+        true
+    }
+
+    fn in_grace(&self, rotation: &VerifierRotation, now: i64) -> bool {
+        now < rotation.rotated_at.saturating_add(self.grace_period_secs)
+    }
+
+    /// * The registered key `key` was eventually rotated to, if it's still registered
+    fn successor(&self, mut key: Pubkey) -> Option<Pubkey> {
+        // * Bounded in case a key was rotated back and forth
+        for _ in 0..=self.rotations.len() {
+            if self.is_registered(&key) {
+                return Some(key);
+            }
+            key = self
+                .rotations
+                .iter()
+                .find(|rotation| rotation.old_key == key)?
+                .new_key;
+        }
+        None
+    }
+}
+
+/// * Passkey / WebAuthn-device verifier keys, checked through the Secp256r1 precompile
+/// * They co-sign like registered Ed25519 verifiers and count towards the same threshold
+/// * PDA: [b"secp256r1_verifier_registry"]
+#[account]
+pub struct Secp256r1VerifierRegistry {
+    /// * Compressed P-256 public keys (0x02 / 0x03 prefix)
+    pub verifiers: Vec<[u8; SECP256R1_PUBKEY_LEN]>,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+impl Secp256r1VerifierRegistry {
+    pub const MAX_VERIFIERS: usize = VerifierRegistry::MAX_VERIFIERS;
+
+    pub const MAX_SIZE: usize =
+        (4 + Self::MAX_VERIFIERS * SECP256R1_PUBKEY_LEN) + // verifiers
+        1;                                                 // bump
+
+    /// * Whether `key` looks like a compressed P-256 point (the precompile does the curve check)
+    pub fn is_compressed_key(key: &[u8; SECP256R1_PUBKEY_LEN]) -> bool {
+        matches!(key[0], 0x02 | 0x03)
+    }
+}
+
+/// * Stake weight and liveness of registered verifiers, read by select_verifier and the SDK so
+/// * clients spread load across verifier operators without a coordinator
+/// * PDA: [b"verifier_weights"]
+#[account]
+pub struct VerifierWeights {
+    /// * One entry per weighted verifier; unweighted verifiers are never selected
+    pub entries: Vec<VerifierWeight>,
+
+    /// * PDA bump
+    pub bump: u8,
+}
+
+/// * Selection weight of one verifier key
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VerifierWeight {
+    pub verifier: Pubkey,
+
+    /// * Relative share of selections (1..=MAX_WEIGHT)
+    pub weight: u8,
+
+    /// * Last verifier_heartbeat (0 = never); stale verifiers are skipped
+    pub last_heartbeat: i64,
+}
+
+impl VerifierWeight {
+    pub const SIZE: usize = 32 + 1 + 8;
+}
+
+impl VerifierWeights {
+    pub const MAX_ENTRIES: usize = VerifierRegistry::MAX_VERIFIERS;
+    pub const MAX_WEIGHT: u8 = 16;
+
+    /// * A verifier counts as live for this long after its last heartbeat
+    pub const LIVENESS_WINDOW_SECS: i64 = 10 * 60;
+
+    /// * Domain separator for selection draws (mirrored by the SDK)
+    pub const SELECTION_SEED: &'static [u8] = b"veiled-verifier-selection";
+
+    pub const MAX_SIZE: usize =
+        (4 + Self::MAX_ENTRIES * VerifierWeight::SIZE) + // entries
+        1;                                               // bump
+
+    /// * Set `verifier`'s weight, 0 removing it; false when a new entry wouldn't fit
+    pub fn set_weight(&mut self, verifier: Pubkey, weight: u8) -> bool {
+        let index = self
+            .entries
+            .iter()
+            .position(|entry| entry.verifier == verifier);
+        match (index, weight) {
+            (Some(index), 0) => {
+                self.entries.swap_remove(index);
+            }
+            (Some(index), _) => self.entries[index].weight = weight,
+            (None, 0) => {}
+            (None, _) => {
+                if self.entries.len() >= Self::MAX_ENTRIES {
+                    return false;
+                }
+                self.entries.push(VerifierWeight {
+                    verifier,
+                    weight,
+                    last_heartbeat: 0,
+                });
+            }
+        }
+        true
+    }
+
+    pub fn entry_mut(&mut self, verifier: &Pubkey) -> Option<&mut VerifierWeight> {
+        self.entries
+            .iter_mut()
+            .find(|entry| &entry.verifier == verifier)
+    }
+
+    /// * Weighted rendezvous: every registered, live entry gets `weight` draws of
+    /// * selection_draw and the highest draw wins, so each verifier is picked with probability
+    /// * weight / total weight and removing one only moves the nullifiers it owned
+    pub fn select(
+        &self,
+        registry: &VerifierRegistry,
+        nullifier: &[u8; 32],
+        now: i64,
+    ) -> Option<Pubkey> {
+        self.entries
+            .iter()
+            .filter(|entry| entry.weight > 0 && registry.is_registered(&entry.verifier))
+            .filter(|entry| {
+                now < entry
+                    .last_heartbeat
+                    .saturating_add(Self::LIVENESS_WINDOW_SECS)
+            })
+            .map(|entry| {
+                let best = (0..entry.weight)
+                    .map(|draw| selection_draw(nullifier, &entry.verifier, draw))
+                    .max()
+                    .unwrap_or(0);
+                // * Key bytes break (practically impossible) draw ties deterministically
+                (best, entry.verifier.to_bytes())
+            })
+            .max()
+            .map(|(_, verifier)| Pubkey::new_from_array(verifier))
+    }
+}
+
+/// * Draw `index` for (nullifier, verifier): the first 8 bytes, big-endian, of
+/// * SHA-256(SELECTION_SEED || nullifier || verifier || index)
+pub fn selection_draw(nullifier: &[u8; 32], verifier: &Pubkey, index: u8) -> u64 {
+    let hash = hashv(&[
+        VerifierWeights::SELECTION_SEED,
+        nullifier,
+        verifier.as_ref(),
+        &[index],
+    ])
+    .to_bytes();
+    let mut draw = [0u8; 8];
+    draw.copy_from_slice(&hash[..8]);
+    u64::from_be_bytes(draw)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(byte: u8) -> Pubkey {
+        Pubkey::new_from_array([byte; 32])
+    }
+
+    fn registry(verifiers: Vec<Pubkey>, grace_period_secs: i64) -> VerifierRegistry {
+        VerifierRegistry {
+            verifiers,
+            threshold: 1,
+            bump: 255,
+            rotations: Vec::new(),
+            grace_period_secs,
+            receipt_retention_secs: 0,
+        }
+    }
+
+    fn rotate(registry: &mut VerifierRegistry, old_key: Pubkey, new_key: Pubkey, at: i64) {
+        let index = registry.verifiers.iter().position(|k| k == &old_key).unwrap();
+        registry.verifiers[index] = new_key;
+        assert!(registry.record_rotation(VerifierRotation {
+            old_key,
+            new_key,
+            rotated_at: at,
+        }));
+    }
+
+    #[test]
+    fn test_rotation_grace_window() {
+        let mut registry = registry(vec![key(1), key(2)], 600);
+        rotate(&mut registry, key(1), key(3), 1_000);
+
+        assert!(registry.is_accepted(&key(1), 1_599));
+        assert!(!registry.is_accepted(&key(1), 1_600));
+        assert!(registry.is_accepted(&key(3), 1_600));
+        assert_eq!(registry.retiring_keys(1_000), vec![(key(1), key(3))]);
+
+        // * Chained rotations resolve to the current key
+        rotate(&mut registry, key(3), key(4), 1_100);
+        assert_eq!(
+            registry.retiring_keys(1_100),
+            vec![(key(1), key(4)), (key(3), key(4))]
+        );
+
+        // * Removing the successor ends the old keys' grace
+        registry.verifiers.retain(|k| k != &key(4));
+        assert!(registry.retiring_keys(1_100).is_empty());
+    }
+
+    #[test]
+    fn test_rotation_without_grace() {
+        let mut registry = registry(vec![key(1)], 0);
+        rotate(&mut registry, key(1), key(2), 1_000);
+
+        assert!(!registry.is_accepted(&key(1), 1_000));
+        assert_eq!(registry.rotations.len(), 1);
+
+        // * Expired records are pruned on the next rotation
+        rotate(&mut registry, key(2), key(3), 1_001);
+        assert_eq!(registry.rotations.len(), 1);
+    }
+
+    fn weights(entries: &[(u8, u8, i64)]) -> VerifierWeights {
+        VerifierWeights {
+            entries: entries
+                .iter()
+                .map(|&(byte, weight, last_heartbeat)| VerifierWeight {
+                    verifier: key(byte),
+                    weight,
+                    last_heartbeat,
+                })
+                .collect(),
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn test_receipt_retention() {
+        let mut registry = registry(vec![key(1)], 0);
+        assert_eq!(registry.receipt_retention(), 24 * 60 * 60);
+
+        registry.receipt_retention_secs = VerifierRegistry::MIN_RECEIPT_RETENTION_SECS;
+        assert_eq!(registry.receipt_retention(), 60 * 60);
+    }
+
+    #[test]
+    fn test_selection_skips_ineligible_verifiers() {
+        // * key(2) is stale, key(3) unregistered, key(4) has no weight
+        let registry = registry(vec![key(1), key(2), key(4)], 0);
+        let weights = weights(&[(1, 1, 1_000), (2, 16, 0), (3, 16, 1_000), (4, 0, 1_000)]);
+
+        for byte in 0..32u8 {
+            assert_eq!(weights.select(&registry, &[byte; 32], 1_000), Some(key(1)));
+        }
+
+        let stale = 1_000 + VerifierWeights::LIVENESS_WINDOW_SECS;
+        assert_eq!(weights.select(&registry, &[0; 32], stale), None);
+    }
+
+    fn nullifier(i: u32) -> [u8; 32] {
+        let mut nullifier = [0u8; 32];
+        nullifier[..4].copy_from_slice(&i.to_le_bytes());
+        nullifier
+    }
+
+    #[test]
+    fn test_selection_follows_weight() {
+        let registry = registry(vec![key(1), key(2)], 0);
+        let weights = weights(&[(1, 1, 1_000), (2, 3, 1_000)]);
+
+        let heavy = (0..2_000)
+            .filter(|&i| weights.select(&registry, &nullifier(i), 1_000) == Some(key(2)))
+            .count();
+
+        // * Expect ~1500 of 2000 (3 / 4)
+        assert!(
+            (1_400..1_600).contains(&heavy),
+            "heavy verifier picked {heavy} times"
+        );
+    }
+
+    #[test]
+    fn test_removal_only_moves_owned_nullifiers() {
+        let registry = registry(vec![key(1), key(2), key(3)], 0);
+        let mut weights = weights(&[(1, 2, 1_000), (2, 2, 1_000), (3, 2, 1_000)]);
+        let before: Vec<_> = (0..300)
+            .map(|i| weights.select(&registry, &nullifier(i), 1_000).unwrap())
+            .collect();
+
+        assert!(weights.set_weight(key(1), 0));
+        for (i, pick) in (0..300).zip(before) {
+            let after = weights.select(&registry, &nullifier(i), 1_000).unwrap();
+            if pick != key(1) {
+                assert_eq!(after, pick);
+            }
+        }
+    }
+}
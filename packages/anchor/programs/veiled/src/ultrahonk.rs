@@ -7,11 +7,17 @@
 // * Verification Flow:
 // * 1. Client generates proof using Noir
 // * 2. Client verifies proof using @aztec/bb.js (WASM) - ~100-500ms
-// * 3. Client signs verification result: sign(sha256(proof_hash || is_valid || timestamp))
+// * 3. Client signs verification result: sign(["veiled:v1" ||] proof_hash || is_valid || timestamp || nullifier || domain || public_inputs_hash || circuit_id || vk_hash || cluster_id || instance_id || claim_type)
 // * 4. Client submits signed result to Solana program
 // * 5. Program validates signature and stores result
 
+use crate::cluster;
 use crate::errors::VeiledError;
+use crate::genesis;
+use crate::sig_introspection::{
+    self, ED25519_PROGRAM_ID, MAX_ED25519_INSTRUCTIONS, SECP256R1_PROGRAM_ID,
+};
+use crate::state::verification_result::{ClaimType, SignedVerificationResult};
 use anchor_lang::prelude::*;
 // * Use Anchor's re-exported Solana types to avoid version conflicts
 // * This ensures AccountInfo and Instruction types match across the codebase
@@ -19,15 +25,398 @@ use anchor_lang::solana_program::instruction::Instruction as SolanaInstruction;
 // * Import instruction introspection helpers from solana-instructions-sysvar crate
 // * Anchor 0.32+ uses split Solana crates, so these functions are in a separate crate
 // * Functions are at the crate root, not under a module
-use solana_instructions_sysvar::{load_current_index_checked, load_instruction_at_checked};
-use std::io::{Cursor, Read};
+use solana_instructions_sysvar::load_instruction_at_checked;
+
+/// * Length of the Ed25519-signed message for V1/V2 results
+/// * proof_hash (32) || is_valid (1) || timestamp (8) || nullifier (32) || domain (32)
+pub const MESSAGE_LEN: usize = 105;
+
+/// * Length of the Ed25519-signed message for V3 results
+/// * V1/V2 message (105) || public_inputs_hash (32)
+pub const MESSAGE_V3_LEN: usize = MESSAGE_LEN + 32;
+
+/// * Length of the Ed25519-signed message for V4 results
+/// * V3 message (137) || circuit_id (4, little-endian)
+pub const MESSAGE_V4_LEN: usize = MESSAGE_V3_LEN + 4;
+
+/// * Length of the Ed25519-signed message for V5 results
+/// * V4 message (141) || vk_hash (32)
+pub const MESSAGE_V5_LEN: usize = MESSAGE_V4_LEN + 32;
+
+/// * Length of the Ed25519-signed message for V6 results
+/// * V5 message (173) || cluster_id (32, see cluster.rs)
+pub const MESSAGE_V6_LEN: usize = MESSAGE_V5_LEN + 32;
+
+/// * Length of the Ed25519-signed message for V7 results
+/// * V6 message (205) || instance_id (32, see genesis.rs)
+pub const MESSAGE_V7_LEN: usize = MESSAGE_V6_LEN + 32;
+
+/// * Length of the Ed25519-signed message for V8 results
+/// * V7 message (237) || claim_type (1, see ClaimType)
+pub const MESSAGE_V8_LEN: usize = MESSAGE_V7_LEN + 1;
+
+/// * Protocol tag V9 messages start with, so a signature a verifier key made for another
+/// * protocol (one that happens to share a result's layout) can't pass as a Veiled result
+pub const MESSAGE_TAG: &[u8] = b"veiled:v1";
+
+/// * Length of the Ed25519-signed message for V9 results
+/// * MESSAGE_TAG (9) || V8 message (238)
+pub const MESSAGE_V9_LEN: usize = MESSAGE_TAG.len() + MESSAGE_V8_LEN;
+
+/// * Length of the serialized verification result body passed to verify_auth
+/// * is_valid (1) || proof_hash (32) || timestamp (8) || nullifier (32) || domain (32) || signature (64)
+/// * Legacy (V1) clients send the body alone; V2 prefixes it with a version byte
+pub const RESULT_LEN: usize = 169;
+
+/// * Same bound for Secp256r1 precompile instructions (passkey co-signers)
+pub const MAX_SECP256R1_INSTRUCTIONS: usize = MAX_ED25519_INSTRUCTIONS;
+
+/// * How far ahead of the cluster clock a result's timestamp may be (verifier clock drift)
+pub const MAX_CLOCK_SKEW_SECS: i64 = 60;
+
+/// * Compressed secp256r1 (P-256) public key length, as the Secp256r1 precompile takes it
+pub const SECP256R1_PUBKEY_LEN: usize = 33;
+
+/// * Optional fields a result body carries between domain and signature, by version
+/// * Each layout includes every field of the ones before it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum BodyLayout {
+    Legacy,       // * V1/V2: none
+    PublicInputs, // * V3: public_inputs_hash (32)
+    CircuitId,    // * V4: + circuit_id (4)
+    VkHash,       // * V5: + vk_hash (32)
+    ClusterId,    // * V6: + cluster_id (32)
+    InstanceId,   // * V7: + instance_id (32)
+    ClaimType,    // * V8: + claim_type (1)
+    Tagged,       // * V9: V8 fields; the signed message starts with MESSAGE_TAG
+}
+
+impl BodyLayout {
+    const fn extension_len(self) -> usize {
+        match self {
+            BodyLayout::Legacy => 0,
+            BodyLayout::PublicInputs => 32,
+            BodyLayout::CircuitId => 32 + 4,
+            BodyLayout::VkHash => 32 + 4 + 32,
+            BodyLayout::ClusterId => 32 + 4 + 32 + 32,
+            BodyLayout::InstanceId => 32 + 4 + 32 + 32 + 32,
+            BodyLayout::ClaimType | BodyLayout::Tagged => 32 + 4 + 32 + 32 + 32 + 1,
+        }
+    }
+}
+
+/// * Signed message bytes for a result (105, 137, 141, 173, 205, 237, 238 or 247 bytes)
+/// * Backed by a fixed-size array to avoid BPF heap allocation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignedMessage {
+    bytes: [u8; MESSAGE_V9_LEN],
+    len: usize,
+}
+
+impl std::ops::Deref for SignedMessage {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+}
+
+/// * Unread rest of a result body; fields are split off the front as fixed-size arrays
+struct BodyReader<'a>(&'a [u8]);
+
+impl BodyReader<'_> {
+    fn take<const N: usize>(&mut self) -> Result<[u8; N]> {
+        require!(self.0.len() >= N, VeiledError::ResultLengthMismatch);
+        let (field, rest) = self.0.split_at(N);
+        self.0 = rest;
+        <[u8; N]>::try_from(field)
+            .map_err(|_| anchor_lang::error!(VeiledError::ResultLengthMismatch))
+    }
+
+    /// * Next field if `layout` carries it (layouts from `since` on), None otherwise
+    fn take_from<const N: usize>(
+        &mut self,
+        layout: BodyLayout,
+        since: BodyLayout,
+    ) -> Result<Option<[u8; N]>> {
+        if layout >= since {
+            self.take().map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+}
+
+/// * Equality without an early exit, so timing doesn't reveal how much of a signature matched
+fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// * Version 9 verification result envelope
+/// * Format: V8 layout with version = 9
+/// * Total: 303 bytes (exact)
+/// *
+/// * The body is unchanged from V8, but the signed message is prefixed with MESSAGE_TAG. A
+/// * verifier that signs only tagged messages can't have a signature it made for another
+/// * protocol replayed as a Veiled result.
+#[derive(Debug, Clone)]
+pub struct VerificationResultV9 {
+    pub result: VerificationResult,
+}
+
+impl VerificationResultV9 {
+    pub const VERSION: u8 = 9;
+    pub const LEN: usize = 1 + RESULT_LEN + BodyLayout::Tagged.extension_len();
+
+    pub fn from_instruction_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.first() == Some(&Self::VERSION),
+            VeiledError::UnsupportedResultVersion
+        );
+        require!(data.len() == Self::LEN, VeiledError::ResultLengthMismatch);
+
+        let result = VerificationResult::from_body(&data[1..], BodyLayout::Tagged)?;
+        require!(
+            result.public_inputs_hash != Some([0u8; 32]),
+            VeiledError::InvalidPublicInputs
+        );
+
+        Ok(Self { result })
+    }
+}
+
+/// * Version 8 verification result envelope
+/// * Format: V7 layout with [1 byte: claim_type] after instance_id
+/// * Total: 303 bytes (exact)
+/// *
+/// * Says what the result attests to (see ClaimType), so one verifier can sign results for
+/// * proof products other than login without those being accepted as logins.
+#[derive(Debug, Clone)]
+pub struct VerificationResultV8 {
+    pub result: VerificationResult,
+}
 
-// * Ed25519 signature verification program id (Solana built-in program)
-// * Base58: Ed25519SigVerify111111111111111111111111111
-const ED25519_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
-    0x03, 0x7d, 0x46, 0xd6, 0x7c, 0x93, 0xfb, 0xbe, 0x12, 0xf9, 0x42, 0x8f, 0x83, 0x8d, 0x40, 0xff,
-    0x05, 0x70, 0x74, 0x49, 0x27, 0xf4, 0x8a, 0x64, 0xfc, 0xca, 0x70, 0x44, 0x80, 0x00, 0x00, 0x00,
-]);
+impl VerificationResultV8 {
+    pub const VERSION: u8 = 8;
+    pub const LEN: usize = 1 + RESULT_LEN + BodyLayout::ClaimType.extension_len();
+
+    pub fn from_instruction_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.first() == Some(&Self::VERSION),
+            VeiledError::UnsupportedResultVersion
+        );
+        require!(data.len() == Self::LEN, VeiledError::ResultLengthMismatch);
+
+        let result = VerificationResult::from_body(&data[1..], BodyLayout::ClaimType)?;
+        require!(
+            result.public_inputs_hash != Some([0u8; 32]),
+            VeiledError::InvalidPublicInputs
+        );
+
+        Ok(Self { result })
+    }
+}
+
+/// * Version 7 verification result envelope
+/// * Format: V6 layout with [32 bytes: instance_id] after cluster_id
+/// * Total: 302 bytes (exact)
+/// *
+/// * Binds the deployment the result was issued for (see genesis.rs), so a result signed for
+/// * one white-label instance can't be replayed on another that trusts the same verifier.
+/// * Only results for this build's cluster and instance are accepted.
+#[derive(Debug, Clone)]
+pub struct VerificationResultV7 {
+    pub result: VerificationResult,
+}
+
+impl VerificationResultV7 {
+    pub const VERSION: u8 = 7;
+    pub const LEN: usize = 1 + RESULT_LEN + BodyLayout::InstanceId.extension_len();
+
+    pub fn from_instruction_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.first() == Some(&Self::VERSION),
+            VeiledError::UnsupportedResultVersion
+        );
+        require!(data.len() == Self::LEN, VeiledError::ResultLengthMismatch);
+
+        let result = VerificationResult::from_body(&data[1..], BodyLayout::InstanceId)?;
+        require!(
+            result.public_inputs_hash != Some([0u8; 32]),
+            VeiledError::InvalidPublicInputs
+        );
+
+        Ok(Self { result })
+    }
+}
+
+/// * Version 6 verification result envelope
+/// * Format: V5 layout with [32 bytes: cluster_id] after vk_hash
+/// * Total: 270 bytes (exact)
+/// *
+/// * Binds the cluster the result was issued for (see cluster.rs), so a result signed for
+/// * devnet can't be replayed on mainnet. Only results for this build's cluster are accepted.
+#[derive(Debug, Clone)]
+pub struct VerificationResultV6 {
+    pub result: VerificationResult,
+}
+
+impl VerificationResultV6 {
+    pub const VERSION: u8 = 6;
+    pub const LEN: usize = 1 + RESULT_LEN + BodyLayout::ClusterId.extension_len();
+
+    pub fn from_instruction_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.first() == Some(&Self::VERSION),
+            VeiledError::UnsupportedResultVersion
+        );
+        require!(data.len() == Self::LEN, VeiledError::ResultLengthMismatch);
+
+        let result = VerificationResult::from_body(&data[1..], BodyLayout::ClusterId)?;
+        require!(
+            result.public_inputs_hash != Some([0u8; 32]),
+            VeiledError::InvalidPublicInputs
+        );
+
+        Ok(Self { result })
+    }
+}
+
+/// * Version 5 verification result envelope
+/// * Format: [1 byte: version = 5] [1 byte: is_valid] [32 bytes: proof_hash] [8 bytes: timestamp] [32 bytes: nullifier] [32 bytes: domain] [32 bytes: public_inputs_hash] [4 bytes: circuit_id] [32 bytes: vk_hash] [64 bytes: signature]
+/// * Total: 238 bytes (exact)
+/// *
+/// * Binds the verification key hash the verifier checked the proof against, so domains
+/// * that pin a key hash can detect a verifier swapping circuits underneath them.
+#[derive(Debug, Clone)]
+pub struct VerificationResultV5 {
+    pub result: VerificationResult,
+}
+
+impl VerificationResultV5 {
+    pub const VERSION: u8 = 5;
+    pub const LEN: usize = 1 + RESULT_LEN + BodyLayout::VkHash.extension_len();
+
+    pub fn from_instruction_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.first() == Some(&Self::VERSION),
+            VeiledError::UnsupportedResultVersion
+        );
+        require!(data.len() == Self::LEN, VeiledError::ResultLengthMismatch);
+
+        let result = VerificationResult::from_body(&data[1..], BodyLayout::VkHash)?;
+        require!(
+            result.public_inputs_hash != Some([0u8; 32]),
+            VeiledError::InvalidPublicInputs
+        );
+
+        Ok(Self { result })
+    }
+}
+
+/// * Version 4 verification result envelope
+/// * Format: [1 byte: version = 4] [1 byte: is_valid] [32 bytes: proof_hash] [8 bytes: timestamp] [32 bytes: nullifier] [32 bytes: domain] [32 bytes: public_inputs_hash] [4 bytes: circuit_id] [64 bytes: signature]
+/// * Total: 206 bytes (exact)
+/// *
+/// * Binds the circuit ID into the signed message, so a result for one circuit
+/// * (e.g. balance) can't be presented as another (e.g. auth).
+#[derive(Debug, Clone)]
+pub struct VerificationResultV4 {
+    pub result: VerificationResult,
+}
+
+impl VerificationResultV4 {
+    pub const VERSION: u8 = 4;
+    pub const LEN: usize = 1 + RESULT_LEN + BodyLayout::CircuitId.extension_len();
+
+    pub fn from_instruction_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.first() == Some(&Self::VERSION),
+            VeiledError::UnsupportedResultVersion
+        );
+        require!(data.len() == Self::LEN, VeiledError::ResultLengthMismatch);
+
+        let result = VerificationResult::from_body(&data[1..], BodyLayout::CircuitId)?;
+        require!(
+            result.public_inputs_hash != Some([0u8; 32]),
+            VeiledError::InvalidPublicInputs
+        );
+
+        Ok(Self { result })
+    }
+}
+
+/// * Version 3 verification result envelope
+/// * Format: [1 byte: version = 3] [1 byte: is_valid] [32 bytes: proof_hash] [8 bytes: timestamp] [32 bytes: nullifier] [32 bytes: domain] [32 bytes: public_inputs_hash] [64 bytes: signature]
+/// * Total: 202 bytes (exact)
+/// *
+/// * Binds the hash of the circuit's public inputs into the signed message, so the
+/// * stored result records which statement was verified.
+#[derive(Debug, Clone)]
+pub struct VerificationResultV3 {
+    pub result: VerificationResult,
+}
+
+impl VerificationResultV3 {
+    pub const VERSION: u8 = 3;
+    pub const LEN: usize = 1 + RESULT_LEN + BodyLayout::PublicInputs.extension_len();
+
+    pub fn from_instruction_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.first() == Some(&Self::VERSION),
+            VeiledError::UnsupportedResultVersion
+        );
+        require!(data.len() == Self::LEN, VeiledError::ResultLengthMismatch);
+
+        let result = VerificationResult::from_body(&data[1..], BodyLayout::PublicInputs)?;
+        require!(
+            result.public_inputs_hash != Some([0u8; 32]),
+            VeiledError::InvalidPublicInputs
+        );
+
+        Ok(Self { result })
+    }
+}
+
+/// * Version 2 verification result envelope
+/// * Format: [1 byte: version = 2] [169 bytes: V1 body]
+/// * Total: 170 bytes (exact; trailing bytes are rejected)
+/// *
+/// * The signed message is unchanged from V1. A future version that changes the
+/// * signed content or signature scheme gets its own parser and message builder.
+#[derive(Debug, Clone)]
+pub struct VerificationResultV2 {
+    pub result: VerificationResult,
+}
+
+impl VerificationResultV2 {
+    pub const VERSION: u8 = 2;
+    pub const LEN: usize = 1 + RESULT_LEN;
+
+    pub fn from_instruction_data(data: &[u8]) -> Result<Self> {
+        require!(
+            data.first() == Some(&Self::VERSION),
+            VeiledError::UnsupportedResultVersion
+        );
+        require!(data.len() == Self::LEN, VeiledError::ResultLengthMismatch);
+
+        Ok(Self {
+            result: VerificationResult::from_body(&data[1..], BodyLayout::Legacy)?,
+        })
+    }
+}
+
+/// * proof_hash bytes of serialized result data, for deriving the ProofRecord PDA before parsing
+/// * Every version carries it right after is_valid; empty when the data is too short (parsing
+/// * rejects such data anyway)
+pub fn proof_hash_seed(data: &[u8]) -> &[u8] {
+    // * Legacy bodies start with is_valid, versioned envelopes with the version byte
+    let offset = match data.first() {
+        Some(0) | Some(1) => 1,
+        _ => 2,
+    };
+    data.get(offset..offset + 32).unwrap_or(&[])
+}
 
 /// * Verification result structure
 /// * Client verifies proof off-chain and signs this result
@@ -36,127 +425,352 @@ pub struct VerificationResult {
     pub is_valid: bool,
     pub proof_hash: [u8; 32], // * SHA256 hash of proof (prevents tampering)
     pub timestamp: u64,       // * Unix timestamp when verified
-    pub verifier_signature: [u8; 64], // * Ed25519 signature from verifier wallet
+    pub nullifier: [u8; 32],  // * Nullifier the result was issued for (prevents replay with another nullifier)
+    pub domain: [u8; 32],     // * Domain the result was issued for (prevents cross-domain reuse)
+    pub public_inputs_hash: Option<[u8; 32]>, // * SHA256 of the circuit public inputs (V3+; None for V1/V2)
+    pub circuit_id: Option<u32>, // * Registered circuit the proof was verified against (V4+; None for V1-V3)
+    pub vk_hash: Option<[u8; 32]>, // * SHA256 of the verification key used (V5+; None for V1-V4)
+    pub cluster_id: Option<[u8; 32]>, // * Cluster the result was issued for (V6+; None for V1-V5)
+    pub instance_id: Option<[u8; 32]>, // * Deployment the result was issued for (V7+; None for V1-V6)
+    pub claim_type: Option<ClaimType>, // * What the result attests to (V8+; None for V1-V7, meaning Auth)
+    pub tagged: bool,                  // * Signed message starts with MESSAGE_TAG (V9+)
+    pub verifier_signature: [u8; 64],  // * Ed25519 signature from verifier wallet
+}
+
+impl TryFrom<SignedVerificationResult> for VerificationResult {
+    type Error = anchor_lang::error::Error;
+
+    /// * Typed verify_auth argument; rejects an extension set without the ones before it, which
+    /// * the signed message (and so the signature) wouldn't cover
+    fn try_from(result: SignedVerificationResult) -> Result<Self> {
+        let extensions = [
+            result.public_inputs_hash.is_some(),
+            result.circuit_id.is_some(),
+            result.vk_hash.is_some(),
+            result.cluster_id.is_some(),
+            result.instance_id.is_some(),
+            result.claim_type.is_some(),
+            result.tagged,
+        ];
+        require!(
+            extensions.windows(2).all(|pair| pair[0] || !pair[1]),
+            VeiledError::InvalidResultLayout
+        );
+
+        Ok(Self {
+            is_valid: result.is_valid,
+            proof_hash: result.proof_hash,
+            timestamp: result.timestamp,
+            nullifier: result.nullifier,
+            domain: result.domain,
+            public_inputs_hash: result.public_inputs_hash,
+            circuit_id: result.circuit_id,
+            vk_hash: result.vk_hash,
+            cluster_id: result.cluster_id,
+            instance_id: result.instance_id,
+            claim_type: result.claim_type,
+            tagged: result.tagged,
+            verifier_signature: result.signature,
+        })
+    }
 }
 
 impl VerificationResult {
-    /// * Parse verification result from instruction data
-    /// * Format: [1 byte: is_valid] [32 bytes: proof_hash] [8 bytes: timestamp] [64 bytes: signature]
-    /// * Total: 105 bytes
+    /// * Parse a byte-envelope result (verify_auth_v1, challenge evidence); only tagged (V9)
+    /// * envelopes are accepted, since an untagged signature could have been made for another
+    /// * protocol. Older versions can still be submitted as the typed argument.
     pub fn from_instruction_data(data: &[u8]) -> Result<Self> {
-        require!(data.len() >= 105, VeiledError::InvalidProof);
-
-        let mut reader = Cursor::new(data);
-
-        // * Read is_valid (1 byte)
-        let mut is_valid_bytes = [0u8; 1];
-        reader
-            .read_exact(&mut is_valid_bytes)
-            .map_err(|_| anchor_lang::error!(VeiledError::InvalidProof))?;
-        let is_valid = is_valid_bytes[0] == 1;
-
-        // * Read proof_hash (32 bytes)
-        let mut proof_hash = [0u8; 32];
-        reader
-            .read_exact(&mut proof_hash)
-            .map_err(|_| anchor_lang::error!(VeiledError::InvalidProof))?;
-
-        // * Read timestamp (8 bytes, little-endian)
-        let mut timestamp_bytes = [0u8; 8];
-        reader
-            .read_exact(&mut timestamp_bytes)
-            .map_err(|_| anchor_lang::error!(VeiledError::InvalidProof))?;
-        let timestamp = u64::from_le_bytes(timestamp_bytes);
-
-        // * Read verifier_signature (64 bytes)
-        let mut verifier_signature = [0u8; 64];
-        reader
-            .read_exact(&mut verifier_signature)
-            .map_err(|_| anchor_lang::error!(VeiledError::InvalidProof))?;
+        let result = Self::decode(data)?;
+        require!(result.tagged, VeiledError::UntaggedResult);
+        Ok(result)
+    }
+
+    /// * Decode verification result bytes of any version, routing by version
+    /// * - First byte 0 or 1: legacy unversioned (V1) body, where that byte is is_valid
+    /// * - First byte 2: VerificationResultV2 envelope
+    /// * - First byte 3: VerificationResultV3 envelope (binds public_inputs_hash)
+    /// * - First byte 4: VerificationResultV4 envelope (also binds circuit_id)
+    /// * - First byte 5: VerificationResultV5 envelope (also binds vk_hash)
+    /// * - First byte 6: VerificationResultV6 envelope (also binds cluster_id)
+    /// * - First byte 7: VerificationResultV7 envelope (also binds instance_id)
+    /// * - First byte 8: VerificationResultV8 envelope (also binds claim_type)
+    /// * - First byte 9: VerificationResultV9 envelope (V8 fields, message prefixed with MESSAGE_TAG)
+    /// * - Anything else: rejected with UnsupportedResultVersion
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        match data.first() {
+            Some(0) | Some(1) => Self::from_body(data, BodyLayout::Legacy),
+            Some(&VerificationResultV2::VERSION) => {
+                Ok(VerificationResultV2::from_instruction_data(data)?.result)
+            }
+            Some(&VerificationResultV3::VERSION) => {
+                Ok(VerificationResultV3::from_instruction_data(data)?.result)
+            }
+            Some(&VerificationResultV4::VERSION) => {
+                Ok(VerificationResultV4::from_instruction_data(data)?.result)
+            }
+            Some(&VerificationResultV5::VERSION) => {
+                Ok(VerificationResultV5::from_instruction_data(data)?.result)
+            }
+            Some(&VerificationResultV6::VERSION) => {
+                Ok(VerificationResultV6::from_instruction_data(data)?.result)
+            }
+            Some(&VerificationResultV7::VERSION) => {
+                Ok(VerificationResultV7::from_instruction_data(data)?.result)
+            }
+            Some(&VerificationResultV8::VERSION) => {
+                Ok(VerificationResultV8::from_instruction_data(data)?.result)
+            }
+            Some(&VerificationResultV9::VERSION) => {
+                Ok(VerificationResultV9::from_instruction_data(data)?.result)
+            }
+            Some(_) => err!(VeiledError::UnsupportedResultVersion),
+            None => err!(VeiledError::ResultLengthMismatch),
+        }
+    }
+
+    /// * Parse a result body (everything after the version byte; the whole blob for V1)
+    /// * Format: [1 byte: is_valid] [32 bytes: proof_hash] [8 bytes: timestamp] [32 bytes: nullifier] [32 bytes: domain] [layout extension: public_inputs_hash (32), circuit_id (4), vk_hash (32), cluster_id (32), instance_id (32), claim_type (1)] [64 bytes: signature]
+    /// * Total: 169 bytes plus the layout's extension (201 / 205 / 237 / 269 / 301 / 302)
+    fn from_body(data: &[u8], layout: BodyLayout) -> Result<Self> {
+        // * One encoding per result: no trailing bytes, and is_valid is exactly 0 or 1
+        require!(
+            data.len() == RESULT_LEN + layout.extension_len(),
+            VeiledError::ResultLengthMismatch
+        );
+
+        // * Fields are read straight out of the instruction data in order; no io::Read or heap
+        let mut body = BodyReader(data);
+        let is_valid = match body.take::<1>()? {
+            [0] => false,
+            [1] => true,
+            _ => return err!(VeiledError::NonCanonicalBool),
+        };
+        let proof_hash = body.take::<32>()?;
+        let timestamp = u64::from_le_bytes(body.take::<8>()?);
+        let nullifier = body.take::<32>()?;
+        let domain = body.take::<32>()?; // * Null-padded
+
+        // * Extensions, each present from the version that introduced it
+        let public_inputs_hash = body.take_from::<32>(layout, BodyLayout::PublicInputs)?;
+        let circuit_id = body
+            .take_from::<4>(layout, BodyLayout::CircuitId)?
+            .map(u32::from_le_bytes);
+        let vk_hash = body.take_from::<32>(layout, BodyLayout::VkHash)?;
+        let cluster_id = body.take_from::<32>(layout, BodyLayout::ClusterId)?;
+        let instance_id = body.take_from::<32>(layout, BodyLayout::InstanceId)?;
+        let claim_type = body
+            .take_from::<1>(layout, BodyLayout::ClaimType)?
+            .map(|[byte]| ClaimType::from_byte(byte))
+            .transpose()?;
+
+        let verifier_signature = body.take::<64>()?;
 
         Ok(Self {
             is_valid,
             proof_hash,
             timestamp,
+            nullifier,
+            domain,
+            public_inputs_hash,
+            circuit_id,
+            vk_hash,
+            cluster_id,
+            instance_id,
+            claim_type,
+            tagged: layout >= BodyLayout::Tagged,
             verifier_signature,
         })
     }
 
+    /// * Reconstruct the signed message:
+    /// * proof_hash (32) || is_valid (1) || timestamp (8) || nullifier (32) || domain (32) = 105 bytes
+    /// * V3 appends public_inputs_hash (32) = 137 bytes
+    /// * V4 also appends circuit_id (4, little-endian) = 141 bytes
+    /// * V5 also appends vk_hash (32) = 173 bytes
+    /// * V6 also appends cluster_id (32) = 205 bytes
+    /// * V7 also appends instance_id (32) = 237 bytes
+    /// * V8 also appends claim_type (1) = 238 bytes
+    /// * V9 prefixes the V8 message with MESSAGE_TAG (9) = 247 bytes
+    pub fn signed_message(&self) -> SignedMessage {
+        let mut bytes = [0u8; MESSAGE_V9_LEN];
+        bytes[0..32].copy_from_slice(&self.proof_hash);
+        bytes[32] = if self.is_valid { 1 } else { 0 };
+        bytes[33..41].copy_from_slice(&self.timestamp.to_le_bytes());
+        bytes[41..73].copy_from_slice(&self.nullifier);
+        bytes[73..105].copy_from_slice(&self.domain);
+
+        let mut len = MESSAGE_LEN;
+        if let Some(public_inputs_hash) = &self.public_inputs_hash {
+            bytes[MESSAGE_LEN..MESSAGE_V3_LEN].copy_from_slice(public_inputs_hash);
+            len = MESSAGE_V3_LEN;
+
+            if let Some(circuit_id) = self.circuit_id {
+                bytes[MESSAGE_V3_LEN..MESSAGE_V4_LEN].copy_from_slice(&circuit_id.to_le_bytes());
+                len = MESSAGE_V4_LEN;
+
+                if let Some(vk_hash) = &self.vk_hash {
+                    bytes[MESSAGE_V4_LEN..MESSAGE_V5_LEN].copy_from_slice(vk_hash);
+                    len = MESSAGE_V5_LEN;
+
+                    if let Some(cluster_id) = &self.cluster_id {
+                        bytes[MESSAGE_V5_LEN..MESSAGE_V6_LEN].copy_from_slice(cluster_id);
+                        len = MESSAGE_V6_LEN;
+
+                        if let Some(instance_id) = &self.instance_id {
+                            bytes[MESSAGE_V6_LEN..MESSAGE_V7_LEN].copy_from_slice(instance_id);
+                            len = MESSAGE_V7_LEN;
+
+                            if let Some(claim_type) = self.claim_type {
+                                bytes[MESSAGE_V7_LEN] = claim_type.to_byte();
+                                len = MESSAGE_V8_LEN;
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        if self.tagged {
+            bytes.copy_within(..len, MESSAGE_TAG.len());
+            bytes[..MESSAGE_TAG.len()].copy_from_slice(MESSAGE_TAG);
+            len += MESSAGE_TAG.len();
+        }
+
+        SignedMessage { bytes, len }
+    }
+
     /// * Validate signature against verifier pubkey
     /// * Uses Ed25519 signature verification via Solana's Ed25519Program
     /// *
-    /// * Message format: proof_hash (32 bytes) || is_valid (1 byte) || timestamp (8 bytes)
-    /// * Total: 41 bytes
+    /// * Message format: proof_hash (32 bytes) || is_valid (1 byte) || timestamp (8 bytes) || nullifier (32 bytes) || domain (32 bytes)
+    /// * Total: 105 bytes
     /// * - proof_hash: SHA256 hash of the proof (32 bytes)
     /// * - is_valid: Boolean as u8 (1 = valid, 0 = invalid)
     /// * - timestamp: Unix timestamp as u64 little-endian (8 bytes)
+    /// * - nullifier: Nullifier the result was issued for (32 bytes)
+    /// * - domain: Null-padded domain the result was issued for (32 bytes)
+    /// * - public_inputs_hash: SHA256 of the circuit public inputs (32 bytes, V3+)
+    /// * - circuit_id: Registered circuit ID as u32 little-endian (4 bytes, V4+)
+    /// * - vk_hash: SHA256 of the verification key (32 bytes, V5+)
+    /// * - cluster_id: Cluster the result was issued for (32 bytes, V6+; must be this build's)
+    /// * - instance_id: Deployment the result was issued for (32 bytes, V7+; must be this one)
+    /// * - claim_type: What the result attests to (1 byte, V8+; see ClaimType)
+    /// * - V9 messages start with MESSAGE_TAG ("veiled:v1") ahead of all of the above
     /// *
     /// * Security validations performed:
     /// * - Program ID validation (must be Ed25519Program)
     /// * - No accounts check (Ed25519Program is stateless)
     /// * - Strict offset validation (all offsets must == u16::MAX)
     /// * - Bounds checking (all slices within instruction data)
-    /// * - Message content validation (size, proof_hash, is_valid match expected)
-    /// * - Authority validation (public key matches expected verifier)
+    /// * - Message content validation (tag, size, proof_hash, is_valid, nullifier, domain, public inputs, circuit, vk hash, cluster, instance, claim type match expected)
+    /// * - Authority validation (public key is one of the accepted verifiers)
+    /// * - Quorum: at least `threshold` distinct verifiers signed the same message
+    /// *
+    /// * `retiring` pairs rotated-out keys still in their grace period with the current key that
+    /// * replaced them: they're accepted, but count as that key towards the quorum.
+    /// *
+    /// * `passkeys` are registered secp256r1 verifier keys: Secp256r1 precompile instructions
+    /// * over the same message count towards the quorum too, and may carry the result's
+    /// * signature themselves. With no passkeys, secp256r1 instructions are ignored.
+    /// *
+    /// * `consent_ix` is the Ed25519 instruction carrying the end user's consent (see
+    /// * state::session::UserConsent), already checked by the caller and left out of the scan.
+    /// *
+    /// * Returns the Ed25519 verifier key whose instruction carried the result's signature, or
+    /// * the default key when a passkey carried it.
     /// *
     /// * Note: Anchor's Signer constraint validates the transaction signature
     /// * This function performs cryptographic validation of the signature field
     pub fn validate_signature(
         &self,
-        verifier_pubkey: &Pubkey,
+        verifiers: &[Pubkey],
+        retiring: &[(Pubkey, Pubkey)],
+        passkeys: &[[u8; SECP256R1_PUBKEY_LEN]],
+        threshold: usize,
         instructions_sysvar: &anchor_lang::prelude::AccountInfo,
-    ) -> Result<()> {
-        // * Reconstruct signed message: proof_hash (32) || is_valid (1) || timestamp (8) = 41 bytes
-        // * Use fixed-size array to avoid BPF memory allocation issues
-        let mut message = [0u8; 41];
-        message[0..32].copy_from_slice(&self.proof_hash);
-        message[32] = if self.is_valid { 1 } else { 0 };
-        message[33..41].copy_from_slice(&self.timestamp.to_le_bytes());
+        consent_ix: Option<usize>,
+    ) -> Result<Pubkey> {
+        let message = self.signed_message();
+        let accepted: Vec<Pubkey> = verifiers
+            .iter()
+            .copied()
+            .chain(retiring.iter().map(|(old_key, _)| *old_key))
+            .collect();
 
         // * Verify Ed25519 signature via Solana's built-in Ed25519 program.
         // * This avoids expensive curve operations in BPF and is the standard pattern:
         // * - Client includes an Ed25519 verification instruction in the same tx
         // * - Program validates that instruction exists and matches (pubkey, msg, sig)
-        Self::verify_ed25519_instruction(
+        // * Co-signers add one Ed25519 instruction each over the same message
+        let (signers, signed_by) = Self::verify_ed25519_instructions(
             instructions_sysvar,
-            verifier_pubkey,
+            &accepted,
+            retiring,
             &message,
             &self.verifier_signature,
+            consent_ix,
         )?;
 
+        // * Passkey verifiers sign through the Secp256r1 precompile the same way
+        let (passkey_signers, passkey_signature_found) = if passkeys.is_empty() {
+            (0, false)
+        } else {
+            Self::verify_secp256r1_instructions(
+                instructions_sysvar,
+                passkeys,
+                &message,
+                &self.verifier_signature,
+            )?
+        };
+
+        require!(
+            signed_by.is_some() || passkey_signature_found,
+            VeiledError::InvalidProof
+        );
+        require!(
+            signers + passkey_signers >= threshold,
+            VeiledError::QuorumNotMet
+        );
+
         msg!("✓ Verification result signature validated");
         msg!("  Proof hash: {:?}", self.proof_hash);
         msg!("  Timestamp: {}", self.timestamp);
         msg!("  Valid: {}", self.is_valid);
 
-        Ok(())
+        Ok(signed_by.unwrap_or_default())
     }
 
-    /// * Verifies an Ed25519Program instruction exists earlier in the transaction that matches
-    /// * (public key, message, signature).
+    /// * Verifies the Ed25519Program instructions elsewhere in the transaction, before or after
+    /// * this one (some wallets append precompile instructions last): every one must sign the
+    /// * expected message with an expected key, except `consent_ix` (the user's consent).
+    /// * Returns how many distinct verifiers signed, counting a retiring key as its successor,
+    /// * and the key (as signed, before that mapping) that carried the result's signature.
     /// *
     /// * Security validations performed:
     /// * 1. Program ID validation (must be Ed25519Program)
     /// * 2. No accounts check (Ed25519Program is stateless)
-    /// * 3. Instruction matching (delegated to ed25519_ix_matches)
-    fn verify_ed25519_instruction(
+    /// * 3. At most MAX_ED25519_INSTRUCTIONS Ed25519 instructions
+    /// * 4. Instruction matching (delegated to ed25519_ix_signers)
+    fn verify_ed25519_instructions(
         instructions_sysvar: &anchor_lang::prelude::AccountInfo,
-        expected_pubkey: &Pubkey,
+        expected_pubkeys: &[Pubkey],
+        retiring: &[(Pubkey, Pubkey)],
         expected_message: &[u8],
         expected_signature: &[u8; 64],
-    ) -> Result<()> {
-        // * Use solana-instructions-sysvar helper functions
-        // * These are available in Solana 3.x split crates
-        let current_index = load_current_index_checked(instructions_sysvar)
-            .map_err(|_| anchor_lang::error!(VeiledError::InvalidProof))?;
-
-        // * Search all prior instructions for a matching Ed25519 verification ix
-        // * Start from the most recent instruction (most likely to be Ed25519)
-        // * This minimizes memory allocations by checking likely candidates first
-        for idx in (0..current_index).rev() {
-            let ix: SolanaInstruction =
-                load_instruction_at_checked(idx as usize, instructions_sysvar)
-                    .map_err(|_| anchor_lang::error!(VeiledError::InvalidProof))?;
+        consent_ix: Option<usize>,
+    ) -> Result<(usize, Option<Pubkey>)> {
+        let mut signers: Vec<Pubkey> = Vec::with_capacity(MAX_ED25519_INSTRUCTIONS);
+        let mut ed25519_count = 0usize;
+        let mut signed_by = None;
+
+        // * Check every other instruction in the transaction
+        for idx in sig_introspection::other_instruction_indices(instructions_sysvar)? {
+            if Some(idx) == consent_ix {
+                continue;
+            }
+
+            let ix: SolanaInstruction = load_instruction_at_checked(idx, instructions_sysvar)
+                .map_err(|_| anchor_lang::error!(VeiledError::InvalidProof))?;
 
             // * SECURITY CHECK 1: Verify program ID (early exit to avoid unnecessary processing)
             if ix.program_id != ED25519_PROGRAM_ID {
@@ -166,162 +780,280 @@ impl VerificationResult {
             // * SECURITY CHECK 2: Verify no accounts (Ed25519Program is stateless)
             require!(ix.accounts.is_empty(), VeiledError::BadEd25519Accounts);
 
-            if Self::ed25519_ix_matches(
-                &ix,
-                idx as u16,
-                expected_pubkey.as_ref(),
-                expected_message,
-                expected_signature,
-            )? {
-                return Ok(());
+            // * SECURITY CHECK 3: Bound the work done per transaction
+            ed25519_count += 1;
+            require!(
+                ed25519_count <= MAX_ED25519_INSTRUCTIONS,
+                VeiledError::TooManyEd25519Instructions
+            );
+
+            let entries =
+                Self::ed25519_ix_signers(&ix, idx as u16, expected_pubkeys, expected_message)?;
+            for (signer, signature) in entries {
+                if ct_eq(&signature, expected_signature) {
+                    signed_by.get_or_insert(signer);
+                }
+                let signer = retiring
+                    .iter()
+                    .find(|(old_key, _)| old_key == &signer)
+                    .map_or(signer, |(_, current)| *current);
+                if !signers.contains(&signer) {
+                    signers.push(signer);
+                }
             }
         }
 
-        Err(anchor_lang::error!(VeiledError::InvalidProof))
+        Ok((signers.len(), signed_by))
     }
 
-    /// * Checks whether a single Ed25519Program instruction verifies the expected tuple.
+    /// * Secp256r1 counterpart of verify_ed25519_instructions, for passkey / WebAuthn-device
+    /// * verifiers. Every other Secp256r1 precompile instruction in the transaction must sign
+    /// * the expected message with one of `expected_pubkeys` (compressed P-256 keys).
+    /// * Returns how many distinct keys signed and whether one carried the result's signature.
     /// *
-    /// * This parses the Ed25519 instruction data layout:
-    /// * [num_signatures: u8][padding: u8][SignatureOffsets * num_signatures][...data blobs...]
-    /// *
-    /// * SignatureOffsets (14 bytes, little-endian u16 fields):
-    /// * - signature_offset
-    /// * - signature_instruction_index
-    /// * - public_key_offset
-    /// * - public_key_instruction_index
-    /// * - message_data_offset
-    /// * - message_data_size
-    /// * - message_instruction_index
+    /// * The precompile checks ECDSA over sha256(message), so the device must sign the raw
+    /// * result message (e.g. a non-extractable WebCrypto P-256 key); WebAuthn assertions,
+    /// * which sign authenticatorData || sha256(clientDataJSON), are not unwrapped here.
     /// *
     /// * Security validations performed:
-    /// * 1. Header length validation (minimum 16 bytes)
-    /// * 2. Signature count validation (must be exactly 1)
-    /// * 3. CRITICAL: Offset index validation (all must == u16::MAX for current instruction)
-    /// * 4. Bounds checking (all offsets >= HEADER_LEN, all slices within bounds)
-    /// * 5. Message content validation (size, proof_hash, is_valid match expected)
-    /// * 6. Authority validation (public key matches expected)
-    #[cfg_attr(test, allow(dead_code))]
+    /// * 1. Program ID validation (must be the Secp256r1 precompile)
+    /// * 2. No accounts check (the precompile is stateless)
+    /// * 3. At most MAX_SECP256R1_INSTRUCTIONS Secp256r1 instructions
+    /// * 4. Instruction matching (delegated to secp256r1_ix_signers)
+    fn verify_secp256r1_instructions(
+        instructions_sysvar: &anchor_lang::prelude::AccountInfo,
+        expected_pubkeys: &[[u8; SECP256R1_PUBKEY_LEN]],
+        expected_message: &[u8],
+        expected_signature: &[u8; 64],
+    ) -> Result<(usize, bool)> {
+        let mut signers: Vec<[u8; SECP256R1_PUBKEY_LEN]> =
+            Vec::with_capacity(MAX_SECP256R1_INSTRUCTIONS);
+        let mut secp256r1_count = 0usize;
+        let mut signature_found = false;
+
+        for idx in sig_introspection::other_instruction_indices(instructions_sysvar)? {
+            let ix: SolanaInstruction = load_instruction_at_checked(idx, instructions_sysvar)
+                .map_err(|_| anchor_lang::error!(VeiledError::InvalidProof))?;
+
+            // * SECURITY CHECK 1: Verify program ID
+            if ix.program_id != SECP256R1_PROGRAM_ID {
+                continue;
+            }
+
+            // * SECURITY CHECK 2: Verify no accounts
+            require!(ix.accounts.is_empty(), VeiledError::BadSecp256r1Accounts);
+
+            // * SECURITY CHECK 3: Bound the work done per transaction
+            secp256r1_count += 1;
+            require!(
+                secp256r1_count <= MAX_SECP256R1_INSTRUCTIONS,
+                VeiledError::TooManySecp256r1Instructions
+            );
+
+            let entries = Self::secp256r1_ix_signers(&ix, expected_pubkeys, expected_message)?;
+            for (signer, signature) in entries {
+                signature_found |= ct_eq(&signature, expected_signature);
+                if !signers.contains(&signer) {
+                    signers.push(signer);
+                }
+            }
+        }
+
+        Ok((signers.len(), signature_found))
+    }
+
+    /// * Checks whether a single Ed25519Program instruction verifies the expected tuple in any
+    /// * of its entries. See ed25519_ix_signers for the validations performed.
+    #[cfg(test)]
     fn ed25519_ix_matches(
         ix: &SolanaInstruction,
-        _ix_index: u16,
-        expected_pubkey: &[u8],
+        ix_index: u16,
+        expected_pubkeys: &[Pubkey],
         expected_message: &[u8],
         expected_signature: &[u8; 64],
     ) -> Result<bool> {
-        const HEADER_LEN: usize = 16;
-        const PUBKEY_LEN: usize = 32;
-        const SIG_LEN: usize = 64;
-        const MSG_LEN: usize = 41; // * proof_hash (32) || is_valid (1) || timestamp (8)
-
-        let data = ix.data.as_slice();
+        let entries = Self::ed25519_ix_signers(ix, ix_index, expected_pubkeys, expected_message)?;
+        Ok(entries
+            .iter()
+            .any(|(_, signature)| ct_eq(signature, expected_signature)))
+    }
 
-        // * SECURITY CHECK 1: Validate header length
+    /// * Checks a signed message against the result's expected one (per-entry callback of
+    /// * sig_introspection::signature_entries, which has already validated offsets and bounds)
+    /// *
+    /// * Security validations performed:
+    /// * 6. Protocol tag (present exactly when the expected message has it) and message size
+    /// *    (one of the result message lengths, matching the expected one)
+    /// * 7. Message content (proof_hash, is_valid, nullifier, domain, public inputs, circuit, vk hash, cluster, instance, claim type match expected)
+    fn check_result_message(expected_message: &[u8], msg_bytes: &[u8]) -> Result<()> {
+        // * SECURITY CHECK 6: A tagged (V9) result only matches a message carrying the tag; the
+        // * rest is checked as the V8 message it prefixes
+        let (expected_message, msg_bytes) = match expected_message.strip_prefix(MESSAGE_TAG) {
+            Some(expected_message) => (
+                expected_message,
+                msg_bytes
+                    .strip_prefix(MESSAGE_TAG)
+                    .ok_or(VeiledError::MessageTagMismatch)?,
+            ),
+            None => (expected_message, msg_bytes),
+        };
+        let message_size = msg_bytes.len();
+
+        // * Validate message size
+        // * 105 bytes for V1/V2 results, 137 for V3, 141 for V4, 173 for V5, 205 for V6, 237 for V7,
+        // * 238 for V8 and (after the tag) V9
+        // * (must match what the result claims)
         require!(
-            data.len() >= HEADER_LEN,
-            VeiledError::InvalidInstructionData
+            message_size == expected_message.len()
+                && matches!(
+                    message_size,
+                    MESSAGE_LEN
+                        | MESSAGE_V3_LEN
+                        | MESSAGE_V4_LEN
+                        | MESSAGE_V5_LEN
+                        | MESSAGE_V6_LEN
+                        | MESSAGE_V7_LEN
+                        | MESSAGE_V8_LEN
+                ),
+            VeiledError::InvalidMessageSize
         );
 
-        // * SECURITY CHECK 2: Validate signature count (must be exactly 1)
-        let num_signatures = data[0] as usize;
-        require!(num_signatures == 1, VeiledError::InvalidSignatureCount);
-
-        // * Offsets table begins at byte 2
-        let table_start = 2usize;
-        let entry_len = 14usize;
-        let table_len = num_signatures
-            .checked_mul(entry_len)
-            .ok_or_else(|| anchor_lang::error!(VeiledError::InvalidInstructionData))?;
+        // * SECURITY CHECK 7: Validate message content
+        // * Message format: proof_hash (32) || is_valid (1) || timestamp (8) || nullifier (32) || domain (32)
+        // *                 [|| public_inputs_hash (32) [|| circuit_id (4) [|| vk_hash (32)
+        // *                 [|| cluster_id (32) [|| instance_id (32) [|| claim_type (1)]]]]]]
+        let expected_proof_hash = &expected_message[0..32];
+        let expected_is_valid = expected_message[32];
+        let expected_nullifier = &expected_message[41..73];
+        let expected_domain = &expected_message[73..105];
+        let msg_proof_hash = &msg_bytes[0..32];
+        let msg_is_valid = msg_bytes[32];
+        let msg_nullifier = &msg_bytes[41..73];
+        let msg_domain = &msg_bytes[73..105];
 
         require!(
-            data.len() >= table_start + table_len,
-            VeiledError::InvalidInstructionData
+            msg_proof_hash == expected_proof_hash,
+            VeiledError::ProofHashMismatch
         );
-
-        // * Parse offsets for the first (and only) signature
-        let base = table_start;
-
-        let signature_offset = u16::from_le_bytes([data[base], data[base + 1]]) as usize;
-        let signature_ix_idx = u16::from_le_bytes([data[base + 2], data[base + 3]]);
-        let public_key_offset = u16::from_le_bytes([data[base + 4], data[base + 5]]) as usize;
-        let public_key_ix_idx = u16::from_le_bytes([data[base + 6], data[base + 7]]);
-        let message_offset = u16::from_le_bytes([data[base + 8], data[base + 9]]) as usize;
-        let message_size = u16::from_le_bytes([data[base + 10], data[base + 11]]) as usize;
-        let message_ix_idx = u16::from_le_bytes([data[base + 12], data[base + 13]]);
-
-        // * SECURITY CHECK 3: CRITICAL - Validate all offsets point to current instruction
-        // * All offset indices MUST == u16::MAX (current instruction sentinel)
-        // * This prevents attackers from pointing to data in other instructions
         require!(
-            signature_ix_idx == u16::MAX
-                && public_key_ix_idx == u16::MAX
-                && message_ix_idx == u16::MAX,
-            VeiledError::OffsetMismatch
+            msg_is_valid == expected_is_valid,
+            VeiledError::IsValidMismatch
         );
-
-        // * SECURITY CHECK 4: Bounds check all offsets
-        // * All offsets must be past the header region
         require!(
-            signature_offset >= HEADER_LEN
-                && public_key_offset >= HEADER_LEN
-                && message_offset >= HEADER_LEN,
-            VeiledError::InvalidInstructionData
+            msg_nullifier == expected_nullifier,
+            VeiledError::NullifierMismatch
         );
-
-        // * SECURITY CHECK 5: Bounds check all slices
+        require!(msg_domain == expected_domain, VeiledError::DomainMismatch);
+        // * Version extensions: each range is empty when the message stops before it
+        let extension = |start: usize, end: usize| {
+            let (start, end) = (start.min(message_size), end.min(message_size));
+            msg_bytes[start..end] == expected_message[start..end]
+        };
         require!(
-            data.len() >= signature_offset + SIG_LEN,
-            VeiledError::InvalidInstructionData
+            extension(MESSAGE_LEN, MESSAGE_V3_LEN),
+            VeiledError::PublicInputsMismatch
         );
         require!(
-            data.len() >= public_key_offset + PUBKEY_LEN,
-            VeiledError::InvalidInstructionData
+            extension(MESSAGE_V3_LEN, MESSAGE_V4_LEN),
+            VeiledError::CircuitMismatch
         );
         require!(
-            data.len() >= message_offset + message_size,
-            VeiledError::InvalidInstructionData
+            extension(MESSAGE_V4_LEN, MESSAGE_V5_LEN),
+            VeiledError::VkHashMismatch
         );
-
-        // * SECURITY CHECK 6: Validate message size
-        require!(message_size == MSG_LEN, VeiledError::InvalidMessageSize);
-
-        // * Extract slices (now safe due to bounds checking)
-        let sig_bytes = &data[signature_offset..signature_offset + SIG_LEN];
-        let pk_bytes = &data[public_key_offset..public_key_offset + PUBKEY_LEN];
-        let msg_bytes = &data[message_offset..message_offset + MSG_LEN];
-
-        // * SECURITY CHECK 7: Validate message content
-        // * Message format: proof_hash (32) || is_valid (1) || timestamp (8)
-        let expected_proof_hash = &expected_message[0..32];
-        let expected_is_valid = expected_message[32];
-        let msg_proof_hash = &msg_bytes[0..32];
-        let msg_is_valid = msg_bytes[32];
-
+        // * A cluster-bound message must have been signed for this build's cluster, whatever
+        // * the result claims
         require!(
-            msg_proof_hash == expected_proof_hash,
-            VeiledError::ProofHashMismatch
+            message_size < MESSAGE_V6_LEN
+                || msg_bytes[MESSAGE_V5_LEN..MESSAGE_V6_LEN] == cluster::CLUSTER_ID,
+            VeiledError::ClusterMismatch
         );
         require!(
-            msg_is_valid == expected_is_valid,
-            VeiledError::IsValidMismatch
+            extension(MESSAGE_V5_LEN, MESSAGE_V6_LEN),
+            VeiledError::ClusterMismatch
+        );
+        // * Likewise an instance-bound message must be for this deployment
+        require!(
+            message_size < MESSAGE_V7_LEN
+                || msg_bytes[MESSAGE_V6_LEN..MESSAGE_V7_LEN] == genesis::INSTANCE_ID,
+            VeiledError::InstanceMismatch
+        );
+        require!(
+            extension(MESSAGE_V6_LEN, MESSAGE_V7_LEN),
+            VeiledError::InstanceMismatch
+        );
+        require!(
+            extension(MESSAGE_V7_LEN, MESSAGE_V8_LEN),
+            VeiledError::ClaimTypeMismatch
         );
 
-        // * SECURITY CHECK 8: Validate authority (public key)
-        require!(pk_bytes == expected_pubkey, VeiledError::AuthorityMismatch);
+        Ok(())
+    }
 
-        // * Validate signature matches (final check)
-        if sig_bytes != expected_signature {
-            return Ok(false);
-        }
+    /// * Validates a single Ed25519Program instruction against the expected keys and message,
+    /// * returning the signer and signature of every matching entry.
+    /// *
+    /// * Security validations performed:
+    /// * 1-5. Layout, offsets and bounds (see sig_introspection::signature_entries)
+    /// * 6-7. Message size and content (see check_result_message)
+    /// * 8. Authority validation (at least one matching entry's key is an expected verifier)
+    #[cfg_attr(test, allow(dead_code))]
+    fn ed25519_ix_signers(
+        ix: &SolanaInstruction,
+        _ix_index: u16,
+        expected_pubkeys: &[Pubkey],
+        expected_message: &[u8],
+    ) -> Result<Vec<(Pubkey, [u8; 64])>> {
+        // * SECURITY CHECK 8: Validate authority (public key), skipping entries by other keys
+        let entries = sig_introspection::signature_entries::<32>(ix, |message| {
+            Self::check_result_message(expected_message, message)
+        })?;
+        let signers: Vec<(Pubkey, [u8; 64])> = entries
+            .into_iter()
+            .filter_map(|(public_key, signature)| {
+                expected_pubkeys
+                    .iter()
+                    .find(|pubkey| pubkey.to_bytes() == public_key)
+                    .map(|signer| (*signer, signature))
+            })
+            .collect();
+        require!(!signers.is_empty(), VeiledError::AuthorityMismatch);
+
+        Ok(signers)
+    }
 
-        Ok(true)
+    /// * Validates a single Secp256r1 precompile instruction against the expected passkeys and
+    /// * message, returning every matching entry (same checks as ed25519_ix_signers)
+    fn secp256r1_ix_signers(
+        ix: &SolanaInstruction,
+        expected_pubkeys: &[[u8; SECP256R1_PUBKEY_LEN]],
+        expected_message: &[u8],
+    ) -> Result<Vec<([u8; SECP256R1_PUBKEY_LEN], [u8; 64])>> {
+        let entries =
+            sig_introspection::signature_entries::<SECP256R1_PUBKEY_LEN>(ix, |message| {
+                Self::check_result_message(expected_message, message)
+            })?;
+        let signers: Vec<([u8; SECP256R1_PUBKEY_LEN], [u8; 64])> = entries
+            .into_iter()
+            .filter(|(public_key, _)| expected_pubkeys.contains(public_key))
+            .collect();
+        require!(!signers.is_empty(), VeiledError::AuthorityMismatch);
+
+        Ok(signers)
     }
 
     /// * Check if verification result is recent (not stale)
-    /// * Rejects results older than 5 minutes
-    pub fn is_recent(&self, current_timestamp: i64) -> Result<()> {
-        let age = current_timestamp.saturating_sub(self.timestamp as i64);
-        let max_age = 5 * 60; // * 5 minutes in seconds
+    /// * Rejects results older than `max_age` seconds (see AssurancePolicy), and results dated
+    /// * more than MAX_CLOCK_SKEW_SECS ahead, which would otherwise stay fresh until then
+    pub fn is_recent(&self, current_timestamp: i64, max_age: i64) -> Result<()> {
+        let timestamp =
+            i64::try_from(self.timestamp).map_err(|_| VeiledError::TimestampInFuture)?;
+        require!(
+            timestamp <= current_timestamp.saturating_add(MAX_CLOCK_SKEW_SECS),
+            VeiledError::TimestampInFuture
+        );
+
+        let age = current_timestamp.saturating_sub(timestamp);
 
         require!(age <= max_age, VeiledError::ProofExpired);
 
@@ -335,12 +1067,16 @@ pub fn create_instruction_data(
     is_valid: bool,
     proof_hash: [u8; 32],
     timestamp: u64,
+    nullifier: [u8; 32],
+    domain: [u8; 32],
     signature: [u8; 64],
 ) -> Vec<u8> {
-    let mut data = Vec::with_capacity(105);
+    let mut data = Vec::with_capacity(RESULT_LEN);
     data.push(if is_valid { 1 } else { 0 });
     data.extend_from_slice(&proof_hash);
     data.extend_from_slice(&timestamp.to_le_bytes());
+    data.extend_from_slice(&nullifier);
+    data.extend_from_slice(&domain);
     data.extend_from_slice(&signature);
     data
 }
@@ -349,6 +1085,7 @@ pub fn create_instruction_data(
 mod tests {
     use super::*;
     use anchor_lang::solana_program::instruction::Instruction as SolanaInstruction;
+    use veiled_sig_introspect::MAX_SIGNATURES_PER_INSTRUCTION;
 
     // * Helper: Create a mock Ed25519 instruction with specified offsets
     fn create_mock_ed25519_instruction(
@@ -399,7 +1136,7 @@ mod tests {
     #[test]
     fn test_valid_signature() {
         let pubkey = [1u8; 32];
-        let message = vec![0u8; 41]; // * proof_hash (32) || is_valid (1) || timestamp (8)
+        let message = vec![0u8; MESSAGE_LEN]; // * proof_hash (32) || is_valid (1) || timestamp (8) || nullifier (32) || domain (32)
         let signature = [2u8; 64];
 
         let instruction = create_mock_ed25519_instruction(
@@ -431,7 +1168,7 @@ mod tests {
     #[test]
     fn test_offset_mismatch() {
         let pubkey = [1u8; 32];
-        let message = vec![0u8; 41];
+        let message = vec![0u8; MESSAGE_LEN];
         let signature = [2u8; 64];
 
         // * Create instruction with wrong offset index (pointing to instruction 0 instead of current)
@@ -460,8 +1197,8 @@ mod tests {
     #[test]
     fn test_message_mismatch() {
         let pubkey = [1u8; 32];
-        let expected_message = vec![0u8; 41];
-        let wrong_message = vec![1u8; 41]; // * Different message
+        let expected_message = vec![0u8; MESSAGE_LEN];
+        let wrong_message = vec![1u8; MESSAGE_LEN]; // * Different message
         let signature = [2u8; 64];
 
         let _instruction = create_mock_ed25519_instruction(
@@ -485,7 +1222,7 @@ mod tests {
     fn test_authority_mismatch() {
         let expected_pubkey = [1u8; 32];
         let wrong_pubkey = [2u8; 32]; // * Different public key
-        let message = vec![0u8; 41];
+        let message = vec![0u8; MESSAGE_LEN];
         let signature = [2u8; 64];
 
         let _instruction = create_mock_ed25519_instruction(
@@ -508,7 +1245,7 @@ mod tests {
     #[test]
     fn test_invalid_signature_count() {
         let pubkey = [1u8; 32];
-        let message = vec![0u8; 41];
+        let message = vec![0u8; MESSAGE_LEN];
         let signature = [2u8; 64];
 
         // * Create instruction with 0 signatures
@@ -532,7 +1269,7 @@ mod tests {
     #[test]
     fn test_invalid_message_size() {
         let pubkey = [1u8; 32];
-        let wrong_size_message = vec![0u8; 40]; // * Wrong size (should be 41)
+        let wrong_size_message = vec![0u8; 41]; // * Wrong size (legacy message without nullifier/domain)
         let signature = [2u8; 64];
 
         let _instruction = create_mock_ed25519_instruction(
@@ -545,16 +1282,915 @@ mod tests {
         );
 
         // * Verify message size is wrong
-        assert_ne!(wrong_size_message.len(), 41);
+        assert_ne!(wrong_size_message.len(), MESSAGE_LEN);
 
         // * In real integration test, ed25519_ix_matches should return Err(InvalidMessageSize)
     }
 
+    // * Test: Signed message layout includes the nullifier and domain
+    #[test]
+    fn test_signed_message_layout() {
+        let data = create_instruction_data(true, [3u8; 32], 42, [4u8; 32], [6u8; 32], [5u8; 64]);
+        assert_eq!(data.len(), RESULT_LEN);
+
+        let result = VerificationResult::decode(&data).unwrap();
+        let message = result.signed_message();
+
+        assert_eq!(&message[0..32], &[3u8; 32]);
+        assert_eq!(message[32], 1);
+        assert_eq!(&message[33..41], &42u64.to_le_bytes());
+        assert_eq!(&message[41..73], &[4u8; 32]);
+        assert_eq!(&message[73..105], &[6u8; 32]);
+    }
+
+    // * Test: Result signed for another nullifier is rejected
+    #[test]
+    fn test_nullifier_mismatch() {
+        let pubkey = [1u8; 32];
+        let signature = [2u8; 64];
+        let signed = VerificationResult {
+            is_valid: true,
+            proof_hash: [3u8; 32],
+            timestamp: 42,
+            nullifier: [4u8; 32],
+            domain: [6u8; 32],
+            public_inputs_hash: None,
+            circuit_id: None,
+            vk_hash: None,
+            cluster_id: None,
+            instance_id: None,
+            claim_type: None,
+            tagged: false,
+            verifier_signature: signature,
+        };
+        let replayed = VerificationResult {
+            nullifier: [9u8; 32],
+            ..signed.clone()
+        };
+
+        let instruction = create_mock_ed25519_instruction(
+            u16::MAX,
+            u16::MAX,
+            u16::MAX,
+            &pubkey,
+            &signed.signed_message(),
+            &signature,
+        );
+
+        assert!(VerificationResult::ed25519_ix_matches(
+            &instruction,
+            0,
+            &[Pubkey::new_from_array(pubkey)],
+            &signed.signed_message(),
+            &signature,
+        )
+        .unwrap());
+        assert_eq!(
+            VerificationResult::ed25519_ix_matches(
+                &instruction,
+                0,
+                &[Pubkey::new_from_array(pubkey)],
+                &replayed.signed_message(),
+                &signature,
+            )
+            .unwrap_err(),
+            VeiledError::NullifierMismatch.into()
+        );
+    }
+
+    // * Test: Result signed for another domain is rejected
+    #[test]
+    fn test_domain_mismatch() {
+        let pubkey = [1u8; 32];
+        let signature = [2u8; 64];
+        let signed = VerificationResult {
+            is_valid: true,
+            proof_hash: [3u8; 32],
+            timestamp: 42,
+            nullifier: [4u8; 32],
+            domain: [6u8; 32],
+            public_inputs_hash: None,
+            circuit_id: None,
+            vk_hash: None,
+            cluster_id: None,
+            instance_id: None,
+            claim_type: None,
+            tagged: false,
+            verifier_signature: signature,
+        };
+        let other_domain = VerificationResult {
+            domain: [7u8; 32],
+            ..signed.clone()
+        };
+
+        let instruction = create_mock_ed25519_instruction(
+            u16::MAX,
+            u16::MAX,
+            u16::MAX,
+            &pubkey,
+            &signed.signed_message(),
+            &signature,
+        );
+
+        assert_eq!(
+            VerificationResult::ed25519_ix_matches(
+                &instruction,
+                0,
+                &[Pubkey::new_from_array(pubkey)],
+                &other_domain.signed_message(),
+                &signature,
+            )
+            .unwrap_err(),
+            VeiledError::DomainMismatch.into()
+        );
+    }
+
+    // * Test: Any key in the accepted verifier set matches; keys outside it are rejected
+    #[test]
+    fn test_verifier_set() {
+        let pubkey = [1u8; 32];
+        let signature = [2u8; 64];
+        let signed = VerificationResult {
+            is_valid: true,
+            proof_hash: [3u8; 32],
+            timestamp: 42,
+            nullifier: [4u8; 32],
+            domain: [6u8; 32],
+            public_inputs_hash: None,
+            circuit_id: None,
+            vk_hash: None,
+            cluster_id: None,
+            instance_id: None,
+            claim_type: None,
+            tagged: false,
+            verifier_signature: signature,
+        };
+
+        let instruction = create_mock_ed25519_instruction(
+            u16::MAX,
+            u16::MAX,
+            u16::MAX,
+            &pubkey,
+            &signed.signed_message(),
+            &signature,
+        );
+
+        assert!(VerificationResult::ed25519_ix_matches(
+            &instruction,
+            0,
+            &[
+                Pubkey::new_from_array([8u8; 32]),
+                Pubkey::new_from_array(pubkey)
+            ],
+            &signed.signed_message(),
+            &signature,
+        )
+        .unwrap());
+        assert_eq!(
+            VerificationResult::ed25519_ix_matches(
+                &instruction,
+                0,
+                &[Pubkey::new_from_array([8u8; 32])],
+                &signed.signed_message(),
+                &signature,
+            )
+            .unwrap_err(),
+            VeiledError::AuthorityMismatch.into()
+        );
+    }
+
+    // * Test: A batched instruction matches when any of its entries carries the expected tuple
+    #[test]
+    fn test_multiple_signatures() {
+        let pubkey = [1u8; 32];
+        let signature = [2u8; 64];
+        let signed = VerificationResult {
+            is_valid: true,
+            proof_hash: [3u8; 32],
+            timestamp: 42,
+            nullifier: [4u8; 32],
+            domain: [6u8; 32],
+            public_inputs_hash: None,
+            circuit_id: None,
+            vk_hash: None,
+            cluster_id: None,
+            instance_id: None,
+            claim_type: None,
+            tagged: false,
+            verifier_signature: signature,
+        };
+        let message = signed.signed_message();
+        let unrelated = vec![9u8; 32];
+
+        // * Entry 0 is someone else's signature over another message, entry 1 is the verifier's
+        let header_len = 2 + 2 * 14;
+        let entries: [(&[u8; 32], &[u8], [u8; 64]); 2] = [
+            (&[7u8; 32], &unrelated, [5u8; 64]),
+            (&pubkey, &message, signature),
+        ];
+        let mut data = vec![2u8, 0u8];
+        let mut blobs = Vec::new();
+        for (entry_pubkey, entry_message, entry_signature) in entries {
+            let signature_offset = (header_len + blobs.len()) as u16;
+            blobs.extend_from_slice(&entry_signature);
+            let public_key_offset = (header_len + blobs.len()) as u16;
+            blobs.extend_from_slice(entry_pubkey);
+            let message_offset = (header_len + blobs.len()) as u16;
+            blobs.extend_from_slice(entry_message);
+
+            for field in [
+                signature_offset,
+                u16::MAX,
+                public_key_offset,
+                u16::MAX,
+                message_offset,
+                entry_message.len() as u16,
+                u16::MAX,
+            ] {
+                data.extend_from_slice(&field.to_le_bytes());
+            }
+        }
+        data.extend_from_slice(&blobs);
+        let instruction = SolanaInstruction {
+            program_id: ED25519_PROGRAM_ID,
+            accounts: vec![],
+            data,
+        };
+
+        assert!(VerificationResult::ed25519_ix_matches(
+            &instruction,
+            0,
+            &[Pubkey::new_from_array(pubkey)],
+            &message,
+            &signature,
+        )
+        .unwrap());
+
+        // * Without the matching entry, the first entry's error surfaces
+        let mut single = instruction.clone();
+        single.data[0] = 1;
+        assert_eq!(
+            VerificationResult::ed25519_ix_matches(
+                &single,
+                0,
+                &[Pubkey::new_from_array(pubkey)],
+                &message,
+                &signature,
+            )
+            .unwrap_err(),
+            VeiledError::InvalidMessageSize.into()
+        );
+
+        // * Too many entries for one instruction
+        let mut oversized = instruction;
+        oversized.data[0] = (MAX_SIGNATURES_PER_INSTRUCTION + 1) as u8;
+        assert_eq!(
+            VerificationResult::ed25519_ix_matches(
+                &oversized,
+                0,
+                &[Pubkey::new_from_array(pubkey)],
+                &message,
+                &signature,
+            )
+            .unwrap_err(),
+            VeiledError::InvalidSignatureCount.into()
+        );
+    }
+
+    // * Test: Results must be neither stale nor dated ahead of the clock skew allowance
+    #[test]
+    fn test_is_recent() {
+        let now = 1_700_000_000;
+        let result_at = |timestamp: u64| VerificationResult {
+            is_valid: true,
+            proof_hash: [3u8; 32],
+            timestamp,
+            nullifier: [4u8; 32],
+            domain: [6u8; 32],
+            public_inputs_hash: None,
+            circuit_id: None,
+            vk_hash: None,
+            cluster_id: None,
+            instance_id: None,
+            claim_type: None,
+            tagged: false,
+            verifier_signature: [2u8; 64],
+        };
+
+        assert!(result_at(now as u64 - 300).is_recent(now, 300).is_ok());
+        assert_eq!(
+            result_at(now as u64 - 301).is_recent(now, 300).unwrap_err(),
+            VeiledError::ProofExpired.into()
+        );
+
+        assert!(result_at(now as u64 + 60).is_recent(now, 300).is_ok());
+        for future in [now as u64 + 61, now as u64 + 90 * 24 * 60 * 60, u64::MAX] {
+            assert_eq!(
+                result_at(future).is_recent(now, 300).unwrap_err(),
+                VeiledError::TimestampInFuture.into()
+            );
+        }
+    }
+
+    // * Test: Legacy and V2 encodings parse to the same result
+    #[test]
+    fn test_versioned_parsing() {
+        let v1 = create_instruction_data(true, [3u8; 32], 42, [4u8; 32], [6u8; 32], [5u8; 64]);
+        let mut v2 = vec![VerificationResultV2::VERSION];
+        v2.extend_from_slice(&v1);
+
+        let from_v1 = VerificationResult::decode(&v1).unwrap();
+        let from_v2 = VerificationResult::decode(&v2).unwrap();
+        assert_eq!(from_v1.signed_message(), from_v2.signed_message());
+        assert_eq!(from_v1.verifier_signature, from_v2.verifier_signature);
+
+        // * V2 is exact-length
+        v2.push(0);
+        assert_eq!(
+            VerificationResult::decode(&v2).unwrap_err(),
+            VeiledError::ResultLengthMismatch.into()
+        );
+    }
+
+    // * Test: Each result has exactly one encoding
+    #[test]
+    fn test_canonical_encoding() {
+        let v1 = create_instruction_data(true, [3u8; 32], 42, [4u8; 32], [6u8; 32], [5u8; 64]);
+
+        // * Legacy bodies don't tolerate trailing data either
+        let mut padded = v1.clone();
+        padded.push(0);
+        assert_eq!(
+            VerificationResult::decode(&padded).unwrap_err(),
+            VeiledError::ResultLengthMismatch.into()
+        );
+        assert_eq!(
+            VerificationResult::decode(&v1[..RESULT_LEN - 1]).unwrap_err(),
+            VeiledError::ResultLengthMismatch.into()
+        );
+
+        // * is_valid = 2 would otherwise read as false under a signature over 0
+        let mut v2 = vec![VerificationResultV2::VERSION];
+        v2.extend_from_slice(&v1);
+        v2[1] = 2;
+        assert_eq!(
+            VerificationResult::decode(&v2).unwrap_err(),
+            VeiledError::NonCanonicalBool.into()
+        );
+    }
+
+    // * Test: Unknown versions are rejected
+    #[test]
+    fn test_unknown_version() {
+        let mut data = vec![10u8];
+        data.extend_from_slice(&[0u8; RESULT_LEN]);
+
+        assert_eq!(
+            VerificationResult::decode(&data).unwrap_err(),
+            VeiledError::UnsupportedResultVersion.into()
+        );
+    }
+
+    // * Test: V3 binds public_inputs_hash into the signed message
+    #[test]
+    fn test_v3_public_inputs() {
+        let body = create_instruction_data(true, [3u8; 32], 42, [4u8; 32], [6u8; 32], [5u8; 64]);
+        let mut v3 = vec![VerificationResultV3::VERSION];
+        v3.extend_from_slice(&body[..RESULT_LEN - 64]);
+        v3.extend_from_slice(&[8u8; 32]); // * public_inputs_hash
+        v3.extend_from_slice(&body[RESULT_LEN - 64..]);
+
+        let result = VerificationResult::decode(&v3).unwrap();
+        let message = result.signed_message();
+        assert_eq!(result.public_inputs_hash, Some([8u8; 32]));
+        assert_eq!(message.len(), MESSAGE_V3_LEN);
+        assert_eq!(&message[105..137], &[8u8; 32]);
+
+        // * Signed over different public inputs
+        let other_inputs = VerificationResult {
+            public_inputs_hash: Some([9u8; 32]),
+            ..result.clone()
+        };
+        let instruction = create_mock_ed25519_instruction(
+            u16::MAX,
+            u16::MAX,
+            u16::MAX,
+            &[1u8; 32],
+            &other_inputs.signed_message(),
+            &result.verifier_signature,
+        );
+        assert_eq!(
+            VerificationResult::ed25519_ix_matches(
+                &instruction,
+                0,
+                &[Pubkey::new_from_array([1u8; 32])],
+                &message,
+                &result.verifier_signature,
+            )
+            .unwrap_err(),
+            VeiledError::PublicInputsMismatch.into()
+        );
+
+        // * All-zero public inputs hash is rejected
+        v3[1 + RESULT_LEN - 64..1 + RESULT_LEN - 32].copy_from_slice(&[0u8; 32]);
+        assert_eq!(
+            VerificationResult::decode(&v3).unwrap_err(),
+            VeiledError::InvalidPublicInputs.into()
+        );
+    }
+
+    // * Test: V4 binds circuit_id into the signed message
+    #[test]
+    fn test_v4_circuit_id() {
+        let body = create_instruction_data(true, [3u8; 32], 42, [4u8; 32], [6u8; 32], [5u8; 64]);
+        let mut v4 = vec![VerificationResultV4::VERSION];
+        v4.extend_from_slice(&body[..RESULT_LEN - 64]);
+        v4.extend_from_slice(&[8u8; 32]); // * public_inputs_hash
+        v4.extend_from_slice(&2u32.to_le_bytes()); // * circuit_id
+        v4.extend_from_slice(&body[RESULT_LEN - 64..]);
+        assert_eq!(v4.len(), VerificationResultV4::LEN);
+
+        let result = VerificationResult::decode(&v4).unwrap();
+        let message = result.signed_message();
+        assert_eq!(result.circuit_id, Some(2));
+        assert_eq!(message.len(), MESSAGE_V4_LEN);
+        assert_eq!(&message[137..141], &2u32.to_le_bytes());
+
+        // * Signed for a different circuit
+        let other_circuit = VerificationResult {
+            circuit_id: Some(1),
+            ..result.clone()
+        };
+        let instruction = create_mock_ed25519_instruction(
+            u16::MAX,
+            u16::MAX,
+            u16::MAX,
+            &[1u8; 32],
+            &other_circuit.signed_message(),
+            &result.verifier_signature,
+        );
+        assert_eq!(
+            VerificationResult::ed25519_ix_matches(
+                &instruction,
+                0,
+                &[Pubkey::new_from_array([1u8; 32])],
+                &message,
+                &result.verifier_signature,
+            )
+            .unwrap_err(),
+            VeiledError::CircuitMismatch.into()
+        );
+
+        // * A V3 signature (no circuit ID) doesn't satisfy a V4 result
+        let without_circuit = VerificationResult {
+            circuit_id: None,
+            ..result.clone()
+        };
+        let instruction = create_mock_ed25519_instruction(
+            u16::MAX,
+            u16::MAX,
+            u16::MAX,
+            &[1u8; 32],
+            &without_circuit.signed_message(),
+            &result.verifier_signature,
+        );
+        assert_eq!(
+            VerificationResult::ed25519_ix_matches(
+                &instruction,
+                0,
+                &[Pubkey::new_from_array([1u8; 32])],
+                &message,
+                &result.verifier_signature,
+            )
+            .unwrap_err(),
+            VeiledError::InvalidMessageSize.into()
+        );
+    }
+
+    // * Test: V5 binds vk_hash into the signed message
+    #[test]
+    fn test_v5_vk_hash() {
+        let body = create_instruction_data(true, [3u8; 32], 42, [4u8; 32], [6u8; 32], [5u8; 64]);
+        let mut v5 = vec![VerificationResultV5::VERSION];
+        v5.extend_from_slice(&body[..RESULT_LEN - 64]);
+        v5.extend_from_slice(&[8u8; 32]); // * public_inputs_hash
+        v5.extend_from_slice(&2u32.to_le_bytes()); // * circuit_id
+        v5.extend_from_slice(&[7u8; 32]); // * vk_hash
+        v5.extend_from_slice(&body[RESULT_LEN - 64..]);
+        assert_eq!(v5.len(), VerificationResultV5::LEN);
+
+        let result = VerificationResult::decode(&v5).unwrap();
+        let message = result.signed_message();
+        assert_eq!(result.circuit_id, Some(2));
+        assert_eq!(result.vk_hash, Some([7u8; 32]));
+        assert_eq!(message.len(), MESSAGE_V5_LEN);
+        assert_eq!(&message[141..173], &[7u8; 32]);
+
+        // * Signed over a different verification key
+        let other_vk = VerificationResult {
+            vk_hash: Some([9u8; 32]),
+            ..result.clone()
+        };
+        let instruction = create_mock_ed25519_instruction(
+            u16::MAX,
+            u16::MAX,
+            u16::MAX,
+            &[1u8; 32],
+            &other_vk.signed_message(),
+            &result.verifier_signature,
+        );
+        assert_eq!(
+            VerificationResult::ed25519_ix_matches(
+                &instruction,
+                0,
+                &[Pubkey::new_from_array([1u8; 32])],
+                &message,
+                &result.verifier_signature,
+            )
+            .unwrap_err(),
+            VeiledError::VkHashMismatch.into()
+        );
+    }
+
+    // * Test: The typed argument signs the same message as the byte envelope it replaces
+    #[test]
+    fn test_signed_verification_result() {
+        let body = create_instruction_data(true, [3u8; 32], 42, [4u8; 32], [6u8; 32], [5u8; 64]);
+        let mut v4 = vec![VerificationResultV4::VERSION];
+        v4.extend_from_slice(&body[..RESULT_LEN - 64]);
+        v4.extend_from_slice(&[8u8; 32]); // * public_inputs_hash
+        v4.extend_from_slice(&2u32.to_le_bytes()); // * circuit_id
+        v4.extend_from_slice(&body[RESULT_LEN - 64..]);
+        let from_bytes = VerificationResult::decode(&v4).unwrap();
+
+        let typed = SignedVerificationResult {
+            is_valid: true,
+            proof_hash: [3u8; 32],
+            timestamp: 42,
+            nullifier: [4u8; 32],
+            domain: [6u8; 32],
+            public_inputs_hash: Some([8u8; 32]),
+            circuit_id: Some(2),
+            vk_hash: None,
+            cluster_id: None,
+            instance_id: None,
+            claim_type: None,
+            tagged: false,
+            signature: [5u8; 64],
+        };
+        let from_typed = VerificationResult::try_from(typed.clone()).unwrap();
+        assert_eq!(&*from_typed.signed_message(), &*from_bytes.signed_message());
+        assert_eq!(from_typed.circuit_id, Some(2));
+        assert_eq!(from_typed.verifier_signature, [5u8; 64]);
+
+        // * A circuit ID the message wouldn't carry is rejected
+        let gap = SignedVerificationResult {
+            public_inputs_hash: None,
+            ..typed
+        };
+        assert!(VerificationResult::try_from(gap).is_err());
+    }
+
+    // * Test: V6 binds the cluster and only accepts this build's
+    #[test]
+    fn test_v6_cluster_id() {
+        let body = create_instruction_data(true, [3u8; 32], 42, [4u8; 32], [6u8; 32], [5u8; 64]);
+        let mut v6 = vec![VerificationResultV6::VERSION];
+        v6.extend_from_slice(&body[..RESULT_LEN - 64]);
+        v6.extend_from_slice(&[8u8; 32]); // * public_inputs_hash
+        v6.extend_from_slice(&2u32.to_le_bytes()); // * circuit_id
+        v6.extend_from_slice(&[7u8; 32]); // * vk_hash
+        v6.extend_from_slice(&cluster::CLUSTER_ID);
+        v6.extend_from_slice(&body[RESULT_LEN - 64..]);
+        assert_eq!(v6.len(), VerificationResultV6::LEN);
+
+        let result = VerificationResult::decode(&v6).unwrap();
+        let message = result.signed_message();
+        assert_eq!(result.cluster_id, Some(cluster::CLUSTER_ID));
+        assert_eq!(message.len(), MESSAGE_V6_LEN);
+        assert_eq!(&message[173..205], &cluster::CLUSTER_ID);
+
+        let instruction = create_mock_ed25519_instruction(
+            u16::MAX,
+            u16::MAX,
+            u16::MAX,
+            &[1u8; 32],
+            &message,
+            &result.verifier_signature,
+        );
+        assert!(VerificationResult::ed25519_ix_matches(
+            &instruction,
+            0,
+            &[Pubkey::new_from_array([1u8; 32])],
+            &message,
+            &result.verifier_signature,
+        )
+        .unwrap());
+
+        // * Signed for another cluster: rejected even when the result claims the same one
+        let other_cluster = if cluster::CLUSTER_ID == cluster::DEVNET {
+            cluster::MAINNET_BETA
+        } else {
+            cluster::DEVNET
+        };
+        let replayed = VerificationResult {
+            cluster_id: Some(other_cluster),
+            ..result.clone()
+        };
+        let instruction = create_mock_ed25519_instruction(
+            u16::MAX,
+            u16::MAX,
+            u16::MAX,
+            &[1u8; 32],
+            &replayed.signed_message(),
+            &result.verifier_signature,
+        );
+        assert_eq!(
+            VerificationResult::ed25519_ix_matches(
+                &instruction,
+                0,
+                &[Pubkey::new_from_array([1u8; 32])],
+                &replayed.signed_message(),
+                &result.verifier_signature,
+            )
+            .unwrap_err(),
+            VeiledError::ClusterMismatch.into()
+        );
+    }
+
+    // * Test: V7 binds the deployment and only accepts this program's instance ID
+    #[test]
+    fn test_v7_instance_id() {
+        let body = create_instruction_data(true, [3u8; 32], 42, [4u8; 32], [6u8; 32], [5u8; 64]);
+        let mut v7 = vec![VerificationResultV7::VERSION];
+        v7.extend_from_slice(&body[..RESULT_LEN - 64]);
+        v7.extend_from_slice(&[8u8; 32]); // * public_inputs_hash
+        v7.extend_from_slice(&2u32.to_le_bytes()); // * circuit_id
+        v7.extend_from_slice(&[7u8; 32]); // * vk_hash
+        v7.extend_from_slice(&cluster::CLUSTER_ID);
+        v7.extend_from_slice(&genesis::INSTANCE_ID);
+        v7.extend_from_slice(&body[RESULT_LEN - 64..]);
+        assert_eq!(v7.len(), VerificationResultV7::LEN);
+
+        let result = VerificationResult::decode(&v7).unwrap();
+        let message = result.signed_message();
+        assert_eq!(genesis::INSTANCE_ID, crate::ID.to_bytes());
+        assert_eq!(result.instance_id, Some(genesis::INSTANCE_ID));
+        assert_eq!(message.len(), MESSAGE_V7_LEN);
+        assert_eq!(&message[205..237], &genesis::INSTANCE_ID);
+
+        let verifier = [Pubkey::new_from_array([1u8; 32])];
+        let instruction = create_mock_ed25519_instruction(
+            u16::MAX,
+            u16::MAX,
+            u16::MAX,
+            &[1u8; 32],
+            &message,
+            &result.verifier_signature,
+        );
+        assert!(VerificationResult::ed25519_ix_matches(
+            &instruction,
+            0,
+            &verifier,
+            &message,
+            &result.verifier_signature,
+        )
+        .unwrap());
+
+        // * Signed for another instance: rejected even when the result claims the same one
+        let replayed = VerificationResult {
+            instance_id: Some(Pubkey::new_unique().to_bytes()),
+            ..result.clone()
+        };
+        let instruction = create_mock_ed25519_instruction(
+            u16::MAX,
+            u16::MAX,
+            u16::MAX,
+            &[1u8; 32],
+            &replayed.signed_message(),
+            &result.verifier_signature,
+        );
+        assert_eq!(
+            VerificationResult::ed25519_ix_matches(
+                &instruction,
+                0,
+                &verifier,
+                &replayed.signed_message(),
+                &result.verifier_signature,
+            )
+            .unwrap_err(),
+            VeiledError::InstanceMismatch.into()
+        );
+    }
+
+    // * Test: V8 binds the claim type; unknown claim bytes and swapped claims are rejected
+    #[test]
+    fn test_v8_claim_type() {
+        let body = create_instruction_data(true, [3u8; 32], 42, [4u8; 32], [6u8; 32], [5u8; 64]);
+        let mut v8 = vec![VerificationResultV8::VERSION];
+        v8.extend_from_slice(&body[..RESULT_LEN - 64]);
+        v8.extend_from_slice(&[8u8; 32]); // * public_inputs_hash
+        v8.extend_from_slice(&2u32.to_le_bytes()); // * circuit_id
+        v8.extend_from_slice(&[7u8; 32]); // * vk_hash
+        v8.extend_from_slice(&cluster::CLUSTER_ID);
+        v8.extend_from_slice(&genesis::INSTANCE_ID);
+        v8.push(ClaimType::AgeOver.to_byte());
+        v8.extend_from_slice(&body[RESULT_LEN - 64..]);
+        assert_eq!(v8.len(), VerificationResultV8::LEN);
+
+        let result = VerificationResult::decode(&v8).unwrap();
+        let message = result.signed_message();
+        assert_eq!(result.claim_type, Some(ClaimType::AgeOver));
+        assert!(!ClaimType::AgeOver.opens_session());
+        assert_eq!(message.len(), MESSAGE_V8_LEN);
+        assert_eq!(message[237], 2);
+
+        let mut unknown = v8.clone();
+        unknown[VerificationResultV8::LEN - 65] = 4; // * claim_type, just before the signature
+        assert_eq!(
+            VerificationResult::decode(&unknown).unwrap_err(),
+            VeiledError::UnknownClaimType.into()
+        );
+
+        // * An AgeOver signature doesn't stand in for an Auth result
+        let verifier = [Pubkey::new_from_array([1u8; 32])];
+        let instruction = create_mock_ed25519_instruction(
+            u16::MAX,
+            u16::MAX,
+            u16::MAX,
+            &[1u8; 32],
+            &message,
+            &result.verifier_signature,
+        );
+        let relabeled = VerificationResult {
+            claim_type: Some(ClaimType::Auth),
+            ..result.clone()
+        };
+        assert_eq!(
+            VerificationResult::ed25519_ix_matches(
+                &instruction,
+                0,
+                &verifier,
+                &relabeled.signed_message(),
+                &result.verifier_signature,
+            )
+            .unwrap_err(),
+            VeiledError::ClaimTypeMismatch.into()
+        );
+    }
+
+    // * Test: V9 prefixes the V8 message with the protocol tag, and the tag must be signed
+    #[test]
+    fn test_v9_message_tag() {
+        let body = create_instruction_data(true, [3u8; 32], 42, [4u8; 32], [6u8; 32], [5u8; 64]);
+        let mut v9 = vec![VerificationResultV9::VERSION];
+        v9.extend_from_slice(&body[..RESULT_LEN - 64]);
+        v9.extend_from_slice(&[8u8; 32]); // * public_inputs_hash
+        v9.extend_from_slice(&2u32.to_le_bytes()); // * circuit_id
+        v9.extend_from_slice(&[7u8; 32]); // * vk_hash
+        v9.extend_from_slice(&cluster::CLUSTER_ID);
+        v9.extend_from_slice(&genesis::INSTANCE_ID);
+        v9.push(ClaimType::Auth.to_byte());
+        v9.extend_from_slice(&body[RESULT_LEN - 64..]);
+        assert_eq!(v9.len(), VerificationResultV9::LEN);
+
+        let result = VerificationResult::decode(&v9).unwrap();
+        let message = result.signed_message();
+        assert!(result.tagged);
+        assert_eq!(message.len(), MESSAGE_V9_LEN);
+        assert_eq!(&message[..MESSAGE_TAG.len()], MESSAGE_TAG);
+
+        let untagged = VerificationResult {
+            tagged: false,
+            ..result.clone()
+        };
+        assert_eq!(&message[MESSAGE_TAG.len()..], &*untagged.signed_message());
+
+        let verifier = [Pubkey::new_from_array([1u8; 32])];
+        let tagged_ix = create_mock_ed25519_instruction(
+            u16::MAX,
+            u16::MAX,
+            u16::MAX,
+            &[1u8; 32],
+            &message,
+            &result.verifier_signature,
+        );
+        assert!(VerificationResult::ed25519_ix_matches(
+            &tagged_ix,
+            0,
+            &verifier,
+            &message,
+            &result.verifier_signature,
+        )
+        .unwrap());
+
+        // * A signature over the bare layout (e.g. made for another protocol) isn't a V9 result
+        let bare_ix = create_mock_ed25519_instruction(
+            u16::MAX,
+            u16::MAX,
+            u16::MAX,
+            &[1u8; 32],
+            &untagged.signed_message(),
+            &result.verifier_signature,
+        );
+        assert_eq!(
+            VerificationResult::ed25519_ix_matches(
+                &bare_ix,
+                0,
+                &verifier,
+                &message,
+                &result.verifier_signature,
+            )
+            .unwrap_err(),
+            VeiledError::MessageTagMismatch.into()
+        );
+    }
+
+    // * Test: Byte envelopes must be tagged; the same fields as V8 are rejected
+    #[test]
+    fn test_untagged_envelope_rejected() {
+        let body = create_instruction_data(true, [3u8; 32], 42, [4u8; 32], [6u8; 32], [5u8; 64]);
+        let mut v9 = vec![VerificationResultV9::VERSION];
+        v9.extend_from_slice(&body[..RESULT_LEN - 64]);
+        v9.extend_from_slice(&[8u8; 32]); // * public_inputs_hash
+        v9.extend_from_slice(&2u32.to_le_bytes()); // * circuit_id
+        v9.extend_from_slice(&[7u8; 32]); // * vk_hash
+        v9.extend_from_slice(&cluster::CLUSTER_ID);
+        v9.extend_from_slice(&genesis::INSTANCE_ID);
+        v9.push(ClaimType::Auth.to_byte());
+        v9.extend_from_slice(&body[RESULT_LEN - 64..]);
+        let result = VerificationResult::from_instruction_data(&v9).unwrap();
+        assert!(result.tagged);
+
+        let mut v8 = v9.clone();
+        v8[0] = VerificationResultV8::VERSION;
+        assert!(VerificationResult::decode(&v8).is_ok());
+        assert_eq!(
+            VerificationResult::from_instruction_data(&v8).unwrap_err(),
+            VeiledError::UntaggedResult.into()
+        );
+        assert_eq!(
+            VerificationResult::from_instruction_data(&body).unwrap_err(),
+            VeiledError::UntaggedResult.into()
+        );
+    }
+
+    // * Test: Body fields split off in order, and running out of data is an error
+    #[test]
+    fn test_body_reader() {
+        let data = [1u8, 2, 3, 4, 5];
+        let mut body = BodyReader(&data);
+
+        assert_eq!(body.take::<2>().unwrap(), [1, 2]);
+        assert_eq!(
+            body.take_from::<1>(BodyLayout::Legacy, BodyLayout::PublicInputs)
+                .unwrap(),
+            None
+        );
+        assert_eq!(
+            body.take_from::<1>(BodyLayout::PublicInputs, BodyLayout::PublicInputs)
+                .unwrap(),
+            Some([3])
+        );
+        assert_eq!(
+            body.take::<4>().unwrap_err(),
+            VeiledError::ResultLengthMismatch.into()
+        );
+    }
+
+    // * Test: Signature comparison is plain equality, whatever the timing
+    #[test]
+    fn test_ct_eq() {
+        let signature = [7u8; 64];
+        let mut other = signature;
+
+        assert!(ct_eq(&signature, &other));
+        other[63] ^= 1;
+        assert!(!ct_eq(&signature, &other));
+        assert!(!ct_eq(&signature, &signature[..63]));
+    }
+
+    // * Test: The ProofRecord seed is the proof hash in every layout
+    #[test]
+    fn test_proof_hash_seed() {
+        let body = create_instruction_data(true, [3u8; 32], 42, [4u8; 32], [6u8; 32], [5u8; 64]);
+        assert_eq!(proof_hash_seed(&body), &[3u8; 32]);
+
+        let mut v2 = vec![VerificationResultV2::VERSION];
+        v2.extend_from_slice(&body);
+        assert_eq!(
+            proof_hash_seed(&v2),
+            &VerificationResult::decode(&v2).unwrap().proof_hash
+        );
+
+        assert!(proof_hash_seed(&[2u8; 10]).is_empty());
+    }
+
     // * Test: Instruction with Accounts (should fail)
     #[test]
     fn test_instruction_with_accounts() {
         let pubkey = [1u8; 32];
-        let message = vec![0u8; 41];
+        let message = vec![0u8; MESSAGE_LEN];
         let signature = [2u8; 64];
 
         let mut instruction = create_mock_ed25519_instruction(
@@ -0,0 +1,490 @@
+// * Verification result checks
+// * Everything verify_auth decides about a signed result, shared by its typed and byte-argument
+// * instructions and by verify_auth_batch, so every path holds a result to exactly the same checks
+
+use anchor_lang::prelude::*;
+
+use crate::errors::VeiledError;
+use crate::localnet;
+use crate::sandbox;
+use crate::sig_introspection;
+use crate::state;
+use crate::state::attestation::Attestation;
+use crate::state::ban::NullifierBan;
+use crate::state::circuit::CircuitRegistry;
+use crate::state::config::AssurancePolicy;
+use crate::state::domain::{DomainConfig, NullifierStore};
+use crate::state::expiry::ExpiryKind;
+use crate::state::proof_record::ProofRecord;
+use crate::state::rate_limit::PayerRateLimit;
+use crate::state::result_receipt::ResultReceipt;
+use crate::state::session::{SessionDetails, UserConsent};
+use crate::state::stats::{ProtocolStats, StatsCounter};
+use crate::state::verification_result::ClaimType;
+use crate::state::verifier::{Secp256r1VerifierRegistry, VerifierRegistry};
+use crate::ultrahonk::{VerificationResult, SECP256R1_PUBKEY_LEN};
+use crate::NullifierAccount;
+
+/// * Registries and submitter a result is judged against; loaded once per instruction
+pub struct ResultChecker<'a, 'info> {
+    authority: Pubkey,
+    instructions_sysvar: &'a AccountInfo<'info>,
+    circuit_registry: &'a CircuitRegistry,
+    verifier_registry: &'a VerifierRegistry,
+    passkeys: Vec<[u8; SECP256R1_PUBKEY_LEN]>,
+    assurance_policy: Option<AssurancePolicy>,
+    now: i64,
+    consent_ix: Option<usize>,
+    named_verifier: Option<Pubkey>,
+}
+
+/// * verify_auth's accounts, borrowed from either of its instructions' Accounts structs or
+/// * from a verify_auth_batch entry's remaining_accounts
+pub struct VerifyAuthAccounts<'a, 'info> {
+    pub nullifier_account: &'a mut Account<'info, NullifierAccount>,
+    pub nullifier_account_bump: u8,
+    pub proof_record: &'a mut Account<'info, ProofRecord>,
+    pub proof_record_bump: u8,
+    pub result_receipt: &'a AccountInfo<'info>,
+    pub authority: &'a Signer<'info>,
+    pub verifier: Option<Pubkey>,
+    pub instructions_sysvar: &'a AccountInfo<'info>,
+    pub circuit_registry: &'a CircuitRegistry,
+    pub domain_config: &'a AccountInfo<'info>,
+    pub nullifier_ban: &'a AccountInfo<'info>,
+    pub logout_epoch: Option<&'a AccountInfo<'info>>,
+    pub verifier_registry: &'a VerifierRegistry,
+    pub secp256r1_verifier_registry: &'a AccountInfo<'info>,
+    pub assurance_policy: &'a AccountInfo<'info>,
+    pub payer_rate_limit: &'a AccountLoader<'info, PayerRateLimit>,
+    pub payer_rate_limit_bump: u8,
+    pub protocol_stats: &'a mut Account<'info, ProtocolStats>,
+    pub protocol_stats_bump: u8,
+    pub system_program: &'a Program<'info, System>,
+}
+
+impl VerifyAuthAccounts<'_, '_> {
+    /// * Check the result and register its nullifier; returns the new session
+    /// * origin_hash is the origin that started auth, if the caller supplied one
+    /// * With consent, the session belongs to the consenting user rather than the submitter
+    /// * expires_in is the session lifetime asked for (0 = the domain's default)
+    pub fn verify_and_register(
+        self,
+        result: VerificationResult,
+        nullifier: [u8; 32],
+        domain: [u8; 32],
+        origin_hash: Option<[u8; 32]>,
+        consent: Option<UserConsent>,
+        expires_in: i64,
+    ) -> Result<SessionDetails> {
+        let clock = Clock::get()?;
+        let current_timestamp = clock.unix_timestamp;
+        let domain_config = DomainConfig::load(self.domain_config)?;
+        if let Some(domain_config) = &domain_config {
+            domain_config.require_not_paused()?;
+            domain_config.require_nullifier_store(NullifierStore::Accounts)?;
+            domain_config.check_origin(origin_hash.as_ref())?;
+        }
+        NullifierBan::require_not_banned(self.nullifier_ban)?;
+        let expires_at =
+            DomainConfig::session_expires_at(domain_config.as_ref(), expires_in, &clock)?;
+
+        // * One payer can only push so many verifications through per window, sandbox included
+        PayerRateLimit::enforce(
+            self.payer_rate_limit,
+            self.authority.key(),
+            self.payer_rate_limit_bump,
+            domain_config.as_ref().map_or(0, |config| config.rate_limit),
+            current_timestamp,
+        )?;
+
+        // * Signature, circuit, freshness and nullifier / domain binding
+        let mut checker = ResultChecker::new(
+            self.authority.key(),
+            self.instructions_sysvar,
+            self.circuit_registry,
+            self.verifier_registry,
+            self.secp256r1_verifier_registry,
+            self.assurance_policy,
+            current_timestamp,
+        )?;
+        if let Some(verifier) = self.verifier {
+            checker.name_verifier(verifier);
+        }
+
+        // * The user's wallet must have signed off on this nullifier and domain, so whoever
+        // * submits (and pays) can't open sessions in someone else's name
+        let session_authority = match consent {
+            Some(consent) => {
+                require!(
+                    consent.expires_at > current_timestamp,
+                    VeiledError::UserConsentExpired
+                );
+                let consent_ix = sig_introspection::signed_instruction(
+                    self.instructions_sysvar,
+                    &consent.user,
+                    &consent.message(&nullifier, &domain),
+                    VeiledError::MissingUserConsent,
+                )?;
+                checker.skip_consent_instruction(consent_ix);
+                consent.user
+            }
+            None => self.authority.key(),
+        };
+        let accepted = checker.check(result, nullifier, domain, domain_config.as_ref())?;
+        accepted.require_session_claim()?;
+
+        // * Check if nullifier has already been used
+        // * With init_if_needed, account might already exist
+        // * Check nullifier value first (more specific check)
+        let nullifier_account = self.nullifier_account;
+
+        // * Check if this exact nullifier was already used (replay protection)
+        // * This is the primary check - if nullifier matches and its session is still live,
+        // * reject; once that session expires or is logged out of, a fresh proof replaces it
+        if nullifier_account.nullifier == nullifier
+            && nullifier_account.is_live(&clock, self.logout_epoch)?
+        {
+            return Err(VeiledError::DuplicateNullifier.into());
+        }
+
+        // * A proof registers once: reusing it for another nullifier or domain (or replaying it
+        // * for the same one) is rejected
+        let proof_record = self.proof_record;
+        require!(proof_record.created_at == 0, VeiledError::ProofAlreadyUsed);
+        proof_record.set_inner(accepted.proof_record(self.proof_record_bump, current_timestamp));
+
+        // * ...and so does the signed result, whichever instruction it's submitted through
+        accepted.consume_receipt(
+            self.result_receipt,
+            self.authority,
+            self.system_program,
+            self.verifier_registry,
+            current_timestamp,
+        )?;
+
+        // * Store nullifier in PDA account
+        // * A replaced session keeps its payer: the rent it put up is still in the account
+        let payer = if nullifier_account.created_at == 0 {
+            self.authority.key()
+        } else {
+            nullifier_account.payer
+        };
+        let session = accepted.into_session(
+            session_authority,
+            payer,
+            self.nullifier_account_bump,
+            current_timestamp,
+            expires_at,
+        );
+        let details = SessionDetails {
+            nullifier_account: nullifier_account.key(),
+            created_at: session.created_at,
+            expires_at: session.expires_at,
+        };
+        nullifier_account.set_inner(session);
+        self.protocol_stats
+            .record(self.protocol_stats_bump, StatsCounter::Verifications, 1);
+
+        Ok(details)
+    }
+}
+
+/// * A result that passed every check, ready to register
+pub struct AcceptedResult {
+    pub result: VerificationResult,
+    pub nullifier: [u8; 32],
+    pub domain: [u8; 32],
+    pub domain_str: String,
+    pub circuit_id: u32,
+    pub claim_type: ClaimType,
+    pub challenge_window_secs: i64,
+    pub idle_timeout_secs: i64,
+    pub verifier: Pubkey,
+}
+
+impl<'a, 'info> ResultChecker<'a, 'info> {
+    /// * secp256r1_verifier_registry and assurance_policy are optional PDAs (pinned by seeds)
+    pub fn new(
+        authority: Pubkey,
+        instructions_sysvar: &'a AccountInfo<'info>,
+        circuit_registry: &'a CircuitRegistry,
+        verifier_registry: &'a VerifierRegistry,
+        secp256r1_verifier_registry: &AccountInfo,
+        assurance_policy: &AccountInfo,
+        now: i64,
+    ) -> Result<Self> {
+        let passkeys =
+            state::load_optional::<Secp256r1VerifierRegistry>(secp256r1_verifier_registry)?
+                .map_or_else(Vec::new, |registry| registry.verifiers);
+        Ok(Self {
+            authority,
+            instructions_sysvar,
+            circuit_registry,
+            verifier_registry,
+            passkeys,
+            assurance_policy: state::load_optional::<AssurancePolicy>(assurance_policy)?,
+            now,
+            consent_ix: None,
+            named_verifier: None,
+        })
+    }
+
+    /// * Judge results by `verifier` (an explicit verifier account) instead of the submitting
+    /// * authority, so a relayer or app can pay while a dedicated service signs
+    pub fn name_verifier(&mut self, verifier: Pubkey) {
+        self.named_verifier = Some(verifier);
+    }
+
+    /// * Leave the Ed25519 instruction carrying the user's consent out of the verifier
+    /// * signature scan
+    pub fn skip_consent_instruction(&mut self, index: usize) {
+        self.consent_ix = Some(index);
+    }
+
+    /// * Check the result was validly signed, recently, for this nullifier and domain
+    /// * domain_config is the (possibly missing) DomainConfig at the domain's PDA
+    pub fn check(
+        &self,
+        result: VerificationResult,
+        nullifier: [u8; 32],
+        domain: [u8; 32],
+        domain_config: Option<&DomainConfig>,
+    ) -> Result<AcceptedResult> {
+        // * Canonical form only (lowercase, see DomainConfig::is_canonical), so case variants of
+        // * a domain can't register separate sessions
+        let domain_str = DomainConfig::validate_domain(&domain)?.to_string();
+
+        // * Reserved sandbox domain: relaxed checks on devnet builds, rejected everywhere else
+        let sandbox = sandbox::is_sandbox_domain(domain_str.as_bytes());
+        require!(
+            !sandbox || sandbox::ENABLED,
+            VeiledError::SandboxUnavailable
+        );
+
+        // * The signed result must have been issued for this exact nullifier
+        require!(
+            result.nullifier == nullifier,
+            VeiledError::NullifierMismatch
+        );
+
+        // * ...and for this domain, so a result signed for one app can't be reused by another
+        require!(result.domain == domain, VeiledError::DomainMismatch);
+
+        // * Results without a circuit ID came from the legacy auth circuit
+        let circuit_id = result
+            .circuit_id
+            .unwrap_or(CircuitRegistry::LEGACY_CIRCUIT_ID);
+
+        // * Results without a claim type (before V8) were all logins
+        let claim_type = result.claim_type.unwrap_or(ClaimType::Auth);
+
+        // * Domains using optimistic verification hold new sessions pending for this long
+        let mut challenge_window_secs = 0;
+
+        // * Key whose Ed25519 instruction carried the result's signature (default if none did)
+        let mut verifier = Pubkey::default();
+
+        // * Sandbox results need no registered circuit, verifier signature, or fresh timestamp
+        if !sandbox {
+            // * The issuing circuit must be registered and not deprecated
+            let circuit = self.circuit_registry.require_active(circuit_id)?;
+
+            // * A carried vk_hash must be the key registered for that circuit
+            if let Some(vk_hash) = &result.vk_hash {
+                require!(vk_hash == &circuit.vk_hash, VeiledError::VkHashMismatch);
+            }
+
+            // * Domains that pin a key hash only accept results carrying it
+            if let Some(domain_config) = domain_config {
+                domain_config.check_vk_hash(result.vk_hash.as_ref())?;
+                challenge_window_secs = domain_config.challenge_window_secs;
+            }
+
+            // * Domains that run their own verification service accept only its keys, from any
+            // * submitter. Otherwise the named verifier (by default the submitting authority)
+            // * must be allowlisted, and the registry's threshold of allowlisted verifiers must
+            // * co-sign the result. A named verifier must be one of those signers, so naming a
+            // * registered key that never saw the result doesn't pass.
+            // * Keys rotated out of the registry keep counting for their successor during the
+            // * grace period, so results signed just before a rotation still land.
+            // * Registered passkey verifiers co-sign through the Secp256r1 precompile instead.
+            let (verifiers, retiring, passkeys, threshold) =
+                match domain_config.and_then(DomainConfig::designated_verifiers) {
+                    Some(domain_verifiers) => (domain_verifiers, Vec::new(), &[][..], 1),
+                    None => {
+                        let registry = self.verifier_registry;
+                        let verifier = self.named_verifier.unwrap_or(self.authority);
+                        require!(
+                            registry.is_accepted(&verifier, self.now),
+                            VeiledError::UnregisteredVerifier
+                        );
+                        if self.named_verifier.is_some() && !localnet::ENABLED {
+                            sig_introspection::require_signed(
+                                self.instructions_sysvar,
+                                &verifier,
+                                &result.signed_message(),
+                                VeiledError::UnregisteredVerifier,
+                            )?;
+                        }
+                        (
+                            registry.verifiers.as_slice(),
+                            registry.retiring_keys(self.now),
+                            self.passkeys.as_slice(),
+                            registry.required_signatures(),
+                        )
+                    }
+                };
+
+            // * Validate signature(s) via Ed25519Program / Secp256r1 instructions present in tx
+            verifier = if localnet::ENABLED {
+                // * Stubbed on localnet builds: the submitter stands in for the signer
+                msg!("localnet-insecure build: verifier signature not checked");
+                let signer = self.named_verifier.unwrap_or(self.authority);
+                require!(
+                    verifiers.contains(&signer),
+                    VeiledError::UnregisteredVerifier
+                );
+                signer
+            } else {
+                result.validate_signature(
+                    verifiers,
+                    &retiring,
+                    passkeys,
+                    threshold,
+                    self.instructions_sysvar,
+                    self.consent_ix,
+                )?
+            };
+
+            // * Check if verification result is recent (not stale), by the domain's window
+            let max_result_age = match domain_config {
+                Some(domain_config) => domain_config.max_result_age(self.assurance_policy.as_ref()),
+                None => AssurancePolicy::max_result_age(self.assurance_policy.as_ref(), 0),
+            };
+            result.is_recent(self.now, max_result_age)?;
+        }
+
+        // * Only accept valid proofs
+        require!(result.is_valid, VeiledError::InvalidProof);
+
+        msg!("✓ Proof verified off-chain and validated on-chain");
+        msg!("  Proof hash: {:?}", result.proof_hash);
+        msg!("  Verified at: {}", result.timestamp);
+
+        Ok(AcceptedResult {
+            result,
+            nullifier,
+            domain,
+            domain_str,
+            circuit_id,
+            claim_type,
+            challenge_window_secs,
+            idle_timeout_secs: DomainConfig::idle_timeout(domain_config),
+            verifier,
+        })
+    }
+}
+
+impl AcceptedResult {
+    /// * Only Auth results register sessions, so a signed balance or age claim can't be
+    /// * submitted as a login
+    pub fn require_session_claim(&self) -> Result<()> {
+        require!(
+            self.claim_type.opens_session(),
+            VeiledError::ClaimNotSession
+        );
+        Ok(())
+    }
+
+    /// * Spend the result's signature (see ResultReceipt); sandbox fixtures stay replayable
+    pub fn consume_receipt<'info>(
+        &self,
+        result_receipt: &AccountInfo<'info>,
+        payer: &Signer<'info>,
+        system_program: &Program<'info, System>,
+        verifier_registry: &VerifierRegistry,
+        now: i64,
+    ) -> Result<()> {
+        if sandbox::is_sandbox_domain(self.domain_str.as_bytes()) {
+            return Ok(());
+        }
+        ResultReceipt::consume(
+            result_receipt,
+            payer,
+            system_program,
+            &self.result.verifier_signature,
+            now,
+            verifier_registry.receipt_retention(),
+        )
+    }
+
+    /// * Record of the proof's first use
+    pub fn proof_record(&self, bump: u8, now: i64) -> ProofRecord {
+        ProofRecord {
+            proof_hash: self.result.proof_hash,
+            nullifier: self.nullifier,
+            domain: self.domain,
+            created_at: now,
+            bump,
+        }
+    }
+
+    /// * Attestation of the result's claim, lasting until `expires_at` (see Attestation::expiry)
+    pub fn attestation(&self, bump: u8, now: i64, expires_at: i64) -> Attestation {
+        Attestation {
+            nullifier: self.nullifier,
+            claim_type: self.claim_type,
+            domain: self.domain,
+            // * Always set: claim types arrive with V8, which carries every earlier extension
+            claim_data_hash: self.result.public_inputs_hash.unwrap_or_default(),
+            circuit_id: self.circuit_id,
+            issuer: self.verifier,
+            issued_at: now,
+            expires_at,
+            bump,
+            revoked_at: 0,
+        }
+    }
+
+    /// * Session registered for `authority`, expiring at `expires_at` (see
+    /// * DomainConfig::session_expires_at)
+    /// * `payer` funded the account's rent and gets it back when the session is closed
+    pub fn into_session(
+        self,
+        authority: Pubkey,
+        payer: Pubkey,
+        bump: u8,
+        now: i64,
+        expires_at: ExpiryKind,
+    ) -> NullifierAccount {
+        msg!("Nullifier: {:?}", self.nullifier);
+        msg!("Domain: {}", self.domain_str);
+
+        NullifierAccount {
+            nullifier: self.nullifier,
+            domain: self.domain,
+            domain_len: self.domain_str.len() as u8,
+            authority,
+            created_at: now,
+            last_active_at: now,
+            idle_timeout_secs: self.idle_timeout_secs,
+            expires_at,
+            // * Records which statement was verified (V3+); zero means the result didn't bind it
+            public_inputs_hash: self.result.public_inputs_hash.unwrap_or_default(),
+            circuit_id: self.circuit_id,
+            proof_hash: self.result.proof_hash,
+            // * Checked against the clock by is_recent, so it fits (sandbox results aren't)
+            verified_at: i64::try_from(self.result.timestamp).unwrap_or(i64::MAX),
+            verifier: self.verifier,
+            finalizes_at: if self.challenge_window_secs > 0 {
+                now + self.challenge_window_secs
+            } else {
+                0
+            },
+            payer,
+            device_key: Pubkey::default(),
+            bump,
+        }
+    }
+}
@@ -0,0 +1,663 @@
+// * Account Substitution Regression Tests
+// *
+// * Permutes, omits, and substitutes accounts for each instruction and asserts the
+// * exact rejection error, so constraint coverage can't silently regress as the
+// * account surface grows.
+// *
+// * Attack shapes covered:
+// * - Wrong sysvar / fixed-address account
+// * - PDA derived for someone else (correct owner and layout, wrong seeds)
+// * - Program-owned account of a different type (discriminator mismatch)
+// * - Identical-size account owned by another program
+// * - Uninitialized address
+// * - Omitted account / swapped account order
+// * - Signer flag stripped from a required signer
+// * - Wrong authority for has_one-protected accounts
+// *
+// * All substitutions use accountsStrict() or raw instructions so Anchor's client-side
+// * account resolution can't "fix" the substituted account. On top of the cases below, every
+// * instruction in the IDL is sent with its accounts omitted, so a new instruction is covered
+// * as soon as it lands in the IDL.
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import {
+  Keypair,
+  PublicKey,
+  SystemProgram,
+  SYSVAR_CLOCK_PUBKEY,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
+  Transaction,
+  TransactionInstruction,
+} from "@solana/web3.js";
+import { createHash } from "crypto";
+import { expect } from "chai";
+import type { Veiled } from "../target/types/veiled";
+
+describe("Account Substitution", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.Veiled as Program<Veiled>;
+  const wallet = provider.wallet.publicKey;
+
+  // * Test fixtures
+  const nullifier = new Uint8Array(32).fill(7);
+  const otherNullifier = new Uint8Array(32).fill(8);
+  const appId = Keypair.generate().publicKey;
+  const otherAppId = Keypair.generate().publicKey;
+  const attacker = Keypair.generate();
+  const tokenProgram = new PublicKey("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+  const associatedTokenProgram = new PublicKey("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL");
+
+  // * Helper: Derive a program PDA
+  function pda(...seeds: (Buffer | Uint8Array)[]): PublicKey {
+    const [address] = PublicKey.findProgramAddressSync(
+      seeds.map((seed) => Buffer.from(seed)),
+      program.programId
+    );
+    return address;
+  }
+
+  const permissionPDA = pda(Buffer.from("permission"), nullifier, appId.toBuffer());
+  const otherPermissionPDA = pda(Buffer.from("permission"), otherNullifier, otherAppId.toBuffer());
+  const logoutEpochPDA = pda(Buffer.from("logout_epoch"), wallet.toBuffer());
+  // * Sessions and domain configs are seeded by sha256 of the (all-zero) domain the tests pass
+  const domainHash = createHash("sha256").update(new Uint8Array(32)).digest();
+  const domainConfigPDA = pda(Buffer.from("domain_config"), domainHash);
+  const expiryHistogramPDA = pda(Buffer.from("expiry_histogram"), domainHash);
+
+  // * Accounts #[event_cpi] appends to every instruction that emits events
+  const eventAccounts = {
+    eventAuthority: pda(Buffer.from("__event_authority")),
+    program: program.programId,
+  };
+
+  // * Helper: Extract the Anchor error code from an rpc() or sendAndConfirm() failure
+  function errorCode(error: any): string {
+    const parsed = error?.logs ? anchor.AnchorError.parse(error.logs) : null;
+    return (
+      parsed?.error.errorCode.code ??
+      error?.error?.errorCode?.code ??
+      error?.message ??
+      String(error)
+    );
+  }
+
+  // * Helper: Assert a transaction is rejected with exactly `code`
+  async function expectRejected(action: Promise<unknown>, code: string): Promise<void> {
+    let rejected = false;
+    try {
+      await action;
+    } catch (error: any) {
+      rejected = true;
+      expect(errorCode(error)).to.equal(code);
+    }
+    expect(rejected, "transaction should have been rejected").to.equal(true);
+  }
+
+  // * Helper: Send a raw instruction (for omitted / reordered / unsigned accounts)
+  async function sendRaw(ix: TransactionInstruction, signers: Keypair[] = []): Promise<string> {
+    return provider.sendAndConfirm(new Transaction().add(ix), signers);
+  }
+
+  before(async () => {
+    try {
+      const sig = await provider.connection.requestAirdrop(
+        attacker.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(sig);
+    } catch (error) {
+      console.warn("Could not airdrop SOL:", error);
+    }
+
+    // * Two grants (ours and someone else's) plus a LogoutEpoch to use as a wrong-type account
+    for (const [n, app] of [
+      [nullifier, appId],
+      [otherNullifier, otherAppId],
+    ] as const) {
+      await program.methods
+        .grantPermissions(
+          Array.from(n),
+          Array.from(new Uint8Array(32)),
+          app,
+          [{ revealWalletAddress: {} }],
+          { unixTime: { 0: new anchor.BN(3600) } }
+        )
+        .accounts({
+          domainConfig: domainConfigPDA,
+          expiryHistogram: expiryHistogramPDA,
+          payer: wallet,
+        })
+        .rpc();
+    }
+
+    await program.methods.globalLogout().accounts({ identity: wallet }).rpc();
+  });
+
+  // * verify_auth
+
+  it("verify_auth: rejects a non-instructions sysvar", async () => {
+    await expectRejected(
+      program.methods
+        .verifyAuthV1(Buffer.alloc(202), Array.from(nullifier), Array.from(new Uint8Array(32)))
+        .accountsStrict({
+          nullifierAccount: pda(Buffer.from("nullifier"), nullifier, domainHash),
+          proofRecord: pda(Buffer.from("proof_record"), new Uint8Array(32)),
+          resultReceipt: pda(Buffer.from("result_receipt"), new Uint8Array(32)),
+          authority: wallet,
+          instructionsSysvar: SYSVAR_CLOCK_PUBKEY,
+          circuitRegistry: pda(Buffer.from("circuit_registry")),
+          domainConfig: pda(Buffer.from("domain_config"), domainHash),
+          nullifierBan: pda(Buffer.from("nullifier_ban"), new Uint8Array(32), nullifier),
+          verifierRegistry: pda(Buffer.from("verifier_registry")),
+          secp256r1VerifierRegistry: pda(Buffer.from("secp256r1_verifier_registry")),
+          assurancePolicy: pda(Buffer.from("assurance_policy")),
+          payerRateLimit: pda(Buffer.from("rate_limit"), wallet.toBuffer()),
+          protocolStats: pda(Buffer.from("protocol_stats")),
+          systemProgram: SystemProgram.programId,
+          ...eventAccounts,
+        })
+        .rpc(),
+      "ConstraintAddress"
+    );
+  });
+
+  it("verify_auth: rejects a nullifier PDA derived for another nullifier", async () => {
+    await expectRejected(
+      program.methods
+        .verifyAuthV1(Buffer.alloc(202), Array.from(nullifier), Array.from(new Uint8Array(32)))
+        .accountsStrict({
+          nullifierAccount: pda(Buffer.from("nullifier"), otherNullifier, domainHash),
+          proofRecord: pda(Buffer.from("proof_record"), new Uint8Array(32)),
+          resultReceipt: pda(Buffer.from("result_receipt"), new Uint8Array(32)),
+          authority: wallet,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          circuitRegistry: pda(Buffer.from("circuit_registry")),
+          domainConfig: pda(Buffer.from("domain_config"), domainHash),
+          nullifierBan: pda(Buffer.from("nullifier_ban"), new Uint8Array(32), nullifier),
+          verifierRegistry: pda(Buffer.from("verifier_registry")),
+          secp256r1VerifierRegistry: pda(Buffer.from("secp256r1_verifier_registry")),
+          assurancePolicy: pda(Buffer.from("assurance_policy")),
+          payerRateLimit: pda(Buffer.from("rate_limit"), wallet.toBuffer()),
+          protocolStats: pda(Buffer.from("protocol_stats")),
+          systemProgram: SystemProgram.programId,
+          ...eventAccounts,
+        })
+        .rpc(),
+      "ConstraintSeeds"
+    );
+  });
+
+  it("verify_auth: rejects an omitted account", async () => {
+    const ix = await program.methods
+      .verifyAuthV1(Buffer.alloc(202), Array.from(nullifier), Array.from(new Uint8Array(32)))
+      .accountsStrict({
+        nullifierAccount: pda(Buffer.from("nullifier"), nullifier, domainHash),
+        proofRecord: pda(Buffer.from("proof_record"), new Uint8Array(32)),
+        resultReceipt: pda(Buffer.from("result_receipt"), new Uint8Array(32)),
+        authority: wallet,
+        instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        circuitRegistry: pda(Buffer.from("circuit_registry")),
+        domainConfig: pda(Buffer.from("domain_config"), domainHash),
+        nullifierBan: pda(Buffer.from("nullifier_ban"), new Uint8Array(32), nullifier),
+        verifierRegistry: pda(Buffer.from("verifier_registry")),
+        secp256r1VerifierRegistry: pda(Buffer.from("secp256r1_verifier_registry")),
+        assurancePolicy: pda(Buffer.from("assurance_policy")),
+        payerRateLimit: pda(Buffer.from("rate_limit"), wallet.toBuffer()),
+        protocolStats: pda(Buffer.from("protocol_stats")),
+        systemProgram: SystemProgram.programId,
+        ...eventAccounts,
+      })
+      .instruction();
+    ix.keys = ix.keys.slice(0, 3); // * Drop everything after the authority
+
+    await expectRejected(sendRaw(ix), "AccountNotEnoughKeys");
+  });
+
+  // * grant_permissions
+
+  it("grant_permissions: rejects a domain config derived for another domain", async () => {
+    // * The config carries the domain's permission cap, so it can't be swapped for a laxer one
+    const otherDomainHash = createHash("sha256").update(new Uint8Array(32).fill(1)).digest();
+    await expectRejected(
+      program.methods
+        .grantPermissions(
+          Array.from(nullifier),
+          Array.from(new Uint8Array(32)),
+          appId,
+          [{ revealWalletAddress: {} }],
+          { unixTime: { 0: new anchor.BN(3600) } }
+        )
+        .accountsStrict({
+          permissionGrant: permissionPDA,
+          expiryHistogram: expiryHistogramPDA,
+          domainConfig: pda(Buffer.from("domain_config"), otherDomainHash),
+          payer: wallet,
+          protocolStats: pda(Buffer.from("protocol_stats")),
+          systemProgram: SystemProgram.programId,
+          ...eventAccounts,
+        })
+        .rpc(),
+      "ConstraintSeeds"
+    );
+  });
+
+  // * revoke_permissions
+
+  it("revoke_permissions: rejects swapped account order", async () => {
+    const ix = await program.methods
+      .revokePermissions()
+      .accountsStrict({
+        permissionGrant: permissionPDA,
+        authority: wallet,
+        protocolStats: pda(Buffer.from("protocol_stats")),
+        systemProgram: SystemProgram.programId,
+        ...eventAccounts,
+      })
+      .instruction();
+    ix.keys = [ix.keys[1], ix.keys[0], ...ix.keys.slice(2)]; // * Wallet where the grant should be
+
+    await expectRejected(sendRaw(ix), "AccountOwnedByWrongProgram");
+  });
+
+  it("revoke_permissions: rejects an authority without a signature", async () => {
+    const ix = await program.methods
+      .revokePermissions()
+      .accountsStrict({
+        permissionGrant: permissionPDA,
+        authority: attacker.publicKey,
+        protocolStats: pda(Buffer.from("protocol_stats")),
+        systemProgram: SystemProgram.programId,
+        ...eventAccounts,
+      })
+      .instruction();
+    ix.keys[1] = { ...ix.keys[1], isSigner: false };
+
+    await expectRejected(sendRaw(ix), "AccountNotSigner");
+  });
+
+  // * log_permission_access / try_check_permission
+
+  it("try_check_permission: rejects a program-owned account of another type", async () => {
+    await expectRejected(
+      program.methods
+        .tryCheckPermission({ revealWalletAddress: {} })
+        .accountsStrict({ permissionGrant: logoutEpochPDA, ...eventAccounts })
+        .rpc(),
+      "AccountDiscriminatorMismatch"
+    );
+  });
+
+  it("try_check_permission: rejects an identical-size account owned by another program", async () => {
+    // * Any owner other than Veiled is rejected before the data is read,
+    // * so a foreign account with a byte-identical layout fails the same way
+    const foreign = Keypair.generate();
+    const space = (await provider.connection.getAccountInfo(permissionPDA))!.data.length;
+    await provider.sendAndConfirm(
+      new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: wallet,
+          newAccountPubkey: foreign.publicKey,
+          lamports: await provider.connection.getMinimumBalanceForRentExemption(space),
+          space,
+          programId: Keypair.generate().publicKey,
+        })
+      ),
+      [foreign]
+    );
+
+    await expectRejected(
+      program.methods
+        .tryCheckPermission({ revealWalletAddress: {} })
+        .accountsStrict({ permissionGrant: foreign.publicKey, ...eventAccounts })
+        .rpc(),
+      "AccountOwnedByWrongProgram"
+    );
+  });
+
+  it("log_permission_access: rejects a ban PDA derived for another domain", async () => {
+    const access = Keypair.generate();
+    await expectRejected(
+      program.methods
+        .logPermissionAccess({ revealWalletAddress: {} }, "", Array.from(new Uint8Array(32)))
+        .accountsStrict({
+          permissionAccess: access.publicKey,
+          permissionGrant: permissionPDA,
+          nullifierBan: pda(Buffer.from("nullifier_ban"), new Uint8Array(32).fill(1), nullifier),
+          payer: wallet,
+          systemProgram: SystemProgram.programId,
+          ...eventAccounts,
+        })
+        .signers([access])
+        .rpc(),
+      "ConstraintSeeds"
+    );
+  });
+
+  it("try_check_permission: rejects an uninitialized address", async () => {
+    await expectRejected(
+      program.methods
+        .tryCheckPermission({ revealWalletAddress: {} })
+        .accountsStrict({ permissionGrant: Keypair.generate().publicKey, ...eventAccounts })
+        .rpc(),
+      "AccountNotInitialized"
+    );
+  });
+
+  // * validate_session_token
+
+  it("validate_session_token: rejects a non-session account in place of the session", async () => {
+    // * logout_epoch seeds are derived from nullifier_account.authority, so the session
+    // * account must be authenticated before its authority field is trusted
+    await expectRejected(
+      program.methods
+        .validateSessionToken()
+        .accountsStrict({
+          nullifierAccount: permissionPDA,
+          logoutEpoch: logoutEpochPDA,
+          nullifierBan: pda(Buffer.from("nullifier_ban"), new Uint8Array(32), new Uint8Array(32)),
+        })
+        .rpc(),
+      "AccountDiscriminatorMismatch"
+    );
+  });
+
+  // * compact_access_logs
+
+  it("compact_access_logs: rejects malformed batches and substituted entries", async () => {
+    const access = Keypair.generate();
+    await program.methods
+      .logPermissionAccess({ revealWalletAddress: {} }, "substitution test", Array(32).fill(0))
+      .accounts({
+        permissionAccess: access.publicKey,
+        permissionGrant: permissionPDA,
+        payer: wallet,
+      })
+      .signers([access])
+      .rpc();
+
+    const compact = (grant: PublicKey, remaining: PublicKey[]) =>
+      program.methods
+        .compactAccessLogs()
+        .accountsStrict({
+          permissionGrant: grant,
+          summary: pda(Buffer.from("access_summary"), grant.toBuffer()),
+          cranker: wallet,
+          systemProgram: SystemProgram.programId,
+          ...eventAccounts,
+        })
+        .remainingAccounts(
+          remaining.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+        )
+        .rpc();
+
+    // * Odd number of remaining accounts
+    await expectRejected(compact(permissionPDA, [access.publicKey]), "InvalidCompactionBatch");
+
+    // * Entry belongs to a different grant
+    await expectRejected(
+      compact(otherPermissionPDA, [access.publicKey, wallet]),
+      "AccessLogMismatch"
+    );
+
+    // * Rent refund redirected to the cranker's attacker key
+    await expectRejected(
+      compact(permissionPDA, [access.publicKey, attacker.publicKey]),
+      "AccessLogPayerMismatch"
+    );
+
+    // * Grant account passed off as an access log
+    await expectRejected(
+      compact(permissionPDA, [otherPermissionPDA, wallet]),
+      "AccountDiscriminatorMismatch"
+    );
+
+    // * Correct entry, but too recent
+    await expectRejected(
+      compact(permissionPDA, [access.publicKey, wallet]),
+      "AccessLogTooRecent"
+    );
+  });
+
+  // * revoke_auditor
+
+  it("revoke_auditor: rejects a signer other than the granter", async () => {
+    const auditor = Keypair.generate().publicKey;
+    await program.methods
+      .grantAuditor(Array.from(nullifier), auditor, Array.from(new Uint8Array(32)), {
+        unixTime: { 0: new anchor.BN(3600) },
+      })
+      .accounts({ payer: wallet })
+      .rpc();
+
+    await expectRejected(
+      program.methods
+        .revokeAuditor()
+        .accountsStrict({
+          auditorGrant: pda(Buffer.from("auditor"), nullifier, auditor.toBuffer()),
+          grantedBy: attacker.publicKey,
+          ...eventAccounts,
+        })
+        .signers([attacker])
+        .rpc(),
+      "UnauthorizedRevocation"
+    );
+  });
+
+  // * grant_auditor / acknowledge_access_log
+
+  it("grant_auditor: rejects an auditor grant PDA derived for another auditor", async () => {
+    const auditor = Keypair.generate().publicKey;
+    await expectRejected(
+      program.methods
+        .grantAuditor(Array.from(nullifier), auditor, Array.from(new Uint8Array(32)), {
+          unixTime: { 0: new anchor.BN(3600) },
+        })
+        .accountsStrict({
+          auditorGrant: pda(Buffer.from("auditor"), nullifier, attacker.publicKey.toBuffer()),
+          payer: wallet,
+          systemProgram: SystemProgram.programId,
+          ...eventAccounts,
+        })
+        .rpc(),
+      "ConstraintSeeds"
+    );
+  });
+
+  it("acknowledge_access_log: rejects an auditor without a signature", async () => {
+    const auditor = Keypair.generate();
+    await program.methods
+      .grantAuditor(Array.from(nullifier), auditor.publicKey, Array.from(new Uint8Array(32)), {
+        unixTime: { 0: new anchor.BN(3600) },
+      })
+      .accounts({ payer: wallet })
+      .rpc();
+    const access = Keypair.generate();
+    await program.methods
+      .logPermissionAccess({ revealWalletAddress: {} }, "", Array.from(new Uint8Array(32)))
+      .accounts({
+        permissionAccess: access.publicKey,
+        permissionGrant: permissionPDA,
+        payer: wallet,
+      })
+      .signers([access])
+      .rpc();
+
+    const ix = await program.methods
+      .acknowledgeAccessLog()
+      .accountsStrict({
+        auditorGrant: pda(Buffer.from("auditor"), nullifier, auditor.publicKey.toBuffer()),
+        permissionAccess: access.publicKey,
+        permissionGrant: permissionPDA,
+        auditor: auditor.publicKey,
+        ...eventAccounts,
+      })
+      .instruction();
+    ix.keys[3] = { ...ix.keys[3], isSigner: false };
+
+    await expectRejected(sendRaw(ix), "AccountNotSigner");
+  });
+
+  // * global_logout
+
+  it("global_logout: rejects an identity without a signature", async () => {
+    const ix = await program.methods
+      .globalLogout()
+      .accountsStrict({
+        logoutEpoch: pda(Buffer.from("logout_epoch"), attacker.publicKey.toBuffer()),
+        identity: attacker.publicKey,
+        systemProgram: SystemProgram.programId,
+        ...eventAccounts,
+      })
+      .instruction();
+    ix.keys[1] = { ...ix.keys[1], isSigner: false };
+
+    await expectRejected(sendRaw(ix), "AccountNotSigner");
+  });
+
+  // * Protocol config and fees
+
+  it("initialize_config: rejects a config at a non-PDA address", async () => {
+    await expectRejected(
+      program.methods
+        .initializeConfig()
+        .accountsStrict({
+          config: Keypair.generate().publicKey,
+          authority: wallet,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc(),
+      "ConstraintSeeds"
+    );
+  });
+
+  it("set_accepted_mint: rejects an account of another type as the config", async () => {
+    await expectRejected(
+      program.methods
+        .setAcceptedMint(Keypair.generate().publicKey, Array.from(new Uint8Array(32)), true)
+        .accountsStrict({ config: logoutEpochPDA, authority: wallet, ...eventAccounts })
+        .rpc(),
+      "AccountDiscriminatorMismatch"
+    );
+  });
+
+  it("set_pricing: rejects an uninitialized config", async () => {
+    await expectRejected(
+      program.methods
+        .setPricing(new anchor.BN(1), new anchor.BN(60), 100)
+        .accountsStrict({
+          config: Keypair.generate().publicKey,
+          authority: wallet,
+          ...eventAccounts,
+        })
+        .rpc(),
+      "AccountNotInitialized"
+    );
+  });
+
+  // * Treasury accounts, for the fee instructions below
+  const treasuryPDA = pda(Buffer.from("treasury"));
+  const mint = Keypair.generate().publicKey;
+  const treasuryAta = PublicKey.findProgramAddressSync(
+    [treasuryPDA.toBuffer(), tokenProgram.toBuffer(), mint.toBuffer()],
+    associatedTokenProgram
+  )[0];
+
+  it("create_treasury_ata: rejects a grant in place of the config", async () => {
+    await expectRejected(
+      program.methods
+        .createTreasuryAta()
+        .accountsStrict({
+          config: permissionPDA,
+          treasury: treasuryPDA,
+          mint,
+          treasuryAta,
+          payer: wallet,
+          tokenProgram,
+          associatedTokenProgram,
+          systemProgram: SystemProgram.programId,
+          ...eventAccounts,
+        })
+        .rpc(),
+      "AccountDiscriminatorMismatch"
+    );
+  });
+
+  it("sweep_treasury_ata: rejects a config owned by another program", async () => {
+    await expectRejected(
+      program.methods
+        .sweepTreasuryAta(new anchor.BN(1))
+        .accountsStrict({
+          config: attacker.publicKey,
+          treasury: treasuryPDA,
+          mint,
+          treasuryAta,
+          destination: wallet,
+          authority: wallet,
+          tokenProgram,
+          ...eventAccounts,
+        })
+        .rpc(),
+      "AccountOwnedByWrongProgram"
+    );
+  });
+
+  it("pay_fee: rejects someone else's grant in place of the config", async () => {
+    await expectRejected(
+      program.methods
+        .payFee(new anchor.BN(1))
+        .accountsStrict({
+          config: otherPermissionPDA,
+          treasury: treasuryPDA,
+          mint,
+          treasuryAta,
+          payerTokenAccount: wallet,
+          priceUpdate: wallet,
+          payer: wallet,
+          tokenProgram,
+          ...eventAccounts,
+        })
+        .rpc(),
+      "AccountDiscriminatorMismatch"
+    );
+  });
+
+  // * Every instruction in the IDL
+
+  // * Borsh bytes an argument of `type` takes when every field is zero: empty vecs and strings,
+  // * None, the first enum variant. Zeroes decode as those values, so an all-zero buffer of
+  // * this length is a valid argument list for any instruction.
+  function zeroArgLen(type: any): number {
+    if (typeof type === "string") {
+      // * Numbers carry their width in the name (u8 ... i128, f32, f64)
+      const widths: Record<string, number> = { bool: 1, pubkey: 32, string: 4, bytes: 4 };
+      return widths[type] ?? Number(type.slice(1)) / 8;
+    }
+    if (type.array) return type.array[1] * zeroArgLen(type.array[0]);
+    if (type.vec) return 4;
+    if (type.option) return 1;
+    const defined = program.idl.types!.find((t) => t.name === type.defined.name)!.type as any;
+    const fieldsLen = (fields: any[] = []) =>
+      fields.reduce((len, field) => len + zeroArgLen(field.type ?? field), 0);
+    return defined.kind === "enum"
+      ? 1 + fieldsLen(defined.variants[0].fields)
+      : fieldsLen(defined.fields);
+  }
+
+  for (const ix of program.idl.instructions.filter((ix) => ix.accounts.length > 0)) {
+    it(`${ix.name}: rejects an omitted account list`, async () => {
+      const argsLen = ix.args.reduce((len, arg) => len + zeroArgLen(arg.type), 0);
+      await expectRejected(
+        sendRaw(
+          new TransactionInstruction({
+            programId: program.programId,
+            keys: [],
+            data: Buffer.concat([Buffer.from(ix.discriminator), Buffer.alloc(argsLen)]),
+          })
+        ),
+        "AccountNotEnoughKeys"
+      );
+    });
+  }
+});
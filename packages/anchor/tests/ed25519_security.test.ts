@@ -5,12 +5,80 @@
 // *
 // * Test Cases:
 // * 1. Valid Signature (Success Path)
-// * 2. Wrong Instruction Order
+// * 2. Ed25519 instruction after the program instruction accepted
 // * 3. Message Content Mismatch
 // * 4. Authority Mismatch
 // * 5. Invalid Signature
 // * 6. Expired Timestamp
 // * 7. Duplicate Nullifier
+// * 8. Nullifier Mismatch (result replayed with another nullifier)
+// * 9. Domain Mismatch
+// * 10. Legacy (V1) result accepted
+// * 11. Unknown result version rejected
+// * 12. V3 result stores public inputs hash
+// * 13. V4 result stores circuit ID
+// * 14. Unregistered circuit rejected
+// * 15. Deprecated circuit rejected
+// * 16. Sandbox domain rejected on non-devnet builds
+// * 17. Pinned domain rejects a result without a VK hash
+// * 18. Pinned domain accepts a V5 result carrying the pinned VK hash
+// * 19. Result signed by a verifier missing from the VerifierRegistry rejected
+// * 20. Domain verifier's result accepted from any submitter
+// * 21. Domain with its own verifiers rejects results signed by global verifiers
+// * 22. Result short of the verifier quorum rejected
+// * 23. Result co-signed by a quorum of registered verifiers accepted
+// * 24. Rotated-out verifier key accepted within the grace period
+// * 25. Rotated-out verifier key rejected once the grace period ends
+// * 26. Optimistic domain's session pending during the challenge window
+// * 27. Challenge with evidence that doesn't dispute the proof rejected
+// * 28. Verifier-signed invalid result voids a pending session
+// * 29. Payer past the domain's rate limit rejected
+// * 30. Allowlisted relayer's quota replaces the domain's rate limit
+// * 31. Registered passkey (secp256r1) co-signature meets the quorum
+// * 32. Secp256r1 signature by an unregistered passkey rejected
+// * 33. Result older than a high-assurance tier's window rejected
+// * 34. Same result age accepted on a default-tier domain
+// * 35. Result dated past the clock skew allowance rejected
+// * 36. Domain's own staleness window replaces its tier's
+// * 37. Domain operator rotates verifiers but can't change owner-only settings
+// * 38. Paused domain rejects new verifications
+// * 39. Operator resumes the domain; a cleared operator can't act
+// * 40. V6 result bound to this build's cluster accepted
+// * 41. V6 result signed for another cluster rejected
+// * 42. Proof reused for another nullifier rejected
+// * 43. Batch registers results for two domains in one transaction
+// * 44. Batch repeating a nullifier rejected as a whole
+// * 45. Typed verify_auth result accepted
+// * 46. Typed result with a circuit ID but no public inputs hash rejected
+// * 47. verify_auth returns the session it registers
+// * 48. verify_auth emits AuthVerifiedEvent as an inner instruction (emit_cpi!)
+// * 49. Guarded domain accepts a verification naming a registered origin
+// * 50. Guarded domain rejects an unregistered origin
+// * 51. Guarded domain rejects verify_auth without an origin
+// * 52. Relayed verification with the user's consent registers the session for the user
+// * 53. Consent signed by another wallet rejected
+// * 54. Consent signed for another nullifier rejected
+// * 55. Expired consent rejected
+// * 56. Relayer pays for a result signed by a named verifier
+// * 57. Named verifier that didn't sign the result rejected
+// * 58. V7 result bound to this program's instance accepted
+// * 59. V7 result signed for another instance rejected
+// * 60. Session lifetime asked for with expires_in is honored
+// * 61. Session lifetime past the domain's bounds rejected
+// * 62. Only the domain owner sets session bounds, within the protocol's
+// * 63. Session records the proof hash and when the verifier checked it
+// * 64. Session records the verifier that signed the result
+// * 65. Result with trailing bytes rejected
+// * 66. Consumed result keeps its receipt until retention ends
+// * 67. Receipt retention shorter than an hour rejected
+// * 68. V8 Auth claim opens a session
+// * 69. V8 claim other than Auth rejected as a login
+// * 70. V8 AgeOver claim stored as an attestation
+// * 71. V8 Auth claim rejected as an attestation
+// * 72. Attestation renewed from a fresh result for the same claim
+// * 73. Renewal with a result for another claim type rejected
+// * 74. Issuer revokes an attestation, which then fails validation
+// * 75. Revocation by anyone but the issuer rejected
 // *
 // * Note: Offset Mismatch test (Test 3 from plan) requires manual instruction
 // * construction with wrong offsets. Ed25519Program.createInstructionWithPublicKey
@@ -27,6 +95,7 @@ import {
   Transaction,
 } from "@solana/web3.js";
 import * as nacl from "tweetnacl";
+import { createHash, generateKeyPairSync, KeyObject, sign as signWithKey } from "crypto";
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { expect } from "chai";
@@ -34,37 +103,232 @@ import type { Veiled } from "../target/types/veiled";
 
 // * Program ID will be loaded from workspace
 
-// * Helper: Create verification result data
-// * Format: [1 byte: is_valid] [32 bytes: proof_hash] [8 bytes: timestamp] [64 bytes: signature]
-function createVerificationResult(
-  isValid: boolean,
-  proofHash: Uint8Array,
-  timestamp: number,
+// * Fields covered by the verifier signature
+interface VerificationFields {
+  isValid: boolean;
+  proofHash: Uint8Array;
+  timestamp: number;
+  nullifier: Uint8Array;
+  domain: Uint8Array;
+  publicInputsHash?: Uint8Array; // * V3+
+  circuitId?: number; // * V4+
+  vkHash?: Uint8Array; // * V5+
+  clusterId?: Uint8Array; // * V6+
+  instanceId?: Uint8Array; // * V7+
+  claimType?: number; // * V8+ (0 Auth, 1 BalanceThreshold, 2 AgeOver, 3 NftOwnership)
+  tagged?: boolean; // * V9 only: message prefixed with "veiled:v1"
+}
+
+// * CAIP-2 cluster references (program's cluster.rs); non-devnet builds bind to mainnet-beta
+const MAINNET_BETA_CLUSTER_ID = new TextEncoder().encode("5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp");
+const DEVNET_CLUSTER_ID = new TextEncoder().encode("EtWTRABZaYq6iMfeYKouRu166VU2xqa1");
+
+// * Helper: Create legacy (V1, unversioned) verification result data
+// * Format: [1 byte: is_valid] [32 bytes: proof_hash] [8 bytes: timestamp] [32 bytes: nullifier] [32 bytes: domain] [64 bytes: signature]
+function createLegacyVerificationResult(
+  fields: VerificationFields,
   signature: Uint8Array
 ): Uint8Array {
-  const result = new Uint8Array(105);
-  result[0] = isValid ? 1 : 0;
-  result.set(proofHash, 1);
+  const result = new Uint8Array(169);
+  result[0] = fields.isValid ? 1 : 0;
+  result.set(fields.proofHash, 1);
   const timestampBytes = new Uint8Array(8);
-  new DataView(timestampBytes.buffer).setBigUint64(0, BigInt(timestamp), true); // * Little-endian
+  new DataView(timestampBytes.buffer).setBigUint64(0, BigInt(fields.timestamp), true); // * Little-endian
   result.set(timestampBytes, 33);
-  result.set(signature, 41);
+  result.set(fields.nullifier, 41);
+  result.set(fields.domain, 73);
+  result.set(signature, 105);
   return result;
 }
 
-// * Helper: Create Ed25519 signed message
-// * Message format: proof_hash (32) || is_valid (1) || timestamp (8) = 41 bytes
-function createEd25519Message(
-  proofHash: Uint8Array,
-  isValid: boolean,
-  timestamp: number
+// * Helper: Create versioned verification result data
+// * Format: [1 byte: version] [169 bytes: legacy body]
+function createVerificationResult(
+  fields: VerificationFields,
+  signature: Uint8Array,
+  version = 2
+): Uint8Array {
+  const body = createLegacyVerificationResult(fields, signature);
+  const result = new Uint8Array(1 + body.length);
+  result[0] = version;
+  result.set(body, 1);
+  return result;
+}
+
+// * Helper: Create V3 verification result data
+// * Format: [1 byte: version = 3] [legacy body with 32 bytes: public_inputs_hash before the signature]
+function createV3VerificationResult(
+  fields: VerificationFields,
+  signature: Uint8Array
+): Uint8Array {
+  const body = createLegacyVerificationResult(fields, signature);
+  const result = new Uint8Array(1 + body.length + 32);
+  result[0] = 3;
+  result.set(body.subarray(0, 105), 1);
+  result.set(fields.publicInputsHash!, 106);
+  result.set(signature, 138);
+  return result;
+}
+
+// * Helper: Create V4 verification result data
+// * Format: [1 byte: version = 4] [legacy body with 32 bytes: public_inputs_hash and 4 bytes: circuit_id before the signature]
+function createV4VerificationResult(
+  fields: VerificationFields,
+  signature: Uint8Array
+): Uint8Array {
+  const result = new Uint8Array(206);
+  result.set(createV3VerificationResult(fields, signature).subarray(0, 138), 0);
+  result[0] = 4;
+  new DataView(result.buffer).setUint32(138, fields.circuitId!, true); // * Little-endian
+  result.set(signature, 142);
+  return result;
+}
+
+// * Helper: Create V5 verification result data
+// * Format: V4 with [32 bytes: vk_hash] after circuit_id
+function createV5VerificationResult(
+  fields: VerificationFields,
+  signature: Uint8Array
+): Uint8Array {
+  const result = new Uint8Array(238);
+  result.set(createV4VerificationResult(fields, signature).subarray(0, 142), 0);
+  result[0] = 5;
+  result.set(fields.vkHash!, 142);
+  result.set(signature, 174);
+  return result;
+}
+
+// * Helper: Create V6 verification result data
+// * Format: V5 with [32 bytes: cluster_id] after vk_hash
+function createV6VerificationResult(
+  fields: VerificationFields,
+  signature: Uint8Array
+): Uint8Array {
+  const result = new Uint8Array(270);
+  result.set(createV5VerificationResult(fields, signature).subarray(0, 174), 0);
+  result[0] = 6;
+  result.set(fields.clusterId!, 174);
+  result.set(signature, 206);
+  return result;
+}
+
+// * Helper: Create V7 verification result data
+// * Format: V6 with [32 bytes: instance_id] after cluster_id
+function createV7VerificationResult(
+  fields: VerificationFields,
+  signature: Uint8Array
+): Uint8Array {
+  const result = new Uint8Array(302);
+  result.set(createV6VerificationResult(fields, signature).subarray(0, 206), 0);
+  result[0] = 7;
+  result.set(fields.instanceId!, 206);
+  result.set(signature, 238);
+  return result;
+}
+
+// * Helper: Create V8 verification result data
+// * Format: V7 with [1 byte: claim_type] after instance_id
+function createV8VerificationResult(
+  fields: VerificationFields,
+  signature: Uint8Array
+): Uint8Array {
+  const result = new Uint8Array(303);
+  result.set(createV7VerificationResult(fields, signature).subarray(0, 238), 0);
+  result[0] = 8;
+  result[238] = fields.claimType!;
+  result.set(signature, 239);
+  return result;
+}
+
+// * Helper: Create V9 verification result data
+// * Format: V8 layout with version = 9 (the signed message carries the "veiled:v1" tag)
+function createV9VerificationResult(
+  fields: VerificationFields,
+  signature: Uint8Array
 ): Uint8Array {
-  const message = new Uint8Array(41);
-  message.set(proofHash, 0);
-  message[32] = isValid ? 1 : 0;
+  const result = createV8VerificationResult(fields, signature);
+  result[0] = 9;
+  return result;
+}
+
+// * Helper: verify_auth's typed result argument (SignedVerificationResult)
+function createSignedResultArg(fields: VerificationFields, signature: Uint8Array) {
+  const optional = (bytes?: Uint8Array) => (bytes ? Array.from(bytes) : null);
+  return {
+    isValid: fields.isValid,
+    proofHash: Array.from(fields.proofHash),
+    timestamp: new anchor.BN(fields.timestamp),
+    nullifier: Array.from(fields.nullifier),
+    domain: Array.from(fields.domain),
+    publicInputsHash: optional(fields.publicInputsHash),
+    circuitId: fields.circuitId ?? null,
+    vkHash: optional(fields.vkHash),
+    clusterId: optional(fields.clusterId),
+    instanceId: optional(fields.instanceId),
+    claimType:
+      fields.claimType === undefined
+        ? null
+        : { [["auth", "balanceThreshold", "ageOver", "nftOwnership"][fields.claimType]]: {} },
+    tagged: fields.tagged ?? false,
+    signature: Array.from(signature),
+  };
+}
+
+// * Helper: Create Ed25519 signed message
+// * Message format: proof_hash (32) || is_valid (1) || timestamp (8) || nullifier (32) || domain (32) = 105 bytes
+// * V3 appends public_inputs_hash (32) = 137 bytes; V4 also appends circuit_id (4) = 141 bytes;
+// * V5 also appends vk_hash (32) = 173 bytes; V6 also appends cluster_id (32) = 205 bytes;
+// * V7 also appends instance_id (32) = 237 bytes; V8 also appends claim_type (1) = 238 bytes
+// * V9 prefixes the V8 message with the "veiled:v1" protocol tag = 247 bytes
+function createEd25519Message(fields: VerificationFields): Uint8Array {
+  if (fields.tagged) {
+    const tag = new TextEncoder().encode("veiled:v1");
+    const body = createEd25519Message({ ...fields, tagged: false });
+    const tagged = new Uint8Array(tag.length + body.length);
+    tagged.set(tag, 0);
+    tagged.set(body, tag.length);
+    return tagged;
+  }
+  const message = new Uint8Array(
+    fields.claimType !== undefined
+      ? 238
+      : fields.instanceId
+        ? 237
+        : fields.clusterId
+          ? 205
+          : fields.vkHash
+            ? 173
+            : fields.circuitId !== undefined
+              ? 141
+              : fields.publicInputsHash
+                ? 137
+                : 105
+  );
+  message.set(fields.proofHash, 0);
+  message[32] = fields.isValid ? 1 : 0;
   const timestampBytes = new Uint8Array(8);
-  new DataView(timestampBytes.buffer).setBigUint64(0, BigInt(timestamp), true); // * Little-endian
+  new DataView(timestampBytes.buffer).setBigUint64(0, BigInt(fields.timestamp), true); // * Little-endian
   message.set(timestampBytes, 33);
+  message.set(fields.nullifier, 41);
+  message.set(fields.domain, 73);
+  if (fields.publicInputsHash) {
+    message.set(fields.publicInputsHash, 105);
+  }
+  if (fields.circuitId !== undefined) {
+    new DataView(message.buffer).setUint32(137, fields.circuitId, true); // * Little-endian
+  }
+  if (fields.vkHash) {
+    message.set(fields.vkHash, 141);
+  }
+  if (fields.clusterId) {
+    message.set(fields.clusterId, 173);
+  }
+  if (fields.instanceId) {
+    message.set(fields.instanceId, 205);
+  }
+  if (fields.claimType !== undefined) {
+    message[237] = fields.claimType;
+  }
   return message;
 }
 
@@ -76,6 +340,122 @@ function signMessage(
   return nacl.sign.detached(message, keypair.secretKey);
 }
 
+// * Secp256r1 precompile (SIMD-0075); web3.js has no instruction builder for it yet
+const SECP256R1_PROGRAM_ID = new PublicKey("Secp256r1SigVerify1111111111111111111111111");
+
+// * SPL Account Compression program (cloned into the test validator, see Anchor.toml)
+const COMPRESSION_PROGRAM_ID = new PublicKey("cmtDvXumGCrqC1Age74AVPhSRVXJMd8PJS91L8KbNCK");
+
+// * P-256 group order; the precompile only accepts low-S signatures
+const P256_ORDER = BigInt("0xffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551");
+
+// * Helper: Generate a P-256 key, returning it with its compressed (33-byte) public key
+function generatePasskey(): { privateKey: KeyObject; publicKey: Uint8Array } {
+  const { privateKey, publicKey } = generateKeyPairSync("ec", { namedCurve: "prime256v1" });
+  const jwk = publicKey.export({ format: "jwk" });
+  const x = Buffer.from(jwk.x!, "base64url");
+  const y = Buffer.from(jwk.y!, "base64url");
+  const compressed = new Uint8Array(33);
+  compressed[0] = y[31] & 1 ? 0x03 : 0x02;
+  compressed.set(x, 1);
+  return { privateKey, publicKey: compressed };
+}
+
+// * Helper: ECDSA-P256 / SHA-256 signature as r || s, normalized to low S
+function signPasskey(privateKey: KeyObject, message: Uint8Array): Uint8Array {
+  const signature = signWithKey("sha256", message, { key: privateKey, dsaEncoding: "ieee-p1363" });
+  const s = BigInt("0x" + signature.subarray(32).toString("hex"));
+  if (s > P256_ORDER / BigInt(2)) {
+    signature.set(Buffer.from((P256_ORDER - s).toString(16).padStart(64, "0"), "hex"), 32);
+  }
+  return signature;
+}
+
+// * Helper: Secp256r1 precompile instruction, same offsets layout as Ed25519Program
+// * [num_signatures: u8][padding: u8][offsets (14)][signature (64)][public key (33)][message]
+function createSecp256r1Instruction(
+  publicKey: Uint8Array,
+  message: Uint8Array,
+  signature: Uint8Array
+): anchor.web3.TransactionInstruction {
+  const signatureOffset = 16;
+  const publicKeyOffset = signatureOffset + 64;
+  const messageOffset = publicKeyOffset + 33;
+  const data = Buffer.alloc(messageOffset + message.length);
+  data[0] = 1;
+  for (const [i, value] of [
+    signatureOffset,
+    0xffff,
+    publicKeyOffset,
+    0xffff,
+    messageOffset,
+    message.length,
+    0xffff,
+  ].entries()) {
+    data.writeUInt16LE(value, 2 + i * 2);
+  }
+  data.set(signature, signatureOffset);
+  data.set(publicKey, publicKeyOffset);
+  data.set(message, messageOffset);
+  return new anchor.web3.TransactionInstruction({
+    programId: SECP256R1_PROGRAM_ID,
+    keys: [],
+    data,
+  });
+}
+
+// * Helper: Convert domain string to fixed-size array [u8; 32] for Rust
+function domainToArray(domainStr: string): number[] {
+  const domain = new Uint8Array(32);
+  const domainBytes = Buffer.from(domainStr, "utf8");
+  domain.set(domainBytes.slice(0, 32), 0);
+  return Array.from(domain);
+}
+
+// * Domain every test result is signed for
+const TEST_DOMAIN = Uint8Array.from(domainToArray("test-domain"));
+
+// * Helper: Session PDA [b"nullifier", nullifier, sha256(domain)]
+function sessionPda(nullifier: Uint8Array, domain: Uint8Array = TEST_DOMAIN): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("nullifier"),
+      Buffer.from(nullifier),
+      createHash("sha256").update(domain).digest(),
+    ],
+    VEILED_PROGRAM_ID
+  )[0];
+}
+
+// * Helper: DomainConfig PDA for a null-padded domain, keyed by its hash like sessions
+function domainConfigPda(domain: Uint8Array): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("domain_config"), createHash("sha256").update(domain).digest()],
+    VEILED_PROGRAM_ID
+  )[0];
+}
+
+// * Helper: A domain's grant ExpiryHistogram PDA, keyed like its DomainConfig
+function expiryHistogramPda(domain: Uint8Array): PublicKey {
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from("expiry_histogram"), createHash("sha256").update(domain).digest()],
+    VEILED_PROGRAM_ID
+  )[0];
+}
+
+// * Circuits registered for the V4 tests
+const AUTH_V4_CIRCUIT_ID = 1;
+const DEPRECATED_CIRCUIT_ID = 7;
+const UNREGISTERED_CIRCUIT_ID = 99;
+const GROTH16_CIRCUIT_ID = 8;
+const HONK_CIRCUIT_ID = 9;
+const MISMATCHED_HONK_CIRCUIT_ID = 10;
+
+// * VK hash ensureCircuitRegistered() registers for a circuit
+function registeredVkHash(circuitId: number): Uint8Array {
+  return new Uint8Array(32).fill(circuitId + 1);
+}
+
 describe("Ed25519 Security Tests", () => {
   // * Use Anchor's built-in provider (from Anchor.toml or environment)
   // * anchor test automatically sets up provider and workspace
@@ -88,6 +468,26 @@ describe("Ed25519 Security Tests", () => {
   // * Program ID from workspace
   const VEILED_PROGRAM_ID = program.programId;
 
+  // * Helper: ProofRecord PDA for a serialized result (keyed by its proof_hash)
+  function proofRecordPda(verificationResult: Uint8Array): PublicKey {
+    // * Legacy bodies start with is_valid (0/1), versioned envelopes with the version byte
+    const offset = verificationResult[0] <= 1 ? 1 : 2;
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("proof_record"), Buffer.from(verificationResult.subarray(offset, offset + 32))],
+      VEILED_PROGRAM_ID
+    )[0];
+  }
+
+  // * Helper: ResultReceipt PDA for a serialized result or a bare signature (keyed by
+  // * sha256 of the trailing 64-byte signature)
+  function resultReceiptPda(signed: Uint8Array): PublicKey {
+    const signature = signed.subarray(signed.length - 64);
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("result_receipt"), createHash("sha256").update(signature).digest()],
+      VEILED_PROGRAM_ID
+    )[0];
+  }
+
   // * Create test authority keypair
   const authority = Keypair.generate();
 
@@ -102,24 +502,126 @@ describe("Ed25519 Security Tests", () => {
     } catch (error) {
       console.warn("Could not airdrop SOL:", error);
     }
+
+    // * verify_auth only accepts registered circuits; results without a circuit ID map to circuit 0
+    for (const circuitId of [0, AUTH_V4_CIRCUIT_ID, DEPRECATED_CIRCUIT_ID]) {
+      await ensureCircuitRegistered(circuitId);
+    }
+    try {
+      await program.methods
+        .deprecateCircuit(DEPRECATED_CIRCUIT_ID)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    } catch {
+      // * Already deprecated by an earlier run
+    }
+
+    // * verify_auth only accepts results signed by allowlisted verifiers
+    await program.methods
+      .addVerifier(authority.publicKey)
+      .accounts({ authority: provider.wallet.publicKey })
+      .rpc();
+  });
+
+  // * Keep the registry from filling up across runs against a persistent validator
+  after(async () => {
+    await program.methods
+      .removeVerifier(authority.publicKey)
+      .accounts({ authority: provider.wallet.publicKey })
+      .rpc();
   });
 
+  // * Helper: Register a circuit (initializing config if this is the first suite to need it)
+  async function ensureCircuitRegistered(circuitId: number): Promise<void> {
+    try {
+      await program.methods
+        .initializeConfig()
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    } catch {
+      // * Already initialized
+    }
+    try {
+      await program.methods
+        .registerCircuit(circuitId, Array.from(registeredVkHash(circuitId)))
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    } catch {
+      // * Already registered
+    }
+  }
+
+  // * Helper: Sign a fresh V4 result for `circuitId` (V5 when `vkHash` is given)
+  function signedV4Result(
+    circuitId: number,
+    domain: Uint8Array = TEST_DOMAIN,
+    vkHash?: Uint8Array,
+    ageSecs = 0
+  ) {
+    const fields = {
+      isValid: true,
+      proofHash: crypto.getRandomValues(new Uint8Array(32)),
+      timestamp: Math.floor(Date.now() / 1000) - ageSecs,
+      nullifier: crypto.getRandomValues(new Uint8Array(32)),
+      domain,
+      publicInputsHash: crypto.getRandomValues(new Uint8Array(32)),
+      circuitId,
+      vkHash,
+    };
+    const message = createEd25519Message(fields);
+    const signature = signMessage(authority, message);
+    const result = vkHash
+      ? createV5VerificationResult(fields, signature)
+      : createV4VerificationResult(fields, signature);
+    return { fields, message, signature, result };
+  }
+
+  // * Helper: Fill in the V9 fields (registered Auth circuit, this cluster and instance) around
+  // * the V1 body fields, so the result can be sent as verify_auth_v1's tagged byte envelope
+  function taggedFields(fields: VerificationFields): VerificationFields {
+    return {
+      publicInputsHash: new Uint8Array(32),
+      circuitId: AUTH_V4_CIRCUIT_ID,
+      vkHash: registeredVkHash(AUTH_V4_CIRCUIT_ID),
+      clusterId: MAINNET_BETA_CLUSTER_ID,
+      instanceId: program.programId.toBytes(),
+      claimType: 0,
+      ...fields,
+      tagged: true,
+    };
+  }
+
+  // * Helper: Sign a fresh V9 result, the only byte envelope verify_auth_v1 accepts
+  function signedV9Result(domain: Uint8Array = TEST_DOMAIN, ageSecs = 0) {
+    const fields = taggedFields({
+      isValid: true,
+      proofHash: crypto.getRandomValues(new Uint8Array(32)),
+      timestamp: Math.floor(Date.now() / 1000) - ageSecs,
+      nullifier: crypto.getRandomValues(new Uint8Array(32)),
+      domain,
+    });
+    const message = createEd25519Message(fields);
+    const signature = signMessage(authority, message);
+    const result = createV9VerificationResult(fields, signature);
+    return { fields, message, signature, result };
+  }
+
   // * Test 1: Valid Signature (Success Path)
   it("should accept valid Ed25519 signature", async () => {
     const proofHash = new Uint8Array(32);
     crypto.getRandomValues(proofHash);
     const timestamp = Math.floor(Date.now() / 1000);
     const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
 
     // * Create message and sign it
-    const message = createEd25519Message(proofHash, isValid, timestamp);
+    const message = createEd25519Message(taggedFields({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN }));
     const signature = signMessage(authority, message);
 
     // * Create verification result
-    const verificationResult = createVerificationResult(
-      isValid,
-      proofHash,
-      timestamp,
+    const verificationResult = createV9VerificationResult(
+      taggedFields({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN }),
       signature
     );
 
@@ -131,29 +633,27 @@ describe("Ed25519 Security Tests", () => {
     });
 
     // * Create nullifier
-    const nullifier = new Uint8Array(32);
-    crypto.getRandomValues(nullifier);
-    const [nullifierPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("nullifier"), Buffer.from(nullifier)],
-      VEILED_PROGRAM_ID
-    );
+    const nullifierPda = sessionPda(nullifier);
 
     // * Call verify_auth with Ed25519 instruction as pre-instruction
     // * Convert Uint8Array to Buffer for Anchor encoding
     const txSignature = await program.methods
-      .verifyAuth(
+      .verifyAuthV1(
         Buffer.from(verificationResult),
         Array.from(nullifier),
-        "test-domain"
+        Array.from(TEST_DOMAIN)
       )
       .preInstructions([ed25519Ix])
       .accounts({
         nullifierAccount: nullifierPda,
+        proofRecord: proofRecordPda(verificationResult),
+        resultReceipt: resultReceiptPda(verificationResult),
         authority: authority.publicKey,
         instructionsSysvar: new PublicKey(
           "Sysvar1nstructions1111111111111111111111111"
         ),
         systemProgram: SystemProgram.programId,
+        domainConfig: domainConfigPda(TEST_DOMAIN),
       })
       .signers([authority])
       .rpc();
@@ -162,21 +662,21 @@ describe("Ed25519 Security Tests", () => {
     console.log("✓ Valid signature test passed:", txSignature);
   });
 
-  // * Test 2: Wrong Instruction Order
-  // * Note: This tests that Ed25519 instruction must come before program instruction
-  it("should reject when program instruction comes before Ed25519 instruction", async () => {
+  // * Test 2: Ed25519 instruction after the program instruction
+  // * Note: Some wallets append precompile instructions last; Test 1 covers the usual order
+  it("should accept an Ed25519 instruction placed after the program instruction", async () => {
     const proofHash = new Uint8Array(32);
     crypto.getRandomValues(proofHash);
     const timestamp = Math.floor(Date.now() / 1000);
     const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
 
-    const message = createEd25519Message(proofHash, isValid, timestamp);
+    const message = createEd25519Message(taggedFields({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN }));
     const signature = signMessage(authority, message);
 
-    const verificationResult = createVerificationResult(
-      isValid,
-      proofHash,
-      timestamp,
+    const verificationResult = createV9VerificationResult(
+      taggedFields({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN }),
       signature
     );
 
@@ -186,40 +686,35 @@ describe("Ed25519 Security Tests", () => {
       signature: signature,
     });
 
-    const nullifier = new Uint8Array(32);
-    crypto.getRandomValues(nullifier);
-    const [nullifierPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("nullifier"), Buffer.from(nullifier)],
-      VEILED_PROGRAM_ID
-    );
+    const nullifierPda = sessionPda(nullifier);
 
-    // * Create transaction with WRONG order: program instruction first, then Ed25519
+    // * Program instruction first, then Ed25519
     const programIx = await program.methods
-      .verifyAuth(
+      .verifyAuthV1(
         Buffer.from(verificationResult),
         Array.from(nullifier),
-        "test-domain"
+        Array.from(TEST_DOMAIN)
       )
       .accounts({
         nullifierAccount: nullifierPda,
+        proofRecord: proofRecordPda(verificationResult),
+        resultReceipt: resultReceiptPda(verificationResult),
         authority: authority.publicKey,
         instructionsSysvar: new PublicKey(
           "Sysvar1nstructions1111111111111111111111111"
         ),
         systemProgram: SystemProgram.programId,
+        domainConfig: domainConfigPda(TEST_DOMAIN),
       })
       .instruction();
 
     const tx = new Transaction().add(programIx).add(ed25519Ix);
 
-    // * Should fail because Ed25519 instruction is not before program instruction
-    try {
-      await provider.sendAndConfirm(tx, [authority]);
-      expect.fail("Transaction should have failed due to wrong instruction order");
-    } catch (error) {
-      // * Expected to fail
-      expect(error).to.exist;
-    }
+    const txSignature = await provider.sendAndConfirm(tx, [authority]);
+    expect(txSignature).to.exist;
+
+    const nullifierAccount = await program.account.nullifierAccount.fetch(nullifierPda);
+    expect(Buffer.from(nullifierAccount.nullifier)).to.deep.equal(Buffer.from(nullifier));
   });
 
   // * Test 3: Message Content Mismatch
@@ -230,16 +725,16 @@ describe("Ed25519 Security Tests", () => {
     crypto.getRandomValues(wrongProofHash);
     const timestamp = Math.floor(Date.now() / 1000);
     const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
 
     // * Sign with correct message
-    const correctMessage = createEd25519Message(proofHash, isValid, timestamp);
+    const correctMessage = createEd25519Message(taggedFields({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN }));
     const signature = signMessage(authority, correctMessage);
 
     // * But create verification result with wrong proof hash
-    const verificationResult = createVerificationResult(
-      isValid,
-      wrongProofHash, // * Wrong proof hash!
-      timestamp,
+    const verificationResult = createV9VerificationResult(
+      taggedFields({ isValid, proofHash: wrongProofHash, timestamp, nullifier, domain: TEST_DOMAIN }), // * Wrong proof hash!
       signature
     );
 
@@ -249,29 +744,27 @@ describe("Ed25519 Security Tests", () => {
       signature: signature,
     });
 
-    const nullifier = new Uint8Array(32);
-    crypto.getRandomValues(nullifier);
-    const [nullifierPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("nullifier"), Buffer.from(nullifier)],
-      VEILED_PROGRAM_ID
-    );
+    const nullifierPda = sessionPda(nullifier);
 
     // * Should fail because proof hash in verification result doesn't match message
     try {
       await program.methods
-        .verifyAuth(
+        .verifyAuthV1(
           Buffer.from(verificationResult),
           Array.from(nullifier),
-          "test-domain"
+          Array.from(TEST_DOMAIN)
         )
         .preInstructions([ed25519Ix])
         .accounts({
           nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(verificationResult),
+          resultReceipt: resultReceiptPda(verificationResult),
           authority: authority.publicKey,
           instructionsSysvar: new PublicKey(
             "Sysvar1nstructions1111111111111111111111111"
           ),
           systemProgram: SystemProgram.programId,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
         })
         .signers([authority])
         .rpc();
@@ -288,18 +781,18 @@ describe("Ed25519 Security Tests", () => {
     crypto.getRandomValues(proofHash);
     const timestamp = Math.floor(Date.now() / 1000);
     const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
 
     // * Create different authority
     const wrongAuthority = Keypair.generate();
 
-    const message = createEd25519Message(proofHash, isValid, timestamp);
+    const message = createEd25519Message(taggedFields({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN }));
     // * Sign with wrong authority
     const signature = signMessage(wrongAuthority, message);
 
-    const verificationResult = createVerificationResult(
-      isValid,
-      proofHash,
-      timestamp,
+    const verificationResult = createV9VerificationResult(
+      taggedFields({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN }),
       signature
     );
 
@@ -310,29 +803,27 @@ describe("Ed25519 Security Tests", () => {
       signature: signature,
     });
 
-    const nullifier = new Uint8Array(32);
-    crypto.getRandomValues(nullifier);
-    const [nullifierPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("nullifier"), Buffer.from(nullifier)],
-      VEILED_PROGRAM_ID
-    );
+    const nullifierPda = sessionPda(nullifier);
 
     // * Should fail because authority doesn't match
     try {
       await program.methods
-        .verifyAuth(
+        .verifyAuthV1(
           Buffer.from(verificationResult),
           Array.from(nullifier),
-          "test-domain"
+          Array.from(TEST_DOMAIN)
         )
         .preInstructions([ed25519Ix])
         .accounts({
           nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(verificationResult),
+          resultReceipt: resultReceiptPda(verificationResult),
           authority: authority.publicKey, // * Different from signer!
           instructionsSysvar: new PublicKey(
             "Sysvar1nstructions1111111111111111111111111"
           ),
           systemProgram: SystemProgram.programId,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
         })
         .signers([authority])
         .rpc();
@@ -349,16 +840,16 @@ describe("Ed25519 Security Tests", () => {
     crypto.getRandomValues(proofHash);
     const timestamp = Math.floor(Date.now() / 1000);
     const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
 
-    const message = createEd25519Message(proofHash, isValid, timestamp);
+    const message = createEd25519Message(taggedFields({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN }));
     // * Create invalid signature (random bytes)
     const invalidSignature = new Uint8Array(64);
     crypto.getRandomValues(invalidSignature);
 
-    const verificationResult = createVerificationResult(
-      isValid,
-      proofHash,
-      timestamp,
+    const verificationResult = createV9VerificationResult(
+      taggedFields({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN }),
       invalidSignature
     );
 
@@ -368,29 +859,27 @@ describe("Ed25519 Security Tests", () => {
       signature: invalidSignature, // * Invalid signature
     });
 
-    const nullifier = new Uint8Array(32);
-    crypto.getRandomValues(nullifier);
-    const [nullifierPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("nullifier"), Buffer.from(nullifier)],
-      VEILED_PROGRAM_ID
-    );
+    const nullifierPda = sessionPda(nullifier);
 
     // * Should fail because Ed25519Program will reject invalid signature
     try {
       await program.methods
-        .verifyAuth(
+        .verifyAuthV1(
           Buffer.from(verificationResult),
           Array.from(nullifier),
-          "test-domain"
+          Array.from(TEST_DOMAIN)
         )
         .preInstructions([ed25519Ix])
         .accounts({
           nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(verificationResult),
+          resultReceipt: resultReceiptPda(verificationResult),
           authority: authority.publicKey,
           instructionsSysvar: new PublicKey(
             "Sysvar1nstructions1111111111111111111111111"
           ),
           systemProgram: SystemProgram.programId,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
         })
         .signers([authority])
         .rpc();
@@ -408,14 +897,14 @@ describe("Ed25519 Security Tests", () => {
     // * Timestamp from 10 minutes ago (expired)
     const expiredTimestamp = Math.floor(Date.now() / 1000) - 10 * 60;
     const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
 
-    const message = createEd25519Message(proofHash, isValid, expiredTimestamp);
+    const message = createEd25519Message(taggedFields({ isValid, proofHash, timestamp: expiredTimestamp, nullifier, domain: TEST_DOMAIN }));
     const signature = signMessage(authority, message);
 
-    const verificationResult = createVerificationResult(
-      isValid,
-      proofHash,
-      expiredTimestamp,
+    const verificationResult = createV9VerificationResult(
+      taggedFields({ isValid, proofHash, timestamp: expiredTimestamp, nullifier, domain: TEST_DOMAIN }),
       signature
     );
 
@@ -425,29 +914,27 @@ describe("Ed25519 Security Tests", () => {
       signature: signature,
     });
 
-    const nullifier = new Uint8Array(32);
-    crypto.getRandomValues(nullifier);
-    const [nullifierPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("nullifier"), Buffer.from(nullifier)],
-      VEILED_PROGRAM_ID
-    );
+    const nullifierPda = sessionPda(nullifier);
 
     // * Should fail because timestamp is expired (>5 minutes old)
     try {
       await program.methods
-        .verifyAuth(
+        .verifyAuthV1(
           Buffer.from(verificationResult),
           Array.from(nullifier),
-          "test-domain"
+          Array.from(TEST_DOMAIN)
         )
         .preInstructions([ed25519Ix])
         .accounts({
           nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(verificationResult),
+          resultReceipt: resultReceiptPda(verificationResult),
           authority: authority.publicKey,
           instructionsSysvar: new PublicKey(
             "Sysvar1nstructions1111111111111111111111111"
           ),
           systemProgram: SystemProgram.programId,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
         })
         .signers([authority])
         .rpc();
@@ -464,14 +951,14 @@ describe("Ed25519 Security Tests", () => {
     crypto.getRandomValues(proofHash);
     const timestamp = Math.floor(Date.now() / 1000);
     const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
 
-    const message = createEd25519Message(proofHash, isValid, timestamp);
+    const message = createEd25519Message(taggedFields({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN }));
     const signature = signMessage(authority, message);
 
-    const verificationResult = createVerificationResult(
-      isValid,
-      proofHash,
-      timestamp,
+    const verificationResult = createV9VerificationResult(
+      taggedFields({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN }),
       signature
     );
 
@@ -481,28 +968,26 @@ describe("Ed25519 Security Tests", () => {
       signature: signature,
     });
 
-    const nullifier = new Uint8Array(32);
-    crypto.getRandomValues(nullifier);
-    const [nullifierPda] = PublicKey.findProgramAddressSync(
-      [Buffer.from("nullifier"), Buffer.from(nullifier)],
-      VEILED_PROGRAM_ID
-    );
+    const nullifierPda = sessionPda(nullifier);
 
     // * First use - should succeed
     const tx1 = await program.methods
-      .verifyAuth(
+      .verifyAuthV1(
         Buffer.from(verificationResult),
         Array.from(nullifier),
-        "test-domain"
+        Array.from(TEST_DOMAIN)
       )
       .preInstructions([ed25519Ix])
       .accounts({
         nullifierAccount: nullifierPda,
+        proofRecord: proofRecordPda(verificationResult),
+        resultReceipt: resultReceiptPda(verificationResult),
         authority: authority.publicKey,
         instructionsSysvar: new PublicKey(
           "Sysvar1nstructions1111111111111111111111111"
         ),
         systemProgram: SystemProgram.programId,
+        domainConfig: domainConfigPda(TEST_DOMAIN),
       })
       .signers([authority])
       .rpc();
@@ -512,12 +997,10 @@ describe("Ed25519 Security Tests", () => {
     // * Create new verification result for second attempt
     const proofHash2 = new Uint8Array(32);
     crypto.getRandomValues(proofHash2);
-    const message2 = createEd25519Message(proofHash2, isValid, timestamp);
+    const message2 = createEd25519Message(taggedFields({ isValid, proofHash: proofHash2, timestamp, nullifier, domain: TEST_DOMAIN }));
     const signature2 = signMessage(authority, message2);
-    const verificationResult2 = createVerificationResult(
-      isValid,
-      proofHash2,
-      timestamp,
+    const verificationResult2 = createV9VerificationResult(
+      taggedFields({ isValid, proofHash: proofHash2, timestamp, nullifier, domain: TEST_DOMAIN }),
       signature2
     );
     const ed25519Ix2 = Ed25519Program.createInstructionWithPublicKey({
@@ -529,19 +1012,22 @@ describe("Ed25519 Security Tests", () => {
     // * Second use with same nullifier - should fail
     try {
       await program.methods
-        .verifyAuth(
+        .verifyAuthV1(
           Buffer.from(verificationResult2),
           Array.from(nullifier), // * Same nullifier!
-          "test-domain"
+          Array.from(TEST_DOMAIN)
         )
         .preInstructions([ed25519Ix2])
         .accounts({
           nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(verificationResult2),
+          resultReceipt: resultReceiptPda(verificationResult2),
           authority: authority.publicKey,
           instructionsSysvar: new PublicKey(
             "Sysvar1nstructions1111111111111111111111111"
           ),
           systemProgram: SystemProgram.programId,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
         })
         .signers([authority])
         .rpc();
@@ -551,4 +1037,3999 @@ describe("Ed25519 Security Tests", () => {
       expect(error).to.exist;
     }
   });
+
+  // * Test 8: Nullifier Mismatch
+  it("should reject a result replayed with a different nullifier", async () => {
+    const proofHash = new Uint8Array(32);
+    crypto.getRandomValues(proofHash);
+    const timestamp = Math.floor(Date.now() / 1000);
+    const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
+
+    // * Result is signed for `nullifier`...
+    const message = createEd25519Message(taggedFields({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN }));
+    const signature = signMessage(authority, message);
+    const verificationResult = createV9VerificationResult(
+      taggedFields({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN }),
+      signature
+    );
+
+    const ed25519Ix = Ed25519Program.createInstructionWithPublicKey({
+      publicKey: authority.publicKey.toBytes(),
+      message: message,
+      signature: signature,
+    });
+
+    // * ...but submitted for `otherNullifier`
+    const otherNullifier = new Uint8Array(32);
+    crypto.getRandomValues(otherNullifier);
+    const otherNullifierPda = sessionPda(otherNullifier);
+
+    try {
+      await program.methods
+        .verifyAuthV1(
+          Buffer.from(verificationResult),
+          Array.from(otherNullifier),
+          Array.from(TEST_DOMAIN)
+        )
+        .preInstructions([ed25519Ix])
+        .accounts({
+          nullifierAccount: otherNullifierPda,
+          proofRecord: proofRecordPda(verificationResult),
+          resultReceipt: resultReceiptPda(verificationResult),
+          authority: authority.publicKey,
+          instructionsSysvar: new PublicKey(
+            "Sysvar1nstructions1111111111111111111111111"
+          ),
+          systemProgram: SystemProgram.programId,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
+        })
+        .signers([authority])
+        .rpc();
+      expect.fail("Transaction should have failed with nullifier mismatch");
+    } catch (error: any) {
+      expect(error.message).to.include("NullifierMismatch");
+    }
+  });
+
+  // * Test 9: Domain Mismatch
+  it("should reject a result replayed on a different domain", async () => {
+    const proofHash = new Uint8Array(32);
+    crypto.getRandomValues(proofHash);
+    const timestamp = Math.floor(Date.now() / 1000);
+    const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
+
+    // * Result is signed for TEST_DOMAIN...
+    const message = createEd25519Message(taggedFields({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN }));
+    const signature = signMessage(authority, message);
+    const verificationResult = createV9VerificationResult(
+      taggedFields({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN }),
+      signature
+    );
+
+    const ed25519Ix = Ed25519Program.createInstructionWithPublicKey({
+      publicKey: authority.publicKey.toBytes(),
+      message: message,
+      signature: signature,
+    });
+
+    const nullifierPda = sessionPda(nullifier, Uint8Array.from(domainToArray("other-domain")));
+
+    try {
+      // * ...but submitted for another domain
+      await program.methods
+        .verifyAuthV1(
+          Buffer.from(verificationResult),
+          Array.from(nullifier),
+          domainToArray("other-domain")
+        )
+        .preInstructions([ed25519Ix])
+        .accounts({
+          nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(verificationResult),
+          resultReceipt: resultReceiptPda(verificationResult),
+          authority: authority.publicKey,
+          instructionsSysvar: new PublicKey(
+            "Sysvar1nstructions1111111111111111111111111"
+          ),
+          systemProgram: SystemProgram.programId,
+          domainConfig: domainConfigPda(Uint8Array.from(domainToArray("other-domain"))),
+        })
+        .signers([authority])
+        .rpc();
+      expect.fail("Transaction should have failed with domain mismatch");
+    } catch (error: any) {
+      expect(error.message).to.include("DomainMismatch");
+    }
+  });
+
+  // * Test 10: Byte envelopes must be tagged; a V8 or legacy envelope is rejected even when
+  // * its signature checks out, since the verifier may have signed it for another protocol
+  it("should reject an untagged verification result envelope", async () => {
+    const { fields } = signedV9Result();
+    const untagged = { ...fields, tagged: false };
+    const message = createEd25519Message(untagged);
+    const signature = signMessage(authority, message);
+
+    for (const result of [
+      createV8VerificationResult(untagged, signature),
+      createLegacyVerificationResult(untagged, signature),
+    ]) {
+      try {
+        await program.methods
+          .verifyAuthV1(Buffer.from(result), Array.from(fields.nullifier), Array.from(TEST_DOMAIN))
+          .preInstructions([
+            Ed25519Program.createInstructionWithPublicKey({
+              publicKey: authority.publicKey.toBytes(),
+              message,
+              signature,
+            }),
+          ])
+          .accounts({
+            nullifierAccount: sessionPda(fields.nullifier, fields.domain),
+            proofRecord: proofRecordPda(result),
+            resultReceipt: resultReceiptPda(result),
+            authority: authority.publicKey,
+            domainConfig: domainConfigPda(TEST_DOMAIN),
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("UntaggedResult");
+      }
+    }
+
+    // * The same fields still verify through the typed argument
+    const nullifierPda = await submitTypedVerifyAuth(untagged, message, signature);
+    const account = await program.account.nullifierAccount.fetch(nullifierPda);
+    expect(Buffer.from(account.nullifier)).to.deep.equal(Buffer.from(fields.nullifier));
+  });
+
+  // * Test 11: Unknown result version
+  it("should reject an unknown verification result version", async () => {
+    const proofHash = new Uint8Array(32);
+    crypto.getRandomValues(proofHash);
+    const timestamp = Math.floor(Date.now() / 1000);
+    const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
+
+    const message = createEd25519Message({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN });
+    const signature = signMessage(authority, message);
+    const verificationResult = createVerificationResult(
+      { isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN },
+      signature,
+      10 // * Not a known version
+    );
+
+    const ed25519Ix = Ed25519Program.createInstructionWithPublicKey({
+      publicKey: authority.publicKey.toBytes(),
+      message: message,
+      signature: signature,
+    });
+
+    const nullifierPda = sessionPda(nullifier);
+
+    try {
+      await program.methods
+        .verifyAuthV1(
+          Buffer.from(verificationResult),
+          Array.from(nullifier),
+          Array.from(TEST_DOMAIN)
+        )
+        .preInstructions([ed25519Ix])
+        .accounts({
+          nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(verificationResult),
+          resultReceipt: resultReceiptPda(verificationResult),
+          authority: authority.publicKey,
+          instructionsSysvar: new PublicKey(
+            "Sysvar1nstructions1111111111111111111111111"
+          ),
+          systemProgram: SystemProgram.programId,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
+        })
+        .signers([authority])
+        .rpc();
+      expect.fail("Transaction should have failed with unsupported version");
+    } catch (error: any) {
+      expect(error.message).to.include("UnsupportedResultVersion");
+    }
+  });
+
+  // * Test 65: A result has one encoding; padding it would give the same result another hash
+  it("should reject a verification result with trailing bytes", async () => {
+    const { fields, message, signature, result } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+    const padded = new Uint8Array(result.length + 1);
+    padded.set(result);
+
+    try {
+      await program.methods
+        .verifyAuthV1(Buffer.from(padded), Array.from(fields.nullifier), Array.from(TEST_DOMAIN))
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+        ])
+        .accounts({
+          nullifierAccount: sessionPda(fields.nullifier, fields.domain),
+          proofRecord: proofRecordPda(padded),
+          resultReceipt: resultReceiptPda(signature),
+          authority: authority.publicKey,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
+        })
+        .signers([authority])
+        .rpc();
+      expect.fail("Transaction should have failed");
+    } catch (error: any) {
+      expect(error.message).to.include("ResultLengthMismatch");
+    }
+  });
+
+  // * Test 12: V3 results bind and persist the public inputs hash
+  it("should store the public inputs hash from a V3 result", async () => {
+    const proofHash = new Uint8Array(32);
+    crypto.getRandomValues(proofHash);
+    const timestamp = Math.floor(Date.now() / 1000);
+    const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
+    const publicInputsHash = new Uint8Array(32);
+    crypto.getRandomValues(publicInputsHash);
+
+    const fields = { isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN, publicInputsHash };
+    const message = createEd25519Message(fields);
+    const signature = signMessage(authority, message);
+    const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+
+    const account = await program.account.nullifierAccount.fetch(nullifierPda);
+    expect(Buffer.from(account.publicInputsHash)).to.deep.equal(Buffer.from(publicInputsHash));
+  });
+
+  // * Test 13: V4 results bind and persist the circuit ID
+  it("should store the circuit ID from a V4 result", async () => {
+    const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+
+    const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+
+    const account = await program.account.nullifierAccount.fetch(nullifierPda);
+    expect(account.circuitId).to.equal(AUTH_V4_CIRCUIT_ID);
+  });
+
+  // * Test 14: Results for circuits missing from the registry are rejected
+  it("should reject a result for an unregistered circuit", async () => {
+    const { fields, message, signature } = signedV4Result(UNREGISTERED_CIRCUIT_ID);
+
+    try {
+      await submitTypedVerifyAuth(fields, message, signature);
+      expect.fail("Transaction should have failed");
+    } catch (error: any) {
+      expect(error.message).to.include("CircuitNotRegistered");
+    }
+  });
+
+  // * Test 15: Results for deprecated circuits are rejected
+  it("should reject a result for a deprecated circuit", async () => {
+    const { fields, message, signature } = signedV4Result(DEPRECATED_CIRCUIT_ID);
+
+    try {
+      await submitTypedVerifyAuth(fields, message, signature);
+      expect.fail("Transaction should have failed");
+    } catch (error: any) {
+      expect(error.message).to.include("CircuitDeprecated");
+    }
+  });
+
+  // * Test 16: The reserved sandbox domain is only enabled by the `devnet` build feature
+  it("should reject the sandbox domain on non-devnet builds", async () => {
+    const sandboxDomain = Uint8Array.from(domainToArray("sandbox"));
+    const nullifier = crypto.getRandomValues(new Uint8Array(32));
+    const fields = {
+      isValid: true,
+      proofHash: crypto.getRandomValues(new Uint8Array(32)),
+      timestamp: Math.floor(Date.now() / 1000),
+      nullifier,
+      domain: sandboxDomain,
+    };
+    const verificationResult = createV9VerificationResult(taggedFields(fields), new Uint8Array(64));
+
+    const nullifierPda = sessionPda(nullifier, sandboxDomain);
+
+    try {
+      await program.methods
+        .verifyAuthV1(Buffer.from(verificationResult), Array.from(nullifier), Array.from(sandboxDomain))
+        .accounts({
+          nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(verificationResult),
+          resultReceipt: resultReceiptPda(verificationResult),
+          authority: authority.publicKey,
+          domainConfig: domainConfigPda(sandboxDomain),
+        })
+        .signers([authority])
+        .rpc();
+      expect.fail("Transaction should have failed");
+    } catch (error: any) {
+      expect(error.message).to.include("SandboxUnavailable");
+    }
+  });
+
+  // * Test 19: A correctly signed result from a key outside the VerifierRegistry is rejected
+  it("should reject a result signed by an unregistered verifier", async () => {
+    const rogueVerifier = Keypair.generate();
+    const airdropSig = await provider.connection.requestAirdrop(
+      rogueVerifier.publicKey,
+      anchor.web3.LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropSig);
+
+    const { fields, message } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+    const signature = signMessage(rogueVerifier, message);
+
+    try {
+      await submitTypedVerifyAuth(fields, message, signature, 0, rogueVerifier);
+      expect.fail("Transaction should have failed");
+    } catch (error: any) {
+      expect(error.message).to.include("UnregisteredVerifier");
+    }
+  });
+
+  // * Tests 17-18 run against a fresh domain that pins circuit 1's VK hash
+  describe("pinned verification key", () => {
+    const pinnedDomain = Uint8Array.from(
+      domainToArray(`pinned-${Math.random().toString(36).slice(2, 10)}`)
+    );
+
+    before(async () => {
+      await program.methods
+        .initializeDomainConfig(Array.from(pinnedDomain))
+        .accounts({ domainConfig: domainConfigPda(pinnedDomain), owner: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .pinVkHash(Array.from(registeredVkHash(AUTH_V4_CIRCUIT_ID)))
+        .accounts({
+          domainConfig: domainConfigPda(pinnedDomain),
+          owner: provider.wallet.publicKey,
+        })
+        .rpc();
+    });
+
+    // * Test 17: A pinned domain rejects results that don't carry the VK hash
+    it("should reject a V4 result for a domain that pins a VK hash", async () => {
+      const { fields, message, signature } = signedV4Result(
+        AUTH_V4_CIRCUIT_ID,
+        pinnedDomain
+      );
+
+      try {
+        await submitTypedVerifyAuth(fields, message, signature);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("VkHashRequired");
+      }
+    });
+
+    // * Test 18: A V5 result carrying the pinned VK hash verifies
+    it("should accept a V5 result carrying the pinned VK hash", async () => {
+      const { fields, message, signature } = signedV4Result(
+        AUTH_V4_CIRCUIT_ID,
+        pinnedDomain,
+        registeredVkHash(AUTH_V4_CIRCUIT_ID)
+      );
+
+      const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+
+      const account = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(account.circuitId).to.equal(AUTH_V4_CIRCUIT_ID);
+    });
+  });
+
+  // * Tests 20-21 run against a fresh domain that designates its own verifier key
+  describe("domain verifiers", () => {
+    const verifierDomain = Uint8Array.from(
+      domainToArray(`verifier-${Math.random().toString(36).slice(2, 10)}`)
+    );
+    const domainVerifier = Keypair.generate();
+
+    before(async () => {
+      await program.methods
+        .initializeDomainConfig(Array.from(verifierDomain))
+        .accounts({
+          domainConfig: domainConfigPda(verifierDomain),
+          owner: provider.wallet.publicKey,
+        })
+        .rpc();
+      await program.methods
+        .setDomainVerifiers([domainVerifier.publicKey])
+        .accounts({
+          domainConfig: domainConfigPda(verifierDomain),
+          authority: provider.wallet.publicKey,
+        })
+        .rpc();
+    });
+
+    // * Helper: Submit a result signed by `verifier` from the provider wallet
+    async function submitFromWallet(verifier: Keypair): Promise<PublicKey> {
+      const { fields, message } = signedV9Result(verifierDomain);
+      const signature = signMessage(verifier, message);
+      const result = createV9VerificationResult(fields, signature);
+      const nullifierPda = sessionPda(fields.nullifier, fields.domain);
+
+      await program.methods
+        .verifyAuthV1(
+          Buffer.from(result),
+          Array.from(fields.nullifier),
+          Array.from(verifierDomain)
+        )
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: verifier.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+        ])
+        .accounts({
+          nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(result),
+          resultReceipt: resultReceiptPda(result),
+          authority: provider.wallet.publicKey,
+          domainConfig: domainConfigPda(verifierDomain),
+        })
+        .rpc();
+
+      return nullifierPda;
+    }
+
+    // * Test 20: The domain's verifier signs; an unregistered wallet submits and pays
+    it("should accept a result signed by the domain's verifier from any submitter", async () => {
+      const nullifierPda = await submitFromWallet(domainVerifier);
+
+      const account = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(account.authority.toBase58()).to.equal(provider.wallet.publicKey.toBase58());
+    });
+
+    // * Test 21: Globally registered verifiers can't sign for a domain that designates its own
+    it("should reject a result signed by a global verifier for the domain", async () => {
+      try {
+        await submitFromWallet(authority);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("AuthorityMismatch");
+      }
+    });
+  });
+
+  // * Tests 22-23 raise the registry threshold to 2-of-n for their duration
+  describe("verifier quorum", () => {
+    const coSigner = Keypair.generate();
+
+    before(async () => {
+      await program.methods
+        .addVerifier(coSigner.publicKey)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .setVerifierThreshold(2)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .setVerifierThreshold(1)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .removeVerifier(coSigner.publicKey)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    // * Helper: Submit a fresh result signed by `authority` plus one Ed25519 ix per co-signer
+    async function submitCoSigned(coSigners: Keypair[]): Promise<PublicKey> {
+      const { fields, message, signature, result } = signedV9Result();
+      const nullifierPda = sessionPda(fields.nullifier, fields.domain);
+
+      await program.methods
+        .verifyAuthV1(Buffer.from(result), Array.from(fields.nullifier), Array.from(TEST_DOMAIN))
+        .preInstructions(
+          [authority, ...coSigners].map((signer) =>
+            Ed25519Program.createInstructionWithPublicKey({
+              publicKey: signer.publicKey.toBytes(),
+              message,
+              signature: signer === authority ? signature : signMessage(signer, message),
+            })
+          )
+        )
+        .accounts({
+          nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(result),
+          resultReceipt: resultReceiptPda(result),
+          authority: authority.publicKey,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
+        })
+        .signers([authority])
+        .rpc();
+
+      return nullifierPda;
+    }
+
+    // * Test 22: One signature no longer satisfies a 2-of-n registry
+    it("should reject a result signed by fewer verifiers than the threshold", async () => {
+      try {
+        await submitCoSigned([]);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("QuorumNotMet");
+      }
+    });
+
+    // * Test 23: A second registered verifier's signature over the same message meets quorum
+    it("should accept a result co-signed by a quorum of registered verifiers", async () => {
+      const nullifierPda = await submitCoSigned([coSigner]);
+
+      const account = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(account.circuitId).to.equal(AUTH_V4_CIRCUIT_ID);
+    });
+  });
+
+  // * Tests 24-25 rotate a registered verifier key and exercise the grace period
+  describe("verifier key rotation", () => {
+    const retiredKey = Keypair.generate();
+    const successorKey = Keypair.generate();
+
+    before(async () => {
+      await program.methods
+        .addVerifier(retiredKey.publicKey)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .setVerifierGracePeriod(new anchor.BN(600))
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .rotateVerifierKey(retiredKey.publicKey, successorKey.publicKey)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .setVerifierGracePeriod(new anchor.BN(0))
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .removeVerifier(successorKey.publicKey)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    // * Helper: Submit a fresh result signed by `verifier` from the registered authority
+    async function submitSignedBy(verifier: Keypair): Promise<PublicKey> {
+      const { fields, message } = signedV9Result();
+      const signature = signMessage(verifier, message);
+      const result = createV9VerificationResult(fields, signature);
+      const nullifierPda = sessionPda(fields.nullifier, fields.domain);
+
+      await program.methods
+        .verifyAuthV1(
+          Buffer.from(result),
+          Array.from(fields.nullifier),
+          Array.from(TEST_DOMAIN)
+        )
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: verifier.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+        ])
+        .accounts({
+          nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(result),
+          resultReceipt: resultReceiptPda(result),
+          authority: authority.publicKey,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
+        })
+        .signers([authority])
+        .rpc();
+
+      return nullifierPda;
+    }
+
+    // * Test 24: A result signed just before the rotation still lands during the grace period
+    it("should accept a result signed by a rotated-out key within the grace period", async () => {
+      const nullifierPda = await submitSignedBy(retiredKey);
+
+      const account = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(account.circuitId).to.equal(AUTH_V4_CIRCUIT_ID);
+    });
+
+    // * Test 25: Ending the grace period retires the old key; its replacement keeps working
+    it("should reject the rotated-out key once the grace period ends", async () => {
+      await program.methods
+        .setVerifierGracePeriod(new anchor.BN(0))
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+
+      try {
+        await submitSignedBy(retiredKey);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("AuthorityMismatch");
+      }
+
+      const nullifierPda = await submitSignedBy(successorKey);
+      const account = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(account.circuitId).to.equal(AUTH_V4_CIRCUIT_ID);
+    });
+  });
+
+  // * Tests 26-28 run against a fresh domain with a one-hour challenge window
+  describe("optimistic verification", () => {
+    const optimisticDomain = Uint8Array.from(
+      domainToArray(`optimistic-${Math.random().toString(36).slice(2, 10)}`)
+    );
+    let pending: ReturnType<typeof signedV4Result>;
+    let nullifierPda: PublicKey;
+
+    before(async () => {
+      await program.methods
+        .initializeDomainConfig(Array.from(optimisticDomain))
+        .accounts({
+          domainConfig: domainConfigPda(optimisticDomain),
+          owner: provider.wallet.publicKey,
+        })
+        .rpc();
+      await program.methods
+        .setChallengeWindow(new anchor.BN(3600))
+        .accounts({
+          domainConfig: domainConfigPda(optimisticDomain),
+          owner: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      pending = signedV4Result(AUTH_V4_CIRCUIT_ID, optimisticDomain);
+      nullifierPda = await submitTypedVerifyAuth(pending.fields, pending.message, pending.signature);
+    });
+
+    // * Helper: Challenge the pending session with a result signed by `authority`
+    async function challenge(isValid: boolean): Promise<void> {
+      const fields = taggedFields({ ...pending.fields, isValid });
+      const message = createEd25519Message(fields);
+      const signature = signMessage(authority, message);
+
+      await program.methods
+        .challengeVerification(
+          Buffer.from(createV9VerificationResult(fields, signature)),
+          Array.from(optimisticDomain)
+        )
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+        ])
+        .accounts({
+          nullifierAccount: nullifierPda,
+          challenger: provider.wallet.publicKey,
+          domainConfig: domainConfigPda(optimisticDomain),
+        })
+        .rpc();
+    }
+
+    // * Test 26: The session exists but can't be used until the window passes
+    it("should hold a new session pending during the challenge window", async () => {
+      const account = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(account.finalizesAt.toNumber()).to.equal(account.createdAt.toNumber() + 3600);
+
+      try {
+        await program.methods
+          .validateSessionToken()
+          .accountsPartial({
+            nullifierAccount: nullifierPda,
+            logoutEpoch: PublicKey.findProgramAddressSync(
+              [Buffer.from("logout_epoch"), authority.publicKey.toBuffer()],
+              VEILED_PROGRAM_ID
+            )[0],
+          })
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("SessionPending");
+      }
+    });
+
+    // * Test 27: A result that still says "valid" is no evidence against the proof
+    it("should reject a challenge that doesn't dispute the proof", async () => {
+      try {
+        await challenge(true);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidChallenge");
+      }
+    });
+
+    // * Test 28: A verifier marking the same proof invalid voids the session
+    it("should void a pending session with a verifier-signed invalid result", async () => {
+      await challenge(false);
+
+      const account = await provider.connection.getAccountInfo(nullifierPda);
+      expect(account).to.be.null;
+    });
+  });
+
+  // * Tests 29-30 submit from a fresh relayer so earlier tests don't count against its window
+  describe("payer rate limit", () => {
+    const limitedDomain = Uint8Array.from(
+      domainToArray(`limited-${Math.random().toString(36).slice(2, 10)}`)
+    );
+    const relayer = Keypair.generate();
+
+    before(async () => {
+      const airdropSig = await provider.connection.requestAirdrop(
+        relayer.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropSig);
+
+      // * The domain designates `authority`, so the relayer may submit without being a verifier
+      const domainConfig = domainConfigPda(limitedDomain);
+      await program.methods
+        .initializeDomainConfig(Array.from(limitedDomain))
+        .accounts({
+          domainConfig: domainConfigPda(limitedDomain),
+          owner: provider.wallet.publicKey,
+        })
+        .rpc();
+      await program.methods
+        .setDomainVerifiers([authority.publicKey])
+        .accounts({ domainConfig, authority: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .setDomainRateLimit(2)
+        .accounts({ domainConfig, owner: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    // * Helper: Submit a fresh authority-signed result, paid for by the relayer
+    async function relay(): Promise<void> {
+      const { fields, message, signature, result } = signedV9Result(limitedDomain);
+
+      await program.methods
+        .verifyAuthV1(Buffer.from(result), Array.from(fields.nullifier), Array.from(limitedDomain))
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+        ])
+        .accounts({
+          nullifierAccount: sessionPda(fields.nullifier, fields.domain),
+          proofRecord: proofRecordPda(result),
+          resultReceipt: resultReceiptPda(result),
+          authority: relayer.publicKey,
+          domainConfig: domainConfigPda(limitedDomain),
+        })
+        .signers([relayer])
+        .rpc();
+    }
+
+    // * Test 29: Two verifications fit the domain's limit; the third within the minute doesn't
+    it("should reject a payer past the domain's rate limit", async () => {
+      await relay();
+      await relay();
+
+      try {
+        await relay();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("RateLimited");
+      }
+    });
+
+    // * Test 30: An admin-set quota exempts the relayer from the domain's limit
+    it("should let an allowlisted relayer submit up to its own quota", async () => {
+      await program.methods
+        .setRelayerQuota(relayer.publicKey, 10)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+
+      await relay();
+
+      const counter = await program.account.payerRateLimit.fetch(
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("rate_limit"), relayer.publicKey.toBuffer()],
+          VEILED_PROGRAM_ID
+        )[0]
+      );
+      expect(counter.quota).to.equal(10);
+    });
+  });
+
+  // * Tests 31-32 register a passkey verifier and raise the threshold to 2 (restored after)
+  describe("passkey verifiers", () => {
+    const passkey = generatePasskey();
+
+    before(async () => {
+      await program.methods
+        .addSecp256r1Verifier(Array.from(passkey.publicKey))
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .setVerifierThreshold(2)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .setVerifierThreshold(1)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .removeSecp256r1Verifier(Array.from(passkey.publicKey))
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    // * Helper: Submit a fresh authority-signed result co-signed by `signer` through the precompile
+    async function submitWithPasskey(signer: ReturnType<typeof generatePasskey>): Promise<PublicKey> {
+      const { fields, message, signature, result } = signedV9Result();
+      const nullifierPda = sessionPda(fields.nullifier, fields.domain);
+
+      await program.methods
+        .verifyAuthV1(Buffer.from(result), Array.from(fields.nullifier), Array.from(TEST_DOMAIN))
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+          createSecp256r1Instruction(
+            signer.publicKey,
+            message,
+            signPasskey(signer.privateKey, message)
+          ),
+        ])
+        .accounts({
+          nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(result),
+          resultReceipt: resultReceiptPda(result),
+          authority: authority.publicKey,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
+        })
+        .signers([authority])
+        .rpc();
+
+      return nullifierPda;
+    }
+
+    // * Test 31: The passkey's signature is the second of a 2-of-n quorum
+    it("should accept a result co-signed by a registered passkey", async () => {
+      const nullifierPda = await submitWithPasskey(passkey);
+
+      const account = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(account.circuitId).to.equal(AUTH_V4_CIRCUIT_ID);
+    });
+
+    // * Test 32: A valid P-256 signature from a key that isn't registered
+    it("should reject a secp256r1 signature from an unregistered passkey", async () => {
+      try {
+        await submitWithPasskey(generatePasskey());
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("AuthorityMismatch");
+      }
+    });
+  });
+
+  // * Tests 33-34 put a domain on a 60-second high-assurance tier; Test 36 gives another its
+  // * own 30-minute window
+  describe("assurance tiers", () => {
+    const strictDomain = Uint8Array.from(domainToArray("strict-domain"));
+    const offlineDomain = Uint8Array.from(domainToArray("offline-domain"));
+    const HIGH_ASSURANCE_TIER = 1;
+
+    before(async () => {
+      // * Tier 0 keeps the default window; tier 1 is the high-assurance one
+      await program.methods
+        .setAssuranceTier(0, 300)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .setAssuranceTier(HIGH_ASSURANCE_TIER, 60)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+
+      const domainConfig = domainConfigPda(strictDomain);
+      await program.methods
+        .initializeDomainConfig(Array.from(strictDomain))
+        .accounts({ domainConfig: domainConfigPda(strictDomain), owner: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .setDomainVerifiers([authority.publicKey])
+        .accounts({ domainConfig, authority: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .setDomainAssuranceTier(HIGH_ASSURANCE_TIER)
+        .accounts({ domainConfig, owner: provider.wallet.publicKey })
+        .rpc();
+
+      const offlineConfig = domainConfigPda(offlineDomain);
+      await program.methods
+        .initializeDomainConfig(Array.from(offlineDomain))
+        .accounts({
+          domainConfig: domainConfigPda(offlineDomain),
+          owner: provider.wallet.publicKey,
+        })
+        .rpc();
+      await program.methods
+        .setDomainVerifiers([authority.publicKey])
+        .accounts({ domainConfig: offlineConfig, authority: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .setDomainMaxResultAge(30 * 60)
+        .accounts({ domainConfig: offlineConfig, owner: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    // * Helper: Submit an authority-signed result issued `ageSecs` ago
+    async function submitAged(domain: Uint8Array, ageSecs: number): Promise<void> {
+      const { fields, message, signature, result } = signedV9Result(domain, ageSecs);
+
+      await program.methods
+        .verifyAuthV1(Buffer.from(result), Array.from(fields.nullifier), Array.from(domain))
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+        ])
+        .accounts({
+          nullifierAccount: sessionPda(fields.nullifier, fields.domain),
+          proofRecord: proofRecordPda(result),
+          resultReceipt: resultReceiptPda(result),
+          authority: authority.publicKey,
+          domainConfig: domainConfigPda(domain),
+        })
+        .signers([authority])
+        .rpc();
+    }
+
+    // * Test 33: Two minutes is past the high-assurance tier's 60 seconds
+    it("should reject a result older than the domain's tier window", async () => {
+      try {
+        await submitAged(strictDomain, 120);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("ProofExpired");
+      }
+    });
+
+    // * Test 34: The same age is fine under the default tier's 5 minutes
+    it("should accept the same result age on a default-tier domain", async () => {
+      await submitAged(TEST_DOMAIN, 120);
+    });
+
+    // * Test 36: Ten minutes is past the default tier but inside the domain's own window
+    it("should apply a domain's own staleness window", async () => {
+      await submitAged(offlineDomain, 10 * 60);
+    });
+  });
+
+  // * Test 35: A result signed an hour ahead would otherwise stay fresh for an hour longer
+  it("should reject a result timestamped in the future", async () => {
+    const { fields, message, signature, result } = signedV9Result(TEST_DOMAIN, -3600);
+
+    try {
+      await program.methods
+        .verifyAuthV1(Buffer.from(result), Array.from(fields.nullifier), Array.from(TEST_DOMAIN))
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+        ])
+        .accounts({
+          nullifierAccount: sessionPda(fields.nullifier, fields.domain),
+          proofRecord: proofRecordPda(result),
+          resultReceipt: resultReceiptPda(result),
+          authority: authority.publicKey,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
+        })
+        .signers([authority])
+        .rpc();
+      expect.fail("Transaction should have failed");
+    } catch (error: any) {
+      expect(error.message).to.include("TimestampInFuture");
+    }
+  });
+
+  // * Tests 37-39 delegate a domain's operator role to a separate key
+  describe("domain roles", () => {
+    const rolesDomain = Uint8Array.from(domainToArray("roles-domain"));
+    const operator = Keypair.generate();
+    const domainConfig = domainConfigPda(rolesDomain);
+
+    before(async () => {
+      await program.methods
+        .initializeDomainConfig(Array.from(rolesDomain))
+        .accounts({ domainConfig: domainConfigPda(rolesDomain), owner: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .setDomainRole({ operator: {} }, operator.publicKey)
+        .accounts({ domainConfig, owner: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    // * Helper: Submit a fresh authority-signed result for the domain
+    async function submit(): Promise<void> {
+      const { fields, message, signature, result } = signedV9Result(rolesDomain);
+
+      await program.methods
+        .verifyAuthV1(Buffer.from(result), Array.from(fields.nullifier), Array.from(rolesDomain))
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+        ])
+        .accounts({
+          nullifierAccount: sessionPda(fields.nullifier, fields.domain),
+          proofRecord: proofRecordPda(result),
+          resultReceipt: resultReceiptPda(result),
+          authority: authority.publicKey,
+          domainConfig: domainConfigPda(rolesDomain),
+        })
+        .signers([authority])
+        .rpc();
+    }
+
+    // * Test 37: Verifier rotation is delegated, rate limits stay with the owner
+    it("should let the operator rotate verifiers but not change owner settings", async () => {
+      await program.methods
+        .setDomainVerifiers([authority.publicKey])
+        .accounts({ domainConfig, authority: operator.publicKey })
+        .signers([operator])
+        .rpc();
+
+      const config = await program.account.domainConfig.fetch(domainConfig);
+      expect(config.verifiers.map((key: PublicKey) => key.toBase58())).to.deep.equal([
+        authority.publicKey.toBase58(),
+      ]);
+
+      try {
+        await program.methods
+          .setDomainRateLimit(1)
+          .accounts({ domainConfig, owner: operator.publicKey })
+          .signers([operator])
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("NotDomainOwner");
+      }
+    });
+
+    // * Test 38: Pausing stops verify_auth for the domain
+    it("should reject verifications for a paused domain", async () => {
+      await program.methods
+        .setDomainPaused(true)
+        .accounts({ domainConfig, authority: operator.publicKey })
+        .signers([operator])
+        .rpc();
+
+      try {
+        await submit();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("DomainPaused");
+      }
+    });
+
+    // * Test 39: Resuming restores verifications; clearing the role revokes the operator
+    it("should resume the domain and honor a cleared operator", async () => {
+      await program.methods
+        .setDomainPaused(false)
+        .accounts({ domainConfig, authority: operator.publicKey })
+        .signers([operator])
+        .rpc();
+      await submit();
+
+      await program.methods
+        .setDomainRole({ operator: {} }, PublicKey.default)
+        .accounts({ domainConfig, owner: provider.wallet.publicKey })
+        .rpc();
+
+      try {
+        await program.methods
+          .setDomainPaused(true)
+          .accounts({ domainConfig, authority: operator.publicKey })
+          .signers([operator])
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("NotDomainOperator");
+      }
+    });
+  });
+
+  // * Tests 40-41 bind V6 results to a cluster
+  describe("cluster binding", () => {
+    // * Helper: Sign a fresh V6 result for `clusterId`
+    function signedV6Result(clusterId: Uint8Array) {
+      const { fields } = signedV4Result(
+        AUTH_V4_CIRCUIT_ID,
+        TEST_DOMAIN,
+        registeredVkHash(AUTH_V4_CIRCUIT_ID)
+      );
+      const clusterFields = { ...fields, clusterId };
+      const message = createEd25519Message(clusterFields);
+      const signature = signMessage(authority, message);
+      const result = createV6VerificationResult(clusterFields, signature);
+      return { fields: clusterFields, message, signature, result };
+    }
+
+    // * Test 40: Test validator builds aren't devnet builds, so they bind to mainnet-beta
+    it("should accept a V6 result bound to this build's cluster", async () => {
+      const { fields, message, signature } = signedV6Result(MAINNET_BETA_CLUSTER_ID);
+
+      const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+
+      const account = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(account.circuitId).to.equal(AUTH_V4_CIRCUIT_ID);
+    });
+
+    // * Test 41: A devnet result can't be replayed against the same program ID elsewhere
+    it("should reject a V6 result signed for another cluster", async () => {
+      const { fields, message, signature } = signedV6Result(DEVNET_CLUSTER_ID);
+
+      try {
+        await submitTypedVerifyAuth(fields, message, signature);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("ClusterMismatch");
+      }
+    });
+  });
+
+  // * Tests 58-59 bind V7 results to a deployment (white-label instances share verifier keys)
+  describe("instance binding", () => {
+    // * Helper: Sign a fresh V7 result for `instanceId`
+    function signedV7Result(instanceId: Uint8Array) {
+      const { fields } = signedV4Result(
+        AUTH_V4_CIRCUIT_ID,
+        TEST_DOMAIN,
+        registeredVkHash(AUTH_V4_CIRCUIT_ID)
+      );
+      const instanceFields = { ...fields, clusterId: MAINNET_BETA_CLUSTER_ID, instanceId };
+      const message = createEd25519Message(instanceFields);
+      const signature = signMessage(authority, message);
+      const result = createV7VerificationResult(instanceFields, signature);
+      return { fields: instanceFields, message, signature, result };
+    }
+
+    // * Test 58: The instance ID is the program ID the result is submitted to
+    it("should accept a V7 result bound to this program's instance", async () => {
+      const { fields, message, signature } = signedV7Result(program.programId.toBytes());
+
+      const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+
+      const account = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(account.circuitId).to.equal(AUTH_V4_CIRCUIT_ID);
+    });
+
+    // * Test 59: A result for another deployment trusting the same verifier can't be replayed
+    it("should reject a V7 result signed for another instance", async () => {
+      const otherInstance = Keypair.generate().publicKey.toBytes();
+      const { fields, message, signature } = signedV7Result(otherInstance);
+
+      try {
+        await submitTypedVerifyAuth(fields, message, signature);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("InstanceMismatch");
+      }
+    });
+  });
+
+  // * Tests 68-79 route V8 results by claim type; logins open sessions, other claims are attested
+  describe("claim types", () => {
+    // * Helper: Sign a fresh V8 result with `claimType` (for `nullifier` when given)
+    function signedV8Result(claimType: number, nullifier?: Uint8Array, domain = TEST_DOMAIN) {
+      const { fields } = signedV4Result(
+        AUTH_V4_CIRCUIT_ID,
+        domain,
+        registeredVkHash(AUTH_V4_CIRCUIT_ID)
+      );
+      const claimFields = {
+        ...fields,
+        nullifier: nullifier ?? fields.nullifier,
+        clusterId: MAINNET_BETA_CLUSTER_ID,
+        instanceId: program.programId.toBytes(),
+        claimType,
+      };
+      const message = createEd25519Message(claimFields);
+      const signature = signMessage(authority, message);
+      const result = createV8VerificationResult(claimFields, signature);
+      return { fields: claimFields, message, signature, result };
+    }
+
+    // * Test 68: An Auth claim is a login like any earlier result
+    it("should accept a V8 Auth claim as a login", async () => {
+      const { fields, message, signature } = signedV8Result(0);
+
+      const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+
+      const account = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(account.circuitId).to.equal(AUTH_V4_CIRCUIT_ID);
+    });
+
+    // * Test 69: A signed age claim can't be submitted as a login, typed or as bytes
+    it("should reject a V8 AgeOver claim as a login", async () => {
+      const { fields, message, signature } = signedV8Result(2);
+
+      try {
+        await submitTypedVerifyAuth(fields, message, signature);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("ClaimNotSession");
+      }
+
+      // * As bytes: the same claim in a tagged V9 envelope
+      const tagged = { ...fields, tagged: true };
+      const taggedMessage = createEd25519Message(tagged);
+      const taggedSignature = signMessage(authority, taggedMessage);
+      const result = createV9VerificationResult(tagged, taggedSignature);
+      try {
+        await program.methods
+          .verifyAuthV1(Buffer.from(result), Array.from(fields.nullifier), Array.from(TEST_DOMAIN))
+          .preInstructions([
+            Ed25519Program.createInstructionWithPublicKey({
+              publicKey: authority.publicKey.toBytes(),
+              message: taggedMessage,
+              signature: taggedSignature,
+            }),
+          ])
+          .accounts({
+            nullifierAccount: sessionPda(fields.nullifier, fields.domain),
+            proofRecord: proofRecordPda(result),
+            resultReceipt: resultReceiptPda(result),
+            authority: authority.publicKey,
+            domainConfig: domainConfigPda(TEST_DOMAIN),
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("ClaimNotSession");
+      }
+    });
+
+    // * Helper: Submit store_attestation for a signed V8 result
+    async function submitStoreAttestation(
+      fields: VerificationFields,
+      message: Uint8Array,
+      signature: Uint8Array,
+      attestor: PublicKey | null = null
+    ): Promise<PublicKey> {
+      const [attestationPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("attestation"),
+          Buffer.from(fields.nullifier),
+          Buffer.from([fields.claimType ?? 0]),
+          Buffer.from(fields.domain),
+        ],
+        VEILED_PROGRAM_ID
+      );
+
+      await program.methods
+        .storeAttestation(
+          createSignedResultArg(fields, signature),
+          Array.from(fields.nullifier),
+          Array.from(fields.domain),
+          new anchor.BN(0)
+        )
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+        ])
+        .accounts({
+          attestation: attestationPda,
+          resultReceipt: resultReceiptPda(signature),
+          authority: authority.publicKey,
+          attestor,
+          domainConfig: domainConfigPda(fields.domain),
+        })
+        .signers([authority])
+        .rpc();
+
+      return attestationPda;
+    }
+
+    // * Test 70: An age claim is kept as an attestation, and its result is spent
+    it("should store a V8 AgeOver claim as an attestation", async () => {
+      const { fields, message, signature } = signedV8Result(2);
+
+      const attestationPda = await submitStoreAttestation(fields, message, signature);
+
+      const attestation = await program.account.attestation.fetch(attestationPda);
+      expect(attestation.claimType).to.deep.equal({ ageOver: {} });
+      expect(Buffer.from(attestation.claimDataHash)).to.deep.equal(
+        Buffer.from(fields.publicInputsHash!)
+      );
+      expect(attestation.issuer.toString()).to.equal(authority.publicKey.toString());
+      expect(attestation.expiresAt.sub(attestation.issuedAt).toNumber()).to.equal(
+        365 * 24 * 60 * 60
+      );
+
+      try {
+        await submitStoreAttestation(fields, message, signature);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("AttestationActive");
+      }
+    });
+
+    // * Test 71: Logins go through verify_auth, so an Auth claim can't be stored here
+    it("should reject a V8 Auth claim as an attestation", async () => {
+      const { fields, message, signature } = signedV8Result(0);
+
+      try {
+        await submitStoreAttestation(fields, message, signature);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("ClaimNotAttestation");
+      }
+    });
+
+    // * Helper: Submit renew_attestation for a signed V8 result
+    async function submitRenewAttestation(
+      attestation: PublicKey,
+      fields: VerificationFields,
+      message: Uint8Array,
+      signature: Uint8Array,
+      expiresIn: number
+    ): Promise<void> {
+      await program.methods
+        .renewAttestation(createSignedResultArg(fields, signature), new anchor.BN(expiresIn))
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+        ])
+        .accounts({
+          attestation,
+          resultReceipt: resultReceiptPda(signature),
+          authority: authority.publicKey,
+          domainConfig: domainConfigPda(fields.domain),
+        })
+        .signers([authority])
+        .rpc();
+    }
+
+    // * Test 72: A fresh result refreshes the claim in place, and never shortens it
+    it("should renew an attestation from a fresh result for the same claim", async () => {
+      const stored = signedV8Result(1);
+      const attestationPda = await submitStoreAttestation(
+        stored.fields,
+        stored.message,
+        stored.signature
+      );
+      const before = await program.account.attestation.fetch(attestationPda);
+
+      const fresh = signedV8Result(1, stored.fields.nullifier);
+      await submitRenewAttestation(attestationPda, fresh.fields, fresh.message, fresh.signature, 60);
+
+      const after = await program.account.attestation.fetch(attestationPda);
+      expect(Buffer.from(after.claimDataHash)).to.deep.equal(
+        Buffer.from(fresh.fields.publicInputsHash!)
+      );
+      expect(after.expiresAt.toNumber()).to.equal(before.expiresAt.toNumber());
+
+      await program.methods.validateAttestation().accounts({ attestation: attestationPda }).rpc();
+    });
+
+    // * Test 73: A balance attestation can't be renewed with a signed NFT claim
+    it("should reject renewing an attestation with another claim type", async () => {
+      const stored = signedV8Result(1);
+      const attestationPda = await submitStoreAttestation(
+        stored.fields,
+        stored.message,
+        stored.signature
+      );
+
+      const other = signedV8Result(3, stored.fields.nullifier);
+      try {
+        await submitRenewAttestation(attestationPda, other.fields, other.message, other.signature, 0);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("AttestationClaimMismatch");
+      }
+    });
+
+    // * Test 74: A compromised record is withdrawn by the verifier that issued it
+    it("should let the issuer revoke an attestation", async () => {
+      const { fields, message, signature } = signedV8Result(1);
+      const attestationPda = await submitStoreAttestation(fields, message, signature);
+
+      await program.methods
+        .revokeAttestation()
+        .accounts({ attestation: attestationPda, issuer: authority.publicKey })
+        .signers([authority])
+        .rpc();
+
+      const attestation = await program.account.attestation.fetch(attestationPda);
+      expect(attestation.revokedAt.toNumber()).to.be.greaterThan(0);
+
+      try {
+        await program.methods.validateAttestation().accounts({ attestation: attestationPda }).rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("AttestationRevoked");
+      }
+    });
+
+    // * Test 75: Only the issuer's key can revoke what it attested
+    it("should reject revocation by anyone but the issuer", async () => {
+      const { fields, message, signature } = signedV8Result(1);
+      const attestationPda = await submitStoreAttestation(fields, message, signature);
+      const stranger = Keypair.generate();
+
+      try {
+        await program.methods
+          .revokeAttestation()
+          .accounts({ attestation: attestationPda, issuer: stranger.publicKey })
+          .signers([stranger])
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("NotAttestationIssuer");
+      }
+    });
+
+    describe("registered attestors", () => {
+      const kycDomain = Uint8Array.from(domainToArray("kyc-domain"));
+      const domainConfig = domainConfigPda(kycDomain);
+      const attestorPda = PublicKey.findProgramAddressSync(
+        [Buffer.from("attestor"), authority.publicKey.toBuffer()],
+        VEILED_PROGRAM_ID
+      )[0];
+
+      // * Age claims (bit 2) must come from a registered attestor; balances from anyone
+      before(async () => {
+        await program.methods
+          .initializeDomainConfig(Array.from(kycDomain))
+          .accounts({ domainConfig: domainConfigPda(kycDomain), owner: provider.wallet.publicKey })
+          .rpc();
+        await program.methods
+          .setDomainAttestorClaims(1 << 2)
+          .accounts({ domainConfig, owner: provider.wallet.publicKey })
+          .rpc();
+      });
+
+      // * Test 76: A restricted claim needs the issuer's registration
+      it("should only store a restricted claim from a registered attestor", async () => {
+        const unvetted = signedV8Result(2, undefined, kycDomain);
+        try {
+          await submitStoreAttestation(unvetted.fields, unvetted.message, unvetted.signature);
+          expect.fail("Transaction should have failed");
+        } catch (error: any) {
+          expect(error.message).to.include("UntrustedAttestor");
+        }
+
+        const balance = signedV8Result(1, undefined, kycDomain);
+        await submitStoreAttestation(balance.fields, balance.message, balance.signature);
+
+        await program.methods
+          .registerAttestor(authority.publicKey, Array.from(new Uint8Array(32).fill(7)), 1 << 2)
+          .accounts({ authority: provider.wallet.publicKey })
+          .rpc();
+        const registered = await program.account.attestor.fetch(attestorPda);
+        expect(registered.status).to.deep.equal({ active: {} });
+
+        const vetted = signedV8Result(2, undefined, kycDomain);
+        await submitStoreAttestation(vetted.fields, vetted.message, vetted.signature, attestorPda);
+      });
+
+      // * Test 77: A suspended attestor issues nothing to restricting domains until reinstated
+      it("should reject a restricted claim from a suspended attestor", async () => {
+        await program.methods
+          .setAttestorStatus({ suspended: {} })
+          .accounts({ attestor: attestorPda, authority: provider.wallet.publicKey })
+          .rpc();
+
+        const { fields, message, signature } = signedV8Result(2, undefined, kycDomain);
+        try {
+          await submitStoreAttestation(fields, message, signature, attestorPda);
+          expect.fail("Transaction should have failed");
+        } catch (error: any) {
+          expect(error.message).to.include("AttestorSuspended");
+        }
+
+        await program.methods
+          .setAttestorStatus({ active: {} })
+          .accounts({ attestor: attestorPda, authority: provider.wallet.publicKey })
+          .rpc();
+        await submitStoreAttestation(fields, message, signature, attestorPda);
+      });
+    });
+
+    // * Test 78: A revoked claim isn't copied into the Solana Attestation Service
+    it("should reject mirroring a revoked attestation to SAS", async () => {
+      const credential = Keypair.generate().publicKey;
+      const schema = Keypair.generate().publicKey;
+      await program.methods
+        .setSasBridge(credential, schema)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+
+      const { fields, message, signature } = signedV8Result(1);
+      const attestationPda = await submitStoreAttestation(fields, message, signature);
+      await program.methods
+        .revokeAttestation()
+        .accounts({ attestation: attestationPda, issuer: authority.publicKey })
+        .signers([authority])
+        .rpc();
+
+      try {
+        await program.methods
+          .mirrorAttestation()
+          .accounts({
+            attestation: attestationPda,
+            credential,
+            schema,
+            sasAttestation: Keypair.generate().publicKey,
+            payer: provider.wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("AttestationRevoked");
+      }
+    });
+
+    // * Test 79: A V9 signature covers the protocol tag, so it can't be replayed untagged
+    it("should accept a tagged V9 login and reject a tag the signature doesn't cover", async () => {
+      const tagged = signedV8Result(0);
+      const taggedFields = { ...tagged.fields, tagged: true };
+      const taggedMessage = createEd25519Message(taggedFields);
+      const taggedSignature = signMessage(authority, taggedMessage);
+
+      const nullifierPda = await submitTypedVerifyAuth(taggedFields, taggedMessage, taggedSignature);
+      const account = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(account.circuitId).to.equal(AUTH_V4_CIRCUIT_ID);
+
+      // * Claims the tag over a signature of the untagged message
+      const { fields, message, signature } = signedV8Result(0);
+      try {
+        await submitTypedVerifyAuth({ ...fields, tagged: true }, message, signature);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("MessageTagMismatch");
+      }
+    });
+  });
+
+  // * Test 42: One proof can only register one nullifier, even with a fresh verifier signature
+  it("should reject a proof reused for another nullifier", async () => {
+    const first = signedV4Result(AUTH_V4_CIRCUIT_ID);
+    await submitTypedVerifyAuth(first.fields, first.message, first.signature);
+
+    const fields = { ...first.fields, nullifier: crypto.getRandomValues(new Uint8Array(32)) };
+    const message = createEd25519Message(fields);
+    const signature = signMessage(authority, message);
+
+    try {
+      await submitTypedVerifyAuth(fields, message, signature);
+      expect.fail("Transaction should have failed");
+    } catch (error: any) {
+      expect(error.message).to.include("ProofAlreadyUsed");
+    }
+  });
+
+  // * Helper: Submit verify_auth_batch, one Ed25519 instruction per entry
+  async function submitVerifyAuthBatch(
+    entries: { result: Uint8Array; message: Uint8Array; signature: Uint8Array; fields: any }[]
+  ): Promise<void> {
+    const remainingAccounts = entries.flatMap(({ result, signature, fields }) => [
+      {
+        pubkey: sessionPda(fields.nullifier, fields.domain),
+        isSigner: false,
+        isWritable: true,
+      },
+      { pubkey: proofRecordPda(result), isSigner: false, isWritable: true },
+      {
+        pubkey: domainConfigPda(fields.domain),
+        isSigner: false,
+        isWritable: false,
+      },
+      {
+        pubkey: PublicKey.findProgramAddressSync(
+          [Buffer.from("nullifier_ban"), Buffer.from(fields.domain), Buffer.from(fields.nullifier)],
+          VEILED_PROGRAM_ID
+        )[0],
+        isSigner: false,
+        isWritable: false,
+      },
+      { pubkey: resultReceiptPda(signature), isSigner: false, isWritable: true },
+      {
+        pubkey: PublicKey.findProgramAddressSync(
+          [Buffer.from("logout_epoch"), authority.publicKey.toBuffer()],
+          VEILED_PROGRAM_ID
+        )[0],
+        isSigner: false,
+        isWritable: false,
+      },
+    ]);
+
+    await program.methods
+      .verifyAuthBatch(
+        entries.map(({ signature, fields }) => ({
+          result: createSignedResultArg(fields, signature),
+          nullifier: Array.from(fields.nullifier as Uint8Array),
+          domain: Array.from(fields.domain as Uint8Array),
+        }))
+      )
+      .preInstructions(
+        entries.map(({ message, signature }) =>
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          })
+        )
+      )
+      .accounts({ authority: authority.publicKey })
+      .remainingAccounts(remainingAccounts)
+      .signers([authority])
+      .rpc();
+  }
+
+  // * Test 43: Onboarding to several domains takes one transaction
+  it("should register a batch of results for two domains", async () => {
+    const first = signedV4Result(AUTH_V4_CIRCUIT_ID);
+    const second = signedV4Result(
+      AUTH_V4_CIRCUIT_ID,
+      Uint8Array.from(domainToArray("batch-domain"))
+    );
+
+    await submitVerifyAuthBatch([first, second]);
+
+    for (const { fields } of [first, second]) {
+      const nullifierPda = sessionPda(fields.nullifier, fields.domain);
+      const account = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(Buffer.from(account.nullifier)).to.deep.equal(Buffer.from(fields.nullifier));
+      expect(account.circuitId).to.equal(AUTH_V4_CIRCUIT_ID);
+    }
+  });
+
+  // * Test 44: A failing entry fails the whole batch
+  it("should reject a batch that repeats a nullifier", async () => {
+    const first = signedV4Result(AUTH_V4_CIRCUIT_ID);
+    const fields = { ...first.fields, proofHash: crypto.getRandomValues(new Uint8Array(32)) };
+    const message = createEd25519Message(fields);
+    const signature = signMessage(authority, message);
+    const repeat = { fields, message, signature, result: createV4VerificationResult(fields, signature) };
+
+    try {
+      await submitVerifyAuthBatch([first, repeat]);
+      expect.fail("Transaction should have failed");
+    } catch (error: any) {
+      expect(error.message).to.include("DuplicateNullifier");
+    }
+
+    // * The first entry didn't register on its own either
+    const nullifierPda = sessionPda(first.fields.nullifier, first.fields.domain);
+    expect(await provider.connection.getAccountInfo(nullifierPda)).to.be.null;
+  });
+
+  // * Helper: Submit a signed result through verify_auth's typed argument
+  // * expiresIn is the session lifetime asked for (0 = the longest the domain allows)
+  async function submitTypedVerifyAuth(
+    fields: VerificationFields,
+    message: Uint8Array,
+    signature: Uint8Array,
+    expiresIn = 0,
+    signer: Keypair = authority
+  ): Promise<PublicKey> {
+    const nullifierPda = sessionPda(fields.nullifier, fields.domain);
+    const [proofRecord] = PublicKey.findProgramAddressSync(
+      [Buffer.from("proof_record"), Buffer.from(fields.proofHash)],
+      VEILED_PROGRAM_ID
+    );
+
+    await program.methods
+      .verifyAuth(
+        createSignedResultArg(fields, signature),
+        Array.from(fields.nullifier),
+        Array.from(fields.domain),
+        new anchor.BN(expiresIn)
+      )
+      .preInstructions([
+        Ed25519Program.createInstructionWithPublicKey({
+          publicKey: signer.publicKey.toBytes(),
+          message,
+          signature,
+        }),
+      ])
+      .accounts({
+        nullifierAccount: nullifierPda,
+        proofRecord,
+        resultReceipt: resultReceiptPda(signature),
+        authority: signer.publicKey,
+        domainConfig: domainConfigPda(fields.domain),
+        // * The signer's own sessions are the ones it replaces
+        logoutEpoch: PublicKey.findProgramAddressSync(
+          [Buffer.from("logout_epoch"), signer.publicKey.toBuffer()],
+          VEILED_PROGRAM_ID
+        )[0],
+      })
+      .signers([signer])
+      .rpc();
+
+    return nullifierPda;
+  }
+
+  // * Test 45: The typed argument registers the same session as the byte envelope
+  it("should accept a typed verification result", async () => {
+    const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+
+    const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+
+    const account = await program.account.nullifierAccount.fetch(nullifierPda);
+    expect(Buffer.from(account.nullifier)).to.deep.equal(Buffer.from(fields.nullifier));
+    expect(Buffer.from(account.publicInputsHash)).to.deep.equal(
+      Buffer.from(fields.publicInputsHash!)
+    );
+    expect(account.circuitId).to.equal(AUTH_V4_CIRCUIT_ID);
+  });
+
+  // * Test 63: Auditors tie a session to its proof from the account alone
+  it("should record the proof hash and verification time in the session", async () => {
+    const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+
+    const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+
+    const account = await program.account.nullifierAccount.fetch(nullifierPda);
+    expect(Buffer.from(account.proofHash)).to.deep.equal(Buffer.from(fields.proofHash));
+    expect(account.verifiedAt.toNumber()).to.equal(fields.timestamp);
+  });
+
+  // * Test 64: Audits can tell which verification service authorized each session
+  it("should record the verifier that signed the result in the session", async () => {
+    const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+
+    const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+
+    const account = await program.account.nullifierAccount.fetch(nullifierPda);
+    expect(account.verifier.toString()).to.equal(authority.publicKey.toString());
+  });
+
+  // * Test 66: The signed result is spent on first use and its receipt outlives its freshness
+  it("should keep a receipt for the consumed result until its retention ends", async () => {
+    const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+
+    await submitTypedVerifyAuth(fields, message, signature);
+
+    const receiptPda = resultReceiptPda(signature);
+    const receipt = await program.account.resultReceipt.fetch(receiptPda);
+    expect(Buffer.from(receipt.signatureHash)).to.deep.equal(
+      createHash("sha256").update(signature).digest()
+    );
+    expect(receipt.payer.toString()).to.equal(authority.publicKey.toString());
+    expect(receipt.retainUntil.sub(receipt.consumedAt).toNumber()).to.equal(24 * 60 * 60);
+
+    try {
+      await program.methods
+        .closeResultReceipt()
+        .accounts({ resultReceipt: receiptPda, payer: authority.publicKey })
+        .rpc();
+      expect.fail("Transaction should have failed");
+    } catch (error: any) {
+      expect(error.message).to.include("ReceiptRetained");
+    }
+  });
+
+  // * Test 67: A retention shorter than the staleness window would let a closed receipt's
+  // * result verify again
+  it("should reject a receipt retention shorter than an hour", async () => {
+    try {
+      await program.methods
+        .setReceiptRetention(new anchor.BN(60))
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+      expect.fail("Transaction should have failed");
+    } catch (error: any) {
+      expect(error.message).to.include("InvalidReceiptRetention");
+    }
+  });
+
+  // * Test 46: Optional fields extend the signed message in order, so gaps are rejected
+  it("should reject a typed result with a circuit ID but no public inputs hash", async () => {
+    const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+
+    try {
+      await submitTypedVerifyAuth({ ...fields, publicInputsHash: undefined }, message, signature);
+      expect.fail("Transaction should have failed");
+    } catch (error: any) {
+      expect(error.message).to.include("InvalidResultLayout");
+    }
+  });
+
+  // * Test 47: Simulating clients read the session from return data, with no account fetch
+  it("should return the registered session via return data", async () => {
+    const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+    const nullifierPda = sessionPda(fields.nullifier, fields.domain);
+    const [proofRecord] = PublicKey.findProgramAddressSync(
+      [Buffer.from("proof_record"), Buffer.from(fields.proofHash)],
+      VEILED_PROGRAM_ID
+    );
+
+    const { raw } = await program.methods
+      .verifyAuth(
+        createSignedResultArg(fields, signature),
+        Array.from(fields.nullifier),
+        Array.from(fields.domain),
+        new anchor.BN(0)
+      )
+      .preInstructions([
+        Ed25519Program.createInstructionWithPublicKey({
+          publicKey: authority.publicKey.toBytes(),
+          message,
+          signature,
+        }),
+      ])
+      .accounts({
+        nullifierAccount: nullifierPda,
+        proofRecord,
+        resultReceipt: resultReceiptPda(signature),
+        authority: authority.publicKey,
+        domainConfig: domainConfigPda(fields.domain),
+      })
+      .signers([authority])
+      .simulate();
+
+    const prefix = `Program return: ${VEILED_PROGRAM_ID.toBase58()} `;
+    const returnLog = raw.find((log) => log.startsWith(prefix));
+    expect(returnLog).to.not.be.undefined;
+    const session = program.coder.types.decode(
+      "SessionDetails",
+      Buffer.from(returnLog!.slice(prefix.length), "base64")
+    );
+    expect(session.nullifierAccount.toBase58()).to.equal(nullifierPda.toBase58());
+    expect(session.expiresAt.unixTime[0].sub(session.createdAt).toNumber()).to.equal(
+      30 * 24 * 60 * 60
+    );
+  });
+
+  // * Helper: Events a confirmed transaction emitted through emit_cpi!
+  // * Each is a Veiled self-CPI whose data is EVENT_IX_TAG (8 bytes) followed by the event
+  async function cpiEvents(signature: string) {
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const keys = tx!.transaction.message.getAccountKeys({
+      accountKeysFromLookups: tx!.meta!.loadedAddresses,
+    });
+
+    return (tx!.meta!.innerInstructions ?? [])
+      .flatMap(({ instructions }) => instructions)
+      .filter(({ programIdIndex }) => keys.get(programIdIndex)!.equals(VEILED_PROGRAM_ID))
+      .map(({ data }) =>
+        program.coder.events.decode(
+          Buffer.from(anchor.utils.bytes.bs58.decode(data).subarray(8)).toString("base64")
+        )
+      )
+      .filter((event) => event !== null);
+  }
+
+  // * Test 48: Indexers follow authentications through AuthVerifiedEvent, read from the
+  // * transaction's inner instructions rather than its (truncatable) logs
+  it("should emit AuthVerifiedEvent for a registered session", async () => {
+    const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+    const [proofRecord] = PublicKey.findProgramAddressSync(
+      [Buffer.from("proof_record"), Buffer.from(fields.proofHash)],
+      VEILED_PROGRAM_ID
+    );
+
+    const txSignature = await program.methods
+      .verifyAuth(
+        createSignedResultArg(fields, signature),
+        Array.from(fields.nullifier),
+        Array.from(fields.domain),
+        new anchor.BN(0)
+      )
+      .preInstructions([
+        Ed25519Program.createInstructionWithPublicKey({
+          publicKey: authority.publicKey.toBytes(),
+          message,
+          signature,
+        }),
+      ])
+      .accounts({
+        nullifierAccount: sessionPda(fields.nullifier, fields.domain),
+        proofRecord,
+        resultReceipt: resultReceiptPda(signature),
+        authority: authority.publicKey,
+        domainConfig: domainConfigPda(fields.domain),
+      })
+      .signers([authority])
+      .rpc({ commitment: "confirmed" });
+
+    const event = (await cpiEvents(txSignature)).find(({ name }) => name === "AuthVerifiedEvent");
+    expect(event).to.not.be.undefined;
+    expect(Buffer.from(event!.data.nullifier as number[])).to.deep.equal(
+      Buffer.from(fields.nullifier)
+    );
+    expect(Buffer.from(event!.data.domain as number[])).to.deep.equal(Buffer.from(fields.domain));
+    expect(Buffer.from(event!.data.proofHash as number[])).to.deep.equal(
+      Buffer.from(fields.proofHash)
+    );
+  });
+
+  // * Tests 49-51 guard a domain with registered origins
+  describe("origin allowlist", () => {
+    const originDomain = Uint8Array.from(domainToArray("origin-domain"));
+    const registeredOrigin = createHash("sha256").update("https://app.example.com").digest();
+    const domainConfig = domainConfigPda(originDomain);
+
+    before(async () => {
+      await program.methods
+        .initializeDomainConfig(Array.from(originDomain))
+        .accounts({ domainConfig: domainConfigPda(originDomain), owner: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .setDomainOrigins([Array.from(registeredOrigin)])
+        .accounts({ domainConfig, owner: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    // * Helper: Submit a fresh result for the domain through verify_auth_with_origin
+    async function submitWithOrigin(originHash: Uint8Array): Promise<PublicKey> {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, originDomain);
+      const nullifierPda = sessionPda(fields.nullifier, fields.domain);
+      const [proofRecord] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proof_record"), Buffer.from(fields.proofHash)],
+        VEILED_PROGRAM_ID
+      );
+
+      await program.methods
+        .verifyAuthWithOrigin(
+          createSignedResultArg(fields, signature),
+          Array.from(fields.nullifier),
+          Array.from(originDomain),
+          Array.from(originHash)
+        )
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+        ])
+        .accounts({
+          nullifierAccount: nullifierPda,
+          proofRecord,
+          resultReceipt: resultReceiptPda(signature),
+          authority: authority.publicKey,
+          domainConfig: domainConfigPda(originDomain),
+        })
+        .signers([authority])
+        .rpc();
+
+      return nullifierPda;
+    }
+
+    // * Test 49: The app's own frontend names its registered origin
+    it("should accept a verification from a registered origin", async () => {
+      const nullifierPda = await submitWithOrigin(registeredOrigin);
+
+      const account = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(account.domain).to.deep.equal(Array.from(originDomain));
+      expect(account.domainLen).to.equal("origin-domain".length);
+    });
+
+    // * Test 50: A phishing frontend's origin isn't registered for the domain
+    it("should reject a verification from an unregistered origin", async () => {
+      const phishingOrigin = createHash("sha256").update("https://app-example.com").digest();
+
+      try {
+        await submitWithOrigin(phishingOrigin);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("OriginNotAllowed");
+      }
+    });
+
+    // * Test 51: Plain verify_auth names no origin, so it can't bypass the allowlist
+    it("should reject verify_auth without an origin for a guarded domain", async () => {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, originDomain);
+
+      try {
+        await submitTypedVerifyAuth(fields, message, signature);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("OriginNotAllowed");
+      }
+    });
+  });
+
+  describe("relayed verification with user consent", () => {
+    const user = Keypair.generate();
+    const CONSENT_PREFIX = Buffer.from("veiled:consent:v2");
+
+    // * Helper: UserConsent::message (instance_id || nullifier || domain || expires_at LE)
+    function consentMessage(nullifier: Uint8Array, expiresAt: number): Uint8Array {
+      const message = Buffer.alloc(CONSENT_PREFIX.length + 32 + 32 + 32 + 8);
+      CONSENT_PREFIX.copy(message, 0);
+      message.set(program.programId.toBytes(), CONSENT_PREFIX.length);
+      message.set(nullifier, CONSENT_PREFIX.length + 32);
+      message.set(TEST_DOMAIN, CONSENT_PREFIX.length + 64);
+      message.writeBigInt64LE(BigInt(expiresAt), CONSENT_PREFIX.length + 96);
+      return message;
+    }
+
+    // * Helper: Relay a fresh result with a consent signed by `signer` over `consentNullifier`
+    // * (the result's own nullifier unless testing a mismatch)
+    async function relayWithConsent(
+      signer: Keypair,
+      expiresAt: number,
+      consentNullifier?: Uint8Array
+    ): Promise<PublicKey> {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+      const consent = consentMessage(consentNullifier ?? fields.nullifier, expiresAt);
+      const nullifierPda = sessionPda(fields.nullifier, fields.domain);
+      const [proofRecord] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proof_record"), Buffer.from(fields.proofHash)],
+        VEILED_PROGRAM_ID
+      );
+
+      await program.methods
+        .verifyAuthWithConsent(
+          createSignedResultArg(fields, signature),
+          Array.from(fields.nullifier),
+          Array.from(TEST_DOMAIN),
+          { user: user.publicKey, expiresAt: new anchor.BN(expiresAt) }
+        )
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: signer.publicKey.toBytes(),
+            message: consent,
+            signature: nacl.sign.detached(consent, signer.secretKey),
+          }),
+        ])
+        .accounts({
+          nullifierAccount: nullifierPda,
+          proofRecord,
+          resultReceipt: resultReceiptPda(signature),
+          authority: authority.publicKey,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
+        })
+        .signers([authority])
+        .rpc();
+
+      return nullifierPda;
+    }
+
+    // * Test 52: The relayer pays, the user owns the session
+    it("should register a relayed session for the consenting user", async () => {
+      const nullifierPda = await relayWithConsent(user, Math.floor(Date.now() / 1000) + 300);
+
+      const account = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(account.authority.toString()).to.equal(user.publicKey.toString());
+    });
+
+    // * Test 53: A relayer can't sign consent on the user's behalf
+    it("should reject consent signed by another wallet", async () => {
+      try {
+        await relayWithConsent(Keypair.generate(), Math.floor(Date.now() / 1000) + 300);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("MissingUserConsent");
+      }
+    });
+
+    // * Test 54: Consent covers one nullifier, so it can't be reused for another login
+    it("should reject consent signed for another nullifier", async () => {
+      try {
+        await relayWithConsent(
+          user,
+          Math.floor(Date.now() / 1000) + 300,
+          crypto.getRandomValues(new Uint8Array(32))
+        );
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("MissingUserConsent");
+      }
+    });
+
+    // * Test 55: Old consents can't be held back and submitted later
+    it("should reject expired consent", async () => {
+      try {
+        await relayWithConsent(user, Math.floor(Date.now() / 1000) - 60);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("UserConsentExpired");
+      }
+    });
+  });
+
+  describe("verifier separate from the fee payer", () => {
+    const relayer = Keypair.generate();
+    const bystander = Keypair.generate();
+
+    before(async () => {
+      const airdropSig = await provider.connection.requestAirdrop(
+        relayer.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropSig);
+      await program.methods
+        .addVerifier(bystander.publicKey)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    after(async () => {
+      await program.methods
+        .removeVerifier(bystander.publicKey)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    // * Helper: Relayer submits a result signed by the test verifier, naming `verifier`
+    async function relay(verifier: PublicKey): Promise<PublicKey> {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+      const nullifierPda = sessionPda(fields.nullifier, fields.domain);
+      const [proofRecord] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proof_record"), Buffer.from(fields.proofHash)],
+        VEILED_PROGRAM_ID
+      );
+
+      await program.methods
+        .verifyAuth(
+          createSignedResultArg(fields, signature),
+          Array.from(fields.nullifier),
+          Array.from(TEST_DOMAIN),
+          new anchor.BN(0)
+        )
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+        ])
+        .accounts({
+          nullifierAccount: nullifierPda,
+          proofRecord,
+          resultReceipt: resultReceiptPda(signature),
+          authority: relayer.publicKey,
+          verifier,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
+        })
+        .signers([relayer])
+        .rpc();
+
+      return nullifierPda;
+    }
+
+    // * Test 56: The verifier signs, the relayer pays and owns the session
+    it("should accept a relayed result signed by the named verifier", async () => {
+      const nullifierPda = await relay(authority.publicKey);
+
+      const account = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(account.authority.toString()).to.equal(relayer.publicKey.toString());
+      expect(account.verifier.toString()).to.equal(authority.publicKey.toString());
+    });
+
+    // * Test 57: Naming a registered key only counts if that key signed the result
+    it("should reject a named verifier that didn't sign the result", async () => {
+      try {
+        await relay(bystander.publicKey);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("UnregisteredVerifier");
+      }
+    });
+  });
+
+  describe("session expiry", () => {
+    const shortDomain = Uint8Array.from(domainToArray("short-session-domain"));
+    const domainConfig = domainConfigPda(shortDomain);
+
+    before(async () => {
+      await program.methods
+        .initializeDomainConfig(Array.from(shortDomain))
+        .accounts({ domainConfig: domainConfigPda(shortDomain), owner: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .setDomainSessionBounds(new anchor.BN(60), new anchor.BN(15 * 60))
+        .accounts({ domainConfig, owner: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    // * Test 60: A sensitive operation asks for a five-minute session
+    it("should honor the session lifetime asked for", async () => {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, shortDomain);
+
+      const nullifierPda = await submitTypedVerifyAuth(fields, message, signature, 5 * 60);
+
+      const session = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(session.expiresAt.unixTime[0].sub(session.createdAt).toNumber()).to.equal(5 * 60);
+    });
+
+    // * Test 61: The domain caps sessions at 15 minutes, whatever the caller asks for
+    it("should reject a session lifetime past the domain's bounds", async () => {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, shortDomain);
+
+      try {
+        await submitTypedVerifyAuth(fields, message, signature, 60 * 60);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("SessionExpiryOutOfBounds");
+      }
+    });
+
+    // * Test 62: Bounds stay inside the protocol's and only the owner moves them
+    it("should reject session bounds from a non-owner or past the protocol's", async () => {
+      try {
+        await program.methods
+          .setDomainSessionBounds(new anchor.BN(0), new anchor.BN(365 * 24 * 60 * 60))
+          .accounts({ domainConfig, owner: provider.wallet.publicKey })
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidSessionBounds");
+      }
+
+      try {
+        await program.methods
+          .setDomainSessionBounds(new anchor.BN(0), new anchor.BN(0))
+          .accounts({ domainConfig, owner: authority.publicKey })
+          .signers([authority])
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("NotDomainOwner");
+      }
+    });
+
+    // * Test 80: A nullifier signs in again once its session lapses, but not before
+    it("should replace an expired session with a fresh proof", async function () {
+      this.timeout(120_000);
+      const first = signedV4Result(AUTH_V4_CIRCUIT_ID, shortDomain);
+      const nullifierPda = await submitTypedVerifyAuth(
+        first.fields,
+        first.message,
+        first.signature,
+        60
+      );
+
+      // * Helper: Sign a fresh proof for the same nullifier
+      const again = () => {
+        const fields = {
+          ...signedV4Result(AUTH_V4_CIRCUIT_ID, shortDomain).fields,
+          nullifier: first.fields.nullifier,
+        };
+        const message = createEd25519Message(fields);
+        return { fields, message, signature: signMessage(authority, message) };
+      };
+
+      const early = again();
+      try {
+        await submitTypedVerifyAuth(early.fields, early.message, early.signature);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("DuplicateNullifier");
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 62_000));
+      const before = await program.account.nullifierAccount.fetch(nullifierPda);
+      const late = again();
+      await submitTypedVerifyAuth(late.fields, late.message, late.signature);
+
+      const session = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(session.createdAt.gt(before.createdAt)).to.be.true;
+      expect(Buffer.from(session.proofHash)).to.deep.equal(Buffer.from(late.fields.proofHash));
+    });
+
+    // * Test 81: Nobody can reclaim a live session's rent
+    it("should reject closing a nullifier account before its session expires", async () => {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, shortDomain);
+      const nullifierPda = await submitTypedVerifyAuth(fields, message, signature, 60);
+      const session = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(session.payer.toBase58()).to.equal(authority.publicKey.toBase58());
+
+      try {
+        await program.methods
+          .closeExpiredNullifier()
+          .accounts({ nullifierAccount: nullifierPda, payer: session.payer })
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("SessionNotExpired");
+      }
+    });
+  });
+
+  describe("logout", () => {
+    // * Helper: "veiled:close_session:v1" || instance_id || nullifier || created_at (i64 LE)
+    function closeRequestMessage(nullifier: Uint8Array, createdAt: anchor.BN): Uint8Array {
+      return Buffer.concat([
+        Buffer.from("veiled:close_session:v1"),
+        program.programId.toBuffer(),
+        Buffer.from(nullifier),
+        createdAt.toArrayLike(Buffer, "le", 8),
+      ]);
+    }
+
+    // * Test 82: The session's authority logs out, and the nullifier frees up
+    it("should close a session signed for by its authority", async () => {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+      const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+
+      await program.methods
+        .closeSession()
+        .accounts({
+          nullifierAccount: nullifierPda,
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(nullifierPda)).to.be.null;
+    });
+
+    // * Test 83: A relayer closes a session only with the authority's signed request for it
+    it("should close a relayed session only with the authority's close request", async () => {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+      const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+      const session = await program.account.nullifierAccount.fetch(nullifierPda);
+      const request = closeRequestMessage(fields.nullifier, session.createdAt);
+      const accounts = {
+        nullifierAccount: nullifierPda,
+        authority: authority.publicKey,
+        payer: authority.publicKey,
+      };
+
+      const stranger = Keypair.generate();
+      try {
+        await program.methods
+          .closeSession()
+          .accounts(accounts)
+          .preInstructions([
+            Ed25519Program.createInstructionWithPublicKey({
+              publicKey: stranger.publicKey.toBytes(),
+              message: request,
+              signature: signMessage(stranger, request),
+            }),
+          ])
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("MissingSessionCloseSignature");
+      }
+
+      await program.methods
+        .closeSession()
+        .accounts(accounts)
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message: request,
+            signature: signMessage(authority, request),
+          }),
+        ])
+        .rpc();
+
+      expect(await provider.connection.getAccountInfo(nullifierPda)).to.be.null;
+    });
+  });
+
+  // * Helper: Renew `nullifier`'s session with a fresh proof signed for `domain`
+  async function renew(
+    nullifierPda: PublicKey,
+    nullifier: Uint8Array,
+    domain: Uint8Array,
+    signer: Keypair = authority
+  ) {
+    const fields = { ...signedV4Result(AUTH_V4_CIRCUIT_ID, domain).fields, nullifier };
+    const message = createEd25519Message(fields);
+    const signature = signMessage(authority, message);
+    const [proofRecord] = PublicKey.findProgramAddressSync(
+      [Buffer.from("proof_record"), Buffer.from(fields.proofHash)],
+      VEILED_PROGRAM_ID
+    );
+    await program.methods
+      .renewSession(createSignedResultArg(fields, signature), Array.from(domain), new anchor.BN(0))
+      .preInstructions([
+        Ed25519Program.createInstructionWithPublicKey({
+          publicKey: authority.publicKey.toBytes(),
+          message,
+          signature,
+        }),
+      ])
+      .accounts({
+        nullifierAccount: nullifierPda,
+        proofRecord,
+        resultReceipt: resultReceiptPda(signature),
+        authority: signer.publicKey,
+        verifier: authority.publicKey,
+        domainConfig: domainConfigPda(domain),
+      })
+      .signers([signer])
+      .rpc();
+    return fields;
+  }
+
+  describe("session renewal", () => {
+    // * Test 84: A fresh result extends the session in place; one for another domain can't
+    it("should renew a session from a fresh result for its domain", async () => {
+      const first = signedV4Result(AUTH_V4_CIRCUIT_ID, TEST_DOMAIN);
+      const nullifierPda = await submitTypedVerifyAuth(
+        first.fields,
+        first.message,
+        first.signature,
+        60 * 60
+      );
+      const before = await program.account.nullifierAccount.fetch(nullifierPda);
+
+      try {
+        await renew(
+          nullifierPda,
+          first.fields.nullifier,
+          Uint8Array.from(domainToArray("other-renewal-domain"))
+        );
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("DomainMismatch");
+      }
+
+      const fields = await renew(nullifierPda, first.fields.nullifier, TEST_DOMAIN);
+      const session = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(session.createdAt.eq(before.createdAt)).to.be.true;
+      expect(session.expiresAt.unixTime[0].gt(before.expiresAt.unixTime[0])).to.be.true;
+      expect(Buffer.from(session.proofHash)).to.deep.equal(Buffer.from(fields.proofHash));
+    });
+
+    // * Test 85: Only the session's authority can renew it
+    it("should reject a renewal signed by another wallet", async () => {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+      const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+
+      try {
+        await renew(nullifierPda, fields.nullifier, TEST_DOMAIN, Keypair.generate());
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+    });
+
+    // * Test 102: A renewal runs verify_auth's ban check and the session gate
+    it("should refuse to renew a banned nullifier or a pending session", async () => {
+      const renewalDomain = Uint8Array.from(domainToArray(`renew-${Date.now().toString(36)}`));
+      await program.methods
+        .initializeDomainConfig(Array.from(renewalDomain))
+        .accounts({
+          domainConfig: domainConfigPda(renewalDomain),
+          owner: provider.wallet.publicKey,
+        })
+        .rpc();
+      const domainConfig = domainConfigPda(renewalDomain);
+
+      const banned = signedV4Result(AUTH_V4_CIRCUIT_ID, renewalDomain);
+      const bannedPda = await submitTypedVerifyAuth(banned.fields, banned.message, banned.signature);
+      await program.methods
+        .banNullifier(Array.from(banned.fields.nullifier))
+        .accounts({ domainConfig, owner: provider.wallet.publicKey })
+        .rpc();
+      try {
+        await renew(bannedPda, banned.fields.nullifier, renewalDomain);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("NullifierBanned");
+      }
+
+      // * A pending session can't trade in the proof a challenge would dispute
+      await program.methods
+        .setChallengeWindow(new anchor.BN(3600))
+        .accounts({ domainConfig, owner: provider.wallet.publicKey })
+        .rpc();
+      const pending = signedV4Result(AUTH_V4_CIRCUIT_ID, renewalDomain);
+      const pendingPda = await submitTypedVerifyAuth(
+        pending.fields,
+        pending.message,
+        pending.signature
+      );
+      try {
+        await renew(pendingPda, pending.fields.nullifier, renewalDomain);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("SessionPending");
+      }
+    });
+  });
+
+  describe("idle timeout", () => {
+    const idleDomain = Uint8Array.from(domainToArray("idle-timeout-domain"));
+    const domainConfig = domainConfigPda(idleDomain);
+
+    before(async () => {
+      await program.methods
+        .initializeDomainConfig(Array.from(idleDomain))
+        .accounts({ domainConfig: domainConfigPda(idleDomain), owner: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    // * Test 86: Sessions take the domain's idle timeout, and a heartbeat moves last_active_at
+    it("should record activity against the domain's idle timeout", async () => {
+      try {
+        await program.methods
+          .setDomainIdleTimeout(new anchor.BN(30))
+          .accounts({ domainConfig, owner: provider.wallet.publicKey })
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidIdleTimeout");
+      }
+      await program.methods
+        .setDomainIdleTimeout(new anchor.BN(10 * 60))
+        .accounts({ domainConfig, owner: provider.wallet.publicKey })
+        .rpc();
+
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, idleDomain);
+      const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+      const before = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(before.idleTimeoutSecs.toNumber()).to.equal(10 * 60);
+      expect(before.lastActiveAt.eq(before.createdAt)).to.be.true;
+
+      try {
+        const intruder = Keypair.generate();
+        await program.methods
+          .touchSession()
+          .accounts({ nullifierAccount: nullifierPda, authority: intruder.publicKey })
+          .signers([intruder])
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("Unauthorized");
+      }
+
+      await new Promise((resolve) => setTimeout(resolve, 2_000));
+      await program.methods
+        .touchSession()
+        .accounts({ nullifierAccount: nullifierPda, authority: authority.publicKey })
+        .signers([authority])
+        .rpc();
+      const after = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(after.lastActiveAt.gt(before.lastActiveAt)).to.be.true;
+    });
+  });
+
+  describe("device binding", () => {
+    function deviceProofMessage(nullifier: Uint8Array, lastActiveAt: anchor.BN): Uint8Array {
+      return Buffer.concat([
+        Buffer.from("veiled:device:v1"),
+        program.programId.toBuffer(),
+        Buffer.from(nullifier),
+        lastActiveAt.toArrayLike(Buffer, "le", 8),
+      ]);
+    }
+
+    // * Test 87: A device-bound session's heartbeat needs the device's signature
+    it("should require the bound device key to touch a session", async () => {
+      const device = Keypair.generate();
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+      const nullifierPda = sessionPda(fields.nullifier, fields.domain);
+
+      await program.methods
+        .verifyAuthWithDevice(
+          createSignedResultArg(fields, signature),
+          Array.from(fields.nullifier),
+          Array.from(fields.domain),
+          device.publicKey,
+          new anchor.BN(0)
+        )
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+        ])
+        .accounts({
+          nullifierAccount: nullifierPda,
+          proofRecord: PublicKey.findProgramAddressSync(
+            [Buffer.from("proof_record"), Buffer.from(fields.proofHash)],
+            VEILED_PROGRAM_ID
+          )[0],
+          resultReceipt: resultReceiptPda(signature),
+          authority: authority.publicKey,
+          domainConfig: domainConfigPda(fields.domain),
+        })
+        .signers([authority])
+        .rpc();
+      const session = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(session.deviceKey.equals(device.publicKey)).to.be.true;
+
+      const accounts = { nullifierAccount: nullifierPda, authority: authority.publicKey };
+      try {
+        await program.methods.touchSession().accounts(accounts).signers([authority]).rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("MissingDeviceSignature");
+      }
+
+      const proof = deviceProofMessage(fields.nullifier, session.lastActiveAt);
+      await program.methods
+        .touchSession()
+        .accounts(accounts)
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: device.publicKey.toBytes(),
+            message: proof,
+            signature: signMessage(device, proof),
+          }),
+        ])
+        .signers([authority])
+        .rpc();
+    });
+  });
+
+  describe("session delegates", () => {
+    // * Test 88: A delegate acts within its scope until the parent revokes it
+    it("should validate a delegate within its scope until revoked", async () => {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+      const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+      const delegate = Keypair.generate();
+      const [sessionDelegate] = PublicKey.findProgramAddressSync(
+        [Buffer.from("session_delegate"), nullifierPda.toBuffer(), delegate.publicKey.toBuffer()],
+        VEILED_PROGRAM_ID
+      );
+
+      // * Scope: SignTransactions only
+      await program.methods
+        .delegateSession(delegate.publicKey, new anchor.BN(1 << 7), new anchor.BN(0))
+        .accounts({ nullifierAccount: nullifierPda, authority: authority.publicKey })
+        .signers([authority])
+        .rpc();
+      const minted = await program.account.sessionDelegate.fetch(sessionDelegate);
+      expect(minted.expiresAt.sub(minted.createdAt).toNumber()).to.equal(15 * 60);
+
+      const validate = (permission: object) =>
+        program.methods
+          .validateSessionDelegate(permission as any)
+          .accounts({
+            sessionDelegate,
+            nullifierAccount: nullifierPda,
+            delegate: delegate.publicKey,
+          })
+          .signers([delegate])
+          .rpc();
+
+      await validate({ signTransactions: {} });
+      try {
+        await validate({ revealWalletAddress: {} });
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("DelegateNotAllowed");
+      }
+
+      await program.methods
+        .revokeSessionDelegate()
+        .accounts({ sessionDelegate, authority: authority.publicKey })
+        .signers([authority])
+        .rpc();
+      expect(await provider.connection.getAccountInfo(sessionDelegate)).to.be.null;
+    });
+  });
+
+  describe("bitmap nullifier store", () => {
+    const bitmapDomain = Uint8Array.from(domainToArray("bitmap-store-domain"));
+    const domainConfig = domainConfigPda(bitmapDomain);
+
+    before(async () => {
+      await program.methods
+        .initializeDomainConfig(Array.from(bitmapDomain))
+        .accounts({ domainConfig: domainConfigPda(bitmapDomain), owner: provider.wallet.publicKey })
+        .rpc();
+      await program.methods
+        .setDomainNullifierStore({ bitmap: {} })
+        .accounts({ domainConfig, owner: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    // * Helper: Submit verify_auth_sharded for a signed result; returns its shard
+    async function submitSharded(
+      fields: VerificationFields,
+      message: Uint8Array,
+      signature: Uint8Array
+    ): Promise<PublicKey> {
+      const nullifierShard = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("nullifier_shard"),
+          createHash("sha256").update(fields.domain).digest(),
+          Buffer.from([fields.nullifier[31], fields.nullifier[30]]),
+        ],
+        VEILED_PROGRAM_ID
+      )[0];
+      await program.methods
+        .verifyAuthSharded(
+          createSignedResultArg(fields, signature),
+          Array.from(fields.nullifier),
+          Array.from(fields.domain)
+        )
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+        ])
+        .accounts({
+          domainConfig: domainConfigPda(fields.domain),
+          nullifierShard,
+          authority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      return nullifierShard;
+    }
+
+    // * Test 89: A bitmap domain registers each nullifier once, and only through its shard
+    it("should register a nullifier once in its shard", async () => {
+      const first = signedV4Result(AUTH_V4_CIRCUIT_ID, bitmapDomain);
+      const nullifierShard = await submitSharded(first.fields, first.message, first.signature);
+      const shard = await program.account.nullifierShard.fetch(nullifierShard);
+      expect(shard.registered).to.equal(1);
+
+      // * A fresh proof for the same nullifier
+      const fields = {
+        ...signedV4Result(AUTH_V4_CIRCUIT_ID, bitmapDomain).fields,
+        nullifier: first.fields.nullifier,
+      };
+      const message = createEd25519Message(fields);
+      try {
+        await submitSharded(fields, message, signMessage(authority, message));
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("DuplicateNullifier");
+      }
+
+      const session = signedV4Result(AUTH_V4_CIRCUIT_ID, bitmapDomain);
+      try {
+        await submitTypedVerifyAuth(session.fields, session.message, session.signature);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("WrongNullifierStore");
+      }
+    });
+
+    // * Test 104: verify_auth_sharded refuses a nullifier banned from the domain
+    it("should refuse a banned nullifier in its shard", async () => {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, bitmapDomain);
+      await program.methods
+        .banNullifier(Array.from(fields.nullifier))
+        .accounts({ domainConfig, owner: provider.wallet.publicKey })
+        .rpc();
+
+      try {
+        await submitSharded(fields, message, signature);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("NullifierBanned");
+      }
+    });
+  });
+
+  describe("protocol stats", () => {
+    const protocolStatsPda = PublicKey.findProgramAddressSync(
+      [Buffer.from("protocol_stats")],
+      VEILED_PROGRAM_ID
+    )[0];
+
+    // * Test 90: Verifications, grants and revocations add to the program-wide totals
+    it("should count verifications, grants and revocations", async () => {
+      const before = await program.account.protocolStats.fetchNullable(protocolStatsPda);
+      const total = (value: anchor.BN | undefined) => (value ?? new anchor.BN(0)).toNumber();
+
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+      await submitTypedVerifyAuth(fields, message, signature);
+
+      const appId = Keypair.generate().publicKey;
+      const permissionGrant = PublicKey.findProgramAddressSync(
+        [Buffer.from("permission"), Buffer.from(fields.nullifier), appId.toBuffer()],
+        VEILED_PROGRAM_ID
+      )[0];
+      await program.methods
+        .grantPermissions(
+          Array.from(fields.nullifier),
+          Array.from(fields.domain),
+          appId,
+          [{ revealWalletAddress: {} }],
+          { unixTime: { 0: new anchor.BN(3600) } }
+        )
+        .accounts({
+          domainConfig: domainConfigPda(fields.domain),
+          expiryHistogram: expiryHistogramPda(fields.domain),
+          payer: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      // * Revoking twice counts once
+      for (let i = 0; i < 2; i++) {
+        await program.methods
+          .revokePermissions()
+          .accounts({ permissionGrant, authority: authority.publicKey })
+          .signers([authority])
+          .rpc();
+      }
+
+      const after = await program.account.protocolStats.fetch(protocolStatsPda);
+      expect(after.totalVerifications.toNumber()).to.equal(total(before?.totalVerifications) + 1);
+      expect(after.totalGrants.toNumber()).to.equal(total(before?.totalGrants) + 1);
+      expect(after.totalRevocations.toNumber()).to.equal(total(before?.totalRevocations) + 1);
+      expect(after.totalAttestations.toNumber()).to.equal(total(before?.totalAttestations));
+    });
+  });
+
+  describe("aliases", () => {
+    // * Test 91: A handle resolves to the nullifier that claimed it, and can't be claimed twice
+    it("should map a claimed handle to its nullifier", async () => {
+      const handleHash = createHash("sha256").update(`user-${Date.now()}`).digest();
+      const [alias] = PublicKey.findProgramAddressSync(
+        [Buffer.from("alias"), handleHash],
+        VEILED_PROGRAM_ID
+      );
+      const claim = async () => {
+        const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+        const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+        await program.methods
+          .claimAlias(Array.from(handleHash))
+          .accounts({ nullifierAccount: nullifierPda, authority: authority.publicKey })
+          .signers([authority])
+          .rpc();
+        return fields.nullifier;
+      };
+
+      const nullifier = await claim();
+      const claimed = await program.account.alias.fetch(alias);
+      expect(Buffer.from(claimed.nullifier)).to.deep.equal(Buffer.from(nullifier));
+
+      try {
+        await claim();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("already in use");
+      }
+    });
+  });
+
+  describe("usernames", () => {
+    // * Test 92: A username is unique across case, moves with transfer and frees on release
+    it("should claim, transfer and release a case-insensitive username", async () => {
+      const suffix = Date.now().toString(36);
+      const [username] = PublicKey.findProgramAddressSync(
+        [Buffer.from("username"), Buffer.from(`alice_${suffix}`.padEnd(32, "\0"))],
+        VEILED_PROGRAM_ID
+      );
+      const newSession = async () => {
+        const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+        const session = await submitTypedVerifyAuth(fields, message, signature);
+        return { nullifier: fields.nullifier, session };
+      };
+      const claim = async (name: string, nullifierAccount: PublicKey) =>
+        program.methods
+          .claimUsername(name)
+          .accounts({ nullifierAccount, username, authority: authority.publicKey })
+          .signers([authority])
+          .rpc();
+
+      const first = await newSession();
+      await claim(`Alice_${suffix}`, first.session);
+      try {
+        await claim(`ALICE_${suffix}`, (await newSession()).session);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("already in use");
+      }
+
+      const second = await newSession();
+      await program.methods
+        .transferUsername()
+        .accounts({
+          username,
+          authority: authority.publicKey,
+          newSession: second.session,
+          newAuthority: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+      const moved = await program.account.username.fetch(username);
+      expect(Buffer.from(moved.nullifier)).to.deep.equal(Buffer.from(second.nullifier));
+      expect(moved.transferredAt.toNumber()).to.be.greaterThan(0);
+
+      await program.methods
+        .releaseUsername()
+        .accounts({ username, authority: authority.publicKey })
+        .signers([authority])
+        .rpc();
+      expect(await provider.connection.getAccountInfo(username)).to.be.null;
+    });
+  });
+
+  describe("guardian recovery", () => {
+    // * Helper: "veiled:recover_identity:v1" || instance_id || nullifier || new nullifier ||
+    // * new authority || nonce (u64 LE)
+    function recoveryMessage(
+      nullifier: Uint8Array,
+      newNullifier: Uint8Array,
+      newAuthority: PublicKey,
+      nonce: anchor.BN
+    ): Uint8Array {
+      return Buffer.concat([
+        Buffer.from("veiled:recover_identity:v1"),
+        program.programId.toBuffer(),
+        Buffer.from(nullifier),
+        Buffer.from(newNullifier),
+        newAuthority.toBuffer(),
+        nonce.toArrayLike(Buffer, "le", 8),
+      ]);
+    }
+
+    // * Helper: A session in `domain` guarded 2-of-3, and a funded new authority to recover it to
+    async function guardedSession(domain: Uint8Array = TEST_DOMAIN) {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, domain);
+      const oldSession = await submitTypedVerifyAuth(fields, message, signature);
+      const guardians = [Keypair.generate(), Keypair.generate(), Keypair.generate()];
+      const [guardianSet] = PublicKey.findProgramAddressSync(
+        [Buffer.from("guardian_set"), Buffer.from(fields.nullifier)],
+        VEILED_PROGRAM_ID
+      );
+      await program.methods
+        .setGuardians(guardians.map((guardian) => guardian.publicKey), 2)
+        .accounts({ nullifierAccount: oldSession, authority: authority.publicKey })
+        .signers([authority])
+        .rpc();
+      const { nonce } = await program.account.guardianSet.fetch(guardianSet);
+
+      const newAuthority = Keypair.generate();
+      const airdropSig = await provider.connection.requestAirdrop(
+        newAuthority.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropSig);
+      return { fields, oldSession, guardians, guardianSet, nonce, newAuthority };
+    }
+
+    // * Helper: Submit recover_identity with `signers`' approvals and a verifier-signed result
+    // * for newNullifier in the session's domain
+    function recover(
+      guarded: Awaited<ReturnType<typeof guardedSession>>,
+      newNullifier: Uint8Array,
+      signers: Keypair[],
+      pairs: PublicKey[]
+    ) {
+      const { fields, nonce, newAuthority } = guarded;
+      const approval = recoveryMessage(
+        fields.nullifier,
+        newNullifier,
+        newAuthority.publicKey,
+        nonce
+      );
+      const proven = {
+        ...signedV4Result(AUTH_V4_CIRCUIT_ID, fields.domain).fields,
+        nullifier: newNullifier,
+      };
+      const message = createEd25519Message(proven);
+      const signature = signMessage(authority, message);
+      return program.methods
+        .recoverIdentity(
+          createSignedResultArg(proven, signature),
+          Array.from(fields.nullifier),
+          Array.from(newNullifier),
+          Array.from(fields.domain)
+        )
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+          ...signers.map((guardian) =>
+            Ed25519Program.createInstructionWithPublicKey({
+              publicKey: guardian.publicKey.toBytes(),
+              message: approval,
+              signature: signMessage(guardian, approval),
+            })
+          ),
+        ])
+        .accounts({
+          proofRecord: PublicKey.findProgramAddressSync(
+            [Buffer.from("proof_record"), Buffer.from(proven.proofHash)],
+            VEILED_PROGRAM_ID
+          )[0],
+          resultReceipt: resultReceiptPda(signature),
+          newAuthority: newAuthority.publicKey,
+          verifier: authority.publicKey,
+          domainConfig: domainConfigPda(fields.domain),
+        })
+        .remainingAccounts(
+          pairs.map((pubkey) => ({ pubkey, isSigner: false, isWritable: true }))
+        )
+        .signers([newAuthority])
+        .rpc();
+    }
+
+    // * Test 93: Two of three guardians move a session and a grant to a new nullifier
+    it("should recover a session and grant with 2-of-3 guardian signatures", async () => {
+      const guarded = await guardedSession();
+      const { fields, oldSession, guardians, guardianSet, newAuthority } = guarded;
+      const appId = Keypair.generate().publicKey;
+      const grantPda = (nullifier: Uint8Array) =>
+        PublicKey.findProgramAddressSync(
+          [Buffer.from("permission"), Buffer.from(nullifier), appId.toBuffer()],
+          VEILED_PROGRAM_ID
+        )[0];
+      await program.methods
+        .grantPermissions(
+          Array.from(fields.nullifier),
+          Array.from(fields.domain),
+          appId,
+          [{ revealWalletAddress: {} }],
+          { unixTime: { 0: new anchor.BN(3600) } }
+        )
+        .accounts({
+          domainConfig: domainConfigPda(fields.domain),
+          expiryHistogram: expiryHistogramPda(fields.domain),
+          payer: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      const newNullifier = createHash("sha256").update(`recovered-${Date.now()}`).digest();
+      const newSession = sessionPda(newNullifier, fields.domain);
+      const pairs = [oldSession, newSession, grantPda(fields.nullifier), grantPda(newNullifier)];
+
+      try {
+        await recover(guarded, newNullifier, guardians.slice(0, 1), pairs);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("NotEnoughGuardianSignatures");
+      }
+
+      await recover(guarded, newNullifier, guardians.slice(1), pairs);
+      const recovered = await program.account.nullifierAccount.fetch(newSession);
+      expect(Buffer.from(recovered.nullifier)).to.deep.equal(newNullifier);
+      expect(recovered.authority.toBase58()).to.equal(newAuthority.publicKey.toBase58());
+      const grant = await program.account.permissionGrant.fetch(grantPda(newNullifier));
+      expect(grant.appId.toBase58()).to.equal(appId.toBase58());
+      expect(await provider.connection.getAccountInfo(oldSession)).to.be.null;
+      expect(await provider.connection.getAccountInfo(grantPda(fields.nullifier))).to.be.null;
+      expect(await provider.connection.getAccountInfo(guardianSet)).to.be.null;
+    });
+
+    // * Test 110: Guardians can't move a banned nullifier onto a fresh one
+    it("should refuse to recover a banned nullifier", async () => {
+      const recoveryDomain = Uint8Array.from(
+        domainToArray(`recover-${Date.now().toString(36)}`)
+      );
+      await program.methods
+        .initializeDomainConfig(Array.from(recoveryDomain))
+        .accounts({
+          domainConfig: domainConfigPda(recoveryDomain),
+          owner: provider.wallet.publicKey,
+        })
+        .rpc();
+      const guarded = await guardedSession(recoveryDomain);
+      const { fields, oldSession, guardians } = guarded;
+      await program.methods
+        .banNullifier(Array.from(fields.nullifier))
+        .accounts({
+          domainConfig: domainConfigPda(recoveryDomain),
+          owner: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      const newNullifier = createHash("sha256").update(`evading-${Date.now()}`).digest();
+      try {
+        await recover(guarded, newNullifier, guardians, [
+          oldSession,
+          sessionPda(newNullifier, recoveryDomain),
+        ]);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("NullifierBanned");
+      }
+      expect(await provider.connection.getAccountInfo(oldSession)).to.not.be.null;
+    });
+  });
+
+  describe("nullifier migration", () => {
+    // * Test 94: A session moves to a new nullifier and the old one links to it
+    it("should migrate a session to a new nullifier and record the link", async () => {
+      const old = signedV4Result(AUTH_V4_CIRCUIT_ID);
+      const oldSession = await submitTypedVerifyAuth(old.fields, old.message, old.signature);
+      const appId = Keypair.generate().publicKey;
+      const [permissionGrant] = PublicKey.findProgramAddressSync(
+        [Buffer.from("permission"), Buffer.from(old.fields.nullifier), appId.toBuffer()],
+        VEILED_PROGRAM_ID
+      );
+      await program.methods
+        .grantPermissions(
+          Array.from(old.fields.nullifier),
+          Array.from(old.fields.domain),
+          appId,
+          [{ revealWalletAddress: {} }],
+          { unixTime: { 0: new anchor.BN(3600) } }
+        )
+        .accounts({
+          domainConfig: domainConfigPda(old.fields.domain),
+          expiryHistogram: expiryHistogramPda(old.fields.domain),
+          payer: authority.publicKey,
+        })
+        .signers([authority])
+        .rpc();
+
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+      const newSession = sessionPda(fields.nullifier, fields.domain);
+      const [proofRecord] = PublicKey.findProgramAddressSync(
+        [Buffer.from("proof_record"), Buffer.from(fields.proofHash)],
+        VEILED_PROGRAM_ID
+      );
+      const [protocolStats] = PublicKey.findProgramAddressSync(
+        [Buffer.from("protocol_stats")],
+        VEILED_PROGRAM_ID
+      );
+      const before = await program.account.protocolStats.fetch(protocolStats);
+      await program.methods
+        .migrateNullifier(
+          createSignedResultArg(fields, signature),
+          Array.from(fields.nullifier),
+          new anchor.BN(0)
+        )
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: authority.publicKey.toBytes(),
+            message,
+            signature,
+          }),
+        ])
+        .accounts({
+          oldSession,
+          newSession,
+          proofRecord,
+          resultReceipt: resultReceiptPda(signature),
+          authority: authority.publicKey,
+          payer: authority.publicKey,
+          domainConfig: domainConfigPda(old.fields.domain),
+        })
+        .remainingAccounts([{ pubkey: permissionGrant, isSigner: false, isWritable: true }])
+        .signers([authority])
+        .rpc();
+
+      const [nullifierLink] = PublicKey.findProgramAddressSync(
+        [Buffer.from("nullifier_link"), Buffer.from(old.fields.nullifier)],
+        VEILED_PROGRAM_ID
+      );
+      const link = await program.account.nullifierLink.fetch(nullifierLink);
+      expect(Buffer.from(link.successor)).to.deep.equal(Buffer.from(fields.nullifier));
+      expect(Buffer.from(link.proofHash)).to.deep.equal(Buffer.from(fields.proofHash));
+      const session = await program.account.nullifierAccount.fetch(newSession);
+      expect(session.authority.toBase58()).to.equal(authority.publicKey.toBase58());
+      expect(await provider.connection.getAccountInfo(oldSession)).to.be.null;
+      // * The grant stays put, marked with the successor
+      const grant = await program.account.permissionGrant.fetch(permissionGrant);
+      expect(Buffer.from(grant.successor)).to.deep.equal(Buffer.from(fields.nullifier));
+      // * A migration counts as a verification, like any other new session
+      const after = await program.account.protocolStats.fetch(protocolStats);
+      expect(after.totalVerifications.toNumber()).to.equal(
+        before.totalVerifications.toNumber() + 1
+      );
+    });
+  });
+
+  describe("identity roots", () => {
+    // * Test 95: A root links sessions from two domains with its owner's signed consent
+    it("should link nullifiers from two domains to an identity root", async () => {
+      const owner = Keypair.generate();
+      const airdropSig = await provider.connection.requestAirdrop(
+        owner.publicKey,
+        anchor.web3.LAMPORTS_PER_SOL
+      );
+      await provider.connection.confirmTransaction(airdropSig);
+      const [identityRoot] = PublicKey.findProgramAddressSync(
+        [Buffer.from("identity_root"), owner.publicKey.toBuffer()],
+        VEILED_PROGRAM_ID
+      );
+      await program.methods
+        .createIdentityRoot()
+        .accounts({ owner: owner.publicKey })
+        .signers([owner])
+        .rpc();
+
+      const linkageProofHash = createHash("sha256").update("linkage-proof").digest();
+      for (const domain of [TEST_DOMAIN, Uint8Array.from(domainToArray("other.example"))]) {
+        const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, domain);
+        const nullifierAccount = await submitTypedVerifyAuth(fields, message, signature);
+        // * "veiled:identity_link:v1" || instance_id || root || nullifier || domain || hash
+        const link = Buffer.concat([
+          Buffer.from("veiled:identity_link:v1"),
+          program.programId.toBuffer(),
+          identityRoot.toBuffer(),
+          Buffer.from(fields.nullifier),
+          Buffer.from(domain),
+          linkageProofHash,
+        ]);
+        await program.methods
+          .linkIdentityRoot(Array.from(linkageProofHash))
+          .preInstructions([
+            Ed25519Program.createInstructionWithPublicKey({
+              publicKey: owner.publicKey.toBytes(),
+              message: link,
+              signature: signMessage(owner, link),
+            }),
+          ])
+          .accounts({ nullifierAccount, owner: owner.publicKey, authority: authority.publicKey })
+          .signers([authority])
+          .rpc();
+      }
+
+      const root = await program.account.identityRoot.fetch(identityRoot);
+      expect(root.members).to.have.length(2);
+      await program.methods
+        .unlinkIdentityRoot(root.members[0].nullifier)
+        .accounts({ owner: owner.publicKey })
+        .signers([owner])
+        .rpc();
+      expect((await program.account.identityRoot.fetch(identityRoot)).members).to.have.length(1);
+    });
+  });
+
+  describe("nullifier bans", () => {
+    const bannedDomain = Uint8Array.from(domainToArray(`ban-${Date.now().toString(36)}`));
+    const bannedDomainConfig = domainConfigPda(bannedDomain);
+
+    before(async () => {
+      await program.methods
+        .initializeDomainConfig(Array.from(bannedDomain))
+        .accounts({ domainConfig: domainConfigPda(bannedDomain), owner: provider.wallet.publicKey })
+        .rpc();
+    });
+
+    // * Helper: Ban a nullifier from bannedDomain
+    async function ban(nullifier: Uint8Array): Promise<void> {
+      await program.methods
+        .banNullifier(Array.from(nullifier))
+        .accounts({ domainConfig: bannedDomainConfig, owner: provider.wallet.publicKey })
+        .rpc();
+    }
+
+    // * Test 96: A banned nullifier can't register in the domain until its owner lifts the ban
+    it("should refuse a banned nullifier until it is unbanned", async () => {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, bannedDomain);
+      await ban(fields.nullifier);
+
+      try {
+        await submitTypedVerifyAuth(fields, message, signature);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("NullifierBanned");
+      }
+
+      const [nullifierBan] = PublicKey.findProgramAddressSync(
+        [Buffer.from("nullifier_ban"), Buffer.from(bannedDomain), Buffer.from(fields.nullifier)],
+        VEILED_PROGRAM_ID
+      );
+      await program.methods
+        .unbanNullifier()
+        .accounts({
+          domainConfig: bannedDomainConfig,
+          nullifierBan,
+          owner: provider.wallet.publicKey,
+        })
+        .rpc();
+      const session = await submitTypedVerifyAuth(fields, message, signature);
+      expect(await provider.connection.getAccountInfo(session)).to.not.be.null;
+    });
+
+    // * Test 103: One banned entry fails the whole batch
+    it("should refuse a batch with a banned entry", async () => {
+      const allowed = signedV4Result(AUTH_V4_CIRCUIT_ID);
+      const banned = signedV4Result(AUTH_V4_CIRCUIT_ID, bannedDomain);
+      await ban(banned.fields.nullifier);
+
+      try {
+        await submitVerifyAuthBatch([allowed, banned]);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("NullifierBanned");
+      }
+      const session = sessionPda(allowed.fields.nullifier, allowed.fields.domain);
+      expect(await provider.connection.getAccountInfo(session)).to.be.null;
+    });
+
+    // * Test 105: A ban stops the nullifier's existing session and its delegates until lifted
+    it("should refuse to validate a banned nullifier's session", async () => {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, bannedDomain);
+      const nullifierAccount = await submitTypedVerifyAuth(fields, message, signature);
+      const delegate = Keypair.generate();
+      const [sessionDelegate] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("session_delegate"),
+          nullifierAccount.toBuffer(),
+          delegate.publicKey.toBuffer(),
+        ],
+        VEILED_PROGRAM_ID
+      );
+      await program.methods
+        .delegateSession(delegate.publicKey, new anchor.BN(1 << 7), new anchor.BN(0))
+        .accounts({ nullifierAccount, authority: authority.publicKey })
+        .signers([authority])
+        .rpc();
+
+      const validateToken = () =>
+        program.methods.validateSessionToken().accounts({ nullifierAccount }).rpc();
+      const validateDelegate = () =>
+        program.methods
+          .validateSessionDelegate({ signTransactions: {} } as any)
+          .accounts({ sessionDelegate, nullifierAccount, delegate: delegate.publicKey })
+          .signers([delegate])
+          .rpc();
+
+      await ban(fields.nullifier);
+      for (const validate of [validateToken, validateDelegate]) {
+        try {
+          await validate();
+          expect.fail("Transaction should have failed");
+        } catch (error: any) {
+          expect(error.message).to.include("NullifierBanned");
+        }
+      }
+
+      const [nullifierBan] = PublicKey.findProgramAddressSync(
+        [Buffer.from("nullifier_ban"), Buffer.from(bannedDomain), Buffer.from(fields.nullifier)],
+        VEILED_PROGRAM_ID
+      );
+      await program.methods
+        .unbanNullifier()
+        .accounts({
+          domainConfig: bannedDomainConfig,
+          nullifierBan,
+          owner: provider.wallet.publicKey,
+        })
+        .rpc();
+      await validateToken();
+      await validateDelegate();
+    });
+
+    // * Test 106: verify_auth_groth16 refuses a banned nullifier before checking its proof
+    it("should refuse a banned nullifier on the Groth16 path", async () => {
+      // * A key that only has to hash to its circuit's vk_hash; the proof is never checked
+      const key = {
+        alphaG1: new Uint8Array(64).fill(1),
+        betaG2: new Uint8Array(128).fill(2),
+        gammaG2: new Uint8Array(128).fill(3),
+        deltaG2: new Uint8Array(128).fill(4),
+        ic: [1, 2, 3].map((point) => new Uint8Array(64).fill(point)),
+      };
+      const vkHash = createHash("sha256")
+        .update(Buffer.concat([key.alphaG1, key.betaG2, key.gammaG2, key.deltaG2, ...key.ic]))
+        .digest();
+      try {
+        await program.methods
+          .registerCircuit(GROTH16_CIRCUIT_ID, Array.from(vkHash))
+          .accounts({ authority: provider.wallet.publicKey })
+          .rpc();
+        await program.methods
+          .registerGroth16Vk(
+            GROTH16_CIRCUIT_ID,
+            Array.from(key.alphaG1),
+            Array.from(key.betaG2),
+            Array.from(key.gammaG2),
+            Array.from(key.deltaG2),
+            key.ic.map((point) => Array.from(point))
+          )
+          .accounts({ authority: provider.wallet.publicKey })
+          .rpc();
+      } catch {
+        // * Already registered
+      }
+
+      const nullifier = crypto.getRandomValues(new Uint8Array(32));
+      await ban(nullifier);
+      try {
+        await program.methods
+          .verifyAuthGroth16(
+            GROTH16_CIRCUIT_ID,
+            Array.from(new Uint8Array(256)),
+            [Array.from(nullifier), Array.from(new Uint8Array(32))],
+            Array.from(nullifier),
+            Array.from(bannedDomain)
+          )
+          .accounts({
+            nullifierAccount: sessionPda(nullifier, bannedDomain),
+            authority: provider.wallet.publicKey,
+            domainConfig: domainConfigPda(bannedDomain),
+          })
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("NullifierBanned");
+      }
+    });
+
+    // * Test 107: verify_auth_compressed refuses a banned nullifier before using its proof
+    it("should refuse a banned nullifier in a compressed store", async () => {
+      const compressedDomain = Uint8Array.from(domainToArray(`cban-${Date.now().toString(36)}`));
+      const domainConfig = domainConfigPda(compressedDomain);
+      await program.methods
+        .initializeDomainConfig(Array.from(compressedDomain))
+        .accounts({
+          domainConfig: domainConfigPda(compressedDomain),
+          owner: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      // * Depth 14, buffer 64, no canopy: header + sequence/index/size + changelog + rightmost path
+      const merkleTree = Keypair.generate();
+      const pathSize = 14 * 32 + 40;
+      const space = 56 + 24 + 64 * pathSize + pathSize;
+      await program.methods
+        .initNullifierTree(14, 64)
+        .accounts({
+          domainConfig,
+          merkleTree: merkleTree.publicKey,
+          owner: provider.wallet.publicKey,
+        })
+        .preInstructions([
+          SystemProgram.createAccount({
+            fromPubkey: provider.wallet.publicKey,
+            newAccountPubkey: merkleTree.publicKey,
+            lamports: await provider.connection.getMinimumBalanceForRentExemption(space),
+            space,
+            programId: COMPRESSION_PROGRAM_ID,
+          }),
+        ])
+        .signers([merkleTree])
+        .rpc();
+      await program.methods
+        .setDomainNullifierStore({ compressed: {} })
+        .accounts({ domainConfig, owner: provider.wallet.publicKey })
+        .rpc();
+
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, compressedDomain);
+      await program.methods
+        .banNullifier(Array.from(fields.nullifier))
+        .accounts({ domainConfig, owner: provider.wallet.publicKey })
+        .rpc();
+      try {
+        await program.methods
+          .verifyAuthCompressed(
+            createSignedResultArg(fields, signature),
+            Array.from(fields.nullifier),
+            Array.from(fields.domain),
+            {
+              root: Array.from(new Uint8Array(32)),
+              lowIndex: 0,
+              lowValue: Array.from(new Uint8Array(32)),
+              lowNextIndex: 0,
+              lowNextValue: Array.from(new Uint8Array(32)),
+            }
+          )
+          .preInstructions([
+            Ed25519Program.createInstructionWithPublicKey({
+              publicKey: authority.publicKey.toBytes(),
+              message,
+              signature,
+            }),
+          ])
+          .accounts({
+            domainConfig: domainConfigPda(fields.domain),
+            merkleTree: merkleTree.publicKey,
+            authority: authority.publicKey,
+          })
+          .signers([authority])
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("NullifierBanned");
+      }
+    });
+
+    // * Test 109: Migration can't move a session off a banned nullifier or onto one
+    it("should refuse to migrate from or to a banned nullifier", async () => {
+      for (const banned of ["old", "new"]) {
+        const old = signedV4Result(AUTH_V4_CIRCUIT_ID, bannedDomain);
+        const oldSession = await submitTypedVerifyAuth(old.fields, old.message, old.signature);
+        const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, bannedDomain);
+        await ban(banned === "old" ? old.fields.nullifier : fields.nullifier);
+
+        try {
+          await program.methods
+            .migrateNullifier(
+              createSignedResultArg(fields, signature),
+              Array.from(fields.nullifier),
+              new anchor.BN(0)
+            )
+            .preInstructions([
+              Ed25519Program.createInstructionWithPublicKey({
+                publicKey: authority.publicKey.toBytes(),
+                message,
+                signature,
+              }),
+            ])
+            .accounts({
+              oldSession,
+              newSession: sessionPda(fields.nullifier, bannedDomain),
+              proofRecord: PublicKey.findProgramAddressSync(
+                [Buffer.from("proof_record"), Buffer.from(fields.proofHash)],
+                VEILED_PROGRAM_ID
+              )[0],
+              resultReceipt: resultReceiptPda(signature),
+              authority: authority.publicKey,
+              payer: authority.publicKey,
+              domainConfig: bannedDomainConfig,
+            })
+            .signers([authority])
+            .rpc();
+          expect.fail("Transaction should have failed");
+        } catch (error: any) {
+          expect(error.message).to.include("NullifierBanned");
+        }
+      }
+    });
+  });
+
+  describe("domain registration", () => {
+    const registeredDomain = Uint8Array.from(domainToArray(`reg-${Date.now().toString(36)}`));
+
+    // * Test 97: register_domain sets the core settings, and verify_auth sessions follow them
+    it("should register a domain whose default expiry applies to new sessions", async () => {
+      await program.methods
+        .registerDomain(Array.from(registeredDomain), null, new anchor.BN(3600), 120, 5)
+        .accounts({
+          domainConfig: domainConfigPda(registeredDomain),
+          owner: provider.wallet.publicKey,
+        })
+        .rpc();
+
+      const domainConfig = domainConfigPda(registeredDomain);
+      const config = await program.account.domainConfig.fetch(domainConfig);
+      expect(config.owner.toBase58()).to.equal(provider.wallet.publicKey.toBase58());
+      expect(config.verifiers).to.have.length(0);
+      expect(config.defaultSessionSecs.toNumber()).to.equal(3600);
+      expect(config.maxSessionSecs.toNumber()).to.equal(0);
+      expect(config.maxResultAgeSecs).to.equal(120);
+      expect(config.maxPermissions).to.equal(5);
+
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, registeredDomain);
+      const session = await program.account.nullifierAccount.fetch(
+        await submitTypedVerifyAuth(fields, message, signature)
+      );
+      expect(session.expiresAt.unixTime[0].sub(session.createdAt).toNumber()).to.equal(3600);
+
+      // * The default isn't a cap: a session may still ask for longer
+      const longer = signedV4Result(AUTH_V4_CIRCUIT_ID, registeredDomain);
+      const longSession = await program.account.nullifierAccount.fetch(
+        await submitTypedVerifyAuth(longer.fields, longer.message, longer.signature, 7200)
+      );
+      expect(longSession.expiresAt.unixTime[0].sub(longSession.createdAt).toNumber()).to.equal(
+        7200
+      );
+
+      try {
+        await program.methods
+          .registerDomain(Array.from(registeredDomain), null, new anchor.BN(0), 0, 0)
+          .accounts({
+            domainConfig: domainConfigPda(registeredDomain),
+            owner: provider.wallet.publicKey,
+          })
+          .rpc();
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("already in use");
+      }
+    });
+  });
+
+  describe("SNS domain ownership", () => {
+    // * Test 98: A .sol domain can't be registered without its SNS name account
+    it("should refuse to register a .sol domain without its SNS name", async () => {
+      const solDomain = domainToArray(`sns-${Date.now().toString(36)}.sol`);
+      for (const snsNameAccount of [null, provider.wallet.publicKey]) {
+        try {
+          await program.methods
+            .registerDomain(solDomain, null, new anchor.BN(0), 0, 0)
+            .accounts({
+              domainConfig: domainConfigPda(Uint8Array.from(solDomain)),
+              owner: provider.wallet.publicKey,
+              snsNameAccount,
+            })
+            .rpc();
+          expect.fail("Transaction should have failed");
+        } catch (error: any) {
+          expect(error.message).to.include("InvalidSnsNameAccount");
+        }
+      }
+    });
+  });
+
+  describe("canonical domains", () => {
+    // * Test 99: Domains outside the canonical (lowercase) form are refused everywhere
+    it("should refuse non-canonical domains in verify_auth and the registry", async () => {
+      const upperDomain = Uint8Array.from(domainToArray("Test-Domain"));
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, upperDomain);
+      try {
+        await submitTypedVerifyAuth(fields, message, signature);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidDomain");
+      }
+
+      for (const domain of ["Canonical.example", "under_score", "empty..label"]) {
+        try {
+          await program.methods
+            .registerDomain(domainToArray(domain), null, new anchor.BN(0), 0, 0)
+            .accounts({
+              domainConfig: domainConfigPda(Uint8Array.from(domainToArray(domain))),
+              owner: provider.wallet.publicKey,
+            })
+            .rpc();
+          expect.fail("Transaction should have failed");
+        } catch (error: any) {
+          expect(error.message).to.include("InvalidDomain");
+        }
+      }
+    });
+  });
+
+  describe("slot expiry", () => {
+    const slotDomain = Uint8Array.from(domainToArray(`slot-${Date.now().toString(36)}`));
+
+    // * Test 100: A domain set to slot expiry issues sessions that expire by slot
+    it("should issue slot-based sessions once the domain switches units", async () => {
+      await program.methods
+        .registerDomain(Array.from(slotDomain), null, new anchor.BN(3600), 0, 0)
+        .accounts({ domainConfig: domainConfigPda(slotDomain), owner: provider.wallet.publicKey })
+        .rpc();
+      const domainConfig = domainConfigPda(slotDomain);
+      await program.methods
+        .setDomainExpiryUnit({ slot: {} })
+        .accounts({ domainConfig, owner: provider.wallet.publicKey })
+        .rpc();
+      const config = await program.account.domainConfig.fetch(domainConfig);
+      expect(config.sessionExpiryUnit).to.deep.equal({ slot: {} });
+
+      const slotBefore = await provider.connection.getSlot();
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID, slotDomain);
+      const session = await program.account.nullifierAccount.fetch(
+        await submitTypedVerifyAuth(fields, message, signature)
+      );
+      expect(session.expiresAt.unixTime).to.be.undefined;
+      // * 3600 seconds at 400 ms per slot
+      expect(session.expiresAt.slot[0].toNumber()).to.be.at.least(slotBefore + 9000);
+    });
+  });
+
+  describe("session gates", () => {
+    // * Test 101: Gates other than validate_session_token also refuse a logged-out session
+    it("should refuse a session revoked by global_logout at every gate", async () => {
+      const { fields, message, signature } = signedV4Result(AUTH_V4_CIRCUIT_ID);
+      const nullifierPda = await submitTypedVerifyAuth(fields, message, signature);
+      const renewable = signedV4Result(AUTH_V4_CIRCUIT_ID);
+      const renewablePda = await submitTypedVerifyAuth(
+        renewable.fields,
+        renewable.message,
+        renewable.signature
+      );
+      await program.methods
+        .globalLogout()
+        .accounts({ identity: authority.publicKey })
+        .signers([authority])
+        .rpc();
+
+      const gates = [
+        program.methods.touchSession(),
+        program.methods.delegateSession(
+          Keypair.generate().publicKey,
+          new anchor.BN(1 << 7),
+          new anchor.BN(0)
+        ),
+      ];
+      for (const gate of gates) {
+        try {
+          await gate
+            .accounts({ nullifierAccount: nullifierPda, authority: authority.publicKey })
+            .signers([authority])
+            .rpc();
+          expect.fail("Transaction should have failed");
+        } catch (error: any) {
+          expect(error.message).to.include("SessionRevoked");
+        }
+      }
+
+      // * A fresh proof can't renew it back either
+      try {
+        await renew(renewablePda, renewable.fields.nullifier, TEST_DOMAIN);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("SessionRevoked");
+      }
+    });
+
+    // * Test 108: A logged-out session doesn't hold its nullifier against a fresh sign-in
+    it("should let a user sign back in right after global_logout", async () => {
+      const first = signedV4Result(AUTH_V4_CIRCUIT_ID);
+      const nullifierPda = await submitTypedVerifyAuth(first.fields, first.message, first.signature);
+      await program.methods
+        .globalLogout()
+        .accounts({ identity: authority.publicKey })
+        .signers([authority])
+        .rpc();
+
+      // * Sessions created in the logout's own second count as logged out
+      await new Promise((resolve) => setTimeout(resolve, 1_100));
+      const fields = {
+        ...signedV4Result(AUTH_V4_CIRCUIT_ID).fields,
+        nullifier: first.fields.nullifier,
+      };
+      const message = createEd25519Message(fields);
+      await submitTypedVerifyAuth(fields, message, signMessage(authority, message));
+
+      const session = await program.account.nullifierAccount.fetch(nullifierPda);
+      expect(Buffer.from(session.proofHash)).to.deep.equal(Buffer.from(fields.proofHash));
+      await program.methods
+        .validateSessionToken()
+        .accounts({ nullifierAccount: nullifierPda })
+        .rpc();
+    });
+  });
+
+  describe("on-chain UltraHonk sessions", () => {
+    // * A 2^5-row circuit with the nullifier and domain as its only public inputs; bb appends the
+    // * 16-element pairing point object, and every proof is 456 field elements
+    const LOG_CIRCUIT_SIZE = 5;
+    const PUBLIC_INPUTS = 2;
+    const UPLOAD_LEN = PUBLIC_INPUTS * 32 + 456 * 32;
+    const CHUNK = 900;
+    const commitments = Array.from({ length: 27 }, (_, i) => new Uint8Array(64).fill(i + 1));
+    const honkDomain = Uint8Array.from(domainToArray(`honk-${Date.now().toString(36)}`));
+
+    // * Helper: sha256 over log_circuit_size || public_inputs_size || pub_inputs_offset ||
+    // * commitments, as HonkVerifyingKey::hash computes it
+    function honkVkHash(keyCommitments: Uint8Array[]): Buffer {
+      const header = Buffer.alloc(9);
+      header.writeUInt8(LOG_CIRCUIT_SIZE, 0);
+      header.writeUInt32LE(PUBLIC_INPUTS + 16, 1);
+      header.writeUInt32LE(1, 5);
+      return createHash("sha256")
+        .update(Buffer.concat([header, ...keyCommitments]))
+        .digest();
+    }
+
+    // * Helper: Store a key for a circuit in two write_honk_vk calls
+    async function registerHonkKey(circuitId: number, vkHash: Buffer) {
+      const stored = await program.account.honkVerifyingKey.fetchNullable(honkVkPda(circuitId));
+      if (stored?.written === 2 ** 27 - 1) {
+        return;
+      }
+      try {
+        await program.methods
+          .registerCircuit(circuitId, Array.from(vkHash))
+          .accounts({ authority: provider.wallet.publicKey })
+          .rpc();
+        await program.methods
+          .registerHonkVk(circuitId, LOG_CIRCUIT_SIZE, PUBLIC_INPUTS + 16, 1)
+          .accounts({ authority: provider.wallet.publicKey })
+          .rpc();
+      } catch {
+        // * Already registered
+      }
+      for (const offset of [0, 14]) {
+        await program.methods
+          .writeHonkVk(
+            circuitId,
+            offset,
+            commitments.slice(offset, offset + 14).map((point) => Array.from(point))
+          )
+          .accounts({ authority: provider.wallet.publicKey })
+          .rpc();
+      }
+    }
+
+    // * Helper: HonkVerifyingKey PDA [b"honk_vk", circuit_id (u32 LE)]
+    function honkVkPda(circuitId: number): PublicKey {
+      const id = Buffer.alloc(4);
+      id.writeUInt32LE(circuitId);
+      return PublicKey.findProgramAddressSync([Buffer.from("honk_vk"), id], VEILED_PROGRAM_ID)[0];
+    }
+
+    // * Helper: VerificationSession PDA [b"verification_session", authority, nullifier]
+    function verificationSessionPda(owner: PublicKey, nullifier: Uint8Array): PublicKey {
+      return PublicKey.findProgramAddressSync(
+        [Buffer.from("verification_session"), owner.toBuffer(), Buffer.from(nullifier)],
+        VEILED_PROGRAM_ID
+      )[0];
+    }
+
+    // * Helper: Open a session for an upload; proof bytes are never valid, which is enough
+    // * for everything short of a Verified session
+    async function openSession(upload: Buffer) {
+      const nullifier = crypto.getRandomValues(new Uint8Array(32));
+      await program.methods
+        .openVerificationSession(
+          HONK_CIRCUIT_ID,
+          Array.from(nullifier),
+          Array.from(honkDomain),
+          Array.from(createHash("sha256").update(upload).digest()),
+          PUBLIC_INPUTS
+        )
+        .accounts({
+          nullifierAccount: sessionPda(nullifier, honkDomain),
+          authority: provider.wallet.publicKey,
+          domainConfig: domainConfigPda(honkDomain),
+        })
+        .rpc();
+      return { nullifier, session: verificationSessionPda(provider.wallet.publicKey, nullifier) };
+    }
+
+    // * Helper: Upload bytes [from, end) as the session's authority, CHUNK bytes per call
+    async function uploadChunks(session: PublicKey, upload: Buffer, from = 0) {
+      for (let offset = from; offset < upload.length; offset += CHUNK) {
+        await program.methods
+          .uploadProofChunk(offset, upload.subarray(offset, offset + CHUNK))
+          .accounts({ verificationSession: session, authority: provider.wallet.publicKey })
+          .rpc();
+      }
+    }
+
+    // * Helper: An upload whose public inputs start with the nullifier
+    function uploadFor(nullifier: Uint8Array): Buffer {
+      const upload = Buffer.alloc(UPLOAD_LEN);
+      upload.set(nullifier, 0);
+      return upload;
+    }
+
+    before(async () => {
+      await registerHonkKey(HONK_CIRCUIT_ID, honkVkHash(commitments));
+    });
+
+    // * Test 111: A key is usable once every commitment is written, and only if it hashes to
+    // * the circuit's vk_hash
+    it("should store an UltraHonk key only if it matches the circuit's vk_hash", async () => {
+      const vk = await program.account.honkVerifyingKey.fetch(honkVkPda(HONK_CIRCUIT_ID));
+      expect(vk.written).to.equal(2 ** 27 - 1);
+
+      const otherHash = honkVkHash(commitments.map(() => new Uint8Array(64)));
+      try {
+        await registerHonkKey(MISMATCHED_HONK_CIRCUIT_ID, otherHash);
+        expect.fail("Transaction should have failed");
+      } catch (error: any) {
+        expect(error.message).to.include("InvalidHonkVk");
+      }
+    });
+
+    // * Test 112: Chunks arrive in order, the upload must match its committed hash, and each
+    // * instruction only runs in its own session state
+    it("should accept an upload only in order and matching its hash", async () => {
+      const upload = Buffer.alloc(UPLOAD_LEN);
+      const { nullifier, session } = await openSession(upload);
+      const expectStatusError = async (call: Promise<unknown>, code: string) => {
+        try {
+          await call;
+          expect.fail("Transaction should have failed");
+        } catch (error: any) {
+          expect(error.message).to.include(code);
+        }
+      };
+
+      await expectStatusError(
+        program.methods
+          .advanceVerificationSession(1)
+          .accountsPartial({ verificationSession: session, honkVk: honkVkPda(HONK_CIRCUIT_ID) })
+          .rpc(),
+        "InvalidSessionStatus"
+      );
+      await expectStatusError(uploadChunks(session, upload, CHUNK), "ProofChunkOutOfOrder");
+
+      // * A last chunk that changes the committed upload is refused, the right one completes it
+      const last = Math.floor((UPLOAD_LEN - 1) / CHUNK) * CHUNK;
+      await uploadChunks(session, upload.subarray(0, last));
+      await expectStatusError(
+        uploadChunks(session, Buffer.alloc(UPLOAD_LEN, 1), last),
+        "ProofHashMismatch"
+      );
+      await uploadChunks(session, upload, last);
+
+      const opened = await program.account.verificationSession.fetch(session);
+      expect(opened.bytesReceived).to.equal(UPLOAD_LEN);
+      expect(opened.status).to.deep.equal({ verifying: {} });
+      await expectStatusError(
+        program.methods
+          .finalizeVerificationSession()
+          .accounts({
+            verificationSession: session,
+            nullifierAccount: sessionPda(nullifier, honkDomain),
+            authority: provider.wallet.publicKey,
+            domainConfig: domainConfigPda(honkDomain),
+          })
+          .rpc(),
+        "InvalidSessionStatus"
+      );
+    });
+
+    // * Test 113: A proof whose first public input isn't the session's nullifier is rejected at
+    // * the first verifier step
+    it("should reject a proof that doesn't bind the session's nullifier", async () => {
+      const upload = uploadFor(new Uint8Array(32).fill(1));
+      const { session } = await openSession(upload);
+      await uploadChunks(session, upload);
+
+      await program.methods
+        .advanceVerificationSession(4)
+        .accountsPartial({ verificationSession: session, honkVk: honkVkPda(HONK_CIRCUIT_ID) })
+        .rpc();
+      const rejected = await program.account.verificationSession.fetch(session);
+      expect(rejected.status).to.deep.equal({ rejected: {} });
+      expect(rejected.step).to.equal(0);
+    });
+
+    // * Test 114: Only the session's authority reaches it, and closing refunds all its rent
+    it("should let only the session's authority upload to or close it", async () => {
+      const { nullifier, session } = await openSession(Buffer.alloc(UPLOAD_LEN));
+      await uploadChunks(session, uploadFor(nullifier).subarray(0, 2 * CHUNK));
+
+      const stranger = Keypair.generate();
+      for (const call of [
+        program.methods.uploadProofChunk(2 * CHUNK, Buffer.alloc(CHUNK)),
+        program.methods.closeVerificationSession(),
+      ]) {
+        try {
+          await call
+            .accounts({ verificationSession: session, authority: stranger.publicKey })
+            .signers([stranger])
+            .rpc();
+          expect.fail("Transaction should have failed");
+        } catch (error: any) {
+          expect(error.message).to.include("ConstraintSeeds");
+        }
+      }
+
+      const rent = await provider.connection.getBalance(session);
+      const before = await provider.connection.getBalance(provider.wallet.publicKey);
+      await program.methods
+        .closeVerificationSession()
+        .accounts({ verificationSession: session, authority: provider.wallet.publicKey })
+        .rpc();
+      const after = await provider.connection.getBalance(provider.wallet.publicKey);
+      expect(await program.account.verificationSession.fetchNullable(session)).to.equal(null);
+      // * Less the transaction fee
+      expect(after).to.be.greaterThan(before + rent - 10_000);
+    });
+  });
 });
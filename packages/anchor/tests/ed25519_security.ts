@@ -27,42 +27,65 @@ import {
   Transaction,
 } from "@solana/web3.js";
 import nacl from "tweetnacl";
+import { createHash } from "crypto";
 import { expect } from "chai";
 import type { Veiled } from "../target/types/veiled";
 
 // * Program ID will be loaded from workspace
 
-// * Helper: Create verification result data
-// * Format: [1 byte: is_valid] [32 bytes: proof_hash] [8 bytes: timestamp] [64 bytes: signature]
+// * Fields covered by the verifier signature
+interface VerificationFields {
+  isValid: boolean;
+  proofHash: Uint8Array;
+  timestamp: number;
+  nullifier: Uint8Array;
+  domain: Uint8Array;
+}
+
+// * Extension every result here carries after the V1 body: no public inputs, circuit 0 with the
+// * VK hash the other suites register for it, this build's cluster and program, Auth claim
+// * Format: public_inputs_hash (32) || circuit_id (4) || vk_hash (32) || cluster_id (32) || instance_id (32) || claim_type (1)
+function resultExtension(): Uint8Array {
+  const extension = new Uint8Array(133);
+  extension.set(new Uint8Array(32).fill(1), 36);
+  extension.set(new TextEncoder().encode("5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp"), 68); // * Mainnet-beta
+  extension.set((anchor.workspace.Veiled as Program<Veiled>).programId.toBytes(), 100);
+  return extension;
+}
+
+// * Helper: Create verification result data (V9, the only envelope verify_auth_v1 accepts)
+// * Format: [1 byte: version = 9] [1 byte: is_valid] [32 bytes: proof_hash] [8 bytes: timestamp] [32 bytes: nullifier] [32 bytes: domain] [133 bytes: extension] [64 bytes: signature]
 function createVerificationResult(
-  isValid: boolean,
-  proofHash: Uint8Array,
-  timestamp: number,
+  fields: VerificationFields,
   signature: Uint8Array
 ): Uint8Array {
-  const result = new Uint8Array(105);
-  result[0] = isValid ? 1 : 0;
-  result.set(proofHash, 1);
+  const result = new Uint8Array(303);
+  result[0] = 9;
+  result[1] = fields.isValid ? 1 : 0;
+  result.set(fields.proofHash, 2);
   const timestampBytes = new Uint8Array(8);
-  new DataView(timestampBytes.buffer).setBigUint64(0, BigInt(timestamp), true); // * Little-endian
-  result.set(timestampBytes, 33);
-  result.set(signature, 41);
+  new DataView(timestampBytes.buffer).setBigUint64(0, BigInt(fields.timestamp), true); // * Little-endian
+  result.set(timestampBytes, 34);
+  result.set(fields.nullifier, 42);
+  result.set(fields.domain, 74);
+  result.set(resultExtension(), 106);
+  result.set(signature, 239);
   return result;
 }
 
 // * Helper: Create Ed25519 signed message
-// * Message format: proof_hash (32) || is_valid (1) || timestamp (8) = 41 bytes
-function createEd25519Message(
-  proofHash: Uint8Array,
-  isValid: boolean,
-  timestamp: number
-): Uint8Array {
-  const message = new Uint8Array(41);
-  message.set(proofHash, 0);
-  message[32] = isValid ? 1 : 0;
+// * Message format: "veiled:v1" (9) || proof_hash (32) || is_valid (1) || timestamp (8) || nullifier (32) || domain (32) || extension (133) = 247 bytes
+function createEd25519Message(fields: VerificationFields): Uint8Array {
+  const message = new Uint8Array(247);
+  message.set(new TextEncoder().encode("veiled:v1"), 0);
+  message.set(fields.proofHash, 9);
+  message[41] = fields.isValid ? 1 : 0;
   const timestampBytes = new Uint8Array(8);
-  new DataView(timestampBytes.buffer).setBigUint64(0, BigInt(timestamp), true); // * Little-endian
-  message.set(timestampBytes, 33);
+  new DataView(timestampBytes.buffer).setBigUint64(0, BigInt(fields.timestamp), true); // * Little-endian
+  message.set(timestampBytes, 42);
+  message.set(fields.nullifier, 50);
+  message.set(fields.domain, 82);
+  message.set(resultExtension(), 114);
   return message;
 }
 
@@ -85,6 +108,9 @@ function domainToArray(domainStr: string): number[] {
   return Array.from(domain);
 }
 
+// * Domain every test result is signed for
+const TEST_DOMAIN = Uint8Array.from(domainToArray("test-domain"));
+
 describe("Ed25519 Security Tests", () => {
   // * Use Anchor's built-in provider (from Anchor.toml or environment)
   // * anchor test automatically sets up provider and workspace
@@ -97,6 +123,34 @@ describe("Ed25519 Security Tests", () => {
   // * Program ID from workspace
   const VEILED_PROGRAM_ID = program.programId;
 
+  // * Helper: ProofRecord PDA for a serialized result (keyed by its proof_hash)
+  function proofRecordPda(verificationResult: Uint8Array): PublicKey {
+    // * Legacy bodies start with is_valid (0/1), versioned envelopes with the version byte
+    const offset = verificationResult[0] <= 1 ? 1 : 2;
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("proof_record"), Buffer.from(verificationResult.subarray(offset, offset + 32))],
+      VEILED_PROGRAM_ID
+    )[0];
+  }
+
+  // * Helper: ResultReceipt PDA for a serialized result or a bare signature (keyed by
+  // * sha256 of the trailing 64-byte signature)
+  function resultReceiptPda(signed: Uint8Array): PublicKey {
+    const signature = signed.subarray(signed.length - 64);
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("result_receipt"), createHash("sha256").update(signature).digest()],
+      VEILED_PROGRAM_ID
+    )[0];
+  }
+
+  // * Helper: DomainConfig PDA (keyed by sha256 of the padded domain)
+  function domainConfigPda(domain: Uint8Array): PublicKey {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("domain_config"), createHash("sha256").update(domain).digest()],
+      VEILED_PROGRAM_ID
+    )[0];
+  }
+
   // * Create test authority keypair
   const authority = Keypair.generate();
 
@@ -111,6 +165,27 @@ describe("Ed25519 Security Tests", () => {
     } catch (error) {
       console.warn("Could not airdrop SOL:", error);
     }
+
+    // * verify_auth only accepts results signed by allowlisted verifiers
+    try {
+      await program.methods
+        .initializeConfig()
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    } catch {
+      // * Already initialized
+    }
+    await program.methods
+      .addVerifier(authority.publicKey)
+      .accounts({ authority: provider.wallet.publicKey })
+      .rpc();
+  });
+
+  after(async () => {
+    await program.methods
+      .removeVerifier(authority.publicKey)
+      .accounts({ authority: provider.wallet.publicKey })
+      .rpc();
   });
 
   // * Test 1: Valid Signature (Success Path)
@@ -119,16 +194,16 @@ describe("Ed25519 Security Tests", () => {
     crypto.getRandomValues(proofHash);
     const timestamp = Math.floor(Date.now() / 1000);
     const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
 
     // * Create message and sign it
-    const message = createEd25519Message(proofHash, isValid, timestamp);
+    const message = createEd25519Message({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN });
     const signature = signMessage(authority, message);
 
     // * Create verification result
     const verificationResult = createVerificationResult(
-      isValid,
-      proofHash,
-      timestamp,
+      { isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN },
       signature
     );
 
@@ -140,8 +215,6 @@ describe("Ed25519 Security Tests", () => {
     });
 
     // * Create nullifier
-    const nullifier = new Uint8Array(32);
-    crypto.getRandomValues(nullifier);
     // * Convert to fixed-size array for Anchor
     const nullifierArray = Array.from(nullifier) as number[] & { length: 32 };
     
@@ -153,19 +226,22 @@ describe("Ed25519 Security Tests", () => {
 
     // * Call verify_auth with Ed25519 instruction as pre-instruction
     const txSignature = await program.methods
-      .verifyAuth(
+      .verifyAuthV1(
         Buffer.from(verificationResult),
         nullifierArray,
-        domainToArray("test-domain")
+        Array.from(TEST_DOMAIN)
       )
       .preInstructions([ed25519Ix])
       .accounts({
         nullifierAccount: nullifierPda,
+        proofRecord: proofRecordPda(verificationResult),
+        resultReceipt: resultReceiptPda(verificationResult),
         authority: authority.publicKey,
         instructionsSysvar: new PublicKey(
           "Sysvar1nstructions1111111111111111111111111"
         ),
         systemProgram: SystemProgram.programId,
+        domainConfig: domainConfigPda(TEST_DOMAIN),
       })
       .signers([authority])
       .rpc();
@@ -181,14 +257,14 @@ describe("Ed25519 Security Tests", () => {
     crypto.getRandomValues(proofHash);
     const timestamp = Math.floor(Date.now() / 1000);
     const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
 
-    const message = createEd25519Message(proofHash, isValid, timestamp);
+    const message = createEd25519Message({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN });
     const signature = signMessage(authority, message);
 
     const verificationResult = createVerificationResult(
-      isValid,
-      proofHash,
-      timestamp,
+      { isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN },
       signature
     );
 
@@ -198,8 +274,6 @@ describe("Ed25519 Security Tests", () => {
       signature: signature,
     });
 
-    const nullifier = new Uint8Array(32);
-    crypto.getRandomValues(nullifier);
     const [nullifierPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("nullifier")],
       VEILED_PROGRAM_ID
@@ -207,18 +281,21 @@ describe("Ed25519 Security Tests", () => {
 
     // * Create transaction with WRONG order: program instruction first, then Ed25519
     const programIx = await program.methods
-      .verifyAuth(
+      .verifyAuthV1(
         Buffer.from(verificationResult),
         Array.from(nullifier),
-        domainToArray("test-domain")
+        Array.from(TEST_DOMAIN)
       )
       .accounts({
         nullifierAccount: nullifierPda,
+        proofRecord: proofRecordPda(verificationResult),
+        resultReceipt: resultReceiptPda(verificationResult),
         authority: authority.publicKey,
         instructionsSysvar: new PublicKey(
           "Sysvar1nstructions1111111111111111111111111"
         ),
         systemProgram: SystemProgram.programId,
+        domainConfig: domainConfigPda(TEST_DOMAIN),
       })
       .instruction();
 
@@ -242,16 +319,16 @@ describe("Ed25519 Security Tests", () => {
     crypto.getRandomValues(wrongProofHash);
     const timestamp = Math.floor(Date.now() / 1000);
     const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
 
     // * Sign with correct message
-    const correctMessage = createEd25519Message(proofHash, isValid, timestamp);
+    const correctMessage = createEd25519Message({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN });
     const signature = signMessage(authority, correctMessage);
 
     // * But create verification result with wrong proof hash
     const verificationResult = createVerificationResult(
-      isValid,
-      wrongProofHash, // * Wrong proof hash!
-      timestamp,
+      { isValid, proofHash: wrongProofHash, timestamp, nullifier, domain: TEST_DOMAIN }, // * Wrong proof hash!
       signature
     );
 
@@ -261,8 +338,6 @@ describe("Ed25519 Security Tests", () => {
       signature: signature,
     });
 
-    const nullifier = new Uint8Array(32);
-    crypto.getRandomValues(nullifier);
     const [nullifierPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("nullifier")],
       VEILED_PROGRAM_ID
@@ -271,19 +346,22 @@ describe("Ed25519 Security Tests", () => {
     // * Should fail because proof hash in verification result doesn't match message
     try {
       await program.methods
-        .verifyAuth(
+        .verifyAuthV1(
           Buffer.from(verificationResult),
           Array.from(nullifier),
-          domainToArray("test-domain")
+          Array.from(TEST_DOMAIN)
         )
         .preInstructions([ed25519Ix])
         .accounts({
           nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(verificationResult),
+          resultReceipt: resultReceiptPda(verificationResult),
           authority: authority.publicKey,
           instructionsSysvar: new PublicKey(
             "Sysvar1nstructions1111111111111111111111111"
           ),
           systemProgram: SystemProgram.programId,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
         })
         .signers([authority])
         .rpc();
@@ -300,18 +378,18 @@ describe("Ed25519 Security Tests", () => {
     crypto.getRandomValues(proofHash);
     const timestamp = Math.floor(Date.now() / 1000);
     const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
 
     // * Create different authority
     const wrongAuthority = Keypair.generate();
 
-    const message = createEd25519Message(proofHash, isValid, timestamp);
+    const message = createEd25519Message({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN });
     // * Sign with wrong authority
     const signature = signMessage(wrongAuthority, message);
 
     const verificationResult = createVerificationResult(
-      isValid,
-      proofHash,
-      timestamp,
+      { isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN },
       signature
     );
 
@@ -322,8 +400,6 @@ describe("Ed25519 Security Tests", () => {
       signature: signature,
     });
 
-    const nullifier = new Uint8Array(32);
-    crypto.getRandomValues(nullifier);
     const [nullifierPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("nullifier")],
       VEILED_PROGRAM_ID
@@ -332,19 +408,22 @@ describe("Ed25519 Security Tests", () => {
     // * Should fail because authority doesn't match
     try {
       await program.methods
-        .verifyAuth(
+        .verifyAuthV1(
           Buffer.from(verificationResult),
           Array.from(nullifier),
-          domainToArray("test-domain")
+          Array.from(TEST_DOMAIN)
         )
         .preInstructions([ed25519Ix])
         .accounts({
           nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(verificationResult),
+          resultReceipt: resultReceiptPda(verificationResult),
           authority: authority.publicKey, // * Different from signer!
           instructionsSysvar: new PublicKey(
             "Sysvar1nstructions1111111111111111111111111"
           ),
           systemProgram: SystemProgram.programId,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
         })
         .signers([authority])
         .rpc();
@@ -361,16 +440,16 @@ describe("Ed25519 Security Tests", () => {
     crypto.getRandomValues(proofHash);
     const timestamp = Math.floor(Date.now() / 1000);
     const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
 
-    const message = createEd25519Message(proofHash, isValid, timestamp);
+    const message = createEd25519Message({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN });
     // * Create invalid signature (random bytes)
     const invalidSignature = new Uint8Array(64);
     crypto.getRandomValues(invalidSignature);
 
     const verificationResult = createVerificationResult(
-      isValid,
-      proofHash,
-      timestamp,
+      { isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN },
       invalidSignature
     );
 
@@ -380,8 +459,6 @@ describe("Ed25519 Security Tests", () => {
       signature: invalidSignature, // * Invalid signature
     });
 
-    const nullifier = new Uint8Array(32);
-    crypto.getRandomValues(nullifier);
     const [nullifierPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("nullifier")],
       VEILED_PROGRAM_ID
@@ -390,19 +467,22 @@ describe("Ed25519 Security Tests", () => {
     // * Should fail because Ed25519Program will reject invalid signature
     try {
       await program.methods
-        .verifyAuth(
+        .verifyAuthV1(
           Buffer.from(verificationResult),
           Array.from(nullifier),
-          domainToArray("test-domain")
+          Array.from(TEST_DOMAIN)
         )
         .preInstructions([ed25519Ix])
         .accounts({
           nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(verificationResult),
+          resultReceipt: resultReceiptPda(verificationResult),
           authority: authority.publicKey,
           instructionsSysvar: new PublicKey(
             "Sysvar1nstructions1111111111111111111111111"
           ),
           systemProgram: SystemProgram.programId,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
         })
         .signers([authority])
         .rpc();
@@ -420,14 +500,14 @@ describe("Ed25519 Security Tests", () => {
     // * Timestamp from 10 minutes ago (expired)
     const expiredTimestamp = Math.floor(Date.now() / 1000) - 10 * 60;
     const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
 
-    const message = createEd25519Message(proofHash, isValid, expiredTimestamp);
+    const message = createEd25519Message({ isValid, proofHash, timestamp: expiredTimestamp, nullifier, domain: TEST_DOMAIN });
     const signature = signMessage(authority, message);
 
     const verificationResult = createVerificationResult(
-      isValid,
-      proofHash,
-      expiredTimestamp,
+      { isValid, proofHash, timestamp: expiredTimestamp, nullifier, domain: TEST_DOMAIN },
       signature
     );
 
@@ -437,8 +517,6 @@ describe("Ed25519 Security Tests", () => {
       signature: signature,
     });
 
-    const nullifier = new Uint8Array(32);
-    crypto.getRandomValues(nullifier);
     const [nullifierPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("nullifier")],
       VEILED_PROGRAM_ID
@@ -447,19 +525,22 @@ describe("Ed25519 Security Tests", () => {
     // * Should fail because timestamp is expired (>5 minutes old)
     try {
       await program.methods
-        .verifyAuth(
+        .verifyAuthV1(
           Buffer.from(verificationResult),
           Array.from(nullifier),
-          domainToArray("test-domain")
+          Array.from(TEST_DOMAIN)
         )
         .preInstructions([ed25519Ix])
         .accounts({
           nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(verificationResult),
+          resultReceipt: resultReceiptPda(verificationResult),
           authority: authority.publicKey,
           instructionsSysvar: new PublicKey(
             "Sysvar1nstructions1111111111111111111111111"
           ),
           systemProgram: SystemProgram.programId,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
         })
         .signers([authority])
         .rpc();
@@ -476,14 +557,14 @@ describe("Ed25519 Security Tests", () => {
     crypto.getRandomValues(proofHash);
     const timestamp = Math.floor(Date.now() / 1000);
     const isValid = true;
+    const nullifier = new Uint8Array(32);
+    crypto.getRandomValues(nullifier);
 
-    const message = createEd25519Message(proofHash, isValid, timestamp);
+    const message = createEd25519Message({ isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN });
     const signature = signMessage(authority, message);
 
     const verificationResult = createVerificationResult(
-      isValid,
-      proofHash,
-      timestamp,
+      { isValid, proofHash, timestamp, nullifier, domain: TEST_DOMAIN },
       signature
     );
 
@@ -493,8 +574,6 @@ describe("Ed25519 Security Tests", () => {
       signature: signature,
     });
 
-    const nullifier = new Uint8Array(32);
-    crypto.getRandomValues(nullifier);
     const nullifierArray = Array.from(nullifier) as number[] & { length: 32 };
     const [nullifierPda] = PublicKey.findProgramAddressSync(
       [Buffer.from("nullifier"), authority.publicKey.toBuffer()],
@@ -503,19 +582,22 @@ describe("Ed25519 Security Tests", () => {
 
     // * First use - should succeed
     const tx1 = await program.methods
-      .verifyAuth(
+      .verifyAuthV1(
         Buffer.from(verificationResult),
         nullifierArray,
-        domainToArray("test-domain")
+        Array.from(TEST_DOMAIN)
       )
       .preInstructions([ed25519Ix])
       .accounts({
         nullifierAccount: nullifierPda,
+        proofRecord: proofRecordPda(verificationResult),
+        resultReceipt: resultReceiptPda(verificationResult),
         authority: authority.publicKey,
         instructionsSysvar: new PublicKey(
           "Sysvar1nstructions1111111111111111111111111"
         ),
         systemProgram: SystemProgram.programId,
+        domainConfig: domainConfigPda(TEST_DOMAIN),
       })
       .signers([authority])
       .rpc();
@@ -525,12 +607,10 @@ describe("Ed25519 Security Tests", () => {
     // * Create new verification result for second attempt
     const proofHash2 = new Uint8Array(32);
     crypto.getRandomValues(proofHash2);
-    const message2 = createEd25519Message(proofHash2, isValid, timestamp);
+    const message2 = createEd25519Message({ isValid, proofHash: proofHash2, timestamp, nullifier, domain: TEST_DOMAIN });
     const signature2 = signMessage(authority, message2);
     const verificationResult2 = createVerificationResult(
-      isValid,
-      proofHash2,
-      timestamp,
+      { isValid, proofHash: proofHash2, timestamp, nullifier, domain: TEST_DOMAIN },
       signature2
     );
     const ed25519Ix2 = Ed25519Program.createInstructionWithPublicKey({
@@ -542,19 +622,22 @@ describe("Ed25519 Security Tests", () => {
     // * Second use with same nullifier - should fail
     try {
       await program.methods
-        .verifyAuth(
+        .verifyAuthV1(
           Buffer.from(verificationResult2),
           nullifierArray, // * Same nullifier!
-          domainToArray("test-domain")
+          Array.from(TEST_DOMAIN)
         )
         .preInstructions([ed25519Ix2])
         .accounts({
           nullifierAccount: nullifierPda,
+          proofRecord: proofRecordPda(verificationResult2),
+          resultReceipt: resultReceiptPda(verificationResult2),
           authority: authority.publicKey,
           instructionsSysvar: new PublicKey(
             "Sysvar1nstructions1111111111111111111111111"
           ),
           systemProgram: SystemProgram.programId,
+          domainConfig: domainConfigPda(TEST_DOMAIN),
         })
         .signers([authority])
         .rpc();
@@ -0,0 +1,254 @@
+// * Gated Counter Example Tests
+// *
+// * Exercises examples/gated-counter, which gates `increment` on Veiled's
+// * validate_session_token and try_check_permission via CPI.
+// *
+// * Test Cases:
+// * 1. Increment with a live session and a grant holding the required permission
+// * 2. Reject a grant missing the required permission
+// * 3. Reject a grant issued to another app
+// * 4. Reject a session revoked by a global logout
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Ed25519Program, Keypair, PublicKey } from "@solana/web3.js";
+import * as nacl from "tweetnacl";
+import { createHash } from "crypto";
+import { expect } from "chai";
+import type { Veiled } from "../target/types/veiled";
+import type { GatedCounter } from "../target/types/gated_counter";
+
+describe("Gated Counter Example", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const veiled = anchor.workspace.Veiled as Program<Veiled>;
+  const counterProgram = anchor.workspace.GatedCounter as Program<GatedCounter>;
+
+  // * Verifier that signs and submits the sessions (becomes each session's authority)
+  const verifier = Keypair.generate();
+  const appId = Keypair.generate().publicKey;
+  const domain = new Uint8Array(32);
+  domain.set(Buffer.from("gated-counter.example"));
+
+  const [counterPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("counter"), appId.toBuffer()],
+    counterProgram.programId
+  );
+
+  function veiledPda(...seeds: Buffer[]): PublicKey {
+    return PublicKey.findProgramAddressSync(seeds, veiled.programId)[0];
+  }
+
+  before(async () => {
+    const airdropSig = await provider.connection.requestAirdrop(
+      verifier.publicKey,
+      2 * anchor.web3.LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropSig);
+
+    // * Legacy results map to circuit 0; config and circuit may already exist from other suites
+    try {
+      await veiled.methods
+        .initializeConfig()
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    } catch {
+      // * Already initialized
+    }
+    try {
+      await veiled.methods
+        .registerCircuit(0, Array.from(new Uint8Array(32).fill(1)))
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    } catch {
+      // * Already registered
+    }
+    await veiled.methods
+      .addVerifier(verifier.publicKey)
+      .accounts({ authority: provider.wallet.publicKey })
+      .rpc();
+
+    await counterProgram.methods
+      .initialize(appId)
+      .accounts({ payer: provider.wallet.publicKey })
+      .rpc();
+  });
+
+  after(async () => {
+    await veiled.methods
+      .removeVerifier(verifier.publicKey)
+      .accounts({ authority: provider.wallet.publicKey })
+      .rpc();
+  });
+
+  // * Helper: Open a Veiled session for a fresh nullifier via verify_auth_v1 (V9 result)
+  async function createSession(): Promise<{ nullifier: Uint8Array; session: PublicKey }> {
+    const nullifier = crypto.getRandomValues(new Uint8Array(32));
+    const proofHash = crypto.getRandomValues(new Uint8Array(32));
+    const timestamp = new Uint8Array(8);
+    new DataView(timestamp.buffer).setBigUint64(0, BigInt(Math.floor(Date.now() / 1000)), true);
+
+    // * public_inputs_hash || circuit_id (0) || vk_hash (circuit 0's) || cluster_id || instance_id || claim_type (Auth)
+    const extension = Buffer.concat([
+      new Uint8Array(32),
+      new Uint8Array(4),
+      new Uint8Array(32).fill(1),
+      Buffer.from("5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp"), // * Mainnet-beta (non-devnet builds)
+      veiled.programId.toBuffer(),
+      Buffer.from([0]),
+    ]);
+    // * "veiled:v1" || proof_hash || is_valid || timestamp || nullifier || domain || extension
+    const message = Buffer.concat([
+      Buffer.from("veiled:v1"),
+      proofHash,
+      Buffer.from([1]),
+      timestamp,
+      nullifier,
+      domain,
+      extension,
+    ]);
+    const signature = nacl.sign.detached(message, verifier.secretKey);
+    // * [version = 9] [is_valid] [proof_hash] [timestamp] [nullifier] [domain] [extension] [signature]
+    const result = Buffer.concat([
+      Buffer.from([9, 1]),
+      proofHash,
+      timestamp,
+      nullifier,
+      domain,
+      extension,
+      signature,
+    ]);
+
+    const session = veiledPda(
+      Buffer.from("nullifier"),
+      Buffer.from(nullifier),
+      createHash("sha256").update(domain).digest()
+    );
+    await veiled.methods
+      .verifyAuthV1(result, Array.from(nullifier), Array.from(domain))
+      .preInstructions([
+        Ed25519Program.createInstructionWithPublicKey({
+          publicKey: verifier.publicKey.toBytes(),
+          message,
+          signature,
+        }),
+      ])
+      .accounts({
+        nullifierAccount: session,
+        proofRecord: veiledPda(Buffer.from("proof_record"), Buffer.from(proofHash)),
+        resultReceipt: veiledPda(
+          Buffer.from("result_receipt"),
+          createHash("sha256").update(signature).digest()
+        ),
+        authority: verifier.publicKey,
+        domainConfig: veiledPda(
+          Buffer.from("domain_config"),
+          createHash("sha256").update(domain).digest()
+        ),
+      })
+      .signers([verifier])
+      .rpc();
+
+    return { nullifier, session };
+  }
+
+  // * Helper: Grant `permissions` from `nullifier` to `grantee` for an hour
+  async function grant(
+    nullifier: Uint8Array,
+    grantee: PublicKey,
+    permissions: any[]
+  ): Promise<PublicKey> {
+    const domainHash = createHash("sha256").update(domain).digest();
+    await veiled.methods
+      .grantPermissions(Array.from(nullifier), Array.from(domain), grantee, permissions, {
+        unixTime: { 0: new anchor.BN(3600) },
+      })
+      .accounts({
+        domainConfig: veiledPda(Buffer.from("domain_config"), domainHash),
+        expiryHistogram: veiledPda(Buffer.from("expiry_histogram"), domainHash),
+        payer: provider.wallet.publicKey,
+      })
+      .rpc();
+
+    return veiledPda(Buffer.from("permission"), Buffer.from(nullifier), grantee.toBuffer());
+  }
+
+  // * Helper: Call the gated instruction
+  async function increment(session: PublicKey, permissionGrant: PublicKey): Promise<void> {
+    const { domain, nullifier } = await veiled.account.nullifierAccount.fetch(session);
+    await counterProgram.methods
+      .increment()
+      .accountsPartial({
+        counter: counterPda,
+        session,
+        permissionGrant,
+        logoutEpoch: veiledPda(Buffer.from("logout_epoch"), verifier.publicKey.toBuffer()),
+        nullifierBan: veiledPda(
+          Buffer.from("nullifier_ban"),
+          Buffer.from(domain),
+          Buffer.from(nullifier)
+        ),
+        veiledProgram: veiled.programId,
+      })
+      .rpc();
+  }
+
+  // * Test 1: Live session + grant with the required permission
+  it("should increment for a session holding the required permission", async () => {
+    const { nullifier, session } = await createSession();
+    const permissionGrant = await grant(nullifier, appId, [{ revealWalletAddress: {} }]);
+
+    await increment(session, permissionGrant);
+
+    const counter = await counterProgram.account.counter.fetch(counterPda);
+    expect(counter.count.toNumber()).to.equal(1);
+  });
+
+  // * Test 2: try_check_permission returns false, so the example aborts with its own error
+  it("should reject a grant without the required permission", async () => {
+    const { nullifier, session } = await createSession();
+    const permissionGrant = await grant(nullifier, appId, [{ revealExactBalance: {} }]);
+
+    try {
+      await increment(session, permissionGrant);
+      expect.fail("Transaction should have failed");
+    } catch (error: any) {
+      expect(error.message).to.include("PermissionDenied");
+    }
+  });
+
+  // * Test 3: A grant to another app can't be borrowed
+  it("should reject a grant issued to another app", async () => {
+    const { nullifier, session } = await createSession();
+    const permissionGrant = await grant(nullifier, Keypair.generate().publicKey, [
+      { revealWalletAddress: {} },
+    ]);
+
+    try {
+      await increment(session, permissionGrant);
+      expect.fail("Transaction should have failed");
+    } catch (error: any) {
+      expect(error.message).to.include("GrantAppMismatch");
+    }
+  });
+
+  // * Test 4: validate_session_token fails inside the CPI after a global logout
+  it("should reject a session revoked by a global logout", async () => {
+    const { nullifier, session } = await createSession();
+    const permissionGrant = await grant(nullifier, appId, [{ revealWalletAddress: {} }]);
+
+    // * Revokes every session the verifier created at or before now
+    await veiled.methods
+      .globalLogout()
+      .accounts({ identity: verifier.publicKey })
+      .signers([verifier])
+      .rpc();
+
+    try {
+      await increment(session, permissionGrant);
+      expect.fail("Transaction should have failed");
+    } catch (error: any) {
+      expect(error.message).to.include("SessionRevoked");
+    }
+  });
+});
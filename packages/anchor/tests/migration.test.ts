@@ -0,0 +1,171 @@
+// * Migration Coordinator Tests
+// *
+// * Tests the begin_migration / complete_migration lifecycle
+// *
+// * Test Cases:
+// * 1. Migration without required work completes right away
+// * 2. Unfinished required step blocks completion before the deadline
+// * 3. Second migration can't begin while one is open
+// * 4. Deadline completion goes ahead with unfinished steps, flagged as forced
+// * 5. Only the admin can begin a migration
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { expect } from "chai";
+import type { Veiled } from "../target/types/veiled";
+
+describe("Migration Coordinator", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.Veiled as Program<Veiled>;
+
+  const [migrationStatePda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("migration_state")],
+    program.programId
+  );
+
+  // * Helper: Current state version (0 before the first migration)
+  async function currentVersion(): Promise<number> {
+    const state = await program.account.migrationState.fetchNullable(migrationStatePda);
+    return state?.version ?? 0;
+  }
+
+  // * Helper: Open a migration to the next version with one session step
+  async function begin(required: boolean, total: number, deadlineSecs: number) {
+    await program.methods
+      .beginMigration(
+        (await currentVersion()) + 1,
+        [
+          {
+            account: { session: {} },
+            required,
+            total: new anchor.BN(total),
+            migrated: new anchor.BN(0),
+          },
+        ],
+        new anchor.BN(Math.floor(Date.now() / 1000) + deadlineSecs)
+      )
+      .accounts({ authority: provider.wallet.publicKey })
+      .rpc();
+  }
+
+  // * Helper: complete_migration as the admin; returns the transaction signature
+  async function complete(): Promise<string> {
+    return program.methods
+      .completeMigration()
+      .accounts({ authority: provider.wallet.publicKey })
+      .rpc({ commitment: "confirmed" });
+  }
+
+  // * Helper: Events a confirmed transaction emitted through emit_cpi! (inner instructions)
+  async function cpiEvents(signature: string) {
+    const tx = await provider.connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+    const keys = tx!.transaction.message.getAccountKeys({
+      accountKeysFromLookups: tx!.meta!.loadedAddresses,
+    });
+
+    return (tx!.meta!.innerInstructions ?? [])
+      .flatMap(({ instructions }) => instructions)
+      .filter(({ programIdIndex }) => keys.get(programIdIndex)!.equals(program.programId))
+      .map(({ data }) =>
+        program.coder.events.decode(
+          Buffer.from(anchor.utils.bytes.bs58.decode(data).subarray(8)).toString("base64")
+        )
+      )
+      .filter((event) => event !== null);
+  }
+
+  before(async () => {
+    try {
+      await program.methods
+        .initializeConfig()
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    } catch {
+      // * Already initialized
+    }
+  });
+
+  // * Test 1: Nothing to migrate means nothing to wait for
+  it("should complete a migration without required work", async () => {
+    const version = await currentVersion();
+    await begin(true, 0, 3600);
+
+    const open = await program.account.migrationState.fetch(migrationStatePda);
+    expect(open.targetVersion).to.equal(version + 1);
+
+    await complete();
+
+    const done = await program.account.migrationState.fetch(migrationStatePda);
+    expect(done.version).to.equal(version + 1);
+    expect(done.targetVersion).to.equal(version + 1);
+    expect(done.steps).to.be.empty;
+  });
+
+  // * Test 2: Required accounts still unmigrated before the deadline
+  it("should block completion until required steps finish", async () => {
+    await begin(true, 5, 3);
+
+    try {
+      await complete();
+      expect.fail("Should have blocked completion");
+    } catch (error: any) {
+      expect(error.message).to.include("MigrationIncomplete");
+    }
+  });
+
+  // * Test 3: One migration window at a time
+  it("should reject beginning a second migration", async () => {
+    try {
+      await begin(false, 0, 3600);
+      expect.fail("Should have rejected a second migration");
+    } catch (error: any) {
+      expect(error.message).to.include("MigrationInProgress");
+    }
+  });
+
+  // * Test 4: The deadline from Test 2 unblocks completion
+  it("should complete at the deadline and flag it as forced", async () => {
+    const version = await currentVersion();
+    await new Promise((resolve) => setTimeout(resolve, 4000));
+
+    const events = await cpiEvents(await complete());
+
+    const done = await program.account.migrationState.fetch(migrationStatePda);
+    expect(done.version).to.equal(version + 1);
+
+    const completed = events.find(({ name }) => name === "MigrationCompletedEvent");
+    expect(completed!.data.forced).to.be.true;
+    const [step] = completed!.data.steps as { migrated: anchor.BN }[];
+    expect(step.migrated.toNumber()).to.equal(0);
+  });
+
+  // * Test 5: Migrations are admin only
+  it("should reject a migration begun by a non-admin", async () => {
+    const outsider = Keypair.generate();
+    const signature = await provider.connection.requestAirdrop(
+      outsider.publicKey,
+      anchor.web3.LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(signature);
+
+    try {
+      await program.methods
+        .beginMigration(
+          (await currentVersion()) + 1,
+          [],
+          new anchor.BN(Math.floor(Date.now() / 1000) + 3600)
+        )
+        .accounts({ authority: outsider.publicKey })
+        .signers([outsider])
+        .rpc();
+      expect.fail("Should have rejected a non-admin");
+    } catch (error: any) {
+      expect(error.message).to.include("Unauthorized");
+    }
+  });
+});
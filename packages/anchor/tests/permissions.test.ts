@@ -9,7 +9,13 @@
 // * 4. Reject revoked permission access
 // * 5. Reject expired permission access
 // * 6. Reject access to non-granted permission
-// * 7. Reject too many permissions
+// * 7. Slot-based grant expiry
+// * 8. Reject too many permissions
+// * 9. Non-aborting permission check
+// * 10. Notification channel gated by ReceiveNotifications
+// * 11. Wallet address reveal escrow (delay, early release, cancel)
+// * 12. Domain permission cap
+// * 13. Grant expiry histogram per domain
 
 import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
@@ -19,6 +25,7 @@ import {
   SystemProgram,
 } from "@solana/web3.js";
 import { expect } from "chai";
+import { createHash } from "crypto";
 import type { Veiled } from "../target/types/veiled";
 
 describe("Permission System", () => {
@@ -29,6 +36,18 @@ describe("Permission System", () => {
   // * Test fixtures
   const nullifier = new Uint8Array(32).fill(1);
   const appId = Keypair.generate().publicKey;
+  const domain = Array.from(Buffer.from("test-domain".padEnd(32, "\0")));
+  // * Grants pass the domain's config, which Anchor can't derive from the hashed seed
+  const domainHash = createHash("sha256").update(Buffer.from(domain)).digest();
+  const domainConfigPDA = PublicKey.findProgramAddressSync(
+    [Buffer.from("domain_config"), domainHash],
+    program.programId
+  )[0];
+  // * Grant durations are counted per domain, under the same hashed seed
+  const expiryHistogramPDA = PublicKey.findProgramAddressSync(
+    [Buffer.from("expiry_histogram"), domainHash],
+    program.programId
+  )[0];
   let permissionPDA: PublicKey;
 
   // * Helper: Derive permission PDA
@@ -40,6 +59,16 @@ describe("Permission System", () => {
     return pda;
   }
 
+  // * Helper: ExpiryKind duration in seconds
+  function unixDuration(seconds: number): any {
+    return { unixTime: { 0: new anchor.BN(seconds) } };
+  }
+
+  // * Helper: ExpiryKind duration in slots
+  function slotDuration(slots: number): any {
+    return { slot: { 0: new anchor.BN(slots) } };
+  }
+
   // * Helper: Convert permission enum to Anchor format
   function toPermissionEnum(permission: string): any {
     const map: Record<string, any> = {
@@ -51,6 +80,7 @@ describe("Permission System", () => {
       revealStakingPositions: { revealStakingPositions: {} },
       revealDefiPositions: { revealDefiPositions: {} },
       signTransactions: { signTransactions: {} },
+      receiveNotifications: { receiveNotifications: {} },
     };
     return map[permission] || { revealWalletAddress: {} };
   }
@@ -80,16 +110,19 @@ describe("Permission System", () => {
       { revealExactBalance: {} },
     ];
 
-    const expiresIn = 3600; // * 1 hour
+    const expiresIn = unixDuration(3600); // * 1 hour
 
     const txSignature = await program.methods
       .grantPermissions(
         Array.from(nullifier),
+        domain,
         appId,
         permissions,
         expiresIn
       )
       .accounts({
+        domainConfig: domainConfigPDA,
+        expiryHistogram: expiryHistogramPDA,
         permissionGrant: permissionPDA,
         payer: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
@@ -102,6 +135,7 @@ describe("Permission System", () => {
     // * Verify account was created
     const grant = await program.account.permissionGrant.fetch(permissionPDA);
     expect(grant.permissions.length).to.equal(2);
+    expect(grant.permissionMask.toNumber()).to.equal(0b11); // * RevealWalletAddress | RevealExactBalance
     expect(grant.revoked).to.equal(false);
     expect(grant.nullifier).to.deep.equal(Array.from(nullifier));
     expect(grant.appId.toString()).to.equal(appId.toString());
@@ -113,11 +147,14 @@ describe("Permission System", () => {
     await program.methods
       .grantPermissions(
         Array.from(nullifier),
+        domain,
         appId,
         [{ revealWalletAddress: {} }],
-        3600
+        unixDuration(3600)
       )
       .accounts({
+        domainConfig: domainConfigPDA,
+        expiryHistogram: expiryHistogramPDA,
         permissionGrant: permissionPDA,
         payer: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
@@ -147,11 +184,14 @@ describe("Permission System", () => {
     await program.methods
       .grantPermissions(
         Array.from(nullifier),
+        domain,
         appId,
         [{ revealWalletAddress: {} }],
-        3600
+        unixDuration(3600)
       )
       .accounts({
+        domainConfig: domainConfigPDA,
+        expiryHistogram: expiryHistogramPDA,
         permissionGrant: permissionPDA,
         payer: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
@@ -164,7 +204,8 @@ describe("Permission System", () => {
     const txSignature = await program.methods
       .logPermissionAccess(
         { revealWalletAddress: {} },
-        "User viewed profile page"
+        "User viewed profile page",
+        domain
       )
       .accounts({
         permissionAccess: accessAccount.publicKey,
@@ -192,11 +233,14 @@ describe("Permission System", () => {
     await program.methods
       .grantPermissions(
         Array.from(nullifier),
+        domain,
         appId,
         [{ revealWalletAddress: {} }],
-        3600
+        unixDuration(3600)
       )
       .accounts({
+        domainConfig: domainConfigPDA,
+        expiryHistogram: expiryHistogramPDA,
         permissionGrant: permissionPDA,
         payer: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
@@ -218,7 +262,8 @@ describe("Permission System", () => {
       await program.methods
         .logPermissionAccess(
           { revealWalletAddress: {} },
-          "Attempted access"
+          "Attempted access",
+          domain
         )
         .accounts({
           permissionAccess: accessAccount.publicKey,
@@ -242,11 +287,14 @@ describe("Permission System", () => {
     await program.methods
       .grantPermissions(
         Array.from(nullifier),
+        domain,
         appId,
         [{ revealWalletAddress: {} }],
-        1 // * 1 second
+        unixDuration(1) // * 1 second
       )
       .accounts({
+        domainConfig: domainConfigPDA,
+        expiryHistogram: expiryHistogramPDA,
         permissionGrant: permissionPDA,
         payer: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
@@ -263,7 +311,8 @@ describe("Permission System", () => {
       await program.methods
         .logPermissionAccess(
           { revealWalletAddress: {} },
-          "Attempted access"
+          "Attempted access",
+          domain
         )
         .accounts({
           permissionAccess: accessAccount.publicKey,
@@ -287,11 +336,14 @@ describe("Permission System", () => {
     await program.methods
       .grantPermissions(
         Array.from(nullifier),
+        domain,
         appId,
         [{ revealWalletAddress: {} }], // * Only wallet address
-        3600
+        unixDuration(3600)
       )
       .accounts({
+        domainConfig: domainConfigPDA,
+        expiryHistogram: expiryHistogramPDA,
         permissionGrant: permissionPDA,
         payer: provider.wallet.publicKey,
         systemProgram: SystemProgram.programId,
@@ -305,7 +357,8 @@ describe("Permission System", () => {
       await program.methods
         .logPermissionAccess(
           { revealExactBalance: {} }, // * Not granted
-          "Attempted access"
+          "Attempted access",
+          domain
         )
         .accounts({
           permissionAccess: accessAccount.publicKey,
@@ -323,7 +376,31 @@ describe("Permission System", () => {
     }
   });
 
-  // * Test 7: Reject too many permissions
+  // * Test 7: Slot-based grant expiry
+  it("should grant permissions with slot-based expiry", async () => {
+    await program.methods
+      .grantPermissions(
+        Array.from(nullifier),
+        domain,
+        appId,
+        [{ revealWalletAddress: {} }],
+        slotDuration(1000)
+      )
+      .accounts({
+        domainConfig: domainConfigPDA,
+        expiryHistogram: expiryHistogramPDA,
+        permissionGrant: permissionPDA,
+        payer: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const grant = await program.account.permissionGrant.fetch(permissionPDA);
+    expect(grant.expiresAt.slot).to.exist;
+    console.log("✓ Slot-based expiry test passed");
+  });
+
+  // * Test 8: Reject too many permissions
   it("should reject too many permissions", async () => {
     // * Try to grant 11 permissions (limit is 10)
     const tooManyPermissions = Array(11).fill({ revealWalletAddress: {} });
@@ -332,11 +409,15 @@ describe("Permission System", () => {
       await program.methods
         .grantPermissions(
           Array.from(nullifier),
+          domain,
           appId,
           tooManyPermissions,
-          3600
+          unixDuration(3600)
         )
         .accounts({
+          domainConfig: domainConfigPDA,
+        expiryHistogram: expiryHistogramPDA,
+          expiryHistogram: expiryHistogramPDA,
           permissionGrant: permissionPDA,
           payer: provider.wallet.publicKey,
           systemProgram: SystemProgram.programId,
@@ -349,4 +430,261 @@ describe("Permission System", () => {
       console.log("✓ Reject too many permissions test passed");
     }
   });
+
+  // * Test 9: Non-aborting permission check
+  it("should report permission checks via return data without failing", async () => {
+    await program.methods
+      .grantPermissions(
+        Array.from(nullifier),
+        domain,
+        appId,
+        [{ revealWalletAddress: {} }],
+        unixDuration(3600)
+      )
+      .accounts({
+        domainConfig: domainConfigPDA,
+        expiryHistogram: expiryHistogramPDA,
+        permissionGrant: permissionPDA,
+        payer: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    const granted = await program.methods
+      .tryCheckPermission({ revealWalletAddress: {} })
+      .accounts({ permissionGrant: permissionPDA })
+      .view();
+    expect(granted).to.equal(true);
+
+    // * Not granted - returns false instead of throwing PermissionNotGranted
+    const denied = await program.methods
+      .tryCheckPermission({ revealExactBalance: {} })
+      .accounts({ permissionGrant: permissionPDA })
+      .view();
+    expect(denied).to.equal(false);
+    console.log("✓ Non-aborting permission check test passed");
+  });
+
+  // * Test 10: Notification channel gated by ReceiveNotifications
+  it("should only store a notification channel with ReceiveNotifications", async () => {
+    const channelNullifier = new Uint8Array(32).fill(10);
+    const grantPDA = getPermissionPDA(channelNullifier, appId);
+    const [channelPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("notification_channel"), Buffer.from(channelNullifier), appId.toBuffer()],
+      program.programId
+    );
+    const commitment = Array.from(new Uint8Array(32).fill(7));
+    const encryptedToken = Buffer.from("encrypted-push-token");
+
+    const grant = async (permissions: any[]) =>
+      program.methods
+        .grantPermissions(
+          Array.from(channelNullifier),
+          domain,
+          appId,
+          permissions,
+          unixDuration(3600)
+        )
+        .accounts({
+          domainConfig: domainConfigPDA,
+        expiryHistogram: expiryHistogramPDA,
+          expiryHistogram: expiryHistogramPDA,
+          permissionGrant: grantPDA,
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+    const setChannel = () =>
+      program.methods
+        .setNotificationChannel(commitment, encryptedToken)
+        .accounts({
+          notificationChannel: channelPDA,
+          permissionGrant: grantPDA,
+          authority: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        } as any)
+        .rpc();
+
+    // * Without the permission the channel can't be written
+    await grant([toPermissionEnum("revealWalletAddress")]);
+    try {
+      await setChannel();
+      expect.fail("Should have rejected a channel without ReceiveNotifications");
+    } catch (error: any) {
+      expect(error.message).to.include("PermissionNotGranted");
+    }
+
+    await grant([toPermissionEnum("receiveNotifications")]);
+    await setChannel();
+    const channel = await program.account.notificationChannel.fetch(channelPDA);
+    expect(channel.channelCommitment).to.deep.equal(commitment);
+    expect(Buffer.from(channel.encryptedToken).toString()).to.equal("encrypted-push-token");
+
+    // * Once revoked, anyone can close the channel; rent goes back to its authority
+    await program.methods
+      .revokePermissions()
+      .accounts({ permissionGrant: grantPDA, authority: provider.wallet.publicKey })
+      .rpc();
+    const closer = Keypair.generate();
+    await program.methods
+      .closeNotificationChannel()
+      .accounts({
+        notificationChannel: channelPDA,
+        permissionGrant: grantPDA,
+        authority: provider.wallet.publicKey,
+        closer: closer.publicKey,
+      } as any)
+      .signers([closer])
+      .rpc();
+    expect(await provider.connection.getAccountInfo(channelPDA)).to.equal(null);
+    console.log("✓ Notification channel test passed");
+  });
+
+  // * Test 11: Wallet address reveal escrow
+  it("should hold an escrowed reveal until its delay and allow cancelling", async () => {
+    const escrowNullifier = new Uint8Array(32).fill(11);
+    const grantPDA = getPermissionPDA(escrowNullifier, appId);
+    const [escrowPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("reveal_escrow"), Buffer.from(escrowNullifier), appId.toBuffer()],
+      program.programId
+    );
+    const revealKey = Buffer.alloc(32, 3);
+    const keyCommitment = Array.from(createHash("sha256").update(revealKey).digest());
+
+    await program.methods
+      .grantPermissions(
+        Array.from(escrowNullifier),
+        domain,
+        appId,
+        [toPermissionEnum("revealWalletAddress")],
+        unixDuration(3600)
+      )
+      .accounts({
+        domainConfig: domainConfigPDA,
+        expiryHistogram: expiryHistogramPDA,
+        permissionGrant: grantPDA,
+        payer: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      })
+      .rpc();
+
+    await program.methods
+      .openRevealEscrow(Buffer.alloc(60, 9), keyCommitment, new anchor.BN(7 * 24 * 60 * 60))
+      .accounts({
+        revealEscrow: escrowPDA,
+        permissionGrant: grantPDA,
+        authority: provider.wallet.publicKey,
+        systemProgram: SystemProgram.programId,
+      } as any)
+      .rpc();
+
+    // * The key can't be released before the delay
+    try {
+      await program.methods
+        .releaseRevealEscrow(Array.from(revealKey))
+        .accounts({ revealEscrow: escrowPDA, permissionGrant: grantPDA } as any)
+        .rpc();
+      expect.fail("Should have rejected an early release");
+    } catch (error: any) {
+      expect(error.message).to.include("RevealNotReady");
+    }
+
+    // * The user can still back out
+    await program.methods
+      .cancelRevealEscrow()
+      .accounts({ revealEscrow: escrowPDA, authority: provider.wallet.publicKey } as any)
+      .rpc();
+    expect(await provider.connection.getAccountInfo(escrowPDA)).to.equal(null);
+    console.log("✓ Reveal escrow test passed");
+  });
+
+  // * Test 12: Domain permission cap
+  it("should cap grants at the domain's max_permissions", async () => {
+    const cappedDomain = Array.from(
+      Buffer.from(`cap-${Date.now().toString(36)}`.padEnd(32, "\0"))
+    );
+    const cappedHash = createHash("sha256").update(Buffer.from(cappedDomain)).digest();
+    const [cappedConfigPDA] = PublicKey.findProgramAddressSync(
+      [Buffer.from("domain_config"), cappedHash],
+      program.programId
+    );
+    await program.methods
+      .registerDomain(cappedDomain, null, new anchor.BN(0), 0, 2)
+      .accounts({ domainConfig: cappedConfigPDA, owner: provider.wallet.publicKey } as any)
+      .rpc();
+
+    const cappedNullifier = createHash("sha256").update("capped-nullifier").digest();
+    const grant = (permissions: any[]) =>
+      program.methods
+        .grantPermissions(
+          Array.from(cappedNullifier),
+          cappedDomain,
+          appId,
+          permissions,
+          unixDuration(3600)
+        )
+        .accounts({
+          domainConfig: cappedConfigPDA,
+          expiryHistogram: PublicKey.findProgramAddressSync(
+            [Buffer.from("expiry_histogram"), cappedHash],
+            program.programId
+          )[0],
+          permissionGrant: getPermissionPDA(cappedNullifier, appId),
+          payer: provider.wallet.publicKey,
+        } as any)
+        .rpc();
+
+    try {
+      await grant([
+        toPermissionEnum("revealWalletAddress"),
+        toPermissionEnum("revealExactBalance"),
+        toPermissionEnum("revealTokenBalances"),
+      ]);
+      expect.fail("Should have rejected a grant over the domain's cap");
+    } catch (error: any) {
+      expect(error.message).to.include("TooManyPermissions");
+    }
+
+    await grant([toPermissionEnum("revealWalletAddress"), toPermissionEnum("revealExactBalance")]);
+    const granted = await program.account.permissionGrant.fetch(
+      getPermissionPDA(cappedNullifier, appId)
+    );
+    expect(granted.permissions.length).to.equal(2);
+    console.log("✓ Domain permission cap test passed");
+  });
+
+  // * Test 13: Grant expiry histogram per domain
+  it("should count grant durations in the domain's histogram", async () => {
+    const before = await program.account.expiryHistogram.fetchNullable(expiryHistogramPDA);
+    const counted = (bucket: number) => before?.buckets[bucket].toNumber() ?? 0;
+
+    // * Different apps, same domain: both land in the domain's histogram
+    for (const [grantee, seconds] of [
+      [Keypair.generate().publicKey, 3600],
+      [Keypair.generate().publicKey, 86_400],
+    ] as const) {
+      await program.methods
+        .grantPermissions(
+          Array.from(nullifier),
+          domain,
+          grantee,
+          [{ revealWalletAddress: {} }],
+          unixDuration(seconds)
+        )
+        .accounts({
+          domainConfig: domainConfigPDA,
+          expiryHistogram: expiryHistogramPDA,
+          permissionGrant: getPermissionPDA(nullifier, grantee),
+          payer: provider.wallet.publicKey,
+        } as any)
+        .rpc();
+    }
+
+    const histogram = await program.account.expiryHistogram.fetch(expiryHistogramPDA);
+    expect(histogram.domain).to.deep.equal(domain);
+    expect(histogram.buckets[0].toNumber()).to.equal(counted(0) + 1); // * <= 1 hour
+    expect(histogram.buckets[1].toNumber()).to.equal(counted(1) + 1); // * <= 1 day
+    console.log("✓ Expiry histogram test passed");
+  });
 });
@@ -0,0 +1,265 @@
+// * Personhood Attestation Tests
+// *
+// * Tests binding proof-of-personhood provider scores to nullifiers
+// *
+// * Test Cases:
+// * 1. Registered provider's signed score attaches to a nullifier
+// * 2. Attestation signed by an unregistered provider rejected
+// * 3. Attestation without the provider's Ed25519 signature rejected
+// * 4. Older attestation can't replace a newer one
+// * 5. Only the admin can register providers
+// * 6. Archived attestation restores from its fields
+// * 7. Restore with altered fields rejected
+// * 8. Archived claim can't be re-attached
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Ed25519Program, Keypair, PublicKey } from "@solana/web3.js";
+import * as nacl from "tweetnacl";
+import { expect } from "chai";
+import type { Veiled } from "../target/types/veiled";
+
+// * Domain separator of PersonhoodAttestation::message
+const MESSAGE_PREFIX = Buffer.from("veiled:personhood:v1");
+
+// * Helper: PersonhoodAttestation::message (integers little-endian)
+function attestationMessage(
+  nullifier: Uint8Array,
+  score: number,
+  issuedAt: number,
+  expiresAt: number
+): Uint8Array {
+  const message = Buffer.alloc(MESSAGE_PREFIX.length + 32 + 2 + 8 + 8);
+  MESSAGE_PREFIX.copy(message, 0);
+  message.set(nullifier, MESSAGE_PREFIX.length);
+  message.writeUInt16LE(score, MESSAGE_PREFIX.length + 32);
+  message.writeBigInt64LE(BigInt(issuedAt), MESSAGE_PREFIX.length + 34);
+  message.writeBigInt64LE(BigInt(expiresAt), MESSAGE_PREFIX.length + 42);
+  return message;
+}
+
+describe("Personhood Attestations", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.Veiled as Program<Veiled>;
+
+  const personhoodProvider = Keypair.generate();
+  const nullifier = crypto.getRandomValues(new Uint8Array(32));
+  const [attestationPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("personhood"), Buffer.from(nullifier), personhoodProvider.publicKey.toBuffer()],
+    program.programId
+  );
+  const [archivePda] = PublicKey.findProgramAddressSync(
+    [
+      Buffer.from("personhood_archive"),
+      Buffer.from(nullifier),
+      personhoodProvider.publicKey.toBuffer(),
+    ],
+    program.programId
+  );
+
+  // * Helper: Attach a score signed by `signer` (the provider, unless testing a forgery)
+  async function attach(score: number, issuedAt: number, signer: Keypair = personhoodProvider) {
+    const expiresAt = issuedAt + 30 * 24 * 60 * 60;
+    const message = attestationMessage(nullifier, score, issuedAt, expiresAt);
+
+    await program.methods
+      .attachPersonhoodAttestation(
+        Array.from(nullifier),
+        personhoodProvider.publicKey,
+        score,
+        new anchor.BN(issuedAt),
+        new anchor.BN(expiresAt)
+      )
+      .preInstructions([
+        Ed25519Program.createInstructionWithPublicKey({
+          publicKey: signer.publicKey.toBytes(),
+          message,
+          signature: nacl.sign.detached(message, signer.secretKey),
+        }),
+      ])
+      .accounts({ payer: provider.wallet.publicKey })
+      .rpc();
+  }
+
+  // * Helper: Archive the attestation (signed and paid for by the provider)
+  async function archive() {
+    await program.methods
+      .archiveAttestation()
+      .accountsPartial({
+        attestation: attestationPda,
+        archive: archivePda,
+        provider: personhoodProvider.publicKey,
+      })
+      .signers([personhoodProvider])
+      .rpc();
+  }
+
+  // * Helper: Restore from the given fields
+  async function restore(score: number, issuedAt: anchor.BN, expiresAt: anchor.BN) {
+    await program.methods
+      .restoreAttestation(
+        Array.from(nullifier),
+        personhoodProvider.publicKey,
+        score,
+        issuedAt,
+        expiresAt
+      )
+      .accounts({ payer: provider.wallet.publicKey })
+      .rpc();
+  }
+
+  before(async () => {
+    const airdropSig = await provider.connection.requestAirdrop(
+      personhoodProvider.publicKey,
+      anchor.web3.LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(airdropSig);
+
+    try {
+      await program.methods
+        .initializeConfig()
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    } catch {
+      // * Already initialized
+    }
+  });
+
+  // * Test 1: Registered provider's attestation attaches
+  it("should attach a registered provider's score", async () => {
+    await program.methods
+      .addPersonhoodProvider(personhoodProvider.publicKey)
+      .accounts({ authority: provider.wallet.publicKey })
+      .rpc();
+
+    const issuedAt = Math.floor(Date.now() / 1000) - 60;
+    await attach(80, issuedAt);
+
+    const attestation = await program.account.personhoodAttestation.fetch(attestationPda);
+    expect(attestation.score).to.equal(80);
+    expect(attestation.provider.toString()).to.equal(personhoodProvider.publicKey.toString());
+    expect(attestation.issuedAt.toNumber()).to.equal(issuedAt);
+  });
+
+  // * Test 2: Unregistered providers can't attest
+  it("should reject an attestation from an unregistered provider", async () => {
+    const unregistered = Keypair.generate();
+    const issuedAt = Math.floor(Date.now() / 1000);
+    const expiresAt = issuedAt + 3600;
+    const message = attestationMessage(nullifier, 90, issuedAt, expiresAt);
+
+    try {
+      await program.methods
+        .attachPersonhoodAttestation(
+          Array.from(nullifier),
+          unregistered.publicKey,
+          90,
+          new anchor.BN(issuedAt),
+          new anchor.BN(expiresAt)
+        )
+        .preInstructions([
+          Ed25519Program.createInstructionWithPublicKey({
+            publicKey: unregistered.publicKey.toBytes(),
+            message,
+            signature: nacl.sign.detached(message, unregistered.secretKey),
+          }),
+        ])
+        .accounts({ payer: provider.wallet.publicKey })
+        .rpc();
+      expect.fail("Should have rejected an unregistered provider");
+    } catch (error: any) {
+      expect(error.message).to.include("PersonhoodProviderNotRegistered");
+    }
+  });
+
+  // * Test 3: The score must be signed by the provider it claims
+  it("should reject an attestation the provider didn't sign", async () => {
+    try {
+      await attach(100, Math.floor(Date.now() / 1000), Keypair.generate());
+      expect.fail("Should have rejected a forged attestation");
+    } catch (error: any) {
+      expect(error.message).to.include("MissingPersonhoodSignature");
+    }
+  });
+
+  // * Test 4: Replaying an older (e.g. higher) score over a newer one fails
+  it("should reject an attestation older than the attached one", async () => {
+    const now = Math.floor(Date.now() / 1000);
+    await attach(40, now);
+
+    try {
+      await attach(95, now - 30);
+      expect.fail("Should have rejected an older attestation");
+    } catch (error: any) {
+      expect(error.message).to.include("StalePersonhoodAttestation");
+    }
+
+    const attestation = await program.account.personhoodAttestation.fetch(attestationPda);
+    expect(attestation.score).to.equal(40);
+  });
+
+  // * Test 5: Provider registration is admin only
+  it("should reject provider registration by a non-admin", async () => {
+    const outsider = Keypair.generate();
+    const signature = await provider.connection.requestAirdrop(
+      outsider.publicKey,
+      anchor.web3.LAMPORTS_PER_SOL
+    );
+    await provider.connection.confirmTransaction(signature);
+
+    try {
+      await program.methods
+        .addPersonhoodProvider(Keypair.generate().publicKey)
+        .accounts({ authority: outsider.publicKey })
+        .signers([outsider])
+        .rpc();
+      expect.fail("Should have rejected a non-admin");
+    } catch (error: any) {
+      expect(error.message).to.include("Unauthorized");
+    }
+  });
+
+  // * Test 6: Cold storage round trip
+  it("should archive an attestation and restore it from its fields", async () => {
+    const original = await program.account.personhoodAttestation.fetch(attestationPda);
+    await archive();
+
+    expect(await program.account.personhoodAttestation.fetchNullable(attestationPda)).to.be.null;
+    const archived = await program.account.archivedPersonhoodAttestation.fetch(archivePda);
+    expect(archived.issuedAt.toNumber()).to.equal(original.issuedAt.toNumber());
+
+    await restore(original.score, original.issuedAt, original.expiresAt);
+
+    const restored = await program.account.personhoodAttestation.fetch(attestationPda);
+    expect(restored.score).to.equal(original.score);
+    expect(restored.expiresAt.toNumber()).to.equal(original.expiresAt.toNumber());
+    const closed = await program.account.archivedPersonhoodAttestation.fetchNullable(archivePda);
+    expect(closed).to.be.null;
+  });
+
+  // * Test 7: The fields must hash to the archived record
+  it("should reject a restore with altered fields", async () => {
+    const original = await program.account.personhoodAttestation.fetch(attestationPda);
+    await archive();
+
+    try {
+      await restore(100, original.issuedAt, original.expiresAt);
+      expect.fail("Should have rejected altered fields");
+    } catch (error: any) {
+      expect(error.message).to.include("AttestationPreimageMismatch");
+    }
+  });
+
+  // * Test 8: Archiving doesn't reset replay protection (still archived from Test 7)
+  it("should reject re-attaching an archived claim", async () => {
+    const archived = await program.account.archivedPersonhoodAttestation.fetch(archivePda);
+
+    try {
+      await attach(40, archived.issuedAt.toNumber());
+      expect.fail("Should have rejected the archived claim");
+    } catch (error: any) {
+      expect(error.message).to.include("StalePersonhoodAttestation");
+    }
+  });
+});
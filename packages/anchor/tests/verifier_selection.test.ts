@@ -0,0 +1,164 @@
+// * Verifier Selection Tests
+// *
+// * Tests set_verifier_weight, verifier_heartbeat and the select_verifier view
+// *
+// * Test Cases:
+// * 1. Selection matches the weighted rendezvous computed off-chain and is deterministic
+// * 2. Zero weight stops a verifier from being selected
+// * 3. Reject weighting an unregistered key
+// * 4. Reject a weight above 16
+// * 5. Reject a heartbeat from a key without a weight
+
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { Keypair, PublicKey } from "@solana/web3.js";
+import { createHash } from "crypto";
+import { expect } from "chai";
+import type { Veiled } from "../target/types/veiled";
+
+describe("Verifier Selection", () => {
+  const provider = anchor.AnchorProvider.env();
+  anchor.setProvider(provider);
+  const program = anchor.workspace.Veiled as Program<Veiled>;
+
+  const light = Keypair.generate();
+  const heavy = Keypair.generate();
+
+  const [verifierWeightsPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from("verifier_weights")],
+    program.programId
+  );
+
+  // * Helper: Admin sets a verifier's selection weight
+  async function setWeight(verifier: PublicKey, weight: number) {
+    await program.methods
+      .setVerifierWeight(verifier, weight)
+      .accounts({ authority: provider.wallet.publicKey })
+      .rpc();
+  }
+
+  // * Helper: Heartbeat signed by the verifier key (fees paid by the provider wallet)
+  async function heartbeat(verifier: Keypair) {
+    await program.methods
+      .verifierHeartbeat()
+      .accounts({ verifier: verifier.publicKey })
+      .signers([verifier])
+      .rpc();
+  }
+
+  // * Helper: The select_verifier view for a nullifier
+  async function select(nullifier: Uint8Array): Promise<PublicKey> {
+    return program.methods.selectVerifier(Array.from(nullifier)).view();
+  }
+
+  // * Helper: Highest of `weight` draws SHA-256(seed || nullifier || verifier || k), first 8 bytes BE
+  function bestDraw(nullifier: Uint8Array, verifier: PublicKey, weight: number): bigint {
+    let best = 0n;
+    for (let k = 0; k < weight; k++) {
+      const draw = createHash("sha256")
+        .update(Buffer.from("veiled-verifier-selection"))
+        .update(nullifier)
+        .update(verifier.toBuffer())
+        .update(Buffer.from([k]))
+        .digest()
+        .readBigUInt64BE(0);
+      if (draw > best) best = draw;
+    }
+    return best;
+  }
+
+  before(async () => {
+    try {
+      await program.methods
+        .initializeConfig()
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    } catch {
+      // * Already initialized
+    }
+    for (const verifier of [light, heavy]) {
+      await program.methods
+        .addVerifier(verifier.publicKey)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    }
+
+    await setWeight(light.publicKey, 1);
+    await setWeight(heavy.publicKey, 3);
+    await heartbeat(light);
+    await heartbeat(heavy);
+  });
+
+  after(async () => {
+    for (const verifier of [light, heavy]) {
+      await setWeight(verifier.publicKey, 0);
+      await program.methods
+        .removeVerifier(verifier.publicKey)
+        .accounts({ authority: provider.wallet.publicKey })
+        .rpc();
+    }
+  });
+
+  // * Test 1: Clients can compute the same pick without calling the program
+  it("should select the verifier with the highest weighted draw", async () => {
+    const weights = await program.account.verifierWeights.fetch(verifierWeightsPda);
+    expect(weights.entries).to.have.length(2);
+
+    for (let i = 0; i < 8; i++) {
+      const nullifier = crypto.getRandomValues(new Uint8Array(32));
+      const expected =
+        bestDraw(nullifier, heavy.publicKey, 3) > bestDraw(nullifier, light.publicKey, 1)
+          ? heavy.publicKey
+          : light.publicKey;
+
+      const selected = await select(nullifier);
+      expect(selected.toBase58()).to.equal(expected.toBase58());
+      expect((await select(nullifier)).toBase58()).to.equal(selected.toBase58());
+    }
+  });
+
+  // * Test 2: Weight 0 removes the entry
+  it("should stop selecting a verifier whose weight is cleared", async () => {
+    await setWeight(light.publicKey, 0);
+
+    try {
+      for (let i = 0; i < 8; i++) {
+        const selected = await select(crypto.getRandomValues(new Uint8Array(32)));
+        expect(selected.toBase58()).to.equal(heavy.publicKey.toBase58());
+      }
+    } finally {
+      await setWeight(light.publicKey, 1);
+      await heartbeat(light);
+    }
+  });
+
+  // * Test 3: Only registry members can be weighted
+  it("should reject weighting an unregistered key", async () => {
+    try {
+      await setWeight(Keypair.generate().publicKey, 1);
+      expect.fail("Should have rejected an unregistered key");
+    } catch (error: any) {
+      expect(error.message).to.include("VerifierNotRegistered");
+    }
+  });
+
+  // * Test 4: Weights are bounded to keep selection cheap
+  it("should reject a weight above 16", async () => {
+    try {
+      await setWeight(heavy.publicKey, 17);
+      expect.fail("Should have rejected the weight");
+    } catch (error: any) {
+      expect(error.message).to.include("InvalidVerifierWeight");
+    }
+  });
+
+  // * Test 5: Heartbeats only count for weighted verifiers
+  it("should reject a heartbeat from an unweighted key", async () => {
+    try {
+      await heartbeat(Keypair.generate());
+      expect.fail("Should have rejected the heartbeat");
+    } catch (error: any) {
+      expect(error.message).to.include("VerifierNotWeighted");
+    }
+  });
+});
@@ -0,0 +1,307 @@
+// * Kotlin definitions renderer
+// *
+// * There's no maintained Anchor client generator for Kotlin, so this emits a single
+// * Veiled.kt with what a JVM/Android client needs to talk to the program:
+// * - Program ID and instruction / account / event discriminators
+// * - Typed instruction arguments and IDL-defined types (data / sealed classes)
+// * - The error table keyed by Anchor error code
+// *
+// * Borsh (de)serialization is left to the client's Solana library.
+
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+use std::fmt::Write;
+
+pub fn render(idl: &Value) -> Result<String> {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// Generated by `cargo xtask bindings` from target/idl/veiled.json. Do not edit."
+    )?;
+    writeln!(out, "package veiled")?;
+    writeln!(out)?;
+    writeln!(out, "object Veiled {{")?;
+    writeln!(
+        out,
+        "    const val PROGRAM_ID = \"{}\"",
+        idl["address"]
+            .as_str()
+            .context("IDL has no program address")?
+    )?;
+
+    for (section, key) in [
+        ("Instructions", "instructions"),
+        ("Accounts", "accounts"),
+        ("Events", "events"),
+    ] {
+        writeln!(out)?;
+        writeln!(out, "    object {section} {{")?;
+        for item in list(idl, key) {
+            writeln!(
+                out,
+                "        val {} = {}",
+                constant_case(name(item)?),
+                byte_array(&item["discriminator"])?
+            )?;
+        }
+        writeln!(out, "    }}")?;
+    }
+    writeln!(out, "}}")?;
+
+    for instruction in list(idl, "instructions") {
+        let args = list(instruction, "args");
+        if args.is_empty() {
+            continue;
+        }
+        writeln!(out)?;
+        let class = format!("{}Args", pascal_case(name(instruction)?));
+        render_data_class(&mut out, "", &class, args, None)?;
+    }
+
+    for ty in list(idl, "types") {
+        writeln!(out)?;
+        render_type(&mut out, ty)?;
+    }
+
+    writeln!(out)?;
+    writeln!(
+        out,
+        "enum class VeiledError(val code: Int, val msg: String) {{"
+    )?;
+    let errors = list(idl, "errors");
+    for (i, error) in errors.iter().enumerate() {
+        let separator = if i + 1 == errors.len() { ";" } else { "," };
+        writeln!(
+            out,
+            "    {}({}, \"{}\"){separator}",
+            constant_case(name(error)?),
+            error["code"].as_u64().context("error without a code")?,
+            escape(error["msg"].as_str().unwrap_or_default())
+        )?;
+    }
+    writeln!(out)?;
+    writeln!(out, "    companion object {{")?;
+    writeln!(
+        out,
+        "        fun fromCode(code: Int): VeiledError? = values().firstOrNull {{ it.code == code }}"
+    )?;
+    writeln!(out, "    }}")?;
+    writeln!(out, "}}")?;
+
+    Ok(out)
+}
+
+fn render_type(out: &mut String, ty: &Value) -> Result<()> {
+    let class = pascal_case(name(ty)?);
+    let body = &ty["type"];
+    match body["kind"].as_str() {
+        Some("struct") => render_data_class(out, "", &class, list(body, "fields"), None),
+        Some("enum") => {
+            writeln!(out, "sealed class {class} {{")?;
+            for variant in list(body, "variants") {
+                let variant_name = pascal_case(name(variant)?);
+                let fields = list(variant, "fields");
+                if fields.is_empty() {
+                    writeln!(out, "    object {variant_name} : {class}()")?;
+                } else {
+                    render_data_class(out, "    ", &variant_name, fields, Some(&class))?;
+                }
+            }
+            writeln!(out, "}}")?;
+            Ok(())
+        }
+        other => bail!("unsupported type kind {other:?} for {class}"),
+    }
+}
+
+/// * Named fields (`{ name, type }`) or tuple fields (bare types, rendered as `_0`, `_1`, ...)
+fn render_data_class(
+    out: &mut String,
+    indent: &str,
+    class: &str,
+    fields: &[Value],
+    parent: Option<&str>,
+) -> Result<()> {
+    writeln!(out, "{indent}data class {class}(")?;
+    for (i, field) in fields.iter().enumerate() {
+        let (field_name, field_type) = match field.get("name") {
+            Some(_) => (camel_case(name(field)?), &field["type"]),
+            None => (format!("_{i}"), field),
+        };
+        writeln!(
+            out,
+            "{indent}    val {field_name}: {},",
+            kotlin_type(field_type)?
+        )?;
+    }
+    match parent {
+        Some(parent) => writeln!(out, "{indent}) : {parent}()")?,
+        None => writeln!(out, "{indent})")?,
+    }
+    Ok(())
+}
+
+fn kotlin_type(ty: &Value) -> Result<String> {
+    if let Some(primitive) = ty.as_str() {
+        return Ok(match primitive {
+            "bool" => "Boolean",
+            "u8" => "UByte",
+            "i8" => "Byte",
+            "u16" => "UShort",
+            "i16" => "Short",
+            "u32" => "UInt",
+            "i32" => "Int",
+            "u64" => "ULong",
+            "i64" => "Long",
+            "u128" | "i128" | "u256" | "i256" => "java.math.BigInteger",
+            "f32" => "Float",
+            "f64" => "Double",
+            "string" => "String",
+            "pubkey" => "String", // * Base58
+            "bytes" => "ByteArray",
+            other => bail!("unsupported IDL type `{other}`"),
+        }
+        .to_string());
+    }
+    if let Some(inner) = ty.get("vec") {
+        return Ok(if inner == "u8" {
+            "ByteArray".to_string()
+        } else {
+            format!("List<{}>", kotlin_type(inner)?)
+        });
+    }
+    if let Some(inner) = ty.get("option") {
+        return Ok(format!("{}?", kotlin_type(inner)?));
+    }
+    if let Some(array) = ty.get("array") {
+        let inner = &array[0];
+        return Ok(if inner == "u8" {
+            "ByteArray".to_string()
+        } else {
+            format!("List<{}>", kotlin_type(inner)?)
+        });
+    }
+    if let Some(defined) = ty.get("defined") {
+        let defined = defined.get("name").unwrap_or(defined);
+        return Ok(pascal_case(
+            defined.as_str().context("defined type without a name")?,
+        ));
+    }
+    bail!("unsupported IDL type {ty}")
+}
+
+fn list<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value[key].as_array().map(Vec::as_slice).unwrap_or_default()
+}
+
+fn name(value: &Value) -> Result<&str> {
+    value["name"]
+        .as_str()
+        .with_context(|| format!("IDL entry without a name: {value}"))
+}
+
+fn byte_array(discriminator: &Value) -> Result<String> {
+    let bytes = discriminator
+        .as_array()
+        .context("missing discriminator")?
+        .iter()
+        .map(|byte| {
+            let byte = byte.as_u64().context("discriminator byte")? as u8;
+            Ok((byte as i8).to_string())
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(format!("byteArrayOf({})", bytes.join(", ")))
+}
+
+fn words(name: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    for c in name.chars() {
+        if c == '_' {
+            words.push(std::mem::take(&mut current));
+        } else if c.is_uppercase() && !current.is_empty() {
+            words.push(std::mem::take(&mut current));
+            current.extend(c.to_lowercase());
+        } else {
+            current.extend(c.to_lowercase());
+        }
+    }
+    words.push(current);
+    words.retain(|word| !word.is_empty());
+    words
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn pascal_case(name: &str) -> String {
+    words(name).iter().map(|word| capitalize(word)).collect()
+}
+
+fn camel_case(name: &str) -> String {
+    let pascal = pascal_case(name);
+    let mut chars = pascal.chars();
+    chars
+        .next()
+        .map(|first| first.to_lowercase().chain(chars).collect())
+        .unwrap_or_default()
+}
+
+fn constant_case(name: &str) -> String {
+    words(name).join("_").to_uppercase()
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('$', "\\$")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_render_idl() {
+        let idl = json!({
+            "address": "H6apEGZAw23AKUeqCX41wkDv2LVwX3Ec8oYPip7k3xzA",
+            "instructions": [{
+                "name": "verify_auth",
+                "discriminator": [1, 255],
+                "args": [
+                    { "name": "verification_result", "type": "bytes" },
+                    { "name": "domain", "type": { "array": ["u8", 32] } }
+                ]
+            }],
+            "accounts": [{ "name": "NullifierAccount", "discriminator": [2] }],
+            "types": [{
+                "name": "PermissionScope",
+                "type": {
+                    "kind": "enum",
+                    "variants": [
+                        { "name": "RevealWalletAddress" },
+                        { "name": "Custom", "fields": [{ "option": "u64" }] }
+                    ]
+                }
+            }],
+            "errors": [{ "code": 6000, "name": "InvalidProof", "msg": "Invalid \"proof\"" }]
+        });
+
+        let kotlin = render(&idl).unwrap();
+
+        assert!(kotlin
+            .contains("const val PROGRAM_ID = \"H6apEGZAw23AKUeqCX41wkDv2LVwX3Ec8oYPip7k3xzA\""));
+        assert!(kotlin.contains("val VERIFY_AUTH = byteArrayOf(1, -1)"));
+        assert!(kotlin.contains("val NULLIFIER_ACCOUNT = byteArrayOf(2)"));
+        assert!(kotlin.contains("data class VerifyAuthArgs(\n    val verificationResult: ByteArray,\n    val domain: ByteArray,\n)"));
+        assert!(kotlin.contains("    object RevealWalletAddress : PermissionScope()"));
+        assert!(kotlin.contains("        val _0: ULong?,\n    ) : PermissionScope()"));
+        assert!(kotlin.contains("    INVALID_PROOF(6000, \"Invalid \\\"proof\\\"\");"));
+    }
+}
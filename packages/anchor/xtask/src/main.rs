@@ -0,0 +1,194 @@
+// * Veiled build tasks
+// *
+// * Usage (from packages/anchor):
+// *   cargo xtask bindings               anchor build, then regenerate bindings/ and idls/
+// *   cargo xtask bindings --check       fail if bindings/ or idls/ is stale for the current program
+// *   cargo xtask bindings --skip-build  reuse the existing target/idl/veiled.json
+// *
+// * Every client is generated from the IDL `anchor build` emits, so the multi-language
+// * SDK surface can't drift from the on-chain program:
+// * - bindings/ts      anchor-client-gen
+// * - bindings/python  anchorpy client-gen
+// * - bindings/kotlin  Veiled.kt, rendered here (see kotlin.rs)
+// *
+// * The IDL itself is committed as idls/veiled.json, where `declare_program!(veiled)` finds it
+// * (see examples/idl-consumer)
+
+mod kotlin;
+
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+const USAGE: &str = "usage: cargo xtask bindings [--check] [--skip-build]";
+
+fn main() -> Result<()> {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.first().map(String::as_str) {
+        Some("bindings") => {
+            let mut check = false;
+            let mut skip_build = false;
+            for flag in &args[1..] {
+                match flag.as_str() {
+                    "--check" => check = true,
+                    "--skip-build" => skip_build = true,
+                    other => bail!("unknown flag `{other}`\n{USAGE}"),
+                }
+            }
+            bindings(check, skip_build)
+        }
+        _ => bail!(USAGE),
+    }
+}
+
+/// * packages/anchor (the Anchor workspace root)
+fn anchor_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("xtask lives inside packages/anchor")
+        .to_path_buf()
+}
+
+fn bindings(check: bool, skip_build: bool) -> Result<()> {
+    let root = anchor_root();
+    if !skip_build {
+        run(Command::new("anchor").arg("build").current_dir(&root))?;
+    }
+
+    let idl_path = root.join("target/idl/veiled.json");
+    let idl: Value = serde_json::from_str(
+        &fs::read_to_string(&idl_path)
+            .with_context(|| format!("reading {} (run `anchor build`)", idl_path.display()))?,
+    )
+    .context("parsing IDL")?;
+
+    let bindings_dir = root.join("bindings");
+    let committed_idl_path = root.join("idls/veiled.json");
+    if !check {
+        generate(&root, &idl_path, &idl, &bindings_dir)?;
+        fs::create_dir_all(root.join("idls"))?;
+        fs::write(
+            &committed_idl_path,
+            serde_json::to_string_pretty(&idl)? + "\n",
+        )?;
+        println!("bindings regenerated in {}", bindings_dir.display());
+        return Ok(());
+    }
+
+    // * Regenerate into scratch space and compare against what's checked in
+    let scratch = root.join("target/bindings-check");
+    generate(&root, &idl_path, &idl, &scratch)?;
+    let mut stale: Vec<String> = diff_trees(&bindings_dir, &scratch)?
+        .into_iter()
+        .map(|path| format!("bindings/{path}"))
+        .collect();
+    // * Compared as JSON so formatting differences don't count
+    let committed_idl: Option<Value> = fs::read_to_string(&committed_idl_path)
+        .ok()
+        .and_then(|contents| serde_json::from_str(&contents).ok());
+    if committed_idl.as_ref() != Some(&idl) {
+        stale.push("idls/veiled.json".to_string());
+    }
+    if !stale.is_empty() {
+        for path in &stale {
+            eprintln!("stale: {path}");
+        }
+        bail!("bindings are out of date with the IDL; run `cargo xtask bindings` and commit the result");
+    }
+    println!("bindings are up to date");
+    Ok(())
+}
+
+/// * Writes ts/, python/ and kotlin/ under `out`, replacing any previous output
+fn generate(root: &Path, idl_path: &Path, idl: &Value, out: &Path) -> Result<()> {
+    let program_id = idl["address"]
+        .as_str()
+        .context("IDL has no program address")?;
+
+    let ts = out.join("ts");
+    let python = out.join("python");
+    let kotlin = out.join("kotlin");
+    for dir in [&ts, &python, &kotlin] {
+        if dir.exists() {
+            fs::remove_dir_all(dir).with_context(|| format!("clearing {}", dir.display()))?;
+        }
+        fs::create_dir_all(dir)?;
+    }
+
+    run(Command::new("npx")
+        .args(["--yes", "anchor-client-gen"])
+        .arg(idl_path)
+        .arg(&ts)
+        .args(["--program-id", program_id])
+        .current_dir(root))?;
+
+    run(Command::new("anchorpy")
+        .arg("client-gen")
+        .arg(idl_path)
+        .arg(&python)
+        .args(["--program-id", program_id])
+        .current_dir(root))?;
+
+    fs::write(kotlin.join("Veiled.kt"), kotlin::render(idl)?)?;
+    Ok(())
+}
+
+fn run(command: &mut Command) -> Result<()> {
+    let status = command
+        .status()
+        .with_context(|| format!("spawning {:?}", command.get_program()))?;
+    if !status.success() {
+        bail!("{:?} exited with {status}", command.get_program());
+    }
+    Ok(())
+}
+
+/// * Relative paths that differ between two trees (added, removed or changed)
+fn diff_trees(expected: &Path, actual: &Path) -> Result<Vec<String>> {
+    let expected = read_tree(expected)?;
+    let actual = read_tree(actual)?;
+
+    let mut stale: Vec<String> = expected
+        .iter()
+        .filter(|(path, contents)| actual.get(*path) != Some(contents))
+        .map(|(path, _)| path.clone())
+        .collect();
+    stale.extend(
+        actual
+            .keys()
+            .filter(|path| !expected.contains_key(*path))
+            .cloned(),
+    );
+    stale.sort();
+    Ok(stale)
+}
+
+fn read_tree(root: &Path) -> Result<BTreeMap<String, Vec<u8>>> {
+    let mut files = BTreeMap::new();
+    if root.exists() {
+        collect(root, root, &mut files)?;
+    }
+    Ok(files)
+}
+
+fn collect(root: &Path, dir: &Path, files: &mut BTreeMap<String, Vec<u8>>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect(root, &path, files)?;
+        } else {
+            let relative = path
+                .strip_prefix(root)?
+                .to_string_lossy()
+                .replace('\\', "/");
+            // * bindings/README.md is hand-written
+            if relative != "README.md" {
+                files.insert(relative, fs::read(&path)?);
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,547 @@
+// * Deterministic replay of verify_auth transactions
+// * Usage: bun run cli replay <signature> [--url <rpc>] [--program <veiled.so>] [--idl <veiled.json>]
+// *
+// * For debugging user-reported verification failures:
+// * 1. Fetches the transaction and decodes its verify_auth instruction
+// * 2. Walks the checks verify_auth performs, in program order, printing which passed:
+// *    nullifier/domain binding, circuit registry, VK pins, verifier set, every Ed25519
+// *    instruction (layout, message, signer, signature), quorum and freshness
+// * 3. Re-executes the transaction in a local bank (solana-bankrun) loaded with the deployed
+// *    program (or --program), the fetched accounts and a clock set to the original block time,
+// *    and prints the program logs
+// *
+// * Limits: RPC nodes only serve current account state, so registries are read as they are
+// * now, not as of the transaction's slot. The nullifier account and result receipt are
+// * replayed as empty, i.e. as they were before the transaction created them. The bank simulates the transaction, which
+// * skips fee-payer signatures and precompile checks; step 2 verifies the Ed25519 signatures.
+
+import { AnchorProvider, Program, utils, type Idl } from '@coral-xyz/anchor';
+import {
+  Connection,
+  Ed25519Program,
+  PublicKey,
+  SystemProgram,
+  VersionedTransaction,
+  clusterApiUrl,
+  type AddressLookupTableAccount,
+  type MessageAccountKeys,
+  type MessageCompiledInstruction
+} from '@solana/web3.js';
+import { Clock, start, type AddedAccount } from 'solana-bankrun';
+import nacl from 'tweetnacl';
+import { createHash } from 'node:crypto';
+import { existsSync, mkdtempSync, readFileSync, writeFileSync } from 'node:fs';
+import { tmpdir } from 'node:os';
+import { dirname, join } from 'node:path';
+import { fileURLToPath } from 'node:url';
+import {
+  buildVerificationMessage,
+  decodeVerificationResult,
+  encodeVerificationResult,
+  type VerificationMessageFields
+} from '../src/solana/verification-message.js';
+import { VEILED_PROGRAM_ID, deriveDomainConfigPda } from '../src/solana/program.js';
+
+const __dirname = dirname(fileURLToPath(import.meta.url));
+
+// * Mirrors of program constants (ultrahonk.rs, sandbox.rs)
+const MAX_RESULT_AGE_SECS = 5 * 60;
+const MAX_ED25519_INSTRUCTIONS = 8;
+const SANDBOX_DOMAIN = 'sandbox';
+
+// * Upgradeable loader ProgramData header: tag (4) + slot (8) + authority option (1 + 32)
+const PROGRAM_DATA_HEADER_LEN = 45;
+
+const SYSVAR_OWNER = new PublicKey('Sysvar1111111111111111111111111111111111111');
+
+const instructionDiscriminator = (name: string) =>
+  createHash('sha256').update(`global:${name}`).digest().subarray(0, 8);
+const VERIFY_AUTH_DISCRIMINATOR = instructionDiscriminator('verify_auth');
+const VERIFY_AUTH_V1_DISCRIMINATOR = instructionDiscriminator('verify_auth_v1');
+
+export interface ReplayOptions {
+  url?: string;
+  programPath?: string;
+  idlPath?: string;
+}
+
+// * Collects pass/fail lines; any failure marks the replay as a rejected verification
+class Trace {
+  ok = true;
+
+  check(passed: boolean, label: string, detail?: string): boolean {
+    if (!passed) this.ok = false;
+    console.log(`  ${passed ? '✓' : '✗'} ${label}${detail ? ` (${detail})` : ''}`);
+    return passed;
+  }
+
+  note(label: string): void {
+    console.log(`  · ${label}`);
+  }
+}
+
+interface VerifyAuthArgs {
+  result: Uint8Array;
+  nullifier: Uint8Array;
+  domain: Uint8Array;
+  envelope: boolean; // * Sent as verify_auth_v1's byte envelope, which must be tagged (V9)
+}
+
+interface Ed25519Entry {
+  pubkey: PublicKey;
+  message: Uint8Array;
+  signature: Uint8Array;
+}
+
+// * Key set verify_auth checks signatures against, as of `now`
+interface VerifierSet {
+  source: string;
+  verifiers: PublicKey[];
+  retiring: Map<string, PublicKey>; // * Rotated-out key (base58) -> key it now counts as
+  threshold: number;
+}
+
+function hex(bytes: Uint8Array): string {
+  return Buffer.from(bytes).toString('hex');
+}
+
+function bytesEqual(a: Uint8Array, b: Uint8Array): boolean {
+  return Buffer.from(a).equals(Buffer.from(b));
+}
+
+function domainString(domain: Uint8Array): string {
+  const end = domain.indexOf(0);
+  return Buffer.from(domain.subarray(0, end === -1 ? domain.length : end)).toString('utf8');
+}
+
+function pda(...seeds: Uint8Array[]): PublicKey {
+  return PublicKey.findProgramAddressSync(seeds, VEILED_PROGRAM_ID)[0];
+}
+
+// * Borsh layout of verify_auth_v1(verification_result: Vec<u8>, nullifier: [u8; 32], domain: [u8; 32])
+function decodeVerifyAuthV1Args(data: Uint8Array): VerifyAuthArgs {
+  const buffer = Buffer.from(data);
+  const resultLen = buffer.readUInt32LE(8);
+  const resultEnd = 12 + resultLen;
+  if (buffer.length !== resultEnd + 64) {
+    throw new Error(`verify_auth_v1 data is ${buffer.length} bytes, expected ${resultEnd + 64}`);
+  }
+  return {
+    result: buffer.subarray(12, resultEnd),
+    nullifier: buffer.subarray(resultEnd, resultEnd + 32),
+    domain: buffer.subarray(resultEnd + 32, resultEnd + 64),
+    envelope: true
+  };
+}
+
+// * Borsh layout of verify_auth(result: SignedVerificationResult, nullifier, domain, expires_in)
+// * The result is re-encoded as the byte envelope, so both instructions trace the same way
+function decodeVerifyAuthArgs(data: Uint8Array): VerifyAuthArgs {
+  const buffer = Buffer.from(data);
+  let offset = 8;
+  const take = (len: number) => {
+    if (offset + len > buffer.length) {
+      throw new Error(`verify_auth data is ${buffer.length} bytes, ran out at offset ${offset}`);
+    }
+    offset += len;
+    return buffer.subarray(offset - len, offset);
+  };
+  const option = <T>(read: () => T): T | undefined => (take(1)[0] ? read() : undefined);
+
+  const fields: VerificationMessageFields = {
+    isValid: take(1)[0] === 1,
+    proofHash: take(32),
+    timestamp: take(8).readBigUInt64LE(),
+    nullifier: take(32),
+    domain: take(32)
+  };
+  fields.publicInputsHash = option(() => take(32));
+  fields.circuitId = option(() => take(4).readUInt32LE());
+  fields.vkHash = option(() => take(32));
+  fields.clusterId = option(() => take(32));
+  fields.instanceId = option(() => take(32));
+  fields.claimType = option(() => take(1)[0]); // * Borsh enum tag is the signed claim byte
+  fields.tagged = take(1)[0] === 1 || undefined;
+  const signature = take(64);
+  const args = { nullifier: take(32), domain: take(32) };
+  take(8); // * expires_in: sets the session's lifetime, no bearing on whether it verifies
+  if (offset !== buffer.length) {
+    throw new Error(`verify_auth data is ${buffer.length} bytes, expected ${offset}`);
+  }
+  return { result: encodeVerificationResult(fields, signature), ...args, envelope: false };
+}
+
+// * Same layout rules ed25519_ix_signer enforces: one signature, all data inline (offsets u16::MAX)
+function parseEd25519Instruction(ix: MessageCompiledInstruction): Ed25519Entry | string {
+  const data = Buffer.from(ix.data);
+  if (ix.accountKeyIndexes.length !== 0) return 'has accounts';
+  if (data.length < 16) return 'data shorter than the 16-byte header';
+  if (data[0] !== 1) return `${data[0]} signatures, expected exactly 1`;
+
+  const [sigOffset, sigIx, pkOffset, pkIx, msgOffset, msgSize, msgIx] = Array.from(
+    { length: 7 },
+    (_, i) => data.readUInt16LE(2 + i * 2)
+  );
+  if (sigIx !== 0xffff || pkIx !== 0xffff || msgIx !== 0xffff) {
+    return 'references data in another instruction';
+  }
+  if (sigOffset < 16 || pkOffset < 16 || msgOffset < 16) return 'offsets overlap the header';
+  if (
+    sigOffset + 64 > data.length ||
+    pkOffset + 32 > data.length ||
+    msgOffset + msgSize > data.length
+  ) {
+    return 'offsets out of bounds';
+  }
+
+  return {
+    pubkey: new PublicKey(data.subarray(pkOffset, pkOffset + 32)),
+    message: data.subarray(msgOffset, msgOffset + msgSize),
+    signature: data.subarray(sigOffset, sigOffset + 64)
+  };
+}
+
+// * Registry keys plus rotated-out keys still in their grace window (VerifierRegistry::retiring_keys)
+function registrySet(registry: any, now: number): VerifierSet {
+  const verifiers: PublicKey[] = registry.verifiers;
+  const registered = (key: PublicKey) => verifiers.some((verifier) => verifier.equals(key));
+  const rotations: any[] = registry.rotations ?? [];
+  const grace = Number(registry.gracePeriodSecs ?? 0);
+
+  const successor = (key: PublicKey): PublicKey | null => {
+    for (let hop = 0; hop <= rotations.length; hop++) {
+      if (registered(key)) return key;
+      const next = rotations.find((rotation) => rotation.oldKey.equals(key));
+      if (!next) return null;
+      key = next.newKey;
+    }
+    return null;
+  };
+
+  const retiring = new Map<string, PublicKey>();
+  for (const rotation of rotations) {
+    const current = successor(rotation.newKey);
+    if (now < Number(rotation.rotatedAt) + grace && current) {
+      retiring.set(rotation.oldKey.toBase58(), current);
+    }
+  }
+
+  return {
+    source: 'VerifierRegistry',
+    verifiers,
+    retiring,
+    threshold: Math.max(registry.threshold, 1)
+  };
+}
+
+async function loadIdl(connection: Connection, idlPath?: string): Promise<Idl> {
+  const path = idlPath ?? join(__dirname, '../../anchor/target/idl/veiled.json');
+  if (existsSync(path)) {
+    return JSON.parse(readFileSync(path, 'utf-8'));
+  }
+  const idl = await Program.fetchIdl(VEILED_PROGRAM_ID, { connection } as AnchorProvider);
+  if (!idl) {
+    throw new Error(`No IDL at ${path} and none published on-chain; pass --idl`);
+  }
+  return idl;
+}
+
+// * ELF of the deployed (upgradeable) program
+async function fetchProgramElf(connection: Connection): Promise<Buffer> {
+  const program = await connection.getAccountInfo(VEILED_PROGRAM_ID);
+  if (!program) {
+    throw new Error(`Program ${VEILED_PROGRAM_ID.toBase58()} not found on this cluster`);
+  }
+  const programData = await connection.getAccountInfo(
+    new PublicKey(program.data.subarray(4, 36))
+  );
+  if (!programData) {
+    throw new Error('Program data account not found');
+  }
+  return programData.data.subarray(PROGRAM_DATA_HEADER_LEN);
+}
+
+async function fetchAccounts(connection: Connection, keys: PublicKey[]): Promise<AddedAccount[]> {
+  const accounts: AddedAccount[] = [];
+  for (let i = 0; i < keys.length; i += 100) {
+    const batch = keys.slice(i, i + 100);
+    const infos = await connection.getMultipleAccountsInfo(batch);
+    infos.forEach((info, j) => {
+      // * Builtins and sysvars come with the bank; Veiled itself is loaded as a program
+      if (info && !info.executable && !info.owner.equals(SYSVAR_OWNER)) {
+        accounts.push({ address: batch[j], info });
+      }
+    });
+  }
+  return accounts;
+}
+
+// * Step 2: the checks verify_auth performs, in program order
+function traceChecks(
+  trace: Trace,
+  args: VerifyAuthArgs,
+  authority: PublicKey,
+  ed25519: MessageCompiledInstruction[],
+  blockTime: number,
+  accounts: { circuitRegistry: any; domainConfig: any; verifierRegistry: any }
+): void {
+  let fields: VerificationMessageFields;
+  let signature: Uint8Array;
+  try {
+    ({ fields, signature } = decodeVerificationResult(args.result));
+  } catch (error) {
+    trace.check(false, 'Verification result parses', (error as Error).message);
+    return;
+  }
+  const version = args.result[0] > 1 ? args.result[0] : 1;
+  trace.check(true, 'Verification result parses', `V${version}, ${args.result.length} bytes`);
+  if (args.envelope) {
+    trace.check(fields.tagged === true, 'Byte envelope is tagged (V9)');
+  }
+  trace.note(`proof_hash ${hex(fields.proofHash)}, timestamp ${fields.timestamp}`);
+
+  trace.check(bytesEqual(fields.nullifier, args.nullifier), 'Result nullifier matches the argument');
+  trace.check(bytesEqual(fields.domain, args.domain), 'Result domain matches the argument');
+
+  if (domainString(args.domain) === SANDBOX_DOMAIN) {
+    trace.note('Sandbox domain: circuit, signature and freshness checks skipped (devnet only)');
+    trace.check(fields.isValid, 'Result is_valid');
+    return;
+  }
+
+  // * Circuit registry and VK pins
+  const circuitId = fields.circuitId ?? 0;
+  const circuit = accounts.circuitRegistry?.circuits.find(
+    (entry: any) => entry.circuitId === circuitId
+  );
+  trace.check(
+    !!circuit && !circuit.deprecated,
+    `Circuit ${circuitId} registered and active`,
+    !circuit ? 'not registered' : circuit.deprecated ? 'deprecated' : undefined
+  );
+  if (fields.vkHash && circuit) {
+    trace.check(
+      bytesEqual(fields.vkHash, Uint8Array.from(circuit.vkHash)),
+      'Result vk_hash matches the registered circuit'
+    );
+  }
+  const pinned = accounts.domainConfig
+    ? Uint8Array.from(accounts.domainConfig.pinnedVkHash)
+    : null;
+  if (pinned && pinned.some((byte) => byte !== 0)) {
+    trace.check(
+      !!fields.vkHash && bytesEqual(fields.vkHash, pinned),
+      'Result vk_hash matches the domain pin',
+      hex(pinned)
+    );
+  }
+
+  // * Verifier set: the domain's own keys, or the global registry (submitter must be in it)
+  let set: VerifierSet;
+  if (accounts.domainConfig?.verifiers.length) {
+    set = {
+      source: 'DomainConfig',
+      verifiers: accounts.domainConfig.verifiers,
+      retiring: new Map(),
+      threshold: 1
+    };
+  } else if (accounts.verifierRegistry) {
+    set = registrySet(accounts.verifierRegistry, blockTime);
+    const accepted =
+      set.verifiers.some((verifier) => verifier.equals(authority)) ||
+      set.retiring.has(authority.toBase58());
+    trace.check(accepted, `Submitter ${authority.toBase58()} is an accepted verifier`);
+  } else {
+    trace.check(false, 'VerifierRegistry exists');
+    return;
+  }
+  trace.note(
+    `${set.source}: ${set.verifiers.length} key(s), ${set.retiring.size} in rotation grace, threshold ${set.threshold}`
+  );
+
+  // * Every Ed25519 instruction before verify_auth must verify the expected message with an accepted key
+  const expectedMessage = buildVerificationMessage(fields);
+  trace.check(
+    ed25519.length <= MAX_ED25519_INSTRUCTIONS,
+    `${ed25519.length} Ed25519 instruction(s)`,
+    `max ${MAX_ED25519_INSTRUCTIONS}`
+  );
+  const signers = new Set<string>();
+  let signatureFound = false;
+  ed25519.forEach((ix, i) => {
+    const entry = parseEd25519Instruction(ix);
+    if (typeof entry === 'string') {
+      trace.check(false, `Ed25519 #${i} layout`, entry);
+      return;
+    }
+    const key = entry.pubkey.toBase58();
+    const current =
+      set.verifiers.find((verifier) => verifier.equals(entry.pubkey)) ?? set.retiring.get(key);
+    const messageOk = trace.check(
+      bytesEqual(entry.message, expectedMessage),
+      `Ed25519 #${i} message matches the result`,
+      `${entry.message.length} bytes, expected ${expectedMessage.length}`
+    );
+    const keyOk = trace.check(
+      !!current,
+      `Ed25519 #${i} signer ${key} accepted`,
+      current && !current.equals(entry.pubkey)
+        ? `rotated out, counts as ${current.toBase58()}`
+        : undefined
+    );
+    const sigOk = trace.check(
+      nacl.sign.detached.verify(entry.message, entry.signature, entry.pubkey.toBytes()),
+      `Ed25519 #${i} signature verifies`
+    );
+    if (messageOk && keyOk && sigOk && current) {
+      signers.add(current.toBase58());
+      signatureFound ||= bytesEqual(entry.signature, signature);
+    }
+  });
+  trace.check(signatureFound, "An Ed25519 instruction carries the result's signature");
+  trace.check(signers.size >= set.threshold, 'Quorum met', `${signers.size} of ${set.threshold} required`);
+
+  const age = blockTime - Number(fields.timestamp);
+  trace.check(
+    age <= MAX_RESULT_AGE_SECS,
+    'Result is fresh at block time',
+    `${age}s old, max ${MAX_RESULT_AGE_SECS}s`
+  );
+  trace.check(fields.isValid, 'Result is_valid');
+}
+
+// * Step 3: re-execute in a local bank and print the program's own view
+async function executeInBank(
+  connection: Connection,
+  transaction: VersionedTransaction,
+  accountKeys: MessageAccountKeys,
+  lookupTables: AddressLookupTableAccount[],
+  freshAccounts: PublicKey[],
+  blockTime: number,
+  slot: number,
+  programPath?: string
+): Promise<void> {
+  const elf = programPath ? readFileSync(programPath) : await fetchProgramElf(connection);
+  const programDir = mkdtempSync(join(tmpdir(), 'veiled-replay-'));
+  writeFileSync(join(programDir, 'veiled.so'), elf);
+  process.env.SBF_OUT_DIR = programDir;
+
+  const keys = [
+    ...accountKeys.staticAccountKeys,
+    ...(accountKeys.accountKeysFromLookups?.writable ?? []),
+    ...(accountKeys.accountKeysFromLookups?.readonly ?? []),
+    ...lookupTables.map((table) => table.key)
+  ].filter(
+    (key) =>
+      !key.equals(VEILED_PROGRAM_ID) &&
+      !freshAccounts.some((fresh) => fresh.equals(key)) &&
+      !key.equals(SystemProgram.programId) &&
+      !key.equals(Ed25519Program.programId)
+  );
+  const accounts = await fetchAccounts(connection, keys);
+
+  const context = await start([{ name: 'veiled', programId: VEILED_PROGRAM_ID }], accounts);
+  context.setClock(new Clock(BigInt(slot), 0n, 0n, 0n, BigInt(blockTime)));
+
+  transaction.message.recentBlockhash = context.lastBlockhash;
+  const { result, meta } = await context.banksClient.simulateTransaction(transaction);
+
+  for (const line of meta?.logMessages ?? []) {
+    console.log(`  ${line}`);
+  }
+  console.log(`  compute units: ${meta?.computeUnitsConsumed ?? 'n/a'}`);
+  console.log(result ? `  ✗ Replay failed: ${result}` : '  ✓ Replay succeeded');
+}
+
+/**
+ * * Replays a verify_auth transaction; resolves true when every traced check passed
+ */
+export async function replay(signature: string, options: ReplayOptions = {}): Promise<boolean> {
+  const connection = new Connection(options.url ?? clusterApiUrl('devnet'), 'confirmed');
+  const tx = await connection.getTransaction(signature, {
+    commitment: 'confirmed',
+    maxSupportedTransactionVersion: 0
+  });
+  if (!tx) {
+    throw new Error(`Transaction ${signature} not found (is --url the right cluster?)`);
+  }
+
+  const message = tx.transaction.message;
+  const lookupTables = (
+    await Promise.all(
+      message.addressTableLookups.map((lookup) => connection.getAddressLookupTable(lookup.accountKey))
+    )
+  ).flatMap(({ value }) => (value ? [value] : []));
+  const accountKeys = message.getAccountKeys({ accountKeysFromLookups: tx.meta?.loadedAddresses });
+
+  const instructions = message.compiledInstructions;
+  const verifyAuthIndex = instructions.findIndex(
+    (ix) =>
+      accountKeys.get(ix.programIdIndex)?.equals(VEILED_PROGRAM_ID) &&
+      [VERIFY_AUTH_DISCRIMINATOR, VERIFY_AUTH_V1_DISCRIMINATOR].some((discriminator) =>
+        Buffer.from(ix.data.subarray(0, 8)).equals(discriminator)
+      )
+  );
+  if (verifyAuthIndex === -1) {
+    throw new Error('Transaction has no verify_auth instruction');
+  }
+  const verifyAuth = instructions[verifyAuthIndex];
+  const args = Buffer.from(verifyAuth.data.subarray(0, 8)).equals(VERIFY_AUTH_DISCRIMINATOR)
+    ? decodeVerifyAuthArgs(verifyAuth.data)
+    : decodeVerifyAuthV1Args(verifyAuth.data);
+  // * After nullifier_account, proof_record and result_receipt
+  const authority = accountKeys.get(verifyAuth.accountKeyIndexes[3])!;
+  const blockTime = tx.blockTime ?? Math.floor(Date.now() / 1000);
+  const ed25519 = instructions
+    .slice(0, verifyAuthIndex)
+    .filter((ix) => accountKeys.get(ix.programIdIndex)?.equals(Ed25519Program.programId));
+
+  console.log(`Transaction ${signature}`);
+  console.log(`  slot ${tx.slot}, block time ${tx.blockTime ?? 'unknown'}`);
+  console.log(`  on-chain result: ${tx.meta?.err ? `failed ${JSON.stringify(tx.meta.err)}` : 'success'}`);
+  console.log(`  domain "${domainString(args.domain)}", nullifier ${hex(args.nullifier)}`);
+
+  const idl = await loadIdl(connection, options.idlPath);
+  const program = new Program(idl, { connection } as AnchorProvider);
+  const decode = async (name: string, address: PublicKey) => {
+    const info = await connection.getAccountInfo(address);
+    return info?.owner.equals(VEILED_PROGRAM_ID)
+      ? program.coder.accounts.decode(name, info.data)
+      : null;
+  };
+  const registries = {
+    circuitRegistry: await decode('CircuitRegistry', pda(Buffer.from('circuit_registry'))),
+    domainConfig: await decode('DomainConfig', await deriveDomainConfigPda(args.domain)),
+    verifierRegistry: await decode('VerifierRegistry', pda(Buffer.from('verifier_registry')))
+  };
+
+  console.log('\nValidation trace (registries as of now, clock at block time):');
+  const trace = new Trace();
+  traceChecks(trace, args, authority, ed25519, blockTime, registries);
+
+  console.log('\nBank replay:');
+  await executeInBank(
+    connection,
+    new VersionedTransaction(
+      message,
+      tx.transaction.signatures.map((sig) => utils.bytes.bs58.decode(sig))
+    ),
+    accountKeys,
+    lookupTables,
+    [
+      // * Replayed as before the transaction created it
+      pda(Buffer.from('nullifier'), args.nullifier),
+      pda(
+        Buffer.from('result_receipt'),
+        createHash('sha256').update(args.result.subarray(args.result.length - 64)).digest()
+      ),
+      // * Only the payer's current window is on-chain, so the replay starts from an empty one
+      pda(Buffer.from('rate_limit'), authority.toBuffer())
+    ],
+    blockTime,
+    tx.slot,
+    options.programPath
+  );
+
+  return trace.ok;
+}
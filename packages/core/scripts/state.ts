@@ -0,0 +1,213 @@
+// * Devnet state snapshot / restore
+// * Usage: bun run cli snapshot-state --domain <domain> --out <file> [--url <rpc>] [--idl <veiled.json>]
+// *        bun run cli restore-state <file> [--url <rpc>] [--idl <veiled.json>] [--keypair <path>]
+// *
+// * Devnet and local validators get reset, taking weeks of test fixtures with them. snapshot-state
+// * saves everything one domain owns:
+// * - its DomainConfig
+// * - every session (NullifierAccount) and proof record registered for it
+// * - every permission grant issued to those sessions
+// *
+// * restore-state writes them back through the admin-only import_* instructions, which only
+// * `--features devnet` builds have. Run it as the ProtocolConfig authority, with an IDL built
+// * from that feature set (`anchor build -- --features devnet`); the committed mainnet IDL has
+// * no import instructions. Imports overwrite existing accounts, so a failed restore can simply
+// * be rerun.
+
+import { AnchorProvider, Program, Wallet, utils, type Idl } from '@coral-xyz/anchor';
+import {
+  Connection,
+  Keypair,
+  PublicKey,
+  Transaction,
+  clusterApiUrl,
+  type TransactionInstruction
+} from '@solana/web3.js';
+import { existsSync, readFileSync, writeFileSync } from 'node:fs';
+import { homedir } from 'node:os';
+import { dirname, join } from 'node:path';
+import { fileURLToPath } from 'node:url';
+import { VEILED_PROGRAM_ID, deriveDomainConfigPda } from '../src/solana/program.js';
+
+const __dirname = dirname(fileURLToPath(import.meta.url));
+
+// * 2: NullifierAccount stores its domain as 32 null-padded bytes plus a length
+const SNAPSHOT_VERSION = 2;
+
+// * Imports per transaction; the largest (sessions) keep four well under the size limit
+const IMPORTS_PER_TRANSACTION = 4;
+
+// * Snapshotted account types, in restore order, with the instruction that recreates each
+const IMPORTS = [
+  { account: 'DomainConfig', instruction: 'importDomainConfig' },
+  { account: 'ProofRecord', instruction: 'importProofRecord' },
+  { account: 'NullifierAccount', instruction: 'importSession' },
+  { account: 'PermissionGrant', instruction: 'importPermissionGrant' }
+] as const;
+
+type AccountName = (typeof IMPORTS)[number]['account'];
+
+interface SnapshotAccount {
+  account: AccountName;
+  address: string;
+  data: string; // * Base64 account data, discriminator included
+}
+
+interface Snapshot {
+  version: number;
+  domain: string;
+  takenAt: string;
+  accounts: SnapshotAccount[];
+}
+
+export interface StateOptions {
+  url?: string;
+  idlPath?: string;
+  keypairPath?: string;
+}
+
+function loadIdl(idlPath?: string): Idl {
+  const path = idlPath ?? join(__dirname, '../../anchor/target/idl/veiled.json');
+  if (!existsSync(path)) {
+    throw new Error(`No IDL at ${path}; pass --idl`);
+  }
+  return JSON.parse(readFileSync(path, 'utf-8'));
+}
+
+// * Same encoding as the verify_auth `domain` argument
+function domainBytes(domain: string): Buffer {
+  const bytes = Buffer.from(domain, 'utf8');
+  if (bytes.length === 0 || bytes.length > 32) {
+    throw new Error('Domain must be 1-32 bytes');
+  }
+  return Buffer.concat([bytes, Buffer.alloc(32 - bytes.length)]);
+}
+
+/**
+ * * Saves a domain's config, sessions, proof records and permission grants to `out`
+ */
+export async function snapshotState(domain: string, out: string, options: StateOptions = {}) {
+  const connection = new Connection(options.url ?? clusterApiUrl('devnet'), 'confirmed');
+  const program = new Program(loadIdl(options.idlPath), { connection } as AnchorProvider);
+  const padded = domainBytes(domain);
+
+  // * Accounts of one type, optionally narrowed by a byte match after the discriminator
+  const fetchAll = async (account: AccountName, match?: { offset: number; bytes: Buffer }) => {
+    const accounts = await connection.getProgramAccounts(VEILED_PROGRAM_ID, {
+      filters: [
+        { memcmp: program.coder.accounts.memcmp(account) },
+        ...(match
+          ? [{ memcmp: { offset: match.offset, bytes: utils.bytes.bs58.encode(match.bytes) } }]
+          : [])
+      ]
+    });
+    return accounts.map(({ pubkey, account: info }) => ({
+      account,
+      address: pubkey.toBase58(),
+      data: info.data.toString('base64')
+    }));
+  };
+
+  const domainConfig = await deriveDomainConfigPda(padded);
+  const configInfo = await connection.getAccountInfo(domainConfig);
+
+  // * NullifierAccount: discriminator (8) + nullifier (32) + domain (32)
+  const sessions = await fetchAll('NullifierAccount', { offset: 40, bytes: padded });
+
+  // * ProofRecord: discriminator (8) + proof_hash (32) + nullifier (32) + domain (32)
+  const proofRecords = await fetchAll('ProofRecord', { offset: 72, bytes: padded });
+
+  // * PermissionGrant: discriminator (8) + nullifier (32), for this domain's sessions only
+  const nullifiers = new Set(
+    sessions.map(({ data }) => Buffer.from(data, 'base64').subarray(8, 40).toString('hex'))
+  );
+  const grants = (await fetchAll('PermissionGrant')).filter(({ data }) =>
+    nullifiers.has(Buffer.from(data, 'base64').subarray(8, 40).toString('hex'))
+  );
+
+  const snapshot: Snapshot = {
+    version: SNAPSHOT_VERSION,
+    domain,
+    takenAt: new Date().toISOString(),
+    accounts: [
+      ...(configInfo?.owner.equals(VEILED_PROGRAM_ID)
+        ? [
+            {
+              account: 'DomainConfig' as const,
+              address: domainConfig.toBase58(),
+              data: configInfo.data.toString('base64')
+            }
+          ]
+        : []),
+      ...proofRecords,
+      ...sessions,
+      ...grants
+    ]
+  };
+  writeFileSync(out, JSON.stringify(snapshot, null, 2) + '\n');
+
+  console.log(`Snapshot of "${domain}" written to ${out}`);
+  console.log(`  domain config: ${configInfo ? 'yes' : 'none'}`);
+  console.log(`  sessions: ${sessions.length}, proof records: ${proofRecords.length}`);
+  console.log(`  permission grants: ${grants.length}`);
+}
+
+/**
+ * * Recreates a snapshot's accounts through the import_* instructions (devnet builds only)
+ */
+export async function restoreState(file: string, options: StateOptions = {}) {
+  const snapshot: Snapshot = JSON.parse(readFileSync(file, 'utf-8'));
+  if (snapshot.version !== SNAPSHOT_VERSION) {
+    throw new Error(`Unsupported snapshot version ${snapshot.version}`);
+  }
+
+  const idl = loadIdl(options.idlPath);
+  const missing = IMPORTS.filter(
+    ({ instruction }) => !idl.instructions.some((ix) => camelCase(ix.name) === instruction)
+  );
+  if (missing.length > 0) {
+    throw new Error(
+      'IDL has no import_* instructions; build it with `anchor build -- --features devnet`'
+    );
+  }
+
+  const keypairPath = options.keypairPath ?? join(homedir(), '.config/solana/id.json');
+  const authority = Keypair.fromSecretKey(
+    Uint8Array.from(JSON.parse(readFileSync(keypairPath, 'utf-8')))
+  );
+  const connection = new Connection(options.url ?? clusterApiUrl('devnet'), 'confirmed');
+  const provider = new AnchorProvider(connection, new Wallet(authority), {
+    commitment: 'confirmed'
+  });
+  const program = new Program(idl, provider);
+
+  const instructions: TransactionInstruction[] = [];
+  for (const { account, instruction } of IMPORTS) {
+    for (const entry of snapshot.accounts.filter((entry) => entry.account === account)) {
+      const decoded = program.coder.accounts.decode(account, Buffer.from(entry.data, 'base64'));
+      instructions.push(
+        await program.methods[instruction](decoded)
+          .accounts({
+            authority: authority.publicKey,
+            // * Session and config seeds hash the domain, which the IDL can't express; reuse
+            // * the address
+            ...(account === 'NullifierAccount' && { nullifierAccount: new PublicKey(entry.address) }),
+            ...(account === 'DomainConfig' && { domainConfig: new PublicKey(entry.address) })
+          })
+          .instruction()
+      );
+    }
+  }
+
+  for (let i = 0; i < instructions.length; i += IMPORTS_PER_TRANSACTION) {
+    const batch = instructions.slice(i, i + IMPORTS_PER_TRANSACTION);
+    const signature = await provider.sendAndConfirm(new Transaction().add(...batch));
+    console.log(`  imported ${i + batch.length}/${instructions.length} (${signature})`);
+  }
+
+  console.log(`Restored ${instructions.length} accounts of "${snapshot.domain}"`);
+}
+
+function camelCase(name: string): string {
+  return name.replace(/_([a-z])/g, (_, letter: string) => letter.toUpperCase());
+}
@@ -0,0 +1,99 @@
+// * Veiled support CLI
+// * Usage: bun run cli <command> [options]
+// *
+// * Commands:
+// *   replay <signature> [--url <rpc>] [--program <veiled.so>] [--idl <veiled.json>]
+// *       Re-execute a historical verify_auth transaction against a local bank and print
+// *       the validation trace (see scripts/replay.ts)
+// *   snapshot-state --domain <domain> --out <file> [--url <rpc>] [--idl <veiled.json>]
+// *   restore-state <file> [--url <rpc>] [--idl <veiled.json>] [--keypair <path>]
+// *       Save a domain's accounts and rebuild them after a devnet reset (see scripts/state.ts)
+
+import { replay } from './replay.js';
+import { restoreState, snapshotState } from './state.js';
+
+const USAGE = `usage: bun run cli <command>
+
+commands:
+  replay <signature>   re-execute a verify_auth transaction and trace its checks
+    --url <rpc>        RPC the transaction and accounts are fetched from (default: devnet)
+    --program <path>   run this veiled.so instead of the program deployed on that cluster
+    --idl <path>       IDL used to decode Veiled accounts (default: anchor target/idl/veiled.json)
+  snapshot-state       save a domain's config, sessions, proof records and grants to a file
+    --domain <domain>  domain to snapshot
+    --out <file>       snapshot file to write
+    --url <rpc>        cluster to read from (default: devnet)
+    --idl <path>       IDL used to decode Veiled accounts
+  restore-state <file> recreate a snapshot's accounts (devnet builds, ProtocolConfig authority)
+    --url <rpc>        cluster to write to (default: devnet)
+    --idl <path>       IDL with the import_* instructions (anchor build -- --features devnet)
+    --keypair <path>   authority keypair (default: ~/.config/solana/id.json)`;
+
+// * Splits `--flag value` pairs from positional arguments
+function parseArgs(args: string[]): { positional: string[]; flags: Record<string, string> } {
+  const positional: string[] = [];
+  const flags: Record<string, string> = {};
+  for (let i = 0; i < args.length; i++) {
+    if (args[i].startsWith('--')) {
+      const value = args[i + 1];
+      if (value === undefined || value.startsWith('--')) {
+        throw new Error(`${args[i]} needs a value`);
+      }
+      flags[args[i].slice(2)] = value;
+      i++;
+    } else {
+      positional.push(args[i]);
+    }
+  }
+  return { positional, flags };
+}
+
+async function main(): Promise<number> {
+  const [command, ...rest] = process.argv.slice(2);
+  const { positional, flags } = parseArgs(rest);
+
+  switch (command) {
+    case 'replay': {
+      if (positional.length !== 1) {
+        console.error(USAGE);
+        return 2;
+      }
+      const ok = await replay(positional[0], {
+        url: flags.url,
+        programPath: flags.program,
+        idlPath: flags.idl
+      });
+      return ok ? 0 : 1;
+    }
+    case 'snapshot-state': {
+      if (positional.length !== 0 || !flags.domain || !flags.out) {
+        console.error(USAGE);
+        return 2;
+      }
+      await snapshotState(flags.domain, flags.out, { url: flags.url, idlPath: flags.idl });
+      return 0;
+    }
+    case 'restore-state': {
+      if (positional.length !== 1) {
+        console.error(USAGE);
+        return 2;
+      }
+      await restoreState(positional[0], {
+        url: flags.url,
+        idlPath: flags.idl,
+        keypairPath: flags.keypair
+      });
+      return 0;
+    }
+    default:
+      console.error(USAGE);
+      return 2;
+  }
+}
+
+main()
+  .then((code) => process.exit(code))
+  .catch((error) => {
+    console.error('❌', error instanceof Error ? error.message : error);
+    process.exit(1);
+  });
@@ -25,6 +25,7 @@ export {
   verifyProof,
   createVerificationResult,
   hashProofAsync,
+  hashPublicInputsAsync,
   exportVerificationKey, 
   exportVerificationKeyAsJson 
 } from './proof/generator.js';
@@ -41,13 +42,50 @@ export type {
 export { 
   submitVerificationResultToChain,
   submitProofToChain, // * Deprecated
-  verifyNullifierOnChain 
+  verifyNullifierOnChain,
+  getRentTopUpLamports,
+  topUpRentOnChain,
+  deriveVerifyAuthAccounts,
+  deriveNullifierPda,
+  deriveDomainConfigPda,
+  decodeSessionDomain,
+  normalizeDomain
 } from './solana/program.js';
+export {
+  prewarmVerification,
+  NULLIFIER_ACCOUNT_SPACE,
+  PAYER_RATE_LIMIT_SPACE
+} from './solana/warm-path.js';
+export type { PrewarmVerificationOptions, WarmVerification } from './solana/warm-path.js';
+export {
+  buildVerificationMessage,
+  encodeVerificationResult,
+  decodeVerificationResult,
+  VERIFICATION_MESSAGE_LEN,
+  LEGACY_VERIFICATION_MESSAGE_LEN,
+  VERIFICATION_RESULT_LEN,
+  VERIFICATION_RESULT_VERSION,
+  MESSAGE_TAG,
+  CIRCUIT_IDS,
+  CLAIM_TYPES,
+  CLUSTER_IDS,
+  instanceIdFor
+} from './solana/verification-message.js';
+export type { VerificationMessageFields } from './solana/verification-message.js';
+export {
+  selectVerifier,
+  selectVerifierFrom,
+  decodeRegisteredVerifiers,
+  decodeVerifierWeights,
+  VERIFIER_LIVENESS_WINDOW_SECS
+} from './solana/verifier-selection.js';
+export type { SelectVerifierOptions, VerifierWeightEntry } from './solana/verifier-selection.js';
 export type { 
   SubmitVerificationResultOptions,
   SubmitVerificationResultResponse,
   SubmitProofOptions, // * Deprecated
-  SubmitProofResult 
+  SubmitProofResult,
+  VerifyAuthAccounts
 } from './solana/program.js';
 
 // * Widget exports
@@ -26,6 +26,7 @@ import type { Connection } from '@solana/web3.js';
 import { PublicKey } from '@solana/web3.js';
 import type { Wallet } from '@coral-xyz/anchor';
 import { logger } from '../utils/logger.js';
+import { encodeVerificationResult } from '../solana/verification-message.js';
 import { QuicknodeClient } from '../providers/quicknode-client.js';
 
 // * Beta.3: No WASM initialization needed - Noir.js handles it internally
@@ -1110,42 +1111,68 @@ export async function verifyProof(
 
 /**
  * * Creates verification result data for Solana program
- * * This includes the proof hash, verification status, timestamp, and signature
+ * * This includes the proof hash, verification status, timestamp, nullifier, domain, public inputs hash, and signature
  * * 
  * * @param proof - The proof bytes
  * * @param isValid - Whether the proof is valid (from verifyProof)
- * * @param signature - Ed25519 signature from wallet (64 bytes)
- * * @returns Verification result data (105 bytes) ready for Solana instruction
+ * * @param signature - Ed25519 signature from wallet (64 bytes) over the same fields
+ * * @param nullifier - Nullifier the result is issued for (32 bytes)
+ * * @param timestamp - Unix timestamp (seconds) that was signed
+ * * @param domain - Null-padded domain the result is issued for (32 bytes)
+ * * @param publicInputsHash - Hash of the proof's public inputs (32 bytes, from hashPublicInputsAsync)
+ * * @param circuitId - CircuitRegistry ID of the circuit the proof was generated with (see CIRCUIT_IDS)
+ * * @param vkHash - Optional SHA-256 of the circuit's verification key; required by domains that pin one
+ * * @param clusterId - Optional cluster to bind the result to (see CLUSTER_IDS); requires vkHash
+ * * @param instanceId - Optional Veiled instance to bind the result to (see instanceIdFor); requires clusterId
+ * * @returns Versioned verification result data (206 bytes, 238 with vkHash, 270 with clusterId, 302 with instanceId) ready for Solana instruction
  */
 export async function createVerificationResult(
   proof: Uint8Array,
   isValid: boolean,
-  signature: Uint8Array
+  signature: Uint8Array,
+  nullifier: Uint8Array,
+  timestamp: bigint,
+  domain: Uint8Array,
+  publicInputsHash: Uint8Array,
+  circuitId: number,
+  vkHash?: Uint8Array,
+  clusterId?: Uint8Array,
+  instanceId?: Uint8Array
 ): Promise<Uint8Array> {
   // * Hash the proof (SHA256) - async for browser compatibility
   const proofHash = await hashProofAsync(proof);
-  
-  // * Get current timestamp (Unix timestamp in seconds)
-  const timestamp = BigInt(Math.floor(Date.now() / 1000));
-  
-  // * Format: [1 byte: is_valid] [32 bytes: proof_hash] [8 bytes: timestamp (little-endian)] [64 bytes: signature]
-  const result = new Uint8Array(105);
-  result[0] = isValid ? 1 : 0;
-  result.set(proofHash, 1);
-  
-  // * Write timestamp as little-endian u64 (8 bytes)
-  const timestampBytes = new Uint8Array(8);
-  const timestampView = new DataView(timestampBytes.buffer);
-  timestampView.setBigUint64(0, timestamp, true); // true = little-endian
-  result.set(timestampBytes, 33);
-  
-  // * Write signature (64 bytes)
-  if (signature.length !== 64) {
-    throw new Error(`Signature must be 64 bytes, got ${signature.length}`);
-  }
-  result.set(signature, 41);
-  
-  return result;
+
+  return encodeVerificationResult(
+    {
+      isValid,
+      proofHash,
+      timestamp,
+      nullifier,
+      domain,
+      publicInputsHash,
+      circuitId,
+      vkHash,
+      clusterId,
+      instanceId
+    },
+    signature
+  );
+}
+
+/**
+ * * Hashes a proof's public inputs (SHA-256 over each field element as 32 bytes big-endian)
+ * * Bound into V3 verification results so the stored result records which statement was verified
+ */
+export async function hashPublicInputsAsync(publicInputs: string[]): Promise<Uint8Array> {
+  const encoded = new Uint8Array(publicInputs.length * 32);
+  publicInputs.forEach((input, i) => {
+    let value = BigInt(input); // * Accepts 0x-prefixed hex or decimal
+    for (let j = 31; j >= 0; j--) {
+      encoded[i * 32 + j] = Number(value & 0xffn);
+      value >>= 8n;
+    }
+  });
+  return hashProofAsync(encoded);
 }
 
 /**
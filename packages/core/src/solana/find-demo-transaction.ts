@@ -2,7 +2,7 @@
 // * Shows how to look up transactions by program ID or nullifier PDA
 
 import { Connection, PublicKey } from '@solana/web3.js';
-import { VEILED_PROGRAM_ID } from './program.js';
+import { VEILED_PROGRAM_ID, deriveNullifierPda, encodeDomainToBytes32 } from './program.js';
 
 /**
  * * Finds a recent transaction from your Veiled program for demo purposes
@@ -58,19 +58,20 @@ export async function findDemoTransaction(
 }
 
 /**
- * * Finds the PDA account for a nullifier and shows how to view it on Solscan
+ * * Finds the PDA account for a nullifier's session on a domain and shows how to view it on Solscan
  * * This is useful for checking if a specific nullifier was used
  */
-export function getNullifierPdaAddress(nullifierHex: string): {
+export async function getNullifierPdaAddress(nullifierHex: string, domain: string): Promise<{
   pda: PublicKey;
   explorerUrl: string;
-} {
+}> {
   // * Convert hex string to bytes
   const nullifierBytes = hexToBytes(nullifierHex);
   
   // * Derive PDA (same as in program.ts)
-  const [nullifierPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from('nullifier'), Buffer.from(nullifierBytes)],
+  const nullifierPda = await deriveNullifierPda(
+    nullifierBytes,
+    encodeDomainToBytes32(domain),
     VEILED_PROGRAM_ID
   );
 
@@ -79,6 +80,7 @@ export function getNullifierPdaAddress(nullifierHex: string): {
   console.log('\n🔍 Nullifier PDA Account:');
   console.log('━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━');
   console.log('📝 Nullifier (hex):', nullifierHex);
+  console.log('🌐 Domain:', domain);
   console.log('🏦 PDA Address:', nullifierPda.toBase58());
   console.log('\n🔗 View on Solscan:');
   console.log(`   ${explorerUrl}`);
@@ -117,7 +119,7 @@ export async function showDemoTransaction(rpcUrl: string = 'https://api.devnet.s
   
   if (result) {
     console.log('💡 Tip: You can also search by nullifier PDA address on Solscan');
-    console.log('   Use getNullifierPdaAddress(nullifierHex) to get the PDA address\n');
+    console.log('   Use getNullifierPdaAddress(nullifierHex, domain) to get the PDA address\n');
   }
   
   return result;
@@ -13,17 +13,44 @@ import BN from 'bn.js';
 import type { Idl } from '@coral-xyz/anchor';
 import type { Veiled } from '@veiled/anchor/src/types.js';
 import { Permission } from '../types.js';
+import { buildVerificationMessage, decodeVerificationResult } from './verification-message.js';
 
 // * Program ID (must match on-chain declare_id! in lib.rs)
 // * Deployed Program ID on devnet: H6apEGZAw23AKUeqCX41wkDv2LVwX3Ec8oYPip7k3xzA
+// * White-label deployments run the same program under their own ID: pass it as `programId`
+// * to the verification helpers below (see the program's genesis.rs)
 export const VEILED_PROGRAM_ID = new PublicKey('H6apEGZAw23AKUeqCX41wkDv2LVwX3Ec8oYPip7k3xzA');
 
 export interface SubmitVerificationResultOptions {
-  verificationResult: Uint8Array; // * 105 bytes: [is_valid (1) | proof_hash (32) | timestamp (8) | signature (64)]
+  verificationResult: Uint8Array; // * V4 (206 bytes), V5 (238), V6 (270) or V7 (302 bytes) verification result (layout in verification-message.ts)
   nullifier: string; // Hex string
   domain: string;
   connection: Connection;
   wallet: Wallet;
+  accounts?: VerifyAuthAccounts; // * Pre-derived by prewarmVerification(); derived here otherwise
+  verifier?: PublicKey; // * Verifier that signed the result, when the wallet only pays (default: the wallet)
+  programId?: PublicKey; // * Veiled instance to submit to (default: VEILED_PROGRAM_ID)
+  expiresIn?: number; // * Session lifetime in seconds, within the domain's bounds (default: the longest it allows)
+}
+
+// * verify_auth accounts known before the proof exists (proofRecord and resultReceipt need the
+// * signed result)
+export interface VerifyAuthAccounts {
+  nullifierAccount: PublicKey;
+  authority: PublicKey;
+  instructionsSysvar: PublicKey;
+  circuitRegistry: PublicKey;
+  domainConfig: PublicKey;
+  nullifierBan: PublicKey;
+  logoutEpoch: PublicKey;
+  verifierRegistry: PublicKey;
+  secp256r1VerifierRegistry: PublicKey;
+  assurancePolicy: PublicKey;
+  payerRateLimit: PublicKey;
+  protocolStats: PublicKey;
+  systemProgram: PublicKey;
+  eventAuthority: PublicKey;
+  program: PublicKey;
 }
 
 export interface SubmitVerificationResultResponse {
@@ -140,15 +167,30 @@ export async function loadIdl(): Promise<Idl | null> {
         name: 'verifyAuth',
         accounts: [
           { name: 'nullifierAccount', isMut: true, isSigner: false },
+          { name: 'proofRecord', isMut: true, isSigner: false },
+          { name: 'resultReceipt', isMut: true, isSigner: false },
           { name: 'authority', isMut: true, isSigner: true },
           { name: 'instructionsSysvar', isMut: false, isSigner: false },
-          { name: 'systemProgram', isMut: false, isSigner: false }
+          { name: 'circuitRegistry', isMut: false, isSigner: false },
+          { name: 'domainConfig', isMut: false, isSigner: false },
+          { name: 'nullifierBan', isMut: false, isSigner: false },
+          { name: 'logoutEpoch', isMut: false, isSigner: false, isOptional: true },
+          { name: 'verifierRegistry', isMut: false, isSigner: false },
+          { name: 'secp256r1VerifierRegistry', isMut: false, isSigner: false },
+          { name: 'assurancePolicy', isMut: false, isSigner: false },
+          { name: 'payerRateLimit', isMut: true, isSigner: false },
+          { name: 'protocolStats', isMut: true, isSigner: false },
+          { name: 'systemProgram', isMut: false, isSigner: false },
+          // * Appended by #[event_cpi]: verify_auth emits AuthVerifiedEvent through a self-CPI
+          { name: 'eventAuthority', isMut: false, isSigner: false },
+          { name: 'program', isMut: false, isSigner: false }
         ],
         args: [
-          { name: 'verificationResult', type: 'bytes' }, // * 105 bytes: verification result
+          { name: 'result', type: { defined: { name: 'SignedVerificationResult' } } },
           { name: 'nullifier', type: { array: ['u8', 32] } },
           // * NOTE: Domain is represented on-chain as [u8; 32]
-          { name: 'domain', type: { array: ['u8', 32] } }
+          { name: 'domain', type: { array: ['u8', 32] } },
+          { name: 'expiresIn', type: 'i64' }
         ]
       }
     ],
@@ -159,13 +201,72 @@ export async function loadIdl(): Promise<Idl | null> {
           kind: 'struct',
         fields: [
           { name: 'nullifier', type: { array: ['u8', 32] } },
-          { name: 'domain', type: 'string' },
+          { name: 'domain', type: { array: ['u8', 32] } },
+          { name: 'domainLen', type: 'u8' },
+          { name: 'authority', type: 'pubkey' },
           { name: 'createdAt', type: 'i64' },
-          { name: 'expiresAt', type: 'i64' }
+          { name: 'lastActiveAt', type: 'i64' },
+          { name: 'idleTimeoutSecs', type: 'i64' },
+          { name: 'expiresAt', type: { defined: { name: 'ExpiryKind' } } },
+          { name: 'publicInputsHash', type: { array: ['u8', 32] } },
+          { name: 'circuitId', type: 'u32' },
+          { name: 'proofHash', type: { array: ['u8', 32] } },
+          { name: 'verifiedAt', type: 'i64' },
+          { name: 'verifier', type: 'pubkey' },
+          { name: 'finalizesAt', type: 'i64' },
+          { name: 'payer', type: 'pubkey' },
+          { name: 'deviceKey', type: 'pubkey' },
+          { name: 'bump', type: 'u8' }
         ]
         }
       }
     ],
+    types: [
+      {
+        // * Verifier-signed result; optional fields extend the signed message in order
+        name: 'SignedVerificationResult',
+        type: {
+          kind: 'struct',
+          fields: [
+            { name: 'isValid', type: 'bool' },
+            { name: 'proofHash', type: { array: ['u8', 32] } },
+            { name: 'timestamp', type: 'u64' },
+            { name: 'nullifier', type: { array: ['u8', 32] } },
+            { name: 'domain', type: { array: ['u8', 32] } },
+            { name: 'publicInputsHash', type: { option: { array: ['u8', 32] } } },
+            { name: 'circuitId', type: { option: 'u32' } },
+            { name: 'vkHash', type: { option: { array: ['u8', 32] } } },
+            { name: 'clusterId', type: { option: { array: ['u8', 32] } } },
+            { name: 'instanceId', type: { option: { array: ['u8', 32] } } },
+            { name: 'claimType', type: { option: { defined: { name: 'ClaimType' } } } },
+            { name: 'tagged', type: 'bool' },
+            { name: 'signature', type: { array: ['u8', 64] } }
+          ]
+        }
+      },
+      {
+        name: 'ExpiryKind',
+        type: {
+          kind: 'enum',
+          variants: [
+            { name: 'UnixTime', fields: ['i64'] },
+            { name: 'Slot', fields: ['u64'] }
+          ]
+        }
+      },
+      {
+        name: 'ClaimType',
+        type: {
+          kind: 'enum',
+          variants: [
+            { name: 'Auth' },
+            { name: 'BalanceThreshold' },
+            { name: 'AgeOver' },
+            { name: 'NftOwnership' }
+          ]
+        }
+      }
+    ],
     address: VEILED_PROGRAM_ID.toBase58(),
     metadata: {
       name: 'veiled',
@@ -177,6 +278,95 @@ export async function loadIdl(): Promise<Idl | null> {
   return cachedIdl;
 }
 
+/**
+ * * Derives the session PDA [b"nullifier", nullifier, sha256(domain)] for a nullifier and domain
+ * * The same nullifier holds an independent session per domain
+ */
+export async function deriveNullifierPda(
+  nullifier: Uint8Array,
+  domain: Uint8Array,
+  programId: PublicKey = VEILED_PROGRAM_ID
+): Promise<PublicKey> {
+  const domainHash = await globalThis.crypto.subtle.digest('SHA-256', new Uint8Array(domain).buffer);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('nullifier'), Buffer.from(nullifier), Buffer.from(domainHash)],
+    programId
+  )[0];
+}
+
+/**
+ * * Derives a domain's DomainConfig PDA [b"domain_config", sha256(domain)]
+ * * The account only exists once the domain is registered
+ */
+export async function deriveDomainConfigPda(
+  domain: Uint8Array,
+  programId: PublicKey = VEILED_PROGRAM_ID
+): Promise<PublicKey> {
+  const domainHash = await globalThis.crypto.subtle.digest('SHA-256', new Uint8Array(domain).buffer);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('domain_config'), Buffer.from(domainHash)],
+    programId
+  )[0];
+}
+
+/**
+ * * Derives a domain's grant ExpiryHistogram PDA [b"expiry_histogram", sha256(domain)]
+ * * Created by the domain's first grant_permissions
+ */
+export async function deriveExpiryHistogramPda(
+  domain: Uint8Array,
+  programId: PublicKey = VEILED_PROGRAM_ID
+): Promise<PublicKey> {
+  const domainHash = await globalThis.crypto.subtle.digest('SHA-256', new Uint8Array(domain).buffer);
+  return PublicKey.findProgramAddressSync(
+    [Buffer.from('expiry_histogram'), Buffer.from(domainHash)],
+    programId
+  )[0];
+}
+
+/**
+ * * Derives the verify_auth accounts for a nullifier, domain and fee payer
+ * * Everything but the proof record is known before proving, so the warm path can do this early
+ */
+export async function deriveVerifyAuthAccounts(
+  nullifier: Uint8Array,
+  domain: Uint8Array,
+  payer: PublicKey,
+  programId: PublicKey = VEILED_PROGRAM_ID
+): Promise<VerifyAuthAccounts> {
+  const pda = (...seeds: Uint8Array[]) =>
+    PublicKey.findProgramAddressSync(seeds.map((seed) => Buffer.from(seed)), programId)[0];
+
+  return {
+    nullifierAccount: await deriveNullifierPda(nullifier, domain, programId),
+    authority: payer,
+    instructionsSysvar: new PublicKey('Sysvar1nstructions1111111111111111111111111'),
+    // * Singleton registry of circuits verify_auth accepts
+    circuitRegistry: pda(Buffer.from('circuit_registry')),
+    // * Per-domain settings (e.g. pinned verification key); may not exist
+    domainConfig: await deriveDomainConfigPda(domain, programId),
+    // * Exists only while the domain's owner has banned this nullifier
+    nullifierBan: pda(Buffer.from('nullifier_ban'), domain, nullifier),
+    // * The payer's logout marker, so a session it logged out of everywhere doesn't block a
+    // * fresh sign-in; may not exist
+    logoutEpoch: pda(Buffer.from('logout_epoch'), payer.toBuffer()),
+    // * Allowlist of verifier keys; the signing verifier (by default the payer) must be registered
+    verifierRegistry: pda(Buffer.from('verifier_registry')),
+    // * Passkey (secp256r1) verifier keys; may not exist
+    secp256r1VerifierRegistry: pda(Buffer.from('secp256r1_verifier_registry')),
+    // * Staleness window per assurance tier; may not exist
+    assurancePolicy: pda(Buffer.from('assurance_policy')),
+    // * Per-payer verification counter (rate limit); created on the payer's first verification
+    payerRateLimit: pda(Buffer.from('rate_limit'), payer.toBuffer()),
+    // * Program-wide totals; created by the first instruction that counts
+    protocolStats: pda(Buffer.from('protocol_stats')),
+    systemProgram: SystemProgram.programId,
+    // * Signs the self-CPI that carries verify_auth's events (see #[event_cpi])
+    eventAuthority: pda(Buffer.from('__event_authority')),
+    program: programId
+  };
+}
+
 /**
  * * Submits verification result to Anchor program
  * * The proof has already been verified off-chain using WASM
@@ -189,13 +379,10 @@ export async function submitVerificationResultToChain(
   options: SubmitVerificationResultOptions
 ): Promise<SubmitVerificationResultResponse> {
   const { verificationResult, nullifier, domain, connection, wallet } = options;
+  const programId = options.programId ?? VEILED_PROGRAM_ID;
 
-  // * Validate verification result size (must be 105 bytes)
-  if (verificationResult.length !== 105) {
-    throw new Error(
-      `Verification result must be 105 bytes, got ${verificationResult.length}`
-    );
-  }
+  // * Validate verification result size and split out the signed fields
+  const { fields, signature: signatureBytes } = decodeVerificationResult(verificationResult);
 
   // * Create provider
   const provider = new AnchorProvider(connection, wallet, {
@@ -208,8 +395,8 @@ export async function submitVerificationResultToChain(
     throw new Error('Failed to load program IDL');
   }
 
-  // * Create program instance
-  const program = new Program(idl, provider);
+  // * Create program instance (at the instance's address; the IDL carries the default one)
+  const program = new Program({ ...idl, address: programId.toBase58() }, provider);
 
   // * Convert nullifier hex string to [u8; 32]
   const nullifierBytes = hexToBytes(nullifier);
@@ -217,51 +404,79 @@ export async function submitVerificationResultToChain(
   // * Encode domain as fixed 32-byte array (must match on-chain representation)
   const domainBytes = encodeDomainToBytes32(domain);
 
-  // * Derive PDA for nullifier account
-  const [nullifierPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from('nullifier'), Buffer.from(nullifierBytes)],
-    VEILED_PROGRAM_ID
+  // * Reuse accounts derived ahead of time by the warm path (see warm-path.ts)
+  const accounts =
+    options.accounts ??
+    await deriveVerifyAuthAccounts(nullifierBytes, domainBytes, wallet.publicKey, programId);
+
+  // * One record per proof hash; a proof can only register one nullifier
+  const [proofRecordPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('proof_record'), Buffer.from(fields.proofHash)],
+    programId
+  );
+
+  // * One receipt per signed result, keyed by sha256(signature); a result can only be spent once
+  const signatureDigest = await globalThis.crypto.subtle.digest(
+    'SHA-256',
+    new Uint8Array(signatureBytes).buffer
+  );
+  const [resultReceiptPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('result_receipt'), Buffer.from(signatureDigest)],
+    programId
   );
 
   try {
     // * Reconstruct the Ed25519 signed message from the verification result.
-    // * Message format (41 bytes): proof_hash (32) + is_valid (1) + timestamp (8 LE)
-    const proofHash = verificationResult.slice(1, 33);
-    const isValidByte = verificationResult[0];
-    const timestampBytes = verificationResult.slice(33, 41);
-    const signatureBytes = verificationResult.slice(41, 105);
-
-    const messageToVerify = new Uint8Array(41);
-    messageToVerify.set(proofHash, 0);
-    messageToVerify[32] = isValidByte;
-    messageToVerify.set(timestampBytes, 33);
+    const messageToVerify = buildVerificationMessage(fields);
 
     // * Create Ed25519 verification instruction (runs in Solana's built-in Ed25519 program)
     const ed25519Ix = Ed25519Program.createInstructionWithPublicKey({
-      publicKey: wallet.publicKey.toBytes(),
+      publicKey: (options.verifier ?? wallet.publicKey).toBytes(),
       message: messageToVerify,
       signature: signatureBytes
     });
 
+    // * verify_auth takes the result field by field; absent optional fields are null
+    const optional = (bytes?: Uint8Array) => (bytes ? Array.from(bytes) : null);
+    // * Anchor takes ClaimType as { <variant>: {} }, variants in CLAIM_TYPES order
+    const claimTypeVariants = ['auth', 'balanceThreshold', 'ageOver', 'nftOwnership'];
+    const result = {
+      isValid: fields.isValid,
+      proofHash: Array.from(fields.proofHash),
+      timestamp: new BN(fields.timestamp.toString()),
+      nullifier: Array.from(fields.nullifier),
+      domain: Array.from(fields.domain),
+      publicInputsHash: optional(fields.publicInputsHash),
+      circuitId: fields.circuitId ?? null,
+      vkHash: optional(fields.vkHash),
+      clusterId: optional(fields.clusterId),
+      instanceId: optional(fields.instanceId),
+      claimType:
+        fields.claimType === undefined ? null : { [claimTypeVariants[fields.claimType]]: {} },
+      tagged: fields.tagged ?? false,
+      signature: Array.from(signatureBytes)
+    };
+
     // * Call verify_auth instruction with verification result
     const txSignature = await program.methods
       .verifyAuth(
-        Array.from(verificationResult),
+        result,
         Array.from(nullifierBytes),
-        Array.from(domainBytes)
+        Array.from(domainBytes),
+        new BN(options.expiresIn ?? 0)
       )
       .preInstructions([ed25519Ix])
       .accounts({
-        nullifierAccount: nullifierPda,
-        authority: wallet.publicKey,
-        instructionsSysvar: new PublicKey('Sysvar1nstructions1111111111111111111111111'),
-        systemProgram: SystemProgram.programId
+        ...accounts,
+        proofRecord: proofRecordPda,
+        resultReceipt: resultReceiptPda,
+        verifier: options.verifier ?? null
       })
       .rpc();
 
     return {
       signature: txSignature,
-      nullifierAccount: nullifierPda
+      nullifierAccount: accounts.nullifierAccount
     };
   } catch (error) {
     const message = error instanceof Error ? error.message : String(error);
@@ -292,23 +507,50 @@ export async function submitProofToChain(
 }
 
 /**
- * * Verifies if a nullifier has been used on-chain
+ * * Converts an on-chain ExpiryKind to unix seconds
+ * * Returns undefined for slot-based expiries (no wall-clock equivalent)
+ */
+export function expiryToUnixSeconds(expiry: any): number | undefined {
+  if (expiry?.unixTime) {
+    const value = expiry.unixTime[0];
+    return value?.toNumber ? value.toNumber() : Number(value);
+  }
+  return undefined;
+}
+
+/**
+ * * Human-readable rendering of an on-chain ExpiryKind
+ */
+export function describeExpiry(expiry: any): string {
+  const unixSeconds = expiryToUnixSeconds(expiry);
+  if (unixSeconds !== undefined) {
+    return new Date(unixSeconds * 1000).toISOString();
+  }
+  return `slot ${expiry?.slot?.[0]?.toString()}`;
+}
+
+/**
+ * * Verifies if a nullifier has a session for a domain on-chain
  * * Returns account data including expiry information
  */
 export async function verifyNullifierOnChain(
   connection: Connection,
   nullifier: string,
-  program?: Program<Veiled>
+  domain: string,
+  program?: Program<Veiled>,
+  programId: PublicKey = program?.programId ?? VEILED_PROGRAM_ID
 ): Promise<{ 
   exists: boolean; 
   account?: any;
   expired?: boolean;
   expiresAt?: number;
+  pending?: boolean; // * Optimistic domains: still inside the challenge window
+  finalizesAt?: number;
 }> {
-  const nullifierBytes = hexToBytes(nullifier);
-  const [nullifierPda] = PublicKey.findProgramAddressSync(
-    [Buffer.from('nullifier'), Buffer.from(nullifierBytes)],
-    VEILED_PROGRAM_ID
+  const nullifierPda = await deriveNullifierPda(
+    hexToBytes(nullifier),
+    encodeDomainToBytes32(domain),
+    programId
   );
 
   try {
@@ -317,14 +559,23 @@ export async function verifyNullifierOnChain(
       try {
         const account = await program.account.nullifierAccount.fetch(nullifierPda);
         const now = Math.floor(Date.now() / 1000);
-        const expiresAt = account.expiresAt?.toNumber ? account.expiresAt.toNumber() : Number(account.expiresAt);
-        const expired = expiresAt < now;
+        const expiresAt = expiryToUnixSeconds(account.expiresAt);
+        // * Slot-based expiries can't be compared to wall-clock time here; treat as unexpired
+        // * A session idle past its domain's timeout (see touch_session) is over as well
+        // * counted from finalization at the earliest, as on-chain
+        const idleTimeout = account.idleTimeoutSecs.toNumber();
+        const finalizesAt = account.finalizesAt.toNumber();
+        const lastActiveAt = Math.max(account.lastActiveAt.toNumber(), finalizesAt);
+        const idle = idleTimeout > 0 && now - lastActiveAt >= idleTimeout;
+        const expired = (expiresAt !== undefined && expiresAt < now) || idle;
         
         return {
           exists: true,
           account,
           expired,
-          expiresAt
+          expiresAt,
+          pending: now < finalizesAt,
+          finalizesAt: finalizesAt || undefined
         };
       } catch (fetchError) {
         // * Account doesn't exist or fetch failed
@@ -344,7 +595,7 @@ export async function verifyNullifierOnChain(
 }
 
 // * Helper function to convert hex string to 32-byte array
-function hexToBytes(hex: string): Uint8Array {
+export function hexToBytes(hex: string): Uint8Array {
   // * Remove 0x prefix if present
   const cleanHex = hex.startsWith('0x') ? hex.slice(2) : hex;
   const bytes = new Uint8Array(32);
@@ -358,22 +609,40 @@ function hexToBytes(hex: string): Uint8Array {
   return bytes;
 }
 
-// * Helper: Encode domain string as fixed 32-byte array (UTF-8, padded with zeros)
-function encodeDomainToBytes32(domain: string): Uint8Array {
-  const encoder = new TextEncoder();
-  const encoded = encoder.encode(domain);
+// * Helper: Canonical form of a domain, matching the program's DomainConfig::normalize
+// * ASCII case is folded; the result is 1-32 lowercase letters, digits, '-' or '.', with no
+// * empty labels. verify_auth and the domain registry refuse anything else, so `App.example`
+// * and `app.example` always name the same session
+export function normalizeDomain(domain: string): string {
+  const normalized = domain.replace(/[A-Z]/g, (c) => c.toLowerCase());
 
-  if (encoded.length > 32) {
+  if (normalized.length === 0 || normalized.length > 32) {
+    throw new Error(
+      `Domain must be 1-32 characters (got ${normalized.length}).`
+    );
+  }
+  if (!/^[a-z0-9-]+(\.[a-z0-9-]+)*$/.test(normalized)) {
     throw new Error(
-      `Domain is too long (${encoded.length} bytes). Maximum is 32 bytes.`
+      `Domain "${domain}" must be lowercase ASCII letters, digits, '-' or '.', with no empty labels.`
     );
   }
 
+  return normalized;
+}
+
+// * Helper: Encode domain string as fixed 32-byte array (canonical form, padded with zeros)
+export function encodeDomainToBytes32(domain: string): Uint8Array {
+  const encoded = new TextEncoder().encode(normalizeDomain(domain));
   const out = new Uint8Array(32);
   out.set(encoded, 0);
   return out;
 }
 
+// * Helper: Read a NullifierAccount's domain back as a string (null-padded bytes + length)
+export function decodeSessionDomain(account: { domain: number[] | Uint8Array; domainLen: number }): string {
+  return new TextDecoder().decode(Uint8Array.from(account.domain).subarray(0, account.domainLen));
+}
+
 // * Internal helper: Map SDK Permission enum to Anchor enum layout
 function toRustPermissionVariant(permission: Permission): any {
   switch (permission) {
@@ -393,6 +662,8 @@ function toRustPermissionVariant(permission: Permission): any {
       return { revealDefiPositions: {} };
     case Permission.SignTransactions:
       return { signTransactions: {} };
+    case Permission.ReceiveNotifications:
+      return { receiveNotifications: {} };
     default:
       // * TypeScript exhaustiveness check
       const _exhaustive: never = permission;
@@ -525,8 +796,8 @@ export async function grantPermissionsOnChain(options: {
   // * No need to manually derive or pass it in .accounts()
   
   const rustPermissions = permissions.map(toRustPermissionVariant);
-  // * expires_in is i64 in Rust - typed Program<Veiled> requires BN for i64 types
-  const expiresIn = new BN(durationSeconds ?? 3600);
+  // * expires_in is ExpiryKind in Rust - UnixTime carries an i64 duration in seconds (BN)
+  const expiresIn = { unixTime: { 0: new BN(durationSeconds ?? 3600) } };
 
   // #region agent log
   fetch('http://127.0.0.1:7253/ingest/7771b592-8da6-468a-80be-e69122580b2d',{method:'POST',headers:{'Content-Type':'application/json'},body:JSON.stringify({location:'program.ts:410',message:'GRANT_PERMISSIONS_ENTRY',data:{hasConnection:!!connection,hasWallet:!!wallet,nullifierHexLength:nullifierHex.length,permissionsCount:permissions.length},timestamp:Date.now(),sessionId:'debug-session',runId:'run1',hypothesisId:'E'})}).catch(()=>{});
@@ -550,12 +821,22 @@ export async function grantPermissionsOnChain(options: {
     fetch('http://127.0.0.1:7253/ingest/7771b592-8da6-468a-80be-e69122580b2d',{method:'POST',headers:{'Content-Type':'application/json'},body:JSON.stringify({location:'program.ts:grantPermissionsOnChain',message:'BEFORE_TRANSACTION_BUILD',data:{programProgramId:program.programId.toBase58(),veiledProgramIdConstant:VEILED_PROGRAM_ID.toBase58(),connectionRpcUrl:connection.rpcEndpoint},timestamp:Date.now(),sessionId:'debug-session',runId:'run1',hypothesisId:'C'})}).catch(()=>{});
     // #endregion
     
+    const domainBytes = encodeDomainToBytes32(domain);
     const transaction = await program.methods
-      .grantPermissions(Array.from(nullifierBytes), appId, rustPermissions, expiresIn)
+      .grantPermissions(
+        Array.from(nullifierBytes),
+        Array.from(domainBytes),
+        appId,
+        rustPermissions,
+        expiresIn
+      )
       .accounts({
         // * permissionGrant and systemProgram are auto-resolved by Anchor from IDL (Anchor 0.30.0+)
         // * permissionGrant: resolved from PDA seeds [b"permission", nullifier, appId]
         // * systemProgram: resolved from fixed address in IDL
+        // * domainConfig and expiryHistogram: hashed seeds, so derived here
+        domainConfig: await deriveDomainConfigPda(domainBytes),
+        expiryHistogram: await deriveExpiryHistogramPda(domainBytes),
         payer: wallet.publicKey
       })
       .transaction();
@@ -673,7 +954,7 @@ export async function grantPermissionsOnChain(options: {
         if (existingAccount) {
           console.log('✅ [GRANT PERMISSIONS] Permissions already exist for this nullifier and domain');
           console.log('   Existing permissions:', existingAccount.permissions);
-          console.log('   Expires at:', new Date(Number(existingAccount.expiresAt) * 1000).toISOString());
+          console.log('   Expires at:', describeExpiry(existingAccount.expiresAt));
           console.log('\n💡 Note: The program was updated to use init_if_needed to handle existing accounts.');
           console.log('   If you see this message, please hard-refresh your browser (Ctrl+Shift+R)');
           console.log('   to load the updated IDL. Permissions are already granted, so you can continue.\n');
@@ -805,6 +1086,7 @@ export async function logPermissionAccessOnChain(options: {
 
   // * For logPermissionAccess, permissionGrant is NOT a PDA in the IDL (no seeds defined)
   // * systemProgram has fixed address, so it's auto-resolved
+  // * nullifierBan resolves from the domain argument and the grant's nullifier
   const [permissionPda] = PublicKey.findProgramAddressSync(
     [Buffer.from('permission'), Buffer.from(nullifierBytes), appId.toBuffer()],
     VEILED_PROGRAM_ID
@@ -813,7 +1095,11 @@ export async function logPermissionAccessOnChain(options: {
   const accessAccount = Keypair.generate();
 
   const signature = await program.methods
-    .logPermissionAccess(toRustPermissionVariant(permission), metadata ?? '')
+    .logPermissionAccess(
+      toRustPermissionVariant(permission),
+      metadata ?? '',
+      Array.from(encodeDomainToBytes32(domain))
+    )
       .accounts({
         permissionAccess: accessAccount.publicKey,
         permissionGrant: permissionPda,
@@ -825,3 +1111,42 @@ export async function logPermissionAccessOnChain(options: {
 
   return signature;
 }
+
+// * Lamports an account needs to be rent-exempt again at its current size (0 if it already is)
+// * Accounts grown via realloc can fall below the minimum; see topUpRentOnChain
+export async function getRentTopUpLamports(
+  connection: Connection,
+  account: PublicKey
+): Promise<number> {
+  const info = await connection.getAccountInfo(account);
+  if (!info) {
+    throw new Error(`Account ${account.toBase58()} does not exist`);
+  }
+
+  const required = await connection.getMinimumBalanceForRentExemption(info.data.length);
+  return Math.max(0, required - info.lamports);
+}
+
+// * Fund a Veiled-owned account back to rent exemption (anyone may pay)
+// * Returns null without sending a transaction when the account is already exempt
+export async function topUpRentOnChain(options: {
+  connection: Connection;
+  wallet: Wallet;
+  account: PublicKey;
+}): Promise<string | null> {
+  const { connection, wallet, account } = options;
+
+  if ((await getRentTopUpLamports(connection, account)) === 0) {
+    return null;
+  }
+
+  const program = await getVeiledProgram(connection, wallet);
+  return program.methods
+    .topUpRent()
+    .accounts({
+      account,
+      // * systemProgram is auto-resolved from fixed address in IDL (Anchor 0.30.0+)
+      payer: wallet.publicKey
+    })
+    .rpc();
+}
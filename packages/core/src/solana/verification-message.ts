@@ -0,0 +1,310 @@
+// * Verification result encoding
+// * Single source of truth for the byte layouts checked by the on-chain program (ultrahonk.rs)
+// *
+// * Signed message:  proof_hash (32) || is_valid (1) || timestamp (8 LE) || nullifier (32) || domain (32)
+// *                  || public_inputs_hash (32) || circuit_id (4 LE) || vk_hash (32) || cluster_id (32)
+// *                  || instance_id (32) || claim_type (1) = 238 bytes
+// *                  (V7 stops before claim_type = 237 bytes; V6 before instance_id = 205 bytes; V5 before cluster_id = 173 bytes;
+// *                  V4 before vk_hash = 141 bytes;
+// *                  V3 before circuit_id = 137 bytes; V1/V2 before public_inputs_hash = 105 bytes)
+// *                  V9 prefixes the V8 message with MESSAGE_TAG ("veiled:v1") = 247 bytes
+// * Result body:     is_valid (1) || proof_hash (32) || timestamp (8 LE) || nullifier (32) || domain (32) || signature (64) = 169 bytes
+// * Result blob V2:  version (1) || result body = 170 bytes
+// * Result blob V3:  version (1) || result body with public_inputs_hash (32) before the signature = 202 bytes
+// * Result blob V4:  version (1) || result body with public_inputs_hash (32) || circuit_id (4 LE) before the signature = 206 bytes
+// * Result blob V5:  V4 with vk_hash (32) after circuit_id = 238 bytes
+// * Result blob V6:  V5 with cluster_id (32) after vk_hash = 270 bytes
+// * Result blob V7:  V6 with instance_id (32) after cluster_id = 302 bytes
+// * Result blob V8:  V7 with claim_type (1) after instance_id = 303 bytes
+// * Result blob V9:  V8 layout with version 9 (the signed message carries the tag) = 303 bytes
+// * Legacy (V1) blobs are the bare body; the program still accepts V1-V8
+
+export const LEGACY_VERIFICATION_MESSAGE_LEN = 105;
+export const V3_VERIFICATION_MESSAGE_LEN = LEGACY_VERIFICATION_MESSAGE_LEN + 32;
+export const V4_VERIFICATION_MESSAGE_LEN = V3_VERIFICATION_MESSAGE_LEN + 4;
+export const V5_VERIFICATION_MESSAGE_LEN = V4_VERIFICATION_MESSAGE_LEN + 32;
+export const V6_VERIFICATION_MESSAGE_LEN = V5_VERIFICATION_MESSAGE_LEN + 32;
+export const V7_VERIFICATION_MESSAGE_LEN = V6_VERIFICATION_MESSAGE_LEN + 32;
+export const V8_VERIFICATION_MESSAGE_LEN = V7_VERIFICATION_MESSAGE_LEN + 1;
+// * Protocol tag V9 messages start with (program's ultrahonk::MESSAGE_TAG), so a verifier
+// * signature made for another protocol can't pass as a Veiled result
+export const MESSAGE_TAG = new TextEncoder().encode('veiled:v1');
+export const VERIFICATION_MESSAGE_LEN = MESSAGE_TAG.length + V8_VERIFICATION_MESSAGE_LEN;
+export const VERIFICATION_RESULT_VERSION = 9;
+export const VERIFICATION_RESULT_BODY_LEN = 169;
+export const VERIFICATION_RESULT_LEN = 1 + VERIFICATION_RESULT_BODY_LEN + 32 + 4 + 32 + 32 + 32 + 1;
+export const VERIFIER_SIGNATURE_LEN = 64;
+
+// * Circuit IDs registered in the on-chain CircuitRegistry (register_circuit)
+// * Results without a circuit ID (V1-V3) are treated as wallet_ownership
+export const CIRCUIT_IDS = {
+  wallet_ownership: 0,
+  balance_range: 1,
+  nft_ownership: 2
+} as const;
+
+// * Claim types bound into V8 results (program's ClaimType): what the result attests to
+// * Only auth results open sessions; results without a claim type (V1-V7) are auth
+export const CLAIM_TYPES = {
+  auth: 0,
+  balance_threshold: 1,
+  age_over: 2,
+  nft_ownership: 3
+} as const;
+
+// * Cluster IDs bound into V6 results (program's cluster.rs): the CAIP-2 reference, i.e. the
+// * first 32 base58 characters of the cluster's genesis hash
+// * The program only accepts V6 results for the cluster it was built for
+export const CLUSTER_IDS = {
+  'mainnet-beta': new TextEncoder().encode('5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp'),
+  devnet: new TextEncoder().encode('EtWTRABZaYq6iMfeYKouRu166VU2xqa1')
+} as const;
+
+// * Instance ID bound into V7 results (program's genesis.rs): the 32 bytes of the program ID the
+// * result will be submitted to, so white-label deployments sharing a verifier stay isolated
+export function instanceIdFor(programId: { toBytes(): Uint8Array }): Uint8Array {
+  return programId.toBytes();
+}
+
+// * Fields covered by the verifier signature
+export interface VerificationMessageFields {
+  isValid: boolean;
+  proofHash: Uint8Array; // * 32 bytes
+  timestamp: bigint; // * Unix seconds
+  nullifier: Uint8Array; // * 32 bytes
+  domain: Uint8Array; // * 32 bytes, UTF-8 null-padded (same encoding as the verify_auth domain arg)
+  publicInputsHash?: Uint8Array; // * 32 bytes, SHA-256 of the circuit public inputs (V3+; absent for V1/V2)
+  circuitId?: number; // * u32 CircuitRegistry ID (V4+; requires publicInputsHash)
+  vkHash?: Uint8Array; // * 32 bytes, SHA-256 of the verification key (V5+; requires circuitId)
+  clusterId?: Uint8Array; // * 32 bytes, cluster the result is for (V6+; requires vkHash, see CLUSTER_IDS)
+  instanceId?: Uint8Array; // * 32 bytes, deployment the result is for (V7+; requires clusterId, see instanceIdFor)
+  claimType?: number; // * u8 claim type (V8+; requires instanceId, see CLAIM_TYPES)
+  tagged?: boolean; // * Prefix the message with MESSAGE_TAG (V9; requires claimType)
+}
+
+function timestampToBytes(timestamp: bigint): Uint8Array {
+  const bytes = new Uint8Array(8);
+  new DataView(bytes.buffer).setBigUint64(0, timestamp, true); // * little-endian
+  return bytes;
+}
+
+function circuitIdToBytes(circuitId: number): Uint8Array {
+  const bytes = new Uint8Array(4);
+  new DataView(bytes.buffer).setUint32(0, circuitId, true); // * little-endian
+  return bytes;
+}
+
+// * Version the fields encode to: each extension requires the one before it
+function resultVersion(fields: VerificationMessageFields): 2 | 3 | 4 | 5 | 6 | 7 | 8 | 9 {
+  if (fields.tagged && fields.claimType === undefined) {
+    throw new Error('tagged requires claimType');
+  }
+  if (fields.claimType !== undefined && !fields.instanceId) {
+    throw new Error('claimType requires instanceId');
+  }
+  if (fields.instanceId && !fields.clusterId) {
+    throw new Error('instanceId requires clusterId');
+  }
+  if (fields.clusterId && !fields.vkHash) {
+    throw new Error('clusterId requires vkHash');
+  }
+  if (fields.vkHash && fields.circuitId === undefined) {
+    throw new Error('vkHash requires circuitId');
+  }
+  if (fields.circuitId !== undefined && !fields.publicInputsHash) {
+    throw new Error('circuitId requires publicInputsHash');
+  }
+  if (fields.tagged) return 9;
+  if (fields.claimType !== undefined) return 8;
+  if (fields.instanceId) return 7;
+  if (fields.clusterId) return 6;
+  return fields.vkHash ? 5 : fields.circuitId !== undefined ? 4 : fields.publicInputsHash ? 3 : 2;
+}
+
+// * Bytes appended after the domain: public_inputs_hash (V3+), circuit_id (V4+), vk_hash (V5+),
+// * cluster_id (V6+), instance_id (V7+), claim_type (V8+)
+function extensionBytes(fields: VerificationMessageFields): Uint8Array {
+  const version = resultVersion(fields);
+  const bytes = new Uint8Array([0, 0, 32, 36, 68, 100, 132, 133, 133][version]);
+  if (version >= 3) bytes.set(fields.publicInputsHash!, 0);
+  if (version >= 4) bytes.set(circuitIdToBytes(fields.circuitId!), 32);
+  if (version >= 5) bytes.set(fields.vkHash!, 36);
+  if (version >= 6) bytes.set(fields.clusterId!, 68);
+  if (version >= 7) bytes.set(fields.instanceId!, 100);
+  if (version >= 8) bytes[132] = fields.claimType!;
+  return bytes;
+}
+
+/**
+ * * Builds the message the verifier signs with Ed25519
+ * * 247 bytes when tagged, 238 with claimType, 237 with instanceId, 205 with clusterId, 173 with vkHash, 141 with circuitId, 137 with only publicInputsHash, 105 otherwise
+ */
+export function buildVerificationMessage(fields: VerificationMessageFields): Uint8Array {
+  const extension = extensionBytes(fields);
+  const message = new Uint8Array(LEGACY_VERIFICATION_MESSAGE_LEN + extension.length);
+  message.set(fields.proofHash, 0);
+  message[32] = fields.isValid ? 1 : 0;
+  message.set(timestampToBytes(fields.timestamp), 33);
+  message.set(fields.nullifier, 41);
+  message.set(fields.domain, 73);
+  message.set(extension, 105);
+  if (!fields.tagged) return message;
+
+  const tagged = new Uint8Array(MESSAGE_TAG.length + message.length);
+  tagged.set(MESSAGE_TAG, 0);
+  tagged.set(message, MESSAGE_TAG.length);
+  return tagged;
+}
+
+/**
+ * * Serializes fields + verifier signature into the verify_auth_v1 byte argument
+ * * V9 envelope when tagged, V8 when claimType is set, V7 with instanceId, V6 with clusterId, V5 with vkHash, V4 with circuitId, V3 with only publicInputsHash, V2 otherwise
+ * * verify_auth_v1 rejects anything but V9 (UntaggedResult); earlier versions go through verify_auth
+ */
+export function encodeVerificationResult(
+  fields: VerificationMessageFields,
+  signature: Uint8Array
+): Uint8Array {
+  if (signature.length !== VERIFIER_SIGNATURE_LEN) {
+    throw new Error(`Signature must be ${VERIFIER_SIGNATURE_LEN} bytes, got ${signature.length}`);
+  }
+
+  const extension = extensionBytes(fields);
+  const result = new Uint8Array(1 + VERIFICATION_RESULT_BODY_LEN + extension.length);
+  result[0] = resultVersion(fields);
+  result[1] = fields.isValid ? 1 : 0;
+  result.set(fields.proofHash, 2);
+  result.set(timestampToBytes(fields.timestamp), 34);
+  result.set(fields.nullifier, 42);
+  result.set(fields.domain, 74);
+  result.set(extension, 106);
+  result.set(signature, 106 + extension.length);
+  return result;
+}
+
+/**
+ * * Parses a verify_auth_v1 byte argument back into fields + signature
+ * * Accepts V9-V2 envelopes and legacy V1 bodies (first byte 0/1 is is_valid), mirroring the program
+ */
+export function decodeVerificationResult(result: Uint8Array): {
+  fields: VerificationMessageFields;
+  signature: Uint8Array;
+} {
+  let body: Uint8Array;
+  let hasPublicInputs = false;
+  let hasCircuitId = false;
+  let hasVkHash = false;
+  let hasClusterId = false;
+  let hasInstanceId = false;
+  let hasClaimType = false;
+  let tagged = false;
+  if (result[0] === 9 || result[0] === 8) {
+    if (result.length !== VERIFICATION_RESULT_LEN) {
+      throw new Error(
+        `Verification result must be ${VERIFICATION_RESULT_LEN} bytes, got ${result.length}`
+      );
+    }
+    body = result.subarray(1);
+    hasPublicInputs = true;
+    hasCircuitId = true;
+    hasVkHash = true;
+    hasClusterId = true;
+    hasInstanceId = true;
+    hasClaimType = true;
+    tagged = result[0] === 9;
+  } else if (result[0] === 7) {
+    if (result.length !== 1 + VERIFICATION_RESULT_BODY_LEN + 132) {
+      throw new Error(
+        `V7 verification result must be ${1 + VERIFICATION_RESULT_BODY_LEN + 132} bytes, got ${result.length}`
+      );
+    }
+    body = result.subarray(1);
+    hasPublicInputs = true;
+    hasCircuitId = true;
+    hasVkHash = true;
+    hasClusterId = true;
+    hasInstanceId = true;
+  } else if (result[0] === 6) {
+    if (result.length !== 1 + VERIFICATION_RESULT_BODY_LEN + 100) {
+      throw new Error(
+        `V6 verification result must be ${1 + VERIFICATION_RESULT_BODY_LEN + 100} bytes, got ${result.length}`
+      );
+    }
+    body = result.subarray(1);
+    hasPublicInputs = true;
+    hasCircuitId = true;
+    hasVkHash = true;
+    hasClusterId = true;
+  } else if (result[0] === 5) {
+    if (result.length !== 1 + VERIFICATION_RESULT_BODY_LEN + 68) {
+      throw new Error(
+        `V5 verification result must be ${1 + VERIFICATION_RESULT_BODY_LEN + 68} bytes, got ${result.length}`
+      );
+    }
+    body = result.subarray(1);
+    hasPublicInputs = true;
+    hasCircuitId = true;
+    hasVkHash = true;
+  } else if (result[0] === 4) {
+    if (result.length !== 1 + VERIFICATION_RESULT_BODY_LEN + 36) {
+      throw new Error(
+        `V4 verification result must be ${1 + VERIFICATION_RESULT_BODY_LEN + 36} bytes, got ${result.length}`
+      );
+    }
+    body = result.subarray(1);
+    hasPublicInputs = true;
+    hasCircuitId = true;
+  } else if (result[0] === 3) {
+    if (result.length !== 1 + VERIFICATION_RESULT_BODY_LEN + 32) {
+      throw new Error(
+        `V3 verification result must be ${1 + VERIFICATION_RESULT_BODY_LEN + 32} bytes, got ${result.length}`
+      );
+    }
+    body = result.subarray(1);
+    hasPublicInputs = true;
+  } else if (result[0] === 2) {
+    if (result.length !== 1 + VERIFICATION_RESULT_BODY_LEN) {
+      throw new Error(
+        `V2 verification result must be ${1 + VERIFICATION_RESULT_BODY_LEN} bytes, got ${result.length}`
+      );
+    }
+    body = result.subarray(1);
+  } else if (result[0] === 0 || result[0] === 1) {
+    if (result.length !== VERIFICATION_RESULT_BODY_LEN) {
+      throw new Error(
+        `Legacy verification result must be ${VERIFICATION_RESULT_BODY_LEN} bytes, got ${result.length}`
+      );
+    }
+    body = result;
+  } else {
+    throw new Error(`Unsupported verification result version ${result[0]}`);
+  }
+
+  const signatureOffset =
+    105 +
+    (hasPublicInputs ? 32 : 0) +
+    (hasCircuitId ? 4 : 0) +
+    (hasVkHash ? 32 : 0) +
+    (hasClusterId ? 32 : 0) +
+    (hasInstanceId ? 32 : 0) +
+    (hasClaimType ? 1 : 0);
+  return {
+    fields: {
+      isValid: body[0] === 1,
+      proofHash: body.slice(1, 33),
+      timestamp: new DataView(body.buffer, body.byteOffset + 33, 8).getBigUint64(0, true),
+      nullifier: body.slice(41, 73),
+      domain: body.slice(73, 105),
+      publicInputsHash: hasPublicInputs ? body.slice(105, 137) : undefined,
+      circuitId: hasCircuitId
+        ? new DataView(body.buffer, body.byteOffset + 137, 4).getUint32(0, true)
+        : undefined,
+      vkHash: hasVkHash ? body.slice(141, 173) : undefined,
+      clusterId: hasClusterId ? body.slice(173, 205) : undefined,
+      instanceId: hasInstanceId ? body.slice(205, 237) : undefined,
+      claimType: hasClaimType ? body[237] : undefined,
+      tagged: tagged || undefined
+    },
+    signature: body.slice(signatureOffset, signatureOffset + VERIFIER_SIGNATURE_LEN)
+  };
+}
@@ -0,0 +1,172 @@
+// * Verifier selection
+// * Picks the verifier a client should send a nullifier's proof to, so load spreads across
+// * verifier operators without a central coordinator. Mirrors the program's select_verifier
+// * view (state/verifier.rs) bit for bit:
+// *
+// * - Candidates: VerifierWeights entries with weight > 0 whose key is in the VerifierRegistry
+// *   and whose last heartbeat is less than LIVENESS_WINDOW_SECS old
+// * - Each candidate gets `weight` draws; draw k is the first 8 bytes (big-endian) of
+// *   SHA-256("veiled-verifier-selection" || nullifier || verifier || k)
+// * - The highest draw wins (key bytes break ties), so a verifier is picked with probability
+// *   weight / total weight and removing one only moves the nullifiers it owned
+// *
+// * Account layouts (after the 8-byte discriminator):
+// * VerifierRegistry: verifiers (u32 LE count || 32 bytes each) || ...
+// * VerifierWeights:  entries (u32 LE count || verifier (32) || weight (1) || last_heartbeat (8 LE)
+// *                   each) || bump (1)
+
+import { Connection, PublicKey } from '@solana/web3.js';
+import { VEILED_PROGRAM_ID, hexToBytes } from './program.js';
+
+export const VERIFIER_SELECTION_SEED = new TextEncoder().encode('veiled-verifier-selection');
+export const VERIFIER_LIVENESS_WINDOW_SECS = 10 * 60;
+
+export interface VerifierWeightEntry {
+  verifier: PublicKey;
+  weight: number;
+  lastHeartbeat: number; // * Unix seconds (0 = never)
+}
+
+export interface SelectVerifierOptions {
+  nullifier: string; // Hex string
+  connection: Connection;
+  now?: number; // * Unix seconds to judge liveness at (default: the cluster's latest block time)
+  programId?: PublicKey;
+}
+
+/**
+ * * Selects the verifier for a nullifier from the on-chain registry and weights
+ * *
+ * * @param options - Nullifier, connection and optionally the liveness clock
+ * * @returns The selected verifier key, or null when no weighted verifier is live
+ */
+export async function selectVerifier(options: SelectVerifierOptions): Promise<PublicKey | null> {
+  const programId = options.programId ?? VEILED_PROGRAM_ID;
+  const [registryPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('verifier_registry')],
+    programId
+  );
+  const [weightsPda] = PublicKey.findProgramAddressSync(
+    [Buffer.from('verifier_weights')],
+    programId
+  );
+
+  const [registryInfo, weightsInfo] = await options.connection.getMultipleAccountsInfo([
+    registryPda,
+    weightsPda
+  ]);
+  if (!registryInfo?.owner.equals(programId) || !weightsInfo?.owner.equals(programId)) {
+    return null;
+  }
+
+  const now = options.now ?? (await clusterTime(options.connection));
+  return selectVerifierFrom(
+    hexToBytes(options.nullifier),
+    decodeRegisteredVerifiers(registryInfo.data),
+    decodeVerifierWeights(weightsInfo.data),
+    now
+  );
+}
+
+/**
+ * * The selection itself, for callers that already hold the account data
+ */
+export async function selectVerifierFrom(
+  nullifier: Uint8Array,
+  registered: PublicKey[],
+  entries: VerifierWeightEntry[],
+  now: number
+): Promise<PublicKey | null> {
+  let best: { draw: bigint; verifier: PublicKey } | null = null;
+
+  for (const entry of entries) {
+    if (entry.weight === 0) continue;
+    if (!registered.some((key) => key.equals(entry.verifier))) continue;
+    if (now >= entry.lastHeartbeat + VERIFIER_LIVENESS_WINDOW_SECS) continue;
+
+    let draw = 0n;
+    for (let index = 0; index < entry.weight; index++) {
+      const candidate = await selectionDraw(nullifier, entry.verifier, index);
+      if (candidate > draw) draw = candidate;
+    }
+
+    if (
+      best === null ||
+      draw > best.draw ||
+      (draw === best.draw && compareBytes(entry.verifier.toBytes(), best.verifier.toBytes()) > 0)
+    ) {
+      best = { draw, verifier: entry.verifier };
+    }
+  }
+
+  return best?.verifier ?? null;
+}
+
+// * Draw `index` for (nullifier, verifier), as in the program's selection_draw
+async function selectionDraw(
+  nullifier: Uint8Array,
+  verifier: PublicKey,
+  index: number
+): Promise<bigint> {
+  const input = new Uint8Array(VERIFIER_SELECTION_SEED.length + 32 + 32 + 1);
+  input.set(VERIFIER_SELECTION_SEED, 0);
+  input.set(nullifier, VERIFIER_SELECTION_SEED.length);
+  input.set(verifier.toBytes(), VERIFIER_SELECTION_SEED.length + 32);
+  input[input.length - 1] = index;
+
+  const digest = await sha256(input);
+  return new DataView(digest.buffer, digest.byteOffset, 8).getBigUint64(0, false);
+}
+
+async function sha256(data: Uint8Array): Promise<Uint8Array> {
+  // Browser (WebCrypto)
+  if (globalThis.crypto?.subtle?.digest) {
+    const digest = await globalThis.crypto.subtle.digest('SHA-256', new Uint8Array(data).buffer);
+    return new Uint8Array(digest);
+  }
+
+  // Node.js fallback
+  const { createHash } = await import('node:crypto');
+  return new Uint8Array(createHash('sha256').update(data).digest());
+}
+
+function compareBytes(a: Uint8Array, b: Uint8Array): number {
+  for (let i = 0; i < a.length; i++) {
+    if (a[i] !== b[i]) return a[i] - b[i];
+  }
+  return 0;
+}
+
+// * The program judges liveness by the Clock sysvar, so prefer the cluster's time to the local one
+async function clusterTime(connection: Connection): Promise<number> {
+  const blockTime = await connection.getBlockTime(await connection.getSlot());
+  return blockTime ?? Math.floor(Date.now() / 1000);
+}
+
+// * VerifierRegistry.verifiers
+export function decodeRegisteredVerifiers(data: Uint8Array): PublicKey[] {
+  const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
+  const count = view.getUint32(8, true);
+  const verifiers: PublicKey[] = [];
+  for (let i = 0; i < count; i++) {
+    const offset = 12 + i * 32;
+    verifiers.push(new PublicKey(data.slice(offset, offset + 32)));
+  }
+  return verifiers;
+}
+
+// * VerifierWeights.entries
+export function decodeVerifierWeights(data: Uint8Array): VerifierWeightEntry[] {
+  const view = new DataView(data.buffer, data.byteOffset, data.byteLength);
+  const count = view.getUint32(8, true);
+  const entries: VerifierWeightEntry[] = [];
+  for (let i = 0; i < count; i++) {
+    const offset = 12 + i * 41;
+    entries.push({
+      verifier: new PublicKey(data.slice(offset, offset + 32)),
+      weight: data[offset + 32],
+      lastHeartbeat: Number(view.getBigInt64(offset + 33, true))
+    });
+  }
+  return entries;
+}
@@ -0,0 +1,106 @@
+// * Verification warm path
+// * For latency-sensitive apps (games, trading) that know the nullifier before the user submits:
+// * everything verify_auth needs that doesn't depend on the proof is done ahead of time, so the
+// * confirmation-time critical path is only the verify_auth transaction itself.
+// *
+// * Warming:
+// * 1. Loads the program IDL (a network fetch in browsers) into the cache
+// * 2. Derives the verify_auth PDAs (see deriveVerifyAuthAccounts)
+// * 3. Optionally pre-funds the accounts verify_auth creates with their rent, so the
+// *    verification only allocates them
+// *
+// * Pre-funded lamports sit in program PDAs nobody can sign for: they are only recovered by the
+// * verification that creates the account, so only pre-fund logins that are about to happen.
+
+import { AnchorProvider, Wallet } from '@coral-xyz/anchor';
+import { Connection, PublicKey, SystemProgram, Transaction } from '@solana/web3.js';
+import {
+  VEILED_PROGRAM_ID,
+  deriveVerifyAuthAccounts,
+  encodeDomainToBytes32,
+  hexToBytes,
+  loadIdl
+} from './program.js';
+import type { VerifyAuthAccounts } from './program.js';
+
+// * Account sizes (8-byte discriminator + MAX_SIZE) of what verify_auth creates
+export const NULLIFIER_ACCOUNT_SPACE = 8 + 311;
+export const PAYER_RATE_LIMIT_SPACE = 8 + 72;
+
+export interface PrewarmVerificationOptions {
+  nullifier: string; // Hex string
+  domain: string;
+  connection: Connection;
+  wallet: Wallet;
+  prefund?: boolean; // * Send the rent for the nullifier / rate limit accounts now (default false)
+  programId?: PublicKey; // * Veiled instance the verification will go to (default: VEILED_PROGRAM_ID)
+}
+
+export interface WarmVerification {
+  accounts: VerifyAuthAccounts; // * Pass to submitVerificationResultToChain({ accounts })
+  prefundSignature?: string; // * Set when a pre-funding transaction was sent
+}
+
+/**
+ * * Prepares a verify_auth submission for a known nullifier ahead of the login
+ * *
+ * * @param options - Nullifier, domain and fee payer of the expected verification
+ * * @returns Derived accounts to reuse at submission, and the pre-funding signature if any
+ */
+export async function prewarmVerification(
+  options: PrewarmVerificationOptions
+): Promise<WarmVerification> {
+  const { nullifier, domain, connection, wallet } = options;
+  const programId = options.programId ?? VEILED_PROGRAM_ID;
+
+  const accounts = await deriveVerifyAuthAccounts(
+    hexToBytes(nullifier),
+    encodeDomainToBytes32(domain),
+    wallet.publicKey,
+    programId
+  );
+  await loadIdl();
+
+  if (!options.prefund) {
+    return { accounts };
+  }
+
+  const prefundSignature = await prefundAccounts(connection, wallet, programId, [
+    [accounts.nullifierAccount, NULLIFIER_ACCOUNT_SPACE],
+    [accounts.payerRateLimit, PAYER_RATE_LIMIT_SPACE]
+  ]);
+  return { accounts, prefundSignature };
+}
+
+// * Tops each not-yet-created account up to its rent-exempt minimum in one transaction
+// * Returns undefined without sending anything when every account is created or funded
+async function prefundAccounts(
+  connection: Connection,
+  wallet: Wallet,
+  programId: PublicKey,
+  targets: [PublicKey, number][]
+): Promise<string | undefined> {
+  const infos = await connection.getMultipleAccountsInfo(targets.map(([address]) => address));
+
+  const transaction = new Transaction();
+  for (const [index, [address, space]] of targets.entries()) {
+    const info = infos[index];
+    // * Already created by an earlier verification
+    if (info?.owner.equals(programId)) continue;
+
+    const required = await connection.getMinimumBalanceForRentExemption(space);
+    const lamports = required - (info?.lamports ?? 0);
+    if (lamports > 0) {
+      transaction.add(
+        SystemProgram.transfer({ fromPubkey: wallet.publicKey, toPubkey: address, lamports })
+      );
+    }
+  }
+
+  if (transaction.instructions.length === 0) {
+    return undefined;
+  }
+
+  const provider = new AnchorProvider(connection, wallet, { commitment: 'confirmed' });
+  return provider.sendAndConfirm(transaction);
+}
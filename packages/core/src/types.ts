@@ -50,7 +50,13 @@ export type VeiledConfig = {
   quicknodeApiKey?: string;
   quicknodeEndpoint?: string;
   
-  /** Program ID as base58 string; optional until Anchor integration exists */
+  /**
+   * Hex SHA-256 of each circuit's verification key, as registered on-chain.
+   * When set for the circuit used, results carry it (V5) - required by domains that pin a key hash.
+   */
+  verificationKeyHashes?: Partial<Record<'wallet_ownership' | 'balance_range' | 'nft_ownership', string>>;
+
+  /** Veiled program ID as base58 string, for white-label deployments (default: VEILED_PROGRAM_ID) */
   programId?: string;
   /** Optional wallet adapter - can be a Veiled WalletAdapter or a Solana wallet adapter */
   wallet?: WalletAdapter | SolanaWalletAdapter;
@@ -88,6 +94,7 @@ export enum Permission {
   RevealStakingPositions = 'reveal_staking_positions',
   RevealDeFiPositions = 'reveal_defi_positions',
   SignTransactions = 'sign_transactions',
+  ReceiveNotifications = 'receive_notifications',
 }
 
 export interface PermissionRequest {
@@ -168,6 +168,13 @@ export class PermissionModal {
         risk: 'CRITICAL - Can spend your funds',
         severity: 'critical',
       },
+      [Permission.ReceiveNotifications]: {
+        icon: '🔔',
+        name: 'Push Notifications',
+        description: 'Lets the app message you through an encrypted channel',
+        risk: 'LOW - Revocable, reveals no wallet data',
+        severity: 'low',
+      },
     };
     
     return info[permission] || { icon: '❓', name: permission, description: 'Unknown permission', risk: 'UNKNOWN', severity: 'medium' };
@@ -23,7 +23,8 @@ import {
   generateProof, 
   verifyProof, 
   createVerificationResult,
-  hashProofAsync
+  hashProofAsync,
+  hashPublicInputsAsync
 } from './proof/generator.js';
 import { prepareBalanceRangeCircuitInputs, prepareNFTOwnershipCircuitInputs } from './proof/generator.js';
 import { QuicknodeClient } from './providers/quicknode-client.js';
@@ -33,11 +34,15 @@ import { adaptSolanaWallet, hasSolanaTransactionSigning, type SolanaWalletAdapte
 import type { Transaction, VersionedTransaction } from '@solana/web3.js';
 import {
   submitVerificationResultToChain,
+  hexToBytes,
+  encodeDomainToBytes32,
+  normalizeDomain,
   grantPermissionsOnChain,
   revokePermissionsOnChain,
   logPermissionAccessOnChain
 } from './solana/program.js';
-import { Connection, clusterApiUrl } from '@solana/web3.js';
+import { buildVerificationMessage, CIRCUIT_IDS } from './solana/verification-message.js';
+import { Connection, PublicKey, clusterApiUrl } from '@solana/web3.js';
 import type { Wallet } from '@coral-xyz/anchor';
 import { PermissionModal } from './ui/permission-modal.js';
 
@@ -185,6 +190,13 @@ export class VeiledAuth {
     return new Connection(clusterApiUrl('devnet'), 'confirmed');
   }
 
+  /**
+   * * Veiled instance from config (white-label deployments); undefined for the default program
+   */
+  private programId(): PublicKey | undefined {
+    return this.config.programId ? new PublicKey(this.config.programId) : undefined;
+  }
+
   /**
    * * Sets Solana connection and wallet for on-chain operations
    * * Required for proof submission to Anchor program
@@ -207,6 +219,10 @@ export class VeiledAuth {
     console.log('🔵 [VEILED] ========================================');
     console.log('🔵 [VEILED] VeiledAuth.signIn() called');
     console.log('🔵 [VEILED] Options:', JSON.stringify(options, null, 2));
+
+    // * Prove and register under the canonical domain (see normalizeDomain), so the verifier
+    // * signs the same bytes verify_auth checks and case variants share one session
+    options = { ...options, domain: normalizeDomain(options.domain) };
     
     try {
       // * Stage 1: Wallet Connect
@@ -529,18 +545,28 @@ export class VeiledAuth {
           progress?.onProgress?.(0, 'Sign verification result...');
           
         // * Sign the verification result message for additional security
-        // * Message format: proof_hash (32) + is_valid (1) + timestamp (8) = 41 bytes
+        // * Message format: proof_hash (32) + is_valid (1) + timestamp (8) + nullifier (32) + domain (32)
+        // *                 + public_inputs_hash (32) + circuit_id (4) [+ vk_hash (32)] = 141 [173] bytes
         const proofHash = await hashProofAsync(proofResult.proof);
         const timestamp = BigInt(Math.floor(Date.now() / 1000));
+        const nullifierBytes = hexToBytes(proofResult.publicInputs.nullifier);
+        const domainBytes = encodeDomainToBytes32(options.domain);
+        const publicInputsHash = await hashPublicInputsAsync(proofResult.publicInputsArray);
+        const circuitId = CIRCUIT_IDS[proofResult.circuitType];
+        const vkHashHex = this.config.verificationKeyHashes?.[proofResult.circuitType];
+        const vkHash = vkHashHex ? hexToBytes(vkHashHex) : undefined;
         
         // * Create message to sign
-        const messageToSign = new Uint8Array(41);
-        messageToSign.set(proofHash, 0);
-        messageToSign[32] = isValid ? 1 : 0;
-        const timestampBytes = new Uint8Array(8);
-        const timestampView = new DataView(timestampBytes.buffer);
-        timestampView.setBigUint64(0, timestamp, true); // little-endian
-        messageToSign.set(timestampBytes, 33);
+        const messageToSign = buildVerificationMessage({
+          isValid,
+          proofHash,
+          timestamp,
+          nullifier: nullifierBytes,
+          domain: domainBytes,
+          publicInputsHash,
+          circuitId,
+          vkHash
+        });
         
         // * Sign the message using wallet adapter
         let signature: Uint8Array;
@@ -572,7 +598,13 @@ export class VeiledAuth {
         const verificationResult = await createVerificationResult(
           proofResult.proof,
           isValid,
-          signature
+          signature,
+          nullifierBytes,
+          timestamp,
+          domainBytes,
+          publicInputsHash,
+          circuitId,
+          vkHash
         );
         
         const submitResult = await submitVerificationResultToChain({
@@ -580,7 +612,8 @@ export class VeiledAuth {
           nullifier: proofResult.publicInputs.nullifier,
           domain: options.domain,
           connection: this.connection,
-          wallet: this.wallet
+          wallet: this.wallet,
+          programId: this.programId()
         });
         txSignature = submitResult.signature;
           progress?.onProgress?.(100, 'Submitted');
@@ -649,16 +682,26 @@ export class VeiledAuth {
     }
   }
 
-  async verifySession(nullifier: string): Promise<{ valid: boolean; expired?: boolean }> {
+  async verifySession(
+    nullifier: string,
+    domain: string | undefined = this.currentDomain
+  ): Promise<{ valid: boolean; expired?: boolean }> {
     // * Query on-chain nullifier registry to verify session
     if (!this.connection) {
       throw new Error('Connection not set. Call setSolanaConnection() first.');
     }
+
+    // * Sessions are kept per domain (PDA seeds include sha256(domain))
+    if (!domain) {
+      throw new Error('Session domain is unknown. Pass the domain to verifySession().');
+    }
     
     try {
       const { verifyNullifierOnChain, VEILED_PROGRAM_ID } = await import('./solana/program.js');
       const { Program } = await import('@coral-xyz/anchor');
       
+      const programId = this.programId() ?? VEILED_PROGRAM_ID;
+
       // * Load IDL to fetch account data
       let program: any = null;
       try {
@@ -669,13 +712,19 @@ export class VeiledAuth {
           const provider = new AnchorProvider(this.connection, this.wallet, {
             commitment: 'confirmed'
           });
-          program = new Program(idl, provider);
+          program = new Program({ ...idl, address: programId.toBase58() }, provider);
         }
       } catch (idlError) {
         // * IDL loading failed, will use fallback
       }
       
-      const result = await verifyNullifierOnChain(this.connection, nullifier, program);
+      const result = await verifyNullifierOnChain(
+        this.connection,
+        nullifier,
+        domain,
+        program,
+        programId
+      );
       
       if (!result.exists) {
         return { valid: false };
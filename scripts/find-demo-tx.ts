@@ -1,6 +1,6 @@
 #!/usr/bin/env tsx
 // * Quick script to find a demo transaction for viewing on Solscan
-// * Usage: npx tsx scripts/find-demo-tx.ts
+// * Usage: npx tsx scripts/find-demo-tx.ts [rpc-url] [nullifier-hex domain]
 
 import { Connection } from '@solana/web3.js';
 import { showDemoTransaction, getNullifierPdaAddress } from '../packages/core/src/solana/find-demo-transaction.js';
@@ -21,11 +21,12 @@ async function main() {
     process.exit(1);
   }
   
-  // * Example: If you have a nullifier, you can also look it up
-  if (process.argv[3]) {
+  // * Example: If you have a nullifier and its domain, you can also look it up
+  if (process.argv[3] && process.argv[4]) {
     const nullifierHex = process.argv[3];
+    const domain = process.argv[4];
     console.log('\n🔍 Looking up nullifier PDA...');
-    getNullifierPdaAddress(nullifierHex);
+    await getNullifierPdaAddress(nullifierHex, domain);
   }
 }
 